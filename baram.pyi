@@ -0,0 +1,43 @@
+"""Type stubs for the baram extension module (see src/python.rs)."""
+
+from typing import Any, Literal, Optional, overload
+
+__version__: str
+
+def parse_article(html: str, url: str) -> dict[str, Any]:
+    """Parse a Naver News article page; raises ValueError if it is not one."""
+
+@overload
+def extract_ontology(
+    title: str, content: str, article_id: str = "", format: Literal["json"] = "json"
+) -> dict[str, Any]: ...
+@overload
+def extract_ontology(
+    title: str, content: str, article_id: str = "", format: Literal["turtle", "ttl"] = ...
+) -> str: ...
+
+class EmbeddingClient:
+    def __init__(self, url: str = "http://localhost:8090", model: Optional[str] = None) -> None: ...
+    def embed(self, text: str) -> list[float]: ...
+    def embed_batch(self, texts: list[str]) -> list[list[float]]: ...
+
+class VectorStore:
+    def __init__(
+        self,
+        url: str = "http://localhost:9200",
+        index: str = "baram-articles",
+        username: Optional[str] = None,
+        password: Optional[str] = None,
+    ) -> None: ...
+    def search(
+        self,
+        query: str,
+        k: int = 10,
+        category: Optional[str] = None,
+        embedding: Optional[list[float]] = None,
+    ) -> list[dict[str, Any]]: ...
+    def search_vector(
+        self, embedding: list[float], k: int = 10, category: Optional[str] = None
+    ) -> list[dict[str, Any]]: ...
+    def get(self, id: str) -> Optional[dict[str, Any]]: ...
+    def count(self) -> int: ...
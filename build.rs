@@ -0,0 +1,27 @@
+//! Build script: embeds the git commit for run records
+//!
+//! `BARAM_GIT_COMMIT` is taken from the environment when set (e.g. a Docker
+//! build argument, where `.git` is not in the build context), otherwise
+//! from `git rev-parse`. Builds outside a checkout simply have no commit.
+
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=BARAM_GIT_COMMIT");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+
+    let commit = std::env::var("BARAM_GIT_COMMIT").ok().or_else(|| {
+        let output = Command::new("git")
+            .args(["rev-parse", "--short=12", "HEAD"])
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    });
+    if let Some(commit) = commit.filter(|c| !c.is_empty()) {
+        println!("cargo:rustc-env=BARAM_GIT_COMMIT={commit}");
+    }
+}
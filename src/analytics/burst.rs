@@ -0,0 +1,632 @@
+//! Statistical burst detection over hourly counts
+//!
+//! Flags hours in which a keyword or entity is mentioned far more often than
+//! its recent baseline:
+//! - **Z-score**: compares each hour to the mean and standard deviation of the
+//!   preceding `baseline_hours`
+//! - **EWMA**: compares each hour to an exponentially weighted moving mean and
+//!   variance, which adapts faster to gradual drift
+//!
+//! Bursts are reported as [`Spike`]s wrapped in [`BurstEvent`]s, which map
+//! onto `AlertCondition::KeywordSpike` / `AlertCondition::EntitySurge` so alert
+//! rules can be evaluated against real counts (see
+//! `NotificationManager::process_bursts`).
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::keyword_trends::{KeywordTrend, Spike};
+use crate::notifications::AlertCondition;
+
+/// Baseline points required before a burst can be flagged
+const MIN_BASELINE_POINTS: usize = 3;
+
+/// Keyword/entity name in an alert rule that matches every burst
+pub const WILDCARD: &str = "*";
+
+/// Statistical method used to flag bursts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BurstMethod {
+    /// Z-score against a trailing window
+    #[default]
+    ZScore,
+    /// Exponentially weighted moving average and variance
+    Ewma,
+}
+
+impl BurstMethod {
+    /// Parse a method name (`zscore` or `ewma`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "zscore" | "z-score" => Ok(Self::ZScore),
+            "ewma" => Ok(Self::Ewma),
+            other => Err(format!("Unknown burst method: {other}. Use zscore or ewma")),
+        }
+    }
+
+    /// Get the method name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ZScore => "zscore",
+            Self::Ewma => "ewma",
+        }
+    }
+}
+
+/// Burst detection settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurstConfig {
+    /// Detection method
+    #[serde(default)]
+    pub method: BurstMethod,
+    /// Standard deviations above the baseline needed to flag a burst
+    #[serde(default = "default_sensitivity")]
+    pub sensitivity: f64,
+    /// Minimum count in the hour (filters noise on rare terms)
+    #[serde(default = "default_min_count")]
+    pub min_count: u64,
+    /// Hours of history used as the z-score baseline
+    #[serde(default = "default_baseline_hours")]
+    pub baseline_hours: usize,
+    /// EWMA smoothing factor (0 < alpha <= 1)
+    #[serde(default = "default_ewma_alpha")]
+    pub ewma_alpha: f64,
+}
+
+fn default_sensitivity() -> f64 {
+    3.0
+}
+
+fn default_min_count() -> u64 {
+    5
+}
+
+fn default_baseline_hours() -> usize {
+    24
+}
+
+fn default_ewma_alpha() -> f64 {
+    0.3
+}
+
+impl Default for BurstConfig {
+    fn default() -> Self {
+        Self {
+            method: BurstMethod::default(),
+            sensitivity: default_sensitivity(),
+            min_count: default_min_count(),
+            baseline_hours: default_baseline_hours(),
+            ewma_alpha: default_ewma_alpha(),
+        }
+    }
+}
+
+impl BurstConfig {
+    /// Validate the settings
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sensitivity <= 0.0 {
+            return Err("Burst sensitivity must be greater than 0".to_string());
+        }
+        if self.baseline_hours < MIN_BASELINE_POINTS {
+            return Err(format!(
+                "Burst baseline_hours must be at least {MIN_BASELINE_POINTS}"
+            ));
+        }
+        if !(self.ewma_alpha > 0.0 && self.ewma_alpha <= 1.0) {
+            return Err("Burst ewma_alpha must be in (0, 1]".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Detects bursts in hourly count series
+#[derive(Debug, Clone, Default)]
+pub struct BurstDetector {
+    config: BurstConfig,
+}
+
+impl BurstDetector {
+    /// Create a detector
+    #[must_use]
+    pub fn new(config: BurstConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the detector settings
+    #[must_use]
+    pub fn config(&self) -> &BurstConfig {
+        &self.config
+    }
+
+    /// Detect bursts in a chronological `(hour, count)` series
+    #[must_use]
+    pub fn detect(&self, series: &[(DateTime<Utc>, u64)]) -> Vec<Spike> {
+        match self.config.method {
+            BurstMethod::ZScore => self.detect_zscore(series),
+            BurstMethod::Ewma => self.detect_ewma(series),
+        }
+    }
+
+    /// Detect bursts in a keyword trend's data points
+    #[must_use]
+    pub fn detect_trend(&self, trend: &KeywordTrend) -> Vec<Spike> {
+        let series: Vec<_> = trend
+            .data_points()
+            .iter()
+            .map(|p| (p.timestamp, p.count))
+            .collect();
+        self.detect(&series)
+    }
+
+    fn detect_zscore(&self, series: &[(DateTime<Utc>, u64)]) -> Vec<Spike> {
+        let mut spikes = Vec::new();
+
+        for (i, &(timestamp, count)) in series.iter().enumerate() {
+            let baseline = &series[i.saturating_sub(self.config.baseline_hours)..i];
+            if baseline.len() < MIN_BASELINE_POINTS {
+                continue;
+            }
+
+            let n = baseline.len() as f64;
+            let mean = baseline.iter().map(|(_, c)| *c as f64).sum::<f64>() / n;
+            let variance = baseline
+                .iter()
+                .map(|(_, c)| (*c as f64 - mean).powi(2))
+                .sum::<f64>()
+                / n;
+
+            if let Some(spike) = self.check(timestamp, count, mean, variance.sqrt()) {
+                spikes.push(spike);
+            }
+        }
+
+        spikes
+    }
+
+    fn detect_ewma(&self, series: &[(DateTime<Utc>, u64)]) -> Vec<Spike> {
+        let alpha = self.config.ewma_alpha;
+        let mut spikes = Vec::new();
+        let Some(&(_, first)) = series.first() else {
+            return spikes;
+        };
+
+        let mut mean = first as f64;
+        let mut variance: f64 = 0.0;
+
+        for (i, &(timestamp, count)) in series.iter().enumerate().skip(1) {
+            if i >= MIN_BASELINE_POINTS {
+                if let Some(spike) = self.check(timestamp, count, mean, variance.sqrt()) {
+                    spikes.push(spike);
+                }
+            }
+
+            let diff = count as f64 - mean;
+            let increment = alpha * diff;
+            mean += increment;
+            variance = (1.0 - alpha) * (variance + diff * increment);
+        }
+
+        spikes
+    }
+
+    /// Compare a count against its baseline
+    ///
+    /// The standard deviation is floored at `sqrt(mean)` (Poisson noise) and
+    /// 1.0, so flat or near-zero baselines do not turn every uptick into a burst.
+    fn check(
+        &self,
+        timestamp: DateTime<Utc>,
+        count: u64,
+        mean: f64,
+        std_dev: f64,
+    ) -> Option<Spike> {
+        if count < self.config.min_count {
+            return None;
+        }
+
+        let noise = std_dev.max(mean.sqrt()).max(1.0);
+        let z_score = (count as f64 - mean) / noise;
+        if z_score < self.config.sensitivity {
+            return None;
+        }
+
+        Some(Spike {
+            timestamp,
+            count,
+            expected: mean,
+            magnitude: if mean > 0.0 {
+                count as f64 / mean
+            } else {
+                count as f64
+            },
+            z_score,
+        })
+    }
+}
+
+/// Bucket timestamps into hourly counts between `start` and `end`
+///
+/// Hours without timestamps are included with a zero count, so the series is
+/// continuous; timestamps outside the range are ignored.
+#[must_use]
+pub fn hourly_counts<I>(
+    timestamps: I,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, u64)>
+where
+    I: IntoIterator<Item = DateTime<Utc>>,
+{
+    let hour = Duration::hours(1);
+    let (Ok(start), Ok(end)) = (start.duration_trunc(hour), end.duration_trunc(hour)) else {
+        return Vec::new();
+    };
+
+    let mut counts: HashMap<DateTime<Utc>, u64> = HashMap::new();
+    for timestamp in timestamps {
+        if let Ok(bucket) = timestamp.duration_trunc(hour) {
+            if bucket >= start && bucket <= end {
+                *counts.entry(bucket).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut series = Vec::new();
+    let mut current = start;
+    while current <= end {
+        series.push((current, counts.get(&current).copied().unwrap_or(0)));
+        current += hour;
+    }
+    series
+}
+
+/// What a burst was detected for
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "name", rename_all = "lowercase")]
+pub enum BurstSubject {
+    /// Keyword from article text
+    Keyword(String),
+    /// Named entity (person, organization, ...)
+    Entity(String),
+}
+
+impl BurstSubject {
+    /// Get the keyword or entity name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Keyword(name) | Self::Entity(name) => name,
+        }
+    }
+
+    /// Get the subject kind (`keyword` or `entity`)
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Keyword(_) => "keyword",
+            Self::Entity(_) => "entity",
+        }
+    }
+}
+
+/// An article behind a burst, linked from its alert
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleLink {
+    /// Article title
+    pub title: String,
+    /// Article URL
+    pub url: String,
+    /// Publication time (crawl time if unknown)
+    pub published_at: DateTime<Utc>,
+}
+
+/// A burst detected for a keyword or entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BurstEvent {
+    /// Keyword or entity that burst
+    pub subject: BurstSubject,
+    /// Detected spike
+    pub spike: Spike,
+    /// Length of the counting window in minutes
+    pub window_minutes: u32,
+    /// Articles mentioning the subject in the burst hour, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub articles: Vec<ArticleLink>,
+}
+
+impl BurstEvent {
+    /// Create an event for an hourly spike
+    #[must_use]
+    pub fn new(subject: BurstSubject, spike: Spike) -> Self {
+        Self {
+            subject,
+            spike,
+            window_minutes: 60,
+            articles: Vec::new(),
+        }
+    }
+
+    /// Attach the articles behind the burst
+    #[must_use]
+    pub fn with_articles(mut self, articles: Vec<ArticleLink>) -> Self {
+        self.articles = articles;
+        self
+    }
+
+    /// Alert condition describing this burst
+    ///
+    /// The threshold is the baseline rounded up, i.e. the alert reads
+    /// "appears more than <expected> times in the window".
+    #[must_use]
+    pub fn to_alert_condition(&self) -> AlertCondition {
+        let threshold = (self.spike.expected.ceil() as u32).max(1);
+        let window_minutes = self.window_minutes;
+
+        match &self.subject {
+            BurstSubject::Keyword(keyword) => AlertCondition::KeywordSpike {
+                keyword: keyword.clone(),
+                threshold,
+                window_minutes,
+            },
+            BurstSubject::Entity(entity) => AlertCondition::EntitySurge {
+                entity: entity.clone(),
+                threshold,
+                window_minutes,
+            },
+        }
+    }
+
+    /// Match this burst against an alert rule's condition
+    ///
+    /// A `KeywordSpike` rule matches keyword bursts and an `EntitySurge` rule
+    /// matches entity bursts when the name is equal ignoring case (or the rule
+    /// uses `"*"`) and the hourly count reached the rule's threshold. Returns
+    /// the rule's condition with a wildcard replaced by the actual name, so
+    /// bursts of different keywords are deduplicated separately.
+    #[must_use]
+    pub fn matched_condition(&self, condition: &AlertCondition) -> Option<AlertCondition> {
+        let (name, threshold) = match (&self.subject, condition) {
+            (
+                BurstSubject::Keyword(_),
+                AlertCondition::KeywordSpike {
+                    keyword, threshold, ..
+                },
+            ) => (keyword, *threshold),
+            (
+                BurstSubject::Entity(_),
+                AlertCondition::EntitySurge {
+                    entity, threshold, ..
+                },
+            ) => (entity, *threshold),
+            _ => return None,
+        };
+
+        if name != WILDCARD && name.to_lowercase() != self.subject.name().to_lowercase() {
+            return None;
+        }
+        if self.spike.count < u64::from(threshold) {
+            return None;
+        }
+
+        let mut concrete = condition.clone();
+        match &mut concrete {
+            AlertCondition::KeywordSpike { keyword: name, .. }
+            | AlertCondition::EntitySurge { entity: name, .. } => {
+                *name = self.subject.name().to_string();
+            }
+            _ => {}
+        }
+        Some(concrete)
+    }
+
+    /// Human-readable alert message, followed by one line per linked article
+    #[must_use]
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} '{}' burst: {} mentions in the hour from {} (expected {:.1}, z={:.1})",
+            if matches!(self.subject, BurstSubject::Keyword(_)) {
+                "Keyword"
+            } else {
+                "Entity"
+            },
+            self.subject.name(),
+            self.spike.count,
+            self.spike.timestamp.format("%Y-%m-%d %H:%M UTC"),
+            self.spike.expected,
+            self.spike.z_score,
+        );
+        for article in &self.articles {
+            message.push_str(&format!("\n- {} {}", article.title, article.url));
+        }
+        message
+    }
+
+    /// Alert metadata describing the burst
+    #[must_use]
+    pub fn metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = vec![
+            ("burst_kind".to_string(), self.subject.kind().to_string()),
+            ("burst_name".to_string(), self.subject.name().to_string()),
+            ("burst_count".to_string(), self.spike.count.to_string()),
+            (
+                "burst_expected".to_string(),
+                format!("{:.2}", self.spike.expected),
+            ),
+            (
+                "burst_z_score".to_string(),
+                format!("{:.2}", self.spike.z_score),
+            ),
+            ("burst_hour".to_string(), self.spike.timestamp.to_rfc3339()),
+        ];
+        if !self.articles.is_empty() {
+            let urls: Vec<&str> = self.articles.iter().map(|a| a.url.as_str()).collect();
+            metadata.push(("articles".to_string(), urls.join("\n")));
+        }
+        metadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(counts: &[u64]) -> Vec<(DateTime<Utc>, u64)> {
+        let start = Utc::now().duration_trunc(Duration::hours(1)).unwrap();
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (start + Duration::hours(i as i64), *c))
+            .collect()
+    }
+
+    #[test]
+    fn test_zscore_detects_burst() {
+        let data = series(&[4, 5, 6, 5, 4, 5, 30, 5]);
+        let detector = BurstDetector::default();
+
+        let spikes = detector.detect(&data);
+        assert_eq!(spikes.len(), 1);
+        assert_eq!(spikes[0].count, 30);
+        assert_eq!(spikes[0].timestamp, data[6].0);
+        assert!(spikes[0].z_score >= 3.0);
+        assert!((spikes[0].expected - 4.83).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_sensitivity_and_min_count() {
+        let data = series(&[4, 5, 6, 5, 4, 5, 10, 5]);
+
+        assert!(BurstDetector::default().detect(&data).is_empty());
+
+        let sensitive = BurstDetector::new(BurstConfig {
+            sensitivity: 2.0,
+            ..BurstConfig::default()
+        });
+        assert_eq!(sensitive.detect(&data).len(), 1);
+
+        // Rare terms: 0 → 3 is a large z-score but below min_count
+        let rare = series(&[0, 0, 0, 0, 3]);
+        assert!(sensitive.detect(&rare).is_empty());
+    }
+
+    #[test]
+    fn test_ewma_detects_burst() {
+        let data = series(&[10, 11, 9, 10, 12, 10, 45, 11]);
+        let detector = BurstDetector::new(BurstConfig {
+            method: BurstMethod::Ewma,
+            ..BurstConfig::default()
+        });
+
+        let spikes = detector.detect(&data);
+        assert_eq!(spikes.len(), 1);
+        assert_eq!(spikes[0].count, 45);
+    }
+
+    #[test]
+    fn test_hourly_counts() {
+        let start = Utc::now().duration_trunc(Duration::hours(1)).unwrap();
+        let timestamps = [
+            start + Duration::minutes(5),
+            start + Duration::minutes(50),
+            start + Duration::hours(2),
+            start - Duration::hours(1),
+        ];
+
+        let counts = hourly_counts(timestamps, start, start + Duration::hours(2));
+        assert_eq!(
+            counts.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+            vec![2, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_burst_event_conditions() {
+        let spike = BurstDetector::default()
+            .detect(&series(&[4, 5, 6, 5, 4, 5, 30]))
+            .remove(0);
+        let event = BurstEvent::new(BurstSubject::Keyword("반도체".to_string()), spike);
+
+        assert_eq!(
+            event.to_alert_condition(),
+            AlertCondition::KeywordSpike {
+                keyword: "반도체".to_string(),
+                threshold: 5,
+                window_minutes: 60,
+            }
+        );
+
+        let wildcard = AlertCondition::KeywordSpike {
+            keyword: WILDCARD.to_string(),
+            threshold: 20,
+            window_minutes: 60,
+        };
+        let matched = event.matched_condition(&wildcard).unwrap();
+        assert_eq!(matched.description(), event_condition_description(20));
+
+        let too_high = AlertCondition::KeywordSpike {
+            keyword: "반도체".to_string(),
+            threshold: 50,
+            window_minutes: 60,
+        };
+        assert!(event.matched_condition(&too_high).is_none());
+
+        let entity_rule = AlertCondition::EntitySurge {
+            entity: WILDCARD.to_string(),
+            threshold: 1,
+            window_minutes: 60,
+        };
+        assert!(event.matched_condition(&entity_rule).is_none());
+        assert!(event
+            .message()
+            .contains("Keyword '반도체' burst: 30 mentions"));
+        assert!(!event.metadata().iter().any(|(key, _)| key == "articles"));
+    }
+
+    #[test]
+    fn test_burst_event_links_articles() {
+        let spike = BurstDetector::default()
+            .detect(&series(&[4, 5, 6, 5, 4, 5, 30]))
+            .remove(0);
+        let url = "https://n.news.naver.com/mnews/article/001/0014123456";
+        let event = BurstEvent::new(BurstSubject::Keyword("반도체".to_string()), spike)
+            .with_articles(vec![ArticleLink {
+                title: "반도체 수출 급증".to_string(),
+                url: url.to_string(),
+                published_at: Utc::now(),
+            }]);
+
+        assert!(event
+            .message()
+            .ends_with(&format!("\n- 반도체 수출 급증 {url}")));
+        assert!(event
+            .metadata()
+            .contains(&("articles".to_string(), url.to_string())));
+    }
+
+    fn event_condition_description(threshold: u32) -> String {
+        AlertCondition::KeywordSpike {
+            keyword: "반도체".to_string(),
+            threshold,
+            window_minutes: 60,
+        }
+        .description()
+    }
+
+    #[test]
+    fn test_config_validation_and_toml() {
+        assert!(BurstConfig::default().validate().is_ok());
+        assert!(BurstConfig {
+            ewma_alpha: 0.0,
+            ..BurstConfig::default()
+        }
+        .validate()
+        .is_err());
+
+        let config: BurstConfig = toml::from_str("method = \"ewma\"\nsensitivity = 2.5").unwrap();
+        assert_eq!(config.method, BurstMethod::Ewma);
+        assert_eq!(config.min_count, 5);
+        assert_eq!(BurstMethod::parse("z-score").unwrap(), BurstMethod::ZScore);
+    }
+}
@@ -0,0 +1,540 @@
+//! Publisher coverage comparison
+//!
+//! Compares how publishers cover the same story, using the event clusters
+//! produced by `baram cluster` (articles grouped by embedding similarity).
+//! For each story and publisher it reports:
+//! - Volume: articles the publisher ran on the story
+//! - Timing: first publication and lag behind the first report
+//! - Sentiment: average [`SentimentAnalyzer`] article score
+//! - Emphasis: entities the publisher mentions more often than the others
+//!
+//! Article bodies are optional; without them titles are scored instead.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+
+use super::entity_trends::EntityType;
+use super::sentiment::SentimentAnalyzer;
+use crate::clustering::{ClusterArticle, EventCluster};
+use crate::ontology::{EntitySource, RelationExtractor};
+use crate::parser::Article;
+
+/// Publisher name used when a cluster article has none
+pub const UNKNOWN_PUBLISHER: &str = "unknown";
+
+/// How one publisher covered one story
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublisherCoverage {
+    /// Publisher name
+    pub publisher: String,
+    /// Articles on the story
+    pub articles: usize,
+    /// First article on the story
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_published: Option<DateTime<Utc>>,
+    /// Minutes between the story's first report and this publisher's
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lag_minutes: Option<i64>,
+    /// Average sentiment score in `[-1.0, 1.0]`
+    pub sentiment: f64,
+    /// Entities mentioned more often than by the other publishers, strongest first
+    pub emphasized_entities: Vec<String>,
+}
+
+/// Coverage of one story cluster
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoryCoverage {
+    /// Event cluster ID
+    pub event_id: String,
+    /// Event title
+    pub title: String,
+    /// Primary category
+    pub category: String,
+    /// Articles in the cluster
+    pub articles: usize,
+    /// Earliest publication in the cluster
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_reported: Option<DateTime<Utc>>,
+    /// Publisher that reported first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_publisher: Option<String>,
+    /// Difference between the most positive and most negative publisher
+    pub sentiment_spread: f64,
+    /// Per-publisher coverage, by volume then timing
+    pub publishers: Vec<PublisherCoverage>,
+}
+
+/// One publisher's coverage across all compared stories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublisherSummary {
+    /// Publisher name
+    pub publisher: String,
+    /// Stories covered
+    pub stories: usize,
+    /// Articles across those stories
+    pub articles: usize,
+    /// Stories this publisher reported first
+    pub first_reports: usize,
+    /// Median lag behind the first report, in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub median_lag_minutes: Option<i64>,
+    /// Average sentiment across stories
+    pub sentiment: f64,
+}
+
+/// Coverage comparison over a set of stories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// Stories covered by enough publishers
+    pub stories: Vec<StoryCoverage>,
+    /// Publisher totals, by stories covered
+    pub publishers: Vec<PublisherSummary>,
+}
+
+/// Compares publisher coverage within story clusters
+pub struct CoverageAnalyzer {
+    sentiment: SentimentAnalyzer,
+    extractor: RelationExtractor,
+    min_publishers: usize,
+    top_entities: usize,
+}
+
+impl Default for CoverageAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoverageAnalyzer {
+    /// Create an analyzer comparing stories with at least two publishers
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sentiment: SentimentAnalyzer::new(),
+            extractor: RelationExtractor::new(),
+            min_publishers: 2,
+            top_entities: 5,
+        }
+    }
+
+    /// Skip stories covered by fewer publishers
+    #[must_use]
+    pub fn with_min_publishers(mut self, min_publishers: usize) -> Self {
+        self.min_publishers = min_publishers.max(1);
+        self
+    }
+
+    /// Set how many emphasized entities to keep per publisher
+    #[must_use]
+    pub fn with_top_entities(mut self, top_entities: usize) -> Self {
+        self.top_entities = top_entities;
+        self
+    }
+
+    /// Compare coverage in each cluster
+    ///
+    /// `bodies` maps article URLs to stored articles; cluster articles without
+    /// a stored body are analyzed by title only.
+    #[must_use]
+    pub fn analyze(
+        &self,
+        clusters: &[EventCluster],
+        bodies: &HashMap<String, Article>,
+    ) -> CoverageReport {
+        let mut stories: Vec<StoryCoverage> = clusters
+            .iter()
+            .filter_map(|cluster| self.analyze_story(cluster, bodies))
+            .collect();
+        stories.sort_by(|a, b| {
+            b.publishers
+                .len()
+                .cmp(&a.publishers.len())
+                .then_with(|| b.articles.cmp(&a.articles))
+                .then_with(|| a.event_id.cmp(&b.event_id))
+        });
+
+        let publishers = summarize_publishers(&stories);
+        CoverageReport {
+            stories,
+            publishers,
+        }
+    }
+
+    fn analyze_story(
+        &self,
+        cluster: &EventCluster,
+        bodies: &HashMap<String, Article>,
+    ) -> Option<StoryCoverage> {
+        let mut by_publisher: BTreeMap<String, Vec<&ClusterArticle>> = BTreeMap::new();
+        for article in &cluster.articles {
+            let publisher = article
+                .publisher
+                .clone()
+                .filter(|p| !p.trim().is_empty())
+                .unwrap_or_else(|| UNKNOWN_PUBLISHER.to_string());
+            by_publisher.entry(publisher).or_default().push(article);
+        }
+        if by_publisher.len() < self.min_publishers {
+            return None;
+        }
+
+        // Per publisher: articles, first publication, sentiment sum, entity article counts
+        struct Acc {
+            articles: usize,
+            first: Option<DateTime<Utc>>,
+            sentiment_sum: f64,
+            entities: HashMap<String, usize>,
+        }
+        let mut accs: BTreeMap<String, Acc> = BTreeMap::new();
+        for (publisher, articles) in &by_publisher {
+            let mut acc = Acc {
+                articles: articles.len(),
+                first: None,
+                sentiment_sum: 0.0,
+                entities: HashMap::new(),
+            };
+            for article in articles {
+                let stored = bodies.get(&article.url);
+                let published = stored
+                    .and_then(|a| a.published_at)
+                    .or_else(|| article.published_at.as_deref().and_then(parse_timestamp));
+                acc.first = match (acc.first, published) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+
+                let body = stored.map(|a| a.body.as_str()).unwrap_or_default();
+                acc.sentiment_sum += self.sentiment.score_article(&article.title, body).score;
+                for entity in self.entities(&article.title, body) {
+                    *acc.entities.entry(entity).or_insert(0) += 1;
+                }
+            }
+            accs.insert(publisher.clone(), acc);
+        }
+
+        let first_reported = accs.values().filter_map(|a| a.first).min();
+        let first_publisher = first_reported.and_then(|first| {
+            accs.iter()
+                .find(|(_, a)| a.first == Some(first))
+                .map(|(p, _)| p.clone())
+        });
+
+        let mut publishers: Vec<PublisherCoverage> = accs
+            .iter()
+            .map(|(publisher, acc)| {
+                // Share of this publisher's articles mentioning an entity vs the others'
+                let others: Vec<&Acc> = accs
+                    .iter()
+                    .filter(|(p, _)| *p != publisher)
+                    .map(|(_, a)| a)
+                    .collect();
+                let other_articles: usize = others.iter().map(|a| a.articles).sum();
+                let mut emphasis: Vec<(String, f64)> = acc
+                    .entities
+                    .iter()
+                    .map(|(entity, count)| {
+                        let own = *count as f64 / acc.articles as f64;
+                        let other_count: usize = others
+                            .iter()
+                            .map(|a| a.entities.get(entity).copied().unwrap_or(0))
+                            .sum();
+                        let other = other_count as f64 / other_articles.max(1) as f64;
+                        (entity.clone(), own - other)
+                    })
+                    .filter(|(_, score)| *score > 0.0)
+                    .collect();
+                emphasis.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+                PublisherCoverage {
+                    publisher: publisher.clone(),
+                    articles: acc.articles,
+                    first_published: acc.first,
+                    lag_minutes: acc
+                        .first
+                        .zip(first_reported)
+                        .map(|(own, first)| (own - first).num_minutes()),
+                    sentiment: acc.sentiment_sum / acc.articles as f64,
+                    emphasized_entities: emphasis
+                        .into_iter()
+                        .take(self.top_entities)
+                        .map(|(entity, _)| entity)
+                        .collect(),
+                }
+            })
+            .collect();
+        publishers.sort_by(|a, b| {
+            b.articles
+                .cmp(&a.articles)
+                .then_with(|| {
+                    a.lag_minutes
+                        .unwrap_or(i64::MAX)
+                        .cmp(&b.lag_minutes.unwrap_or(i64::MAX))
+                })
+                .then_with(|| a.publisher.cmp(&b.publisher))
+        });
+
+        let (min, max) = publishers
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), p| {
+                (min.min(p.sentiment), max.max(p.sentiment))
+            });
+
+        Some(StoryCoverage {
+            event_id: cluster.event_id.clone(),
+            title: cluster.title.clone(),
+            category: cluster.category.clone(),
+            articles: cluster.articles.len(),
+            first_reported,
+            first_publisher,
+            sentiment_spread: max - min,
+            publishers,
+        })
+    }
+
+    /// Typed entities mentioned in an article, once each
+    fn entities(&self, title: &str, body: &str) -> HashSet<String> {
+        self.extractor
+            .extract_entities(&format!("{title} {body}"), EntitySource::Both)
+            .into_iter()
+            .filter(|e| EntityType::from_ontology(e.entity_type).is_some())
+            .map(|e| e.canonical_name.unwrap_or(e.text))
+            .collect()
+    }
+}
+
+fn summarize_publishers(stories: &[StoryCoverage]) -> Vec<PublisherSummary> {
+    struct Acc {
+        stories: usize,
+        articles: usize,
+        first_reports: usize,
+        lags: Vec<i64>,
+        sentiment_sum: f64,
+    }
+
+    let mut accs: BTreeMap<&str, Acc> = BTreeMap::new();
+    for story in stories {
+        for coverage in &story.publishers {
+            let acc = accs.entry(coverage.publisher.as_str()).or_insert(Acc {
+                stories: 0,
+                articles: 0,
+                first_reports: 0,
+                lags: Vec::new(),
+                sentiment_sum: 0.0,
+            });
+            acc.stories += 1;
+            acc.articles += coverage.articles;
+            acc.sentiment_sum += coverage.sentiment;
+            if story.first_publisher.as_deref() == Some(coverage.publisher.as_str()) {
+                acc.first_reports += 1;
+            }
+            acc.lags.extend(coverage.lag_minutes);
+        }
+    }
+
+    let mut summaries: Vec<PublisherSummary> = accs
+        .into_iter()
+        .map(|(publisher, mut acc)| {
+            acc.lags.sort_unstable();
+            PublisherSummary {
+                publisher: publisher.to_string(),
+                stories: acc.stories,
+                articles: acc.articles,
+                first_reports: acc.first_reports,
+                median_lag_minutes: acc.lags.get(acc.lags.len() / 2).copied(),
+                sentiment: acc.sentiment_sum / acc.stories as f64,
+            }
+        })
+        .collect();
+    summaries.sort_by(|a, b| {
+        b.stories
+            .cmp(&a.stories)
+            .then_with(|| b.articles.cmp(&a.articles))
+            .then_with(|| a.publisher.cmp(&b.publisher))
+    });
+    summaries
+}
+
+/// Parse an RFC 3339 timestamp
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+impl CoverageReport {
+    /// Keep only the first `limit` stories (publisher totals are unchanged)
+    pub fn truncate(&mut self, limit: usize) {
+        self.stories.truncate(limit);
+    }
+
+    /// Render one CSV row per story and publisher
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "event_id,title,category,publisher,articles,first_published,lag_minutes,sentiment,emphasized_entities\n",
+        );
+        for story in &self.stories {
+            for coverage in &story.publishers {
+                let _ = writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{:.3},{}",
+                    csv_field(&story.event_id),
+                    csv_field(&story.title),
+                    csv_field(&story.category),
+                    csv_field(&coverage.publisher),
+                    coverage.articles,
+                    coverage
+                        .first_published
+                        .map(|t| t.to_rfc3339())
+                        .unwrap_or_default(),
+                    coverage
+                        .lag_minutes
+                        .map(|l| l.to_string())
+                        .unwrap_or_default(),
+                    coverage.sentiment,
+                    csv_field(&coverage.emphasized_entities.join(";"))
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Quote a CSV field if it contains separators, quotes or newlines
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster_article(publisher: Option<&str>, title: &str, published_at: &str) -> ClusterArticle {
+        ClusterArticle {
+            id: title.to_string(),
+            title: title.to_string(),
+            category: "economy".to_string(),
+            publisher: publisher.map(String::from),
+            published_at: Some(published_at.to_string()),
+            url: format!("https://n.news.naver.com/{title}"),
+            similarity_to_centroid: 0.9,
+        }
+    }
+
+    fn cluster(event_id: &str, articles: Vec<ClusterArticle>) -> EventCluster {
+        EventCluster {
+            event_id: event_id.to_string(),
+            title: articles[0].title.clone(),
+            summary: String::new(),
+            article_count: articles.len(),
+            articles,
+            category: "economy".to_string(),
+            first_seen: None,
+            last_updated: None,
+            avg_similarity: 0.9,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_story_coverage() {
+        let clusters = vec![
+            cluster(
+                "evt_1",
+                vec![
+                    cluster_article(
+                        Some("연합뉴스"),
+                        "수출 회복세 반등",
+                        "2026-02-15T09:00:00+09:00",
+                    ),
+                    cluster_article(
+                        Some("연합뉴스"),
+                        "수출 증가 지속",
+                        "2026-02-15T11:00:00+09:00",
+                    ),
+                    cluster_article(
+                        Some("한겨레"),
+                        "수출 부진 우려",
+                        "2026-02-15T09:45:00+09:00",
+                    ),
+                ],
+            ),
+            // Single publisher: skipped
+            cluster(
+                "evt_2",
+                vec![
+                    cluster_article(Some("KBS"), "날씨", "2026-02-15T09:00:00+09:00"),
+                    cluster_article(None, "날씨 속보", "2026-02-15T10:00:00+09:00"),
+                ],
+            ),
+        ];
+
+        let report = CoverageAnalyzer::new()
+            .with_min_publishers(2)
+            .analyze(&clusters[..1], &HashMap::new());
+        assert_eq!(report.stories.len(), 1);
+
+        let story = &report.stories[0];
+        assert_eq!(story.first_publisher.as_deref(), Some("연합뉴스"));
+        assert_eq!(story.publishers[0].publisher, "연합뉴스");
+        assert_eq!(story.publishers[0].articles, 2);
+        assert_eq!(story.publishers[0].lag_minutes, Some(0));
+        assert_eq!(story.publishers[1].lag_minutes, Some(45));
+        assert!(story.publishers[0].sentiment > 0.0);
+        assert!(story.publishers[1].sentiment < 0.0);
+        assert!((story.sentiment_spread - 2.0).abs() < 1e-9);
+
+        // A missing publisher counts as "unknown", making evt_2 a two-publisher story
+        let report = CoverageAnalyzer::new().analyze(&clusters, &HashMap::new());
+        assert_eq!(report.stories.len(), 2);
+        assert!(report.stories[1]
+            .publishers
+            .iter()
+            .any(|p| p.publisher == UNKNOWN_PUBLISHER));
+
+        let yonhap = &report.publishers[0];
+        assert_eq!(yonhap.publisher, "연합뉴스");
+        assert_eq!(yonhap.first_reports, 1);
+    }
+
+    #[test]
+    fn test_emphasized_entities_and_csv() {
+        let clusters = vec![cluster(
+            "evt_1",
+            vec![
+                cluster_article(
+                    Some("A, Inc."),
+                    "삼성전자 실적 발표",
+                    "2026-02-15T09:00:00Z",
+                ),
+                cluster_article(Some("B"), "반도체 실적 발표", "2026-02-15T10:00:00Z"),
+            ],
+        )];
+
+        let report = CoverageAnalyzer::new().analyze(&clusters, &HashMap::new());
+        let story = &report.stories[0];
+        let a = story
+            .publishers
+            .iter()
+            .find(|p| p.publisher == "A, Inc.")
+            .unwrap();
+        assert!(a.emphasized_entities.contains(&"삼성전자".to_string()));
+        let b = story
+            .publishers
+            .iter()
+            .find(|p| p.publisher == "B")
+            .unwrap();
+        assert!(!b.emphasized_entities.contains(&"삼성전자".to_string()));
+
+        let csv = report.to_csv();
+        assert!(csv.starts_with("event_id,title,"));
+        assert!(csv.contains("\"A, Inc.\""));
+        assert_eq!(csv.lines().count(), 3);
+    }
+}
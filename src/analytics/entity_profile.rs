@@ -0,0 +1,437 @@
+//! Everything known about one entity, for entity pages
+//!
+//! [`EntityProfile::from_extractions`] gathers what the ontology extraction
+//! results say about an entity: how many articles mention it each day, its
+//! latest quotes (`Said` relations) and the entities mentioned alongside it.
+//! Callers add the sentiment series and the top search results from their
+//! own stores with [`EntityProfile::with_sentiment`] and
+//! [`EntityProfile::with_articles`].
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::entity_trends::EntityNetwork;
+use crate::embedding::SearchResult;
+use crate::ontology::{RelationType, TripleStore};
+use crate::storage::SentimentPoint;
+
+/// Minimum characters for a quote to be shown
+const MIN_QUOTE_CHARS: usize = 10;
+
+/// Profile of one entity over a range of days
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EntityProfile {
+    /// Entity name, as extracted
+    pub name: String,
+
+    /// Entity type (person, organization, location, ...), if it was extracted
+    pub entity_type: Option<String>,
+
+    /// First day of the profile
+    #[schema(value_type = String, format = Date)]
+    pub since: NaiveDate,
+
+    /// Last day of the profile
+    #[schema(value_type = String, format = Date)]
+    pub until: NaiveDate,
+
+    /// Articles mentioning the entity in the range
+    pub articles: u64,
+
+    /// Articles mentioning the entity per day, oldest first, every day included
+    pub mentions: Vec<DailyMentions>,
+
+    /// Latest quotes of the entity, newest first
+    pub quotes: Vec<EntityQuote>,
+
+    /// Entities mentioned in the most articles together with this one
+    pub related: Vec<RelatedEntity>,
+
+    /// Daily sentiment of articles mentioning the entity, oldest first
+    pub sentiment: Vec<DailySentiment>,
+
+    /// Best-matching indexed articles
+    pub top_articles: Vec<EntityArticle>,
+}
+
+/// Articles mentioning an entity on one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct DailyMentions {
+    #[schema(value_type = String, format = Date)]
+    pub day: NaiveDate,
+    pub articles: u64,
+}
+
+/// A quote attributed to the entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EntityQuote {
+    /// Quoted text
+    pub text: String,
+    pub article_id: String,
+    pub article_title: String,
+    /// Publication time of the article (RFC 3339)
+    pub published_at: Option<String>,
+}
+
+/// An entity mentioned together with the profiled one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RelatedEntity {
+    pub name: String,
+    pub entity_type: String,
+    /// Articles mentioning both entities
+    pub shared_articles: u64,
+}
+
+/// Sentiment of the articles mentioning the entity on one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DailySentiment {
+    #[schema(value_type = String, format = Date)]
+    pub day: NaiveDate,
+    pub articles: u64,
+    /// Average article score in `[-1.0, 1.0]`
+    pub average: f64,
+    pub positive: u64,
+    pub negative: u64,
+}
+
+/// An indexed article about the entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EntityArticle {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub publisher: Option<String>,
+    pub published_at: Option<String>,
+    /// Search relevance score
+    pub score: f32,
+}
+
+impl EntityProfile {
+    /// Profile of `name` from extraction results of articles published from
+    /// `since` to `until` (inclusive)
+    ///
+    /// Returns `None` if no article in the range mentions the entity. At most
+    /// `limit` quotes and related entities are kept.
+    #[must_use]
+    pub fn from_extractions(
+        name: &str,
+        stores: &[TripleStore],
+        since: NaiveDate,
+        until: NaiveDate,
+        limit: usize,
+    ) -> Option<Self> {
+        let name = name.trim();
+        let start = since.and_hms_opt(0, 0, 0)?.and_utc();
+        let end =
+            (until + Duration::days(1)).and_hms_opt(0, 0, 0)?.and_utc() - Duration::nanoseconds(1);
+        let mut network = EntityNetwork::from_extractions(stores, Some(start), Some(end));
+        let entity = network.get_entity(name)?;
+
+        let days = (until - since).num_days().max(0) as usize + 1;
+        let mut daily = vec![0u64; days];
+        for mention in entity.mentions_in_range(start, end) {
+            let offset = (mention.timestamp.date_naive() - since).num_days();
+            if let Some(count) = usize::try_from(offset).ok().and_then(|i| daily.get_mut(i)) {
+                *count += 1;
+            }
+        }
+        let mentions = daily
+            .into_iter()
+            .enumerate()
+            .map(|(i, articles)| DailyMentions {
+                day: since + Duration::days(i as i64),
+                articles,
+            })
+            .collect();
+        let articles = entity.document_ids().len() as u64;
+        let entity_type = entity.entity_type.as_str().to_string();
+
+        let mut related: Vec<RelatedEntity> = network
+            .find_related(name, 1)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(other, cooccurrence)| RelatedEntity {
+                entity_type: network
+                    .get_entity(&other)
+                    .map(|e| e.entity_type.as_str().to_string())
+                    .unwrap_or_default(),
+                name: other,
+                shared_articles: cooccurrence.count,
+            })
+            .collect();
+        // Ties by name, so the order does not depend on hashing
+        related.sort_by(|a, b| {
+            b.shared_articles
+                .cmp(&a.shared_articles)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        related.truncate(limit);
+
+        Some(Self {
+            name: name.to_string(),
+            entity_type: Some(entity_type),
+            since,
+            until,
+            articles,
+            mentions,
+            quotes: quotes(name, stores, start, end, limit),
+            related,
+            sentiment: Vec::new(),
+            top_articles: Vec::new(),
+        })
+    }
+
+    /// Empty profile of `name`, for entities known only to other stores
+    #[must_use]
+    pub fn empty(name: &str, since: NaiveDate, until: NaiveDate) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            entity_type: None,
+            since,
+            until,
+            articles: 0,
+            mentions: Vec::new(),
+            quotes: Vec::new(),
+            related: Vec::new(),
+            sentiment: Vec::new(),
+            top_articles: Vec::new(),
+        }
+    }
+
+    /// Add the daily sentiment aggregates of the entity
+    #[must_use]
+    pub fn with_sentiment(mut self, points: Vec<SentimentPoint>) -> Self {
+        self.sentiment = points
+            .into_iter()
+            .filter(|p| p.day >= self.since && p.day <= self.until)
+            .map(|p| DailySentiment {
+                day: p.day,
+                articles: p.articles,
+                average: p.average,
+                positive: p.positive,
+                negative: p.negative,
+            })
+            .collect();
+        self
+    }
+
+    /// Add the best search results for the entity
+    #[must_use]
+    pub fn with_articles(mut self, results: &[SearchResult]) -> Self {
+        self.top_articles = results
+            .iter()
+            .map(|r| EntityArticle {
+                id: r.id.clone(),
+                title: r.title.clone(),
+                url: r.url.clone(),
+                publisher: r.publisher.clone(),
+                published_at: r.published_at.clone(),
+                score: r.score,
+            })
+            .collect();
+        self
+    }
+
+    /// Whether no source knows anything about the entity
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.articles == 0 && self.sentiment.is_empty() && self.top_articles.is_empty()
+    }
+}
+
+/// Latest distinct quotes of `speaker` in articles from `start` to `end`
+fn quotes(
+    speaker: &str,
+    stores: &[TripleStore],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    limit: usize,
+) -> Vec<EntityQuote> {
+    let mut found: Vec<(DateTime<Utc>, EntityQuote)> = Vec::new();
+    for store in stores {
+        let Some(timestamp) = store.timestamp() else {
+            continue;
+        };
+        if timestamp < start || timestamp > end {
+            continue;
+        }
+        for triple in &store.triples {
+            if RelationType::from_string(&triple.predicate_label) != RelationType::Said {
+                continue;
+            }
+            // Patterns capture either (speaker, quote) or (quote, speaker)
+            let text = if triple.subject.trim() == speaker {
+                &triple.object
+            } else if triple.object.trim() == speaker {
+                &triple.subject
+            } else {
+                continue;
+            };
+            let text = text.trim().trim_matches(['"', '\'', '“', '”']).to_string();
+            if text.chars().count() < MIN_QUOTE_CHARS
+                || found.iter().any(|(_, quote)| quote.text == text)
+            {
+                continue;
+            }
+            found.push((
+                timestamp,
+                EntityQuote {
+                    text,
+                    article_id: store.article_id.clone(),
+                    article_title: store.article_title.clone(),
+                    published_at: store.published_at.clone(),
+                },
+            ));
+        }
+    }
+
+    found.sort_by_key(|(timestamp, _)| std::cmp::Reverse(*timestamp));
+    found
+        .into_iter()
+        .take(limit)
+        .map(|(_, quote)| quote)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::{EntityType, ExtractedRelation, ExtractionResult};
+
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn said(speaker: &str, text: &str) -> ExtractedRelation {
+        ExtractedRelation {
+            subject: speaker.to_string(),
+            subject_type: EntityType::Person,
+            predicate: RelationType::Said,
+            object: text.to_string(),
+            object_type: EntityType::Other,
+            confidence: 0.9,
+            evidence: String::new(),
+            verified: true,
+        }
+    }
+
+    fn works_for(person: &str, org: &str) -> ExtractedRelation {
+        ExtractedRelation {
+            subject: person.to_string(),
+            subject_type: EntityType::Person,
+            predicate: RelationType::WorksFor,
+            object: org.to_string(),
+            object_type: EntityType::Organization,
+            confidence: 0.9,
+            evidence: String::new(),
+            verified: true,
+        }
+    }
+
+    fn article(id: &str, published_at: &str, relations: &[ExtractedRelation]) -> TripleStore {
+        let result = ExtractionResult {
+            article_id: id.to_string(),
+            entities: Vec::new(),
+            relations: relations.to_vec(),
+        };
+        TripleStore::from_extraction(&result, &format!("기사 {id}"))
+            .with_published_at(Some(published_at.parse().unwrap()))
+    }
+
+    fn stores() -> Vec<TripleStore> {
+        vec![
+            article(
+                "001_1",
+                "2026-03-01T09:00:00Z",
+                &[
+                    works_for("홍길동", "바람전자"),
+                    said("홍길동", "반도체 투자를 늘리겠다는 입장입니다"),
+                ],
+            ),
+            article(
+                "001_2",
+                "2026-03-03T09:00:00Z",
+                &[
+                    works_for("홍길동", "바람전자"),
+                    said("\"수출 회복세가 뚜렷하다고 봅니다\"", "홍길동"),
+                ],
+            ),
+            article(
+                "001_3",
+                "2026-03-03T10:00:00Z",
+                &[works_for("김철수", "바람전자")],
+            ),
+            // Outside the range
+            article(
+                "001_4",
+                "2026-02-01T09:00:00Z",
+                &[said("홍길동", "예전 발언이라 보이지 않아야 합니다")],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_profile_from_extractions() {
+        let profile = EntityProfile::from_extractions(
+            "홍길동",
+            &stores(),
+            day("2026-03-01"),
+            day("2026-03-03"),
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(profile.entity_type.as_deref(), Some("person"));
+        assert_eq!(profile.articles, 2);
+        let daily: Vec<u64> = profile.mentions.iter().map(|m| m.articles).collect();
+        assert_eq!(daily, [1, 0, 1]);
+
+        let quotes: Vec<&str> = profile.quotes.iter().map(|q| q.text.as_str()).collect();
+        assert_eq!(
+            quotes,
+            [
+                "수출 회복세가 뚜렷하다고 봅니다",
+                "반도체 투자를 늘리겠다는 입장입니다"
+            ]
+        );
+        assert_eq!(profile.quotes[0].article_id, "001_2");
+
+        assert_eq!(profile.related[0].name, "바람전자");
+        assert_eq!(profile.related[0].shared_articles, 2);
+        assert!(profile.related.iter().all(|r| r.name != "김철수"));
+    }
+
+    #[test]
+    fn test_profile_unknown_entity() {
+        let profile = EntityProfile::from_extractions(
+            "이영희",
+            &stores(),
+            day("2026-03-01"),
+            day("2026-03-03"),
+            10,
+        );
+        assert!(profile.is_none());
+
+        let empty = EntityProfile::empty("이영희", day("2026-03-01"), day("2026-03-03"));
+        assert!(empty.is_empty());
+        let with_sentiment = empty.with_sentiment(vec![
+            SentimentPoint {
+                day: day("2026-03-02"),
+                articles: 3,
+                average: 0.4,
+                positive: 2,
+                negative: 0,
+            },
+            SentimentPoint {
+                day: day("2026-02-02"),
+                articles: 1,
+                average: -0.4,
+                positive: 0,
+                negative: 1,
+            },
+        ]);
+        assert_eq!(with_sentiment.sentiment.len(), 1);
+        assert!(!with_sentiment.is_empty());
+    }
+}
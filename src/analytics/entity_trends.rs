@@ -4,13 +4,17 @@
 //! - Tracking entity mentions over time
 //! - Co-occurrence analysis between entities
 //! - Network graph construction for entity relationships
+//! - Community detection and GraphML/JSON export of the network
 //! - Temporal evolution of entity relevance
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
 use thiserror::Error;
 
+use crate::ontology::{self, TripleStore};
+
 /// Errors that can occur during entity trend analysis
 #[derive(Debug, Error)]
 pub enum EntityError {
@@ -63,6 +67,25 @@ impl EntityType {
         }
     }
 
+    /// Map an extracted ontology entity type
+    ///
+    /// Dates, amounts and percentages are values rather than actors, so they
+    /// are not part of the network.
+    #[must_use]
+    pub fn from_ontology(entity_type: ontology::EntityType) -> Option<Self> {
+        match entity_type {
+            ontology::EntityType::Person => Some(Self::Person),
+            ontology::EntityType::Organization => Some(Self::Organization),
+            ontology::EntityType::Location => Some(Self::Location),
+            ontology::EntityType::Product => Some(Self::Product),
+            ontology::EntityType::Event => Some(Self::Event),
+            ontology::EntityType::Policy | ontology::EntityType::Other => Some(Self::Other),
+            ontology::EntityType::DateTime
+            | ontology::EntityType::Money
+            | ontology::EntityType::Percentage => None,
+        }
+    }
+
     /// Get string representation
     #[must_use]
     pub fn as_str(&self) -> &'static str {
@@ -516,6 +539,198 @@ impl EntityNetwork {
         trending.truncate(limit);
         trending
     }
+
+    /// Record the entities extracted from one article
+    ///
+    /// Uses the extracted entities plus the subjects and objects of the
+    /// article's triples, one mention per entity.
+    pub fn record_extraction(&mut self, store: &TripleStore, timestamp: DateTime<Utc>) {
+        let mut found: BTreeMap<String, EntityType> = BTreeMap::new();
+
+        for entity in &store.entities {
+            if let Some(entity_type) = EntityType::from_ontology(entity.entity_type) {
+                let name = entity.canonical_name.as_ref().unwrap_or(&entity.text);
+                found.entry(name.trim().to_string()).or_insert(entity_type);
+            }
+        }
+        for triple in &store.triples {
+            for (name, entity_type) in [
+                (&triple.subject, triple.subject_type),
+                (&triple.object, triple.object_type),
+            ] {
+                if let Some(entity_type) = EntityType::from_ontology(entity_type) {
+                    found.entry(name.trim().to_string()).or_insert(entity_type);
+                }
+            }
+        }
+        found.retain(|name, _| !name.is_empty());
+
+        if found.is_empty() {
+            return;
+        }
+
+        let mut mentions = Vec::with_capacity(found.len());
+        for (name, entity_type) in found {
+            self.entities
+                .entry(name.clone())
+                .or_insert_with(|| Entity::new(name.clone(), entity_type));
+            mentions.push((
+                name,
+                EntityMention::new(timestamp, store.article_id.clone()),
+            ));
+        }
+        self.record_document(store.article_id.clone(), mentions);
+    }
+
+    /// Build a network from extracted articles within a date range
+    ///
+    /// Articles are dated by publication time, falling back to extraction
+    /// time; articles without a parseable date are skipped.
+    ///
+    /// # Arguments
+    /// * `stores` - Extraction results, one per article
+    /// * `start` - Earliest article date (inclusive)
+    /// * `end` - Latest article date (inclusive)
+    pub fn from_extractions<'a, I>(
+        stores: I,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = &'a TripleStore>,
+    {
+        let mut network = Self::new();
+        for store in stores {
+            let Some(timestamp) = store.timestamp() else {
+                continue;
+            };
+            if start.is_some_and(|s| timestamp < s) || end.is_some_and(|e| timestamp > e) {
+                continue;
+            }
+            network.record_extraction(store, timestamp);
+        }
+        network
+    }
+
+    /// Get the number of recorded documents
+    #[must_use]
+    pub fn document_count(&self) -> usize {
+        self.document_entities.len()
+    }
+
+    /// Compute all co-occurrence edges in a single pass over documents
+    ///
+    /// Unlike [`cooccurrence_matrix`](Self::cooccurrence_matrix), this does
+    /// not compare every pair of entities, so it scales to large networks.
+    ///
+    /// # Arguments
+    /// * `min_count` - Minimum number of shared documents
+    /// * `min_pmi` - Minimum PMI score
+    ///
+    /// # Returns
+    /// Edges sorted by count descending, then by entity names
+    #[must_use]
+    pub fn weighted_edges(&self, min_count: u64, min_pmi: f64) -> Vec<Cooccurrence> {
+        let mut shared: HashMap<(&str, &str), Vec<&str>> = HashMap::new();
+        for (document_id, names) in &self.document_entities {
+            let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+            names.sort_unstable();
+            for i in 0..names.len() {
+                for j in (i + 1)..names.len() {
+                    shared
+                        .entry((names[i], names[j]))
+                        .or_default()
+                        .push(document_id);
+                }
+            }
+        }
+
+        let document_frequency: HashMap<&str, usize> = self
+            .document_entities
+            .values()
+            .flatten()
+            .fold(HashMap::new(), |mut counts, name| {
+                *counts.entry(name.as_str()).or_insert(0) += 1;
+                counts
+            });
+        let total_docs = self.document_entities.len() as f64;
+
+        let mut edges: Vec<Cooccurrence> = shared
+            .into_iter()
+            .filter(|(_, documents)| documents.len() as u64 >= min_count)
+            .map(|((a, b), documents)| {
+                let p_a = document_frequency[a] as f64 / total_docs;
+                let p_b = document_frequency[b] as f64 / total_docs;
+                let p_ab = documents.len() as f64 / total_docs;
+
+                let mut document_ids: Vec<String> =
+                    documents.into_iter().map(str::to_string).collect();
+                document_ids.sort_unstable();
+
+                let mut edge = Cooccurrence::new(a.to_string(), b.to_string(), document_ids);
+                edge.calculate_pmi(p_a, p_b, p_ab);
+                edge
+            })
+            .filter(|edge| edge.pmi >= min_pmi)
+            .collect();
+
+        edges.sort_by(|x, y| {
+            y.count
+                .cmp(&x.count)
+                .then_with(|| x.entity_a.cmp(&y.entity_a))
+                .then_with(|| x.entity_b.cmp(&y.entity_b))
+        });
+        edges
+    }
+
+    /// Build an exportable graph with community assignments
+    ///
+    /// Only entities connected by at least one edge are included.
+    ///
+    /// # Arguments
+    /// * `min_count` - Minimum number of shared documents per edge
+    /// * `min_pmi` - Minimum PMI score per edge
+    #[must_use]
+    pub fn to_graph(&self, min_count: u64, min_pmi: f64) -> NetworkGraph {
+        let edges = self.weighted_edges(min_count, min_pmi);
+        let communities = detect_communities(&edges);
+
+        let mut nodes: Vec<GraphNode> = communities
+            .iter()
+            .filter_map(|(name, community)| {
+                self.entities.get(name).map(|entity| GraphNode {
+                    id: entity.name.clone(),
+                    entity_type: entity.entity_type,
+                    mentions: entity.total_mentions,
+                    community: *community,
+                })
+            })
+            .collect();
+        nodes.sort_by(|a, b| {
+            a.community
+                .cmp(&b.community)
+                .then_with(|| b.mentions.cmp(&a.mentions))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let community_count = communities.values().max().map_or(0, |max| max + 1);
+        let edges = edges
+            .into_iter()
+            .map(|edge| GraphEdge {
+                source: edge.entity_a,
+                target: edge.entity_b,
+                weight: edge.count,
+                pmi: edge.pmi,
+            })
+            .collect();
+
+        NetworkGraph {
+            documents: self.document_count(),
+            communities: community_count,
+            nodes,
+            edges,
+        }
+    }
 }
 
 impl Default for EntityNetwork {
@@ -524,6 +739,202 @@ impl Default for EntityNetwork {
     }
 }
 
+/// Maximum label propagation rounds before giving up on convergence
+const MAX_PROPAGATION_ROUNDS: usize = 50;
+
+/// Detect communities with weighted label propagation
+///
+/// Every entity starts in its own community and repeatedly adopts the
+/// community with the highest total edge weight among its neighbors. Ties go
+/// to the smallest label and entities are visited in name order, so results
+/// are deterministic. Communities are numbered from largest to smallest.
+#[must_use]
+pub fn detect_communities(edges: &[Cooccurrence]) -> HashMap<String, usize> {
+    let mut neighbors: BTreeMap<&str, Vec<(&str, u64)>> = BTreeMap::new();
+    for edge in edges {
+        neighbors
+            .entry(&edge.entity_a)
+            .or_default()
+            .push((&edge.entity_b, edge.count));
+        neighbors
+            .entry(&edge.entity_b)
+            .or_default()
+            .push((&edge.entity_a, edge.count));
+    }
+
+    let mut labels: HashMap<&str, usize> = neighbors
+        .keys()
+        .enumerate()
+        .map(|(i, name)| (*name, i))
+        .collect();
+
+    for _ in 0..MAX_PROPAGATION_ROUNDS {
+        let mut changed = false;
+        for (name, adjacent) in &neighbors {
+            let mut weights: BTreeMap<usize, u64> = BTreeMap::new();
+            for (neighbor, weight) in adjacent {
+                *weights.entry(labels[neighbor]).or_insert(0) += weight;
+            }
+
+            let current = labels[name];
+            let best = weights
+                .iter()
+                .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+                .map(|(label, _)| *label)
+                .unwrap_or(current);
+            if weights.get(&best) > weights.get(&current) {
+                labels.insert(name, best);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // Renumber communities by size (largest first), then by smallest member
+    let mut members: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (name, label) in &labels {
+        members.entry(*label).or_default().push(name);
+    }
+    let mut groups: Vec<Vec<&str>> = members.into_values().collect();
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(b[0])));
+
+    groups
+        .into_iter()
+        .enumerate()
+        .flat_map(|(community, group)| {
+            group
+                .into_iter()
+                .map(move |name| (name.to_string(), community))
+        })
+        .collect()
+}
+
+/// Entity node in an exported network graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    /// Entity name
+    pub id: String,
+
+    /// Entity type
+    pub entity_type: EntityType,
+
+    /// Total mentions across the documents
+    pub mentions: u64,
+
+    /// Community index (0 = largest community)
+    pub community: usize,
+}
+
+/// Weighted co-occurrence edge in an exported network graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphEdge {
+    /// First entity name
+    pub source: String,
+
+    /// Second entity name
+    pub target: String,
+
+    /// Number of documents mentioning both entities
+    pub weight: u64,
+
+    /// Pointwise Mutual Information score
+    pub pmi: f64,
+}
+
+/// Entity co-occurrence graph for visualization tools
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkGraph {
+    /// Number of documents the graph was built from
+    pub documents: usize,
+
+    /// Number of detected communities
+    pub communities: usize,
+
+    /// Entities, grouped by community
+    pub nodes: Vec<GraphNode>,
+
+    /// Co-occurrence edges, heaviest first
+    pub edges: Vec<GraphEdge>,
+}
+
+impl NetworkGraph {
+    /// Export as pretty-printed JSON (nodes/edges, as used by D3 and Sigma.js)
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Export as GraphML (Gephi, Cytoscape, yEd)
+    #[must_use]
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, target, kind) in [
+            ("label", "node", "string"),
+            ("type", "node", "string"),
+            ("mentions", "node", "long"),
+            ("community", "node", "int"),
+            ("weight", "edge", "long"),
+            ("pmi", "edge", "double"),
+        ] {
+            let _ = writeln!(
+                out,
+                "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{id}\" attr.type=\"{kind}\"/>"
+            );
+        }
+        out.push_str("  <graph id=\"entities\" edgedefault=\"undirected\">\n");
+
+        let node_ids: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "    <node id=\"n{i}\"><data key=\"label\">{}</data><data key=\"type\">{}</data>\
+                 <data key=\"mentions\">{}</data><data key=\"community\">{}</data></node>",
+                xml_escape(&node.id),
+                node.entity_type.as_str(),
+                node.mentions,
+                node.community
+            );
+        }
+        for edge in &self.edges {
+            let (Some(source), Some(target)) = (
+                node_ids.get(edge.source.as_str()),
+                node_ids.get(edge.target.as_str()),
+            ) else {
+                continue;
+            };
+            let _ = writeln!(
+                out,
+                "    <edge source=\"n{source}\" target=\"n{target}\"><data key=\"weight\">{}</data>\
+                 <data key=\"pmi\">{:.4}</data></edge>",
+                edge.weight, edge.pmi
+            );
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+/// Escape text for XML element content
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -685,4 +1096,123 @@ mod tests {
         let avg = entity.average_sentiment().unwrap();
         assert!((avg - 0.3).abs() < 0.01); // (0.8 + (-0.2)) / 2 = 0.3
     }
+
+    fn extraction(article_id: &str, entities: &[(&str, ontology::EntityType)]) -> TripleStore {
+        use crate::ontology::{EntitySource, ExtractedEntity, ExtractionResult};
+
+        let result = ExtractionResult {
+            article_id: article_id.to_string(),
+            entities: entities
+                .iter()
+                .map(|(text, entity_type)| ExtractedEntity {
+                    text: (*text).to_string(),
+                    canonical_name: None,
+                    entity_type: *entity_type,
+                    start: 0,
+                    end: text.len(),
+                    confidence: 0.9,
+                    source: EntitySource::Content,
+                })
+                .collect(),
+            relations: vec![],
+        };
+        TripleStore::from_extraction(&result, "test")
+    }
+
+    #[test]
+    fn test_network_from_extractions() {
+        use ontology::EntityType as Ont;
+
+        let now = Utc::now();
+        let old = extraction(
+            "old",
+            &[("삼성전자", Ont::Organization), ("애플", Ont::Organization)],
+        )
+        .with_published_at(Some(now - chrono::Duration::days(30)));
+        let recent = extraction(
+            "recent",
+            &[
+                ("삼성전자", Ont::Organization),
+                ("이재용", Ont::Person),
+                ("3조원", Ont::Money),
+            ],
+        )
+        .with_published_at(Some(now));
+
+        let network = EntityNetwork::from_extractions(
+            [&old, &recent],
+            Some(now - chrono::Duration::days(7)),
+            None,
+        );
+
+        assert_eq!(network.document_count(), 1);
+        assert!(network.get_entity("애플").is_none());
+        assert!(network.get_entity("3조원").is_none());
+        assert_eq!(
+            network.get_entity("이재용").unwrap().entity_type,
+            EntityType::Person
+        );
+    }
+
+    #[test]
+    fn test_weighted_edges_and_communities() {
+        use ontology::EntityType as Ont;
+
+        let org = |name| (name, Ont::Organization);
+        let stores = [
+            extraction("d1", &[org("A"), org("B"), org("C")]),
+            extraction("d2", &[org("A"), org("B")]),
+            extraction("d3", &[org("B"), org("C")]),
+            extraction("d4", &[org("X"), org("Y")]),
+            extraction("d5", &[org("X"), org("Y")]),
+            extraction("d6", &[org("C"), org("X")]),
+        ];
+        let network = EntityNetwork::from_extractions(&stores, None, None);
+
+        let edges = network.weighted_edges(2, f64::MIN);
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[0].entity_a, "A");
+        assert_eq!(edges[0].entity_b, "B");
+        assert_eq!(edges[0].count, 2);
+        // cooccurrence() and weighted_edges() agree on PMI
+        let mut network_copy = network.clone();
+        let pair = network_copy.cooccurrence("A", "B").unwrap();
+        assert!((pair.pmi - edges[0].pmi).abs() < 1e-9);
+
+        let graph = network.to_graph(2, f64::MIN);
+        assert_eq!(graph.documents, 6);
+        assert_eq!(graph.communities, 2);
+        let community = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap().community;
+        assert_eq!(community("A"), 0);
+        assert_eq!(community("A"), community("B"));
+        assert_eq!(community("B"), community("C"));
+        assert_eq!(community("X"), 1);
+        assert_eq!(community("X"), community("Y"));
+    }
+
+    #[test]
+    fn test_graph_exports() {
+        use ontology::EntityType as Ont;
+
+        let stores = [
+            extraction(
+                "d1",
+                &[("AT&T", Ont::Organization), ("미국", Ont::Location)],
+            ),
+            extraction(
+                "d2",
+                &[("AT&T", Ont::Organization), ("미국", Ont::Location)],
+            ),
+        ];
+        let graph = EntityNetwork::from_extractions(&stores, None, None).to_graph(1, f64::MIN);
+
+        let graphml = graph.to_graphml();
+        assert!(graphml.contains("<data key=\"label\">AT&amp;T</data>"));
+        assert!(graphml.contains("<data key=\"type\">location</data>"));
+        assert!(graphml.contains("<edge source=\"n0\" target=\"n1\"><data key=\"weight\">2</data>"));
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 2);
+        assert_eq!(json["edges"][0]["weight"], 2);
+    }
 }
@@ -196,6 +196,35 @@ impl KeywordTrend {
         self.data.values().collect()
     }
 
+    /// Get the total count across all data points
+    #[must_use]
+    pub fn total_count(&self) -> u64 {
+        self.data.values().map(|p| p.count).sum()
+    }
+
+    /// Render the series as a text spark line (`▁▂▃▄▅▆▇█`)
+    ///
+    /// Each data point becomes one bar scaled between the series minimum
+    /// and maximum; a flat series renders as mid-height bars.
+    #[must_use]
+    pub fn sparkline(&self) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let min = self.data.values().map(|p| p.count).min().unwrap_or(0);
+        let max = self.data.values().map(|p| p.count).max().unwrap_or(0);
+        let range = max - min;
+
+        self.data
+            .values()
+            .map(
+                |point| match ((point.count - min) * (BARS.len() as u64 - 1)).checked_div(range) {
+                    Some(level) => BARS[level as usize],
+                    None => BARS[3],
+                },
+            )
+            .collect()
+    }
+
     /// Get data points within a time range
     ///
     /// # Arguments
@@ -500,6 +529,25 @@ impl TrendAnalyzer {
         velocities
     }
 
+    /// Find the fastest falling keywords based on recent velocity
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of keywords to return
+    /// * `recent_points` - Number of recent points for trend calculation
+    ///
+    /// # Returns
+    /// Keywords sorted by velocity (ascending)
+    pub fn top_falling(
+        &mut self,
+        limit: usize,
+        recent_points: Option<usize>,
+    ) -> Vec<(String, f64)> {
+        let mut velocities = self.top_trending(usize::MAX, recent_points);
+        velocities.reverse();
+        velocities.truncate(limit);
+        velocities
+    }
+
     /// Find keywords with recent spikes
     ///
     /// # Arguments
@@ -629,4 +677,42 @@ mod tests {
         assert!((points[1].normalized - 1.0).abs() < 0.01); // max
         assert!((points[2].normalized - 0.5).abs() < 0.01); // middle
     }
+
+    #[test]
+    fn test_sparkline_and_total() {
+        let mut trend = KeywordTrend::new("test".to_string(), Some(3));
+        let now = Utc::now();
+
+        trend.add_points([
+            (now, 0),
+            (now + Duration::days(1), 7),
+            (now + Duration::days(2), 14),
+        ]);
+
+        assert_eq!(trend.sparkline(), "▁▄█");
+        assert_eq!(trend.total_count(), 21);
+
+        let mut flat = KeywordTrend::new("flat".to_string(), None);
+        flat.add_points([(now, 5), (now + Duration::days(1), 5)]);
+        assert_eq!(flat.sparkline(), "▄▄");
+    }
+
+    #[test]
+    fn test_top_falling() {
+        let mut analyzer = TrendAnalyzer::new(Some(3));
+        let now = Utc::now();
+
+        for (i, (up, down)) in [(1, 30), (10, 20), (20, 10)].into_iter().enumerate() {
+            let timestamp = now + Duration::days(i as i64);
+            analyzer.add_observation("up", timestamp, up);
+            analyzer.add_observation("down", timestamp, down);
+        }
+
+        let rising = analyzer.top_trending(1, None);
+        assert_eq!(rising[0].0, "up");
+
+        let falling = analyzer.top_falling(1, None);
+        assert_eq!(falling[0].0, "down");
+        assert!(falling[0].1 < 0.0);
+    }
 }
@@ -1,12 +1,41 @@
 //! Analytics module for trend analysis and insights
 //! Issue #8: Trend Analysis & Notification Features
 
+pub mod burst;
+pub mod coverage;
+pub mod entity_profile;
 pub mod entity_trends;
 pub mod keyword_trends;
+pub mod reaction;
+pub mod report;
+pub mod sentiment;
+pub mod tokenizer;
+pub mod vocabulary;
+pub mod volume;
 
+pub use burst::{
+    hourly_counts, ArticleLink, BurstConfig, BurstDetector, BurstEvent, BurstMethod, BurstSubject,
+};
+pub use coverage::{
+    CoverageAnalyzer, CoverageReport, PublisherCoverage, PublisherSummary, StoryCoverage,
+};
+pub use entity_profile::{
+    DailyMentions, DailySentiment, EntityArticle, EntityProfile, EntityQuote, RelatedEntity,
+};
 pub use entity_trends::{
-    Cooccurrence, Entity, EntityError, EntityMention, EntityNetwork, EntityType,
+    detect_communities, Cooccurrence, Entity, EntityError, EntityMention, EntityNetwork,
+    EntityType, GraphEdge, GraphNode, NetworkGraph,
 };
 pub use keyword_trends::{
     DataPoint, KeywordTrend, Spike, TrendAnalyzer, TrendDirection, TrendError,
 };
+pub use reaction::{
+    entity_reactions, ArticleReaction, EntityReaction, ReactionAnalyzer, DEFAULT_REACTION_TERMS,
+};
+pub use report::{
+    FailureSummary, NewsReport, Quote, RankedItem, ReportBuilder, ReportPeriod, VolumeBucket,
+};
+pub use sentiment::{SentimentAnalyzer, SentimentDimension, SentimentLabel, SentimentScore};
+pub use tokenizer::TextTokenizer;
+pub use vocabulary::Vocabulary;
+pub use volume::{category_volumes, CategoryVolume};
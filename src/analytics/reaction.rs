@@ -0,0 +1,319 @@
+//! Public reaction analytics from reader comments
+//!
+//! Summarizes the comments crawled with an article — volume, likes,
+//! like-weighted sentiment and the terms readers use most — and rolls the
+//! per-article summaries up per entity mentioned in the article:
+//! - Each comment weighs `1 + likes`, so reactions other readers endorsed
+//!   count more than ignored ones
+//! - Terms count once per comment
+//!
+//! Article summaries are kept in the analytics store by
+//! `Database::store_article` and feed the "Public reaction" section of
+//! [`NewsReport`](super::NewsReport).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::entity_trends::EntityType;
+use super::sentiment::SentimentAnalyzer;
+use super::tokenizer::TextTokenizer;
+use crate::ontology::{EntitySource, RelationExtractor};
+use crate::parser::Article;
+
+/// Default number of top terms kept per article or entity
+pub const DEFAULT_REACTION_TERMS: usize = 10;
+
+/// Reader reaction to one article
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleReaction {
+    /// Article ID
+    pub article_id: String,
+    /// Article title
+    pub title: String,
+    /// Article URL
+    pub url: String,
+    /// Article publication time
+    pub published_at: DateTime<Utc>,
+    /// Entities mentioned in the article (canonical names, sorted)
+    pub entities: Vec<String>,
+    /// Number of comments
+    pub comments: u64,
+    /// Total likes on the comments
+    pub likes: u64,
+    /// Total comment weight (`comments + likes`)
+    pub weight: f64,
+    /// Like-weighted mean comment sentiment in `[-1.0, 1.0]`
+    pub sentiment: f64,
+    /// Most used terms with the number of comments using them
+    pub top_terms: Vec<(String, u64)>,
+}
+
+/// Reader reaction to the articles mentioning one entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntityReaction {
+    /// Entity canonical name
+    pub entity: String,
+    /// Articles with comments mentioning the entity
+    pub articles: u64,
+    /// Number of comments
+    pub comments: u64,
+    /// Total likes on the comments
+    pub likes: u64,
+    /// Total comment weight
+    pub weight: f64,
+    /// Like-weighted mean comment sentiment in `[-1.0, 1.0]`
+    pub sentiment: f64,
+    /// Most used terms with the number of comments using them
+    pub top_terms: Vec<(String, u64)>,
+}
+
+/// Builds [`ArticleReaction`]s from articles with comments
+pub struct ReactionAnalyzer {
+    sentiment: SentimentAnalyzer,
+    tokenizer: TextTokenizer,
+    extractor: RelationExtractor,
+    top_terms: usize,
+}
+
+impl Default for ReactionAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReactionAnalyzer {
+    /// Create an analyzer keeping [`DEFAULT_REACTION_TERMS`] terms
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sentiment: SentimentAnalyzer::new(),
+            tokenizer: TextTokenizer::new(),
+            extractor: RelationExtractor::new(),
+            top_terms: DEFAULT_REACTION_TERMS,
+        }
+    }
+
+    /// Set how many top terms to keep per article
+    #[must_use]
+    pub fn with_top_terms(mut self, top_terms: usize) -> Self {
+        self.top_terms = top_terms.max(1);
+        self
+    }
+
+    /// Summarize the comments of an article
+    ///
+    /// Returns `None` for articles without comments or publication time.
+    #[must_use]
+    pub fn analyze(&self, article: &Article) -> Option<ArticleReaction> {
+        let published_at = article.published_at?;
+        if article.comments.is_empty() {
+            return None;
+        }
+
+        let mut likes = 0u64;
+        let mut weight = 0.0;
+        let mut weighted_sentiment = 0.0;
+        let mut terms: HashMap<String, u64> = HashMap::new();
+
+        for comment in &article.comments {
+            let comment_likes = comment.likes.max(0) as u64;
+            let comment_weight = 1.0 + comment_likes as f64;
+            likes += comment_likes;
+            weight += comment_weight;
+            weighted_sentiment += self.sentiment.score(&comment.text).score * comment_weight;
+
+            let unique: HashSet<String> =
+                self.tokenizer.tokenize(&comment.text).into_iter().collect();
+            for term in unique {
+                *terms.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        let entities: BTreeSet<String> = self
+            .extractor
+            .extract_entities(
+                &format!("{} {}", article.title, article.body),
+                EntitySource::Both,
+            )
+            .into_iter()
+            .filter(|e| EntityType::from_ontology(e.entity_type).is_some())
+            .map(|e| e.canonical_name.unwrap_or(e.text))
+            .collect();
+
+        Some(ArticleReaction {
+            article_id: article.id.to_string(),
+            title: article.title.clone(),
+            url: article.url.clone(),
+            published_at,
+            entities: entities.into_iter().collect(),
+            comments: article.comments.len() as u64,
+            likes,
+            weight,
+            sentiment: weighted_sentiment / weight,
+            top_terms: top_terms(terms, self.top_terms),
+        })
+    }
+}
+
+/// Roll article reactions up per mentioned entity
+///
+/// Sentiment is re-weighted by each article's comment weight. Results are
+/// sorted by comment count, most discussed first.
+#[must_use]
+pub fn entity_reactions(
+    reactions: &[ArticleReaction],
+    top_terms_limit: usize,
+) -> Vec<EntityReaction> {
+    #[derive(Default)]
+    struct Totals {
+        articles: u64,
+        comments: u64,
+        likes: u64,
+        weight: f64,
+        weighted_sentiment: f64,
+        terms: HashMap<String, u64>,
+    }
+
+    let mut by_entity: HashMap<&str, Totals> = HashMap::new();
+    for reaction in reactions {
+        for entity in &reaction.entities {
+            let totals = by_entity.entry(entity).or_default();
+            totals.articles += 1;
+            totals.comments += reaction.comments;
+            totals.likes += reaction.likes;
+            totals.weight += reaction.weight;
+            totals.weighted_sentiment += reaction.sentiment * reaction.weight;
+            for (term, count) in &reaction.top_terms {
+                *totals.terms.entry(term.clone()).or_insert(0) += count;
+            }
+        }
+    }
+
+    let mut entities: Vec<EntityReaction> = by_entity
+        .into_iter()
+        .map(|(entity, totals)| EntityReaction {
+            entity: entity.to_string(),
+            articles: totals.articles,
+            comments: totals.comments,
+            likes: totals.likes,
+            weight: totals.weight,
+            sentiment: if totals.weight > 0.0 {
+                totals.weighted_sentiment / totals.weight
+            } else {
+                0.0
+            },
+            top_terms: top_terms(totals.terms, top_terms_limit),
+        })
+        .collect();
+    entities.sort_by(|a, b| {
+        b.comments
+            .cmp(&a.comments)
+            .then_with(|| a.entity.cmp(&b.entity))
+    });
+    entities
+}
+
+/// Highest counts first, ties by term
+fn top_terms(terms: HashMap<String, u64>, limit: usize) -> Vec<(String, u64)> {
+    let mut terms: Vec<(String, u64)> = terms.into_iter().collect();
+    terms.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    terms.truncate(limit);
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Comment;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn comment(text: &str, likes: i32) -> Comment {
+        Comment {
+            id: Uuid::new_v4().to_string(),
+            author: "reader".to_string(),
+            text: text.to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 2, 15, 10, 0, 0).unwrap(),
+            likes,
+        }
+    }
+
+    fn article(title: &str, comments: Vec<Comment>) -> Article {
+        Article {
+            id: Uuid::new_v4(),
+            url: format!("https://n.news.naver.com/{}", Uuid::new_v4()),
+            title: title.to_string(),
+            body: String::new(),
+            author: None,
+            published_at: Some(Utc.with_ymd_and_hms(2026, 2, 15, 9, 0, 0).unwrap()),
+            category: Some("economy".to_string()),
+            content_hash: String::new(),
+            comments,
+        }
+    }
+
+    #[test]
+    fn test_analyze_weights_by_likes() {
+        let analyzer = ReactionAnalyzer::new();
+        let reaction = analyzer
+            .analyze(&article(
+                "삼성전자 실적 발표",
+                vec![
+                    comment("실적 개선 기대", 9),
+                    comment("실적 부진 우려", 0),
+                    comment("실적 발표", -3),
+                ],
+            ))
+            .unwrap();
+
+        assert_eq!(reaction.comments, 3);
+        assert_eq!(reaction.likes, 9);
+        assert!((reaction.weight - 12.0).abs() < 1e-9);
+        // +1.0 × 10, -1.0 × 1, 0.0 × 1
+        assert!((reaction.sentiment - 9.0 / 12.0).abs() < 1e-9);
+        assert_eq!(reaction.top_terms[0], ("실적".to_string(), 3));
+    }
+
+    #[test]
+    fn test_analyze_requires_comments() {
+        let analyzer = ReactionAnalyzer::new();
+        assert!(analyzer
+            .analyze(&article("댓글 없는 기사", Vec::new()))
+            .is_none());
+
+        let mut undated = article("날짜 없는 기사", vec![comment("좋아요", 1)]);
+        undated.published_at = None;
+        assert!(analyzer.analyze(&undated).is_none());
+    }
+
+    #[test]
+    fn test_entity_reactions() {
+        let reaction = |entities: &[&str], comments, weight, sentiment| ArticleReaction {
+            article_id: Uuid::new_v4().to_string(),
+            title: String::new(),
+            url: String::new(),
+            published_at: Utc.with_ymd_and_hms(2026, 2, 15, 9, 0, 0).unwrap(),
+            entities: entities.iter().map(|e| (*e).to_string()).collect(),
+            comments,
+            likes: 0,
+            weight,
+            sentiment,
+            top_terms: vec![("반도체".to_string(), comments)],
+        };
+        let reactions = vec![
+            reaction(&["삼성전자", "SK하이닉스"], 10, 30.0, 1.0),
+            reaction(&["삼성전자"], 5, 10.0, -1.0),
+        ];
+
+        let entities = entity_reactions(&reactions, 5);
+
+        assert_eq!(entities.len(), 2);
+        assert_eq!(entities[0].entity, "삼성전자");
+        assert_eq!(entities[0].articles, 2);
+        assert_eq!(entities[0].comments, 15);
+        assert!((entities[0].sentiment - 0.5).abs() < 1e-9);
+        assert_eq!(entities[0].top_terms, vec![("반도체".to_string(), 15)]);
+        assert_eq!(entities[1].entity, "SK하이닉스");
+    }
+}
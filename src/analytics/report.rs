@@ -0,0 +1,1021 @@
+//! Daily/weekly news reports
+//!
+//! Builds a [`NewsReport`] from the articles of a period (and the period
+//! before it, for comparison) plus failed crawls, and renders it as Markdown,
+//! standalone HTML, or a short summary for chat notifications:
+//! - Article volume per hour (daily) or per day (weekly) and per category
+//! - Top keywords and entities with the change from the previous period
+//! - Notable quotes (Said relations), ranked by how often the speaker appears
+//! - Public reaction: reader comments per entity and the most discussed articles
+//! - Crawl failures grouped by error message
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+use super::entity_trends::EntityType;
+use super::reaction::{entity_reactions, ArticleReaction, EntityReaction, ReactionAnalyzer};
+use super::tokenizer::TextTokenizer;
+use crate::ontology::{EntitySource, RelationExtractor, RelationType};
+use crate::parser::Article;
+use crate::storage::CrawlRecord;
+
+/// Minimum characters for a quote to be reported
+const MIN_QUOTE_CHARS: usize = 10;
+
+/// Top comment terms shown per entity or article
+const REACTION_TERMS: usize = 5;
+
+/// Report period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportPeriod {
+    /// One UTC day
+    Daily,
+    /// Seven UTC days ending on the report date
+    Weekly,
+}
+
+impl ReportPeriod {
+    /// Parse a period name (`daily` or `weekly`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "daily" | "day" => Ok(Self::Daily),
+            "weekly" | "week" => Ok(Self::Weekly),
+            other => Err(format!(
+                "Unknown report period: {other}. Use daily or weekly"
+            )),
+        }
+    }
+
+    /// Get the period name
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+
+    /// Length of the period
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Daily => Duration::days(1),
+            Self::Weekly => Duration::days(7),
+        }
+    }
+
+    /// `[start, end)` of the period ending with the given day
+    #[must_use]
+    pub fn window(&self, last_day: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+        let end = (last_day + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is valid")
+            .and_utc();
+        (end - self.duration(), end)
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Self::Daily => "Daily News Report",
+            Self::Weekly => "Weekly News Report",
+        }
+    }
+}
+
+/// A name counted in this period and the previous one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankedItem {
+    /// Keyword, entity or category
+    pub name: String,
+    /// Articles in this period
+    pub count: u64,
+    /// Articles in the previous period
+    pub previous: u64,
+}
+
+impl RankedItem {
+    /// Change from the previous period as a fraction (`None` if new)
+    #[must_use]
+    pub fn change(&self) -> Option<f64> {
+        (self.previous > 0)
+            .then(|| (self.count as f64 - self.previous as f64) / self.previous as f64)
+    }
+
+    fn change_label(&self) -> String {
+        match self.change() {
+            Some(change) => format!("{:+.0}%", change * 100.0),
+            None => "new".to_string(),
+        }
+    }
+}
+
+/// Articles in one hour or day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VolumeBucket {
+    /// Bucket start
+    pub start: DateTime<Utc>,
+    /// Articles published in the bucket
+    pub count: u64,
+}
+
+/// A quote attributed to a speaker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quote {
+    /// Speaker name
+    pub speaker: String,
+    /// Quoted text
+    pub text: String,
+    /// Title of the article quoting it
+    pub article_title: String,
+    /// Article URL
+    pub url: String,
+}
+
+/// Crawl failures in the period
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FailureSummary {
+    /// Number of failed crawls
+    pub total: usize,
+    /// Most common error messages with their counts
+    pub top_errors: Vec<(String, usize)>,
+}
+
+/// Report over one period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsReport {
+    /// Report period
+    pub period: ReportPeriod,
+    /// Period start (inclusive)
+    pub start: DateTime<Utc>,
+    /// Period end (exclusive)
+    pub end: DateTime<Utc>,
+    /// When the report was generated
+    pub generated_at: DateTime<Utc>,
+    /// Articles published in the period
+    pub total_articles: usize,
+    /// Articles published in the previous period
+    pub previous_articles: usize,
+    /// Articles per hour (daily) or day (weekly)
+    pub volume: Vec<VolumeBucket>,
+    /// Articles per category
+    pub categories: Vec<RankedItem>,
+    /// Most mentioned keywords
+    pub top_keywords: Vec<RankedItem>,
+    /// Most mentioned entities
+    pub top_entities: Vec<RankedItem>,
+    /// Notable quotes
+    pub quotes: Vec<Quote>,
+    /// Comment reaction per entity, most discussed first
+    pub entity_reactions: Vec<EntityReaction>,
+    /// Articles with the most comments
+    pub discussed_articles: Vec<ArticleReaction>,
+    /// Crawl failures
+    pub failures: FailureSummary,
+}
+
+/// Builds reports from articles and crawl records
+#[derive(Debug, Clone)]
+pub struct ReportBuilder {
+    period: ReportPeriod,
+    last_day: NaiveDate,
+    limit: usize,
+    /// Reactions recorded when the articles were stored
+    reactions: Option<Vec<ArticleReaction>>,
+}
+
+impl ReportBuilder {
+    /// Create a builder for the period ending with `last_day`
+    #[must_use]
+    pub fn new(period: ReportPeriod, last_day: NaiveDate) -> Self {
+        Self {
+            period,
+            last_day,
+            limit: 10,
+            reactions: None,
+        }
+    }
+
+    /// Set how many keywords, entities, quotes, reactions and errors to keep
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.max(1);
+        self
+    }
+
+    /// Use the comment reactions of the report period recorded in the time
+    /// series ([`PgTimeSeriesStore::reactions`]) instead of analyzing the
+    /// comments of the articles again
+    ///
+    /// [`PgTimeSeriesStore::reactions`]: crate::storage::PgTimeSeriesStore::reactions
+    #[must_use]
+    pub fn with_reactions(mut self, reactions: Vec<ArticleReaction>) -> Self {
+        self.reactions = Some(reactions);
+        self
+    }
+
+    /// `[start, end)` of the report period
+    #[must_use]
+    pub fn window(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        self.period.window(self.last_day)
+    }
+
+    /// `[start, end)` of the period before the report period
+    #[must_use]
+    pub fn previous_window(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        let (start, _) = self.window();
+        (start - self.period.duration(), start)
+    }
+
+    /// Build the report
+    ///
+    /// `articles` may include the previous period; articles outside both
+    /// windows are ignored. Keywords and entities count once per article.
+    #[must_use]
+    pub fn build(&self, articles: &[Article], failures: &[CrawlRecord]) -> NewsReport {
+        let (start, end) = self.window();
+        let (previous_start, _) = self.previous_window();
+        let in_range = |a: &&Article, from: DateTime<Utc>, to: DateTime<Utc>| {
+            a.published_at.is_some_and(|t| t >= from && t < to)
+        };
+        let current: Vec<&Article> = articles
+            .iter()
+            .filter(|a| in_range(a, start, end))
+            .collect();
+        let previous: Vec<&Article> = articles
+            .iter()
+            .filter(|a| in_range(a, previous_start, start))
+            .collect();
+
+        let tokenizer = TextTokenizer::new();
+        let extractor = RelationExtractor::new();
+        let keywords = |a: &Article| -> HashSet<String> {
+            tokenizer
+                .tokenize(&format!("{} {}", a.title, a.body))
+                .into_iter()
+                .collect()
+        };
+        let entities = |a: &Article| -> HashSet<String> {
+            extractor
+                .extract_entities(&format!("{} {}", a.title, a.body), EntitySource::Both)
+                .into_iter()
+                .filter(|e| EntityType::from_ontology(e.entity_type).is_some())
+                .map(|e| e.canonical_name.unwrap_or(e.text))
+                .collect()
+        };
+        let category = |a: &Article| -> HashSet<String> {
+            HashSet::from([a.category.clone().unwrap_or_else(|| "unknown".to_string())])
+        };
+
+        let entity_counts = count_per_article(&current, entities);
+        let top_entities = rank(
+            &entity_counts,
+            &count_per_article(&previous, entities),
+            self.limit,
+        );
+        let (entity_reactions, discussed_articles) = self.reactions(&current);
+
+        NewsReport {
+            period: self.period,
+            start,
+            end,
+            generated_at: Utc::now(),
+            total_articles: current.len(),
+            previous_articles: previous.len(),
+            volume: self.volume(&current, start, end),
+            categories: rank(
+                &count_per_article(&current, category),
+                &count_per_article(&previous, category),
+                usize::MAX,
+            ),
+            top_keywords: rank(
+                &count_per_article(&current, keywords),
+                &count_per_article(&previous, keywords),
+                self.limit,
+            ),
+            top_entities,
+            quotes: self.quotes(&current, &extractor, &entity_counts),
+            entity_reactions,
+            discussed_articles,
+            failures: self.failures(failures),
+        }
+    }
+
+    fn volume(
+        &self,
+        articles: &[&Article],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<VolumeBucket> {
+        let step = match self.period {
+            ReportPeriod::Daily => Duration::hours(1),
+            ReportPeriod::Weekly => Duration::days(1),
+        };
+
+        let mut buckets = Vec::new();
+        let mut bucket_start = start;
+        while bucket_start < end {
+            let bucket_end = bucket_start + step;
+            let count = articles
+                .iter()
+                .filter(|a| {
+                    a.published_at
+                        .is_some_and(|t| t >= bucket_start && t < bucket_end)
+                })
+                .count() as u64;
+            buckets.push(VolumeBucket {
+                start: bucket_start,
+                count,
+            });
+            bucket_start = bucket_end;
+        }
+        buckets
+    }
+
+    /// Said relations, ranked by how often the speaker is mentioned
+    fn quotes(
+        &self,
+        articles: &[&Article],
+        extractor: &RelationExtractor,
+        entity_counts: &HashMap<String, u64>,
+    ) -> Vec<Quote> {
+        let mut seen = HashSet::new();
+        let mut quotes = Vec::new();
+
+        for article in articles {
+            let entities = extractor.extract_entities(&article.body, EntitySource::Content);
+            for relation in extractor.extract_relations(&article.body, &entities) {
+                if relation.predicate != RelationType::Said {
+                    continue;
+                }
+
+                // Patterns capture either (speaker, quote) or (quote, speaker)
+                let (speaker, text) =
+                    if relation.subject.chars().count() <= relation.object.chars().count() {
+                        (relation.subject, relation.object)
+                    } else {
+                        (relation.object, relation.subject)
+                    };
+                let text = text.trim().trim_matches(['"', '\'', '“', '”']).to_string();
+                if text.chars().count() < MIN_QUOTE_CHARS || !seen.insert(text.clone()) {
+                    continue;
+                }
+
+                quotes.push(Quote {
+                    speaker,
+                    text,
+                    article_title: article.title.clone(),
+                    url: article.url.clone(),
+                });
+            }
+        }
+
+        let mentions = |q: &Quote| {
+            entity_counts
+                .iter()
+                .filter(|(name, _)| name.contains(&q.speaker))
+                .map(|(_, count)| *count)
+                .max()
+                .unwrap_or(0)
+        };
+        quotes.sort_by_key(|q| std::cmp::Reverse(mentions(q)));
+        quotes.truncate(self.limit);
+        quotes
+    }
+
+    /// Comment reactions per entity and the most commented articles
+    fn reactions(&self, articles: &[&Article]) -> (Vec<EntityReaction>, Vec<ArticleReaction>) {
+        let mut reactions: Vec<ArticleReaction> = match &self.reactions {
+            Some(recorded) => recorded.clone(),
+            None => {
+                let analyzer = ReactionAnalyzer::new();
+                articles
+                    .iter()
+                    .filter_map(|a| analyzer.analyze(a))
+                    .collect()
+            }
+        };
+
+        let mut entities = entity_reactions(&reactions, REACTION_TERMS);
+        entities.truncate(self.limit);
+
+        reactions.sort_by(|a, b| {
+            b.comments
+                .cmp(&a.comments)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        reactions.truncate(self.limit);
+        (entities, reactions)
+    }
+
+    fn failures(&self, failures: &[CrawlRecord]) -> FailureSummary {
+        let mut by_error: HashMap<String, usize> = HashMap::new();
+        for failure in failures {
+            let error = failure
+                .error_message
+                .clone()
+                .unwrap_or_else(|| "unknown error".to_string());
+            *by_error.entry(error).or_insert(0) += 1;
+        }
+
+        let mut top_errors: Vec<(String, usize)> = by_error.into_iter().collect();
+        top_errors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_errors.truncate(self.limit);
+
+        FailureSummary {
+            total: failures.len(),
+            top_errors,
+        }
+    }
+}
+
+/// Count in how many articles each extracted name appears
+fn count_per_article<F>(articles: &[&Article], extract: F) -> HashMap<String, u64>
+where
+    F: Fn(&Article) -> HashSet<String>,
+{
+    let mut counts = HashMap::new();
+    for article in articles {
+        for name in extract(article) {
+            *counts.entry(name).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Top names by count, with their previous-period counts
+fn rank(
+    current: &HashMap<String, u64>,
+    previous: &HashMap<String, u64>,
+    limit: usize,
+) -> Vec<RankedItem> {
+    let mut items: Vec<RankedItem> = current
+        .iter()
+        .map(|(name, count)| RankedItem {
+            name: name.clone(),
+            count: *count,
+            previous: previous.get(name).copied().unwrap_or(0),
+        })
+        .collect();
+    items.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    items.truncate(limit);
+    items
+}
+
+impl NewsReport {
+    /// Report title including the period
+    #[must_use]
+    pub fn title(&self) -> String {
+        let last_day = (self.end - Duration::days(1)).format("%Y-%m-%d");
+        match self.period {
+            ReportPeriod::Daily => format!("{} — {last_day}", self.period.title()),
+            ReportPeriod::Weekly => format!(
+                "{} — {} ~ {last_day}",
+                self.period.title(),
+                self.start.format("%Y-%m-%d")
+            ),
+        }
+    }
+
+    fn volume_label(&self, bucket: &VolumeBucket) -> String {
+        match self.period {
+            ReportPeriod::Daily => bucket.start.format("%H:00").to_string(),
+            ReportPeriod::Weekly => bucket.start.format("%m-%d (%a)").to_string(),
+        }
+    }
+
+    fn article_change(&self) -> String {
+        RankedItem {
+            name: String::new(),
+            count: self.total_articles as u64,
+            previous: self.previous_articles as u64,
+        }
+        .change_label()
+    }
+
+    /// Short plain-text summary for chat notifications
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let names = |items: &[RankedItem]| {
+            items
+                .iter()
+                .take(5)
+                .map(|i| i.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut out = format!(
+            "{}\nArticles: {} ({} vs previous period)\n",
+            self.title(),
+            self.total_articles,
+            self.article_change()
+        );
+        if !self.top_keywords.is_empty() {
+            let _ = writeln!(out, "Top keywords: {}", names(&self.top_keywords));
+        }
+        if !self.top_entities.is_empty() {
+            let _ = writeln!(out, "Top entities: {}", names(&self.top_entities));
+        }
+        if let Some(article) = self.discussed_articles.first() {
+            let _ = writeln!(
+                out,
+                "Most discussed: {} ({} comments)",
+                article.title, article.comments
+            );
+        }
+        let _ = write!(out, "Crawl failures: {}", self.failures.total);
+        out
+    }
+
+    /// Render the report as Markdown
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}\n", self.title());
+        let _ = writeln!(
+            out,
+            "Generated {} · {} articles ({} vs previous period)\n",
+            self.generated_at.format("%Y-%m-%d %H:%M UTC"),
+            self.total_articles,
+            self.article_change()
+        );
+
+        out.push_str("## Article volume\n\n| Time | Articles |\n|---|---:|\n");
+        for bucket in &self.volume {
+            let _ = writeln!(out, "| {} | {} |", self.volume_label(bucket), bucket.count);
+        }
+
+        for (heading, items) in [
+            ("Categories", &self.categories),
+            ("Top keywords", &self.top_keywords),
+            ("Top entities", &self.top_entities),
+        ] {
+            let _ = write!(out, "\n## {heading}\n\n");
+            if items.is_empty() {
+                out.push_str("_None_\n");
+                continue;
+            }
+            out.push_str("| # | Name | Articles | Change |\n|---:|---|---:|---:|\n");
+            for (i, item) in items.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | {} |",
+                    i + 1,
+                    markdown_escape(&item.name),
+                    item.count,
+                    item.change_label()
+                );
+            }
+        }
+
+        out.push_str("\n## Notable quotes\n\n");
+        if self.quotes.is_empty() {
+            out.push_str("_None_\n");
+        }
+        for quote in &self.quotes {
+            let _ = writeln!(
+                out,
+                "> “{}”\n> — **{}**, [{}]({})\n",
+                markdown_escape(&quote.text),
+                markdown_escape(&quote.speaker),
+                markdown_escape(&quote.article_title),
+                quote.url
+            );
+        }
+
+        out.push_str("\n## Public reaction\n\n");
+        if self.discussed_articles.is_empty() {
+            out.push_str("_No comments_\n");
+        }
+        if !self.entity_reactions.is_empty() {
+            out.push_str(
+                "| # | Entity | Articles | Comments | Likes | Sentiment | Top terms |\n\
+                 |---:|---|---:|---:|---:|---:|---|\n",
+            );
+            for (i, reaction) in self.entity_reactions.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "| {} | {} | {} | {} | {} | {:+.2} | {} |",
+                    i + 1,
+                    markdown_escape(&reaction.entity),
+                    reaction.articles,
+                    reaction.comments,
+                    reaction.likes,
+                    reaction.sentiment,
+                    markdown_escape(&term_list(&reaction.top_terms))
+                );
+            }
+        }
+        if !self.discussed_articles.is_empty() {
+            out.push_str(
+                "\n**Most discussed articles**\n\n\
+                 | Article | Comments | Likes | Sentiment | Top terms |\n\
+                 |---|---:|---:|---:|---|\n",
+            );
+            for reaction in &self.discussed_articles {
+                let _ = writeln!(
+                    out,
+                    "| [{}]({}) | {} | {} | {:+.2} | {} |",
+                    markdown_escape(&reaction.title),
+                    reaction.url,
+                    reaction.comments,
+                    reaction.likes,
+                    reaction.sentiment,
+                    markdown_escape(&term_list(&reaction.top_terms))
+                );
+            }
+        }
+
+        let _ = write!(
+            out,
+            "\n## Crawl failures\n\n{} failed crawls\n",
+            self.failures.total
+        );
+        if !self.failures.top_errors.is_empty() {
+            out.push_str("\n| Error | Count |\n|---|---:|\n");
+            for (error, count) in &self.failures.top_errors {
+                let _ = writeln!(out, "| {} | {count} |", markdown_escape(error));
+            }
+        }
+
+        out
+    }
+
+    /// Render the report as a standalone HTML document (e.g. for email)
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        let title = html_escape(&self.title());
+        let _ = write!(
+            out,
+            "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>\n\
+             body {{ font-family: sans-serif; max-width: 860px; margin: 2em auto; color: #222; }}\n\
+             table {{ border-collapse: collapse; margin-bottom: 1em; }}\n\
+             th, td {{ border: 1px solid #ddd; padding: 4px 10px; }}\n\
+             td.num {{ text-align: right; }}\n\
+             blockquote {{ border-left: 4px solid #ccc; margin: 0 0 1em; padding-left: 1em; }}\n\
+             </style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+        );
+        let _ = writeln!(
+            out,
+            "<p>Generated {} · {} articles ({} vs previous period)</p>",
+            self.generated_at.format("%Y-%m-%d %H:%M UTC"),
+            self.total_articles,
+            self.article_change()
+        );
+
+        out.push_str("<h2>Article volume</h2>\n<table>\n<tr><th>Time</th><th>Articles</th></tr>\n");
+        for bucket in &self.volume {
+            let _ = writeln!(
+                out,
+                "<tr><td>{}</td><td class=\"num\">{}</td></tr>",
+                html_escape(&self.volume_label(bucket)),
+                bucket.count
+            );
+        }
+        out.push_str("</table>\n");
+
+        for (heading, items) in [
+            ("Categories", &self.categories),
+            ("Top keywords", &self.top_keywords),
+            ("Top entities", &self.top_entities),
+        ] {
+            let _ = writeln!(out, "<h2>{heading}</h2>");
+            if items.is_empty() {
+                out.push_str("<p><em>None</em></p>\n");
+                continue;
+            }
+            out.push_str(
+                "<table>\n<tr><th>#</th><th>Name</th><th>Articles</th><th>Change</th></tr>\n",
+            );
+            for (i, item) in items.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "<tr><td class=\"num\">{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td></tr>",
+                    i + 1,
+                    html_escape(&item.name),
+                    item.count,
+                    item.change_label()
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        out.push_str("<h2>Notable quotes</h2>\n");
+        if self.quotes.is_empty() {
+            out.push_str("<p><em>None</em></p>\n");
+        }
+        for quote in &self.quotes {
+            let _ = writeln!(
+                out,
+                "<blockquote>“{}”<br>— <strong>{}</strong>, <a href=\"{}\">{}</a></blockquote>",
+                html_escape(&quote.text),
+                html_escape(&quote.speaker),
+                html_escape(&quote.url),
+                html_escape(&quote.article_title)
+            );
+        }
+
+        out.push_str("<h2>Public reaction</h2>\n");
+        if self.discussed_articles.is_empty() {
+            out.push_str("<p><em>No comments</em></p>\n");
+        }
+        if !self.entity_reactions.is_empty() {
+            out.push_str(
+                "<table>\n<tr><th>#</th><th>Entity</th><th>Articles</th><th>Comments</th>\
+                 <th>Likes</th><th>Sentiment</th><th>Top terms</th></tr>\n",
+            );
+            for (i, reaction) in self.entity_reactions.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "<tr><td class=\"num\">{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{:+.2}</td><td>{}</td></tr>",
+                    i + 1,
+                    html_escape(&reaction.entity),
+                    reaction.articles,
+                    reaction.comments,
+                    reaction.likes,
+                    reaction.sentiment,
+                    html_escape(&term_list(&reaction.top_terms))
+                );
+            }
+            out.push_str("</table>\n");
+        }
+        if !self.discussed_articles.is_empty() {
+            out.push_str(
+                "<h3>Most discussed articles</h3>\n<table>\n<tr><th>Article</th>\
+                 <th>Comments</th><th>Likes</th><th>Sentiment</th><th>Top terms</th></tr>\n",
+            );
+            for reaction in &self.discussed_articles {
+                let _ = writeln!(
+                    out,
+                    "<tr><td><a href=\"{}\">{}</a></td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{:+.2}</td><td>{}</td></tr>",
+                    html_escape(&reaction.url),
+                    html_escape(&reaction.title),
+                    reaction.comments,
+                    reaction.likes,
+                    reaction.sentiment,
+                    html_escape(&term_list(&reaction.top_terms))
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        let _ = writeln!(
+            out,
+            "<h2>Crawl failures</h2>\n<p>{} failed crawls</p>",
+            self.failures.total
+        );
+        if !self.failures.top_errors.is_empty() {
+            out.push_str("<table>\n<tr><th>Error</th><th>Count</th></tr>\n");
+            for (error, count) in &self.failures.top_errors {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td class=\"num\">{count}</td></tr>",
+                    html_escape(error)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Comma-separated terms without counts
+fn term_list(terms: &[(String, u64)]) -> String {
+    terms
+        .iter()
+        .take(REACTION_TERMS)
+        .map(|(term, _)| term.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Escape characters that break Markdown tables and emphasis
+fn markdown_escape(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Comment;
+    use crate::storage::CrawlStatus;
+    use uuid::Uuid;
+
+    fn article(title: &str, body: &str, category: &str, published_at: DateTime<Utc>) -> Article {
+        Article {
+            id: Uuid::new_v4(),
+            url: format!("https://n.news.naver.com/{}", Uuid::new_v4()),
+            title: title.to_string(),
+            body: body.to_string(),
+            author: None,
+            published_at: Some(published_at),
+            category: Some(category.to_string()),
+            content_hash: String::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn failure(error: &str) -> CrawlRecord {
+        CrawlRecord {
+            id: String::new(),
+            url: "https://n.news.naver.com/failed".to_string(),
+            content_hash: String::new(),
+            crawled_at: Utc::now(),
+            status: CrawlStatus::Failed,
+            error_message: Some(error.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_period_windows() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+
+        let builder = ReportBuilder::new(ReportPeriod::Weekly, day);
+        let (start, end) = builder.window();
+        assert_eq!(
+            start.date_naive(),
+            NaiveDate::from_ymd_opt(2026, 2, 9).unwrap()
+        );
+        assert_eq!(
+            end.date_naive(),
+            NaiveDate::from_ymd_opt(2026, 2, 16).unwrap()
+        );
+        assert_eq!(
+            builder.previous_window().0.date_naive(),
+            NaiveDate::from_ymd_opt(2026, 2, 2).unwrap()
+        );
+
+        assert_eq!(ReportPeriod::parse("Daily").unwrap(), ReportPeriod::Daily);
+        assert!(ReportPeriod::parse("monthly").is_err());
+    }
+
+    #[test]
+    fn test_build_daily_report() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        let builder = ReportBuilder::new(ReportPeriod::Daily, day).with_limit(5);
+        let (start, _) = builder.window();
+
+        let articles = vec![
+            article(
+                "반도체 수출 호조",
+                "",
+                "economy",
+                start + Duration::hours(9),
+            ),
+            article(
+                "반도체 투자 확대",
+                "",
+                "economy",
+                start + Duration::hours(9),
+            ),
+            article(
+                "국회 본회의 개최",
+                "",
+                "politics",
+                start + Duration::hours(14),
+            ),
+            article("반도체 업황", "", "economy", start - Duration::hours(3)),
+            article("범위 밖 기사", "", "economy", start + Duration::days(2)),
+        ];
+        let failures = vec![failure("HTTP 404"), failure("HTTP 404"), failure("timeout")];
+
+        let report = builder.build(&articles, &failures);
+        assert_eq!(report.total_articles, 3);
+        assert_eq!(report.previous_articles, 1);
+        assert_eq!(report.volume.len(), 24);
+        assert_eq!(report.volume[9].count, 2);
+
+        assert_eq!(report.categories[0].name, "economy");
+        assert_eq!(report.categories[0].count, 2);
+        assert_eq!(report.top_keywords[0].name, "반도체");
+        assert_eq!(report.top_keywords[0].previous, 1);
+        assert_eq!(report.top_keywords[0].change_label(), "+100%");
+
+        assert_eq!(report.failures.total, 3);
+        assert_eq!(report.failures.top_errors[0], ("HTTP 404".to_string(), 2));
+    }
+
+    #[test]
+    fn test_report_public_reaction() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        let builder = ReportBuilder::new(ReportPeriod::Daily, day);
+        let (start, _) = builder.window();
+        let comment = |text: &str, likes| Comment {
+            id: Uuid::new_v4().to_string(),
+            author: "reader".to_string(),
+            text: text.to_string(),
+            created_at: start + Duration::hours(10),
+            likes,
+        };
+
+        let mut quiet = article("삼성전자 신제품", "", "it", start + Duration::hours(8));
+        quiet.comments = vec![comment("디자인 혁신", 2)];
+        let mut discussed = article(
+            "삼성전자 실적 발표",
+            "",
+            "economy",
+            start + Duration::hours(9),
+        );
+        discussed.comments = vec![comment("실적 개선 기대", 5), comment("실적 부진 우려", 1)];
+        let silent = article(
+            "국회 본회의 개최",
+            "",
+            "politics",
+            start + Duration::hours(9),
+        );
+
+        let report = builder.build(&[quiet, discussed, silent], &[]);
+
+        assert_eq!(report.discussed_articles.len(), 2);
+        assert_eq!(report.discussed_articles[0].title, "삼성전자 실적 발표");
+        assert_eq!(report.entity_reactions[0].entity, "삼성전자");
+        assert_eq!(report.entity_reactions[0].comments, 3);
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("## Public reaction"));
+        assert!(markdown.contains("| 1 | 삼성전자 | 2 | 3 | 8 |"));
+        assert!(report
+            .summary()
+            .contains("Most discussed: 삼성전자 실적 발표 (2 comments)"));
+        assert!(report
+            .to_html()
+            .contains("<h3>Most discussed articles</h3>"));
+    }
+
+    #[test]
+    fn test_report_recorded_reactions() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        let (start, _) = ReportPeriod::Daily.window(day);
+        let recorded = ArticleReaction {
+            article_id: "001_0000000001".to_string(),
+            title: "삼성전자 실적 발표".to_string(),
+            url: "https://n.news.naver.com/article/001/0000000001".to_string(),
+            published_at: start + Duration::hours(9),
+            entities: vec!["삼성전자".to_string()],
+            comments: 4,
+            likes: 7,
+            weight: 4.0,
+            sentiment: 0.5,
+            top_terms: vec![("실적".to_string(), 4)],
+        };
+
+        // Recorded reactions are used even without the articles' comments
+        let report = ReportBuilder::new(ReportPeriod::Daily, day)
+            .with_reactions(vec![recorded.clone()])
+            .build(&[], &[]);
+        assert_eq!(report.discussed_articles, vec![recorded]);
+        assert_eq!(report.entity_reactions[0].entity, "삼성전자");
+    }
+
+    #[test]
+    fn test_render_report() {
+        let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        let builder = ReportBuilder::new(ReportPeriod::Daily, day);
+        let (start, _) = builder.window();
+        let mut report = builder.build(
+            &[article(
+                "AI <칩> 경쟁",
+                "",
+                "it",
+                start + Duration::hours(1),
+            )],
+            &[failure("HTTP 500 | upstream")],
+        );
+        report.quotes.push(Quote {
+            speaker: "홍길동".to_string(),
+            text: "반도체 경쟁력을 강화하겠다".to_string(),
+            article_title: "<단독> 인터뷰".to_string(),
+            url: "https://example.com/a?x=1&y=2".to_string(),
+        });
+
+        let markdown = report.to_markdown();
+        assert!(markdown.starts_with("# Daily News Report — 2026-02-15"));
+        assert!(markdown.contains("| 01:00 | 1 |"));
+        assert!(markdown.contains("HTTP 500 \\| upstream"));
+        assert!(markdown.contains("— **홍길동**"));
+        assert!(markdown.contains("_No comments_"));
+
+        let html = report.to_html();
+        assert!(html.contains("<title>Daily News Report — 2026-02-15</title>"));
+        assert!(html.contains("&lt;단독&gt; 인터뷰"));
+        assert!(html.contains("x=1&amp;y=2"));
+
+        let summary = report.summary();
+        assert!(summary.contains("Articles: 1 (new vs previous period)"));
+        assert!(summary.contains("Crawl failures: 1"));
+    }
+}
@@ -0,0 +1,311 @@
+//! Lexicon-based sentiment scoring for news articles
+//!
+//! Scores Korean/English text by counting positive and negative terms:
+//! - Tokens come from [`TextTokenizer`], so Korean particles are stripped
+//! - Korean lexicon entries are stems and match as prefixes (`상승` matches `상승세`)
+//! - A following negation (`않다`, `못했다`, ...) or preceding `not`/`no` flips a term
+//!
+//! The score is `(positive - negative) / (positive + negative)` in `[-1.0, 1.0]`,
+//! with 0.0 for text without sentiment terms. This is a fast first stage meant
+//! for aggregating trends over many articles, not per-sentence accuracy.
+
+use serde::{Deserialize, Serialize};
+
+use super::tokenizer::TextTokenizer;
+
+/// Positive term stems
+const POSITIVE_TERMS: &[&str] = &[
+    // Korean
+    "성장",
+    "상승",
+    "호조",
+    "개선",
+    "회복",
+    "증가",
+    "흑자",
+    "성공",
+    "합의",
+    "기대",
+    "긍정",
+    "최고",
+    "돌파",
+    "강세",
+    "호재",
+    "혁신",
+    "수상",
+    "환영",
+    "안정",
+    "확대",
+    "활성화",
+    "선정",
+    "타결",
+    "반등",
+    "호평",
+    "지원",
+    "협력",
+    "축하",
+    "우승",
+    "신기록",
+    // English
+    "growth",
+    "gain",
+    "gains",
+    "rise",
+    "rises",
+    "surge",
+    "record",
+    "success",
+    "improve",
+    "improved",
+    "recovery",
+    "profit",
+    "agreement",
+    "win",
+    "wins",
+    "strong",
+    "boost",
+];
+
+/// Negative term stems
+const NEGATIVE_TERMS: &[&str] = &[
+    // Korean
+    "하락", "감소", "위기", "우려", "적자", "실패", "논란", "비판", "갈등", "사고", "사망", "부진",
+    "악화", "폭락", "약세", "악재", "반발", "의혹", "혐의", "규탄", "피해", "충격", "불안", "손실",
+    "침체", "파업", "철회", "구속", "참사", "비난", // English
+    "fall", "falls", "decline", "drop", "drops", "crisis", "loss", "losses", "fail", "failure",
+    "concern", "concerns", "scandal", "accident", "death", "weak", "protest",
+];
+
+/// Tokens that negate the preceding Korean term
+const KOREAN_NEGATIONS: &[&str] = &["않", "못", "없"];
+
+/// Tokens that negate the following English term
+const ENGLISH_NEGATIONS: &[&str] = &["not", "no", "never", "without"];
+
+/// Absolute score beyond which text is labeled positive or negative
+const LABEL_THRESHOLD: f64 = 0.2;
+
+/// Overall polarity of a text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SentimentLabel {
+    /// Mostly positive terms
+    Positive,
+    /// Balanced or no sentiment terms
+    Neutral,
+    /// Mostly negative terms
+    Negative,
+}
+
+/// Sentiment of a single text
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SentimentScore {
+    /// Polarity in `[-1.0, 1.0]`
+    pub score: f64,
+    /// Positive terms found (after negation)
+    pub positive_terms: usize,
+    /// Negative terms found (after negation)
+    pub negative_terms: usize,
+}
+
+impl SentimentScore {
+    /// Build a score from term counts
+    #[must_use]
+    pub fn from_counts(positive_terms: usize, negative_terms: usize) -> Self {
+        let total = positive_terms + negative_terms;
+        let score = if total == 0 {
+            0.0
+        } else {
+            (positive_terms as f64 - negative_terms as f64) / total as f64
+        };
+
+        Self {
+            score,
+            positive_terms,
+            negative_terms,
+        }
+    }
+
+    /// Get the polarity label
+    #[must_use]
+    pub fn label(&self) -> SentimentLabel {
+        if self.score > LABEL_THRESHOLD {
+            SentimentLabel::Positive
+        } else if self.score < -LABEL_THRESHOLD {
+            SentimentLabel::Negative
+        } else {
+            SentimentLabel::Neutral
+        }
+    }
+}
+
+/// What article sentiment is aggregated by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SentimentDimension {
+    /// Named entity mentioned in the article
+    Entity,
+    /// Publisher (Naver press ID)
+    Publisher,
+    /// News category
+    Category,
+}
+
+impl SentimentDimension {
+    /// Parse a dimension name (`entity`, `publisher` or `category`)
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "entity" => Ok(Self::Entity),
+            "publisher" => Ok(Self::Publisher),
+            "category" => Ok(Self::Category),
+            other => Err(format!(
+                "Unknown sentiment dimension: {other}. Use entity, publisher or category"
+            )),
+        }
+    }
+
+    /// Get the dimension name
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Entity => "entity",
+            Self::Publisher => "publisher",
+            Self::Category => "category",
+        }
+    }
+}
+
+/// Lexicon-based sentiment analyzer
+#[derive(Debug, Clone)]
+pub struct SentimentAnalyzer {
+    tokenizer: TextTokenizer,
+}
+
+impl Default for SentimentAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SentimentAnalyzer {
+    /// Create an analyzer with the built-in lexicon
+    #[must_use]
+    pub fn new() -> Self {
+        // Standalone negations such as `못` are a single character
+        Self {
+            tokenizer: TextTokenizer::new().with_min_length(1),
+        }
+    }
+
+    /// Score a text
+    #[must_use]
+    pub fn score(&self, text: &str) -> SentimentScore {
+        let tokens = self.tokenizer.tokenize(text);
+        let (mut positive, mut negative) = (0usize, 0usize);
+
+        for (i, token) in tokens.iter().enumerate() {
+            let polarity = if matches_term(token, POSITIVE_TERMS) {
+                1
+            } else if matches_term(token, NEGATIVE_TERMS) {
+                -1
+            } else {
+                continue;
+            };
+
+            let negated_before = i > 0 && ENGLISH_NEGATIONS.contains(&tokens[i - 1].as_str());
+            let negated_after = tokens
+                .get(i + 1)
+                .is_some_and(|next| KOREAN_NEGATIONS.iter().any(|n| next.starts_with(n)));
+
+            match (polarity, negated_before || negated_after) {
+                (1, false) | (-1, true) => positive += 1,
+                _ => negative += 1,
+            }
+        }
+
+        SentimentScore::from_counts(positive, negative)
+    }
+
+    /// Score an article title and body
+    ///
+    /// Title and body scores are averaged, so the short title, which carries
+    /// the framing of the story, weighs as much as the body.
+    #[must_use]
+    pub fn score_article(&self, title: &str, body: &str) -> SentimentScore {
+        let title_score = self.score(title);
+        let body_score = self.score(body);
+
+        let score = match (
+            title_score.positive_terms + title_score.negative_terms,
+            body_score.positive_terms + body_score.negative_terms,
+        ) {
+            (0, 0) => 0.0,
+            (0, _) => body_score.score,
+            (_, 0) => title_score.score,
+            _ => (title_score.score + body_score.score) / 2.0,
+        };
+
+        SentimentScore {
+            score,
+            positive_terms: title_score.positive_terms + body_score.positive_terms,
+            negative_terms: title_score.negative_terms + body_score.negative_terms,
+        }
+    }
+}
+
+fn matches_term(token: &str, terms: &[&str]) -> bool {
+    terms.iter().any(|term| {
+        if term.is_ascii() {
+            token == *term
+        } else {
+            token.starts_with(term)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_polarity() {
+        let analyzer = SentimentAnalyzer::new();
+
+        let positive = analyzer.score("반도체 수출이 회복세를 보이며 상승했다");
+        assert_eq!(positive.positive_terms, 2);
+        assert_eq!(positive.label(), SentimentLabel::Positive);
+
+        let negative = analyzer.score("경기 침체 우려에 주가 폭락");
+        assert_eq!(negative.negative_terms, 3);
+        assert!((negative.score + 1.0).abs() < f64::EPSILON);
+
+        let neutral = analyzer.score("정부는 오늘 회의를 열었다");
+        assert_eq!(neutral.score, 0.0);
+        assert_eq!(neutral.label(), SentimentLabel::Neutral);
+    }
+
+    #[test]
+    fn test_negation_flips_terms() {
+        let analyzer = SentimentAnalyzer::new();
+
+        let korean = analyzer.score("협상은 성공하지 못했다");
+        assert_eq!(korean.negative_terms, 1);
+        assert_eq!(korean.positive_terms, 0);
+
+        let english = analyzer.score("Profit did not fall, but there was no growth");
+        assert_eq!(english.positive_terms, 2);
+        assert_eq!(english.negative_terms, 1);
+    }
+
+    #[test]
+    fn test_score_article_combines_title_and_body() {
+        let analyzer = SentimentAnalyzer::new();
+        let score =
+            analyzer.score_article("실적 개선", "매출은 감소했지만 이익이 증가했다. 흑자 전환");
+
+        // Title +1.0, body (2 - 1) / 3
+        assert!((score.score - (1.0 + 1.0 / 3.0) / 2.0).abs() < 1e-9);
+        assert_eq!(score.positive_terms, 3);
+        assert_eq!(score.negative_terms, 1);
+    }
+}
@@ -0,0 +1,301 @@
+//! Lightweight text tokenizer for keyword analytics
+//!
+//! Splits Korean/English news text into keyword tokens:
+//! - Splits on whitespace and punctuation
+//! - Lowercases Latin text
+//! - Strips common Korean postpositions (조사) such as `은/는/이/가/을/를/의`
+//! - Drops stopwords, numbers and tokens shorter than the minimum length
+//! - Keeps user dictionary words whole and rewrites synonyms to their
+//!   canonical form (see [`Vocabulary`])
+//!
+//! This is not a morphological analyzer; it is meant to be good enough for
+//! counting keyword frequencies across many articles.
+
+use std::collections::{HashMap, HashSet};
+
+use super::vocabulary::Vocabulary;
+
+/// Korean postpositions stripped from the end of Hangul tokens, longest first
+const KOREAN_PARTICLES: &[&str] = &[
+    "에서는",
+    "으로는",
+    "에게서",
+    "이라는",
+    "에서",
+    "으로",
+    "에게",
+    "까지",
+    "부터",
+    "처럼",
+    "보다",
+    "라는",
+    "하고",
+    "은",
+    "는",
+    "이",
+    "가",
+    "을",
+    "를",
+    "의",
+    "에",
+    "와",
+    "과",
+    "도",
+    "로",
+    "만",
+];
+
+/// Default stopwords (Korean function words and common English words)
+const DEFAULT_STOPWORDS: &[&str] = &[
+    // Korean
+    "그",
+    "이",
+    "저",
+    "것",
+    "수",
+    "등",
+    "및",
+    "또",
+    "더",
+    "때",
+    "중",
+    "위해",
+    "통해",
+    "대한",
+    "관련",
+    "있는",
+    "있다",
+    "없다",
+    "했다",
+    "한다",
+    "하는",
+    "하고",
+    "밝혔다",
+    "말했다",
+    "있습니다",
+    "기자",
+    "뉴스",
+    "무단",
+    "전재",
+    "배포",
+    "금지",
+    "오늘",
+    "이번",
+    "지난",
+    "올해",
+    "현재",
+    // English
+    "the",
+    "a",
+    "an",
+    "and",
+    "or",
+    "of",
+    "to",
+    "in",
+    "on",
+    "for",
+    "is",
+    "are",
+    "was",
+    "with",
+    "by",
+    "at",
+    "from",
+    "as",
+    "it",
+    "this",
+    "that",
+];
+
+/// Tokenizer that turns article text into keyword tokens
+#[derive(Debug, Clone)]
+pub struct TextTokenizer {
+    /// Minimum token length in characters
+    min_length: usize,
+
+    /// Tokens that are never returned
+    stopwords: HashSet<String>,
+
+    /// Words kept whole, even when short, numeric or ending in a particle
+    user_words: HashSet<String>,
+
+    /// Canonical form of each synonym
+    synonyms: HashMap<String, String>,
+}
+
+impl Default for TextTokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextTokenizer {
+    /// Create a tokenizer with the default stopword list and the
+    /// [installed](Vocabulary::installed) vocabulary
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            min_length: 2,
+            stopwords: DEFAULT_STOPWORDS.iter().map(|s| (*s).to_string()).collect(),
+            user_words: HashSet::new(),
+            synonyms: HashMap::new(),
+        }
+        .with_vocabulary(Vocabulary::installed())
+    }
+
+    /// Add the stopwords, user dictionary words and synonyms of a vocabulary
+    #[must_use]
+    pub fn with_vocabulary(mut self, vocabulary: &Vocabulary) -> Self {
+        self.user_words.extend(vocabulary.user_words());
+        self.synonyms.extend(vocabulary.synonym_map());
+        self.with_stopwords(vocabulary.stopwords.iter().cloned())
+    }
+
+    /// Set the minimum token length in characters
+    #[must_use]
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length.max(1);
+        self
+    }
+
+    /// Add stopwords on top of the defaults
+    #[must_use]
+    pub fn with_stopwords<I, S>(mut self, stopwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords
+            .extend(stopwords.into_iter().map(|s| s.into().to_lowercase()));
+        self
+    }
+
+    /// Split text into keyword tokens, in order of appearance
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|raw| !raw.is_empty())
+            .filter_map(|raw| {
+                let token = raw.to_lowercase();
+                if let Some(word) = self.user_word(&token) {
+                    return Some(self.canonical(word.to_string()));
+                }
+                let token = self.canonical(strip_particle(&token));
+                self.is_keyword(&token).then_some(token)
+            })
+            .collect()
+    }
+
+    /// Count how often each token occurs in the text
+    pub fn term_frequencies(&self, text: &str) -> HashMap<String, u64> {
+        let mut counts = HashMap::new();
+        for token in self.tokenize(text) {
+            *counts.entry(token).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// The user dictionary word a token is, alone or followed by a particle
+    fn user_word<'a>(&self, token: &'a str) -> Option<&'a str> {
+        if self.user_words.is_empty() {
+            return None;
+        }
+        if self.user_words.contains(token) {
+            return Some(token);
+        }
+        KOREAN_PARTICLES.iter().find_map(|particle| {
+            token
+                .strip_suffix(particle)
+                .filter(|stem| self.user_words.contains(*stem))
+        })
+    }
+
+    fn canonical(&self, token: String) -> String {
+        match self.synonyms.get(&token) {
+            Some(canonical) => canonical.clone(),
+            None => token,
+        }
+    }
+
+    fn is_keyword(&self, token: &str) -> bool {
+        token.chars().count() >= self.min_length
+            && !token.chars().all(|c| c.is_numeric())
+            && !self.stopwords.contains(token)
+    }
+}
+
+/// Remove a trailing Korean postposition, keeping at least two characters
+fn strip_particle(token: &str) -> String {
+    if !token.chars().last().is_some_and(is_hangul) {
+        return token.to_string();
+    }
+
+    for particle in KOREAN_PARTICLES {
+        if let Some(stem) = token.strip_suffix(particle) {
+            if stem.chars().count() >= 2 {
+                return stem.to_string();
+            }
+        }
+    }
+    token.to_string()
+}
+
+fn is_hangul(c: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_strips_particles_and_stopwords() {
+        let tokenizer = TextTokenizer::new();
+        let tokens = tokenizer.tokenize("정부는 반도체 수출을 위해 AI 지원책을 발표했다. 2024년");
+
+        assert_eq!(
+            tokens,
+            vec![
+                "정부",
+                "반도체",
+                "수출",
+                "ai",
+                "지원책",
+                "발표했다",
+                "2024년"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_short_stems_keep_particle() {
+        let tokenizer = TextTokenizer::new();
+        // Stripping would leave a single character
+        assert_eq!(tokenizer.tokenize("국가 나이"), vec!["국가", "나이"]);
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let vocabulary =
+            Vocabulary::parse("관계자", "에코프로\n005930\nK", "금융감독원, 금감원").unwrap();
+        let tokenizer = TextTokenizer::new().with_vocabulary(&vocabulary);
+
+        // Without the dictionary `로` is stripped as a particle
+        assert_eq!(TextTokenizer::new().tokenize("에코프로"), vec!["에코프"]);
+        assert_eq!(
+            tokenizer.tokenize("에코프로는 005930 K 금감원이 관계자에게"),
+            vec!["에코프로", "005930", "k", "금융감독원"]
+        );
+    }
+
+    #[test]
+    fn test_term_frequencies_and_custom_stopwords() {
+        let tokenizer = TextTokenizer::new().with_stopwords(["반도체"]);
+        let counts = tokenizer.term_frequencies("경제 경제가 반도체 123 Economy economy");
+
+        assert_eq!(counts.get("경제"), Some(&2));
+        assert_eq!(counts.get("economy"), Some(&2));
+        assert!(!counts.contains_key("반도체"));
+        assert!(!counts.contains_key("123"));
+    }
+}
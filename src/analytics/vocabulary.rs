@@ -0,0 +1,231 @@
+//! Domain vocabulary shared by keyword analytics and the search index
+//!
+//! Three plain-text resources, each configured under `[analysis]`:
+//! - Stopwords: one word per line, added to [`TextTokenizer`]'s defaults and
+//!   dropped by a `stop` filter in the OpenSearch analyzers
+//! - User dictionary: nori user dictionary rules, a word per line optionally
+//!   followed by its parts (`세종시 세종 시`). The tokenizer keeps these words
+//!   whole and never strips a particle off them
+//! - Synonyms: Solr rules, `금융감독원, 금감원` (the first term is
+//!   canonical) or `금감원, 금융위 => 금융당국`. The tokenizer rewrites
+//!   single-word terms to their canonical form; the search analyzer expands
+//!   them at query time
+//!
+//! Lines starting with `#` and blank lines are ignored in all three files.
+//!
+//! The vocabulary loaded at startup is [installed](Vocabulary::install) for
+//! the process, so every [`TextTokenizer::new`] and every index created
+//! afterwards use the same words.
+//!
+//! [`TextTokenizer`]: super::TextTokenizer
+//! [`TextTokenizer::new`]: super::TextTokenizer::new
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::config::AnalysisConfig;
+
+/// Vocabulary installed for the process
+static INSTALLED: OnceLock<Vocabulary> = OnceLock::new();
+
+/// Vocabulary used when none is installed
+static EMPTY: Vocabulary = Vocabulary {
+    stopwords: Vec::new(),
+    user_dictionary: Vec::new(),
+    synonyms: Vec::new(),
+};
+
+/// Stopwords, user dictionary and synonym rules
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    /// Extra stopwords, lowercased
+    pub stopwords: Vec<String>,
+    /// Nori user dictionary rules
+    pub user_dictionary: Vec<String>,
+    /// Solr synonym rules
+    pub synonyms: Vec<String>,
+}
+
+impl Vocabulary {
+    /// Load the files configured in `[analysis]`
+    pub fn load(config: &AnalysisConfig) -> Result<Self> {
+        let read = |path: &Option<std::path::PathBuf>| -> Result<String> {
+            path.as_deref()
+                .map(read_resource)
+                .transpose()
+                .map(Option::unwrap_or_default)
+        };
+        Self::parse(
+            &read(&config.stopwords_path)?,
+            &read(&config.user_dictionary_path)?,
+            &read(&config.synonyms_path)?,
+        )
+    }
+
+    /// Build a vocabulary from the contents of the three files
+    pub fn parse(stopwords: &str, user_dictionary: &str, synonyms: &str) -> Result<Self> {
+        let synonyms: Vec<String> = lines(synonyms).map(str::to_string).collect();
+        for rule in &synonyms {
+            parse_synonym_rule(rule)?;
+        }
+        Ok(Self {
+            stopwords: lines(stopwords).map(str::to_lowercase).collect(),
+            user_dictionary: lines(user_dictionary)
+                .map(|rule| rule.split_whitespace().collect::<Vec<_>>().join(" "))
+                .collect(),
+            synonyms,
+        })
+    }
+
+    /// Whether the vocabulary holds no words
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stopwords.is_empty() && self.user_dictionary.is_empty() && self.synonyms.is_empty()
+    }
+
+    /// Install this vocabulary for the process
+    ///
+    /// Only the first call takes effect; returns whether this one did.
+    pub fn install(self) -> bool {
+        INSTALLED.set(self).is_ok()
+    }
+
+    /// The installed vocabulary, or an empty one
+    #[must_use]
+    pub fn installed() -> &'static Self {
+        INSTALLED.get().unwrap_or(&EMPTY)
+    }
+
+    /// Words of the user dictionary, lowercased
+    pub fn user_words(&self) -> impl Iterator<Item = String> + '_ {
+        self.user_dictionary
+            .iter()
+            .filter_map(|rule| rule.split_whitespace().next())
+            .map(str::to_lowercase)
+    }
+
+    /// Canonical form of each single-word synonym, lowercased
+    ///
+    /// Multi-word terms, and rules with a multi-word canonical form, are left
+    /// to the search analyzer. A term in several rules maps to the last.
+    #[must_use]
+    pub fn synonym_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        for rule in &self.synonyms {
+            let Ok((terms, canonical)) = parse_synonym_rule(rule) else {
+                continue;
+            };
+            if canonical.contains(char::is_whitespace) {
+                continue;
+            }
+            for term in terms {
+                if !term.contains(char::is_whitespace) && term != canonical {
+                    map.insert(term, canonical.clone());
+                }
+            }
+        }
+        map
+    }
+}
+
+/// Read a vocabulary file
+fn read_resource(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Non-empty, non-comment lines, trimmed
+fn lines(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Terms of a synonym rule and their canonical form, lowercased
+fn parse_synonym_rule(rule: &str) -> Result<(Vec<String>, String)> {
+    let terms = |list: &str| -> Vec<String> {
+        list.split(',')
+            .map(|term| term.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|term| !term.is_empty())
+            .map(|term| term.to_lowercase())
+            .collect()
+    };
+    match rule.split_once("=>") {
+        Some((from, to)) => {
+            let (from, to) = (terms(from), terms(to));
+            match (from.is_empty(), to.as_slice()) {
+                (false, [canonical]) => Ok((from, canonical.clone())),
+                _ => bail!("Invalid synonym rule: {rule}. Use `a, b => c`"),
+            }
+        }
+        None => {
+            let terms = terms(rule);
+            if terms.len() < 2 {
+                bail!("Invalid synonym rule: {rule}. Use `a, b, c` with two or more terms");
+            }
+            let canonical = terms[0].clone();
+            Ok((terms, canonical))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let vocabulary = Vocabulary::parse(
+            "# 불용어\n관계자\nSaid\n\n",
+            "세종시 세종 시\n에코프로\n",
+            "금융감독원, 금감원\n금감원, 금융위 => 금융당국\nFed, 미 연준 => 연준\n중앙은행, 한국 은행 => 한국 은행\n",
+        )
+        .unwrap();
+
+        assert_eq!(vocabulary.stopwords, ["관계자", "said"]);
+        assert_eq!(vocabulary.user_dictionary, ["세종시 세종 시", "에코프로"]);
+        assert_eq!(
+            vocabulary.user_words().collect::<Vec<_>>(),
+            ["세종시", "에코프로"]
+        );
+
+        // The later rule wins; multi-word terms are skipped
+        let synonyms = vocabulary.synonym_map();
+        assert_eq!(synonyms.get("금감원").map(String::as_str), Some("금융당국"));
+        assert_eq!(synonyms.get("금융위").map(String::as_str), Some("금융당국"));
+        assert_eq!(synonyms.get("fed").map(String::as_str), Some("연준"));
+        assert!(!synonyms.contains_key("금융감독원"));
+        assert!(!synonyms.contains_key("중앙은행"));
+    }
+
+    #[test]
+    fn test_invalid_synonym_rules() {
+        assert!(Vocabulary::parse("", "", "금감원").is_err());
+        assert!(Vocabulary::parse("", "", "=> 금융당국").is_err());
+        assert!(Vocabulary::parse("", "", "금감원 => 금융당국, 당국").is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let stopwords = dir.path().join("stopwords.txt");
+        std::fs::write(&stopwords, "관계자\n").unwrap();
+
+        let config = AnalysisConfig {
+            stopwords_path: Some(stopwords),
+            ..Default::default()
+        };
+        assert_eq!(Vocabulary::load(&config).unwrap().stopwords, ["관계자"]);
+        assert!(Vocabulary::load(&AnalysisConfig::default())
+            .unwrap()
+            .is_empty());
+
+        let missing = AnalysisConfig {
+            synonyms_path: Some(dir.path().join("missing.txt")),
+            ..Default::default()
+        };
+        assert!(Vocabulary::load(&missing).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Category volume anomaly detection
+//!
+//! Compares the article count of the latest complete hour in each category to
+//! the rolling mean and standard deviation of the preceding hours. Results map
+//! onto `AlertCondition::VolumeAnomaly` so alert rules can be evaluated against
+//! real counts (see `NotificationManager::process_volume_anomalies`). Both
+//! surges and drops count as anomalies.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::burst::WILDCARD;
+use crate::notifications::AlertCondition;
+
+/// Default length of the rolling window (one week of hours)
+pub const DEFAULT_VOLUME_WINDOW_HOURS: usize = 168;
+
+/// Hours of history required before a category can be evaluated
+pub const MIN_VOLUME_HISTORY_HOURS: usize = 24;
+
+/// Article volume of one category in one hour, against its rolling baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryVolume {
+    /// News category
+    pub category: String,
+    /// Start of the evaluated hour
+    pub hour: DateTime<Utc>,
+    /// Articles published in the hour
+    pub count: u64,
+    /// Rolling mean of the preceding hours
+    pub mean: f64,
+    /// Rolling standard deviation of the preceding hours
+    pub std_dev: f64,
+    /// Number of preceding hours in the baseline
+    pub history_hours: usize,
+}
+
+impl CategoryVolume {
+    /// Evaluate the last point of a chronological hourly series
+    ///
+    /// The preceding points form the baseline. Returns `None` when fewer than
+    /// [`MIN_VOLUME_HISTORY_HOURS`] baseline points are available.
+    #[must_use]
+    pub fn from_series(category: &str, series: &[(DateTime<Utc>, u64)]) -> Option<Self> {
+        let (&(hour, count), history) = series.split_last()?;
+        if history.len() < MIN_VOLUME_HISTORY_HOURS {
+            return None;
+        }
+
+        let n = history.len() as f64;
+        let mean = history.iter().map(|(_, c)| *c as f64).sum::<f64>() / n;
+        let variance = history
+            .iter()
+            .map(|(_, c)| (*c as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Some(Self {
+            category: category.to_string(),
+            hour,
+            count,
+            mean,
+            std_dev: variance.sqrt(),
+            history_hours: history.len(),
+        })
+    }
+
+    /// Signed deviation from the mean in standard deviations
+    ///
+    /// The standard deviation is floored at `sqrt(mean)` (Poisson noise) and
+    /// 1.0, so flat baselines do not turn every change into an anomaly.
+    #[must_use]
+    pub fn z_score(&self) -> f64 {
+        let noise = self.std_dev.max(self.mean.sqrt()).max(1.0);
+        (self.count as f64 - self.mean) / noise
+    }
+
+    /// Match this volume against an alert rule's condition
+    ///
+    /// Matches `VolumeAnomaly` conditions whose category is equal (or `"*"`)
+    /// when the absolute z-score reaches the rule's `threshold_stddev`.
+    /// Returns the condition with a wildcard replaced by the actual category,
+    /// so anomalies in different categories are deduplicated separately.
+    #[must_use]
+    pub fn matched_condition(&self, condition: &AlertCondition) -> Option<AlertCondition> {
+        let AlertCondition::VolumeAnomaly {
+            category,
+            threshold_stddev,
+        } = condition
+        else {
+            return None;
+        };
+
+        if category != WILDCARD && category != &self.category {
+            return None;
+        }
+        if self.z_score().abs() < *threshold_stddev {
+            return None;
+        }
+
+        Some(AlertCondition::VolumeAnomaly {
+            category: self.category.clone(),
+            threshold_stddev: *threshold_stddev,
+        })
+    }
+
+    /// Human-readable alert message
+    #[must_use]
+    pub fn message(&self) -> String {
+        format!(
+            "Article volume in '{}' {}: {} articles in the hour from {} (mean {:.1}, σ {:.1}, z={:.1})",
+            self.category,
+            if self.count as f64 >= self.mean {
+                "surged"
+            } else {
+                "dropped"
+            },
+            self.count,
+            self.hour.format("%Y-%m-%d %H:%M UTC"),
+            self.mean,
+            self.std_dev,
+            self.z_score(),
+        )
+    }
+
+    /// Alert metadata describing the volume
+    #[must_use]
+    pub fn metadata(&self) -> Vec<(String, String)> {
+        vec![
+            ("volume_category".to_string(), self.category.clone()),
+            ("volume_hour".to_string(), self.hour.to_rfc3339()),
+            ("volume_count".to_string(), self.count.to_string()),
+            ("volume_mean".to_string(), format!("{:.2}", self.mean)),
+            ("volume_stddev".to_string(), format!("{:.2}", self.std_dev)),
+            (
+                "volume_z_score".to_string(),
+                format!("{:.2}", self.z_score()),
+            ),
+        ]
+    }
+}
+
+/// Evaluate `hour` for every category against the preceding `window_hours`
+///
+/// `counts` are `(category, hour, count)` triples; repeated keys are summed.
+/// Each category's series starts at its first observed hour within the window
+/// (so new categories are not compared against a run of zeros) and missing
+/// hours count as zero. Results are sorted by category.
+#[must_use]
+pub fn category_volumes<I>(
+    counts: I,
+    hour: DateTime<Utc>,
+    window_hours: usize,
+) -> Vec<CategoryVolume>
+where
+    I: IntoIterator<Item = (String, DateTime<Utc>, u64)>,
+{
+    let start = hour - Duration::hours(window_hours as i64);
+
+    let mut by_category: BTreeMap<String, HashMap<DateTime<Utc>, u64>> = BTreeMap::new();
+    for (category, bucket, count) in counts {
+        if bucket >= start && bucket <= hour {
+            *by_category
+                .entry(category)
+                .or_default()
+                .entry(bucket)
+                .or_insert(0) += count;
+        }
+    }
+
+    by_category
+        .into_iter()
+        .filter_map(|(category, buckets)| {
+            let first = buckets.keys().min().copied()?;
+            let mut series = Vec::new();
+            let mut current = first;
+            while current <= hour {
+                series.push((current, buckets.get(&current).copied().unwrap_or(0)));
+                current += Duration::hours(1);
+            }
+            CategoryVolume::from_series(&category, &series)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn hour(h: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap() + Duration::hours(h)
+    }
+
+    fn series(counts: &[u64]) -> Vec<(DateTime<Utc>, u64)> {
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (hour(i as i64), *c))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_series_requires_history() {
+        assert!(CategoryVolume::from_series("politics", &series(&[10; 10])).is_none());
+        assert!(CategoryVolume::from_series("politics", &[]).is_none());
+
+        let volume = CategoryVolume::from_series("politics", &series(&[10; 25])).unwrap();
+        assert_eq!(volume.history_hours, 24);
+        assert_eq!(volume.hour, hour(24));
+        assert!((volume.mean - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_z_score_surge_and_drop() {
+        let mut counts: Vec<u64> = (0..48).map(|i| if i % 2 == 0 { 18 } else { 22 }).collect();
+        counts.push(40);
+        let surge = CategoryVolume::from_series("economy", &series(&counts)).unwrap();
+        assert!((surge.mean - 20.0).abs() < 1e-9);
+        assert!(surge.z_score() > 4.0);
+        assert!(surge.message().contains("surged"));
+
+        *counts.last_mut().unwrap() = 0;
+        let drop = CategoryVolume::from_series("economy", &series(&counts)).unwrap();
+        assert!(drop.z_score() < -4.0);
+        assert!(drop.message().contains("dropped"));
+    }
+
+    #[test]
+    fn test_matched_condition() {
+        let mut counts = vec![20; 30];
+        counts.push(60);
+        let volume = CategoryVolume::from_series("politics", &series(&counts)).unwrap();
+
+        let rule = AlertCondition::VolumeAnomaly {
+            category: "politics".to_string(),
+            threshold_stddev: 3.0,
+        };
+        assert_eq!(volume.matched_condition(&rule), Some(rule.clone()));
+
+        let wildcard = AlertCondition::VolumeAnomaly {
+            category: WILDCARD.to_string(),
+            threshold_stddev: 3.0,
+        };
+        assert_eq!(volume.matched_condition(&wildcard), Some(rule));
+
+        let other = AlertCondition::VolumeAnomaly {
+            category: "sports".to_string(),
+            threshold_stddev: 3.0,
+        };
+        assert!(volume.matched_condition(&other).is_none());
+
+        let strict = AlertCondition::VolumeAnomaly {
+            category: "politics".to_string(),
+            threshold_stddev: 100.0,
+        };
+        assert!(volume.matched_condition(&strict).is_none());
+
+        let spike = AlertCondition::KeywordSpike {
+            keyword: "*".to_string(),
+            threshold: 1,
+            window_minutes: 60,
+        };
+        assert!(volume.matched_condition(&spike).is_none());
+    }
+
+    #[test]
+    fn test_category_volumes_sums_and_fills() {
+        let now = hour(30);
+        let mut counts = Vec::new();
+        for h in 0..30 {
+            // Two publishers per hour, with a gap every 10th hour
+            if h % 10 != 5 {
+                counts.push(("politics".to_string(), hour(h), 3));
+                counts.push(("politics".to_string(), hour(h), 2));
+            }
+        }
+        counts.push(("politics".to_string(), now, 30));
+        // Too little history to evaluate
+        counts.push(("sports".to_string(), hour(25), 4));
+        // Outside the window
+        counts.push(("politics".to_string(), hour(31), 99));
+
+        let volumes = category_volumes(counts, now, 48);
+
+        assert_eq!(volumes.len(), 1);
+        let politics = &volumes[0];
+        assert_eq!(politics.category, "politics");
+        assert_eq!(politics.count, 30);
+        assert_eq!(politics.history_hours, 30);
+        assert!((politics.mean - 4.5).abs() < 1e-9);
+    }
+}
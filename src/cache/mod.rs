@@ -23,8 +23,16 @@ use deadpool_redis::{Config as PoolConfig, Pool, Runtime};
 use redis::AsyncCommands;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
+use crate::config::secrets;
+use crate::embedding::{Passage, SearchResult};
+
+/// Keys asked of each SCAN step, and removed by each UNLINK
+const SCAN_BATCH: usize = 500;
+
 /// Cache configuration
 #[derive(Debug, Clone)]
 pub struct CacheConfig {
@@ -64,8 +72,9 @@ impl CacheConfig {
     /// Create config from environment variables
     pub fn from_env() -> Result<Self> {
         Ok(Self {
-            url: std::env::var("REDIS_URL")
-                .unwrap_or_else(|_| "redis://localhost:6379".to_string()),
+            url: secrets::read_env("REDIS_URL")?
+                .map(|(_, url)| url)
+                .unwrap_or_else(|| "redis://localhost:6379".to_string()),
             pool_size: std::env::var("REDIS_POOL_SIZE")
                 .ok()
                 .and_then(|s| s.parse().ok())
@@ -87,6 +96,52 @@ impl CacheConfig {
     }
 }
 
+/// Kind of cached entry, each stored under its own key namespace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    /// Text embeddings, by model and content hash
+    Embedding,
+    /// Search results, by query hash
+    Search,
+    /// Article metadata, by article ID
+    Metadata,
+}
+
+impl CacheKind {
+    /// All kinds
+    pub const ALL: [Self; 3] = [Self::Embedding, Self::Search, Self::Metadata];
+
+    /// Parse `embedding`, `search` or `metadata`
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "embedding" | "embeddings" => Ok(Self::Embedding),
+            "search" => Ok(Self::Search),
+            "metadata" => Ok(Self::Metadata),
+            other => {
+                anyhow::bail!("Unknown cache kind: {other}. Use embedding, search or metadata")
+            }
+        }
+    }
+
+    /// Name accepted by [`CacheKind::parse`]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Embedding => "embedding",
+            Self::Search => "search",
+            Self::Metadata => "metadata",
+        }
+    }
+
+    /// Key segment after the prefix
+    fn segment(self) -> &'static str {
+        match self {
+            Self::Embedding => "embed",
+            Self::Search => "search",
+            Self::Metadata => "meta",
+        }
+    }
+}
+
 /// Cached embedding data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedEmbedding {
@@ -105,6 +160,9 @@ pub struct CachedSearchResult {
     pub results: Vec<SearchResultItem>,
     /// Query that was executed
     pub query: String,
+    /// Search mode the results came from
+    #[serde(default)]
+    pub mode: String,
     /// Timestamp when cached
     pub cached_at: i64,
 }
@@ -121,6 +179,46 @@ pub struct SearchResultItem {
     pub url: String,
     pub published_at: Option<String>,
     pub highlights: Option<Vec<String>>,
+    #[serde(default)]
+    pub passage: Option<Passage>,
+    #[serde(default)]
+    pub story_id: Option<String>,
+}
+
+impl From<&SearchResult> for SearchResultItem {
+    fn from(result: &SearchResult) -> Self {
+        Self {
+            id: result.id.clone(),
+            score: result.score,
+            title: result.title.clone(),
+            content: result.content.clone(),
+            category: result.category.clone(),
+            publisher: result.publisher.clone(),
+            url: result.url.clone(),
+            published_at: result.published_at.clone(),
+            highlights: result.highlights.clone(),
+            passage: result.passage.clone(),
+            story_id: result.story_id.clone(),
+        }
+    }
+}
+
+impl From<SearchResultItem> for SearchResult {
+    fn from(item: SearchResultItem) -> Self {
+        Self {
+            id: item.id,
+            score: item.score,
+            title: item.title,
+            content: item.content,
+            category: item.category,
+            publisher: item.publisher,
+            url: item.url,
+            published_at: item.published_at,
+            highlights: item.highlights,
+            passage: item.passage,
+            story_id: item.story_id,
+        }
+    }
 }
 
 /// Cache statistics
@@ -203,19 +301,24 @@ impl Cache {
     // Key Generation
     // =========================================================================
 
+    /// Generate cache key for an entry of `kind`
+    fn key(&self, kind: CacheKind, id: &str) -> String {
+        format!("{}:{}:{}", self.config.key_prefix, kind.segment(), id)
+    }
+
     /// Generate cache key for embedding
     fn embedding_key(&self, content_hash: &str) -> String {
-        format!("{}:embed:{}", self.config.key_prefix, content_hash)
+        self.key(CacheKind::Embedding, content_hash)
     }
 
     /// Generate cache key for search query
     fn search_key(&self, query_hash: &str) -> String {
-        format!("{}:search:{}", self.config.key_prefix, query_hash)
+        self.key(CacheKind::Search, query_hash)
     }
 
     /// Generate cache key for article metadata
     fn metadata_key(&self, article_id: &str) -> String {
-        format!("{}:meta:{}", self.config.key_prefix, article_id)
+        self.key(CacheKind::Metadata, article_id)
     }
 
     /// Hash content for cache key
@@ -318,8 +421,30 @@ impl Cache {
 
     /// Invalidate search cache (called when new articles are indexed)
     pub async fn invalidate_search_cache(&self) -> Result<u64> {
-        let pattern = format!("{}:search:*", self.config.key_prefix);
-        self.delete_pattern(&pattern).await
+        self.invalidate(CacheKind::Search).await
+    }
+
+    /// Delete every entry of `kind`, returning how many there were
+    pub async fn invalidate(&self, kind: CacheKind) -> Result<u64> {
+        self.delete_pattern(&self.key(kind, "*")).await
+    }
+
+    /// Number of entries of `kind`
+    ///
+    /// Counted with SCAN, so entries written or expiring meanwhile may be
+    /// missed or counted twice.
+    pub async fn count(&self, kind: CacheKind) -> Result<u64> {
+        let mut conn = self.pool.get().await.context("Failed to get connection")?;
+        let pattern = self.key(kind, "*");
+        let (mut cursor, mut count) = (0, 0);
+        loop {
+            let (next, keys) = scan_step(&mut conn, cursor, &pattern).await?;
+            count += keys.len() as u64;
+            if next == 0 {
+                return Ok(count);
+            }
+            cursor = next;
+        }
     }
 
     // =========================================================================
@@ -374,26 +499,28 @@ impl Cache {
     }
 
     /// Delete keys matching pattern
+    ///
+    /// Walks the keyspace with SCAN and unlinks each step's keys, so Redis
+    /// is never blocked on the whole keyspace or on freeing large values.
     async fn delete_pattern(&self, pattern: &str) -> Result<u64> {
         let mut conn = self.pool.get().await.context("Failed to get connection")?;
 
-        // Use SCAN to find keys (safer for production)
-        let keys: Vec<String> = redis::cmd("KEYS")
-            .arg(pattern)
-            .query_async(&mut *conn)
-            .await
-            .context("Failed to scan keys")?;
-
-        if keys.is_empty() {
-            return Ok(0);
+        let (mut cursor, mut count) = (0, 0);
+        loop {
+            let (next, keys) = scan_step(&mut conn, cursor, pattern).await?;
+            if !keys.is_empty() {
+                let unlinked: u64 = conn.unlink(&keys).await.context("Failed to delete keys")?;
+                count += unlinked;
+            }
+            if next == 0 {
+                break;
+            }
+            cursor = next;
         }
 
-        let count = keys.len() as u64;
-
-        // Delete all matching keys
-        let _: () = conn.del(keys).await.context("Failed to delete keys")?;
-
-        tracing::info!(pattern = %pattern, count = count, "Invalidated cache entries");
+        if count > 0 {
+            tracing::info!(pattern = %pattern, count = count, "Invalidated cache entries");
+        }
 
         Ok(count)
     }
@@ -409,10 +536,8 @@ impl Cache {
     pub async fn get_stats(&self) -> Result<CacheInfo> {
         let mut conn = self.pool.get().await?;
 
-        let info: String = redis::cmd("INFO")
-            .arg("stats")
-            .query_async(&mut *conn)
-            .await?;
+        // The default sections include both stats and memory
+        let info: String = redis::cmd("INFO").query_async(&mut *conn).await?;
 
         // Parse basic stats
         let mut hits = 0u64;
@@ -448,7 +573,7 @@ impl Cache {
 }
 
 /// Cache information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CacheInfo {
     pub hits: u64,
     pub misses: u64,
@@ -456,6 +581,25 @@ pub struct CacheInfo {
     pub hit_rate: f64,
 }
 
+/// One SCAN step from `cursor` over the keys matching `pattern`
+///
+/// Returns the cursor to continue from, `0` once the keyspace is covered.
+async fn scan_step(
+    conn: &mut deadpool_redis::Connection,
+    cursor: u64,
+    pattern: &str,
+) -> Result<(u64, Vec<String>)> {
+    redis::cmd("SCAN")
+        .arg(cursor)
+        .arg("MATCH")
+        .arg(pattern)
+        .arg("COUNT")
+        .arg(SCAN_BATCH)
+        .query_async(&mut **conn)
+        .await
+        .context("Failed to scan keys")
+}
+
 // ============================================================================
 // Serialization helpers using JSON (simpler than MessagePack for now)
 // ============================================================================
@@ -473,21 +617,27 @@ fn rmp_serde_decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
 // ============================================================================
 
 /// Optional cache that gracefully handles Redis unavailability
+///
+/// Counts the hits and misses of its lookups, see [`OptionalCache::stats`].
 pub struct OptionalCache {
     inner: Option<Cache>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl OptionalCache {
     /// Create with an optional cache
     pub fn new(cache: Option<Cache>) -> Self {
-        Self { inner: cache }
+        Self {
+            inner: cache,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
     }
 
     /// Create from config, returning empty cache if Redis unavailable
     pub async fn from_config(config: &CacheConfig) -> Self {
-        Self {
-            inner: Cache::try_new(config).await,
-        }
+        Self::new(Cache::try_new(config).await)
     }
 
     /// Check if cache is available
@@ -495,10 +645,31 @@ impl OptionalCache {
         self.inner.is_some()
     }
 
+    /// Hits and misses of the lookups so far; bytes are not tracked
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+
+    /// Count a lookup; an error counts as a miss
+    fn record<T>(&self, found: Result<Option<T>>) -> Option<T> {
+        let found = found.ok().flatten();
+        let counter = if found.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        found
+    }
+
     /// Get cached embedding
     pub async fn get_embedding(&self, content_hash: &str) -> Option<CachedEmbedding> {
         match &self.inner {
-            Some(cache) => cache.get_embedding(content_hash).await.ok().flatten(),
+            Some(cache) => self.record(cache.get_embedding(content_hash).await),
             None => None,
         }
     }
@@ -510,10 +681,75 @@ impl OptionalCache {
         }
     }
 
+    /// Get the embedding of `content` from the cache, or compute and cache it
+    ///
+    /// Unlike [`Cache::get_or_compute_embedding`], Redis errors are ignored.
+    pub async fn get_or_compute_embedding<F, Fut>(
+        &self,
+        content: &str,
+        model: &str,
+        compute_fn: F,
+    ) -> Result<Vec<f32>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<f32>>>,
+    {
+        let content_hash = Cache::hash_content(content);
+        if let Some(cached) = self.get_embedding(&content_hash).await {
+            tracing::debug!(hash = %content_hash, "Embedding cache hit");
+            return Ok(cached.embedding);
+        }
+
+        let embedding = compute_fn().await?;
+        self.set_embedding(&content_hash, &embedding, model).await;
+        Ok(embedding)
+    }
+
+    /// Get the embeddings of `contents`, computing the ones not cached with
+    /// a single call to `compute_fn`
+    ///
+    /// `compute_fn` gets the indices of the missing contents and returns
+    /// their embeddings in that order, which are then cached.
+    pub async fn get_or_compute_embeddings<F, Fut>(
+        &self,
+        contents: &[String],
+        model: &str,
+        compute_fn: F,
+    ) -> Result<Vec<Vec<f32>>>
+    where
+        F: FnOnce(Vec<usize>) -> Fut,
+        Fut: Future<Output = Result<Vec<Vec<f32>>>>,
+    {
+        let hashes: Vec<String> = contents.iter().map(|c| Cache::hash_content(c)).collect();
+        let mut embeddings = Vec::with_capacity(contents.len());
+        for hash in &hashes {
+            embeddings.push(self.get_embedding(hash).await.map(|c| c.embedding));
+        }
+
+        let missing: Vec<usize> = (0..contents.len())
+            .filter(|&i| embeddings[i].is_none())
+            .collect();
+        if !missing.is_empty() {
+            let computed = compute_fn(missing.clone()).await?;
+            if computed.len() != missing.len() {
+                anyhow::bail!(
+                    "Expected {} embeddings, got {}",
+                    missing.len(),
+                    computed.len()
+                );
+            }
+            for (i, embedding) in missing.into_iter().zip(computed) {
+                self.set_embedding(&hashes[i], &embedding, model).await;
+                embeddings[i] = Some(embedding);
+            }
+        }
+        Ok(embeddings.into_iter().flatten().collect())
+    }
+
     /// Get cached search results
     pub async fn get_search(&self, query_hash: &str) -> Option<CachedSearchResult> {
         match &self.inner {
-            Some(cache) => cache.get_search(query_hash).await.ok().flatten(),
+            Some(cache) => self.record(cache.get_search(query_hash).await),
             None => None,
         }
     }
@@ -567,6 +803,18 @@ mod tests {
         assert_ne!(hash1, hash3);
     }
 
+    #[test]
+    fn test_cache_kind_parse() {
+        for kind in CacheKind::ALL {
+            assert_eq!(CacheKind::parse(kind.as_str()).unwrap(), kind);
+        }
+        assert_eq!(
+            CacheKind::parse("Embeddings").unwrap(),
+            CacheKind::Embedding
+        );
+        assert!(CacheKind::parse("articles").is_err());
+    }
+
     #[test]
     fn test_cache_stats_hit_rate() {
         let mut stats = CacheStats::default();
@@ -598,6 +846,47 @@ mod tests {
         assert!(!cache.is_available());
     }
 
+    #[tokio::test]
+    async fn test_get_or_compute_embeddings_without_redis() {
+        let cache = OptionalCache::new(None);
+        let contents = vec!["a".to_string(), "bb".to_string()];
+
+        let embeddings = cache
+            .get_or_compute_embeddings(&contents, "test-model", |missing| async move {
+                assert_eq!(missing, vec![0, 1]);
+                Ok(missing.iter().map(|&i| vec![i as f32]).collect())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(embeddings, vec![vec![0.0], vec![1.0]]);
+        // Lookups without Redis are not counted
+        assert_eq!(cache.stats().hits + cache.stats().misses, 0);
+    }
+
+    #[test]
+    fn test_search_result_item_round_trip() {
+        let item = SearchResultItem {
+            id: "001_0001".to_string(),
+            score: 1.5,
+            title: "제목".to_string(),
+            content: "본문".to_string(),
+            category: "politics".to_string(),
+            publisher: None,
+            url: "https://n.news.naver.com/article/001/0001".to_string(),
+            published_at: None,
+            highlights: None,
+            passage: None,
+            story_id: Some("story-1".to_string()),
+        };
+
+        let result = SearchResult::from(item);
+        let item = SearchResultItem::from(&result);
+
+        assert_eq!(item.id, "001_0001");
+        assert_eq!(item.story_id.as_deref(), Some("story-1"));
+    }
+
     // Integration tests require running Redis
     #[tokio::test]
     #[ignore = "Requires running Redis"]
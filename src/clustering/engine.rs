@@ -8,12 +8,14 @@ use chrono::Utc;
 use std::collections::HashMap;
 use std::time::Instant;
 
-use crate::config::OpenSearchConfig;
+use crate::config::Config;
 use crate::embedding::{cosine_similarity, VectorStore};
 
 use super::models::{
-    ClusterArticle, ClusterConfig, ClusterMetadata, ClusterOutput, EventCluster,
+    ClusterArticle, ClusterConfig, ClusterMetadata, ClusterOutput, EventCluster, TopicConfig,
+    TopicMetadata, TopicOutput,
 };
+use super::topics::{discover_weekly_topics, TopicDocument};
 
 /// Internal representation of an article with its embedding
 #[derive(Debug, Clone)]
@@ -36,14 +38,7 @@ pub struct ClusterEngine {
 impl ClusterEngine {
     /// Create a new cluster engine with the given configuration
     pub fn new(config: ClusterConfig) -> Result<Self> {
-        let opensearch_config = OpenSearchConfig {
-            url: std::env::var("OPENSEARCH_URL")
-                .unwrap_or_else(|_| "http://localhost:9200".to_string()),
-            index_name: std::env::var("OPENSEARCH_INDEX")
-                .unwrap_or_else(|_| "baram-articles".to_string()),
-            username: std::env::var("OPENSEARCH_USER").ok(),
-            password: std::env::var("OPENSEARCH_PASSWORD").ok(),
-        };
+        let opensearch_config = Config::default().opensearch.with_env_overrides();
 
         let store =
             VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
@@ -148,6 +143,59 @@ impl ClusterEngine {
         })
     }
 
+    /// Discover weekly topics over the articles matching the clustering filters
+    pub async fn discover_topics(&self, topic_config: &TopicConfig) -> Result<TopicOutput> {
+        let start = Instant::now();
+
+        tracing::info!("Loading articles with embeddings from OpenSearch...");
+        let articles = self.load_articles().await?;
+        let total_articles = articles.len();
+        tracing::info!(count = total_articles, "Loaded articles");
+
+        let documents = articles
+            .into_iter()
+            .map(|article| TopicDocument {
+                article: ClusterArticle {
+                    id: article.id,
+                    title: article.title,
+                    category: article.category,
+                    publisher: article.publisher,
+                    published_at: article.published_at,
+                    url: article.url,
+                    similarity_to_centroid: 0.0,
+                },
+                embedding: article.embedding,
+            })
+            .collect();
+
+        let (weeks, undated_articles) = discover_weekly_topics(documents, topic_config);
+        let total_topics = weeks.iter().map(|w| w.topics.len()).sum();
+
+        let duration = start.elapsed().as_secs_f64();
+        tracing::info!(
+            total_articles = total_articles,
+            weeks = weeks.len(),
+            topics = total_topics,
+            duration_secs = format!("{duration:.1}"),
+            "Topic discovery complete"
+        );
+
+        Ok(TopicOutput {
+            metadata: TopicMetadata {
+                total_articles,
+                undated_articles,
+                total_topics,
+                num_topics: topic_config.num_topics,
+                min_topic_size: topic_config.min_topic_size,
+                category_filter: self.config.category.clone(),
+                since_filter: self.config.since.clone(),
+                created_at: Utc::now(),
+                duration_secs: duration,
+            },
+            weeks,
+        })
+    }
+
     /// Load articles with embeddings from OpenSearch using scroll API
     async fn load_articles(&self) -> Result<Vec<ArticleWithEmbedding>> {
         let mut articles = Vec::new();
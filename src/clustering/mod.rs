@@ -10,11 +10,23 @@
 //! 3. Incremental clustering with threshold-based merging
 //! 4. (Optional) Generate event summaries via vLLM
 //! 5. Output clusters as JSON files
+//!
+//! Topic discovery (`topics`) groups each week's articles into broader topics
+//! with spherical k-means over the same embeddings, labeled by distinctive
+//! keywords and optionally named and summarized via vLLM.
+//!
+//! Story clustering (`stories`) runs incrementally while articles are indexed,
+//! grouping coverage of the same event across publishers under a story ID.
 
 pub mod engine;
 pub mod models;
+pub mod stories;
 pub mod summary;
+pub mod topics;
 
 pub use engine::ClusterEngine;
 pub use models::{ClusterConfig, ClusterMetadata, EventCluster, ClusterArticle, ClusterOutput};
+pub use models::{StoryConfig, Topic, TopicConfig, TopicMetadata, TopicOutput, WeeklyTopics};
+pub use stories::StoryTracker;
 pub use summary::ClusterSummarizer;
+pub use topics::{discover_weekly_topics, TopicDocument};
@@ -157,6 +157,148 @@ pub struct ClusterMetadata {
     pub duration_secs: f64,
 }
 
+/// Configuration for incremental story clustering
+#[derive(Debug, Clone)]
+pub struct StoryConfig {
+    /// Minimum time-decayed cosine similarity to join an existing story (0.0-1.0)
+    pub similarity_threshold: f64,
+
+    /// Hours after which a story's similarity weight halves
+    pub half_life_hours: f64,
+
+    /// Stories not updated for this many hours are closed
+    pub max_age_hours: i64,
+}
+
+impl Default for StoryConfig {
+    fn default() -> Self {
+        Self {
+            similarity_threshold: 0.8,
+            half_life_hours: 24.0,
+            max_age_hours: 72,
+        }
+    }
+}
+
+/// Configuration for weekly topic discovery
+#[derive(Debug, Clone)]
+pub struct TopicConfig {
+    /// Maximum number of topics per week (k in k-means)
+    pub num_topics: usize,
+
+    /// Minimum number of articles to form a topic
+    pub min_topic_size: usize,
+
+    /// Maximum k-means iterations
+    pub max_iterations: usize,
+
+    /// Number of keywords kept per topic
+    pub top_keywords: usize,
+
+    /// Seed for k-means++ initialization (same seed, same topics)
+    pub seed: u64,
+}
+
+impl Default for TopicConfig {
+    fn default() -> Self {
+        Self {
+            num_topics: 8,
+            min_topic_size: 3,
+            max_iterations: 50,
+            top_keywords: 5,
+            seed: 42,
+        }
+    }
+}
+
+/// A topic discovered within one week of articles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Topic {
+    /// Unique topic ID (e.g., "topic_2026W07_01")
+    pub topic_id: String,
+
+    /// Topic label (generated by LLM or from the top keywords)
+    pub label: String,
+
+    /// Topic summary (generated by LLM, empty if not generated)
+    #[serde(default)]
+    pub summary: String,
+
+    /// Most distinctive title keywords, best first
+    pub keywords: Vec<String>,
+
+    /// Number of articles
+    pub article_count: usize,
+
+    /// Primary category (most frequent among articles)
+    pub category: String,
+
+    /// Average cosine similarity to the topic centroid
+    pub cohesion: f64,
+
+    /// Articles in this topic, most central first
+    pub articles: Vec<ClusterArticle>,
+}
+
+/// Topics discovered for one ISO week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyTopics {
+    /// ISO week (e.g., "2026-W07")
+    pub week: String,
+
+    /// Monday of the week (YYYY-MM-DD)
+    pub week_start: String,
+
+    /// Articles published in the week
+    pub article_count: usize,
+
+    /// Topics, largest first
+    pub topics: Vec<Topic>,
+}
+
+/// Full output of a topic discovery run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicOutput {
+    /// Run metadata
+    pub metadata: TopicMetadata,
+
+    /// Topics per week, oldest first
+    pub weeks: Vec<WeeklyTopics>,
+}
+
+/// Metadata about a topic discovery run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicMetadata {
+    /// Total articles processed
+    pub total_articles: usize,
+
+    /// Articles skipped because they have no publication date
+    pub undated_articles: usize,
+
+    /// Total topics discovered across all weeks
+    pub total_topics: usize,
+
+    /// Maximum topics per week used
+    pub num_topics: usize,
+
+    /// Minimum topic size used
+    pub min_topic_size: usize,
+
+    /// Category filter applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_filter: Option<String>,
+
+    /// Date filter applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_filter: Option<String>,
+
+    /// When topic discovery was run
+    pub created_at: DateTime<Utc>,
+
+    /// Processing duration in seconds
+    pub duration_secs: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +382,14 @@ mod tests {
         assert_eq!(deserialized.metadata.total_clusters, 5);
     }
 
+    #[test]
+    fn test_topic_config_defaults() {
+        let config = TopicConfig::default();
+        assert_eq!(config.num_topics, 8);
+        assert_eq!(config.min_topic_size, 3);
+        assert_eq!(config.top_keywords, 5);
+    }
+
     #[test]
     fn test_optional_fields_skipped_in_serialization() {
         let article = ClusterArticle {
@@ -0,0 +1,308 @@
+//! Incremental story clustering
+//!
+//! Assigns each incoming article to a "story" — the same event covered by
+//! many outlets — as it is indexed. An article joins the open story whose
+//! centroid is most similar once that similarity, decayed by the time since
+//! the story was last updated, clears the threshold; otherwise it opens a new
+//! story. Story IDs are stored on the indexed documents so search results can
+//! be collapsed by story.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use crate::embedding::{cosine_similarity, VectorStore};
+
+use super::models::StoryConfig;
+
+/// Documents fetched per page when loading open stories from the index
+const LOAD_BATCH_SIZE: usize = 500;
+
+/// An open story tracked in memory
+#[derive(Debug, Clone)]
+struct Story {
+    id: String,
+    centroid: Vec<f32>,
+    size: usize,
+    last_seen: DateTime<Utc>,
+}
+
+impl Story {
+    /// Fold an article into the running-mean centroid
+    fn add(&mut self, embedding: &[f32], published_at: DateTime<Utc>) {
+        let n = self.size as f32;
+        for (c, v) in self.centroid.iter_mut().zip(embedding) {
+            *c = (*c * n + v) / (n + 1.0);
+        }
+        self.size += 1;
+        self.last_seen = self.last_seen.max(published_at);
+    }
+}
+
+/// Incremental story assignment over a stream of article embeddings
+#[derive(Debug, Clone)]
+pub struct StoryTracker {
+    config: StoryConfig,
+    stories: Vec<Story>,
+}
+
+impl StoryTracker {
+    /// Create a tracker with no open stories
+    pub fn new(config: StoryConfig) -> Self {
+        Self {
+            config,
+            stories: Vec::new(),
+        }
+    }
+
+    /// Number of open stories
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stories.len()
+    }
+
+    /// Whether no story is open
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stories.is_empty()
+    }
+
+    /// Add an article whose story is already known (e.g. loaded from the index)
+    pub fn seed(&mut self, story_id: &str, embedding: &[f32], published_at: DateTime<Utc>) {
+        if is_placeholder(embedding) {
+            return;
+        }
+        match self.stories.iter_mut().find(|s| s.id == story_id) {
+            Some(story) => story.add(embedding, published_at),
+            None => self.stories.push(Story {
+                id: story_id.to_string(),
+                centroid: embedding.to_vec(),
+                size: 1,
+                last_seen: published_at,
+            }),
+        }
+    }
+
+    /// Assign an article to a story and return the story ID
+    ///
+    /// Returns `None` for empty or all-zero (placeholder) embeddings, which
+    /// carry no similarity signal.
+    pub fn assign(
+        &mut self,
+        article_id: &str,
+        embedding: &[f32],
+        published_at: DateTime<Utc>,
+    ) -> Option<String> {
+        if is_placeholder(embedding) {
+            return None;
+        }
+        self.expire(published_at);
+
+        let best = self
+            .stories
+            .iter()
+            .enumerate()
+            .map(|(idx, story)| (idx, self.score(story, embedding, published_at)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        if let Some((idx, score)) = best {
+            if score >= self.config.similarity_threshold {
+                let story = &mut self.stories[idx];
+                story.add(embedding, published_at);
+                return Some(story.id.clone());
+            }
+        }
+
+        let id = format!("story_{}_{article_id}", published_at.format("%Y%m%d"));
+        self.stories.push(Story {
+            id: id.clone(),
+            centroid: embedding.to_vec(),
+            size: 1,
+            last_seen: published_at,
+        });
+        Some(id)
+    }
+
+    /// Load the open stories from documents already in the index
+    ///
+    /// Reads documents with a story ID published within `max_age_hours` of
+    /// `now`, so an indexing run continues the stories of earlier runs.
+    /// Returns the number of documents loaded.
+    pub async fn load_from_store(
+        &mut self,
+        store: &VectorStore,
+        now: DateTime<Utc>,
+    ) -> Result<usize> {
+        let since = now - Duration::hours(self.config.max_age_hours);
+        let mut search_after: Option<serde_json::Value> = None;
+        let mut loaded = 0usize;
+
+        loop {
+            let mut query = serde_json::json!({
+                "size": LOAD_BATCH_SIZE,
+                "sort": [{"_id": "asc"}],
+                "_source": ["story_id", "published_at", "embedding"],
+                "query": {
+                    "bool": {
+                        "must": [
+                            {"exists": {"field": "story_id"}},
+                            {"exists": {"field": "embedding"}},
+                            {"range": {"published_at": {"gte": since.to_rfc3339()}}}
+                        ]
+                    }
+                }
+            });
+            if let Some(after) = search_after.take() {
+                query["search_after"] = after;
+            }
+
+            let response = store
+                .raw_search(&query)
+                .await
+                .context("Failed to load open stories from OpenSearch")?;
+            let hits = match response["hits"]["hits"].as_array() {
+                Some(hits) if !hits.is_empty() => hits,
+                _ => break,
+            };
+
+            for hit in hits {
+                let source = &hit["_source"];
+                let Some(story_id) = source["story_id"].as_str() else {
+                    continue;
+                };
+                let Some(published_at) = source["published_at"].as_str().and_then(parse_timestamp)
+                else {
+                    continue;
+                };
+                let embedding: Vec<f32> = source["embedding"]
+                    .as_array()
+                    .map(|arr| {
+                        arr.iter()
+                            .filter_map(|v| v.as_f64().map(|f| f as f32))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                self.seed(story_id, &embedding, published_at);
+                loaded += 1;
+            }
+
+            if hits.len() < LOAD_BATCH_SIZE {
+                break;
+            }
+            search_after = hits.last().map(|hit| hit["sort"].clone());
+        }
+
+        tracing::debug!(
+            documents = loaded,
+            stories = self.len(),
+            "Loaded open stories"
+        );
+        Ok(loaded)
+    }
+
+    /// Cosine similarity to the story centroid, decayed by the time gap
+    fn score(&self, story: &Story, embedding: &[f32], published_at: DateTime<Utc>) -> f64 {
+        let hours = (published_at - story.last_seen).num_minutes().abs() as f64 / 60.0;
+        let decay = 0.5f64.powf(hours / self.config.half_life_hours.max(f64::EPSILON));
+        cosine_similarity(embedding, &story.centroid) as f64 * decay
+    }
+
+    /// Close stories idle for longer than `max_age_hours` before `now`
+    fn expire(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - Duration::hours(self.config.max_age_hours);
+        self.stories.retain(|story| story.last_seen >= cutoff);
+    }
+}
+
+/// Parse an indexed `published_at` value (RFC 3339, or naive UTC)
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })
+}
+
+/// Whether an embedding is missing or the all-zero placeholder
+fn is_placeholder(embedding: &[f32]) -> bool {
+    embedding.iter().all(|v| *v == 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 15, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_assign_groups_similar_articles() {
+        let mut tracker = StoryTracker::new(StoryConfig::default());
+
+        let first = tracker.assign("001_1", &[1.0, 0.0, 0.0], at(9)).unwrap();
+        let second = tracker.assign("002_1", &[0.98, 0.1, 0.0], at(10)).unwrap();
+        let other = tracker.assign("003_1", &[0.0, 0.0, 1.0], at(10)).unwrap();
+
+        assert_eq!(first, "story_20260215_001_1");
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(tracker.len(), 2);
+    }
+
+    #[test]
+    fn test_assign_time_decay_splits_stories() {
+        let mut tracker = StoryTracker::new(StoryConfig::default());
+
+        let first = tracker.assign("001_1", &[1.0, 0.0], at(0)).unwrap();
+        // Identical content a day later only scores 0.5 after decay
+        let later = tracker
+            .assign("001_2", &[1.0, 0.0], at(0) + Duration::hours(24))
+            .unwrap();
+
+        assert_ne!(first, later);
+    }
+
+    #[test]
+    fn test_assign_expires_old_stories() {
+        let mut tracker = StoryTracker::new(StoryConfig::default());
+
+        tracker.assign("001_1", &[1.0, 0.0], at(0));
+        tracker.assign("001_2", &[0.0, 1.0], at(0) + Duration::hours(100));
+
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn test_assign_skips_placeholder_embeddings() {
+        let mut tracker = StoryTracker::new(StoryConfig::default());
+
+        assert!(tracker.assign("001_1", &[0.0; 4], at(0)).is_none());
+        assert!(tracker.assign("001_2", &[], at(0)).is_none());
+        assert!(tracker.is_empty());
+    }
+
+    #[test]
+    fn test_seed_continues_story() {
+        let mut tracker = StoryTracker::new(StoryConfig::default());
+        tracker.seed("story_20260214_001_9", &[1.0, 0.0], at(8));
+        tracker.seed("story_20260214_001_9", &[0.9, 0.1], at(9));
+
+        let id = tracker.assign("002_1", &[1.0, 0.05], at(10)).unwrap();
+
+        assert_eq!(id, "story_20260214_001_9");
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("2026-02-15T09:00:00Z"), Some(at(9)));
+        assert_eq!(parse_timestamp("2026-02-15T18:00:00+09:00"), Some(at(9)));
+        assert_eq!(parse_timestamp("2026-02-15T09:00:00"), Some(at(9)));
+        assert!(parse_timestamp("yesterday").is_none());
+    }
+}
@@ -1,12 +1,13 @@
 //! Cluster summarization using vLLM
 //!
-//! Generates event titles and summaries for each cluster using the vLLM API.
+//! Generates event titles and summaries for each cluster, and labels and
+//! summaries for weekly topics, using the vLLM API.
 
 use anyhow::{Context, Result};
 
 use crate::llm::{LlmClient, LlmConfig};
 
-use super::models::EventCluster;
+use super::models::{EventCluster, Topic, WeeklyTopics};
 
 /// Generates summaries for event clusters using vLLM
 pub struct ClusterSummarizer {
@@ -91,6 +92,101 @@ impl ClusterSummarizer {
         Ok(success_count)
     }
 
+    /// Generate a label and summary for a single topic
+    pub async fn summarize_topic(&self, topic: &mut Topic) -> Result<()> {
+        let prompt = self.build_topic_prompt(topic);
+        let response = self.generate(&prompt).await?;
+        let (label, summary) = self.parse_summary_response(&response);
+
+        if !label.is_empty() {
+            topic.label = label;
+        }
+        topic.summary = summary;
+
+        Ok(())
+    }
+
+    /// Generate labels and summaries for every topic of every week
+    pub async fn summarize_topics(&self, weeks: &mut [WeeklyTopics]) -> Result<usize> {
+        let total: usize = weeks.iter().map(|w| w.topics.len()).sum();
+        let mut success_count = 0usize;
+
+        for topic in weeks.iter_mut().flat_map(|w| w.topics.iter_mut()) {
+            match self.summarize_topic(topic).await {
+                Ok(()) => {
+                    success_count += 1;
+                    tracing::debug!(
+                        topic_id = %topic.topic_id,
+                        label = %topic.label,
+                        "Topic summary generated"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        topic_id = %topic.topic_id,
+                        error = %e,
+                        "Failed to generate topic summary, keeping keyword label"
+                    );
+                }
+            }
+        }
+
+        tracing::info!(
+            total = total,
+            success = success_count,
+            failed = total - success_count,
+            "Topic summarization complete"
+        );
+
+        Ok(success_count)
+    }
+
+    /// Build the prompt for topic labeling
+    fn build_topic_prompt(&self, topic: &Topic) -> String {
+        let mut articles_text = String::new();
+        for (i, article) in topic.articles.iter().take(15).enumerate() {
+            articles_text.push_str(&format!(
+                "{}. [{}] {}\n",
+                i + 1,
+                article.category,
+                article.title
+            ));
+        }
+
+        if topic.articles.len() > 15 {
+            articles_text.push_str(&format!(
+                "... 외 {}개 기사\n",
+                topic.articles.len() - 15
+            ));
+        }
+
+        let keywords = topic.keywords.join(", ");
+
+        format!(
+            r#"당신은 한국어 뉴스 주제 분석 전문가입니다.
+
+다음 뉴스 기사들은 한 주 동안 같은 주제로 묶였습니다.
+이 주제를 대표하는 짧은 이름과 요약을 생성하세요.
+
+## 핵심 키워드:
+{keywords}
+
+## 기사 목록:
+{articles_text}
+## 규칙:
+1. 이름은 15자 이내로 주제를 나타내세요 (개별 사건이 아닌 넓은 주제)
+2. 요약은 2-3문장으로 이번 주 이 주제의 주요 흐름을 정리하세요
+3. 한국어로 작성하세요
+
+## 출력 형식 (JSON):
+```json
+{{"title": "주제 이름", "summary": "주제 요약"}}
+```
+
+## 결과 (JSON):"#
+        )
+    }
+
     /// Build the prompt for cluster summarization
     fn build_summary_prompt(&self, cluster: &EventCluster) -> String {
         let mut articles_text = String::new();
@@ -135,6 +231,18 @@ impl ClusterSummarizer {
 
     /// Generate text using the LLM client
     async fn generate(&self, prompt: &str) -> Result<String> {
+        let started = std::time::Instant::now();
+        let result = self.request_completion(prompt).await;
+        crate::metrics::record_llm_latency(
+            crate::llm::LlmBackend::Vllm.as_str(),
+            started.elapsed().as_secs_f64(),
+            result.is_ok(),
+        );
+        result
+    }
+
+    /// Send a chat completion request to vLLM
+    async fn request_completion(&self, prompt: &str) -> Result<String> {
         // Use the vLLM generate_vllm via the public API
         // LlmClient::extract_said_relations uses generate internally,
         // but we need raw generation, so we call it via a workaround:
@@ -0,0 +1,459 @@
+//! Weekly topic discovery over article embeddings
+//!
+//! Groups each ISO week's articles into topics with spherical k-means (cosine
+//! similarity, k-means++ seeding) and labels every topic with its most
+//! distinctive title keywords (class-based TF-IDF). Topics can additionally be
+//! named and summarized by the LLM (see [`ClusterSummarizer::summarize_topics`]).
+//!
+//! [`ClusterSummarizer::summarize_topics`]: super::ClusterSummarizer::summarize_topics
+
+use chrono::{DateTime, Datelike, NaiveDate};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::analytics::TextTokenizer;
+use crate::embedding::cosine_similarity;
+
+use super::models::{ClusterArticle, Topic, TopicConfig, WeeklyTopics};
+
+/// An article and its embedding, input to topic discovery
+#[derive(Debug, Clone)]
+pub struct TopicDocument {
+    /// Article metadata (`similarity_to_centroid` is filled in per topic)
+    pub article: ClusterArticle,
+
+    /// Article embedding
+    pub embedding: Vec<f32>,
+}
+
+/// Discover topics for each week of documents
+///
+/// Returns the weeks in chronological order and the number of documents
+/// skipped because their publication date is missing or unparseable.
+pub fn discover_weekly_topics(
+    documents: Vec<TopicDocument>,
+    config: &TopicConfig,
+) -> (Vec<WeeklyTopics>, usize) {
+    let mut weeks: BTreeMap<NaiveDate, Vec<TopicDocument>> = BTreeMap::new();
+    let mut undated = 0usize;
+
+    for document in documents {
+        match document
+            .article
+            .published_at
+            .as_deref()
+            .and_then(parse_date)
+        {
+            Some(date) => {
+                let monday =
+                    date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64);
+                weeks.entry(monday).or_default().push(document);
+            }
+            None => undated += 1,
+        }
+    }
+
+    let weekly = weeks
+        .into_iter()
+        .map(|(monday, documents)| {
+            let iso = monday.iso_week();
+            let week = format!("{}-W{:02}", iso.year(), iso.week());
+            let topics = discover_topics(&documents, config, &week);
+            WeeklyTopics {
+                week_start: monday.format("%Y-%m-%d").to_string(),
+                article_count: documents.len(),
+                week,
+                topics,
+            }
+        })
+        .collect();
+
+    (weekly, undated)
+}
+
+/// Discover topics within one set of documents
+///
+/// k is capped so every topic can reach `min_topic_size`; clusters smaller
+/// than that are dropped as noise.
+pub fn discover_topics(
+    documents: &[TopicDocument],
+    config: &TopicConfig,
+    week: &str,
+) -> Vec<Topic> {
+    let min_size = config.min_topic_size.max(1);
+    if documents.len() < min_size {
+        return Vec::new();
+    }
+
+    let k = config.num_topics.min(documents.len() / min_size).max(1);
+    let embeddings: Vec<&[f32]> = documents.iter().map(|d| d.embedding.as_slice()).collect();
+    let assignments = spherical_kmeans(&embeddings, k, config.max_iterations, config.seed);
+
+    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); k];
+    for (idx, cluster) in assignments.into_iter().enumerate() {
+        groups[cluster].push(idx);
+    }
+    groups.retain(|group| group.len() >= min_size);
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+    let keywords = distinctive_keywords(documents, &groups, config.top_keywords);
+    let week_id = week.replace('-', "");
+
+    groups
+        .iter()
+        .zip(keywords)
+        .enumerate()
+        .map(|(i, (group, keywords))| {
+            build_topic(
+                documents,
+                group,
+                keywords,
+                format!("topic_{week_id}_{:02}", i + 1),
+            )
+        })
+        .collect()
+}
+
+/// Spherical k-means: cluster unit-normalized vectors by cosine similarity
+///
+/// Centroids are seeded with k-means++ using a deterministic RNG, so the same
+/// input and seed always give the same assignment.
+pub fn spherical_kmeans(
+    vectors: &[&[f32]],
+    k: usize,
+    max_iterations: usize,
+    seed: u64,
+) -> Vec<usize> {
+    if vectors.is_empty() || k == 0 {
+        return Vec::new();
+    }
+    let k = k.min(vectors.len());
+    let points: Vec<Vec<f32>> = vectors.iter().map(|v| normalize(v)).collect();
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    // k-means++ seeding with cosine distance
+    let mut centroids = vec![points[rng.gen_range(0..points.len())].clone()];
+    while centroids.len() < k {
+        let distances: Vec<f32> = points
+            .iter()
+            .map(|p| {
+                centroids
+                    .iter()
+                    .map(|c| (1.0 - cosine_similarity(p, c)).max(0.0))
+                    .fold(f32::INFINITY, f32::min)
+            })
+            .collect();
+        let total: f32 = distances.iter().map(|d| d * d).sum();
+        if total <= f32::EPSILON {
+            // All remaining points coincide with a centroid
+            break;
+        }
+
+        let mut target = rng.gen_range(0.0..total);
+        let mut chosen = points.len() - 1;
+        for (idx, d) in distances.iter().enumerate() {
+            target -= d * d;
+            if target <= 0.0 {
+                chosen = idx;
+                break;
+            }
+        }
+        centroids.push(points[chosen].clone());
+    }
+
+    let mut assignments = vec![usize::MAX; points.len()];
+    for _ in 0..max_iterations.max(1) {
+        let mut changed = false;
+        for (idx, point) in points.iter().enumerate() {
+            let best = centroids
+                .iter()
+                .enumerate()
+                .map(|(c, centroid)| (c, cosine_similarity(point, centroid)))
+                .fold((0, f32::NEG_INFINITY), |best, cur| {
+                    if cur.1 > best.1 {
+                        cur
+                    } else {
+                        best
+                    }
+                })
+                .0;
+            if assignments[idx] != best {
+                assignments[idx] = best;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        for (c, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<&[f32]> = points
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, a)| **a == c)
+                .map(|(p, _)| p.as_slice())
+                .collect();
+            // Empty clusters keep their previous centroid
+            if let Some(mean) = mean_vector(&members) {
+                *centroid = normalize(&mean);
+            }
+        }
+    }
+
+    assignments
+}
+
+/// Rank each group's title keywords by class-based TF-IDF
+///
+/// A keyword scores high for a topic when many of its articles use it and few
+/// articles in other topics do: `tf(t, c) * ln(1 + A / f(t))`, where `A` is the
+/// average topic size and `f(t)` the number of articles using `t` overall.
+fn distinctive_keywords(
+    documents: &[TopicDocument],
+    groups: &[Vec<usize>],
+    limit: usize,
+) -> Vec<Vec<String>> {
+    let tokenizer = TextTokenizer::new();
+    let titles: Vec<HashSet<String>> = documents
+        .iter()
+        .map(|d| tokenizer.tokenize(&d.article.title).into_iter().collect())
+        .collect();
+
+    let group_counts: Vec<HashMap<&str, usize>> = groups
+        .iter()
+        .map(|group| {
+            let mut counts = HashMap::new();
+            for &idx in group {
+                for token in &titles[idx] {
+                    *counts.entry(token.as_str()).or_insert(0) += 1;
+                }
+            }
+            counts
+        })
+        .collect();
+
+    let mut overall: HashMap<&str, usize> = HashMap::new();
+    for counts in &group_counts {
+        for (token, count) in counts {
+            *overall.entry(token).or_insert(0) += count;
+        }
+    }
+
+    let average_size =
+        groups.iter().map(Vec::len).sum::<usize>() as f64 / groups.len().max(1) as f64;
+
+    group_counts
+        .iter()
+        .map(|counts| {
+            let mut scored: Vec<(&str, f64)> = counts
+                .iter()
+                .map(|(token, tf)| {
+                    let idf = (1.0 + average_size / overall[token] as f64).ln();
+                    (*token, *tf as f64 * idf)
+                })
+                .collect();
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+            scored
+                .into_iter()
+                .take(limit)
+                .map(|(token, _)| token.to_string())
+                .collect()
+        })
+        .collect()
+}
+
+fn build_topic(
+    documents: &[TopicDocument],
+    group: &[usize],
+    keywords: Vec<String>,
+    topic_id: String,
+) -> Topic {
+    let embeddings: Vec<&[f32]> = group
+        .iter()
+        .map(|&idx| documents[idx].embedding.as_slice())
+        .collect();
+    let centroid = mean_vector(&embeddings).unwrap_or_default();
+
+    let mut articles: Vec<ClusterArticle> = group
+        .iter()
+        .map(|&idx| ClusterArticle {
+            similarity_to_centroid: cosine_similarity(&documents[idx].embedding, &centroid) as f64,
+            ..documents[idx].article.clone()
+        })
+        .collect();
+    articles.sort_by(|a, b| {
+        b.similarity_to_centroid
+            .total_cmp(&a.similarity_to_centroid)
+    });
+
+    let mut category_counts: HashMap<&str, usize> = HashMap::new();
+    for article in &articles {
+        *category_counts.entry(&article.category).or_insert(0) += 1;
+    }
+    let category = category_counts
+        .into_iter()
+        .max_by_key(|&(category, count)| (count, std::cmp::Reverse(category)))
+        .map(|(category, _)| category.to_string())
+        .unwrap_or_default();
+
+    let cohesion = articles
+        .iter()
+        .map(|a| a.similarity_to_centroid)
+        .sum::<f64>()
+        / articles.len() as f64;
+    let label = if keywords.is_empty() {
+        articles
+            .first()
+            .map(|a| a.title.clone())
+            .unwrap_or_default()
+    } else {
+        keywords
+            .iter()
+            .take(3)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" · ")
+    };
+
+    Topic {
+        topic_id,
+        label,
+        summary: String::new(),
+        keywords,
+        article_count: articles.len(),
+        category,
+        cohesion,
+        articles,
+    }
+}
+
+fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm <= f32::EPSILON {
+        return vector.to_vec();
+    }
+    vector.iter().map(|x| x / norm).collect()
+}
+
+fn mean_vector(vectors: &[&[f32]]) -> Option<Vec<f32>> {
+    let first = vectors.first()?;
+    let mut mean = vec![0.0f32; first.len()];
+    for vector in vectors {
+        for (m, v) in mean.iter_mut().zip(vector.iter()) {
+            *m += v;
+        }
+    }
+    let n = vectors.len() as f32;
+    mean.iter_mut().for_each(|m| *m /= n);
+    Some(mean)
+}
+
+/// Parse an RFC 3339 timestamp or a leading `YYYY-MM-DD` date
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.date_naive());
+    }
+    value
+        .get(..10)
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(id: &str, title: &str, published_at: &str, embedding: Vec<f32>) -> TopicDocument {
+        TopicDocument {
+            article: ClusterArticle {
+                id: id.to_string(),
+                title: title.to_string(),
+                category: "economy".to_string(),
+                publisher: None,
+                published_at: Some(published_at.to_string()),
+                url: format!("https://example.com/{id}"),
+                similarity_to_centroid: 0.0,
+            },
+            embedding,
+        }
+    }
+
+    #[test]
+    fn test_spherical_kmeans_separates_directions() {
+        let vectors: Vec<Vec<f32>> = vec![
+            vec![1.0, 0.1, 0.0],
+            vec![0.9, 0.0, 0.1],
+            vec![2.0, 0.2, 0.1],
+            vec![0.0, 1.0, 0.1],
+            vec![0.1, 0.8, 0.0],
+            vec![0.0, 3.0, 0.2],
+        ];
+        let refs: Vec<&[f32]> = vectors.iter().map(Vec::as_slice).collect();
+
+        let assignments = spherical_kmeans(&refs, 2, 20, 7);
+        assert_eq!(assignments[0], assignments[1]);
+        assert_eq!(assignments[0], assignments[2]);
+        assert_eq!(assignments[3], assignments[4]);
+        assert_eq!(assignments[3], assignments[5]);
+        assert_ne!(assignments[0], assignments[3]);
+
+        // Deterministic for the same seed
+        assert_eq!(assignments, spherical_kmeans(&refs, 2, 20, 7));
+    }
+
+    #[test]
+    fn test_discover_weekly_topics() {
+        let mut documents = Vec::new();
+        for i in 0..4 {
+            documents.push(document(
+                &format!("chip{i}"),
+                "반도체 수출 호조",
+                "2026-02-10T09:00:00+09:00",
+                vec![1.0, 0.05 * i as f32, 0.0],
+            ));
+            documents.push(document(
+                &format!("house{i}"),
+                "부동산 대출 규제",
+                "2026-02-11",
+                vec![0.0, 1.0, 0.05 * i as f32],
+            ));
+        }
+        documents.push(document(
+            "next",
+            "다음 주 기사",
+            "2026-02-17",
+            vec![1.0, 0.0, 0.0],
+        ));
+        let mut undated = document("undated", "날짜 없음", "", vec![1.0, 0.0, 0.0]);
+        undated.article.published_at = None;
+        documents.push(undated);
+
+        let (weeks, skipped) = discover_weekly_topics(documents, &TopicConfig::default());
+        assert_eq!(skipped, 1);
+        assert_eq!(weeks.len(), 2);
+
+        let first = &weeks[0];
+        assert_eq!(first.week, "2026-W07");
+        assert_eq!(first.week_start, "2026-02-09");
+        assert_eq!(first.article_count, 8);
+        assert_eq!(first.topics.len(), 2);
+        assert_eq!(first.topics[0].article_count, 4);
+        assert_eq!(first.topics[0].topic_id, "topic_2026W07_01");
+
+        let labels: Vec<&str> = first
+            .topics
+            .iter()
+            .map(|t| t.keywords[0].as_str())
+            .collect();
+        assert!(labels
+            .iter()
+            .any(|k| ["반도체", "수출", "호조"].contains(k)));
+        assert!(labels
+            .iter()
+            .any(|k| ["부동산", "대출", "규제"].contains(k)));
+        assert!(first.topics.iter().all(|t| t.cohesion > 0.9));
+
+        // A single article cannot form a topic
+        assert!(weeks[1].topics.is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+//! Cache command implementation: inspect and manage the Redis cache
+
+use anyhow::{bail, Context, Result};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use baram::cache::{Cache, CacheConfig, CacheKind, OptionalCache};
+use baram::config::Config;
+use baram::embedding::ChunkConfig;
+use baram::utils::retry::RetryConfig;
+
+use super::index::{
+    embedding_source, parse_markdown_to_document, BatchEmbedder, Chunker, DocumentBatch,
+    EmbedderKind, EmbeddingSource,
+};
+use super::output::say;
+use super::progress::Progress;
+use super::{print_cache_stats, print_title};
+
+/// Parameters for `baram cache warm`
+pub struct CacheWarmParams {
+    /// Markdown file or directory whose articles are embedded
+    pub input: PathBuf,
+    /// Articles per embedding request
+    pub batch_size: usize,
+    /// Embedding source: `local` or `server`
+    pub embedder: String,
+    /// HuggingFace model loaded by the `local` embedder
+    pub embedding_model: String,
+    /// Texts per forward pass of the `local` embedder
+    pub embed_batch_size: usize,
+    /// Chunk size of the `baram index` run to warm up for; 0 for whole
+    /// articles
+    pub chunk_tokens: usize,
+    /// Tokens each chunk repeats from the end of the previous one
+    pub chunk_overlap: usize,
+}
+
+/// Connect to the Redis at `REDIS_URL`, failing if it is unreachable
+async fn connect() -> Result<Cache> {
+    let config = CacheConfig::from_env()?;
+    Cache::new(&config)
+        .await
+        .with_context(|| t!("cli.cache.unreachable").to_string())
+}
+
+/// Print the hit rate and memory use of Redis and the entries of each kind
+pub async fn cache_stats(format: &str) -> Result<()> {
+    if !matches!(format, "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let cache = connect().await?;
+    let info = cache.get_stats().await?;
+    let mut entries = Vec::with_capacity(CacheKind::ALL.len());
+    for kind in CacheKind::ALL {
+        entries.push((kind, cache.count(kind).await?));
+    }
+
+    if format == "json" {
+        let entries: serde_json::Map<String, serde_json::Value> = entries
+            .iter()
+            .map(|(kind, count)| (kind.as_str().to_string(), (*count).into()))
+            .collect();
+        let report = serde_json::json!({
+            "key_prefix": cache.config().key_prefix,
+            "stats": info,
+            "entries": entries,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    print_title(&t!("cli.cache.stats_title"), '=');
+    println!(
+        "{}",
+        t!("cli.cache.key_prefix", prefix = cache.config().key_prefix)
+    );
+    println!(
+        "{}",
+        t!(
+            "cli.cache.hit_rate",
+            rate = format!("{:.1}", info.hit_rate * 100.0),
+            hits = info.hits,
+            misses = info.misses
+        )
+    );
+    println!(
+        "{}",
+        t!(
+            "cli.cache.memory",
+            size = format!("{:.1}", info.memory_bytes as f64 / (1024.0 * 1024.0))
+        )
+    );
+    println!("{}", t!("cli.cache.entries"));
+    for (kind, count) in entries {
+        println!("  {:<10} {count}", kind.as_str());
+    }
+    Ok(())
+}
+
+/// Delete every cached entry of `kind`
+pub async fn cache_invalidate(kind: &str) -> Result<()> {
+    let kind = CacheKind::parse(kind)?;
+    let cache = connect().await?;
+    let count = cache.invalidate(kind).await?;
+    say!(
+        "{}",
+        t!("cli.cache.invalidated", count = count, kind = kind.as_str())
+    );
+    Ok(())
+}
+
+/// Embed the articles in `input` into the cache, so that a following
+/// `baram index` with the same embedder finds their vectors there
+///
+/// Articles are embedded exactly like `baram index` does, chunks included,
+/// so the cache keys match. Vectors already cached are not computed again.
+pub async fn cache_warm(
+    config: &Config,
+    params: CacheWarmParams,
+    progress: &Progress,
+) -> Result<()> {
+    let CacheWarmParams {
+        input,
+        batch_size,
+        embedder,
+        embedding_model,
+        embed_batch_size,
+        chunk_tokens,
+        chunk_overlap,
+    } = params;
+    let embedder_kind = EmbedderKind::parse(&embedder)?;
+    if embedder_kind == EmbedderKind::None {
+        bail!("{}", t!("cli.cache.warm_needs_embedder"));
+    }
+
+    print_title(&t!("cli.cache.warm_starting", input = input.display()), '=');
+    let cache = OptionalCache::new(Some(connect().await?));
+
+    let client = reqwest::Client::new();
+    let embedding_server_url = std::env::var("EMBEDDING_SERVER_URL")
+        .unwrap_or_else(|_| "http://localhost:8090".to_string());
+    let (source, _) = embedding_source(
+        embedder_kind,
+        &embedding_model,
+        embed_batch_size,
+        &client,
+        &embedding_server_url,
+    )
+    .await?;
+    if matches!(source, EmbeddingSource::None) {
+        bail!("{}", t!("cli.cache.warm_needs_embedder"));
+    }
+
+    let chunker = if chunk_tokens > 0 {
+        let config = ChunkConfig {
+            max_tokens: chunk_tokens,
+            overlap_tokens: chunk_overlap.min(chunk_tokens / 2),
+            ..Default::default()
+        };
+        Some(Chunker::load(&embedding_model, config).await?)
+    } else {
+        None
+    };
+
+    let mut files: Vec<PathBuf> = if input.is_dir() {
+        std::fs::read_dir(&input)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+            .collect()
+    } else if input.is_file() {
+        vec![input.clone()]
+    } else {
+        bail!(
+            "{}",
+            t!("cli.common.input_not_found", path = input.display())
+        );
+    };
+    files.sort();
+
+    let imported = HashMap::new();
+    let retry_config = RetryConfig::with_delays(2, 1000, 5000);
+    let embedder = BatchEmbedder {
+        client: &client,
+        source,
+        model: &embedding_model,
+        chunker: chunker.as_ref(),
+        cache: &cache,
+        config,
+        imported: &imported,
+        retry_config: &retry_config,
+    };
+
+    let batch_size = batch_size.max(1);
+    let task = progress.task("cache_warm", "documents", files.len() as u64);
+    let (mut warmed, mut failed) = (0, 0);
+    for (i, paths) in files.chunks(batch_size).enumerate() {
+        let mut batch = DocumentBatch::new(i + 1, paths.len());
+        for path in paths {
+            match parse_markdown_to_document(path) {
+                Ok(doc) => batch.docs.push(doc),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to parse markdown");
+                    failed += 1;
+                }
+            }
+        }
+        let (_, vectors, _) = embedder.embed(batch).await;
+        let embedded = vectors.iter().flatten().count();
+        warmed += embedded;
+        failed += vectors.len() - embedded;
+        task.inc(paths.len() as u64);
+    }
+    task.finish();
+
+    say!(
+        "{}",
+        t!("cli.cache.warmed", count = warmed, failed = failed)
+    );
+    print_cache_stats(&cache);
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
+use rust_i18n::t;
 use std::path::Path;
 
-use baram::clustering::{ClusterConfig, ClusterEngine, ClusterSummarizer};
+use baram::clustering::{ClusterConfig, ClusterEngine, ClusterSummarizer, TopicConfig};
+
+use super::output::say;
+use super::print_title;
 
 /// Run the event clustering pipeline
 pub async fn cluster(
@@ -13,22 +17,21 @@ pub async fn cluster(
     output: String,
     summarize: bool,
 ) -> Result<()> {
-    println!("Event Clustering");
-    println!("================");
-    println!("  Similarity threshold: {threshold}");
-    println!("  Min cluster size: {min_size}");
+    print_title(&t!("cli.cluster.title"), '=');
+    say!("  {}", t!("cli.cluster.threshold", threshold = threshold));
+    say!("  {}", t!("cli.cluster.min_size", size = min_size));
     if let Some(ref cat) = category {
-        println!("  Category filter: {cat}");
+        say!("  {}", t!("cli.cluster.category", category = cat));
     }
     if let Some(ref since_date) = since {
-        println!("  Since: {since_date}");
+        say!("  {}", t!("cli.cluster.since", date = since_date));
     }
     if max_articles > 0 {
-        println!("  Max articles: {max_articles}");
+        say!("  {}", t!("cli.cluster.max_articles", max = max_articles));
     }
-    println!("  Output: {output}");
-    println!("  Summarize (vLLM): {summarize}");
-    println!();
+    say!("  {}", t!("cli.cluster.output", path = output));
+    say!("  {}", t!("cli.cluster.summarize", enabled = summarize));
+    say!();
 
     // Build clustering config
     let config = ClusterConfig {
@@ -50,7 +53,7 @@ pub async fn cluster(
 
     // Generate summaries if requested
     if summarize && !result.events.is_empty() {
-        println!("Generating event summaries with vLLM...");
+        say!("{}", t!("cli.cluster.generating_summaries"));
         match ClusterSummarizer::new() {
             Ok(summarizer) => {
                 if summarizer.is_available().await {
@@ -58,18 +61,25 @@ pub async fn cluster(
                         .summarize_all(&mut result.events)
                         .await
                         .context("Summary generation failed")?;
-                    println!("  Generated {success}/{} summaries", result.events.len());
+                    say!(
+                        "  {}",
+                        t!(
+                            "cli.cluster.generated",
+                            success = success,
+                            total = result.events.len()
+                        )
+                    );
                 } else {
                     tracing::warn!("vLLM service not available, skipping summaries");
-                    println!("  Warning: vLLM service not available, skipping summaries");
+                    say!("  {}", t!("cli.cluster.vllm_unavailable"));
                 }
             }
             Err(e) => {
                 tracing::warn!(error = %e, "Failed to create summarizer");
-                println!("  Warning: Failed to create summarizer: {e}");
+                say!("  {}", t!("cli.cluster.summarizer_failed", error = e));
             }
         }
-        println!();
+        say!();
     }
 
     // Save results
@@ -90,57 +100,77 @@ pub async fn cluster(
         ))?;
 
     // Print summary
-    println!("Results");
-    println!("=======");
-    println!(
-        "  Total articles: {}",
-        result.metadata.total_articles
+    print_title(&t!("cli.cluster.results"), '=');
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.total_articles",
+            count = result.metadata.total_articles
+        )
     );
-    println!(
-        "  Clusters formed: {}",
-        result.metadata.total_clusters
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.clusters_formed",
+            count = result.metadata.total_clusters
+        )
     );
-    println!(
-        "  Clustered articles: {}",
-        result.metadata.clustered_articles
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.clustered",
+            count = result.metadata.clustered_articles
+        )
     );
-    println!(
-        "  Unclustered: {}",
-        result.metadata.unclustered_articles
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.unclustered",
+            count = result.metadata.unclustered_articles
+        )
     );
-    println!(
-        "  Processing time: {:.1}s",
-        result.metadata.duration_secs
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.processing_time",
+            secs = format!("{:.1}", result.metadata.duration_secs)
+        )
     );
-    println!();
+    say!();
 
     // Print top clusters
     let top_n = 10.min(result.events.len());
     if top_n > 0 {
-        println!("Top {top_n} Events:");
-        println!("{}", "-".repeat(80));
+        say!("{}", t!("cli.cluster.top_events", count = top_n));
+        say!("{}", "-".repeat(80));
         for (i, event) in result.events.iter().take(top_n).enumerate() {
-            println!(
-                "{}. [{}] {} ({} articles, sim: {:.2})",
+            say!(
+                "{}. [{}] {} ({})",
                 i + 1,
                 event.category,
                 event.title,
-                event.article_count,
-                event.avg_similarity
+                t!(
+                    "cli.cluster.event_stats",
+                    count = event.article_count,
+                    similarity = format!("{:.2}", event.avg_similarity)
+                )
             );
             if !event.summary.is_empty() {
-                println!("   {}", event.summary);
+                say!("   {}", event.summary);
             }
             if let (Some(first), Some(last)) = (&event.first_seen, &event.last_updated) {
-                println!("   Period: {first} ~ {last}");
+                say!(
+                    "   {}",
+                    t!("cli.cluster.period", first = first, last = last)
+                );
             }
-            println!();
+            say!();
         }
     }
 
-    println!(
-        "Output saved to: {}",
-        output_file.display()
+    say!(
+        "{}",
+        t!("cli.common.output_saved", path = output_file.display())
     );
     tracing::info!(
         clusters = result.metadata.total_clusters,
@@ -152,9 +182,196 @@ pub async fn cluster(
     Ok(())
 }
 
+/// Parameters for `baram topics`
+pub struct TopicsParams {
+    /// Filter by category
+    pub category: Option<String>,
+    /// Only use articles published after this date (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Maximum number of topics per week
+    pub topics: usize,
+    /// Minimum number of articles per topic
+    pub min_size: usize,
+    /// Maximum number of articles to process (0 = unlimited)
+    pub max_articles: usize,
+    /// Output directory for the topics JSON file
+    pub output: String,
+    /// Generate topic labels and summaries using vLLM
+    pub summarize: bool,
+}
+
+/// Discover emergent topics per week from stored article embeddings
+pub async fn topics(params: TopicsParams) -> Result<()> {
+    if params.topics == 0 {
+        anyhow::bail!("{}", t!("cli.topics.min_topics"));
+    }
+
+    print_title(&t!("cli.topics.title"), '=');
+    say!("  {}", t!("cli.topics.per_week", count = params.topics));
+    say!("  {}", t!("cli.topics.min_size", size = params.min_size));
+    if let Some(ref cat) = params.category {
+        say!("  {}", t!("cli.cluster.category", category = cat));
+    }
+    if let Some(ref since_date) = params.since {
+        say!("  {}", t!("cli.cluster.since", date = since_date));
+    }
+    say!("  {}", t!("cli.cluster.output", path = params.output));
+    say!(
+        "  {}",
+        t!("cli.cluster.summarize", enabled = params.summarize)
+    );
+    say!();
+
+    let config = ClusterConfig {
+        category: params.category,
+        since: params.since,
+        max_articles: params.max_articles,
+        output_dir: params.output.clone(),
+        generate_summaries: params.summarize,
+        ..Default::default()
+    };
+    let topic_config = TopicConfig {
+        num_topics: params.topics,
+        min_topic_size: params.min_size,
+        ..Default::default()
+    };
+
+    let engine = ClusterEngine::new(config).context("Failed to create clustering engine")?;
+    let mut result = engine
+        .discover_topics(&topic_config)
+        .await
+        .context("Topic discovery failed")?;
+
+    if params.summarize && result.metadata.total_topics > 0 {
+        say!("{}", t!("cli.topics.generating_labels"));
+        match ClusterSummarizer::new() {
+            Ok(summarizer) => {
+                if summarizer.is_available().await {
+                    let success = summarizer
+                        .summarize_topics(&mut result.weeks)
+                        .await
+                        .context("Topic summary generation failed")?;
+                    say!(
+                        "  {}",
+                        t!(
+                            "cli.cluster.generated",
+                            success = success,
+                            total = result.metadata.total_topics
+                        )
+                    );
+                } else {
+                    tracing::warn!("vLLM service not available, keeping keyword labels");
+                    say!("  {}", t!("cli.topics.vllm_unavailable"));
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to create summarizer");
+                say!("  {}", t!("cli.cluster.summarizer_failed", error = e));
+            }
+        }
+        say!();
+    }
+
+    let output_path = Path::new(&params.output);
+    tokio::fs::create_dir_all(output_path)
+        .await
+        .context(format!("Failed to create output directory: {}", params.output))?;
+
+    let output_file = output_path.join("topics.json");
+    let json =
+        serde_json::to_string_pretty(&result).context("Failed to serialize topic output")?;
+    tokio::fs::write(&output_file, &json)
+        .await
+        .context(format!(
+            "Failed to write output file: {}",
+            output_file.display()
+        ))?;
+
+    print_title(&t!("cli.cluster.results"), '=');
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.total_articles",
+            count = result.metadata.total_articles
+        )
+    );
+    if result.metadata.undated_articles > 0 {
+        say!(
+            "  {}",
+            t!(
+                "cli.topics.undated",
+                count = result.metadata.undated_articles
+            )
+        );
+    }
+    say!("  {}", t!("cli.topics.weeks", count = result.weeks.len()));
+    say!(
+        "  {}",
+        t!("cli.topics.topics", count = result.metadata.total_topics)
+    );
+    say!(
+        "  {}",
+        t!(
+            "cli.cluster.processing_time",
+            secs = format!("{:.1}", result.metadata.duration_secs)
+        )
+    );
+
+    for week in &result.weeks {
+        say!();
+        say!(
+            "{} ({})",
+            week.week,
+            t!(
+                "cli.topics.week_stats",
+                start = week.week_start,
+                count = week.article_count
+            )
+        );
+        say!("{}", "-".repeat(80));
+        if week.topics.is_empty() {
+            say!("  {}", t!("cli.topics.no_topics"));
+        }
+        for (i, topic) in week.topics.iter().enumerate() {
+            say!(
+                "{}. [{}] {} ({})",
+                i + 1,
+                topic.category,
+                topic.label,
+                t!(
+                    "cli.topics.topic_stats",
+                    count = topic.article_count,
+                    cohesion = format!("{:.2}", topic.cohesion)
+                )
+            );
+            say!(
+                "   {}",
+                t!("cli.topics.keywords", keywords = topic.keywords.join(", "))
+            );
+            if !topic.summary.is_empty() {
+                say!("   {}", topic.summary);
+            }
+        }
+    }
+
+    say!();
+    say!(
+        "{}",
+        t!("cli.common.output_saved", path = output_file.display())
+    );
+    tracing::info!(
+        topics = result.metadata.total_topics,
+        articles = result.metadata.total_articles,
+        file = %output_file.display(),
+        "Topic results saved"
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use baram::clustering::{ClusterConfig, ClusterOutput, ClusterMetadata};
+    use baram::clustering::{ClusterConfig, ClusterOutput};
 
     #[test]
     fn test_cluster_output_deserialization() {
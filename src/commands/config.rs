@@ -0,0 +1,61 @@
+//! Config command implementation
+
+use anyhow::{Context, Result};
+use rust_i18n::t;
+use std::path::Path;
+use unicode_width::UnicodeWidthStr;
+
+use baram::config::ResolvedConfig;
+
+use super::output::say;
+
+/// Print the effective config, with secrets masked
+///
+/// With `sources`, every value is printed on its own line as a dotted key
+/// followed by the layer it came from (default, file, profile, environment
+/// variable or command-line flag).
+pub fn config_show(resolved: &ResolvedConfig, sources: bool) -> Result<()> {
+    if !sources {
+        print!("{}", resolved.to_toml()?);
+        return Ok(());
+    }
+
+    let lines: Vec<(String, String)> = resolved
+        .values()
+        .into_iter()
+        .map(|(key, value, source)| (format!("{key} = {value}"), source.to_string()))
+        .collect();
+    let width = lines
+        .iter()
+        .map(|(line, _)| line.width())
+        .max()
+        .unwrap_or(0);
+    for (line, source) in lines {
+        let pad = width - line.width();
+        println!("{line}{:pad$}  # {source}", "");
+    }
+    Ok(())
+}
+
+/// Write a commented sample config to `path`, or print it with `stdout`
+///
+/// Every key is listed with its default value; an existing file is only
+/// replaced with `force`.
+pub fn config_init(path: &Path, force: bool, stdout: bool) -> Result<()> {
+    let sample = baram::config::sample();
+    if stdout {
+        print!("{sample}");
+        return Ok(());
+    }
+    if path.exists() && !force {
+        anyhow::bail!("{}", t!("cli.config.exists", path = path.display()));
+    }
+
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(path, sample).with_context(|| format!("Failed to write {}", path.display()))?;
+    say!("{}", t!("cli.config.written", path = path.display()));
+    Ok(())
+}
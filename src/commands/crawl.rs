@@ -1,44 +1,233 @@
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use rust_i18n::t;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
 
-use baram::config::{Config, DatabaseConfig};
+use baram::config::{CategoryConfig, Config, DatabaseConfig};
+use baram::crawler::assets::AssetDownloader;
+use baram::crawler::comment::CommentClient;
+use baram::crawler::feed::Discovery;
 use baram::crawler::fetcher::NaverFetcher;
 use baram::crawler::list::NewsListCrawler;
+#[cfg(feature = "mock")]
+use baram::crawler::mock::{MockNaver, MockNaverConfig};
+use baram::crawler::sites::{site_adapter, NaverSite, SiteAdapter};
+use baram::crawler::url::{validators, UrlExtractor};
 use baram::crawler::Crawler;
-use baram::models::{CrawlState, NewsCategory};
-use baram::parser::ArticleParser;
-use baram::storage::{ArticleStorage, CrawlStatus, Database};
+use baram::error::ErrorClass;
+use baram::metrics;
+use baram::metrics::push::JobMetrics;
+use baram::models::{ArticleSource, CrawlState, NewsCategory, ParsedArticle};
+use baram::notifications::digest::spawn_digest_task;
+use baram::notifications::{KeywordAlerts, NotificationsConfig};
+use baram::storage::{
+    article_samples, ArticleStorage, CategoryRunStats, CrawlStatus, Database, HttpCache, RunInfo,
+    RunStatus, StorageFormat, TimeSeriesStore,
+};
+
+use super::inline_index::{spill_path, InlineIndexer};
+use super::output::say;
+use super::print_title;
+use super::progress::Progress;
+use super::runs::crawl_db_path;
+use super::shutdown::Shutdown;
+
+/// Instance label for metrics recorded by `baram crawl`
+const METRICS_INSTANCE: &str = "standalone";
+
+/// URLs read from a URL file before they are deduplicated against the database
+const URL_BATCH_SIZE: usize = 100;
+
+/// Run stats category for `--url-file`
+const URL_FILE_CATEGORY: &str = "url-file";
+
+/// Run stats category for `--url`
+const URL_CATEGORY: &str = "url";
+
+/// Run stats category for `--query`
+const SEARCH_CATEGORY: &str = "search";
+
+/// Naver News search results per page
+const SEARCH_PAGE_SIZE: usize = 10;
+
+/// Articles per category without `--max-articles` or a `[category]` block
+const DEFAULT_MAX_ARTICLES: usize = 100;
+
+/// Parameters for `baram crawl`
+pub struct CrawlParams {
+    /// News site to crawl (default: naver)
+    pub site: Option<String>,
+    /// News category to crawl (default: politics)
+    pub category: Option<String>,
+    /// Maximum number of articles to crawl per category; `None` uses the
+    /// category's `max_articles` block, then [`DEFAULT_MAX_ARTICLES`]
+    pub max_articles: Option<usize>,
+    /// Crawl a single article URL instead of a category
+    pub url: Option<String>,
+    /// Crawl the article URLs listed in this file, one per line (`-` for stdin)
+    pub url_file: Option<PathBuf>,
+    /// Crawl the Naver News search results for this keyword
+    pub query: Option<String>,
+    /// How category article URLs are discovered
+    pub discovery: Discovery,
+    /// First day of the search, YYYY-MM-DD (default: today)
+    pub from: Option<String>,
+    /// Last day of the search, YYYY-MM-DD (default: today)
+    pub to: Option<String>,
+    /// Fetch the reader comments of crawled articles (Naver only)
+    pub with_comments: bool,
+    /// Save article images under `assets/` next to the output directory
+    pub download_images: bool,
+    /// Output directory for markdown files
+    pub output: PathBuf,
+    /// Files each article is saved as
+    pub format: StorageFormat,
+    /// Skip already crawled articles
+    pub skip_existing: bool,
+    /// Serve Prometheus metrics on this port while crawling
+    pub metrics_port: Option<u16>,
+    /// Crawl a local [`MockNaver`] with this config instead of Naver News
+    #[cfg(feature = "mock")]
+    pub mock: Option<MockNaverConfig>,
+    /// Embed and index articles into OpenSearch as they are crawled
+    pub index: bool,
+    /// Articles waiting to be indexed before more are spilled to disk
+    pub index_queue: usize,
+    /// Stops the crawl before the next article once requested
+    pub shutdown: Shutdown,
+}
 
 pub async fn crawl(
     config: Config,
-    category: Option<String>,
-    max_articles: usize,
-    url: Option<String>,
-    _with_comments: bool,
-    output: PathBuf,
-    skip_existing: bool,
+    params: CrawlParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
 ) -> Result<()> {
-    println!("Starting Naver News Crawl");
-    println!("========================");
+    let CrawlParams {
+        site,
+        category,
+        max_articles,
+        url,
+        url_file,
+        query,
+        discovery,
+        from,
+        to,
+        with_comments,
+        download_images,
+        output,
+        format,
+        skip_existing,
+        metrics_port,
+        #[cfg(feature = "mock")]
+        mock,
+        index,
+        index_queue,
+        shutdown,
+    } = params;
+
+    let mut site = parse_site(site.as_deref())?;
+    if query.is_some() && site.source() != ArticleSource::Naver {
+        anyhow::bail!(
+            "{}",
+            t!("cli.crawl.search_naver_only", site = site.source().as_str())
+        );
+    }
+
+    print_title(&t!("cli.crawl.starting"), '=');
+
+    // Kept until the crawl ends, which stops the server
+    #[cfg(feature = "mock")]
+    let mock_server = match mock {
+        Some(mock) => {
+            let server = MockNaver::start(mock).await;
+            say!("{}", t!("cli.crawl.mock", uri = server.uri()));
+            Some(server)
+        }
+        None => None,
+    };
+    #[cfg(feature = "mock")]
+    let mocked = mock_server.is_some();
+    #[cfg(not(feature = "mock"))]
+    let mocked = false;
+
+    #[cfg(feature = "mock")]
+    if let Some(server) = &mock_server {
+        site = Arc::new(server.site());
+        if with_comments {
+            tracing::warn!("The mock server has no comments; --with-comments is ignored");
+        }
+    }
+    let metrics_server = match metrics_port {
+        Some(port) => {
+            let (addr, handle) = start_metrics_server(port).await?;
+            say!("{}", t!("cli.common.metrics_endpoint", addr = addr));
+            Some(handle)
+        }
+        None => None,
+    };
 
     // Initialize database for deduplication
-    let db_path = output.parent().unwrap_or(&output).join("crawl.db");
+    let db_path = crawl_db_path(&output);
     let db_config = DatabaseConfig {
         sqlite_path: db_path.clone(),
         postgres_url: String::new(),
         pool_size: 5,
+        store_bodies: config.database.store_bodies,
     };
     let mut db = Database::new(&db_config)?;
     db.init_sqlite(&db_path)?;
 
+    // Hourly article/keyword aggregates for trend queries
+    let mut series = TimeSeriesStore::open(&db_path)?;
+
     // Initialize storage
-    let storage = ArticleStorage::new(&output, skip_existing)?;
+    let storage = ArticleStorage::with_format(&output, skip_existing, format)?;
 
-    // Initialize parser
-    let parser = ArticleParser::new();
+    // Initialize crawler; articles saved before are only fetched if changed
+    let mut crawler = Crawler::new(config.clone())?.with_http_cache(HttpCache::open(&db_path)?);
+    if download_images {
+        let assets = AssetDownloader::for_markdown_dir(&output);
+        say!(
+            "{}",
+            t!("cli.crawl.download_images", path = assets.dir().display())
+        );
+        crawler = crawler.with_assets(assets);
+    }
 
-    // Initialize crawler
-    let crawler = Crawler::new(config.clone())?;
+    if with_comments && !mocked {
+        if site.source() == ArticleSource::Naver {
+            let fetcher = NaverFetcher::with_rate_limit(config.crawler.rate_limit)
+                .context("Failed to create comment client")?
+                .with_policy(crawler.fetch_policy());
+            let client = CommentClient::with_fetcher(fetcher);
+            site = Arc::new(NaverSite::new().with_comments(client));
+        } else {
+            tracing::warn!(
+                site = site.source().as_str(),
+                "Comments are only crawled from Naver; --with-comments is ignored"
+            );
+        }
+    }
+
+    let spill = spill_path(&output);
+    let indexer = if index {
+        let indexer = InlineIndexer::start(&config, spill.clone(), index_queue).await?;
+        say!(
+            "{}",
+            t!(
+                "cli.crawl.inline_index",
+                size = index_queue,
+                path = spill.display()
+            )
+        );
+        Some(indexer)
+    } else {
+        None
+    };
 
     // Track stats
     let mut state = CrawlState::new();
@@ -46,119 +235,768 @@ pub async fn crawl(
     // Get today's date for list crawling
     let today = chrono::Local::now().format("%Y%m%d").to_string();
 
-    if let Some(url) = url {
-        // Single URL crawl
-        println!("Crawling single URL: {url}");
-        crawl_single_url(&crawler, &parser, &storage, &db, &url, &mut state, None).await?;
+    // Recorded in crawl_runs for `baram report crawl`
+    let run_id = db.start_crawl_run(&RunInfo::current("crawl"), &output)?;
+    let mut run_stats: Vec<CategoryRunStats> = Vec::new();
+
+    // Create fetcher for list crawling
+    let fetcher = NaverFetcher::with_rate_limit(config.crawler.rate_limit)
+        .context("Failed to create fetcher")?
+        .with_policy(crawler.fetch_policy());
+    let list_crawler = NewsListCrawler::with_site(fetcher, Arc::clone(&site));
+    let category_crawler = CategoryCrawler {
+        crawler: &crawler,
+        site: site.as_ref(),
+        storage: &storage,
+        db: &db,
+        list_crawler: &list_crawler,
+        url_extractor: UrlExtractor::new(),
+        skip_existing,
+        instance: METRICS_INSTANCE,
+        progress,
+        keyword_alerts: None,
+        indexer: indexer.as_ref(),
+        shutdown: &shutdown,
+    };
+
+    // A Naver article URL is crawled from the mock server at the same path
+    let url = if mocked {
+        url.map(|url| site.normalize_url(&url).unwrap_or(url))
     } else {
-        // Category crawl
-        let categories = if let Some(cat) = category {
-            vec![parse_category(&cat)?]
+        url
+    };
+
+    let result: Result<()> = async {
+        if let Some(url) = url {
+            // Single URL crawl
+            say!("{}", t!("cli.crawl.single_url", url = url));
+            let mut stats = CategoryRunStats::new(URL_CATEGORY);
+            stats.found = 1;
+            stats.new = 1;
+            let crawled = crawl_single_url(
+                &crawler,
+                site.as_ref(),
+                &storage,
+                &db,
+                &url,
+                &mut state,
+                None,
+            )
+            .await;
+            match &crawled {
+                Ok(Some(crawled)) => {
+                    record_timeseries(&mut series, &crawled.article);
+                    if let Some(indexer) = &indexer {
+                        indexer.submit(&crawled.article);
+                    }
+                    stats.saved = 1;
+                    stats.bytes_written = crawled.bytes_written;
+                }
+                Ok(None) => stats.duplicates = 1,
+                Err(e) => stats.record_error(&e.to_string()),
+            }
+            run_stats.push(stats);
+            crawled.map(|_| ())
+        } else if let Some(path) = url_file {
+            let reader = open_url_file(&path).await?;
+            let stats = category_crawler
+                .crawl_url_lines(reader, &mut state, &mut series)
+                .await?;
+            run_stats.push(stats);
+            Ok(())
+        } else if let Some(query) = query {
+            let from = search_date(from.as_deref(), &today)?;
+            let to = search_date(to.as_deref(), &today)?;
+            let stats = category_crawler
+                .search(
+                    &query,
+                    &from,
+                    &to,
+                    max_articles.unwrap_or(DEFAULT_MAX_ARTICLES),
+                    &mut state,
+                    &mut series,
+                )
+                .await?;
+            run_stats.push(stats);
+            Ok(())
         } else {
-            // Default to politics if no category specified
-            vec![NewsCategory::Politics]
-        };
+            // Category crawl
+            let categories = if let Some(cat) = category {
+                vec![parse_category(&cat)?]
+            } else {
+                // Default to politics if no category specified
+                vec![NewsCategory::Politics]
+            };
 
-        // Create fetcher for list crawling
-        let fetcher = NaverFetcher::new(config.crawler.rate_limit as u32)
-            .context("Failed to create fetcher")?;
-        let list_crawler = NewsListCrawler::new(fetcher);
+            for cat in categories {
+                if shutdown.is_requested() {
+                    break;
+                }
+                let overrides = config.category(cat);
+                let max_articles = max_articles
+                    .or(overrides.max_articles)
+                    .unwrap_or(DEFAULT_MAX_ARTICLES);
+                if overrides.rate_limit.is_some() {
+                    category_crawler.set_rate_limit(config.category_rate_limit(cat))?;
+                }
+                let stats = match discovery {
+                    Discovery::List => {
+                        category_crawler
+                            .crawl(cat, &today, max_articles, &mut state, &mut series)
+                            .await?
+                    }
+                    _ => {
+                        category_crawler
+                            .discover(
+                                cat,
+                                discovery,
+                                &overrides,
+                                max_articles,
+                                &mut state,
+                                &mut series,
+                            )
+                            .await?
+                    }
+                };
+                run_stats.push(stats);
+                db.update_crawl_run(run_id, RunStatus::Running, &run_stats)?;
+                db.save_checkpoint("last_category", cat.as_str())?;
+            }
+            Ok(())
+        }
+    }
+    .await;
+
+    // Waits for the queued articles, even if the crawl failed
+    let index_stats = match indexer {
+        Some(indexer) => Some(indexer.finish().await),
+        None => None,
+    };
+
+    let status = shutdown.run_status(result.is_ok());
+    db.update_crawl_run(run_id, status, &run_stats)?;
+
+    let mut totals = CategoryRunStats::new("total");
+    for stats in &run_stats {
+        totals.merge(stats);
+    }
+    job.set("run_id", run_id as u64);
+    job.set("found", totals.found as u64);
+    job.set("skipped", totals.skipped as u64);
+    job.set("saved", totals.saved as u64);
+    job.set("duplicates", totals.duplicates as u64);
+    job.set("failed", totals.failed as u64);
+    if let Some(stats) = &index_stats {
+        job.set("indexed", stats.indexed as u64);
+        job.set("index_failed", stats.failed as u64);
+        job.set("index_pending", stats.pending as u64);
+    }
+    if status == RunStatus::Interrupted {
+        job.set("interrupted", 1);
+    }
+    db.set_crawl_run_counts(run_id, job.counts())?;
+    result?;
 
-        for cat in categories {
-            println!(
-                "\nCrawling category: {} ({})",
-                cat.korean_name(),
-                cat.as_str()
+    // Print summary
+    let crawl_stats = state.stats();
+    say!();
+    print_title(&t!("cli.crawl.summary"), '=');
+    say!(
+        "{}",
+        t!(
+            "cli.common.total_processed",
+            count = crawl_stats.total_crawled
+        )
+    );
+    say!(
+        "{}",
+        t!(
+            "cli.common.successful",
+            count = crawl_stats.total_crawled - crawl_stats.total_errors
+        )
+    );
+    say!(
+        "{}",
+        t!("cli.common.failed", count = crawl_stats.total_errors)
+    );
+    say!(
+        "{}",
+        t!("cli.common.output_directory", path = output.display())
+    );
+    say!("{}", t!("cli.common.database", path = db_path.display()));
+    say!("{}", t!("cli.crawl.run", id = run_id));
+    if let Some(stats) = &index_stats {
+        say!(
+            "{}",
+            t!(
+                "cli.crawl.inline_index_summary",
+                indexed = stats.indexed,
+                failed = stats.failed,
+                spilled = stats.spilled
+            )
+        );
+        if stats.pending > 0 {
+            say!(
+                "{}",
+                t!(
+                    "cli.crawl.inline_index_pending",
+                    count = stats.pending,
+                    path = spill.display()
+                )
             );
+        }
+    }
+
+    // Show database stats
+    let db_stats = db.get_stats()?;
+    say!();
+    print_title(&t!("cli.crawl.db_stats"), '-');
+    say!("{}", t!("cli.common.total_records", count = db_stats.total));
+    say!("{}", t!("cli.common.success", count = db_stats.success));
+    say!("{}", t!("cli.common.failed", count = db_stats.failed));
+    say!(
+        "{}",
+        t!(
+            "cli.common.success_rate",
+            rate = format!("{:.1}", db_stats.success_rate() * 100.0)
+        )
+    );
+
+    if let Some(handle) = metrics_server {
+        handle.abort();
+    }
 
-            // Calculate max pages needed (roughly 20 articles per page)
-            let max_pages = max_articles.div_ceil(20) as u32;
+    if status == RunStatus::Interrupted {
+        say!();
+        say!("{}", t!("cli.crawl.interrupted", path = db_path.display()));
+    }
 
-            // Get list of article URLs
-            let urls = list_crawler
-                .collect_urls(cat, &today, max_pages)
-                .await
-                .map_err(|e| anyhow::anyhow!("Failed to collect URLs: {e}"))?;
+    Ok(())
+}
 
-            println!("Found {} article URLs", urls.len());
+/// Crawls the article list of one category, shared by `crawl` and `watch`,
+/// or a stream of article URLs
+pub(crate) struct CategoryCrawler<'a> {
+    pub crawler: &'a Crawler,
+    pub site: &'a dyn SiteAdapter,
+    pub storage: &'a ArticleStorage<'a>,
+    pub db: &'a Database,
+    pub list_crawler: &'a NewsListCrawler,
+    pub url_extractor: UrlExtractor,
+    pub skip_existing: bool,
+    /// Instance label for metrics
+    pub instance: &'a str,
+    pub progress: &'a Progress,
+    /// Counts saved articles for keyword alerts
+    pub keyword_alerts: Option<&'a KeywordAlerts>,
+    /// Indexes saved articles while crawling
+    pub indexer: Option<&'a InlineIndexer>,
+    /// Stops the crawl before the next article once requested
+    pub shutdown: &'a Shutdown,
+}
 
-            // Filter out already crawled URLs
-            let uncrawled_urls = if skip_existing {
-                db.filter_uncrawled(&urls)?
-            } else {
-                urls.clone()
-            };
+impl CategoryCrawler<'_> {
+    /// Crawl at `rate_limit` requests per second from now on
+    pub fn set_rate_limit(&self, rate_limit: f64) -> Result<()> {
+        self.crawler.set_rate_limit(rate_limit)?;
+        self.list_crawler
+            .fetcher()
+            .set_requests_per_second(rate_limit);
+        Ok(())
+    }
+
+    /// Crawl up to `max_articles` new articles listed for `cat` on `date`
+    /// (`YYYYMMDD`) and return what happened
+    pub async fn crawl(
+        &self,
+        cat: NewsCategory,
+        date: &str,
+        max_articles: usize,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+    ) -> Result<CategoryRunStats> {
+        say!(
+            "\n{}",
+            t!(
+                "cli.crawl.category",
+                name = category_name(cat),
+                code = cat.as_str()
+            )
+        );
+
+        // Calculate max pages needed (roughly 20 articles per page)
+        let max_pages = max_articles.div_ceil(20) as u32;
+
+        // Get list of article URLs
+        let urls = self
+            .list_crawler
+            .collect_urls(cat, date, max_pages)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", t!("cli.crawl.collect_failed", error = e)))?;
 
-            println!(
-                "New articles to crawl: {} (skipped: {})",
-                uncrawled_urls.len(),
-                urls.len() - uncrawled_urls.len()
+        self.crawl_listed(cat.as_str(), Some(cat), &urls, max_articles, state, series)
+            .await
+    }
+
+    /// Crawl up to `max_articles` new articles of `cat` listed by its
+    /// configured feeds or sitemaps, newest first, and return what happened
+    pub async fn discover(
+        &self,
+        cat: NewsCategory,
+        discovery: Discovery,
+        sources: &CategoryConfig,
+        max_articles: usize,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+    ) -> Result<CategoryRunStats> {
+        let (key, urls) = match discovery {
+            Discovery::Sitemap => ("sitemaps", &sources.sitemaps),
+            _ => ("feeds", &sources.feeds),
+        };
+        if urls.is_empty() {
+            anyhow::bail!(
+                "{}",
+                t!("cli.crawl.no_sources", key = key, category = cat.as_str())
             );
+        }
+
+        say!(
+            "\n{}",
+            t!(
+                "cli.crawl.category",
+                name = category_name(cat),
+                code = cat.as_str()
+            )
+        );
+        say!(
+            "{}",
+            t!(
+                "cli.crawl.discovery",
+                count = urls.len(),
+                mode = discovery.as_str()
+            )
+        );
+
+        let discovered = match discovery {
+            Discovery::Sitemap => self.list_crawler.sitemap_urls(urls, None).await,
+            _ => self.list_crawler.feed_urls(urls).await,
+        };
+        let urls = discovered
+            .map_err(|e| anyhow::anyhow!("{}", t!("cli.crawl.collect_failed", error = e)))?;
+
+        self.crawl_listed(cat.as_str(), Some(cat), &urls, max_articles, state, series)
+            .await
+    }
+
+    /// Crawl up to `max_articles` new articles found by a Naver News search
+    /// for `query` from `from` to `to` (`YYYYMMDD`) and return what happened
+    ///
+    /// Up to enough result pages for `max_articles` are read per day, so a
+    /// range of days yields articles from each of them.
+    pub async fn search(
+        &self,
+        query: &str,
+        from: &str,
+        to: &str,
+        max_articles: usize,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+    ) -> Result<CategoryRunStats> {
+        say!(
+            "\n{}",
+            t!("cli.crawl.search", query = query, from = from, to = to)
+        );
+
+        // Any day may fill the budget alone; the days' URLs are interleaved,
+        // so the budget is split across them when they all have enough
+        let max_pages = max_articles.div_ceil(SEARCH_PAGE_SIZE) as u32;
+        let urls = self
+            .list_crawler
+            .search_urls(query, from, to, max_pages)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", t!("cli.crawl.collect_failed", error = e)))?;
+
+        self.crawl_listed(SEARCH_CATEGORY, None, &urls, max_articles, state, series)
+            .await
+    }
+
+    /// Crawl the first `max_articles` of the listed `urls` not crawled yet,
+    /// recording them under the run stats category `label`
+    async fn crawl_listed(
+        &self,
+        label: &str,
+        category: Option<NewsCategory>,
+        urls: &[String],
+        max_articles: usize,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+    ) -> Result<CategoryRunStats> {
+        let instance = self.instance;
+        say!("{}", t!("cli.crawl.found_urls", count = urls.len()));
+
+        // Filter out already crawled URLs
+        let uncrawled_urls = if self.skip_existing {
+            self.db.filter_uncrawled(urls)?
+        } else {
+            urls.to_vec()
+        };
 
-            // Crawl each URL
-            for (i, url) in uncrawled_urls.iter().enumerate().take(max_articles) {
-                print!(
-                    "\r[{}/{}] Crawling: {}...",
-                    i + 1,
-                    uncrawled_urls.len().min(max_articles),
-                    truncate_url(url, 50)
-                );
-                std::io::Write::flush(&mut std::io::stdout())?;
-
-                match crawl_single_url(&crawler, &parser, &storage, &db, url, &mut state, Some(&cat)).await {
-                    Ok(_) => {}
-                    Err(e) => {
-                        tracing::warn!(url = %url, error = %e, "Failed to crawl article");
-                        state.record_error();
-                        db.record_failure(url, &e.to_string())?;
+        say!(
+            "{}",
+            t!(
+                "cli.crawl.new_articles",
+                new_count = uncrawled_urls.len(),
+                skipped_count = urls.len() - uncrawled_urls.len()
+            )
+        );
+        metrics::record_dedup_results(
+            instance,
+            uncrawled_urls.len(),
+            urls.len() - uncrawled_urls.len(),
+        );
+        metrics::update_crawler_state(instance, true, None);
+        let _timer = metrics::start_crawl_timer(instance, label);
+
+        let mut stats = CategoryRunStats::new(label);
+        stats.found = urls.len();
+        stats.new = uncrawled_urls.len();
+        stats.skipped = urls.len() - uncrawled_urls.len();
+
+        // Crawl each URL
+        let task = self.progress.task(
+            "crawl",
+            label,
+            uncrawled_urls.len().min(max_articles) as u64,
+        );
+        for url in uncrawled_urls.iter().take(max_articles) {
+            if self.shutdown.is_requested() {
+                break;
+            }
+            task.set_message(url.clone());
+
+            self.crawl_url(url, category, state, series, &mut stats)
+                .await?;
+
+            task.inc(1);
+
+            // Small delay between requests
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        task.finish();
+        metrics::update_crawler_state(instance, false, None);
+
+        Ok(stats)
+    }
+
+    /// Crawl the article URLs read from `reader`, one per line, and return
+    /// what happened
+    ///
+    /// Blank lines and lines starting with `#` are ignored. URLs are checked
+    /// against the allowed domains, normalized, and deduplicated against the
+    /// file itself and (with `skip_existing`) the crawl database in batches,
+    /// so arbitrarily long lists are streamed rather than loaded up front.
+    pub async fn crawl_url_lines<R: AsyncBufRead + Unpin>(
+        &self,
+        reader: R,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+    ) -> Result<CategoryRunStats> {
+        say!("\n{}", t!("cli.crawl.url_file"));
+
+        let mut lines = reader.lines();
+        let mut seen = HashSet::new();
+        let mut batch = Vec::with_capacity(URL_BATCH_SIZE);
+        let mut stats = CategoryRunStats::new(URL_FILE_CATEGORY);
+        let mut invalid = 0;
+
+        metrics::update_crawler_state(self.instance, true, None);
+        let task = self.progress.spinner("crawl", "url-file");
+        loop {
+            let line = lines.next_line().await.context("Failed to read URL file")?;
+            let done = line.is_none();
+            if let Some(line) = line {
+                match parse_url_line(&line, self.site) {
+                    None => {}
+                    Some(Err(e)) => {
+                        tracing::warn!(line = %line.trim(), error = %e, "Skipping invalid URL");
+                        invalid += 1;
+                    }
+                    Some(Ok(url)) if !seen.insert(url.clone()) => {
+                        stats.found += 1;
+                        stats.skipped += 1;
                     }
+                    Some(Ok(url)) => {
+                        stats.found += 1;
+                        batch.push(url);
+                    }
+                }
+            }
+            if batch.len() < URL_BATCH_SIZE && !done {
+                continue;
+            }
+
+            let urls = if self.skip_existing {
+                self.db.filter_uncrawled(&batch)?
+            } else {
+                batch.clone()
+            };
+            stats.new += urls.len();
+            stats.skipped += batch.len() - urls.len();
+            metrics::record_dedup_results(self.instance, urls.len(), batch.len() - urls.len());
+            batch.clear();
+
+            for url in &urls {
+                if self.shutdown.is_requested() {
+                    break;
                 }
+                task.set_message(url.clone());
+                self.crawl_url(url, None, state, series, &mut stats).await?;
+                task.inc(1);
 
                 // Small delay between requests
                 tokio::time::sleep(std::time::Duration::from_millis(100)).await;
             }
-            println!(); // New line after progress
+            if done || self.shutdown.is_requested() {
+                break;
+            }
         }
+        task.finish();
+        metrics::update_crawler_state(self.instance, false, None);
+
+        say!(
+            "{}",
+            t!(
+                "cli.crawl.url_file_saved",
+                saved = stats.saved,
+                skipped = stats.skipped,
+                invalid = invalid
+            )
+        );
+        Ok(stats)
     }
 
-    // Print summary
-    println!("\nCrawl Summary");
-    println!("=============");
-    println!("Total processed: {}", state.stats().total_crawled);
-    println!(
-        "Successful: {}",
-        state.stats().total_crawled - state.stats().total_errors
-    );
-    println!("Failed: {}", state.stats().total_errors);
-    println!("Output directory: {}", output.display());
-    println!("Database: {}", db_path.display());
+    /// Crawl one article and record the outcome in `stats`
+    ///
+    /// Fetch and parse failures are recorded in the database and metrics
+    /// rather than returned, so one bad article does not stop a run. An
+    /// article still in flight when the shutdown grace period ends is left
+    /// unrecorded for the next run.
+    async fn crawl_url(
+        &self,
+        url: &str,
+        category: Option<NewsCategory>,
+        state: &mut CrawlState,
+        series: &mut TimeSeriesStore,
+        stats: &mut CategoryRunStats,
+    ) -> Result<()> {
+        let instance = self.instance;
+        let label = category.map_or("unknown", |cat| cat.as_str());
+        let crawled = crawl_single_url(
+            self.crawler,
+            self.site,
+            self.storage,
+            self.db,
+            url,
+            state,
+            category.as_ref(),
+        );
+        let Some(crawled) = self.shutdown.with_grace(crawled).await else {
+            tracing::warn!(url = %url, "Shutdown grace period over, leaving the article for the next run");
+            return Ok(());
+        };
+        match crawled {
+            Ok(Some(CrawledArticle {
+                article,
+                bytes_written,
+            })) => {
+                let label = if category.is_none() && !article.category.is_empty() {
+                    article.category.as_str()
+                } else {
+                    label
+                };
+                record_timeseries(series, &article);
+                if let Some(alerts) = self.keyword_alerts {
+                    alerts.observe(&article);
+                }
+                if let Some(indexer) = self.indexer {
+                    indexer.submit(&article);
+                }
+                metrics::record_articles_crawled(instance, label, 1);
+                metrics::record_pipeline_results(instance, label, 1, 0, 0);
+                metrics::record_publisher_success(instance, article.publisher_id());
+                stats.saved += 1;
+                stats.bytes_written += bytes_written;
+                Ok(())
+            }
+            Ok(None) => {
+                metrics::record_pipeline_results(instance, label, 0, 0, 1);
+                stats.duplicates += 1;
+                Ok(())
+            }
+            Err(e) => {
+                tracing::warn!(url = %url, error = %e, "Failed to crawl article");
+                state.record_error();
+                self.db
+                    .record_failure(url, &e.to_string(), ErrorClass::of(&e))?;
+                metrics::record_pipeline_results(instance, label, 0, 1, 0);
+                let oid = self
+                    .url_extractor
+                    .extract_ids(url)
+                    .map(|(oid, _)| oid)
+                    .unwrap_or_default();
+                metrics::record_crawl_error(instance, &oid, ErrorClass::of(&e));
+                stats.record_error(&e.to_string());
+                Ok(())
+            }
+        }
+    }
+}
 
-    // Show database stats
-    let db_stats = db.get_stats()?;
-    println!("\nDatabase Stats");
-    println!("--------------");
-    println!("Total records: {}", db_stats.total);
-    println!("Success: {}", db_stats.success);
-    println!("Failed: {}", db_stats.failed);
-    println!("Success rate: {:.1}%", db_stats.success_rate() * 100.0);
+/// Open a URL file, or stdin for `-`
+async fn open_url_file(path: &Path) -> Result<Box<dyn AsyncBufRead + Unpin>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(BufReader::new(tokio::io::stdin())));
+    }
+    let file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("Failed to open URL file: {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
 
-    Ok(())
+/// Read one line of a URL file
+///
+/// Returns `None` for blank and comment lines. Article URLs of `site` in any
+/// supported format are normalized so they deduplicate against list crawls.
+fn parse_url_line(line: &str, site: &dyn SiteAdapter) -> Option<Result<String, String>> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    if let Err(e) = validators::validate_url(line) {
+        return Some(Err(e));
+    }
+    Some(Ok(site
+        .normalize_url(line)
+        .unwrap_or_else(|| line.to_string())))
+}
+
+/// The adapter for a `--site` name (default: Naver)
+pub(crate) fn parse_site(name: Option<&str>) -> Result<Arc<dyn SiteAdapter>> {
+    let name = name.unwrap_or(ArticleSource::Naver.as_str());
+    ArticleSource::parse(name)
+        .and_then(site_adapter)
+        .map(Arc::from)
+        .ok_or_else(|| anyhow::anyhow!("{}", t!("cli.crawl.unknown_site", site = name)))
+}
+
+/// Adapters of every site, for revisiting URLs crawled from any of them
+pub(crate) fn all_sites() -> Vec<Box<dyn SiteAdapter>> {
+    [ArticleSource::Naver, ArticleSource::Daum]
+        .into_iter()
+        .filter_map(site_adapter)
+        .collect()
+}
+
+/// The adapter in `sites` whose article URLs include `url`, or the first one
+pub(crate) fn site_for_url<'a>(
+    sites: &'a [Box<dyn SiteAdapter>],
+    url: &str,
+) -> &'a dyn SiteAdapter {
+    sites
+        .iter()
+        .find(|site| site.normalize_url(url).is_some())
+        .unwrap_or(&sites[0])
+        .as_ref()
 }
 
-async fn crawl_single_url(
+/// A `--from`/`--to` day (YYYY-MM-DD) as YYYYMMDD, or `today` without one
+fn search_date(date: Option<&str>, today: &str) -> Result<String> {
+    let Some(date) = date else {
+        return Ok(today.to_string());
+    };
+    let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("Invalid search date: {date}. Expected YYYY-MM-DD"))?;
+    Ok(date.format("%Y%m%d").to_string())
+}
+
+/// Initialize metrics and serve them on `port` until the task is aborted
+///
+/// Shared by `crawl` and `distributed`.
+pub(crate) async fn start_metrics_server(
+    port: u16,
+) -> Result<(SocketAddr, tokio::task::JoinHandle<()>)> {
+    if let Err(e) = metrics::init_metrics() {
+        tracing::warn!("Failed to initialize metrics (metrics will be disabled): {e}");
+    }
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    metrics::spawn_metrics_server(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint on {addr}"))
+}
+
+/// Keyword alerts for the `keyword_spike` rules in `[notifications]`
+///
+/// Shared by `watch` and `distributed`. Starts the digest sender when digests
+/// are enabled. Returns `None` if no rule watches a keyword.
+pub(crate) fn start_keyword_alerts(
+    notifications: &NotificationsConfig,
+) -> Result<Option<KeywordAlerts>> {
+    let Some(alerts) = KeywordAlerts::from_config(notifications).map_err(anyhow::Error::msg)?
+    else {
+        return Ok(None);
+    };
+
+    if notifications.digest.enabled {
+        spawn_digest_task(
+            alerts.manager().clone(),
+            notifications.digest.interval.duration(),
+        );
+    }
+    say!(
+        "{}",
+        t!(
+            "cli.crawl.keyword_alerts",
+            keywords = alerts.keywords().join(", ")
+        )
+    );
+    Ok(Some(alerts))
+}
+
+/// An article saved by [`crawl_single_url`]
+pub(crate) struct CrawledArticle {
+    pub article: ParsedArticle,
+    /// Size of the files written (0 when existing files were kept)
+    pub bytes_written: u64,
+}
+
+pub(crate) async fn crawl_single_url(
     crawler: &Crawler,
-    parser: &ArticleParser,
+    site: &dyn SiteAdapter,
     storage: &ArticleStorage<'_>,
     db: &Database,
     url: &str,
     state: &mut CrawlState,
     category: Option<&NewsCategory>,
-) -> Result<()> {
-    // Fetch HTML
-    let html = crawler.fetch_text(url).await?;
+) -> Result<Option<CrawledArticle>> {
+    // Fetch HTML, unless it has not changed since it was last saved and
+    // saved articles are kept; otherwise it is saved again
+    let page = if storage.skip_existing() {
+        crawler.fetch_text_if_modified(url).await?
+    } else {
+        Some(crawler.fetch_page(url).await?)
+    };
+    let Some(page) = page else {
+        tracing::debug!(url = %url, "Skipping unchanged article");
+        state.mark_completed(url);
+        return Ok(None);
+    };
 
     // Parse article
-    let mut article = parser.parse_with_fallback(&html, url)?;
+    let started = std::time::Instant::now();
+    let parsed = site.parse(&page.text, url);
+    metrics::record_parse_duration(started.elapsed().as_secs_f64(), parsed.is_ok());
+    let mut article = parsed?;
 
     // Assign category from crawl context if available and not already set
     if article.category.is_empty() {
@@ -172,23 +1010,83 @@ async fn crawl_single_url(
         if db.is_content_duplicate(hash)? {
             tracing::debug!(url = %url, "Skipping duplicate content");
             db.mark_url_crawled(&article.id(), url, hash, CrawlStatus::Skipped, None)?;
-            return Ok(());
+            crawler.remember_validators(url, &page.validators)?;
+            return Ok(None);
         }
     }
 
-    // Save to markdown
+    // Comments are fetched only for articles that are kept; the article is
+    // saved without them if that fails
+    match site.comments(&article).await {
+        Ok(comments) => article.comments = comments,
+        Err(e) => tracing::warn!(url = %url, error = %e, "Failed to fetch comments"),
+    }
+    let assets = crawler.download_images(&mut article).await;
+    link_saved_images(storage, db, &mut article)?;
+
+    // Save to markdown and/or JSON
+    let mut bytes_written = 0;
     if let Some(path) = storage.save(&article)? {
         tracing::debug!(path = %path.display(), "Saved article");
+        bytes_written = storage
+            .paths(&article)
+            .iter()
+            .map(|path| std::fs::metadata(path).map_or(0, |m| m.len()))
+            .sum();
     }
 
     // Record in database
     db.record_success(&article)?;
+    if !assets.is_empty() {
+        db.record_assets(&assets)?;
+    }
+    crawler.remember_validators(url, &page.validators)?;
     state.mark_completed(url);
 
+    Ok(Some(CrawledArticle {
+        article,
+        bytes_written,
+    }))
+}
+
+/// Link the images of `article` downloaded by an earlier crawl to their
+/// copies in the assets directory beside `storage`, so saving it again
+/// keeps the local links
+pub(crate) fn link_saved_images(
+    storage: &ArticleStorage<'_>,
+    db: &Database,
+    article: &mut ParsedArticle,
+) -> Result<()> {
+    if article
+        .images
+        .iter()
+        .all(|image| image.local_path.is_some())
+    {
+        return Ok(());
+    }
+    let saved = db.get_assets(&article.id())?;
+    AssetDownloader::for_markdown_dir(storage.output_dir()).link_saved(article, &saved);
     Ok(())
 }
 
-fn parse_category(s: &str) -> Result<NewsCategory> {
+/// Add a crawled article to the time series
+///
+/// Failures are logged rather than failing the crawl; the article itself
+/// has already been saved.
+pub(crate) fn record_timeseries(series: &mut TimeSeriesStore, article: &ParsedArticle) {
+    let samples = article_samples(
+        article.published_at.unwrap_or(article.crawled_at),
+        &article.category,
+        Some(article.publisher_id()),
+        &article.title,
+        &article.content,
+    );
+    if let Err(e) = series.record_article(&article.id(), &samples) {
+        tracing::warn!(url = %article.url, error = %e, "Failed to update time series");
+    }
+}
+
+pub(crate) fn parse_category(s: &str) -> Result<NewsCategory> {
     match s.to_lowercase().as_str() {
         "politics" | "정치" => Ok(NewsCategory::Politics),
         "economy" | "경제" => Ok(NewsCategory::Economy),
@@ -197,67 +1095,105 @@ fn parse_category(s: &str) -> Result<NewsCategory> {
         "world" | "세계" => Ok(NewsCategory::World),
         "it" | "과학" | "it/과학" => Ok(NewsCategory::IT),
         _ => anyhow::bail!(
-            "Unknown category: {s}. Valid: politics, economy, society, culture, world, it"
+            "{}. {}",
+            t!("cli.category.unknown", category = s),
+            t!("cli.category.valid_categories")
         ),
     }
 }
 
-fn truncate_url(url: &str, max_len: usize) -> &str {
-    if url.len() <= max_len {
-        url
-    } else {
-        &url[..max_len]
-    }
+/// Display name of a category in the current locale
+pub(crate) fn category_name(cat: NewsCategory) -> String {
+    let key = format!("cli.category.{}", cat.as_str());
+    t!(&key).to_string()
 }
 
 pub async fn resume(
     checkpoint: PathBuf,
     max_articles: Option<usize>,
     output: PathBuf,
+    job: &mut JobMetrics,
+    progress: &Progress,
 ) -> Result<()> {
-    println!("Resuming crawl from checkpoint: {}", checkpoint.display());
+    say!("{}", t!("cli.resume.starting", path = checkpoint.display()));
 
     // Load checkpoint database
     let db_config = DatabaseConfig {
         sqlite_path: checkpoint.clone(),
         postgres_url: String::new(),
         pool_size: 5,
+        store_bodies: false,
     };
     let mut db = Database::new(&db_config)?;
     db.init_sqlite(&checkpoint)?;
 
     // Get stats
     let stats = db.get_stats()?;
-    println!("\nCheckpoint Stats");
-    println!("----------------");
-    println!("Total: {}", stats.total);
-    println!("Success: {}", stats.success);
-    println!("Failed: {}", stats.failed);
+    say!();
+    print_title(&t!("cli.resume.checkpoint_stats"), '-');
+    say!("{}", t!("cli.resume.total", count = stats.total));
+    say!("{}", t!("cli.common.success", count = stats.success));
+    say!("{}", t!("cli.common.failed", count = stats.failed));
 
     // Load last checkpoint state
     if let Some(last_category) = db.load_checkpoint("last_category")? {
-        println!("Last category: {last_category}");
+        say!(
+            "{}",
+            t!("cli.resume.last_category", category = last_category)
+        );
     }
     if let Some(last_page) = db.load_checkpoint("last_page")? {
-        println!("Last page: {last_page}");
+        say!("{}", t!("cli.resume.last_page", page = last_page));
     }
 
     // Load config and continue crawling
     let config = Config::default();
-    let max = max_articles.unwrap_or(100);
+    let max = max_articles.unwrap_or(DEFAULT_MAX_ARTICLES);
 
-    println!("\nContinuing crawl with max {max} articles...");
-    println!("Output directory: {}", output.display());
+    say!("\n{}", t!("cli.resume.continuing", max = max));
+    say!(
+        "{}",
+        t!("cli.common.output_directory", path = output.display())
+    );
 
-    // For now, just restart the crawl with the existing database
+    // For now, just restart the crawl with the existing database, in the
+    // format it was saved in
     // A full resume implementation would track the exact position
-    crawl(config, None, max, None, false, output, true).await
+    let format = StorageFormat::recorded(&output);
+    crawl(
+        config,
+        CrawlParams {
+            site: None,
+            category: None,
+            max_articles: Some(max),
+            url: None,
+            url_file: None,
+            query: None,
+            discovery: Discovery::List,
+            from: None,
+            to: None,
+            with_comments: false,
+            download_images: false,
+            output,
+            format,
+            skip_existing: true,
+            metrics_port: None,
+            #[cfg(feature = "mock")]
+            mock: None,
+            index: false,
+            index_queue: 0,
+            shutdown: Shutdown::listen(),
+        },
+        job,
+        progress,
+    )
+    .await
 }
 
 pub fn stats(database: PathBuf) -> Result<()> {
     if !database.exists() {
-        println!("Database not found: {}", database.display());
-        println!("Run a crawl first to create the database.");
+        println!("{}", t!("cli.stats.not_found", path = database.display()));
+        println!("{}", t!("cli.stats.run_crawl_first"));
         return Ok(());
     }
 
@@ -265,44 +1201,216 @@ pub fn stats(database: PathBuf) -> Result<()> {
         sqlite_path: database.clone(),
         postgres_url: String::new(),
         pool_size: 5,
+        store_bodies: false,
     };
     let mut db = Database::new(&db_config)?;
     db.init_sqlite(&database)?;
 
     let stats = db.get_stats()?;
 
-    println!("Crawl Statistics");
-    println!("================");
-    println!("Database: {}", database.display());
-    println!();
-    println!("Total records: {}", stats.total);
-    println!(
-        "  Success: {} ({:.1}%)",
-        stats.success,
-        if stats.total > 0 {
-            stats.success as f64 / stats.total as f64 * 100.0
+    let percent = |count: usize| {
+        let percent = if stats.total > 0 {
+            count as f64 / stats.total as f64 * 100.0
         } else {
             0.0
-        }
+        };
+        format!("{percent:.1}")
+    };
+
+    print_title(&t!("cli.stats.title"), '=');
+    println!("{}", t!("cli.common.database", path = database.display()));
+    println!();
+    println!("{}", t!("cli.common.total_records", count = stats.total));
+    println!(
+        "  {}",
+        t!(
+            "cli.stats.success",
+            count = stats.success,
+            percent = percent(stats.success)
+        )
     );
     println!(
-        "  Failed:  {} ({:.1}%)",
-        stats.failed,
-        if stats.total > 0 {
-            stats.failed as f64 / stats.total as f64 * 100.0
-        } else {
-            0.0
-        }
+        "  {}",
+        t!(
+            "cli.stats.failed",
+            count = stats.failed,
+            percent = percent(stats.failed)
+        )
     );
     println!(
-        "  Skipped: {} ({:.1}%)",
-        stats.skipped,
-        if stats.total > 0 {
-            stats.skipped as f64 / stats.total as f64 * 100.0
-        } else {
-            0.0
-        }
+        "  {}",
+        t!(
+            "cli.stats.skipped",
+            count = stats.skipped,
+            percent = percent(stats.skipped)
+        )
     );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baram::crawler::sites::{DaumSite, NaverSite};
+
+    #[test]
+    fn test_parse_url_line() {
+        let naver = NaverSite::new();
+        assert!(parse_url_line("", &naver).is_none());
+        assert!(parse_url_line("  # curated list", &naver).is_none());
+
+        let url = parse_url_line(
+            " https://m.news.naver.com/article/001/0014123456 \n",
+            &naver,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(url, "https://n.news.naver.com/mnews/article/001/0014123456");
+
+        assert!(parse_url_line("not a url", &naver).unwrap().is_err());
+        assert!(parse_url_line("https://evil.com/article/001/1", &naver)
+            .unwrap()
+            .is_err());
+
+        let url = parse_url_line(
+            "https://v.daum.net/v/20240115093000123?f=o",
+            &DaumSite::new(),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(url, "https://v.daum.net/v/20240115093000123");
+    }
+
+    #[test]
+    fn test_parse_site() {
+        assert_eq!(parse_site(None).unwrap().source(), ArticleSource::Naver);
+        assert_eq!(
+            parse_site(Some("daum")).unwrap().source(),
+            ArticleSource::Daum
+        );
+        assert!(parse_site(Some("rss")).is_err());
+        assert!(parse_site(Some("nate")).is_err());
+    }
+
+    #[test]
+    fn test_site_for_url() {
+        let sites = all_sites();
+        let source = |url| site_for_url(&sites, url).source();
+        assert_eq!(
+            source("https://v.daum.net/v/20240115093000123"),
+            ArticleSource::Daum
+        );
+        assert_eq!(
+            source("https://n.news.naver.com/mnews/article/001/0014123456"),
+            ArticleSource::Naver
+        );
+        assert_eq!(source("https://example.com/news/1"), ArticleSource::Naver);
+    }
+
+    #[test]
+    fn test_search_date() {
+        assert_eq!(search_date(None, "20241215").unwrap(), "20241215");
+        assert_eq!(
+            search_date(Some("2024-12-01"), "20241215").unwrap(),
+            "20241201"
+        );
+        assert!(search_date(Some("20241201"), "20241215").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_crawl_single_url_revalidates_only_when_skipping_existing() {
+        use wiremock::matchers::{header, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/mnews/article/001/0014500001"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/mnews/article/001/0014500001"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_raw(
+                        include_str!("../../tests/fixtures/html/general_news.html"),
+                        "text/html; charset=UTF-8",
+                    ),
+            )
+            .mount(&server)
+            .await;
+        let url = format!("{}/mnews/article/001/0014500001", server.uri());
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config::default();
+        config.crawler.robots.obey = false;
+        let crawler = Crawler::new(config)
+            .unwrap()
+            .with_http_cache(HttpCache::open_in_memory().unwrap());
+        let site = NaverSite::new();
+        // A fresh database each time, so the article is not a duplicate
+        let database = |name: &str| {
+            let path = dir.path().join(name);
+            let mut db = Database::new(&DatabaseConfig {
+                sqlite_path: path.clone(),
+                postgres_url: String::new(),
+                pool_size: 1,
+                store_bodies: false,
+            })
+            .unwrap();
+            db.init_sqlite(&path).unwrap();
+            db
+        };
+        let output = dir.path().join("raw");
+        let keep = ArticleStorage::new(&output, true).unwrap();
+        let overwrite = ArticleStorage::new(&output, false).unwrap();
+        let mut state = CrawlState::new();
+
+        let crawled = crawl_single_url(
+            &crawler,
+            &site,
+            &keep,
+            &database("1.db"),
+            &url,
+            &mut state,
+            None,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let paths = keep.paths(&crawled.article);
+        std::fs::remove_file(&paths[0]).unwrap();
+
+        // Unchanged, so the article is skipped when saved ones are kept...
+        let skipped = crawl_single_url(
+            &crawler,
+            &site,
+            &keep,
+            &database("2.db"),
+            &url,
+            &mut state,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(skipped.is_none());
+        assert!(!paths[0].exists());
+
+        // ...but fetched in full and saved again when they are overwritten
+        let crawled = crawl_single_url(
+            &crawler,
+            &site,
+            &overwrite,
+            &database("3.db"),
+            &url,
+            &mut state,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(crawled.is_some_and(|crawled| crawled.bytes_written > 0));
+        assert!(paths[0].exists());
+    }
+}
@@ -0,0 +1,375 @@
+//! Dedupe command implementation
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use rust_i18n::t;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use baram::config::Config;
+use baram::embedding::{IndexDocument, VectorStore};
+use baram::storage::simhash::{
+    duplicate_groups, hamming_distance, simhash, Fingerprint, MAX_DISTANCE_LIMIT,
+};
+use baram::storage::MarkdownReader;
+
+use super::index::markdown_to_document;
+use super::output::say;
+use super::progress::Progress;
+use super::{pad_end, print_title};
+
+/// Parameters for `baram dedupe`
+pub struct DedupeParams {
+    /// Markdown directory to scan
+    pub input: PathBuf,
+    /// Maximum SimHash distance between near-duplicates
+    pub max_distance: u32,
+    /// Delete redundant markdown files (otherwise only report them)
+    pub delete: bool,
+    /// Also delete redundant documents from the OpenSearch index
+    pub index: bool,
+    /// Where to write the JSON report
+    pub report: PathBuf,
+}
+
+/// One scanned markdown file
+struct ScannedArticle {
+    path: PathBuf,
+    doc: IndexDocument,
+    fingerprint: Fingerprint,
+}
+
+impl ScannedArticle {
+    /// Sort key for picking the article to keep: earliest published, then
+    /// lowest ID, so the original report wins over later reprints
+    fn keep_order(&self) -> (bool, Option<&str>, &str, &Path) {
+        let published = self.doc.published_at.as_deref();
+        (published.is_none(), published, &self.doc.id, &self.path)
+    }
+}
+
+/// Written to `--report`
+#[derive(Debug, Serialize)]
+struct DedupeReport {
+    generated_at: DateTime<Utc>,
+    input: PathBuf,
+    max_distance: u32,
+    scanned: usize,
+    dry_run: bool,
+    redundant: usize,
+    removed_files: usize,
+    removed_index_documents: usize,
+    groups: Vec<GroupReport>,
+}
+
+/// A kept article and the duplicates merged into it
+#[derive(Debug, Serialize)]
+struct GroupReport {
+    kept: ArticleRef,
+    duplicates: Vec<DuplicateRef>,
+}
+
+#[derive(Debug, Serialize)]
+struct ArticleRef {
+    id: String,
+    title: String,
+    publisher: Option<String>,
+    published_at: Option<String>,
+    path: PathBuf,
+}
+
+impl From<&ScannedArticle> for ArticleRef {
+    fn from(article: &ScannedArticle) -> Self {
+        Self {
+            id: article.doc.id.clone(),
+            title: article.doc.title.clone(),
+            publisher: article.doc.publisher.clone(),
+            published_at: article.doc.published_at.clone(),
+            path: article.path.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DuplicateRef {
+    #[serde(flatten)]
+    article: ArticleRef,
+    /// `content_hash` for verbatim copies, `simhash` for near-duplicates
+    reason: &'static str,
+    /// SimHash distance to the kept article
+    distance: Option<u32>,
+    removed: bool,
+}
+
+/// Find duplicate articles in the markdown store and optionally remove them
+///
+/// Articles are grouped when they share a content hash or their SimHashes
+/// are within `max_distance` bits. The earliest published article of each
+/// group is kept. Crawl database records are left alone, so removed
+/// duplicates are not crawled again.
+pub async fn dedupe(config: &Config, params: DedupeParams, progress: &Progress) -> Result<()> {
+    let DedupeParams {
+        input,
+        max_distance,
+        delete,
+        index,
+        report,
+    } = params;
+
+    if max_distance > MAX_DISTANCE_LIMIT {
+        bail!(
+            "{}",
+            t!("cli.dedupe.max_distance", max = MAX_DISTANCE_LIMIT)
+        );
+    }
+    if !input.is_dir() {
+        bail!(
+            "{}",
+            t!("cli.common.markdown_dir_not_found", path = input.display())
+        );
+    }
+
+    print_title(&t!("cli.dedupe.starting", path = input.display()), '=');
+
+    let articles = scan(&input, progress)?;
+    let fingerprints: Vec<Fingerprint> = articles.iter().map(|a| a.fingerprint.clone()).collect();
+    let groups = duplicate_groups(&fingerprints, max_distance);
+
+    let store = if delete && index {
+        let opensearch_config = config.opensearch.clone().with_env_overrides();
+        Some(VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?)
+    } else {
+        None
+    };
+
+    // A file saved twice under different titles has the same document ID;
+    // its index document belongs to the kept copy
+    let mut kept_ids = HashSet::new();
+    let mut group_reports = Vec::with_capacity(groups.len());
+    for mut group in groups {
+        group.sort_by(|&a, &b| articles[a].keep_order().cmp(&articles[b].keep_order()));
+        let kept = &articles[group[0]];
+        kept_ids.insert(kept.doc.id.as_str());
+
+        let duplicates = group[1..]
+            .iter()
+            .map(|&i| {
+                let article = &articles[i];
+                let exact = !kept.fingerprint.content_hash.is_empty()
+                    && article.fingerprint.content_hash == kept.fingerprint.content_hash;
+                let distance = kept
+                    .fingerprint
+                    .simhash
+                    .zip(article.fingerprint.simhash)
+                    .map(|(a, b)| hamming_distance(a, b));
+                DuplicateRef {
+                    article: ArticleRef::from(article),
+                    reason: if exact { "content_hash" } else { "simhash" },
+                    distance,
+                    removed: false,
+                }
+            })
+            .collect();
+        group_reports.push(GroupReport {
+            kept: ArticleRef::from(kept),
+            duplicates,
+        });
+    }
+
+    let mut removed_files = 0;
+    let mut removed_index_documents = 0;
+    if delete {
+        for duplicate in group_reports.iter_mut().flat_map(|g| &mut g.duplicates) {
+            let path = &duplicate.article.path;
+            match std::fs::remove_file(path) {
+                Ok(()) => {
+                    duplicate.removed = true;
+                    removed_files += 1;
+                }
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to remove duplicate");
+                    continue;
+                }
+            }
+
+            let Some(store) = &store else { continue };
+            let id = &duplicate.article.id;
+            if kept_ids.contains(id.as_str()) {
+                continue;
+            }
+            match store.delete_by_query("id", id).await {
+                Ok(deleted) => removed_index_documents += deleted,
+                Err(e) => {
+                    tracing::warn!(id = %id, error = %e, "Failed to delete index document");
+                }
+            }
+        }
+    }
+
+    let redundant: usize = group_reports.iter().map(|g| g.duplicates.len()).sum();
+    print_groups(&group_reports);
+
+    say!();
+    say!("{}", t!("cli.dedupe.scanned", count = articles.len()));
+    say!("{}", t!("cli.dedupe.groups", count = group_reports.len()));
+    say!("{}", t!("cli.dedupe.redundant", count = redundant));
+    if delete {
+        say!("{}", t!("cli.dedupe.removed_files", count = removed_files));
+        if store.is_some() {
+            say!(
+                "{}",
+                t!(
+                    "cli.dedupe.removed_documents",
+                    count = removed_index_documents
+                )
+            );
+        }
+    } else if redundant > 0 {
+        say!("{}", t!("cli.dedupe.dry_run"));
+    }
+
+    let dedupe_report = DedupeReport {
+        generated_at: Utc::now(),
+        input,
+        max_distance,
+        scanned: articles.len(),
+        dry_run: !delete,
+        redundant,
+        removed_files,
+        removed_index_documents,
+        groups: group_reports,
+    };
+    if let Some(parent) = report.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&dedupe_report)?;
+    std::fs::write(&report, json)
+        .with_context(|| format!("Failed to write report: {}", report.display()))?;
+    say!("{}", t!("cli.dedupe.report", path = report.display()));
+
+    Ok(())
+}
+
+/// Read and fingerprint every markdown file in `input`
+fn scan(input: &Path, progress: &Progress) -> Result<Vec<ScannedArticle>> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(input)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    let task = progress.task("dedupe", "scan", paths.len() as u64);
+    let mut articles = Vec::with_capacity(paths.len());
+    for path in paths {
+        task.inc(1);
+        let markdown = match MarkdownReader::new().read(&path) {
+            Ok(markdown) => markdown,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to read markdown");
+                continue;
+            }
+        };
+        // The reader leaves out the title, byline and footer, which differ
+        // between copies of the same story and would skew the SimHash
+        let fingerprint = Fingerprint {
+            content_hash: markdown
+                .frontmatter
+                .content_hash
+                .clone()
+                .unwrap_or_default(),
+            simhash: (!markdown.body.is_empty()).then(|| simhash(&markdown.body)),
+        };
+        let doc = markdown_to_document(markdown);
+        articles.push(ScannedArticle {
+            path,
+            doc,
+            fingerprint,
+        });
+    }
+    task.finish();
+    Ok(articles)
+}
+
+fn print_groups(groups: &[GroupReport]) {
+    let (keep, removed, dup) = (
+        t!("cli.dedupe.keep"),
+        t!("cli.dedupe.removed"),
+        t!("cli.dedupe.dup"),
+    );
+    for group in groups {
+        say!();
+        say!(
+            "{} {}  {}",
+            pad_end(&keep, 7),
+            group.kept.id,
+            group.kept.title
+        );
+        for duplicate in &group.duplicates {
+            let action = if duplicate.removed { &removed } else { &dup };
+            let how = match (duplicate.reason, duplicate.distance) {
+                ("simhash", Some(distance)) => format!("simhash ±{distance}"),
+                (reason, _) => reason.to_string(),
+            };
+            say!(
+                "{} {}  {}  ({how})",
+                pad_end(action, 7),
+                duplicate.article.id,
+                duplicate.article.title
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::progress::ProgressMode;
+
+    #[test]
+    fn test_scan_groups_copies() {
+        let dir = tempfile::tempdir().unwrap();
+        let body = "정부는 오늘 내년도 예산안을 국회에 제출했다. 총지출은 역대 최대 규모다.";
+        let files = [
+            ("001_0000000002_a.md", "h1", "2026-03-01 10:00", body),
+            ("001_0000000001_a.md", "h1", "2026-03-01 09:00", body),
+            ("023_0000000003_b.md", "h2", "2026-03-01 11:00", body),
+            (
+                "023_0000000004_c.md",
+                "h3",
+                "2026-03-01 12:00",
+                "프로야구 개막전에서 홈팀이 연장 접전 끝에 승리를 거뒀다.",
+            ),
+        ];
+        for (name, hash, published_at, body) in files {
+            let markdown = format!(
+                "---\ntitle: \"t\"\npublished_at: {published_at}\ncontent_hash: {hash}\n---\n\n# t\n\n**p** | {published_at}\n\n---\n\n{body}\n\n<!-- body-end -->\n---\n\n*Crawled at: {name}*\n"
+            );
+            std::fs::write(dir.path().join(name), markdown).unwrap();
+        }
+
+        let progress = Progress::new(ProgressMode::None);
+        let articles = scan(dir.path(), &progress).unwrap();
+        assert_eq!(articles.len(), 4);
+        let fingerprints: Vec<Fingerprint> =
+            articles.iter().map(|a| a.fingerprint.clone()).collect();
+        let groups = duplicate_groups(&fingerprints, 3);
+        assert_eq!(groups.len(), 1);
+
+        let mut group = groups[0].clone();
+        group.sort_by(|&a, &b| articles[a].keep_order().cmp(&articles[b].keep_order()));
+        let names: Vec<&str> = group
+            .iter()
+            .map(|&i| articles[i].path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "001_0000000001_a.md",
+                "001_0000000002_a.md",
+                "023_0000000003_b.md"
+            ]
+        );
+    }
+}
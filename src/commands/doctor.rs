@@ -0,0 +1,183 @@
+//! Doctor command implementation
+
+use anyhow::{bail, Result};
+use rust_i18n::t;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
+
+use baram::config::{check_file, ConfigCheck, IssueSeverity};
+use baram::health::{DependencyChecker, HealthReport, HealthStatus};
+
+/// Config check and dependency probes, as printed by `--format json`
+#[derive(Serialize)]
+struct DoctorReport {
+    config: ConfigCheck,
+    dependencies: HealthReport,
+}
+
+/// Check the config file, probe every configured dependency and print a
+/// report with suggested fixes
+///
+/// Fails when the config has errors or a required dependency is unhealthy,
+/// so the command can gate deployment scripts. Dependencies are probed with
+/// the checked config (with `profile` applied), or the defaults if it does
+/// not load.
+pub async fn doctor(
+    config_path: &Path,
+    profile: Option<&str>,
+    timeout_secs: u64,
+    format: &str,
+) -> Result<()> {
+    if !matches!(format, "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let config_check = check_file(config_path, profile);
+    let config = config_check.config.clone().unwrap_or_default();
+    let dependencies = DependencyChecker::from_config(&config)
+        .with_timeout(Duration::from_secs(timeout_secs.max(1)))
+        .check()
+        .await;
+
+    let report = DoctorReport {
+        config: config_check,
+        dependencies,
+    };
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&report)?),
+        _ => print!("{}", render_report(&report)),
+    }
+
+    if !report.config.is_ok() {
+        bail!("{}", t!("cli.doctor.config_errors"));
+    }
+    if !report.dependencies.is_ready() {
+        bail!("{}", t!("cli.doctor.dependency_unhealthy"));
+    }
+    Ok(())
+}
+
+fn render_report(report: &DoctorReport) -> String {
+    let mut out = String::new();
+    let title = t!("cli.doctor.title");
+    out.push_str(&format!("{title}\n{}\n", "=".repeat(title.width())));
+
+    match report
+        .config
+        .config
+        .as_ref()
+        .and_then(|c| c.profile.as_deref())
+    {
+        Some(profile) => out.push_str(&format!(
+            "{}\n",
+            t!(
+                "cli.doctor.config_profile",
+                path = report.config.path.display(),
+                profile = profile
+            )
+        )),
+        None => out.push_str(&format!(
+            "{}\n",
+            t!("cli.doctor.config", path = report.config.path.display())
+        )),
+    }
+    if report.config.issues.is_empty() {
+        out.push_str(&format!("  [OK  ] {}\n", t!("cli.doctor.no_problems")));
+    }
+    let fix = t!("cli.doctor.fix");
+    for issue in &report.config.issues {
+        let marker = match issue.severity {
+            IssueSeverity::Error => "FAIL",
+            IssueSeverity::Warning => "WARN",
+        };
+        match &issue.key {
+            Some(key) => out.push_str(&format!("  [{marker}] {key}: {}\n", issue.message)),
+            None => out.push_str(&format!("  [{marker}] {}\n", issue.message)),
+        }
+        out.push_str(&format!("         {fix}: {}\n", issue.fix));
+    }
+
+    out.push_str(&format!("\n{}\n", t!("cli.doctor.dependencies")));
+    let optional = format!(" ({})", t!("cli.doctor.optional"));
+    for check in &report.dependencies.checks {
+        let marker = match check.status {
+            HealthStatus::Healthy => "OK  ",
+            HealthStatus::Degraded => "WARN",
+            HealthStatus::Unhealthy => "FAIL",
+        };
+        out.push_str(&format!(
+            "  [{marker}] {:<17} {:>6}ms  {}{}\n",
+            check.dependency.as_str(),
+            check.latency_ms,
+            check.target,
+            if check.required { "" } else { &optional },
+        ));
+        if let Some(message) = &check.message {
+            out.push_str(&format!("         {message}\n"));
+        }
+        if let Some(check_fix) = &check.fix {
+            out.push_str(&format!("         {fix}: {check_fix}\n"));
+        }
+    }
+
+    let overall = if report.config.is_ok() {
+        report.dependencies.status
+    } else {
+        HealthStatus::Unhealthy
+    };
+    out.push('\n');
+    out.push_str(&format!(
+        "{}\n",
+        t!("cli.doctor.overall", status = overall.as_str())
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baram::config::{Config, ConfigIssue};
+    use baram::health::{Dependency, DependencyHealth};
+
+    #[test]
+    fn test_render_report_shows_fixes() {
+        let report = DoctorReport {
+            config: ConfigCheck {
+                path: "config.toml".into(),
+                found: true,
+                issues: vec![ConfigIssue {
+                    severity: IssueSeverity::Warning,
+                    key: Some("crawler.rate_limt".to_string()),
+                    message: "unknown key, it is ignored".to_string(),
+                    fix: "Did you mean `rate_limit`?".to_string(),
+                }],
+                config: Some(Config::default()),
+            },
+            dependencies: HealthReport::from_checks(vec![DependencyHealth {
+                dependency: Dependency::OpenSearchIndex,
+                status: HealthStatus::Unhealthy,
+                required: true,
+                target: "http://localhost:9200/baram-articles".to_string(),
+                latency_ms: 3,
+                message: Some("embedding dimension 1024 does not match the model's 384".into()),
+                fix: Some("Recreate the index with `baram index --force`".to_string()),
+            }]),
+        };
+
+        let text = render_report(&report);
+        assert!(text.contains("[WARN] crawler.rate_limt: unknown key"));
+        assert!(text.contains("fix: Did you mean `rate_limit`?"));
+        assert!(text.contains("[FAIL] opensearch_index"));
+        assert!(text.contains("fix: Recreate the index"));
+        assert!(text.ends_with("Overall: unhealthy\n"));
+    }
+}
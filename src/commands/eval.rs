@@ -0,0 +1,618 @@
+use anyhow::{bail, Context, Result};
+use rust_i18n::t;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use baram::config::Config;
+use baram::embedding::eval::{QueryMetrics, RelevanceSet};
+use baram::embedding::{SearchConfig, VectorStore};
+use baram::ontology::eval::{GoldSet, OntologyEval, Regression, TypeScores};
+use baram::ontology::CombinedExtractor;
+
+use super::output::say;
+use super::print_title;
+use super::search::get_query_embedding;
+
+/// Parameters for `baram eval search`
+pub struct EvalSearchParams {
+    /// Relevance set (.csv or .json)
+    pub qrels: PathBuf,
+    /// Comma-separated search modes (bm25, knn, hybrid)
+    pub modes: String,
+    /// Comma-separated BM25 weights tried for hybrid search
+    pub bm25_weights: String,
+    /// Cutoff for nDCG and recall
+    pub k: usize,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// One search configuration under evaluation
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Variant {
+    Bm25,
+    Knn,
+    Hybrid { bm25_weight: f32 },
+}
+
+impl Variant {
+    fn name(&self) -> String {
+        match self {
+            Self::Bm25 => "bm25".to_string(),
+            Self::Knn => "knn".to_string(),
+            Self::Hybrid { bm25_weight } => format!("hybrid@{bm25_weight:.2}"),
+        }
+    }
+
+    fn mode(&self) -> &'static str {
+        match self {
+            Self::Bm25 => "bm25",
+            Self::Knn => "knn",
+            Self::Hybrid { .. } => "hybrid",
+        }
+    }
+
+    fn needs_embedding(&self) -> bool {
+        !matches!(self, Self::Bm25)
+    }
+}
+
+/// Expand `--modes` and `--bm25-weights` into the variants to run
+///
+/// Hybrid search is run once per weight.
+fn parse_variants(modes: &str, bm25_weights: &str) -> Result<Vec<Variant>, String> {
+    let weights = bm25_weights
+        .split(',')
+        .filter(|w| !w.trim().is_empty())
+        .map(|w| match w.trim().parse::<f32>() {
+            Ok(weight) if (0.0..=1.0).contains(&weight) => Ok(weight),
+            _ => Err(format!("Invalid BM25 weight: {w}. Expected 0.0 - 1.0")),
+        })
+        .collect::<Result<Vec<f32>, _>>()?;
+
+    let mut variants = Vec::new();
+    for mode in modes.split(',').map(str::trim).filter(|m| !m.is_empty()) {
+        match mode {
+            "keyword" | "bm25" => variants.push(Variant::Bm25),
+            "vector" | "knn" => variants.push(Variant::Knn),
+            "hybrid" => {
+                if weights.is_empty() {
+                    return Err("--bm25-weights must list at least one weight".to_string());
+                }
+                variants.extend(
+                    weights
+                        .iter()
+                        .map(|&bm25_weight| Variant::Hybrid { bm25_weight }),
+                );
+            }
+            other => {
+                return Err(format!(
+                    "Unknown search mode: {other}. Use bm25, knn or hybrid"
+                ))
+            }
+        }
+    }
+    variants.dedup();
+    if variants.is_empty() {
+        return Err("--modes must name at least one search mode".to_string());
+    }
+    Ok(variants)
+}
+
+/// Metrics of one query under one variant
+#[derive(Debug, Serialize)]
+struct QueryReport {
+    query: String,
+    retrieved: usize,
+    #[serde(flatten)]
+    metrics: QueryMetrics,
+}
+
+/// Metrics of one variant over all queries
+#[derive(Debug, Serialize)]
+struct VariantReport {
+    name: String,
+    mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bm25_weight: Option<f32>,
+    mean: QueryMetrics,
+    queries: Vec<QueryReport>,
+}
+
+#[derive(Debug, Serialize)]
+struct EvalReport {
+    qrels: PathBuf,
+    queries: usize,
+    judgments: usize,
+    k: usize,
+    variants: Vec<VariantReport>,
+    /// Variant with the highest mean nDCG
+    best: Option<String>,
+}
+
+/// Variant with the highest mean nDCG, ties broken by MRR
+fn best_variant(variants: &[VariantReport]) -> Option<&VariantReport> {
+    variants.iter().max_by(|a, b| {
+        a.mean
+            .ndcg
+            .total_cmp(&b.mean.ndcg)
+            .then(a.mean.mrr.total_cmp(&b.mean.mrr))
+            // Keep the first of equal variants
+            .then(std::cmp::Ordering::Greater)
+    })
+}
+
+pub async fn eval_search(config: &Config, params: EvalSearchParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+    if params.k == 0 {
+        bail!("{}", t!("cli.eval.invalid_k"));
+    }
+    let variants =
+        parse_variants(&params.modes, &params.bm25_weights).map_err(anyhow::Error::msg)?;
+    let set = RelevanceSet::load(&params.qrels)?;
+
+    let opensearch_config = config.opensearch.clone().with_env_overrides();
+    let store = VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
+    if !store.index_exists().await? {
+        bail!(
+            "{}",
+            t!("cli.search.no_index", index = opensearch_config.index_name)
+        );
+    }
+
+    // Embed each query once, shared by k-NN and every hybrid weight
+    let mut embeddings = Vec::new();
+    if variants.iter().any(Variant::needs_embedding) {
+        for labeled in &set.queries {
+            embeddings.push(get_query_embedding(&labeled.query).await?);
+        }
+    }
+
+    let mut reports = Vec::new();
+    for variant in &variants {
+        let search_config = SearchConfig {
+            k: params.k,
+            include_highlights: false,
+            hybrid: matches!(variant, Variant::Hybrid { .. }),
+            bm25_weight: match variant {
+                Variant::Hybrid { bm25_weight } => *bm25_weight,
+                _ => SearchConfig::default().bm25_weight,
+            },
+            ..Default::default()
+        };
+
+        let mut queries = Vec::new();
+        for (i, labeled) in set.queries.iter().enumerate() {
+            let results = match variant {
+                Variant::Bm25 => store.search_bm25(&labeled.query, &search_config).await,
+                Variant::Knn => store.search_knn(&embeddings[i], &search_config).await,
+                Variant::Hybrid { .. } => {
+                    store
+                        .search_hybrid(&labeled.query, &embeddings[i], &search_config)
+                        .await
+                }
+            }
+            .with_context(|| format!("{} search failed for {:?}", variant.name(), labeled.query))?;
+
+            let ranked: Vec<String> = results.into_iter().map(|r| r.id).collect();
+            queries.push(QueryReport {
+                query: labeled.query.clone(),
+                retrieved: ranked.len(),
+                metrics: QueryMetrics::compute(&ranked, &labeled.relevant, params.k),
+            });
+        }
+
+        let per_query: Vec<QueryMetrics> = queries.iter().map(|q| q.metrics).collect();
+        let mean = QueryMetrics::mean(&per_query);
+        tracing::info!(
+            variant = %variant.name(),
+            ndcg = mean.ndcg,
+            mrr = mean.mrr,
+            recall = mean.recall,
+            "Evaluated search variant"
+        );
+        reports.push(VariantReport {
+            name: variant.name(),
+            mode: variant.mode(),
+            bm25_weight: match variant {
+                Variant::Hybrid { bm25_weight } => Some(*bm25_weight),
+                _ => None,
+            },
+            mean,
+            queries,
+        });
+    }
+
+    let report = EvalReport {
+        qrels: params.qrels,
+        queries: set.queries.len(),
+        judgments: set.judgments(),
+        k: params.k,
+        best: best_variant(&reports).map(|v| v.name.clone()),
+        variants: reports,
+    };
+
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_eval_report(&report);
+    }
+
+    Ok(())
+}
+
+fn print_eval_report(report: &EvalReport) {
+    print_title(&t!("cli.eval.search_title"), '=');
+    say!(
+        "  {}",
+        t!("cli.eval.qrels", path = report.qrels.display().to_string())
+    );
+    say!(
+        "  {}",
+        t!(
+            "cli.eval.queries",
+            count = report.queries,
+            judgments = report.judgments
+        )
+    );
+    say!();
+
+    let name_width = report
+        .variants
+        .iter()
+        .map(|v| v.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("variant".len());
+    let ndcg = format!("nDCG@{}", report.k);
+    let recall = format!("Recall@{}", report.k);
+    println!(
+        "  {:<name_width$}  {ndcg:>8}  {:>8}  {recall:>9}",
+        "variant", "MRR"
+    );
+    for variant in &report.variants {
+        let marker = if report.best.as_deref() == Some(variant.name.as_str()) {
+            " *"
+        } else {
+            ""
+        };
+        println!(
+            "  {:<name_width$}  {:>8.4}  {:>8.4}  {:>9.4}{marker}",
+            variant.name, variant.mean.ndcg, variant.mean.mrr, variant.mean.recall
+        );
+    }
+
+    if let Some(best) = report
+        .variants
+        .iter()
+        .find(|v| Some(&v.name) == report.best.as_ref())
+    {
+        say!();
+        say!(
+            "{}",
+            t!(
+                "cli.eval.best",
+                variant = best.name,
+                k = report.k,
+                ndcg = format!("{:.4}", best.mean.ndcg)
+            )
+        );
+    }
+}
+
+/// Parameters for `baram eval ontology`
+pub struct EvalOntologyParams {
+    /// Gold-annotated articles (.json or .jsonl)
+    pub gold: PathBuf,
+    /// Add LLM-extracted Said relations to the regex extraction
+    pub llm: bool,
+    /// Earlier JSON report to check for regressions
+    pub baseline: Option<PathBuf>,
+    /// F1 drop below the baseline that counts as a regression
+    pub tolerance: f64,
+    /// Output format (text, json)
+    pub format: String,
+    /// Also write the JSON report here (e.g. as the next baseline)
+    pub output: Option<PathBuf>,
+}
+
+/// Scores of one entity or relation type
+#[derive(Debug, Serialize)]
+struct TypeRow {
+    name: String,
+    precision: f64,
+    recall: f64,
+    f1: f64,
+    #[serde(flatten)]
+    counts: TypeScores,
+}
+
+impl TypeRow {
+    fn new(name: &str, counts: TypeScores) -> Self {
+        Self {
+            name: name.to_string(),
+            precision: counts.precision(),
+            recall: counts.recall(),
+            f1: counts.f1(),
+            counts,
+        }
+    }
+
+    /// One row per type, then the micro-averaged `all`
+    fn table(
+        scores: &std::collections::BTreeMap<String, TypeScores>,
+        total: TypeScores,
+    ) -> Vec<Self> {
+        scores
+            .iter()
+            .map(|(name, counts)| Self::new(name, *counts))
+            .chain([Self::new("all", total)])
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OntologyReport {
+    gold: PathBuf,
+    extractor: &'static str,
+    articles: usize,
+    entities: Vec<TypeRow>,
+    relations: Vec<TypeRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline: Option<PathBuf>,
+    regressions: Vec<Regression>,
+    /// Raw counts, read back by `--baseline`
+    counts: OntologyEval,
+}
+
+/// Read the counts of an earlier report
+fn load_baseline(path: &std::path::Path) -> Result<OntologyEval> {
+    #[derive(serde::Deserialize)]
+    struct Baseline {
+        counts: OntologyEval,
+    }
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+    let baseline: Baseline = serde_json::from_str(&data)
+        .with_context(|| format!("Invalid baseline report {}", path.display()))?;
+    Ok(baseline.counts)
+}
+
+pub async fn eval_ontology(params: EvalOntologyParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+    let gold = GoldSet::load(&params.gold)?;
+    let baseline = params.baseline.as_deref().map(load_baseline).transpose()?;
+
+    let extractor = if params.llm {
+        let extractor = CombinedExtractor::with_llm()?;
+        if !extractor.llm_available().await {
+            bail!("{}", t!("cli.eval.llm_unavailable"));
+        }
+        extractor
+    } else {
+        CombinedExtractor::new()
+    };
+
+    let mut eval = OntologyEval::default();
+    for annotated in &gold.articles {
+        let article = annotated.to_article();
+        let extracted = if params.llm {
+            extractor.extract_with_llm(&article).await?
+        } else {
+            extractor.extract(&article)
+        };
+        eval.add(annotated, &extracted);
+    }
+
+    let entity_total = eval.entity_total();
+    let relation_total = eval.relation_total();
+    tracing::info!(
+        articles = eval.articles,
+        entity_f1 = entity_total.f1(),
+        relation_f1 = relation_total.f1(),
+        "Evaluated ontology extraction"
+    );
+
+    let regressions = baseline
+        .as_ref()
+        .map(|baseline| eval.regressions(baseline, params.tolerance))
+        .unwrap_or_default();
+    let report = OntologyReport {
+        gold: params.gold,
+        extractor: if params.llm { "regex+llm" } else { "regex" },
+        articles: eval.articles,
+        entities: TypeRow::table(&eval.entities, entity_total),
+        relations: TypeRow::table(&eval.relations, relation_total),
+        baseline: params.baseline,
+        regressions,
+        counts: eval,
+    };
+
+    let json = serde_json::to_string_pretty(&report)?;
+    if let Some(path) = &params.output {
+        std::fs::write(path, format!("{json}\n"))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    if params.format == "json" {
+        println!("{json}");
+    } else {
+        print_ontology_report(&report);
+        if let Some(path) = &params.output {
+            say!(
+                "{}",
+                t!("cli.report.written", path = path.display().to_string())
+            );
+        }
+    }
+
+    if !report.regressions.is_empty() {
+        bail!(
+            "{}",
+            t!("cli.eval.regressed", count = report.regressions.len())
+        );
+    }
+    Ok(())
+}
+
+fn print_ontology_report(report: &OntologyReport) {
+    print_title(&t!("cli.eval.ontology_title"), '=');
+    say!(
+        "  {}",
+        t!("cli.eval.gold", path = report.gold.display().to_string())
+    );
+    say!(
+        "  {}",
+        t!(
+            "cli.eval.extractor",
+            extractor = report.extractor,
+            count = report.articles
+        )
+    );
+
+    for (title, rows) in [
+        (t!("cli.eval.entities"), &report.entities),
+        (t!("cli.eval.relations"), &report.relations),
+    ] {
+        say!();
+        println!("{title}");
+        let width = rows.iter().map(|r| r.name.len()).max().unwrap_or(0).max(4);
+        println!(
+            "  {:<width$}  {:>9}  {:>6}  {:>6}  {:>4}  {:>4}  {:>4}",
+            "type", "precision", "recall", "f1", "tp", "fp", "fn"
+        );
+        for row in rows {
+            println!(
+                "  {:<width$}  {:>9.3}  {:>6.3}  {:>6.3}  {:>4}  {:>4}  {:>4}",
+                row.name,
+                row.precision,
+                row.recall,
+                row.f1,
+                row.counts.true_positives,
+                row.counts.false_positives,
+                row.counts.false_negatives
+            );
+        }
+    }
+
+    if let Some(baseline) = &report.baseline {
+        say!();
+        println!(
+            "{}",
+            t!("cli.eval.baseline", path = baseline.display().to_string())
+        );
+        if report.regressions.is_empty() {
+            println!("  {}", t!("cli.eval.no_regressions"));
+        }
+        for regression in &report.regressions {
+            println!(
+                "  {} {}: F1 {:.3} -> {:.3}",
+                regression.kind, regression.name, regression.baseline, regression.current
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_variants_expands_hybrid_weights() {
+        let variants = parse_variants("bm25, hybrid,knn", "0.2,0.5").unwrap();
+        assert_eq!(
+            variants,
+            vec![
+                Variant::Bm25,
+                Variant::Hybrid { bm25_weight: 0.2 },
+                Variant::Hybrid { bm25_weight: 0.5 },
+                Variant::Knn,
+            ]
+        );
+        assert_eq!(variants[1].name(), "hybrid@0.20");
+        assert!(!variants[0].needs_embedding());
+    }
+
+    #[test]
+    fn test_parse_variants_rejects_invalid() {
+        assert!(parse_variants("bm25,fuzzy", "0.3").is_err());
+        assert!(parse_variants("hybrid", "1.5").is_err());
+        assert!(parse_variants("hybrid", "").is_err());
+        assert!(parse_variants("", "0.3").is_err());
+        // Weights are only needed for hybrid search
+        assert_eq!(parse_variants("keyword", "").unwrap(), vec![Variant::Bm25]);
+    }
+
+    #[test]
+    fn test_best_variant_prefers_ndcg_then_mrr() {
+        let variant = |name: &str, ndcg: f64, mrr: f64| VariantReport {
+            name: name.to_string(),
+            mode: "bm25",
+            bm25_weight: None,
+            mean: QueryMetrics {
+                ndcg,
+                mrr,
+                recall: 0.0,
+            },
+            queries: Vec::new(),
+        };
+        let reports = vec![
+            variant("a", 0.5, 0.5),
+            variant("b", 0.7, 0.4),
+            variant("c", 0.7, 0.6),
+            variant("d", 0.7, 0.6),
+        ];
+        assert_eq!(best_variant(&reports).unwrap().name, "c");
+        assert!(best_variant(&[]).is_none());
+    }
+
+    #[test]
+    fn test_ontology_report_reads_back_as_baseline() {
+        let mut counts = OntologyEval {
+            articles: 2,
+            ..Default::default()
+        };
+        counts.entities.insert(
+            "Person".to_string(),
+            TypeScores {
+                true_positives: 3,
+                false_positives: 1,
+                false_negatives: 0,
+            },
+        );
+        let report = OntologyReport {
+            gold: PathBuf::from("gold.json"),
+            extractor: "regex",
+            articles: 2,
+            entities: TypeRow::table(&counts.entities, counts.entity_total()),
+            relations: Vec::new(),
+            baseline: None,
+            regressions: Vec::new(),
+            counts: counts.clone(),
+        };
+        assert_eq!(report.entities.last().unwrap().name, "all");
+        assert_eq!(report.entities[0].precision, 0.75);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(&path, serde_json::to_string(&report).unwrap()).unwrap();
+        assert_eq!(load_baseline(&path).unwrap(), counts);
+    }
+}
@@ -0,0 +1,914 @@
+//! Export command implementation
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use baram::config::{secrets, Config};
+use baram::embedding::{IndexDocument, VectorStore};
+use baram::export::cypher::{constraint_statements, triple_statements, write_cypher, Neo4jClient};
+use baram::export::npy::{ids_path, EmbeddingDump};
+use baram::export::{
+    select_columns, write_fields, write_turtle, ArticleRecord, CommentRecord, EmbeddingRecord,
+    ExportFilter, ExportFormat, ExportKind, TripleRecord,
+};
+use baram::models::ArticleSource;
+use baram::ontology::{RelationExtractor, TripleStore};
+use baram::parser::Article;
+use baram::storage::Database;
+
+use super::index::parse_markdown_to_document;
+use super::output::say;
+use super::runs::crawl_db_path;
+
+/// Documents fetched per OpenSearch request
+const OPENSEARCH_PAGE_SIZE: usize = 500;
+
+/// Parameters for the export command
+pub struct ExportParams {
+    /// What to export (articles, triples, comments, index, embeddings)
+    pub what: String,
+    /// Output format (jsonl, csv, parquet, turtle, cypher, npy)
+    pub format: String,
+    /// Where to read from (db, markdown, opensearch); defaults per target
+    pub source: Option<String>,
+    /// Markdown directory for the markdown and sqlite sources
+    pub input: PathBuf,
+    /// Crawl database for the sqlite source (default: `crawl.db` next to
+    /// the markdown directory)
+    pub database: Option<PathBuf>,
+    /// Output file (stdout if not set)
+    pub output: Option<PathBuf>,
+    /// Only articles published on or after this date (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Only articles published up to and including this date (YYYY-MM-DD)
+    pub until: Option<String>,
+    /// Comma-separated categories to keep
+    pub category: Option<String>,
+    /// Maximum number of articles (0 = unlimited)
+    pub limit: usize,
+    /// Comma-separated columns to write (all if not set)
+    pub fields: Option<String>,
+    /// Neo4j HTTP URL to load triples into (e.g. http://localhost:7474)
+    pub neo4j_url: Option<String>,
+    /// Neo4j database name
+    pub neo4j_database: String,
+    /// Neo4j user; the password is read from `NEO4J_PASSWORD` (or the file
+    /// named by `NEO4J_PASSWORD_FILE`)
+    pub neo4j_user: String,
+}
+
+/// Where exported data is read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    /// PostgreSQL articles table
+    Database,
+    /// SQLite crawl database, joined with the crawled markdown files
+    Sqlite,
+    /// Crawled markdown files
+    Markdown,
+    /// OpenSearch article index
+    OpenSearch,
+}
+
+impl Source {
+    fn parse(value: Option<&str>, kind: ExportKind) -> Result<Self> {
+        let Some(value) = value else {
+            return Ok(match kind {
+                ExportKind::Articles | ExportKind::Triples => Self::Markdown,
+                ExportKind::Comments => Self::Database,
+                ExportKind::Index | ExportKind::Embeddings => Self::OpenSearch,
+            });
+        };
+        match value.to_lowercase().as_str() {
+            "db" | "database" | "postgres" => Ok(Self::Database),
+            "sqlite" => Ok(Self::Sqlite),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "opensearch" => Ok(Self::OpenSearch),
+            _ => bail!("{}", t!("cli.export.unknown_source", source = value)),
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Database => "db",
+            Self::Sqlite => "sqlite",
+            Self::Markdown => "markdown",
+            Self::OpenSearch => "opensearch",
+        }
+    }
+
+    /// Whether the source holds the data of an export target
+    fn supports(&self, kind: ExportKind) -> bool {
+        match kind {
+            ExportKind::Articles | ExportKind::Triples => true,
+            ExportKind::Comments => matches!(self, Self::Database | Self::Sqlite),
+            ExportKind::Index | ExportKind::Embeddings => *self == Self::OpenSearch,
+        }
+    }
+}
+
+/// Export articles, triples, comments, index documents or embeddings to a file
+pub async fn export(config: &Config, params: ExportParams) -> Result<()> {
+    let kind: ExportKind = params.what.parse().map_err(anyhow::Error::msg)?;
+    let format: ExportFormat = params.format.parse().map_err(anyhow::Error::msg)?;
+    let source = Source::parse(params.source.as_deref(), kind)?;
+
+    if !format.supports(kind) {
+        bail!(
+            "{}",
+            t!(
+                "cli.export.format_unavailable",
+                format = format.as_str(),
+                kind = kind.as_str()
+            )
+        );
+    }
+    if !source.supports(kind) {
+        bail!(
+            "{}",
+            t!(
+                "cli.export.source_unavailable",
+                kind = kind.as_str(),
+                source = source.as_str()
+            )
+        );
+    }
+    if format == ExportFormat::Parquet && params.output.is_none() {
+        bail!("{}", t!("cli.export.parquet_needs_output"));
+    }
+    if format == ExportFormat::Npy && params.output.is_none() {
+        bail!("{}", t!("cli.export.npy_needs_output"));
+    }
+    if params.neo4j_url.is_some() && kind != ExportKind::Triples {
+        bail!("{}", t!("cli.export.neo4j_triples_only"));
+    }
+
+    let filter = build_filter(&params)?;
+    let fields = split_list(params.fields.as_deref());
+    if !fields.is_empty() {
+        if matches!(
+            format,
+            ExportFormat::Turtle | ExportFormat::Cypher | ExportFormat::Npy
+        ) {
+            bail!(
+                "{}",
+                t!("cli.export.fields_unavailable", format = format.as_str())
+            );
+        }
+        check_fields(kind, &fields)?;
+    }
+
+    let count = match kind {
+        ExportKind::Index => {
+            let docs = load_index_documents(config, &filter, params.limit, true).await?;
+            write_output(&params.output, |w| write_fields(&docs, format, &fields, w))?;
+            docs.len()
+        }
+        ExportKind::Embeddings => {
+            let records = load_embeddings(config, &filter, params.limit).await?;
+            match (&params.output, format) {
+                (Some(path), ExportFormat::Npy) => {
+                    let dump = EmbeddingDump::from_records(&records)?;
+                    create_parent_dir(path)?;
+                    dump.write(path)?;
+                    say!(
+                        "{}",
+                        t!(
+                            "cli.export.npy_written",
+                            dim = dump.dim(),
+                            ids = ids_path(path).display()
+                        )
+                    );
+                }
+                _ => write_output(&params.output, |w| {
+                    write_fields(&records, format, &fields, w)
+                })?,
+            }
+            records.len()
+        }
+        ExportKind::Comments => {
+            let comments: Vec<CommentRecord> = if source == Source::Sqlite {
+                load_sqlite_articles(config, &params, &filter)?
+                    .into_iter()
+                    .flat_map(|article| article.comments)
+                    .collect()
+            } else {
+                load_database_articles(config, &filter, params.limit)
+                    .await?
+                    .iter()
+                    .flat_map(CommentRecord::from_article)
+                    .collect()
+            };
+            write_output(&params.output, |w| {
+                write_fields(&comments, format, &fields, w)
+            })?;
+            comments.len()
+        }
+        ExportKind::Articles => {
+            let articles = load_articles(config, source, &params, &filter).await?;
+            write_output(&params.output, |w| {
+                write_fields(&articles, format, &fields, w)
+            })?;
+            articles.len()
+        }
+        ExportKind::Triples => {
+            let articles = load_articles(config, source, &params, &filter).await?;
+            let extractor = RelationExtractor::new();
+            let stores: Vec<TripleStore> = articles
+                .iter()
+                .map(|record| {
+                    let article = record.to_parsed_article();
+                    let result = extractor.extract_from_article(&article);
+                    TripleStore::from_extraction(&result, &article.title)
+                        .with_published_at(article.published_at)
+                })
+                .collect();
+
+            if let Some(url) = &params.neo4j_url {
+                load_neo4j(&params, url, &stores).await?;
+                // The graph is the output; only write a file when asked to
+                if params.output.is_none() {
+                    return Ok(());
+                }
+            }
+
+            if format == ExportFormat::Turtle {
+                write_output(&params.output, |w| write_turtle(&stores, w))?;
+                stores.iter().map(|s| s.triples.len()).sum()
+            } else if format == ExportFormat::Cypher {
+                write_output(&params.output, |w| write_cypher(&stores, w))?;
+                stores.iter().map(|s| s.triples.len()).sum()
+            } else {
+                let triples: Vec<TripleRecord> =
+                    stores.iter().flat_map(TripleRecord::from_store).collect();
+                write_output(&params.output, |w| {
+                    write_fields(&triples, format, &fields, w)
+                })?;
+                triples.len()
+            }
+        }
+    };
+
+    tracing::info!(
+        what = kind.as_str(),
+        source = source.as_str(),
+        format = format.as_str(),
+        count,
+        "Export finished"
+    );
+    if let Some(path) = &params.output {
+        say!(
+            "{}",
+            t!(
+                "cli.export.done",
+                count = count,
+                kind = kind.as_str(),
+                source = source.as_str(),
+                path = path.display()
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Merge the triples into the Neo4j graph at `url`
+async fn load_neo4j(params: &ExportParams, url: &str, stores: &[TripleStore]) -> Result<()> {
+    let password = secrets::read_env("NEO4J_PASSWORD")?
+        .map(|(_, password)| password)
+        .unwrap_or_default();
+    let client = Neo4jClient::new(url, &params.neo4j_database, &params.neo4j_user, &password)?;
+    // Schema changes cannot share a transaction with writes
+    client.run(&constraint_statements()).await?;
+    let count = client.run(&triple_statements(stores)).await?;
+
+    tracing::info!(url, count, "Loaded triples into Neo4j");
+    say!(
+        "{}",
+        t!(
+            "cli.export.neo4j_done",
+            count = count,
+            url = url,
+            database = params.neo4j_database
+        )
+    );
+    Ok(())
+}
+
+fn build_filter(params: &ExportParams) -> Result<ExportFilter> {
+    let since = params
+        .since
+        .as_deref()
+        .map(|date| parse_day(date, "--since"))
+        .transpose()?;
+    let until = params
+        .until
+        .as_deref()
+        .map(|date| parse_day(date, "--until").map(|day| day + Duration::days(1)))
+        .transpose()?;
+    if let (Some(since), Some(until)) = (since, until) {
+        if since >= until {
+            bail!("{}", t!("cli.export.since_after_until"));
+        }
+    }
+
+    Ok(ExportFilter {
+        since,
+        until,
+        categories: split_list(params.category.as_deref()),
+    })
+}
+
+/// Non-empty items of a comma-separated list
+fn split_list(list: Option<&str>) -> Vec<String> {
+    list.map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Fail on selected fields that are not columns of the export target
+fn check_fields(kind: ExportKind, fields: &[String]) -> Result<()> {
+    match kind {
+        ExportKind::Articles => select_columns::<ArticleRecord>(fields),
+        ExportKind::Triples => select_columns::<TripleRecord>(fields),
+        ExportKind::Comments => select_columns::<CommentRecord>(fields),
+        ExportKind::Index => select_columns::<IndexDocument>(fields),
+        ExportKind::Embeddings => select_columns::<EmbeddingRecord>(fields),
+    }?;
+    Ok(())
+}
+
+fn parse_day(date: &str, flag: &str) -> Result<DateTime<Utc>> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("{flag}: {}", t!("cli.common.invalid_date", date = date)))?;
+    Ok(day
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc())
+}
+
+/// Write to the output file, or stdout if none is set
+fn write_output(
+    output: &Option<PathBuf>,
+    write: impl FnOnce(&mut (dyn Write + Send)) -> Result<()>,
+) -> Result<()> {
+    match output {
+        Some(path) => {
+            create_parent_dir(path)?;
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            let mut writer = BufWriter::new(file);
+            write(&mut writer)?;
+            writer.flush()?;
+        }
+        None => {
+            let mut writer = BufWriter::new(std::io::stdout());
+            write(&mut writer)?;
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Create the directory an output file goes in
+fn create_parent_dir(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    Ok(())
+}
+
+async fn load_articles(
+    config: &Config,
+    source: Source,
+    params: &ExportParams,
+    filter: &ExportFilter,
+) -> Result<Vec<ArticleRecord>> {
+    match source {
+        Source::Database => Ok(load_database_articles(config, filter, params.limit)
+            .await?
+            .iter()
+            .map(ArticleRecord::from)
+            .collect()),
+        Source::Sqlite => Ok(load_sqlite_articles(config, params, filter)?
+            .into_iter()
+            .map(|article| article.record)
+            .collect()),
+        Source::Markdown => load_markdown_articles(&params.input, filter, params.limit),
+        Source::OpenSearch => Ok(load_index_documents(config, filter, params.limit, false)
+            .await?
+            .iter()
+            .map(ArticleRecord::from)
+            .collect()),
+    }
+}
+
+async fn load_database_articles(
+    config: &Config,
+    filter: &ExportFilter,
+    limit: usize,
+) -> Result<Vec<Article>> {
+    let mut db = Database::new(&config.database)?;
+    db.init_postgres(&config.database.postgres_url)
+        .await
+        .context("Failed to connect to PostgreSQL")?;
+    let articles = db
+        .get_articles_since(filter.since.unwrap_or(DateTime::UNIX_EPOCH), 0)
+        .await
+        .context("Failed to load articles")?;
+
+    let mut articles: Vec<Article> = articles
+        .into_iter()
+        .filter(|a| filter.matches(a.published_at, a.category.as_deref().unwrap_or_default()))
+        .collect();
+    if limit > 0 {
+        articles.truncate(limit);
+    }
+    Ok(articles)
+}
+
+/// Article recorded in the SQLite crawl database
+struct CrawledArticle {
+    record: ArticleRecord,
+    comments: Vec<CommentRecord>,
+}
+
+/// Load the articles the SQLite crawl database records as crawled
+///
+/// Titles, bodies and publication times come from the article's markdown
+/// file in the input directory, when there is one; crawl times and comments
+/// come from the database.
+fn load_sqlite_articles(
+    config: &Config,
+    params: &ExportParams,
+    filter: &ExportFilter,
+) -> Result<Vec<CrawledArticle>> {
+    let path = params
+        .database
+        .clone()
+        .unwrap_or_else(|| crawl_db_path(&params.input));
+    if !path.exists() {
+        bail!(
+            "{} {}",
+            t!("cli.stats.not_found", path = path.display()),
+            t!("cli.stats.run_crawl_first")
+        );
+    }
+    let mut db = Database::new(&config.database)?;
+    db.init_sqlite(&path)?;
+
+    let markdown: HashMap<String, ArticleRecord> = if params.input.is_dir() {
+        load_markdown_articles(&params.input, &ExportFilter::default(), 0)?
+            .into_iter()
+            .map(|record| (record.id.clone(), record))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let mut articles = Vec::new();
+    for crawl in db.get_successful_crawls()? {
+        let mut record = markdown
+            .get(&crawl.id)
+            .cloned()
+            .unwrap_or_else(|| record_without_markdown(&crawl.id, &crawl.url));
+        record.crawled_at = Some(crawl.crawled_at);
+        if !filter.matches(record.published_at, &record.category) {
+            continue;
+        }
+
+        let mut article = record.to_parsed_article();
+        let mut comments = Vec::new();
+        if db.load_article_details(&mut article)? {
+            record.comment_count = Some(article.comment_count() as i64);
+            comments = CommentRecord::from_article(&Article::from(&article));
+            for comment in &mut comments {
+                comment.article_id.clone_from(&record.id);
+            }
+        }
+
+        articles.push(CrawledArticle { record, comments });
+        if params.limit > 0 && articles.len() >= params.limit {
+            break;
+        }
+    }
+    Ok(articles)
+}
+
+/// Article known only by its crawl record
+fn record_without_markdown(id: &str, url: &str) -> ArticleRecord {
+    let (source, source_id) = ArticleSource::split_article_id(id);
+    let (oid, aid) = match source {
+        ArticleSource::Naver => source_id.split_once('_').unwrap_or_default(),
+        _ => ("", ""),
+    };
+    ArticleRecord {
+        id: id.to_string(),
+        source,
+        oid: oid.to_string(),
+        aid: aid.to_string(),
+        title: String::new(),
+        content: String::new(),
+        url: url.to_string(),
+        category: String::new(),
+        publisher: None,
+        author: None,
+        published_at: None,
+        crawled_at: None,
+        comment_count: None,
+    }
+}
+
+fn load_markdown_articles(
+    input: &PathBuf,
+    filter: &ExportFilter,
+    limit: usize,
+) -> Result<Vec<ArticleRecord>> {
+    if !input.is_dir() {
+        bail!(
+            "{}",
+            t!("cli.common.markdown_dir_not_found", path = input.display())
+        );
+    }
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(input)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    let mut articles = Vec::new();
+    for path in paths {
+        let doc = match parse_markdown_to_document(&path) {
+            Ok(doc) => doc,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to parse markdown");
+                continue;
+            }
+        };
+        let mut record = ArticleRecord::from(&doc);
+        // The parser stamps the parse time; markdown files carry no crawl time
+        record.crawled_at = None;
+
+        if filter.matches(record.published_at, &record.category) {
+            articles.push(record);
+            if limit > 0 && articles.len() >= limit {
+                break;
+            }
+        }
+    }
+    Ok(articles)
+}
+
+/// Load index documents
+///
+/// Embeddings are only fetched when `with_embeddings` is set.
+async fn load_index_documents(
+    config: &Config,
+    filter: &ExportFilter,
+    limit: usize,
+    with_embeddings: bool,
+) -> Result<Vec<IndexDocument>> {
+    let source = if with_embeddings {
+        serde_json::json!(true)
+    } else {
+        serde_json::json!({"excludes": ["embedding"]})
+    };
+
+    let mut docs = Vec::new();
+    scan_index(config, filter, limit, source, |hit| {
+        let mut source = hit["_source"].clone();
+        if source.get("embedding").is_none() {
+            source["embedding"] = serde_json::json!([]);
+        }
+        match serde_json::from_value::<IndexDocument>(source) {
+            Ok(doc) => {
+                docs.push(doc);
+                true
+            }
+            Err(e) => {
+                tracing::warn!(id = %hit["_id"], error = %e, "Skipping unreadable document");
+                false
+            }
+        }
+    })
+    .await?;
+    Ok(docs)
+}
+
+/// Load document embeddings
+///
+/// Documents without an embedding, or with the all-zero placeholder indexed
+/// while the embedding server was down, are left out.
+async fn load_embeddings(
+    config: &Config,
+    filter: &ExportFilter,
+    limit: usize,
+) -> Result<Vec<EmbeddingRecord>> {
+    let mut records = Vec::new();
+    let source = serde_json::json!({"includes": ["embedding"]});
+    scan_index(config, filter, limit, source, |hit| {
+        let embedding: Vec<f32> =
+            serde_json::from_value(hit["_source"]["embedding"].clone()).unwrap_or_default();
+        let (Some(id), true) = (hit["_id"].as_str(), embedding.iter().any(|v| *v != 0.0)) else {
+            return false;
+        };
+        records.push(EmbeddingRecord {
+            id: id.to_string(),
+            embedding,
+        });
+        true
+    })
+    .await?;
+    Ok(records)
+}
+
+/// Page through the OpenSearch index with `search_after`
+///
+/// Each hit, with the `_source` fields selected by `source`, goes to
+/// `visit`, which returns whether it kept it. Stops after `limit` kept hits
+/// (0 = unlimited). Returns the number kept.
+async fn scan_index(
+    config: &Config,
+    filter: &ExportFilter,
+    limit: usize,
+    source: serde_json::Value,
+    mut visit: impl FnMut(&serde_json::Value) -> bool,
+) -> Result<usize> {
+    let opensearch_config = config.opensearch.clone().with_env_overrides();
+    let store = VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
+
+    let mut must = Vec::new();
+    if !filter.categories.is_empty() {
+        must.push(serde_json::json!({"terms": {"category": filter.categories}}));
+    }
+    if filter.since.is_some() || filter.until.is_some() {
+        let mut range = serde_json::Map::new();
+        if let Some(since) = filter.since {
+            range.insert("gte".to_string(), since.to_rfc3339().into());
+        }
+        if let Some(until) = filter.until {
+            range.insert("lt".to_string(), until.to_rfc3339().into());
+        }
+        must.push(serde_json::json!({"range": {"published_at": range}}));
+    }
+
+    let mut kept = 0;
+    let mut search_after: Option<serde_json::Value> = None;
+    loop {
+        let page_size = if limit > 0 {
+            OPENSEARCH_PAGE_SIZE.min(limit - kept)
+        } else {
+            OPENSEARCH_PAGE_SIZE
+        };
+        let mut query = serde_json::json!({
+            "size": page_size,
+            "sort": [{"_id": "asc"}],
+            "query": {"bool": {"must": must}},
+            "_source": source,
+        });
+        if let Some(after) = &search_after {
+            query["search_after"] = after.clone();
+        }
+
+        let response = store
+            .raw_search(&query)
+            .await
+            .context("Failed to fetch documents from OpenSearch")?;
+        let hits = response["hits"]["hits"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        if hits.is_empty() {
+            break;
+        }
+        search_after = hits.last().map(|hit| hit["sort"].clone());
+
+        for hit in &hits {
+            if visit(hit) {
+                kept += 1;
+                if limit > 0 && kept >= limit {
+                    return Ok(kept);
+                }
+            }
+        }
+        tracing::debug!(loaded = kept, "Loading index documents...");
+    }
+    Ok(kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn params(what: &str) -> ExportParams {
+        ExportParams {
+            what: what.to_string(),
+            format: "jsonl".to_string(),
+            source: None,
+            input: PathBuf::from("./output/raw"),
+            database: None,
+            output: None,
+            since: Some("2026-02-10".to_string()),
+            until: Some("2026-02-10".to_string()),
+            category: Some("economy, it,".to_string()),
+            limit: 0,
+            fields: None,
+            neo4j_url: None,
+            neo4j_database: "neo4j".to_string(),
+            neo4j_user: "neo4j".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_source_defaults_and_support() {
+        assert_eq!(
+            Source::parse(None, ExportKind::Comments).unwrap(),
+            Source::Database
+        );
+        assert_eq!(
+            Source::parse(None, ExportKind::Index).unwrap(),
+            Source::OpenSearch
+        );
+        assert_eq!(
+            Source::parse(Some("MD"), ExportKind::Articles).unwrap(),
+            Source::Markdown
+        );
+        assert!(Source::parse(Some("redis"), ExportKind::Articles).is_err());
+        assert!(!Source::Markdown.supports(ExportKind::Comments));
+        assert!(Source::Sqlite.supports(ExportKind::Comments));
+        assert!(!Source::Database.supports(ExportKind::Index));
+        assert!(Source::OpenSearch.supports(ExportKind::Triples));
+    }
+
+    #[test]
+    fn test_build_filter() {
+        let filter = build_filter(&params("articles")).unwrap();
+        let day = |d| Utc.with_ymd_and_hms(2026, 2, d, 0, 0, 0).unwrap();
+        assert_eq!(filter.since, Some(day(10)));
+        // --until includes the whole day
+        assert_eq!(filter.until, Some(day(11)));
+        assert_eq!(filter.categories, vec!["economy", "it"]);
+
+        let mut reversed = params("articles");
+        reversed.since = Some("2026-02-12".to_string());
+        assert!(build_filter(&reversed).is_err());
+
+        let mut invalid = params("articles");
+        invalid.until = Some("02/10/2026".to_string());
+        assert!(build_filter(&invalid).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_export_markdown_articles() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("raw");
+        std::fs::create_dir_all(&input).unwrap();
+        for (aid, category, date) in [
+            ("0000000001", "economy", "2026-02-10 09:00"),
+            ("0000000002", "politics", "2026-02-10 10:00"),
+            ("0000000003", "economy", "2026-02-12 09:00"),
+        ] {
+            std::fs::write(
+                input.join(format!("001_{aid}.md")),
+                format!(
+                    "---\noid: \"001\"\naid: \"{aid}\"\ncategory: \"{category}\"\npublished_at: \"{date}\"\n---\n\n# 기사 {aid}\n\n본문\n"
+                ),
+            )
+            .unwrap();
+        }
+
+        let output = dir.path().join("out/articles.csv");
+        let mut export_params = params("articles");
+        export_params.format = "csv".to_string();
+        export_params.input = input;
+        export_params.output = Some(output.clone());
+        export(&Config::default(), export_params).await.unwrap();
+
+        let mut reader = csv::Reader::from_path(output).unwrap();
+        let headers = reader.headers().unwrap().clone();
+        assert_eq!(
+            headers.iter().take(5).collect::<Vec<_>>(),
+            ["id", "source", "oid", "aid", "title"]
+        );
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(&rows[0][0], "001_0000000001");
+        assert_eq!(&rows[0][1], "naver");
+        assert_eq!(&rows[0][10], "2026-02-10T09:00:00+00:00");
+    }
+
+    #[tokio::test]
+    async fn test_export_sqlite_articles_and_comments() {
+        use baram::crawler::comment::Comment;
+        use baram::models::ParsedArticle;
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("raw");
+        std::fs::create_dir_all(&input).unwrap();
+        std::fs::write(
+            input.join("001_0000000001_a.md"),
+            "---\noid: \"001\"\naid: \"0000000001\"\ncategory: \"economy\"\npublished_at: \"2026-02-10 09:00\"\n---\n\n# 기사 1\n\n본문\n",
+        )
+        .unwrap();
+
+        let mut db = Database::new(&Config::default().database).unwrap();
+        db.init_sqlite(&dir.path().join("crawl.db")).unwrap();
+        for aid in ["0000000001", "0000000002"] {
+            let mut article = ParsedArticle {
+                oid: "001".to_string(),
+                aid: aid.to_string(),
+                url: format!("https://n.news.naver.com/mnews/article/001/{aid}"),
+                ..Default::default()
+            };
+            if aid == "0000000001" {
+                article.comments = vec![Comment {
+                    id: "c1".to_string(),
+                    parent_id: None,
+                    content: "댓글".to_string(),
+                    author: "reader".to_string(),
+                    author_id: "abc****".to_string(),
+                    created_at: Utc::now(),
+                    modified_at: None,
+                    likes: 0,
+                    dislikes: 0,
+                    reply_count: 0,
+                    is_best: false,
+                    is_deleted: false,
+                    replies: vec![],
+                }];
+            }
+            db.record_success(&article).unwrap();
+        }
+        drop(db);
+
+        let output = dir.path().join("articles.csv");
+        let mut articles = params("articles");
+        articles.source = Some("sqlite".to_string());
+        articles.format = "csv".to_string();
+        articles.input = input.clone();
+        articles.output = Some(output.clone());
+        articles.since = None;
+        articles.until = None;
+        articles.category = None;
+        articles.fields = Some("id, title, comment_count".to_string());
+        export(&Config::default(), articles).await.unwrap();
+
+        let csv = std::fs::read_to_string(&output).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            [
+                "id,title,comment_count",
+                "001_0000000001,기사 1,1",
+                "001_0000000002,,"
+            ]
+        );
+
+        let output = dir.path().join("comments.jsonl");
+        let mut comments = params("comments");
+        comments.source = Some("sqlite".to_string());
+        comments.input = input;
+        comments.output = Some(output.clone());
+        comments.since = None;
+        comments.until = None;
+        comments.category = None;
+        comments.fields = Some("article_id,text".to_string());
+        export(&Config::default(), comments).await.unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&output).unwrap().trim(),
+            r#"{"article_id":"001_0000000001","text":"댓글"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_invalid_combinations() {
+        let mut turtle = params("articles");
+        turtle.format = "turtle".to_string();
+        assert!(export(&Config::default(), turtle).await.is_err());
+
+        let mut comments = params("comments");
+        comments.source = Some("markdown".to_string());
+        assert!(export(&Config::default(), comments).await.is_err());
+
+        let mut parquet = params("articles");
+        parquet.format = "parquet".to_string();
+        assert!(export(&Config::default(), parquet).await.is_err());
+
+        let mut unknown_field = params("articles");
+        unknown_field.fields = Some("id,headline".to_string());
+        assert!(export(&Config::default(), unknown_field).await.is_err());
+    }
+}
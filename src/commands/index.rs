@@ -1,62 +1,197 @@
 use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use rust_i18n::t;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
-use std::time::SystemTime;
-
-use baram::config::OpenSearchConfig;
-use baram::embedding::VectorStore;
-use baram::storage::checkpoint::CheckpointManager;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::mpsc;
+
+use baram::cache::{CacheConfig, OptionalCache};
+use baram::clustering::stories::parse_timestamp;
+use baram::clustering::{StoryConfig, StoryTracker};
+use baram::config::Config;
+use baram::embedding::{
+    ChunkConfig, Embedder, EmbeddingConfig, IndexDocument, TextChunk, TextTokenizer, VectorStore,
+};
+use baram::export::npy::EmbeddingDump;
+use baram::metrics;
+use baram::metrics::push::JobMetrics;
+use baram::models::NewsCategory;
+use baram::storage::{
+    article_id_from_path, parse_frontmatter_date, Frontmatter, IndexProgress, IndexRecord,
+    MarkdownArticle, MarkdownReader,
+};
 use baram::utils::retry::{with_retry, RetryConfig};
 
-#[derive(Serialize, Deserialize, Clone)]
-struct IndexCheckpoint {
-    last_processed_batch: usize,
-    total_success: usize,
-    total_failed: usize,
-    processed_doc_ids: std::collections::HashSet<String>,
+use super::output::say;
+use super::progress::{Progress, ProgressTask};
+use super::shutdown::Shutdown;
+use super::{print_cache_stats, print_title};
+
+/// Embedding dimension of the index (and of placeholder embeddings)
+pub(crate) const EMBEDDING_DIM: usize = 384;
+
+/// Parameters for `baram index`
+pub struct IndexParams {
+    /// Markdown file or directory to index
+    pub input: String,
+    /// Documents per bulk request
+    pub batch_size: usize,
+    /// Recreate the index first
+    pub force: bool,
+    /// Only index files modified after this time
+    pub since: Option<String>,
+    /// Embedding dump (`baram export --what embeddings --format npy`) whose
+    /// vectors are used instead of embedding those articles again
+    pub embeddings: Option<PathBuf>,
+    /// Batches embedded and bulk indexed at once; also the number of
+    /// batches each pipeline stage may hold before the previous one waits
+    pub parallelism: usize,
+    /// Embedding source: `local`, `server` or `none`
+    pub embedder: String,
+    /// HuggingFace model loaded by the `local` embedder
+    pub embedding_model: String,
+    /// Texts per forward pass of the `local` embedder
+    pub embed_batch_size: usize,
+    /// Recreate an existing index whose embedding dimension differs from
+    /// the embedder's, instead of failing
+    pub migrate_index: bool,
+    /// Split articles longer than this many tokens into chunks embedded and
+    /// indexed on their own; 0 embeds whole articles
+    pub chunk_tokens: usize,
+    /// Tokens each chunk repeats from the end of the previous one
+    pub chunk_overlap: usize,
+    /// Cache embeddings in Redis (`REDIS_URL`), and invalidate cached
+    /// search results once articles are indexed
+    pub cache: bool,
+    /// Stops reading files once requested; batches already read are still
+    /// indexed and recorded
+    pub shutdown: Shutdown,
 }
 
-/// Extract document ID ({oid}_{aid}) from markdown filename.
-///
-/// Filename format: {oid}_{aid}_{sanitized_title}.md
-/// Both oid and aid are purely numeric.
-fn extract_doc_id_from_filename(path: &std::path::Path) -> Option<String> {
-    let stem = path.file_stem()?.to_str()?;
-    let mut parts = stem.splitn(3, '_');
-    let oid = parts.next()?;
-    let aid = parts.next()?;
-
-    if oid.chars().all(|c| c.is_ascii_digit()) && aid.chars().all(|c| c.is_ascii_digit()) {
-        Some(format!("{oid}_{aid}"))
-    } else {
-        None
+/// Where `baram index` gets document embeddings from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedderKind {
+    /// A model loaded in-process
+    Local,
+    /// The embedding server at `EMBEDDING_SERVER_URL`
+    Server,
+    /// No embeddings; documents are indexed for keyword search only
+    None,
+}
+
+impl EmbedderKind {
+    /// Parse `local`, `server` or `none`
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "local" => Ok(Self::Local),
+            "server" => Ok(Self::Server),
+            "none" => Ok(Self::None),
+            other => anyhow::bail!("{}", t!("cli.index.unknown_embedder", embedder = other)),
+        }
     }
 }
 
 pub async fn index(
-    input: String,
-    batch_size: usize,
-    force: bool,
-    since: Option<String>,
+    config: &Config,
+    params: IndexParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
 ) -> Result<()> {
     use std::fs;
 
-    println!("Indexing articles from: {input}");
-    println!("================================");
-
-    // Initialize checkpoint manager
-    let checkpoint_dir = PathBuf::from("./checkpoints");
-    let checkpoint_mgr = CheckpointManager::with_interval(&checkpoint_dir, 10)?;
+    let IndexParams {
+        input,
+        batch_size,
+        force,
+        since,
+        embeddings,
+        parallelism,
+        embedder,
+        embedding_model,
+        embed_batch_size,
+        migrate_index,
+        chunk_tokens,
+        chunk_overlap,
+        cache,
+        shutdown,
+    } = params;
+    let embedder_kind = EmbedderKind::parse(&embedder)?;
+
+    print_title(&t!("cli.index.starting", input = input), '=');
+
+    let dump_dim = embeddings
+        .as_deref()
+        .map(EmbeddingDump::read_dim)
+        .transpose()?;
+
+    // The embedding source comes first: its dimension decides how the index
+    // is created, and whether an existing one fits
+    let client = reqwest::Client::new();
+    let embedding_server_url = std::env::var("EMBEDDING_SERVER_URL")
+        .unwrap_or_else(|_| "http://localhost:8090".to_string());
+    let (source, model_dim) = embedding_source(
+        embedder_kind,
+        &embedding_model,
+        embed_batch_size,
+        &client,
+        &embedding_server_url,
+    )
+    .await?;
+    if let (Some(dump), Some(dim)) = (dump_dim, model_dim) {
+        if dump != dim {
+            let source = match embedder_kind {
+                EmbedderKind::Local => embedding_model.as_str(),
+                _ => embedding_server_url.as_str(),
+            };
+            anyhow::bail!(
+                "{}",
+                t!(
+                    "cli.index.embedding_dim_mismatch",
+                    dump = dump,
+                    source = source,
+                    dim = dim
+                )
+            );
+        }
+    }
+    let wanted_dim = model_dim.or(dump_dim);
+    let imported = match &embeddings {
+        Some(path) => load_embeddings(path, wanted_dim)?,
+        None => HashMap::new(),
+    };
+    let use_embeddings = !matches!(source, EmbeddingSource::None);
+
+    // Chunks only pay off when they get vectors of their own
+    let chunker = if chunk_tokens > 0 && use_embeddings {
+        let overlap = chunk_overlap.min(chunk_tokens / 2);
+        let chunker = Chunker::load(
+            &embedding_model,
+            ChunkConfig {
+                max_tokens: chunk_tokens,
+                overlap_tokens: overlap,
+                ..Default::default()
+            },
+        )
+        .await?;
+        say!(
+            "{}",
+            t!(
+                "cli.index.chunking",
+                tokens = chunk_tokens,
+                overlap = overlap,
+                model = embedding_model
+            )
+        );
+        Some(chunker)
+    } else {
+        None
+    };
 
     // Create OpenSearch client
-    let opensearch_config = OpenSearchConfig {
-        url: std::env::var("OPENSEARCH_URL")
-            .unwrap_or_else(|_| "http://localhost:9200".to_string()),
-        index_name: std::env::var("OPENSEARCH_INDEX")
-            .unwrap_or_else(|_| "baram-articles".to_string()),
-        username: std::env::var("OPENSEARCH_USER").ok(),
-        password: std::env::var("OPENSEARCH_PASSWORD").ok(),
-    };
+    let opensearch_config = config.opensearch.clone().with_env_overrides();
 
     let store = VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
 
@@ -65,54 +200,49 @@ pub async fn index(
 
     let input_path = PathBuf::from(&input);
     if !input_path.exists() {
-        anyhow::bail!("Input path does not exist: {input}");
+        anyhow::bail!("{}", t!("cli.common.input_not_found", path = input));
     }
-
-    let checkpoint_name = format!(
-        "index_{}",
-        input_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-    );
-
-    // Load checkpoint BEFORE file scanning (key optimization)
-    let mut checkpoint_state: IndexCheckpoint =
-        checkpoint_mgr
-            .load(&checkpoint_name)?
-            .unwrap_or(IndexCheckpoint {
-                last_processed_batch: 0,
-                total_success: 0,
-                total_failed: 0,
-                processed_doc_ids: std::collections::HashSet::new(),
-            });
-
-    if !index_exists {
-        println!("Creating index '{}'...", opensearch_config.index_name);
-        store
-            .create_index(384)
-            .await
-            .context("Failed to create index")?;
-        println!("Index created successfully.");
-    } else if force {
-        println!("Force reindex: deleting existing index...");
+    // Progress is keyed by absolute path, whatever directory we run from
+    let input_path = input_path.canonicalize()?;
+
+    // Per-file progress of this index, shared by all runs
+    let mut index_progress =
+        IndexProgress::open(&config.database.sqlite_path, &opensearch_config.index_name)
+            .context("Failed to open index progress")?;
+
+    // Dimension of the index, and of placeholder embeddings
+    let embedding_dim = if index_exists && force {
+        let embedding_dim = wanted_dim.unwrap_or(EMBEDDING_DIM);
+        say!("{}", t!("cli.index.force"));
         store.delete_index().await?;
-        store.create_index(384).await?;
-        // Clear checkpoint for force reindex
-        checkpoint_mgr.delete(&checkpoint_name)?;
-        checkpoint_state = IndexCheckpoint {
-            last_processed_batch: 0,
-            total_success: 0,
-            total_failed: 0,
-            processed_doc_ids: std::collections::HashSet::new(),
-        };
-        println!("Index recreated, checkpoint cleared.");
+        store.create_index(embedding_dim).await?;
+        index_progress.clear()?;
+        say!("{}", t!("cli.index.recreated"));
+        embedding_dim
     } else {
-        println!("Index '{}' already exists.", opensearch_config.index_name);
-    }
+        if index_exists {
+            say!(
+                "{}",
+                t!("cli.index.exists", index = opensearch_config.index_name)
+            );
+        }
+        let opened = open_index(
+            &store,
+            &opensearch_config.index_name,
+            wanted_dim,
+            migrate_index,
+        )
+        .await?;
+        // Anything recorded belongs to an index that no longer exists, or
+        // is embedded again with the new model
+        if !matches!(opened, OpenedIndex::Existing(_)) {
+            index_progress.clear()?;
+        }
+        opened.dim()
+    };
 
     // Parse --since filter
-    let since_time: Option<SystemTime> = if let Some(since_str) = &since {
+    let since_time: Option<i64> = if let Some(since_str) = &since {
         let naive = if since_str.contains('T') {
             chrono::NaiveDateTime::parse_from_str(since_str, "%Y-%m-%dT%H:%M:%S")
                 .context("Invalid --since format. Expected YYYY-MM-DDTHH:MM:SS")?
@@ -122,245 +252,826 @@ pub async fn index(
                 .and_hms_opt(0, 0, 0)
                 .unwrap()
         };
-        let dt: chrono::DateTime<chrono::Utc> =
-            chrono::DateTime::from_naive_utc_and_offset(naive, chrono::Utc);
-        Some(dt.into())
+        Some(naive.and_utc().timestamp())
     } else {
         None
     };
 
-    // Collect markdown files with pre-filtering
-    let mut documents: Vec<baram::embedding::IndexDocument> = Vec::new();
-
-    if input_path.is_dir() {
-        let entries: Vec<_> = fs::read_dir(&input_path)?
+    // Collect markdown files with their modification times
+    let files: Vec<SourceFile> = if input_path.is_dir() {
+        fs::read_dir(&input_path)?
             .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().map(|ext| ext == "md").unwrap_or(false))
-            .collect();
-
-        let total_files = entries.len();
-
-        // Pre-filter by --since (file mtime)
-        let time_filtered: Vec<_> = match &since_time {
-            None => entries,
-            Some(since) => entries
-                .into_iter()
-                .filter(|e| {
-                    e.metadata()
-                        .ok()
-                        .and_then(|m| m.modified().ok())
-                        .map(|mtime| mtime >= *since)
-                        .unwrap_or(true)
-                })
-                .collect(),
-        };
+            .map(|e| e.path())
+            .filter(|path| path.extension().map(|ext| ext == "md").unwrap_or(false))
+            .map(SourceFile::new)
+            .collect()
+    } else {
+        vec![SourceFile::new(input_path.clone())]
+    };
+    let total_files = files.len();
+
+    // Pre-filter by --since (file mtime)
+    let time_filtered: Vec<SourceFile> = match since_time {
+        None => files,
+        Some(since) => files
+            .into_iter()
+            .filter(|file| file.modified == 0 || file.modified >= since)
+            .collect(),
+    };
+    let after_time_filter = time_filtered.len();
 
-        let after_time_filter = time_filtered.len();
+    // Skip files already indexed unless they changed since
+    let indexed = index_progress.indexed_files()?;
+    let unprocessed: Vec<SourceFile> = time_filtered
+        .into_iter()
+        .filter(|file| indexed.get(&file.key()) != Some(&file.modified))
+        .collect();
+    let skipped_by_progress = after_time_filter - unprocessed.len();
 
-        // Pre-filter by checkpoint: extract ID from filename, skip already indexed
-        let unprocessed: Vec<_> = if checkpoint_state.processed_doc_ids.is_empty() {
-            time_filtered
+    if input_path.is_dir() {
+        let found = t!(
+            "cli.index.found_files",
+            count = total_files,
+            new_count = unprocessed.len(),
+            indexed = skipped_by_progress
+        );
+        if since.is_some() {
+            say!(
+                "{found} {}",
+                t!(
+                    "cli.index.filtered_since",
+                    count = total_files - after_time_filter
+                )
+            );
         } else {
-            time_filtered
-                .into_iter()
-                .filter(|entry| {
-                    match extract_doc_id_from_filename(&entry.path()) {
-                        Some(id) => !checkpoint_state.processed_doc_ids.contains(&id),
-                        None => true, // can't extract ID → parse it anyway
-                    }
-                })
-                .collect()
-        };
+            say!("{found}");
+        }
+    }
 
-        let skipped_by_checkpoint = after_time_filter - unprocessed.len();
-
-        println!(
-            "Found {} markdown files ({} new, {} already indexed{})",
-            total_files,
-            unprocessed.len(),
-            skipped_by_checkpoint,
-            if since.is_some() {
-                format!(", {} filtered by --since", total_files - after_time_filter)
-            } else {
-                String::new()
-            }
-        );
+    if !indexed.is_empty() {
+        say!("{}", t!("cli.index.resuming", count = indexed.len()));
+    }
 
-        if checkpoint_state.last_processed_batch > 0 {
-            println!(
-                "Resuming from checkpoint: {} documents previously indexed",
-                checkpoint_state.processed_doc_ids.len()
-            );
+    if unprocessed.is_empty() {
+        say!("{}", t!("cli.index.nothing_new"));
+        return Ok(());
+    }
+
+    // Story assignment is incremental, so feed the files in publication
+    // order
+    let reader = MarkdownReader::new();
+    let mut files: Vec<(i64, SourceFile)> = unprocessed
+        .into_iter()
+        .map(|file| (file.published(&reader), file))
+        .collect();
+    files.sort_by_key(|(published, _)| *published);
+    let files: Vec<SourceFile> = files.into_iter().map(|(_, file)| file).collect();
+    let to_index = files.len();
+    let parallelism = parallelism.max(1);
+
+    say!(
+        "{}",
+        t!(
+            "cli.index.indexing",
+            count = to_index,
+            batch_size = batch_size,
+            parallelism = parallelism
+        )
+    );
+
+    // Continue the stories of earlier runs
+    let mut stories = StoryTracker::new(StoryConfig::default());
+    if use_embeddings || !imported.is_empty() {
+        match stories.load_from_store(&store, chrono::Utc::now()).await {
+            Ok(loaded) => tracing::info!(
+                documents = loaded,
+                stories = stories.len(),
+                "Loaded open stories"
+            ),
+            Err(e) => tracing::warn!(error = %e, "Failed to load open stories, starting fresh"),
         }
+    }
 
-        // Parallel file parsing with buffer_unordered
-        {
-            use futures::stream::{self, StreamExt};
-            let paths: Vec<PathBuf> = unprocessed.iter().map(|e| e.path()).collect();
-            let concurrency = std::thread::available_parallelism()
-                .map(|n| n.get().min(8))
-                .unwrap_or(4);
-
-            let results: Vec<_> = stream::iter(paths)
-                .map(|path| {
-                    tokio::task::spawn_blocking(move || {
-                        let res = parse_markdown_to_document(&path);
-                        (path, res)
-                    })
-                })
-                .buffer_unordered(concurrency)
-                .collect()
-                .await;
+    let cache = if cache {
+        OptionalCache::from_config(&CacheConfig::from_env()?).await
+    } else {
+        OptionalCache::new(None)
+    };
+
+    let retry_config = RetryConfig::with_delays(2, 1000, 5000);
+    let embedder = BatchEmbedder {
+        client: &client,
+        source,
+        model: &embedding_model,
+        chunker: chunker.as_ref(),
+        cache: &cache,
+        config,
+        imported: &imported,
+        retry_config: &retry_config,
+    };
 
-            for result in results {
-                match result {
-                    Ok((_, Ok(doc))) => documents.push(doc),
-                    Ok((path, Err(e))) => {
-                        tracing::warn!(path = %path.display(), error = %e, "Failed to parse markdown");
+    // Files are parsed, embedded and bulk indexed in a pipeline of bounded
+    // channels: a stage that falls behind holds back the stages before it,
+    // so at most a few batches per stage are in memory at any time.
+    let (parsed_tx, mut parsed_rx) = mpsc::channel::<DocumentBatch>(parallelism);
+    let (embedded_tx, mut embedded_rx) = mpsc::channel::<DocumentBatch>(parallelism);
+    let batch_task = progress.task("index", "documents", to_index as u64);
+    let fail_fast = !input_path.is_dir();
+
+    let read = read_documents(
+        files,
+        batch_size,
+        fail_fast,
+        &shutdown,
+        parsed_tx,
+        &batch_task,
+    );
+
+    // Embeddings are requested concurrently, stories assigned in file order
+    let embed = async {
+        let mut imported_count = 0usize;
+        let mut stories_assigned = 0usize;
+        let (mut chunked_articles, mut chunk_count) = (0usize, 0usize);
+        let mut embedded = stream::poll_fn(|cx| parsed_rx.poll_recv(cx))
+            .map(|batch| embedder.embed(batch))
+            .buffered(parallelism);
+        while let Some((mut batch, vectors, imported)) = embedded.next().await {
+            imported_count += imported;
+            let DocumentBatch { docs, chunks, .. } = &mut batch;
+            for ((doc, chunks), emb) in docs.iter_mut().zip(chunks.iter_mut()).zip(vectors) {
+                if let Some(emb) = emb {
+                    doc.story_id = stories.assign(&doc.id, &emb, document_time(doc));
+                    if doc.story_id.is_some() {
+                        stories_assigned += 1;
                     }
-                    Err(e) => {
-                        tracing::warn!(error = %e, "Parse task panicked");
+                    doc.embedding = emb;
+                } else if doc.embedding.len() != embedding_dim {
+                    // The placeholder must fit the index mapping
+                    doc.embedding = vec![0.0; embedding_dim];
+                }
+                if !chunks.is_empty() {
+                    chunked_articles += 1;
+                    chunk_count += chunks.len();
+                }
+                for chunk in chunks.iter_mut() {
+                    chunk.story_id.clone_from(&doc.story_id);
+                    if chunk.embedding.len() != embedding_dim {
+                        chunk.embedding = vec![0.0; embedding_dim];
                     }
                 }
             }
+            if embedded_tx.send(batch).await.is_err() {
+                break;
+            }
         }
-    } else {
-        // Single file
-        documents.push(parse_markdown_to_document(&input_path)?);
-    }
+        drop(embedded_tx);
+        anyhow::Ok((
+            imported_count,
+            stories_assigned,
+            chunked_articles,
+            chunk_count,
+        ))
+    };
 
-    if documents.is_empty() {
-        println!("No new documents to index.");
+    let bulk = async {
+        let mut success = 0usize;
+        let mut failed = 0usize;
+        let (store, retry_config) = (&store, &retry_config);
+        let mut results = stream::poll_fn(|cx| embedded_rx.poll_recv(cx))
+            .map(|batch| async move {
+                let result = {
+                    let docs = batch.index_documents();
+                    with_retry(retry_config, || async { store.bulk_index(&docs).await }).await
+                };
+                (batch, result)
+            })
+            .buffer_unordered(parallelism);
+        while let Some((batch, result)) = results.next().await {
+            let result = result?;
+            success += result.success;
+            failed += result.failed;
+
+            // Record the batch before moving on, so an interrupted run resumes here.
+            // An article fails if any of its chunks does.
+            let rejected = |doc: &IndexDocument| result.failed_ids.contains(&doc.doc_id());
+            let records: Vec<IndexRecord> = batch
+                .docs
+                .iter()
+                .zip(&batch.sources)
+                .zip(&batch.chunks)
+                .map(|((doc, file), chunks)| {
+                    if rejected(doc) || chunks.iter().any(rejected) {
+                        IndexRecord::failed(
+                            file.key(),
+                            file.modified,
+                            Some(doc.id.clone()),
+                            "rejected by OpenSearch",
+                        )
+                    } else {
+                        IndexRecord::indexed(file.key(), file.modified, doc.id.clone())
+                    }
+                })
+                .collect();
+            index_progress.record(&records)?;
+
+            // Print errors if any
+            if !result.errors.is_empty() {
+                eprintln!("\n{}", t!("cli.index.batch_errors", batch = batch.number));
+                for (i, err) in result.errors.iter().take(3).enumerate() {
+                    eprintln!("  {}: {}", i + 1, err);
+                }
+                if result.errors.len() > 3 {
+                    eprintln!(
+                        "  {}",
+                        t!("cli.index.more_errors", count = result.errors.len() - 3)
+                    );
+                }
+            }
+            batch_task.inc(batch.docs.len() as u64);
+        }
+        anyhow::Ok((success, failed))
+    };
+
+    // Batches recorded so far are kept if the grace period runs out
+    let Some(indexed) = shutdown
+        .with_grace(async { tokio::try_join!(read, embed, bulk) })
+        .await
+    else {
+        batch_task.finish();
+        job.set("interrupted", 1);
+        say!("\n{}", t!("cli.common.interrupted"));
         return Ok(());
+    };
+    let (
+        parse_failures,
+        (total_imported, total_stories_assigned, total_chunked, total_chunks),
+        (total_success, total_failed),
+    ) = indexed?;
+    batch_task.finish();
+
+    // Recorded so they are retried on the next run
+    index_progress.record(&parse_failures)?;
+    job.set("documents", (to_index - parse_failures.len()) as u64);
+
+    job.set("indexed", total_success as u64);
+    job.set("failed", total_failed as u64);
+    job.set("stories_assigned", total_stories_assigned as u64);
+    if embeddings.is_some() {
+        job.set("embeddings_imported", total_imported as u64);
+    }
+    if chunker.is_some() {
+        job.set("chunks", total_chunks as u64);
     }
 
-    println!(
-        "Indexing {} documents (batch size: {})...",
-        documents.len(),
-        batch_size
+    say!();
+    print_title(&t!("cli.index.complete"), '=');
+    say!("{}", t!("cli.common.successful", count = total_success));
+    say!("{}", t!("cli.common.failed", count = total_failed));
+    if embeddings.is_some() {
+        say!(
+            "{}",
+            t!("cli.index.embeddings_imported", count = total_imported)
+        );
+    }
+    if chunker.is_some() {
+        say!(
+            "{}",
+            t!(
+                "cli.index.chunked",
+                articles = total_chunked,
+                chunks = total_chunks
+            )
+        );
+    }
+    say!(
+        "{}",
+        t!(
+            "cli.index.stories",
+            count = total_stories_assigned,
+            open = stories.len()
+        )
     );
 
-    // Check for embedding server
-    let embedding_server_url = std::env::var("EMBEDDING_SERVER_URL")
-        .unwrap_or_else(|_| "http://localhost:8090".to_string());
+    // Refresh index
+    store.refresh().await?;
 
-    let use_embeddings = check_embedding_server(&embedding_server_url).await;
-    if use_embeddings {
-        println!("Embedding server available at {embedding_server_url}");
-    } else {
-        println!("Warning: Embedding server not available, using dummy embeddings");
+    // Cached results may miss the articles just indexed
+    if total_success > 0 {
+        let invalidated = cache.invalidate_search_cache().await;
+        tracing::debug!(entries = invalidated, "Invalidated cached search results");
     }
+    print_cache_stats(&cache);
 
-    // Index in batches
-    let mut total_success = checkpoint_state.total_success;
-    let mut total_failed = checkpoint_state.total_failed;
-    let client = reqwest::Client::new();
-    let retry_config = RetryConfig::with_delays(2, 1000, 5000);
+    let count = store.count().await?;
+    say!("{}", t!("cli.index.total_documents", count = count));
+
+    let stats = index_progress.stats()?;
+    say!(
+        "{}",
+        t!(
+            "cli.index.progress",
+            indexed = stats.indexed,
+            failed = stats.failed
+        )
+    );
+
+    if shutdown.is_requested() {
+        job.set("interrupted", 1);
+        say!("\n{}", t!("cli.common.interrupted"));
+    }
+
+    Ok(())
+}
+
+/// Where embeddings come from with `kind`, and the dimension of its vectors
+///
+/// Loads the local model, or asks the embedding server for its dimension;
+/// without a reachable server there are no embeddings.
+pub(crate) async fn embedding_source<'a>(
+    kind: EmbedderKind,
+    model: &str,
+    batch_size: usize,
+    client: &reqwest::Client,
+    server_url: &'a str,
+) -> Result<(EmbeddingSource<'a>, Option<usize>)> {
+    Ok(match kind {
+        EmbedderKind::Local => {
+            let (local, dim) = load_local_embedder(model, batch_size).await?;
+            say!(
+                "{}",
+                t!(
+                    "cli.index.embedding_local",
+                    model = model,
+                    dim = dim,
+                    batch_size = batch_size
+                )
+            );
+            (EmbeddingSource::Local(local), Some(dim))
+        }
+        EmbedderKind::Server if check_embedding_server(server_url).await => {
+            let dim = embedding_server_dimension(client, server_url, None)
+                .await
+                .context("Failed to query the embedding dimension of the server")?;
+            say!(
+                "{}",
+                t!("cli.index.embedding_server", url = server_url, dim = dim)
+            );
+            (EmbeddingSource::Server(server_url), Some(dim))
+        }
+        EmbedderKind::Server => {
+            say!("{}", t!("cli.index.embedding_unavailable"));
+            (EmbeddingSource::None, None)
+        }
+        EmbedderKind::None => {
+            say!("{}", t!("cli.index.embedding_disabled"));
+            (EmbeddingSource::None, None)
+        }
+    })
+}
 
-    for (batch_num, batch) in documents.chunks(batch_size).enumerate() {
-        let actual_batch_num = checkpoint_state.last_processed_batch + batch_num;
-        print!(
-            "\rProcessing batch {}/{}...",
-            actual_batch_num + 1,
-            checkpoint_state.last_processed_batch + documents.len().div_ceil(batch_size)
+/// Embeddings by document ID from an `.npy` dump of `dim` dimensions
+fn load_embeddings(path: &Path, dim: Option<usize>) -> Result<HashMap<String, Vec<f32>>> {
+    let dump = EmbeddingDump::read(path, dim)?;
+    if dump.is_empty() {
+        anyhow::bail!(
+            "{}",
+            t!("cli.index.embeddings_empty", path = path.display())
         );
-        std::io::Write::flush(&mut std::io::stdout())?;
+    }
+    say!(
+        "{}",
+        t!(
+            "cli.index.embeddings_loaded",
+            count = dump.len(),
+            dim = dump.dim(),
+            path = path.display()
+        )
+    );
+    Ok(dump.into_map())
+}
+
+/// Load `model` for in-process embedding, with its embedding dimension
+async fn load_local_embedder(model: &str, batch_size: usize) -> Result<(Arc<Embedder>, usize)> {
+    say!("{}", t!("cli.index.loading_model", model = model));
+    let model = model.to_string();
+    // Loading may download the model and is CPU-bound
+    tokio::task::spawn_blocking(move || {
+        let embedder = Embedder::from_pretrained(EmbeddingConfig {
+            model_id: model.clone(),
+            batch_size: batch_size.max(1),
+            ..Default::default()
+        })
+        .with_context(|| format!("Failed to load embedding model {model}"))?;
+        // The configured dimension is a default; the model decides
+        let dim = embedder.embed("임베딩 차원 확인")?.len();
+        if dim == 0 {
+            anyhow::bail!("Embedding model {model} returned an empty vector");
+        }
+        Ok((Arc::new(embedder), dim))
+    })
+    .await
+    .context("Local embedding task panicked")?
+}
+
+/// Documents moving through the index pipeline, with their files
+pub(crate) struct DocumentBatch {
+    /// 1-based position in the run, for error messages
+    number: usize,
+    pub(crate) docs: Vec<IndexDocument>,
+    sources: Vec<SourceFile>,
+    /// Chunks of each article of `docs`, empty where it is indexed whole;
+    /// filled in by the embedding stage
+    chunks: Vec<Vec<IndexDocument>>,
+}
 
-        // Generate embeddings in batch (single API call for entire batch)
-        let batch_with_embeddings: Vec<baram::embedding::IndexDocument> = if use_embeddings {
-            let texts: Vec<String> = batch
+impl DocumentBatch {
+    pub(crate) fn new(number: usize, capacity: usize) -> Self {
+        Self {
+            number,
+            docs: Vec::with_capacity(capacity),
+            sources: Vec::with_capacity(capacity),
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Documents to bulk index: each article whole, or its chunks
+    fn index_documents(&self) -> Cow<'_, [IndexDocument]> {
+        if self.chunks.iter().all(Vec::is_empty) {
+            return Cow::Borrowed(&self.docs);
+        }
+        Cow::Owned(
+            self.docs
+                .iter()
+                .zip(&self.chunks)
+                .flat_map(|(doc, chunks)| match chunks.as_slice() {
+                    [] => std::slice::from_ref(doc),
+                    chunks => chunks,
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// Parse `files` in parallel and send them on in batches of `batch_size`,
+/// in file order
+///
+/// Returns the parse failures, or the first one with `fail_fast`. Stops
+/// early if the next stage has stopped or a shutdown is requested; the
+/// documents read so far are still sent on.
+async fn read_documents(
+    files: Vec<SourceFile>,
+    batch_size: usize,
+    fail_fast: bool,
+    shutdown: &Shutdown,
+    batches: mpsc::Sender<DocumentBatch>,
+    task: &ProgressTask,
+) -> Result<Vec<IndexRecord>> {
+    let concurrency = std::thread::available_parallelism()
+        .map(|n| n.get().min(8))
+        .unwrap_or(4);
+    let batch_size = batch_size.max(1);
+
+    let mut parsed = stream::iter(files)
+        .map(|file| {
+            tokio::task::spawn_blocking(move || {
+                let res = parse_markdown_to_document(&file.path);
+                (file, res)
+            })
+        })
+        .buffered(concurrency);
+
+    let mut failures = Vec::new();
+    let mut batch = DocumentBatch::new(1, batch_size);
+    while let Some(result) = parsed.next().await {
+        if shutdown.is_requested() {
+            break;
+        }
+        match result {
+            Ok((file, Ok(doc))) => {
+                batch.docs.push(doc);
+                batch.sources.push(file);
+            }
+            Ok((file, Err(e))) => {
+                if fail_fast {
+                    return Err(e);
+                }
+                tracing::warn!(path = %file.path.display(), error = %e, "Failed to parse markdown");
+                failures.push(IndexRecord::failed(
+                    file.key(),
+                    file.modified,
+                    article_id_from_path(&file.path),
+                    e.to_string(),
+                ));
+                task.inc(1);
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Parse task panicked");
+                task.inc(1);
+            }
+        }
+
+        if batch.docs.len() >= batch_size {
+            let next = DocumentBatch::new(batch.number + 1, batch_size);
+            if batches
+                .send(std::mem::replace(&mut batch, next))
+                .await
+                .is_err()
+            {
+                return Ok(failures);
+            }
+        }
+    }
+    if !batch.docs.is_empty() {
+        // A closed channel means the next stage failed, which is reported
+        let _ = batches.send(batch).await;
+    }
+    Ok(failures)
+}
+
+/// Where the index pipeline gets embeddings for documents without an
+/// imported vector
+pub(crate) enum EmbeddingSource<'a> {
+    /// Model loaded in-process
+    Local(Arc<Embedder>),
+    /// Embedding server at this URL
+    Server(&'a str),
+    /// No embeddings (disabled, or the server was not reachable)
+    None,
+}
+
+/// Embeds document batches for the index pipeline
+pub(crate) struct BatchEmbedder<'a> {
+    pub(crate) client: &'a reqwest::Client,
+    pub(crate) source: EmbeddingSource<'a>,
+    /// HuggingFace model of the `Local` source
+    pub(crate) model: &'a str,
+    /// Splits long articles, when chunked indexing is on
+    pub(crate) chunker: Option<&'a Chunker>,
+    /// Embeddings of texts seen before, by model and text
+    pub(crate) cache: &'a OptionalCache,
+    pub(crate) config: &'a Config,
+    /// Vectors of an embedding dump, by document ID
+    pub(crate) imported: &'a HashMap<String, Vec<f32>>,
+    pub(crate) retry_config: &'a RetryConfig,
+}
+
+impl BatchEmbedder<'_> {
+    /// Vectors of the documents of `batch`, `None` where there is none, and
+    /// the number of imported vectors
+    ///
+    /// Imported vectors come first. With a chunker, the other long articles
+    /// are split into `batch.chunks`, which get vectors of their own, and
+    /// the article takes its first chunk's vector for story assignment. The
+    /// rest come from the cache, or are generated in one request per
+    /// embedding model by the server, or all by the local model, which
+    /// ignores the per-category `embedding_model`.
+    pub(crate) async fn embed(
+        &self,
+        mut batch: DocumentBatch,
+    ) -> (DocumentBatch, Vec<Option<Vec<f32>>>, usize) {
+        let mut vectors: Vec<Option<Vec<f32>>> = batch
+            .docs
+            .iter()
+            .map(|doc| self.imported.get(&doc.id).cloned())
+            .collect();
+        let imported = vectors.iter().flatten().count();
+
+        batch.chunks = vec![Vec::new(); batch.docs.len()];
+        if let Some(chunker) = self.chunker {
+            // Articles with an imported vector are indexed whole
+            let contents = batch
+                .docs
+                .iter()
+                .zip(&vectors)
+                .map(|(doc, emb)| emb.is_none().then(|| doc.content.clone()))
+                .collect();
+            match chunker.split(contents).await {
+                Ok(chunks) => {
+                    for ((doc, chunks), split) in
+                        batch.docs.iter().zip(&mut batch.chunks).zip(chunks)
+                    {
+                        *chunks = doc.chunk_documents(&split);
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Chunking failed, embedding whole articles");
+                }
+            }
+        }
+
+        // Whole articles without a vector, then all chunks
+        let missing: Vec<IndexDocument> = batch
+            .docs
+            .iter()
+            .zip(&vectors)
+            .zip(&batch.chunks)
+            .filter(|((_, emb), chunks)| emb.is_none() && chunks.is_empty())
+            .map(|((doc, _), _)| doc.clone())
+            .chain(batch.chunks.iter().flatten().cloned())
+            .collect();
+        if !missing.is_empty() && !matches!(self.source, EmbeddingSource::None) {
+            let texts: Vec<String> = missing
                 .iter()
                 .map(|doc| {
-                    let text = format!("{} {}", doc.title, doc.content);
+                    let body = doc.chunk_text.as_deref().unwrap_or(&doc.content);
+                    let text = format!("{} {}", doc.title, body);
                     text.chars().take(2000).collect()
                 })
                 .collect();
 
-            match with_retry(&retry_config, || async {
-                generate_embeddings_batch(&client, &embedding_server_url, &texts).await
-            })
-            .await
-            {
-                Ok(embeddings) => batch
-                    .iter()
-                    .zip(embeddings.into_iter())
-                    .map(|(doc, emb)| {
-                        let mut new_doc = doc.clone();
-                        new_doc.embedding = emb;
-                        new_doc
-                    })
-                    .collect(),
+            let keys: Vec<String> = missing
+                .iter()
+                .zip(&texts)
+                .map(|(doc, text)| format!("{}\n{text}", self.cache_model(doc)))
+                .collect();
+            let result = self
+                .cache
+                .get_or_compute_embeddings(&keys, self.cache_model_label(), |indices| {
+                    let docs: Vec<IndexDocument> =
+                        indices.iter().map(|&i| missing[i].clone()).collect();
+                    let texts: Vec<String> = indices.iter().map(|&i| texts[i].clone()).collect();
+                    async move {
+                        match &self.source {
+                            EmbeddingSource::Local(embedder) => {
+                                embed_locally(embedder, texts).await
+                            }
+                            EmbeddingSource::Server(server_url) => {
+                                with_retry(self.retry_config, || async {
+                                    embed_by_category(
+                                        self.client,
+                                        server_url,
+                                        self.config,
+                                        &docs,
+                                        &texts,
+                                    )
+                                    .await
+                                })
+                                .await
+                            }
+                            EmbeddingSource::None => unreachable!("checked above"),
+                        }
+                    }
+                })
+                .await;
+            match result {
+                Ok(generated) => {
+                    let mut generated = generated.into_iter();
+                    for (emb, chunks) in vectors.iter_mut().zip(&batch.chunks) {
+                        if emb.is_none() && chunks.is_empty() {
+                            *emb = generated.next();
+                        }
+                    }
+                    for (emb, chunks) in vectors.iter_mut().zip(&mut batch.chunks) {
+                        for (i, chunk) in chunks.iter_mut().enumerate() {
+                            let Some(vector) = generated.next() else {
+                                break;
+                            };
+                            if i == 0 {
+                                *emb = Some(vector.clone());
+                            }
+                            chunk.embedding = vector;
+                        }
+                    }
+                }
                 Err(e) => {
                     tracing::warn!(error = %e, "Batch embedding failed, using dummy embeddings");
-                    batch.to_vec()
                 }
             }
-        } else {
-            batch.to_vec()
-        };
-
-        // Bulk index with retry
-        let result = with_retry(&retry_config, || async {
-            store.bulk_index(&batch_with_embeddings).await
-        })
-        .await?;
+        }
 
-        total_success += result.success;
-        total_failed += result.failed;
+        (batch, vectors, imported)
+    }
 
-        // Update checkpoint state
-        for doc in batch {
-            checkpoint_state.processed_doc_ids.insert(doc.id.clone());
-        }
-        checkpoint_state.last_processed_batch = actual_batch_num + 1;
-        checkpoint_state.total_success = total_success;
-        checkpoint_state.total_failed = total_failed;
-
-        // Save checkpoint periodically
-        if checkpoint_mgr.should_auto_save() {
-            checkpoint_mgr.save(&checkpoint_name, &checkpoint_state)?;
-            tracing::debug!("Checkpoint saved at batch {}", actual_batch_num + 1);
+    /// Model `doc` is embedded with, as part of its embedding cache key
+    fn cache_model(&self, doc: &IndexDocument) -> String {
+        match &self.source {
+            EmbeddingSource::Local(_) => self.model.to_string(),
+            _ => match NewsCategory::parse(&doc.category)
+                .and_then(|category| self.config.category(category).embedding_model)
+            {
+                Some(model) => format!("embedding-server/{model}"),
+                None => "embedding-server".to_string(),
+            },
         }
+    }
 
-        // Print errors if any
-        if !result.errors.is_empty() {
-            eprintln!("\nErrors in batch {}:", actual_batch_num + 1);
-            for (i, err) in result.errors.iter().take(3).enumerate() {
-                eprintln!("  {}: {}", i + 1, err);
-            }
-            if result.errors.len() > 3 {
-                eprintln!("  ... and {} more errors", result.errors.len() - 3);
-            }
+    /// Model recorded with cached embeddings
+    fn cache_model_label(&self) -> &str {
+        match &self.source {
+            EmbeddingSource::Local(_) => self.model,
+            _ => "embedding-server",
         }
     }
+}
 
-    // Final checkpoint save
-    checkpoint_mgr.save(&checkpoint_name, &checkpoint_state)?;
+/// Splits long articles into overlapping, sentence-aligned chunks
+pub(crate) struct Chunker {
+    tokenizer: Arc<Mutex<TextTokenizer>>,
+}
 
-    println!("\n\nIndexing Complete");
-    println!("=================");
-    println!("Successful: {total_success}");
-    println!("Failed: {total_failed}");
+impl Chunker {
+    /// Load the tokenizer of `model`, downloading it if needed
+    pub(crate) async fn load(model: &str, config: ChunkConfig) -> Result<Self> {
+        let model = model.to_string();
+        let tokenizer = tokio::task::spawn_blocking(move || {
+            TextTokenizer::from_pretrained(&model)
+                .map(|tokenizer| tokenizer.with_config(config))
+                .with_context(|| format!("Failed to load the tokenizer of {model}"))
+        })
+        .await
+        .context("Tokenizer loading task panicked")??;
+        Ok(Self {
+            tokenizer: Arc::new(Mutex::new(tokenizer)),
+        })
+    }
 
-    // Refresh index
-    store.refresh().await?;
+    /// Chunks of each text, off the async runtime; empty for `None` and for
+    /// texts that fit in one chunk
+    async fn split(&self, texts: Vec<Option<String>>) -> Result<Vec<Vec<TextChunk>>> {
+        let tokenizer = Arc::clone(&self.tokenizer);
+        tokio::task::spawn_blocking(move || {
+            let mut tokenizer = tokenizer.lock().unwrap_or_else(PoisonError::into_inner);
+            texts
+                .iter()
+                .map(|text| match text {
+                    Some(text) => tokenizer.chunk_text_sentences(text).map(|chunks| {
+                        if chunks.len() > 1 {
+                            chunks
+                        } else {
+                            Vec::new()
+                        }
+                    }),
+                    None => Ok(Vec::new()),
+                })
+                .collect()
+        })
+        .await
+        .context("Chunking task panicked")?
+    }
+}
 
-    let count = store.count().await?;
-    println!("Total documents in index: {count}");
+/// Embed `texts` with the in-process model, off the async runtime
+async fn embed_locally(embedder: &Arc<Embedder>, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
+    let embedder = Arc::clone(embedder);
+    let started = std::time::Instant::now();
+    let result = tokio::task::spawn_blocking(move || embedder.embed_batch(&texts))
+        .await
+        .context("Local embedding task panicked")
+        .and_then(|result| result);
+    metrics::record_embedding_latency("local", started.elapsed().as_secs_f64(), result.is_ok());
+    result
+}
 
-    // Delete checkpoint on successful completion
-    if total_failed == 0 {
-        checkpoint_mgr.delete(&checkpoint_name)?;
-        println!("Checkpoint deleted (all documents indexed successfully)");
-    } else {
-        println!("Checkpoint saved for retry of failed documents");
+/// A markdown file handed to the indexer
+struct SourceFile {
+    path: PathBuf,
+    /// Modification time (Unix seconds), 0 if unknown
+    modified: i64,
+}
+
+impl SourceFile {
+    fn new(path: PathBuf) -> Self {
+        let modified = std::fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|mtime| mtime.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        Self { path, modified }
     }
 
-    Ok(())
+    /// Publication time (Unix seconds) in the frontmatter, else crawl
+    /// time, else modification time
+    fn published(&self, reader: &MarkdownReader) -> i64 {
+        let frontmatter = reader.read_frontmatter(&self.path).unwrap_or_default();
+        frontmatter
+            .published_at
+            .as_deref()
+            .or(frontmatter.crawled_at.as_deref())
+            .and_then(parse_frontmatter_date)
+            .map_or(self.modified, |time| time.timestamp())
+    }
+
+    /// Key of the file in the index progress
+    fn key(&self) -> String {
+        self.path.to_string_lossy().into_owned()
+    }
+}
+
+/// Timestamp used for story assignment: publication time, else crawl time
+pub(crate) fn document_time(
+    doc: &baram::embedding::IndexDocument,
+) -> chrono::DateTime<chrono::Utc> {
+    doc.published_at
+        .as_deref()
+        .and_then(parse_timestamp)
+        .or_else(|| parse_timestamp(&doc.crawled_at))
+        .unwrap_or_else(chrono::Utc::now)
 }
 
 /// Check if embedding server is available
-async fn check_embedding_server(url: &str) -> bool {
+pub(crate) async fn check_embedding_server(url: &str) -> bool {
     let client = reqwest::Client::new();
     match client.get(format!("{url}/health")).send().await {
         Ok(resp) => resp.status().is_success(),
@@ -368,15 +1079,139 @@ async fn check_embedding_server(url: &str) -> bool {
     }
 }
 
+/// How [`open_index`] found the index, with the dimension of its embeddings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OpenedIndex {
+    /// Created, as it did not exist
+    Created(usize),
+    /// Already there, fitting the wanted dimension
+    Existing(usize),
+    /// Recreated for the wanted dimension, keeping its documents without
+    /// their embeddings
+    Migrated(usize),
+}
+
+impl OpenedIndex {
+    /// Dimension of the index's embeddings
+    pub(crate) fn dim(self) -> usize {
+        match self {
+            Self::Created(dim) | Self::Existing(dim) | Self::Migrated(dim) => dim,
+        }
+    }
+}
+
+/// Create the index for `wanted_dim`-dimensional embeddings, or check that
+/// the existing one holds them
+///
+/// An index mapped for another dimension is migrated with `migrate`, and
+/// refused otherwise. Without a `wanted_dim` an existing index is taken as
+/// it is, and a new one gets [`EMBEDDING_DIM`].
+pub(crate) async fn open_index(
+    store: &VectorStore,
+    index_name: &str,
+    wanted_dim: Option<usize>,
+    migrate: bool,
+) -> Result<OpenedIndex> {
+    if !store.index_exists().await? {
+        let dim = wanted_dim.unwrap_or(EMBEDDING_DIM);
+        say!("{}", t!("cli.index.creating", index = index_name));
+        store
+            .create_index(dim)
+            .await
+            .context("Failed to create index")?;
+        say!("{}", t!("cli.index.created"));
+        return Ok(OpenedIndex::Created(dim));
+    }
+
+    let mapped = store
+        .embedding_dimension()
+        .await
+        .context("Failed to read the index mapping")?;
+    match (mapped, wanted_dim) {
+        (Some(mapped), Some(wanted)) if mapped != wanted => {
+            if !migrate {
+                anyhow::bail!(
+                    "{}",
+                    t!(
+                        "cli.index.dimension_mismatch",
+                        index = index_name,
+                        mapped = mapped,
+                        dim = wanted
+                    )
+                );
+            }
+            say!("{}", t!("cli.index.migrating", from = mapped, to = wanted));
+            let kept = store
+                .migrate_embedding_dimension(wanted)
+                .await
+                .context("Failed to migrate the index")?;
+            say!("{}", t!("cli.index.migrated", count = kept));
+            Ok(OpenedIndex::Migrated(wanted))
+        }
+        (Some(mapped), _) => Ok(OpenedIndex::Existing(mapped)),
+        (None, wanted) => Ok(OpenedIndex::Existing(wanted.unwrap_or(EMBEDDING_DIM))),
+    }
+}
+
+/// Embed the `texts` of `batch` with the `embedding_model` of each
+/// document's category, one request per model
+pub(crate) async fn embed_by_category(
+    client: &reqwest::Client,
+    server_url: &str,
+    config: &Config,
+    batch: &[baram::embedding::IndexDocument],
+    texts: &[String],
+) -> Result<Vec<Vec<f32>>> {
+    let mut by_model: BTreeMap<Option<String>, Vec<usize>> = BTreeMap::new();
+    for (i, doc) in batch.iter().enumerate() {
+        let model = NewsCategory::parse(&doc.category)
+            .and_then(|category| config.category(category).embedding_model);
+        by_model.entry(model).or_default().push(i);
+    }
+
+    let mut embeddings = vec![Vec::new(); texts.len()];
+    for (model, indices) in by_model {
+        let group: Vec<String> = indices.iter().map(|&i| texts[i].clone()).collect();
+        let vectors = generate_embeddings_batch(client, server_url, &group, model.as_deref())
+            .await
+            .with_context(|| format!("model {}", model.as_deref().unwrap_or("default")))?;
+        for (i, vector) in indices.into_iter().zip(vectors) {
+            embeddings[i] = vector;
+        }
+    }
+    Ok(embeddings)
+}
+
+/// Dimension of the vectors `model` (default: the server's first) produces
+pub(crate) async fn embedding_server_dimension(
+    client: &reqwest::Client,
+    server_url: &str,
+    model: Option<&str>,
+) -> Result<usize> {
+    let vectors =
+        generate_embeddings_batch(client, server_url, &["임베딩 차원 확인".to_string()], model)
+            .await?;
+    vectors
+        .first()
+        .map(Vec::len)
+        .filter(|dim| *dim > 0)
+        .context("Embedding server returned no vector")
+}
+
 /// Generate embeddings for a batch of texts using the batch API endpoint
-async fn generate_embeddings_batch(
+///
+/// `model` picks one of the models the server loaded (default: its first).
+pub(crate) async fn generate_embeddings_batch(
     client: &reqwest::Client,
     server_url: &str,
     texts: &[String],
+    model: Option<&str>,
 ) -> Result<Vec<Vec<f32>>> {
     #[derive(Serialize)]
     struct BatchEmbedRequest<'a> {
         texts: &'a [String],
+        #[serde(skip_serializing_if = "Option::is_none")]
+        model: Option<&'a str>,
     }
 
     #[derive(Deserialize)]
@@ -384,183 +1219,289 @@ async fn generate_embeddings_batch(
         embeddings: Vec<Vec<f32>>,
     }
 
-    let response = client
-        .post(format!("{server_url}/embed/batch"))
-        .json(&BatchEmbedRequest { texts })
-        .send()
-        .await
-        .context("Failed to send batch embedding request")?;
+    let started = std::time::Instant::now();
+    let result = async {
+        let response = client
+            .post(format!("{server_url}/embed/batch"))
+            .json(&BatchEmbedRequest { texts, model })
+            .send()
+            .await
+            .context("Failed to send batch embedding request")?;
 
-    let batch_response: BatchEmbedResponse = response
-        .json()
-        .await
-        .context("Failed to parse batch embedding response")?;
+        let batch_response: BatchEmbedResponse = response
+            .json()
+            .await
+            .context("Failed to parse batch embedding response")?;
 
-    Ok(batch_response.embeddings)
+        Ok(batch_response.embeddings)
+    }
+    .await;
+    metrics::record_embedding_latency("batch", started.elapsed().as_secs_f64(), result.is_ok());
+    result
 }
 
+/// Read a saved article markdown file as a document to index
+///
+/// The embedding is a placeholder, filled in before indexing.
 pub fn parse_markdown_to_document(
     path: &std::path::Path,
 ) -> Result<baram::embedding::IndexDocument> {
-    let content = std::fs::read_to_string(path)
-        .with_context(|| format!("Failed to read file: {}", path.display()))?;
-
-    // Parse markdown to extract metadata
-    let lines: Vec<&str> = content.lines().collect();
-
-    // Extract title (first # heading)
-    let title = lines
-        .iter()
-        .find(|l| l.starts_with("# "))
-        .map(|l| l.trim_start_matches("# ").to_string())
-        .unwrap_or_else(|| "Untitled".to_string());
-
-    // Extract metadata from YAML frontmatter or inline
-    let mut oid = String::new();
-    let mut aid = String::new();
-    let mut category = String::new();
-    let mut publisher = None;
-    let mut author = None;
-    let mut url = String::new();
-    let mut published_at = None;
-
-    let mut frontmatter_delim_count = 0;
-    let mut in_metadata = false;
-    let mut body_lines = Vec::new();
-
-    for line in &lines {
-        // Handle YAML frontmatter delimiters (only first two --- are special)
-        if line.starts_with("---") {
-            if frontmatter_delim_count < 2 {
-                frontmatter_delim_count += 1;
-                in_metadata = frontmatter_delim_count == 1;
-                continue;
-            }
-            // After frontmatter, --- is just a content separator
-        }
-
-        if in_metadata {
-            if let Some((key, value)) = line.split_once(':') {
-                let key = key.trim();
-                let value = value.trim().trim_matches('"');
-                match key {
-                    "oid" => oid = value.to_string(),
-                    "aid" => aid = value.to_string(),
-                    "category" => category = value.to_string(),
-                    "publisher" => publisher = Some(value.to_string()),
-                    "author" => author = Some(value.to_string()),
-                    "url" => url = value.to_string(),
-                    "published_at" | "date" => published_at = Some(value.to_string()),
-                    _ => {}
-                }
-            }
-        } else if !line.is_empty() {
-            body_lines.push(*line);
-        }
-    }
-
-    // Build content from body
-    let article_content = body_lines.join("\n");
+    Ok(markdown_to_document(MarkdownReader::new().read(path)?))
+}
 
-    // Generate ID from filename if not available
-    if oid.is_empty() || aid.is_empty() {
-        let stem = path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("unknown");
-        if let Some((o, a)) = stem.split_once('_') {
-            oid = o.to_string();
-            aid = a.to_string();
-        } else {
-            oid = "000".to_string();
-            aid = stem.to_string();
-        }
-    }
+/// A markdown article as a document to index, with a placeholder embedding
+pub fn markdown_to_document(markdown: MarkdownArticle) -> baram::embedding::IndexDocument {
+    let id = markdown.id();
+    let source = markdown.source();
+    let (oid, aid) = markdown.oid_aid();
+    let published_at = markdown
+        .published_at()
+        .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    let crawled_at = markdown.crawled_at().unwrap_or_else(chrono::Utc::now);
+    // Zero usually means comments were not crawled
+    let comment_count = markdown
+        .frontmatter
+        .comment_count
+        .filter(|n| *n > 0)
+        .and_then(|n| i32::try_from(n).ok());
+    let Frontmatter {
+        category,
+        publisher,
+        author,
+        url,
+        ..
+    } = markdown.frontmatter;
 
     // Create dummy embedding (will be replaced with real embedding later)
-    let embedding = vec![0.0f32; 384];
-
-    // Convert published_at to ISO 8601 format
-    let published_at_iso = published_at.and_then(|dt| {
-        let dt = dt.trim();
-        // Skip empty or invalid dates
-        if dt.is_empty()
-            || !dt
-                .chars()
-                .next()
-                .map(|c| c.is_ascii_digit())
-                .unwrap_or(false)
-        {
-            return None;
-        }
-        // Try to parse "YYYY-MM-DD HH:MM" format and convert to ISO 8601
-        if dt.contains('T') {
-            Some(dt.to_string()) // Already in ISO format
-        } else {
-            // Replace space with T and add seconds + timezone
-            Some(dt.replace(' ', "T") + ":00Z")
-        }
-    });
+    let embedding = vec![0.0f32; EMBEDDING_DIM];
 
-    Ok(baram::embedding::IndexDocument {
-        id: format!("{oid}_{aid}"),
+    baram::embedding::IndexDocument {
+        id,
+        source,
         oid,
         aid,
-        title,
-        content: article_content,
-        category,
+        title: markdown.title,
+        content: markdown.body,
+        category: category.unwrap_or_default(),
         publisher,
         author,
-        url,
-        published_at: published_at_iso,
-        crawled_at: chrono::Utc::now().to_rfc3339(),
-        comment_count: None,
+        url: url.unwrap_or_default(),
+        published_at,
+        crawled_at: crawled_at.to_rfc3339(),
+        comment_count,
+        image_count: None,
+        video_count: None,
+        reaction_count: None,
         embedding,
         chunk_index: None,
         chunk_text: None,
-    })
+        story_id: None,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use baram::models::ArticleSource;
 
     #[test]
-    fn test_extract_doc_id_standard_filename() {
-        let path = std::path::PathBuf::from("001_0015812889_강남구_국민권익위_청렴도_평가서.md");
+    fn test_document_time_prefers_published_at() {
+        let mut doc = baram::embedding::IndexDocument {
+            id: "001_0000000001".to_string(),
+            source: ArticleSource::Naver,
+            oid: "001".to_string(),
+            aid: "0000000001".to_string(),
+            title: "Test".to_string(),
+            content: String::new(),
+            category: "politics".to_string(),
+            publisher: None,
+            author: None,
+            url: String::new(),
+            published_at: Some("2026-02-15T09:30:00Z".to_string()),
+            crawled_at: "2026-02-16T00:00:00+00:00".to_string(),
+            comment_count: None,
+            image_count: None,
+            video_count: None,
+            reaction_count: None,
+            embedding: vec![],
+            chunk_index: None,
+            chunk_text: None,
+            story_id: None,
+        };
+        assert_eq!(
+            document_time(&doc).to_rfc3339(),
+            "2026-02-15T09:30:00+00:00"
+        );
+
+        doc.published_at = None;
         assert_eq!(
-            extract_doc_id_from_filename(&path),
-            Some("001_0015812889".to_string())
+            document_time(&doc).to_rfc3339(),
+            "2026-02-16T00:00:00+00:00"
         );
     }
 
     #[test]
-    fn test_extract_doc_id_no_title() {
-        let path = std::path::PathBuf::from("422_0000832799.md");
+    fn test_index_documents_replaces_chunked_articles() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut batch = DocumentBatch::new(1, 2);
+        for (i, body) in ["짧은 기사.", "긴 기사. 둘째 문장."].iter().enumerate() {
+            let path = dir.path().join(format!("001_000000000{i}_test.md"));
+            std::fs::write(
+                &path,
+                format!(
+                    "---\nid: 001_000000000{i}\ntitle: \"T\"\noid: 001\naid: 000000000{i}\n---\n\n{body}\n"
+                ),
+            )
+            .unwrap();
+            batch.docs.push(parse_markdown_to_document(&path).unwrap());
+            batch.sources.push(SourceFile::new(path));
+        }
+        batch.chunks = vec![Vec::new(); 2];
+        assert!(matches!(batch.index_documents(), Cow::Borrowed(_)));
+
+        let chunks: Vec<TextChunk> = ["긴 기사.", "둘째 문장."]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| TextChunk {
+                text: text.to_string(),
+                token_count: 3,
+                chunk_index: i,
+                total_chunks: 2,
+                start_pos: 0,
+                end_pos: 0,
+            })
+            .collect();
+        batch.chunks[1] = batch.docs[1].chunk_documents(&chunks);
+        let ids: Vec<String> = batch
+            .index_documents()
+            .iter()
+            .map(IndexDocument::doc_id)
+            .collect();
         assert_eq!(
-            extract_doc_id_from_filename(&path),
-            Some("422_0000832799".to_string())
+            ids,
+            ["001_0000000000", "001_0000000001", "001_0000000001#1"]
         );
     }
 
     #[test]
-    fn test_extract_doc_id_invalid_format() {
-        let path = std::path::PathBuf::from("unknown.md");
-        assert_eq!(extract_doc_id_from_filename(&path), None);
+    fn test_parse_markdown_comment_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("001_0000000001_test.md");
+        let markdown = |count: u32| {
+            format!(
+                "---\nid: 001_0000000001\ntitle: \"Test\"\noid: 001\naid: 0000000001\n\
+                 comment_count: {count}\n---\n\n# Test\n\nBody\n"
+            )
+        };
+
+        std::fs::write(&path, markdown(12)).unwrap();
+        let doc = parse_markdown_to_document(&path).unwrap();
+        assert_eq!(doc.comment_count, Some(12));
+        assert_eq!(doc.content, "Body");
+
+        std::fs::write(&path, markdown(0)).unwrap();
+        let doc = parse_markdown_to_document(&path).unwrap();
+        assert_eq!(doc.comment_count, None);
     }
 
     #[test]
-    fn test_extract_doc_id_non_numeric_oid() {
-        let path = std::path::PathBuf::from("abc_0015812889_title.md");
-        assert_eq!(extract_doc_id_from_filename(&path), None);
+    fn test_source_file_published() {
+        let dir = tempfile::tempdir().unwrap();
+        let reader = MarkdownReader::new();
+        let file = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            SourceFile::new(path)
+        };
+
+        let published = file(
+            "a.md",
+            "---\npublished_at: 2024-01-15T09:30:00Z\ncrawled_at: 2024-03-01T00:00:00Z\n---\n",
+        );
+        assert_eq!(published.published(&reader), 1705311000);
+        let crawled = file("b.md", "---\ncrawled_at: 2024-03-01T00:00:00Z\n---\n");
+        assert_eq!(crawled.published(&reader), 1709251200);
+        let undated = file("c.md", "# Title\n\nBody\n");
+        assert_eq!(undated.published(&reader), undated.modified);
     }
 
-    #[test]
-    fn test_extract_doc_id_three_digit_oid() {
-        let path = std::path::PathBuf::from("661_0000071158_강득구_지방선거_이후_합당이.md");
+    #[tokio::test]
+    async fn test_read_documents_batches_in_file_order() {
+        use super::super::progress::ProgressMode;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut files = Vec::new();
+        for i in 1..=5 {
+            let path = dir.path().join(format!("001_000000000{i}_test.md"));
+            std::fs::write(
+                &path,
+                format!("---\noid: 001\naid: 000000000{i}\n---\n\nBody {i}\n"),
+            )
+            .unwrap();
+            files.push(SourceFile::new(path));
+        }
+        // Unreadable: recorded as a failure, the others are still indexed
+        files.insert(
+            2,
+            SourceFile::new(dir.path().join("001_0000000009_missing.md")),
+        );
+
+        let progress = Progress::new(ProgressMode::None);
+        let task = progress.task("index", "documents", files.len() as u64);
+        let (tx, mut rx) = mpsc::channel(1);
+        // Capacity 1: the reader can only finish while batches are taken
+        let reader = tokio::spawn(async move {
+            let task = task;
+            read_documents(files, 2, false, &Shutdown::new(), tx, &task).await
+        });
+
+        let mut batches = Vec::new();
+        while let Some(batch) = rx.recv().await {
+            let ids: Vec<String> = batch.docs.iter().map(|doc| doc.id.clone()).collect();
+            assert_eq!(batch.sources.len(), ids.len());
+            batches.push((batch.number, ids));
+        }
         assert_eq!(
-            extract_doc_id_from_filename(&path),
-            Some("661_0000071158".to_string())
+            batches,
+            vec![
+                (
+                    1,
+                    vec!["001_0000000001".to_string(), "001_0000000002".to_string()]
+                ),
+                (
+                    2,
+                    vec!["001_0000000003".to_string(), "001_0000000004".to_string()]
+                ),
+                (3, vec!["001_0000000005".to_string()]),
+            ]
         );
+
+        let failures = reader.await.unwrap().unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].doc_id.as_deref(), Some("001_0000000009"));
+    }
+
+    #[tokio::test]
+    async fn test_read_documents_fail_fast() {
+        use super::super::progress::ProgressMode;
+
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![SourceFile::new(
+            dir.path().join("001_0000000001_missing.md"),
+        )];
+        let progress = Progress::new(ProgressMode::None);
+        let task = progress.task("index", "documents", 1);
+        let (tx, _rx) = mpsc::channel(1);
+        assert!(read_documents(files, 10, true, &Shutdown::new(), tx, &task)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_embedder_kind_parse() {
+        assert_eq!(EmbedderKind::parse("local").unwrap(), EmbedderKind::Local);
+        assert_eq!(EmbedderKind::parse("Server").unwrap(), EmbedderKind::Server);
+        assert_eq!(EmbedderKind::parse("none").unwrap(), EmbedderKind::None);
+        assert!(EmbedderKind::parse("candle").is_err());
     }
 }
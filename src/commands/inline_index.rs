@@ -0,0 +1,527 @@
+//! Indexing articles while they are crawled
+//!
+//! `baram crawl --index` hands every saved article to an [`InlineIndexer`],
+//! which embeds and indexes them in batches in the background, so new
+//! articles are searchable without a separate `baram index` run. The crawl
+//! never waits for it: articles go through a bounded queue, and while the
+//! queue is full because OpenSearch or the embedding server is slow, they
+//! are appended to a spill file next to the crawl database instead.
+//!
+//! The indexer works through the spill file whenever the queue runs dry.
+//! Batches it cannot embed or index are spilled again and retried after
+//! [`RETRY_PAUSE`]. Whatever is still spilled when the crawl ends is picked
+//! up by the next `crawl --index`; the markdown files can also be indexed
+//! with `baram index` as usual.
+
+use anyhow::{Context, Result};
+use rust_i18n::t;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use baram::clustering::{StoryConfig, StoryTracker};
+use baram::config::Config;
+use baram::embedding::{article_to_document, IndexDocument, VectorStore};
+use baram::models::ParsedArticle;
+
+use super::index::{
+    check_embedding_server, document_time, embed_by_category, embedding_server_dimension,
+    open_index,
+};
+use super::output::say;
+
+/// Articles embedded and indexed together
+const BATCH_SIZE: usize = 16;
+
+/// How long the queue may stay empty before spilled articles are indexed
+const IDLE_POLL: Duration = Duration::from_millis(500);
+
+/// Pause after a failed batch before spilled articles are tried again
+const RETRY_PAUSE: Duration = Duration::from_secs(10);
+
+/// Longest wait for one embedding or bulk request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Spill file of a markdown directory, next to its crawl database
+pub(crate) fn spill_path(markdown_dir: &Path) -> PathBuf {
+    markdown_dir
+        .parent()
+        .unwrap_or(markdown_dir)
+        .join("index-spill.jsonl")
+}
+
+/// Articles waiting to be indexed, one JSON object per line
+///
+/// Taken articles are not cut from the file one batch at a time; a read
+/// offset skips them until the file is used up and removed, or is
+/// [compacted](Self::compact) when the crawl ends. Should the crawl die
+/// before that, the next one indexes the taken articles again.
+struct SpillFile {
+    path: PathBuf,
+    /// Bytes at the start of the file already taken
+    offset: u64,
+}
+
+impl SpillFile {
+    fn new(path: PathBuf) -> Self {
+        Self { path, offset: 0 }
+    }
+
+    /// Append `articles`
+    fn push(&self, articles: &[ParsedArticle]) -> Result<()> {
+        if articles.is_empty() {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open spill file: {}", self.path.display()))?;
+        let mut lines = String::new();
+        for article in articles {
+            lines.push_str(&serde_json::to_string(article)?);
+            lines.push('\n');
+        }
+        file.write_all(lines.as_bytes())
+            .with_context(|| format!("Failed to write spill file: {}", self.path.display()))
+    }
+
+    /// Remove and return up to `max` of the oldest articles
+    ///
+    /// Lines that do not parse are dropped with a warning; the article is
+    /// still in its markdown file.
+    fn take(&mut self, max: usize) -> Result<Vec<ParsedArticle>> {
+        let Some(mut reader) = self.reader()? else {
+            return Ok(Vec::new());
+        };
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        while lines.len() < max {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            self.offset += read as u64;
+            if !line.trim().is_empty() {
+                lines.push(line.trim_end().to_string());
+            }
+        }
+        if reader.fill_buf()?.is_empty() {
+            std::fs::remove_file(&self.path)?;
+            self.offset = 0;
+        }
+
+        Ok(lines
+            .iter()
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(article) => Some(article),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Dropping unreadable spilled article");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Number of spilled articles not taken yet
+    fn len(&self) -> Result<usize> {
+        let Some(reader) = self.reader()? else {
+            return Ok(0);
+        };
+        let mut count = 0;
+        for line in reader.lines() {
+            if !line?.trim().is_empty() {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Cut the taken articles from the file
+    fn compact(&mut self) -> Result<()> {
+        if self.offset == 0 {
+            return Ok(());
+        }
+        let mut rest = Vec::new();
+        if let Some(mut reader) = self.reader()? {
+            reader.read_to_end(&mut rest)?;
+        }
+        std::fs::write(&self.path, rest)
+            .with_context(|| format!("Failed to write spill file: {}", self.path.display()))?;
+        self.offset = 0;
+        Ok(())
+    }
+
+    /// Reader at the first article not taken yet; `None` without a file
+    fn reader(&self) -> Result<Option<BufReader<File>>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to open spill file: {}", self.path.display()))
+            }
+        };
+        file.seek(SeekFrom::Start(self.offset))?;
+        Ok(Some(BufReader::new(file)))
+    }
+}
+
+/// Outcome of inline indexing
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct InlineIndexStats {
+    pub indexed: usize,
+    /// Rejected by OpenSearch
+    pub failed: usize,
+    /// Written to the spill file because the queue was full
+    pub spilled: usize,
+    /// Still in the spill file after the run
+    pub pending: usize,
+}
+
+/// Embeds and indexes crawled articles in the background
+pub(crate) struct InlineIndexer {
+    queue: mpsc::Sender<ParsedArticle>,
+    spill: Arc<Mutex<SpillFile>>,
+    spilled: AtomicUsize,
+    worker: Option<JoinHandle<InlineIndexStats>>,
+}
+
+impl InlineIndexer {
+    /// Connect to OpenSearch, creating the index if needed, and start
+    /// indexing with a queue of `queue_size` articles
+    pub async fn start(config: &Config, spill_path: PathBuf, queue_size: usize) -> Result<Self> {
+        let opensearch_config = config.opensearch.clone().with_env_overrides();
+        let store =
+            VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
+        let embedding_url = std::env::var("EMBEDDING_SERVER_URL")
+            .unwrap_or_else(|_| "http://localhost:8090".to_string());
+        let embedding_up = check_embedding_server(&embedding_url).await;
+        // The index must fit the model's vectors; while the server is down
+        // an existing index is taken as it is
+        let wanted_dim = if embedding_up {
+            Some(
+                embedding_server_dimension(&reqwest::Client::new(), &embedding_url, None)
+                    .await
+                    .context("Failed to read the embedding dimension")?,
+            )
+        } else {
+            None
+        };
+        open_index(&store, &opensearch_config.index_name, wanted_dim, false).await?;
+
+        let mut stories = StoryTracker::new(StoryConfig::default());
+        if embedding_up {
+            if let Err(e) = stories.load_from_store(&store, chrono::Utc::now()).await {
+                tracing::warn!(error = %e, "Failed to load open stories, starting fresh");
+            }
+        } else {
+            say!(
+                "{}",
+                t!("cli.crawl.index_embedding_down", url = embedding_url)
+            );
+        }
+
+        let spill = Arc::new(Mutex::new(SpillFile::new(spill_path)));
+        let (queue, receiver) = mpsc::channel(queue_size.max(1));
+        let worker = Worker {
+            store,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .context("Failed to create HTTP client for embedding server")?,
+            embedding_url,
+            config: config.clone(),
+            stories,
+            spill: Arc::clone(&spill),
+            retry_at: Instant::now(),
+            stats: InlineIndexStats::default(),
+        };
+        Ok(Self {
+            queue,
+            spill,
+            spilled: AtomicUsize::new(0),
+            worker: Some(tokio::spawn(worker.run(receiver))),
+        })
+    }
+
+    /// Queue `article` for indexing, or spill it if the queue is full
+    ///
+    /// Never waits for the indexer. Spill failures are logged; the article
+    /// is still in its markdown file.
+    pub fn submit(&self, article: &ParsedArticle) {
+        let article = match self.queue.try_send(article.clone()) {
+            Ok(()) => return,
+            Err(mpsc::error::TrySendError::Full(article))
+            | Err(mpsc::error::TrySendError::Closed(article)) => article,
+        };
+        let spilled = self
+            .spill
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Spill file lock poisoned"))
+            .and_then(|spill| spill.push(std::slice::from_ref(&article)));
+        match spilled {
+            Ok(()) => {
+                self.spilled.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => tracing::warn!(url = %article.url, error = %e, "Failed to spill article"),
+        }
+    }
+
+    /// Index the queued and spilled articles, then stop
+    pub async fn finish(self) -> InlineIndexStats {
+        let Self {
+            queue,
+            spill,
+            spilled,
+            worker,
+        } = self;
+        // Closing the queue lets the worker run out
+        drop(queue);
+
+        let mut stats = match worker {
+            Some(worker) => worker.await.unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Inline indexer panicked");
+                InlineIndexStats::default()
+            }),
+            None => InlineIndexStats::default(),
+        };
+        stats.spilled = spilled.load(Ordering::Relaxed);
+        stats.pending = match spill.lock() {
+            Ok(mut spill) => {
+                if let Err(e) = spill.compact() {
+                    tracing::warn!(error = %e, "Failed to compact spill file");
+                }
+                spill.len().unwrap_or(0)
+            }
+            Err(_) => 0,
+        };
+        stats
+    }
+}
+
+/// Background side of an [`InlineIndexer`]
+struct Worker {
+    store: VectorStore,
+    client: reqwest::Client,
+    embedding_url: String,
+    config: Config,
+    stories: StoryTracker,
+    spill: Arc<Mutex<SpillFile>>,
+    /// No spilled articles are tried before this
+    retry_at: Instant,
+    stats: InlineIndexStats,
+}
+
+impl Worker {
+    async fn run(mut self, mut queue: mpsc::Receiver<ParsedArticle>) -> InlineIndexStats {
+        loop {
+            let mut batch = Vec::with_capacity(BATCH_SIZE);
+            match tokio::time::timeout(IDLE_POLL, queue.recv()).await {
+                Ok(Some(article)) => batch.push(article),
+                Ok(None) => break,
+                Err(_) => {}
+            }
+            while batch.len() < BATCH_SIZE {
+                match queue.try_recv() {
+                    Ok(article) => batch.push(article),
+                    Err(_) => break,
+                }
+            }
+
+            let drained = batch.len() < BATCH_SIZE;
+            self.index(batch).await;
+            // Spilled articles wait until the crawl lets up
+            if drained && Instant::now() >= self.retry_at {
+                self.index_spilled().await;
+            }
+        }
+
+        // One last pass over the spill file, stopping at the first failure
+        self.retry_at = Instant::now();
+        while Instant::now() >= self.retry_at && self.index_spilled().await > 0 {}
+
+        if let Err(e) = self.store.refresh().await {
+            tracing::warn!(error = %e, "Failed to refresh index");
+        }
+        self.stats
+    }
+
+    /// Index a batch of spilled articles and return how many were taken
+    async fn index_spilled(&mut self) -> usize {
+        let taken = self
+            .spill
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Spill file lock poisoned"))
+            .and_then(|mut spill| spill.take(BATCH_SIZE));
+        match taken {
+            Ok(articles) => {
+                let count = articles.len();
+                self.index(articles).await;
+                count
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read spill file");
+                self.retry_at = Instant::now() + RETRY_PAUSE;
+                0
+            }
+        }
+    }
+
+    /// Embed and index `articles`, spilling them if either step fails
+    async fn index(&mut self, articles: Vec<ParsedArticle>) {
+        if articles.is_empty() {
+            return;
+        }
+        match self.try_index(&articles).await {
+            Ok((indexed, failed)) => {
+                self.stats.indexed += indexed;
+                self.stats.failed += failed;
+            }
+            Err(e) => {
+                tracing::warn!(
+                    count = articles.len(),
+                    error = %format!("{e:#}"),
+                    "Inline indexing failed, spilling batch"
+                );
+                self.retry_at = Instant::now() + RETRY_PAUSE;
+                let spilled = self
+                    .spill
+                    .lock()
+                    .map_err(|_| anyhow::anyhow!("Spill file lock poisoned"))
+                    .and_then(|spill| spill.push(&articles));
+                if let Err(e) = spilled {
+                    tracing::warn!(error = %e, "Failed to spill articles");
+                }
+            }
+        }
+    }
+
+    /// Indexed and rejected counts of `articles`
+    async fn try_index(&mut self, articles: &[ParsedArticle]) -> Result<(usize, usize)> {
+        let mut docs: Vec<IndexDocument> = articles
+            .iter()
+            .map(|article| article_to_document(article, Vec::new(), None, None))
+            .collect();
+        let texts: Vec<String> = docs
+            .iter()
+            .map(|doc| {
+                let text = format!("{} {}", doc.title, doc.content);
+                text.chars().take(2000).collect()
+            })
+            .collect();
+        let embeddings = embed_by_category(
+            &self.client,
+            &self.embedding_url,
+            &self.config,
+            &docs,
+            &texts,
+        )
+        .await?;
+
+        for (doc, embedding) in docs.iter_mut().zip(embeddings) {
+            doc.embedding = embedding;
+        }
+        // Story assignment is incremental, so assign in publication order
+        docs.sort_by_key(document_time);
+        for doc in &mut docs {
+            doc.story_id = self
+                .stories
+                .assign(&doc.id, &doc.embedding, document_time(doc));
+        }
+
+        let result = self.store.bulk_index(&docs).await?;
+        if !result.errors.is_empty() {
+            tracing::warn!(errors = ?result.errors, "OpenSearch rejected articles");
+        }
+        Ok((result.success, result.failed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(aid: &str) -> ParsedArticle {
+        ParsedArticle {
+            oid: "001".to_string(),
+            aid: aid.to_string(),
+            title: format!("기사 {aid}"),
+            ..ParsedArticle::new_with_timestamp()
+        }
+    }
+
+    #[test]
+    fn test_spill_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut spill = SpillFile::new(dir.path().join("spill.jsonl"));
+        assert_eq!(spill.len().unwrap(), 0);
+        assert!(spill.take(10).unwrap().is_empty());
+
+        spill
+            .push(&[article("0000000001"), article("0000000002")])
+            .unwrap();
+        spill.push(&[article("0000000003")]).unwrap();
+        assert_eq!(spill.len().unwrap(), 3);
+
+        let taken = spill.take(2).unwrap();
+        let aids: Vec<&str> = taken.iter().map(|a| a.aid.as_str()).collect();
+        assert_eq!(aids, ["0000000001", "0000000002"]);
+        assert_eq!(spill.len().unwrap(), 1);
+
+        // Articles spilled after a take come after the rest
+        spill.push(&[article("0000000004")]).unwrap();
+        spill.compact().unwrap();
+        assert_eq!(spill.len().unwrap(), 2);
+        let reopened = SpillFile::new(spill.path.clone());
+        assert_eq!(reopened.len().unwrap(), 2);
+
+        let taken = spill.take(10).unwrap();
+        let aids: Vec<&str> = taken.iter().map(|a| a.aid.as_str()).collect();
+        assert_eq!(aids, ["0000000003", "0000000004"]);
+        assert!(!spill.path.exists());
+    }
+
+    #[test]
+    fn test_spill_file_skips_unreadable_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut spill = SpillFile::new(dir.path().join("spill.jsonl"));
+        spill.push(&[article("0000000001")]).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&spill.path).unwrap();
+        writeln!(file, "{{not json").unwrap();
+
+        let taken = spill.take(10).unwrap();
+        assert_eq!(taken.len(), 1);
+        assert_eq!(spill.len().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_submit_spills_when_queue_is_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let spill = Arc::new(Mutex::new(SpillFile::new(dir.path().join("spill.jsonl"))));
+        let (queue, mut receiver) = mpsc::channel(2);
+        let indexer = InlineIndexer {
+            queue,
+            spill: Arc::clone(&spill),
+            spilled: AtomicUsize::new(0),
+            worker: None,
+        };
+
+        for i in 1..=5 {
+            indexer.submit(&article(&format!("000000000{i}")));
+        }
+        assert_eq!(receiver.recv().await.unwrap().aid, "0000000001");
+        assert_eq!(receiver.recv().await.unwrap().aid, "0000000002");
+
+        let stats = indexer.finish().await;
+        assert_eq!(stats.spilled, 3);
+        assert_eq!(stats.pending, 3);
+        assert_eq!(spill.lock().unwrap().take(1).unwrap()[0].aid, "0000000003");
+    }
+}
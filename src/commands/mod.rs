@@ -1,17 +1,116 @@
+pub mod cache;
 pub mod cluster;
+pub mod config;
 pub mod crawl;
+pub mod dedupe;
+pub mod doctor;
+pub mod eval;
+pub mod export;
 pub mod index;
+pub mod inline_index;
 pub mod ontology;
+pub mod output;
+pub mod pipeline;
+pub mod progress;
+pub mod recrawl;
+pub mod report;
+pub mod retry_failed;
+pub mod runs;
 pub mod search;
 pub mod serve;
+pub mod shutdown;
+pub mod trends;
+pub mod tui;
+pub mod watch;
 
 // Re-export command functions for convenience
-pub use cluster::cluster;
-pub use crawl::{crawl, resume, stats};
-pub use index::index;
-pub use ontology::ontology;
-pub use search::search;
+pub use cache::{cache_invalidate, cache_stats, cache_warm, CacheWarmParams};
+pub use cluster::{cluster, topics, TopicsParams};
+pub use config::{config_init, config_show};
+pub use crawl::{crawl, resume, stats, CrawlParams};
+pub use dedupe::{dedupe, DedupeParams};
+pub use doctor::doctor;
+pub use eval::{eval_ontology, eval_search, EvalOntologyParams, EvalSearchParams};
+pub use export::{export, ExportParams};
+pub use index::{index, IndexParams};
+pub use ontology::{ontology, ontology_query, OntologyParams, OntologyQueryParams};
+pub use output::CommandResult;
+pub use pipeline::{pipeline, PipelineParams};
+pub use progress::{Progress, ProgressMode};
+pub use recrawl::{recrawl, RecrawlParams};
+pub use report::{crawl_report, report, CrawlReportParams, ReportParams};
+pub use retry_failed::{retry_failed, RetryFailedParams};
+pub use runs::RunRecord;
+pub use search::{search, SearchParams};
 pub use serve::{
-    api_server, coordinator_server, distributed_crawler, embedding_server, CoordinatorParams,
-    DistributedCrawlerParams,
+    api_server, coordinator_server, distributed_crawler, embedding_server, ApiServerParams,
+    CoordinatorParams, DistributedCrawlerParams,
 };
+pub use shutdown::Shutdown;
+pub use trends::{
+    bursts, entity_network, entity_profile, keyword_trends, publisher_coverage, sentiment_trends,
+    BurstParams, CoverageParams, EntityNetworkParams, EntityProfileParams, KeywordTrendsParams,
+    SentimentTrendsParams,
+};
+pub use tui::{tui, TuiParams};
+pub use watch::{watch, WatchParams};
+
+use baram::cache::OptionalCache;
+use rust_i18n::t;
+use unicode_width::UnicodeWidthStr;
+
+/// Print a section title underlined with `underline` to its display width
+///
+/// Titles come from the locale files, so the underline cannot be a fixed
+/// string: Korean and Chinese titles are twice as wide per character.
+/// Skipped with `--quiet`.
+pub(crate) fn print_title(title: &str, underline: char) {
+    if output::is_quiet() {
+        return;
+    }
+    println!("{title}");
+    println!("{}", underline.to_string().repeat(title.width()));
+}
+
+/// `text` padded with spaces to `width` columns, left aligned
+///
+/// `format!("{:<12}")` counts characters, which leaves Korean and Chinese
+/// labels twice as wide as their column.
+pub(crate) fn pad_end(text: &str, width: usize) -> String {
+    format!("{text}{}", " ".repeat(width.saturating_sub(text.width())))
+}
+
+/// `text` padded with spaces to `width` columns, right aligned
+pub(crate) fn pad_start(text: &str, width: usize) -> String {
+    format!("{}{text}", " ".repeat(width.saturating_sub(text.width())))
+}
+
+/// Print the hits and misses of the lookups in `cache`, if Redis was used
+pub(crate) fn print_cache_stats(cache: &OptionalCache) {
+    if !cache.is_available() {
+        return;
+    }
+    let stats = cache.stats();
+    output::say!(
+        "{}",
+        t!(
+            "cli.common.cache_stats",
+            hits = stats.hits,
+            misses = stats.misses,
+            rate = format!("{:.1}", stats.hit_rate() * 100.0)
+        )
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_by_display_width() {
+        assert_eq!(pad_end("정치", 6), "정치  ");
+        assert_eq!(pad_end("it", 6), "it    ");
+        assert_eq!(pad_start("成功率", 8), "  成功率");
+        assert_eq!(pad_end("카테고리", 4), "카테고리");
+    }
+}
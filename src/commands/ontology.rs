@@ -1,13 +1,30 @@
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use rust_i18n::t;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use baram::config::Config;
+use baram::export::write_turtle;
+use baram::health::{Dependency, DependencyChecker, DependencyMonitor, OutageNotifier};
 use baram::llm::{LlmBackend, LlmClient};
+use baram::metrics::push::JobMetrics;
 use baram::models::ParsedArticle;
-use baram::ontology::{RelationExtractor, RelationType, TripleStore};
+use baram::ontology::{
+    EntityLinker, HallucinationVerifier, LinkedTripleStore, RelationExtractor, RelationSource,
+    StorageConfig, TriplePattern, TripleQuery, TripleStorage, TripleStore,
+};
+use baram::storage::{MarkdownArticle, MarkdownReader};
 use futures::stream::{self, StreamExt};
 use tokio::sync::Mutex;
 
+use super::output::say;
+use super::progress::Progress;
+use super::search::format_table;
+use super::shutdown::Shutdown;
+use super::trends::load_extractions;
+
 /// Checkpoint data structure for resumable ontology extraction
 #[derive(serde::Deserialize, serde::Serialize, Default)]
 struct OntologyCheckpoint {
@@ -76,16 +93,46 @@ impl OntologyCheckpoint {
     }
 }
 
+/// Parameters for `baram ontology`
+pub struct OntologyParams {
+    /// Markdown file or directory of articles
+    pub input: String,
+    /// Output format (json, turtle, rdf, ntriples, jsonld)
+    pub format: String,
+    /// Output file path
+    pub output: Option<String>,
+    /// Link entities to knowledge base URIs for ntriples and jsonld output
+    pub link_entities: bool,
+    /// Triple storage directory to save each article's triples to
+    pub store: Option<PathBuf>,
+    /// Add verified LLM Said relations to the regex extraction
+    pub llm: bool,
+    /// Maximum concurrent LLM requests
+    pub max_concurrent: usize,
+    /// Stops sending LLM batches once requested; the articles of batches
+    /// already done are still exported
+    pub shutdown: Shutdown,
+}
+
 pub async fn ontology(
-    input: String,
-    format: String,
-    output: Option<String>,
-    use_llm: bool,
-    max_concurrent: usize,
+    config: &Config,
+    params: OntologyParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
 ) -> Result<()> {
+    let OntologyParams {
+        input,
+        format,
+        output,
+        link_entities,
+        store: store_dir,
+        llm: use_llm,
+        max_concurrent,
+        shutdown,
+    } = params;
     let input_path = PathBuf::from(&input);
     if !input_path.exists() {
-        anyhow::bail!("Input path does not exist: {input}");
+        anyhow::bail!("{}", t!("cli.common.input_not_found", path = input));
     }
 
     // Collect markdown files
@@ -98,14 +145,14 @@ pub async fn ontology(
             .map(|e| e.path())
             .collect();
 
-        println!("Found {} markdown files, parsing...", paths.len());
+        say!("{}", t!("cli.ontology.found_files", count = paths.len()));
 
         // Parallel file parsing using spawn_blocking + buffer_unordered
         let concurrency = num_cpus().min(8);
         let results: Vec<_> = stream::iter(paths)
             .map(|path| {
                 tokio::task::spawn_blocking(move || {
-                    let res = parse_markdown_to_article(&path);
+                    let res = read_article(&path);
                     (path, res)
                 })
             })
@@ -125,18 +172,15 @@ pub async fn ontology(
             }
         }
     } else {
-        articles.push(parse_markdown_to_article(&input_path)?);
+        articles.push(read_article(&input_path)?);
     }
 
     if articles.is_empty() {
-        println!("No articles to process.");
+        say!("{}", t!("cli.ontology.no_articles"));
         return Ok(());
     }
 
-    println!(
-        "Processing {} articles for ontology extraction...",
-        articles.len()
-    );
+    say!("{}", t!("cli.ontology.processing", count = articles.len()));
 
     // Initialize LLM client if requested
     let llm_client = if use_llm {
@@ -145,17 +189,22 @@ pub async fn ontology(
                 let backend_name = match client.backend() {
                     LlmBackend::Vllm => "vLLM",
                     LlmBackend::Ollama => "Ollama",
+                    LlmBackend::OpenAi => "Hosted API",
+                    LlmBackend::Mock => "Mock",
                 };
                 if client.is_available().await {
-                    println!("LLM extraction enabled ({backend_name})");
+                    say!("{}", t!("cli.ontology.llm_enabled", backend = backend_name));
                     Some(client)
                 } else {
-                    println!("Warning: {backend_name} not available, falling back to regex-only extraction");
+                    say!(
+                        "{}",
+                        t!("cli.ontology.llm_unavailable", backend = backend_name)
+                    );
                     None
                 }
             }
             Err(e) => {
-                println!("Warning: Failed to initialize LLM client: {e}");
+                say!("{}", t!("cli.ontology.llm_init_failed", error = e));
                 None
             }
         }
@@ -177,7 +226,7 @@ pub async fn ontology(
 
     // Load checkpoint
     let checkpoint_path = PathBuf::from("./output/checkpoints/ontology_checkpoint.json");
-    let checkpoint =
+    let mut checkpoint =
         OntologyCheckpoint::load(&checkpoint_path).context("Failed to load checkpoint")?;
 
     // Filter out already-processed articles
@@ -188,25 +237,48 @@ pub async fn ontology(
         .collect();
 
     if unprocessed_articles.len() < initial_count {
-        println!(
-            "Resuming from checkpoint: {} already processed, {} remaining",
-            initial_count - unprocessed_articles.len(),
-            unprocessed_articles.len()
+        say!(
+            "{}",
+            t!(
+                "cli.ontology.resuming",
+                processed = initial_count - unprocessed_articles.len(),
+                remaining = unprocessed_articles.len()
+            )
         );
     }
 
-    let articles = unprocessed_articles;
+    let mut articles = unprocessed_articles;
 
     if articles.is_empty() {
-        println!("All articles have been processed. No work to do.");
+        say!("{}", t!("cli.ontology.all_processed"));
         return Ok(());
     }
 
     // Pre-extract LLM Said relations in batches for better performance
     let llm_results: Arc<Mutex<std::collections::HashMap<String, Vec<baram::llm::SaidRelation>>>> =
         Arc::new(Mutex::new(std::collections::HashMap::new()));
+    // Articles whose LLM batch succeeded; checkpointed once exported
+    let llm_processed: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
 
     if let Some(ref client) = llm_client {
+        // Hold batches back while Ollama is down instead of failing them
+        let health = &config.notifications.dependency_health;
+        let (llm_gate, _llm_monitor) = if *client.backend() == LlmBackend::Ollama {
+            let mut monitor =
+                DependencyMonitor::new(DependencyChecker::new().with_ollama(client.endpoint()))
+                    .with_interval(health.probe_interval());
+            if let Some(notifier) =
+                OutageNotifier::from_config(&config.notifications).map_err(anyhow::Error::msg)?
+            {
+                monitor = monitor.with_notifier(notifier);
+            }
+            let gate = monitor.gate(Dependency::Ollama);
+            (gate, Some(monitor.start().await))
+        } else {
+            (None, None)
+        };
+        let max_wait = health.max_wait();
+
         // Prepare all batches
         let batches: Vec<(usize, Vec<baram::llm::ArticleInfo>)> = articles
             .chunks(LLM_BATCH_SIZE)
@@ -227,40 +299,57 @@ pub async fn ontology(
             .collect();
 
         let total_batches = batches.len();
-        println!(
-            "Processing {} batches for LLM extraction (max {} concurrent)...",
-            total_batches, max_concurrent
+        say!(
+            "{}",
+            t!(
+                "cli.ontology.llm_batches",
+                count = total_batches,
+                max = max_concurrent
+            )
         );
 
         // Shared state for progress tracking (thread-safe)
         let failed_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let said_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
         let failed_articles_shared = Arc::new(Mutex::new(Vec::new()));
+        // Articles of the batches sent, kept if the run is interrupted
+        let sent_articles = Arc::new(Mutex::new(HashSet::new()));
+        let llm_task = progress.task("llm", "batches", total_batches as u64);
 
-        // Process batches concurrently
-        stream::iter(batches)
+        // Process batches concurrently; once a shutdown is requested the
+        // batches not started are skipped and the rest get the grace period
+        let batches = stream::iter(batches)
             .map(|(batch_idx, batch)| {
                 let client_ref = client;
                 let results = Arc::clone(&llm_results);
                 let fail_count = Arc::clone(&failed_count);
                 let said = Arc::clone(&said_count);
                 let failed_list = Arc::clone(&failed_articles_shared);
-                let checkpoint_path_clone = checkpoint_path.clone();
+                let processed = Arc::clone(&llm_processed);
+                let llm_task = llm_task.clone();
+                let llm_gate = llm_gate.clone();
+                let sent = Arc::clone(&sent_articles);
+                let shutdown = &shutdown;
 
                 async move {
                     use baram::utils::retry::{with_retry_if, RetryConfig};
 
+                    if shutdown.is_requested() {
+                        llm_task.inc(1);
+                        return;
+                    }
+                    sent.lock()
+                        .await
+                        .extend(batch.iter().map(|article| article.id.clone()));
+
+                    if let Some(gate) = llm_gate.filter(|gate| !gate.is_available()) {
+                        llm_task.set_message(t!("cli.ontology.llm_waiting").to_string());
+                        gate.wait_available(max_wait).await;
+                    }
+
                     let retry_config = RetryConfig::with_delays(2, 2000, 10_000);
                     let batch_clone = batch.clone();
 
-                    // Progress indicator
-                    println!(
-                        "  Starting LLM batch {}/{} ({} articles)...",
-                        batch_idx + 1,
-                        total_batches,
-                        batch.len()
-                    );
-
                     match with_retry_if(
                         &retry_config,
                         || {
@@ -279,7 +368,6 @@ pub async fn ontology(
                     .await
                     {
                         Ok(batch_results) => {
-                            let mut batch_ids = Vec::new();
                             let mut batch_said_count = 0;
 
                             // Insert results
@@ -287,26 +375,20 @@ pub async fn ontology(
                                 let mut results_guard = results.lock().await;
                                 for (id, relations) in batch_results {
                                     batch_said_count += relations.len();
-                                    batch_ids.push(id.clone());
                                     results_guard.insert(id, relations);
                                 }
                             }
-
-                            // Update checkpoint atomically
-                            {
-                                let mut cp = OntologyCheckpoint::load(&checkpoint_path_clone)
-                                    .unwrap_or_default();
-                                cp.add_processed(batch_ids);
-                                let _ = cp.save(&checkpoint_path_clone);
-                            }
+                            processed
+                                .lock()
+                                .await
+                                .extend(batch_clone.iter().map(|article| article.id.clone()));
 
                             said.fetch_add(batch_said_count, std::sync::atomic::Ordering::Relaxed);
-                            println!(
-                                "  Completed LLM batch {}/{} ({} Said relations)",
+                            llm_task.set_message(format!(
+                                "batch {} done ({} Said relations)",
                                 batch_idx + 1,
-                                total_batches,
                                 batch_said_count
-                            );
+                            ));
                         }
                         Err(e) => {
                             fail_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
@@ -324,11 +406,20 @@ pub async fn ontology(
                             }
                         }
                     }
+                    llm_task.inc(1);
                 }
             })
             .buffer_unordered(max_concurrent)
-            .collect::<Vec<_>>()
-            .await;
+            .collect::<Vec<_>>();
+        if shutdown.with_grace(batches).await.is_none() {
+            tracing::warn!("Shutdown grace period over, leaving the LLM batches in flight");
+        }
+        llm_task.finish();
+
+        if shutdown.is_requested() {
+            let sent = sent_articles.lock().await;
+            articles.retain(|article| sent.contains(&article.id()));
+        }
 
         // Update final counts
         total_said_relations = said_count.load(std::sync::atomic::Ordering::Relaxed);
@@ -338,46 +429,34 @@ pub async fn ontology(
         let failed_from_llm = failed_articles_shared.lock().await;
         failed_articles.extend_from_slice(&failed_from_llm);
 
-        println!(
-            "\n  LLM extraction: {} Said relations found",
-            total_said_relations
+        say!(
+            "  {}",
+            t!("cli.ontology.said_found", count = total_said_relations)
         );
     }
 
     // Now process articles with regex extraction + merge LLM results
+    let mut said_by_article = std::mem::take(&mut *llm_results.lock().await);
+    let llm_processed = std::mem::take(&mut *llm_processed.lock().await);
+    let mut exported_ids = Vec::new();
+    let verifier = HallucinationVerifier::for_said_relations();
+    let mut merged_said = 0;
     let mut successful_articles = 0;
-    for (idx, article) in articles.iter().enumerate() {
-        print!(
-            "\r  Building ontology {}/{} articles...",
-            idx + 1,
-            articles.len()
-        );
-        std::io::Write::flush(&mut std::io::stdout())?;
+    let build_task = progress.task("ontology", "articles", articles.len() as u64);
+    let mut built = 0;
+    for article in &articles {
+        if shutdown.is_requested() {
+            break;
+        }
+        built += 1;
+        build_task.set_message(article.title.clone());
+        if llm_processed.contains(&article.id()) {
+            exported_ids.push(article.id());
+        }
 
         // Regex-based extraction with error handling
         let result = match std::panic::catch_unwind(|| extractor.extract_from_article(article)) {
-            Ok(mut result) => {
-                // Merge LLM Said relations if available (remove to free memory after use)
-                if llm_client.is_some() {
-                    let mut results_guard = llm_results.blocking_lock();
-                    if let Some(said_relations) = results_guard.remove(&article.id()) {
-                        for said in &said_relations {
-                            let relation = baram::ontology::ExtractedRelation {
-                                subject: said.speaker.clone(),
-                                subject_type: baram::ontology::EntityType::Person,
-                                predicate: RelationType::Said,
-                                object: said.content.clone(),
-                                object_type: baram::ontology::EntityType::Other,
-                                confidence: said.confidence,
-                                evidence: said.evidence.clone(),
-                                verified: true,
-                            };
-                            result.relations.push(relation);
-                        }
-                    }
-                }
-                Some(result)
-            }
+            Ok(result) => Some(result),
             Err(e) => {
                 tracing::warn!(
                     article_id = %article.id(),
@@ -390,69 +469,125 @@ pub async fn ontology(
         };
 
         if let Some(result) = result {
-            total_entities += result.entities.len();
-            total_relations += result.relations.len();
+            let mut store = TripleStore::from_extraction(&result, &article.title)
+                .with_published_at(article.published_at);
+
+            // Merge LLM Said relations whose speaker appears in the article
+            // (remove to free memory after use)
+            if let Some(said_relations) = said_by_article.remove(&article.id()) {
+                let text = format!("{}\n{}", article.title, article.content);
+                let verified: Vec<_> = verifier
+                    .verify_said(said_relations, &text)
+                    .into_iter()
+                    .filter(|relation| relation.verified)
+                    .collect();
+                merged_said += verified.len();
+                store.add_relations(&verified, RelationSource::Llm);
+            }
 
-            let store = TripleStore::from_extraction(&result, &article.title);
+            total_entities += result.entities.len();
+            total_relations += store.triples.len();
             all_stores.push(store);
             successful_articles += 1;
         }
+        build_task.inc(1);
     }
-    println!();
-
-    println!("Extraction complete:");
-    println!(
-        "  Successful articles: {}/{}",
-        successful_articles,
-        articles.len()
+    build_task.finish();
+
+    job.set("articles", built as u64);
+    job.set("successful", successful_articles as u64);
+    job.set("failed", failed_articles.len() as u64);
+    job.set("entities", total_entities as u64);
+    job.set("relations", total_relations as u64);
+    job.set("said_relations", merged_said as u64);
+    job.set("failed_llm_batches", failed_llm_batches as u64);
+
+    say!("{}", t!("cli.ontology.complete"));
+    say!(
+        "  {}",
+        t!(
+            "cli.ontology.successful",
+            count = successful_articles,
+            total = built
+        )
     );
-    println!("  Total entities: {total_entities}");
-    println!("  Total relations: {total_relations}");
-    if total_said_relations > 0 {
-        println!("  Said relations (LLM): {total_said_relations}");
+    say!("  {}", t!("cli.ontology.entities", count = total_entities));
+    say!(
+        "  {}",
+        t!("cli.ontology.relations", count = total_relations)
+    );
+    if llm_client.is_some() {
+        say!(
+            "  {}",
+            t!("cli.ontology.said_relations", count = merged_said)
+        );
     }
 
     // Report failures if any
     if !failed_articles.is_empty() {
-        println!("\n  Failed articles: {}", failed_articles.len());
+        say!(
+            "\n  {}",
+            t!(
+                "cli.ontology.failed_articles",
+                count = failed_articles.len()
+            )
+        );
         for (id, reason) in &failed_articles {
             tracing::warn!(article_id = %id, reason = %reason, "Article processing failed");
         }
     }
     if failed_llm_batches > 0 {
-        println!("  Failed LLM batches: {}", failed_llm_batches);
+        say!(
+            "  {}",
+            t!("cli.ontology.failed_batches", count = failed_llm_batches)
+        );
+    }
+
+    if let Some(dir) = store_dir {
+        let mut storage = TripleStorage::new(StorageConfig::builder().base_dir(&dir).build()?)?;
+        for store in &all_stores {
+            storage.save(store)?;
+        }
+        say!(
+            "  {}",
+            t!(
+                "cli.ontology.stored",
+                count = all_stores.len(),
+                path = dir.display()
+            )
+        );
     }
 
     // Combine all stores and export
     let combined_output = match format.to_lowercase().as_str() {
+        // `json-ld` named the JSON output before `jsonld` existed and keeps
+        // doing so for existing scripts
         "json" | "json-ld" => {
-            let combined: Vec<_> = all_stores
-                .iter()
-                .map(|s| {
-                    serde_json::json!({
-                        "article_id": s.article_id,
-                        "article_title": s.article_title,
-                        "extracted_at": s.extracted_at,
-                        "triples": s.triples,
-                        "stats": s.stats,
-                    })
-                })
-                .collect();
+            let combined: Vec<_> = all_stores.iter().map(store_to_json).collect();
             serde_json::to_string_pretty(&combined)?
         }
-        "turtle" | "ttl" => {
+        "ntriples" | "nt" => {
             let mut output = String::new();
-            output.push_str("@prefix schema: <https://schema.org/> .\n");
-            output.push_str("@prefix baram: <https://baram.example.org/ontology/> .\n");
-            output.push_str("@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\n");
-
-            for store in &all_stores {
-                output.push_str(&format!("# Article: {}\n", store.article_title));
-                output.push_str(&store.to_turtle());
-                output.push('\n');
+            for store in linked_stores(&all_stores, link_entities) {
+                for triple in &store.triples {
+                    output.push_str(&triple.to_ntriples());
+                    output.push('\n');
+                }
             }
             output
         }
+        "jsonld" => {
+            let combined = linked_stores(&all_stores, link_entities)
+                .iter()
+                .map(|store| Ok(serde_json::from_str(&store.to_json_ld()?)?))
+                .collect::<Result<Vec<serde_json::Value>>>()?;
+            serde_json::to_string_pretty(&combined)?
+        }
+        "turtle" | "ttl" => {
+            let mut output = Vec::new();
+            write_turtle(&all_stores, &mut output)?;
+            String::from_utf8(output)?
+        }
         "rdf" | "rdf-xml" => {
             let mut output = String::new();
             output.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
@@ -478,96 +613,185 @@ pub async fn ontology(
             output.push_str("</rdf:RDF>\n");
             output
         }
-        _ => anyhow::bail!("Unsupported format: {format}. Use json, turtle, or rdf."),
+        _ => anyhow::bail!("{}", t!("cli.ontology.unsupported_format", format = format)),
     };
 
-    // Write output
-    if let Some(output_path) = output {
+    // Write output; an interrupted run that built nothing must not replace
+    // an earlier export
+    if all_stores.is_empty() {
+        say!("{}", t!("cli.ontology.nothing_to_export"));
+    } else if let Some(output_path) = output {
         std::fs::write(&output_path, &combined_output)?;
-        println!("Output written to: {output_path}");
+        say!("{}", t!("cli.common.output_written", path = output_path));
     } else {
         println!("\n{combined_output}");
     }
 
+    // Articles count as processed only once their triples are exported
+    if !exported_ids.is_empty() {
+        checkpoint.add_processed(exported_ids);
+        if let Err(e) = checkpoint.save(&checkpoint_path) {
+            tracing::warn!(error = %e, "Failed to save ontology checkpoint");
+        }
+    }
+
+    if shutdown.is_requested() {
+        job.set("interrupted", 1);
+        say!("\n{}", t!("cli.common.interrupted"));
+    }
+
     Ok(())
 }
 
-fn num_cpus() -> usize {
-    std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(4)
+/// JSON export of one article's triples (`--format json`)
+pub(crate) fn store_to_json(store: &TripleStore) -> serde_json::Value {
+    serde_json::json!({
+        "article_id": store.article_id,
+        "article_title": store.article_title,
+        "extracted_at": store.extracted_at,
+        "published_at": store.published_at,
+        "triples": store.triples,
+        "entities": store.entities,
+        "stats": store.stats,
+    })
 }
 
-/// Parse markdown file to ParsedArticle for ontology extraction
-pub fn parse_markdown_to_article(path: &std::path::Path) -> Result<ParsedArticle> {
-    use chrono::Utc;
+/// Parameters for `baram ontology query`
+pub struct OntologyQueryParams {
+    /// Triple storage directory or `baram ontology` JSON output
+    pub input: PathBuf,
+    /// Subject pattern
+    pub subject: Option<String>,
+    /// Predicate pattern
+    pub predicate: Option<String>,
+    /// Object pattern
+    pub object: Option<String>,
+    /// Minimum triple confidence
+    pub min_confidence: f32,
+    /// Only verified triples
+    pub verified: bool,
+    /// Earliest article date (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Latest article date (YYYY-MM-DD)
+    pub until: Option<String>,
+    /// Maximum number of triples (0 = unlimited)
+    pub limit: usize,
+    /// Output format (table, json)
+    pub format: String,
+}
 
-    let content = std::fs::read_to_string(path)
-        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+/// Print the stored triples matching a subject/predicate/object pattern
+pub fn ontology_query(params: OntologyQueryParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "table" | "json") {
+        anyhow::bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "table, json"
+            )
+        );
+    }
+    if !params.input.exists() {
+        anyhow::bail!(
+            "{}",
+            t!("cli.common.input_not_found", path = params.input.display())
+        );
+    }
+    let parse_date = |date: &Option<String>| -> Result<Option<NaiveDate>> {
+        date.as_deref()
+            .map(|date| {
+                NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                    .with_context(|| t!("cli.common.invalid_date", date = date).to_string())
+            })
+            .transpose()
+    };
 
-    let lines: Vec<&str> = content.lines().collect();
+    let query = TripleQuery {
+        pattern: TriplePattern::new(
+            params.subject.as_deref(),
+            params.predicate.as_deref(),
+            params.object.as_deref(),
+        ),
+        min_confidence: params.min_confidence,
+        verified_only: params.verified,
+        since: parse_date(&params.since)?,
+        until: parse_date(&params.until)?,
+        limit: params.limit,
+    };
+    let matches = if params.input.is_dir() {
+        let config = StorageConfig::builder()
+            .base_dir(&params.input)
+            .create_dirs(false)
+            .build_unchecked();
+        TripleStorage::new(config)?.query(&query)?
+    } else {
+        query.run(&load_extractions(&params.input)?)
+    };
 
-    // Extract title
-    let title = lines
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&matches)?);
+        return Ok(());
+    }
+    if matches.is_empty() {
+        say!("{}", t!("cli.ontology.no_matches"));
+        return Ok(());
+    }
+
+    let rows: Vec<Vec<String>> = matches
         .iter()
-        .find(|l| l.starts_with("# "))
-        .map(|l| l.trim_start_matches("# ").to_string())
-        .unwrap_or_else(|| "Untitled".to_string());
-
-    // Extract metadata from frontmatter
-    let mut oid = String::new();
-    let mut aid = String::new();
-    let mut category = String::new();
-    let mut publisher = None;
-    let mut author = None;
-    let mut url = String::new();
-    let published_at = None;
-
-    let mut frontmatter_delim_count = 0;
-    let mut in_metadata = false;
-    let mut body_lines = Vec::new();
-
-    for line in &lines {
-        if line.starts_with("---") {
-            if frontmatter_delim_count < 2 {
-                frontmatter_delim_count += 1;
-                in_metadata = frontmatter_delim_count == 1;
-                continue;
-            }
-        }
+        .map(|m| {
+            vec![
+                m.date.map(|d| d.to_string()).unwrap_or_default(),
+                m.triple.subject.clone(),
+                m.triple.predicate_label.clone(),
+                m.triple.object.clone(),
+                format!("{:.2}", m.triple.confidence),
+                m.article_title.clone(),
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        format_table(
+            &[
+                "date",
+                "subject",
+                "predicate",
+                "object",
+                "confidence",
+                "article"
+            ],
+            &rows
+        )
+    );
+    say!("{}", t!("cli.ontology.matches", count = matches.len()));
+    Ok(())
+}
 
-        if in_metadata {
-            if let Some((key, value)) = line.split_once(':') {
-                let key = key.trim();
-                let value = value.trim().trim_matches('"');
-                match key {
-                    "oid" => oid = value.to_string(),
-                    "aid" => aid = value.to_string(),
-                    "category" => category = value.to_string(),
-                    "publisher" => publisher = Some(value.to_string()),
-                    "author" => author = Some(value.to_string()),
-                    "url" => url = value.to_string(),
-                    _ => {}
-                }
-            }
-        } else if frontmatter_delim_count >= 2 && !line.starts_with('#') {
-            body_lines.push(*line);
-        }
+/// Stores for the RDF serializations, linked to the knowledge base when
+/// `--link-entities` is set
+fn linked_stores(stores: &[TripleStore], link_entities: bool) -> Vec<LinkedTripleStore> {
+    if link_entities {
+        let mut linker = EntityLinker::new();
+        stores
+            .iter()
+            .map(|store| linker.apply_to_triple_store(store))
+            .collect()
+    } else {
+        stores.iter().map(LinkedTripleStore::from).collect()
     }
+}
 
-    let article_content = body_lines.join("\n");
-
-    Ok(ParsedArticle {
-        oid,
-        aid,
-        title,
-        content: article_content,
-        url,
-        category,
-        publisher,
-        author,
-        published_at,
-        crawled_at: Utc::now(),
-        content_hash: None,
-    })
+/// Read a saved article markdown file for extraction
+fn read_article(path: &std::path::Path) -> Result<ParsedArticle> {
+    MarkdownReader::new()
+        .read(path)
+        .map(MarkdownArticle::into_article)
+}
+
+fn num_cpus() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
 }
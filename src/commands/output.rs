@@ -0,0 +1,111 @@
+//! Quiet and JSON result output for automation
+//!
+//! `--quiet` drops the decorative stdout output of a command: titles,
+//! echoed settings, status lines and summaries, all printed with [`say!`].
+//! What the command was asked to produce (search results, reports, exports
+//! to stdout) is still printed.
+//!
+//! `--output-json` implies `--quiet` and ends every run with exactly one
+//! JSON object on stdout, so cron jobs and CI wrappers do not have to parse
+//! the human-readable output:
+//!
+//! ```text
+//! {"command":"index","status":"ok","duration_ms":81234,"counts":{"failed":2,"indexed":118}}
+//! {"command":"report crawl","status":"error","duration_ms":3,"error":"No crawl runs recorded in output/crawl.db"}
+//! ```
+//!
+//! Logs and progress go to stderr and are not affected.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress decorative output for the rest of the run
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` (or `--output-json`) is set
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// `println!` for decorative output, skipped with `--quiet`
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::commands::output::is_quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+pub(crate) use say;
+
+/// Outcome of one command, as printed by `--output-json`
+#[derive(Debug, Serialize)]
+pub struct CommandResult {
+    /// Subcommand path, e.g. `crawl` or `report crawl`
+    pub command: String,
+    /// `ok` or `error`
+    pub status: &'static str,
+    pub duration_ms: u64,
+    /// Items handled by the run (e.g. `indexed`, `failed`), if the command
+    /// records them
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub counts: BTreeMap<String, u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CommandResult {
+    pub fn new(
+        command: impl Into<String>,
+        elapsed: Duration,
+        counts: BTreeMap<String, u64>,
+        result: &anyhow::Result<()>,
+    ) -> Self {
+        Self {
+            command: command.into(),
+            status: if result.is_ok() { "ok" } else { "error" },
+            duration_ms: elapsed.as_millis() as u64,
+            counts,
+            error: result.as_ref().err().map(|e| format!("{e:#}")),
+        }
+    }
+
+    /// Print the result as one line of JSON on stdout
+    pub fn print(&self) {
+        match serde_json::to_string(self) {
+            Ok(line) => println!("{line}"),
+            Err(e) => tracing::error!(error = %e, "Failed to encode command result"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_result_json() {
+        let counts = BTreeMap::from([("indexed".to_string(), 118), ("failed".to_string(), 2)]);
+        let ok = CommandResult::new("index", Duration::from_millis(1500), counts, &Ok(()));
+        assert_eq!(
+            serde_json::to_string(&ok).unwrap(),
+            r#"{"command":"index","status":"ok","duration_ms":1500,"counts":{"failed":2,"indexed":118}}"#
+        );
+
+        let failed = CommandResult::new(
+            "report crawl",
+            Duration::from_millis(3),
+            BTreeMap::new(),
+            &Err(anyhow::anyhow!("No crawl runs recorded")),
+        );
+        assert_eq!(
+            serde_json::to_string(&failed).unwrap(),
+            r#"{"command":"report crawl","status":"error","duration_ms":3,"error":"No crawl runs recorded"}"#
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use anyhow::{Context, Result};
+use rust_i18n::t;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use baram::clustering::{StoryConfig, StoryTracker};
+use baram::config::{Config, DatabaseConfig};
+use baram::crawler::fetcher::NaverFetcher;
+use baram::crawler::list::NewsListCrawler;
+use baram::crawler::sites::NaverSite;
+use baram::crawler::url::UrlExtractor;
+use baram::crawler::Crawler;
+use baram::embedding::{article_to_document, VectorStore};
+use baram::error::ErrorClass;
+use baram::health::{
+    Dependency, DependencyChecker, DependencyGate, DependencyMonitor, MonitorHandle, OutageNotifier,
+};
+use baram::metrics;
+use baram::metrics::push::JobMetrics;
+use baram::models::{CrawlState, NewsCategory, ParsedArticle};
+use baram::ontology::{RelationExtractor, TripleStore};
+use baram::storage::{ArticleStorage, Database, TimeSeriesStore};
+
+use super::crawl::{
+    category_name, crawl_single_url, parse_category, record_timeseries, start_metrics_server,
+};
+use super::index::{
+    check_embedding_server, document_time, embedding_server_dimension, generate_embeddings_batch,
+    open_index,
+};
+use super::ontology::store_to_json;
+use super::output::say;
+use super::print_title;
+use super::progress::Progress;
+use super::runs::crawl_db_path;
+
+/// Instance label for metrics recorded by `baram pipeline`
+const METRICS_INSTANCE: &str = "pipeline";
+
+/// Parameters for `baram pipeline`
+pub struct PipelineParams {
+    /// News category to crawl (default: politics)
+    pub category: Option<String>,
+    /// Maximum number of articles to crawl
+    pub max_articles: usize,
+    /// Output directory for markdown files
+    pub output: PathBuf,
+    /// Skip already crawled articles
+    pub skip_existing: bool,
+    /// Embed and index each crawled article into OpenSearch
+    pub index: bool,
+    /// Extract the ontology of each crawled article
+    pub ontology: bool,
+    /// JSON file the extracted ontology is written to
+    pub ontology_output: PathBuf,
+    /// Serve Prometheus metrics on this port while running
+    pub metrics_port: Option<u16>,
+}
+
+/// Counts and stage timings of one pipeline run
+#[derive(Debug, Default)]
+struct PipelineSummary {
+    urls_found: usize,
+    crawled: usize,
+    skipped: usize,
+    crawl_failed: usize,
+    indexed: usize,
+    index_failed: usize,
+    placeholder_embeddings: usize,
+    held_back: usize,
+    stories_assigned: usize,
+    ontology_articles: usize,
+    entities: usize,
+    relations: usize,
+    crawl_time: Duration,
+    index_time: Duration,
+    ontology_time: Duration,
+}
+
+impl PipelineSummary {
+    fn record(&self, job: &mut JobMetrics) {
+        job.set("crawled", self.crawled as u64);
+        job.set("skipped", self.skipped as u64);
+        job.set("crawl_failed", self.crawl_failed as u64);
+        job.set("indexed", self.indexed as u64);
+        job.set("index_failed", self.index_failed as u64);
+        job.set("stories_assigned", self.stories_assigned as u64);
+        job.set("entities", self.entities as u64);
+        job.set("relations", self.relations as u64);
+    }
+}
+
+/// Most articles held back while the embedding server is down; later ones
+/// are indexed with dummy embeddings right away
+const MAX_HELD_BACK: usize = 1000;
+
+/// Indexing stage state
+struct IndexStage {
+    store: VectorStore,
+    client: reqwest::Client,
+    embedding_url: String,
+    /// `embedding_model` of the crawled category, if set
+    embedding_model: Option<String>,
+    /// Dimension of the index's embeddings, and of dummy embeddings
+    embedding_dim: usize,
+    stories: StoryTracker,
+    /// Availability of the embedding server, probed in the background
+    embedding: DependencyGate,
+    _monitor: MonitorHandle,
+    /// Articles held back while the embedding server is down
+    pending: Vec<ParsedArticle>,
+    /// Whether an article was embedded during the run
+    embedded_any: bool,
+    /// How long held-back articles wait for the server at the end of the run
+    max_wait: Duration,
+}
+
+impl IndexStage {
+    async fn connect(config: &Config, category: NewsCategory) -> Result<Self> {
+        let opensearch_config = config.opensearch.clone().with_env_overrides();
+        let store =
+            VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
+        let embedding_url = std::env::var("EMBEDDING_SERVER_URL")
+            .unwrap_or_else(|_| "http://localhost:8090".to_string());
+        let embedding_model = config.category(category).embedding_model;
+        let client = reqwest::Client::new();
+
+        // The index must fit the model's vectors; while the server is down
+        // an existing index is taken as it is
+        let wanted_dim = if check_embedding_server(&embedding_url).await {
+            Some(
+                embedding_server_dimension(&client, &embedding_url, embedding_model.as_deref())
+                    .await
+                    .context("Failed to read the embedding dimension")?,
+            )
+        } else {
+            None
+        };
+        let embedding_dim = open_index(&store, &opensearch_config.index_name, wanted_dim, false)
+            .await?
+            .dim();
+
+        let health = &config.notifications.dependency_health;
+        let mut monitor =
+            DependencyMonitor::new(DependencyChecker::new().with_embedding_server(&embedding_url))
+                .with_interval(health.probe_interval());
+        if let Some(notifier) =
+            OutageNotifier::from_config(&config.notifications).map_err(anyhow::Error::msg)?
+        {
+            monitor = monitor.with_notifier(notifier);
+        }
+        let embedding = monitor
+            .gate(Dependency::EmbeddingServer)
+            .context("Embedding server is not monitored")?;
+        let monitor = monitor.start().await;
+        if embedding.is_available() {
+            say!(
+                "{}",
+                t!("cli.index.embedding_available", url = embedding_url)
+            );
+        } else {
+            say!("{}", t!("cli.pipeline.embedding_down", url = embedding_url));
+        }
+
+        let mut stories = StoryTracker::new(StoryConfig::default());
+        if embedding.is_available() {
+            if let Err(e) = stories.load_from_store(&store, chrono::Utc::now()).await {
+                tracing::warn!(error = %e, "Failed to load open stories, starting fresh");
+            }
+        }
+
+        Ok(Self {
+            store,
+            client,
+            embedding_url,
+            embedding_model,
+            embedding_dim,
+            stories,
+            embedding,
+            _monitor: monitor,
+            pending: Vec::new(),
+            embedded_any: false,
+            max_wait: health.max_wait(),
+        })
+    }
+
+    /// Embed and index one article, or hold it back while the embedding
+    /// server is down
+    async fn index(&mut self, article: &ParsedArticle, summary: &mut PipelineSummary) {
+        if !self.embedding.is_available() {
+            self.hold_back(article, summary).await;
+            return;
+        }
+        self.drain(summary).await;
+
+        match self.embed(article).await {
+            Ok(embedding) => self.store(article, embedding, summary).await,
+            Err(e) => {
+                tracing::warn!(url = %article.url, error = %e, "Embedding failed, holding article back");
+                self.hold_back(article, summary).await;
+            }
+        }
+    }
+
+    /// Index the held-back articles once the embedding server is back
+    async fn drain(&mut self, summary: &mut PipelineSummary) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let pending = std::mem::take(&mut self.pending);
+        say!(
+            "{}",
+            t!("cli.pipeline.resuming_held_back", count = pending.len())
+        );
+        for article in &pending {
+            let embedding = match self.embed(article).await {
+                Ok(embedding) => embedding,
+                Err(e) => {
+                    tracing::warn!(url = %article.url, error = %e, "Embedding failed, using dummy embedding");
+                    summary.placeholder_embeddings += 1;
+                    vec![0.0; self.embedding_dim]
+                }
+            };
+            self.store(article, embedding, summary).await;
+        }
+    }
+
+    /// Index held-back articles with dummy embeddings, without waiting for
+    /// the embedding server
+    async fn store_placeholders(&mut self, summary: &mut PipelineSummary) {
+        for article in std::mem::take(&mut self.pending) {
+            summary.placeholder_embeddings += 1;
+            self.store(&article, vec![0.0; self.embedding_dim], summary)
+                .await;
+        }
+    }
+
+    /// Index the articles still held back at the end of the run
+    ///
+    /// Waits up to `max_wait` for the embedding server, unless no article
+    /// could be embedded during the run; articles it cannot embed are
+    /// indexed with dummy embeddings rather than dropped.
+    async fn finish(&mut self, summary: &mut PipelineSummary) {
+        if self.pending.is_empty() {
+            return;
+        }
+        if !self.embedded_any {
+            tracing::warn!(
+                count = self.pending.len(),
+                "Embedding server was never reached, indexing held-back articles with dummy embeddings"
+            );
+            self.store_placeholders(summary).await;
+            return;
+        }
+        say!(
+            "{}",
+            t!(
+                "cli.pipeline.waiting_embedding",
+                count = self.pending.len(),
+                secs = self.max_wait.as_secs()
+            )
+        );
+        self.embedding.wait_available(self.max_wait).await;
+        self.drain(summary).await;
+    }
+
+    /// Hold `article` back until the embedding server is back, or index it
+    /// with a dummy embedding once [`MAX_HELD_BACK`] articles are waiting
+    async fn hold_back(&mut self, article: &ParsedArticle, summary: &mut PipelineSummary) {
+        if self.pending.len() >= MAX_HELD_BACK {
+            tracing::warn!(
+                url = %article.url,
+                held_back = self.pending.len(),
+                "Too many articles held back, using dummy embedding"
+            );
+            summary.placeholder_embeddings += 1;
+            self.store(article, vec![0.0; self.embedding_dim], summary)
+                .await;
+            return;
+        }
+        summary.held_back += 1;
+        self.pending.push(article.clone());
+    }
+
+    async fn embed(&mut self, article: &ParsedArticle) -> Result<Vec<f32>> {
+        let text: String = format!("{} {}", article.title, article.content)
+            .chars()
+            .take(2000)
+            .collect();
+        let mut embeddings = generate_embeddings_batch(
+            &self.client,
+            &self.embedding_url,
+            &[text],
+            self.embedding_model.as_deref(),
+        )
+        .await?;
+        if embeddings.is_empty() {
+            anyhow::bail!("Embedding server returned no embedding");
+        }
+        self.embedded_any = true;
+        Ok(embeddings.remove(0))
+    }
+
+    async fn store(
+        &mut self,
+        article: &ParsedArticle,
+        embedding: Vec<f32>,
+        summary: &mut PipelineSummary,
+    ) {
+        let mut doc = article_to_document(article, embedding, None, None);
+        doc.story_id = self
+            .stories
+            .assign(&doc.id, &doc.embedding, document_time(&doc));
+        if doc.story_id.is_some() {
+            summary.stories_assigned += 1;
+        }
+
+        match self.store.bulk_index(std::slice::from_ref(&doc)).await {
+            Ok(result) if result.failed == 0 => summary.indexed += 1,
+            Ok(result) => {
+                summary.index_failed += 1;
+                tracing::warn!(id = %doc.id, errors = ?result.errors, "Failed to index article");
+            }
+            Err(e) => {
+                summary.index_failed += 1;
+                tracing::warn!(id = %doc.id, error = %e, "Failed to index article");
+            }
+        }
+    }
+}
+
+/// Crawl a category and index and extract each article as it is crawled
+pub async fn pipeline(
+    config: Config,
+    params: PipelineParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
+) -> Result<()> {
+    let PipelineParams {
+        category,
+        max_articles,
+        output,
+        skip_existing,
+        index,
+        ontology,
+        ontology_output,
+        metrics_port,
+    } = params;
+
+    print_title(&t!("cli.pipeline.starting"), '=');
+    let mut stages = vec![t!("cli.pipeline.stage_crawl")];
+    if index {
+        stages.push(t!("cli.pipeline.stage_index"));
+    }
+    if ontology {
+        stages.push(t!("cli.pipeline.stage_ontology"));
+    }
+    say!("{}", t!("cli.pipeline.stages", stages = stages.join(" → ")));
+
+    let category = parse_category(category.as_deref().unwrap_or("politics"))?;
+
+    let metrics_server = match metrics_port {
+        Some(port) => {
+            let (addr, handle) = start_metrics_server(port).await?;
+            say!("{}", t!("cli.common.metrics_endpoint", addr = addr));
+            Some(handle)
+        }
+        None => None,
+    };
+
+    // Crawl stage setup, as in `baram crawl`
+    let db_path = crawl_db_path(&output);
+    let db_config = DatabaseConfig {
+        sqlite_path: db_path.clone(),
+        postgres_url: String::new(),
+        pool_size: 5,
+        store_bodies: config.database.store_bodies,
+    };
+    let mut db = Database::new(&db_config)?;
+    db.init_sqlite(&db_path)?;
+    let mut series = TimeSeriesStore::open(&db_path)?;
+    let storage = ArticleStorage::with_recorded_format(&output, skip_existing)?;
+    let site = NaverSite::new();
+    let crawler = Crawler::new(config.clone())?;
+    let mut state = CrawlState::new();
+
+    let mut index_stage = if index {
+        Some(IndexStage::connect(&config, category).await?)
+    } else {
+        None
+    };
+    let extractor = ontology.then(RelationExtractor::new);
+    let mut ontology_stores: Vec<TripleStore> = Vec::new();
+
+    let mut summary = PipelineSummary::default();
+
+    // Collect article URLs
+    let fetcher = NaverFetcher::with_rate_limit(config.crawler.rate_limit)
+        .context("Failed to create fetcher")?
+        .with_policy(crawler.fetch_policy());
+    let list_crawler = NewsListCrawler::new(fetcher);
+    let url_extractor = UrlExtractor::new();
+    let today = chrono::Local::now().format("%Y%m%d").to_string();
+
+    say!(
+        "\n{}",
+        t!(
+            "cli.crawl.category",
+            name = category_name(category),
+            code = category.as_str()
+        )
+    );
+    let urls = list_crawler
+        .collect_urls(category, &today, max_articles.div_ceil(20) as u32)
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", t!("cli.crawl.collect_failed", error = e)))?;
+    summary.urls_found = urls.len();
+
+    let uncrawled_urls = if skip_existing {
+        db.filter_uncrawled(&urls)?
+    } else {
+        urls.clone()
+    };
+    metrics::record_dedup_results(
+        METRICS_INSTANCE,
+        uncrawled_urls.len(),
+        urls.len() - uncrawled_urls.len(),
+    );
+    metrics::update_crawler_state(METRICS_INSTANCE, true, None);
+    let _timer = metrics::start_crawl_timer(METRICS_INSTANCE, category.as_str());
+
+    let total = uncrawled_urls.len().min(max_articles);
+    say!(
+        "{}",
+        t!(
+            "cli.pipeline.found_urls",
+            count = urls.len(),
+            new_count = total
+        )
+    );
+
+    // One streaming pass: each article goes through every enabled stage
+    let task = progress.task("pipeline", category.as_str(), total as u64);
+    for url in uncrawled_urls.iter().take(max_articles) {
+        task.set_message(url.clone());
+
+        let started = Instant::now();
+        let crawled = crawl_single_url(
+            &crawler,
+            &site,
+            &storage,
+            &db,
+            url,
+            &mut state,
+            Some(&category),
+        )
+        .await;
+        summary.crawl_time += started.elapsed();
+
+        let article = match crawled {
+            Ok(Some(crawled)) => crawled.article,
+            Ok(None) => {
+                summary.skipped += 1;
+                metrics::record_pipeline_results(METRICS_INSTANCE, category.as_str(), 0, 0, 1);
+                task.inc(1);
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!(url = %url, error = %e, "Failed to crawl article");
+                summary.crawl_failed += 1;
+                state.record_error();
+                db.record_failure(url, &e.to_string(), ErrorClass::of(&e))?;
+                metrics::record_pipeline_results(METRICS_INSTANCE, category.as_str(), 0, 1, 0);
+                let oid = url_extractor
+                    .extract_ids(url)
+                    .map(|(oid, _)| oid)
+                    .unwrap_or_default();
+                metrics::record_crawl_error(METRICS_INSTANCE, &oid, ErrorClass::of(&e));
+                task.inc(1);
+                continue;
+            }
+        };
+
+        summary.crawled += 1;
+        record_timeseries(&mut series, &article);
+        metrics::record_articles_crawled(METRICS_INSTANCE, category.as_str(), 1);
+        metrics::record_pipeline_results(METRICS_INSTANCE, category.as_str(), 1, 0, 0);
+        metrics::record_publisher_success(METRICS_INSTANCE, article.publisher_id());
+
+        if let Some(stage) = index_stage.as_mut() {
+            let started = Instant::now();
+            stage.index(&article, &mut summary).await;
+            summary.index_time += started.elapsed();
+        }
+
+        if let Some(extractor) = &extractor {
+            let started = Instant::now();
+            let result = extractor.extract_from_article(&article);
+            summary.entities += result.entities.len();
+            summary.relations += result.relations.len();
+            summary.ontology_articles += 1;
+            ontology_stores.push(
+                TripleStore::from_extraction(&result, &article.title)
+                    .with_published_at(article.published_at),
+            );
+            summary.ontology_time += started.elapsed();
+        }
+        task.inc(1);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    task.finish();
+    metrics::update_crawler_state(METRICS_INSTANCE, false, None);
+
+    if let Some(stage) = index_stage.as_mut() {
+        let started = Instant::now();
+        stage.finish(&mut summary).await;
+        summary.index_time += started.elapsed();
+        stage.store.refresh().await?;
+    }
+
+    if ontology && !ontology_stores.is_empty() {
+        if let Some(parent) = ontology_output.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let combined: Vec<_> = ontology_stores.iter().map(store_to_json).collect();
+        std::fs::write(&ontology_output, serde_json::to_string_pretty(&combined)?)
+            .with_context(|| format!("Failed to write {}", ontology_output.display()))?;
+    }
+
+    summary.record(job);
+
+    say!();
+    print_title(&t!("cli.pipeline.summary"), '=');
+    say!(
+        "{}",
+        t!(
+            "cli.pipeline.summary_crawl",
+            crawled = summary.crawled,
+            skipped = summary.skipped,
+            failed = summary.crawl_failed,
+            urls = summary.urls_found,
+            secs = format!("{:.1}", summary.crawl_time.as_secs_f64())
+        )
+    );
+    if index {
+        say!(
+            "{}",
+            t!(
+                "cli.pipeline.summary_index",
+                indexed = summary.indexed,
+                failed = summary.index_failed,
+                stories = summary.stories_assigned,
+                secs = format!("{:.1}", summary.index_time.as_secs_f64())
+            )
+        );
+        if summary.held_back > 0 {
+            say!(
+                "          {}",
+                t!("cli.pipeline.held_back", count = summary.held_back)
+            );
+        }
+        if summary.placeholder_embeddings > 0 {
+            say!(
+                "          {}",
+                t!(
+                    "cli.pipeline.placeholder_embeddings",
+                    count = summary.placeholder_embeddings
+                )
+            );
+        }
+    }
+    if ontology {
+        say!(
+            "{}",
+            t!(
+                "cli.pipeline.summary_ontology",
+                articles = summary.ontology_articles,
+                entities = summary.entities,
+                relations = summary.relations,
+                secs = format!("{:.1}", summary.ontology_time.as_secs_f64())
+            )
+        );
+        if !ontology_stores.is_empty() {
+            say!(
+                "          {}",
+                t!("cli.pipeline.written_to", path = ontology_output.display())
+            );
+        }
+    }
+    say!(
+        "{}",
+        t!("cli.common.output_directory", path = output.display())
+    );
+    say!("{}", t!("cli.common.database", path = db_path.display()));
+
+    if let Some(handle) = metrics_server {
+        handle.abort();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! Progress reporting for long-running commands
+//!
+//! `--progress bar` (the default) draws one indicatif bar per category or
+//! batch stage on stderr; bars are hidden when stderr is not a terminal.
+//! `--progress json` instead writes one JSON object per line to stderr, so
+//! wrappers can follow a run without parsing the human-readable output:
+//!
+//! ```text
+//! {"event":"start","task":"crawl","label":"politics","position":0,"total":100,"elapsed_ms":0}
+//! {"event":"progress","task":"crawl","label":"politics","position":1,"total":100,"elapsed_ms":812,"eta_ms":80388,"message":"https://n.news.naver.com/..."}
+//! {"event":"finish","task":"crawl","label":"politics","position":100,"total":100,"elapsed_ms":79544}
+//! ```
+
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use serde::Serialize;
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// How progress is reported
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Progress bars on stderr
+    #[default]
+    Bar,
+    /// JSON progress events on stderr, one per line
+    Json,
+    /// No progress output
+    None,
+}
+
+impl FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bar" => Ok(Self::Bar),
+            "json" => Ok(Self::Json),
+            "none" => Ok(Self::None),
+            _ => Err(format!("Unknown progress mode: {s}. Use bar, json or none")),
+        }
+    }
+}
+
+/// Progress display shared by the stages of one command
+pub struct Progress {
+    mode: ProgressMode,
+    multi: MultiProgress,
+}
+
+impl Progress {
+    pub fn new(mode: ProgressMode) -> Self {
+        let target = match mode {
+            ProgressMode::Bar => ProgressDrawTarget::stderr(),
+            ProgressMode::Json | ProgressMode::None => ProgressDrawTarget::hidden(),
+        };
+        Self {
+            mode,
+            multi: MultiProgress::with_draw_target(target),
+        }
+    }
+
+    /// Start tracking `total` items of `task` (e.g. "crawl") for `label`
+    /// (e.g. a category)
+    pub fn task(&self, task: &'static str, label: impl Into<String>, total: u64) -> ProgressTask {
+        self.start(ProgressBar::new(total), bar_style(), task, label.into())
+    }
+
+    /// Start tracking a `task` whose total is not known up front, e.g. URLs
+    /// read from stdin
+    pub fn spinner(&self, task: &'static str, label: impl Into<String>) -> ProgressTask {
+        self.start(
+            ProgressBar::no_length(),
+            spinner_style(),
+            task,
+            label.into(),
+        )
+    }
+
+    fn start(
+        &self,
+        bar: ProgressBar,
+        style: ProgressStyle,
+        task: &'static str,
+        label: String,
+    ) -> ProgressTask {
+        let bar = self.multi.add(bar);
+        bar.set_style(style);
+        bar.set_prefix(format!("{task} {label}"));
+
+        let progress = ProgressTask {
+            bar,
+            json: self.mode == ProgressMode::Json,
+            task,
+            label,
+        };
+        progress.emit("start");
+        progress
+    }
+}
+
+fn bar_style() -> ProgressStyle {
+    ProgressStyle::with_template(
+        "{prefix:>18.bold} [{bar:30.cyan/blue}] {pos}/{len} ({eta}) {wide_msg}",
+    )
+    .unwrap_or_else(|_| ProgressStyle::default_bar())
+    .progress_chars("=> ")
+}
+
+fn spinner_style() -> ProgressStyle {
+    ProgressStyle::with_template("{prefix:>18.bold} {spinner} {pos} ({per_sec}) {wide_msg}")
+        .unwrap_or_else(|_| ProgressStyle::default_spinner())
+}
+
+/// Progress of one stage; clones update the same bar
+#[derive(Clone)]
+pub struct ProgressTask {
+    bar: ProgressBar,
+    json: bool,
+    task: &'static str,
+    label: String,
+}
+
+/// One line of `--progress json` output
+#[derive(Debug, Serialize)]
+struct ProgressEvent<'a> {
+    event: &'a str,
+    task: &'a str,
+    label: &'a str,
+    position: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<u64>,
+    elapsed_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eta_ms: Option<u64>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    message: String,
+}
+
+impl ProgressTask {
+    /// Show what is being worked on, e.g. the current URL
+    pub fn set_message(&self, message: impl Into<Cow<'static, str>>) {
+        self.bar.set_message(message);
+    }
+
+    /// Mark `delta` more items as done
+    pub fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+        self.emit("progress");
+    }
+
+    /// Mark the stage as done, leaving the bar at its final count
+    pub fn finish(&self) {
+        self.bar.finish();
+        self.emit("finish");
+    }
+
+    fn event(&self, event: &'static str) -> ProgressEvent<'_> {
+        let position = self.bar.position();
+        let total = self.bar.length();
+        let running = event == "progress" && position > 0 && total.is_some_and(|t| position < t);
+        ProgressEvent {
+            event,
+            task: self.task,
+            label: &self.label,
+            position,
+            total,
+            elapsed_ms: self.bar.elapsed().as_millis() as u64,
+            eta_ms: running.then(|| self.bar.eta().as_millis() as u64),
+            message: self.bar.message(),
+        }
+    }
+
+    fn emit(&self, event: &'static str) {
+        if !self.json {
+            return;
+        }
+        if let Ok(line) = serde_json::to_string(&self.event(event)) {
+            eprintln!("{line}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!("bar".parse::<ProgressMode>().unwrap(), ProgressMode::Bar);
+        assert_eq!("JSON".parse::<ProgressMode>().unwrap(), ProgressMode::Json);
+        assert_eq!("none".parse::<ProgressMode>().unwrap(), ProgressMode::None);
+        assert!("quiet".parse::<ProgressMode>().is_err());
+    }
+
+    #[test]
+    fn test_progress_events() {
+        let progress = Progress::new(ProgressMode::None);
+        let task = progress.task("crawl", "politics", 4);
+
+        let start = serde_json::to_value(task.event("start")).unwrap();
+        assert_eq!(start["task"], "crawl");
+        assert_eq!(start["label"], "politics");
+        assert_eq!(start["position"], 0);
+        assert_eq!(start["total"], 4);
+        assert!(start.get("eta_ms").is_none());
+        assert!(start.get("message").is_none());
+
+        task.set_message("https://n.news.naver.com/article/001/0000000001");
+        task.inc(1);
+        let step = serde_json::to_value(task.event("progress")).unwrap();
+        assert_eq!(step["position"], 1);
+        assert!(step.get("eta_ms").is_some());
+        assert!(step["message"].as_str().unwrap().starts_with("https://"));
+
+        task.inc(3);
+        task.finish();
+        let finish = serde_json::to_value(task.event("finish")).unwrap();
+        assert_eq!(finish["event"], "finish");
+        assert_eq!(finish["position"], 4);
+        assert!(finish.get("eta_ms").is_none());
+    }
+
+    #[test]
+    fn test_spinner_events_have_no_total() {
+        let progress = Progress::new(ProgressMode::None);
+        let task = progress.spinner("crawl", "url-file");
+        task.inc(3);
+
+        let step = serde_json::to_value(task.event("progress")).unwrap();
+        assert_eq!(step["label"], "url-file");
+        assert_eq!(step["position"], 3);
+        assert!(step.get("total").is_none());
+        assert!(step.get("eta_ms").is_none());
+    }
+}
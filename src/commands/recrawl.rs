@@ -0,0 +1,282 @@
+//! Recrawl command implementation
+
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Utc};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use baram::config::{Config, DatabaseConfig};
+use baram::crawler::sites::SiteAdapter;
+use baram::crawler::Crawler;
+use baram::embedding::{article_to_document, IndexDocument};
+use baram::metrics::push::JobMetrics;
+use baram::storage::{
+    article_id_from_path, ArticleJson, ArticleRevision, ArticleStorage, CategoryRunStats,
+    CrawlRecord, Database, HttpCache, RunInfo, RunStatus,
+};
+
+use super::crawl::{all_sites, link_saved_images, site_for_url};
+use super::index::parse_markdown_to_document;
+use super::output::say;
+use super::print_title;
+use super::progress::Progress;
+use super::runs::crawl_db_path;
+
+/// Run stats category for `baram recrawl`
+const RECRAWL_CATEGORY: &str = "recrawl";
+
+/// Parameters for `baram recrawl`
+pub struct RecrawlParams {
+    /// Markdown directory of the crawl; the crawl database is next to it
+    pub output: PathBuf,
+    /// Only revisit articles last crawled on or after this day (YYYY-MM-DD)
+    pub since: Option<String>,
+    /// Maximum number of articles to revisit, most recently crawled first
+    /// (0 = unlimited)
+    pub limit: usize,
+}
+
+/// Revisit crawled articles and record the revisions of edited ones
+///
+/// Articles are fetched with `If-None-Match`/`If-Modified-Since` where the
+/// crawl stored validators, so unchanged ones are not downloaded again.
+/// An article whose content hash differs from `crawl_metadata.content_hash`
+/// gets its markdown file rewritten and its crawl record updated, and both
+/// the text it had and the new text are kept in `article_revisions`.
+/// Articles that can no longer be fetched or parsed are reported but keep
+/// their record.
+pub async fn recrawl(
+    config: Config,
+    params: RecrawlParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
+) -> Result<()> {
+    let RecrawlParams {
+        output,
+        since,
+        limit,
+    } = params;
+
+    let db_path = crawl_db_path(&output);
+    if !db_path.exists() {
+        bail!(
+            "{} {}",
+            t!("cli.stats.not_found", path = db_path.display()),
+            t!("cli.stats.run_crawl_first")
+        );
+    }
+    let since = since
+        .as_deref()
+        .map(|date| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .with_context(|| format!("--since: {}", t!("cli.common.invalid_date", date = date)))
+        })
+        .transpose()?;
+
+    print_title(&t!("cli.recrawl.starting"), '=');
+
+    let mut db = Database::new(&DatabaseConfig {
+        sqlite_path: db_path.clone(),
+        postgres_url: String::new(),
+        pool_size: 5,
+        store_bodies: config.database.store_bodies,
+    })?;
+    db.init_sqlite(&db_path)?;
+
+    let mut records = db.get_successful_crawls()?;
+    if let Some(since) = since {
+        records.retain(|record| record.crawled_at.date_naive() >= since);
+    }
+    records.reverse();
+    if limit > 0 {
+        records.truncate(limit);
+    }
+    say!("{}", t!("cli.recrawl.articles", count = records.len()));
+
+    // Always overwritten, the file is what changed
+    let storage = ArticleStorage::with_recorded_format(&output, false)?;
+    let crawler = Crawler::new(config)?.with_http_cache(HttpCache::open(&db_path)?);
+    let sites = all_sites();
+    let extension = if storage.format().markdown() {
+        "md"
+    } else {
+        "json"
+    };
+    let mut files = article_files(storage.writer().output_dir(), extension);
+
+    let run_id = db.start_crawl_run(&RunInfo::current("recrawl"), &output)?;
+    let mut stats = CategoryRunStats::new(RECRAWL_CATEGORY);
+    stats.found = records.len();
+    let mut unchanged = 0;
+
+    let task = progress.task("recrawl", RECRAWL_CATEGORY, records.len() as u64);
+    for record in &records {
+        task.set_message(record.url.clone());
+        let site = site_for_url(&sites, &record.url);
+        match revisit(&crawler, site, &storage, &db, &mut files, record).await {
+            Ok(Some(version)) => {
+                tracing::info!(url = %record.url, version, "Article was edited");
+                stats.saved += 1;
+            }
+            Ok(None) => unchanged += 1,
+            Err(e) => {
+                tracing::warn!(url = %record.url, error = %e, "Failed to recrawl article");
+                stats.record_error(&e.to_string());
+            }
+        }
+        task.inc(1);
+
+        // Small delay between requests
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    task.finish();
+
+    stats.skipped = unchanged;
+    db.update_crawl_run(run_id, RunStatus::Finished, std::slice::from_ref(&stats))?;
+    job.set("run_id", run_id as u64);
+    job.set("checked", stats.found as u64);
+    job.set("unchanged", unchanged as u64);
+    job.set("revised", stats.saved as u64);
+    job.set("failed", stats.failed as u64);
+    db.set_crawl_run_counts(run_id, job.counts())?;
+
+    say!();
+    print_title(&t!("cli.recrawl.summary"), '=');
+    say!("{}", t!("cli.recrawl.checked", count = stats.found));
+    say!("{}", t!("cli.recrawl.unchanged", count = unchanged));
+    say!("{}", t!("cli.recrawl.revised", count = stats.saved));
+    say!("{}", t!("cli.common.failed", count = stats.failed));
+    say!("{}", t!("cli.common.database", path = db_path.display()));
+    say!("{}", t!("cli.crawl.run", id = run_id));
+
+    Ok(())
+}
+
+/// Fetch the article of `record` again and, if its content changed, save it
+/// and record the revision
+///
+/// Returns the new version number, or `None` if the article is unchanged:
+/// the server answered `304 Not Modified` or the content hash is the same.
+async fn revisit(
+    crawler: &Crawler,
+    site: &dyn SiteAdapter,
+    storage: &ArticleStorage<'_>,
+    db: &Database,
+    files: &mut HashMap<String, PathBuf>,
+    record: &CrawlRecord,
+) -> Result<Option<u32>> {
+    let Some(page) = crawler.fetch_text_if_modified(&record.url).await? else {
+        return Ok(None);
+    };
+    let mut article = site.parse(&page.text, &record.url)?;
+    if article.content_hash.as_deref() == Some(record.content_hash.as_str()) {
+        crawler.remember_validators(&record.url, &page.validators)?;
+        return Ok(None);
+    }
+
+    // The text before the edit is whatever was saved by the last crawl
+    let previous_path = files.get(&record.id).cloned();
+    let previous_doc = previous_path.as_deref().and_then(saved_article);
+    if article.category.is_empty() {
+        if let Some(doc) = &previous_doc {
+            article.category = doc.category.clone();
+        }
+    }
+    let previous = ArticleRevision {
+        article_id: record.id.clone(),
+        url: record.url.clone(),
+        version: 0,
+        content_hash: record.content_hash.clone(),
+        title: previous_doc
+            .as_ref()
+            .map(|doc| doc.title.clone())
+            .unwrap_or_default(),
+        body: previous_doc.map(|doc| doc.content).unwrap_or_default(),
+        recorded_at: record.crawled_at,
+        run_id: None,
+    };
+
+    // Comments are not fetched again; keep the ones crawled before
+    let mut stored = article.clone();
+    if db.load_article_details(&mut stored)? && article.comments.is_empty() {
+        article.comments = stored.comments;
+    }
+    article.crawled_at = Utc::now();
+    link_saved_images(storage, db, &mut article)?;
+
+    let saved = storage.save(&article)?;
+    let version = db.record_revision(&previous, &article)?;
+    if let Some(path) = saved {
+        // An edited title renames the files
+        if let Some(old) = previous_path.filter(|old| *old != path) {
+            let current = storage.paths(&article);
+            for stale in ["md", "json"].map(|ext| old.with_extension(ext)) {
+                if stale.exists() && !current.contains(&stale) {
+                    std::fs::remove_file(&stale)
+                        .with_context(|| format!("Failed to remove {}", stale.display()))?;
+                }
+            }
+        }
+        files.insert(record.id.clone(), path);
+    }
+    db.record_success(&article)?;
+    crawler.remember_validators(&record.url, &page.validators)?;
+
+    Ok(Some(version))
+}
+
+/// The article files in `dir` with `extension` by article ID, named
+/// `{id}.{extension}` or `{id}_{title}.{extension}`
+fn article_files(dir: &Path, extension: &str) -> HashMap<String, PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return HashMap::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .filter_map(|path| Some((article_id_from_path(&path)?, path)))
+        .collect()
+}
+
+/// The article saved at `path`, a markdown or JSON file
+fn saved_article(path: &Path) -> Option<IndexDocument> {
+    if path.extension().is_some_and(|ext| ext == "json") {
+        let saved = ArticleJson::read(path).ok()?;
+        return Some(article_to_document(&saved.article, Vec::new(), None, None));
+    }
+    parse_markdown_to_document(path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_files() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "001_00000000012_다른_기사.md",
+            "001_0000000001_속보_화재.md",
+            "001_0000000002.md",
+            "001_0000000003_notes.txt",
+        ] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        let files = article_files(dir.path(), "md");
+        assert_eq!(
+            files.get("001_0000000001"),
+            Some(&dir.path().join("001_0000000001_속보_화재.md"))
+        );
+        assert_eq!(
+            files.get("001_0000000002"),
+            Some(&dir.path().join("001_0000000002.md"))
+        );
+        assert_eq!(files.get("001_0000000003"), None);
+        assert_eq!(files.len(), 3);
+        assert!(article_files(dir.path(), "json").is_empty());
+        assert!(article_files(&dir.path().join("missing"), "md").is_empty());
+    }
+}
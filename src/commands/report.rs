@@ -0,0 +1,407 @@
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, NaiveDate, Utc};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use baram::analytics::{NewsReport, ReportBuilder, ReportPeriod};
+use baram::config::{Config, DatabaseConfig};
+use baram::metrics::push::JobMetrics;
+use baram::notifications::{Alert, AlertCondition, AlertSeverity, NotificationManager};
+use baram::storage::{CrawlRun, Database};
+use baram::utils::format_bytes;
+use unicode_width::UnicodeWidthStr;
+
+use super::output::say;
+use super::{pad_end, pad_start};
+
+/// Parameters for `baram report`
+pub struct ReportParams {
+    /// Report period
+    pub period: ReportPeriod,
+    /// Last day covered by the report, YYYY-MM-DD (default: yesterday, UTC)
+    pub date: Option<String>,
+    /// Output format (markdown, html)
+    pub format: String,
+    /// Write the report to this file instead of stdout
+    pub output: Option<PathBuf>,
+    /// Number of keywords, entities, quotes and errors to list
+    pub limit: usize,
+    /// Send the report summary to the notification channels
+    pub notify: bool,
+    /// Channels to notify (empty = all configured channels)
+    pub channels: Vec<String>,
+}
+
+/// Parameters for `baram report crawl`
+pub struct CrawlReportParams {
+    /// Run id (default: the latest run)
+    pub run: Option<i64>,
+    /// Crawl database written by `baram crawl`
+    pub database: PathBuf,
+    /// Number of error messages to list
+    pub errors: usize,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Generate a daily or weekly report
+pub async fn report(config: Config, params: ReportParams, job: &mut JobMetrics) -> Result<()> {
+    if !matches!(params.format.as_str(), "markdown" | "md" | "html") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "markdown, html"
+            )
+        );
+    }
+
+    let last_day = match params.date {
+        Some(ref date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .with_context(|| t!("cli.common.invalid_date", date = date).to_string())?,
+        None => Utc::now().date_naive() - chrono::Duration::days(1),
+    };
+    let builder = ReportBuilder::new(params.period, last_day).with_limit(params.limit);
+    let (start, end) = builder.window();
+    let (previous_start, _) = builder.previous_window();
+
+    let mut db = Database::new(&config.database)?;
+    db.init_postgres(&config.database.postgres_url)
+        .await
+        .context("Failed to connect to PostgreSQL")?;
+    let articles = db
+        .get_articles_since(previous_start, 0)
+        .await
+        .context("Failed to load articles")?;
+    let reactions = db
+        .timeseries()?
+        .reactions(start, end)
+        .await
+        .context("Failed to load comment reactions")?;
+    let builder = builder.with_reactions(reactions);
+
+    db.init_sqlite(&config.database.sqlite_path)?;
+    let failures = db.get_failures_between(start, end)?;
+
+    tracing::info!(
+        period = params.period.as_str(),
+        start = %start,
+        end = %end,
+        articles = articles.len(),
+        failures = failures.len(),
+        "Building report"
+    );
+
+    let report = builder.build(&articles, &failures);
+    job.set("articles", report.total_articles as u64);
+    job.set("crawl_failures", report.failures.total as u64);
+    let rendered = if params.format == "html" {
+        report.to_html()
+    } else {
+        report.to_markdown()
+    };
+
+    match params.output {
+        Some(ref path) => {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, &rendered)
+                .with_context(|| format!("Failed to write report to {}", path.display()))?;
+            say!("{}", t!("cli.report.written", path = path.display()));
+        }
+        None => print!("{rendered}"),
+    }
+
+    if params.notify {
+        let manager =
+            NotificationManager::from_config(&config.notifications).map_err(anyhow::Error::msg)?;
+        let alert = report_alert(&report, params.output.as_ref());
+        let statuses = manager.deliver(&alert, &params.channels).await;
+        if statuses.is_empty() {
+            tracing::warn!("No matching notification channels, report was not sent");
+        }
+        for status in statuses {
+            if status.success {
+                say!("{}", t!("cli.report.sent", channel = status.channel));
+            } else {
+                eprintln!(
+                    "{}",
+                    t!(
+                        "cli.report.send_failed",
+                        channel = status.channel,
+                        error = status.message.unwrap_or_default()
+                    )
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Informational alert carrying the report summary
+fn report_alert(report: &NewsReport, output: Option<&PathBuf>) -> Alert {
+    let name = format!("{}_report", report.period.as_str());
+    let condition = AlertCondition::Custom {
+        name,
+        description: report.title(),
+        parameters: HashMap::new(),
+    };
+
+    let mut alert = Alert::new(condition, AlertSeverity::Info, report.summary())
+        .with_metadata("report_start".to_string(), report.start.to_rfc3339())
+        .with_metadata("report_end".to_string(), report.end.to_rfc3339())
+        .with_metadata(
+            "report_articles".to_string(),
+            report.total_articles.to_string(),
+        );
+    if let Some(path) = output {
+        alert = alert.with_metadata("report_path".to_string(), path.display().to_string());
+    }
+    alert.trigger();
+    alert
+}
+
+/// Summarize one recorded `baram crawl` run
+pub fn crawl_report(params: CrawlReportParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+    if !params.database.exists() {
+        bail!(
+            "{} {}",
+            t!("cli.stats.not_found", path = params.database.display()),
+            t!("cli.stats.run_crawl_first")
+        );
+    }
+
+    let db_config = DatabaseConfig {
+        sqlite_path: params.database.clone(),
+        postgres_url: String::new(),
+        pool_size: 5,
+        store_bodies: false,
+    };
+    let mut db = Database::new(&db_config)?;
+    db.init_sqlite(&params.database)?;
+
+    let run = match (db.get_crawl_run(params.run)?, params.run) {
+        (Some(run), _) => run,
+        (None, Some(id)) => bail!("{}", t!("cli.report.run_not_found", id = id)),
+        (None, None) => bail!(
+            "{}",
+            t!("cli.report.no_runs", path = params.database.display())
+        ),
+    };
+
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+    } else {
+        print!("{}", render_crawl_run(&run, params.errors));
+    }
+    Ok(())
+}
+
+/// Plain-text summary of a crawl run
+fn render_crawl_run(run: &CrawlRun, errors: usize) -> String {
+    let mut out = String::new();
+    let title = t!("cli.report.crawl_title", id = run.id);
+    let _ = writeln!(out, "{title}\n{}", "=".repeat(title.width()));
+    let _ = writeln!(out, "{}", t!("cli.report.status", status = run.status));
+    let _ = writeln!(
+        out,
+        "{}",
+        t!(
+            "cli.report.started",
+            time = run.started_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+    );
+    if let Some(finished) = run.finished_at {
+        let _ = writeln!(
+            out,
+            "{}",
+            t!(
+                "cli.report.finished",
+                time = finished.format("%Y-%m-%d %H:%M:%S UTC")
+            )
+        );
+    }
+    let wall_time = run
+        .wall_time()
+        .map_or_else(|| "-".to_string(), format_duration);
+    let _ = writeln!(out, "{}", t!("cli.report.wall_time", time = wall_time));
+    let _ = writeln!(out, "{}", t!("cli.report.output", path = run.output_dir));
+    let _ = writeln!(
+        out,
+        "{}",
+        t!(
+            "cli.report.command",
+            command = format!("baram {}", run.info.args.join(" "))
+        )
+    );
+    let _ = writeln!(
+        out,
+        "{}",
+        t!("cli.report.version", version = run.info.version())
+    );
+    let _ = writeln!(out, "{}", t!("cli.report.articles", count = run.articles));
+
+    // Index and ontology runs have counts but no categories
+    if run.categories.is_empty() {
+        if !run.counts.is_empty() {
+            let heading = t!("cli.report.counts");
+            let _ = writeln!(out, "\n{heading}\n{}", "-".repeat(heading.width()));
+            for (name, count) in &run.counts {
+                let _ = writeln!(out, "{count:>8}  {name}");
+            }
+        }
+        return out;
+    }
+
+    let totals = run.totals();
+    let _ = writeln!(
+        out,
+        "\n{} {} {} {} {} {} {} {} {}",
+        pad_end(&t!("cli.report.col_category"), 12),
+        pad_start(&t!("cli.report.col_found"), 6),
+        pad_start(&t!("cli.report.col_new"), 6),
+        pad_start(&t!("cli.report.col_skipped"), 8),
+        pad_start(&t!("cli.report.col_saved"), 6),
+        pad_start(&t!("cli.report.col_duplicates"), 6),
+        pad_start(&t!("cli.report.col_failed"), 7),
+        pad_start(&t!("cli.report.col_success"), 8),
+        pad_start(&t!("cli.report.col_written"), 11)
+    );
+    for stats in run.categories.iter().chain(std::iter::once(&totals)) {
+        let attempted = stats.saved + stats.failed;
+        let success = if attempted > 0 {
+            format!("{:.1}%", stats.saved as f64 / attempted as f64 * 100.0)
+        } else {
+            "-".to_string()
+        };
+        let _ = writeln!(
+            out,
+            "{:<12} {:>6} {:>6} {:>8} {:>6} {:>6} {:>7} {:>8} {:>11}",
+            stats.category,
+            stats.found,
+            stats.new,
+            stats.skipped,
+            stats.saved,
+            stats.duplicates,
+            stats.failed,
+            success,
+            format_bytes(stats.bytes_written)
+        );
+    }
+
+    let top_errors = totals.top_errors(errors);
+    if !top_errors.is_empty() {
+        let heading = t!("cli.report.top_errors");
+        let _ = writeln!(out, "\n{heading}\n{}", "-".repeat(heading.width()));
+        for (message, count) in top_errors {
+            let _ = writeln!(out, "{count:>6}  {message}");
+        }
+    }
+    out
+}
+
+/// Format a wall time as e.g. `1h 02m 03s`
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m {seconds:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baram::storage::{CategoryRunStats, RunInfo, RunStatus};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_render_crawl_run() {
+        let started = Utc::now();
+        let mut politics = CategoryRunStats::new("politics");
+        politics.found = 20;
+        politics.new = 12;
+        politics.skipped = 8;
+        politics.saved = 9;
+        politics.bytes_written = 2048;
+        politics.record_error("HTTP 503 for https://n.news.naver.com/article/001/1");
+        politics.record_error("HTTP 503 for https://n.news.naver.com/article/001/2");
+        politics.record_error("parse error");
+        let run = CrawlRun {
+            id: 7,
+            info: RunInfo {
+                command: "crawl".to_string(),
+                args: vec![
+                    "crawl".to_string(),
+                    "-c".to_string(),
+                    "politics".to_string(),
+                ],
+                app_version: "0.1.6".to_string(),
+                git_commit: Some("1a2b3c4d5e6f".to_string()),
+            },
+            started_at: started,
+            finished_at: Some(started + Duration::seconds(125)),
+            status: RunStatus::Finished,
+            output_dir: "./output/raw".to_string(),
+            categories: vec![politics],
+            counts: BTreeMap::new(),
+            articles: 9,
+        };
+
+        let text = render_crawl_run(&run, 1);
+        assert!(text.starts_with("Crawl Run 7\n==========="));
+        assert!(text.contains("Wall time: 2m 05s"));
+        assert!(text.contains("Command:   baram crawl -c politics"));
+        assert!(text.contains("Version:   0.1.6 (1a2b3c4d5e6f)"));
+        assert!(text.contains("Articles:  9"));
+        assert!(text.contains("75.0%"));
+        assert!(text.contains("2.00 KB"));
+        assert!(text.contains("     2  HTTP 503 for <url>"));
+        assert!(!text.contains("parse error"));
+    }
+
+    #[test]
+    fn test_render_index_run() {
+        let started = Utc::now();
+        let run = CrawlRun {
+            id: 8,
+            info: RunInfo::current("index"),
+            started_at: started,
+            finished_at: None,
+            status: RunStatus::Failed,
+            output_dir: "./output/raw".to_string(),
+            categories: Vec::new(),
+            counts: BTreeMap::from([("indexed".to_string(), 12)]),
+            articles: 0,
+        };
+
+        let text = render_crawl_run(&run, 5);
+        assert!(text.contains("      12  indexed"));
+        assert!(!text.contains("Category"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::seconds(42)), "42s");
+        assert_eq!(format_duration(Duration::seconds(3723)), "1h 02m 03s");
+    }
+}
@@ -0,0 +1,259 @@
+//! Retry-failed command implementation
+
+use anyhow::{bail, Context, Result};
+use rust_i18n::t;
+use std::path::PathBuf;
+
+use baram::config::{Config, DatabaseConfig, DeadLetterConfig};
+use baram::crawler::Crawler;
+use baram::error::ErrorClass;
+use baram::metrics::push::JobMetrics;
+use baram::models::CrawlState;
+use baram::storage::{
+    ArticleStorage, CategoryRunStats, Database, FailureStatus, QueuedFailure, RunInfo, RunStatus,
+    TimeSeriesStore,
+};
+
+use super::crawl::{all_sites, crawl_single_url, record_timeseries, site_for_url};
+use super::output::say;
+use super::print_title;
+use super::progress::Progress;
+use super::runs::crawl_db_path;
+
+/// Run stats category for `baram retry-failed`
+const RETRY_CATEGORY: &str = "retry-failed";
+
+/// Dead URLs listed in the summary; `--report` has all of them
+const DEAD_URLS_SHOWN: usize = 20;
+
+/// Parameters for `baram retry-failed`
+pub struct RetryFailedParams {
+    /// Markdown directory of the crawl; the crawl database is next to it
+    pub output: PathBuf,
+    /// Maximum number of URLs to retry, least recently failed first
+    /// (0 = unlimited)
+    pub limit: usize,
+    /// Write every permanently failed URL to this JSON file
+    pub report: Option<PathBuf>,
+}
+
+/// Retry the URLs in the dead-letter queue of a crawl
+///
+/// URLs whose error class is not retried or that are out of attempts under
+/// `[crawler.dead_letter]` are marked dead first; the rest are crawled again.
+/// A URL that fails again counts another attempt and is marked dead once it
+/// has used up the attempts of its new error class.
+pub async fn retry_failed(
+    config: Config,
+    params: RetryFailedParams,
+    job: &mut JobMetrics,
+    progress: &Progress,
+) -> Result<()> {
+    let RetryFailedParams {
+        output,
+        limit,
+        report,
+    } = params;
+
+    let db_path = crawl_db_path(&output);
+    if !db_path.exists() {
+        bail!(
+            "{} {}",
+            t!("cli.stats.not_found", path = db_path.display()),
+            t!("cli.stats.run_crawl_first")
+        );
+    }
+
+    print_title(&t!("cli.retry_failed.starting"), '=');
+
+    let mut db = Database::new(&DatabaseConfig {
+        sqlite_path: db_path.clone(),
+        postgres_url: String::new(),
+        pool_size: 5,
+        store_bodies: config.database.store_bodies,
+    })?;
+    db.init_sqlite(&db_path)?;
+    let mut series = TimeSeriesStore::open(&db_path)?;
+
+    let policy = config.crawler.dead_letter.clone();
+    let (mut retry, given_up) = partition(db.get_failed_urls(FailureStatus::Pending)?, &policy);
+    for failure in &given_up {
+        db.mark_failure_dead(&failure.url)?;
+    }
+    if limit > 0 {
+        retry.truncate(limit);
+    }
+    say!(
+        "{}",
+        t!(
+            "cli.retry_failed.queue",
+            retry = retry.len(),
+            dead = given_up.len()
+        )
+    );
+
+    let storage = ArticleStorage::with_recorded_format(&output, false)?;
+    let crawler = Crawler::new(config)?;
+    let sites = all_sites();
+    let mut state = CrawlState::new();
+
+    let run_id = db.start_crawl_run(&RunInfo::current("retry-failed"), &output)?;
+    let mut stats = CategoryRunStats::new(RETRY_CATEGORY);
+    stats.found = retry.len();
+    stats.new = retry.len();
+    let mut dead = given_up.len();
+
+    let task = progress.task("retry-failed", RETRY_CATEGORY, retry.len() as u64);
+    for failure in &retry {
+        task.set_message(failure.url.clone());
+        let site = site_for_url(&sites, &failure.url);
+        match crawl_single_url(
+            &crawler,
+            site,
+            &storage,
+            &db,
+            &failure.url,
+            &mut state,
+            None,
+        )
+        .await
+        {
+            Ok(Some(crawled)) => {
+                record_timeseries(&mut series, &crawled.article);
+                stats.saved += 1;
+                stats.bytes_written += crawled.bytes_written;
+            }
+            Ok(None) => {
+                // Crawled fine, but already stored under another URL
+                db.resolve_failure(&failure.url)?;
+                stats.duplicates += 1;
+            }
+            Err(e) => {
+                let class = ErrorClass::of(&e);
+                tracing::warn!(
+                    url = %failure.url,
+                    class = class.as_str(),
+                    error = %e,
+                    "Retry failed"
+                );
+                db.record_failure(&failure.url, &e.to_string(), class)?;
+                if failure.attempts + 1 >= policy.max_attempts(class) {
+                    db.mark_failure_dead(&failure.url)?;
+                    dead += 1;
+                }
+                stats.record_error(&e.to_string());
+            }
+        }
+        task.inc(1);
+
+        // Small delay between requests
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    task.finish();
+
+    db.update_crawl_run(run_id, RunStatus::Finished, std::slice::from_ref(&stats))?;
+    job.set("run_id", run_id as u64);
+    job.set("retried", retry.len() as u64);
+    job.set("recovered", (stats.saved + stats.duplicates) as u64);
+    job.set("failed", stats.failed as u64);
+    job.set("dead", dead as u64);
+    db.set_crawl_run_counts(run_id, job.counts())?;
+
+    let all_dead = db.get_failed_urls(FailureStatus::Dead)?;
+    if let Some(path) = &report {
+        std::fs::write(path, serde_json::to_string_pretty(&all_dead)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    say!();
+    print_title(&t!("cli.retry_failed.summary"), '=');
+    say!("{}", t!("cli.retry_failed.retried", count = retry.len()));
+    say!(
+        "{}",
+        t!(
+            "cli.retry_failed.recovered",
+            count = stats.saved + stats.duplicates
+        )
+    );
+    say!("{}", t!("cli.common.failed", count = stats.failed));
+    say!("{}", t!("cli.retry_failed.newly_dead", count = dead));
+    say!("{}", t!("cli.crawl.run", id = run_id));
+
+    if !all_dead.is_empty() {
+        say!();
+        print_title(&t!("cli.retry_failed.dead", count = all_dead.len()), '-');
+        for failure in all_dead.iter().take(DEAD_URLS_SHOWN) {
+            say!(
+                "  [{}] x{} {} - {}",
+                failure.error_class.as_str(),
+                failure.attempts,
+                failure.url,
+                failure.error_message
+            );
+        }
+        if all_dead.len() > DEAD_URLS_SHOWN {
+            say!(
+                "  {}",
+                t!(
+                    "cli.retry_failed.more",
+                    count = all_dead.len() - DEAD_URLS_SHOWN
+                )
+            );
+        }
+    }
+    if let Some(path) = &report {
+        say!("{}", t!("cli.retry_failed.report", path = path.display()));
+    }
+
+    Ok(())
+}
+
+/// Split pending failures into those to retry and those `policy` gives up on
+fn partition(
+    pending: Vec<QueuedFailure>,
+    policy: &DeadLetterConfig,
+) -> (Vec<QueuedFailure>, Vec<QueuedFailure>) {
+    pending
+        .into_iter()
+        .partition(|failure| failure.attempts < policy.max_attempts(failure.error_class))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn failure(url: &str, class: ErrorClass, attempts: u32) -> QueuedFailure {
+        QueuedFailure {
+            url: url.to_string(),
+            error_class: class,
+            error_message: String::new(),
+            attempts,
+            first_failed_at: Utc::now(),
+            last_failed_at: Utc::now(),
+            status: FailureStatus::Pending,
+        }
+    }
+
+    #[test]
+    fn test_partition() {
+        let policy = DeadLetterConfig {
+            max_attempts: 3,
+            skip: vec!["not_found".to_string()],
+            max_attempts_by_class: [("timeout".to_string(), 5)].into(),
+        };
+        let (retry, dead) = partition(
+            vec![
+                failure("https://a", ErrorClass::NotFound, 1),
+                failure("https://b", ErrorClass::Timeout, 4),
+                failure("https://c", ErrorClass::ServerError, 3),
+                failure("https://d", ErrorClass::ServerError, 2),
+            ],
+            &policy,
+        );
+        let urls =
+            |failures: &[QueuedFailure]| failures.iter().map(|f| f.url.clone()).collect::<Vec<_>>();
+        assert_eq!(urls(&retry), ["https://b", "https://d"]);
+        assert_eq!(urls(&dead), ["https://a", "https://c"]);
+    }
+}
@@ -0,0 +1,118 @@
+//! Run records of commands that read a crawled dataset
+//!
+//! `baram index` and `baram ontology` record their runs in the crawl
+//! database next to the markdown directory they read, alongside the
+//! `baram crawl` runs that wrote it, so `baram report crawl` can show what
+//! was derived from a dataset and with which version.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use baram::config::DatabaseConfig;
+use baram::storage::{Database, RunInfo, RunStatus};
+
+/// Crawl database of a markdown directory, as written by `baram crawl`
+pub(crate) fn crawl_db_path(markdown_dir: &Path) -> PathBuf {
+    markdown_dir
+        .parent()
+        .unwrap_or(markdown_dir)
+        .join("crawl.db")
+}
+
+/// A run being recorded
+pub struct RunRecord {
+    db: Database,
+    id: i64,
+}
+
+impl RunRecord {
+    /// Record the start of `command` reading the markdown file or
+    /// directory `input`
+    ///
+    /// Recording is best effort: without an existing crawl database the
+    /// command runs unrecorded, and failures are only logged.
+    pub fn start(command: &str, input: &Path) -> Option<Self> {
+        let dir = if input.is_file() {
+            input.parent()?
+        } else {
+            input
+        };
+        let db_path = crawl_db_path(dir);
+        if !db_path.exists() {
+            return None;
+        }
+
+        let result = (|| -> anyhow::Result<Self> {
+            let mut db = Database::new(&DatabaseConfig {
+                sqlite_path: db_path.clone(),
+                postgres_url: String::new(),
+                pool_size: 1,
+                store_bodies: false,
+            })?;
+            db.init_sqlite(&db_path)?;
+            let id = db.start_crawl_run(&RunInfo::current(command), dir)?;
+            Ok(Self { db, id })
+        })();
+        match result {
+            Ok(record) => Some(record),
+            Err(e) => {
+                tracing::warn!(
+                    command,
+                    path = %db_path.display(),
+                    error = %format!("{e:#}"),
+                    "Failed to record run"
+                );
+                None
+            }
+        }
+    }
+
+    /// Record how the run ended and what it handled
+    pub fn finish(self, status: RunStatus, counts: &BTreeMap<String, u64>) {
+        let result = self
+            .db
+            .update_crawl_run(self.id, status, &[])
+            .and_then(|()| self.db.set_crawl_run_counts(self.id, counts));
+        if let Err(e) = result {
+            tracing::warn!(run_id = self.id, error = %format!("{e:#}"), "Failed to record run");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let raw = dir.path().join("raw");
+        std::fs::create_dir(&raw).unwrap();
+
+        // Not a crawled dataset: nothing to record into
+        assert!(RunRecord::start("index", &raw).is_none());
+
+        let db_path = crawl_db_path(&raw);
+        let mut db = Database::new(&DatabaseConfig {
+            sqlite_path: db_path.clone(),
+            postgres_url: String::new(),
+            pool_size: 1,
+            store_bodies: false,
+        })
+        .unwrap();
+        db.init_sqlite(&db_path).unwrap();
+
+        let record = RunRecord::start("index", &raw).unwrap();
+        record.finish(
+            RunStatus::Failed,
+            &BTreeMap::from([("indexed".to_string(), 4)]),
+        );
+
+        let run = db.get_crawl_run(None).unwrap().unwrap();
+        assert_eq!(run.info.command, "index");
+        assert_eq!(run.status, RunStatus::Failed);
+        assert_eq!(run.counts["indexed"], 4);
+        assert_eq!(run.output_dir, raw.display().to_string());
+        assert!(run.categories.is_empty());
+    }
+}
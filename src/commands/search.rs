@@ -1,10 +1,350 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use rust_i18n::t;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::SystemTime;
+use unicode_width::UnicodeWidthStr;
 
-use baram::config::OpenSearchConfig;
-use baram::embedding::{SearchConfig, VectorStore};
+use baram::cache::{Cache, CacheConfig, CachedSearchResult, OptionalCache, SearchResultItem};
+use baram::config::Config;
+use baram::embedding::{
+    Embedder, EmbeddingConfig, LocalSearch, RerankConfig, RerankMethod, SearchConfig, SearchResult,
+    VectorStore,
+};
+
+use super::index::parse_markdown_to_document;
+use super::runs::crawl_db_path;
+
+use super::output::{is_quiet, say};
+use super::{print_cache_stats, print_title};
+
+/// Fields shown by `--output table|markdown|csv` when `--fields` is not given
+const DEFAULT_FIELDS: [SearchField; 6] = [
+    SearchField::Rank,
+    SearchField::Score,
+    SearchField::Title,
+    SearchField::Publisher,
+    SearchField::PublishedAt,
+    SearchField::Url,
+];
+
+/// Widest table cell, in terminal columns
+const MAX_TABLE_CELL_WIDTH: usize = 60;
+
+/// Parameters for `baram search`
+pub struct SearchParams {
+    /// Search query
+    pub query: String,
+    /// Number of results to return
+    pub k: usize,
+    /// Minimum similarity threshold
+    pub threshold: Option<f32>,
+    /// Search mode (hybrid, keyword/bm25, vector/knn)
+    pub mode: String,
+    /// Keep only the best-scoring article of each story
+    pub collapse: bool,
+    /// Output format (text, json, csv, table, markdown)
+    pub output: String,
+    /// Comma-separated fields to output (default: all for json, a summary
+    /// otherwise)
+    pub fields: Option<String>,
+    /// Embed the query with this model loaded in-process instead of the
+    /// embedding server
+    pub local_model: Option<String>,
+    /// Search the markdown files in `input` instead of OpenSearch
+    pub local: bool,
+    /// Markdown directory searched with `local`
+    pub input: PathBuf,
+    /// Cache query embeddings and results in Redis (`REDIS_URL`)
+    pub cache: bool,
+    /// Reranking method: none, rrf or cross-encoder
+    pub rerank: String,
+    /// Candidates fetched for reranking
+    pub rerank_window: usize,
+    /// Cross-encoder to ask the embedding server for
+    pub rerank_model: Option<String>,
+}
+
+/// How results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchOutput {
+    /// Human-oriented layout with previews and highlights
+    Text,
+    Json,
+    Csv,
+    /// Aligned columns
+    Table,
+    /// Markdown table
+    Markdown,
+}
+
+impl FromStr for SearchOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "table" => Ok(Self::Table),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(format!(
+                "Unsupported output format: {s}. Use text, json, csv, table or markdown"
+            )),
+        }
+    }
+}
+
+/// A selectable field of a search result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchField {
+    /// 1-based position in the results
+    Rank,
+    Id,
+    Score,
+    Title,
+    Content,
+    Category,
+    Publisher,
+    Url,
+    PublishedAt,
+    Highlights,
+    StoryId,
+}
+
+impl SearchField {
+    const ALL: [Self; 11] = [
+        Self::Rank,
+        Self::Id,
+        Self::Score,
+        Self::Title,
+        Self::Content,
+        Self::Category,
+        Self::Publisher,
+        Self::Url,
+        Self::PublishedAt,
+        Self::Highlights,
+        Self::StoryId,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Rank => "rank",
+            Self::Id => "id",
+            Self::Score => "score",
+            Self::Title => "title",
+            Self::Content => "content",
+            Self::Category => "category",
+            Self::Publisher => "publisher",
+            Self::Url => "url",
+            Self::PublishedAt => "published_at",
+            Self::Highlights => "highlights",
+            Self::StoryId => "story_id",
+        }
+    }
+
+    /// Value for JSON output; missing values are `null`
+    fn json(self, rank: usize, result: &SearchResult) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            Self::Rank => json!(rank),
+            Self::Id => json!(result.id),
+            Self::Score => json!(result.score),
+            Self::Title => json!(result.title),
+            Self::Content => json!(result.content),
+            Self::Category => json!(result.category),
+            Self::Publisher => json!(result.publisher),
+            Self::Url => json!(result.url),
+            Self::PublishedAt => json!(result.published_at),
+            Self::Highlights => json!(result.highlights),
+            Self::StoryId => json!(result.story_id),
+        }
+    }
+
+    /// Value for CSV and table cells; missing values are empty
+    fn text(self, rank: usize, result: &SearchResult) -> String {
+        match self {
+            Self::Rank => rank.to_string(),
+            Self::Id => result.id.clone(),
+            Self::Score => format!("{:.3}", result.score),
+            Self::Title => result.title.clone(),
+            Self::Content => result.content.clone(),
+            Self::Category => result.category.clone(),
+            Self::Publisher => result.publisher.clone().unwrap_or_default(),
+            Self::Url => result.url.clone(),
+            Self::PublishedAt => result.published_at.clone().unwrap_or_default(),
+            Self::Highlights => result
+                .highlights
+                .iter()
+                .flatten()
+                .map(|h| strip_marks(h))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            Self::StoryId => result.story_id.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl FromStr for SearchField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str() == s)
+            .ok_or_else(|| {
+                let valid: Vec<&str> = Self::ALL.iter().map(|f| f.as_str()).collect();
+                format!("Unknown field: {s}. Valid: {}", valid.join(", "))
+            })
+    }
+}
+
+/// Parse `--fields`, defaulting to every field for JSON and a summary otherwise
+fn parse_fields(fields: Option<&str>, output: SearchOutput) -> Result<Vec<SearchField>, String> {
+    match fields {
+        Some(fields) => {
+            let fields = fields
+                .split(',')
+                .filter(|f| !f.trim().is_empty())
+                .map(str::parse)
+                .collect::<Result<Vec<SearchField>, _>>()?;
+            if fields.is_empty() {
+                return Err("--fields must name at least one field".to_string());
+            }
+            Ok(fields)
+        }
+        None if output == SearchOutput::Json => Ok(SearchField::ALL.to_vec()),
+        None => Ok(DEFAULT_FIELDS.to_vec()),
+    }
+}
+
+/// Render results in a machine-readable or tabular format
+fn render_results(
+    results: &[SearchResult],
+    output: SearchOutput,
+    fields: &[SearchField],
+) -> Result<String> {
+    let rows = || {
+        results
+            .iter()
+            .enumerate()
+            .map(|(i, result)| (i + 1, result))
+    };
+    let cells =
+        |rank, result| -> Vec<String> { fields.iter().map(|f| f.text(rank, result)).collect() };
+    let header: Vec<&str> = fields.iter().map(|f| f.as_str()).collect();
+
+    match output {
+        SearchOutput::Json => {
+            let objects: Vec<serde_json::Map<String, serde_json::Value>> = rows()
+                .map(|(rank, result)| {
+                    fields
+                        .iter()
+                        .map(|f| (f.as_str().to_string(), f.json(rank, result)))
+                        .collect()
+                })
+                .collect();
+            Ok(serde_json::to_string_pretty(&objects)? + "\n")
+        }
+        SearchOutput::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(&header)?;
+            for (rank, result) in rows() {
+                writer.write_record(cells(rank, result))?;
+            }
+            Ok(String::from_utf8(writer.into_inner()?)?)
+        }
+        SearchOutput::Table => {
+            let body: Vec<Vec<String>> = rows().map(|(rank, result)| cells(rank, result)).collect();
+            Ok(format_table(&header, &body))
+        }
+        SearchOutput::Markdown => {
+            let line = |row: Vec<String>| format!("| {} |\n", row.join(" | "));
+            let mut out = line(header.iter().map(|h| h.to_string()).collect());
+            out.push_str(&line(header.iter().map(|_| "---".to_string()).collect()));
+            for (rank, result) in rows() {
+                out.push_str(&line(
+                    cells(rank, result)
+                        .iter()
+                        .map(|cell| single_line(cell).replace('|', "\\|"))
+                        .collect(),
+                ));
+            }
+            Ok(out)
+        }
+        SearchOutput::Text => bail!("Text output is printed by print_results"),
+    }
+}
+
+/// Align rows under a header by display width, cutting long cells
+pub(crate) fn format_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let body: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| truncate_width(&single_line(cell), MAX_TABLE_CELL_WIDTH))
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            body.iter()
+                .map(|row| row[i].width())
+                .chain([name.width()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |row: &[String]| {
+        let padded: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - cell.width())))
+            .collect();
+        padded.join("  ").trim_end().to_string() + "\n"
+    };
+
+    let mut out = line(&header.iter().map(|h| h.to_string()).collect::<Vec<_>>());
+    out.push_str(&line(
+        &widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>(),
+    ));
+    for row in &body {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+/// Replace OpenSearch highlight tags with brackets
+fn strip_marks(text: &str) -> String {
+    text.replace("<mark>", "[").replace("</mark>", "]")
+}
+
+/// Collapse line breaks so a value fits in one table row
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cut `text` to at most `max` terminal columns, marking the cut with `…`
+fn truncate_width(text: &str, max: usize) -> String {
+    if text.width() <= max {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    for c in text.chars() {
+        if out.width() + unicode_width::UnicodeWidthChar::width(c).unwrap_or(0) >= max {
+            break;
+        }
+        out.push(c);
+    }
+    out.push('…');
+    out
+}
 
 /// Fetch a query embedding from the embedding server.
-async fn get_query_embedding(text: &str) -> Result<Vec<f32>> {
+pub(crate) async fn get_query_embedding(text: &str) -> Result<Vec<f32>> {
     let url = std::env::var("EMBEDDING_SERVER_URL")
         .unwrap_or_else(|_| "http://localhost:8090".to_string());
 
@@ -48,34 +388,109 @@ async fn get_query_embedding(text: &str) -> Result<Vec<f32>> {
     Ok(embedding)
 }
 
+/// Embed the query with a local model, or with the embedding server if
+/// `local_model` is not set, going through the cache when it is available
+async fn query_embedding(
+    query: &str,
+    local_model: Option<&str>,
+    cache: &OptionalCache,
+) -> Result<Vec<f32>> {
+    let model = local_model.unwrap_or("embedding-server");
+    cache
+        .get_or_compute_embedding(&format!("{model}\n{query}"), model, || {
+            embed_query(query, local_model)
+        })
+        .await
+}
+
+/// Result cache key of a search: everything besides the index contents
+/// that decides its results
+fn search_cache_key(
+    query: &str,
+    mode: &str,
+    config: &SearchConfig,
+    local_model: Option<&str>,
+    rerank: Option<&RerankConfig>,
+    index: &str,
+) -> String {
+    let rerank = rerank.map(|r| (rerank_label(r.method), r.window, r.model.as_deref()));
+    let descriptor = format!(
+        "{index}\n{mode}\n{query}\n{}\n{:?}\n{}\n{}\n{local_model:?}\n{rerank:?}",
+        config.offset, config.min_score, config.collapse_by_story, config.chunked
+    );
+    Cache::hash_query(&descriptor, config.k, config.category.as_deref())
+}
+
+async fn embed_query(query: &str, local_model: Option<&str>) -> Result<Vec<f32>> {
+    let Some(model) = local_model else {
+        return get_query_embedding(query).await;
+    };
+
+    let model = model.to_string();
+    let query = query.to_string();
+    // Loading the model may download it and is CPU-bound
+    tokio::task::spawn_blocking(move || {
+        let config = EmbeddingConfig {
+            model_id: model.clone(),
+            ..Default::default()
+        };
+        let embedder = Embedder::from_pretrained(config)
+            .with_context(|| format!("Failed to load embedding model {model}"))?;
+        embedder.embed(&query)
+    })
+    .await
+    .context("Local embedding task panicked")?
+}
+
+/// Whether `mode` needs a query embedding, or an error for unknown modes
+fn uses_embedding(mode: &str) -> Result<bool> {
+    match mode {
+        "keyword" | "bm25" => Ok(false),
+        "vector" | "knn" | "hybrid" => Ok(true),
+        other => bail!("{}", t!("cli.search.unknown_mode", mode = other)),
+    }
+}
+
 /// Print search results to stdout.
-fn print_results(results: &[baram::embedding::SearchResult], query: &str, mode: &str) {
+fn print_results(results: &[SearchResult], query: &str, mode: &str) {
     if results.is_empty() {
         tracing::info!(query = %query, mode = %mode, "No results found");
-        println!("\nNo results found for \"{query}\"");
+        println!("\n{}", t!("cli.search.no_results", query = query));
         return;
     }
 
-    println!("\nFound {} results (mode: {mode}):\n", results.len());
+    say!(
+        "\n{}\n",
+        t!("cli.search.found", count = results.len(), mode = mode)
+    );
 
+    let unknown = t!("cli.search.unknown_publisher");
     for (i, result) in results.iter().enumerate() {
-        println!("{}. {} (score: {:.3})", i + 1, result.title, result.score);
         println!(
-            "   Category: {} | Publisher: {}",
-            result.category,
-            result.publisher.as_deref().unwrap_or("Unknown")
+            "{}. {} ({})",
+            i + 1,
+            result.title,
+            t!("cli.search.score", score = format!("{:.3}", result.score))
+        );
+        println!(
+            "   {}",
+            t!(
+                "cli.search.category_publisher",
+                category = result.category,
+                publisher = result.publisher.as_deref().unwrap_or(&unknown)
+            )
         );
         if let Some(date) = &result.published_at {
-            println!("   Published: {date}");
+            println!("   {}", t!("cli.search.published", date = date));
+        }
+        if let Some(story_id) = &result.story_id {
+            println!("   {}", t!("cli.search.story", id = story_id));
         }
 
         // Show highlights if available
         if let Some(highlights) = &result.highlights {
             for highlight in highlights.iter().take(2) {
-                println!(
-                    "   > {}",
-                    highlight.replace("<mark>", "[").replace("</mark>", "]")
-                );
+                println!("   > {}", strip_marks(highlight));
             }
         } else {
             // Show content preview (char-boundary safe for Korean text)
@@ -92,75 +507,304 @@ fn print_results(results: &[baram::embedding::SearchResult], query: &str, mode:
     }
 }
 
-pub async fn search(query: String, k: usize, threshold: Option<f32>, mode: &str) -> Result<()> {
-    println!("Searching for: \"{query}\" (mode: {mode})");
-    println!("================================");
+pub async fn search(config: &Config, params: SearchParams) -> Result<()> {
+    let SearchParams {
+        query,
+        k,
+        threshold,
+        mode,
+        collapse,
+        output,
+        fields,
+        local_model,
+        local,
+        input,
+        cache,
+        rerank,
+        rerank_window,
+        rerank_model,
+    } = params;
+    let rerank_config = RerankMethod::parse(&rerank)?.map(|method| RerankConfig {
+        window: rerank_window.max(1),
+        model: rerank_model,
+        ..RerankConfig::new(method)
+    });
+    let mode = if local { "local" } else { mode.as_str() };
+    let embed_query = !local && uses_embedding(mode)?;
+    let output: SearchOutput = output.parse().map_err(anyhow::Error::msg)?;
+    let fields = parse_fields(fields.as_deref(), output).map_err(anyhow::Error::msg)?;
 
-    // Create OpenSearch client with default config
-    let opensearch_config = OpenSearchConfig {
-        url: std::env::var("OPENSEARCH_URL")
-            .unwrap_or_else(|_| "http://localhost:9200".to_string()),
-        index_name: std::env::var("OPENSEARCH_INDEX")
-            .unwrap_or_else(|_| "baram-articles".to_string()),
-        username: std::env::var("OPENSEARCH_USER").ok(),
-        password: std::env::var("OPENSEARCH_PASSWORD").ok(),
+    // Keep stdout parseable for the machine-readable formats
+    if output == SearchOutput::Text {
+        print_title(&t!("cli.search.starting", query = query, mode = mode), '=');
+    }
+
+    // Configure search
+    let mut search_config = SearchConfig {
+        k,
+        min_score: threshold,
+        include_highlights: true,
+        collapse_by_story: collapse,
+        ..Default::default()
     };
 
+    if local {
+        tracing::info!(query = %query, input = %input.display(), k = k, "Running local full-text search");
+        let results = local_search(&input, &query, &search_config)?;
+        match output {
+            SearchOutput::Text => print_results(&results, &query, mode),
+            _ => print!("{}", render_results(&results, output, &fields)?),
+        }
+        return Ok(());
+    }
+
+    let opensearch_config = config.opensearch.clone().with_env_overrides();
+
     let store = VectorStore::new(&opensearch_config).context("Failed to connect to OpenSearch")?;
 
     // Check if index exists
     if !store.index_exists().await? {
-        println!("Index '{}' does not exist.", opensearch_config.index_name);
-        println!("Run 'baram index' first to create and populate the index.");
+        if output != SearchOutput::Text || is_quiet() {
+            bail!(
+                "{}",
+                t!("cli.search.no_index", index = opensearch_config.index_name)
+            );
+        }
+        println!(
+            "{}",
+            t!("cli.search.no_index", index = opensearch_config.index_name)
+        );
+        println!("{}", t!("cli.search.run_index_first"));
         return Ok(());
     }
 
-    // Configure search
-    let search_config = SearchConfig {
-        k,
-        min_score: threshold,
-        include_highlights: true,
-        ..Default::default()
+    // Hits of an article's chunks are merged into one result
+    search_config.chunked = store.has_chunks().await.unwrap_or(false);
+
+    let cache = if cache {
+        OptionalCache::from_config(&CacheConfig::from_env()?).await
+    } else {
+        OptionalCache::new(None)
+    };
+
+    // The same search within the cache TTL gets the same results
+    let result_key = search_cache_key(
+        &query,
+        mode,
+        &search_config,
+        local_model.as_deref(),
+        rerank_config.as_ref(),
+        &opensearch_config.index_name,
+    );
+    if let Some(cached) = cache.get_search(&result_key).await {
+        tracing::info!(query = %query, mode = %cached.mode, "Search result cache hit");
+        let results: Vec<SearchResult> = cached.results.into_iter().map(Into::into).collect();
+        match output {
+            SearchOutput::Text => {
+                print_results(&results, &query, &cached.mode);
+                print_cache_stats(&cache);
+            }
+            _ => print!("{}", render_results(&results, output, &fields)?),
+        }
+        return Ok(());
+    }
+
+    // Without a reachable embedding backend, vector modes degrade to BM25
+    let query_vector = if embed_query {
+        match query_embedding(&query, local_model.as_deref(), &cache).await {
+            Ok(vector) => Some(vector),
+            Err(e) => {
+                tracing::warn!(error = %format!("{e:#}"), mode = mode, "Query embedding failed, falling back to BM25");
+                if output == SearchOutput::Text {
+                    say!(
+                        "{}",
+                        t!("cli.search.fallback_bm25", error = format!("{e:#}"))
+                    );
+                }
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Rerank a window of candidates; if that fails, keep the first-stage order
+    let reranked = match &rerank_config {
+        Some(rerank_config) => match store
+            .search_reranked(
+                &query,
+                query_vector.as_deref(),
+                &search_config,
+                rerank_config,
+            )
+            .await
+        {
+            Ok(results) => Some((results, rerank_label(rerank_config.method))),
+            Err(e) => {
+                tracing::warn!(error = %format!("{e:#}"), "Reranking failed, keeping first-stage order");
+                if output == SearchOutput::Text {
+                    say!(
+                        "{}",
+                        t!("cli.search.rerank_failed", error = format!("{e:#}"))
+                    );
+                }
+                None
+            }
+        },
+        None => None,
     };
 
+    // Results of a degraded search are not cached, so the next run retries
+    let degraded =
+        (embed_query && query_vector.is_none()) || (rerank_config.is_some() && reranked.is_none());
+
     // Execute search based on mode
-    match mode {
-        "keyword" | "bm25" => {
-            tracing::info!(query = %query, mode = "bm25", k = k, "Running BM25 keyword search");
+    let (results, mode) = match (reranked, mode, query_vector) {
+        (Some(reranked), _, _) => reranked,
+        (None, "hybrid", Some(query_vector)) => {
+            tracing::info!(query = %query, mode = "hybrid", k = k, "Running hybrid search (BM25 + kNN)");
             let results = store
-                .search_bm25(&query, &search_config)
+                .search_hybrid(&query, &query_vector, &search_config)
                 .await
-                .context("BM25 search failed")?;
-            print_results(&results, &query, "bm25");
+                .context("Hybrid search failed")?;
+            (results, "hybrid")
         }
-        "vector" | "knn" => {
+        (None, _, Some(query_vector)) => {
             tracing::info!(query = %query, mode = "knn", k = k, "Running kNN vector search");
-            let query_vector = get_query_embedding(&query).await?;
             let results = store
                 .search_knn(&query_vector, &search_config)
                 .await
                 .context("kNN search failed")?;
-            print_results(&results, &query, "knn");
+            (results, "knn")
         }
-        "hybrid" => {
-            tracing::info!(query = %query, mode = "hybrid", k = k, "Running hybrid search (BM25 + kNN)");
-            let query_vector = get_query_embedding(&query).await?;
+        (None, _, None) => {
+            tracing::info!(query = %query, mode = "bm25", k = k, "Running BM25 keyword search");
             let results = store
-                .search_hybrid(&query, &query_vector, &search_config)
+                .search_bm25(&query, &search_config)
                 .await
-                .context("Hybrid search failed")?;
-            print_results(&results, &query, "hybrid");
+                .context("BM25 search failed")?;
+            (results, "bm25")
         }
-        other => {
-            anyhow::bail!(
-                "Unknown search mode: '{other}'. Valid modes: keyword, bm25, vector, knn, hybrid"
-            );
+    };
+
+    if !degraded {
+        let cached = CachedSearchResult {
+            results: results.iter().map(SearchResultItem::from).collect(),
+            query: query.clone(),
+            mode: mode.to_string(),
+            cached_at: chrono::Utc::now().timestamp(),
+        };
+        cache.set_search(&result_key, &cached).await;
+    }
+
+    match output {
+        SearchOutput::Text => {
+            print_results(&results, &query, mode);
+            print_cache_stats(&cache);
         }
+        _ => print!("{}", render_results(&results, output, &fields)?),
     }
 
     Ok(())
 }
 
+/// Mode label of reranked results
+fn rerank_label(method: RerankMethod) -> &'static str {
+    match method {
+        RerankMethod::Rrf => "rrf",
+        RerankMethod::CrossEncoder => "cross-encoder",
+    }
+}
+
+/// Search the article bodies stored in the crawl database of `input`, or
+/// the markdown files in `input` with an in-memory full-text index
+///
+/// The stored bodies are used unless a markdown file was written after the
+/// database last changed (a recrawl or retry without `store_bodies`, or an
+/// edited file), so the newer copy of an article is the one searched.
+fn local_search(input: &Path, query: &str, config: &SearchConfig) -> Result<Vec<SearchResult>> {
+    let paths = if input.is_dir() {
+        markdown_paths(input)?
+    } else {
+        Vec::new()
+    };
+
+    let db_path = crawl_db_path(input);
+    if db_path.is_file() {
+        match LocalSearch::open(&db_path) {
+            Ok(index) if !index.is_empty() => {
+                let stale = newest_markdown(&paths).is_some_and(|newest| {
+                    stored_at(&db_path).map_or(true, |stored| newest > stored)
+                });
+                if !stale {
+                    tracing::debug!(
+                        path = %db_path.display(),
+                        documents = index.len(),
+                        "Searching stored article bodies"
+                    );
+                    return index.search(query, config);
+                }
+                tracing::info!(
+                    path = %db_path.display(),
+                    "Markdown files are newer than the stored article bodies, searching the files"
+                );
+            }
+            Ok(_) => {}
+            Err(e) => tracing::debug!(error = %e, "No stored article bodies"),
+        }
+    }
+
+    if !input.is_dir() {
+        bail!(
+            "{}",
+            t!("cli.common.markdown_dir_not_found", path = input.display())
+        );
+    }
+
+    let documents = paths
+        .iter()
+        .filter_map(|path| match parse_markdown_to_document(path) {
+            Ok(doc) => Some(doc),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to parse markdown");
+                None
+            }
+        });
+    let index = LocalSearch::build(documents)?;
+    tracing::debug!(documents = index.len(), "Built local full-text index");
+    index.search(query, config)
+}
+
+/// Markdown files in `dir`, sorted
+fn markdown_paths(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Latest modification time of `paths`
+fn newest_markdown(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
+/// When the crawl database at `path` last changed, counting writes still
+/// in its write-ahead log
+fn stored_at(path: &Path) -> Option<SystemTime> {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    [path, Path::new(&wal)]
+        .iter()
+        .filter_map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +824,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_uses_embedding() {
+        for mode in ["vector", "knn", "hybrid"] {
+            assert!(uses_embedding(mode).unwrap(), "{mode}");
+        }
+        for mode in ["keyword", "bm25"] {
+            assert!(!uses_embedding(mode).unwrap(), "{mode}");
+        }
+        assert!(uses_embedding("semantic").is_err());
+    }
+
+    #[test]
+    fn test_search_cache_key() {
+        let config = SearchConfig {
+            k: 10,
+            ..Default::default()
+        };
+        let key = |query, mode, config: &SearchConfig| {
+            search_cache_key(query, mode, config, None, None, "baram-articles")
+        };
+
+        assert_eq!(
+            key("반도체", "hybrid", &config),
+            key("반도체", "hybrid", &config)
+        );
+        assert_ne!(
+            key("반도체", "hybrid", &config),
+            key("반도체", "bm25", &config)
+        );
+        assert_ne!(
+            key("반도체", "hybrid", &config),
+            key("배터리", "hybrid", &config)
+        );
+        let collapsed = SearchConfig {
+            collapse_by_story: true,
+            ..config.clone()
+        };
+        assert_ne!(
+            key("반도체", "hybrid", &config),
+            key("반도체", "hybrid", &collapsed)
+        );
+        let rrf = RerankConfig::new(RerankMethod::Rrf);
+        assert_ne!(
+            key("반도체", "hybrid", &config),
+            search_cache_key(
+                "반도체",
+                "hybrid",
+                &config,
+                None,
+                Some(&rrf),
+                "baram-articles"
+            )
+        );
+    }
+
     #[test]
     fn test_print_results_empty() {
         // Should not panic on empty results
@@ -188,7 +887,7 @@ mod tests {
 
     #[test]
     fn test_print_results_with_data() {
-        let results = vec![baram::embedding::SearchResult {
+        let results = vec![SearchResult {
             id: "001_002".to_string(),
             score: 0.95,
             title: "Test Article".to_string(),
@@ -198,8 +897,186 @@ mod tests {
             url: "https://example.com/article".to_string(),
             published_at: Some("2026-02-15T10:00:00Z".to_string()),
             highlights: Some(vec!["<mark>Test</mark> highlight".to_string()]),
+            passage: None,
+            story_id: Some("story_20260215_001_002".to_string()),
         }];
         // Should not panic
         print_results(&results, "test", "hybrid");
     }
+
+    fn sample_results() -> Vec<SearchResult> {
+        vec![
+            SearchResult {
+                id: "001_0000000001".to_string(),
+                score: 0.95,
+                title: "국회, 예산안 처리".to_string(),
+                content: "본문\n둘째 줄".to_string(),
+                category: "politics".to_string(),
+                publisher: Some("연합뉴스".to_string()),
+                url: "https://n.news.naver.com/mnews/article/001/0000000001".to_string(),
+                published_at: Some("2026-02-15T10:00:00Z".to_string()),
+                highlights: Some(vec!["<mark>예산안</mark> 처리".to_string()]),
+                passage: None,
+                story_id: None,
+            },
+            SearchResult {
+                id: "023_0000000002".to_string(),
+                score: 0.5,
+                title: "A | B, \"quoted\"".to_string(),
+                content: String::new(),
+                category: "economy".to_string(),
+                publisher: None,
+                url: "https://n.news.naver.com/mnews/article/023/0000000002".to_string(),
+                published_at: None,
+                highlights: None,
+                passage: None,
+                story_id: Some("story_1".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        assert_eq!(
+            parse_fields(None, SearchOutput::Json).unwrap().len(),
+            SearchField::ALL.len()
+        );
+        assert_eq!(
+            parse_fields(None, SearchOutput::Csv).unwrap(),
+            DEFAULT_FIELDS.to_vec()
+        );
+        assert_eq!(
+            parse_fields(Some("url, Score"), SearchOutput::Table).unwrap(),
+            [SearchField::Url, SearchField::Score]
+        );
+        assert!(parse_fields(Some("url,body"), SearchOutput::Csv).is_err());
+        assert!(parse_fields(Some(","), SearchOutput::Csv).is_err());
+        assert!("yaml".parse::<SearchOutput>().is_err());
+    }
+
+    #[test]
+    fn test_render_json() {
+        let fields = [
+            SearchField::Rank,
+            SearchField::Publisher,
+            SearchField::StoryId,
+        ];
+        let json = render_results(&sample_results(), SearchOutput::Json, &fields).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["rank"], 1);
+        assert_eq!(value[0]["publisher"], "연합뉴스");
+        assert!(value[0]["story_id"].is_null());
+        assert_eq!(value[1]["story_id"], "story_1");
+        assert!(value[0].get("title").is_none());
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let fields = [
+            SearchField::Title,
+            SearchField::Highlights,
+            SearchField::Score,
+        ];
+        let csv = render_results(&sample_results(), SearchOutput::Csv, &fields).unwrap();
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        assert_eq!(
+            reader.headers().unwrap(),
+            vec!["title", "highlights", "score"]
+        );
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(&rows[0][1], "[예산안] 처리");
+        assert_eq!(&rows[1][0], "A | B, \"quoted\"");
+        assert_eq!(&rows[1][2], "0.500");
+    }
+
+    #[test]
+    fn test_render_table_and_markdown() {
+        let fields = [SearchField::Rank, SearchField::Title, SearchField::Content];
+        let table = render_results(&sample_results(), SearchOutput::Table, &fields).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("rank  title"));
+        // Wide Korean characters are aligned by display width
+        let content_column = lines[0].find("content").unwrap();
+        assert_eq!(lines[2].width() - "본문 둘째 줄".width(), content_column);
+
+        let markdown = render_results(&sample_results(), SearchOutput::Markdown, &fields).unwrap();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "| rank | title | content |");
+        assert_eq!(lines[1], "| --- | --- | --- |");
+        assert_eq!(lines[3], "| 2 | A \\| B, \"quoted\" |  |");
+    }
+
+    #[test]
+    fn test_truncate_width() {
+        assert_eq!(truncate_width("short", 10), "short");
+        let cut = truncate_width("가나다라마바사", 6);
+        assert_eq!(cut, "가나…");
+        assert!(cut.width() <= 6);
+    }
+
+    #[test]
+    fn test_local_search() {
+        let dir = tempfile::tempdir().unwrap();
+        let raw = dir.path().join("raw");
+        std::fs::create_dir(&raw).unwrap();
+        for (aid, title, body) in [
+            ("0000000001", "반도체 수출 증가", "반도체 수출이 늘었다."),
+            ("0000000002", "국회 본회의", "예산안이 통과됐다."),
+        ] {
+            std::fs::write(
+                raw.join(format!("001_{aid}.md")),
+                format!("---\noid: \"001\"\naid: \"{aid}\"\ncategory: \"economy\"\n---\n\n# {title}\n\n{body}\n"),
+            )
+            .unwrap();
+        }
+        std::fs::write(raw.join("notes.txt"), "반도체").unwrap();
+
+        let results = local_search(&raw, "반도체", &SearchConfig::default()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "반도체 수출 증가");
+
+        // Stored bodies take precedence over older markdown files
+        let mut db = baram::storage::Database::new(&baram::config::DatabaseConfig {
+            sqlite_path: crawl_db_path(&raw),
+            postgres_url: String::new(),
+            pool_size: 1,
+            store_bodies: true,
+        })
+        .unwrap();
+        db.init_sqlite(&crawl_db_path(&raw)).unwrap();
+        db.record_success(&baram::models::ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0000000003".to_string(),
+            title: "반도체 장비 투자".to_string(),
+            content: "반도체 장비 투자가 늘었다.".to_string(),
+            url: "https://n.news.naver.com/article/001/0000000003".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        let results = local_search(&raw, "반도체", &SearchConfig::default()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "반도체 장비 투자");
+
+        // Markdown written after the database last changed is searched instead
+        let newer = raw.join("001_0000000004.md");
+        std::fs::write(
+            &newer,
+            "---\noid: \"001\"\naid: \"0000000004\"\n---\n\n# 반도체 감산\n\n반도체 감산 발표.\n",
+        )
+        .unwrap();
+        let later = stored_at(&crawl_db_path(&raw)).unwrap() + std::time::Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(&newer)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        let results = local_search(&raw, "반도체", &SearchConfig::default()).unwrap();
+        let mut titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, ["반도체 감산", "반도체 수출 증가"]);
+
+        assert!(local_search(&raw.join("missing"), "반도체", &SearchConfig::default()).is_err());
+    }
 }
@@ -1,42 +1,130 @@
 use anyhow::{Context, Result};
 use axum::{
-    extract::{Path as AxumPath, Query, State},
-    http::StatusCode,
+    extract::{Path as AxumPath, Query, Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
+use rust_i18n::t;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use utoipa_swagger_ui::SwaggerUi;
 
+use baram::config::{secrets, CategoryConfig, Config, CrawlerConfig};
 use baram::coordinator::{CoordinatorConfig, CoordinatorServer};
 use baram::crawler::distributed::DistributedRunner;
 use baram::crawler::instance::InstanceConfig;
-use baram::embedding::{Embedder, EmbeddingConfig};
+use baram::crawler::FetchPolicy;
+use baram::embedding::rerank::{RerankRequest, RerankResponse};
+use baram::embedding::{CrossEncoder, Embedder, EmbeddingConfig};
+use baram::health::{Dependency, DependencyChecker, DependencyHealth, HealthReport};
+use baram::llm::{ArticleInfo, LlmClient, LlmConfig};
+use baram::notifications::{NotificationManager, NotificationsConfig};
 use baram::scheduler::rotation::CrawlerInstance;
 
+use super::output::say;
+use super::print_title;
+
 // ============================================================================
 // Embedding Server Implementation
 // ============================================================================
 
 /// Shared state for embedding server
 struct EmbeddingServerState {
-    embedder: Embedder,
-    model_name: String,
+    /// Loaded models by ID; the first is used when a request names none
+    embedders: Vec<(String, Embedder)>,
+    /// Loaded cross-encoders by ID; the first is used when a request names none
+    rerankers: Vec<(String, CrossEncoder)>,
     ready: std::sync::atomic::AtomicBool,
 }
 
+impl EmbeddingServerState {
+    /// The embedder for `model`, or the default one
+    fn embedder(
+        &self,
+        model: Option<&str>,
+    ) -> Result<&Embedder, (StatusCode, Json<ErrorResponse>)> {
+        let found = match model {
+            Some(model) => self.embedders.iter().find(|(id, _)| id == model),
+            None => self.embedders.first(),
+        };
+        found.map(|(_, embedder)| embedder).ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: format!(
+                        "Model {} is not served; available: {}",
+                        model.unwrap_or_default(),
+                        self.model_ids().join(", ")
+                    ),
+                }),
+            )
+        })
+    }
+
+    fn model_ids(&self) -> Vec<String> {
+        self.embedders.iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    /// The reranker for `model`, or the default one
+    fn reranker(
+        &self,
+        model: Option<&str>,
+    ) -> Result<&CrossEncoder, (StatusCode, Json<ErrorResponse>)> {
+        if self.rerankers.is_empty() {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse {
+                    error: "No reranker is loaded; start the server with --rerank-model"
+                        .to_string(),
+                }),
+            ));
+        }
+        let found = match model {
+            Some(model) => self.rerankers.iter().find(|(id, _)| id == model),
+            None => self.rerankers.first(),
+        };
+        found.map(|(_, reranker)| reranker).ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: format!(
+                        "Reranker {} is not served; available: {}",
+                        model.unwrap_or_default(),
+                        self.rerank_model_ids().join(", ")
+                    ),
+                }),
+            )
+        })
+    }
+
+    fn rerank_model_ids(&self) -> Vec<String> {
+        self.rerankers.iter().map(|(id, _)| id.clone()).collect()
+    }
+}
+
 /// Request for single text embedding
 #[derive(Debug, Deserialize)]
 struct EmbedRequest {
     text: String,
+    /// Model to use (default: the first model the server loaded)
+    #[serde(default)]
+    model: Option<String>,
 }
 
 /// Request for batch text embedding
 #[derive(Debug, Deserialize)]
 struct BatchEmbedRequest {
     texts: Vec<String>,
+    /// Model to use (default: the first model the server loaded)
+    #[serde(default)]
+    model: Option<String>,
 }
 
 /// Response for embedding requests
@@ -58,7 +146,12 @@ struct BatchEmbedResponse {
 #[derive(Debug, Serialize)]
 struct HealthResponse {
     status: String,
+    /// Default model
     model: String,
+    /// Every model requests can name
+    models: Vec<String>,
+    /// Cross-encoders served at `/rerank`
+    rerank_models: Vec<String>,
     ready: bool,
     device: String,
 }
@@ -70,36 +163,59 @@ struct ErrorResponse {
 }
 
 /// Start the embedding server
+///
+/// Every model in `models` is loaded; requests pick one by ID and default to
+/// the first. All models must produce embeddings of the index dimension.
+/// The cross-encoders in `rerank_models` are served at `/rerank` the same way.
 pub async fn embedding_server(
     host: String,
     port: u16,
-    model: String,
+    models: Vec<String>,
+    rerank_models: Vec<String>,
     max_seq_length: usize,
     batch_size: usize,
     use_gpu: bool,
 ) -> Result<()> {
-    println!("Starting Embedding Server");
-    println!("=========================");
-    println!("  Host: {host}");
-    println!("  Port: {port}");
-    println!("  Model: {model}");
-    println!("  Max Sequence Length: {max_seq_length}");
-    println!("  Batch Size: {batch_size}");
-    println!("  Use GPU: {use_gpu}");
-    println!();
-
-    // Initialize embedding model
-    println!("Loading embedding model...");
-    let config = EmbeddingConfig {
-        model_id: model.clone(),
-        embedding_dim: 1024, // multilingual-e5-large uses 1024 dimensions
-        max_seq_length,
-        use_gpu,
-        batch_size,
-        normalize: true,
-    };
-
-    let embedder = Embedder::from_pretrained(config).context("Failed to load embedding model")?;
+    print_title(&t!("cli.serve.embedding_title"), '=');
+    say!("  {}", t!("cli.serve.host", host = host));
+    say!("  {}", t!("cli.serve.port", port = port));
+    say!("  {}", t!("cli.serve.model", model = models.join(", ")));
+    if !rerank_models.is_empty() {
+        say!(
+            "  {}",
+            t!("cli.serve.rerank_model", model = rerank_models.join(", "))
+        );
+    }
+    say!(
+        "  {}",
+        t!("cli.serve.max_seq_length", length = max_seq_length)
+    );
+    say!("  {}", t!("cli.serve.batch_size", size = batch_size));
+    say!("  {}", t!("cli.serve.use_gpu", enabled = use_gpu));
+    say!();
+
+    // Initialize embedding models
+    say!("{}", t!("cli.serve.loading_model"));
+    let mut embedders = Vec::with_capacity(models.len());
+    for model in models {
+        let config = EmbeddingConfig {
+            model_id: model.clone(),
+            embedding_dim: 1024, // multilingual-e5-large uses 1024 dimensions
+            max_seq_length,
+            use_gpu,
+            batch_size,
+            normalize: true,
+        };
+        let embedder = Embedder::from_pretrained(config)
+            .with_context(|| format!("Failed to load embedding model {model}"))?;
+        embedders.push((model, embedder));
+    }
+    let mut rerankers = Vec::with_capacity(rerank_models.len());
+    for model in rerank_models {
+        let reranker = CrossEncoder::from_pretrained(&model, max_seq_length, batch_size, use_gpu)
+            .with_context(|| format!("Failed to load reranker model {model}"))?;
+        rerankers.push((model, reranker));
+    }
 
     let device = if use_gpu {
         "cuda (if available)"
@@ -107,14 +223,14 @@ pub async fn embedding_server(
         "cpu"
     };
 
-    println!("Model loaded successfully!");
-    println!("  Device: {device}");
-    println!();
+    say!("{}", t!("cli.serve.model_loaded"));
+    say!("  {}", t!("cli.serve.device", device = device));
+    say!();
 
     // Create shared state
     let state = Arc::new(EmbeddingServerState {
-        embedder,
-        model_name: model,
+        embedders,
+        rerankers,
         ready: std::sync::atomic::AtomicBool::new(true),
     });
 
@@ -123,6 +239,7 @@ pub async fn embedding_server(
         .route("/health", get(health_handler))
         .route("/embed", post(embed_handler))
         .route("/embed/batch", post(batch_embed_handler))
+        .route("/rerank", post(rerank_handler))
         .route("/", get(root_handler))
         .layer(TraceLayer::new_for_http())
         .layer(
@@ -139,13 +256,14 @@ pub async fn embedding_server(
         .await
         .context(format!("Failed to bind to {addr}"))?;
 
-    println!("Embedding server listening on http://{addr}");
-    println!();
-    println!("Endpoints:");
-    println!("  GET  /health      - Health check");
-    println!("  POST /embed       - Single text embedding");
-    println!("  POST /embed/batch - Batch text embedding");
-    println!();
+    say!("{}", t!("cli.serve.embedding_listening", addr = addr));
+    say!();
+    say!("{}", t!("cli.serve.endpoints"));
+    say!("  GET  /health      - Health check");
+    say!("  POST /embed       - Single text embedding");
+    say!("  POST /embed/batch - Batch text embedding");
+    say!("  POST /rerank      - Cross-encoder reranking");
+    say!();
 
     axum::serve(listener, app).await.context("Server error")?;
 
@@ -160,7 +278,8 @@ async fn root_handler() -> Json<serde_json::Value> {
         "endpoints": {
             "health": "GET /health",
             "embed": "POST /embed",
-            "batch_embed": "POST /embed/batch"
+            "batch_embed": "POST /embed/batch",
+            "rerank": "POST /rerank"
         }
     }))
 }
@@ -174,7 +293,9 @@ async fn health_handler(State(state): State<Arc<EmbeddingServerState>>) -> Json<
         } else {
             "loading".to_string()
         },
-        model: state.model_name.clone(),
+        model: state.model_ids().first().cloned().unwrap_or_default(),
+        models: state.model_ids(),
+        rerank_models: state.rerank_model_ids(),
         ready,
         device: "auto".to_string(),
     })
@@ -194,7 +315,10 @@ async fn embed_handler(
         ));
     }
 
-    match state.embedder.embed(&request.text) {
+    match state
+        .embedder(request.model.as_deref())?
+        .embed(&request.text)
+    {
         Ok(embedding) => {
             let dimension = embedding.len();
             Ok(Json(EmbedResponse {
@@ -237,7 +361,10 @@ async fn batch_embed_handler(
         ));
     }
 
-    match state.embedder.embed_batch(&request.texts) {
+    match state
+        .embedder(request.model.as_deref())?
+        .embed_batch(&request.texts)
+    {
         Ok(embeddings) => {
             let count = embeddings.len();
             let dimension = embeddings.first().map(|e| e.len()).unwrap_or(0);
@@ -259,6 +386,46 @@ async fn batch_embed_handler(
     }
 }
 
+/// Cross-encoder reranking handler
+async fn rerank_handler(
+    State(state): State<Arc<EmbeddingServerState>>,
+    Json(request): Json<RerankRequest>,
+) -> Result<Json<RerankResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if request.query.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Query cannot be empty".to_string(),
+            }),
+        ));
+    }
+
+    if request.documents.len() > 200 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Maximum 200 documents per request".to_string(),
+            }),
+        ));
+    }
+
+    match state
+        .reranker(request.model.as_deref())?
+        .score(&request.query, &request.documents)
+    {
+        Ok(scores) => Ok(Json(RerankResponse { scores })),
+        Err(e) => {
+            tracing::error!(error = %e, "Reranking failed");
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Reranking failed: {e}"),
+                }),
+            ))
+        }
+    }
+}
+
 // ============================================================================
 // REST API Server Implementation
 // ============================================================================
@@ -269,10 +436,70 @@ struct ApiServerState {
     embedding_server_url: String,
     http_client: reqwest::Client,
     clusters_dir: String,
+    /// Triple storage behind the entity profiles
+    extractions: Arc<super::trends::ExtractionCache>,
+    /// SQLite database holding the sentiment aggregates
+    sqlite_path: std::path::PathBuf,
+    /// PostgreSQL time series backing the trends endpoints
+    timeseries: baram::storage::PgTimeSeriesStore,
+    /// Dependency probes for the health and readiness endpoints
+    health: DependencyChecker,
+    /// Accepted API keys (authentication is disabled if empty)
+    api_keys: Vec<String>,
+    /// LLM answering questions from search result passages
+    llm: LlmClient,
 }
 
+/// Parameters for the REST API server
+pub struct ApiServerParams {
+    pub host: String,
+    pub port: u16,
+    pub database: baram::config::DatabaseConfig,
+    /// OpenSearch connection; `OPENSEARCH_*` environment variables override it
+    pub opensearch: baram::config::OpenSearchConfig,
+    /// API keys accepted on the data endpoints, in addition to `BARAM_API_KEYS`
+    pub api_keys: Vec<String>,
+}
+
+/// Deepest result a search can page to
+const MAX_SEARCH_WINDOW: usize = 1000;
+
+/// Passages of the top results an answer is generated from
+const ANSWER_PASSAGES: usize = 5;
+
+/// OpenAPI document for the search API server
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Baram Search API",
+        description = "Hybrid (BM25 + kNN) article search and event cluster browsing"
+    ),
+    paths(
+        api_health_handler,
+        api_ready_handler,
+        api_search_handler,
+        api_article_handler,
+        api_similar_handler,
+        api_stats_handler,
+        api_events_handler,
+        api_event_detail_handler,
+        api_trends_keywords_handler,
+        api_trends_entities_handler,
+        api_entity_handler,
+    ),
+    tags(
+        (name = "health", description = "Service and dependency health"),
+        (name = "search", description = "Article search"),
+        (name = "articles", description = "Indexed articles and index statistics"),
+        (name = "events", description = "Event clusters produced by `baram cluster`"),
+        (name = "trends", description = "Trending keywords and entities, and entity profiles"),
+    )
+)]
+struct SearchApiDoc;
+
 /// Query parameters for the search endpoint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct SearchQuery {
     /// Search query text
     q: String,
@@ -285,6 +512,10 @@ struct SearchQuery {
     #[serde(default = "default_k")]
     k: usize,
 
+    /// Number of results to skip (pagination)
+    #[serde(default)]
+    offset: usize,
+
     /// Minimum score threshold
     threshold: Option<f32>,
 
@@ -296,6 +527,18 @@ struct SearchQuery {
 
     /// Filter by date range end (ISO 8601)
     date_to: Option<String>,
+
+    /// Keep only the best-scoring article of each story
+    #[serde(default)]
+    collapse: bool,
+
+    /// Add each result's best-matching passage, with character offsets
+    #[serde(default)]
+    passages: bool,
+
+    /// Answer the query from the top passages with the LLM (implies `passages`)
+    #[serde(default)]
+    answer: bool,
 }
 
 fn default_search_mode() -> String {
@@ -307,26 +550,107 @@ fn default_k() -> usize {
 }
 
 /// API search response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct ApiSearchResponse {
     query: String,
     mode: String,
+    offset: usize,
+    total: usize,
+    results: Vec<baram::embedding::SearchResult>,
+    /// LLM answer citing results as `[1]`, `[2]`, ... in passage order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+}
+
+/// Query parameters for the similar-articles endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct SimilarQuery {
+    /// Number of results to return
+    #[serde(default = "default_k")]
+    k: usize,
+
+    /// Filter by category
+    category: Option<String>,
+
+    /// Keep only the best-scoring article of each story
+    #[serde(default)]
+    collapse: bool,
+}
+
+/// API similar-articles response
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiSimilarResponse {
+    id: String,
     total: usize,
     results: Vec<baram::embedding::SearchResult>,
 }
 
+/// Indexed article
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiArticle {
+    id: String,
+    oid: String,
+    aid: String,
+    title: String,
+    content: String,
+    category: String,
+    publisher: Option<String>,
+    author: Option<String>,
+    url: String,
+    published_at: Option<String>,
+    crawled_at: String,
+    comment_count: Option<i32>,
+    story_id: Option<String>,
+}
+
+impl From<baram::embedding::IndexDocument> for ApiArticle {
+    fn from(doc: baram::embedding::IndexDocument) -> Self {
+        Self {
+            id: doc.id,
+            oid: doc.oid,
+            aid: doc.aid,
+            title: doc.title,
+            content: doc.content,
+            category: doc.category,
+            publisher: doc.publisher,
+            author: doc.author,
+            url: doc.url,
+            published_at: doc.published_at,
+            crawled_at: doc.crawled_at,
+            comment_count: doc.comment_count,
+            story_id: doc.story_id,
+        }
+    }
+}
+
+/// Query parameters for the stats endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct StatsQuery {
+    /// Number of publishers to list
+    #[serde(default = "default_stats_publishers")]
+    publishers: usize,
+}
+
+fn default_stats_publishers() -> usize {
+    20
+}
+
 /// API health response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct ApiHealthResponse {
     status: String,
     service: String,
     version: String,
     opensearch_connected: bool,
     document_count: Option<usize>,
+    /// Dependency checks (OpenSearch, PostgreSQL, embedding server, Redis)
+    checks: Vec<DependencyHealth>,
 }
 
 /// API error response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 struct ApiErrorResponse {
     error: String,
     code: u16,
@@ -337,6 +661,22 @@ async fn fetch_query_embedding(
     client: &reqwest::Client,
     embedding_url: &str,
     text: &str,
+) -> Result<Vec<f32>, (StatusCode, Json<ApiErrorResponse>)> {
+    let started = std::time::Instant::now();
+    let result = request_query_embedding(client, embedding_url, text).await;
+    baram::metrics::record_embedding_latency(
+        "query",
+        started.elapsed().as_secs_f64(),
+        result.is_ok(),
+    );
+    result
+}
+
+/// Send a single-text embedding request
+async fn request_query_embedding(
+    client: &reqwest::Client,
+    embedding_url: &str,
+    text: &str,
 ) -> Result<Vec<f32>, (StatusCode, Json<ApiErrorResponse>)> {
     let resp = client
         .post(format!("{embedding_url}/embed"))
@@ -409,6 +749,19 @@ async fn fetch_query_embedding(
 }
 
 /// GET /api/search — Search articles with hybrid/keyword/vector modes
+#[utoipa::path(
+    get,
+    path = "/api/search",
+    tag = "search",
+    params(SearchQuery),
+    responses(
+        (status = 200, description = "Search results", body = ApiSearchResponse),
+        (status = 400, description = "Empty query or unknown mode", body = ApiErrorResponse),
+        (status = 500, description = "OpenSearch query failed", body = ApiErrorResponse),
+        (status = 502, description = "Embedding server returned an invalid response", body = ApiErrorResponse),
+        (status = 503, description = "Embedding server unavailable", body = ApiErrorResponse)
+    )
+)]
 async fn api_search_handler(
     State(state): State<Arc<ApiServerState>>,
     Query(params): Query<SearchQuery>,
@@ -424,14 +777,19 @@ async fn api_search_handler(
     }
 
     let k = params.k.min(100); // Cap at 100 results
+    let offset = params.offset.min(MAX_SEARCH_WINDOW.saturating_sub(k));
 
     let search_config = baram::embedding::SearchConfig {
         k,
+        offset,
         min_score: params.threshold,
         category: params.category.clone(),
         date_from: params.date_from.clone(),
         date_to: params.date_to.clone(),
         include_highlights: true,
+        collapse_by_story: params.collapse,
+        passage_query: (params.passages || params.answer).then(|| params.q.clone()),
+        chunked: state.store.has_chunks().await.unwrap_or(false),
         ..Default::default()
     };
 
@@ -510,41 +868,262 @@ async fn api_search_handler(
     let total = results.len();
     tracing::info!(query = %params.q, mode = mode, total = total, "Search completed");
 
+    // A failed answer leaves the search results usable
+    let answer = if params.answer {
+        let passages = answer_passages(&results);
+        match state.llm.answer_question(&params.q, &passages).await {
+            Ok(answer) => Some(answer).filter(|a| !a.is_empty()),
+            Err(e) => {
+                tracing::warn!(error = %e, "Answer generation failed");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     Ok(Json(ApiSearchResponse {
         query: params.q,
         mode: mode.to_string(),
+        offset,
         total,
         results,
+        answer,
+    }))
+}
+
+/// Passages of the top results with one, numbered from 1 in the answer
+fn answer_passages(results: &[baram::embedding::SearchResult]) -> Vec<ArticleInfo> {
+    results
+        .iter()
+        .filter_map(|result| {
+            result.passage.as_ref().map(|passage| ArticleInfo {
+                id: result.id.clone(),
+                title: result.title.clone(),
+                content: passage.text.clone(),
+            })
+        })
+        .take(ANSWER_PASSAGES)
+        .collect()
+}
+
+/// GET /api/articles/:id — Get an indexed article by ID
+#[utoipa::path(
+    get,
+    path = "/api/articles/{id}",
+    tag = "articles",
+    params(("id" = String, Path, description = "Article ID (`{oid}_{aid}`)")),
+    responses(
+        (status = 200, description = "Indexed article", body = ApiArticle),
+        (status = 404, description = "Article not found", body = ApiErrorResponse),
+        (status = 500, description = "OpenSearch query failed", body = ApiErrorResponse)
+    )
+)]
+async fn api_article_handler(
+    State(state): State<Arc<ApiServerState>>,
+    AxumPath(id): AxumPath<String>,
+) -> Result<Json<ApiArticle>, (StatusCode, Json<ApiErrorResponse>)> {
+    let doc = state.store.get_document(&id).await.map_err(|e| {
+        tracing::error!(error = %e, id = %id, "Article fetch failed");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse {
+                error: format!("Failed to fetch article: {e}"),
+                code: 500,
+            }),
+        )
+    })?;
+
+    match doc {
+        Some(doc) => Ok(Json(doc.into())),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse {
+                error: format!("Article not found: {id}"),
+                code: 404,
+            }),
+        )),
+    }
+}
+
+/// GET /api/articles/:id/similar — Articles similar to an indexed article
+#[utoipa::path(
+    get,
+    path = "/api/articles/{id}/similar",
+    tag = "articles",
+    params(("id" = String, Path, description = "Article ID (`{oid}_{aid}`)"), SimilarQuery),
+    responses(
+        (status = 200, description = "Similar articles", body = ApiSimilarResponse),
+        (status = 404, description = "Article not found", body = ApiErrorResponse),
+        (status = 500, description = "OpenSearch query failed", body = ApiErrorResponse)
+    )
+)]
+async fn api_similar_handler(
+    State(state): State<Arc<ApiServerState>>,
+    AxumPath(id): AxumPath<String>,
+    Query(params): Query<SimilarQuery>,
+) -> Result<Json<ApiSimilarResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let search_config = baram::embedding::SearchConfig {
+        k: params.k.min(100),
+        category: params.category,
+        include_highlights: false,
+        collapse_by_story: params.collapse,
+        ..Default::default()
+    };
+
+    let results = state
+        .store
+        .search_similar(&id, &search_config)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, id = %id, "Similar article search failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse {
+                    error: format!("Search failed: {e}"),
+                    code: 500,
+                }),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse {
+                    error: format!("Article not found: {id}"),
+                    code: 404,
+                }),
+            )
+        })?;
+
+    Ok(Json(ApiSimilarResponse {
+        id,
+        total: results.len(),
+        results,
     }))
 }
 
-/// GET /api/health — Health check with OpenSearch connectivity
+/// GET /api/stats — Indexed article counts by category, publisher and date
+#[utoipa::path(
+    get,
+    path = "/api/stats",
+    tag = "articles",
+    params(StatsQuery),
+    responses(
+        (status = 200, description = "Index statistics", body = baram::embedding::IndexStats),
+        (status = 500, description = "OpenSearch query failed", body = ApiErrorResponse)
+    )
+)]
+async fn api_stats_handler(
+    State(state): State<Arc<ApiServerState>>,
+    Query(params): Query<StatsQuery>,
+) -> Result<Json<baram::embedding::IndexStats>, (StatusCode, Json<ApiErrorResponse>)> {
+    state
+        .store
+        .index_stats(params.publishers.min(100))
+        .await
+        .map(Json)
+        .map_err(|e| {
+            tracing::error!(error = %e, "Index stats query failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse {
+                    error: format!("Failed to load stats: {e}"),
+                    code: 500,
+                }),
+            )
+        })
+}
+
+/// Reject data endpoint requests without a valid API key
+///
+/// Accepts `Authorization: Bearer <key>` or `X-API-Key: <key>`. Does nothing
+/// when no keys are configured.
+async fn require_api_key(
+    State(state): State<Arc<ApiServerState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if state.api_keys.is_empty() {
+        return next.run(request).await;
+    }
+
+    let headers = request.headers();
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .or_else(|| headers.get("x-api-key").and_then(|v| v.to_str().ok()));
+
+    match presented {
+        Some(key) if state.api_keys.iter().any(|k| k == key.trim()) => next.run(request).await,
+        _ => (
+            StatusCode::UNAUTHORIZED,
+            Json(ApiErrorResponse {
+                error: "Missing or invalid API key".to_string(),
+                code: 401,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+/// GET /api/health — Health check with dependency probes
+#[utoipa::path(
+    get,
+    path = "/api/health",
+    tag = "health",
+    responses((status = 200, description = "Service status", body = ApiHealthResponse))
+)]
 async fn api_health_handler(
     State(state): State<Arc<ApiServerState>>,
 ) -> Json<ApiHealthResponse> {
-    let (connected, count) = match state.store.count().await {
-        Ok(c) => (true, Some(c)),
+    let (report, count) = tokio::join!(state.health.check(), state.store.count());
+    let count = match count {
+        Ok(c) => Some(c),
         Err(e) => {
-            tracing::warn!(error = %e, "OpenSearch health check failed");
-            (false, None)
+            tracing::warn!(error = %e, "OpenSearch document count failed");
+            None
         }
     };
+    let connected = report
+        .check(Dependency::OpenSearch)
+        .is_some_and(|c| c.status != baram::health::HealthStatus::Unhealthy);
 
     Json(ApiHealthResponse {
-        status: if connected {
-            "healthy".to_string()
-        } else {
-            "degraded".to_string()
-        },
+        status: report.status.as_str().to_string(),
         service: "baram API Server".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         opensearch_connected: connected,
         document_count: count,
+        checks: report.checks,
     })
 }
 
+/// GET /api/ready — Readiness probe
+///
+/// Returns 503 while a required dependency (OpenSearch) is unhealthy.
+#[utoipa::path(
+    get,
+    path = "/api/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready (possibly degraded)", body = HealthReport),
+        (status = 503, description = "Not ready", body = HealthReport)
+    )
+)]
+async fn api_ready_handler(
+    State(state): State<Arc<ApiServerState>>,
+) -> (StatusCode, Json<HealthReport>) {
+    let report = state.health.check().await;
+    if !report.is_ready() {
+        tracing::warn!(status = report.status.as_str(), "Readiness check failed");
+    }
+    (report.status.status_code(), Json(report))
+}
+
 /// Query parameters for the events endpoint
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct EventsQuery {
     /// Filter by category
     category: Option<String>,
@@ -563,6 +1142,16 @@ fn default_events_limit() -> usize {
 }
 
 /// GET /api/events — List event clusters
+#[utoipa::path(
+    get,
+    path = "/api/events",
+    tag = "events",
+    params(EventsQuery),
+    responses(
+        (status = 200, description = "Paginated event clusters", body = Object),
+        (status = 500, description = "Clusters file unreadable", body = ApiErrorResponse)
+    )
+)]
 async fn api_events_handler(
     State(state): State<Arc<ApiServerState>>,
     Query(params): Query<EventsQuery>,
@@ -628,6 +1217,17 @@ async fn api_events_handler(
 }
 
 /// GET /api/events/:event_id — Get a single event cluster by ID
+#[utoipa::path(
+    get,
+    path = "/api/events/{event_id}",
+    tag = "events",
+    params(("event_id" = String, Path, description = "Event cluster ID")),
+    responses(
+        (status = 200, description = "Event cluster", body = Object),
+        (status = 404, description = "Event or clusters file not found", body = ApiErrorResponse),
+        (status = 500, description = "Clusters file unreadable", body = ApiErrorResponse)
+    )
+)]
 async fn api_event_detail_handler(
     State(state): State<Arc<ApiServerState>>,
     AxumPath(event_id): AxumPath<String>,
@@ -682,6 +1282,278 @@ async fn api_event_detail_handler(
     }
 }
 
+/// Query parameters for the trends endpoints
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct TrendsQuery {
+    /// Window length in hours, compared with the window before it (default: 24, max: 720)
+    #[serde(default = "default_trends_hours")]
+    hours: i64,
+
+    /// Number of items to return (default: 10, max: 100)
+    #[serde(default = "default_trends_limit")]
+    limit: usize,
+
+    /// Rank by `count` (default) or by `delta` from the previous window
+    #[serde(default = "default_trends_sort")]
+    sort: String,
+
+    /// Entity type filter (person, organization, location, product, event, other)
+    #[serde(rename = "type")]
+    entity_type: Option<String>,
+}
+
+fn default_trends_hours() -> i64 {
+    24
+}
+
+fn default_trends_limit() -> usize {
+    10
+}
+
+fn default_trends_sort() -> String {
+    "count".to_string()
+}
+
+/// A trending keyword or entity
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiTrendItem {
+    /// Keyword or entity name
+    name: String,
+    /// Entity type (entities only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_type: Option<String>,
+    /// Articles mentioning it in the current window
+    count: u64,
+    /// Articles mentioning it in the previous window
+    previous: u64,
+    /// `count - previous`
+    delta: i64,
+    /// Relative change in percent (absent if new)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_pct: Option<f64>,
+}
+
+/// Trends response
+#[derive(Debug, Serialize, ToSchema)]
+struct ApiTrendsResponse {
+    /// Time-series metric (keyword or entity)
+    metric: String,
+    /// Window length in hours
+    hours: i64,
+    /// Current window start (inclusive)
+    since: chrono::DateTime<chrono::Utc>,
+    /// Current window end (exclusive)
+    until: chrono::DateTime<chrono::Utc>,
+    items: Vec<ApiTrendItem>,
+}
+
+/// GET /api/trends/keywords — Top keywords with change from the previous window
+#[utoipa::path(
+    get,
+    path = "/api/trends/keywords",
+    tag = "trends",
+    params(TrendsQuery),
+    responses(
+        (status = 200, description = "Top keywords", body = ApiTrendsResponse),
+        (status = 400, description = "Invalid parameters", body = ApiErrorResponse),
+        (status = 503, description = "Time series unavailable", body = ApiErrorResponse)
+    )
+)]
+async fn api_trends_keywords_handler(
+    State(state): State<Arc<ApiServerState>>,
+    Query(params): Query<TrendsQuery>,
+) -> Result<Json<ApiTrendsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    trends_response(
+        &state,
+        baram::storage::METRIC_KEYWORD,
+        "keyword",
+        &[],
+        &params,
+    )
+    .await
+}
+
+/// GET /api/trends/entities — Top named entities with change from the previous window
+#[utoipa::path(
+    get,
+    path = "/api/trends/entities",
+    tag = "trends",
+    params(TrendsQuery),
+    responses(
+        (status = 200, description = "Top entities", body = ApiTrendsResponse),
+        (status = 400, description = "Invalid parameters", body = ApiErrorResponse),
+        (status = 503, description = "Time series unavailable", body = ApiErrorResponse)
+    )
+)]
+async fn api_trends_entities_handler(
+    State(state): State<Arc<ApiServerState>>,
+    Query(params): Query<TrendsQuery>,
+) -> Result<Json<ApiTrendsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let entity_type = params.entity_type.as_deref().map(str::to_lowercase);
+    let filter: Vec<(&str, &str)> = entity_type.iter().map(|t| ("type", t.as_str())).collect();
+    trends_response(
+        &state,
+        baram::storage::METRIC_ENTITY,
+        "entity",
+        &filter,
+        &params,
+    )
+    .await
+}
+
+/// Compare the current window of a time-series metric with the one before it
+async fn trends_response(
+    state: &ApiServerState,
+    metric: &str,
+    dimension: &str,
+    filter: &[(&str, &str)],
+    params: &TrendsQuery,
+) -> Result<Json<ApiTrendsResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let bad_request = |error: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse { error, code: 400 }),
+        )
+    };
+    if !(1..=720).contains(&params.hours) {
+        return Err(bad_request(format!(
+            "hours must be between 1 and 720, got {}",
+            params.hours
+        )));
+    }
+    let by_delta = match params.sort.as_str() {
+        "count" => false,
+        "delta" => true,
+        other => {
+            return Err(bad_request(format!(
+                "Unknown sort: '{other}'. Valid: count, delta"
+            )))
+        }
+    };
+
+    // The current (partial) hour is included so the widget reflects the latest crawl
+    let until = baram::storage::hour_bucket(chrono::Utc::now()) + chrono::Duration::hours(1);
+    let since = until - chrono::Duration::hours(params.hours);
+    let previous_since = since - chrono::Duration::hours(params.hours);
+
+    let unavailable = |e: anyhow::Error| {
+        tracing::error!(error = %e, metric, "Failed to query time series");
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiErrorResponse {
+                error: format!("Time series unavailable: {e}"),
+                code: 503,
+            }),
+        )
+    };
+    let current = state
+        .timeseries
+        .query(metric, filter, since, until)
+        .await
+        .map_err(unavailable)?;
+    let previous = state
+        .timeseries
+        .query(metric, filter, previous_since, since)
+        .await
+        .map_err(unavailable)?;
+
+    let items = baram::storage::top_deltas(
+        &current,
+        &previous,
+        dimension,
+        params.limit.min(100),
+        by_delta,
+    )
+    .into_iter()
+    .map(|d| ApiTrendItem {
+        entity_type: d.dimensions.get("type").cloned(),
+        count: d.count as u64,
+        previous: d.previous as u64,
+        delta: d.delta() as i64,
+        change_pct: d.change().map(|c| (c * 1000.0).round() / 10.0),
+        name: d.name,
+    })
+    .collect();
+
+    Ok(Json(ApiTrendsResponse {
+        metric: metric.to_string(),
+        hours: params.hours,
+        since,
+        until,
+        items,
+    }))
+}
+
+/// Query parameters for the entity profile endpoint
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct EntityProfileQuery {
+    /// Number of days to profile, ending today (default: 30, max: 365)
+    #[serde(default = "default_entity_days")]
+    days: i64,
+
+    /// Number of quotes, related entities and articles (default: 10, max: 100)
+    #[serde(default = "default_trends_limit")]
+    limit: usize,
+}
+
+fn default_entity_days() -> i64 {
+    30
+}
+
+/// GET /api/entities/:name — Everything known about one entity
+#[utoipa::path(
+    get,
+    path = "/api/entities/{name}",
+    tag = "trends",
+    params(("name" = String, Path, description = "Entity name, as extracted"), EntityProfileQuery),
+    responses(
+        (status = 200, description = "Entity profile", body = baram::analytics::EntityProfile),
+        (status = 400, description = "Invalid parameters", body = ApiErrorResponse),
+        (status = 404, description = "Entity not found", body = ApiErrorResponse)
+    )
+)]
+async fn api_entity_handler(
+    State(state): State<Arc<ApiServerState>>,
+    AxumPath(name): AxumPath<String>,
+    Query(params): Query<EntityProfileQuery>,
+) -> Result<Json<baram::analytics::EntityProfile>, (StatusCode, Json<ApiErrorResponse>)> {
+    if !(1..=365).contains(&params.days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse {
+                error: format!("days must be between 1 and 365, got {}", params.days),
+                code: 400,
+            }),
+        ));
+    }
+
+    let until = chrono::Utc::now().date_naive();
+    let since = until - chrono::Duration::days(params.days - 1);
+    let profile = super::trends::assemble_entity_profile(
+        &name,
+        &state.extractions,
+        &state.sqlite_path,
+        Some(&state.store),
+        since,
+        until,
+        params.limit.min(100),
+    )
+    .await;
+
+    if profile.is_empty() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse {
+                error: format!("Entity not found: {name}"),
+                code: 404,
+            }),
+        ));
+    }
+    Ok(Json(profile))
+}
+
 /// GET / — API root with endpoint listing
 async fn api_root_handler() -> Json<serde_json::Value> {
     Json(serde_json::json!({
@@ -689,31 +1561,43 @@ async fn api_root_handler() -> Json<serde_json::Value> {
         "version": env!("CARGO_PKG_VERSION"),
         "endpoints": {
             "health": "GET /api/health",
-            "search": "GET /api/search?q=<query>&mode=hybrid|keyword|vector&k=10&category=...&date_from=...&date_to=...",
+            "ready": "GET /api/ready",
+            "search": "GET /api/search?q=<query>&mode=hybrid|keyword|vector&k=10&offset=0&category=...&date_from=...&date_to=...&passages=true&answer=true",
+            "article": "GET /api/articles/:id",
+            "similar": "GET /api/articles/:id/similar?k=10&category=...",
+            "stats": "GET /api/stats?publishers=20",
             "events": "GET /api/events?category=...&limit=50&offset=0",
-            "event_detail": "GET /api/events/:event_id"
+            "event_detail": "GET /api/events/:event_id",
+            "trends_keywords": "GET /api/trends/keywords?hours=24&limit=10&sort=count|delta",
+            "trends_entities": "GET /api/trends/entities?hours=24&limit=10&sort=count|delta&type=person",
+            "entity": "GET /api/entities/:name?days=30&limit=10",
+            "openapi": "GET /api/openapi.json",
+            "docs": "GET /swagger-ui"
         }
     }))
 }
 
 /// Start the REST API server (`baram serve`)
-pub async fn api_server(host: String, port: u16) -> Result<()> {
+pub async fn api_server(params: ApiServerParams) -> Result<()> {
+    let ApiServerParams {
+        host,
+        port,
+        database,
+        opensearch,
+        mut api_keys,
+    } = params;
     tracing::info!(host = %host, port = %port, "Starting Baram API server");
 
-    let opensearch_url = std::env::var("OPENSEARCH_URL")
-        .unwrap_or_else(|_| "http://localhost:9200".to_string());
-    let opensearch_index = std::env::var("OPENSEARCH_INDEX")
-        .unwrap_or_else(|_| "baram-articles".to_string());
+    if let Err(e) = baram::metrics::init_metrics() {
+        tracing::warn!("Failed to initialize metrics (metrics will be disabled): {e}");
+    }
+
+    let opensearch_config = opensearch.with_env_overrides();
+    let opensearch_url = opensearch_config.url.clone();
+    let opensearch_index = opensearch_config.index_name.clone();
     let embedding_server_url = std::env::var("EMBEDDING_SERVER_URL")
         .unwrap_or_else(|_| "http://localhost:8090".to_string());
 
-    let opensearch_config = baram::config::OpenSearchConfig {
-        url: opensearch_url.clone(),
-        index_name: opensearch_index.clone(),
-        username: std::env::var("OPENSEARCH_USER").ok(),
-        password: std::env::var("OPENSEARCH_PASSWORD").ok(),
-    };
-
     let store = baram::embedding::VectorStore::new(&opensearch_config)
         .context("Failed to connect to OpenSearch")?;
 
@@ -741,20 +1625,78 @@ pub async fn api_server(host: String, port: u16) -> Result<()> {
 
     let clusters_dir = std::env::var("BARAM_CLUSTERS_DIR")
         .unwrap_or_else(|_| "./output/clusters".to_string());
+    let ontology_dir = secrets::read_env("BARAM_ONTOLOGY_DIR")?
+        .map_or_else(|| "data/triples".to_string(), |(_, dir)| dir);
+
+    // The pool connects lazily, so the server starts even if PostgreSQL is down
+    let mut db = baram::storage::Database::new(&database)?;
+    db.init_postgres(&database.postgres_url)
+        .await
+        .context("Failed to create PostgreSQL pool")?;
+
+    // Only OpenSearch is required; the other dependencies back single endpoints
+    let mut health = DependencyChecker::new()
+        .with_opensearch(&opensearch_config)
+        .with_postgres(&database.postgres_url)
+        .with_embedding_server(&embedding_server_url)
+        .with_optional(Dependency::Postgres)
+        .with_optional(Dependency::EmbeddingServer);
+    if let Some((_, redis_url)) = secrets::read_env("REDIS_URL")? {
+        health = health
+            .with_redis(&redis_url)
+            .with_optional(Dependency::Redis);
+    }
+
+    if let Some((_, keys)) = secrets::read_env("BARAM_API_KEYS")? {
+        api_keys.extend(keys.split(',').map(str::trim).map(String::from));
+    }
+    api_keys.retain(|k| !k.is_empty());
+    if api_keys.is_empty() {
+        tracing::warn!("No API keys configured — data endpoints are unauthenticated");
+    }
+    let auth_enabled = !api_keys.is_empty();
+
+    let llm = LlmClient::with_config(LlmConfig {
+        max_tokens: 256,
+        ..LlmConfig::from_env()
+    })?;
 
     let state = Arc::new(ApiServerState {
         store,
         embedding_server_url: embedding_server_url.clone(),
         http_client,
         clusters_dir: clusters_dir.clone(),
+        extractions: Arc::new(super::trends::ExtractionCache::new(&ontology_dir)),
+        sqlite_path: database.sqlite_path.clone(),
+        timeseries: db.timeseries()?,
+        health,
+        api_keys,
+        llm,
     });
 
-    let app = Router::new()
-        .route("/", get(api_root_handler))
-        .route("/api/health", get(api_health_handler))
+    // Data endpoints require an API key once keys are configured
+    let data_routes = Router::new()
         .route("/api/search", get(api_search_handler))
+        .route("/api/articles/{id}", get(api_article_handler))
+        .route("/api/articles/{id}/similar", get(api_similar_handler))
+        .route("/api/stats", get(api_stats_handler))
         .route("/api/events", get(api_events_handler))
         .route("/api/events/{event_id}", get(api_event_detail_handler))
+        .route("/api/trends/keywords", get(api_trends_keywords_handler))
+        .route("/api/trends/entities", get(api_trends_entities_handler))
+        .route("/api/entities/{name}", get(api_entity_handler))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_api_key,
+        ));
+
+    let app = Router::new()
+        .route("/", get(api_root_handler))
+        .route("/api/health", get(api_health_handler))
+        .route("/api/ready", get(api_ready_handler))
+        .route("/metrics", get(baram::metrics::metrics_response))
+        .merge(data_routes)
+        .merge(SwaggerUi::new("/swagger-ui").url("/api/openapi.json", SearchApiDoc::openapi()))
         .layer(TraceLayer::new_for_http())
         .layer(
             CorsLayer::new()
@@ -776,30 +1718,68 @@ pub async fn api_server(host: String, port: u16) -> Result<()> {
         "Baram API server listening"
     );
 
-    println!("Baram API Server");
-    println!("================");
-    println!("  Listen: http://{addr}");
-    println!("  OpenSearch: {opensearch_url} (index: {opensearch_index})");
-    println!("  Embedding: {embedding_server_url}");
-    println!();
-    println!("Endpoints:");
-    println!("  GET  /              - API info");
-    println!("  GET  /api/health    - Health check");
-    println!("  GET  /api/search    - Search articles");
-    println!("    ?q=<query>            Search query (required)");
-    println!("    &mode=hybrid          hybrid (default), keyword/bm25, vector/knn");
-    println!("    &k=10                 Number of results (default: 10, max: 100)");
-    println!("    &threshold=0.5        Minimum score threshold");
-    println!("    &category=politics    Filter by category");
-    println!("    &date_from=2026-01-01 Filter by start date");
-    println!("    &date_to=2026-02-21   Filter by end date");
-    println!("  GET  /api/events    - List event clusters");
-    println!("    ?category=politics    Filter by category");
-    println!("    &limit=50             Number of events (max: 200)");
-    println!("    &offset=0             Pagination offset");
-    println!("  GET  /api/events/:id - Get event details");
-    println!("  Clusters dir: {clusters_dir}");
-    println!();
+    print_title(&t!("cli.serve.api_title"), '=');
+    say!("  {}", t!("cli.serve.listen", addr = addr));
+    say!(
+        "  {}",
+        t!(
+            "cli.serve.opensearch",
+            url = opensearch_url,
+            index = opensearch_index
+        )
+    );
+    say!(
+        "  {}",
+        t!("cli.serve.embedding", url = embedding_server_url)
+    );
+    say!(
+        "  {}",
+        if auth_enabled {
+            t!("cli.serve.auth_required")
+        } else {
+            t!("cli.serve.auth_disabled")
+        }
+    );
+    say!();
+    say!("{}", t!("cli.serve.endpoints"));
+    say!("  GET  /              - API info");
+    say!("  GET  /api/health    - Health check");
+    say!("  GET  /api/ready     - Readiness probe (503 if OpenSearch is down)");
+    say!("  GET  /api/search    - Search articles");
+    say!("    ?q=<query>            Search query (required)");
+    say!("    &mode=hybrid          hybrid (default), keyword/bm25, vector/knn");
+    say!("    &k=10                 Number of results (default: 10, max: 100)");
+    say!("    &offset=0             Results to skip (pagination)");
+    say!("    &threshold=0.5        Minimum score threshold");
+    say!("    &category=politics    Filter by category");
+    say!("    &date_from=2026-01-01 Filter by start date");
+    say!("    &date_to=2026-02-21   Filter by end date");
+    say!("    &passages=true        Best-matching passage of each result");
+    say!("    &answer=true          LLM answer from the top passages");
+    say!("  GET  /api/articles/:id - Get an indexed article");
+    say!("  GET  /api/articles/:id/similar - Similar articles");
+    say!("    ?k=10                 Number of results (max: 100)");
+    say!("  GET  /api/stats     - Index statistics");
+    say!("  GET  /api/events    - List event clusters");
+    say!("    ?category=politics    Filter by category");
+    say!("    &limit=50             Number of events (max: 200)");
+    say!("    &offset=0             Pagination offset");
+    say!("  GET  /api/events/:id - Get event details");
+    say!("  GET  /api/trends/keywords - Top keywords with deltas");
+    say!("  GET  /api/trends/entities - Top entities with deltas");
+    say!("    ?hours=24             Window length (max: 720)");
+    say!("    &limit=10             Number of items (max: 100)");
+    say!("    &sort=count           count or delta");
+    say!("    &type=person          Entity type (entities only)");
+    say!("  GET  /api/entities/:name - Entity profile");
+    say!("    ?days=30              Days to profile (max: 365)");
+    say!("    &limit=10             Quotes, related entities and articles (max: 100)");
+    say!("  GET  /metrics       - Prometheus metrics");
+    say!("  GET  /api/openapi.json - OpenAPI specification");
+    say!("  GET  /swagger-ui    - Interactive API docs");
+    say!("  Clusters dir: {clusters_dir}");
+    say!("  Ontology dir: {ontology_dir}");
+    say!();
 
     axum::serve(listener, app).await.context("API server error")?;
 
@@ -820,6 +1800,18 @@ pub struct DistributedCrawlerParams {
     pub output: String,
     pub with_comments: bool,
     pub once: bool,
+    pub metrics_port: Option<u16>,
+    /// Upsert crawled articles into PostgreSQL as well as writing markdown
+    pub store_articles: bool,
+    /// Reloaded configs; a changed `crawler.rate_limit` replaces `rps`
+    pub config_updates: Option<tokio::sync::watch::Receiver<Arc<Config>>>,
+    /// `[category.<name>]` blocks overriding `rps`, the page budget and
+    /// `with_comments` per category
+    pub categories: BTreeMap<String, CategoryConfig>,
+    /// Alert rules; `keyword_spike` rules are evaluated after each slot
+    pub notifications: NotificationsConfig,
+    /// `[crawler]` settings; its robots.txt rules apply to every fetch
+    pub crawler: CrawlerConfig,
 }
 
 /// Start the distributed crawler
@@ -833,6 +1825,12 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
         output,
         with_comments,
         once,
+        metrics_port,
+        store_articles,
+        config_updates,
+        categories,
+        notifications,
+        crawler,
     } = params;
 
     // Initialize Prometheus metrics for crawler
@@ -844,22 +1842,38 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
     } else {
         tracing::info!("Prometheus metrics initialized for crawler");
     }
+    let metrics_server = match metrics_port {
+        Some(port) => Some(super::crawl::start_metrics_server(port).await?),
+        None => None,
+    };
 
-    println!("Starting Distributed Crawler");
-    println!("============================");
-    println!("  Instance ID: {instance}");
-    println!("  Coordinator: {coordinator}");
-    println!("  Database: {}...***", &database[..20.min(database.len())]);
-    println!("  Heartbeat: {heartbeat_interval}s");
-    println!("  Rate limit: {rps} req/s");
-    println!("  Output: {output}");
-    println!("  Comments: {with_comments}");
-    println!("  Run once: {once}");
-    println!();
+    print_title(&t!("cli.serve.distributed_title"), '=');
+    say!("  {}", t!("cli.serve.instance_id", id = instance));
+    say!("  {}", t!("cli.serve.coordinator", url = coordinator));
+    say!(
+        "  {}",
+        t!(
+            "cli.serve.database",
+            url = &database[..20.min(database.len())]
+        )
+    );
+    say!("  {}", t!("cli.serve.heartbeat", secs = heartbeat_interval));
+    say!("  {}", t!("cli.crawl.rate_limit", rate = rps));
+    say!("  {}", t!("cli.cluster.output", path = output));
+    say!("  {}", t!("cli.serve.comments", enabled = with_comments));
+    say!("  {}", t!("cli.serve.run_once", enabled = once));
+    say!(
+        "  {}",
+        t!("cli.serve.store_articles", enabled = store_articles)
+    );
+    if let Some((addr, _)) = &metrics_server {
+        say!("  {}", t!("cli.serve.metrics", addr = addr));
+    }
+    say!();
 
     // Parse instance ID
     let instance_id = CrawlerInstance::from_id(&instance)
-        .map_err(|_| anyhow::anyhow!("Invalid instance ID: {instance}. Valid: main, sub1, sub2"))?;
+        .map_err(|_| anyhow::anyhow!("{}", t!("cli.serve.invalid_instance", id = instance)))?;
 
     // Create instance config
     let config = InstanceConfig::builder()
@@ -870,50 +1884,89 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
         .requests_per_second(rps)
         .output_dir(&output)
         .include_comments(with_comments)
+        .categories(categories)
         .build()
         .context("Failed to build instance config")?;
 
-    println!("{}", config.display());
-    println!();
+    say!("{}", config.display());
+    say!();
 
     // Create distributed runner with deduplication
-    let runner = DistributedRunner::with_dedup(config)
+    let mut runner = DistributedRunner::with_dedup(config)
         .await
-        .context("Failed to create distributed runner")?;
+        .context("Failed to create distributed runner")?
+        .with_fetch_policy(
+            FetchPolicy::from_config(&crawler).context("Failed to set up robots.txt checks")?,
+        );
+    if let Some(updates) = config_updates {
+        runner = runner.with_config_updates(updates);
+    }
+    if store_articles {
+        let store = baram::storage::PgArticleStore::connect(&database)?;
+        store
+            .ensure_schema()
+            .await
+            .context("Failed to create the PostgreSQL articles schema")?;
+        runner = runner.with_article_store(store);
+    }
+    let keyword_alerts = super::crawl::start_keyword_alerts(&notifications)?;
+    if let Some(alerts) = &keyword_alerts {
+        runner = runner.with_keyword_alerts(alerts.clone());
+    }
 
     if once {
         // Run once mode: execute current slot and exit
-        println!("Running in 'once' mode - executing current slot...");
+        say!("{}", t!("cli.serve.once_mode"));
 
         if let Some(slot) = runner
             .check_current_slot()
             .await
             .context("Failed to check current slot")?
         {
-            println!(
-                "Current slot: hour {} with categories {:?}",
-                slot.hour, slot.categories
+            say!(
+                "{}",
+                t!(
+                    "cli.serve.current_slot",
+                    hour = slot.hour,
+                    categories = format!("{:?}", slot.categories)
+                )
             );
 
             let result = runner.run_slot(&slot).await.context("Failed to run slot")?;
 
-            println!("\nSlot Execution Complete");
-            println!("=======================");
-            println!("Hour: {}", result.hour);
-            println!("Articles crawled: {}", result.articles_crawled);
-            println!("Errors: {}", result.errors);
-            println!("Categories: {:?}", result.categories);
-            println!("Success rate: {:.1}%", result.success_rate() * 100.0);
-        } else {
-            println!("This instance is not scheduled for the current hour.");
-            println!(
-                "Use --instance to specify a different instance or wait for the scheduled slot."
+            say!();
+            print_title(&t!("cli.serve.slot_complete"), '=');
+            say!("{}", t!("cli.serve.hour", hour = result.hour));
+            say!(
+                "{}",
+                t!(
+                    "cli.serve.articles_crawled",
+                    count = result.articles_crawled
+                )
             );
+            say!("{}", t!("cli.serve.errors", count = result.errors));
+            say!(
+                "{}",
+                t!(
+                    "cli.serve.categories",
+                    categories = format!("{:?}", result.categories)
+                )
+            );
+            say!(
+                "{}",
+                t!(
+                    "cli.serve.success_rate",
+                    rate = format!("{:.1}", result.success_rate() * 100.0)
+                )
+            );
+        } else {
+            say!("{}", t!("cli.serve.not_scheduled"));
+            say!("{}", t!("cli.serve.not_scheduled_hint"));
         }
     } else {
         // Continuous mode: start background tasks
-        println!("Starting continuous distributed crawling...");
-        println!("Press Ctrl+C to stop.\n");
+        say!("{}", t!("cli.serve.continuous"));
+        say!("{}\n", t!("cli.common.press_ctrl_c"));
 
         // Start the runner
         let handle = runner
@@ -925,11 +1978,18 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
         match runner.get_my_slots().await {
             Ok(slots) => {
                 if slots.is_empty() {
-                    println!("No slots assigned for today.");
+                    say!("{}", t!("cli.serve.no_slots"));
                 } else {
-                    println!("Assigned slots for today:");
+                    say!("{}", t!("cli.serve.assigned_slots"));
                     for slot in &slots {
-                        println!("  Hour {}: {:?}", slot.hour, slot.categories);
+                        say!(
+                            "  {}",
+                            t!(
+                                "cli.serve.slot",
+                                hour = slot.hour,
+                                categories = format!("{:?}", slot.categories)
+                            )
+                        );
                     }
                 }
             }
@@ -937,12 +1997,12 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
                 tracing::warn!("Failed to get assigned slots: {}", e);
             }
         }
-        println!();
+        say!();
 
         // Wait for shutdown signal
         match tokio::signal::ctrl_c().await {
             Ok(()) => {
-                println!("\nShutdown signal received, stopping...");
+                say!("\n{}", t!("cli.serve.shutdown"));
                 handle.shutdown().await;
             }
             Err(e) => {
@@ -951,7 +2011,15 @@ pub async fn distributed_crawler(params: DistributedCrawlerParams) -> Result<()>
         }
     }
 
-    println!("Distributed crawler stopped.");
+    if let Some(alerts) = &keyword_alerts {
+        let outbox = alerts.manager().write().await.take_digests();
+        outbox.send().await;
+    }
+    if let Some((_, handle)) = metrics_server {
+        handle.abort();
+    }
+
+    say!("{}", t!("cli.serve.distributed_stopped"));
     Ok(())
 }
 
@@ -966,9 +2034,23 @@ pub struct CoordinatorParams {
     pub heartbeat_timeout: u64,
     pub heartbeat_interval: u64,
     pub max_instances: usize,
+    /// SQLite file that keeps the instance registry across restarts
+    pub registry_db: Option<String>,
+    /// Days of heartbeat history kept in the registry database
+    pub registry_retention_days: u32,
+    /// Seconds before an uncompleted work lease is requeued
+    pub work_lease: u64,
+    /// Maximum URLs in one work lease
+    pub work_batch_size: usize,
     pub schedule_cache: Option<String>,
     pub enable_cors: bool,
     pub enable_logging: bool,
+    pub notifications: NotificationsConfig,
+    pub database: baram::config::DatabaseConfig,
+    /// Search index for the dashboard's index lag
+    pub opensearch: baram::config::OpenSearchConfig,
+    /// Reloaded configs whose alert rules replace the running ones
+    pub config_updates: Option<tokio::sync::watch::Receiver<Arc<Config>>>,
 }
 
 /// Start the coordinator server
@@ -979,9 +2061,17 @@ pub async fn coordinator_server(params: CoordinatorParams) -> Result<()> {
         heartbeat_timeout,
         heartbeat_interval,
         max_instances,
+        registry_db,
+        registry_retention_days,
+        work_lease,
+        work_batch_size,
         schedule_cache,
         enable_cors,
         enable_logging,
+        notifications,
+        database,
+        opensearch,
+        config_updates,
     } = params;
 
     // Initialize Prometheus metrics
@@ -994,29 +2084,52 @@ pub async fn coordinator_server(params: CoordinatorParams) -> Result<()> {
         tracing::info!("Prometheus metrics initialized");
     }
 
-    println!("Starting Coordinator Server");
-    println!("===========================");
-    println!("  Host: {host}");
-    println!("  Port: {port}");
-    println!("  Heartbeat Timeout: {heartbeat_timeout}s");
-    println!("  Heartbeat Interval: {heartbeat_interval}s");
-    println!("  Max Instances: {max_instances}");
-    println!(
-        "  CORS: {}",
-        if enable_cors { "enabled" } else { "disabled" }
-    );
-    println!(
-        "  Request Logging: {}",
-        if enable_logging {
-            "enabled"
+    let on_off = |enabled: bool| {
+        if enabled {
+            t!("cli.common.enabled")
         } else {
-            "disabled"
+            t!("cli.common.disabled")
         }
+    };
+    print_title(&t!("cli.serve.coordinator_title"), '=');
+    say!("  {}", t!("cli.serve.host", host = host));
+    say!("  {}", t!("cli.serve.port", port = port));
+    say!(
+        "  {}",
+        t!("cli.serve.heartbeat_timeout", secs = heartbeat_timeout)
+    );
+    say!(
+        "  {}",
+        t!("cli.serve.heartbeat_interval", secs = heartbeat_interval)
+    );
+    say!("  {}", t!("cli.serve.max_instances", count = max_instances));
+    say!(
+        "  {}",
+        t!(
+            "cli.serve.work_lease",
+            secs = work_lease,
+            count = work_batch_size
+        )
+    );
+    say!("  {}", t!("cli.serve.cors", state = on_off(enable_cors)));
+    say!(
+        "  {}",
+        t!("cli.serve.request_logging", state = on_off(enable_logging))
     );
     if let Some(ref cache) = schedule_cache {
-        println!("  Schedule Cache: {cache}");
+        say!("  {}", t!("cli.serve.schedule_cache", path = cache));
+    }
+    if let Some(ref registry) = registry_db {
+        say!(
+            "  {}",
+            t!(
+                "cli.serve.registry_db",
+                path = registry,
+                days = registry_retention_days
+            )
+        );
     }
-    println!();
+    say!();
 
     // Build bind address
     let bind_address = format!("{host}:{port}")
@@ -1029,34 +2142,109 @@ pub async fn coordinator_server(params: CoordinatorParams) -> Result<()> {
         .heartbeat_timeout_secs(heartbeat_timeout)
         .heartbeat_interval_secs(heartbeat_interval)
         .max_instances(max_instances)
+        .work_lease_secs(work_lease)
+        .work_batch_size(work_batch_size)
+        .registry_retention_days(registry_retention_days)
         .enable_cors(enable_cors)
         .enable_request_logging(enable_logging);
 
-    let config = if let Some(cache_path) = schedule_cache {
-        config.schedule_cache_path(cache_path).build()?
-    } else {
-        config.build()?
+    let config = match schedule_cache {
+        Some(cache_path) => config.schedule_cache_path(cache_path),
+        None => config,
     };
+    let config = match registry_db {
+        Some(registry_path) => config.registry_path(registry_path),
+        None => config,
+    };
+    let config = config.build()?;
+
+    // Load alert rules and channels from configuration
+    let manager = NotificationManager::from_config(&notifications)
+        .map_err(|e| anyhow::anyhow!("Invalid notifications config: {e}"))?;
+    let enabled_rules = manager.rules().iter().filter(|r| r.enabled).count();
+    tracing::info!(
+        channels = manager.channel_names().len(),
+        rules = manager.rules().len(),
+        enabled_rules = enabled_rules,
+        "Notification rules loaded"
+    );
+
+    // Volume anomaly rules and the dashboard read hourly aggregates from
+    // PostgreSQL; the pool connects lazily
+    let timeseries = async {
+        let mut db = baram::storage::Database::new(&database)?;
+        db.init_postgres(&database.postgres_url)
+            .await
+            .context("Failed to create PostgreSQL pool")?;
+        db.timeseries()
+    }
+    .await;
+    let timeseries = match timeseries {
+        Ok(store) => Some(store),
+        Err(e) if manager.has_volume_rules() => return Err(e),
+        Err(e) => {
+            tracing::warn!(error = %e, "Time series unavailable — dashboard volume and keywords are disabled");
+            None
+        }
+    };
+    let index = baram::embedding::VectorStore::new(&opensearch.with_env_overrides())
+        .inspect_err(|e| tracing::warn!(error = %e, "Search index unavailable — dashboard index lag is disabled"))
+        .ok();
 
     // Create and start server
-    let server = CoordinatorServer::new(config).context("Failed to create coordinator server")?;
-
-    println!("{}", server.info().display());
-    println!();
-    println!("API Endpoints:");
-    println!("  GET  /api/health              - Health check");
-    println!("  GET  /metrics                 - Prometheus metrics endpoint");
-    println!("  GET  /api/schedule/today      - Get today's schedule");
-    println!("  GET  /api/schedule/tomorrow   - Get tomorrow's schedule");
-    println!("  GET  /api/schedule/:date      - Get schedule by date (YYYY-MM-DD)");
-    println!("  GET  /api/instances           - List all instances");
-    println!("  GET  /api/instances/:id       - Get instance by ID");
-    println!("  POST /api/instances/register  - Register new instance");
-    println!("  POST /api/instances/heartbeat - Send heartbeat");
-    println!("  GET  /api/stats               - Get coordinator stats");
-    println!();
-    println!("Coordinator server listening on http://{bind_address}");
-    println!("Press Ctrl+C to stop.\n");
+    let mut server = CoordinatorServer::new(config)
+        .context("Failed to create coordinator server")?
+        .with_notifications(manager);
+    if let Some(store) = timeseries {
+        server = server.with_timeseries(store);
+    }
+    if let Some(store) = index {
+        server = server.with_index(store);
+    }
+
+    if let Some(mut updates) = config_updates {
+        let notifications = server.state().notifications;
+        tokio::spawn(async move {
+            while updates.changed().await.is_ok() {
+                let rules = updates.borrow_and_update().notifications.rules.clone();
+                let count = rules.len();
+                match notifications.write().await.set_rules(rules) {
+                    Ok(()) => tracing::info!(rules = count, "Alert rules reloaded"),
+                    Err(e) => tracing::warn!(error = %e, "Keeping the running alert rules"),
+                }
+            }
+        });
+    }
+
+    say!("{}", server.info().display());
+    say!();
+    say!("{}", t!("cli.serve.api_endpoints"));
+    say!("  GET  /api/health              - Health check");
+    say!("  GET  /metrics                 - Prometheus metrics endpoint");
+    say!("  GET  /api/schedule/today      - Get today's schedule");
+    say!("  GET  /api/schedule/tomorrow   - Get tomorrow's schedule");
+    say!("  GET  /api/schedule/:date      - Get schedule by date (YYYY-MM-DD)");
+    say!("  GET  /api/instances           - List all instances");
+    say!("  GET  /api/instances/:id       - Get instance by ID");
+    say!("  POST /api/instances/register  - Register new instance");
+    say!("  POST /api/instances/heartbeat - Send heartbeat");
+    say!("  GET  /api/stats               - Get coordinator stats");
+    say!("  GET  /api/alerts              - List active alerts (?status=all)");
+    say!("  POST /api/alerts/:id/acknowledge - Acknowledge an alert");
+    say!("  POST /api/alerts/:id/resolve  - Resolve an alert");
+    say!("  GET  /api/dashboard/volume    - Hourly crawl volume (?hours=24)");
+    say!("  GET  /api/dashboard/instances - Instance map");
+    say!("  GET  /api/dashboard/keywords  - Top keywords (?hours=24&limit=10)");
+    say!("  GET  /api/dashboard/alerts    - Recent alerts (?limit=10)");
+    say!("  GET  /api/dashboard/index-lag - Index lag behind the crawler");
+    say!("  GET  /api/openapi.json        - OpenAPI specification");
+    say!("  GET  /swagger-ui              - Interactive API docs");
+    say!();
+    say!(
+        "{}",
+        t!("cli.serve.coordinator_listening", addr = bind_address)
+    );
+    say!("{}\n", t!("cli.common.press_ctrl_c"));
 
     // Start with graceful shutdown
     server
@@ -1072,7 +2260,7 @@ pub async fn coordinator_server(params: CoordinatorParams) -> Result<()> {
         })
         .await?;
 
-    println!("Coordinator server stopped.");
+    say!("{}", t!("cli.serve.coordinator_stopped"));
     Ok(())
 }
 
@@ -1117,6 +2305,10 @@ mod tests {
         assert_eq!(query.q, "test");
         assert_eq!(query.mode, "hybrid");
         assert_eq!(query.k, 10);
+        assert_eq!(query.offset, 0);
+        assert!(!query.collapse);
+        assert!(!query.passages);
+        assert!(!query.answer);
     }
 
     #[test]
@@ -1124,13 +2316,60 @@ mod tests {
         let response = ApiSearchResponse {
             query: "test".to_string(),
             mode: "hybrid".to_string(),
+            offset: 20,
             total: 0,
             results: vec![],
+            answer: None,
         };
         let json = serde_json::to_value(&response).expect("should serialize");
         assert_eq!(json["query"], "test");
         assert_eq!(json["mode"], "hybrid");
+        assert_eq!(json["offset"], 20);
         assert_eq!(json["total"], 0);
+        assert!(json.get("answer").is_none());
+    }
+
+    #[test]
+    fn test_answer_passages() {
+        let result = |id: &str, passage: Option<&str>| baram::embedding::SearchResult {
+            id: id.to_string(),
+            score: 1.0,
+            title: format!("제목 {id}"),
+            content: String::new(),
+            category: String::new(),
+            publisher: None,
+            url: String::new(),
+            published_at: None,
+            highlights: None,
+            passage: passage.map(|text| baram::embedding::Passage {
+                text: text.to_string(),
+                start: 0,
+                end: text.chars().count(),
+                chunk_index: None,
+            }),
+            story_id: None,
+        };
+        let mut results = vec![result("a", Some("첫 발췌")), result("b", None)];
+        results.extend((0..10).map(|i| result(&i.to_string(), Some("발췌"))));
+
+        let passages = answer_passages(&results);
+        assert_eq!(passages.len(), ANSWER_PASSAGES);
+        assert_eq!(passages[0].id, "a");
+        assert_eq!(passages[0].content, "첫 발췌");
+        assert_eq!(passages[1].id, "0");
+    }
+
+    #[test]
+    fn test_similar_and_stats_query_defaults() {
+        let similar: SimilarQuery =
+            serde_json::from_value(serde_json::json!({})).expect("should deserialize");
+        assert_eq!(similar.k, 10);
+        assert!(similar.category.is_none());
+        assert!(!similar.collapse);
+
+        let stats: StatsQuery =
+            serde_json::from_value(serde_json::json!({})).expect("should deserialize");
+        assert_eq!(stats.publishers, 20);
     }
 
     #[test]
@@ -1158,6 +2397,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_trends_query_defaults() {
+        let query: TrendsQuery =
+            serde_json::from_value(serde_json::json!({})).expect("should deserialize");
+        assert_eq!(query.hours, 24);
+        assert_eq!(query.limit, 10);
+        assert_eq!(query.sort, "count");
+        assert!(query.entity_type.is_none());
+
+        let query: TrendsQuery =
+            serde_json::from_value(serde_json::json!({ "hours": 6, "type": "person" }))
+                .expect("should deserialize");
+        assert_eq!(query.hours, 6);
+        assert_eq!(query.entity_type.as_deref(), Some("person"));
+    }
+
+    #[test]
+    fn test_api_trend_item_serialization() {
+        let item = ApiTrendItem {
+            name: "반도체".to_string(),
+            entity_type: None,
+            count: 12,
+            previous: 8,
+            delta: 4,
+            change_pct: Some(50.0),
+        };
+        let json = serde_json::to_value(&item).expect("should serialize");
+        assert_eq!(json["name"], "반도체");
+        assert_eq!(json["delta"], 4);
+        assert_eq!(json["change_pct"], 50.0);
+        assert!(json.get("entity_type").is_none());
+    }
+
+    #[test]
+    fn test_search_openapi_spec_paths() {
+        let spec = SearchApiDoc::openapi();
+        for path in [
+            "/api/health",
+            "/api/search",
+            "/api/events",
+            "/api/events/{event_id}",
+            "/api/trends/keywords",
+            "/api/trends/entities",
+        ] {
+            assert!(spec.paths.paths.contains_key(path), "missing path: {path}");
+        }
+    }
+
     #[test]
     fn test_default_events_limit() {
         assert_eq!(default_events_limit(), 50);
@@ -0,0 +1,146 @@
+//! Graceful shutdown on SIGINT/SIGTERM
+//!
+//! The first signal asks the running command to stop: it takes no new
+//! work (URLs, files, LLM batches), gives what is in flight
+//! [`SHUTDOWN_GRACE`] to finish, records its progress and prints how to
+//! resume. A second signal exits at once.
+
+use rust_i18n::t;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use baram::storage::RunStatus;
+
+use super::output::say;
+
+/// How long in-flight work may run on after a shutdown is requested
+pub const SHUTDOWN_GRACE: Duration = Duration::from_secs(30);
+
+/// Exit code of a run stopped by a second signal (128 + SIGINT)
+const FORCED_EXIT_CODE: i32 = 130;
+
+/// Whether the running command was asked to stop
+///
+/// Clones share the same state.
+#[derive(Clone)]
+pub struct Shutdown {
+    requested: Arc<watch::Sender<bool>>,
+}
+
+impl Shutdown {
+    /// A shutdown that is only requested with [`Shutdown::request`]
+    pub fn new() -> Self {
+        Self {
+            requested: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Request a shutdown on the first SIGINT or SIGTERM, and exit on the
+    /// second
+    pub fn listen() -> Self {
+        let shutdown = Self::new();
+        let requested = shutdown.clone();
+        tokio::spawn(async move {
+            if !signal().await {
+                return;
+            }
+            say!("\n{}", t!("cli.common.shutdown"));
+            requested.request();
+            if signal().await {
+                std::process::exit(FORCED_EXIT_CODE);
+            }
+        });
+        shutdown
+    }
+
+    /// Ask the command to stop
+    pub fn request(&self) {
+        self.requested.send_replace(true);
+    }
+
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        *self.requested.borrow()
+    }
+
+    /// Wait until a shutdown is requested
+    pub async fn requested(&self) {
+        let mut requested = self.requested.subscribe();
+        // The sender lives as long as `self`, so this cannot fail
+        let _ = requested.wait_for(|requested| *requested).await;
+    }
+
+    /// Run `future` to completion, or for at most [`SHUTDOWN_GRACE`] once a
+    /// shutdown is requested; `None` if it was cut short
+    pub async fn with_grace<F: Future>(&self, future: F) -> Option<F::Output> {
+        tokio::pin!(future);
+        tokio::select! {
+            output = &mut future => return Some(output),
+            () = self.requested() => {}
+        }
+        tokio::time::timeout(SHUTDOWN_GRACE, future).await.ok()
+    }
+
+    /// How a run that returned `ok` ended
+    #[must_use]
+    pub fn run_status(&self, ok: bool) -> RunStatus {
+        match (ok, self.is_requested()) {
+            (false, _) => RunStatus::Failed,
+            (true, true) => RunStatus::Interrupted,
+            (true, false) => RunStatus::Finished,
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wait for SIGINT or (on Unix) SIGTERM; `false` if signals cannot be
+/// received
+async fn signal() -> bool {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut terminate = match signal(SignalKind::terminate()) {
+            Ok(terminate) => terminate,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to listen for SIGTERM");
+                return tokio::signal::ctrl_c().await.is_ok();
+            }
+        };
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result.is_ok(),
+            _ = terminate.recv() => true,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await.is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_with_grace() {
+        let shutdown = Shutdown::new();
+        assert!(!shutdown.is_requested());
+        assert_eq!(shutdown.with_grace(async { 1 }).await, Some(1));
+        assert_eq!(shutdown.run_status(true), RunStatus::Finished);
+
+        shutdown.clone().request();
+        assert!(shutdown.is_requested());
+        shutdown.requested().await;
+        assert_eq!(shutdown.with_grace(async { 2 }).await, Some(2));
+        assert_eq!(shutdown.run_status(true), RunStatus::Interrupted);
+        assert_eq!(shutdown.run_status(false), RunStatus::Failed);
+    }
+}
@@ -0,0 +1,1645 @@
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use rust_i18n::t;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use baram::analytics::{
+    hourly_counts, BurstConfig, BurstDetector, BurstEvent, BurstSubject, CoverageAnalyzer,
+    CoverageReport, EntityNetwork, EntityProfile, EntityType, SentimentAnalyzer,
+    SentimentDimension, TextTokenizer, TrendAnalyzer, TrendDirection,
+};
+use baram::clustering::{ClusterOutput, EventCluster};
+use baram::config::Config;
+use baram::crawler::url::UrlExtractor;
+use baram::embedding::{SearchConfig, VectorStore};
+use baram::notifications::NotificationManager;
+use baram::ontology::{EntitySource, RelationExtractor, StorageConfig, TripleStorage, TripleStore};
+use baram::parser::Article;
+use baram::storage::{
+    Database, SentimentPoint, SentimentStore, SentimentSummary, TimeSeriesPoint, METRIC_ARTICLES,
+    METRIC_KEYWORD, METRIC_TITLE_KEYWORD,
+};
+
+use super::output::say;
+use super::{pad_end, print_title};
+
+/// Parameters for `baram trends keywords`
+pub struct KeywordTrendsParams {
+    /// Number of days to analyze, ending today
+    pub days: usize,
+    /// Number of risers and fallers to show
+    pub limit: usize,
+    /// Minimum number of articles mentioning a keyword over the period
+    pub min_count: u64,
+    /// Output format (text, json)
+    pub format: String,
+    /// Only tokenize titles, not article bodies
+    pub titles_only: bool,
+    /// Re-tokenize stored articles even when the time series covers the period
+    pub rescan: bool,
+}
+
+/// A keyword and its daily series
+#[derive(Debug, Serialize)]
+struct KeywordTrendEntry {
+    keyword: String,
+    velocity: f64,
+    direction: TrendDirection,
+    total: u64,
+    /// Articles mentioning the keyword per day, oldest first
+    counts: Vec<u64>,
+    sparkline: String,
+}
+
+/// Top rising and falling keywords over a period
+#[derive(Debug, Serialize)]
+struct KeywordTrendReport {
+    since: DateTime<Utc>,
+    days: usize,
+    articles: usize,
+    keywords_tracked: usize,
+    risers: Vec<KeywordTrendEntry>,
+    fallers: Vec<KeywordTrendEntry>,
+}
+
+/// Show the fastest rising and falling keywords from stored articles
+pub async fn keyword_trends(config: Config, params: KeywordTrendsParams) -> Result<()> {
+    if params.days < 2 {
+        bail!("{}", t!("cli.trends.min_days_trend"));
+    }
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let today = Utc::now()
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc();
+    let since = today - Duration::days(params.days as i64 - 1);
+
+    let mut db = Database::new(&config.database)?;
+    db.init_postgres(&config.database.postgres_url)
+        .await
+        .context("Failed to connect to PostgreSQL")?;
+
+    let metric = if params.titles_only {
+        METRIC_TITLE_KEYWORD
+    } else {
+        METRIC_KEYWORD
+    };
+    let series = db.timeseries()?;
+    let covered = !params.rescan
+        && series
+            .first_bucket(metric)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Time series unavailable, rescanning articles");
+                None
+            })
+            .is_some_and(|start| start <= since);
+
+    let report = if covered {
+        let end = since + Duration::days(params.days as i64);
+        let keywords = series
+            .query(metric, &[], since, end)
+            .await
+            .context("Failed to load keyword time series")?;
+        let articles = series
+            .query(METRIC_ARTICLES, &[], since, end)
+            .await
+            .context("Failed to load article time series")?;
+
+        tracing::info!(
+            buckets = keywords.len(),
+            since = %since,
+            "Loaded keyword time series"
+        );
+
+        let total = articles.iter().map(|p| p.value).sum::<f64>() as usize;
+        rank_keywords(
+            daily_from_timeseries(&keywords, since, params.days),
+            total,
+            since,
+            &params,
+        )
+    } else {
+        let articles = db
+            .get_articles_since(since, 0)
+            .await
+            .context("Failed to load articles")?;
+
+        tracing::info!(
+            articles = articles.len(),
+            since = %since,
+            "Loaded articles for keyword trends"
+        );
+
+        build_report(&articles, since, &params)
+    };
+
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report);
+    }
+
+    Ok(())
+}
+
+/// Count keyword mentions per day and rank them by trend velocity
+///
+/// A keyword counts once per article, so a single long article cannot
+/// dominate a day.
+fn build_report(
+    articles: &[Article],
+    since: DateTime<Utc>,
+    params: &KeywordTrendsParams,
+) -> KeywordTrendReport {
+    let tokenizer = TextTokenizer::new();
+    let mut daily: Vec<HashMap<String, u64>> = vec![HashMap::new(); params.days];
+
+    for article in articles {
+        let Some(published_at) = article.published_at else {
+            continue;
+        };
+        let day = (published_at - since).num_days();
+        if day < 0 || day as usize >= params.days {
+            continue;
+        }
+
+        let keywords: HashSet<String> = if params.titles_only {
+            tokenizer.tokenize(&article.title).into_iter().collect()
+        } else {
+            tokenizer
+                .tokenize(&format!("{} {}", article.title, article.body))
+                .into_iter()
+                .collect()
+        };
+        for keyword in keywords {
+            *daily[day as usize].entry(keyword).or_insert(0) += 1;
+        }
+    }
+
+    rank_keywords(daily, articles.len(), since, params)
+}
+
+/// Sum hourly keyword buckets into per-day counts
+fn daily_from_timeseries(
+    points: &[TimeSeriesPoint],
+    since: DateTime<Utc>,
+    days: usize,
+) -> Vec<HashMap<String, u64>> {
+    let mut daily: Vec<HashMap<String, u64>> = vec![HashMap::new(); days];
+    for point in points {
+        let day = (point.bucket - since).num_days();
+        let Some(keyword) = point.dimension("keyword") else {
+            continue;
+        };
+        if day < 0 || day as usize >= days {
+            continue;
+        }
+        *daily[day as usize].entry(keyword.to_string()).or_insert(0) += point.value as u64;
+    }
+    daily
+}
+
+/// Rank keywords by trend velocity over per-day counts
+fn rank_keywords(
+    daily: Vec<HashMap<String, u64>>,
+    articles: usize,
+    since: DateTime<Utc>,
+    params: &KeywordTrendsParams,
+) -> KeywordTrendReport {
+    let mut totals: HashMap<&str, u64> = HashMap::new();
+    for counts in &daily {
+        for (keyword, count) in counts {
+            *totals.entry(keyword.as_str()).or_insert(0) += count;
+        }
+    }
+
+    let mut analyzer = TrendAnalyzer::new(Some(params.days));
+    let mut keywords_tracked = 0;
+    for (keyword, total) in &totals {
+        if *total < params.min_count {
+            continue;
+        }
+        keywords_tracked += 1;
+        // Fill missing days with zero so the series is continuous
+        for (i, counts) in daily.iter().enumerate() {
+            let count = counts.get(*keyword).copied().unwrap_or(0);
+            analyzer.add_observation(keyword, since + Duration::days(i as i64), count);
+        }
+    }
+
+    let risers = analyzer
+        .top_trending(params.limit, Some(params.days))
+        .into_iter()
+        .filter(|(_, velocity)| *velocity > 0.0)
+        .map(|(keyword, velocity)| trend_entry(&analyzer, keyword, velocity))
+        .collect();
+    let fallers = analyzer
+        .top_falling(params.limit, Some(params.days))
+        .into_iter()
+        .filter(|(_, velocity)| *velocity < 0.0)
+        .map(|(keyword, velocity)| trend_entry(&analyzer, keyword, velocity))
+        .collect();
+
+    KeywordTrendReport {
+        since,
+        days: params.days,
+        articles,
+        keywords_tracked,
+        risers,
+        fallers,
+    }
+}
+
+fn trend_entry(analyzer: &TrendAnalyzer, keyword: String, velocity: f64) -> KeywordTrendEntry {
+    let trend = analyzer
+        .get_trend(&keyword)
+        .expect("ranked keyword is tracked");
+
+    KeywordTrendEntry {
+        velocity,
+        direction: TrendDirection::from_velocity(velocity),
+        total: trend.total_count(),
+        counts: trend.data_points().iter().map(|p| p.count).collect(),
+        sparkline: trend.sparkline(),
+        keyword,
+    }
+}
+
+fn print_report(report: &KeywordTrendReport) {
+    let until = report.since + Duration::days(report.days as i64 - 1);
+
+    print_title(&t!("cli.trends.keywords_title"), '=');
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.period_days",
+            from = report.since.format("%Y-%m-%d"),
+            to = until.format("%Y-%m-%d"),
+            days = report.days
+        )
+    );
+    say!("  {}", t!("cli.trends.articles", count = report.articles));
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.keywords_tracked",
+            count = report.keywords_tracked
+        )
+    );
+
+    for (title, entries) in [
+        (t!("cli.trends.risers"), &report.risers),
+        (t!("cli.trends.fallers"), &report.fallers),
+    ] {
+        println!();
+        println!("{title}:");
+        if entries.is_empty() {
+            println!("  {}", t!("cli.common.none"));
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            println!(
+                "  {:>2}. {} {}  {:+.2}  ({})",
+                i + 1,
+                pad_end(&entry.keyword, 16),
+                entry.sparkline,
+                entry.velocity,
+                t!("cli.trends.article_count", count = entry.total)
+            );
+        }
+    }
+}
+
+/// Parameters for `baram trends bursts`
+pub struct BurstParams {
+    /// Hours of history to load (baseline plus recent window)
+    pub hours: usize,
+    /// Only report bursts within the most recent N hours
+    pub recent: usize,
+    /// Detection settings
+    pub burst: BurstConfig,
+    /// Also detect bursts for named entities
+    pub entities: bool,
+    /// Send matching bursts to the configured notification rules
+    pub notify: bool,
+    /// Number of bursts to show
+    pub limit: usize,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Bursts detected in the recent window
+#[derive(Debug, Serialize)]
+struct BurstReport {
+    since: DateTime<Utc>,
+    hours: usize,
+    articles: usize,
+    method: &'static str,
+    sensitivity: f64,
+    bursts: Vec<BurstEvent>,
+}
+
+/// Detect keyword (and entity) bursts in recent hourly article counts
+pub async fn bursts(config: Config, params: BurstParams) -> Result<()> {
+    params.burst.validate().map_err(anyhow::Error::msg)?;
+    if params.recent == 0 || params.recent >= params.hours {
+        bail!("{}", t!("cli.trends.invalid_recent"));
+    }
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let end = Utc::now();
+    let since = end - Duration::hours(params.hours as i64 - 1);
+
+    let mut db = Database::new(&config.database)?;
+    db.init_postgres(&config.database.postgres_url)
+        .await
+        .context("Failed to connect to PostgreSQL")?;
+    let articles = db
+        .get_articles_since(since, 0)
+        .await
+        .context("Failed to load articles")?;
+
+    tracing::info!(
+        articles = articles.len(),
+        since = %since,
+        "Loaded articles for burst detection"
+    );
+
+    let report = build_burst_report(&articles, since, end, &params);
+
+    if params.notify {
+        let mut manager =
+            NotificationManager::from_config(&config.notifications).map_err(anyhow::Error::msg)?;
+        if manager.rules().is_empty() {
+            tracing::warn!("No notification rules configured, bursts will not be sent");
+        }
+        let fired = manager
+            .process_bursts(&report.bursts)
+            .await
+            .map_err(anyhow::Error::msg)?;
+        manager.flush_digests().await;
+        tracing::info!(alerts = fired.len(), "Processed bursts");
+    }
+
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_burst_report(&report, params.recent);
+    }
+
+    Ok(())
+}
+
+/// Count mentions per hour and keep bursts within the recent window
+///
+/// Like keyword trends, a keyword or entity counts once per article.
+fn build_burst_report(
+    articles: &[Article],
+    since: DateTime<Utc>,
+    end: DateTime<Utc>,
+    params: &BurstParams,
+) -> BurstReport {
+    let tokenizer = TextTokenizer::new();
+    let extractor = RelationExtractor::new();
+    let mut mentions: HashMap<BurstSubject, Vec<DateTime<Utc>>> = HashMap::new();
+
+    for article in articles {
+        let Some(published_at) = article.published_at else {
+            continue;
+        };
+        let text = format!("{} {}", article.title, article.body);
+
+        let mut subjects: HashSet<BurstSubject> = tokenizer
+            .tokenize(&text)
+            .into_iter()
+            .map(BurstSubject::Keyword)
+            .collect();
+        if params.entities {
+            subjects.extend(
+                extractor
+                    .extract_entities(&text, EntitySource::Both)
+                    .into_iter()
+                    .filter(|e| EntityType::from_ontology(e.entity_type).is_some())
+                    .map(|e| BurstSubject::Entity(e.canonical_name.unwrap_or(e.text))),
+            );
+        }
+
+        for subject in subjects {
+            mentions.entry(subject).or_default().push(published_at);
+        }
+    }
+
+    let detector = BurstDetector::new(params.burst.clone());
+    let recent_start = end - Duration::hours(params.recent as i64);
+    let mut bursts: Vec<BurstEvent> = mentions
+        .into_iter()
+        // A burst needs at least min_count mentions in one hour
+        .filter(|(_, timestamps)| timestamps.len() as u64 >= params.burst.min_count)
+        .flat_map(|(subject, timestamps)| {
+            let series = hourly_counts(timestamps, since, end);
+            detector
+                .detect(&series)
+                .into_iter()
+                .filter(|spike| spike.timestamp > recent_start)
+                .map(move |spike| BurstEvent::new(subject.clone(), spike))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    bursts.sort_by(|a, b| b.spike.z_score.total_cmp(&a.spike.z_score));
+    bursts.truncate(params.limit);
+
+    BurstReport {
+        since,
+        hours: params.hours,
+        articles: articles.len(),
+        method: params.burst.method.as_str(),
+        sensitivity: params.burst.sensitivity,
+        bursts,
+    }
+}
+
+fn print_burst_report(report: &BurstReport, recent: usize) {
+    print_title(&t!("cli.trends.bursts_title"), '=');
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.period_hours",
+            from = report.since.format("%Y-%m-%d %H:00"),
+            hours = report.hours
+        )
+    );
+    say!("  {}", t!("cli.trends.articles", count = report.articles));
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.method",
+            method = report.method,
+            sensitivity = format!("{:.1}", report.sensitivity)
+        )
+    );
+    say!();
+    println!("{}", t!("cli.trends.bursts_recent", hours = recent));
+    if report.bursts.is_empty() {
+        println!("  {}", t!("cli.common.none"));
+    }
+    for event in &report.bursts {
+        println!(
+            "  {}  {:<7} {} {:>5}  ({})",
+            event.spike.timestamp.format("%m-%d %H:00"),
+            event.subject.kind(),
+            pad_end(event.subject.name(), 16),
+            event.spike.count,
+            t!(
+                "cli.trends.burst_stats",
+                expected = format!("{:.1}", event.spike.expected),
+                z = format!("{:.1}", event.spike.z_score)
+            )
+        );
+    }
+}
+
+/// Parameters for `baram trends sentiment`
+pub struct SentimentTrendsParams {
+    /// Dimension to aggregate by
+    pub by: SentimentDimension,
+    /// Show the daily series of one entity, publisher or category
+    pub key: Option<String>,
+    /// Number of days to show, ending today
+    pub days: usize,
+    /// Number of keys to list when no key is given
+    pub limit: usize,
+    /// Minimum articles per key when listing
+    pub min_articles: u64,
+    /// Score new articles from PostgreSQL before querying
+    pub update: bool,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Daily sentiment of one key
+#[derive(Debug, Serialize)]
+struct SentimentSeriesReport {
+    dimension: SentimentDimension,
+    key: String,
+    since: NaiveDate,
+    days: usize,
+    /// One entry per day, oldest first; days without articles have no average
+    points: Vec<SentimentDay>,
+}
+
+#[derive(Debug, Serialize)]
+struct SentimentDay {
+    day: NaiveDate,
+    articles: u64,
+    average: Option<f64>,
+    positive: u64,
+    negative: u64,
+}
+
+/// Sentiment of the most covered keys
+#[derive(Debug, Serialize)]
+struct SentimentListReport {
+    dimension: SentimentDimension,
+    since: NaiveDate,
+    days: usize,
+    keys: Vec<SentimentListEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct SentimentListEntry {
+    #[serde(flatten)]
+    summary: SentimentSummary,
+    sparkline: String,
+}
+
+/// Show daily sentiment by entity, publisher or category
+pub async fn sentiment_trends(config: Config, params: SentimentTrendsParams) -> Result<()> {
+    if params.days == 0 {
+        bail!("{}", t!("cli.trends.min_days"));
+    }
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let today = Utc::now().date_naive();
+    let since = today - Duration::days(params.days as i64 - 1);
+    let mut store = SentimentStore::open(&config.database.sqlite_path)?;
+
+    if params.update {
+        let mut db = Database::new(&config.database)?;
+        db.init_postgres(&config.database.postgres_url)
+            .await
+            .context("Failed to connect to PostgreSQL")?;
+        let since_utc = since
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is valid")
+            .and_utc();
+        let articles = db
+            .get_articles_since(since_utc, 0)
+            .await
+            .context("Failed to load articles")?;
+
+        let recorded = record_sentiment(&mut store, &articles)?;
+        tracing::info!(
+            articles = articles.len(),
+            recorded = recorded,
+            "Updated sentiment aggregates"
+        );
+    }
+
+    match params.key {
+        Some(ref key) => {
+            let points = store.series(params.by, key, since, today)?;
+            let mut by_day: HashMap<NaiveDate, SentimentPoint> =
+                points.into_iter().map(|p| (p.day, p)).collect();
+            let points = (0..params.days)
+                .map(|i| {
+                    let day = since + Duration::days(i as i64);
+                    match by_day.remove(&day) {
+                        Some(p) => SentimentDay {
+                            day,
+                            articles: p.articles,
+                            average: Some(p.average),
+                            positive: p.positive,
+                            negative: p.negative,
+                        },
+                        None => SentimentDay {
+                            day,
+                            articles: 0,
+                            average: None,
+                            positive: 0,
+                            negative: 0,
+                        },
+                    }
+                })
+                .collect();
+
+            let report = SentimentSeriesReport {
+                dimension: params.by,
+                key: key.clone(),
+                since,
+                days: params.days,
+                points,
+            };
+            if params.format == "json" {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_sentiment_series(&report);
+            }
+        }
+        None => {
+            let mut keys = Vec::new();
+            for summary in
+                store.top_keys(params.by, since, today, params.min_articles, params.limit)?
+            {
+                let points = store.series(params.by, &summary.key, since, today)?;
+                let averages: HashMap<NaiveDate, f64> =
+                    points.iter().map(|p| (p.day, p.average)).collect();
+                let daily: Vec<Option<f64>> = (0..params.days)
+                    .map(|i| averages.get(&(since + Duration::days(i as i64))).copied())
+                    .collect();
+                keys.push(SentimentListEntry {
+                    sparkline: sentiment_sparkline(&daily),
+                    summary,
+                });
+            }
+
+            let report = SentimentListReport {
+                dimension: params.by,
+                since,
+                days: params.days,
+                keys,
+            };
+            if params.format == "json" {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_sentiment_list(&report);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Score articles and add them to the daily aggregates
+///
+/// Each article is recorded under its category, its publisher (Naver press
+/// ID) and every person, organization, location or event it mentions.
+/// Returns the number of newly recorded articles.
+fn record_sentiment(store: &mut SentimentStore, articles: &[Article]) -> Result<usize> {
+    let analyzer = SentimentAnalyzer::new();
+    let extractor = RelationExtractor::new();
+    let url_extractor = UrlExtractor::new();
+    let mut recorded = 0;
+
+    for article in articles {
+        let Some(published_at) = article.published_at else {
+            continue;
+        };
+        let article_id = article.id.to_string();
+        if store.is_recorded(&article_id)? {
+            continue;
+        }
+
+        let score = analyzer.score_article(&article.title, &article.body);
+
+        let mut keys = Vec::new();
+        if let Some(ref category) = article.category {
+            keys.push((SentimentDimension::Category, category.clone()));
+        }
+        if let Ok((oid, _)) = url_extractor.extract_ids(&article.url) {
+            keys.push((SentimentDimension::Publisher, oid));
+        }
+        let entities: HashSet<String> = extractor
+            .extract_entities(
+                &format!("{} {}", article.title, article.body),
+                EntitySource::Both,
+            )
+            .into_iter()
+            .filter(|e| EntityType::from_ontology(e.entity_type).is_some())
+            .map(|e| e.canonical_name.unwrap_or(e.text))
+            .collect();
+        keys.extend(
+            entities
+                .into_iter()
+                .map(|name| (SentimentDimension::Entity, name)),
+        );
+
+        if store.record_article(&article_id, published_at.date_naive(), &score, &keys)? {
+            recorded += 1;
+        }
+    }
+
+    Ok(recorded)
+}
+
+/// Render daily averages in `[-1.0, 1.0]` as a sparkline, `·` for days without articles
+fn sentiment_sparkline(daily: &[Option<f64>]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    daily
+        .iter()
+        .map(|average| match average {
+            Some(v) => BLOCKS[(((v.clamp(-1.0, 1.0) + 1.0) / 2.0) * 7.0).round() as usize],
+            None => '·',
+        })
+        .collect()
+}
+
+fn print_sentiment_series(report: &SentimentSeriesReport) {
+    print_title(&t!("cli.trends.sentiment_title"), '=');
+    say!("  {}: {}", report.dimension.as_str(), report.key);
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.period_days",
+            from = report.since,
+            to = report.since + Duration::days(report.days as i64 - 1),
+            days = report.days
+        )
+    );
+    say!();
+
+    for point in &report.points {
+        match point.average {
+            Some(average) => println!(
+                "  {}  {:+.2}  ({})",
+                point.day,
+                average,
+                t!(
+                    "cli.trends.sentiment_stats",
+                    articles = point.articles,
+                    positive = point.positive,
+                    negative = point.negative
+                )
+            ),
+            None => println!("  {}     -", point.day),
+        }
+    }
+}
+
+fn print_sentiment_list(report: &SentimentListReport) {
+    print_title(
+        &t!(
+            "cli.trends.sentiment_by",
+            dimension = report.dimension.as_str()
+        ),
+        '=',
+    );
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.period_days",
+            from = report.since,
+            to = report.since + Duration::days(report.days as i64 - 1),
+            days = report.days
+        )
+    );
+    say!();
+
+    if report.keys.is_empty() {
+        println!("  {}", t!("cli.common.none"));
+    }
+    for (i, entry) in report.keys.iter().enumerate() {
+        let summary = &entry.summary;
+        println!(
+            "  {:>2}. {} {}  {:+.2}  ({})",
+            i + 1,
+            pad_end(&summary.key, 16),
+            entry.sparkline,
+            summary.average,
+            t!(
+                "cli.trends.sentiment_stats",
+                articles = summary.articles,
+                positive = summary.positive,
+                negative = summary.negative
+            )
+        );
+    }
+}
+
+/// Parameters for `baram trends network`
+pub struct EntityNetworkParams {
+    /// Triple storage directory or `baram ontology` JSON output
+    pub input: PathBuf,
+    /// Earliest article date (YYYY-MM-DD)
+    pub from: Option<String>,
+    /// Latest article date (YYYY-MM-DD)
+    pub to: Option<String>,
+    /// Minimum number of shared articles per edge
+    pub min_count: u64,
+    /// Minimum PMI score per edge
+    pub min_pmi: f64,
+    /// Output format (json, graphml)
+    pub format: String,
+    /// Output file (stdout if not set)
+    pub output: Option<PathBuf>,
+}
+
+/// Build the entity co-occurrence network and export it for visualization
+pub async fn entity_network(params: EntityNetworkParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "json" | "graphml") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "json, graphml"
+            )
+        );
+    }
+
+    let start = params
+        .from
+        .as_deref()
+        .map(|d| parse_day(d, "--from"))
+        .transpose()?;
+    let end = params
+        .to
+        .as_deref()
+        .map(|d| parse_day(d, "--to"))
+        .transpose()?
+        .map(|d| d + Duration::days(1) - Duration::nanoseconds(1));
+
+    let stores = load_extractions(&params.input)?;
+    let network = EntityNetwork::from_extractions(&stores, start, end);
+    let graph = network.to_graph(params.min_count, params.min_pmi);
+
+    tracing::info!(
+        articles = graph.documents,
+        nodes = graph.nodes.len(),
+        edges = graph.edges.len(),
+        communities = graph.communities,
+        "Built entity network"
+    );
+
+    let rendered = match params.format.as_str() {
+        "graphml" => graph.to_graphml(),
+        _ => graph.to_json()?,
+    };
+
+    match params.output {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.ok();
+            }
+            tokio::fs::write(&path, rendered)
+                .await
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+
+            print_title(&t!("cli.trends.network_title"), '=');
+            say!("  {}", t!("cli.trends.articles", count = graph.documents));
+            say!("  {}", t!("cli.trends.entities", count = graph.nodes.len()));
+            say!("  {}", t!("cli.trends.edges", count = graph.edges.len()));
+            say!(
+                "  {}",
+                t!("cli.trends.communities", count = graph.communities)
+            );
+            say!("  {}", t!("cli.cluster.output", path = path.display()));
+        }
+        None => println!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+/// Load extraction results from triple storage or an ontology JSON export
+pub(crate) fn load_extractions(input: &Path) -> Result<Vec<TripleStore>> {
+    if input.is_dir() {
+        let config = StorageConfig::builder()
+            .base_dir(input)
+            .create_dirs(false)
+            .build_unchecked();
+        let storage = TripleStorage::new(config)?;
+        let ids = storage.list_articles();
+        return storage.load_batch(&ids.iter().map(|id| id.as_str()).collect::<Vec<_>>());
+    }
+
+    let content = std::fs::read_to_string(input)
+        .with_context(|| format!("Failed to read {}", input.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse extraction results: {}", input.display()))
+}
+
+/// Extraction results kept in memory, reloaded when they change on disk
+///
+/// A triple storage directory counts as changed when its `index.json`, which
+/// every save rewrites, is newer than the loaded results; a JSON export when
+/// the file itself is.
+pub(crate) struct ExtractionCache {
+    input: PathBuf,
+    loaded: Mutex<Option<(SystemTime, Arc<Vec<TripleStore>>)>>,
+}
+
+impl ExtractionCache {
+    /// Cache of the triple storage directory or ontology JSON export `input`
+    pub(crate) fn new(input: impl Into<PathBuf>) -> Self {
+        Self {
+            input: input.into(),
+            loaded: Mutex::new(None),
+        }
+    }
+
+    /// The extraction results, loading them if they changed since the last
+    /// call; blocks while loading
+    pub(crate) fn get(&self) -> Result<Arc<Vec<TripleStore>>> {
+        let mut loaded = self
+            .loaded
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Extraction cache lock poisoned"))?;
+        let modified = self.modified();
+        if let (Some((stamp, stores)), Some(modified)) = (loaded.as_ref(), modified) {
+            if *stamp >= modified {
+                return Ok(Arc::clone(stores));
+            }
+        }
+        let stores = Arc::new(load_extractions(&self.input)?);
+        if let Some(modified) = modified {
+            *loaded = Some((modified, Arc::clone(&stores)));
+        }
+        Ok(stores)
+    }
+
+    /// Modification time of the results; `None` if unknown, which reloads
+    /// them every time
+    fn modified(&self) -> Option<SystemTime> {
+        let path = if self.input.is_dir() {
+            self.input.join("index.json")
+        } else {
+            self.input.clone()
+        };
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+}
+
+/// Parameters for `baram trends entity`
+pub struct EntityProfileParams {
+    /// Entity name, as extracted
+    pub name: String,
+    /// Triple storage directory or `baram ontology` JSON output
+    pub input: PathBuf,
+    /// Number of days to profile, ending today
+    pub days: usize,
+    /// Number of quotes, related entities and articles to show
+    pub limit: usize,
+    /// Output format (text, json)
+    pub format: String,
+}
+
+/// Show everything known about one entity
+pub async fn entity_profile(config: Config, params: EntityProfileParams) -> Result<()> {
+    if params.days == 0 {
+        bail!("{}", t!("cli.trends.min_days"));
+    }
+    if !matches!(params.format.as_str(), "text" | "json") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json"
+            )
+        );
+    }
+
+    let until = Utc::now().date_naive();
+    let since = until - Duration::days(params.days as i64 - 1);
+    let store = match VectorStore::new(&config.opensearch.clone().with_env_overrides()) {
+        Ok(store) => Some(store),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to connect to OpenSearch, skipping top articles");
+            None
+        }
+    };
+
+    let profile = assemble_entity_profile(
+        &params.name,
+        &Arc::new(ExtractionCache::new(&params.input)),
+        &config.database.sqlite_path,
+        store.as_ref(),
+        since,
+        until,
+        params.limit,
+    )
+    .await;
+    if profile.is_empty() {
+        bail!(
+            "{}",
+            t!(
+                "cli.trends.entity_not_found",
+                name = params.name,
+                days = params.days
+            )
+        );
+    }
+
+    if params.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&profile)?);
+    } else {
+        print_entity_profile(&profile);
+    }
+
+    Ok(())
+}
+
+/// Assemble the profile of `name` from the triple store, the sentiment
+/// aggregates and the search index
+///
+/// Every source is optional: one that cannot be read is logged and left out,
+/// so the profile is empty only if no source knows the entity.
+pub(crate) async fn assemble_entity_profile(
+    name: &str,
+    extractions: &Arc<ExtractionCache>,
+    sqlite_path: &Path,
+    store: Option<&VectorStore>,
+    since: NaiveDate,
+    until: NaiveDate,
+    limit: usize,
+) -> EntityProfile {
+    let key = name.trim().to_string();
+    let (extractions, database) = (Arc::clone(extractions), sqlite_path.to_path_buf());
+    let local = tokio::task::spawn_blocking(move || {
+        let profile = match extractions.get() {
+            Ok(stores) => EntityProfile::from_extractions(&key, &stores, since, until, limit),
+            Err(e) => {
+                tracing::warn!(error = %e, input = %extractions.input.display(), "Failed to load extraction results");
+                None
+            }
+        }
+        .unwrap_or_else(|| EntityProfile::empty(&key, since, until));
+
+        match SentimentStore::open(&database)
+            .and_then(|s| s.series(SentimentDimension::Entity, &key, since, until))
+        {
+            Ok(points) => profile.with_sentiment(points),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load sentiment aggregates");
+                profile
+            }
+        }
+    })
+    .await;
+    let profile = match local {
+        Ok(profile) => profile,
+        Err(e) => {
+            tracing::warn!(error = %e, "Entity profile task failed");
+            EntityProfile::empty(name, since, until)
+        }
+    };
+
+    let Some(store) = store else {
+        return profile;
+    };
+    let search_config = SearchConfig {
+        k: limit,
+        date_from: Some(since.to_string()),
+        date_to: Some(format!("{until}T23:59:59")),
+        include_highlights: false,
+        ..Default::default()
+    };
+    match store.search_bm25(&profile.name, &search_config).await {
+        Ok(results) => profile.with_articles(&results),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to search articles about the entity");
+            profile
+        }
+    }
+}
+
+/// Render daily counts as a sparkline scaled to the busiest day
+fn count_sparkline(daily: &[u64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let max = daily.iter().copied().max().unwrap_or(0).max(1);
+    daily
+        .iter()
+        .map(|&count| BLOCKS[((count as f64 / max as f64) * 7.0).round() as usize])
+        .collect()
+}
+
+fn print_entity_profile(profile: &EntityProfile) {
+    let title = match &profile.entity_type {
+        Some(entity_type) => format!("{} ({entity_type})", profile.name),
+        None => profile.name.clone(),
+    };
+    print_title(&title, '=');
+    let days = (profile.until - profile.since).num_days() + 1;
+    say!(
+        "  {}",
+        t!(
+            "cli.trends.period_days",
+            from = profile.since,
+            to = profile.until,
+            days = days
+        )
+    );
+    println!("  {}", t!("cli.trends.articles", count = profile.articles));
+    if !profile.mentions.is_empty() {
+        let daily: Vec<u64> = profile.mentions.iter().map(|m| m.articles).collect();
+        println!(
+            "  {} {}",
+            pad_end(&t!("cli.trends.mentions"), 10),
+            count_sparkline(&daily)
+        );
+    }
+    if !profile.sentiment.is_empty() {
+        let averages: HashMap<NaiveDate, f64> = profile
+            .sentiment
+            .iter()
+            .map(|p| (p.day, p.average))
+            .collect();
+        let daily: Vec<Option<f64>> = (0..days)
+            .map(|i| averages.get(&(profile.since + Duration::days(i))).copied())
+            .collect();
+        let articles: u64 = profile.sentiment.iter().map(|p| p.articles).sum();
+        let average = profile
+            .sentiment
+            .iter()
+            .map(|p| p.average * p.articles as f64)
+            .sum::<f64>()
+            / articles.max(1) as f64;
+        println!(
+            "  {} {}  {:+.2}",
+            pad_end(&t!("cli.trends.sentiment"), 10),
+            sentiment_sparkline(&daily),
+            average
+        );
+    }
+
+    if !profile.quotes.is_empty() {
+        println!();
+        println!("  {}", t!("cli.trends.quotes"));
+        for quote in &profile.quotes {
+            let day = quote
+                .published_at
+                .as_deref()
+                .and_then(|t| t.get(..10))
+                .unwrap_or("");
+            println!("    {day}  \"{}\"", quote.text);
+            println!("                {}", quote.article_title);
+        }
+    }
+
+    if !profile.related.is_empty() {
+        println!();
+        println!("  {}", t!("cli.trends.related"));
+        for related in &profile.related {
+            println!(
+                "    {} {:<14} {}",
+                pad_end(&related.name, 16),
+                related.entity_type,
+                t!("cli.trends.article_count", count = related.shared_articles)
+            );
+        }
+    }
+
+    if !profile.top_articles.is_empty() {
+        println!();
+        println!("  {}", t!("cli.trends.top_articles"));
+        for (i, article) in profile.top_articles.iter().enumerate() {
+            println!("    {:>2}. {}", i + 1, article.title);
+            println!(
+                "        {}  {}",
+                article.publisher.as_deref().unwrap_or("-"),
+                article.url
+            );
+        }
+    }
+}
+
+/// Parameters for `baram trends coverage`
+pub struct CoverageParams {
+    /// `clusters.json` written by `baram cluster`
+    pub input: PathBuf,
+    /// Minimum publishers covering a story
+    pub min_publishers: usize,
+    /// Emphasized entities to list per publisher
+    pub top_entities: usize,
+    /// Number of stories to report (0 = all)
+    pub limit: usize,
+    /// Load article bodies from PostgreSQL for sentiment and entities
+    pub bodies: bool,
+    /// Output format (text, json, csv)
+    pub format: String,
+    /// Output file (stdout if not set)
+    pub output: Option<PathBuf>,
+}
+
+/// Compare how publishers cover the same story clusters
+pub async fn publisher_coverage(config: Config, params: CoverageParams) -> Result<()> {
+    if !matches!(params.format.as_str(), "text" | "json" | "csv") {
+        bail!(
+            "{}",
+            t!(
+                "cli.report.unsupported_format",
+                format = params.format,
+                formats = "text, json, csv"
+            )
+        );
+    }
+
+    let content = std::fs::read_to_string(&params.input)
+        .with_context(|| format!("Failed to read {}", params.input.display()))?;
+    let clusters: ClusterOutput = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse clusters: {}", params.input.display()))?;
+
+    let bodies = if params.bodies {
+        load_cluster_bodies(&config, &clusters.events).await?
+    } else {
+        HashMap::new()
+    };
+
+    let mut report = CoverageAnalyzer::new()
+        .with_min_publishers(params.min_publishers)
+        .with_top_entities(params.top_entities)
+        .analyze(&clusters.events, &bodies);
+    if params.limit > 0 {
+        report.truncate(params.limit);
+    }
+
+    tracing::info!(
+        clusters = clusters.events.len(),
+        stories = report.stories.len(),
+        publishers = report.publishers.len(),
+        bodies = bodies.len(),
+        "Compared publisher coverage"
+    );
+
+    let rendered = match params.format.as_str() {
+        "json" => serde_json::to_string_pretty(&report)?,
+        "csv" => report.to_csv(),
+        _ => render_coverage_text(&report),
+    };
+
+    match params.output {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await.ok();
+            }
+            tokio::fs::write(&path, rendered)
+                .await
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            say!(
+                "{}",
+                t!(
+                    "cli.trends.coverage_written",
+                    stories = report.stories.len(),
+                    publishers = report.publishers.len(),
+                    path = path.display()
+                )
+            );
+        }
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+/// Load stored articles for the clustered URLs, keyed by URL
+async fn load_cluster_bodies(
+    config: &Config,
+    clusters: &[EventCluster],
+) -> Result<HashMap<String, Article>> {
+    let urls: HashSet<&str> = clusters
+        .iter()
+        .flat_map(|c| c.articles.iter().map(|a| a.url.as_str()))
+        .collect();
+    let since = clusters
+        .iter()
+        .flat_map(|c| c.articles.iter())
+        .filter_map(|a| a.published_at.as_deref())
+        .filter_map(|t| DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&Utc))
+        .min();
+    let Some(since) = since else {
+        return Ok(HashMap::new());
+    };
+
+    let mut db = Database::new(&config.database)?;
+    db.init_postgres(&config.database.postgres_url)
+        .await
+        .context("Failed to connect to PostgreSQL (use --no-bodies to compare titles only)")?;
+    // Allow for clock differences between the index and the database
+    let articles = db
+        .get_articles_since(since - Duration::days(1), 0)
+        .await
+        .context("Failed to load articles")?;
+
+    Ok(articles
+        .into_iter()
+        .filter(|a| urls.contains(a.url.as_str()))
+        .map(|a| (a.url.clone(), a))
+        .collect())
+}
+
+fn render_coverage_text(report: &CoverageReport) -> String {
+    let mut out = String::new();
+    out.push_str("Publisher Coverage\n==================\n\n");
+
+    for story in &report.stories {
+        out.push_str(&format!(
+            "{} [{}] {} ({} articles, {} publishers, sentiment spread {:.2})\n",
+            story.event_id,
+            story.category,
+            story.title,
+            story.articles,
+            story.publishers.len(),
+            story.sentiment_spread
+        ));
+        for coverage in &story.publishers {
+            let lag = match coverage.lag_minutes {
+                Some(0) => "first".to_string(),
+                Some(minutes) => format!("+{minutes}m"),
+                None => "-".to_string(),
+            };
+            out.push_str(&format!(
+                "  {} {:>3} articles  {:>7}  sentiment {:+.2}  {}\n",
+                pad_end(&coverage.publisher, 16),
+                coverage.articles,
+                lag,
+                coverage.sentiment,
+                coverage.emphasized_entities.join(", ")
+            ));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("Publishers\n----------\n");
+    out.push_str(&format!(
+        "  {:<16} {:>7} {:>8} {:>6} {:>10} {:>9}\n",
+        "publisher", "stories", "articles", "first", "median lag", "sentiment"
+    ));
+    for summary in &report.publishers {
+        let lag = summary
+            .median_lag_minutes
+            .map(|m| format!("{m}m"))
+            .unwrap_or_else(|| "-".to_string());
+        out.push_str(&format!(
+            "  {} {:>7} {:>8} {:>6} {:>10} {:>+9.2}\n",
+            pad_end(&summary.publisher, 16),
+            summary.stories,
+            summary.articles,
+            summary.first_reports,
+            lag,
+            summary.sentiment
+        ));
+    }
+    out
+}
+
+fn parse_day(date: &str, flag: &str) -> Result<DateTime<Utc>> {
+    let day = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .with_context(|| format!("Invalid {flag} date: {date}. Expected YYYY-MM-DD"))?;
+    Ok(day
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_extraction_cache_reloads_changed_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ontology.json");
+        let store = |id: &str| {
+            format!(r#"{{"article_id":"{id}","article_title":"t","extracted_at":"","triples":[]}}"#)
+        };
+        std::fs::write(&path, format!("[{}]", store("a"))).unwrap();
+        let cache = ExtractionCache::new(&path);
+        let first = cache.get().unwrap();
+        assert!(Arc::ptr_eq(&first, &cache.get().unwrap()));
+
+        std::fs::write(&path, format!("[{},{}]", store("a"), store("b"))).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + std::time::Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(cache.get().unwrap().len(), 2);
+    }
+
+    fn article(title: &str, published_at: DateTime<Utc>) -> Article {
+        Article {
+            id: Uuid::new_v4(),
+            url: format!("https://n.news.naver.com/{}", Uuid::new_v4()),
+            title: title.to_string(),
+            body: String::new(),
+            author: None,
+            published_at: Some(published_at),
+            category: None,
+            content_hash: String::new(),
+            comments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_report_ranks_risers_and_fallers() {
+        let since = Utc::now() - Duration::days(2);
+        let mut articles = Vec::new();
+        for (day, rising, falling) in [(0, 1, 6), (1, 3, 3), (2, 6, 1)] {
+            let published_at = since + Duration::days(day) + Duration::hours(1);
+            for _ in 0..rising {
+                articles.push(article("반도체 수출이 늘었다", published_at));
+            }
+            for _ in 0..falling {
+                articles.push(article("부동산 거래가 줄었다", published_at));
+            }
+        }
+
+        let params = KeywordTrendsParams {
+            days: 3,
+            limit: 5,
+            min_count: 5,
+            format: "json".to_string(),
+            titles_only: true,
+            rescan: false,
+        };
+        let report = build_report(&articles, since, &params);
+
+        assert_eq!(report.articles, 20);
+        assert_eq!(report.risers[0].counts, vec![1, 3, 6]);
+        assert!(report
+            .risers
+            .iter()
+            .any(|e| e.keyword == "반도체" && e.sparkline == "▁▃█"));
+        assert!(report.fallers.iter().any(|e| e.keyword == "부동산"));
+        assert!(report.risers.iter().all(|e| e.keyword != "부동산"));
+    }
+
+    #[test]
+    fn test_build_burst_report_keeps_recent_bursts() {
+        let end = Utc::now();
+        let since = end - Duration::hours(11);
+        let mut articles = Vec::new();
+        for hour in 0..12 {
+            let published_at = since + Duration::hours(hour);
+            let count = if hour == 11 { 15 } else { 2 };
+            for _ in 0..count {
+                articles.push(article("반도체 수출", published_at));
+            }
+            articles.push(article("날씨 맑음", published_at));
+        }
+
+        let params = BurstParams {
+            hours: 12,
+            recent: 2,
+            burst: BurstConfig::default(),
+            entities: false,
+            notify: false,
+            limit: 10,
+            format: "json".to_string(),
+        };
+        let report = build_burst_report(&articles, since, end, &params);
+
+        assert_eq!(report.bursts.len(), 2);
+        assert!(report
+            .bursts
+            .iter()
+            .all(|e| e.spike.count == 15 && e.subject.kind() == "keyword"));
+        assert!(report
+            .bursts
+            .iter()
+            .any(|e| e.subject == BurstSubject::Keyword("반도체".to_string())));
+    }
+
+    #[test]
+    fn test_record_sentiment_by_dimension() {
+        let published_at = Utc::now();
+        let mut positive = article("반도체 수출 호조로 실적 개선", published_at);
+        positive.url = "https://n.news.naver.com/mnews/article/001/0014123456".to_string();
+        positive.category = Some("economy".to_string());
+        let mut negative = article("부동산 시장 침체 우려", published_at);
+        negative.category = Some("economy".to_string());
+        let undated = Article {
+            published_at: None,
+            ..article("기대", published_at)
+        };
+
+        let mut store = SentimentStore::open_in_memory().unwrap();
+        let articles = vec![positive, negative, undated];
+        assert_eq!(record_sentiment(&mut store, &articles).unwrap(), 2);
+        // Already recorded articles are skipped
+        assert_eq!(record_sentiment(&mut store, &articles).unwrap(), 0);
+
+        let day = published_at.date_naive();
+        let economy = store
+            .series(SentimentDimension::Category, "economy", day, day)
+            .unwrap();
+        assert_eq!(economy[0].articles, 2);
+        assert_eq!((economy[0].positive, economy[0].negative), (1, 1));
+
+        let publisher = store
+            .top_keys(SentimentDimension::Publisher, day, day, 1, 10)
+            .unwrap();
+        assert_eq!(publisher.len(), 1);
+        assert_eq!(publisher[0].key, "001");
+        assert!(publisher[0].average > 0.0);
+
+        assert_eq!(
+            sentiment_sparkline(&[Some(-1.0), None, Some(0.0), Some(1.0)]),
+            "▁·▅█"
+        );
+    }
+
+    #[test]
+    fn test_load_extractions_from_ontology_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ontology.json");
+        std::fs::write(
+            &path,
+            r#"[{"article_id": "001_0001", "article_title": "t",
+                "extracted_at": "2024-01-15T09:00:00+09:00", "triples": [], "entities": []}]"#,
+        )
+        .unwrap();
+
+        let stores = load_extractions(&path).unwrap();
+        assert_eq!(stores.len(), 1);
+        assert_eq!(stores[0].article_id, "001_0001");
+
+        assert!(parse_day("2024-01-15", "--from").is_ok());
+        assert!(parse_day("15/01/2024", "--from").is_err());
+    }
+
+    #[test]
+    fn test_render_coverage_text() {
+        use baram::analytics::{PublisherCoverage, PublisherSummary, StoryCoverage};
+
+        let report = CoverageReport {
+            stories: vec![StoryCoverage {
+                event_id: "evt_20260215_001".to_string(),
+                title: "수출 회복".to_string(),
+                category: "economy".to_string(),
+                articles: 3,
+                first_reported: None,
+                first_publisher: Some("연합뉴스".to_string()),
+                sentiment_spread: 0.5,
+                publishers: vec![PublisherCoverage {
+                    publisher: "연합뉴스".to_string(),
+                    articles: 2,
+                    first_published: None,
+                    lag_minutes: Some(0),
+                    sentiment: 0.25,
+                    emphasized_entities: vec!["삼성전자".to_string()],
+                }],
+            }],
+            publishers: vec![PublisherSummary {
+                publisher: "연합뉴스".to_string(),
+                stories: 1,
+                articles: 2,
+                first_reports: 1,
+                median_lag_minutes: Some(0),
+                sentiment: 0.25,
+            }],
+        };
+
+        let text = render_coverage_text(&report);
+        assert!(text.contains("evt_20260215_001 [economy] 수출 회복"));
+        assert!(text.contains("first"));
+        assert!(text.contains("삼성전자"));
+        assert!(text.contains("+0.25"));
+    }
+
+    #[test]
+    fn test_daily_from_timeseries() {
+        let since = Utc::now()
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        let point = |hours: i64, keyword: &str, value: f64| TimeSeriesPoint {
+            dimensions: [("keyword".to_string(), keyword.to_string())].into(),
+            bucket: since + Duration::hours(hours),
+            value,
+        };
+        let points = vec![
+            point(1, "반도체", 2.0),
+            point(5, "반도체", 1.0),
+            point(30, "반도체", 4.0),
+            point(30, "부동산", 1.0),
+            point(80, "반도체", 9.0),
+        ];
+
+        let daily = daily_from_timeseries(&points, since, 3);
+        assert_eq!(daily[0]["반도체"], 3);
+        assert_eq!(daily[1]["반도체"], 4);
+        assert_eq!(daily[1]["부동산"], 1);
+        assert!(daily[2].is_empty());
+    }
+}
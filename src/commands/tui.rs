@@ -0,0 +1,567 @@
+//! TUI command implementation
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, Local, Utc};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+    Bar, BarChart, BarGroup, Block, List, ListItem, Paragraph, Row, Sparkline, Table,
+};
+use ratatui::Frame;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration as StdDuration, Instant};
+
+use baram::config::DatabaseConfig;
+use baram::coordinator::{InstanceInfo, InstanceStatus};
+use baram::metrics::scrape::{self, ScrapedSample};
+use baram::storage::{
+    CrawlRecord, CrawlStats, CrawlStatus, Database, TimeSeriesStore, METRIC_ARTICLES,
+};
+
+/// Minutes of history in the throughput sparkline
+const THROUGHPUT_MINUTES: i64 = 60;
+
+/// Number of recent errors listed
+const RECENT_ERRORS: usize = 20;
+
+/// Timeout for coordinator requests
+const COORDINATOR_TIMEOUT: StdDuration = StdDuration::from_secs(5);
+
+/// Parameters for `baram tui`
+pub struct TuiParams {
+    /// Crawl database written by `baram crawl` or `baram watch`
+    pub database: PathBuf,
+    /// `/metrics` endpoint of a running crawler or coordinator
+    pub metrics_url: Option<String>,
+    /// Coordinator base URL, for instance status
+    pub coordinator_url: Option<String>,
+    /// Seconds between refreshes
+    pub refresh_secs: u64,
+}
+
+/// Dashboard data gathered on each refresh
+struct Snapshot {
+    taken_at: DateTime<Local>,
+    stats: CrawlStats,
+    /// Crawls per minute over the last hour, oldest first
+    throughput: Vec<u64>,
+    /// Failed crawls over the last hour
+    failed_last_hour: u64,
+    /// Articles per category over the last 24 hours, largest first
+    categories: Vec<(String, u64)>,
+    /// Most recent failures over the last 24 hours, newest first
+    errors: Vec<CrawlRecord>,
+    /// `None` when no coordinator is configured
+    instances: Option<Result<Vec<InstanceInfo>, String>>,
+    /// `None` when no metrics endpoint is configured
+    metrics: Option<Result<LiveMetrics, String>>,
+}
+
+/// Gauges read from a `/metrics` endpoint
+#[derive(Debug, Default, PartialEq)]
+struct LiveMetrics {
+    crawling: Option<f64>,
+    queue_depth: Option<f64>,
+    alive_tasks: Option<f64>,
+    rate_limit_wait: Option<f64>,
+    resident_memory: Option<f64>,
+}
+
+impl LiveMetrics {
+    fn from_samples(samples: &[ScrapedSample]) -> Self {
+        Self {
+            crawling: scrape::sum(samples, "baram_crawler_is_crawling", &[]),
+            queue_depth: scrape::sum(samples, "baram_tokio_global_queue_depth", &[]),
+            alive_tasks: scrape::sum(samples, "baram_tokio_alive_tasks", &[]),
+            rate_limit_wait: scrape::sum(samples, "baram_rate_limiter_last_wait_seconds", &[]),
+            resident_memory: scrape::sum(samples, "baram_process_resident_memory_bytes", &[]),
+        }
+    }
+}
+
+/// `data` of the coordinator's `GET /api/instances` response
+#[derive(Deserialize)]
+struct InstancesData {
+    instances: Vec<InstanceInfo>,
+}
+
+#[derive(Deserialize)]
+struct InstancesResponse {
+    data: Option<InstancesData>,
+    error: Option<String>,
+}
+
+/// Data sources of the dashboard
+struct Sources {
+    db: Database,
+    series: TimeSeriesStore,
+    client: reqwest::Client,
+    metrics_url: Option<String>,
+    coordinator_url: Option<String>,
+}
+
+impl Sources {
+    async fn snapshot(&self) -> Result<Snapshot> {
+        let now = Utc::now();
+
+        let crawls = self
+            .db
+            .get_crawls_between(now - Duration::minutes(THROUGHPUT_MINUTES), now)?;
+        let failed_last_hour = crawls
+            .iter()
+            .filter(|(_, status)| *status == CrawlStatus::Failed)
+            .count() as u64;
+
+        let mut errors = self
+            .db
+            .get_failures_between(now - Duration::hours(24), now)?;
+        errors.truncate(RECENT_ERRORS);
+
+        let mut categories: BTreeMap<String, u64> = BTreeMap::new();
+        for point in self
+            .series
+            .query(METRIC_ARTICLES, &[], now - Duration::hours(24), now)?
+        {
+            if let Some(category) = point.dimension("category") {
+                *categories.entry(category.to_string()).or_default() += point.value as u64;
+            }
+        }
+        let mut categories: Vec<(String, u64)> = categories.into_iter().collect();
+        categories.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        let instances = match &self.coordinator_url {
+            Some(url) => Some(self.fetch_instances(url).await),
+            None => None,
+        };
+        let metrics = match &self.metrics_url {
+            Some(url) => Some(
+                scrape::scrape(&self.client, url)
+                    .await
+                    .map(|samples| LiveMetrics::from_samples(&samples)),
+            ),
+            None => None,
+        };
+
+        Ok(Snapshot {
+            taken_at: Local::now(),
+            stats: self.db.get_stats()?,
+            throughput: per_minute(&crawls, now),
+            failed_last_hour,
+            categories,
+            errors,
+            instances,
+            metrics,
+        })
+    }
+
+    async fn fetch_instances(&self, base_url: &str) -> Result<Vec<InstanceInfo>, String> {
+        let url = format!("{}/api/instances", base_url.trim_end_matches('/'));
+        let response = self
+            .client
+            .get(&url)
+            .timeout(COORDINATOR_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| format!("request failed: {e}"))?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        let body: InstancesResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("invalid response: {e}"))?;
+        match body.data {
+            Some(data) => Ok(data.instances),
+            None => Err(body.error.unwrap_or_else(|| "empty response".to_string())),
+        }
+    }
+}
+
+/// Show a live dashboard of crawl progress until `q` is pressed
+///
+/// Throughput, per-category counts and recent errors come from the crawl
+/// database; instance status and runtime gauges come from the coordinator API
+/// and a `/metrics` endpoint when given.
+pub async fn tui(params: TuiParams) -> Result<()> {
+    let TuiParams {
+        database,
+        metrics_url,
+        coordinator_url,
+        refresh_secs,
+    } = params;
+
+    if !database.exists() {
+        bail!(
+            "Crawl database not found: {}. Run `baram crawl` first or pass --database",
+            database.display()
+        );
+    }
+    let db_config = DatabaseConfig {
+        sqlite_path: database.clone(),
+        postgres_url: String::new(),
+        pool_size: 1,
+        store_bodies: false,
+    };
+    let mut db = Database::new(&db_config)?;
+    db.init_sqlite(&database)?;
+
+    let sources = Sources {
+        db,
+        series: TimeSeriesStore::open(&database)?,
+        client: reqwest::Client::new(),
+        metrics_url,
+        coordinator_url,
+    };
+    let refresh = StdDuration::from_secs(refresh_secs.max(1));
+
+    let mut terminal = ratatui::init();
+    let result = async {
+        loop {
+            let snapshot = sources.snapshot().await?;
+            terminal.draw(|frame| draw(frame, &snapshot))?;
+
+            let deadline = Instant::now() + refresh;
+            loop {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                if timeout.is_zero() || !event::poll(timeout)? {
+                    break;
+                }
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('r') => break,
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    .await;
+    ratatui::restore();
+    result
+}
+
+/// Count crawls per minute over the last [`THROUGHPUT_MINUTES`], oldest first
+fn per_minute(crawls: &[(DateTime<Utc>, CrawlStatus)], now: DateTime<Utc>) -> Vec<u64> {
+    let mut counts = vec![0; THROUGHPUT_MINUTES as usize];
+    for (crawled_at, _) in crawls {
+        let age = (now - *crawled_at).num_minutes();
+        if (0..THROUGHPUT_MINUTES).contains(&age) {
+            counts[(THROUGHPUT_MINUTES - 1 - age) as usize] += 1;
+        }
+    }
+    counts
+}
+
+fn draw(frame: &mut Frame, snapshot: &Snapshot) {
+    let [header, top, middle, bottom, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(7),
+        Constraint::Min(8),
+        Constraint::Min(6),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [throughput, totals] =
+        Layout::horizontal([Constraint::Min(30), Constraint::Length(28)]).areas(top);
+    let [categories, instances] =
+        Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(middle);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(" Baram ", Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(
+                "updated {}   q: quit  r: refresh",
+                snapshot.taken_at.format("%H:%M:%S")
+            )),
+        ])),
+        header,
+    );
+    draw_throughput(frame, throughput, snapshot);
+    draw_totals(frame, totals, snapshot);
+    draw_categories(frame, categories, snapshot);
+    draw_instances(frame, instances, snapshot);
+    draw_errors(frame, bottom, snapshot);
+    frame.render_widget(
+        Paragraph::new(metrics_line(snapshot.metrics.as_ref())),
+        footer,
+    );
+}
+
+fn draw_throughput(frame: &mut Frame, area: Rect, snapshot: &Snapshot) {
+    let last_hour: u64 = snapshot.throughput.iter().sum();
+    let title = format!(
+        " Throughput (last hour: {last_hour} crawls, {} failed) ",
+        snapshot.failed_last_hour
+    );
+    // Show the most recent minutes that fit
+    let width = area.width.saturating_sub(2) as usize;
+    let data = &snapshot.throughput[snapshot.throughput.len().saturating_sub(width)..];
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::bordered().title(title))
+            .data(data)
+            .style(Style::new().fg(Color::Cyan)),
+        area,
+    );
+}
+
+fn draw_totals(frame: &mut Frame, area: Rect, snapshot: &Snapshot) {
+    let stats = &snapshot.stats;
+    let lines = vec![
+        Line::from(format!("Total:    {}", stats.total)),
+        Line::from(format!("Success:  {}", stats.success)),
+        Line::from(format!("Failed:   {}", stats.failed)),
+        Line::from(format!("Skipped:  {}", stats.skipped)),
+        Line::from(format!("Rate:     {:.1}%", stats.success_rate() * 100.0)),
+    ];
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::bordered().title(" Totals ")),
+        area,
+    );
+}
+
+fn draw_categories(frame: &mut Frame, area: Rect, snapshot: &Snapshot) {
+    let block = Block::bordered().title(" Articles per category (24h) ");
+    if snapshot.categories.is_empty() {
+        frame.render_widget(Paragraph::new("No articles recorded").block(block), area);
+        return;
+    }
+    let bars: Vec<Bar> = snapshot
+        .categories
+        .iter()
+        .map(|(category, count)| Bar::default().label(category.as_str().into()).value(*count))
+        .collect();
+    frame.render_widget(
+        BarChart::default()
+            .block(block)
+            .direction(ratatui::layout::Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .bar_style(Style::new().fg(Color::Green))
+            .data(BarGroup::default().bars(&bars)),
+        area,
+    );
+}
+
+fn draw_instances(frame: &mut Frame, area: Rect, snapshot: &Snapshot) {
+    let block = Block::bordered().title(" Instances ");
+    let instances = match &snapshot.instances {
+        None => {
+            frame.render_widget(
+                Paragraph::new("No coordinator (use --coordinator)").block(block),
+                area,
+            );
+            return;
+        }
+        Some(Err(e)) => {
+            frame.render_widget(
+                Paragraph::new(format!("Coordinator unreachable: {e}"))
+                    .style(Style::new().fg(Color::Red))
+                    .block(block),
+                area,
+            );
+            return;
+        }
+        Some(Ok(instances)) => instances,
+    };
+
+    let now = Utc::now();
+    let rows = instances.iter().map(|info| {
+        let color = match info.status {
+            InstanceStatus::Online => Color::Green,
+            InstanceStatus::Degraded => Color::Yellow,
+            InstanceStatus::Offline => Color::Red,
+            InstanceStatus::Maintenance => Color::Blue,
+        };
+        Row::new(vec![
+            Span::raw(info.instance.to_string()),
+            Span::styled(format!("{:?}", info.status), Style::new().fg(color)),
+            Span::raw(format!(
+                "{}s ago",
+                (now - info.last_heartbeat).num_seconds().max(0)
+            )),
+            Span::raw(info.articles_crawled.to_string()),
+            Span::raw(info.error_count.to_string()),
+            Span::raw(
+                info.current_category
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+        ])
+    });
+    frame.render_widget(
+        Table::new(
+            rows,
+            [
+                Constraint::Length(6),
+                Constraint::Length(11),
+                Constraint::Length(10),
+                Constraint::Length(9),
+                Constraint::Length(7),
+                Constraint::Min(8),
+            ],
+        )
+        .header(
+            Row::new([
+                "ID",
+                "Status",
+                "Heartbeat",
+                "Articles",
+                "Errors",
+                "Category",
+            ])
+            .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .block(block),
+        area,
+    );
+}
+
+fn draw_errors(frame: &mut Frame, area: Rect, snapshot: &Snapshot) {
+    let block = Block::bordered().title(" Recent errors (24h) ");
+    if snapshot.errors.is_empty() {
+        frame.render_widget(Paragraph::new("No failures").block(block), area);
+        return;
+    }
+    let items = snapshot.errors.iter().map(|record| {
+        ListItem::new(Line::from(vec![
+            Span::styled(
+                record
+                    .crawled_at
+                    .with_timezone(&Local)
+                    .format("%H:%M:%S ")
+                    .to_string(),
+                Style::new().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                record
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| "unknown error".to_string()),
+                Style::new().fg(Color::Red),
+            ),
+            Span::raw(format!("  {}", record.url)),
+        ]))
+    });
+    frame.render_widget(List::new(items).block(block), area);
+}
+
+fn metrics_line(metrics: Option<&Result<LiveMetrics, String>>) -> Line<'static> {
+    let metrics = match metrics {
+        None => return Line::from(" No metrics endpoint (use --metrics-url)"),
+        Some(Err(e)) => {
+            return Line::styled(
+                format!(" Metrics unreachable: {e}"),
+                Style::new().fg(Color::Red),
+            )
+        }
+        Some(Ok(metrics)) => metrics,
+    };
+
+    let value = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{v:.0}"));
+    Line::from(format!(
+        " Crawling: {}   Queue depth: {}   Tasks: {}   Rate-limit wait: {}   RSS: {}",
+        match metrics.crawling {
+            Some(v) if v > 0.0 => "yes",
+            Some(_) => "no",
+            None => "-",
+        },
+        value(metrics.queue_depth),
+        value(metrics.alive_tasks),
+        metrics
+            .rate_limit_wait
+            .map_or("-".to_string(), |v| format!("{v:.2}s")),
+        metrics
+            .resident_memory
+            .map_or("-".to_string(), |v| format!("{:.0} MiB", v / 1_048_576.0)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_per_minute() {
+        let now = Utc::now();
+        let crawls = [
+            (now - Duration::seconds(10), CrawlStatus::Success),
+            (now - Duration::seconds(20), CrawlStatus::Failed),
+            (now - Duration::minutes(5), CrawlStatus::Success),
+            (now - Duration::minutes(90), CrawlStatus::Success),
+        ];
+        let counts = per_minute(&crawls, now);
+        assert_eq!(counts.len(), THROUGHPUT_MINUTES as usize);
+        assert_eq!(counts[59], 2);
+        assert_eq!(counts[54], 1);
+        assert_eq!(counts.iter().sum::<u64>(), 3);
+    }
+
+    #[test]
+    fn test_live_metrics_from_samples() {
+        let samples = scrape::parse_text(
+            "baram_crawler_is_crawling{instance=\"main\"} 1\n\
+             baram_tokio_global_queue_depth 4\n",
+        );
+        let metrics = LiveMetrics::from_samples(&samples);
+        assert_eq!(metrics.crawling, Some(1.0));
+        assert_eq!(metrics.queue_depth, Some(4.0));
+        assert_eq!(metrics.alive_tasks, None);
+        assert!(metrics_line(Some(&Ok(metrics)))
+            .to_string()
+            .contains("Queue depth: 4"));
+    }
+
+    #[test]
+    fn test_draw_dashboard() {
+        let now = Utc::now();
+        let snapshot = Snapshot {
+            taken_at: Local::now(),
+            stats: CrawlStats {
+                total: 10,
+                success: 8,
+                failed: 2,
+                skipped: 0,
+            },
+            throughput: vec![1; THROUGHPUT_MINUTES as usize],
+            failed_last_hour: 2,
+            categories: vec![("politics".to_string(), 7), ("economy".to_string(), 3)],
+            errors: vec![CrawlRecord {
+                id: "001_0000000001".to_string(),
+                url: "https://n.news.naver.com/article/001/0000000001".to_string(),
+                content_hash: String::new(),
+                crawled_at: now,
+                status: CrawlStatus::Failed,
+                error_message: Some("HTTP 403".to_string()),
+            }],
+            instances: Some(Err("request failed: connection refused".to_string())),
+            metrics: None,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| draw(frame, &snapshot)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(screen.contains("last hour: 60 crawls, 2 failed"));
+        assert!(screen.contains("politics"));
+        assert!(screen.contains("HTTP 403"));
+        assert!(screen.contains("Coordinator unreachable"));
+        assert!(screen.contains("No metrics endpoint"));
+    }
+}
@@ -0,0 +1,612 @@
+//! Watch command implementation
+
+use anyhow::{bail, Context, Result};
+use axum::{extract::State, routing::get, Json, Router};
+use chrono::{DateTime, Local, Utc};
+use rust_i18n::t;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::watch;
+
+use baram::config::{Config, CrawlerConfig, DatabaseConfig};
+use baram::crawler::assets::AssetDownloader;
+use baram::crawler::comment::CommentClient;
+use baram::crawler::fetcher::NaverFetcher;
+use baram::crawler::list::NewsListCrawler;
+use baram::crawler::sites::NaverSite;
+use baram::crawler::url::UrlExtractor;
+use baram::crawler::watch::{WatchJob, WatchSchedule, WatchStatus};
+use baram::crawler::Crawler;
+use baram::metrics;
+use baram::models::{CrawlState, NewsCategory};
+use baram::scheduler::schedule::ScheduleCache;
+use baram::scheduler::trigger::{ScheduleTrigger, TriggerConfig, TriggerEvent};
+use baram::storage::{ArticleStorage, Database, HttpCache, StorageFormat, TimeSeriesStore};
+
+use super::crawl::{parse_category, start_keyword_alerts, start_metrics_server, CategoryCrawler};
+use super::output::say;
+use super::print_title;
+use super::progress::Progress;
+use super::shutdown::Shutdown;
+
+/// Instance label for metrics recorded by `baram watch`
+const METRICS_INSTANCE: &str = "watch";
+
+/// Parameters for `baram watch`
+pub struct WatchParams {
+    /// Categories crawled on the default interval
+    pub categories: Vec<String>,
+    /// Default schedule: an interval (`30m`) or a cron expression
+    pub interval: String,
+    /// Per-category schedules (`category=schedule`); a schedule without a
+    /// category replaces `interval`
+    pub schedules: Vec<String>,
+    /// Maximum number of articles per category run
+    pub max_articles: usize,
+    /// Output directory for markdown files
+    pub output: PathBuf,
+    /// Files each article is saved as
+    pub format: StorageFormat,
+    /// Save article images under `assets/` next to the output directory
+    pub download_images: bool,
+    /// Crawl the comments of Naver articles
+    pub with_comments: bool,
+    /// Skip articles already in the crawl database
+    pub skip_existing: bool,
+    /// Serve Prometheus metrics on this port
+    pub metrics_port: Option<u16>,
+    /// Serve the schedule and progress as JSON at `/status` on this port
+    pub status_port: Option<u16>,
+    /// Crawl every category once and exit
+    pub once: bool,
+    /// Reloaded configs; their rate limit and category weights apply from
+    /// the next category run
+    pub config_updates: Option<watch::Receiver<Arc<Config>>>,
+    /// Ends the watch once requested, after the article in flight
+    pub shutdown: Shutdown,
+}
+
+/// Crawl categories continuously on their schedules until SIGINT or SIGTERM
+///
+/// A [`ScheduleTrigger`] fires each category's runs. The end of each
+/// category run is checkpointed in the crawl database, and with
+/// `skip_existing` articles already in it are skipped, so a restarted watch
+/// resumes without crawling anything twice. A run cut short by a shutdown is
+/// not checkpointed, so a restart finishes it right away.
+pub async fn watch(config: Config, params: WatchParams, progress: &Progress) -> Result<()> {
+    let WatchParams {
+        categories,
+        interval,
+        schedules,
+        max_articles,
+        output,
+        format,
+        download_images,
+        with_comments,
+        skip_existing,
+        metrics_port,
+        status_port,
+        once,
+        mut config_updates,
+        shutdown,
+    } = params;
+
+    let mut jobs = build_jobs(&categories, &interval, &schedules)?;
+
+    print_title(&t!("cli.watch.starting"), '=');
+
+    let metrics_server = match metrics_port {
+        Some(port) => {
+            let (addr, handle) = start_metrics_server(port).await?;
+            say!("{}", t!("cli.common.metrics_endpoint", addr = addr));
+            Some(handle)
+        }
+        None => None,
+    };
+
+    // Same database layout as `baram crawl`, so both share deduplication
+    let db_path = output.parent().unwrap_or(&output).join("crawl.db");
+    let db_config = DatabaseConfig {
+        sqlite_path: db_path.clone(),
+        postgres_url: String::new(),
+        pool_size: 5,
+        store_bodies: config.database.store_bodies,
+    };
+    let mut db = Database::new(&db_config)?;
+    db.init_sqlite(&db_path)?;
+    let mut series = TimeSeriesStore::open(&db_path)?;
+
+    // Keyword alert baselines start from the counts already in the series
+    let keyword_alerts = start_keyword_alerts(&config.notifications)?;
+    if let Some(alerts) = &keyword_alerts {
+        if let Err(e) = alerts.seed(&series, Utc::now()) {
+            tracing::warn!(error = %e, "Failed to load keyword alert baseline");
+        }
+    }
+
+    for job in &mut jobs {
+        if let Some(value) = db.load_checkpoint(&job.checkpoint_key())? {
+            job.restore(&value);
+        }
+    }
+
+    let storage = ArticleStorage::with_format(&output, skip_existing, format)?;
+    let mut crawler = Crawler::new(config.clone())?.with_http_cache(HttpCache::open(&db_path)?);
+    if download_images {
+        crawler = crawler.with_assets(AssetDownloader::for_markdown_dir(&output));
+    }
+    let list_fetcher = NaverFetcher::with_rate_limit(config.crawler.rate_limit)
+        .context("Failed to create fetcher")?
+        .with_policy(crawler.fetch_policy());
+    let list_crawler = if with_comments {
+        let fetcher = NaverFetcher::with_rate_limit(config.crawler.rate_limit)
+            .context("Failed to create comment client")?
+            .with_policy(crawler.fetch_policy());
+        let site = NaverSite::new().with_comments(CommentClient::with_fetcher(fetcher));
+        NewsListCrawler::with_site(list_fetcher, Arc::new(site))
+    } else {
+        NewsListCrawler::new(list_fetcher)
+    };
+    let category_crawler = CategoryCrawler {
+        crawler: &crawler,
+        site: list_crawler.site(),
+        storage: &storage,
+        db: &db,
+        list_crawler: &list_crawler,
+        url_extractor: UrlExtractor::new(),
+        skip_existing,
+        instance: METRICS_INSTANCE,
+        progress,
+        keyword_alerts: keyword_alerts.as_ref(),
+        indexer: None,
+        shutdown: &shutdown,
+    };
+
+    say!(
+        "{}",
+        t!("cli.common.output_directory", path = output.display())
+    );
+    say!("{}", t!("cli.common.database", path = db_path.display()));
+    say!();
+    print_plan(&jobs, Utc::now());
+
+    let status = Arc::new(RwLock::new(WatchStatus::new(&jobs, Utc::now())));
+    let status_server = match status_port {
+        Some(port) => {
+            let (addr, handle) = start_status_server(port, Arc::clone(&status)).await?;
+            say!("{}", t!("cli.watch.status_endpoint", addr = addr));
+            Some(handle)
+        }
+        None => None,
+    };
+
+    let mut crawler_config = config.crawler.clone();
+    let mut rate_limit = crawler_config.rate_limit;
+    let mut state = CrawlState::new();
+
+    if once {
+        say!("\n{}", t!("cli.watch.once"));
+        for job in &mut jobs {
+            let max_articles = prepare_run(
+                &category_crawler,
+                &config,
+                &crawler_config,
+                &mut rate_limit,
+                job.category,
+                max_articles,
+            );
+            if shutdown.is_requested() {
+                break;
+            }
+            update_status(&status, |s| s.start_run(job.category));
+            run_job(
+                &category_crawler,
+                job,
+                max_articles,
+                &mut state,
+                &mut series,
+            )
+            .await?;
+            finish_status(&status, job, &state);
+        }
+    } else {
+        say!("\n{}", t!("cli.common.press_ctrl_c"));
+        let trigger = Arc::new(
+            ScheduleTrigger::new(
+                TriggerConfig {
+                    trigger_on_startup: false,
+                    enable_hourly_triggers: false,
+                    ..Default::default()
+                },
+                Arc::new(ScheduleCache::new()),
+            )?
+            .with_crawl_jobs(jobs.clone()),
+        );
+        let mut events = trigger.subscribe();
+        let runner = tokio::spawn({
+            let trigger = Arc::clone(&trigger);
+            async move { trigger.start().await }
+        });
+
+        loop {
+            if !trigger.has_crawls_left(Utc::now()).await {
+                say!("{}", t!("cli.watch.no_runs_left"));
+                break;
+            }
+            let event = tokio::select! {
+                () = shutdown.requested() => break,
+                event = events.recv() => event,
+            };
+            let category = match event {
+                Ok(TriggerEvent::CategoryCrawl { category, .. }) => category,
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Missed schedule trigger events");
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+            let Some(job) = jobs.iter_mut().find(|job| job.category == category) else {
+                continue;
+            };
+
+            if let Some(updates) = &mut config_updates {
+                if updates.has_changed().unwrap_or(false) {
+                    crawler_config = updates.borrow_and_update().crawler.clone();
+                }
+            }
+            let max_articles = prepare_run(
+                &category_crawler,
+                &config,
+                &crawler_config,
+                &mut rate_limit,
+                job.category,
+                max_articles,
+            );
+            update_status(&status, |s| s.start_run(job.category));
+            let result = run_job(
+                &category_crawler,
+                job,
+                max_articles,
+                &mut state,
+                &mut series,
+            )
+            .await;
+            finish_status(&status, job, &state);
+            trigger
+                .finish_crawl(category, job.last_run.unwrap_or_else(Utc::now))
+                .await;
+            result?;
+            if shutdown.is_requested() {
+                break;
+            }
+        }
+
+        trigger.stop().await;
+        if let Ok(Err(e)) = runner.await {
+            tracing::warn!(error = %e, "Schedule trigger stopped");
+        }
+    }
+
+    say!();
+    print_title(&t!("cli.watch.summary"), '=');
+    say!(
+        "{}",
+        t!(
+            "cli.common.total_processed",
+            count = state.stats().total_crawled
+        )
+    );
+    say!(
+        "{}",
+        t!("cli.common.failed", count = state.stats().total_errors)
+    );
+
+    if let Some(alerts) = &keyword_alerts {
+        let outbox = alerts.manager().write().await.take_digests();
+        outbox.send().await;
+    }
+    if let Some(handle) = metrics_server {
+        handle.abort();
+    }
+    if let Some(handle) = status_server {
+        handle.abort();
+    }
+
+    Ok(())
+}
+
+fn update_status(status: &RwLock<WatchStatus>, update: impl FnOnce(&mut WatchStatus)) {
+    update(&mut status.write().unwrap_or_else(PoisonError::into_inner));
+}
+
+fn finish_status(status: &RwLock<WatchStatus>, job: &WatchJob, state: &CrawlState) {
+    let stats = state.stats();
+    update_status(status, |s| {
+        s.finish_run(job, Utc::now(), stats.total_crawled, stats.total_errors)
+    });
+}
+
+/// Serve `status` as JSON at `/status`, with `/health` for liveness probes
+async fn start_status_server(
+    port: u16,
+    status: Arc<RwLock<WatchStatus>>,
+) -> Result<(SocketAddr, tokio::task::JoinHandle<()>)> {
+    let app = Router::new()
+        .route("/status", get(status_handler))
+        .route("/health", get(|| async { "ok" }))
+        .with_state(status);
+    let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind status endpoint on {addr}"))?;
+    let local_addr = listener.local_addr()?;
+    tracing::info!(addr = %local_addr, "Status endpoint listening");
+
+    let handle = tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            tracing::warn!(error = %e, "Status endpoint stopped");
+        }
+    });
+    Ok((local_addr, handle))
+}
+
+async fn status_handler(State(status): State<Arc<RwLock<WatchStatus>>>) -> Json<WatchStatus> {
+    Json(
+        status
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+    )
+}
+
+/// Switch to the rate limit of the next run of `category` and return its
+/// article budget
+///
+/// The `[category.<name>]` block overrides the rate limit and `--max`; the
+/// category weight then scales the budget. `rate_limit` is the rate in use,
+/// which `crawler_config` may have changed on reload. A rate the crawler
+/// rejects is logged and the rate in use is kept, so a bad reload cannot end
+/// the watch.
+fn prepare_run(
+    category_crawler: &CategoryCrawler<'_>,
+    config: &Config,
+    crawler_config: &CrawlerConfig,
+    rate_limit: &mut f64,
+    category: NewsCategory,
+    max_articles: usize,
+) -> usize {
+    let overrides = config.category(category);
+    let rate = overrides.rate_limit.unwrap_or(crawler_config.rate_limit);
+    if rate != *rate_limit {
+        match category_crawler.set_rate_limit(rate) {
+            Ok(()) => *rate_limit = rate,
+            Err(e) => tracing::warn!(
+                category = %category,
+                rate_limit = rate,
+                current = *rate_limit,
+                error = %e,
+                "Keeping the current rate limit"
+            ),
+        }
+    }
+    let budget = overrides.max_articles.unwrap_or(max_articles);
+    crawler_config.category_budget(category, budget)
+}
+
+/// Crawl one category, checkpoint the run and evaluate keyword alerts
+///
+/// A run that fails (e.g. the article list cannot be fetched) is logged and
+/// retried at the next scheduled time rather than ending the watch; it is not
+/// checkpointed, so a restart retries it right away.
+async fn run_job(
+    category_crawler: &CategoryCrawler<'_>,
+    job: &mut WatchJob,
+    max_articles: usize,
+    state: &mut CrawlState,
+    series: &mut TimeSeriesStore,
+) -> Result<()> {
+    let started = Utc::now();
+    let mut result = Ok(0);
+    let dates = if max_articles == 0 {
+        tracing::info!(category = %job.category, "Skipping run, the category budget is 0");
+        Vec::new()
+    } else {
+        job.crawl_dates(started)
+    };
+    for date in dates {
+        if category_crawler.shutdown.is_requested() {
+            break;
+        }
+        let date = date.format("%Y%m%d").to_string();
+        match category_crawler
+            .crawl(job.category, &date, max_articles, state, series)
+            .await
+        {
+            Ok(stats) => result = result.map(|total| total + stats.saved),
+            Err(e) => {
+                result = Err(e);
+                break;
+            }
+        }
+    }
+
+    let finished = Utc::now();
+    match result {
+        Ok(saved) if category_crawler.shutdown.is_requested() => {
+            tracing::info!(category = %job.category, saved, "Scheduled crawl interrupted");
+        }
+        Ok(saved) => {
+            category_crawler
+                .db
+                .save_checkpoint(&job.checkpoint_key(), &WatchJob::checkpoint_value(finished))?;
+            tracing::info!(category = %job.category, saved, "Scheduled crawl finished");
+        }
+        Err(e) => {
+            tracing::warn!(category = %job.category, error = %e, "Scheduled crawl failed");
+        }
+    }
+    job.last_run = Some(finished);
+
+    if let Some(alerts) = category_crawler.keyword_alerts {
+        alerts.evaluate_now().await;
+    }
+
+    let next = job.next_run(finished);
+    metrics::update_schedule(
+        METRICS_INSTANCE,
+        job.category.as_str(),
+        Some(finished.timestamp()),
+        next.map(|t| t.timestamp()),
+    );
+    if let Some(next) = next {
+        say!(
+            "{}",
+            t!(
+                "cli.watch.next_run",
+                category = job.category,
+                time = format_local(next)
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Build the job list from `--category`, `--interval` and `--schedule`
+///
+/// A `--schedule` for a category overrides `--interval` for it. At most one
+/// `--schedule` without a category replaces `--interval`. Every category is
+/// watched without `--category`, unless only `category=schedule` are given.
+fn build_jobs(
+    categories: &[String],
+    interval: &str,
+    schedules: &[String],
+) -> Result<Vec<WatchJob>> {
+    let (schedules, plain): (Vec<&String>, Vec<&String>) =
+        schedules.iter().partition(|s| s.contains('='));
+    let (interval, flag) = match plain.as_slice() {
+        [] => (interval, "--interval"),
+        [schedule] => (schedule.as_str(), "--schedule"),
+        _ => bail!("{}", t!("cli.watch.several_schedules")),
+    };
+    let default_schedule: WatchSchedule = interval
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid {flag}: {e}"))?;
+
+    let mut jobs: Vec<WatchJob> = Vec::new();
+    let mut add = |job: WatchJob| match jobs.iter_mut().find(|j| j.category == job.category) {
+        Some(existing) => *existing = job,
+        None => jobs.push(job),
+    };
+
+    let defaults = if categories.is_empty() && (schedules.is_empty() || !plain.is_empty()) {
+        NewsCategory::all()
+    } else {
+        categories
+            .iter()
+            .map(|c| parse_category(c))
+            .collect::<Result<_>>()?
+    };
+    for category in defaults {
+        add(WatchJob::new(category, default_schedule.clone()));
+    }
+    for spec in schedules {
+        add(WatchJob::parse_spec(spec).map_err(|e| anyhow::anyhow!("Invalid --schedule: {e}"))?);
+    }
+
+    if jobs.is_empty() {
+        bail!("{}", t!("cli.watch.no_categories"));
+    }
+    Ok(jobs)
+}
+
+fn print_plan(jobs: &[WatchJob], now: DateTime<Utc>) {
+    say!("{}", t!("cli.watch.schedule"));
+    let never = t!("cli.watch.never");
+    for job in jobs {
+        let last = job.last_run.map_or(never.to_string(), format_local);
+        let next = job.next_run(now).map_or(never.to_string(), format_local);
+        say!(
+            "  {:<9} {:<24} {}",
+            job.category.as_str(),
+            job.schedule.to_string(),
+            t!("cli.watch.plan", last = last, next = next)
+        );
+        metrics::update_schedule(
+            METRICS_INSTANCE,
+            job.category.as_str(),
+            job.last_run.map(|t| t.timestamp()),
+            job.next_run(now).map(|t| t.timestamp()),
+        );
+    }
+}
+
+fn format_local(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_build_jobs_defaults_to_all_categories() {
+        let jobs = build_jobs(&[], "30m", &[]).unwrap();
+        assert_eq!(jobs.len(), NewsCategory::all().len());
+        assert!(jobs.iter().all(|j| j.schedule.to_string() == "every 30m"));
+    }
+
+    #[test]
+    fn test_build_jobs_schedule_overrides_interval() {
+        let jobs = build_jobs(
+            &strings(&["politics", "economy"]),
+            "1h",
+            &strings(&["economy=*/10 * * * *", "it=2h"]),
+        )
+        .unwrap();
+
+        let schedules: Vec<(NewsCategory, String)> = jobs
+            .iter()
+            .map(|j| (j.category, j.schedule.to_string()))
+            .collect();
+        assert_eq!(schedules.len(), 3);
+        assert_eq!(
+            schedules[0],
+            (NewsCategory::Politics, "every 1h".to_string())
+        );
+        assert!(schedules[1].1.starts_with("cron"));
+        assert_eq!(schedules[2], (NewsCategory::IT, "every 2h".to_string()));
+    }
+
+    #[test]
+    fn test_build_jobs_plain_schedule_replaces_interval() {
+        let jobs = build_jobs(&[], "30m", &strings(&["0 */2 * * *", "politics=10m"])).unwrap();
+        assert_eq!(jobs.len(), NewsCategory::all().len());
+        let politics = jobs
+            .iter()
+            .find(|j| j.category == NewsCategory::Politics)
+            .unwrap();
+        assert_eq!(politics.schedule.to_string(), "every 10m");
+        assert!(jobs
+            .iter()
+            .filter(|j| j.category != NewsCategory::Politics)
+            .all(|j| j.schedule.to_string().starts_with("cron")));
+
+        assert!(build_jobs(&[], "30m", &strings(&["1h", "2h"])).is_err());
+    }
+
+    #[test]
+    fn test_build_jobs_rejects_invalid_input() {
+        assert!(build_jobs(&[], "often", &[]).is_err());
+        assert!(build_jobs(&strings(&["sports"]), "1h", &[]).is_err());
+        assert!(build_jobs(&[], "1h", &strings(&["politics"])).is_err());
+    }
+}
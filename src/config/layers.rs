@@ -0,0 +1,554 @@
+//! Layered config loading
+//!
+//! [`ConfigLoader`] builds the effective config in one pass from four layers,
+//! each overriding the one before:
+//!
+//! 1. built-in defaults
+//! 2. the config file, with `[profile.<name>]` applied
+//! 3. environment variables (see [`ENV_VARS`]), each also readable from a
+//!    file named by `<VAR>_FILE`
+//! 4. command-line overrides (`--set key=value`, `--log-format`, ...)
+//!
+//! The file is still loaded strictly: a file that is present must be a
+//! complete config. `file:` and `secret:` credential references are resolved
+//! last (see [`super::secrets`]). The loader records where every value came from, for
+//! `baram config show --resolved`:
+//!
+//! ```
+//! use baram::config::layers::{ConfigLoader, ValueSource};
+//!
+//! let resolved = ConfigLoader::new()
+//!     .set("--set", "crawler.rate_limit", toml::Value::Float(0.5))
+//!     .unwrap()
+//!     .load()
+//!     .unwrap();
+//! assert_eq!(resolved.config.crawler.rate_limit, 0.5);
+//! assert_eq!(
+//!     resolved.source("crawler.rate_limit"),
+//!     Some(&ValueSource::Cli("--set".to_string()))
+//! );
+//! assert_eq!(resolved.source("crawler.max_concurrent_requests"), Some(&ValueSource::Default));
+//! ```
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::secrets::{self, SecretsConfig};
+use super::{check, take_profile, Config};
+
+/// Environment variables read by the env layer, in the order they are
+/// applied (a later variable for the same key wins)
+///
+/// Every variable can instead be given as `<VAR>_FILE`, the path of a file
+/// holding the value.
+pub const ENV_VARS: &[(&str, &str, EnvKind)] = &[
+    (
+        "BARAM_MAX_CONCURRENT_REQUESTS",
+        "crawler.max_concurrent_requests",
+        EnvKind::Integer,
+    ),
+    ("BARAM_RATE_LIMIT", "crawler.rate_limit", EnvKind::Float),
+    (
+        "BARAM_REQUEST_TIMEOUT",
+        "crawler.request_timeout_secs",
+        EnvKind::Integer,
+    ),
+    ("BARAM_USER_AGENT", "crawler.user_agent", EnvKind::String),
+    ("BARAM_SQLITE_PATH", "database.sqlite_path", EnvKind::String),
+    ("DATABASE_URL", "database.postgres_url", EnvKind::String),
+    ("POSTGRES_URL", "database.postgres_url", EnvKind::String),
+    ("OPENSEARCH_URL", "opensearch.url", EnvKind::String),
+    ("OPENSEARCH_INDEX", "opensearch.index_name", EnvKind::String),
+    ("OPENSEARCH_USER", "opensearch.username", EnvKind::String),
+    (
+        "OPENSEARCH_USERNAME",
+        "opensearch.username",
+        EnvKind::String,
+    ),
+    (
+        "OPENSEARCH_PASSWORD",
+        "opensearch.password",
+        EnvKind::String,
+    ),
+    ("BARAM_LOG_LEVEL", "logging.level", EnvKind::String),
+    ("BARAM_LOG_FORMAT", "logging.format", EnvKind::String),
+    (
+        "BARAM_PUSHGATEWAY_URL",
+        "metrics.pushgateway_url",
+        EnvKind::String,
+    ),
+    (
+        "BARAM_METRICS_INSTANCE",
+        "metrics.instance",
+        EnvKind::String,
+    ),
+];
+
+/// Type an environment variable is parsed as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvKind {
+    String,
+    Integer,
+    Float,
+}
+
+/// Where an effective config value came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "layer", content = "name", rename_all = "lowercase")]
+pub enum ValueSource {
+    /// Built-in default
+    Default,
+    /// Base sections of the config file
+    File(PathBuf),
+    /// `[profile.<name>]` of the config file
+    Profile(String),
+    /// Environment variable
+    Env(String),
+    /// Command-line flag
+    Cli(String),
+}
+
+impl std::fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::File(path) => write!(f, "file {}", path.display()),
+            Self::Profile(name) => write!(f, "profile {name}"),
+            Self::Env(var) => write!(f, "env {var}"),
+            Self::Cli(flag) => write!(f, "flag {flag}"),
+        }
+    }
+}
+
+/// Builds a [`Config`] from defaults, file, environment and CLI layers
+#[derive(Debug, Clone, Default)]
+pub struct ConfigLoader {
+    file: Option<PathBuf>,
+    profile: Option<String>,
+    env: bool,
+    overrides: Vec<(String, String, toml::Value)>,
+}
+
+impl ConfigLoader {
+    /// Loader with only the defaults layer
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load `path`, overlaying `[profile.<profile>]` when a profile is given
+    #[must_use]
+    pub fn file(mut self, path: impl Into<PathBuf>, profile: Option<&str>) -> Self {
+        self.file = Some(path.into());
+        self.profile = profile.map(str::to_string);
+        self
+    }
+
+    /// Read the variables in [`ENV_VARS`]
+    #[must_use]
+    pub fn with_env(mut self) -> Self {
+        self.env = true;
+        self
+    }
+
+    /// Override `key` (e.g. `crawler.rate_limit`) from the command-line flag
+    /// `flag`
+    pub fn set(mut self, flag: &str, key: &str, value: toml::Value) -> Result<Self> {
+        check::check_key(key)
+            .map_err(|hint| anyhow::anyhow!("{flag}: unknown key {key}. {hint}"))?;
+        self.overrides
+            .push((flag.to_string(), key.to_string(), value));
+        Ok(self)
+    }
+
+    /// The config file, if one is loaded
+    pub fn file_path(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Build the config
+    pub fn load(&self) -> Result<ResolvedConfig> {
+        let mut table = match toml::Value::try_from(Config::default())? {
+            toml::Value::Table(table) => table,
+            _ => unreachable!("a config serializes to a table"),
+        };
+        let mut sources: BTreeMap<String, ValueSource> = flatten(&table)
+            .into_keys()
+            .map(|key| (key, ValueSource::Default))
+            .collect();
+
+        if let Some(path) = &self.file {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+            self.merge_file(&mut table, &mut sources, path, &content)
+                .with_context(|| format!("Failed to parse TOML config file: {}", path.display()))?;
+        }
+
+        if self.env {
+            for (var, key, kind) in ENV_VARS {
+                let Some((var, raw)) = secrets::read_env(var)? else {
+                    continue;
+                };
+                let value = kind
+                    .parse(&raw)
+                    .with_context(|| format!("Invalid {var}: {raw}"))?;
+                insert(&mut table, key, value);
+                sources.insert(key.to_string(), ValueSource::Env(var));
+            }
+        }
+
+        for (flag, key, value) in &self.overrides {
+            insert(&mut table, key, value.clone());
+            set_source(&mut sources, key, &ValueSource::Cli(flag.clone()), &table);
+        }
+
+        let hook: SecretsConfig = match table.get(super::SECRETS_SECTION) {
+            Some(section) => section
+                .clone()
+                .try_into()
+                .context("Invalid [secrets] section")?,
+            None => SecretsConfig::default(),
+        };
+        secrets::resolve(&mut table, &hook)?;
+
+        let mut config: Config = toml::Value::Table(table)
+            .try_into()
+            .context("Invalid config value")?;
+        if self.file.is_some() {
+            config.profile.clone_from(&self.profile);
+        }
+        Ok(ResolvedConfig { config, sources })
+    }
+
+    fn merge_file(
+        &self,
+        table: &mut toml::Table,
+        sources: &mut BTreeMap<String, ValueSource>,
+        path: &Path,
+        content: &str,
+    ) -> Result<()> {
+        // A present file must be complete on its own, profile applied
+        Config::from_toml(content, self.profile.as_deref())?;
+
+        let mut file: toml::Table = toml::from_str(content)?;
+        let overlay = match &self.profile {
+            Some(name) => Some(take_profile(&mut file, name)?),
+            None => {
+                file.remove(super::PROFILE_SECTION);
+                None
+            }
+        };
+
+        let source = ValueSource::File(path.to_path_buf());
+        for key in flatten(&file).into_keys() {
+            sources.insert(key, source.clone());
+        }
+        super::merge_tables(table, file);
+
+        if let (Some(overlay), Some(name)) = (overlay, &self.profile) {
+            let source = ValueSource::Profile(name.clone());
+            for key in flatten(&overlay).into_keys() {
+                set_source(sources, &key, &source, table);
+            }
+            super::merge_tables(table, overlay);
+        }
+        Ok(())
+    }
+}
+
+/// Effective config and the source of each of its values
+#[derive(Debug, Clone)]
+pub struct ResolvedConfig {
+    pub config: Config,
+    /// Source of every value, by dotted key
+    pub sources: BTreeMap<String, ValueSource>,
+}
+
+impl ResolvedConfig {
+    /// Where the value of `key` came from
+    pub fn source(&self, key: &str) -> Option<&ValueSource> {
+        self.sources.get(key)
+    }
+
+    /// Every value of the effective config by dotted key, with its source
+    ///
+    /// Secrets (passwords, tokens, webhook URLs) are masked.
+    pub fn values(&self) -> Vec<(String, toml::Value, ValueSource)> {
+        let Ok(toml::Value::Table(mut table)) = toml::Value::try_from(&self.config) else {
+            return Vec::new();
+        };
+        redact(&mut table);
+        flatten(&table)
+            .into_iter()
+            .map(|(key, value)| {
+                let source = self.source(&key).cloned().unwrap_or(ValueSource::Default);
+                (key, value, source)
+            })
+            .collect()
+    }
+
+    /// The effective config as TOML, with secrets masked
+    pub fn to_toml(&self) -> Result<String> {
+        let mut table = match toml::Value::try_from(&self.config)? {
+            toml::Value::Table(table) => table,
+            _ => unreachable!("a config serializes to a table"),
+        };
+        redact(&mut table);
+        Ok(toml::to_string(&table)?)
+    }
+}
+
+impl EnvKind {
+    fn parse(self, raw: &str) -> Result<toml::Value> {
+        Ok(match self {
+            Self::String => toml::Value::String(raw.to_string()),
+            Self::Integer => {
+                toml::Value::Integer(raw.trim().parse().context("expected an integer")?)
+            }
+            Self::Float => toml::Value::Float(raw.trim().parse().context("expected a number")?),
+        })
+    }
+}
+
+/// Parse a `--set key=value` argument
+///
+/// The value is read as a TOML value (`2.5`, `true`, `["a", "b"]`) and
+/// falls back to a plain string.
+pub fn parse_set(arg: &str) -> Result<(String, toml::Value)> {
+    let (key, raw) = arg
+        .split_once('=')
+        .with_context(|| format!("--set expects key=value, got {arg}"))?;
+    let value = toml::from_str::<toml::Table>(&format!("value = {raw}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw.to_string()));
+    Ok((key.trim().to_string(), value))
+}
+
+/// Leaf values of a table by dotted key; arrays are leaves
+pub(crate) fn flatten(table: &toml::Table) -> BTreeMap<String, toml::Value> {
+    let mut values = BTreeMap::new();
+    flatten_into(&mut values, "", table);
+    values
+}
+
+fn flatten_into(values: &mut BTreeMap<String, toml::Value>, prefix: &str, table: &toml::Table) {
+    for (key, value) in table {
+        let key = format!("{prefix}{key}");
+        match value {
+            toml::Value::Table(table) => flatten_into(values, &format!("{key}."), table),
+            value => {
+                values.insert(key, value.clone());
+            }
+        }
+    }
+}
+
+/// Set the value at a dotted key, creating tables on the way
+fn insert(table: &mut toml::Table, key: &str, value: toml::Value) {
+    let mut current = table;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            current.insert(part.to_string(), value);
+            return;
+        }
+        let entry = current
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        let toml::Value::Table(next) = entry else {
+            unreachable!()
+        };
+        current = next;
+    }
+}
+
+/// Record `source` for `key` and, when it is a table, every key below it
+fn set_source(
+    sources: &mut BTreeMap<String, ValueSource>,
+    key: &str,
+    source: &ValueSource,
+    table: &toml::Table,
+) {
+    let prefix = format!("{key}.");
+    sources.retain(|existing, _| !existing.starts_with(&prefix));
+    let below: Vec<String> = flatten(table)
+        .into_keys()
+        .filter(|existing| existing.starts_with(&prefix))
+        .collect();
+    if below.is_empty() {
+        sources.insert(key.to_string(), source.clone());
+    }
+    for existing in below {
+        sources.insert(existing, source.clone());
+    }
+}
+
+const MASK: &str = "********";
+
+/// Mask passwords, tokens and webhook URLs, and passwords inside URLs
+fn redact(table: &mut toml::Table) {
+    for (key, value) in table.iter_mut() {
+        match value {
+            toml::Value::Table(inner) => redact(inner),
+            toml::Value::Array(items) => {
+                for item in items {
+                    if let toml::Value::Table(inner) = item {
+                        redact(inner);
+                    }
+                }
+            }
+            toml::Value::String(s) => {
+                let secret = ["password", "token", "secret"]
+                    .iter()
+                    .any(|word| key.contains(word))
+                    || key == "webhook_url";
+                if secret {
+                    *s = MASK.to_string();
+                } else if let Ok(mut url) = url::Url::parse(s) {
+                    if url.password().is_some() && url.set_password(Some(MASK)).is_ok() {
+                        *s = url.to_string();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    fn write_config(dir: &Path, extra: &str) -> PathBuf {
+        let path = dir.join("config.toml");
+        let base = toml::to_string(&Config::default()).unwrap();
+        std::fs::write(&path, format!("{base}\n{extra}")).unwrap();
+        path
+    }
+
+    #[test]
+    #[serial]
+    fn test_layer_precedence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(
+            dir.path(),
+            "[profile.prod.opensearch]\nindex_name = \"baram-prod\"\nurl = \"https://search.prod:9200\"\n",
+        );
+        let text = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("rate_limit = 2.0", "rate_limit = 3.0");
+        std::fs::write(&path, text).unwrap();
+
+        std::env::set_var("OPENSEARCH_URL", "http://env:9200");
+        std::env::set_var("BARAM_RATE_LIMIT", "4");
+        let resolved = ConfigLoader::new()
+            .file(&path, Some("prod"))
+            .with_env()
+            .set("--set", "crawler.rate_limit", toml::Value::Float(5.0))
+            .unwrap()
+            .load();
+        std::env::remove_var("OPENSEARCH_URL");
+        std::env::remove_var("BARAM_RATE_LIMIT");
+        let resolved = resolved.unwrap();
+
+        let config = &resolved.config;
+        assert_eq!(config.profile.as_deref(), Some("prod"));
+        assert_eq!(config.crawler.rate_limit, 5.0);
+        assert_eq!(config.opensearch.url, "http://env:9200");
+        assert_eq!(config.opensearch.index_name, "baram-prod");
+        assert_eq!(
+            resolved.source("crawler.rate_limit"),
+            Some(&ValueSource::Cli("--set".to_string()))
+        );
+        assert_eq!(
+            resolved.source("opensearch.url"),
+            Some(&ValueSource::Env("OPENSEARCH_URL".to_string()))
+        );
+        assert_eq!(
+            resolved.source("opensearch.index_name"),
+            Some(&ValueSource::Profile("prod".to_string()))
+        );
+        assert_eq!(
+            resolved.source("database.pool_size"),
+            Some(&ValueSource::File(path.clone()))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_invalid_env_value() {
+        std::env::set_var("BARAM_MAX_CONCURRENT_REQUESTS", "many");
+        let result = ConfigLoader::new().with_env().load();
+        std::env::remove_var("BARAM_MAX_CONCURRENT_REQUESTS");
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("BARAM_MAX_CONCURRENT_REQUESTS"), "{err}");
+    }
+
+    #[test]
+    fn test_incomplete_file_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[crawler]\nrate_limit = 1.0\n").unwrap();
+        assert!(ConfigLoader::new().file(&path, None).load().is_err());
+    }
+
+    #[test]
+    fn test_parse_set() {
+        assert_eq!(
+            parse_set("crawler.rate_limit=2.5").unwrap(),
+            ("crawler.rate_limit".to_string(), toml::Value::Float(2.5))
+        );
+        assert_eq!(
+            parse_set("opensearch.url=http://x:9200").unwrap().1,
+            toml::Value::String("http://x:9200".to_string())
+        );
+        assert!(parse_set("crawler.rate_limit").is_err());
+        assert!(ConfigLoader::new()
+            .set("--set", "crawler.rate_limt", toml::Value::Float(1.0))
+            .is_err());
+
+        let resolved = ConfigLoader::new()
+            .set(
+                "--set",
+                "crawler.category_weights.it",
+                toml::Value::Float(0.5),
+            )
+            .unwrap()
+            .load()
+            .unwrap();
+        assert_eq!(resolved.config.crawler.category_weights["it"], 0.5);
+    }
+
+    #[test]
+    fn test_values_mask_secrets() {
+        let mut config = Config::default();
+        config.opensearch.password = Some("hunter2".to_string());
+        config.database.postgres_url = "postgresql://baram:hunter2@db/baram".to_string();
+        let resolved = ResolvedConfig {
+            config,
+            sources: BTreeMap::new(),
+        };
+
+        let values = resolved.values();
+        let value = |key: &str| {
+            values
+                .iter()
+                .find(|(k, _, _)| k == key)
+                .map(|(_, v, _)| v.as_str().unwrap().to_string())
+                .unwrap()
+        };
+        assert_eq!(value("opensearch.password"), MASK);
+        assert_eq!(
+            value("database.postgres_url"),
+            "postgresql://baram:********@db/baram"
+        );
+        assert!(!resolved.to_toml().unwrap().contains("hunter2"));
+    }
+}
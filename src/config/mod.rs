@@ -1,13 +1,72 @@
 //! Configuration management for baram crawler
 //!
 //! This module handles loading and validating configuration from environment variables,
-//! files, and command-line arguments.
+//! files, and command-line arguments. [`ConfigLoader`] layers them in that
+//! order of precedence: defaults < file < environment < command line.
+//!
+//! A config file can define named profiles under `[profile.<name>]`. Each
+//! profile holds the same sections as the file itself and only needs the keys
+//! that differ; `--profile <name>` overlays them on the base sections, so one
+//! file can point staging and production runs at different databases,
+//! indices and endpoints:
+//!
+//! ```
+//! use baram::config::Config;
+//!
+//! let base = toml::to_string(&Config::default()).unwrap();
+//! let file = format!("{base}\n[profile.staging.opensearch]\nindex_name = \"baram-staging\"\n");
+//!
+//! let staging = Config::from_toml(&file, Some("staging")).unwrap();
+//! assert_eq!(staging.opensearch.index_name, "baram-staging");
+//! assert_eq!(staging.opensearch.url, "http://localhost:9200");
+//! assert_eq!(staging.profile.as_deref(), Some("staging"));
+//! assert!(Config::from_toml(&file, Some("prod")).is_err());
+//! ```
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use crate::error::ErrorClass;
+use crate::models::NewsCategory;
+use crate::notifications::NotificationsConfig;
+
+pub mod check;
+pub mod layers;
+pub mod reload;
+pub mod sample;
+pub mod secrets;
+
+pub use check::{check_file, ConfigCheck, ConfigIssue, IssueSeverity};
+pub use layers::{ConfigLoader, ResolvedConfig, ValueSource};
+pub use sample::sample;
+pub use secrets::SecretsConfig;
+
+/// A config value rejected by [`Config::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Dotted key of the value, e.g. `crawler.rate_limit`
+    pub key: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Check that `url` parses as an http(s) URL
+fn http_url(url: &str) -> Result<(), String> {
+    match url::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+        Ok(_) => Err(format!("not an http(s) URL: {url}")),
+        Err(e) => Err(format!("not a valid URL ({e}): {url}")),
+    }
+}
+
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -22,8 +81,48 @@ pub struct Config {
 
     /// Logging configuration
     pub logging: LoggingConfig,
+
+    /// Notification channels and alert rules
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
+    /// Prometheus metrics export
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    /// Hook that fetches `secret:<name>` credentials from an external store
+    #[serde(default, skip_serializing_if = "SecretsConfig::is_empty")]
+    pub secrets: SecretsConfig,
+
+    /// Stopword, user dictionary and synonym files for tokenizing and search
+    #[serde(default, skip_serializing_if = "AnalysisConfig::is_empty")]
+    pub analysis: AnalysisConfig,
+
+    /// Per-category overrides (`[category.<name>]`), by category name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub category: BTreeMap<String, CategoryConfig>,
+
+    /// Profile the config was loaded with, if any
+    #[serde(skip)]
+    pub profile: Option<String>,
 }
 
+/// Top-level table holding the named profiles
+pub const PROFILE_SECTION: &str = "profile";
+
+/// `(section, key)` pairs a profile cannot override
+///
+/// `crawl`, `watch` and the commands reading their results keep the crawl
+/// database next to `--output`, so a per-profile `sqlite_path` would only
+/// move part of the data. Give each profile its own `--output` instead.
+pub const PROFILE_FIXED_KEYS: &[(&str, &str)] = &[("database", "sqlite_path")];
+
+/// Top-level table holding the secrets hook
+pub const SECRETS_SECTION: &str = "secrets";
+
+/// Top-level table holding the per-category blocks
+pub const CATEGORY_SECTION: &str = "category";
+
 /// Crawler-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrawlerConfig {
@@ -39,8 +138,354 @@ pub struct CrawlerConfig {
     /// User agent string
     pub user_agent: String,
 
+    /// User agents `Crawler::fetch()` picks from at random for each request,
+    /// instead of `user_agent`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub user_agents: Vec<String>,
+
     /// Enable cookie persistence
     pub enable_cookies: bool,
+
+    /// Stricter limits (requests per second, may be below 1) for each host
+    /// of a domain and its subdomains, on top of `rate_limit`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub domain_rate_limits: BTreeMap<String, f64>,
+
+    /// Share of the per-run article budget for each category (default 1.0;
+    /// 0 skips the category)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub category_weights: BTreeMap<String, f64>,
+
+    /// robots.txt compliance of `Crawler::fetch()`
+    #[serde(default)]
+    pub robots: RobotsConfig,
+
+    /// Retries of `Crawler::fetch()` on HTTP 429, 5xx and transport errors
+    #[serde(default)]
+    pub retry: FetchRetryConfig,
+
+    /// Per-host circuit breaker of `Crawler::fetch()`
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+
+    /// Proxies `Crawler::fetch()` rotates through
+    #[serde(default)]
+    pub proxy: ProxyConfig,
+
+    /// Which failed URLs `baram retry-failed` attempts again
+    #[serde(default)]
+    pub dead_letter: DeadLetterConfig,
+}
+
+/// robots.txt compliance settings (`[crawler.robots]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RobotsConfig {
+    /// Obey `Disallow` rules and `Crawl-delay` (`false` ignores robots.txt)
+    #[serde(default = "default_obey_robots")]
+    pub obey: bool,
+
+    /// How long a fetched robots.txt is reused before it is fetched again (seconds)
+    #[serde(default = "default_robots_cache_ttl")]
+    pub cache_ttl_secs: u64,
+}
+
+fn default_obey_robots() -> bool {
+    true
+}
+
+fn default_robots_cache_ttl() -> u64 {
+    86_400
+}
+
+impl Default for RobotsConfig {
+    fn default() -> Self {
+        Self {
+            obey: default_obey_robots(),
+            cache_ttl_secs: default_robots_cache_ttl(),
+        }
+    }
+}
+
+impl RobotsConfig {
+    /// Get the cache TTL
+    #[must_use]
+    pub fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_secs)
+    }
+}
+
+/// Retry settings of `Crawler::fetch()` (`[crawler.retry]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FetchRetryConfig {
+    /// Retries after the first attempt (0 disables retrying)
+    #[serde(default = "default_fetch_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubled for each further one (milliseconds)
+    #[serde(default = "default_fetch_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// Upper bound of a retry delay, also for `Retry-After` (milliseconds)
+    #[serde(default = "default_fetch_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// Share of each delay that is randomized (0.0-1.0), so crawlers that
+    /// failed together do not retry together
+    #[serde(default = "default_fetch_jitter")]
+    pub jitter: f64,
+}
+
+fn default_fetch_max_retries() -> u32 {
+    3
+}
+
+fn default_fetch_base_delay_ms() -> u64 {
+    500
+}
+
+fn default_fetch_max_delay_ms() -> u64 {
+    30_000
+}
+
+fn default_fetch_jitter() -> f64 {
+    0.5
+}
+
+impl Default for FetchRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: default_fetch_max_retries(),
+            base_delay_ms: default_fetch_base_delay_ms(),
+            max_delay_ms: default_fetch_max_delay_ms(),
+            jitter: default_fetch_jitter(),
+        }
+    }
+}
+
+/// Retry policy of failed URLs for `baram retry-failed`
+/// (`[crawler.dead_letter]`)
+///
+/// Error classes are those of [`ErrorClass`](crate::error::ErrorClass):
+/// `timeout`, `blocked`, `not_found`, `server_error`, `network`,
+/// `parse_failure`, `storage` and `other`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetterConfig {
+    /// Attempts per URL, counting the crawl that first failed, before it is
+    /// given up
+    #[serde(default = "default_dead_letter_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Error classes given up on the first failure
+    #[serde(default = "default_dead_letter_skip")]
+    pub skip: Vec<String>,
+
+    /// `max_attempts` overrides by error class
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_attempts_by_class: BTreeMap<String, u32>,
+}
+
+fn default_dead_letter_max_attempts() -> u32 {
+    5
+}
+
+fn default_dead_letter_skip() -> Vec<String> {
+    vec!["not_found".to_string()]
+}
+
+impl Default for DeadLetterConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_dead_letter_max_attempts(),
+            skip: default_dead_letter_skip(),
+            max_attempts_by_class: BTreeMap::new(),
+        }
+    }
+}
+
+impl DeadLetterConfig {
+    /// Attempts a URL failing with `class` gets in total
+    #[must_use]
+    pub fn max_attempts(&self, class: ErrorClass) -> u32 {
+        if self.skip.iter().any(|skip| skip == class.as_str()) {
+            return 1;
+        }
+        self.max_attempts_by_class
+            .get(class.as_str())
+            .copied()
+            .unwrap_or(self.max_attempts)
+    }
+}
+
+/// Per-host circuit breaker settings of `Crawler::fetch()`
+/// (`[crawler.circuit_breaker]`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failed requests to a host that open its circuit
+    /// (0 disables the breaker)
+    #[serde(default = "default_circuit_failure_threshold")]
+    pub failure_threshold: u32,
+
+    /// How long requests to a host with an open circuit are refused before a
+    /// trial request is let through (seconds)
+    #[serde(default = "default_circuit_open_secs")]
+    pub open_secs: u64,
+}
+
+fn default_circuit_failure_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_open_secs() -> u64 {
+    60
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_circuit_failure_threshold(),
+            open_secs: default_circuit_open_secs(),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// How long an open circuit refuses requests
+    #[must_use]
+    pub fn open_duration(&self) -> Duration {
+        Duration::from_secs(self.open_secs)
+    }
+}
+
+/// Proxy pool of `Crawler::fetch()` (`[crawler.proxy]`); without servers,
+/// requests go out directly
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// HTTP(S) or SOCKS5 proxies
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<ProxyServer>,
+
+    /// How the next proxy is picked
+    #[serde(default)]
+    pub rotation: ProxyRotation,
+
+    /// Consecutive connection failures that take a proxy out of rotation
+    #[serde(default = "default_proxy_max_failures")]
+    pub max_failures: u32,
+
+    /// URL fetched through every proxy to check it; proxies that fail are
+    /// taken out of rotation and put back once they pass again
+    #[serde(default = "default_proxy_health_check_url")]
+    pub health_check_url: String,
+
+    /// Seconds between health checks (0 disables them, and proxies out of
+    /// rotation are retried after a cooldown instead)
+    #[serde(default = "default_proxy_health_check_interval_secs")]
+    pub health_check_interval_secs: u64,
+}
+
+/// One proxy of the pool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxyServer {
+    /// `http://`, `https://`, `socks5://` or `socks5h://` URL, with
+    /// credentials if needed
+    pub url: String,
+
+    /// Share of requests under `weighted` rotation
+    #[serde(default = "default_proxy_weight")]
+    pub weight: u32,
+}
+
+/// How the proxy pool picks the proxy of a request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyRotation {
+    /// Each live proxy in turn
+    #[default]
+    RoundRobin,
+    /// At random, in proportion to the proxies' `weight`
+    Weighted,
+}
+
+fn default_proxy_max_failures() -> u32 {
+    3
+}
+
+fn default_proxy_health_check_url() -> String {
+    String::from("https://news.naver.com/")
+}
+
+fn default_proxy_health_check_interval_secs() -> u64 {
+    300
+}
+
+fn default_proxy_weight() -> u32 {
+    1
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        Self {
+            servers: Vec::new(),
+            rotation: ProxyRotation::default(),
+            max_failures: default_proxy_max_failures(),
+            health_check_url: default_proxy_health_check_url(),
+            health_check_interval_secs: default_proxy_health_check_interval_secs(),
+        }
+    }
+}
+
+impl ProxyConfig {
+    /// Time between health checks, `None` when they are disabled
+    #[must_use]
+    pub fn health_check_interval(&self) -> Option<Duration> {
+        (self.health_check_interval_secs > 0)
+            .then(|| Duration::from_secs(self.health_check_interval_secs))
+    }
+}
+
+/// Settings of one news category (`[category.<name>]`); unset values fall
+/// back to the global ones
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CategoryConfig {
+    /// Articles per run, for `crawl` without `--max-articles`, `watch`
+    /// (before `category_weights` apply) and the distributed crawler
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_articles: Option<usize>,
+
+    /// Requests per second, instead of `crawler.rate_limit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<f64>,
+
+    /// Crawl comments (distributed crawler), instead of `--with-comments`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<bool>,
+
+    /// Embedding model `index` requests for the category's articles; must be
+    /// served by the embedding server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_model: Option<String>,
+
+    /// RSS/Atom feeds listing the category's articles, for
+    /// `crawl --discovery rss`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub feeds: Vec<String>,
+
+    /// XML sitemaps (or sitemap indexes) listing the category's articles, for
+    /// `crawl --discovery sitemap`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sitemaps: Vec<String>,
+}
+
+impl CategoryConfig {
+    /// The block for `category` in `blocks`, or an empty one
+    #[must_use]
+    pub fn find(blocks: &BTreeMap<String, Self>, category: NewsCategory) -> Self {
+        blocks
+            .iter()
+            .find(|(name, _)| NewsCategory::parse(name) == Some(category))
+            .map(|(_, block)| block.clone())
+            .unwrap_or_default()
+    }
 }
 
 /// Database configuration
@@ -54,6 +499,11 @@ pub struct DatabaseConfig {
 
     /// Maximum pool size
     pub pool_size: usize,
+
+    /// Also store article bodies with a full-text index in the crawl
+    /// database, for `baram search --local`
+    #[serde(default)]
+    pub store_bodies: bool,
 }
 
 /// OpenSearch configuration
@@ -82,100 +532,244 @@ pub struct LoggingConfig {
     pub format: String,
 }
 
-impl Config {
-    /// Load configuration from environment variables
-    pub fn from_env() -> Result<Self> {
-        let max_concurrent_requests = std::env::var("BARAM_MAX_CONCURRENT_REQUESTS")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(10);
-
-        let rate_limit = std::env::var("BARAM_RATE_LIMIT")
-            .ok()
-            .and_then(|v| v.parse::<f64>().ok())
-            .unwrap_or(2.0);
-
-        let request_timeout_secs = std::env::var("BARAM_REQUEST_TIMEOUT")
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .unwrap_or(30);
+/// Vocabulary files (`[analysis]`)
+///
+/// Loaded into an [`crate::analytics::Vocabulary`] that the keyword
+/// tokenizer and the OpenSearch index settings share. Relative paths are
+/// resolved against the working directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisConfig {
+    /// Extra stopwords, one per line
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopwords_path: Option<PathBuf>,
 
-        let user_agent = std::env::var("BARAM_USER_AGENT")
-            .unwrap_or_else(|_| format!("baram/{}", env!("CARGO_PKG_VERSION")));
+    /// Nori user dictionary: a word per line, optionally followed by its parts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_dictionary_path: Option<PathBuf>,
 
-        let sqlite_path = std::env::var("BARAM_SQLITE_PATH")
-            .unwrap_or_else(|_| String::from("data/metadata.db"))
-            .into();
-
-        let postgres_url = std::env::var("POSTGRES_URL")
-            .or_else(|_| std::env::var("DATABASE_URL"))
-            .unwrap_or_else(|_| String::from("postgresql://localhost/baram"));
-
-        let opensearch_url = std::env::var("OPENSEARCH_URL")
-            .unwrap_or_else(|_| String::from("http://localhost:9200"));
-
-        let opensearch_index =
-            std::env::var("OPENSEARCH_INDEX").unwrap_or_else(|_| String::from("baram-articles"));
+    /// Synonym rules in Solr format (`a, b, c` or `a, b => c`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synonyms_path: Option<PathBuf>,
+}
 
-        let opensearch_username = std::env::var("OPENSEARCH_USERNAME").ok();
-        let opensearch_password = std::env::var("OPENSEARCH_PASSWORD").ok();
+impl AnalysisConfig {
+    /// Whether no file is configured
+    pub fn is_empty(&self) -> bool {
+        self.stopwords_path.is_none()
+            && self.user_dictionary_path.is_none()
+            && self.synonyms_path.is_none()
+    }
+}
 
-        let log_level = std::env::var("BARAM_LOG_LEVEL").unwrap_or_else(|_| String::from("info"));
+/// Prometheus metrics configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Pushgateway URL that batch jobs push their final metrics to (disabled if unset)
+    #[serde(default)]
+    pub pushgateway_url: Option<String>,
 
-        let log_format = std::env::var("BARAM_LOG_FORMAT").unwrap_or_else(|_| String::from("text"));
+    /// `instance` grouping label for pushed metrics (optional)
+    #[serde(default)]
+    pub instance: Option<String>,
+}
 
-        Ok(Self {
-            crawler: CrawlerConfig {
-                max_concurrent_requests,
-                rate_limit,
-                request_timeout_secs,
-                user_agent,
-                enable_cookies: true,
-            },
-            database: DatabaseConfig {
-                sqlite_path,
-                postgres_url,
-                pool_size: 10,
-            },
-            opensearch: OpenSearchConfig {
-                url: opensearch_url,
-                index_name: opensearch_index,
-                username: opensearch_username,
-                password: opensearch_password,
-            },
-            logging: LoggingConfig {
-                level: log_level,
-                format: log_format,
-            },
-        })
+impl Config {
+    /// Load configuration from the defaults and environment variables
+    ///
+    /// See [`layers::ENV_VARS`] for the variables read.
+    pub fn from_env() -> Result<Self> {
+        Ok(ConfigLoader::new().with_env().load()?.config)
     }
 
     /// Load configuration from a file
     pub fn from_file(path: &Path) -> Result<Self> {
-        let content = std::fs::read_to_string(path)
-            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        Self::from_file_with_profile(path, None)
+    }
 
-        let config: Self = toml::from_str(&content)
-            .with_context(|| format!("Failed to parse TOML config file: {}", path.display()))?;
+    /// Load configuration from a TOML file with a profile applied
+    pub fn from_file_with_profile(path: &Path, profile: Option<&str>) -> Result<Self> {
+        Ok(ConfigLoader::new().file(path, profile).load()?.config)
+    }
 
+    /// Parse a TOML config, overlaying `[profile.<profile>]` on the base
+    /// sections when a profile is given
+    pub fn from_toml(content: &str, profile: Option<&str>) -> Result<Self> {
+        let Some(name) = profile else {
+            return Ok(toml::from_str(content)?);
+        };
+
+        let table = apply_profile(toml::from_str(content)?, name)?;
+        let mut config: Self = table.try_into()?;
+        config.profile = Some(name.to_string());
         Ok(config)
     }
 
     /// Validate configuration values
+    ///
+    /// Every rejected value is reported, each with its dotted key; see
+    /// [`Config::field_errors`].
     pub fn validate(&self) -> Result<()> {
-        if self.crawler.max_concurrent_requests == 0 {
-            anyhow::bail!("max_concurrent_requests must be greater than 0");
+        let errors = self.field_errors();
+        if errors.is_empty() {
+            return Ok(());
         }
+        let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        anyhow::bail!("{}", errors.join("; "))
+    }
+
+    /// Values [`Config::validate`] rejects, in file order
+    #[must_use]
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let mut error = |key: &str, message: String| {
+            errors.push(FieldError {
+                key: key.to_string(),
+                message,
+            });
+        };
 
-        if self.crawler.rate_limit <= 0.0 {
-            anyhow::bail!("rate_limit must be positive");
+        if self.crawler.max_concurrent_requests == 0 {
+            error(
+                "crawler.max_concurrent_requests",
+                "must be greater than 0".to_string(),
+            );
+        }
+        if !self.crawler.rate_limit.is_finite() || self.crawler.rate_limit <= 0.0 {
+            error("crawler.rate_limit", "must be positive".to_string());
+        }
+        for (domain, rate) in &self.crawler.domain_rate_limits {
+            let key = format!("crawler.domain_rate_limits.{domain}");
+            if domain.is_empty() || domain.contains(['/', ':', ' ']) {
+                error(
+                    &key,
+                    "must be a domain name, e.g. news.naver.com".to_string(),
+                );
+            } else if !rate.is_finite() || *rate <= 0.0 {
+                error(&key, "must be positive".to_string());
+            }
+        }
+        let retry = &self.crawler.retry;
+        if !(0.0..=1.0).contains(&retry.jitter) {
+            error(
+                "crawler.retry.jitter",
+                "must be between 0.0 and 1.0".to_string(),
+            );
+        }
+        if retry.max_delay_ms < retry.base_delay_ms {
+            error(
+                "crawler.retry.max_delay_ms",
+                "must not be less than base_delay_ms".to_string(),
+            );
+        }
+        let dead_letter = &self.crawler.dead_letter;
+        for class in dead_letter
+            .skip
+            .iter()
+            .chain(dead_letter.max_attempts_by_class.keys())
+        {
+            if ErrorClass::parse(class).is_none() {
+                error(
+                    "crawler.dead_letter",
+                    format!("unknown error class: {class}"),
+                );
+            }
+        }
+        if self
+            .crawler
+            .user_agents
+            .iter()
+            .any(|agent| agent.trim().is_empty())
+        {
+            error(
+                "crawler.user_agents",
+                "must not contain empty user agents".to_string(),
+            );
+        }
+        let proxy = &self.crawler.proxy;
+        for (i, server) in proxy.servers.iter().enumerate() {
+            let key = format!("crawler.proxy.servers[{i}]");
+            match url::Url::parse(&server.url) {
+                Ok(parsed)
+                    if matches!(parsed.scheme(), "http" | "https" | "socks5" | "socks5h") => {}
+                Ok(_) => error(&key, "not an http(s) or socks5 proxy URL".to_string()),
+                Err(e) => error(&key, format!("not a valid URL: {e}")),
+            }
+            if server.weight == 0 {
+                error(
+                    &format!("{key}.weight"),
+                    "must be greater than 0".to_string(),
+                );
+            }
+        }
+        if !proxy.servers.is_empty() {
+            if proxy.max_failures == 0 {
+                error(
+                    "crawler.proxy.max_failures",
+                    "must be greater than 0".to_string(),
+                );
+            }
+            if let Err(message) = http_url(&proxy.health_check_url) {
+                error("crawler.proxy.health_check_url", message);
+            }
+        }
+        for (category, weight) in &self.crawler.category_weights {
+            let key = format!("crawler.category_weights.{category}");
+            if NewsCategory::parse(category).is_none() {
+                error(&key, format!("unknown category {category}"));
+            } else if !weight.is_finite() || *weight < 0.0 {
+                error(&key, "must be 0 or more".to_string());
+            }
         }
 
         if self.database.pool_size == 0 {
-            anyhow::bail!("pool_size must be greater than 0");
+            error("database.pool_size", "must be greater than 0".to_string());
+        }
+        if let Err(e) = url::Url::parse(&self.database.postgres_url) {
+            error("database.postgres_url", format!("not a valid URL: {e}"));
         }
 
-        Ok(())
+        if let Err(message) = http_url(&self.opensearch.url) {
+            error("opensearch.url", message);
+        }
+        if self.opensearch.index_name.trim().is_empty() {
+            error("opensearch.index_name", "must not be empty".to_string());
+        }
+
+        if let Err(e) = self.notifications.validate() {
+            error("notifications", e);
+        }
+
+        if let Some(url) = &self.metrics.pushgateway_url {
+            if let Err(message) = http_url(url) {
+                error("metrics.pushgateway_url", message);
+            }
+        }
+
+        for (category, block) in &self.category {
+            let key = format!("{CATEGORY_SECTION}.{category}");
+            if NewsCategory::parse(category).is_none() {
+                error(&key, format!("unknown category {category}"));
+                continue;
+            }
+            if block
+                .rate_limit
+                .is_some_and(|rate| !rate.is_finite() || rate <= 0.0)
+            {
+                error(&format!("{key}.rate_limit"), "must be positive".to_string());
+            }
+            if block
+                .embedding_model
+                .as_ref()
+                .is_some_and(|model| model.trim().is_empty())
+            {
+                error(
+                    &format!("{key}.embedding_model"),
+                    "must not be empty".to_string(),
+                );
+            }
+        }
+
+        errors
     }
 
     /// Get request timeout as Duration
@@ -183,6 +777,20 @@ impl Config {
     pub fn request_timeout(&self) -> Duration {
         Duration::from_secs(self.crawler.request_timeout_secs)
     }
+
+    /// The `[category.<name>]` block for `category`, or an empty one
+    #[must_use]
+    pub fn category(&self, category: NewsCategory) -> CategoryConfig {
+        CategoryConfig::find(&self.category, category)
+    }
+
+    /// Requests per second for `category`
+    #[must_use]
+    pub fn category_rate_limit(&self, category: NewsCategory) -> f64 {
+        self.category(category)
+            .rate_limit
+            .unwrap_or(self.crawler.rate_limit)
+    }
 }
 
 impl Default for Config {
@@ -193,12 +801,21 @@ impl Default for Config {
                 rate_limit: 2.0,
                 request_timeout_secs: 30,
                 user_agent: format!("baram/{}", env!("CARGO_PKG_VERSION")),
+                user_agents: Vec::new(),
                 enable_cookies: true,
+                domain_rate_limits: BTreeMap::new(),
+                category_weights: BTreeMap::new(),
+                robots: RobotsConfig::default(),
+                retry: FetchRetryConfig::default(),
+                circuit_breaker: CircuitBreakerConfig::default(),
+                proxy: ProxyConfig::default(),
+                dead_letter: DeadLetterConfig::default(),
             },
             database: DatabaseConfig {
                 sqlite_path: PathBuf::from("data/metadata.db"),
                 postgres_url: String::from("postgresql://localhost/baram"),
                 pool_size: 10,
+                store_bodies: false,
             },
             opensearch: OpenSearchConfig {
                 url: String::from("http://localhost:9200"),
@@ -210,6 +827,116 @@ impl Default for Config {
                 level: String::from("info"),
                 format: String::from("text"),
             },
+            notifications: NotificationsConfig::default(),
+            metrics: MetricsConfig::default(),
+            secrets: SecretsConfig::default(),
+            analysis: AnalysisConfig::default(),
+            category: BTreeMap::new(),
+            profile: None,
+        }
+    }
+}
+
+impl CrawlerConfig {
+    /// Article budget of one run for `category`, scaled by its weight
+    #[must_use]
+    pub fn category_budget(&self, category: NewsCategory, budget: usize) -> usize {
+        let weight = self
+            .category_weights
+            .iter()
+            .find(|(name, _)| NewsCategory::parse(name) == Some(category))
+            .map_or(1.0, |(_, weight)| *weight);
+        (budget as f64 * weight).round() as usize
+    }
+}
+
+impl OpenSearchConfig {
+    /// Let `OPENSEARCH_URL`, `OPENSEARCH_INDEX`, `OPENSEARCH_USER` and
+    /// `OPENSEARCH_PASSWORD` (or their `_FILE` variants) override the
+    /// configured values
+    #[must_use]
+    pub fn with_env_overrides(mut self) -> Self {
+        let env = |var: &str| match secrets::read_env(var) {
+            Ok(value) => value.map(|(_, value)| value),
+            Err(e) => {
+                tracing::warn!(error = %format!("{e:#}"), "Ignoring {var}");
+                None
+            }
+        };
+        if let Some(url) = env("OPENSEARCH_URL") {
+            self.url = url;
+        }
+        if let Some(index_name) = env("OPENSEARCH_INDEX") {
+            self.index_name = index_name;
+        }
+        if let Some(username) = env("OPENSEARCH_USER") {
+            self.username = Some(username);
+        }
+        if let Some(password) = env("OPENSEARCH_PASSWORD") {
+            self.password = Some(password);
+        }
+        self
+    }
+}
+
+/// Names of the profiles defined in a parsed config file
+#[must_use]
+pub fn profile_names(table: &toml::Table) -> Vec<String> {
+    table
+        .get(PROFILE_SECTION)
+        .and_then(toml::Value::as_table)
+        .map(|profiles| profiles.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Overlay `[profile.<name>]` on the base sections of a parsed config file
+/// and drop the profile table
+///
+/// Tables are merged key by key; any other value in the profile replaces the
+/// base value. Keys in [`PROFILE_FIXED_KEYS`] are ignored.
+pub fn apply_profile(mut table: toml::Table, name: &str) -> Result<toml::Table> {
+    let mut overlay = take_profile(&mut table, name)?;
+    for (section, key) in PROFILE_FIXED_KEYS {
+        if let Some(toml::Value::Table(section)) = overlay.get_mut(*section) {
+            section.remove(*key);
+        }
+    }
+    merge_tables(&mut table, overlay);
+    Ok(table)
+}
+
+/// Remove the profile table from a parsed config file, returning
+/// `[profile.<name>]`
+fn take_profile(table: &mut toml::Table, name: &str) -> Result<toml::Table> {
+    let names = profile_names(table);
+    let overlay = table
+        .remove(PROFILE_SECTION)
+        .and_then(|profiles| match profiles {
+            toml::Value::Table(mut profiles) => profiles.remove(name),
+            _ => None,
+        });
+    match overlay {
+        Some(toml::Value::Table(overlay)) => Ok(overlay),
+        Some(_) => anyhow::bail!("[{PROFILE_SECTION}.{name}] must be a table"),
+        None if names.is_empty() => {
+            anyhow::bail!("Unknown profile: {name}. The config file defines no profiles")
+        }
+        None => anyhow::bail!(
+            "Unknown profile: {name}. Defined profiles: {}",
+            names.join(", ")
+        ),
+    }
+}
+
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_tables(base, overlay);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
         }
     }
 }
@@ -231,6 +958,197 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_field_errors() {
+        let mut config = Config::default();
+        config.crawler.rate_limit = f64::NAN;
+        config.database.postgres_url = "not a url".to_string();
+        config.opensearch.url = "ftp://localhost:9200".to_string();
+
+        let keys: Vec<_> = config.field_errors().into_iter().map(|e| e.key).collect();
+        assert_eq!(
+            keys,
+            [
+                "crawler.rate_limit",
+                "database.postgres_url",
+                "opensearch.url"
+            ]
+        );
+        let message = config.validate().unwrap_err().to_string();
+        assert!(message.starts_with("crawler.rate_limit: must be positive; "));
+    }
+
+    #[test]
+    fn test_notifications_section_is_optional() {
+        let mut config = Config::default();
+        let toml = toml::to_string(&config).unwrap();
+        let without: String = toml.split("[notifications]").next().unwrap().to_string();
+        let parsed: Config = toml::from_str(&without).unwrap();
+        assert!(parsed.notifications.is_empty());
+
+        config.notifications.dedup_window_minutes = -1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_metrics_section() {
+        let toml = toml::to_string(&Config::default()).unwrap();
+        let base = toml.split("[metrics]").next().unwrap();
+        let parsed: Config = toml::from_str(&format!(
+            "{base}\n[metrics]\npushgateway_url = \"http://pushgateway:9091\"\n"
+        ))
+        .unwrap();
+        assert_eq!(
+            parsed.metrics.pushgateway_url.as_deref(),
+            Some("http://pushgateway:9091")
+        );
+        assert!(parsed.validate().is_ok());
+
+        let mut config = Config::default();
+        assert!(config.metrics.pushgateway_url.is_none());
+        config.metrics.pushgateway_url = Some("pushgateway:9091".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_profiles_overlay_base_sections() {
+        let base = toml::to_string(&Config::default()).unwrap();
+        let file = format!(
+            "{base}
+[profile.prod.database]
+postgres_url = \"postgresql://db.prod/baram\"
+sqlite_path = \"data/prod/metadata.db\"
+
+[profile.prod.opensearch]
+url = \"https://search.prod:9200\"
+index_name = \"baram-prod\"
+
+[profile.dev.crawler]
+rate_limit = 0.5
+"
+        );
+
+        let config = Config::from_toml(&file, None).unwrap();
+        assert_eq!(config.opensearch.index_name, "baram-articles");
+        assert!(config.profile.is_none());
+
+        let prod = Config::from_toml(&file, Some("prod")).unwrap();
+        assert_eq!(prod.database.postgres_url, "postgresql://db.prod/baram");
+        assert_eq!(prod.database.pool_size, config.database.pool_size);
+        assert_eq!(prod.database.sqlite_path, config.database.sqlite_path);
+        assert_eq!(prod.opensearch.url, "https://search.prod:9200");
+        assert_eq!(prod.opensearch.index_name, "baram-prod");
+        assert_eq!(prod.crawler.rate_limit, config.crawler.rate_limit);
+
+        let dev = Config::from_toml(&file, Some("dev")).unwrap();
+        assert_eq!(dev.crawler.rate_limit, 0.5);
+        assert_eq!(dev.opensearch.index_name, "baram-articles");
+
+        let err = Config::from_toml(&file, Some("staging")).unwrap_err();
+        assert!(err.to_string().contains("Defined profiles: dev, prod"));
+        let err = Config::from_toml(&base, Some("prod")).unwrap_err();
+        assert!(err.to_string().contains("defines no profiles"));
+    }
+
+    #[test]
+    fn test_domain_rate_limits() {
+        let base = toml::to_string(&Config::default()).unwrap();
+        assert!(!base.contains("domain_rate_limits"));
+
+        let file = base.replace(
+            "[database]",
+            "[crawler.domain_rate_limits]\n\"n.news.naver.com\" = 1.0\n\"chosun.com\" = 0.2\n\n[database]",
+        );
+        let config = Config::from_toml(&file, None).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.crawler.domain_rate_limits["chosun.com"], 0.2);
+
+        let mut config = Config::default();
+        config
+            .crawler
+            .domain_rate_limits
+            .insert("https://chosun.com".to_string(), 1.0);
+        assert!(config.validate().is_err());
+        config.crawler.domain_rate_limits.clear();
+        config
+            .crawler
+            .domain_rate_limits
+            .insert("chosun.com".to_string(), 0.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_category_weights() {
+        let base = toml::to_string(&Config::default()).unwrap();
+        assert!(!base.contains("category_weights"));
+
+        let file = base.replace(
+            "[database]",
+            "[crawler.category_weights]\npolitics = 2.0\nworld = 0.0\n\n[database]",
+        );
+        let config = Config::from_toml(&file, None).unwrap();
+        assert!(config.validate().is_ok());
+        let crawler = &config.crawler;
+        assert_eq!(crawler.category_budget(NewsCategory::Politics, 100), 200);
+        assert_eq!(crawler.category_budget(NewsCategory::World, 100), 0);
+        assert_eq!(crawler.category_budget(NewsCategory::Economy, 100), 100);
+
+        let mut config = Config::default();
+        config
+            .crawler
+            .category_weights
+            .insert("weather".to_string(), 1.0);
+        assert!(config.validate().is_err());
+        config.crawler.category_weights.clear();
+        config
+            .crawler
+            .category_weights
+            .insert("it".to_string(), -1.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_category_blocks() {
+        let base = toml::to_string(&Config::default()).unwrap();
+        assert!(!base.contains("[category"));
+
+        let file = format!(
+            "{base}\n[category.politics]\nmax_articles = 500\nrate_limit = 0.5\ncomments = true\n\n\
+             [category.it]\nembedding_model = \"BAAI/bge-m3\"\n"
+        );
+        let config = Config::from_toml(&file, None).unwrap();
+        assert!(config.validate().is_ok());
+
+        let politics = config.category(NewsCategory::Politics);
+        assert_eq!(politics.max_articles, Some(500));
+        assert_eq!(politics.comments, Some(true));
+        assert_eq!(config.category_rate_limit(NewsCategory::Politics), 0.5);
+        assert_eq!(config.category_rate_limit(NewsCategory::IT), 2.0);
+        assert_eq!(
+            config.category(NewsCategory::IT).embedding_model.as_deref(),
+            Some("BAAI/bge-m3")
+        );
+        assert_eq!(
+            config.category(NewsCategory::World),
+            CategoryConfig::default()
+        );
+
+        let mut config = Config::default();
+        config
+            .category
+            .insert("weather".to_string(), CategoryConfig::default());
+        assert!(config.validate().is_err());
+        config.category.clear();
+        config.category.insert(
+            "it".to_string(),
+            CategoryConfig {
+                rate_limit: Some(0.0),
+                ..CategoryConfig::default()
+            },
+        );
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_request_timeout_conversion() {
         let config = Config::default();
@@ -0,0 +1,340 @@
+//! Config hot-reload for long-running modes
+//!
+//! [`ConfigWatcher`] watches the config file and re-reads it whenever it is
+//! written. Changes to the keys a mode can apply while running (see
+//! [`HOT_KEYS`]) are logged and published to subscribers; changes to any
+//! other key are logged as needing a restart and otherwise ignored. A file
+//! that no longer parses or validates is reported and the running config is
+//! kept.
+
+use anyhow::{Context, Result};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+
+use super::layers::{flatten, ConfigLoader};
+use super::Config;
+
+/// `crawler.rate_limit`
+pub const RATE_LIMIT: &str = "crawler.rate_limit";
+
+/// `crawler.category_weights`
+pub const CATEGORY_WEIGHTS: &str = "crawler.category_weights";
+
+/// `notifications.rules`
+pub const ALERT_RULES: &str = "notifications.rules";
+
+/// Keys that can be applied without a restart
+pub const HOT_KEYS: &[&str] = &[RATE_LIMIT, CATEGORY_WEIGHTS, ALERT_RULES];
+
+/// Editors often write a file in several steps; events this close together
+/// are handled as one change
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// One changed value between two configs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Dotted key, e.g. `crawler.rate_limit` or `crawler.category_weights.it`
+    pub key: String,
+    /// Previous value (`None` if unset)
+    pub old: Option<String>,
+    /// New value (`None` if removed)
+    pub new: Option<String>,
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "unset".to_string());
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.key,
+            show(&self.old),
+            show(&self.new)
+        )
+    }
+}
+
+impl ConfigChange {
+    /// Whether the change is to one of `hot` or a key below it
+    pub fn is_hot(&self, hot: &[&str]) -> bool {
+        hot.iter().any(|key| {
+            self.key == *key
+                || self
+                    .key
+                    .strip_prefix(key)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
+
+/// Values that differ between `old` and `new`, by dotted key
+///
+/// Arrays are compared as a whole; arrays of tables (channels, rules) are
+/// shown by their length.
+pub fn changes(old: &Config, new: &Config) -> Vec<ConfigChange> {
+    let old = config_values(old);
+    let mut new = config_values(new);
+    let mut changes = Vec::new();
+    for (key, old_value) in old {
+        match new.remove(&key) {
+            Some(new_value) if new_value == old_value => {}
+            new_value => changes.push(ConfigChange {
+                key,
+                old: Some(display(&old_value)),
+                new: new_value.as_ref().map(display),
+            }),
+        }
+    }
+    for (key, new_value) in new {
+        changes.push(ConfigChange {
+            key,
+            old: None,
+            new: Some(display(&new_value)),
+        });
+    }
+    changes.sort_by(|a, b| a.key.cmp(&b.key));
+    changes
+}
+
+/// `current` with the `hot` keys taken over from `new`
+pub fn apply(current: &Config, new: &Config, hot: &[&str]) -> Config {
+    let mut config = current.clone();
+    if hot.contains(&RATE_LIMIT) {
+        config.crawler.rate_limit = new.crawler.rate_limit;
+    }
+    if hot.contains(&CATEGORY_WEIGHTS) {
+        config.crawler.category_weights = new.crawler.category_weights.clone();
+    }
+    if hot.contains(&ALERT_RULES) {
+        config.notifications.rules = new.notifications.rules.clone();
+    }
+    config
+}
+
+/// Watches the config file and publishes the config with changes applied
+pub struct ConfigWatcher {
+    updates: watch::Receiver<Arc<Config>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    /// Watch the config file of `loader`, which `config` was loaded with,
+    /// applying changes to the `hot` keys
+    ///
+    /// Every layer is re-read, so environment and command-line overrides keep
+    /// their precedence over the file. Must be called from within a Tokio
+    /// runtime.
+    pub fn spawn(
+        loader: ConfigLoader,
+        config: Config,
+        hot: &'static [&'static str],
+    ) -> Result<Self> {
+        let path = loader
+            .file_path()
+            .context("No config file to watch")?
+            .to_path_buf();
+        let (events_tx, mut events_rx) = mpsc::unbounded_channel();
+        let file_name = path.file_name().map(ToOwned::to_owned);
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                let written = matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_));
+                if written
+                    && event
+                        .paths
+                        .iter()
+                        .any(|p| p.file_name() == file_name.as_deref())
+                {
+                    let _ = events_tx.send(());
+                }
+            })
+            .context("Failed to create config file watcher")?;
+
+        // Watch the directory: editors often replace the file instead of
+        // writing it in place
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Failed to watch {}", dir.display()))?;
+
+        tracing::info!(path = %path.display(), hot = ?hot, "Watching config file for changes");
+
+        let (updates_tx, updates) = watch::channel(Arc::new(config));
+        tokio::spawn(async move {
+            while events_rx.recv().await.is_some() {
+                tokio::time::sleep(DEBOUNCE).await;
+                while events_rx.try_recv().is_ok() {}
+
+                let current = Arc::clone(&updates_tx.borrow());
+                if let Some(updated) = reload(&loader, &current, hot) {
+                    updates_tx.send_replace(Arc::new(updated));
+                }
+            }
+        });
+
+        Ok(Self {
+            updates,
+            _watcher: watcher,
+        })
+    }
+
+    /// Receiver that sees every config with applied changes
+    pub fn subscribe(&self) -> watch::Receiver<Arc<Config>> {
+        self.updates.clone()
+    }
+}
+
+/// Re-read the config; the updated config if any hot key changed
+fn reload(loader: &ConfigLoader, current: &Config, hot: &[&str]) -> Option<Config> {
+    let new = match loader
+        .load()
+        .and_then(|resolved| resolved.config.validate().map(|()| resolved.config))
+    {
+        Ok(new) => new,
+        Err(e) => {
+            tracing::warn!(
+                path = ?loader.file_path(),
+                error = %format!("{e:#}"),
+                "Ignoring config change, the file is invalid"
+            );
+            return None;
+        }
+    };
+
+    let mut applied = false;
+    for change in changes(current, &new) {
+        if change.is_hot(hot) {
+            tracing::info!(change = %change, "Config change applied");
+            applied = true;
+        } else {
+            // Values are not logged: the key may hold a password
+            tracing::warn!(key = %change.key, "Config change needs a restart to take effect");
+        }
+    }
+    applied.then(|| apply(current, &new, hot))
+}
+
+fn config_values(config: &Config) -> BTreeMap<String, toml::Value> {
+    match toml::Value::try_from(config) {
+        Ok(toml::Value::Table(table)) => flatten(&table),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn display(value: &toml::Value) -> String {
+    match value {
+        toml::Value::Array(items)
+            if items.is_empty() || items.iter().any(toml::Value::is_table) =>
+        {
+            format!("{} entries", items.len())
+        }
+        toml::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::{AlertCondition, AlertRule, AlertSeverity};
+
+    fn rule(name: &str) -> AlertRule {
+        AlertRule::new(
+            name,
+            AlertCondition::ZeroArticleSlots {
+                consecutive_slots: 3,
+            },
+            AlertSeverity::Warning,
+        )
+    }
+
+    #[test]
+    fn test_changes() {
+        let old = Config::default();
+        assert!(changes(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        new.crawler.rate_limit = 0.5;
+        new.crawler.category_weights.insert("it".to_string(), 2.0);
+        new.notifications.rules.push(rule("empty-slots"));
+        new.opensearch.password = Some("secret".to_string());
+
+        let found = changes(&old, &new);
+        let keys: Vec<&str> = found.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "crawler.category_weights.it",
+                "crawler.rate_limit",
+                "notifications.rules",
+                "opensearch.password",
+            ]
+        );
+        assert_eq!(
+            found[0].to_string(),
+            "crawler.category_weights.it: unset -> 2.0"
+        );
+        assert_eq!(found[1].to_string(), "crawler.rate_limit: 2.0 -> 0.5");
+        assert_eq!(
+            found[2].to_string(),
+            "notifications.rules: 0 entries -> 1 entries"
+        );
+
+        assert!(found[0].is_hot(HOT_KEYS));
+        assert!(found[1].is_hot(&[RATE_LIMIT]));
+        assert!(!found[1].is_hot(&[ALERT_RULES]));
+        assert!(!found[3].is_hot(HOT_KEYS));
+    }
+
+    #[test]
+    fn test_apply_takes_only_hot_keys() {
+        let current = Config::default();
+        let mut new = current.clone();
+        new.crawler.rate_limit = 5.0;
+        new.crawler.max_concurrent_requests = 1;
+        new.notifications.rules.push(rule("empty-slots"));
+
+        let applied = apply(&current, &new, &[RATE_LIMIT]);
+        assert_eq!(applied.crawler.rate_limit, 5.0);
+        assert_eq!(applied.crawler.max_concurrent_requests, 10);
+        assert!(applied.notifications.rules.is_empty());
+
+        let applied = apply(&current, &new, &[ALERT_RULES]);
+        assert_eq!(applied.crawler.rate_limit, 2.0);
+        assert_eq!(applied.notifications.rules.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_watcher_publishes_hot_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = Config::default();
+        std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+        let loader = ConfigLoader::new().file(&path, None);
+        let watcher = ConfigWatcher::spawn(loader, config.clone(), &[RATE_LIMIT]).unwrap();
+        let mut updates = watcher.subscribe();
+
+        let mut edited = config.clone();
+        edited.crawler.rate_limit = 7.0;
+        edited.database.pool_size = 3;
+        std::fs::write(&path, toml::to_string(&edited).unwrap()).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(10), updates.changed())
+            .await
+            .expect("no config update")
+            .unwrap();
+        let updated = updates.borrow_and_update().clone();
+        assert_eq!(updated.crawler.rate_limit, 7.0);
+        assert_eq!(updated.database.pool_size, config.database.pool_size);
+    }
+}
@@ -0,0 +1,255 @@
+//! Credentials read from files and external secret stores
+//!
+//! Besides plain values, a credential can be given in three ways that keep
+//! it out of both the environment and the config file:
+//!
+//! - `<VAR>_FILE` for any variable in [`ENV_VARS`](super::layers::ENV_VARS),
+//!   e.g. `OPENSEARCH_PASSWORD_FILE=/run/secrets/opensearch_password`: the
+//!   value is read from the file (Docker and Kubernetes secrets)
+//! - `file:<path>` as the value of a credential key: read from `<path>`
+//! - `secret:<name>` as the value of a credential key: printed by the
+//!   `[secrets] command` hook, e.g. `vault kv get` or `sops -d`
+//!
+//! Credential keys are passwords, tokens, usernames and URLs (see
+//! [`is_credential`]); other values are used as written. A trailing newline
+//! is stripped from every secret read from a file or a command.
+//!
+//! ```toml
+//! [opensearch]
+//! password = "secret:opensearch_password"
+//!
+//! [secrets]
+//! command = ["vault", "kv", "get", "-field={name}", "secret/baram"]
+//! ```
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Prefix of a value read from a file
+pub const FILE_PREFIX: &str = "file:";
+
+/// Prefix of a value fetched with the secrets command
+pub const SECRET_PREFIX: &str = "secret:";
+
+/// Placeholder for the secret name in the command arguments
+const NAME_PLACEHOLDER: &str = "{name}";
+
+/// External secret store hook (`[secrets]`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretsConfig {
+    /// Program and arguments that print the secret `{name}` on stdout; the
+    /// name is appended when no argument holds `{name}`
+    #[serde(default)]
+    pub command: Vec<String>,
+}
+
+impl SecretsConfig {
+    /// Whether no hook is configured
+    pub fn is_empty(&self) -> bool {
+        self.command.is_empty()
+    }
+
+    /// Run the command for the secret `name`
+    fn fetch(&self, name: &str) -> Result<String> {
+        let Some((program, args)) = self.command.split_first() else {
+            bail!("{SECRET_PREFIX}{name} needs a [secrets] command");
+        };
+        let mut args: Vec<String> = args
+            .iter()
+            .map(|arg| arg.replace(NAME_PLACEHOLDER, name))
+            .collect();
+        if !self
+            .command
+            .iter()
+            .any(|arg| arg.contains(NAME_PLACEHOLDER))
+        {
+            args.push(name.to_string());
+        }
+
+        let output = Command::new(program)
+            .args(&args)
+            .stdin(Stdio::null())
+            .output()
+            .with_context(|| format!("Failed to run secrets command {program}"))?;
+        if !output.status.success() {
+            bail!(
+                "Secrets command {program} failed for {name} ({}): {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let secret = String::from_utf8(output.stdout)
+            .with_context(|| format!("Secrets command {program} printed invalid UTF-8"))?;
+        Ok(trim_newline(secret))
+    }
+}
+
+/// Whether the leaf key `key` holds a credential that may be a reference
+pub fn is_credential(key: &str) -> bool {
+    ["password", "token", "secret"]
+        .iter()
+        .any(|word| key.contains(word))
+        || key == "username"
+        || key.ends_with("url")
+}
+
+/// Read `var`, or the file named by `<var>_FILE`
+///
+/// Returns the variable that was read with its value. Setting both is an
+/// error, so a stale plain variable cannot shadow the mounted secret.
+pub fn read_env(var: &str) -> Result<Option<(String, String)>> {
+    let file_var = format!("{var}_FILE");
+    match (std::env::var(var), std::env::var(&file_var)) {
+        (Ok(_), Ok(_)) => bail!("Set either {var} or {file_var}, not both"),
+        (Ok(value), Err(_)) => Ok(Some((var.to_string(), value))),
+        (Err(_), Ok(path)) => {
+            let value =
+                read_file(Path::new(&path)).with_context(|| format!("Invalid {file_var}"))?;
+            Ok(Some((file_var, value)))
+        }
+        (Err(_), Err(_)) => Ok(None),
+    }
+}
+
+/// Replace `file:` and `secret:` references in the credential values of
+/// `table`, including those in arrays of tables (notification channels)
+pub(crate) fn resolve(table: &mut toml::Table, hook: &SecretsConfig) -> Result<()> {
+    resolve_table(table, "", hook)
+}
+
+fn resolve_table(table: &mut toml::Table, prefix: &str, hook: &SecretsConfig) -> Result<()> {
+    for (key, value) in table.iter_mut() {
+        let dotted = format!("{prefix}{key}");
+        match value {
+            toml::Value::Table(inner) => resolve_table(inner, &format!("{dotted}."), hook)?,
+            toml::Value::Array(items) => {
+                for (i, item) in items.iter_mut().enumerate() {
+                    if let toml::Value::Table(inner) = item {
+                        resolve_table(inner, &format!("{dotted}[{i}]."), hook)?;
+                    }
+                }
+            }
+            toml::Value::String(s) if is_credential(key) => {
+                if let Some(resolved) = resolve_value(s, hook)
+                    .with_context(|| format!("Failed to resolve the secret for {dotted}"))?
+                {
+                    *s = resolved;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The secret a reference points to, or `None` for a plain value
+fn resolve_value(value: &str, hook: &SecretsConfig) -> Result<Option<String>> {
+    if let Some(path) = value.strip_prefix(FILE_PREFIX) {
+        read_file(Path::new(path)).map(Some)
+    } else if let Some(name) = value.strip_prefix(SECRET_PREFIX) {
+        hook.fetch(name).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    let secret = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read secret file {}", path.display()))?;
+    Ok(trim_newline(secret))
+}
+
+fn trim_newline(mut secret: String) -> String {
+    let len = secret.trim_end_matches(['\n', '\r']).len();
+    secret.truncate(len);
+    secret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    fn table(content: &str) -> toml::Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_file_references() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("password");
+        std::fs::write(&path, "hunter2\n").unwrap();
+
+        let mut config = table(&format!(
+            "[opensearch]\npassword = \"file:{0}\"\nindex_name = \"file:{0}\"\n",
+            path.display()
+        ));
+        resolve(&mut config, &SecretsConfig::default()).unwrap();
+        assert_eq!(config["opensearch"]["password"].as_str(), Some("hunter2"));
+        // Not a credential: left as written
+        assert!(config["opensearch"]["index_name"]
+            .as_str()
+            .unwrap()
+            .starts_with(FILE_PREFIX));
+
+        let mut missing = table("[opensearch]\npassword = \"file:/nonexistent/password\"\n");
+        let err = resolve(&mut missing, &SecretsConfig::default()).unwrap_err();
+        assert!(format!("{err:#}").contains("opensearch.password"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_secret_command() {
+        let hook = SecretsConfig {
+            command: vec!["echo".to_string(), "value-of-{name}".to_string()],
+        };
+        let mut config = table(
+            "[[notifications.channels]]\ntype = \"slack\"\nwebhook_url = \"secret:slack_hook\"\n",
+        );
+        resolve(&mut config, &hook).unwrap();
+        assert_eq!(
+            config["notifications"]["channels"][0]["webhook_url"].as_str(),
+            Some("value-of-slack_hook")
+        );
+
+        let appended = SecretsConfig {
+            command: vec!["echo".to_string()],
+        };
+        assert_eq!(appended.fetch("db").unwrap(), "db");
+
+        let failing = SecretsConfig {
+            command: vec!["false".to_string()],
+        };
+        assert!(failing.fetch("db").is_err());
+
+        let mut unhooked = table("[database]\npostgres_url = \"secret:db\"\n");
+        assert!(resolve(&mut unhooked, &SecretsConfig::default()).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_read_env_file_variant() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        std::fs::write(&path, "s3cret\r\n").unwrap();
+
+        std::env::remove_var("BARAM_TEST_SECRET");
+        std::env::set_var("BARAM_TEST_SECRET_FILE", &path);
+        assert_eq!(
+            read_env("BARAM_TEST_SECRET").unwrap(),
+            Some(("BARAM_TEST_SECRET_FILE".to_string(), "s3cret".to_string()))
+        );
+
+        std::env::set_var("BARAM_TEST_SECRET", "plain");
+        assert!(read_env("BARAM_TEST_SECRET").is_err());
+
+        std::env::remove_var("BARAM_TEST_SECRET_FILE");
+        assert_eq!(
+            read_env("BARAM_TEST_SECRET").unwrap(),
+            Some(("BARAM_TEST_SECRET".to_string(), "plain".to_string()))
+        );
+        std::env::remove_var("BARAM_TEST_SECRET");
+    }
+}
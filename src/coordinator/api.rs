@@ -1,30 +1,106 @@
 //! REST API handlers for the Coordinator server
 //!
 //! This module defines the API routes and handlers for the coordinator.
+//! Every handler is annotated with `utoipa` so the OpenAPI document served at
+//! `/api/openapi.json` (and rendered by Swagger UI at `/swagger-ui`) stays in
+//! sync with the router.
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
     routing::{get, post},
     Json, Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::time::Instant;
+use tokio::sync::broadcast;
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
-use crate::metrics;
+use crate::notifications::{Alert, AlertStatus};
+use crate::scheduler::failover::{OverrideError, OverrideRequest as ScheduleOverride};
 use crate::scheduler::rotation::CrawlerInstance;
 use crate::scheduler::schedule::DailySchedule;
 
-use super::registry::{HeartbeatRequest, InstanceInfo, RegisterRequest, RegistryStats};
+use super::dashboard::DashboardApiDoc;
+use super::health::HealthApiDoc;
+use super::registry::{
+    HeartbeatRequest, HeartbeatResponse, InstanceInfo, RegisterRequest, RegisterResponse,
+    RegistryStats,
+};
 use super::server::AppState;
+use super::store::HeartbeatRecord;
+use super::work::{
+    CompleteRequest, CompleteResponse, EnqueueRequest, EnqueueResponse, LeaseRequest,
+    LeaseResponse, WorkError, WorkQueueStats,
+};
+
+// ============================================================================
+// OpenAPI Specification
+// ============================================================================
+
+/// OpenAPI document for the coordinator REST API
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Baram Coordinator API",
+        description = "Schedule distribution and instance management for distributed crawling"
+    ),
+    paths(
+        health_check,
+        metrics_handler,
+        get_today_schedule,
+        get_tomorrow_schedule,
+        get_schedule_by_date,
+        override_schedule,
+        stream_events,
+        list_instances,
+        get_instance,
+        get_heartbeats,
+        register_instance,
+        heartbeat,
+        set_maintenance,
+        enqueue_work,
+        lease_work,
+        complete_work,
+        get_stats,
+        list_alerts,
+        get_alert,
+        acknowledge_alert,
+        resolve_alert,
+    ),
+    tags(
+        (name = "health", description = "Liveness, readiness and health checks"),
+        (name = "schedule", description = "Daily crawl schedules"),
+        (name = "events", description = "Server-sent schedule, failover and override events"),
+        (name = "instances", description = "Crawler instance registration and heartbeats"),
+        (name = "work", description = "Pull-based work queue of URLs leased to instances"),
+        (name = "stats", description = "Coordinator statistics and metrics"),
+        (name = "alerts", description = "Alert listing, acknowledgement and resolution"),
+    )
+)]
+pub struct CoordinatorApiDoc;
+
+/// Build the complete coordinator OpenAPI document (API, dashboard and health probes)
+pub fn openapi_spec() -> utoipa::openapi::OpenApi {
+    let mut spec = CoordinatorApiDoc::openapi();
+    spec.merge(DashboardApiDoc::openapi());
+    spec.merge(HealthApiDoc::openapi());
+    spec
+}
 
 // ============================================================================
 // API Response Types
 // ============================================================================
 
 /// Generic API response wrapper
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ApiResponse<T: Serialize> {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,7 +130,7 @@ impl<T: Serialize + Default> ApiResponse<T> {
 }
 
 /// Simple error response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub success: bool,
     pub error: String,
@@ -70,7 +146,7 @@ impl ErrorResponse {
 }
 
 /// Health check response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct HealthResponse {
     pub status: String,
     pub version: String,
@@ -78,13 +154,14 @@ pub struct HealthResponse {
 }
 
 /// Schedule response
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, ToSchema)]
 pub struct ScheduleResponse {
     pub date: String,
     pub slots: Vec<SlotResponse>,
 }
 
-#[derive(Debug, Default, Serialize)]
+/// A single hourly slot in a schedule response
+#[derive(Debug, Default, Serialize, ToSchema)]
 pub struct SlotResponse {
     pub hour: u8,
     pub instance: String,
@@ -109,17 +186,20 @@ impl From<&DailySchedule> for ScheduleResponse {
 }
 
 /// Instance list response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct InstancesResponse {
     pub instances: Vec<InstanceInfo>,
     pub stats: RegistryStats,
 }
 
 /// Override request
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct OverrideRequest {
     pub hour: u8,
     pub instance: String,
+    /// Why the hour is reassigned, kept with the override
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 // ============================================================================
@@ -137,14 +217,27 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/schedule/today", get(get_today_schedule))
         .route("/api/schedule/tomorrow", get(get_tomorrow_schedule))
         .route("/api/schedule/{date}", get(get_schedule_by_date))
+        .route("/api/schedule/override", post(override_schedule))
+        // Event stream
+        .route("/api/events", get(stream_events))
         // Instance endpoints
         .route("/api/instances", get(list_instances))
         .route("/api/instances/{id}", get(get_instance))
         .route("/api/instances/register", post(register_instance))
         .route("/api/instances/heartbeat", post(heartbeat))
         .route("/api/instances/{id}/maintenance", post(set_maintenance))
+        .route("/api/instances/{id}/heartbeats", get(get_heartbeats))
+        // Work queue endpoints
+        .route("/api/work/enqueue", post(enqueue_work))
+        .route("/api/work/lease", post(lease_work))
+        .route("/api/work/complete", post(complete_work))
         // Stats endpoints
         .route("/api/stats", get(get_stats))
+        // Alert endpoints
+        .route("/api/alerts", get(list_alerts))
+        .route("/api/alerts/{id}", get(get_alert))
+        .route("/api/alerts/{id}/acknowledge", post(acknowledge_alert))
+        .route("/api/alerts/{id}/resolve", post(resolve_alert))
         .with_state(state)
 }
 
@@ -153,6 +246,12 @@ pub fn create_router(state: AppState) -> Router {
 // ============================================================================
 
 /// Health check endpoint
+#[utoipa::path(
+    get,
+    path = "/api/health",
+    tag = "health",
+    responses((status = 200, description = "Coordinator is running", body = ApiResponse<HealthResponse>))
+)]
 async fn health_check(State(state): State<AppState>) -> impl IntoResponse {
     let start = Instant::now();
     let uptime = state.start_time.elapsed().as_secs();
@@ -164,25 +263,25 @@ async fn health_check(State(state): State<AppState>) -> impl IntoResponse {
     }));
 
     let duration = start.elapsed().as_secs_f64();
-    metrics::record_api_request("/api/health", 200, duration);
+    state
+        .metrics
+        .record_api_request("/api/health", 200, duration);
 
     response
 }
 
 /// Prometheus metrics endpoint
-async fn metrics_handler() -> impl IntoResponse {
-    match metrics::encode_metrics() {
-        Ok(body) => (
-            StatusCode::OK,
-            [("Content-Type", "text/plain; version=0.0.4")],
-            body,
-        ),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            [("Content-Type", "text/plain; version=0.0.4")],
-            format!("Error encoding metrics: {e}"),
-        ),
-    }
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "stats",
+    responses(
+        (status = 200, description = "Prometheus text exposition format", body = String, content_type = "text/plain"),
+        (status = 500, description = "Metrics encoding failed", body = String, content_type = "text/plain")
+    )
+)]
+async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    state.metrics.http_response()
 }
 
 // ============================================================================
@@ -190,8 +289,17 @@ async fn metrics_handler() -> impl IntoResponse {
 // ============================================================================
 
 /// Get today's schedule
+#[utoipa::path(
+    get,
+    path = "/api/schedule/today",
+    tag = "schedule",
+    responses(
+        (status = 200, description = "Today's schedule", body = ApiResponse<ScheduleResponse>),
+        (status = 500, description = "Schedule unavailable", body = ApiResponse<ScheduleResponse>)
+    )
+)]
 async fn get_today_schedule(State(state): State<AppState>) -> impl IntoResponse {
-    match state.trigger.get_current_schedule().await {
+    match state.today_schedule().await {
         Ok(schedule) => (
             StatusCode::OK,
             Json(ApiResponse::success(ScheduleResponse::from(&schedule))),
@@ -206,6 +314,15 @@ async fn get_today_schedule(State(state): State<AppState>) -> impl IntoResponse
 }
 
 /// Get tomorrow's schedule
+#[utoipa::path(
+    get,
+    path = "/api/schedule/tomorrow",
+    tag = "schedule",
+    responses(
+        (status = 200, description = "Tomorrow's schedule", body = ApiResponse<ScheduleResponse>),
+        (status = 500, description = "Schedule generation failed", body = ApiResponse<ScheduleResponse>)
+    )
+)]
 async fn get_tomorrow_schedule(State(state): State<AppState>) -> impl IntoResponse {
     match state.trigger.generate_tomorrow_schedule().await {
         Ok(schedule) => (
@@ -222,6 +339,16 @@ async fn get_tomorrow_schedule(State(state): State<AppState>) -> impl IntoRespon
 }
 
 /// Get schedule for a specific date
+#[utoipa::path(
+    get,
+    path = "/api/schedule/{date}",
+    tag = "schedule",
+    params(("date" = String, Path, description = "Schedule date (YYYY-MM-DD)")),
+    responses(
+        (status = 200, description = "Schedule for the date", body = ApiResponse<ScheduleResponse>),
+        (status = 400, description = "Invalid date format", body = ApiResponse<ScheduleResponse>)
+    )
+)]
 async fn get_schedule_by_date(
     State(state): State<AppState>,
     Path(date_str): Path<String>,
@@ -248,11 +375,135 @@ async fn get_schedule_by_date(
     )
 }
 
+/// Assign an hour of today's schedule to an instance
+#[utoipa::path(
+    post,
+    path = "/api/schedule/override",
+    tag = "schedule",
+    request_body = OverrideRequest,
+    responses(
+        (status = 200, description = "Today's schedule with the override", body = ApiResponse<ScheduleResponse>),
+        (status = 400, description = "Invalid hour or instance ID", body = ErrorResponse),
+        (status = 500, description = "Schedule unavailable", body = ErrorResponse)
+    )
+)]
+async fn override_schedule(
+    State(state): State<AppState>,
+    Json(request): Json<OverrideRequest>,
+) -> axum::response::Response {
+    let instance = match CrawlerInstance::from_id(&request.instance) {
+        Ok(i) => i,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!(
+                    "Invalid instance ID: {}",
+                    request.instance
+                ))),
+            )
+                .into_response();
+        }
+    };
+
+    let schedule = match state.today_schedule().await {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new(e.to_string())),
+            )
+                .into_response();
+        }
+    };
+
+    let result = state
+        .overrides
+        .apply_override(ScheduleOverride {
+            date: schedule.date,
+            hours: vec![request.hour],
+            instance,
+            reason: request
+                .reason
+                .unwrap_or_else(|| "Manual override".to_string()),
+            operator: None,
+        })
+        .await;
+    if let Err(e @ OverrideError::InvalidHour(_)) = result {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(e.to_string())),
+        )
+            .into_response();
+    }
+    if let Err(e) = state.save_schedule().await {
+        tracing::warn!(error = %e, "Failed to cache overridden schedule");
+    }
+
+    match state.today_schedule().await {
+        Ok(schedule) => (
+            StatusCode::OK,
+            Json(ApiResponse::success(ScheduleResponse::from(&schedule))),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new(e.to_string())),
+        )
+            .into_response(),
+    }
+}
+
+// ============================================================================
+// Event Handlers
+// ============================================================================
+
+/// Stream schedule, failover and override events
+///
+/// Each server-sent event is named after the kind of distribution event
+/// (`schedule_ready`, `schedule_updated`, `assignment_changed`, ...) and
+/// carries it as JSON.
+#[utoipa::path(
+    get,
+    path = "/api/events",
+    tag = "events",
+    responses((status = 200, description = "Event stream", content_type = "text/event-stream", body = String))
+)]
+async fn stream_events(
+    State(state): State<AppState>,
+) -> Sse<impl futures::Stream<Item = Result<Event, Infallible>>> {
+    let receiver = state.distributor.subscribe();
+    let stream = futures::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    let sse = Event::default()
+                        .event(event.name())
+                        .json_data(&event)
+                        .unwrap_or_else(|_| Event::default().comment("unserializable event"));
+                    return Some((Ok(sse), receiver));
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    tracing::warn!(missed, "Event subscriber lagged, events dropped");
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
 // ============================================================================
 // Instance Handlers
 // ============================================================================
 
 /// List all registered instances
+#[utoipa::path(
+    get,
+    path = "/api/instances",
+    tag = "instances",
+    responses((status = 200, description = "Registered instances", body = ApiResponse<InstancesResponse>))
+)]
 async fn list_instances(State(state): State<AppState>) -> impl IntoResponse {
     let instances = state.registry.get_all_instances().await;
     let stats = state.registry.stats().await;
@@ -261,6 +512,17 @@ async fn list_instances(State(state): State<AppState>) -> impl IntoResponse {
 }
 
 /// Get a specific instance
+#[utoipa::path(
+    get,
+    path = "/api/instances/{id}",
+    tag = "instances",
+    params(("id" = String, Path, description = "Instance ID (main, sub1, sub2)")),
+    responses(
+        (status = 200, description = "Instance details", body = ApiResponse<InstanceInfo>),
+        (status = 400, description = "Invalid instance ID", body = ErrorResponse),
+        (status = 404, description = "Instance not registered", body = ErrorResponse)
+    )
+)]
 async fn get_instance(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -286,7 +548,73 @@ async fn get_instance(
     }
 }
 
+/// Heartbeat history window
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct HeartbeatQuery {
+    /// Hours of history, ending now (default 24)
+    pub hours: Option<i64>,
+}
+
+/// Get the heartbeat history of an instance, oldest first
+///
+/// Needs the coordinator to run with a registry database.
+#[utoipa::path(
+    get,
+    path = "/api/instances/{id}/heartbeats",
+    tag = "instances",
+    params(("id" = String, Path, description = "Instance ID (main, sub1, sub2)"), HeartbeatQuery),
+    responses(
+        (status = 200, description = "Heartbeats in the window", body = ApiResponse<Vec<HeartbeatRecord>>),
+        (status = 400, description = "Invalid instance ID", body = ErrorResponse),
+        (status = 500, description = "Registry database error", body = ErrorResponse),
+        (status = 503, description = "No registry database configured", body = ErrorResponse)
+    )
+)]
+async fn get_heartbeats(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<HeartbeatQuery>,
+) -> axum::response::Response {
+    let instance = match CrawlerInstance::from_id(&id) {
+        Ok(i) => i,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!("Invalid instance ID: {id}"))),
+            )
+                .into_response();
+        }
+    };
+    let Some(store) = state.registry.store() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ErrorResponse::new("Registry database is not configured")),
+        )
+            .into_response();
+    };
+
+    let since = Utc::now() - chrono::Duration::hours(query.hours.unwrap_or(24).max(1));
+    match store.heartbeats(instance, since) {
+        Ok(records) => (StatusCode::OK, Json(ApiResponse::success(records))).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new(e.to_string())),
+        )
+            .into_response(),
+    }
+}
+
 /// Register a new instance
+#[utoipa::path(
+    post,
+    path = "/api/instances/register",
+    tag = "instances",
+    request_body = RegisterRequest,
+    responses(
+        (status = 200, description = "Instance registered", body = ApiResponse<RegisterResponse>),
+        (status = 400, description = "Registration rejected", body = ErrorResponse)
+    )
+)]
 async fn register_instance(
     State(state): State<AppState>,
     Json(request): Json<RegisterRequest>,
@@ -297,16 +625,22 @@ async fn register_instance(
         Ok(response) => {
             // Update instance metrics
             let stats = state.registry.stats().await;
-            metrics::update_coordinator_instance_metrics(stats.total_instances, stats.online);
+            state
+                .metrics
+                .update_coordinator_instance_metrics(stats.total_instances, stats.online);
 
             let duration = start.elapsed().as_secs_f64();
-            metrics::record_api_request("/api/instances/register", 200, duration);
+            state
+                .metrics
+                .record_api_request("/api/instances/register", 200, duration);
 
             (StatusCode::OK, Json(ApiResponse::success(response))).into_response()
         }
         Err(e) => {
             let duration = start.elapsed().as_secs_f64();
-            metrics::record_api_request("/api/instances/register", 400, duration);
+            state
+                .metrics
+                .record_api_request("/api/instances/register", 400, duration);
 
             (
                 StatusCode::BAD_REQUEST,
@@ -320,6 +654,16 @@ async fn register_instance(
 }
 
 /// Process heartbeat from instance
+#[utoipa::path(
+    post,
+    path = "/api/instances/heartbeat",
+    tag = "instances",
+    request_body = HeartbeatRequest,
+    responses(
+        (status = 200, description = "Heartbeat accepted", body = ApiResponse<HeartbeatResponse>),
+        (status = 400, description = "Heartbeat rejected", body = ErrorResponse)
+    )
+)]
 async fn heartbeat(
     State(state): State<AppState>,
     Json(request): Json<HeartbeatRequest>,
@@ -332,22 +676,30 @@ async fn heartbeat(
     let response = match state.registry.heartbeat(request).await {
         Ok(response) => {
             // Record heartbeat metrics
-            metrics::record_heartbeat(&instance_id, articles, errors);
+            state
+                .metrics
+                .record_heartbeat(&instance_id, articles, errors);
 
             // Update instance metrics
             let stats = state.registry.stats().await;
-            metrics::update_coordinator_instance_metrics(stats.total_instances, stats.online);
+            state
+                .metrics
+                .update_coordinator_instance_metrics(stats.total_instances, stats.online);
 
             let duration = start.elapsed().as_secs_f64();
-            metrics::record_api_request("/api/instances/heartbeat", 200, duration);
+            state
+                .metrics
+                .record_api_request("/api/instances/heartbeat", 200, duration);
 
             (StatusCode::OK, Json(ApiResponse::success(response))).into_response()
         }
         Err(e) => {
-            metrics::record_heartbeat_error();
+            state.metrics.record_heartbeat_error();
 
             let duration = start.elapsed().as_secs_f64();
-            metrics::record_api_request("/api/instances/heartbeat", 400, duration);
+            state
+                .metrics
+                .record_api_request("/api/instances/heartbeat", 400, duration);
 
             (
                 StatusCode::BAD_REQUEST,
@@ -361,11 +713,23 @@ async fn heartbeat(
 }
 
 /// Set maintenance mode for an instance
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct MaintenanceRequest {
     pub enabled: bool,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/instances/{id}/maintenance",
+    tag = "instances",
+    params(("id" = String, Path, description = "Instance ID (main, sub1, sub2)")),
+    request_body = MaintenanceRequest,
+    responses(
+        (status = 200, description = "Maintenance mode updated", body = ApiResponse<String>),
+        (status = 400, description = "Invalid instance ID", body = ErrorResponse),
+        (status = 404, description = "Instance not registered", body = ErrorResponse)
+    )
+)]
 async fn set_maintenance(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -408,31 +772,446 @@ async fn set_maintenance(
     }
 }
 
+// ============================================================================
+// Work Queue Handlers
+// ============================================================================
+
+/// Add URLs to the work queue
+#[utoipa::path(
+    post,
+    path = "/api/work/enqueue",
+    tag = "work",
+    request_body = EnqueueRequest,
+    responses((status = 200, description = "URLs queued", body = ApiResponse<EnqueueResponse>))
+)]
+async fn enqueue_work(
+    State(state): State<AppState>,
+    Json(request): Json<EnqueueRequest>,
+) -> impl IntoResponse {
+    let response = state.work.enqueue(request.urls).await;
+    tracing::info!(
+        queued = response.queued,
+        pending = response.pending,
+        "URLs added to work queue"
+    );
+
+    Json(ApiResponse::success(response))
+}
+
+/// Lease a batch of URLs
+///
+/// Instances ask for work when they have capacity, so busy instances get
+/// fewer URLs. Instances that are offline or in maintenance get no lease.
+#[utoipa::path(
+    post,
+    path = "/api/work/lease",
+    tag = "work",
+    request_body = LeaseRequest,
+    responses(
+        (status = 200, description = "Leased batch, if there is work", body = ApiResponse<LeaseResponse>),
+        (status = 400, description = "Invalid instance ID", body = ErrorResponse),
+        (status = 404, description = "Instance not registered", body = ErrorResponse)
+    )
+)]
+async fn lease_work(
+    State(state): State<AppState>,
+    Json(request): Json<LeaseRequest>,
+) -> axum::response::Response {
+    let start = Instant::now();
+
+    let instance = match CrawlerInstance::from_id(&request.instance_id) {
+        Ok(i) => i,
+        Err(_) => {
+            state
+                .metrics
+                .record_api_request("/api/work/lease", 400, start.elapsed().as_secs_f64());
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!(
+                    "Invalid instance ID: {}",
+                    request.instance_id
+                ))),
+            )
+                .into_response();
+        }
+    };
+
+    let lease = match state.registry.get_instance(instance).await {
+        Some(info) if info.status.is_available() => {
+            state.work.lease(instance, request.max_urls).await
+        }
+        Some(_) => None,
+        None => {
+            state
+                .metrics
+                .record_api_request("/api/work/lease", 404, start.elapsed().as_secs_f64());
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new(format!(
+                    "Instance not found: {}",
+                    request.instance_id
+                ))),
+            )
+                .into_response();
+        }
+    };
+
+    let duration = start.elapsed().as_secs_f64();
+    state
+        .metrics
+        .record_api_request("/api/work/lease", 200, duration);
+
+    (
+        StatusCode::OK,
+        Json(ApiResponse::success(LeaseResponse { lease })),
+    )
+        .into_response()
+}
+
+/// Complete a lease
+///
+/// Every leased URL counts as done except those listed in `retry`, which go
+/// back into the queue.
+#[utoipa::path(
+    post,
+    path = "/api/work/complete",
+    tag = "work",
+    request_body = CompleteRequest,
+    responses(
+        (status = 200, description = "Lease completed", body = ApiResponse<CompleteResponse>),
+        (status = 400, description = "Invalid instance ID", body = ErrorResponse),
+        (status = 404, description = "Lease not found or expired", body = ErrorResponse),
+        (status = 409, description = "Lease held by another instance", body = ErrorResponse)
+    )
+)]
+async fn complete_work(
+    State(state): State<AppState>,
+    Json(request): Json<CompleteRequest>,
+) -> axum::response::Response {
+    let start = Instant::now();
+
+    let instance = match CrawlerInstance::from_id(&request.instance_id) {
+        Ok(i) => i,
+        Err(_) => {
+            state.metrics.record_api_request(
+                "/api/work/complete",
+                400,
+                start.elapsed().as_secs_f64(),
+            );
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!(
+                    "Invalid instance ID: {}",
+                    request.instance_id
+                ))),
+            )
+                .into_response();
+        }
+    };
+
+    let (status, response) = match state
+        .work
+        .complete(instance, &request.lease_id, &request.retry)
+        .await
+    {
+        Ok(response) => (
+            StatusCode::OK,
+            Json(ApiResponse::success(response)).into_response(),
+        ),
+        Err(e) => {
+            let status = match e {
+                WorkError::LeaseNotFound(_) => StatusCode::NOT_FOUND,
+                WorkError::NotLeaseHolder { .. } => StatusCode::CONFLICT,
+            };
+            (
+                status,
+                Json(ErrorResponse::new(e.to_string())).into_response(),
+            )
+        }
+    };
+
+    let duration = start.elapsed().as_secs_f64();
+    state
+        .metrics
+        .record_api_request("/api/work/complete", status.as_u16(), duration);
+
+    (status, response).into_response()
+}
+
 // ============================================================================
 // Stats Handlers
 // ============================================================================
 
+/// Coordinator stats response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StatsResponse {
+    pub registry: RegistryStats,
+    pub work: WorkQueueStats,
+    pub cache_valid: bool,
+    pub cache_has_schedule: bool,
+    pub uptime_secs: u64,
+}
+
 /// Get coordinator stats
+#[utoipa::path(
+    get,
+    path = "/api/stats",
+    tag = "stats",
+    responses((status = 200, description = "Coordinator statistics", body = ApiResponse<StatsResponse>))
+)]
 async fn get_stats(State(state): State<AppState>) -> impl IntoResponse {
     let registry_stats = state.registry.stats().await;
     let cache_status = state.cache.status().await;
 
-    #[derive(Serialize)]
-    struct StatsResponse {
-        registry: RegistryStats,
-        cache_valid: bool,
-        cache_has_schedule: bool,
-        uptime_secs: u64,
-    }
-
     Json(ApiResponse::success(StatsResponse {
         registry: registry_stats,
+        work: state.work.stats().await,
         cache_valid: cache_status.is_valid,
         cache_has_schedule: cache_status.has_schedule,
         uptime_secs: state.start_time.elapsed().as_secs(),
     }))
 }
 
+// ============================================================================
+// Alert Handlers
+// ============================================================================
+
+/// Alert as returned by the API
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertResponse {
+    pub id: String,
+    /// Rule that fired the alert, if any
+    pub rule: Option<String>,
+    pub severity: String,
+    pub status: String,
+    pub message: String,
+    pub condition: String,
+    pub condition_type: String,
+    pub occurrence_count: u32,
+    pub metadata: HashMap<String, String>,
+    pub created_at: DateTime<Utc>,
+    pub triggered_at: Option<DateTime<Utc>>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    pub acknowledged_by: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by: Option<String>,
+}
+
+impl From<&Alert> for AlertResponse {
+    fn from(alert: &Alert) -> Self {
+        Self {
+            id: alert.id.clone(),
+            rule: alert.metadata.get("rule").cloned(),
+            severity: alert.severity.as_str().to_string(),
+            status: alert.status.as_str().to_string(),
+            message: alert.message.clone(),
+            condition: alert.condition.description(),
+            condition_type: alert.condition.condition_type().to_string(),
+            occurrence_count: alert.occurrence_count,
+            metadata: alert.metadata.clone(),
+            created_at: alert.created_at,
+            triggered_at: alert.triggered_at,
+            acknowledged_at: alert.acknowledged_at,
+            acknowledged_by: alert.acknowledged_by.clone(),
+            resolved_at: alert.resolved_at,
+            resolved_by: alert.resolved_by.clone(),
+        }
+    }
+}
+
+/// Alert list filter
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AlertQuery {
+    /// `active` (default), `created`, `triggered`, `acknowledged`, `resolved` or `all`
+    pub status: Option<String>,
+}
+
+/// Operator performing an alert action
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AlertActionRequest {
+    /// Name of the operator acknowledging or resolving the alert
+    pub actor: String,
+}
+
+/// List alerts, newest first
+#[utoipa::path(
+    get,
+    path = "/api/alerts",
+    tag = "alerts",
+    params(AlertQuery),
+    responses(
+        (status = 200, description = "Alerts matching the filter", body = ApiResponse<Vec<AlertResponse>>),
+        (status = 400, description = "Unknown status filter", body = ErrorResponse)
+    )
+)]
+async fn list_alerts(
+    State(state): State<AppState>,
+    Query(query): Query<AlertQuery>,
+) -> axum::response::Response {
+    let filter = query.status.as_deref().unwrap_or("active");
+    let manager = state.notifications.read().await;
+
+    let mut alerts: Vec<&Alert> = match filter {
+        "active" => manager.active_alerts(),
+        "all" => manager.all_alerts(),
+        "created" | "triggered" | "acknowledged" | "resolved" => manager
+            .all_alerts()
+            .into_iter()
+            .filter(|a| a.status.as_str() == filter)
+            .collect(),
+        other => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!(
+                    "Invalid status filter: {other}"
+                ))),
+            )
+                .into_response();
+        }
+    };
+    alerts.sort_by_key(|a| std::cmp::Reverse(a.created_at));
+
+    let alerts: Vec<AlertResponse> = alerts.into_iter().map(AlertResponse::from).collect();
+    (StatusCode::OK, Json(ApiResponse::success(alerts))).into_response()
+}
+
+/// Get a specific alert
+#[utoipa::path(
+    get,
+    path = "/api/alerts/{id}",
+    tag = "alerts",
+    params(("id" = String, Path, description = "Alert ID")),
+    responses(
+        (status = 200, description = "Alert details", body = ApiResponse<AlertResponse>),
+        (status = 404, description = "Alert not found", body = ErrorResponse)
+    )
+)]
+async fn get_alert(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> axum::response::Response {
+    match state.notifications.read().await.get_alert(&id) {
+        Some(alert) => (
+            StatusCode::OK,
+            Json(ApiResponse::success(AlertResponse::from(alert))),
+        )
+            .into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(format!("Alert not found: {id}"))),
+        )
+            .into_response(),
+    }
+}
+
+/// Acknowledge an alert
+///
+/// Acknowledged alerts are no longer active and stop escalating.
+#[utoipa::path(
+    post,
+    path = "/api/alerts/{id}/acknowledge",
+    tag = "alerts",
+    params(("id" = String, Path, description = "Alert ID")),
+    request_body = AlertActionRequest,
+    responses(
+        (status = 200, description = "Alert acknowledged", body = ApiResponse<AlertResponse>),
+        (status = 400, description = "Missing actor", body = ErrorResponse),
+        (status = 404, description = "Alert not found", body = ErrorResponse),
+        (status = 409, description = "Alert is already acknowledged or resolved", body = ErrorResponse)
+    )
+)]
+async fn acknowledge_alert(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<AlertActionRequest>,
+) -> axum::response::Response {
+    update_alert(&state, &id, request, AlertStatus::Acknowledged).await
+}
+
+/// Resolve an alert
+#[utoipa::path(
+    post,
+    path = "/api/alerts/{id}/resolve",
+    tag = "alerts",
+    params(("id" = String, Path, description = "Alert ID")),
+    request_body = AlertActionRequest,
+    responses(
+        (status = 200, description = "Alert resolved", body = ApiResponse<AlertResponse>),
+        (status = 400, description = "Missing actor", body = ErrorResponse),
+        (status = 404, description = "Alert not found", body = ErrorResponse),
+        (status = 409, description = "Alert is already resolved", body = ErrorResponse)
+    )
+)]
+async fn resolve_alert(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<AlertActionRequest>,
+) -> axum::response::Response {
+    update_alert(&state, &id, request, AlertStatus::Resolved).await
+}
+
+/// Move an alert to `target` on behalf of the requesting operator
+async fn update_alert(
+    state: &AppState,
+    id: &str,
+    request: AlertActionRequest,
+    target: AlertStatus,
+) -> axum::response::Response {
+    let actor = request.actor.trim();
+    if actor.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("actor cannot be empty")),
+        )
+            .into_response();
+    }
+
+    let mut manager = state.notifications.write().await;
+    let status = match manager.get_alert(id) {
+        Some(alert) => alert.status,
+        None => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new(format!("Alert not found: {id}"))),
+            )
+                .into_response();
+        }
+    };
+
+    // Acknowledging twice or touching a resolved alert would overwrite who handled it
+    if status == AlertStatus::Resolved || status == target {
+        return (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(format!(
+                "Alert {id} is already {status}"
+            ))),
+        )
+            .into_response();
+    }
+
+    let result = match target {
+        AlertStatus::Acknowledged => manager.acknowledge_alert(id, actor.to_string()),
+        _ => manager.resolve_alert_by(id, actor.to_string()),
+    };
+    if let Err(e) = result {
+        return (StatusCode::NOT_FOUND, Json(ErrorResponse::new(e))).into_response();
+    }
+    tracing::info!(alert_id = %id, actor = %actor, status = %target, "Alert updated");
+
+    match manager.get_alert(id) {
+        Some(alert) => (
+            StatusCode::OK,
+            Json(ApiResponse::success(AlertResponse::from(alert))),
+        )
+            .into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(format!("Alert not found: {id}"))),
+        )
+            .into_response(),
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -470,4 +1249,256 @@ mod tests {
         assert_eq!(response.date, "2024-01-15");
         assert_eq!(response.slots.len(), 24);
     }
+
+    #[test]
+    fn test_openapi_spec_covers_routes() {
+        let spec = openapi_spec();
+        let paths = &spec.paths.paths;
+
+        for path in [
+            "/api/health",
+            "/metrics",
+            "/api/schedule/today",
+            "/api/schedule/{date}",
+            "/api/schedule/override",
+            "/api/events",
+            "/api/instances",
+            "/api/instances/{id}",
+            "/api/instances/register",
+            "/api/instances/heartbeat",
+            "/api/instances/{id}/maintenance",
+            "/api/instances/{id}/heartbeats",
+            "/api/work/enqueue",
+            "/api/work/lease",
+            "/api/work/complete",
+            "/api/stats",
+            "/api/alerts",
+            "/api/alerts/{id}",
+            "/api/alerts/{id}/acknowledge",
+            "/api/alerts/{id}/resolve",
+            "/api/dashboard/volume",
+            "/api/dashboard/instances",
+            "/api/dashboard/keywords",
+            "/api/dashboard/alerts",
+            "/api/dashboard/index-lag",
+            "/health/live",
+            "/health/ready",
+        ] {
+            assert!(paths.contains_key(path), "missing path: {path}");
+        }
+
+        let schemas = &spec.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("RegisterRequest"));
+        assert!(schemas.contains_key("InstanceInfo"));
+        assert!(schemas.contains_key("AlertResponse"));
+        assert!(schemas.contains_key("WorkLease"));
+        assert!(schemas.contains_key("InstanceMapResponse"));
+    }
+
+    async fn alert_router() -> (Router, String) {
+        use crate::coordinator::{CoordinatorConfig, CoordinatorServer};
+        use crate::notifications::{AlertCondition, AlertSeverity};
+
+        let server = CoordinatorServer::new(CoordinatorConfig::default()).unwrap();
+        let state = server.state();
+        let alert = state
+            .notifications
+            .write()
+            .await
+            .alert(
+                AlertCondition::SourceFailure {
+                    source: "naver".to_string(),
+                    failure_count: 3,
+                },
+                AlertSeverity::Warning,
+                "Crawler failing".to_string(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        (create_router(state), alert.id)
+    }
+
+    async fn send(
+        router: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Content-Type", "application/json")
+            .body(match body {
+                Some(json) => Body::from(json.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_alert_acknowledge_and_resolve() {
+        let (router, id) = alert_router().await;
+
+        let (status, body) = send(&router, "GET", "/api/alerts", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"][0]["id"], id.as_str());
+        assert_eq!(body["data"][0]["status"], "triggered");
+
+        let ack = serde_json::json!({ "actor": "kim" });
+        let uri = format!("/api/alerts/{id}/acknowledge");
+        let (status, body) = send(&router, "POST", &uri, Some(ack.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "acknowledged");
+        assert_eq!(body["data"]["acknowledged_by"], "kim");
+
+        // Acknowledged alerts drop out of the active list
+        let (_, body) = send(&router, "GET", "/api/alerts", None).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 0);
+        let (status, _) = send(&router, "POST", &uri, Some(ack)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let uri = format!("/api/alerts/{id}/resolve");
+        let (status, body) = send(
+            &router,
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "actor": "lee" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "resolved");
+        assert_eq!(body["data"]["resolved_by"], "lee");
+
+        let (_, body) = send(&router, "GET", "/api/alerts?status=resolved", None).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_work_lease_and_complete() {
+        use crate::coordinator::{CoordinatorConfig, CoordinatorServer};
+
+        let server = CoordinatorServer::new(CoordinatorConfig::default()).unwrap();
+        let router = create_router(server.state());
+        let lease = serde_json::json!({ "instance_id": "main", "max_urls": 10 });
+
+        // Only registered instances get work
+        let (status, _) = send(&router, "POST", "/api/work/lease", Some(lease.clone())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let register = serde_json::json!({
+            "instance_id": "main",
+            "ip_address": "127.0.0.1",
+            "port": 9000,
+            "version": null
+        });
+        send(&router, "POST", "/api/instances/register", Some(register)).await;
+
+        let urls = serde_json::json!({ "urls": ["https://a", "https://b"] });
+        let (_, body) = send(&router, "POST", "/api/work/enqueue", Some(urls)).await;
+        assert_eq!(body["data"]["queued"], 2);
+
+        let (status, body) = send(&router, "POST", "/api/work/lease", Some(lease.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        let lease_id = body["data"]["lease"]["lease_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(body["data"]["lease"]["urls"].as_array().unwrap().len(), 2);
+        let (_, body) = send(&router, "POST", "/api/work/lease", Some(lease)).await;
+        assert!(body["data"]["lease"].is_null());
+
+        let complete = serde_json::json!({
+            "instance_id": "sub1",
+            "lease_id": lease_id,
+            "retry": ["https://b"]
+        });
+        let (status, _) = send(&router, "POST", "/api/work/complete", Some(complete)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let complete = serde_json::json!({
+            "instance_id": "main",
+            "lease_id": lease_id,
+            "retry": ["https://b"]
+        });
+        let (status, body) = send(&router, "POST", "/api/work/complete", Some(complete)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["completed"], 1);
+        assert_eq!(body["data"]["requeued"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_override_publishes_event() {
+        use crate::coordinator::{CoordinatorConfig, CoordinatorServer};
+        use crate::scheduler::distribution::{DistributionEvent, UpdateReason};
+
+        let server = CoordinatorServer::new(CoordinatorConfig::default()).unwrap();
+        let state = server.state();
+        let router = create_router(state.clone());
+        let today = state.today_schedule().await.unwrap();
+        let mut events = state.distributor.subscribe();
+
+        let request = serde_json::json!({ "hour": 24, "instance": "sub2" });
+        let (status, _) = send(&router, "POST", "/api/schedule/override", Some(request)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let request = serde_json::json!({ "hour": 5, "instance": "sub2", "reason": "test" });
+        let (status, body) = send(&router, "POST", "/api/schedule/override", Some(request)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["slots"][5]["instance"], "sub2");
+        assert_eq!(
+            state.cache.get().await.unwrap().instance_at_hour(5),
+            Some(CrawlerInstance::Sub2)
+        );
+
+        match events.recv().await.unwrap() {
+            DistributionEvent::ScheduleUpdated {
+                date,
+                reason,
+                affected_hours,
+            } => {
+                assert_eq!(date, today.date);
+                assert_eq!(reason, UpdateReason::ManualOverride);
+                assert_eq!(affected_hours, [5]);
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alert_action_errors() {
+        let (router, id) = alert_router().await;
+
+        let (status, _) = send(
+            &router,
+            "POST",
+            "/api/alerts/missing/acknowledge",
+            Some(serde_json::json!({ "actor": "kim" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let uri = format!("/api/alerts/{id}/resolve");
+        let (status, _) = send(
+            &router,
+            "POST",
+            &uri,
+            Some(serde_json::json!({ "actor": " " })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send(&router, "GET", "/api/alerts?status=bogus", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
 }
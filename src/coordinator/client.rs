@@ -7,10 +7,12 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+use crate::scheduler::distribution::DistributionEvent;
 use crate::scheduler::rotation::CrawlerInstance;
 use crate::scheduler::schedule::ScheduleCache;
 
 use super::registry::{HeartbeatRequest, HeartbeatResponse, RegisterRequest, RegisterResponse};
+use super::work::{CompleteRequest, CompleteResponse, LeaseRequest, LeaseResponse, WorkLease};
 
 // ============================================================================
 // Client Configuration
@@ -170,6 +172,53 @@ impl CoordinatorClient {
         })
     }
 
+    /// Lease up to `max_urls` URLs from the coordinator's work queue
+    ///
+    /// Returns `None` when there is no work for this instance.
+    pub async fn lease_work(&self, max_urls: usize) -> Result<Option<WorkLease>, ClientError> {
+        let request = LeaseRequest {
+            instance_id: self.config.instance_id.id().to_string(),
+            max_urls,
+        };
+
+        let url = format!("{}/api/work/lease", self.config.coordinator_url);
+
+        let response: ApiResponse<LeaseResponse> = self.post_with_retry(&url, &request).await?;
+
+        response.data.map(|data| data.lease).ok_or_else(|| {
+            ClientError::InvalidResponse(
+                response
+                    .error
+                    .unwrap_or_else(|| "Missing lease response data".to_string()),
+            )
+        })
+    }
+
+    /// Complete a lease, asking for the URLs in `retry` to be handed out again
+    pub async fn complete_work(
+        &self,
+        lease_id: &str,
+        retry: Vec<String>,
+    ) -> Result<CompleteResponse, ClientError> {
+        let request = CompleteRequest {
+            instance_id: self.config.instance_id.id().to_string(),
+            lease_id: lease_id.to_string(),
+            retry,
+        };
+
+        let url = format!("{}/api/work/complete", self.config.coordinator_url);
+
+        let response: ApiResponse<CompleteResponse> = self.post_with_retry(&url, &request).await?;
+
+        response.data.ok_or_else(|| {
+            ClientError::InvalidResponse(
+                response
+                    .error
+                    .unwrap_or_else(|| "Missing complete response data".to_string()),
+            )
+        })
+    }
+
     /// Get today's schedule
     pub async fn get_today_schedule(&self) -> Result<ScheduleResponse, ClientError> {
         let url = format!("{}/api/schedule/today", self.config.coordinator_url);
@@ -232,6 +281,36 @@ impl CoordinatorClient {
         }
     }
 
+    /// Subscribe to the coordinator's schedule, failover and override events
+    ///
+    /// The stream stays open until the coordinator closes it, so it does not
+    /// use the request timeout; only connecting is bounded by it.
+    pub async fn subscribe_events(&self) -> Result<EventStream, ClientError> {
+        let url = format!("{}/api/events", self.config.coordinator_url);
+        let client = Client::builder()
+            .connect_timeout(self.config.timeout)
+            .build()
+            .map_err(|e| ClientError::InitError(e.to_string()))?;
+
+        let response = client
+            .get(&url)
+            .header("Accept", "text/event-stream")
+            .send()
+            .await
+            .map_err(|e| ClientError::NetworkError(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ClientError::HttpError {
+                status: response.status().as_u16(),
+                message: response.text().await.unwrap_or_default(),
+            });
+        }
+
+        Ok(EventStream {
+            response,
+            buffer: Vec::new(),
+        })
+    }
+
     /// Check coordinator health
     pub async fn health_check(&self) -> Result<HealthStatus, ClientError> {
         let url = format!("{}/api/health", self.config.coordinator_url);
@@ -353,6 +432,51 @@ impl CoordinatorClient {
     }
 }
 
+// ============================================================================
+// Event Stream
+// ============================================================================
+
+/// Server-sent events from `GET /api/events`
+pub struct EventStream {
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+}
+
+impl EventStream {
+    /// Wait for the next event; `None` once the coordinator closed the stream
+    pub async fn next_event(&mut self) -> Result<Option<DistributionEvent>, ClientError> {
+        loop {
+            while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+                let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+                if let Some(data) = sse_data(&String::from_utf8_lossy(&block)) {
+                    return serde_json::from_str(&data)
+                        .map(Some)
+                        .map_err(|e| ClientError::ParseError(e.to_string()));
+                }
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => self
+                    .buffer
+                    .extend(chunk.iter().filter(|byte| **byte != b'\r')),
+                Ok(None) => return Ok(None),
+                Err(e) => return Err(ClientError::NetworkError(e.to_string())),
+            }
+        }
+    }
+}
+
+/// The data of one server-sent event block, `None` for comments such as
+/// keep-alives
+fn sse_data(block: &str) -> Option<String> {
+    let lines: Vec<&str> = block
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.strip_prefix(' ').unwrap_or(data))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
 // ============================================================================
 // Response Types
 // ============================================================================
@@ -442,6 +566,16 @@ mod tests {
         assert_eq!(config.retry_count, 5);
     }
 
+    #[test]
+    fn test_sse_data() {
+        assert_eq!(sse_data(":\n\n"), None);
+
+        let block = "event: rotation_triggered\ndata: {\"RotationTriggered\":\ndata: {\"from_date\":\"2026-10-19\",\"to_date\":\"2026-10-20\"}}\n\n";
+        let data = sse_data(block).unwrap();
+        let event: DistributionEvent = serde_json::from_str(&data).unwrap();
+        assert_eq!(event.name(), "rotation_triggered");
+    }
+
     #[test]
     fn test_client_creation() {
         let config = ClientConfig::new("http://localhost:8080", CrawlerInstance::Main);
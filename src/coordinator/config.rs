@@ -28,11 +28,42 @@ pub struct CoordinatorConfig {
     /// Schedule cache file path (optional)
     pub schedule_cache_path: Option<String>,
 
+    /// SQLite file that keeps registered instances and their heartbeats
+    /// across restarts (optional)
+    #[serde(default)]
+    pub registry_path: Option<String>,
+
+    /// Days of heartbeat history kept; instances silent for longer are not
+    /// restored
+    #[serde(default = "default_registry_retention_days")]
+    pub registry_retention_days: u32,
+
     /// Enable request logging
     pub enable_request_logging: bool,
 
     /// API key for authentication (optional)
     pub api_key: Option<String>,
+
+    /// Seconds an instance has to complete a work lease before its URLs
+    /// are handed out again
+    #[serde(default = "default_work_lease_secs")]
+    pub work_lease_secs: u64,
+
+    /// Maximum URLs in one work lease
+    #[serde(default = "default_work_batch_size")]
+    pub work_batch_size: usize,
+}
+
+fn default_registry_retention_days() -> u32 {
+    7
+}
+
+fn default_work_lease_secs() -> u64 {
+    300
+}
+
+fn default_work_batch_size() -> usize {
+    50
 }
 
 impl Default for CoordinatorConfig {
@@ -47,8 +78,12 @@ impl Default for CoordinatorConfig {
             enable_cors: true,
             max_instances: 10,
             schedule_cache_path: None,
+            registry_path: None,
+            registry_retention_days: default_registry_retention_days(),
             enable_request_logging: true,
             api_key: None,
+            work_lease_secs: default_work_lease_secs(),
+            work_batch_size: default_work_batch_size(),
         }
     }
 }
@@ -75,6 +110,34 @@ impl CoordinatorConfig {
             });
         }
 
+        if self.registry_retention_days == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "registry_retention_days".to_string(),
+                reason: "Must keep at least 1 day".to_string(),
+            });
+        }
+
+        if super::store::retention_window(self.registry_retention_days).is_none() {
+            return Err(ConfigError::InvalidValue {
+                field: "registry_retention_days".to_string(),
+                reason: "Too many days".to_string(),
+            });
+        }
+
+        if self.work_lease_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "work_lease_secs".to_string(),
+                reason: "Must be at least 1 second".to_string(),
+            });
+        }
+
+        if self.work_batch_size == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "work_batch_size".to_string(),
+                reason: "Must lease at least 1 URL".to_string(),
+            });
+        }
+
         Ok(())
     }
 }
@@ -88,8 +151,12 @@ pub struct CoordinatorConfigBuilder {
     enable_cors: Option<bool>,
     max_instances: Option<usize>,
     schedule_cache_path: Option<String>,
+    registry_path: Option<String>,
+    registry_retention_days: Option<u32>,
     enable_request_logging: Option<bool>,
     api_key: Option<String>,
+    work_lease_secs: Option<u64>,
+    work_batch_size: Option<usize>,
 }
 
 impl CoordinatorConfigBuilder {
@@ -138,6 +205,18 @@ impl CoordinatorConfigBuilder {
         self
     }
 
+    /// Set registry database path
+    pub fn registry_path(mut self, path: impl Into<String>) -> Self {
+        self.registry_path = Some(path.into());
+        self
+    }
+
+    /// Set registry retention in days
+    pub fn registry_retention_days(mut self, days: u32) -> Self {
+        self.registry_retention_days = Some(days);
+        self
+    }
+
     /// Enable/disable request logging
     pub fn enable_request_logging(mut self, enable: bool) -> Self {
         self.enable_request_logging = Some(enable);
@@ -150,6 +229,18 @@ impl CoordinatorConfigBuilder {
         self
     }
 
+    /// Set work lease duration
+    pub fn work_lease_secs(mut self, secs: u64) -> Self {
+        self.work_lease_secs = Some(secs);
+        self
+    }
+
+    /// Set maximum URLs per work lease
+    pub fn work_batch_size(mut self, size: usize) -> Self {
+        self.work_batch_size = Some(size);
+        self
+    }
+
     /// Build the config
     pub fn build(self) -> Result<CoordinatorConfig, ConfigError> {
         let config = CoordinatorConfig {
@@ -161,8 +252,14 @@ impl CoordinatorConfigBuilder {
             enable_cors: self.enable_cors.unwrap_or(true),
             max_instances: self.max_instances.unwrap_or(10),
             schedule_cache_path: self.schedule_cache_path,
+            registry_path: self.registry_path,
+            registry_retention_days: self
+                .registry_retention_days
+                .unwrap_or_else(default_registry_retention_days),
             enable_request_logging: self.enable_request_logging.unwrap_or(true),
             api_key: self.api_key,
+            work_lease_secs: self.work_lease_secs.unwrap_or_else(default_work_lease_secs),
+            work_batch_size: self.work_batch_size.unwrap_or_else(default_work_batch_size),
         };
 
         config.validate()?;
@@ -216,6 +313,7 @@ mod tests {
         assert_eq!(config.heartbeat_timeout_secs, 120);
         assert_eq!(config.heartbeat_interval_secs, 60);
         assert_eq!(config.max_instances, 5);
+        assert_eq!(config.work_lease_secs, 300);
     }
 
     #[test]
@@ -229,6 +327,21 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_registry_retention_validation() {
+        for days in [0, u32::MAX] {
+            let result = CoordinatorConfig::builder()
+                .registry_retention_days(days)
+                .build();
+            assert!(result.is_err(), "{days}");
+        }
+        let config = CoordinatorConfig::builder()
+            .registry_retention_days(365)
+            .build()
+            .unwrap();
+        assert_eq!(config.registry_retention_days, 365);
+    }
+
     #[test]
     fn test_config_builder_with_address() {
         let config = CoordinatorConfig::builder()
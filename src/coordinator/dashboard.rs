@@ -0,0 +1,564 @@
+//! Dashboard widgets for the web UI
+//!
+//! Each `/api/dashboard/*` endpoint returns one small, pre-aggregated widget,
+//! so the dashboard needs no direct access to OpenSearch or PostgreSQL:
+//!
+//! - `volume`: articles per hour and category, from the hourly time series
+//! - `instances`: every crawler instance with its status and schedule
+//! - `keywords`: top keywords with the count of the window before
+//! - `alerts`: most recent alerts and the number still active
+//! - `index-lag`: how far the search index is behind the crawler
+//!
+//! Widgets whose backend (time series or search index) is not configured
+//! answer 503.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Local, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::notifications::{Alert, AlertSeverity};
+use crate::scheduler::rotation::CrawlerInstance;
+use crate::scheduler::schedule::DailySchedule;
+use crate::storage::{hour_bucket, top_deltas, TimeSeriesPoint, METRIC_ARTICLES, METRIC_KEYWORD};
+
+use super::api::{AlertResponse, ApiResponse, ErrorResponse};
+use super::registry::{InstanceInfo, InstanceStatus, RegistryStats};
+use super::server::AppState;
+
+/// OpenAPI document for the dashboard widgets
+#[derive(OpenApi)]
+#[openapi(
+    paths(volume, instances, keywords, alerts, index_lag),
+    tags((name = "dashboard", description = "Pre-aggregated widgets for the web dashboard"))
+)]
+pub struct DashboardApiDoc;
+
+/// Longest window a widget aggregates, in hours (one week)
+const MAX_HOURS: i64 = 168;
+
+/// Most entries returned by a list widget
+const MAX_LIMIT: usize = 100;
+
+/// Create the dashboard router
+pub fn create_dashboard_router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/dashboard/volume", get(volume))
+        .route("/api/dashboard/instances", get(instances))
+        .route("/api/dashboard/keywords", get(keywords))
+        .route("/api/dashboard/alerts", get(alerts))
+        .route("/api/dashboard/index-lag", get(index_lag))
+        .with_state(state)
+}
+
+/// Widget window and size
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct DashboardQuery {
+    /// Window in hours, ending with the current hour (default 24, at most 168)
+    pub hours: Option<i64>,
+    /// Number of entries (default 10, at most 100)
+    pub limit: Option<usize>,
+}
+
+impl DashboardQuery {
+    fn hours(&self) -> i64 {
+        self.hours.unwrap_or(24).clamp(1, MAX_HOURS)
+    }
+
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(10).clamp(1, MAX_LIMIT)
+    }
+
+    /// `[since, until)` covering the last `hours` hours, current hour included
+    fn window(&self, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        let until = hour_bucket(now) + Duration::hours(1);
+        (until - Duration::hours(self.hours()), until)
+    }
+}
+
+fn unavailable(what: &str) -> Response {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse::new(format!("{what} is not configured"))),
+    )
+        .into_response()
+}
+
+fn failed(widget: &str, e: &anyhow::Error) -> Response {
+    tracing::error!(widget, error = %e, "Dashboard query failed");
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::new(format!("Failed to load {widget}: {e}"))),
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Crawl volume
+// ============================================================================
+
+/// Articles published in one hour
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct VolumeBucket {
+    /// Start of the hour
+    pub bucket: DateTime<Utc>,
+    /// Articles in all categories
+    pub articles: u64,
+    /// Articles per category
+    pub categories: BTreeMap<String, u64>,
+}
+
+/// Hourly crawl volume widget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VolumeResponse {
+    /// Window start (inclusive)
+    pub since: DateTime<Utc>,
+    /// Window end (exclusive)
+    pub until: DateTime<Utc>,
+    /// Articles in the window
+    pub total: u64,
+    /// One bucket per hour, oldest first, including empty hours
+    pub buckets: Vec<VolumeBucket>,
+}
+
+/// Sum `articles` points into one bucket per hour of `[since, since + hours)`
+fn hourly_volume(
+    points: &[TimeSeriesPoint],
+    since: DateTime<Utc>,
+    hours: i64,
+) -> Vec<VolumeBucket> {
+    let mut buckets: Vec<VolumeBucket> = (0..hours)
+        .map(|i| VolumeBucket {
+            bucket: since + Duration::hours(i),
+            articles: 0,
+            categories: BTreeMap::new(),
+        })
+        .collect();
+
+    for point in points {
+        let Ok(i) = usize::try_from((point.bucket - since).num_hours()) else {
+            continue;
+        };
+        let Some(bucket) = buckets.get_mut(i) else {
+            continue;
+        };
+        let count = point.value.max(0.0) as u64;
+        bucket.articles += count;
+        let category = point.dimension("category").unwrap_or("unknown");
+        *bucket.categories.entry(category.to_string()).or_insert(0) += count;
+    }
+    buckets
+}
+
+/// Hourly crawl volume by category
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/volume",
+    tag = "dashboard",
+    params(DashboardQuery),
+    responses(
+        (status = 200, description = "Articles per hour", body = ApiResponse<VolumeResponse>),
+        (status = 503, description = "Time series not configured", body = ErrorResponse)
+    )
+)]
+async fn volume(State(state): State<AppState>, Query(query): Query<DashboardQuery>) -> Response {
+    let Some(store) = &state.timeseries else {
+        return unavailable("Time series");
+    };
+    let (since, until) = query.window(Utc::now());
+
+    match store.query(METRIC_ARTICLES, &[], since, until).await {
+        Ok(points) => {
+            let buckets = hourly_volume(&points, since, query.hours());
+            let total = buckets.iter().map(|b| b.articles).sum();
+            Json(ApiResponse::success(VolumeResponse {
+                since,
+                until,
+                total,
+                buckets,
+            }))
+            .into_response()
+        }
+        Err(e) => failed("volume", &e),
+    }
+}
+
+// ============================================================================
+// Instance map
+// ============================================================================
+
+/// One crawler instance on the map
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct InstanceMapEntry {
+    /// Instance ID (main, sub1, sub2)
+    pub instance: String,
+    /// Whether the instance has registered with the coordinator
+    pub registered: bool,
+    /// Current status (offline if never registered)
+    pub status: InstanceStatus,
+    /// Whether today's schedule assigns the current hour to the instance
+    pub on_duty: bool,
+    /// Hours of today's schedule assigned to the instance
+    pub scheduled_hours: Vec<u8>,
+    /// Categories scheduled for the current hour, if on duty
+    pub scheduled_categories: Vec<String>,
+    /// Category the instance reported in its last heartbeat
+    pub current_category: Option<String>,
+    /// Last heartbeat received
+    pub last_heartbeat: Option<DateTime<Utc>>,
+    /// Articles crawled in the current session
+    pub articles_crawled: u64,
+    /// Errors in the current session
+    pub error_count: u64,
+}
+
+/// Instance map widget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InstanceMapResponse {
+    /// Current hour of the schedule (local time)
+    pub hour: u8,
+    /// Every known instance, registered or not
+    pub instances: Vec<InstanceMapEntry>,
+    /// Registry totals
+    pub stats: RegistryStats,
+}
+
+/// Place every instance on the map, with its share of `schedule`
+fn instance_map(
+    registered: &[InstanceInfo],
+    schedule: Option<&DailySchedule>,
+    hour: u8,
+) -> Vec<InstanceMapEntry> {
+    CrawlerInstance::all()
+        .into_iter()
+        .map(|instance| {
+            let info = registered.iter().find(|i| i.instance == instance);
+            let slots = schedule
+                .map(|s| s.slots_for_instance(instance))
+                .unwrap_or_default();
+            let current = slots.iter().find(|slot| slot.hour == hour);
+            InstanceMapEntry {
+                instance: instance.id().to_string(),
+                registered: info.is_some(),
+                status: info.map(|i| i.status).unwrap_or_default(),
+                on_duty: current.is_some(),
+                scheduled_hours: slots.iter().map(|slot| slot.hour).collect(),
+                scheduled_categories: current
+                    .map(|slot| slot.categories.iter().map(|c| c.id().to_string()).collect())
+                    .unwrap_or_default(),
+                current_category: info.and_then(|i| i.current_category.clone()),
+                last_heartbeat: info.map(|i| i.last_heartbeat),
+                articles_crawled: info.map_or(0, |i| i.articles_crawled),
+                error_count: info.map_or(0, |i| i.error_count),
+            }
+        })
+        .collect()
+}
+
+/// Crawler instances with their status and schedule
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/instances",
+    tag = "dashboard",
+    responses((status = 200, description = "Instance map", body = ApiResponse<InstanceMapResponse>))
+)]
+async fn instances(State(state): State<AppState>) -> Response {
+    let registered = state.registry.get_all_instances().await;
+    let stats = state.registry.stats().await;
+    let schedule = state.today_schedule().await.ok();
+    let hour = Local::now().hour() as u8;
+
+    Json(ApiResponse::success(InstanceMapResponse {
+        hour,
+        instances: instance_map(&registered, schedule.as_ref(), hour),
+        stats,
+    }))
+    .into_response()
+}
+
+// ============================================================================
+// Top keywords
+// ============================================================================
+
+/// One keyword of the widget
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct KeywordItem {
+    /// Keyword
+    pub keyword: String,
+    /// Articles mentioning it in the window
+    pub count: u64,
+    /// Articles mentioning it in the window before
+    pub previous: u64,
+    /// `count - previous`
+    pub delta: i64,
+}
+
+/// Top keywords widget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct KeywordsResponse {
+    /// Window start (inclusive)
+    pub since: DateTime<Utc>,
+    /// Window end (exclusive)
+    pub until: DateTime<Utc>,
+    /// Most mentioned first
+    pub keywords: Vec<KeywordItem>,
+}
+
+/// Most mentioned keywords, with the count of the window before
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/keywords",
+    tag = "dashboard",
+    params(DashboardQuery),
+    responses(
+        (status = 200, description = "Top keywords", body = ApiResponse<KeywordsResponse>),
+        (status = 503, description = "Time series not configured", body = ErrorResponse)
+    )
+)]
+async fn keywords(State(state): State<AppState>, Query(query): Query<DashboardQuery>) -> Response {
+    let Some(store) = &state.timeseries else {
+        return unavailable("Time series");
+    };
+    let (since, until) = query.window(Utc::now());
+    let before = since - Duration::hours(query.hours());
+
+    let (current, previous) = match tokio::try_join!(
+        store.query(METRIC_KEYWORD, &[], since, until),
+        store.query(METRIC_KEYWORD, &[], before, since),
+    ) {
+        Ok(points) => points,
+        Err(e) => return failed("keywords", &e),
+    };
+
+    let keywords = top_deltas(&current, &previous, "keyword", query.limit(), false)
+        .into_iter()
+        .map(|d| KeywordItem {
+            delta: d.delta() as i64,
+            keyword: d.name,
+            count: d.count as u64,
+            previous: d.previous as u64,
+        })
+        .collect();
+    Json(ApiResponse::success(KeywordsResponse {
+        since,
+        until,
+        keywords,
+    }))
+    .into_response()
+}
+
+// ============================================================================
+// Recent alerts
+// ============================================================================
+
+/// Recent alerts widget
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AlertsWidgetResponse {
+    /// Alerts not yet acknowledged or resolved
+    pub active: usize,
+    /// Active alerts with critical severity
+    pub critical: usize,
+    /// Most recent alerts of any status, newest first
+    pub alerts: Vec<AlertResponse>,
+}
+
+/// Most recent alerts and the number still active
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/alerts",
+    tag = "dashboard",
+    params(DashboardQuery),
+    responses((status = 200, description = "Recent alerts", body = ApiResponse<AlertsWidgetResponse>))
+)]
+async fn alerts(State(state): State<AppState>, Query(query): Query<DashboardQuery>) -> Response {
+    let manager = state.notifications.read().await;
+    let active = manager.active_alerts();
+    let critical = active
+        .iter()
+        .filter(|a| a.severity == AlertSeverity::Critical)
+        .count();
+
+    let mut recent: Vec<&Alert> = manager.all_alerts();
+    recent.sort_by_key(|a| std::cmp::Reverse(a.created_at));
+    recent.truncate(query.limit());
+
+    Json(ApiResponse::success(AlertsWidgetResponse {
+        active: active.len(),
+        critical,
+        alerts: recent.into_iter().map(AlertResponse::from).collect(),
+    }))
+    .into_response()
+}
+
+// ============================================================================
+// Index lag
+// ============================================================================
+
+/// Index lag widget
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct IndexLagResponse {
+    /// When the crawler last recorded an article
+    pub last_crawled_at: Option<DateTime<Utc>>,
+    /// Crawl time of the newest indexed article
+    pub last_indexed_at: Option<DateTime<Utc>>,
+    /// Seconds the index is behind the crawler (0 when caught up, absent
+    /// when either time is unknown)
+    pub lag_secs: Option<i64>,
+}
+
+impl IndexLagResponse {
+    fn new(last_crawled_at: Option<DateTime<Utc>>, last_indexed_at: Option<DateTime<Utc>>) -> Self {
+        let lag_secs = last_crawled_at
+            .zip(last_indexed_at)
+            .map(|(crawled, indexed)| (crawled - indexed).num_seconds().max(0));
+        Self {
+            last_crawled_at,
+            last_indexed_at,
+            lag_secs,
+        }
+    }
+}
+
+/// How far the search index is behind the crawler
+#[utoipa::path(
+    get,
+    path = "/api/dashboard/index-lag",
+    tag = "dashboard",
+    responses(
+        (status = 200, description = "Index lag", body = ApiResponse<IndexLagResponse>),
+        (status = 503, description = "Time series or search index not configured", body = ErrorResponse)
+    )
+)]
+async fn index_lag(State(state): State<AppState>) -> Response {
+    let Some(store) = &state.timeseries else {
+        return unavailable("Time series");
+    };
+    let Some(index) = &state.index else {
+        return unavailable("Search index");
+    };
+
+    match tokio::try_join!(store.last_recorded_at(), index.latest_crawled_at()) {
+        Ok((crawled, indexed)) => Json(ApiResponse::success(IndexLagResponse::new(
+            crawled, indexed,
+        )))
+        .into_response(),
+        Err(e) => failed("index lag", &e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinator::{CoordinatorConfig, CoordinatorServer};
+    use chrono::TimeZone;
+
+    fn point(category: &str, bucket: DateTime<Utc>, value: f64) -> TimeSeriesPoint {
+        TimeSeriesPoint {
+            dimensions: [("category".to_string(), category.to_string())].into(),
+            bucket,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_hourly_volume_fills_empty_hours() {
+        let since = Utc.with_ymd_and_hms(2026, 2, 15, 9, 0, 0).unwrap();
+        let points = [
+            point("economy", since, 3.0),
+            point("politics", since, 2.0),
+            point("economy", since + Duration::hours(2), 1.0),
+            // Outside the window
+            point("economy", since + Duration::hours(3), 5.0),
+        ];
+
+        let buckets = hourly_volume(&points, since, 3);
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0].articles, 5);
+        assert_eq!(buckets[0].categories["politics"], 2);
+        assert_eq!(buckets[1].articles, 0);
+        assert_eq!(buckets[2].bucket, since + Duration::hours(2));
+        assert_eq!(buckets[2].articles, 1);
+    }
+
+    #[test]
+    fn test_index_lag() {
+        let crawled = Utc.with_ymd_and_hms(2026, 2, 15, 10, 0, 0).unwrap();
+        let lag = IndexLagResponse::new(Some(crawled), Some(crawled - Duration::minutes(90)));
+        assert_eq!(lag.lag_secs, Some(5400));
+
+        // Indexed documents newer than the last crawl: caught up
+        let lag = IndexLagResponse::new(Some(crawled), Some(crawled + Duration::minutes(5)));
+        assert_eq!(lag.lag_secs, Some(0));
+
+        assert_eq!(IndexLagResponse::new(None, Some(crawled)).lag_secs, None);
+    }
+
+    #[test]
+    fn test_instance_map() {
+        use crate::scheduler::rotation::RotationScheduler;
+
+        let schedule = RotationScheduler::new()
+            .generate_daily_schedule(chrono::NaiveDate::from_ymd_opt(2026, 2, 15).unwrap());
+        let registered = [InstanceInfo::new(
+            CrawlerInstance::Main,
+            "10.0.0.1".to_string(),
+            9101,
+        )];
+
+        let map = instance_map(&registered, Some(&schedule), 0);
+        assert_eq!(map.len(), CrawlerInstance::all().len());
+        assert_eq!(map.iter().filter(|e| e.on_duty).count(), 1);
+        assert_eq!(
+            map.iter().map(|e| e.scheduled_hours.len()).sum::<usize>(),
+            24
+        );
+        assert!(map[0].registered);
+        assert!(!map[1].registered);
+        assert_eq!(map[1].status, InstanceStatus::Offline);
+    }
+
+    async fn get(router: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_dashboard_routes() {
+        let server = CoordinatorServer::new(CoordinatorConfig::default()).unwrap();
+        let router = create_dashboard_router(server.state());
+
+        let (status, body) = get(&router, "/api/dashboard/instances").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["instances"].as_array().unwrap().len(), 3);
+
+        let (status, body) = get(&router, "/api/dashboard/alerts?limit=5").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["active"], 0);
+
+        // No PostgreSQL or OpenSearch configured
+        for uri in [
+            "/api/dashboard/volume",
+            "/api/dashboard/keywords",
+            "/api/dashboard/index-lag",
+        ] {
+            let (status, _) = get(&router, uri).await;
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE, "{uri}");
+        }
+    }
+}
@@ -35,42 +35,22 @@ use serde::Serialize;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
+use utoipa::{OpenApi, ToSchema};
 
 use super::server::AppState;
+pub use crate::health::HealthStatus;
+
+/// OpenAPI document for the health probe endpoints
+#[derive(OpenApi)]
+#[openapi(paths(liveness_probe, readiness_probe, startup_probe, health_check))]
+pub struct HealthApiDoc;
 
 // ============================================================================
 // Health Status Types
 // ============================================================================
 
-/// Overall health status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum HealthStatus {
-    Healthy,
-    Degraded,
-    Unhealthy,
-}
-
-impl HealthStatus {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            HealthStatus::Healthy => "healthy",
-            HealthStatus::Degraded => "degraded",
-            HealthStatus::Unhealthy => "unhealthy",
-        }
-    }
-
-    pub fn status_code(&self) -> StatusCode {
-        match self {
-            HealthStatus::Healthy => StatusCode::OK,
-            HealthStatus::Degraded => StatusCode::OK, // 200 to keep pod running
-            HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
-        }
-    }
-}
-
 /// Component health check result
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct ComponentHealth {
     pub name: String,
     pub status: HealthStatus,
@@ -81,14 +61,14 @@ pub struct ComponentHealth {
 }
 
 /// Liveness probe response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct LivenessResponse {
     pub status: HealthStatus,
     pub timestamp: String,
 }
 
 /// Readiness probe response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ReadinessResponse {
     pub status: HealthStatus,
     pub timestamp: String,
@@ -96,7 +76,7 @@ pub struct ReadinessResponse {
 }
 
 /// Startup probe response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct StartupResponse {
     pub ready: bool,
     pub status: HealthStatus,
@@ -106,7 +86,8 @@ pub struct StartupResponse {
 }
 
 /// Comprehensive health response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
+#[schema(as = ComponentHealthReport)]
 pub struct HealthResponse {
     pub status: HealthStatus,
     pub version: String,
@@ -206,6 +187,12 @@ pub fn create_health_router(state: AppState) -> Router {
 ///
 /// Returns 200 if the application process is running.
 /// This should always succeed unless the process is dead.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses((status = 200, description = "Process is alive", body = LivenessResponse))
+)]
 async fn liveness_probe() -> impl IntoResponse {
     let response = LivenessResponse {
         status: HealthStatus::Healthy,
@@ -219,6 +206,15 @@ async fn liveness_probe() -> impl IntoResponse {
 ///
 /// Returns 200 if the application is ready to receive traffic.
 /// Checks critical dependencies like registry connectivity.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready (possibly degraded)", body = ReadinessResponse),
+        (status = 503, description = "Not ready", body = ReadinessResponse)
+    )
+)]
 async fn readiness_probe(State(state): State<AppState>) -> impl IntoResponse {
     let mut checks = Vec::new();
     let mut overall_status = HealthStatus::Healthy;
@@ -286,6 +282,15 @@ async fn readiness_probe(State(state): State<AppState>) -> impl IntoResponse {
 ///
 /// Returns 200 once the application has finished starting up.
 /// Used by Kubernetes to know when to start liveness/readiness probes.
+#[utoipa::path(
+    get,
+    path = "/health/startup",
+    tag = "health",
+    responses(
+        (status = 200, description = "Startup complete", body = StartupResponse),
+        (status = 503, description = "Still starting", body = StartupResponse)
+    )
+)]
 async fn startup_probe(State(state): State<AppState>) -> impl IntoResponse {
     // Consider startup complete after 5 seconds
     let uptime = state.start_time.elapsed().as_secs();
@@ -313,6 +318,15 @@ async fn startup_probe(State(state): State<AppState>) -> impl IntoResponse {
 /// Comprehensive health check handler
 ///
 /// Returns detailed health information about all components.
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Healthy or degraded", body = HealthResponse),
+        (status = 503, description = "Unhealthy", body = HealthResponse)
+    )
+)]
 async fn health_check(State(state): State<AppState>) -> impl IntoResponse {
     let mut checks = Vec::new();
     let mut overall_status = HealthStatus::Healthy;
@@ -35,9 +35,31 @@
 //! │  │        REST API              │  │
 //! │  │  GET  /api/health            │  │
 //! │  │  GET  /api/schedule/today    │  │
+//! │  │  POST /api/schedule/override │  │
+//! │  │  GET  /api/events (SSE)      │  │
 //! │  │  GET  /api/instances         │  │
 //! │  │  POST /api/instances/register│  │
 //! │  │  POST /api/instances/heartbeat│ │
+//! │  │  POST /api/work/enqueue      │  │
+//! │  │  POST /api/work/lease        │  │
+//! │  │  POST /api/work/complete     │  │
+//! │  │  GET  /api/alerts            │  │
+//! │  │  POST /api/alerts/{id}/...   │  │
+//! │  └──────────────────────────────┘  │
+//! │                                     │
+//! │  ┌──────────────────────────────┐  │
+//! │  │        Dashboard             │  │
+//! │  │  GET  /api/dashboard/volume  │  │
+//! │  │  GET  /api/dashboard/instances│ │
+//! │  │  GET  /api/dashboard/keywords│  │
+//! │  │  GET  /api/dashboard/alerts  │  │
+//! │  │  GET  /api/dashboard/index-lag│ │
+//! │  └──────────────────────────────┘  │
+//! │                                     │
+//! │  ┌──────────────────────────────┐  │
+//! │  │        API Docs              │  │
+//! │  │  GET  /api/openapi.json      │  │
+//! │  │  GET  /swagger-ui            │  │
 //! │  └──────────────────────────────┘  │
 //! └─────────────────────────────────────┘
 //! ```
@@ -55,13 +77,18 @@
 pub mod api;
 pub mod client;
 pub mod config;
+pub mod dashboard;
 pub mod health;
 pub mod registry;
 pub mod server;
+pub mod store;
+pub mod work;
 
 // Re-export main types
-pub use client::{ClientConfig, CoordinatorClient};
+pub use client::{ClientConfig, CoordinatorClient, EventStream};
 pub use config::CoordinatorConfig;
 pub use health::{HealthChecker, HealthResponse, HealthStatus};
 pub use registry::{InstanceInfo, InstanceRegistry, InstanceStatus};
 pub use server::CoordinatorServer;
+pub use store::{HeartbeatRecord, RegistryStore};
+pub use work::{WorkLease, WorkQueue, WorkQueueStats};
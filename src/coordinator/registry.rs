@@ -1,22 +1,26 @@
 //! Instance registry for tracking crawler instances
 //!
 //! This module manages the registration and health monitoring of
-//! distributed crawler instances.
+//! distributed crawler instances. With a [`RegistryStore`] the registry
+//! survives coordinator restarts.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use utoipa::ToSchema;
 
 use crate::scheduler::rotation::CrawlerInstance;
 
+use super::store::{HeartbeatRecord, RegistryStore};
+
 // ============================================================================
 // Instance Status
 // ============================================================================
 
 /// Status of a registered instance
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum InstanceStatus {
     /// Instance is online and healthy
@@ -60,7 +64,7 @@ impl Default for InstanceStatus {
 // ============================================================================
 
 /// Information about a registered instance
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InstanceInfo {
     /// Instance identifier
     pub instance: CrawlerInstance,
@@ -86,6 +90,15 @@ pub struct InstanceInfo {
     /// Number of errors encountered
     pub error_count: u64,
 
+    /// Articles crawled since the instance first registered, across
+    /// instance and coordinator restarts
+    #[serde(default)]
+    pub total_articles: u64,
+
+    /// Errors since the instance first registered
+    #[serde(default)]
+    pub total_errors: u64,
+
     /// Current crawling category (if active)
     pub current_category: Option<String>,
 
@@ -110,6 +123,8 @@ impl InstanceInfo {
             last_heartbeat: now,
             articles_crawled: 0,
             error_count: 0,
+            total_articles: 0,
+            total_errors: 0,
             current_category: None,
             version: None,
             metadata: HashMap::new(),
@@ -166,6 +181,20 @@ impl InstanceInfo {
         self.articles_crawled += articles;
         self.error_count += errors;
     }
+
+    /// Record the session counts reported in a heartbeat, adding what is new
+    /// since the last heartbeat to the cumulative totals
+    ///
+    /// A count lower than the last one means the instance restarted and
+    /// began a new session.
+    pub fn record_session_counts(&mut self, articles: u64, errors: u64) {
+        self.total_articles += articles
+            .checked_sub(self.articles_crawled)
+            .unwrap_or(articles);
+        self.total_errors += errors.checked_sub(self.error_count).unwrap_or(errors);
+        self.articles_crawled = articles;
+        self.error_count = errors;
+    }
 }
 
 // ============================================================================
@@ -173,7 +202,7 @@ impl InstanceInfo {
 // ============================================================================
 
 /// Request to register an instance
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegisterRequest {
     pub instance_id: String,
     pub ip_address: String,
@@ -184,7 +213,7 @@ pub struct RegisterRequest {
 }
 
 /// Response to registration request
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegisterResponse {
     pub success: bool,
     pub instance: CrawlerInstance,
@@ -193,7 +222,7 @@ pub struct RegisterResponse {
 }
 
 /// Heartbeat request from instance
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HeartbeatRequest {
     pub instance_id: String,
     pub articles_crawled: u64,
@@ -202,7 +231,7 @@ pub struct HeartbeatRequest {
 }
 
 /// Heartbeat response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HeartbeatResponse {
     pub success: bool,
     pub message: String,
@@ -227,6 +256,9 @@ pub struct InstanceRegistry {
 
     /// Max instances allowed
     max_instances: usize,
+
+    /// Persistent copy of registrations and heartbeats (optional)
+    store: Option<RegistryStore>,
 }
 
 impl InstanceRegistry {
@@ -237,6 +269,41 @@ impl InstanceRegistry {
             heartbeat_timeout_secs: heartbeat_timeout_secs as i64,
             degraded_threshold_secs: (heartbeat_timeout_secs as i64) / 2,
             max_instances,
+            store: None,
+        }
+    }
+
+    /// Persist the registry to `store`, restoring the instances it holds
+    pub fn with_store(mut self, store: RegistryStore) -> anyhow::Result<Self> {
+        let restored: HashMap<CrawlerInstance, InstanceInfo> = store
+            .load_instances()?
+            .into_iter()
+            .map(|info| (info.instance, info))
+            .collect();
+        if !restored.is_empty() {
+            tracing::info!(count = restored.len(), "Restored registered instances");
+        }
+
+        self.instances = Arc::new(RwLock::new(restored));
+        self.store = Some(store);
+        Ok(self)
+    }
+
+    /// Get the persistent store, if configured
+    pub fn store(&self) -> Option<&RegistryStore> {
+        self.store.as_ref()
+    }
+
+    /// Save `info` to the store; the in-memory registry stays authoritative,
+    /// so failures are only logged
+    ///
+    /// Called after the registry lock is released, so other requests do not
+    /// wait on SQLite.
+    fn persist(&self, info: &InstanceInfo) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save_instance(info) {
+                tracing::warn!(instance = %info.instance, error = %e, "Failed to persist instance");
+            }
         }
     }
 
@@ -258,12 +325,19 @@ impl InstanceRegistry {
             });
         }
 
-        // Create or update instance info
+        // Create or update instance info. Instances register when they
+        // start, so the totals are kept but the session counts start over
         let mut info = InstanceInfo::new(instance, request.ip_address, request.port);
         info.version = request.version;
         info.metadata = request.metadata;
+        if let Some(previous) = instances.get(&instance) {
+            info.total_articles = previous.total_articles;
+            info.total_errors = previous.total_errors;
+        }
 
-        instances.insert(instance, info);
+        instances.insert(instance, info.clone());
+        drop(instances);
+        self.persist(&info);
 
         Ok(RegisterResponse {
             success: true,
@@ -288,9 +362,24 @@ impl InstanceRegistry {
             .ok_or(RegistryError::InstanceNotFound(instance))?;
 
         info.update_heartbeat();
-        info.articles_crawled = request.articles_crawled;
-        info.error_count = request.error_count;
+        info.record_session_counts(request.articles_crawled, request.error_count);
         info.current_category = request.current_category;
+        let info = info.clone();
+        drop(instances);
+
+        self.persist(&info);
+        if let Some(store) = &self.store {
+            let record = HeartbeatRecord {
+                instance,
+                received_at: info.last_heartbeat,
+                articles_crawled: info.articles_crawled,
+                error_count: info.error_count,
+                current_category: info.current_category.clone(),
+            };
+            if let Err(e) = store.record_heartbeat(&record) {
+                tracing::warn!(instance = %instance, error = %e, "Failed to persist heartbeat");
+            }
+        }
 
         Ok(HeartbeatResponse {
             success: true,
@@ -323,12 +412,20 @@ impl InstanceRegistry {
 
     /// Unregister an instance
     pub async fn unregister(&self, instance: CrawlerInstance) -> Option<InstanceInfo> {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.remove_instance(instance) {
+                tracing::warn!(instance = %instance, error = %e, "Failed to remove stored instance");
+            }
+        }
         self.instances.write().await.remove(&instance)
     }
 
     /// Update instance statuses based on heartbeat timestamps
-    pub async fn update_statuses(&self) {
+    ///
+    /// Returns the instances that went offline with this update.
+    pub async fn update_statuses(&self) -> Vec<CrawlerInstance> {
         let mut instances = self.instances.write().await;
+        let mut went_offline = Vec::new();
 
         for info in instances.values_mut() {
             if info.status == InstanceStatus::Maintenance {
@@ -338,6 +435,9 @@ impl InstanceRegistry {
             let age = info.seconds_since_heartbeat();
 
             if age > self.heartbeat_timeout_secs {
+                if info.status != InstanceStatus::Offline {
+                    went_offline.push(info.instance);
+                }
                 info.status = InstanceStatus::Offline;
             } else if age > self.degraded_threshold_secs {
                 info.status = InstanceStatus::Degraded;
@@ -345,6 +445,8 @@ impl InstanceRegistry {
                 info.status = InstanceStatus::Online;
             }
         }
+
+        went_offline
     }
 
     /// Get registry statistics
@@ -393,6 +495,9 @@ impl InstanceRegistry {
             .ok_or(RegistryError::InstanceNotFound(instance))?;
 
         info.set_maintenance(enabled);
+        let info = info.clone();
+        drop(instances);
+        self.persist(&info);
         Ok(())
     }
 
@@ -413,7 +518,7 @@ impl InstanceRegistry {
 }
 
 /// Registry statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RegistryStats {
     pub total_instances: usize,
     pub online: usize,
@@ -632,6 +737,27 @@ mod tests {
         assert_eq!(stats.online, 2);
     }
 
+    #[tokio::test]
+    async fn test_registry_reports_offline_once() {
+        let registry = InstanceRegistry::new(90, 10);
+
+        registry
+            .register(create_register_request("main"))
+            .await
+            .unwrap();
+        assert!(registry.update_statuses().await.is_empty());
+
+        registry
+            .instances
+            .write()
+            .await
+            .get_mut(&CrawlerInstance::Main)
+            .unwrap()
+            .last_heartbeat -= chrono::Duration::seconds(120);
+        assert_eq!(registry.update_statuses().await, [CrawlerInstance::Main]);
+        assert!(registry.update_statuses().await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_registry_maintenance() {
         let registry = InstanceRegistry::new(90, 10);
@@ -650,6 +776,59 @@ mod tests {
         assert!(!info.status.is_available());
     }
 
+    #[tokio::test]
+    async fn test_registry_restored_from_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.db");
+        let heartbeat = |articles| HeartbeatRequest {
+            instance_id: "main".to_string(),
+            articles_crawled: articles,
+            error_count: 0,
+            current_category: None,
+        };
+
+        let registry = InstanceRegistry::new(90, 10)
+            .with_store(RegistryStore::open(&path, 7).unwrap())
+            .unwrap();
+        registry
+            .register(create_register_request("main"))
+            .await
+            .unwrap();
+        registry.heartbeat(heartbeat(30)).await.unwrap();
+        registry.heartbeat(heartbeat(50)).await.unwrap();
+        drop(registry);
+
+        // Restored as offline with its counts after a restart
+        let registry = InstanceRegistry::new(90, 10)
+            .with_store(RegistryStore::open(&path, 7).unwrap())
+            .unwrap();
+        let info = registry.get_instance(CrawlerInstance::Main).await.unwrap();
+        assert_eq!(info.status, InstanceStatus::Offline);
+        assert_eq!(info.total_articles, 50);
+
+        // The instance restarted too: its session count starts over, even
+        // past the count of its last session
+        registry
+            .register(create_register_request("main"))
+            .await
+            .unwrap();
+        registry.heartbeat(heartbeat(60)).await.unwrap();
+        let info = registry.get_instance(CrawlerInstance::Main).await.unwrap();
+        assert_eq!(info.status, InstanceStatus::Online);
+        assert_eq!((info.articles_crawled, info.total_articles), (60, 110));
+
+        let history = registry
+            .store()
+            .unwrap()
+            .heartbeats(
+                CrawlerInstance::Main,
+                Utc::now() - chrono::Duration::hours(1),
+            )
+            .unwrap();
+        let counts: Vec<u64> = history.iter().map(|h| h.articles_crawled).collect();
+        assert_eq!(counts, [30, 50, 60]);
+    }
+
     #[test]
     fn test_registry_stats_availability() {
         let stats = RegistryStats {
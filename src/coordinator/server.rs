@@ -8,17 +8,33 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use axum::Router;
+use chrono::{Local, Timelike};
+use tokio::sync::RwLock;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
-
-use crate::scheduler::rotation::RotationScheduler;
-use crate::scheduler::schedule::ScheduleCache;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::embedding::VectorStore;
+use crate::metrics::Metrics;
+use crate::notifications::digest::run_digest_loop;
+use crate::notifications::escalation::run_escalation_loop;
+use crate::notifications::volume::run_volume_anomaly_loop;
+use crate::notifications::NotificationManager;
+use crate::scheduler::distribution::{DistributorHandle, ScheduleDistributor, UpdateReason};
+use crate::scheduler::error::SchedulerResult;
+use crate::scheduler::failover::OverrideManager;
+use crate::scheduler::rotation::{CrawlerInstance, RotationScheduler};
+use crate::scheduler::schedule::{DailySchedule, ScheduleCache};
 use crate::scheduler::trigger::ScheduleTrigger;
+use crate::storage::PgTimeSeriesStore;
 
-use super::api::create_router;
+use super::api::{create_router, openapi_spec};
 use super::config::CoordinatorConfig;
+use super::dashboard::create_dashboard_router;
 use super::health::create_health_router;
 use super::registry::InstanceRegistry;
+use super::store::RegistryStore;
+use super::work::WorkQueue;
 
 // ============================================================================
 // App State
@@ -30,6 +46,9 @@ pub struct AppState {
     /// Instance registry
     pub registry: Arc<InstanceRegistry>,
 
+    /// URLs leased to instances on request
+    pub work: Arc<WorkQueue>,
+
     /// Schedule cache
     pub cache: Arc<ScheduleCache>,
 
@@ -39,13 +58,113 @@ pub struct AppState {
     /// Schedule trigger
     pub trigger: Arc<ScheduleTrigger>,
 
+    /// Today's schedule as changed by failovers and overrides; its events
+    /// are streamed to instances at `/api/events`
+    pub distributor: DistributorHandle,
+
+    /// Manual hour assignments
+    pub overrides: Arc<OverrideManager>,
+
+    /// Alert notifications
+    pub notifications: Arc<RwLock<NotificationManager>>,
+
     /// Server start time
     pub start_time: Instant,
 
+    /// Prometheus metrics
+    pub metrics: Metrics,
+
+    /// Hourly article and keyword aggregates (volume rules, dashboard)
+    pub timeseries: Option<PgTimeSeriesStore>,
+
+    /// Search index, for the dashboard's index lag
+    pub index: Option<Arc<VectorStore>>,
+
     /// Configuration
     pub config: CoordinatorConfig,
 }
 
+impl AppState {
+    /// Today's schedule, including failovers and overrides
+    ///
+    /// The first call of a day loads the schedule from the trigger and
+    /// distributes it, notifying subscribed instances.
+    pub async fn today_schedule(&self) -> SchedulerResult<DailySchedule> {
+        let today = Local::now().date_naive();
+        if let Some(schedule) = self.distributor.get_schedule().await {
+            if schedule.date == today {
+                return Ok(schedule);
+            }
+        }
+
+        let schedule = self.trigger.get_current_schedule().await?;
+        self.distributor.distribute(schedule.clone()).await;
+        Ok(schedule)
+    }
+
+    /// Move the remaining hours of today from `failed` to an available
+    /// instance
+    ///
+    /// Returns the instance taking over, or `None` if `failed` has no hours
+    /// left today or no other instance is available.
+    pub async fn fail_over(
+        &self,
+        failed: CrawlerInstance,
+    ) -> SchedulerResult<Option<CrawlerInstance>> {
+        let schedule = self.today_schedule().await?;
+        let hour = Local::now().hour() as u8;
+        let hours: Vec<u8> = schedule
+            .slots
+            .iter()
+            .filter(|slot| slot.instance == failed && slot.hour >= hour)
+            .map(|slot| slot.hour)
+            .collect();
+        if hours.is_empty() {
+            return Ok(None);
+        }
+
+        // The available instance with the fewest hours left takes over
+        let target = self
+            .registry
+            .get_online_instances()
+            .await
+            .into_iter()
+            .map(|info| info.instance)
+            .filter(|instance| *instance != failed)
+            .min_by_key(|instance| {
+                schedule
+                    .slots
+                    .iter()
+                    .filter(|slot| slot.instance == *instance && slot.hour >= hour)
+                    .count()
+            });
+        let Some(target) = target else {
+            tracing::warn!(instance = %failed, "No instance available to take over");
+            return Ok(None);
+        };
+
+        tracing::warn!(
+            failed = %failed,
+            target = %target,
+            hours = ?hours,
+            "Instance went offline, reassigning its remaining hours"
+        );
+        self.distributor
+            .update_hours(schedule.date, hours, target, UpdateReason::Failover)
+            .await;
+        self.save_schedule().await?;
+        Ok(Some(target))
+    }
+
+    /// Write the distributed schedule back to the schedule cache
+    pub async fn save_schedule(&self) -> SchedulerResult<()> {
+        match self.distributor.get_schedule().await {
+            Some(schedule) => self.cache.update(schedule).await,
+            None => Ok(()),
+        }
+    }
+}
+
 // ============================================================================
 // Coordinator Server
 // ============================================================================
@@ -69,10 +188,22 @@ impl CoordinatorServer {
             None => ScheduleCache::new(),
         });
 
-        // Create registry
-        let registry = Arc::new(InstanceRegistry::new(
-            config.heartbeat_timeout_secs,
-            config.max_instances,
+        // Create registry, restoring persisted instances
+        let mut registry =
+            InstanceRegistry::new(config.heartbeat_timeout_secs, config.max_instances);
+        if let Some(path) = &config.registry_path {
+            let store = RegistryStore::open(path, config.registry_retention_days)
+                .map_err(|e| ServerError::InitError(e.to_string()))?;
+            registry = registry
+                .with_store(store)
+                .map_err(|e| ServerError::InitError(e.to_string()))?;
+        }
+        let registry = Arc::new(registry);
+
+        // Create work queue
+        let work = Arc::new(WorkQueue::new(
+            config.work_lease_secs,
+            config.work_batch_size,
         ));
 
         // Create scheduler
@@ -84,18 +215,54 @@ impl CoordinatorServer {
                 .map_err(|e| ServerError::InitError(e.to_string()))?,
         );
 
+        // Create distributor and override manager
+        let distributor = Arc::new(ScheduleDistributor::with_defaults());
+        let overrides = Arc::new(OverrideManager::new().with_distributor(distributor.clone()));
+
         let state = AppState {
             registry,
+            work,
             cache,
             scheduler,
             trigger,
+            distributor,
+            overrides,
+            notifications: Arc::new(RwLock::new(NotificationManager::new())),
             start_time: Instant::now(),
+            metrics: Metrics::global(),
+            timeseries: None,
+            index: None,
             config: config.clone(),
         };
 
         Ok(Self { config, state })
     }
 
+    /// Use a notification manager, e.g. one built from configured alert rules
+    pub fn with_notifications(mut self, manager: NotificationManager) -> Self {
+        self.state.notifications = Arc::new(RwLock::new(manager));
+        self
+    }
+
+    /// Record metrics to `metrics` instead of the global default
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.state.metrics = metrics;
+        self
+    }
+
+    /// Use a time-series store for evaluating `volume_anomaly` rules and
+    /// the dashboard's volume and keyword widgets
+    pub fn with_timeseries(mut self, store: PgTimeSeriesStore) -> Self {
+        self.state.timeseries = Some(store);
+        self
+    }
+
+    /// Use a search index for the dashboard's index lag
+    pub fn with_index(mut self, store: VectorStore) -> Self {
+        self.state.index = Some(Arc::new(store));
+        self
+    }
+
     /// Get the application state
     pub fn state(&self) -> AppState {
         self.state.clone()
@@ -106,11 +273,21 @@ impl CoordinatorServer {
         // Create API router
         let api_router = create_router(self.state.clone());
 
+        // Pre-aggregated widgets for the web dashboard
+        let dashboard_router = create_dashboard_router(self.state.clone());
+
         // Create health check router
         let health_router = create_health_router(self.state.clone());
 
+        // OpenAPI document and Swagger UI
+        let docs_router = SwaggerUi::new("/swagger-ui").url("/api/openapi.json", openapi_spec());
+
         // Merge routers
-        let mut router = Router::new().merge(health_router).merge(api_router);
+        let mut router = Router::new()
+            .merge(health_router)
+            .merge(api_router)
+            .merge(dashboard_router)
+            .merge(docs_router);
 
         // Add CORS layer if enabled
         if self.config.enable_cors {
@@ -184,16 +361,75 @@ impl CoordinatorServer {
 
     /// Start background tasks
     fn start_background_tasks(&self) {
-        // Start status updater (updates instance statuses every 10 seconds)
-        let registry = self.state.registry.clone();
+        // Start status updater (updates instance statuses, fails over the
+        // hours of instances that went offline and requeues expired work
+        // leases every 10 seconds)
+        let state = self.state.clone();
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(10));
             loop {
                 interval.tick().await;
-                registry.update_statuses().await;
+                for instance in state.registry.update_statuses().await {
+                    if let Err(e) = state.fail_over(instance).await {
+                        tracing::warn!(instance = %instance, error = %e, "Failover failed");
+                    }
+                }
+                state.work.requeue_expired().await;
             }
         });
 
+        // Start pruning of heartbeat history past the retention window
+        if self.state.registry.store().is_some() {
+            let registry = self.state.registry.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+                loop {
+                    interval.tick().await;
+                    if let Some(Err(e)) = registry.store().map(RegistryStore::prune) {
+                        tracing::warn!(error = %e, "Failed to prune registry store");
+                    }
+                }
+            });
+        }
+
+        // Start escalation evaluator for unacknowledged alerts
+        let notifications = self.state.notifications.clone();
+        tokio::spawn(async move {
+            let (has_policies, interval) = {
+                let manager = notifications.read().await;
+                (
+                    !manager.escalation_policies().is_empty(),
+                    manager.escalation_interval(),
+                )
+            };
+            if has_policies {
+                run_escalation_loop(notifications, interval).await;
+            }
+        });
+
+        // Start digest sender for batched Info alerts
+        let notifications = self.state.notifications.clone();
+        tokio::spawn(async move {
+            let digest = notifications.read().await.digest_config().clone();
+            if digest.enabled {
+                run_digest_loop(notifications, digest.interval.duration()).await;
+            }
+        });
+
+        // Start volume anomaly evaluator for category volume rules
+        if let Some(store) = self.state.timeseries.clone() {
+            let notifications = self.state.notifications.clone();
+            tokio::spawn(async move {
+                let (has_rules, interval) = {
+                    let manager = notifications.read().await;
+                    (manager.has_volume_rules(), manager.volume_interval())
+                };
+                if has_rules {
+                    run_volume_anomaly_loop(notifications, store, interval).await;
+                }
+            });
+        }
+
         tracing::info!("Background tasks started");
     }
 
@@ -336,5 +572,104 @@ mod tests {
         // Test cache
         let cache_status = state.cache.status().await;
         assert!(!cache_status.has_schedule);
+
+        // Test notifications
+        assert!(state.notifications.read().await.rules().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_server_restores_registry() {
+        use crate::coordinator::registry::RegisterRequest;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = CoordinatorConfig::builder()
+            .registry_path(dir.path().join("registry.db").to_string_lossy())
+            .build()
+            .unwrap();
+
+        let server = CoordinatorServer::new(config.clone()).unwrap();
+        server
+            .state()
+            .registry
+            .register(RegisterRequest {
+                instance_id: "sub1".to_string(),
+                ip_address: "10.0.0.2".to_string(),
+                port: 9000,
+                version: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .await
+            .unwrap();
+        drop(server);
+
+        let server = CoordinatorServer::new(config).unwrap();
+        let instances = server.state().registry.get_all_instances().await;
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].address(), "10.0.0.2:9000");
+    }
+
+    #[tokio::test]
+    async fn test_fail_over_offline_instance() {
+        use crate::coordinator::registry::RegisterRequest;
+
+        let server = CoordinatorServer::new(CoordinatorConfig::default()).unwrap();
+        let state = server.state();
+        for id in ["main", "sub1"] {
+            state
+                .registry
+                .register(RegisterRequest {
+                    instance_id: id.to_string(),
+                    ip_address: "10.0.0.1".to_string(),
+                    port: 9000,
+                    version: None,
+                    metadata: std::collections::HashMap::new(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let hour = Local::now().hour() as u8;
+        let remaining = |schedule: &DailySchedule, instance| {
+            schedule
+                .slots
+                .iter()
+                .filter(|slot| slot.instance == instance && slot.hour >= hour)
+                .count()
+        };
+        let before = state.today_schedule().await.unwrap();
+        let had_hours = remaining(&before, CrawlerInstance::Main) > 0;
+
+        let target = state.fail_over(CrawlerInstance::Main).await.unwrap();
+        assert_eq!(target, had_hours.then_some(CrawlerInstance::Sub1));
+
+        let after = state.today_schedule().await.unwrap();
+        assert_eq!(remaining(&after, CrawlerInstance::Main), 0);
+        // Earlier hours are left alone
+        for slot in before.slots.iter().filter(|slot| slot.hour < hour) {
+            assert_eq!(after.instance_at_hour(slot.hour), Some(slot.instance));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_with_notifications() {
+        use crate::notifications::{AlertCondition, AlertRule, AlertSeverity};
+
+        let mut manager = NotificationManager::new();
+        manager
+            .add_rule(AlertRule::new(
+                "empty-slots",
+                AlertCondition::ZeroArticleSlots {
+                    consecutive_slots: 2,
+                },
+                AlertSeverity::Critical,
+            ))
+            .unwrap();
+
+        let server = CoordinatorServer::new(CoordinatorConfig::default())
+            .unwrap()
+            .with_notifications(manager);
+
+        let notifications = server.state().notifications;
+        assert!(notifications.read().await.get_rule("empty-slots").is_some());
     }
 }
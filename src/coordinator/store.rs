@@ -0,0 +1,290 @@
+//! SQLite persistence for the instance registry
+//!
+//! The registry lives in memory, so a coordinator restart used to forget
+//! every instance and its counts. With a store, each registration and
+//! heartbeat is also written to SQLite; on startup the instances seen within
+//! the retention window are restored (as offline until they next send a
+//! heartbeat), together with their cumulative article and error counts.
+//! Heartbeats older than the retention window are pruned.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use utoipa::ToSchema;
+
+use crate::scheduler::rotation::CrawlerInstance;
+
+use super::registry::{InstanceInfo, InstanceStatus};
+
+/// One heartbeat received from an instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct HeartbeatRecord {
+    pub instance: CrawlerInstance,
+    pub received_at: DateTime<Utc>,
+    pub articles_crawled: u64,
+    pub error_count: u64,
+    pub current_category: Option<String>,
+}
+
+/// SQLite store of registered instances and their heartbeats
+pub struct RegistryStore {
+    conn: Mutex<Connection>,
+
+    /// How long instances and heartbeats are kept after their last heartbeat
+    retention: TimeDelta,
+}
+
+/// Retention window of `days`, or `None` if it reaches past the earliest
+/// representable time
+pub fn retention_window(days: u32) -> Option<TimeDelta> {
+    TimeDelta::try_days(i64::from(days))
+        .filter(|window| Utc::now().checked_sub_signed(*window).is_some())
+}
+
+impl RegistryStore {
+    /// Open (or create) the store at `path`, keeping `retention_days` of history
+    pub fn open(path: impl AsRef<Path>, retention_days: u32) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Failed to open registry database")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
+
+        let store = Self::with_connection(conn, retention_days)?;
+        tracing::info!(path = %path.display(), "Registry store initialized");
+        Ok(store)
+    }
+
+    /// Create an in-memory store (for testing)
+    pub fn in_memory(retention_days: u32) -> Result<Self> {
+        let conn = Connection::open_in_memory().context("Failed to create in-memory SQLite")?;
+        Self::with_connection(conn, retention_days)
+    }
+
+    fn with_connection(conn: Connection, retention_days: u32) -> Result<Self> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS coordinator_instances (
+                instance TEXT PRIMARY KEY,
+                info TEXT NOT NULL,
+                last_heartbeat TEXT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS coordinator_heartbeats (
+                instance TEXT NOT NULL,
+                received_at TEXT NOT NULL,
+                articles_crawled INTEGER NOT NULL,
+                error_count INTEGER NOT NULL,
+                current_category TEXT
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_coordinator_heartbeats_instance
+                ON coordinator_heartbeats(instance, received_at);
+            "#,
+        )
+        .context("Failed to create registry schema")?;
+
+        let retention = retention_window(retention_days).with_context(|| {
+            format!("Registry retention of {retention_days} days is out of range")
+        })?;
+        let store = Self {
+            conn: Mutex::new(conn),
+            retention,
+        };
+        store.prune()?;
+        Ok(store)
+    }
+
+    /// Save the current state of an instance
+    pub fn save_instance(&self, info: &InstanceInfo) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            r#"
+            INSERT INTO coordinator_instances (instance, info, last_heartbeat)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(instance) DO UPDATE SET
+                info = excluded.info,
+                last_heartbeat = excluded.last_heartbeat
+            WHERE excluded.last_heartbeat >= coordinator_instances.last_heartbeat
+            "#,
+            params![
+                info.instance.id(),
+                serde_json::to_string(info)?,
+                info.last_heartbeat.to_rfc3339(),
+            ],
+        )
+        .context("Failed to save instance")?;
+        Ok(())
+    }
+
+    /// Forget an instance and its heartbeats
+    pub fn remove_instance(&self, instance: CrawlerInstance) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM coordinator_instances WHERE instance = ?1",
+            params![instance.id()],
+        )?;
+        conn.execute(
+            "DELETE FROM coordinator_heartbeats WHERE instance = ?1",
+            params![instance.id()],
+        )
+        .context("Failed to remove instance")?;
+        Ok(())
+    }
+
+    /// Append a heartbeat to the history
+    pub fn record_heartbeat(&self, record: &HeartbeatRecord) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            r#"
+            INSERT INTO coordinator_heartbeats
+                (instance, received_at, articles_crawled, error_count, current_category)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+            params![
+                record.instance.id(),
+                record.received_at.to_rfc3339(),
+                record.articles_crawled as i64,
+                record.error_count as i64,
+                record.current_category,
+            ],
+        )
+        .context("Failed to record heartbeat")?;
+        Ok(())
+    }
+
+    /// Instances with a heartbeat within the retention window, marked
+    /// offline (or kept in maintenance) until they report again
+    pub fn load_instances(&self) -> Result<Vec<InstanceInfo>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            "SELECT info FROM coordinator_instances WHERE last_heartbeat >= ?1 ORDER BY instance",
+        )?;
+        let rows = statement
+            .query_map(params![self.cutoff().to_rfc3339()], |row| {
+                row.get::<_, String>(0)
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load instances")?;
+
+        let mut instances = Vec::with_capacity(rows.len());
+        for json in rows {
+            match serde_json::from_str::<InstanceInfo>(&json) {
+                Ok(mut info) => {
+                    if info.status != InstanceStatus::Maintenance {
+                        info.mark_offline();
+                    }
+                    instances.push(info);
+                }
+                Err(e) => tracing::warn!(error = %e, "Skipping unreadable stored instance"),
+            }
+        }
+        Ok(instances)
+    }
+
+    /// Heartbeats of `instance` received since `since`, oldest first
+    pub fn heartbeats(
+        &self,
+        instance: CrawlerInstance,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<HeartbeatRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut statement = conn.prepare(
+            r#"
+            SELECT received_at, articles_crawled, error_count, current_category
+            FROM coordinator_heartbeats
+            WHERE instance = ?1 AND received_at >= ?2
+            ORDER BY received_at
+            "#,
+        )?;
+        let records = statement
+            .query_map(params![instance.id(), since.to_rfc3339()], |row| {
+                Ok(HeartbeatRecord {
+                    instance,
+                    received_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(0)?)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                    articles_crawled: row.get::<_, i64>(1)? as u64,
+                    error_count: row.get::<_, i64>(2)? as u64,
+                    current_category: row.get(3)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load heartbeats")?;
+        Ok(records)
+    }
+
+    /// Delete heartbeats and instances older than the retention window
+    ///
+    /// Returns the number of heartbeats deleted.
+    pub fn prune(&self) -> Result<usize> {
+        let cutoff = self.cutoff().to_rfc3339();
+        let conn = self.conn.lock().unwrap();
+        let deleted = conn.execute(
+            "DELETE FROM coordinator_heartbeats WHERE received_at < ?1",
+            params![cutoff],
+        )?;
+        conn.execute(
+            "DELETE FROM coordinator_instances WHERE last_heartbeat < ?1",
+            params![cutoff],
+        )
+        .context("Failed to prune registry store")?;
+        Ok(deleted)
+    }
+
+    fn cutoff(&self) -> DateTime<Utc> {
+        Utc::now()
+            .checked_sub_signed(self.retention)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retention_window() {
+        assert!(RegistryStore::in_memory(u32::MAX).is_err());
+        let store = RegistryStore::in_memory(7).unwrap();
+
+        let mut recent = InstanceInfo::new(CrawlerInstance::Main, "127.0.0.1".to_string(), 9000);
+        recent.total_articles = 120;
+        let mut stale = InstanceInfo::new(CrawlerInstance::Sub1, "127.0.0.1".to_string(), 9001);
+        stale.last_heartbeat = Utc::now() - TimeDelta::days(8);
+        store.save_instance(&recent).unwrap();
+        store.save_instance(&stale).unwrap();
+
+        for (instance, received_at) in [
+            (CrawlerInstance::Main, Utc::now() - TimeDelta::days(10)),
+            (CrawlerInstance::Main, Utc::now()),
+        ] {
+            store
+                .record_heartbeat(&HeartbeatRecord {
+                    instance,
+                    received_at,
+                    articles_crawled: 10,
+                    error_count: 0,
+                    current_category: Some("politics".to_string()),
+                })
+                .unwrap();
+        }
+
+        let instances = store.load_instances().unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].instance, CrawlerInstance::Main);
+        assert_eq!(instances[0].status, InstanceStatus::Offline);
+        assert_eq!(instances[0].total_articles, 120);
+
+        assert_eq!(store.prune().unwrap(), 1);
+        let since = Utc::now() - TimeDelta::days(30);
+        let history = store.heartbeats(CrawlerInstance::Main, since).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].current_category.as_deref(), Some("politics"));
+    }
+}
@@ -0,0 +1,363 @@
+//! Pull-based work queue for crawler instances
+//!
+//! Besides the hourly schedule, the coordinator can hand out individual URLs.
+//! URLs submitted to the queue are leased in batches to whichever instance
+//! asks for work, so a busy instance simply asks less often. A lease has to
+//! be completed before it expires; the URLs of an expired lease go back to
+//! the front of the queue for another instance to pick up.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use tokio::sync::RwLock;
+use utoipa::ToSchema;
+
+use crate::scheduler::rotation::CrawlerInstance;
+
+// ============================================================================
+// Requests and Responses
+// ============================================================================
+
+/// URLs to add to the queue
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EnqueueRequest {
+    pub urls: Vec<String>,
+}
+
+/// Result of adding URLs to the queue
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EnqueueResponse {
+    /// URLs added; ones already queued or leased are skipped
+    pub queued: usize,
+    /// URLs waiting to be leased
+    pub pending: usize,
+}
+
+/// Request for a batch of URLs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LeaseRequest {
+    pub instance_id: String,
+    /// Most URLs the instance wants; capped by the coordinator's batch size
+    pub max_urls: usize,
+}
+
+/// A batch of URLs leased to one instance
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkLease {
+    pub lease_id: String,
+    pub instance: CrawlerInstance,
+    pub urls: Vec<String>,
+    pub leased_at: DateTime<Utc>,
+    /// After this the URLs are handed out again
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Result of asking for work
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LeaseResponse {
+    /// The leased batch, or `None` when the queue is empty or the instance
+    /// is not available for work
+    pub lease: Option<WorkLease>,
+}
+
+/// Report of a finished lease
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CompleteRequest {
+    pub instance_id: String,
+    pub lease_id: String,
+    /// Leased URLs that failed transiently and should be handed out again;
+    /// every other URL of the lease counts as done
+    #[serde(default)]
+    pub retry: Vec<String>,
+}
+
+/// Result of completing a lease
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CompleteResponse {
+    pub completed: usize,
+    pub requeued: usize,
+}
+
+/// Work queue statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct WorkQueueStats {
+    /// URLs waiting to be leased
+    pub pending: usize,
+    /// Leases not yet completed or expired
+    pub active_leases: usize,
+    /// URLs in active leases
+    pub leased_urls: usize,
+    /// URLs completed since startup
+    pub completed: u64,
+    /// URLs put back after a lease expired or asked for a retry
+    pub requeued: u64,
+}
+
+// ============================================================================
+// Work Queue
+// ============================================================================
+
+#[derive(Default)]
+struct QueueState {
+    pending: VecDeque<String>,
+    leases: HashMap<String, WorkLease>,
+    /// Every URL pending or leased, to skip duplicates
+    known: HashSet<String>,
+    completed: u64,
+    requeued: u64,
+}
+
+impl QueueState {
+    /// Put the URLs of expired leases back at the front of the queue
+    fn requeue_expired(&mut self, now: DateTime<Utc>) -> usize {
+        let expired: Vec<String> = self
+            .leases
+            .values()
+            .filter(|lease| lease.expires_at <= now)
+            .map(|lease| lease.lease_id.clone())
+            .collect();
+
+        let mut requeued = 0;
+        for lease_id in expired {
+            if let Some(lease) = self.leases.remove(&lease_id) {
+                tracing::warn!(
+                    lease_id = %lease.lease_id,
+                    instance = %lease.instance,
+                    urls = lease.urls.len(),
+                    "Work lease expired, requeueing URLs"
+                );
+                requeued += lease.urls.len();
+                for url in lease.urls.into_iter().rev() {
+                    self.pending.push_front(url);
+                }
+            }
+        }
+        self.requeued += requeued as u64;
+        requeued
+    }
+}
+
+/// Queue of URLs leased to instances in batches
+pub struct WorkQueue {
+    state: RwLock<QueueState>,
+
+    /// How long an instance has to complete a lease
+    lease_secs: i64,
+
+    /// Most URLs in one lease
+    max_batch: usize,
+}
+
+impl WorkQueue {
+    /// Create an empty queue
+    pub fn new(lease_secs: u64, max_batch: usize) -> Self {
+        Self {
+            state: RwLock::new(QueueState::default()),
+            lease_secs: lease_secs as i64,
+            max_batch,
+        }
+    }
+
+    /// Add URLs to the back of the queue
+    pub async fn enqueue(&self, urls: Vec<String>) -> EnqueueResponse {
+        let mut state = self.state.write().await;
+        let mut queued = 0;
+        for url in urls {
+            let url = url.trim().to_string();
+            if url.is_empty() || !state.known.insert(url.clone()) {
+                continue;
+            }
+            state.pending.push_back(url);
+            queued += 1;
+        }
+
+        EnqueueResponse {
+            queued,
+            pending: state.pending.len(),
+        }
+    }
+
+    /// Lease up to `max_urls` URLs to `instance`
+    ///
+    /// Returns `None` when there is nothing to do.
+    pub async fn lease(&self, instance: CrawlerInstance, max_urls: usize) -> Option<WorkLease> {
+        let mut state = self.state.write().await;
+        let now = Utc::now();
+        state.requeue_expired(now);
+
+        let count = max_urls.min(self.max_batch).min(state.pending.len());
+        if count == 0 {
+            return None;
+        }
+
+        let lease = WorkLease {
+            lease_id: uuid::Uuid::new_v4().to_string(),
+            instance,
+            urls: state.pending.drain(..count).collect(),
+            leased_at: now,
+            expires_at: now + Duration::seconds(self.lease_secs),
+        };
+        state.leases.insert(lease.lease_id.clone(), lease.clone());
+        Some(lease)
+    }
+
+    /// Finish a lease of `instance`, handing the URLs in `retry` out again
+    pub async fn complete(
+        &self,
+        instance: CrawlerInstance,
+        lease_id: &str,
+        retry: &[String],
+    ) -> Result<CompleteResponse, WorkError> {
+        let mut state = self.state.write().await;
+
+        let lease = match state.leases.remove(lease_id) {
+            Some(lease) if lease.instance == instance => lease,
+            Some(lease) => {
+                state.leases.insert(lease_id.to_string(), lease);
+                return Err(WorkError::NotLeaseHolder {
+                    lease_id: lease_id.to_string(),
+                    instance,
+                });
+            }
+            None => return Err(WorkError::LeaseNotFound(lease_id.to_string())),
+        };
+
+        let retry: HashSet<&str> = retry.iter().map(String::as_str).collect();
+        let mut response = CompleteResponse {
+            completed: 0,
+            requeued: 0,
+        };
+        for url in lease.urls {
+            if retry.contains(url.as_str()) {
+                state.pending.push_back(url);
+                response.requeued += 1;
+            } else {
+                state.known.remove(&url);
+                response.completed += 1;
+            }
+        }
+        state.completed += response.completed as u64;
+        state.requeued += response.requeued as u64;
+
+        Ok(response)
+    }
+
+    /// Requeue the URLs of expired leases, returning how many were requeued
+    pub async fn requeue_expired(&self) -> usize {
+        self.state.write().await.requeue_expired(Utc::now())
+    }
+
+    /// Get queue statistics
+    pub async fn stats(&self) -> WorkQueueStats {
+        let state = self.state.read().await;
+        WorkQueueStats {
+            pending: state.pending.len(),
+            active_leases: state.leases.len(),
+            leased_urls: state.leases.values().map(|lease| lease.urls.len()).sum(),
+            completed: state.completed,
+            requeued: state.requeued,
+        }
+    }
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Work queue errors
+#[derive(Debug, Clone)]
+pub enum WorkError {
+    /// No active lease with this ID (never issued, completed or expired)
+    LeaseNotFound(String),
+
+    /// The lease belongs to another instance
+    NotLeaseHolder {
+        lease_id: String,
+        instance: CrawlerInstance,
+    },
+}
+
+impl std::fmt::Display for WorkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LeaseNotFound(id) => write!(f, "Lease not found or expired: {id}"),
+            Self::NotLeaseHolder { lease_id, instance } => {
+                write!(f, "Lease {lease_id} is not held by {instance}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorkError {}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("https://example.com/{i}")).collect()
+    }
+
+    #[tokio::test]
+    async fn test_lease_and_complete() {
+        let queue = WorkQueue::new(300, 3);
+
+        let response = queue.enqueue(urls(5)).await;
+        assert_eq!((response.queued, response.pending), (5, 5));
+        // Already queued
+        assert_eq!(queue.enqueue(urls(2)).await.queued, 0);
+
+        // Capped by the batch size
+        let lease = queue.lease(CrawlerInstance::Main, 10).await.unwrap();
+        assert_eq!(lease.urls, urls(3));
+        let other = queue.lease(CrawlerInstance::Sub1, 10).await.unwrap();
+        assert_eq!(other.urls.len(), 2);
+        assert!(queue.lease(CrawlerInstance::Sub2, 10).await.is_none());
+
+        let result = queue
+            .complete(CrawlerInstance::Sub1, &lease.lease_id, &[])
+            .await;
+        assert!(matches!(result, Err(WorkError::NotLeaseHolder { .. })));
+
+        let retry = vec![lease.urls[1].clone()];
+        let response = queue
+            .complete(CrawlerInstance::Main, &lease.lease_id, &retry)
+            .await
+            .unwrap();
+        assert_eq!((response.completed, response.requeued), (2, 1));
+        assert!(queue
+            .complete(CrawlerInstance::Main, &lease.lease_id, &[])
+            .await
+            .is_err());
+
+        let stats = queue.stats().await;
+        assert_eq!(stats.pending, 1);
+        assert_eq!(stats.active_leases, 1);
+        assert_eq!(stats.completed, 2);
+
+        // Completed URLs can be queued again
+        assert_eq!(queue.enqueue(urls(1)).await.queued, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_lease_is_requeued() {
+        let queue = WorkQueue::new(0, 10);
+        queue.enqueue(urls(2)).await;
+
+        let lease = queue.lease(CrawlerInstance::Main, 10).await.unwrap();
+        assert_eq!(queue.requeue_expired().await, 2);
+
+        // Handed out again, in the original order
+        let again = queue.lease(CrawlerInstance::Sub1, 10).await.unwrap();
+        assert_eq!(again.urls, lease.urls);
+        assert!(queue
+            .complete(CrawlerInstance::Main, &lease.lease_id, &[])
+            .await
+            .is_err());
+        assert_eq!(queue.stats().await.requeued, 2);
+    }
+}
@@ -0,0 +1,295 @@
+//! Downloading article images
+//!
+//! With `crawl --download-images`, the images the parser found in an
+//! article body are fetched through the [`Crawler`] (so rate limits,
+//! robots.txt and retries apply) and saved as `{n}.{ext}` under
+//! `assets/{oid}_{aid}/` next to the markdown directory. Each downloaded
+//! image gets a [`local_path`](crate::models::ArticleMedia::local_path)
+//! the markdown links to; an image that fails to download keeps its remote
+//! URL.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use reqwest::header::CONTENT_TYPE;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use url::Url;
+
+use super::Crawler;
+use crate::models::ParsedArticle;
+use crate::storage::ArticleAsset;
+
+/// Largest image that is downloaded (20 MiB)
+const MAX_IMAGE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Saves article images to an assets directory
+#[derive(Debug, Clone)]
+pub struct AssetDownloader {
+    dir: PathBuf,
+    /// `dir` as seen from the markdown files
+    link_prefix: String,
+}
+
+impl AssetDownloader {
+    /// Save images under `dir`, linked from markdown as
+    /// `{link_prefix}/{oid}_{aid}/{file}`
+    pub fn new(dir: impl Into<PathBuf>, link_prefix: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            link_prefix: link_prefix.into(),
+        }
+    }
+
+    /// Save images of articles written to `markdown_dir` in `assets/`
+    /// beside it (`output/raw` → `output/assets`)
+    pub fn for_markdown_dir(markdown_dir: &Path) -> Self {
+        match markdown_dir.parent() {
+            Some(parent) => Self::new(parent.join("assets"), "../assets"),
+            None => Self::new(markdown_dir.join("assets"), "assets"),
+        }
+    }
+
+    /// Directory images are saved under
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Download the images of `article`, setting their `local_path`
+    ///
+    /// Failures are logged and leave the image linked to its URL. Returns
+    /// the files that were saved.
+    pub async fn download(
+        &self,
+        crawler: &Crawler,
+        article: &mut ParsedArticle,
+    ) -> Vec<ArticleAsset> {
+        let id = article.id();
+        let mut assets = Vec::new();
+        for (index, image) in article.images.iter_mut().enumerate() {
+            match self
+                .download_image(crawler, &id, index + 1, &image.url)
+                .await
+            {
+                Ok((file_name, asset)) => {
+                    image.local_path = Some(format!("{}/{id}/{file_name}", self.link_prefix));
+                    assets.push(asset);
+                }
+                Err(e) => {
+                    tracing::warn!(url = %image.url, error = %e, "Failed to download image");
+                }
+            }
+        }
+        assets
+    }
+
+    /// Link the images of `article` downloaded on an earlier crawl to the
+    /// copies in `saved` (see [`Database::get_assets`](crate::storage::Database::get_assets))
+    ///
+    /// Images already linked, and those whose copy is gone, are left as they
+    /// are.
+    pub fn link_saved(&self, article: &mut ParsedArticle, saved: &[ArticleAsset]) {
+        let id = article.id();
+        for image in article.images.iter_mut() {
+            if image.local_path.is_some() {
+                continue;
+            }
+            let copy = saved
+                .iter()
+                .filter(|asset| asset.url == image.url)
+                .map(|asset| Path::new(&asset.path))
+                .find(|path| path.is_file());
+            if let Some(file_name) = copy.and_then(Path::file_name) {
+                image.local_path = Some(format!(
+                    "{}/{id}/{}",
+                    self.link_prefix,
+                    file_name.to_string_lossy()
+                ));
+            }
+        }
+    }
+
+    async fn download_image(
+        &self,
+        crawler: &Crawler,
+        article_id: &str,
+        number: usize,
+        url: &str,
+    ) -> Result<(String, ArticleAsset)> {
+        let mut response = crawler.fetch(url).await?;
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                value
+                    .split(';')
+                    .next()
+                    .unwrap_or(value)
+                    .trim()
+                    .to_lowercase()
+            });
+        if let Some(content_type) = &content_type {
+            anyhow::ensure!(
+                content_type.starts_with("image/") || content_type == "application/octet-stream",
+                "Not an image: {content_type}"
+            );
+        }
+        if let Some(length) = response.content_length() {
+            anyhow::ensure!(length <= MAX_IMAGE_BYTES, "Image too large: {length} bytes");
+        }
+        // Without a Content-Length, stop reading as soon as the cap is passed
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response.chunk().await.context("Failed to read image")? {
+            bytes.extend_from_slice(&chunk);
+            anyhow::ensure!(
+                bytes.len() as u64 <= MAX_IMAGE_BYTES,
+                "Image too large: over {MAX_IMAGE_BYTES} bytes"
+            );
+        }
+
+        let file_name = format!("{number:02}.{}", extension(content_type.as_deref(), url));
+        let dir = self.dir.join(article_id);
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(&file_name);
+        tokio::fs::write(&path, &bytes)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let asset = ArticleAsset {
+            article_id: article_id.to_string(),
+            url: url.to_string(),
+            path: path.display().to_string(),
+            content_type,
+            bytes: bytes.len() as u64,
+            sha256: format!("{:x}", Sha256::digest(&bytes)),
+            downloaded_at: Utc::now(),
+        };
+        Ok((file_name, asset))
+    }
+}
+
+/// File extension for an image, from its content type, else its URL,
+/// else `jpg`
+fn extension(content_type: Option<&str>, url: &str) -> String {
+    let known = match content_type {
+        Some("image/jpeg" | "image/jpg" | "image/pjpeg") => Some("jpg"),
+        Some("image/png") => Some("png"),
+        Some("image/gif") => Some("gif"),
+        Some("image/webp") => Some("webp"),
+        Some("image/avif") => Some("avif"),
+        Some("image/svg+xml") => Some("svg"),
+        Some("image/bmp") => Some("bmp"),
+        _ => None,
+    };
+    if let Some(extension) = known {
+        return extension.to_string();
+    }
+    Url::parse(url)
+        .ok()
+        .and_then(|url| {
+            let name = url.path_segments()?.next_back()?;
+            let (_, extension) = name.rsplit_once('.')?;
+            let valid = (1..=5).contains(&extension.len())
+                && extension.chars().all(|c| c.is_ascii_alphanumeric());
+            valid.then(|| extension.to_ascii_lowercase())
+        })
+        .unwrap_or_else(|| "jpg".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::ArticleMedia;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extension() {
+        let url = "https://imgnews.pstatic.net/image/001/a.PNG?type=w860";
+        assert_eq!(extension(Some("image/jpeg"), url), "jpg");
+        assert_eq!(extension(Some("application/octet-stream"), url), "png");
+        assert_eq!(extension(None, "https://example.com/photo"), "jpg");
+    }
+
+    #[tokio::test]
+    async fn test_download_sets_local_paths() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/a.jpg"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "image/png")
+                    .set_body_bytes(b"\x89PNG".to_vec()),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/page.html"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_string("<html></html>"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut config = Config::default();
+        config.crawler.robots.obey = false;
+        config.crawler.retry.max_retries = 0;
+        let crawler = Crawler::new(config).unwrap();
+
+        let temp = TempDir::new().unwrap();
+        let downloader = AssetDownloader::for_markdown_dir(&temp.path().join("raw"));
+        let mut article = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0000000001".to_string(),
+            ..Default::default()
+        };
+        for file in ["a.jpg", "page.html", "missing.jpg"] {
+            article.images.push(ArticleMedia {
+                url: format!("{}/{file}", server.uri()),
+                ..Default::default()
+            });
+        }
+
+        let assets = downloader.download(&crawler, &mut article).await;
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].bytes, 4);
+        assert_eq!(assets[0].content_type.as_deref(), Some("image/png"));
+        let saved = temp.path().join("assets/001_0000000001/01.png");
+        assert_eq!(std::fs::read(&saved).unwrap(), b"\x89PNG");
+        assert_eq!(
+            article.images[0].local_path.as_deref(),
+            Some("../assets/001_0000000001/01.png")
+        );
+        assert!(article.images[1..]
+            .iter()
+            .all(|image| image.local_path.is_none()));
+
+        // Crawled again without downloading, the copy is linked once more
+        let mut recrawled = article.clone();
+        for image in &mut recrawled.images {
+            image.local_path = None;
+        }
+        let mut gone = assets[0].clone();
+        gone.url = recrawled.images[2].url.clone();
+        gone.path = temp.path().join("missing.jpg").display().to_string();
+        downloader.link_saved(&mut recrawled, &[assets[0].clone(), gone]);
+        let links: Vec<_> = recrawled
+            .images
+            .iter()
+            .map(|i| i.local_path.clone())
+            .collect();
+        assert_eq!(
+            links,
+            article
+                .images
+                .iter()
+                .map(|i| i.local_path.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Per-host circuit breaker for [`Crawler::fetch`](super::Crawler::fetch)
+//!
+//! After `failure_threshold` consecutive failed requests (HTTP 429, 5xx or
+//! transport errors, retries included) a host's circuit opens and
+//! requests to it are refused with [`FetchError::CircuitOpen`] for
+//! `open_secs`, so a struggling site is not hammered by every worker at once.
+//! Then the circuit is half-open: one trial request is let through, and its
+//! outcome closes the circuit or opens it again. Each admitted request holds
+//! a [`CircuitPermit`]; a trial whose permit is dropped without an outcome
+//! (cancelled, or failed for a reason that says nothing about the host)
+//! hands the trial to the next request.
+//!
+//! The state of every host is exported as `baram_crawler_circuit_state`
+//! (0 = closed, 1 = half-open, 2 = open), and each opening is counted in
+//! `baram_crawler_circuit_opened_total`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+use crate::config::CircuitBreakerConfig;
+use crate::utils::error::FetchError;
+
+/// State of a host's circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests pass
+    Closed,
+    /// The open period is over; one trial request may pass
+    HalfOpen,
+    /// Requests are refused
+    Open,
+}
+
+impl CircuitState {
+    /// Name used in logs
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::HalfOpen => "half_open",
+            CircuitState::Open => "open",
+        }
+    }
+
+    /// Value of the `baram_crawler_circuit_state` gauge
+    #[must_use]
+    pub fn metric_value(&self) -> f64 {
+        match self {
+            CircuitState::Closed => 0.0,
+            CircuitState::HalfOpen => 1.0,
+            CircuitState::Open => 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct HostCircuit {
+    /// Consecutive failures
+    failures: u32,
+    /// When the circuit last opened, while it is not closed
+    opened_at: Option<Instant>,
+    /// Whether the trial request of a half-open circuit is in flight
+    trial: bool,
+}
+
+impl HostCircuit {
+    fn state(&self, open_for: Duration) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < open_for => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
+
+/// Tracks consecutive failures per host and refuses requests to failing ones
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    open_for: Duration,
+    hosts: Mutex<HashMap<String, HostCircuit>>,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker; a `failure_threshold` of 0 never opens
+    pub fn new(config: &CircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold,
+            open_for: config.open_duration(),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether the breaker can open at all
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.failure_threshold > 0
+    }
+
+    /// Current state of `host`'s circuit
+    #[must_use]
+    pub fn state(&self, host: &str) -> CircuitState {
+        lock(&self.hosts)
+            .get(host)
+            .map_or(CircuitState::Closed, |circuit| circuit.state(self.open_for))
+    }
+
+    /// Admit a request to `host`
+    ///
+    /// Fails with [`FetchError::CircuitOpen`] while the circuit is open, or
+    /// half-open with the trial request still in flight.
+    pub fn acquire(&self, host: &str) -> Result<CircuitPermit<'_>, FetchError> {
+        let mut permit = CircuitPermit {
+            breaker: self,
+            host: host.to_string(),
+            trial: false,
+        };
+        if !self.is_enabled() {
+            return Ok(permit);
+        }
+        let mut hosts = lock(&self.hosts);
+        let Some(circuit) = hosts.get_mut(host) else {
+            return Ok(permit);
+        };
+        match circuit.state(self.open_for) {
+            CircuitState::Closed => Ok(permit),
+            CircuitState::HalfOpen if !circuit.trial => {
+                circuit.trial = true;
+                permit.trial = true;
+                crate::metrics::update_circuit_state(host, CircuitState::HalfOpen.metric_value());
+                Ok(permit)
+            }
+            _ => Err(FetchError::CircuitOpen(host.to_string())),
+        }
+    }
+
+    /// Record a request to `host` that got an answer; closes its circuit
+    fn record_success(&self, host: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut hosts = lock(&self.hosts);
+        let Some(circuit) = hosts.remove(host) else {
+            return;
+        };
+        if circuit.opened_at.is_some() {
+            tracing::info!(host = %host, "Circuit closed");
+            crate::metrics::update_circuit_state(host, CircuitState::Closed.metric_value());
+        }
+    }
+
+    /// Record a failed request to `host`; opens its circuit at the threshold,
+    /// or again when the trial request of a half-open circuit failed
+    fn record_failure(&self, host: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut hosts = lock(&self.hosts);
+        let circuit = hosts.entry(host.to_string()).or_default();
+        circuit.failures = circuit.failures.saturating_add(1);
+        let reopen = circuit.trial || circuit.state(self.open_for) == CircuitState::HalfOpen;
+        circuit.trial = false;
+        if reopen || (circuit.opened_at.is_none() && circuit.failures >= self.failure_threshold) {
+            circuit.opened_at = Some(Instant::now());
+            tracing::warn!(
+                host = %host,
+                failures = circuit.failures,
+                open_secs = self.open_for.as_secs(),
+                "Circuit opened"
+            );
+            crate::metrics::record_circuit_opened(host);
+            crate::metrics::update_circuit_state(host, CircuitState::Open.metric_value());
+        }
+    }
+}
+
+/// A request admitted by [`CircuitBreaker::acquire`]
+///
+/// Report how the request went with [`Self::success`] or [`Self::failure`].
+/// Dropping the permit without either leaves the circuit as it is, but
+/// frees the trial slot of a half-open circuit.
+#[must_use = "report the outcome of the request"]
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    host: String,
+    /// Whether this is the trial request of a half-open circuit
+    trial: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// The host answered; closes its circuit
+    pub fn success(mut self) {
+        self.trial = false;
+        self.breaker.record_success(&self.host);
+    }
+
+    /// The request failed (HTTP 429, 5xx or a transport error)
+    pub fn failure(mut self) {
+        self.trial = false;
+        self.breaker.record_failure(&self.host);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.trial {
+            if let Some(circuit) = lock(&self.breaker.hosts).get_mut(&self.host) {
+                circuit.trial = false;
+            }
+        }
+    }
+}
+
+/// Circuit key of a URL: its host, with the port if one is given
+#[must_use]
+pub fn circuit_host(url: &str) -> String {
+    let Ok(url) = Url::parse(url) else {
+        return String::new();
+    };
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(failure_threshold: u32, open_secs: u64) -> CircuitBreaker {
+        CircuitBreaker::new(&CircuitBreakerConfig {
+            failure_threshold,
+            open_secs,
+        })
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = breaker(3, 60);
+        let host = "n.news.naver.com";
+
+        breaker.acquire(host).unwrap().failure();
+        breaker.acquire(host).unwrap().failure();
+        breaker.acquire(host).unwrap().success();
+        breaker.acquire(host).unwrap().failure();
+        breaker.acquire(host).unwrap().failure();
+        assert_eq!(breaker.state(host), CircuitState::Closed);
+
+        breaker.acquire(host).unwrap().failure();
+        assert_eq!(breaker.state(host), CircuitState::Open);
+        assert!(matches!(
+            breaker.acquire(host),
+            Err(FetchError::CircuitOpen(h)) if h == host
+        ));
+        assert!(breaker.acquire("news.daum.net").is_ok());
+    }
+
+    #[test]
+    fn test_half_open_trial_closes_or_reopens() {
+        let breaker = breaker(1, 0);
+        let host = "n.news.naver.com";
+
+        breaker.acquire(host).unwrap().failure();
+        assert_eq!(breaker.state(host), CircuitState::HalfOpen);
+        let trial = breaker.acquire(host).unwrap();
+        // Only one trial at a time
+        assert!(breaker.acquire(host).is_err());
+
+        trial.failure();
+        let trial = breaker.acquire(host).unwrap();
+        trial.success();
+        assert_eq!(breaker.state(host), CircuitState::Closed);
+        let _first = breaker.acquire(host).unwrap();
+        assert!(breaker.acquire(host).is_ok());
+    }
+
+    #[test]
+    fn test_dropped_trial_frees_the_slot() {
+        let breaker = breaker(1, 0);
+        let host = "n.news.naver.com";
+
+        breaker.acquire(host).unwrap().failure();
+        let trial = breaker.acquire(host).unwrap();
+        assert!(breaker.acquire(host).is_err());
+        drop(trial);
+        assert_eq!(breaker.state(host), CircuitState::HalfOpen);
+        assert!(breaker.acquire(host).is_ok());
+    }
+
+    #[test]
+    fn test_disabled_breaker_never_opens() {
+        let breaker = breaker(0, 60);
+        for _ in 0..10 {
+            breaker.acquire("example.com").unwrap().failure();
+        }
+        assert!(breaker.acquire("example.com").is_ok());
+        assert_eq!(breaker.state("example.com"), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_host() {
+        assert_eq!(
+            circuit_host("https://n.news.naver.com/mnews/article/001/1"),
+            "n.news.naver.com"
+        );
+        assert_eq!(circuit_host("http://127.0.0.1:8080/a"), "127.0.0.1:8080");
+        assert_eq!(circuit_host("not a url"), "");
+    }
+}
@@ -5,22 +5,39 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio::time::{interval, Duration};
+use tokio::sync::{Notify, RwLock};
+use tokio::time::{interval, interval_at, Duration, Instant};
 
-use chrono::Timelike;
+use chrono::{NaiveDate, Timelike};
 
+use crate::config::Config;
 use crate::coordinator::client::{ClientConfig, ClientError, CoordinatorClient, SlotResponse};
-use crate::crawler::fetcher::NaverFetcher;
+use crate::crawler::fetcher::{FetchPolicy, NaverFetcher};
 use crate::crawler::list::NewsListCrawler;
 use crate::crawler::pipeline::{CrawlerPipeline, PipelineConfig};
-use crate::metrics;
+use crate::metrics::Metrics;
 use crate::models::NewsCategory;
+use crate::notifications::KeywordAlerts;
+use crate::scheduler::distribution::DistributionEvent;
 use crate::scheduler::rotation::CrawlerInstance;
 use crate::storage::dedup::{DedupConfig, DedupRecord, SharedDedupChecker};
+use crate::storage::PgArticleStore;
 
 use super::instance::{InstanceConfig, InstanceState};
 
+/// First wait before resubscribing to coordinator events
+const EVENT_RECONNECT_MIN: Duration = Duration::from_secs(1);
+
+/// Longest wait before resubscribing to coordinator events
+const EVENT_RECONNECT_MAX: Duration = Duration::from_secs(300);
+
+/// URLs asked for in one work lease; the coordinator caps it at its
+/// `--work-batch-size`
+const WORK_LEASE_URLS: usize = 50;
+
+/// Run stats and metrics category of URLs from the work queue
+const WORK_QUEUE_CATEGORY: &str = "work-queue";
+
 // ============================================================================
 // Distributed Crawler Runner
 // ============================================================================
@@ -31,6 +48,8 @@ use super::instance::{InstanceConfig, InstanceState};
 /// - Registration with coordinator
 /// - Periodic heartbeat sending
 /// - Schedule polling and slot execution
+/// - Reacting to schedule changes pushed by the coordinator
+/// - Crawling URLs leased from the coordinator's work queue
 /// - Deduplication via PostgreSQL
 /// - Graceful shutdown
 pub struct DistributedRunner {
@@ -51,6 +70,21 @@ pub struct DistributedRunner {
 
     /// Shutdown receiver
     shutdown_rx: tokio::sync::watch::Receiver<bool>,
+
+    /// Prometheus metrics
+    metrics: Metrics,
+
+    /// Reloaded configs whose `crawler.rate_limit` replaces the configured rate
+    config_updates: Option<tokio::sync::watch::Receiver<Arc<Config>>>,
+
+    /// Alerts for bursts of watched keywords in crawled articles
+    keyword_alerts: Option<KeywordAlerts>,
+
+    /// Central store receiving full crawled articles (optional)
+    article_store: Option<PgArticleStore>,
+
+    /// robots.txt rules the fetchers obey
+    policy: FetchPolicy,
 }
 
 impl DistributedRunner {
@@ -72,9 +106,48 @@ impl DistributedRunner {
             dedup_checker: None,
             shutdown,
             shutdown_rx,
+            metrics: Metrics::global(),
+            config_updates: None,
+            keyword_alerts: None,
+            article_store: None,
+            policy: FetchPolicy::default(),
         })
     }
 
+    /// Record metrics to `metrics` instead of the global default
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Apply `crawler.rate_limit` from reloaded configs to the slots crawled
+    /// after the reload
+    pub fn with_config_updates(
+        mut self,
+        updates: tokio::sync::watch::Receiver<Arc<Config>>,
+    ) -> Self {
+        self.config_updates = Some(updates);
+        self
+    }
+
+    /// Count crawled articles for keyword alerts, evaluated after each slot
+    pub fn with_keyword_alerts(mut self, alerts: KeywordAlerts) -> Self {
+        self.keyword_alerts = Some(alerts);
+        self
+    }
+
+    /// Upsert crawled articles into PostgreSQL as well as writing markdown files
+    pub fn with_article_store(mut self, store: PgArticleStore) -> Self {
+        self.article_store = Some(store);
+        self
+    }
+
+    /// Obey `policy` (robots.txt) when fetching lists and articles
+    pub fn with_fetch_policy(mut self, policy: FetchPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     /// Create a new distributed runner with deduplication
     pub async fn with_dedup(config: InstanceConfig) -> Result<Self, RunnerError> {
         let mut runner = Self::new(config)?;
@@ -278,6 +351,8 @@ impl DistributedRunner {
     /// - Heartbeat sending
     /// - Schedule polling
     /// - Hourly slot execution
+    /// - Listening for schedule changes, which recheck the current hour
+    /// - Leasing URLs from the coordinator's work queue
     pub async fn start(&self) -> Result<RunnerHandle, RunnerError> {
         // Register first
         self.register().await?;
@@ -285,12 +360,17 @@ impl DistributedRunner {
         // Spawn heartbeat task
         let heartbeat_handle = self.spawn_heartbeat_task();
 
-        // Spawn schedule watcher task
-        let schedule_handle = self.spawn_schedule_watcher();
+        // Spawn schedule watcher task, woken early by coordinator events
+        let schedule_changed = Arc::new(Notify::new());
+        let schedule_handle = self.spawn_schedule_watcher(schedule_changed.clone());
+        let events_handle = self.spawn_event_listener(schedule_changed);
+        let work_handle = self.spawn_work_task();
 
         Ok(RunnerHandle {
             heartbeat_handle,
             schedule_handle,
+            events_handle,
+            work_handle,
             shutdown: self.shutdown.clone(),
         })
     }
@@ -312,7 +392,8 @@ impl DistributedRunner {
 
         // Update crawler state metrics
         let instance_id = self.config.instance_id.id();
-        metrics::update_crawler_state(instance_id, true, Some(slot.hour));
+        self.metrics
+            .update_crawler_state(instance_id, true, Some(slot.hour));
 
         let mut articles_crawled = 0u64;
         let mut errors = 0u64;
@@ -355,8 +436,13 @@ impl DistributedRunner {
 
         // Record slot execution metrics
         let instance_id = self.config.instance_id.id();
-        metrics::record_slot_execution(instance_id, slot.hour, errors > 0);
-        metrics::update_crawler_state(instance_id, false, None);
+        self.metrics
+            .record_slot_execution(instance_id, slot.hour, errors > 0);
+        self.metrics.update_crawler_state(instance_id, false, None);
+
+        if let Some(alerts) = &self.keyword_alerts {
+            alerts.evaluate_now().await;
+        }
 
         Ok(SlotResult {
             hour: slot.hour,
@@ -371,7 +457,7 @@ impl DistributedRunner {
         let instance_id = self.config.instance_id.id();
 
         // Start metrics timer
-        let _timer = metrics::start_crawl_timer(instance_id, category);
+        let _timer = self.metrics.start_crawl_timer(instance_id, category);
 
         // Step 1: Parse category string to NewsCategory enum
         let news_category = NewsCategory::parse(category)
@@ -387,15 +473,20 @@ impl DistributedRunner {
         let today = chrono::Local::now().format("%Y%m%d").to_string();
 
         // Step 3: Create fetcher and list crawler
-        let rps = self.config.requests_per_second.ceil() as u32;
+        let overrides = self.config.category(news_category);
+        let rps = overrides
+            .rate_limit
+            .unwrap_or(self.config.requests_per_second)
+            .ceil() as u32;
         let fetcher =
             NaverFetcher::with_config(rps, self.config.max_retries, self.config.timeout())
-                .map_err(|e| RunnerError::InitError(format!("Failed to create fetcher: {e}")))?;
+                .map_err(|e| RunnerError::InitError(format!("Failed to create fetcher: {e}")))?
+                .with_policy(self.policy.clone());
 
         let list_crawler = NewsListCrawler::new(fetcher);
 
         // Step 4: Collect URLs from the category (with pagination)
-        let max_pages = 10; // Default to 10 pages per category
+        let max_pages = overrides.max_articles.map_or(10, pages_for); // Default to 10 pages per category
         let all_urls = list_crawler
             .collect_urls(news_category, &today, max_pages)
             .await
@@ -412,12 +503,13 @@ impl DistributedRunner {
         }
 
         // Step 5: Filter new URLs using deduplication checker
-        let new_urls = self.filter_new_urls(&all_urls).await?;
+        let mut new_urls = self.filter_new_urls(&all_urls).await?;
 
         let existing_urls = all_urls.len() - new_urls.len();
 
         // Record deduplication metrics
-        metrics::record_dedup_results(instance_id, new_urls.len(), existing_urls);
+        self.metrics
+            .record_dedup_results(instance_id, new_urls.len(), existing_urls);
 
         tracing::info!(
             category = %category,
@@ -426,6 +518,10 @@ impl DistributedRunner {
             "Filtered URLs (dedup)"
         );
 
+        if let Some(max_articles) = overrides.max_articles {
+            new_urls.truncate(max_articles);
+        }
+
         if new_urls.is_empty() {
             tracing::info!(category = %category, "No new articles to crawl");
             return Ok(0);
@@ -440,14 +536,19 @@ impl DistributedRunner {
             output_dir: PathBuf::from(&self.config.output_dir).join("raw"),
             requests_per_second: rps,
             request_timeout: self.config.timeout(),
-            crawl_comments: self.config.include_comments,
+            crawl_comments: overrides.comments.unwrap_or(self.config.include_comments),
             max_retries: self.config.max_retries,
+            metrics_instance: instance_id.to_string(),
         };
 
         // Step 7: Run the pipeline
         let pipeline = CrawlerPipeline::new(pipeline_config)
             .await
-            .map_err(|e| RunnerError::InitError(format!("Failed to create pipeline: {e}")))?;
+            .map_err(|e| RunnerError::InitError(format!("Failed to create pipeline: {e}")))?
+            .with_metrics(self.metrics.clone())
+            .with_keyword_alerts(self.keyword_alerts.clone())
+            .with_article_store(self.article_store.clone())
+            .with_policy(self.policy.clone());
 
         let stats = pipeline
             .run(new_urls.clone())
@@ -463,7 +564,7 @@ impl DistributedRunner {
         );
 
         // Step 8: Record metrics for pipeline results
-        metrics::record_pipeline_results(
+        self.metrics.record_pipeline_results(
             instance_id,
             category,
             stats.success_count,
@@ -472,7 +573,8 @@ impl DistributedRunner {
         );
 
         // Record articles crawled for this category
-        metrics::record_articles_crawled(instance_id, category, stats.success_count);
+        self.metrics
+            .record_articles_crawled(instance_id, category, stats.success_count);
 
         // Step 9: Record successful crawls in deduplication database
         // Note: In a full implementation, we would get article IDs and content hashes
@@ -559,12 +661,20 @@ impl DistributedRunner {
     }
 
     /// Spawn schedule watcher background task
-    fn spawn_schedule_watcher(&self) -> tokio::task::JoinHandle<()> {
+    ///
+    /// Checks the schedule at every hour boundary, and right away when
+    /// `schedule_changed` is notified. Each hour is crawled at most once.
+    fn spawn_schedule_watcher(&self, schedule_changed: Arc<Notify>) -> tokio::task::JoinHandle<()> {
         let instance_id = self.config.instance_id;
-        let config = self.config.clone();
+        let mut config = self.config.clone();
+        let mut config_updates = self.config_updates.clone();
         let coordinator = self.coordinator_clone();
         let state = self.state.clone();
         let dedup_checker = self.dedup_checker.clone();
+        let metrics = self.metrics.clone();
+        let keyword_alerts = self.keyword_alerts.clone();
+        let article_store = self.article_store.clone();
+        let policy = self.policy.clone();
         let mut shutdown_rx = self.shutdown_rx.clone();
 
         tokio::spawn(async move {
@@ -586,82 +696,344 @@ impl DistributedRunner {
                 wait_duration
             );
 
-            tokio::time::sleep(wait_duration).await;
-
             // Then check every hour
-            let mut ticker = interval(Duration::from_secs(3600)); // 1 hour
+            let mut ticker = interval_at(
+                Instant::now() + wait_duration,
+                Duration::from_secs(3600), // 1 hour
+            );
             ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
+            // Date and hour of the last slot crawled
+            let mut last_run: Option<(NaiveDate, u8)> = None;
+
             loop {
                 tokio::select! {
-                    _ = ticker.tick() => {
-                        let hour = chrono::Local::now().hour() as u8;
-                        tracing::info!("Checking schedule for hour {}", hour);
+                    _ = ticker.tick() => {}
+                    _ = schedule_changed.notified() => {
+                        tracing::info!("Schedule changed, checking current hour");
+                    }
+                    _ = shutdown_rx.changed() => {
+                        tracing::info!("Schedule watcher shutting down");
+                        break;
+                    }
+                }
+
+                if let Some(updates) = &mut config_updates {
+                    if updates.has_changed().unwrap_or(false) {
+                        config.requests_per_second = updates.borrow_and_update().crawler.rate_limit;
+                        tracing::info!(
+                            rps = config.requests_per_second,
+                            "Using reloaded rate limit"
+                        );
+                    }
+                }
 
-                        match coordinator.should_crawl_at(hour).await {
-                            Ok(Some(categories)) => {
+                let now = chrono::Local::now();
+                let hour = now.hour() as u8;
+                if last_run == Some((now.date_naive(), hour)) {
+                    tracing::debug!("Hour {} already crawled", hour);
+                    continue;
+                }
+                tracing::info!("Checking schedule for hour {}", hour);
+
+                match coordinator.should_crawl_at(hour).await {
+                    Ok(Some(categories)) => {
+                        tracing::info!(
+                            "Instance {} should crawl at {}: {:?}",
+                            instance_id,
+                            hour,
+                            categories
+                        );
+                        last_run = Some((now.date_naive(), hour));
+
+                        // Create a slot response
+                        let slot = SlotResponse {
+                            hour,
+                            instance: instance_id.id().to_string(),
+                            categories: categories.clone(),
+                        };
+
+                        // Execute the crawl for this slot
+                        let result = Self::execute_slot_crawl(
+                            &config,
+                            &state,
+                            &dedup_checker,
+                            &metrics,
+                            keyword_alerts.as_ref(),
+                            article_store.as_ref(),
+                            &policy,
+                            &slot,
+                        )
+                        .await;
+
+                        match result {
+                            Ok(slot_result) => {
                                 tracing::info!(
-                                    "Instance {} should crawl at {}: {:?}",
-                                    instance_id,
-                                    hour,
-                                    categories
+                                    hour = slot_result.hour,
+                                    articles = slot_result.articles_crawled,
+                                    errors = slot_result.errors,
+                                    "Slot crawl completed"
                                 );
+                            }
+                            Err(e) => {
+                                tracing::error!("Slot crawl failed: {}", e);
+                            }
+                        }
+                    }
+                    Ok(None) => {
+                        tracing::debug!(
+                            "Instance {} is not scheduled for hour {}",
+                            instance_id,
+                            hour
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to check schedule: {}", e);
+                    }
+                }
+            }
+        })
+    }
 
-                                // Create a slot response
-                                let slot = SlotResponse {
-                                    hour,
-                                    instance: instance_id.id().to_string(),
-                                    categories: categories.clone(),
-                                };
-
-                                // Execute the crawl for this slot
-                                let result = Self::execute_slot_crawl(
-                                    &config,
-                                    &state,
-                                    &dedup_checker,
-                                    &slot,
-                                ).await;
-
-                                match result {
-                                    Ok(slot_result) => {
-                                        tracing::info!(
-                                            hour = slot_result.hour,
-                                            articles = slot_result.articles_crawled,
-                                            errors = slot_result.errors,
-                                            "Slot crawl completed"
-                                        );
-                                    }
-                                    Err(e) => {
-                                        tracing::error!("Slot crawl failed: {}", e);
+    /// Spawn coordinator event listener background task
+    ///
+    /// Subscribes to `GET /api/events` and notifies `schedule_changed` when
+    /// an event may hand the current hour to this instance. A dropped
+    /// subscription is renewed with exponential backoff.
+    fn spawn_event_listener(&self, schedule_changed: Arc<Notify>) -> tokio::task::JoinHandle<()> {
+        let instance_id = self.config.instance_id;
+        let coordinator = self.coordinator_clone();
+        let mut shutdown_rx = self.shutdown_rx.clone();
+
+        tokio::spawn(async move {
+            let mut backoff = EVENT_RECONNECT_MIN;
+            let mut subscribed_before = false;
+
+            loop {
+                let subscription = tokio::select! {
+                    subscription = coordinator.subscribe_events() => subscription,
+                    _ = shutdown_rx.changed() => break,
+                };
+
+                match subscription {
+                    Ok(mut events) => {
+                        tracing::info!("Subscribed to coordinator events");
+                        backoff = EVENT_RECONNECT_MIN;
+                        // Events may have been missed while disconnected
+                        if subscribed_before {
+                            schedule_changed.notify_one();
+                        }
+                        subscribed_before = true;
+
+                        loop {
+                            let event = tokio::select! {
+                                event = events.next_event() => event,
+                                _ = shutdown_rx.changed() => return,
+                            };
+                            match event {
+                                Ok(Some(event)) => {
+                                    tracing::debug!(event = event.name(), "Coordinator event");
+                                    let now = chrono::Local::now();
+                                    if affects_current_hour(
+                                        &event,
+                                        instance_id,
+                                        now.date_naive(),
+                                        now.hour() as u8,
+                                    ) {
+                                        schedule_changed.notify_one();
                                     }
                                 }
-                            }
-                            Ok(None) => {
-                                tracing::debug!(
-                                    "Instance {} is not scheduled for hour {}",
-                                    instance_id,
-                                    hour
-                                );
-                            }
-                            Err(e) => {
-                                tracing::warn!("Failed to check schedule: {}", e);
+                                Ok(None) => {
+                                    tracing::info!("Coordinator closed the event stream");
+                                    break;
+                                }
+                                Err(e) => {
+                                    tracing::warn!("Coordinator event stream failed: {}", e);
+                                    break;
+                                }
                             }
                         }
                     }
+                    Err(e) => {
+                        tracing::warn!("Failed to subscribe to coordinator events: {}", e);
+                    }
+                }
+
+                tokio::select! {
+                    _ = tokio::time::sleep(backoff) => {}
+                    _ = shutdown_rx.changed() => break,
+                }
+                backoff = (backoff * 2).min(EVENT_RECONNECT_MAX);
+            }
+
+            tracing::info!("Event listener shutting down");
+        })
+    }
+
+    /// Spawn work queue background task
+    ///
+    /// Every schedule poll interval, leases batches of URLs from the
+    /// coordinator until its queue is empty. Each batch is crawled and
+    /// completed, handing the URLs that failed back for a retry.
+    fn spawn_work_task(&self) -> tokio::task::JoinHandle<()> {
+        let config = self.config.clone();
+        let coordinator = self.coordinator_clone();
+        let state = self.state.clone();
+        let dedup_checker = self.dedup_checker.clone();
+        let metrics = self.metrics.clone();
+        let keyword_alerts = self.keyword_alerts.clone();
+        let article_store = self.article_store.clone();
+        let policy = self.policy.clone();
+        let mut shutdown_rx = self.shutdown_rx.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = interval(config.schedule_poll_interval());
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
                     _ = shutdown_rx.changed() => {
-                        tracing::info!("Schedule watcher shutting down");
+                        tracing::info!("Work queue task shutting down");
                         break;
                     }
                 }
+
+                // Lease until the queue is empty, unless shutting down
+                while !*shutdown_rx.borrow() {
+                    let lease = match coordinator.lease_work(WORK_LEASE_URLS).await {
+                        Ok(Some(lease)) => lease,
+                        Ok(None) => break,
+                        Err(e) => {
+                            tracing::warn!("Failed to lease work: {}", e);
+                            break;
+                        }
+                    };
+                    tracing::info!(
+                        lease = %lease.lease_id,
+                        urls = lease.urls.len(),
+                        "Leased work from coordinator"
+                    );
+
+                    let retry = match Self::crawl_urls_static(
+                        &config,
+                        &dedup_checker,
+                        &metrics,
+                        keyword_alerts.as_ref(),
+                        article_store.as_ref(),
+                        &policy,
+                        lease.urls.clone(),
+                    )
+                    .await
+                    {
+                        Ok((count, failed)) => {
+                            state.write().await.record_success(count);
+                            failed
+                        }
+                        Err(e) => {
+                            tracing::error!("Leased crawl failed: {}", e);
+                            state.write().await.record_error();
+                            lease.urls
+                        }
+                    };
+
+                    if let Err(e) = coordinator.complete_work(&lease.lease_id, retry).await {
+                        // The coordinator requeues the URLs when the lease expires
+                        tracing::warn!(lease = %lease.lease_id, "Failed to complete lease: {}", e);
+                    }
+
+                    if let Some(alerts) = &keyword_alerts {
+                        alerts.evaluate_now().await;
+                    }
+                }
             }
         })
     }
 
+    /// Crawl `urls` leased from the work queue (for use in spawned tasks)
+    ///
+    /// Returns the number of articles crawled and the URLs that failed.
+    async fn crawl_urls_static(
+        config: &InstanceConfig,
+        dedup_checker: &Option<SharedDedupChecker>,
+        metrics: &Metrics,
+        keyword_alerts: Option<&KeywordAlerts>,
+        article_store: Option<&PgArticleStore>,
+        policy: &FetchPolicy,
+        urls: Vec<String>,
+    ) -> Result<(u64, Vec<String>), RunnerError> {
+        let instance_id = config.instance_id.id();
+        let _timer = metrics.start_crawl_timer(instance_id, WORK_QUEUE_CATEGORY);
+
+        let total = urls.len();
+        let new_urls = if let Some(checker) = dedup_checker {
+            checker
+                .batch_check_urls(&urls)
+                .await
+                .map_err(|e| RunnerError::CrawlError(format!("Dedup check failed: {e}")))?
+                .new_urls
+        } else {
+            urls
+        };
+        metrics.record_dedup_results(instance_id, new_urls.len(), total - new_urls.len());
+        if new_urls.is_empty() {
+            return Ok((0, Vec::new()));
+        }
+
+        let rps = config.requests_per_second.ceil() as u32;
+        let pipeline_config = PipelineConfig {
+            fetcher_workers: 3,
+            parser_workers: 2,
+            storage_workers: 2,
+            channel_buffer_size: 100,
+            output_dir: PathBuf::from(&config.output_dir).join("raw"),
+            requests_per_second: rps,
+            request_timeout: config.timeout(),
+            crawl_comments: config.include_comments,
+            max_retries: config.max_retries,
+            metrics_instance: instance_id.to_string(),
+        };
+        let pipeline = CrawlerPipeline::new(pipeline_config)
+            .await
+            .map_err(|e| RunnerError::InitError(format!("Failed to create pipeline: {e}")))?
+            .with_metrics(metrics.clone())
+            .with_keyword_alerts(keyword_alerts.cloned())
+            .with_article_store(article_store.cloned())
+            .with_policy(policy.clone());
+
+        let stats = pipeline
+            .run(new_urls)
+            .await
+            .map_err(|e| RunnerError::CrawlError(format!("Pipeline error: {e}")))?;
+
+        tracing::info!(
+            success = stats.success_count,
+            failed = stats.failed_count,
+            skipped = stats.skipped_count,
+            "Leased crawl completed"
+        );
+        metrics.record_pipeline_results(
+            instance_id,
+            WORK_QUEUE_CATEGORY,
+            stats.success_count,
+            stats.failed_count,
+            stats.skipped_count,
+        );
+        metrics.record_articles_crawled(instance_id, WORK_QUEUE_CATEGORY, stats.success_count);
+
+        Ok((stats.success_count, pipeline.failed_urls()))
+    }
+
     /// Execute a slot crawl (static method for use in spawned tasks)
+    #[allow(clippy::too_many_arguments)]
     async fn execute_slot_crawl(
         config: &InstanceConfig,
         state: &Arc<RwLock<InstanceState>>,
         dedup_checker: &Option<SharedDedupChecker>,
+        metrics: &Metrics,
+        keyword_alerts: Option<&KeywordAlerts>,
+        article_store: Option<&PgArticleStore>,
+        policy: &FetchPolicy,
         slot: &SlotResponse,
     ) -> Result<SlotResult, RunnerError> {
         let instance_id = config.instance_id.id();
@@ -680,7 +1052,7 @@ impl DistributedRunner {
         }
 
         // Update crawler state metrics
-        metrics::update_crawler_state(instance_id, true, Some(slot.hour));
+        metrics.update_crawler_state(instance_id, true, Some(slot.hour));
 
         let mut articles_crawled = 0u64;
         let mut errors = 0u64;
@@ -696,7 +1068,17 @@ impl DistributedRunner {
             }
 
             // Execute category crawl
-            match Self::crawl_category_static(config, dedup_checker, category).await {
+            match Self::crawl_category_static(
+                config,
+                dedup_checker,
+                metrics,
+                keyword_alerts,
+                article_store,
+                policy,
+                category,
+            )
+            .await
+            {
                 Ok(count) => {
                     articles_crawled += count;
                     tracing::info!("Crawled {} articles from {}", count, category);
@@ -722,8 +1104,12 @@ impl DistributedRunner {
         }
 
         // Record slot execution metrics
-        metrics::record_slot_execution(instance_id, slot.hour, errors > 0);
-        metrics::update_crawler_state(instance_id, false, None);
+        metrics.record_slot_execution(instance_id, slot.hour, errors > 0);
+        metrics.update_crawler_state(instance_id, false, None);
+
+        if let Some(alerts) = keyword_alerts {
+            alerts.evaluate_now().await;
+        }
 
         Ok(SlotResult {
             hour: slot.hour,
@@ -737,12 +1123,16 @@ impl DistributedRunner {
     async fn crawl_category_static(
         config: &InstanceConfig,
         dedup_checker: &Option<SharedDedupChecker>,
+        metrics: &Metrics,
+        keyword_alerts: Option<&KeywordAlerts>,
+        article_store: Option<&PgArticleStore>,
+        policy: &FetchPolicy,
         category: &str,
     ) -> Result<u64, RunnerError> {
         let instance_id = config.instance_id.id();
 
         // Start metrics timer
-        let _timer = metrics::start_crawl_timer(instance_id, category);
+        let _timer = metrics.start_crawl_timer(instance_id, category);
 
         // Step 1: Parse category string to NewsCategory enum
         let news_category = NewsCategory::parse(category)
@@ -758,14 +1148,19 @@ impl DistributedRunner {
         let today = chrono::Local::now().format("%Y%m%d").to_string();
 
         // Step 3: Create fetcher and list crawler
-        let rps = config.requests_per_second.ceil() as u32;
+        let overrides = config.category(news_category);
+        let rps = overrides
+            .rate_limit
+            .unwrap_or(config.requests_per_second)
+            .ceil() as u32;
         let fetcher = NaverFetcher::with_config(rps, config.max_retries, config.timeout())
-            .map_err(|e| RunnerError::InitError(format!("Failed to create fetcher: {e}")))?;
+            .map_err(|e| RunnerError::InitError(format!("Failed to create fetcher: {e}")))?
+            .with_policy(policy.clone());
 
         let list_crawler = NewsListCrawler::new(fetcher);
 
         // Step 4: Collect URLs from the category (with pagination)
-        let max_pages = 10;
+        let max_pages = overrides.max_articles.map_or(10, pages_for);
         let all_urls = list_crawler
             .collect_urls(news_category, &today, max_pages)
             .await
@@ -782,7 +1177,7 @@ impl DistributedRunner {
         }
 
         // Step 5: Filter new URLs using deduplication checker
-        let new_urls = if let Some(checker) = dedup_checker {
+        let mut new_urls = if let Some(checker) = dedup_checker {
             let result = checker
                 .batch_check_urls(&all_urls)
                 .await
@@ -802,7 +1197,7 @@ impl DistributedRunner {
         let existing_urls = all_urls.len() - new_urls.len();
 
         // Record deduplication metrics
-        metrics::record_dedup_results(instance_id, new_urls.len(), existing_urls);
+        metrics.record_dedup_results(instance_id, new_urls.len(), existing_urls);
 
         tracing::info!(
             category = %category,
@@ -811,6 +1206,10 @@ impl DistributedRunner {
             "Filtered URLs (dedup)"
         );
 
+        if let Some(max_articles) = overrides.max_articles {
+            new_urls.truncate(max_articles);
+        }
+
         if new_urls.is_empty() {
             tracing::info!(category = %category, "No new articles to crawl");
             return Ok(0);
@@ -825,14 +1224,19 @@ impl DistributedRunner {
             output_dir: PathBuf::from(&config.output_dir).join("raw"),
             requests_per_second: rps,
             request_timeout: config.timeout(),
-            crawl_comments: config.include_comments,
+            crawl_comments: overrides.comments.unwrap_or(config.include_comments),
             max_retries: config.max_retries,
+            metrics_instance: instance_id.to_string(),
         };
 
         // Step 7: Run the pipeline
         let pipeline = CrawlerPipeline::new(pipeline_config)
             .await
-            .map_err(|e| RunnerError::InitError(format!("Failed to create pipeline: {e}")))?;
+            .map_err(|e| RunnerError::InitError(format!("Failed to create pipeline: {e}")))?
+            .with_metrics(metrics.clone())
+            .with_keyword_alerts(keyword_alerts.cloned())
+            .with_article_store(article_store.cloned())
+            .with_policy(policy.clone());
 
         let stats = pipeline
             .run(new_urls)
@@ -848,7 +1252,7 @@ impl DistributedRunner {
         );
 
         // Record metrics for pipeline results
-        metrics::record_pipeline_results(
+        metrics.record_pipeline_results(
             instance_id,
             category,
             stats.success_count,
@@ -857,7 +1261,7 @@ impl DistributedRunner {
         );
 
         // Record articles crawled for this category
-        metrics::record_articles_crawled(instance_id, category, stats.success_count);
+        metrics.record_articles_crawled(instance_id, category, stats.success_count);
 
         Ok(stats.success_count)
     }
@@ -904,13 +1308,20 @@ impl DistributedRunner {
 pub struct RunnerHandle {
     heartbeat_handle: tokio::task::JoinHandle<()>,
     schedule_handle: tokio::task::JoinHandle<()>,
+    events_handle: tokio::task::JoinHandle<()>,
+    work_handle: tokio::task::JoinHandle<()>,
     shutdown: tokio::sync::watch::Sender<bool>,
 }
 
 impl RunnerHandle {
     /// Wait for all tasks to complete
     pub async fn wait(self) {
-        let _ = tokio::join!(self.heartbeat_handle, self.schedule_handle);
+        let _ = tokio::join!(
+            self.heartbeat_handle,
+            self.schedule_handle,
+            self.events_handle,
+            self.work_handle
+        );
     }
 
     /// Trigger shutdown and wait
@@ -921,7 +1332,10 @@ impl RunnerHandle {
 
     /// Check if tasks are still running
     pub fn is_running(&self) -> bool {
-        !self.heartbeat_handle.is_finished() && !self.schedule_handle.is_finished()
+        !self.heartbeat_handle.is_finished()
+            && !self.schedule_handle.is_finished()
+            && !self.events_handle.is_finished()
+            && !self.work_handle.is_finished()
     }
 }
 
@@ -1008,6 +1422,38 @@ impl From<ClientError> for RunnerError {
 // Utility Functions
 // ============================================================================
 
+/// Whether `event` may change who crawls `hour` of `today`, so that
+/// `instance` should check the schedule again
+fn affects_current_hour(
+    event: &DistributionEvent,
+    instance: CrawlerInstance,
+    today: NaiveDate,
+    hour: u8,
+) -> bool {
+    match event {
+        DistributionEvent::ScheduleReady { date, .. } => *date == today,
+        DistributionEvent::ScheduleUpdated {
+            date,
+            affected_hours,
+            ..
+        } => *date == today && affected_hours.contains(&hour),
+        DistributionEvent::AssignmentChanged {
+            instance: assigned,
+            hour: changed,
+            ..
+        } => *assigned == instance && *changed == hour,
+        DistributionEvent::EmergencyOverride {
+            affected_instance, ..
+        } => *affected_instance == instance,
+        DistributionEvent::RotationTriggered { .. } => false,
+    }
+}
+
+/// List pages to fetch for `max_articles` (about 20 articles per page)
+fn pages_for(max_articles: usize) -> u32 {
+    max_articles.div_ceil(20) as u32
+}
+
 /// Calculate time until next hour
 ///
 /// Returns the duration from now until the start of the next hour.
@@ -1101,6 +1547,55 @@ mod tests {
         assert!(duration.as_secs() <= 86400); // Less than 24 hours
     }
 
+    #[test]
+    fn test_affects_current_hour() {
+        use crate::scheduler::distribution::UpdateReason;
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let updated = |date, hours: Vec<u8>| DistributionEvent::ScheduleUpdated {
+            date,
+            reason: UpdateReason::Failover,
+            affected_hours: hours,
+        };
+
+        assert!(affects_current_hour(
+            &updated(today, vec![13, 14]),
+            CrawlerInstance::Sub1,
+            today,
+            14
+        ));
+        assert!(!affects_current_hour(
+            &updated(today, vec![15]),
+            CrawlerInstance::Sub1,
+            today,
+            14
+        ));
+        assert!(!affects_current_hour(
+            &updated(today.succ_opt().unwrap(), vec![14]),
+            CrawlerInstance::Sub1,
+            today,
+            14
+        ));
+
+        let changed = DistributionEvent::AssignmentChanged {
+            instance: CrawlerInstance::Sub1,
+            hour: 14,
+            previous: Some(CrawlerInstance::Main),
+        };
+        assert!(affects_current_hour(
+            &changed,
+            CrawlerInstance::Sub1,
+            today,
+            14
+        ));
+        assert!(!affects_current_hour(
+            &changed,
+            CrawlerInstance::Sub2,
+            today,
+            14
+        ));
+    }
+
     #[test]
     fn test_runner_error_display() {
         let error = RunnerError::CoordinatorError("connection refused".to_string());
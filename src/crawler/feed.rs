@@ -0,0 +1,309 @@
+//! RSS/Atom feed and XML sitemap parsing for URL discovery
+//!
+//! Besides paginating list pages, article URLs can be discovered from the
+//! feeds and sitemaps configured per category (`[category.<name>]` `feeds`
+//! and `sitemaps`), selected with `baram crawl --discovery rss|sitemap`:
+//! - RSS 2.0 `<item>`s (`<link>`, or a permalink `<guid>`) and Atom
+//!   `<entry>`s (`<link href>`), dated by `<pubDate>`, `<published>` or
+//!   `<updated>`
+//! - Sitemaps (`<urlset>`) dated by `<news:publication_date>` or `<lastmod>`,
+//!   and sitemap indexes (`<sitemapindex>`) whose newest child sitemaps are
+//!   followed
+//!
+//! Parsing is deliberately lenient: elements are matched by name whatever
+//! their namespace prefix, CDATA and entities are unwrapped, and anything
+//! unrecognized is skipped rather than failing the whole document.
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use regex::Regex;
+
+/// How `crawl` discovers the article URLs of a category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Discovery {
+    /// Paginate the site's article list pages
+    #[default]
+    List,
+    /// Read the category's RSS/Atom feeds
+    Rss,
+    /// Read the category's XML sitemaps
+    Sitemap,
+}
+
+impl Discovery {
+    /// Name used on the command line
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Discovery::List => "list",
+            Discovery::Rss => "rss",
+            Discovery::Sitemap => "sitemap",
+        }
+    }
+}
+
+impl fmt::Display for Discovery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Discovery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "list" => Ok(Discovery::List),
+            "rss" | "feed" | "atom" => Ok(Discovery::Rss),
+            "sitemap" => Ok(Discovery::Sitemap),
+            other => Err(format!(
+                "Unknown discovery mode: {other}. Use list, rss or sitemap"
+            )),
+        }
+    }
+}
+
+/// A URL listed by a feed or sitemap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedEntry {
+    pub url: String,
+    /// Publication (or last modification) time, if given
+    pub published: Option<DateTime<FixedOffset>>,
+}
+
+/// A parsed sitemap document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sitemap {
+    /// `<urlset>`: page URLs
+    Urls(Vec<FeedEntry>),
+    /// `<sitemapindex>`: URLs of further sitemaps
+    Index(Vec<FeedEntry>),
+}
+
+static ITEM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<(?:\w+:)?(item|entry)\b[^>]*>(.*?)</(?:\w+:)?(?:item|entry)>").unwrap()
+});
+static SITEMAP_URL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)<(?:\w+:)?(url|sitemap)\b[^>]*>(.*?)</(?:\w+:)?(?:url|sitemap)>").unwrap()
+});
+static ATOM_LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<(?:\w+:)?link\b([^>]*?)/?>"#).unwrap());
+static GUID_NOT_PERMALINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<guid\b[^>]*isPermaLink\s*=\s*["']false["']"#).unwrap());
+static ATTRIBUTE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(\w+)\s*=\s*["']([^"']*)["']"#).unwrap());
+
+/// Entries of an RSS 2.0 or Atom feed, in document order
+#[must_use]
+pub fn parse_feed(xml: &str) -> Vec<FeedEntry> {
+    ITEM.captures_iter(xml)
+        .filter_map(|item| {
+            let body = &item[2];
+            let url = match &item[1] {
+                "entry" => atom_link(body),
+                _ => element(body, "link").or_else(|| permalink_guid(body)),
+            }?;
+            let published = ["pubDate", "published", "updated", "date"]
+                .iter()
+                .find_map(|name| element(body, name).and_then(|d| parse_date(&d)));
+            Some(FeedEntry { url, published })
+        })
+        .collect()
+}
+
+/// URLs of a sitemap or sitemap index, in document order
+#[must_use]
+pub fn parse_sitemap(xml: &str) -> Sitemap {
+    let mut urls = Vec::new();
+    let mut sitemaps = Vec::new();
+    for entry in SITEMAP_URL.captures_iter(xml) {
+        let body = &entry[2];
+        let Some(url) = element(body, "loc") else {
+            continue;
+        };
+        let published = ["publication_date", "lastmod"]
+            .iter()
+            .find_map(|name| element(body, name).and_then(|d| parse_date(&d)));
+        let entry_list = if &entry[1] == "sitemap" {
+            &mut sitemaps
+        } else {
+            &mut urls
+        };
+        entry_list.push(FeedEntry { url, published });
+    }
+    if urls.is_empty() && !sitemaps.is_empty() {
+        Sitemap::Index(sitemaps)
+    } else {
+        Sitemap::Urls(urls)
+    }
+}
+
+/// Sort entries newest first; undated entries go last, in document order
+pub fn newest_first(entries: &mut [FeedEntry]) {
+    entries.sort_by_key(|e| Reverse(e.published));
+}
+
+/// Keep the entries published on or after `since`, and undated ones
+pub fn published_since(entries: &mut Vec<FeedEntry>, since: NaiveDate) {
+    entries.retain(|e| e.published.map_or(true, |p| p.date_naive() >= since));
+}
+
+/// Text of the first `<name>` child (any namespace prefix), unwrapped
+///
+/// Self-closing tags (`<atom:link href=".." />`) have no text and are skipped.
+fn element(body: &str, name: &str) -> Option<String> {
+    let mut rest = body;
+    let text = loop {
+        let start = rest.find('<')?;
+        rest = &rest[start + 1..];
+        let end = rest.find('>')?;
+        let tag = &rest[..end];
+        let tag_name = tag
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        let local = tag_name.rsplit(':').next().unwrap_or_default();
+        if local == name && !tag.ends_with('/') {
+            let content = &rest[end + 1..];
+            let close = content.find(&format!("</{tag_name}>"))?;
+            break &content[..close];
+        }
+        rest = &rest[end..];
+    };
+    let text = text.trim();
+    let text = text
+        .strip_prefix("<![CDATA[")
+        .and_then(|t| t.strip_suffix("]]>"))
+        .unwrap_or(text);
+    let text = html_escape::decode_html_entities(text.trim())
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// `href` of the alternate (or only) `<link>` of an Atom entry
+fn atom_link(body: &str) -> Option<String> {
+    ATOM_LINK
+        .captures_iter(body)
+        .filter_map(|link| {
+            let attributes: Vec<(String, String)> = ATTRIBUTE
+                .captures_iter(&link[1])
+                .map(|a| (a[1].to_string(), a[2].to_string()))
+                .collect();
+            let attribute = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.as_str())
+            };
+            let href = attribute("href")?;
+            matches!(attribute("rel"), None | Some("alternate"))
+                .then(|| html_escape::decode_html_entities(href).to_string())
+        })
+        .next()
+        // RSS-style <link>text</link> inside an entry
+        .or_else(|| element(body, "link"))
+}
+
+/// An RSS `<guid>` that is a permalink
+fn permalink_guid(body: &str) -> Option<String> {
+    let guid = element(body, "guid")?;
+    let is_permalink = !GUID_NOT_PERMALINK.is_match(body);
+    (is_permalink && guid.starts_with("http")).then_some(guid)
+}
+
+/// RFC 2822 (RSS), RFC 3339 (Atom, sitemaps) or a bare date
+fn parse_date(text: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc2822(text)
+        .or_else(|_| DateTime::parse_from_rfc3339(text))
+        .ok()
+        .or_else(|| {
+            let date = NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()?;
+            Some(date.and_hms_opt(0, 0, 0)?.and_utc().fixed_offset())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discovery_from_str() {
+        assert_eq!("RSS".parse::<Discovery>(), Ok(Discovery::Rss));
+        assert_eq!("sitemap".parse::<Discovery>(), Ok(Discovery::Sitemap));
+        assert_eq!(Discovery::default(), Discovery::List);
+        assert!("crawl".parse::<Discovery>().is_err());
+    }
+
+    #[test]
+    fn test_parse_rss_and_atom() {
+        let rss = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>경제</title><link>https://news.example.com</link>
+<item><title><![CDATA[금리 동결]]></title><atom:link href="https://example.com/feed" rel="self" />
+  <link><![CDATA[https://n.news.naver.com/mnews/article/001/0000000001?sid=101]]></link>
+  <pubDate>Tue, 03 Feb 2026 09:00:00 +0900</pubDate></item>
+<item><title>환율</title><guid isPermaLink="true">https://n.news.naver.com/mnews/article/001/0000000002</guid></item>
+<item><title>no link</title><guid isPermaLink="false">abc</guid></item>
+</channel></rss>"#;
+        let entries = parse_feed(rss);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].url,
+            "https://n.news.naver.com/mnews/article/001/0000000001?sid=101"
+        );
+        assert_eq!(
+            entries[0].published.unwrap().to_rfc3339(),
+            "2026-02-03T09:00:00+09:00"
+        );
+        assert_eq!(entries[1].published, None);
+
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+<entry><title>A</title><link rel="self" href="https://example.com/self"/>
+  <link rel="alternate" href="https://example.com/a?x=1&amp;y=2"/>
+  <updated>2026-02-03T00:00:00Z</updated></entry>
+<entry><title>B</title><link href="https://example.com/b"/></entry>
+</feed>"#;
+        let entries = parse_feed(atom);
+        let urls: Vec<&str> = entries.iter().map(|e| e.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            ["https://example.com/a?x=1&y=2", "https://example.com/b"]
+        );
+    }
+
+    #[test]
+    fn test_parse_sitemap_and_index() {
+        let sitemap = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"
+  xmlns:news="http://www.google.com/schemas/sitemap-news/0.9">
+<url><loc>https://example.com/old</loc><lastmod>2026-01-01</lastmod></url>
+<url><loc>https://example.com/new</loc>
+  <news:news><news:publication_date>2026-02-03T08:00:00+09:00</news:publication_date></news:news></url>
+<url><loc>https://example.com/undated</loc></url>
+</urlset>"#;
+        let Sitemap::Urls(mut entries) = parse_sitemap(sitemap) else {
+            panic!("expected a urlset");
+        };
+        newest_first(&mut entries);
+        let urls: Vec<&str> = entries.iter().map(|e| e.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/new",
+                "https://example.com/old",
+                "https://example.com/undated"
+            ]
+        );
+
+        published_since(&mut entries, NaiveDate::from_ymd_opt(2026, 2, 1).unwrap());
+        assert_eq!(entries.len(), 2);
+
+        let index = r#"<sitemapindex><sitemap><loc>https://example.com/s1.xml</loc>
+<lastmod>2026-02-03</lastmod></sitemap></sitemapindex>"#;
+        assert!(matches!(parse_sitemap(index), Sitemap::Index(children) if children.len() == 1));
+        assert_eq!(parse_sitemap("<html></html>"), Sitemap::Urls(Vec::new()));
+    }
+}
@@ -7,14 +7,14 @@
 //! - Automatic retry with exponential backoff
 //! - EUC-KR encoding detection and conversion
 //! - Proper referer header generation
+//! - robots.txt rules and per-domain rate limits shared with the
+//!   [`Crawler`](super::Crawler), see [`FetchPolicy`]
 
+use crate::config::CrawlerConfig;
+use crate::crawler::rate_limit::{self, AdjustableRateLimiter, DomainRateLimiter};
+use crate::crawler::robots::RobotsManager;
 use crate::utils::error::FetchError;
 use encoding_rs::{EUC_KR, UTF_8};
-use governor::{
-    clock::DefaultClock,
-    state::{InMemoryState, NotKeyed},
-    Quota, RateLimiter,
-};
 use rand::seq::SliceRandom;
 use reqwest::{
     header::{
@@ -22,7 +22,7 @@ use reqwest::{
     },
     Client, Response,
 };
-use std::num::NonZeroU32;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Default User-Agent string used as fallback
@@ -39,6 +39,77 @@ const USER_AGENTS: &[&str] = &[
 // Compile-time assertion that USER_AGENTS is not empty
 const _: () = assert!(!USER_AGENTS.is_empty(), "USER_AGENTS must not be empty");
 
+/// Rules every fetcher of a crawl obeys, shared between them
+///
+/// Cheap to clone. The default obeys nothing beyond the fetcher's own rate
+/// limit.
+#[derive(Clone, Default)]
+pub struct FetchPolicy {
+    /// robots.txt checker, `None` when `crawler.robots.obey` is off
+    pub robots: Option<Arc<RobotsManager>>,
+
+    /// Per-host limits of `crawler.domain_rate_limits`, applied to every
+    /// redirect target too; `None` when no domain is limited
+    pub domain_limits: Option<Arc<DomainRateLimiter>>,
+}
+
+impl FetchPolicy {
+    /// Policy for `config`, for fetchers crawling without a
+    /// [`Crawler`](super::Crawler) to share one with
+    ///
+    /// # Errors
+    ///
+    /// Returns `FetchError::Http` if the robots.txt client cannot be created
+    pub fn from_config(config: &CrawlerConfig) -> Result<Self, FetchError> {
+        let robots = if config.robots.obey {
+            let client = Client::builder()
+                .user_agent(&config.user_agent)
+                .timeout(Duration::from_secs(config.request_timeout_secs))
+                .build()?;
+            Some(Arc::new(RobotsManager::new(
+                client,
+                &config.user_agent,
+                &config.robots,
+            )))
+        } else {
+            None
+        };
+        let domain_limits = DomainRateLimiter::new(&config.domain_rate_limits);
+        Ok(Self {
+            robots,
+            domain_limits: (!domain_limits.is_empty()).then(|| Arc::new(domain_limits)),
+        })
+    }
+
+    /// Wait until `url` may be fetched; see [`RobotsManager::check`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `FetchError::Disallowed` if robots.txt disallows `url`
+    pub async fn check(&self, url: &str) -> Result<(), FetchError> {
+        match &self.robots {
+            Some(robots) => robots.check(url).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Send the GET request `request` builds for `url`, following redirects
+    /// that robots.txt allows and holding each host to its domain limit
+    async fn send(
+        &self,
+        url: &str,
+        request: impl Fn(&str) -> reqwest::RequestBuilder,
+    ) -> Result<Response, FetchError> {
+        rate_limit::send_following_redirects(
+            url,
+            self.domain_limits.as_deref(),
+            self.robots.as_deref(),
+            request,
+        )
+        .await
+    }
+}
+
 /// Naver News fetcher with advanced features
 ///
 /// This fetcher handles the complexities of fetching Naver News articles,
@@ -48,7 +119,7 @@ pub struct NaverFetcher {
     client: Client,
 
     /// Rate limiter to control request frequency
-    rate_limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    rate_limiter: AdjustableRateLimiter,
 
     /// Maximum number of retry attempts for failed requests
     max_retries: u32,
@@ -58,6 +129,9 @@ pub struct NaverFetcher {
 
     /// Optional base URL override for testing with mock servers
     base_url: Option<String>,
+
+    /// robots.txt rules and domain limits checked before each request
+    policy: FetchPolicy,
 }
 
 impl NaverFetcher {
@@ -74,6 +148,18 @@ impl NaverFetcher {
         Self::with_config(requests_per_second, 3, Duration::from_secs(30))
     }
 
+    /// Create a new fetcher allowing `requests_per_second`, which may be
+    /// below 1 (`0.5` is one request every two seconds)
+    ///
+    /// # Errors
+    ///
+    /// Returns `FetchError::Http` if the HTTP client cannot be created
+    pub fn with_rate_limit(requests_per_second: f64) -> Result<Self, FetchError> {
+        let fetcher = Self::new(1)?;
+        fetcher.set_requests_per_second(requests_per_second);
+        Ok(fetcher)
+    }
+
     /// Create a new fetcher with custom configuration
     ///
     /// # Arguments
@@ -94,11 +180,10 @@ impl NaverFetcher {
             .timeout(timeout)
             .gzip(true)
             .cookie_store(true)
+            .redirect(rate_limit::redirect_policy())
             .build()?;
 
-        let rate = NonZeroU32::new(requests_per_second).unwrap_or(NonZeroU32::MIN);
-        let quota = Quota::per_second(rate);
-        let rate_limiter = RateLimiter::direct(quota);
+        let rate_limiter = AdjustableRateLimiter::new(f64::from(requests_per_second));
 
         Ok(Self {
             client,
@@ -106,6 +191,7 @@ impl NaverFetcher {
             max_retries,
             base_delay_ms: 1000,
             base_url: None,
+            policy: FetchPolicy::default(),
         })
     }
 
@@ -148,6 +234,26 @@ impl NaverFetcher {
         Ok(fetcher)
     }
 
+    /// Obey `policy`, usually [`Crawler::fetch_policy`](super::Crawler::fetch_policy)
+    #[must_use]
+    pub fn with_policy(mut self, policy: FetchPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Change the request rate for requests made from now on; a rate that
+    /// is not positive is ignored
+    pub fn set_requests_per_second(&self, requests_per_second: f64) {
+        self.rate_limiter.set_rate(requests_per_second);
+    }
+
+    /// Wait for the rate limiter, recording the wait time
+    async fn wait_for_rate_limit(&self) {
+        let started = std::time::Instant::now();
+        self.rate_limiter.until_ready().await;
+        crate::metrics::record_rate_limiter_wait("fetcher", started.elapsed().as_secs_f64());
+    }
+
     /// Simple fetch that returns raw Response
     ///
     /// This is useful for fetching JSON/JSONP APIs where you want to handle
@@ -161,22 +267,23 @@ impl NaverFetcher {
     ///
     /// Returns `FetchError` on network or HTTP errors
     pub async fn fetch(&self, url: &str) -> Result<Response, FetchError> {
+        let full_url = self.full_url(url);
+        self.policy.check(&full_url).await?;
+
         // Wait for rate limiter
-        self.rate_limiter.until_ready().await;
+        self.wait_for_rate_limit().await;
 
         // Build headers with default referer
         let referer = "https://news.naver.com";
         let headers = self.build_headers(referer);
 
-        // Construct full URL
-        let full_url = if let Some(base) = &self.base_url {
-            format!("{base}{url}")
-        } else {
-            url.to_string()
-        };
-
         // Send request
-        let response = self.client.get(&full_url).headers(headers).send().await?;
+        let response = self
+            .policy
+            .send(&full_url, |url| {
+                self.client.get(url).headers(headers.clone())
+            })
+            .await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -202,8 +309,10 @@ impl NaverFetcher {
     ///
     /// Returns various `FetchError` variants depending on the failure mode
     pub async fn fetch_article(&self, url: &str, section_id: u32) -> Result<String, FetchError> {
+        self.policy.check(&self.full_url(url)).await?;
+
         // Wait for rate limiter
-        self.rate_limiter.until_ready().await;
+        self.wait_for_rate_limit().await;
 
         // Attempt fetch with retry logic
         self.fetch_with_retry(url, section_id).await
@@ -235,15 +344,11 @@ impl NaverFetcher {
             // Build headers with random user agent
             let headers = self.build_headers(&referer);
 
-            // Construct full URL
-            let full_url = if let Some(base) = &self.base_url {
-                format!("{base}{url}")
-            } else {
-                url.to_string()
-            };
+            let full_url = self.full_url(url);
 
             // Send request
-            match self.client.get(&full_url).headers(headers).send().await {
+            let request = |url: &str| self.client.get(url).headers(headers.clone());
+            match self.policy.send(&full_url, request).await {
                 Ok(response) => {
                     let status = response.status();
 
@@ -260,12 +365,14 @@ impl NaverFetcher {
                         return Err(FetchError::ServerError(status.as_u16()));
                     }
                 }
+                // A redirect robots.txt disallows is not retried
+                Err(e @ FetchError::Disallowed(_)) => return Err(e),
                 Err(e) => {
                     // Check if error is timeout
-                    if e.is_timeout() {
+                    if matches!(&e, FetchError::Http(e) if e.is_timeout()) {
                         last_error = Some(FetchError::Timeout);
                     } else {
-                        last_error = Some(FetchError::Http(e));
+                        last_error = Some(e);
                     }
                 }
             }
@@ -277,6 +384,14 @@ impl NaverFetcher {
             .unwrap_or(Err(FetchError::MaxRetriesExceeded))
     }
 
+    /// `url` with the base URL override prepended, if any
+    fn full_url(&self, url: &str) -> String {
+        match &self.base_url {
+            Some(base) => format!("{base}{url}"),
+            None => url.to_string(),
+        }
+    }
+
     /// Determine if a status code should trigger a retry
     ///
     /// Retry on:
@@ -547,6 +662,9 @@ mod tests {
 
         let fetcher = NaverFetcher::with_config(5, 3, Duration::from_secs(10));
         assert!(fetcher.is_ok());
+
+        let fetcher = NaverFetcher::with_rate_limit(0.5);
+        assert!(fetcher.is_ok());
     }
 
     #[test]
@@ -568,4 +686,40 @@ mod tests {
         assert!(decoded.is_ok());
         assert_eq!(decoded.unwrap(), utf8_text);
     }
+
+    #[tokio::test]
+    async fn test_policy_obeys_robots() {
+        use crate::config::Config;
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /private\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/public"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&server)
+            .await;
+
+        let policy = FetchPolicy::from_config(&Config::default().crawler).unwrap();
+        let fetcher =
+            NaverFetcher::with_config_and_base_url(&server.uri(), 10, 0, Duration::from_secs(5))
+                .unwrap()
+                .with_policy(policy);
+
+        assert!(fetcher
+            .fetch("/public")
+            .await
+            .unwrap()
+            .status()
+            .is_success());
+        assert!(matches!(
+            fetcher.fetch("/private/1").await,
+            Err(FetchError::Disallowed(_))
+        ));
+    }
 }
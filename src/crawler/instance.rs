@@ -4,9 +4,12 @@
 //! in the distributed crawling system.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::time::Duration;
 
+use crate::config::CategoryConfig;
+use crate::models::NewsCategory;
 use crate::scheduler::rotation::CrawlerInstance;
 
 // ============================================================================
@@ -57,6 +60,10 @@ pub struct InstanceConfig {
 
     /// Local port for registration
     pub local_port: u16,
+
+    /// Per-category overrides of the article budget, rate and comments
+    #[serde(default)]
+    pub categories: BTreeMap<String, CategoryConfig>,
 }
 
 impl InstanceConfig {
@@ -65,7 +72,7 @@ impl InstanceConfig {
     /// Environment variables:
     /// - `INSTANCE_ID`: Instance identifier (main, sub1, sub2) [required]
     /// - `COORDINATOR_URL`: Coordinator server URL [required]
-    /// - `DATABASE_URL`: PostgreSQL connection URL [required]
+    /// - `DATABASE_URL` (or `DATABASE_URL_FILE`): PostgreSQL connection URL [required]
     /// - `HEARTBEAT_INTERVAL`: Heartbeat interval in seconds [default: 30]
     /// - `SCHEDULE_POLL_INTERVAL`: Schedule poll interval in seconds [default: 60]
     /// - `REQUESTS_PER_SECOND`: Rate limit [default: 1.0]
@@ -87,8 +94,10 @@ impl InstanceConfig {
         let coordinator_url = env::var("COORDINATOR_URL")
             .map_err(|_| ConfigError::MissingEnvVar("COORDINATOR_URL".to_string()))?;
 
-        let database_url = env::var("DATABASE_URL")
-            .map_err(|_| ConfigError::MissingEnvVar("DATABASE_URL".to_string()))?;
+        let database_url = crate::config::secrets::read_env("DATABASE_URL")
+            .map_err(|e| ConfigError::InvalidValue("DATABASE_URL".to_string(), format!("{e:#}")))?
+            .map(|(_, url)| url)
+            .ok_or_else(|| ConfigError::MissingEnvVar("DATABASE_URL".to_string()))?;
 
         Ok(Self {
             instance_id,
@@ -132,6 +141,7 @@ impl InstanceConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(8081),
+            categories: BTreeMap::new(),
         })
     }
 
@@ -155,6 +165,11 @@ impl InstanceConfig {
         Duration::from_secs(self.timeout_secs)
     }
 
+    /// The `[category.<name>]` block for `category`, or an empty one
+    pub fn category(&self, category: NewsCategory) -> CategoryConfig {
+        CategoryConfig::find(&self.categories, category)
+    }
+
     /// Get local address for registration
     pub fn local_address(&self) -> String {
         let ip = self
@@ -244,6 +259,7 @@ impl Default for InstanceConfig {
             include_comments: true,
             local_ip: None,
             local_port: 8081,
+            categories: BTreeMap::new(),
         }
     }
 }
@@ -269,6 +285,7 @@ pub struct InstanceConfigBuilder {
     include_comments: Option<bool>,
     local_ip: Option<String>,
     local_port: Option<u16>,
+    categories: BTreeMap<String, CategoryConfig>,
 }
 
 impl InstanceConfigBuilder {
@@ -342,6 +359,11 @@ impl InstanceConfigBuilder {
         self
     }
 
+    pub fn categories(mut self, categories: BTreeMap<String, CategoryConfig>) -> Self {
+        self.categories = categories;
+        self
+    }
+
     pub fn build(self) -> Result<InstanceConfig, ConfigError> {
         let config = InstanceConfig {
             instance_id: self
@@ -364,6 +386,7 @@ impl InstanceConfigBuilder {
             include_comments: self.include_comments.unwrap_or(true),
             local_ip: self.local_ip,
             local_port: self.local_port.unwrap_or(8081),
+            categories: self.categories,
         };
 
         config.validate()?;
@@ -1,23 +1,41 @@
 //! News list page crawler with pagination support
 //!
-//! This module provides functionality to crawl Naver News list pages and extract
-//! article URLs with pagination handling.
+//! This module provides functionality to crawl news list pages and extract
+//! article URLs with pagination handling, and to collect article URLs from
+//! Naver News search results for a keyword. List pages of other portals go
+//! through a [`SiteAdapter`]. Article URLs can also be discovered from RSS
+//! feeds and XML sitemaps (see [`feed`](crate::crawler::feed)).
 
 use std::collections::HashSet;
+use std::sync::Arc;
 
+use chrono::NaiveDate;
+
+use crate::crawler::feed::{self, FeedEntry, Sitemap};
 use crate::crawler::fetcher::NaverFetcher;
+use crate::crawler::sites::{NaverSite, SiteAdapter};
 use crate::crawler::url::UrlExtractor;
-use crate::models::NewsCategory;
+use crate::models::{ArticleSource, NewsCategory};
 use crate::utils::error::CrawlerError;
 
+/// Results per Naver News search page
+const SEARCH_PAGE_SIZE: u32 = 10;
+
+/// Offset of the last result Naver serves for one search (4,000 results)
+const SEARCH_MAX_START: u32 = 3991;
+
+/// Child sitemaps read from a sitemap index, newest first
+const MAX_CHILD_SITEMAPS: usize = 5;
+
 /// News list page crawler with pagination support
 pub struct NewsListCrawler {
     fetcher: NaverFetcher,
     url_extractor: UrlExtractor,
+    site: Arc<dyn SiteAdapter>,
 }
 
 impl NewsListCrawler {
-    /// Create new list crawler
+    /// Create new list crawler for Naver News
     ///
     /// # Arguments
     ///
@@ -34,12 +52,31 @@ impl NewsListCrawler {
     /// ```
     #[must_use]
     pub fn new(fetcher: NaverFetcher) -> Self {
+        Self::with_site(fetcher, Arc::new(NaverSite::new()))
+    }
+
+    /// Create a list crawler for the category lists of `site`
+    ///
+    /// Keyword search ([`Self::search_urls`]) is Naver-only whatever the site.
+    #[must_use]
+    pub fn with_site(fetcher: NaverFetcher, site: Arc<dyn SiteAdapter>) -> Self {
         Self {
             fetcher,
             url_extractor: UrlExtractor::new(),
+            site,
         }
     }
 
+    /// The fetcher used for list pages
+    pub fn fetcher(&self) -> &NaverFetcher {
+        &self.fetcher
+    }
+
+    /// The site whose lists are crawled
+    pub fn site(&self) -> &dyn SiteAdapter {
+        self.site.as_ref()
+    }
+
     /// Collect article URLs from a category with pagination
     ///
     /// # Arguments
@@ -158,6 +195,242 @@ impl NewsListCrawler {
         Ok(result)
     }
 
+    /// Collect article URLs from Naver News search results for `query`
+    ///
+    /// Each day from `from` to `to` (YYYYMMDD, inclusive) is searched
+    /// separately, up to `max_pages` result pages per day (0 = unlimited),
+    /// since Naver serves at most 4,000 results per search. Only results
+    /// also published on Naver News have an article URL; results linking
+    /// just the press site are left out.
+    ///
+    /// # Returns
+    ///
+    /// Deduplicated article URLs, taking one from each day in turn, so a
+    /// budget taken from the front of the list is split across the days
+    /// rather than spent on the first ones. No results is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CrawlerError` if a date is invalid, `from` is after `to`, or
+    /// fetching fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use baram::crawler::fetcher::NaverFetcher;
+    /// # use baram::crawler::list::NewsListCrawler;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let crawler = NewsListCrawler::new(NaverFetcher::new(10)?);
+    /// let urls = crawler
+    ///     .search_urls("반도체 수출", "20241201", "20241207", 5)
+    ///     .await?;
+    /// println!("Found {} articles", urls.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_urls(
+        &self,
+        query: &str,
+        from: &str,
+        to: &str,
+        max_pages: u32,
+    ) -> Result<Vec<String>, CrawlerError> {
+        let (first, last) = (Self::parse_date(from)?, Self::parse_date(to)?);
+        if first > last {
+            return Err(CrawlerError::InvalidDate(format!(
+                "Search range starts after it ends: {from} > {to}"
+            )));
+        }
+
+        let mut seen = HashSet::new();
+        let mut days = Vec::new();
+        let mut requests = 0;
+        for day in first.iter_days().take_while(|day| *day <= last) {
+            let date = day.format("%Y%m%d").to_string();
+            let mut day_urls = Vec::new();
+            let mut page = 1;
+            loop {
+                if max_pages > 0 && page > max_pages {
+                    break;
+                }
+
+                let url = ListUrlBuilder::news_search(query, &date, page);
+                tracing::debug!(query, date, page, "Fetching search page");
+
+                // Search pages belong to no section
+                let html = self.fetcher.fetch_article(&url, 0).await?;
+                requests += 1;
+                day_urls.extend(
+                    self.url_extractor
+                        .extract_urls(&html)
+                        .into_iter()
+                        .filter(|url| seen.insert(url.clone())),
+                );
+
+                if !Self::has_next_search_page(&html, page) {
+                    break;
+                }
+                page += 1;
+            }
+            day_urls.sort();
+            days.push(day_urls);
+        }
+
+        let result = interleave(days);
+
+        tracing::info!(
+            query,
+            from,
+            to,
+            total_urls = result.len(),
+            pages = requests,
+            "Completed search URL collection"
+        );
+
+        Ok(result)
+    }
+
+    /// Collect article URLs of the site listed by RSS/Atom `feeds`
+    ///
+    /// # Returns
+    ///
+    /// Deduplicated article URLs, newest first. Links that are not articles
+    /// of the site are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CrawlerError` if no feed can be fetched; a feed that fails
+    /// while others succeed is skipped with a warning
+    pub async fn feed_urls(&self, feeds: &[String]) -> Result<Vec<String>, CrawlerError> {
+        let mut entries = Vec::new();
+        let mut last_error = None;
+        for url in feeds {
+            match self.fetcher.fetch_article(url, 0).await {
+                Ok(xml) => {
+                    let found = feed::parse_feed(&xml);
+                    tracing::debug!(feed = %url, entries = found.len(), "Read feed");
+                    entries.extend(found);
+                }
+                Err(e) => {
+                    tracing::warn!(feed = %url, error = %e, "Failed to fetch feed");
+                    last_error = Some(e);
+                }
+            }
+        }
+        if let (Some(e), true) = (last_error, entries.is_empty()) {
+            return Err(e.into());
+        }
+        Ok(self.article_urls(entries, "feed"))
+    }
+
+    /// Collect article URLs of the site listed by XML `sitemaps`
+    ///
+    /// A sitemap index is followed to its [`MAX_CHILD_SITEMAPS`] newest
+    /// child sitemaps. With `since`, only URLs published on or after that
+    /// day (and undated ones) are kept.
+    ///
+    /// # Returns
+    ///
+    /// Deduplicated article URLs, newest first. Links that are not articles
+    /// of the site are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CrawlerError` if no sitemap can be fetched; a sitemap that
+    /// fails while others succeed is skipped with a warning
+    pub async fn sitemap_urls(
+        &self,
+        sitemaps: &[String],
+        since: Option<NaiveDate>,
+    ) -> Result<Vec<String>, CrawlerError> {
+        let mut entries = Vec::new();
+        let mut last_error = None;
+        let mut pending: Vec<(String, bool)> = sitemaps.iter().map(|u| (u.clone(), true)).collect();
+        while let Some((url, follow_index)) = pending.pop() {
+            let xml = match self.fetcher.fetch_article(&url, 0).await {
+                Ok(xml) => xml,
+                Err(e) => {
+                    tracing::warn!(sitemap = %url, error = %e, "Failed to fetch sitemap");
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            match feed::parse_sitemap(&xml) {
+                Sitemap::Urls(found) => {
+                    tracing::debug!(sitemap = %url, entries = found.len(), "Read sitemap");
+                    entries.extend(found);
+                }
+                // Indexes are followed one level deep
+                Sitemap::Index(mut children) if follow_index => {
+                    if let Some(since) = since {
+                        feed::published_since(&mut children, since);
+                    }
+                    feed::newest_first(&mut children);
+                    tracing::debug!(sitemap = %url, children = children.len(), "Read sitemap index");
+                    pending.extend(
+                        children
+                            .into_iter()
+                            .take(MAX_CHILD_SITEMAPS)
+                            .map(|child| (child.url, false)),
+                    );
+                }
+                Sitemap::Index(_) => {
+                    tracing::warn!(sitemap = %url, "Skipping nested sitemap index");
+                }
+            }
+        }
+        if let (Some(e), true) = (last_error, entries.is_empty()) {
+            return Err(e.into());
+        }
+        if let Some(since) = since {
+            feed::published_since(&mut entries, since);
+        }
+        Ok(self.article_urls(entries, "sitemap"))
+    }
+
+    /// Normalized article URLs of the site among `entries`, newest first
+    fn article_urls(&self, mut entries: Vec<FeedEntry>, kind: &str) -> Vec<String> {
+        feed::newest_first(&mut entries);
+        let total = entries.len();
+        let mut seen = HashSet::new();
+        let urls: Vec<String> = entries
+            .into_iter()
+            .filter_map(|entry| self.site.normalize_url(&entry.url))
+            .filter(|url| seen.insert(url.clone()))
+            .collect();
+        tracing::info!(
+            kind,
+            entries = total,
+            total_urls = urls.len(),
+            "Completed {kind} URL collection"
+        );
+        if total > 0 && urls.is_empty() {
+            tracing::warn!(
+                kind,
+                site = self.site.source().as_str(),
+                "No {kind} link is an article of the site; links must point at the crawled site"
+            );
+        }
+        urls
+    }
+
+    /// Whether a search result page links to the page after `page`
+    fn has_next_search_page(html: &str, page: u32) -> bool {
+        let next_start = page * SEARCH_PAGE_SIZE + 1;
+        next_start <= SEARCH_MAX_START && html.contains(&format!("start={next_start}"))
+    }
+
+    /// Parse a YYYYMMDD date
+    fn parse_date(date: &str) -> Result<NaiveDate, CrawlerError> {
+        Self::is_valid_date_format(date)
+            .then(|| NaiveDate::parse_from_str(date, "%Y%m%d").ok())
+            .flatten()
+            .ok_or_else(|| {
+                CrawlerError::InvalidDate(format!("Invalid date format: {date}. Expected YYYYMMDD"))
+            })
+    }
+
     /// Fetch a single list page and extract URLs
     ///
     /// # Arguments
@@ -179,69 +452,26 @@ impl NewsListCrawler {
         date: &str,
         page: u32,
     ) -> Result<(Vec<String>, bool), CrawlerError> {
-        let url = self.build_list_url(category, date, page);
+        let url = self.site.list_url(category, date, page);
 
         tracing::trace!(url = %url, "Fetching URL");
 
-        // Fetch the HTML
-        let html = self
-            .fetcher
-            .fetch_article(&url, category.to_section_id())
-            .await?;
-
-        // Extract URLs
-        let urls = self.url_extractor.extract_urls(&html);
+        // The section ID only builds a Naver referer
+        let section_id = match self.site.source() {
+            ArticleSource::Naver => category.to_section_id(),
+            _ => 0,
+        };
+        let html = self.fetcher.fetch_article(&url, section_id).await?;
 
-        // Check if there are more pages
+        let urls = self.site.extract_urls(&html);
         let has_more = self.has_next_page(&html, page);
 
         Ok((urls, has_more))
     }
 
-    /// Build the list page URL for Naver News
-    ///
-    /// # Arguments
-    ///
-    /// * `category` - News category
-    /// * `date` - Target date in YYYYMMDD format
-    /// * `page` - Page number
-    ///
-    /// # Returns
-    ///
-    /// Formatted URL string
-    fn build_list_url(&self, category: NewsCategory, date: &str, page: u32) -> String {
-        ListUrlBuilder::main_list(category, date, page)
-    }
-
     /// Check if there are more pages based on HTML content
-    ///
-    /// This method checks for:
-    /// - Pagination links in HTML (look for class="paging" or similar)
-    /// - "다음" (Next) button presence
-    /// - Empty article list (indicates end of pages)
-    ///
-    /// # Arguments
-    ///
-    /// * `html` - HTML content to check
-    /// * `current_page` - Current page number
-    ///
-    /// # Returns
-    ///
-    /// `true` if there are more pages to fetch
     fn has_next_page(&self, html: &str, current_page: u32) -> bool {
-        // Check for pagination element with next page link
-        if html.contains(&format!("page={}", current_page + 1)) {
-            return true;
-        }
-
-        // Check for "다음" (Next) button
-        if html.contains("다음</a>") || html.contains("class=\"next\"") {
-            return true;
-        }
-
-        // Check if article list is empty (no URLs found)
-        let urls = self.url_extractor.extract_urls(html);
-        !urls.is_empty()
+        self.site.has_next_page(html, current_page)
     }
 
     /// Validate date format (YYYYMMDD)
@@ -287,6 +517,19 @@ impl NewsListCrawler {
     }
 }
 
+/// Merge the per-day URL lists `days`, one URL of each day in turn
+fn interleave(days: Vec<Vec<String>>) -> Vec<String> {
+    let mut days: Vec<_> = days.into_iter().map(Vec::into_iter).collect();
+    let mut merged = Vec::new();
+    loop {
+        let before = merged.len();
+        merged.extend(days.iter_mut().filter_map(Iterator::next));
+        if merged.len() == before {
+            return merged;
+        }
+    }
+}
+
 /// URL builder for different Naver News list formats
 pub struct ListUrlBuilder;
 
@@ -386,6 +629,39 @@ impl ListUrlBuilder {
             category.to_section_id()
         )
     }
+
+    /// Build a Naver News search URL for one day, newest results first
+    ///
+    /// Format: `https://search.naver.com/search.naver?where=news&query={query}&sort=1&ds={date}&de={date}&nso=so:dd,p:from{date}to{date}&start={start}`
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Search keyword
+    /// * `date` - Date in YYYYMMDD format
+    /// * `page` - Page number, of 10 results each
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use baram::crawler::list::ListUrlBuilder;
+    ///
+    /// let url = ListUrlBuilder::news_search("반도체", "20241215", 2);
+    /// assert!(url.contains("query=%EB%B0%98%EB%8F%84%EC%B2%B4"));
+    /// assert!(url.contains("ds=2024.12.15&de=2024.12.15"));
+    /// assert!(url.ends_with("start=11"));
+    /// ```
+    #[must_use]
+    pub fn news_search(query: &str, date: &str, page: u32) -> String {
+        let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+        let dotted = match (date.get(0..4), date.get(4..6), date.get(6..8)) {
+            (Some(year), Some(month), Some(day)) => format!("{year}.{month}.{day}"),
+            _ => date.to_string(),
+        };
+        format!(
+            "https://search.naver.com/search.naver?where=news&query={query}&sort=1&ds={dotted}&de={dotted}&nso=so:dd,p:from{date}to{date}&start={}",
+            page.saturating_sub(1) * SEARCH_PAGE_SIZE + 1
+        )
+    }
 }
 
 #[cfg(test)]
@@ -420,6 +696,43 @@ mod tests {
         assert_eq!(url, "https://news.naver.com/section/102");
     }
 
+    #[test]
+    fn test_news_search_url() {
+        let url = ListUrlBuilder::news_search("기준 금리", "20241201", 1);
+        assert!(url.starts_with("https://search.naver.com/search.naver?where=news"));
+        assert!(url.contains("query=%EA%B8%B0%EC%A4%80+%EA%B8%88%EB%A6%AC"));
+        assert!(url.contains("nso=so:dd,p:from20241201to20241201"));
+        assert!(url.ends_with("start=1"));
+        assert!(ListUrlBuilder::news_search("a", "20241201", 3).ends_with("start=21"));
+    }
+
+    #[test]
+    fn test_interleave_days() {
+        let day = |urls: &[&str]| urls.iter().map(|url| url.to_string()).collect();
+        let merged = interleave(vec![day(&["a1", "a2", "a3"]), day(&[]), day(&["c1"])]);
+        assert_eq!(merged, ["a1", "c1", "a2", "a3"]);
+    }
+
+    #[test]
+    fn test_has_next_search_page() {
+        let html = r#"<div class="sc_page"><a href="?where=news&query=a&start=11">2</a></div>"#;
+        assert!(NewsListCrawler::has_next_search_page(html, 1));
+        assert!(!NewsListCrawler::has_next_search_page(html, 2));
+
+        // Naver serves no results past the 4,000th
+        let html = r#"<a href="?where=news&query=a&start=4001">401</a>"#;
+        assert!(!NewsListCrawler::has_next_search_page(html, 400));
+    }
+
+    #[tokio::test]
+    async fn test_search_urls_rejects_reversed_range() {
+        let crawler = NewsListCrawler::new(NaverFetcher::new(10).unwrap());
+        let result = crawler.search_urls("a", "20241210", "20241201", 1).await;
+        assert!(matches!(result, Err(CrawlerError::InvalidDate(_))));
+        let result = crawler.search_urls("a", "2024-12-01", "20241201", 1).await;
+        assert!(matches!(result, Err(CrawlerError::InvalidDate(_))));
+    }
+
     #[test]
     fn test_has_next_page_with_pagination() {
         let fetcher = NaverFetcher::new(10).unwrap();
@@ -0,0 +1,312 @@
+//! Mock Naver News server for offline crawls
+//!
+//! [`MockNaver`] serves recorded Naver pages from `tests/fixtures/html` on a
+//! local port, so the crawl pipeline can be regression-tested without the
+//! live site (`baram crawl --mock`, `tests/integration_tests`). Every list
+//! page is the recorded list page; its articles are:
+//!
+//! | Article          | Page                                 |
+//! |------------------|--------------------------------------|
+//! | `001/0014500001` | general news                         |
+//! | `002/0014500002` | sports news                          |
+//! | `003/0014500003` | entertainment news                   |
+//! | `004/0014500004` | card news                            |
+//! | `005/0014500005` | deleted article (fails to parse)     |
+//! | anything else    | 404                                  |
+//!
+//! Responses can be delayed, and a share of them replaced by 503s. Failures
+//! come from a seeded RNG, so the same requests in the same order always
+//! fail the same way.
+//!
+//! [`MockSite`] crawls the server like [`NaverSite`] crawls Naver: list and
+//! article URLs point at the server, and parsed articles keep their Naver
+//! URLs, so the output matches a live crawl of the same pages.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use url::{Position, Url};
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+use crate::models::{ArticleSource, NewsCategory, ParsedArticle};
+use crate::utils::error::ParseError;
+
+use super::sites::{NaverSite, SiteAdapter};
+
+/// Recorded list page
+const LIST_PAGE: &str = include_str!("../../tests/fixtures/html/list_page.html");
+
+/// Recorded article pages by `{oid}/{aid}`
+const ARTICLES: &[(&str, &str)] = &[
+    (
+        "001/0014500001",
+        include_str!("../../tests/fixtures/html/general_news.html"),
+    ),
+    (
+        "002/0014500002",
+        include_str!("../../tests/fixtures/html/sports_news.html"),
+    ),
+    (
+        "003/0014500003",
+        include_str!("../../tests/fixtures/html/entertainment_news.html"),
+    ),
+    (
+        "004/0014500004",
+        include_str!("../../tests/fixtures/html/card_news.html"),
+    ),
+    (
+        "005/0014500005",
+        include_str!("../../tests/fixtures/html/deleted_article.html"),
+    ),
+];
+
+/// Latency and error injection of a [`MockNaver`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockNaverConfig {
+    /// Delay before every response
+    pub latency: Duration,
+
+    /// Share of responses (0.0 - 1.0) replaced by `503 Service Unavailable`
+    pub error_rate: f64,
+
+    /// Seed of the RNG picking the failed responses
+    pub seed: u64,
+}
+
+impl Default for MockNaverConfig {
+    fn default() -> Self {
+        Self {
+            latency: Duration::ZERO,
+            error_rate: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+/// Mock Naver News server, stopped when dropped
+pub struct MockNaver {
+    server: MockServer,
+}
+
+impl MockNaver {
+    /// Start a server on a free local port
+    pub async fn start(config: MockNaverConfig) -> Self {
+        let server = MockServer::start().await;
+        let rng = Arc::new(Mutex::new(ChaCha8Rng::seed_from_u64(config.seed)));
+        let page = |status: u16, body: &str| FlakyResponse {
+            template: ResponseTemplate::new(status)
+                .set_body_raw(body.as_bytes().to_vec(), "text/html; charset=UTF-8")
+                .set_delay(config.latency),
+            error_rate: config.error_rate,
+            rng: Arc::clone(&rng),
+        };
+
+        // robots.txt is never failed, so injected errors only hit pages
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("User-agent: *\nAllow: /\n", "text/plain"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/main/list.naver"))
+            .respond_with(page(200, LIST_PAGE))
+            .mount(&server)
+            .await;
+        for (id, html) in ARTICLES {
+            Mock::given(method("GET"))
+                .and(path(format!("/mnews/article/{id}")))
+                .respond_with(page(200, html))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/mnews/article/"))
+            .respond_with(page(404, "<html><body>Not Found</body></html>"))
+            .with_priority(u8::MAX)
+            .mount(&server)
+            .await;
+
+        Self { server }
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:40321`
+    #[must_use]
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Site adapter crawling this server
+    #[must_use]
+    pub fn site(&self) -> MockSite {
+        MockSite::new(&self.uri())
+    }
+
+    /// Number of requests received so far
+    pub async fn request_count(&self) -> usize {
+        self.server
+            .received_requests()
+            .await
+            .map_or(0, |requests| requests.len())
+    }
+}
+
+/// A page response replaced by a 503 at the configured rate
+struct FlakyResponse {
+    template: ResponseTemplate,
+    error_rate: f64,
+    rng: Arc<Mutex<ChaCha8Rng>>,
+}
+
+impl Respond for FlakyResponse {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        let fail = self.error_rate > 0.0
+            && self
+                .rng
+                .lock()
+                .is_ok_and(|mut rng| rng.gen_bool(self.error_rate.min(1.0)));
+        if fail {
+            ResponseTemplate::new(503)
+        } else {
+            self.template.clone()
+        }
+    }
+}
+
+/// [`NaverSite`] with list and article URLs moved to a mock server
+pub struct MockSite {
+    inner: NaverSite,
+    base: String,
+}
+
+impl MockSite {
+    /// Site serving Naver pages from `base` (scheme, host and port)
+    #[must_use]
+    pub fn new(base: &str) -> Self {
+        Self {
+            inner: NaverSite::new(),
+            base: base.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// `url` with its scheme, host and port replaced by the server's
+    #[must_use]
+    pub fn rebase(&self, url: &str) -> String {
+        match Url::parse(url) {
+            Ok(url) => format!("{}{}", self.base, &url[Position::BeforePath..]),
+            Err(_) => url.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl SiteAdapter for MockSite {
+    fn source(&self) -> ArticleSource {
+        self.inner.source()
+    }
+
+    fn section(&self, category: NewsCategory) -> String {
+        self.inner.section(category)
+    }
+
+    fn category(&self, section: &str) -> Option<NewsCategory> {
+        self.inner.category(section)
+    }
+
+    fn list_url(&self, category: NewsCategory, date: &str, page: u32) -> String {
+        self.rebase(&self.inner.list_url(category, date, page))
+    }
+
+    fn extract_urls(&self, html: &str) -> Vec<String> {
+        self.inner
+            .extract_urls(html)
+            .iter()
+            .map(|url| self.rebase(url))
+            .collect()
+    }
+
+    fn has_next_page(&self, html: &str, page: u32) -> bool {
+        self.inner.has_next_page(html, page)
+    }
+
+    fn normalize_url(&self, url: &str) -> Option<String> {
+        self.inner.normalize_url(url).map(|url| self.rebase(&url))
+    }
+
+    /// Parsed as the Naver article at the same path
+    fn parse(&self, html: &str, url: &str) -> Result<ParsedArticle, ParseError> {
+        let naver_url = self.inner.normalize_url(url);
+        self.inner.parse(html, naver_url.as_deref().unwrap_or(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_site_urls() {
+        let site = MockSite::new("http://127.0.0.1:8080/");
+        assert_eq!(
+            site.list_url(NewsCategory::Politics, "20241215", 2),
+            "http://127.0.0.1:8080/main/list.naver?mode=LSD&mid=shm&sid1=100&date=20241215&page=2"
+        );
+
+        let urls = site.extract_urls(LIST_PAGE);
+        assert!(urls.contains(&"http://127.0.0.1:8080/mnews/article/001/0014500001".to_string()));
+        assert!(urls
+            .iter()
+            .all(|url| url.starts_with("http://127.0.0.1:8080/")));
+
+        assert_eq!(
+            site.normalize_url("https://n.news.naver.com/mnews/article/002/0014500002?sid=100")
+                .as_deref(),
+            Some("http://127.0.0.1:8080/mnews/article/002/0014500002")
+        );
+    }
+
+    #[test]
+    fn test_mock_site_parses_naver_urls() {
+        let site = MockSite::new("http://127.0.0.1:8080");
+        let article = site
+            .parse(
+                ARTICLES[0].1,
+                "http://127.0.0.1:8080/mnews/article/001/0014500001",
+            )
+            .unwrap();
+        assert_eq!(article.oid, "001");
+        assert_eq!(article.aid, "0014500001");
+        assert_eq!(
+            article.url,
+            "https://n.news.naver.com/mnews/article/001/0014500001"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_injection_is_deterministic() {
+        let statuses = |seed: u64| async move {
+            let mock = MockNaver::start(MockNaverConfig {
+                error_rate: 0.5,
+                seed,
+                ..Default::default()
+            })
+            .await;
+            let url = format!("{}/mnews/article/001/0014500001", mock.uri());
+            let mut statuses = Vec::new();
+            for _ in 0..20 {
+                statuses.push(reqwest::get(&url).await.unwrap().status().as_u16());
+            }
+            assert_eq!(mock.request_count().await, 20);
+            statuses
+        };
+
+        let first = statuses(7).await;
+        assert_eq!(first, statuses(7).await);
+        assert!(first.contains(&200) && first.contains(&503));
+    }
+}
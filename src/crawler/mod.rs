@@ -19,19 +19,51 @@
 //! - [`distributed`] - Distributed crawler runner with coordinator integration
 //! - [`trigger`] - Hourly crawling trigger with category logic
 //! - [`status`] - Status reporting and error recovery
+//!
+//! For continuous crawling on a single machine without a coordinator, see
+//! [`watch`].
+//!
+//! [`Crawler::fetch`] obeys robots.txt (`Disallow` and `Crawl-delay`) unless
+//! `crawler.robots.obey` is off; see [`robots`]. It retries HTTP 429, 5xx and
+//! transport errors with exponential backoff (`crawler.retry`) and stops
+//! requesting a failing host for a while; see [`circuit`]. Besides the global
+//! `crawler.rate_limit`, hosts of the domains in `crawler.domain_rate_limits`
+//! get their own, stricter limit; see [`rate_limit`]. With an
+//! [`HttpCache`], [`Crawler::fetch_text_if_modified`] revalidates pages with
+//! `ETag`/`Last-Modified` and skips those that have not changed; with an
+//! [`AssetDownloader`], [`Crawler::download_images`] saves article images
+//! locally (see [`assets`]). Requests can be spread over a pool of proxies
+//! ([`proxy`]) and a pool of user agents (`crawler.user_agents`).
+//!
+//! Everything specific to a news portal (list pages, article URLs, parsing,
+//! sections) is behind a [`sites::SiteAdapter`]; Naver is the default.
+//! Instead of list pages, article URLs can be discovered from RSS feeds and
+//! XML sitemaps; see [`feed`].
+//! [`mock`] serves recorded Naver pages locally for offline crawls.
 
+pub mod assets;
+pub mod circuit;
 pub mod comment;
 pub mod distributed;
+pub mod feed;
 pub mod fetcher;
 pub mod headers;
 pub mod instance;
 pub mod list;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod pipeline;
+pub mod proxy;
+pub mod rate_limit;
+pub mod robots;
+pub mod sites;
 pub mod status;
 pub mod trigger;
 pub mod url;
+pub mod watch;
 
 pub use distributed::{DistributedRunner, RunnerError, RunnerHandle, SlotResult};
+pub use fetcher::FetchPolicy;
 pub use instance::{ConfigError, InstanceConfig, InstanceState};
 pub use pipeline::{
     CrawlerPipeline, PipelineBuilder, PipelineConfig, PipelineStats, StatsSnapshot,
@@ -45,13 +77,21 @@ pub use trigger::{
 };
 
 use anyhow::{Context, Result};
-use governor::{Quota, RateLimiter};
-use reqwest::{Client, Response};
-use std::num::NonZeroU32;
+use rand::Rng;
+use reqwest::{Client, Response, StatusCode};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Semaphore;
 
-use crate::config::Config;
+use crate::config::{Config, FetchRetryConfig};
+use crate::models::ParsedArticle;
+use crate::storage::{ArticleAsset, HttpCache, HttpValidators};
+use crate::utils::error::FetchError;
+use assets::AssetDownloader;
+use circuit::{circuit_host, CircuitBreaker};
+use proxy::ProxyPool;
+use rate_limit::{AdjustableRateLimiter, DomainRateLimiter};
+use robots::RobotsManager;
 
 /// Main crawler structure
 pub struct Crawler {
@@ -59,19 +99,33 @@ pub struct Crawler {
     client: Client,
 
     /// Rate limiter
-    rate_limiter: Arc<
-        RateLimiter<
-            governor::state::direct::NotKeyed,
-            governor::state::InMemoryState,
-            governor::clock::DefaultClock,
-        >,
-    >,
+    rate_limiter: Arc<AdjustableRateLimiter>,
+
+    /// Per-host limits of `crawler.domain_rate_limits`; shared with the
+    /// fetchers given [`Crawler::fetch_policy`]
+    domain_rate_limiter: Arc<DomainRateLimiter>,
 
     /// Concurrency semaphore
     semaphore: Arc<Semaphore>,
 
+    /// robots.txt rules, `None` when `crawler.robots.obey` is off; shared
+    /// with the fetchers given [`Crawler::fetch_policy`]
+    robots: Option<Arc<RobotsManager>>,
+
+    /// Per-host circuit breaker
+    circuit: CircuitBreaker,
+
+    /// Proxies requests rotate through, `None` without `crawler.proxy.servers`
+    proxies: Option<Arc<ProxyPool>>,
+
+    /// Validators for conditional requests, `None` until
+    /// [`Crawler::with_http_cache`]
+    http_cache: Option<HttpCache>,
+
+    /// Where article images go, `None` until [`Crawler::with_assets`]
+    assets: Option<AssetDownloader>,
+
     /// Configuration
-    #[allow(dead_code)]
     config: Config,
 }
 
@@ -80,57 +134,259 @@ impl Crawler {
     pub fn new(config: Config) -> Result<Self> {
         config.validate().context("Invalid configuration")?;
 
-        let client = Client::builder()
-            .user_agent(&config.crawler.user_agent)
-            .timeout(config.request_timeout())
-            .cookie_store(config.crawler.enable_cookies)
-            .gzip(true)
-            .build()
-            .context("Failed to create HTTP client")?;
+        let builder = || {
+            Client::builder()
+                .user_agent(&config.crawler.user_agent)
+                .timeout(config.request_timeout())
+                .cookie_store(config.crawler.enable_cookies)
+                .gzip(true)
+                .redirect(rate_limit::redirect_policy())
+        };
+        let client = builder().build().context("Failed to create HTTP client")?;
+        let proxies = if config.crawler.proxy.servers.is_empty() {
+            None
+        } else {
+            Some(Arc::new(ProxyPool::new(&config.crawler.proxy, builder)?))
+        };
 
         // Create rate limiter based on configuration
-        let rate = NonZeroU32::new(config.crawler.rate_limit as u32)
-            .context("Invalid rate limit value")?;
-        let quota = Quota::per_second(rate);
-        let rate_limiter = Arc::new(RateLimiter::direct(quota));
+        let rate_limiter = Arc::new(AdjustableRateLimiter::new(config.crawler.rate_limit));
+        let domain_rate_limiter =
+            Arc::new(DomainRateLimiter::new(&config.crawler.domain_rate_limits));
 
         let semaphore = Arc::new(Semaphore::new(config.crawler.max_concurrent_requests));
 
+        // robots.txt is fetched without rate limits, but through the proxies
+        let robots = config.crawler.robots.obey.then(|| {
+            let robots = RobotsManager::new(
+                client.clone(),
+                &config.crawler.user_agent,
+                &config.crawler.robots,
+            );
+            Arc::new(match &proxies {
+                Some(pool) => robots.with_proxies(Arc::clone(pool)),
+                None => robots,
+            })
+        });
+
+        let circuit = CircuitBreaker::new(&config.crawler.circuit_breaker);
+
         Ok(Self {
             client,
             rate_limiter,
+            domain_rate_limiter,
             semaphore,
+            robots,
+            circuit,
+            proxies,
+            http_cache: None,
+            assets: None,
             config,
         })
     }
 
-    /// Fetch a URL with rate limiting
+    /// Revalidate pages fetched with [`Crawler::fetch_text_if_modified`]
+    /// against the validators in `cache`
+    #[must_use]
+    pub fn with_http_cache(mut self, cache: HttpCache) -> Self {
+        self.http_cache = Some(cache);
+        self
+    }
+
+    /// Download article images with `downloader` in
+    /// [`Crawler::download_images`]
+    #[must_use]
+    pub fn with_assets(mut self, downloader: AssetDownloader) -> Self {
+        self.assets = Some(downloader);
+        self
+    }
+
+    /// Rules for a [`NaverFetcher`](fetcher::NaverFetcher) crawling along
+    /// with this crawler, sharing its robots.txt cache, `Crawl-delay`s and
+    /// per-domain rate limits
+    #[must_use]
+    pub fn fetch_policy(&self) -> FetchPolicy {
+        FetchPolicy {
+            robots: self.robots.clone(),
+            domain_limits: Some(Arc::clone(&self.domain_rate_limiter)),
+        }
+    }
+
+    /// Change the rate limit (requests per second, may be below 1) for
+    /// requests made from now on
+    pub fn set_rate_limit(&self, rate_limit: f64) -> Result<()> {
+        anyhow::ensure!(
+            self.rate_limiter.set_rate(rate_limit),
+            "Invalid rate limit value: {rate_limit}"
+        );
+        Ok(())
+    }
+
+    /// Fetch a URL with rate limiting and retries
+    ///
+    /// HTTP 429, 5xx, timeouts and connection errors are retried with
+    /// exponential backoff and jitter (`crawler.retry`); a 429's `Retry-After`
+    /// is waited out, up to `max_delay_ms`. Fails with
+    /// [`FetchError::Disallowed`] if the host's robots.txt disallows the URL
+    /// or a redirect target, and with [`FetchError::CircuitOpen`] while the host's circuit is open.
+    ///
+    /// With a proxy pool, each attempt goes through the next proxy; failing
+    /// to connect to it counts against the proxy rather than the host, and
+    /// [`FetchError::ProxyUnavailable`] is returned when none is left.
     pub async fn fetch(&self, url: &str) -> Result<Response> {
+        self.fetch_with(url, None).await
+    }
+
+    /// [`Crawler::fetch`], sending `validators` as `If-None-Match` and
+    /// `If-Modified-Since`; a `304 Not Modified` is then returned as is
+    async fn fetch_with(&self, url: &str, validators: Option<&HttpValidators>) -> Result<Response> {
+        if let Some(robots) = &self.robots {
+            robots.check(url).await?;
+        }
+
+        let host = circuit_host(url);
+        let retry = &self.config.crawler.retry;
+        let mut attempt = 0;
+        loop {
+            let permit = self.circuit.acquire(&host)?;
+            let proxy = match &self.proxies {
+                Some(pool) => {
+                    pool.start_health_checks();
+                    Some((pool, pool.pick().ok_or(FetchError::ProxyUnavailable)?))
+                }
+                None => None,
+            };
+
+            let client = proxy.map_or(&self.client, |(pool, index)| pool.client(index));
+            let result = self.send(client, url, validators).await;
+            if let (Some((pool, index)), Ok(_)) = (proxy, &result) {
+                pool.record_success(index);
+            }
+            let (error, reason, retry_after) = match result {
+                Ok(response)
+                    if response.status().is_success()
+                        || (validators.is_some()
+                            && response.status() == StatusCode::NOT_MODIFIED) =>
+                {
+                    permit.success();
+                    return Ok(response);
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    if !is_retryable_status(status) {
+                        // The host answered; a 404 says nothing about its health
+                        permit.success();
+                        return Err(status_error(status));
+                    }
+                    permit.failure();
+                    let retry_after = retry_after(&response);
+                    (
+                        status_error(status),
+                        status.as_str().to_string(),
+                        retry_after,
+                    )
+                }
+                Err(FetchError::Http(e)) if e.is_connect() && proxy.is_some() => {
+                    if let Some((pool, index)) = proxy {
+                        tracing::debug!(
+                            proxy = %pool.name(index),
+                            error = %e,
+                            "Proxy connection failed"
+                        );
+                        pool.record_failure(index);
+                    }
+                    (
+                        anyhow::Error::new(e).context("Failed to connect through proxy"),
+                        "proxy".to_string(),
+                        None,
+                    )
+                }
+                Err(FetchError::Http(e)) if e.is_timeout() || e.is_connect() => {
+                    permit.failure();
+                    let reason = if e.is_timeout() { "timeout" } else { "connect" };
+                    (
+                        anyhow::Error::new(e).context("Failed to send request"),
+                        reason.to_string(),
+                        None,
+                    )
+                }
+                Err(FetchError::Http(e)) => {
+                    return Err(anyhow::Error::new(e).context("Failed to send request"))
+                }
+                // A redirect robots.txt disallows
+                Err(e) => return Err(e.into()),
+            };
+
+            if attempt >= retry.max_retries {
+                return Err(error);
+            }
+            attempt += 1;
+
+            let delay = retry_delay(retry, attempt, retry_after, rand::thread_rng().gen());
+            tracing::debug!(
+                url = %url,
+                attempt,
+                reason = %reason,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying request"
+            );
+            crate::metrics::record_fetch_retry(&host, &reason);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Send one request with `client`, following the redirects robots.txt
+    /// allows, waiting for the rate limiters and a concurrency slot
+    async fn send(
+        &self,
+        client: &Client,
+        url: &str,
+        validators: Option<&HttpValidators>,
+    ) -> Result<Response, FetchError> {
         // Wait for rate limiter
+        let wait_started = std::time::Instant::now();
         self.rate_limiter.until_ready().await;
+        crate::metrics::record_rate_limiter_wait("crawler", wait_started.elapsed().as_secs_f64());
 
         // Acquire semaphore permit for concurrency control
-        let _permit = self
-            .semaphore
-            .acquire()
-            .await
-            .context("Failed to acquire semaphore permit")?;
+        // The semaphore is never closed
+        let _permit = self.semaphore.acquire().await;
 
         tracing::debug!(url = %url, "Fetching URL");
 
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        // Redirects are followed here so each host waits for its own limit
+        let request = |url: &str| {
+            let mut request = client.get(url);
+            if let Some(user_agent) = self.pick_user_agent() {
+                request = request.header(reqwest::header::USER_AGENT, user_agent);
+            }
+            if let Some(validators) = validators {
+                if let Some(etag) = &validators.etag {
+                    request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+                }
+                if let Some(last_modified) = &validators.last_modified {
+                    request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+                }
+            }
+            request
+        };
+        rate_limit::send_following_redirects(
+            url,
+            Some(&self.domain_rate_limiter),
+            self.robots.as_deref(),
+            request,
+        )
+        .await
+    }
 
-        let status = response.status();
-        if !status.is_success() {
-            anyhow::bail!("Request failed with status: {status}");
+    /// A user agent from `crawler.user_agents`, if any are configured
+    fn pick_user_agent(&self) -> Option<&str> {
+        let user_agents = &self.config.crawler.user_agents;
+        if user_agents.is_empty() {
+            return None;
         }
-
-        Ok(response)
+        let index = rand::thread_rng().gen_range(0..user_agents.len());
+        Some(user_agents[index].as_str())
     }
 
     /// Fetch and decode response body as text
@@ -143,6 +399,56 @@ impl Crawler {
         Ok(text)
     }
 
+    /// Fetch `url` as text, unless it has not changed since the validators
+    /// last remembered for it
+    ///
+    /// With an [`HttpCache`], the stored `ETag` and `Last-Modified` of `url`
+    /// are sent along, and `None` is returned when the server answers
+    /// `304 Not Modified`. The page comes with the validators of the new
+    /// response; pass them to [`Crawler::remember_validators`] once the page
+    /// has been handled, so a page that failed to be processed is fetched
+    /// in full next time. Without a cache this is [`Crawler::fetch_text`].
+    pub async fn fetch_text_if_modified(&self, url: &str) -> Result<Option<FetchedPage>> {
+        let validators = match &self.http_cache {
+            Some(cache) => cache.get(url)?,
+            None => None,
+        };
+        let response = self.fetch_with(url, validators.as_ref()).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            tracing::debug!(url = %url, "Page not modified");
+            crate::metrics::record_not_modified(&circuit_host(url));
+            return Ok(None);
+        }
+        read_page(response).await.map(Some)
+    }
+
+    /// Fetch `url` in full as text, with the validators to pass to
+    /// [`Crawler::remember_validators`] once the page has been handled
+    pub async fn fetch_page(&self, url: &str) -> Result<FetchedPage> {
+        read_page(self.fetch(url).await?).await
+    }
+
+    /// Store the validators of a page fetched with
+    /// [`Crawler::fetch_text_if_modified`]; does nothing without an
+    /// [`HttpCache`]
+    pub fn remember_validators(&self, url: &str, validators: &HttpValidators) -> Result<()> {
+        match &self.http_cache {
+            Some(cache) => cache.put(url, validators),
+            None => Ok(()),
+        }
+    }
+
+    /// Download the images of `article` and point it at the local copies
+    ///
+    /// Returns the files saved; does nothing without an
+    /// [`AssetDownloader`] (see [`assets`]).
+    pub async fn download_images(&self, article: &mut ParsedArticle) -> Vec<ArticleAsset> {
+        match &self.assets {
+            Some(downloader) => downloader.download(self, article).await,
+            None => Vec::new(),
+        }
+    }
+
     /// Crawl a list of URLs concurrently
     pub async fn crawl_urls(&self, urls: Vec<String>) -> Vec<Result<String>> {
         let futures = urls
@@ -153,6 +459,85 @@ impl Crawler {
     }
 }
 
+/// Page fetched by [`Crawler::fetch_text_if_modified`] or
+/// [`Crawler::fetch_page`]
+#[derive(Debug, Clone)]
+pub struct FetchedPage {
+    /// Decoded body
+    pub text: String,
+    /// Validators of the response, to revalidate the page next time
+    pub validators: HttpValidators,
+}
+
+/// Body and validators of a successful response
+async fn read_page(response: Response) -> Result<FetchedPage> {
+    let validators = response_validators(&response);
+    let text = response
+        .text()
+        .await
+        .context("Failed to read response body")?;
+    Ok(FetchedPage { text, validators })
+}
+
+/// `ETag` and `Last-Modified` of a response
+fn response_validators(response: &Response) -> HttpValidators {
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    HttpValidators {
+        etag: header(reqwest::header::ETAG),
+        last_modified: header(reqwest::header::LAST_MODIFIED),
+    }
+}
+
+/// Whether a response status is worth retrying: 429 and 5xx
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn status_error(status: StatusCode) -> anyhow::Error {
+    anyhow::Error::new(FetchError::ServerError(status.as_u16()))
+        .context(format!("Request failed with status: {status}"))
+}
+
+/// `Retry-After` of a response, in seconds form
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Delay before retry `attempt` (from 1)
+///
+/// Exponential backoff from `base_delay_ms`, capped at `max_delay_ms`, of
+/// which the `jitter` share is scaled by `random` (0.0-1.0). A `Retry-After`
+/// raises the delay, still within the cap.
+fn retry_delay(
+    retry: &FetchRetryConfig,
+    attempt: u32,
+    retry_after: Option<Duration>,
+    random: f64,
+) -> Duration {
+    let max_delay = Duration::from_millis(retry.max_delay_ms);
+    let exponent = attempt.saturating_sub(1).min(31) as i32;
+    let backoff = (retry.base_delay_ms as f64 * 2f64.powi(exponent)).min(retry.max_delay_ms as f64);
+    let delay = Duration::from_millis((backoff * (1.0 - retry.jitter * random)) as u64);
+    match retry_after {
+        Some(retry_after) => delay.max(retry_after).min(max_delay),
+        None => delay,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +549,254 @@ mod tests {
         assert!(crawler.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_fetch_obeys_robots_txt() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/article/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&server)
+            .await;
+        let url = format!("{}/article/1", server.uri());
+
+        let crawler = Crawler::new(Config::default()).unwrap();
+        let err = crawler.fetch(&url).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Disallowed(_))
+        ));
+
+        let mut config = Config::default();
+        config.crawler.robots.obey = false;
+        let crawler = Crawler::new(config).unwrap();
+        assert_eq!(crawler.fetch_text(&url).await.unwrap(), "ok");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_checks_redirect_targets_against_robots_txt() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /private\n"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/article/1"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/private/1"))
+            .mount(&server)
+            .await;
+        Mock::given(path("/private/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("secret"))
+            .expect(0)
+            .mount(&server)
+            .await;
+        let url = format!("{}/article/1", server.uri());
+
+        let crawler = Crawler::new(Config::default()).unwrap();
+        let err = crawler.fetch(&url).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Disallowed(target)) if target.ends_with("/private/1")
+        ));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let retry = FetchRetryConfig {
+            max_retries: 5,
+            base_delay_ms: 500,
+            max_delay_ms: 3_000,
+            jitter: 0.5,
+        };
+        let ms = |d: Duration| d.as_millis();
+        assert_eq!(ms(retry_delay(&retry, 1, None, 0.0)), 500);
+        assert_eq!(ms(retry_delay(&retry, 2, None, 0.0)), 1_000);
+        assert_eq!(ms(retry_delay(&retry, 3, None, 1.0)), 1_000);
+        assert_eq!(ms(retry_delay(&retry, 10, None, 0.0)), 3_000);
+        assert_eq!(
+            ms(retry_delay(&retry, 1, Some(Duration::from_secs(2)), 0.0)),
+            2_000
+        );
+        assert_eq!(
+            ms(retry_delay(&retry, 1, Some(Duration::from_secs(60)), 0.0)),
+            3_000
+        );
+    }
+
+    fn resilient_config(max_retries: u32, failure_threshold: u32) -> Config {
+        let mut config = Config::default();
+        config.crawler.robots.obey = false;
+        config.crawler.rate_limit = 100.0;
+        config.crawler.retry.max_retries = max_retries;
+        config.crawler.retry.base_delay_ms = 1;
+        config.crawler.circuit_breaker.failure_threshold = failure_threshold;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_fetch_retries_transient_errors() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/article/1"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/article/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&server)
+            .await;
+        Mock::given(path("/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let crawler = Crawler::new(resilient_config(3, 0)).unwrap();
+        let url = format!("{}/article/1", server.uri());
+        assert_eq!(crawler.fetch_text(&url).await.unwrap(), "ok");
+
+        // Not retried
+        let err = crawler
+            .fetch(&format!("{}/missing", server.uri()))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::ServerError(404))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_opens_after_consecutive_failures() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/article/1"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let url = format!("{}/article/1", server.uri());
+
+        let crawler = Crawler::new(resilient_config(1, 3)).unwrap();
+        let err = crawler.fetch(&url).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::ServerError(500))
+        ));
+        // The third failure opens the circuit, so its retry is refused
+        let err = crawler.fetch(&url).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::CircuitOpen(_))
+        ));
+        let err = crawler.fetch(&url).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::CircuitOpen(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_through_proxies_with_rotating_user_agents() {
+        use crate::config::ProxyServer;
+        use wiremock::matchers::{header, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // Answers the proxied request like a forward proxy would
+        let proxy = MockServer::start().await;
+        Mock::given(path("/article/1"))
+            .and(header("user-agent", "agent-a"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&proxy)
+            .await;
+
+        let mut config = resilient_config(2, 0);
+        config.crawler.user_agents = vec!["agent-a".to_string()];
+        config.crawler.proxy.health_check_interval_secs = 0;
+        config.crawler.proxy.max_failures = 1;
+        config.crawler.proxy.servers = [&proxy.uri(), "http://127.0.0.1:1"]
+            .into_iter()
+            .map(|url| ProxyServer {
+                url: url.to_string(),
+                weight: 1,
+            })
+            .collect();
+        let crawler = Crawler::new(config).unwrap();
+
+        // The dead proxy is dropped after its first failure
+        for _ in 0..3 {
+            let text = crawler
+                .fetch_text("http://news.invalid/article/1")
+                .await
+                .unwrap();
+            assert_eq!(text, "ok");
+        }
+        assert_eq!(crawler.proxies.as_ref().unwrap().live(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_text_if_modified_revalidates() {
+        use wiremock::matchers::{header, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/article/1"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/article/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_string("ok"),
+            )
+            .mount(&server)
+            .await;
+        let url = format!("{}/article/1", server.uri());
+
+        let crawler = Crawler::new(resilient_config(0, 0))
+            .unwrap()
+            .with_http_cache(HttpCache::open_in_memory().unwrap());
+        let page = crawler.fetch_text_if_modified(&url).await.unwrap().unwrap();
+        assert_eq!(page.text, "ok");
+        assert_eq!(page.validators.etag.as_deref(), Some("\"v1\""));
+
+        // Fetched in full until the validators are remembered
+        assert!(crawler
+            .fetch_text_if_modified(&url)
+            .await
+            .unwrap()
+            .is_some());
+        crawler.remember_validators(&url, &page.validators).unwrap();
+        assert!(crawler
+            .fetch_text_if_modified(&url)
+            .await
+            .unwrap()
+            .is_none());
+
+        // A plain fetch does not revalidate
+        assert_eq!(crawler.fetch_text(&url).await.unwrap(), "ok");
+    }
+
     #[test]
     fn test_invalid_config_fails() {
         let mut config = Config::default();
@@ -43,10 +43,17 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
-use crate::crawler::fetcher::NaverFetcher;
+use crate::crawler::fetcher::{FetchPolicy, NaverFetcher};
+use crate::crawler::url::UrlExtractor;
+use crate::metrics::Metrics;
 use crate::models::ParsedArticle;
+use crate::notifications::KeywordAlerts;
 use crate::parser::ArticleParser;
-use crate::storage::MarkdownWriter;
+use crate::storage::{MarkdownWriter, PgArticleStore};
+use crate::utils::error::ErrorClass;
+
+/// Articles a storage worker upserts into PostgreSQL per transaction
+const ARTICLE_STORE_BATCH: usize = 32;
 
 // ============================================================================
 // Configuration
@@ -81,6 +88,9 @@ pub struct PipelineConfig {
 
     /// Maximum retries per URL
     pub max_retries: u32,
+
+    /// `instance` label of the per-publisher metrics
+    pub metrics_instance: String,
 }
 
 impl Default for PipelineConfig {
@@ -95,6 +105,7 @@ impl Default for PipelineConfig {
             request_timeout: Duration::from_secs(30),
             crawl_comments: true,
             max_retries: 3,
+            metrics_instance: "pipeline".to_string(),
         }
     }
 }
@@ -264,6 +275,12 @@ impl StatsSnapshot {
 pub struct CrawlerPipeline {
     config: PipelineConfig,
     stats: Arc<PipelineStats>,
+    /// URLs of the jobs that failed, for handing back to a work queue
+    failed_urls: Arc<std::sync::Mutex<Vec<String>>>,
+    metrics: Metrics,
+    keyword_alerts: Option<KeywordAlerts>,
+    article_store: Option<PgArticleStore>,
+    policy: FetchPolicy,
 }
 
 impl CrawlerPipeline {
@@ -277,9 +294,38 @@ impl CrawlerPipeline {
         Ok(Self {
             config,
             stats: PipelineStats::new(),
+            failed_urls: Arc::default(),
+            metrics: Metrics::global(),
+            keyword_alerts: None,
+            article_store: None,
+            policy: FetchPolicy::default(),
         })
     }
 
+    /// Record metrics to `metrics` instead of the global default
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Count stored articles for keyword alerts
+    pub fn with_keyword_alerts(mut self, alerts: Option<KeywordAlerts>) -> Self {
+        self.keyword_alerts = alerts;
+        self
+    }
+
+    /// Also upsert stored articles into PostgreSQL, in batches per storage worker
+    pub fn with_article_store(mut self, store: Option<PgArticleStore>) -> Self {
+        self.article_store = store;
+        self
+    }
+
+    /// Have the fetcher workers obey `policy` (robots.txt)
+    pub fn with_policy(mut self, policy: FetchPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     /// Run the pipeline with given URLs
     pub async fn run(&self, urls: Vec<String>) -> Result<StatsSnapshot> {
         let total_urls = urls.len() as u64;
@@ -309,6 +355,7 @@ impl CrawlerPipeline {
 
         // Spawn result collector
         let stats = Arc::clone(&self.stats);
+        let failed_urls = Arc::clone(&self.failed_urls);
         let result_handle = tokio::spawn(async move {
             while let Some(result) = result_rx.recv().await {
                 match result {
@@ -319,6 +366,9 @@ impl CrawlerPipeline {
                     JobResult::Failed { job_id, url, error } => {
                         stats.record_failure();
                         tracing::warn!(job_id, url, error, "Job failed");
+                        if let Ok(mut failed) = failed_urls.lock() {
+                            failed.push(url);
+                        }
                     }
                     JobResult::Skipped { job_id, reason } => {
                         stats.record_skip();
@@ -392,10 +442,14 @@ impl CrawlerPipeline {
             let rps = self.config.requests_per_second;
             let timeout = self.config.request_timeout;
             let max_retries = self.config.max_retries;
+            let instance = self.config.metrics_instance.clone();
+            let metrics = self.metrics.clone();
+            let policy = self.policy.clone();
 
             let handle = tokio::spawn(async move {
+                let url_extractor = UrlExtractor::new();
                 let fetcher = match NaverFetcher::with_config(rps, max_retries, timeout) {
-                    Ok(f) => f,
+                    Ok(f) => f.with_policy(policy),
                     Err(e) => {
                         tracing::error!(worker_id, error = %e, "Failed to create fetcher");
                         return;
@@ -441,6 +495,11 @@ impl CrawlerPipeline {
                                     }
                                 }
                                 Err(e) => {
+                                    metrics.record_crawl_error(
+                                        &instance,
+                                        &publisher_from_url(&url_extractor, &job.url),
+                                        ErrorClass::Network,
+                                    );
                                     let _ = result_tx
                                         .send(JobResult::Failed {
                                             job_id: job.job_id,
@@ -463,6 +522,12 @@ impl CrawlerPipeline {
                                 // For now, just record the failure
                             }
 
+                            metrics.record_crawl_error(
+                                &instance,
+                                &publisher_from_url(&url_extractor, &job.url),
+                                e.class(),
+                            );
+
                             let _ = result_tx
                                 .send(JobResult::Failed {
                                     job_id: job.job_id,
@@ -497,9 +562,12 @@ impl CrawlerPipeline {
             let parse_rx = Arc::clone(&parse_rx);
             let store_tx = store_tx.clone();
             let result_tx = result_tx.clone();
+            let instance = self.config.metrics_instance.clone();
+            let metrics = self.metrics.clone();
 
             let handle = tokio::spawn(async move {
                 let parser = ArticleParser::new();
+                let url_extractor = UrlExtractor::new();
 
                 loop {
                     let job = {
@@ -514,7 +582,11 @@ impl CrawlerPipeline {
 
                     tracing::debug!(worker_id, job_id = job.job_id, "Parsing");
 
-                    match parser.parse_with_fallback(&job.html, &job.url) {
+                    let started = std::time::Instant::now();
+                    let parsed = parser.parse_with_fallback(&job.html, &job.url);
+                    metrics.record_parse_duration(started.elapsed().as_secs_f64(), parsed.is_ok());
+
+                    match parsed {
                         Ok(mut article) => {
                             // Set category from URL extraction
                             article.category = job.category;
@@ -530,6 +602,11 @@ impl CrawlerPipeline {
                             }
                         }
                         Err(e) => {
+                            metrics.record_crawl_error(
+                                &instance,
+                                &publisher_from_url(&url_extractor, &job.url),
+                                e.class(),
+                            );
                             let _ = result_tx
                                 .send(JobResult::Failed {
                                     job_id: job.job_id,
@@ -564,8 +641,14 @@ impl CrawlerPipeline {
             let store_rx = Arc::clone(&store_rx);
             let result_tx = result_tx.clone();
             let output_dir = output_dir.clone();
+            let instance = self.config.metrics_instance.clone();
+            let metrics = self.metrics.clone();
+            let keyword_alerts = self.keyword_alerts.clone();
+            let article_store = self.article_store.clone();
 
             let handle = tokio::spawn(async move {
+                let mut pending: Vec<ParsedArticle> = Vec::new();
+
                 let writer = match MarkdownWriter::new(&output_dir) {
                     Ok(w) => w,
                     Err(e) => {
@@ -600,6 +683,16 @@ impl CrawlerPipeline {
 
                     match writer.save(&job.article) {
                         Ok(path) => {
+                            metrics.record_publisher_success(&instance, job.article.publisher_id());
+                            if let Some(alerts) = &keyword_alerts {
+                                alerts.observe(&job.article);
+                            }
+                            if let Some(store) = &article_store {
+                                pending.push(job.article.clone());
+                                if pending.len() >= ARTICLE_STORE_BATCH {
+                                    flush_articles(store, &mut pending, worker_id).await;
+                                }
+                            }
                             let _ = result_tx
                                 .send(JobResult::Success {
                                     job_id: job.job_id,
@@ -608,6 +701,11 @@ impl CrawlerPipeline {
                                 .await;
                         }
                         Err(e) => {
+                            metrics.record_crawl_error(
+                                &instance,
+                                job.article.publisher_id(),
+                                ErrorClass::Storage,
+                            );
                             let _ = result_tx
                                 .send(JobResult::Failed {
                                     job_id: job.job_id,
@@ -619,6 +717,9 @@ impl CrawlerPipeline {
                     }
                 }
 
+                if let Some(store) = &article_store {
+                    flush_articles(store, &mut pending, worker_id).await;
+                }
                 tracing::debug!(worker_id, "Storage worker shutting down");
             });
 
@@ -632,12 +733,52 @@ impl CrawlerPipeline {
     pub fn stats(&self) -> StatsSnapshot {
         self.stats.snapshot()
     }
+
+    /// URLs of the jobs that failed so far
+    pub fn failed_urls(&self) -> Vec<String> {
+        self.failed_urls
+            .lock()
+            .map(|failed| failed.clone())
+            .unwrap_or_default()
+    }
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Upsert a storage worker's pending articles into PostgreSQL
+///
+/// The markdown files are already written, so a failure is logged and the
+/// batch dropped rather than failing its jobs.
+async fn flush_articles(
+    store: &PgArticleStore,
+    pending: &mut Vec<ParsedArticle>,
+    worker_id: usize,
+) {
+    if pending.is_empty() {
+        return;
+    }
+    match store.store_parsed(pending).await {
+        Ok(count) => tracing::debug!(worker_id, count, "Stored articles in PostgreSQL"),
+        Err(e) => tracing::warn!(
+            worker_id,
+            count = pending.len(),
+            error = %e,
+            "Failed to store articles in PostgreSQL"
+        ),
+    }
+    pending.clear();
+}
+
+/// Publisher `oid` of a Naver News URL, empty if it cannot be extracted
+fn publisher_from_url(extractor: &UrlExtractor, url: &str) -> String {
+    extractor
+        .extract_ids(url)
+        .map(|(oid, _)| oid)
+        .unwrap_or_default()
+}
+
 /// Extract category from Naver News URL
 fn extract_category_from_url(url: &str) -> String {
     // URLs like: https://n.news.naver.com/mnews/article/001/0014000001?sid=100
@@ -743,6 +884,12 @@ impl PipelineBuilder {
         self
     }
 
+    /// Set the `instance` label of the per-publisher metrics
+    pub fn metrics_instance(mut self, instance: &str) -> Self {
+        self.config.metrics_instance = instance.to_string();
+        self
+    }
+
     /// Build the pipeline
     pub async fn build(self) -> Result<CrawlerPipeline> {
         CrawlerPipeline::new(self.config).await
@@ -901,4 +1048,22 @@ mod tests {
         let pipeline = CrawlerPipeline::new(config).await;
         assert!(pipeline.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_failed_urls() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = PipelineConfig {
+            output_dir: temp_dir.path().to_path_buf(),
+            max_retries: 0,
+            request_timeout: Duration::from_secs(2),
+            ..Default::default()
+        };
+        let pipeline = CrawlerPipeline::new(config).await.unwrap();
+
+        // Nothing listens on port 9
+        let url = "http://127.0.0.1:9/article/001/0000000001".to_string();
+        let stats = pipeline.run(vec![url.clone()]).await.unwrap();
+        assert_eq!(stats.failed_count, 1);
+        assert_eq!(pipeline.failed_urls(), [url]);
+    }
 }
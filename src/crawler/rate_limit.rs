@@ -0,0 +1,311 @@
+//! Request rate limiters
+//!
+//! [`AdjustableRateLimiter`] is the global limit, whose rate can be changed at
+//! runtime: a governor quota is fixed once the limiter is built, so changing
+//! the rate swaps in a new limiter. Requests already waiting finish at the
+//! old rate.
+//!
+//! Rates are requests per second and may be below 1 (`0.5` is one request
+//! every two seconds).
+//!
+//! [`DomainRateLimiter`] adds the per-domain limits of
+//! `crawler.domain_rate_limits` on top of it. Articles often redirect to
+//! their publisher's site, so [`send_following_redirects`] follows
+//! redirects by hand and holds every host on the way to its limit.
+
+use governor::{
+    clock::DefaultClock,
+    state::{InMemoryState, NotKeyed},
+    DefaultKeyedRateLimiter, Quota, RateLimiter,
+};
+use reqwest::{header::LOCATION, redirect, RequestBuilder, Response, StatusCode};
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use url::Url;
+
+use super::robots::RobotsManager;
+use crate::utils::error::FetchError;
+
+type DirectLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
+
+/// Most redirects followed for one request, as reqwest's default policy
+const MAX_REDIRECTS: usize = 10;
+
+/// Direct (unkeyed) rate limiter with an adjustable rate
+pub struct AdjustableRateLimiter {
+    limiter: RwLock<Arc<DirectLimiter>>,
+}
+
+impl AdjustableRateLimiter {
+    /// Create a limiter allowing `requests_per_second`, or 1 request per
+    /// second if that is not positive
+    pub fn new(requests_per_second: f64) -> Self {
+        let quota = quota(requests_per_second).unwrap_or(Quota::per_second(NonZeroU32::MIN));
+        Self {
+            limiter: RwLock::new(Arc::new(RateLimiter::direct(quota))),
+        }
+    }
+
+    /// Wait until a request is allowed
+    pub async fn until_ready(&self) {
+        let limiter = match self.limiter.read() {
+            Ok(limiter) => Arc::clone(&limiter),
+            Err(poisoned) => Arc::clone(&poisoned.into_inner()),
+        };
+        limiter.until_ready().await;
+    }
+
+    /// Change the rate for requests made from now on
+    ///
+    /// Returns `false`, keeping the current rate, unless `requests_per_second`
+    /// is positive.
+    pub fn set_rate(&self, requests_per_second: f64) -> bool {
+        let Some(quota) = quota(requests_per_second) else {
+            return false;
+        };
+        let limiter = Arc::new(RateLimiter::direct(quota));
+        if let Ok(mut current) = self.limiter.write() {
+            *current = limiter;
+        }
+        true
+    }
+}
+
+/// Per-host rate limits of configured domains (`crawler.domain_rate_limits`)
+///
+/// A host takes the rate of the most specific configured domain it is, or
+/// is a subdomain of, and each host is limited on its own at that rate.
+/// Hosts under no configured domain are only held to the global limit.
+pub struct DomainRateLimiter {
+    /// Configured domains, longest first, each with a limiter keyed by host
+    domains: Vec<(String, DefaultKeyedRateLimiter<String>)>,
+}
+
+impl DomainRateLimiter {
+    /// Create limiters for `rates`, requests per second by domain
+    ///
+    /// Domains with a rate that is not positive are left out.
+    pub fn new(rates: &BTreeMap<String, f64>) -> Self {
+        let mut domains: Vec<_> = rates
+            .iter()
+            .filter_map(|(domain, rate)| {
+                let quota = quota(*rate)?;
+                let domain = domain.trim_start_matches('.').to_ascii_lowercase();
+                Some((domain, RateLimiter::keyed(quota)))
+            })
+            .collect();
+        domains.sort_by_key(|(domain, _)| std::cmp::Reverse(domain.len()));
+        Self { domains }
+    }
+
+    /// Whether no domain is limited
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+
+    /// Configured domain whose limit applies to `host`
+    pub fn domain(&self, host: &str) -> Option<&str> {
+        self.limiter(&host.to_ascii_lowercase())
+            .map(|(domain, _)| domain.as_str())
+    }
+
+    /// Wait until a request to `url` is allowed; `false` (at once) if its
+    /// host is under no configured domain
+    pub async fn until_ready(&self, url: &str) -> bool {
+        let Some(host) = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+        else {
+            return false;
+        };
+        match self.limiter(&host) {
+            Some((_, limiter)) => {
+                limiter.until_key_ready(&host).await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn limiter(&self, host: &str) -> Option<&(String, DefaultKeyedRateLimiter<String>)> {
+        self.domains.iter().find(|(domain, _)| {
+            host == domain
+                || host
+                    .strip_suffix(domain.as_str())
+                    .is_some_and(|subdomain| subdomain.ends_with('.'))
+        })
+    }
+}
+
+/// Redirect policy of clients sending through [`send_following_redirects`]
+pub fn redirect_policy() -> redirect::Policy {
+    redirect::Policy::none()
+}
+
+/// Send the GET request `request` builds for `url`, following redirects
+///
+/// The client `request` uses must not follow redirects itself (see
+/// [`redirect_policy`]). Each request, the first and every redirect, first
+/// waits for the limit of its host in `limits`. Redirect targets must also
+/// pass `robots` (the caller checks `url` itself), or the request fails with
+/// [`FetchError::Disallowed`]. After [`MAX_REDIRECTS`] redirects the
+/// redirect response is returned as is.
+pub async fn send_following_redirects(
+    url: &str,
+    limits: Option<&DomainRateLimiter>,
+    robots: Option<&RobotsManager>,
+    request: impl Fn(&str) -> RequestBuilder,
+) -> Result<Response, FetchError> {
+    let mut url = url.to_string();
+    let mut redirects = 0;
+    loop {
+        if let Some(limits) = limits {
+            let started = std::time::Instant::now();
+            if limits.until_ready(&url).await {
+                crate::metrics::record_rate_limiter_wait("domain", started.elapsed().as_secs_f64());
+            }
+        }
+
+        let response = request(&url).send().await?;
+        let target = match response.status() {
+            StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+                if redirects < MAX_REDIRECTS =>
+            {
+                response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|location| location.to_str().ok())
+                    .and_then(|location| response.url().join(location).ok())
+            }
+            _ => None,
+        };
+        let Some(target) = target else {
+            return Ok(response);
+        };
+        if let Some(robots) = robots {
+            // Boxed, as robots.txt itself is fetched through here
+            Box::pin(robots.check(target.as_str())).await?;
+        }
+        tracing::debug!(from = %url, to = %target, "Following redirect");
+        url = target.into();
+        redirects += 1;
+    }
+}
+
+/// Quota of `requests_per_second`, which may be below 1; `None` unless positive
+fn quota(requests_per_second: f64) -> Option<Quota> {
+    if !requests_per_second.is_finite() || requests_per_second <= 0.0 {
+        return None;
+    }
+    let period = Duration::from_secs_f64(1.0 / requests_per_second);
+    // As with `Quota::per_second`, up to a second's worth of requests may burst
+    let burst = NonZeroU32::new(requests_per_second as u32).unwrap_or(NonZeroU32::MIN);
+    Quota::with_period(period).map(|quota| quota.allow_burst(burst))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn test_set_rate_applies_to_new_requests() {
+        let limiter = AdjustableRateLimiter::new(1.0);
+        limiter.until_ready().await;
+
+        // At 1 rps the next request would wait about a second
+        assert!(limiter.set_rate(1000.0));
+        let started = Instant::now();
+        for _ in 0..5 {
+            limiter.until_ready().await;
+        }
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(!limiter.set_rate(0.0));
+    }
+
+    #[test]
+    fn test_fractional_rates() {
+        let half = quota(0.5).unwrap();
+        assert_eq!(half.replenish_interval(), Duration::from_secs(2));
+        assert_eq!(half.burst_size(), NonZeroU32::MIN);
+        let rate = quota(1.5).unwrap();
+        assert_eq!(rate.replenish_interval().as_millis(), 666);
+        assert_eq!(rate.burst_size(), NonZeroU32::MIN);
+    }
+
+    #[test]
+    fn test_domain_rate_limiter_matches_most_specific_domain() {
+        let rates = BTreeMap::from([
+            ("naver.com".to_string(), 1.0),
+            ("news.naver.com".to_string(), 2.0),
+            ("example.com".to_string(), 0.0),
+        ]);
+        let limiter = DomainRateLimiter::new(&rates);
+        assert!(!limiter.is_empty());
+        assert_eq!(limiter.domain("n.news.naver.com"), Some("news.naver.com"));
+        assert_eq!(limiter.domain("News.Naver.com"), Some("news.naver.com"));
+        assert_eq!(limiter.domain("blog.naver.com"), Some("naver.com"));
+        assert_eq!(limiter.domain("notnaver.com"), None);
+        // A rate of 0 leaves the domain unlimited
+        assert_eq!(limiter.domain("example.com"), None);
+    }
+
+    #[tokio::test]
+    async fn test_domain_rate_limiter_keys_by_host() {
+        let rates = BTreeMap::from([("example.com".to_string(), 0.5)]);
+        let limiter = DomainRateLimiter::new(&rates);
+        assert!(!limiter.until_ready("https://other.org/a").await);
+        assert!(limiter.until_ready("https://a.example.com/1").await);
+
+        // Each host has its own budget: a second request to another host of
+        // the domain does not wait the two seconds
+        let started = Instant::now();
+        assert!(limiter.until_ready("https://b.example.com/1").await);
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(quota(-1.0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redirect_target_waits_for_its_domain() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let port = server.address().port();
+        // The same server under another host name
+        let target = format!("http://localhost:{port}/article");
+        Mock::given(path("/redirect"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", target.as_str()))
+            .mount(&server)
+            .await;
+        Mock::given(path("/article"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("article"))
+            .mount(&server)
+            .await;
+
+        let rates = BTreeMap::from([("localhost".to_string(), 0.5)]);
+        let limiter = DomainRateLimiter::new(&rates);
+        let client = reqwest::Client::builder()
+            .redirect(redirect_policy())
+            .build()
+            .unwrap();
+        let url = format!("http://127.0.0.1:{port}/redirect");
+        let response = send_following_redirects(&url, Some(&limiter), None, |url| client.get(url))
+            .await
+            .unwrap();
+        assert_eq!(response.url().as_str(), target);
+        assert_eq!(response.text().await.unwrap(), "article");
+
+        // The redirect used up the budget of localhost
+        let wait = limiter.until_ready(&target);
+        assert!(tokio::time::timeout(Duration::from_millis(200), wait)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,593 @@
+//! robots.txt compliance
+//!
+//! [`RobotsManager`] fetches `/robots.txt` once per origin, keeps it for
+//! `crawler.robots.cache_ttl_secs` and is consulted by `Crawler::fetch()`
+//! before every request: URLs disallowed for baram's user agent fail with
+//! [`FetchError::Disallowed`], and requests to a host with a `Crawl-delay`
+//! are spaced at least that far apart, on top of the configured rate limit.
+//!
+//! Rules follow RFC 9309:
+//! - The groups naming baram's product token (the user agent up to the first
+//!   `/`) apply, or the `*` groups if none does
+//! - The longest matching `Allow`/`Disallow` path wins, `Allow` on a tie
+//! - `*` matches any characters and a trailing `$` anchors the end
+//! - robots.txt goes through the crawler's proxy pool, if it has one, so
+//!   hosts only ever see the proxies
+//! - A missing robots.txt (4xx) allows everything; one that cannot be fetched
+//!   (5xx or network error) disallows everything, but is only cached for
+//!   [`UNREACHABLE_TTL`] so it is retried soon
+//!
+//! # Example
+//!
+//! ```
+//! use baram::crawler::robots::RobotsTxt;
+//!
+//! let robots = RobotsTxt::parse("User-agent: *\nDisallow: /search\nCrawl-delay: 2\n");
+//! assert!(robots.is_allowed("baram", "/article/001/0015812889"));
+//! assert!(!robots.is_allowed("baram", "/search?query=x"));
+//! assert_eq!(robots.crawl_delay("baram").unwrap().as_secs(), 2);
+//! ```
+
+use reqwest::Client;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use url::Url;
+
+use super::proxy::ProxyPool;
+use super::rate_limit::send_following_redirects;
+use crate::config::RobotsConfig;
+use crate::utils::error::FetchError;
+
+/// How long an unreachable robots.txt blocks its host before it is retried
+pub const UNREACHABLE_TTL: Duration = Duration::from_secs(300);
+
+/// Largest `Crawl-delay` honored, so a bogus value cannot stall a crawl
+const MAX_CRAWL_DELAY: Duration = Duration::from_secs(60);
+
+/// One `Allow` or `Disallow` line
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+impl Rule {
+    fn matches(&self, path: &str) -> bool {
+        let (pattern, anchored) = match self.pattern.strip_suffix('$') {
+            Some(pattern) => (pattern, true),
+            None => (self.pattern.as_str(), false),
+        };
+        wildcard_match(pattern.as_bytes(), path.as_bytes(), anchored)
+    }
+}
+
+/// Match `pattern` (with `*` wildcards) against the start of `path`, or all
+/// of it if `anchored`
+///
+/// Tracks the path positions the pattern so far can end at, so this takes
+/// O(pattern × path) time however many `*`s a rule has.
+fn wildcard_match(pattern: &[u8], path: &[u8], anchored: bool) -> bool {
+    let mut ends = vec![false; path.len() + 1];
+    ends[0] = true;
+    for &c in pattern {
+        if c == b'*' {
+            // Every position from the first reachable one on
+            if let Some(first) = ends.iter().position(|&end| end) {
+                ends[first..].fill(true);
+            }
+        } else {
+            for i in (0..path.len()).rev() {
+                ends[i + 1] = ends[i] && path[i] == c;
+            }
+            ends[0] = false;
+        }
+    }
+    if anchored {
+        ends[path.len()]
+    } else {
+        ends.contains(&true)
+    }
+}
+
+/// Rules of one or more `User-agent` lines
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+/// Parsed robots.txt
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RobotsTxt {
+    groups: Vec<Group>,
+    /// Everything is disallowed (robots.txt could not be fetched)
+    disallow_all: bool,
+}
+
+impl RobotsTxt {
+    /// A robots.txt allowing everything
+    #[must_use]
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// A robots.txt disallowing everything
+    #[must_use]
+    pub fn disallow_all() -> Self {
+        Self {
+            groups: Vec::new(),
+            disallow_all: true,
+        }
+    }
+
+    /// Parse robots.txt content, ignoring lines it does not understand
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut current: Option<Group> = None;
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim().to_ascii_lowercase().as_str() {
+                "user-agent" => {
+                    // A user agent after rules starts a new group
+                    if current
+                        .as_ref()
+                        .is_some_and(|g| !g.rules.is_empty() || g.crawl_delay.is_some())
+                    {
+                        groups.extend(current.take());
+                    }
+                    current
+                        .get_or_insert_with(Group::default)
+                        .agents
+                        .push(value.to_ascii_lowercase());
+                }
+                directive @ ("allow" | "disallow") => {
+                    let Some(group) = current.as_mut() else {
+                        continue;
+                    };
+                    // An empty Disallow allows everything, as no rule
+                    if !value.is_empty() {
+                        group.rules.push(Rule {
+                            allow: directive == "allow",
+                            pattern: value.to_string(),
+                        });
+                    }
+                }
+                "crawl-delay" => {
+                    if let (Some(group), Ok(secs)) = (current.as_mut(), value.parse::<f64>()) {
+                        if secs.is_finite() && secs >= 0.0 {
+                            group.crawl_delay =
+                                Some(Duration::from_secs_f64(secs).min(MAX_CRAWL_DELAY));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        groups.extend(current);
+
+        Self {
+            groups,
+            disallow_all: false,
+        }
+    }
+
+    /// Groups that apply to `agent` (a product token such as `baram`)
+    fn groups_for(&self, agent: &str) -> Vec<&Group> {
+        let agent = agent.to_ascii_lowercase();
+        let specific: Vec<&Group> = self
+            .groups
+            .iter()
+            .filter(|g| g.agents.contains(&agent))
+            .collect();
+        if !specific.is_empty() {
+            return specific;
+        }
+        self.groups
+            .iter()
+            .filter(|g| g.agents.iter().any(|a| a == "*"))
+            .collect()
+    }
+
+    /// Check whether `agent` may fetch `path` (path and query of a URL)
+    #[must_use]
+    pub fn is_allowed(&self, agent: &str, path: &str) -> bool {
+        if path == "/robots.txt" {
+            return true;
+        }
+        if self.disallow_all {
+            return false;
+        }
+
+        let mut best: Option<&Rule> = None;
+        for rule in self
+            .groups_for(agent)
+            .into_iter()
+            .flat_map(|g| &g.rules)
+            .filter(|r| r.matches(path))
+        {
+            let better = match best {
+                None => true,
+                Some(b) => {
+                    rule.pattern.len() > b.pattern.len()
+                        || (rule.pattern.len() == b.pattern.len() && rule.allow && !b.allow)
+                }
+            };
+            if better {
+                best = Some(rule);
+            }
+        }
+        best.map_or(true, |r| r.allow)
+    }
+
+    /// `Crawl-delay` that applies to `agent`, capped at one minute
+    #[must_use]
+    pub fn crawl_delay(&self, agent: &str) -> Option<Duration> {
+        self.groups_for(agent)
+            .into_iter()
+            .filter_map(|g| g.crawl_delay)
+            .max()
+    }
+}
+
+/// A fetched robots.txt and when it goes stale
+struct CachedRobots {
+    robots: Arc<RobotsTxt>,
+    expires: Instant,
+}
+
+/// Fetches, caches and enforces robots.txt per origin
+pub struct RobotsManager {
+    client: Client,
+    /// Proxies robots.txt is fetched through instead of `client`
+    proxies: Option<Arc<ProxyPool>>,
+    /// Product token matched against `User-agent` lines
+    agent: String,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, CachedRobots>>,
+    /// Earliest time of the next request to each origin with a `Crawl-delay`
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl RobotsManager {
+    /// Create a manager fetching robots.txt with `client` for `user_agent`
+    pub fn new(client: Client, user_agent: &str, config: &RobotsConfig) -> Self {
+        let agent = user_agent
+            .split('/')
+            .next()
+            .unwrap_or(user_agent)
+            .trim()
+            .to_string();
+        Self {
+            client,
+            proxies: None,
+            agent,
+            ttl: config.cache_ttl(),
+            cache: Mutex::new(HashMap::new()),
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetch robots.txt through `proxies` rather than directly
+    #[must_use]
+    pub fn with_proxies(mut self, proxies: Arc<ProxyPool>) -> Self {
+        self.proxies = Some(proxies);
+        self
+    }
+
+    /// Get the product token matched against `User-agent` lines
+    #[must_use]
+    pub fn agent(&self) -> &str {
+        &self.agent
+    }
+
+    /// Wait until `url` may be fetched
+    ///
+    /// Fails with [`FetchError::Disallowed`] if robots.txt disallows it and
+    /// otherwise waits out the host's `Crawl-delay` since the previous request.
+    pub async fn check(&self, url: &str) -> Result<(), FetchError> {
+        let parsed = Url::parse(url).map_err(|_| FetchError::InvalidUrl(url.to_string()))?;
+        let origin = parsed.origin().ascii_serialization();
+        let robots = self.robots(&parsed, &origin).await;
+
+        let path = match parsed.query() {
+            Some(query) => format!("{}?{query}", parsed.path()),
+            None => parsed.path().to_string(),
+        };
+        if !robots.is_allowed(&self.agent, &path) {
+            return Err(FetchError::Disallowed(url.to_string()));
+        }
+
+        if let Some(delay) = robots.crawl_delay(&self.agent) {
+            let slot = {
+                let mut next_slot = lock(&self.next_slot);
+                let now = Instant::now();
+                let slot = next_slot.get(&origin).copied().unwrap_or(now).max(now);
+                next_slot.insert(origin, slot + delay);
+                slot
+            };
+            tokio::time::sleep_until(slot).await;
+        }
+        Ok(())
+    }
+
+    /// Get the robots.txt of `origin`, fetching it if not cached or stale
+    async fn robots(&self, url: &Url, origin: &str) -> Arc<RobotsTxt> {
+        if let Some(cached) = lock(&self.cache).get(origin) {
+            if cached.expires > Instant::now() {
+                return Arc::clone(&cached.robots);
+            }
+        }
+
+        let (robots, ttl) = self.fetch(url).await;
+        let robots = Arc::new(robots);
+        lock(&self.cache).insert(
+            origin.to_string(),
+            CachedRobots {
+                robots: Arc::clone(&robots),
+                expires: Instant::now() + ttl,
+            },
+        );
+        robots
+    }
+
+    /// Fetch and parse robots.txt, with how long to keep it
+    async fn fetch(&self, url: &Url) -> (RobotsTxt, Duration) {
+        let mut robots_url = url.clone();
+        robots_url.set_path("/robots.txt");
+        robots_url.set_query(None);
+        robots_url.set_fragment(None);
+
+        let response = match self.send(robots_url.as_str()).await {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::warn!(url = %robots_url, error = %e, "robots.txt unreachable, disallowing host");
+                return (RobotsTxt::disallow_all(), UNREACHABLE_TTL);
+            }
+        };
+
+        let status = response.status();
+        if status.is_client_error() {
+            tracing::debug!(url = %robots_url, status = status.as_u16(), "No robots.txt, allowing all");
+            return (RobotsTxt::allow_all(), self.ttl);
+        }
+        if !status.is_success() {
+            tracing::warn!(url = %robots_url, status = status.as_u16(), "robots.txt unavailable, disallowing host");
+            return (RobotsTxt::disallow_all(), UNREACHABLE_TTL);
+        }
+
+        match response.text().await {
+            Ok(content) => (RobotsTxt::parse(&content), self.ttl),
+            Err(e) => {
+                tracing::warn!(url = %robots_url, error = %e, "Failed to read robots.txt, disallowing host");
+                (RobotsTxt::disallow_all(), UNREACHABLE_TTL)
+            }
+        }
+    }
+}
+
+impl RobotsManager {
+    /// GET `url`, following redirects, through the next proxy if there
+    /// are any
+    async fn send(&self, url: &str) -> Result<reqwest::Response, FetchError> {
+        let Some(pool) = &self.proxies else {
+            return send_following_redirects(url, None, None, |url| self.client.get(url)).await;
+        };
+        pool.start_health_checks();
+        let index = pool.pick().ok_or(FetchError::ProxyUnavailable)?;
+        let client = pool.client(index);
+        let result = send_following_redirects(url, None, None, |url| client.get(url)).await;
+        match &result {
+            Ok(_) => pool.record_success(index),
+            Err(FetchError::Http(e)) if e.is_connect() => pool.record_failure(index),
+            Err(_) => {}
+        }
+        result
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS: &str = "\
+# Example
+User-agent: Googlebot
+Disallow: /
+
+User-agent: *
+User-agent: baram
+Disallow: /search
+Allow: /search/about
+Disallow: /*.json$
+Crawl-delay: 1.5
+
+Sitemap: https://example.com/sitemap.xml
+";
+
+    #[test]
+    fn test_longest_match_wins() {
+        let robots = RobotsTxt::parse(ROBOTS);
+        assert!(robots.is_allowed("baram", "/article/001/1"));
+        assert!(!robots.is_allowed("baram", "/search?query=x"));
+        assert!(robots.is_allowed("baram", "/search/about"));
+        assert!(!robots.is_allowed("Googlebot", "/article/001/1"));
+        assert!(robots.is_allowed("Googlebot", "/robots.txt"));
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let robots = RobotsTxt::parse(ROBOTS);
+        assert!(!robots.is_allowed("baram", "/api/data.json"));
+        assert!(robots.is_allowed("baram", "/api/data.json?page=2"));
+
+        let rule = Rule {
+            allow: false,
+            pattern: "/a*/b".to_string(),
+        };
+        assert!(rule.matches("/a/x/b/c"));
+        assert!(!rule.matches("/b"));
+        assert!(wildcard_match(b"/a*", b"/a", true));
+        assert!(!wildcard_match(b"/a*c", b"/abcd", true));
+        assert!(wildcard_match(b"/a*c", b"/abcd", false));
+    }
+
+    #[test]
+    fn test_wildcards_take_polynomial_time() {
+        // Backtracking over every split of the path would never finish
+        let rule = Rule {
+            allow: false,
+            pattern: format!("/{}b", "*a".repeat(30)),
+        };
+        let path = format!("/{}", "a".repeat(10_000));
+        let started = std::time::Instant::now();
+        assert!(!rule.matches(&path));
+        assert!(rule.matches(&format!("{path}b")));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_specific_agent_replaces_wildcard_group() {
+        let robots = RobotsTxt::parse(
+            "User-agent: *\nDisallow: /\n\nUser-agent: baram\nDisallow: /private\n",
+        );
+        assert!(robots.is_allowed("baram", "/article"));
+        assert!(!robots.is_allowed("baram", "/private/1"));
+        assert!(!robots.is_allowed("other", "/article"));
+        assert_eq!(robots.crawl_delay("baram"), None);
+    }
+
+    #[test]
+    fn test_crawl_delay_and_empty_disallow() {
+        let robots = RobotsTxt::parse(ROBOTS);
+        assert_eq!(
+            robots.crawl_delay("baram"),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(robots.crawl_delay("Googlebot"), None);
+
+        let open = RobotsTxt::parse("User-agent: *\nDisallow:\nCrawl-delay: 3600\n");
+        assert!(open.is_allowed("baram", "/anything"));
+        assert_eq!(open.crawl_delay("baram"), Some(MAX_CRAWL_DELAY));
+
+        assert!(!RobotsTxt::disallow_all().is_allowed("baram", "/"));
+    }
+
+    #[tokio::test]
+    async fn test_manager_fetches_and_caches() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /private\n"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let manager = RobotsManager::new(Client::new(), "baram/0.1.6", &RobotsConfig::default());
+        assert_eq!(manager.agent(), "baram");
+
+        let url = server.uri();
+        assert!(manager.check(&format!("{url}/article/1")).await.is_ok());
+        assert!(matches!(
+            manager.check(&format!("{url}/private/1")).await,
+            Err(FetchError::Disallowed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_manager_missing_and_unreachable_robots() {
+        use wiremock::{MockServer, ResponseTemplate};
+
+        // No robots.txt: everything allowed
+        let server = MockServer::start().await;
+        let manager = RobotsManager::new(Client::new(), "baram", &RobotsConfig::default());
+        assert!(manager.check(&format!("{}/x", server.uri())).await.is_ok());
+
+        // Server error: the host is off limits for now
+        let failing = MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&failing)
+            .await;
+        assert!(manager
+            .check(&format!("{}/x", failing.uri()))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_manager_fetches_through_proxies() {
+        use crate::config::{ProxyConfig, ProxyServer};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // The mock answers proxied requests like a forward proxy would
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nDisallow: /\n"),
+            )
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let config = ProxyConfig {
+            servers: vec![ProxyServer {
+                url: proxy.uri(),
+                weight: 1,
+            }],
+            health_check_interval_secs: 0,
+            ..ProxyConfig::default()
+        };
+        let pool = Arc::new(ProxyPool::new(&config, Client::builder).unwrap());
+        let manager =
+            RobotsManager::new(Client::new(), "baram", &RobotsConfig::default()).with_proxies(pool);
+
+        // Nothing listens on the origin, only the proxy can have answered
+        assert!(matches!(
+            manager.check("http://robots.invalid/article/1").await,
+            Err(FetchError::Disallowed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_crawl_delay_spaces_requests() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::path("/robots.txt"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("User-agent: *\nCrawl-delay: 0.2\n"),
+            )
+            .mount(&server)
+            .await;
+        let manager = RobotsManager::new(Client::new(), "baram", &RobotsConfig::default());
+        let url = format!("{}/a", server.uri());
+
+        let started = Instant::now();
+        manager.check(&url).await.unwrap();
+        manager.check(&url).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,199 @@
+//! Daum News site adapter
+//!
+//! Article lists are the per-section breaking news pages,
+//! `https://news.daum.net/breakingnews/{section}?page={page}&regDate={date}`,
+//! and articles live at `https://v.daum.net/v/{id}`, where the ID is usually
+//! the 17-digit publication timestamp.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+use crate::models::{ArticleSource, NewsCategory, ParsedArticle};
+use crate::parser::daum::DaumParser;
+use crate::utils::error::ParseError;
+
+use super::SiteAdapter;
+
+lazy_static! {
+    static ref HREF: Regex = Regex::new(r#"href=["']([^"']+)["']"#).unwrap();
+    static ref ARTICLE_URL: Regex =
+        Regex::new(r"^https?://(?:news\.)?v\.daum\.net/v/([0-9A-Za-z]+)").unwrap();
+}
+
+/// ID of a Daum article URL, e.g. `20240115093000123`
+#[must_use]
+pub fn article_id(url: &str) -> Option<String> {
+    ARTICLE_URL
+        .captures(url)
+        .and_then(|caps| caps.get(1))
+        .map(|id| id.as_str().to_string())
+}
+
+/// Daum News (`v.daum.net`), sections by name (`politics`, `foreign`, ...)
+pub struct DaumSite {
+    parser: DaumParser,
+}
+
+impl DaumSite {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            parser: DaumParser::new(),
+        }
+    }
+}
+
+impl Default for DaumSite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SiteAdapter for DaumSite {
+    fn source(&self) -> ArticleSource {
+        ArticleSource::Daum
+    }
+
+    fn section(&self, category: NewsCategory) -> String {
+        match category {
+            NewsCategory::Politics => "politics",
+            NewsCategory::Economy => "economic",
+            NewsCategory::Society => "society",
+            NewsCategory::Culture => "culture",
+            NewsCategory::World => "foreign",
+            NewsCategory::IT => "digital",
+        }
+        .to_string()
+    }
+
+    /// Maps section codes and the Korean section names shown on articles
+    fn category(&self, section: &str) -> Option<NewsCategory> {
+        match section.trim().to_lowercase().as_str() {
+            "politics" | "정치" => Some(NewsCategory::Politics),
+            "economic" | "경제" => Some(NewsCategory::Economy),
+            "society" | "사회" => Some(NewsCategory::Society),
+            "culture" | "문화" | "생활/문화" => Some(NewsCategory::Culture),
+            "foreign" | "국제" | "세계" => Some(NewsCategory::World),
+            "digital" | "it" | "it/과학" | "테크" => Some(NewsCategory::IT),
+            _ => None,
+        }
+    }
+
+    fn list_url(&self, category: NewsCategory, date: &str, page: u32) -> String {
+        format!(
+            "https://news.daum.net/breakingnews/{}?page={page}&regDate={date}",
+            self.section(category)
+        )
+    }
+
+    fn extract_urls(&self, html: &str) -> Vec<String> {
+        let urls: HashSet<String> = HREF
+            .captures_iter(html)
+            .filter_map(|caps| caps.get(1))
+            .filter_map(|href| self.normalize_url(href.as_str()))
+            .collect();
+        let mut urls: Vec<String> = urls.into_iter().collect();
+        urls.sort();
+        urls
+    }
+
+    fn has_next_page(&self, html: &str, page: u32) -> bool {
+        html.contains(&format!("page={}", page + 1))
+    }
+
+    fn normalize_url(&self, url: &str) -> Option<String> {
+        Some(format!("https://v.daum.net/v/{}", article_id(url)?))
+    }
+
+    fn parse(&self, html: &str, url: &str) -> Result<ParsedArticle, ParseError> {
+        let id = article_id(url).ok_or(ParseError::IdExtractionFailed)?;
+        let mut article = self.parser.parse(html, url)?;
+        article.source = ArticleSource::Daum;
+        article.source_id = id;
+        article.url = url.to_string();
+        // Sections without a category (e.g. 연예) keep their name
+        if let Some(category) = self.category(&article.category) {
+            article.category = category.as_str().to_string();
+        }
+        article.compute_hash();
+        Ok(article)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_id() {
+        assert_eq!(
+            article_id("https://v.daum.net/v/20240115093000123").as_deref(),
+            Some("20240115093000123")
+        );
+        assert_eq!(
+            article_id("http://news.v.daum.net/v/20240115093000123?f=o").as_deref(),
+            Some("20240115093000123")
+        );
+        assert!(article_id("https://news.daum.net/breakingnews/politics").is_none());
+        assert!(article_id("https://evil.com/?u=https://v.daum.net/v/1").is_none());
+    }
+
+    #[test]
+    fn test_list_url_and_links() {
+        let site = DaumSite::new();
+        assert_eq!(
+            site.list_url(NewsCategory::World, "20240115", 3),
+            "https://news.daum.net/breakingnews/foreign?page=3&regDate=20240115"
+        );
+
+        let html = r#"
+            <ul class="list_news2">
+              <li><a href="https://v.daum.net/v/20240115093000123">기사 1</a></li>
+              <li><a href="https://v.daum.net/v/20240115093000123?f=o">기사 1</a></li>
+              <li><a href='http://v.daum.net/v/20240115080000456'>기사 2</a></li>
+              <li><a href="https://news.daum.net/breakingnews/foreign">목록</a></li>
+            </ul>
+            <a href="?page=2&regDate=20240115">2</a>"#;
+        assert_eq!(
+            site.extract_urls(html),
+            vec![
+                "https://v.daum.net/v/20240115080000456",
+                "https://v.daum.net/v/20240115093000123",
+            ]
+        );
+        assert!(site.has_next_page(html, 1));
+        assert!(!site.has_next_page(html, 2));
+    }
+
+    #[test]
+    fn test_parse_sets_source_and_category() {
+        let html = r#"<html><head>
+            <meta property="og:article:author" content="연합뉴스">
+            <meta property="og:regDate" content="20240115093000">
+            <meta property="article:section" content="국제">
+            </head><body>
+            <h3 class="tit_view">미 연준, 기준금리 동결</h3>
+            <div class="info_view"><span class="txt_info">홍길동 기자</span></div>
+            <div class="article_view"><section>
+              <p dmcf-ptype="general">미국 연방준비제도가 기준금리를 동결했다.</p>
+            </section></div>
+            </body></html>"#;
+        let site = DaumSite::new();
+        let article = site
+            .parse(html, "https://v.daum.net/v/20240115093000123")
+            .unwrap();
+        assert_eq!(article.source, ArticleSource::Daum);
+        assert_eq!(article.id(), "daum_20240115093000123");
+        assert_eq!(article.category, "world");
+        assert!(article.content_hash.is_some());
+
+        assert!(matches!(
+            site.parse(
+                html,
+                "https://n.news.naver.com/mnews/article/001/0014123456"
+            ),
+            Err(ParseError::IdExtractionFailed)
+        ));
+    }
+}
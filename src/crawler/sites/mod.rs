@@ -0,0 +1,114 @@
+//! Pluggable news site adapters
+//!
+//! A [`SiteAdapter`] holds everything that differs between news portals:
+//! where the article lists of a category are and how to find article links
+//! in them, what an article URL looks like, how an article page is parsed,
+//! how the portal's sections map to [`NewsCategory`], and how reader
+//! comments are fetched where there are any. Fetching, rate limiting,
+//! deduplication and storage are shared, so every site runs through the
+//! same crawl pipeline and CLI.
+//!
+//! - [`NaverSite`] - Naver News (`n.news.naver.com`)
+//! - [`DaumSite`] - Daum News (`v.daum.net`)
+//!
+//! # Example
+//!
+//! ```
+//! use baram::crawler::sites::site_adapter;
+//! use baram::models::{ArticleSource, NewsCategory};
+//!
+//! let daum = site_adapter(ArticleSource::Daum).unwrap();
+//! assert_eq!(daum.section(NewsCategory::World), "foreign");
+//! assert_eq!(
+//!     daum.normalize_url("https://v.daum.net/v/20240115093000123?f=o").as_deref(),
+//!     Some("https://v.daum.net/v/20240115093000123")
+//! );
+//! ```
+
+pub mod daum;
+pub mod naver;
+
+pub use daum::DaumSite;
+pub use naver::NaverSite;
+
+use async_trait::async_trait;
+
+use crate::crawler::comment::Comment;
+use crate::models::{ArticleSource, NewsCategory, ParsedArticle};
+use crate::utils::error::ParseError;
+
+/// A news portal the crawler can discover and parse articles on
+#[async_trait]
+pub trait SiteAdapter: Send + Sync {
+    /// Source recorded on the articles of this site
+    fn source(&self) -> ArticleSource;
+
+    /// The site's section code for `category`
+    fn section(&self, category: NewsCategory) -> String;
+
+    /// Category of a section code or section name of the site, if any
+    fn category(&self, section: &str) -> Option<NewsCategory>;
+
+    /// URL of page `page` (from 1) of the article list of `category` on
+    /// `date` (YYYYMMDD)
+    fn list_url(&self, category: NewsCategory, date: &str, page: u32) -> String;
+
+    /// Article URLs linked from a list page, normalized, deduplicated and
+    /// sorted
+    fn extract_urls(&self, html: &str) -> Vec<String>;
+
+    /// Whether list page `page` links to a next page
+    fn has_next_page(&self, html: &str, page: u32) -> bool;
+
+    /// Canonical form of an article URL of this site; `None` for anything
+    /// else
+    fn normalize_url(&self, url: &str) -> Option<String>;
+
+    /// Parse an article page fetched from `url`
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the page is not an article of this site or
+    /// its title or content cannot be found
+    fn parse(&self, html: &str, url: &str) -> Result<ParsedArticle, ParseError>;
+
+    /// Reader comments of a parsed article, as a reply tree
+    ///
+    /// Empty unless the site has comments and the adapter was set up to
+    /// fetch them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comments cannot be fetched
+    async fn comments(&self, _article: &ParsedArticle) -> anyhow::Result<Vec<Comment>> {
+        Ok(Vec::new())
+    }
+}
+
+/// Adapter for the site of `source`; `None` for sources that are not sites
+/// (RSS feeds)
+#[must_use]
+pub fn site_adapter(source: ArticleSource) -> Option<Box<dyn SiteAdapter>> {
+    match source {
+        ArticleSource::Naver => Some(Box::new(NaverSite::new())),
+        ArticleSource::Daum => Some(Box::new(DaumSite::new())),
+        ArticleSource::Rss => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_site_adapter() {
+        for source in [ArticleSource::Naver, ArticleSource::Daum] {
+            let site = site_adapter(source).unwrap();
+            assert_eq!(site.source(), source);
+            for category in NewsCategory::all() {
+                assert_eq!(site.category(&site.section(category)), Some(category));
+            }
+        }
+        assert!(site_adapter(ArticleSource::Rss).is_none());
+    }
+}
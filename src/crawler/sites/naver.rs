@@ -0,0 +1,145 @@
+//! Naver News site adapter
+//!
+//! Wraps the Naver-specific pieces that predate site adapters: the list URL
+//! builder, [`UrlExtractor`] and the multi-format [`ArticleParser`], plus
+//! comments through the [`CommentClient`] when one is set.
+
+use async_trait::async_trait;
+
+use crate::crawler::comment::{Comment, CommentClient};
+use crate::crawler::list::ListUrlBuilder;
+use crate::crawler::url::UrlExtractor;
+use crate::models::{ArticleSource, NewsCategory, ParsedArticle};
+use crate::parser::ArticleParser;
+use crate::utils::error::ParseError;
+
+use super::SiteAdapter;
+
+/// Comment API pages fetched per article (100 comments each)
+const COMMENT_PAGES: u32 = 10;
+
+/// Naver News (`n.news.naver.com`), sections by numeric ID (`sid1=100`)
+pub struct NaverSite {
+    parser: ArticleParser,
+    url_extractor: UrlExtractor,
+    comments: Option<CommentClient>,
+}
+
+impl NaverSite {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            parser: ArticleParser::new(),
+            url_extractor: UrlExtractor::new(),
+            comments: None,
+        }
+    }
+
+    /// Fetch the comments of parsed articles through `client`
+    #[must_use]
+    pub fn with_comments(mut self, client: CommentClient) -> Self {
+        self.comments = Some(client);
+        self
+    }
+}
+
+impl Default for NaverSite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SiteAdapter for NaverSite {
+    fn source(&self) -> ArticleSource {
+        ArticleSource::Naver
+    }
+
+    fn section(&self, category: NewsCategory) -> String {
+        category.to_section_id().to_string()
+    }
+
+    fn category(&self, section: &str) -> Option<NewsCategory> {
+        match section.parse::<u32>() {
+            Ok(id) => NewsCategory::from_section_id(id),
+            Err(_) => NewsCategory::parse(section),
+        }
+    }
+
+    fn list_url(&self, category: NewsCategory, date: &str, page: u32) -> String {
+        ListUrlBuilder::main_list(category, date, page)
+    }
+
+    fn extract_urls(&self, html: &str) -> Vec<String> {
+        self.url_extractor.extract_urls(html)
+    }
+
+    /// Checks for:
+    /// - Pagination links in HTML (look for class="paging" or similar)
+    /// - "다음" (Next) button presence
+    /// - Empty article list (indicates end of pages)
+    fn has_next_page(&self, html: &str, page: u32) -> bool {
+        // Check for pagination element with next page link
+        if html.contains(&format!("page={}", page + 1)) {
+            return true;
+        }
+
+        // Check for "다음" (Next) button
+        if html.contains("다음</a>") || html.contains("class=\"next\"") {
+            return true;
+        }
+
+        // Check if article list is empty (no URLs found)
+        !self.url_extractor.extract_urls(html).is_empty()
+    }
+
+    fn normalize_url(&self, url: &str) -> Option<String> {
+        self.url_extractor.normalize_url(url)
+    }
+
+    fn parse(&self, html: &str, url: &str) -> Result<ParsedArticle, ParseError> {
+        self.parser.parse_with_fallback(html, url)
+    }
+
+    async fn comments(&self, article: &ParsedArticle) -> anyhow::Result<Vec<Comment>> {
+        match &self.comments {
+            Some(client) if !article.oid.is_empty() && !article.aid.is_empty() => {
+                client
+                    .fetch_comment_tree(&article.oid, &article.aid, COMMENT_PAGES)
+                    .await
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_naver_sections() {
+        let site = NaverSite::new();
+        assert_eq!(site.section(NewsCategory::IT), "105");
+        assert_eq!(site.category("101"), Some(NewsCategory::Economy));
+        assert_eq!(site.category("경제"), Some(NewsCategory::Economy));
+        assert_eq!(site.category("999"), None);
+        assert!(site
+            .list_url(NewsCategory::Politics, "20241215", 2)
+            .contains("sid1=100"));
+    }
+
+    #[tokio::test]
+    async fn test_no_comments_without_client() {
+        let article = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0014123456".to_string(),
+            ..Default::default()
+        };
+        assert!(NaverSite::new()
+            .comments(&article)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -260,7 +260,7 @@ impl Default for UrlExtractor {
 pub mod validators {
     use url::Url;
 
-    /// Allowed Naver news domains
+    /// Allowed news domains (Naver and Daum)
     const ALLOWED_DOMAINS: &[&str] = &[
         "n.news.naver.com",
         "news.naver.com",
@@ -268,9 +268,11 @@ pub mod validators {
         "entertain.naver.com",
         "sports.naver.com",
         "sports.news.naver.com",
+        "v.daum.net",
+        "news.v.daum.net",
     ];
 
-    /// Check if URL is from allowed Naver domains
+    /// Check if URL is from allowed news domains
     ///
     /// # Arguments
     ///
@@ -555,6 +557,9 @@ mod tests {
         assert!(validators::is_allowed_domain(
             "https://sports.naver.com/article/001/123"
         ));
+        assert!(validators::is_allowed_domain(
+            "https://v.daum.net/v/20240115093000123"
+        ));
         assert!(!validators::is_allowed_domain("https://evil.com/fake"));
     }
 
@@ -0,0 +1,433 @@
+//! Schedules for single-node continuous crawling
+//!
+//! `baram watch` crawls each category on its own schedule on one machine,
+//! without a coordinator. A schedule is either a fixed interval (`30m`, `2h`)
+//! or a cron expression evaluated in local time (`*/10 * * * *`). The time of
+//! the last completed run of each category is checkpointed, so a restarted
+//! daemon picks up where it left off instead of crawling everything again.
+//!
+//! ```
+//! use baram::crawler::watch::{WatchJob, WatchSchedule};
+//! use baram::models::NewsCategory;
+//! use chrono::{Duration, Utc};
+//!
+//! let schedule: WatchSchedule = "15m".parse().unwrap();
+//! let now = Utc::now();
+//! let job = WatchJob::new(NewsCategory::Politics, schedule).with_last_run(now);
+//!
+//! assert!(!job.is_due(now));
+//! assert!(job.is_due(now + Duration::minutes(15)));
+//! ```
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::models::NewsCategory;
+
+/// Checkpoint key prefix for the last completed run of a category
+pub const CHECKPOINT_PREFIX: &str = "watch.last_run.";
+
+/// When a category is crawled
+#[derive(Debug, Clone)]
+pub enum WatchSchedule {
+    /// A fixed time after the previous run
+    Interval(Duration),
+    /// Every occurrence of a cron expression, in local time
+    Cron(Box<cron::Schedule>),
+}
+
+impl WatchSchedule {
+    /// Next run after `last_run`
+    ///
+    /// A category that never ran is due at `now` with an interval schedule and
+    /// at the next occurrence with a cron schedule. Runs missed while the
+    /// daemon was stopped are made up once, not once per missed occurrence.
+    #[must_use]
+    pub fn next_run(
+        &self,
+        last_run: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Interval(interval) => match last_run {
+                Some(last) => last.checked_add_signed(*interval),
+                None => Some(now),
+            },
+            Self::Cron(schedule) => {
+                let from = last_run.unwrap_or(now).with_timezone(&Local);
+                schedule
+                    .after(&from)
+                    .next()
+                    .map(|next| next.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
+impl FromStr for WatchSchedule {
+    type Err = String;
+
+    /// Parse `30s`, `15m`, `2h` or `1d` as an interval, anything else as a
+    /// cron expression with 5 (minute first) or 6 (second first) fields
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(interval) = parse_interval(s)? {
+            if interval <= Duration::zero() {
+                return Err(format!("Interval must be positive: {s}"));
+            }
+            return Ok(Self::Interval(interval));
+        }
+
+        let expression = match s.split_whitespace().count() {
+            5 => format!("0 {s}"),
+            6 | 7 => s.to_string(),
+            _ => {
+                return Err(format!(
+                    "Invalid schedule: {s}. Use an interval such as 30m or a cron expression such as \"*/10 * * * *\""
+                ))
+            }
+        };
+        cron::Schedule::from_str(&expression)
+            .map(|schedule| Self::Cron(Box::new(schedule)))
+            .map_err(|e| format!("Invalid cron expression {s}: {e}"))
+    }
+}
+
+impl fmt::Display for WatchSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Interval(interval) => write!(f, "every {}", format_interval(*interval)),
+            Self::Cron(schedule) => write!(f, "cron {schedule}"),
+        }
+    }
+}
+
+/// Parse `<number><s|m|h|d>`; `Ok(None)` if `s` is not an interval
+fn parse_interval(s: &str) -> Result<Option<Duration>, String> {
+    let Some(unit) = s.chars().last() else {
+        return Ok(None);
+    };
+    let Ok(value) = s[..s.len() - unit.len_utf8()].parse::<i64>() else {
+        return Ok(None);
+    };
+    let interval = match unit {
+        's' => Duration::try_seconds(value),
+        'm' => Duration::try_minutes(value),
+        'h' => Duration::try_hours(value),
+        'd' => Duration::try_days(value),
+        _ => return Ok(None),
+    };
+    interval
+        .map(Some)
+        .ok_or_else(|| format!("Interval is too long: {s}"))
+}
+
+fn format_interval(interval: Duration) -> String {
+    let secs = interval.num_seconds();
+    match secs {
+        s if s % 86_400 == 0 => format!("{}d", s / 86_400),
+        s if s % 3_600 == 0 => format!("{}h", s / 3_600),
+        s if s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{s}s"),
+    }
+}
+
+/// A category and its schedule
+#[derive(Debug, Clone)]
+pub struct WatchJob {
+    pub category: NewsCategory,
+    pub schedule: WatchSchedule,
+    /// End of the last completed run, restored from the checkpoint
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+impl WatchJob {
+    /// Create a job that has never run
+    pub fn new(category: NewsCategory, schedule: WatchSchedule) -> Self {
+        Self {
+            category,
+            schedule,
+            last_run: None,
+        }
+    }
+
+    /// Set the time of the last completed run
+    pub fn with_last_run(mut self, last_run: DateTime<Utc>) -> Self {
+        self.last_run = Some(last_run);
+        self
+    }
+
+    /// Parse a `category=schedule` spec, e.g. `politics=*/10 * * * *`
+    pub fn parse_spec(spec: &str) -> Result<Self, String> {
+        let (category, schedule) = spec
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid schedule spec: {spec}. Use category=schedule"))?;
+        let category = NewsCategory::parse(category.trim())
+            .ok_or_else(|| format!("Unknown category: {}", category.trim()))?;
+        Ok(Self::new(category, schedule.parse()?))
+    }
+
+    /// Checkpoint key of this job's last run
+    #[must_use]
+    pub fn checkpoint_key(&self) -> String {
+        format!("{CHECKPOINT_PREFIX}{}", self.category.as_str())
+    }
+
+    /// Restore the last run from a checkpoint value (RFC 3339)
+    ///
+    /// An unreadable value is ignored, so the job runs as if it never had.
+    pub fn restore(&mut self, value: &str) {
+        self.last_run = DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|t| t.with_timezone(&Utc));
+    }
+
+    /// Checkpoint value for a run that finished at `finished_at`
+    #[must_use]
+    pub fn checkpoint_value(finished_at: DateTime<Utc>) -> String {
+        finished_at.to_rfc3339()
+    }
+
+    /// Next scheduled run
+    #[must_use]
+    pub fn next_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.schedule.next_run(self.last_run, now)
+    }
+
+    /// Whether the job should run at `now`
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_run(now).is_some_and(|next| next <= now)
+    }
+
+    /// Local dates whose article lists a run at `now` should cover
+    ///
+    /// Naver lists articles by publication date, so a run shortly after
+    /// midnight also covers the previous day when the last run was before
+    /// midnight. At most one earlier day is included.
+    #[must_use]
+    pub fn crawl_dates(&self, now: DateTime<Utc>) -> Vec<NaiveDate> {
+        let today = now.with_timezone(&Local).date_naive();
+        let mut dates = Vec::new();
+        if let Some(last_run) = self.last_run {
+            let last_day = last_run.with_timezone(&Local).date_naive();
+            if last_day < today {
+                dates.extend(today.pred_opt());
+            }
+        }
+        dates.push(today);
+        dates
+    }
+}
+
+/// Earliest next run over all jobs
+#[must_use]
+pub fn next_wakeup(jobs: &[WatchJob], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    jobs.iter().filter_map(|job| job.next_run(now)).min()
+}
+
+/// State of a running watch, as served by its status endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchStatus {
+    pub started_at: DateTime<Utc>,
+    /// Category being crawled right now
+    pub running: Option<String>,
+    /// Articles crawled since the start
+    pub crawled: u32,
+    /// Failed articles since the start
+    pub errors: u32,
+    pub jobs: Vec<JobStatus>,
+}
+
+/// Schedule and runs of one category
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    pub category: String,
+    pub schedule: String,
+    pub last_run: Option<DateTime<Utc>>,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+impl JobStatus {
+    fn new(job: &WatchJob, now: DateTime<Utc>) -> Self {
+        Self {
+            category: job.category.as_str().to_string(),
+            schedule: job.schedule.to_string(),
+            last_run: job.last_run,
+            next_run: job.next_run(now),
+        }
+    }
+}
+
+impl WatchStatus {
+    /// Status of a watch over `jobs` started at `now`
+    #[must_use]
+    pub fn new(jobs: &[WatchJob], now: DateTime<Utc>) -> Self {
+        Self {
+            started_at: now,
+            running: None,
+            crawled: 0,
+            errors: 0,
+            jobs: jobs.iter().map(|job| JobStatus::new(job, now)).collect(),
+        }
+    }
+
+    /// Mark `category` as being crawled
+    pub fn start_run(&mut self, category: NewsCategory) {
+        self.running = Some(category.as_str().to_string());
+    }
+
+    /// Record the end of a run of `job` at `now`, with the totals so far
+    pub fn finish_run(&mut self, job: &WatchJob, now: DateTime<Utc>, crawled: u32, errors: u32) {
+        self.running = None;
+        self.crawled = crawled;
+        self.errors = errors;
+        let status = JobStatus::new(job, now);
+        match self.jobs.iter_mut().find(|j| j.category == status.category) {
+            Some(existing) => *existing = status,
+            None => self.jobs.push(status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_schedule() {
+        let interval: WatchSchedule = "15m".parse().unwrap();
+        assert!(matches!(interval, WatchSchedule::Interval(d) if d == Duration::minutes(15)));
+        assert_eq!(interval.to_string(), "every 15m");
+        assert_eq!(
+            "90s".parse::<WatchSchedule>().unwrap().to_string(),
+            "every 90s"
+        );
+        assert_eq!(
+            "1d".parse::<WatchSchedule>().unwrap().to_string(),
+            "every 1d"
+        );
+
+        assert!(matches!(
+            "*/10 * * * *".parse::<WatchSchedule>().unwrap(),
+            WatchSchedule::Cron(_)
+        ));
+        assert!(matches!(
+            "0 30 9 * * MON-FRI".parse::<WatchSchedule>().unwrap(),
+            WatchSchedule::Cron(_)
+        ));
+
+        assert!("0m".parse::<WatchSchedule>().is_err());
+        assert!("9999999999999999d".parse::<WatchSchedule>().is_err());
+        assert!("soon".parse::<WatchSchedule>().is_err());
+        assert!("61 * * * *".parse::<WatchSchedule>().is_err());
+    }
+
+    #[test]
+    fn test_interval_next_run() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let schedule: WatchSchedule = "30m".parse().unwrap();
+
+        assert_eq!(schedule.next_run(None, now), Some(now));
+        let last = now - Duration::minutes(10);
+        assert_eq!(
+            schedule.next_run(Some(last), now),
+            Some(now + Duration::minutes(20))
+        );
+        // Missed runs are made up once
+        let stale = now - Duration::hours(5);
+        assert_eq!(
+            schedule.next_run(Some(stale), now),
+            Some(stale + Duration::minutes(30))
+        );
+    }
+
+    #[test]
+    fn test_cron_next_run() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 3, 0).unwrap();
+        let schedule: WatchSchedule = "*/10 * * * *".parse().unwrap();
+
+        let next = schedule.next_run(None, now).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2026, 3, 1, 12, 10, 0).unwrap());
+
+        let job = WatchJob::new(NewsCategory::IT, schedule)
+            .with_last_run(Utc.with_ymd_and_hms(2026, 3, 1, 11, 0, 0).unwrap());
+        assert!(job.is_due(now));
+    }
+
+    #[test]
+    fn test_parse_spec_and_checkpoint() {
+        let mut job = WatchJob::parse_spec("economy=0 */2 * * *").unwrap();
+        assert_eq!(job.category, NewsCategory::Economy);
+        assert_eq!(job.checkpoint_key(), "watch.last_run.economy");
+        assert!(job.last_run.is_none());
+
+        let finished = Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 5).unwrap();
+        job.restore(&WatchJob::checkpoint_value(finished));
+        assert_eq!(job.last_run, Some(finished));
+        job.restore("garbage");
+        assert!(job.last_run.is_none());
+
+        assert!(WatchJob::parse_spec("economy").is_err());
+        assert!(WatchJob::parse_spec("sports=1h").is_err());
+    }
+
+    #[test]
+    fn test_crawl_dates_cover_previous_day() {
+        let now = Local
+            .with_ymd_and_hms(2026, 3, 2, 0, 5, 0)
+            .unwrap()
+            .with_timezone(&Utc);
+        let job = WatchJob::new(NewsCategory::World, "30m".parse().unwrap());
+        assert_eq!(job.crawl_dates(now).len(), 1);
+
+        let job = job.with_last_run(now - Duration::minutes(30));
+        let dates = job.crawl_dates(now);
+        assert_eq!(
+            dates,
+            [
+                NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_wakeup() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let jobs = [
+            WatchJob::new(NewsCategory::Politics, "1h".parse().unwrap())
+                .with_last_run(now - Duration::minutes(10)),
+            WatchJob::new(NewsCategory::Economy, "15m".parse().unwrap())
+                .with_last_run(now - Duration::minutes(10)),
+        ];
+        assert_eq!(next_wakeup(&jobs, now), Some(now + Duration::minutes(5)));
+        assert_eq!(next_wakeup(&[], now), None);
+    }
+
+    #[test]
+    fn test_watch_status_records_runs() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let mut job = WatchJob::new(NewsCategory::Politics, "30m".parse().unwrap());
+        let mut status = WatchStatus::new(std::slice::from_ref(&job), now);
+        assert_eq!(status.jobs[0].next_run, Some(now));
+
+        status.start_run(NewsCategory::Politics);
+        assert_eq!(status.running.as_deref(), Some("politics"));
+
+        let finished = now + Duration::minutes(2);
+        job.last_run = Some(finished);
+        status.finish_run(&job, finished, 40, 1);
+        assert_eq!(status.running, None);
+        assert_eq!((status.crawled, status.errors), (40, 1));
+        assert_eq!(status.jobs.len(), 1);
+        assert_eq!(status.jobs[0].last_run, Some(finished));
+        assert_eq!(
+            status.jobs[0].next_run,
+            Some(finished + Duration::minutes(30))
+        );
+    }
+}
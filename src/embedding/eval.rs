@@ -0,0 +1,301 @@
+//! Search quality evaluation against labeled relevance judgments
+//!
+//! A relevance set lists queries with the documents judged relevant to each,
+//! optionally graded (higher is more relevant). Ranked result IDs from any
+//! search mode are scored with the usual IR metrics so BM25, k-NN and hybrid
+//! search with different weights can be compared on the same queries.
+//!
+//! # File formats
+//!
+//! CSV with a header row, one judgment per line (`relevance` is optional and
+//! defaults to 1):
+//!
+//! ```text
+//! query,doc_id,relevance
+//! 반도체 수출,001_0015812889,2
+//! 반도체 수출,015_0005012345,1
+//! ```
+//!
+//! JSON, either graded or as a plain list of relevant IDs:
+//!
+//! ```json
+//! [
+//!   { "query": "반도체 수출", "relevant": { "001_0015812889": 2, "015_0005012345": 1 } },
+//!   { "query": "기준금리", "relevant": ["001_0015800001"] }
+//! ]
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use baram::embedding::eval::{QueryMetrics, RelevanceSet};
+//!
+//! let set = RelevanceSet::from_csv("query,doc_id\nrates,a\n".as_bytes()).unwrap();
+//! let ranked = vec!["b".to_string(), "a".to_string()];
+//! let metrics = QueryMetrics::compute(&ranked, &set.queries[0].relevant, 10);
+//! assert_eq!(metrics.mrr, 0.5);
+//! ```
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A query with its relevance judgments
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabeledQuery {
+    /// Query text
+    pub query: String,
+    /// Relevance grade by document ID (grades of 0 are not relevant)
+    pub relevant: HashMap<String, u32>,
+}
+
+/// Labeled queries to evaluate search against
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelevanceSet {
+    /// Queries in file order
+    pub queries: Vec<LabeledQuery>,
+}
+
+/// Relevant documents of a JSON entry
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonRelevant {
+    Graded(HashMap<String, u32>),
+    Ids(Vec<String>),
+}
+
+#[derive(Deserialize)]
+struct JsonQuery {
+    query: String,
+    relevant: JsonRelevant,
+}
+
+#[derive(Deserialize)]
+struct CsvJudgment {
+    query: String,
+    doc_id: String,
+    #[serde(default)]
+    relevance: Option<u32>,
+}
+
+impl RelevanceSet {
+    /// Load a `.csv` or `.json` relevance set
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read relevance set {}", path.display()))?;
+        let set = match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => Self::from_csv(data.as_bytes())?,
+            Some("json") => Self::from_json(&data)?,
+            _ => bail!(
+                "Unsupported relevance set format: {}. Use .csv or .json",
+                path.display()
+            ),
+        };
+        if set.queries.is_empty() {
+            bail!("Relevance set {} has no queries", path.display());
+        }
+        Ok(set)
+    }
+
+    /// Parse CSV judgments with `query`, `doc_id` and optional `relevance` columns
+    ///
+    /// Judgments of the same query are merged, keeping the first-seen order
+    /// of queries.
+    pub fn from_csv(reader: impl std::io::Read) -> Result<Self> {
+        let mut set = Self::default();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut csv = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        for (line, row) in csv.deserialize::<CsvJudgment>().enumerate() {
+            let row = row.with_context(|| format!("Invalid judgment on row {}", line + 1))?;
+            let position = *positions.entry(row.query.clone()).or_insert_with(|| {
+                set.queries.push(LabeledQuery {
+                    query: row.query.clone(),
+                    relevant: HashMap::new(),
+                });
+                set.queries.len() - 1
+            });
+            set.queries[position]
+                .relevant
+                .insert(row.doc_id, row.relevance.unwrap_or(1));
+        }
+        Ok(set)
+    }
+
+    /// Parse a JSON array of `{ "query", "relevant" }` entries
+    pub fn from_json(data: &str) -> Result<Self> {
+        let entries: Vec<JsonQuery> =
+            serde_json::from_str(data).context("Invalid relevance set JSON")?;
+        let queries = entries
+            .into_iter()
+            .map(|entry| LabeledQuery {
+                query: entry.query,
+                relevant: match entry.relevant {
+                    JsonRelevant::Graded(grades) => grades,
+                    JsonRelevant::Ids(ids) => ids.into_iter().map(|id| (id, 1)).collect(),
+                },
+            })
+            .collect();
+        Ok(Self { queries })
+    }
+
+    /// Number of judged relevant documents across all queries
+    pub fn judgments(&self) -> usize {
+        self.queries
+            .iter()
+            .map(|q| q.relevant.values().filter(|&&grade| grade > 0).count())
+            .sum()
+    }
+}
+
+/// Ranking quality of one result list, each in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct QueryMetrics {
+    /// Normalized discounted cumulative gain at k
+    pub ndcg: f64,
+    /// Reciprocal rank of the first relevant result (0 if none in the top k)
+    pub mrr: f64,
+    /// Share of the relevant documents found in the top k
+    pub recall: f64,
+}
+
+impl QueryMetrics {
+    /// Score the top `k` of `ranked` document IDs against `relevant`
+    pub fn compute(ranked: &[String], relevant: &HashMap<String, u32>, k: usize) -> Self {
+        let top = &ranked[..ranked.len().min(k)];
+        let grade = |id: &String| relevant.get(id).copied().unwrap_or(0);
+
+        let dcg = discounted_gain(top.iter().map(grade));
+        let mut ideal: Vec<u32> = relevant.values().copied().filter(|&g| g > 0).collect();
+        ideal.sort_unstable_by(|a, b| b.cmp(a));
+        ideal.truncate(k);
+        let idcg = discounted_gain(ideal.into_iter());
+
+        let total_relevant = relevant.values().filter(|&&g| g > 0).count();
+        let found = top.iter().filter(|id| grade(id) > 0).count();
+
+        Self {
+            ndcg: if idcg > 0.0 { dcg / idcg } else { 0.0 },
+            mrr: top
+                .iter()
+                .position(|id| grade(id) > 0)
+                .map(|rank| 1.0 / (rank + 1) as f64)
+                .unwrap_or(0.0),
+            recall: if total_relevant > 0 {
+                found as f64 / total_relevant as f64
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Mean of each metric (zero for no queries)
+    pub fn mean(metrics: &[Self]) -> Self {
+        if metrics.is_empty() {
+            return Self::default();
+        }
+        let n = metrics.len() as f64;
+        Self {
+            ndcg: metrics.iter().map(|m| m.ndcg).sum::<f64>() / n,
+            mrr: metrics.iter().map(|m| m.mrr).sum::<f64>() / n,
+            recall: metrics.iter().map(|m| m.recall).sum::<f64>() / n,
+        }
+    }
+}
+
+/// DCG with exponential gain, `(2^grade - 1) / log2(rank + 1)`
+fn discounted_gain(grades: impl Iterator<Item = u32>) -> f64 {
+    grades
+        .enumerate()
+        .map(|(i, grade)| (2f64.powi(grade as i32) - 1.0) / (i as f64 + 2.0).log2())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn graded(grades: &[(&str, u32)]) -> HashMap<String, u32> {
+        grades.iter().map(|(id, g)| (id.to_string(), *g)).collect()
+    }
+
+    #[test]
+    fn test_perfect_ranking() {
+        let relevant = graded(&[("a", 2), ("b", 1)]);
+        let metrics = QueryMetrics::compute(&ids(&["a", "b", "c"]), &relevant, 10);
+        assert!((metrics.ndcg - 1.0).abs() < 1e-9);
+        assert_eq!(metrics.mrr, 1.0);
+        assert_eq!(metrics.recall, 1.0);
+    }
+
+    #[test]
+    fn test_imperfect_ranking() {
+        let relevant = graded(&[("a", 1), ("b", 1)]);
+        let metrics = QueryMetrics::compute(&ids(&["x", "a", "y"]), &relevant, 3);
+
+        // DCG = 1/log2(3), IDCG = 1 + 1/log2(3)
+        let expected = (1.0 / 3f64.log2()) / (1.0 + 1.0 / 3f64.log2());
+        assert!((metrics.ndcg - expected).abs() < 1e-9);
+        assert_eq!(metrics.mrr, 0.5);
+        assert_eq!(metrics.recall, 0.5);
+
+        // Relevant documents beyond k do not count
+        let cut = QueryMetrics::compute(&ids(&["x", "a"]), &relevant, 1);
+        assert_eq!(cut, QueryMetrics::default());
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = QueryMetrics::mean(&[
+            QueryMetrics {
+                ndcg: 1.0,
+                mrr: 1.0,
+                recall: 1.0,
+            },
+            QueryMetrics::default(),
+        ]);
+        assert_eq!(mean.ndcg, 0.5);
+        assert_eq!(mean.mrr, 0.5);
+        assert_eq!(mean.recall, 0.5);
+        assert_eq!(QueryMetrics::mean(&[]), QueryMetrics::default());
+    }
+
+    #[test]
+    fn test_from_csv_merges_queries() {
+        let csv = "query,doc_id,relevance\nrates,a,2\nchips,c,\nrates,b,1\n";
+        let set = RelevanceSet::from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(set.queries.len(), 2);
+        assert_eq!(set.queries[0].query, "rates");
+        assert_eq!(set.queries[0].relevant, graded(&[("a", 2), ("b", 1)]));
+        assert_eq!(set.queries[1].relevant, graded(&[("c", 1)]));
+        assert_eq!(set.judgments(), 3);
+    }
+
+    #[test]
+    fn test_from_json_graded_and_ids() {
+        let json = r#"[
+            { "query": "rates", "relevant": { "a": 2, "b": 0 } },
+            { "query": "chips", "relevant": ["c", "d"] }
+        ]"#;
+        let set = RelevanceSet::from_json(json).unwrap();
+        assert_eq!(set.queries[0].relevant, graded(&[("a", 2), ("b", 0)]));
+        assert_eq!(set.queries[1].relevant, graded(&[("c", 1), ("d", 1)]));
+        assert_eq!(set.judgments(), 3);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("qrels.txt");
+        std::fs::write(&path, "rates a 1").unwrap();
+        assert!(RelevanceSet::load(&path).is_err());
+    }
+}
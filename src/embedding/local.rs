@@ -0,0 +1,286 @@
+//! Local full-text search without OpenSearch
+//!
+//! Degraded search mode of deployments that run without OpenSearch (see
+//! [`crate::health::capabilities`]). Articles are searched in the SQLite FTS5
+//! tables of [`crate::storage::fulltext`] and ranked by BM25, with the title
+//! weighted over the body. Both documents and queries go through
+//! [`TextTokenizer`], so Korean particles are stripped on both sides (`금리를`
+//! matches `금리`) and the installed
+//! [`Vocabulary`](crate::analytics::Vocabulary) applies.
+//!
+//! The index is either the crawl database of a `baram crawl` run with
+//! `[database] store_bodies = true` ([`LocalSearch::open`]), or built in
+//! memory from documents and gone with the [`LocalSearch`]
+//! ([`LocalSearch::build`]).
+//!
+//! # Example
+//!
+//! ```
+//! use baram::embedding::{LocalSearch, SearchConfig};
+//!
+//! # fn example(documents: Vec<baram::embedding::IndexDocument>) -> anyhow::Result<()> {
+//! let search = LocalSearch::build(documents)?;
+//! let results = search.search("반도체 투자", &SearchConfig::default())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use rusqlite::{Connection, OpenFlags};
+
+use super::{finish_results, passage, truncate_string, IndexDocument, SearchConfig, SearchResult};
+use crate::analytics::TextTokenizer;
+use crate::storage::{fulltext, StoredBody};
+
+/// BM25 weight of the title column relative to the body
+const TITLE_WEIGHT: f64 = 2.0;
+
+/// Full-text index of articles
+pub struct LocalSearch {
+    conn: Connection,
+    len: usize,
+    tokenizer: TextTokenizer,
+}
+
+impl LocalSearch {
+    /// Index `documents` in memory
+    pub fn build(documents: impl IntoIterator<Item = IndexDocument>) -> Result<Self> {
+        let conn = Connection::open_in_memory().context("Failed to open in-memory database")?;
+        fulltext::create_schema(&conn)?;
+
+        let tokenizer = TextTokenizer::new();
+        for doc in documents {
+            fulltext::save(&conn, &StoredBody::from(&doc), &tokenizer)?;
+        }
+
+        Ok(Self {
+            len: fulltext::count(&conn)?,
+            conn,
+            tokenizer,
+        })
+    }
+
+    /// Search the article bodies stored in the crawl database at `path`
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        if !fulltext::has_schema(&conn)? {
+            bail!("No article bodies stored in {}", path.display());
+        }
+        Ok(Self {
+            len: fulltext::count(&conn)?,
+            conn,
+            tokenizer: TextTokenizer::new(),
+        })
+    }
+
+    /// Number of indexed documents
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no documents are indexed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Documents matching any query term, best first
+    ///
+    /// Honors `k`, `offset`, `min_score`, the category and date filters,
+    /// `collapse_by_story` and `passage_query` of `config`. The highlight of
+    /// a result is its best-matching passage, without markup.
+    pub fn search(&self, query: &str, config: &SearchConfig) -> Result<Vec<SearchResult>> {
+        let mut terms = self.tokenizer.tokenize(query);
+        terms.sort();
+        terms.dedup();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        // Quoted, so FTS5 operators in the query are taken literally
+        let expression = terms
+            .iter()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+
+        let results = fulltext::search(&self.conn, &expression, TITLE_WEIGHT)?
+            .into_iter()
+            .filter(|(doc, score)| {
+                config.min_score.map_or(true, |min| *score >= min) && matches_filters(doc, config)
+            })
+            .map(|(doc, score)| to_result(doc, score, query, config))
+            .collect();
+        Ok(finish_results(results, config))
+    }
+}
+
+impl From<&IndexDocument> for StoredBody {
+    fn from(doc: &IndexDocument) -> Self {
+        Self {
+            id: doc.id.clone(),
+            url: doc.url.clone(),
+            title: doc.title.clone(),
+            body: doc.content.clone(),
+            category: doc.category.clone(),
+            publisher: doc.publisher.clone(),
+            published_at: doc.published_at.clone(),
+            story_id: doc.story_id.clone(),
+        }
+    }
+}
+
+/// Whether a document passes the category and date filters
+fn matches_filters(doc: &StoredBody, config: &SearchConfig) -> bool {
+    if let Some(category) = &config.category {
+        if !doc.category.eq_ignore_ascii_case(category) {
+            return false;
+        }
+    }
+    if config.date_from.is_none() && config.date_to.is_none() {
+        return true;
+    }
+    // ISO 8601 dates compare as strings; undated articles fail a date filter
+    let Some(published) = doc.published_at.as_deref() else {
+        return false;
+    };
+    let day = published.get(..10).unwrap_or(published);
+    let bound = |date: &str| date.get(..10).unwrap_or(date).to_string();
+    config
+        .date_from
+        .as_deref()
+        .map_or(true, |from| day >= bound(from).as_str())
+        && config
+            .date_to
+            .as_deref()
+            .map_or(true, |to| day <= bound(to).as_str())
+}
+
+fn to_result(doc: StoredBody, score: f32, query: &str, config: &SearchConfig) -> SearchResult {
+    let best = passage::best_passage(&doc.body, query);
+    let highlights = config
+        .include_highlights
+        .then(|| best.as_ref().map(|p| vec![p.text.clone()]))
+        .flatten();
+    SearchResult {
+        id: doc.id,
+        score,
+        title: doc.title,
+        content: truncate_string(&doc.body, 500),
+        category: doc.category,
+        publisher: doc.publisher,
+        url: doc.url,
+        published_at: doc.published_at,
+        highlights,
+        passage: config.passage_query.as_ref().and(best),
+        story_id: doc.story_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(id: &str, title: &str, content: &str, category: &str) -> IndexDocument {
+        IndexDocument {
+            id: id.to_string(),
+            source: Default::default(),
+            oid: String::new(),
+            aid: String::new(),
+            title: title.to_string(),
+            content: content.to_string(),
+            category: category.to_string(),
+            publisher: None,
+            author: None,
+            url: format!("https://n.news.naver.com/{id}"),
+            published_at: Some("2026-02-03T09:00:00+09:00".to_string()),
+            crawled_at: String::new(),
+            comment_count: None,
+            image_count: None,
+            video_count: None,
+            reaction_count: None,
+            embedding: Vec::new(),
+            chunk_index: None,
+            chunk_text: None,
+            story_id: None,
+        }
+    }
+
+    #[test]
+    fn test_search_ranks_and_filters() {
+        let search = LocalSearch::build(vec![
+            document(
+                "a",
+                "증시 마감",
+                "코스피가 소폭 올랐다. 반도체 업종이 강세였다.",
+                "economy",
+            ),
+            document(
+                "b",
+                "반도체 투자 확대",
+                "정부가 반도체 투자를 늘린다.",
+                "economy",
+            ),
+            document("c", "국회 본회의", "여야가 예산안을 처리했다.", "politics"),
+        ])
+        .unwrap();
+        assert_eq!(search.len(), 3);
+
+        let results = search.search("반도체를", &SearchConfig::default()).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["b", "a"]);
+        assert!(results[0].score >= results[1].score);
+        let highlights = results[1].highlights.as_ref().unwrap();
+        assert!(highlights[0].contains("반도체 업종이"));
+        assert!(results[1].passage.is_none());
+
+        let config = SearchConfig {
+            category: Some("politics".to_string()),
+            ..Default::default()
+        };
+        assert!(search.search("반도체", &config).unwrap().is_empty());
+        assert_eq!(search.search("예산안 \"OR", &config).unwrap()[0].id, "c");
+
+        let config = SearchConfig {
+            date_from: Some("2026-03-01".to_string()),
+            ..Default::default()
+        };
+        assert!(search.search("반도체", &config).unwrap().is_empty());
+        assert!(search
+            .search("", &SearchConfig::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_open_crawl_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("crawl.db");
+        let conn = Connection::open(&path).unwrap();
+        assert!(LocalSearch::open(&path).is_err());
+
+        fulltext::create_schema(&conn).unwrap();
+        let tokenizer = TextTokenizer::new();
+        for doc in [
+            document(
+                "a",
+                "금리 동결",
+                "한국은행이 기준금리를 동결했다.",
+                "economy",
+            ),
+            document("b", "국회 본회의", "여야가 예산안을 처리했다.", "politics"),
+        ] {
+            fulltext::save(&conn, &StoredBody::from(&doc), &tokenizer).unwrap();
+        }
+
+        let search = LocalSearch::open(&path).unwrap();
+        assert_eq!(search.len(), 2);
+        let results = search.search("금리는", &SearchConfig::default()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "a");
+        assert_eq!(results[0].url, "https://n.news.naver.com/a");
+    }
+}
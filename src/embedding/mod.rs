@@ -5,13 +5,24 @@
 //!
 //! # Architecture
 //!
+//! - `eval` - Search quality metrics against labeled relevance sets
+//! - `local` - In-memory full-text search when OpenSearch is unavailable
+//! - `passage` - Best-matching passages of search results
+//! - `rerank` - Second-stage reranking (RRF, cross-encoder)
 //! - `tokenizer` - Text tokenization and chunking
 //! - `vectorize` - Embedding generation using Candle/BERT
 //! - `VectorStore` - OpenSearch client for indexing and search
 
+pub mod eval;
+pub mod local;
+pub mod passage;
+pub mod rerank;
 pub mod tokenizer;
 pub mod vectorize;
 
+pub use local::LocalSearch;
+pub use passage::Passage;
+pub use rerank::{CrossEncoder, RerankConfig, RerankMethod};
 pub use tokenizer::{ChunkConfig, TextChunk, TextTokenizer, TokenizerStats};
 pub use vectorize::{
     cosine_similarity, dot_product, l2_normalize_vec, Embedder, EmbeddingConfig, EmbeddingStats,
@@ -19,32 +30,34 @@ pub use vectorize::{
 
 use anyhow::{Context, Result};
 use opensearch::{
-    http::{
-        headers::HeaderMap,
-        request::JsonBody,
-        transport::{SingleNodeConnectionPool, TransportBuilder},
-        Method,
-    },
-    BulkOperation, BulkParts, DeleteByQueryParts, IndexParts, OpenSearch, SearchParts,
+    http::transport::{SingleNodeConnectionPool, TransportBuilder},
+    BulkOperation, BulkParts, DeleteByQueryParts, GetParts, IndexParts, OpenSearch, SearchParts,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::time::Duration;
 use url::Url;
+use utoipa::ToSchema;
 
+use crate::analytics::Vocabulary;
 use crate::config::OpenSearchConfig;
-use crate::models::ParsedArticle;
+use crate::models::{ArticleSource, ParsedArticle};
 
 /// Document to be indexed in OpenSearch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexDocument {
-    /// Unique document ID
+    /// Unique document ID (internal article ID, see [`ParsedArticle::id`])
     pub id: String,
 
-    /// Article OID
+    /// Site or feed the article came from; documents indexed before other
+    /// sources existed are Naver articles
+    #[serde(default)]
+    pub source: ArticleSource,
+
+    /// Article OID (Naver only)
     pub oid: String,
 
-    /// Article AID
+    /// Article AID (Naver only)
     pub aid: String,
 
     /// Article title
@@ -74,6 +87,18 @@ pub struct IndexDocument {
     /// Comment count
     pub comment_count: Option<i32>,
 
+    /// Number of embedded images
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_count: Option<i32>,
+
+    /// Number of embedded videos
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video_count: Option<i32>,
+
+    /// Total reader reactions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reaction_count: Option<i64>,
+
     /// Embedding vector
     pub embedding: Vec<f32>,
 
@@ -82,10 +107,49 @@ pub struct IndexDocument {
 
     /// Chunk text (if different from content)
     pub chunk_text: Option<String>,
+
+    /// Story ID grouping coverage of the same event across publishers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub story_id: Option<String>,
+}
+
+impl IndexDocument {
+    /// OpenSearch `_id` of the document
+    ///
+    /// The first chunk of an article, like an unchunked article, is stored
+    /// under the article ID, so lookups by ID find it; later chunks get
+    /// `{id}#{chunk_index}`.
+    pub fn doc_id(&self) -> String {
+        match self.chunk_index {
+            Some(index) if index > 0 => format!("{}#{index}", self.id),
+            _ => self.id.clone(),
+        }
+    }
+
+    /// One document per chunk of this article
+    ///
+    /// Each keeps the article's metadata, with `chunk_index` and
+    /// `chunk_text` set. Only the first chunk, stored under the article ID,
+    /// keeps the full content; later ones store just their own text.
+    pub fn chunk_documents(&self, chunks: &[TextChunk]) -> Vec<IndexDocument> {
+        chunks
+            .iter()
+            .map(|chunk| IndexDocument {
+                content: if chunk.chunk_index == 0 {
+                    self.content.clone()
+                } else {
+                    String::new()
+                },
+                chunk_index: Some(i32::try_from(chunk.chunk_index).unwrap_or(i32::MAX)),
+                chunk_text: Some(chunk.text.clone()),
+                ..self.clone()
+            })
+            .collect()
+    }
 }
 
 /// Search result from OpenSearch
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SearchResult {
     /// Document ID
     pub id: String,
@@ -113,6 +177,97 @@ pub struct SearchResult {
 
     /// Highlight snippets
     pub highlights: Option<Vec<String>>,
+
+    /// Best-matching passage, if requested with [`SearchConfig::passage_query`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passage: Option<Passage>,
+
+    /// Story ID (same event covered by other outlets)
+    #[serde(default)]
+    pub story_id: Option<String>,
+}
+
+/// Document count for one value of a keyword field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct TermCount {
+    /// Field value
+    pub key: String,
+
+    /// Number of documents
+    pub count: u64,
+}
+
+/// Summary of the indexed articles
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct IndexStats {
+    /// Total indexed documents
+    pub total_documents: u64,
+
+    /// Distinct stories
+    pub stories: u64,
+
+    /// Earliest publication date
+    pub earliest_published_at: Option<String>,
+
+    /// Latest publication date
+    pub latest_published_at: Option<String>,
+
+    /// Documents per category, most first
+    pub categories: Vec<TermCount>,
+
+    /// Documents per publisher, most first
+    pub publishers: Vec<TermCount>,
+}
+
+impl IndexStats {
+    /// Parse the aggregations of an [`index_stats_query`] response
+    #[must_use]
+    pub fn from_response(body: &Value) -> Self {
+        let aggs = &body["aggregations"];
+        let terms = |name: &str| -> Vec<TermCount> {
+            aggs[name]["buckets"]
+                .as_array()
+                .map(|buckets| {
+                    buckets
+                        .iter()
+                        .filter_map(|b| {
+                            Some(TermCount {
+                                key: b["key"].as_str()?.to_string(),
+                                count: b["doc_count"].as_u64().unwrap_or(0),
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            total_documents: body["hits"]["total"]["value"].as_u64().unwrap_or(0),
+            stories: aggs["stories"]["value"].as_u64().unwrap_or(0),
+            earliest_published_at: aggs["earliest"]["value_as_string"]
+                .as_str()
+                .map(String::from),
+            latest_published_at: aggs["latest"]["value_as_string"].as_str().map(String::from),
+            categories: terms("categories"),
+            publishers: terms("publishers"),
+        }
+    }
+}
+
+/// Aggregation query behind [`VectorStore::index_stats`]
+#[must_use]
+pub fn index_stats_query(top_publishers: usize) -> Value {
+    json!({
+        "size": 0,
+        "track_total_hits": true,
+        "aggs": {
+            "categories": { "terms": { "field": "category", "size": 50 } },
+            "publishers": { "terms": { "field": "publisher", "size": top_publishers.max(1) } },
+            "stories": { "cardinality": { "field": "story_id" } },
+            "earliest": { "min": { "field": "published_at" } },
+            "latest": { "max": { "field": "published_at" } }
+        }
+    })
 }
 
 /// Bulk indexing result
@@ -127,6 +282,9 @@ pub struct BulkResult {
     /// Error messages
     pub errors: Vec<String>,
 
+    /// IDs of the documents that failed
+    pub failed_ids: Vec<String>,
+
     /// Time taken in milliseconds
     pub took_ms: u64,
 }
@@ -137,6 +295,9 @@ pub struct SearchConfig {
     /// Number of results to return
     pub k: usize,
 
+    /// Number of leading results to skip (pagination)
+    pub offset: usize,
+
     /// Minimum score threshold
     pub min_score: Option<f32>,
 
@@ -157,12 +318,23 @@ pub struct SearchConfig {
 
     /// Include highlights
     pub include_highlights: bool,
+
+    /// Keep only the best-scoring result of each story
+    pub collapse_by_story: bool,
+
+    /// Query text to pick each result's best-matching passage for
+    pub passage_query: Option<String>,
+
+    /// The index holds chunked articles: over-fetch so `k` articles remain
+    /// once the hits of each article's chunks are merged
+    pub chunked: bool,
 }
 
 impl Default for SearchConfig {
     fn default() -> Self {
         Self {
             k: 10,
+            offset: 0,
             min_score: None,
             category: None,
             date_from: None,
@@ -170,10 +342,124 @@ impl Default for SearchConfig {
             hybrid: true,
             bm25_weight: 0.3,
             include_highlights: true,
+            collapse_by_story: false,
+            passage_query: None,
+            chunked: false,
         }
     }
 }
 
+impl SearchConfig {
+    /// Number of hits to request from OpenSearch
+    ///
+    /// Covers the skipped `offset` results, and over-fetches when collapsing
+    /// by story or merging chunks so `k` results remain after duplicates of
+    /// the same story or article are dropped.
+    #[must_use]
+    pub fn fetch_size(&self) -> usize {
+        let mut size = self.offset + self.k;
+        if self.collapse_by_story {
+            size *= COLLAPSE_OVERFETCH;
+        }
+        if self.chunked {
+            size *= CHUNK_OVERFETCH;
+        }
+        size
+    }
+}
+
+/// Temporary search pipeline that combines hybrid BM25 and k-NN scores
+///
+/// Scores of each sub-query are min-max normalized, then averaged with
+/// weights `bm25_weight` and `1 - bm25_weight`, in the order of the hybrid
+/// query's `queries`.
+pub fn hybrid_pipeline(bm25_weight: f32) -> Value {
+    let bm25_weight = bm25_weight.clamp(0.0, 1.0);
+    json!({
+        "phase_results_processors": [{
+            "normalization-processor": {
+                "normalization": { "technique": "min_max" },
+                "combination": {
+                    "technique": "arithmetic_mean",
+                    "parameters": { "weights": [bm25_weight, 1.0 - bm25_weight] }
+                }
+            }
+        }]
+    })
+}
+
+/// Index analysis settings: the nori analyzers with a vocabulary applied
+///
+/// User dictionary rules go to the nori tokenizer and stopwords to a `stop`
+/// filter in both analyzers. Synonyms are expanded by a `synonym_graph`
+/// filter in the search analyzer only, as graph filters cannot be used at
+/// index time. An empty vocabulary adds nothing.
+#[must_use]
+pub fn analysis_settings(vocabulary: &Vocabulary) -> Value {
+    let mut tokenizer = json!({
+        "type": "nori_tokenizer",
+        "decompound_mode": "mixed",
+        "discard_punctuation": true
+    });
+    let mut filters = json!({
+        "nori_posfilter": {
+            "type": "nori_part_of_speech",
+            "stoptags": [
+                "IC", "MAG", "MM", "SP", "SSC", "SSO",
+                "SC", "SE", "XPN", "XSA", "XSN", "XSV"
+            ]
+        }
+    });
+    let mut index_filters = vec!["lowercase", "nori_posfilter", "nori_readingform"];
+    let mut search_filters = vec!["lowercase", "nori_posfilter"];
+
+    if !vocabulary.user_dictionary.is_empty() {
+        tokenizer["user_dictionary_rules"] = json!(vocabulary.user_dictionary);
+    }
+    if !vocabulary.synonyms.is_empty() {
+        filters["baram_synonyms"] = json!({
+            "type": "synonym_graph",
+            "synonyms": vocabulary.synonyms,
+            "lenient": true
+        });
+        search_filters.push("baram_synonyms");
+    }
+    if !vocabulary.stopwords.is_empty() {
+        filters["baram_stopwords"] = json!({
+            "type": "stop",
+            "stopwords": vocabulary.stopwords
+        });
+        index_filters.push("baram_stopwords");
+        search_filters.push("baram_stopwords");
+    }
+
+    json!({
+        "tokenizer": { "nori_mixed": tokenizer },
+        "analyzer": {
+            "nori_analyzer": {
+                "type": "custom",
+                "tokenizer": "nori_mixed",
+                "filter": index_filters
+            },
+            "nori_search_analyzer": {
+                "type": "custom",
+                "tokenizer": "nori_mixed",
+                "filter": search_filters
+            }
+        },
+        "filter": filters
+    })
+}
+
+/// Over-fetch factor used when collapsing search results by story
+const COLLAPSE_OVERFETCH: usize = 3;
+
+/// Over-fetch factor used when merging the chunk hits of each article
+const CHUNK_OVERFETCH: usize = 3;
+
+/// How long [`VectorStore::has_chunks`] trusts its last answer
+const HAS_CHUNKS_TTL: Duration = Duration::from_secs(60);
+
 /// OpenSearch vector store client
 pub struct VectorStore {
     /// OpenSearch client
@@ -185,6 +471,9 @@ pub struct VectorStore {
     /// Configuration
     #[allow(dead_code)]
     config: OpenSearchConfig,
+
+    /// Last answer of [`Self::has_chunks`], and when it was counted
+    has_chunks: std::sync::Mutex<Option<(std::time::Instant, bool)>>,
 }
 
 impl VectorStore {
@@ -215,6 +504,7 @@ impl VectorStore {
             client,
             index_name: config.index_name.clone(),
             config: config.clone(),
+            has_chunks: std::sync::Mutex::new(None),
         })
     }
 
@@ -234,6 +524,9 @@ impl VectorStore {
     }
 
     /// Create index with nori analyzer and k-NN mapping
+    ///
+    /// The analyzers use the installed [`Vocabulary`]; an index keeps the
+    /// vocabulary it was created with until it is recreated.
     pub async fn create_index(&self, embedding_dim: usize) -> Result<()> {
         let body = json!({
             "settings": {
@@ -244,40 +537,12 @@ impl VectorStore {
                     "knn.algo_param.ef_search": 100,
                     "refresh_interval": "5s"
                 },
-                "analysis": {
-                    "tokenizer": {
-                        "nori_mixed": {
-                            "type": "nori_tokenizer",
-                            "decompound_mode": "mixed",
-                            "discard_punctuation": true
-                        }
-                    },
-                    "analyzer": {
-                        "nori_analyzer": {
-                            "type": "custom",
-                            "tokenizer": "nori_mixed",
-                            "filter": ["lowercase", "nori_posfilter", "nori_readingform"]
-                        },
-                        "nori_search_analyzer": {
-                            "type": "custom",
-                            "tokenizer": "nori_mixed",
-                            "filter": ["lowercase", "nori_posfilter"]
-                        }
-                    },
-                    "filter": {
-                        "nori_posfilter": {
-                            "type": "nori_part_of_speech",
-                            "stoptags": [
-                                "IC", "MAG", "MM", "SP", "SSC", "SSO",
-                                "SC", "SE", "XPN", "XSA", "XSN", "XSV"
-                            ]
-                        }
-                    }
-                }
+                "analysis": analysis_settings(Vocabulary::installed())
             },
             "mappings": {
                 "properties": {
                     "id": { "type": "keyword" },
+                    "source": { "type": "keyword" },
                     "oid": { "type": "keyword" },
                     "aid": { "type": "keyword" },
                     "title": {
@@ -306,6 +571,9 @@ impl VectorStore {
                         "format": "strict_date_optional_time||epoch_millis"
                     },
                     "comment_count": { "type": "integer" },
+                    "image_count": { "type": "integer" },
+                    "video_count": { "type": "integer" },
+                    "reaction_count": { "type": "long" },
                     "embedding": {
                         "type": "knn_vector",
                         "dimension": embedding_dim,
@@ -320,6 +588,7 @@ impl VectorStore {
                         }
                     },
                     "chunk_index": { "type": "integer" },
+                    "story_id": { "type": "keyword" },
                     "chunk_text": {
                         "type": "text",
                         "analyzer": "nori_analyzer",
@@ -359,15 +628,118 @@ impl VectorStore {
             .send()
             .await
             .context("Failed to delete index")?;
+        *self
+            .has_chunks
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = None;
 
         Ok(())
     }
 
+    /// Dimension of the index's `embedding` field, `None` if it has none
+    pub async fn embedding_dimension(&self) -> Result<Option<usize>> {
+        let response = self
+            .client
+            .indices()
+            .get_mapping(opensearch::indices::IndicesGetMappingParts::Index(&[
+                &self.index_name
+            ]))
+            .send()
+            .await
+            .context("Failed to get index mapping")?;
+
+        if !response.status_code().is_success() {
+            let error_body = response.text().await?;
+            anyhow::bail!("Reading the index mapping failed: {error_body}");
+        }
+
+        let body: Value = response.json().await?;
+        Ok(mapped_embedding_dimension(&body))
+    }
+
+    /// Recreate the index for `embedding_dim`, keeping its documents
+    ///
+    /// A k-NN field's dimension cannot change in place, so the documents are
+    /// copied without their embeddings to a temporary index, the index is
+    /// recreated and they are copied back. They stay searchable by keyword
+    /// until they are indexed again with new embeddings. Returns the number
+    /// of documents kept.
+    pub async fn migrate_embedding_dimension(&self, embedding_dim: usize) -> Result<usize> {
+        let temp = Self {
+            client: self.client.clone(),
+            index_name: format!("{}-migrate", self.index_name),
+            config: self.config.clone(),
+            has_chunks: std::sync::Mutex::new(None),
+        };
+        if temp.index_exists().await? {
+            anyhow::bail!(
+                "Index {} is left from an interrupted migration; reindex it into {} or delete it",
+                temp.index_name,
+                self.index_name
+            );
+        }
+
+        temp.create_index(embedding_dim).await?;
+        let kept = self
+            .reindex_into(&temp.index_name, Some("ctx._source.remove('embedding')"))
+            .await?;
+        self.delete_index().await?;
+        self.create_index(embedding_dim).await?;
+        temp.reindex_into(&self.index_name, None).await?;
+        temp.delete_index().await?;
+
+        tracing::info!(
+            index = %self.index_name,
+            embedding_dim,
+            documents = kept,
+            "Index migrated to new embedding dimension"
+        );
+        Ok(kept)
+    }
+
+    /// Copy all documents into `dest`, transformed by a painless `script`
+    async fn reindex_into(&self, dest: &str, script: Option<&str>) -> Result<usize> {
+        let mut body = json!({
+            "source": { "index": self.index_name },
+            "dest": { "index": dest }
+        });
+        if let Some(script) = script {
+            body["script"] = json!({ "source": script, "lang": "painless" });
+        }
+
+        let response = self
+            .client
+            .reindex()
+            .wait_for_completion(true)
+            .refresh(true)
+            // Copying a large index takes longer than the client timeout
+            .request_timeout(Duration::from_secs(3600))
+            .body(body)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reindex {} into {dest}", self.index_name))?;
+
+        let status = response.status_code();
+        let response_body: Value = response.json().await?;
+        let failures = response_body["failures"]
+            .as_array()
+            .map_or(0, |failures| failures.len());
+        if !status.is_success() || failures > 0 {
+            anyhow::bail!(
+                "Reindexing {} into {dest} failed: {}",
+                self.index_name,
+                response_body
+            );
+        }
+
+        Ok(response_body["total"].as_u64().unwrap_or(0) as usize)
+    }
+
     /// Index a single document
     pub async fn index_document(&self, doc: &IndexDocument) -> Result<()> {
         let response = self
             .client
-            .index(IndexParts::IndexId(&self.index_name, &doc.id))
+            .index(IndexParts::IndexId(&self.index_name, &doc.doc_id()))
             .body(doc)
             .send()
             .await
@@ -387,6 +759,16 @@ impl VectorStore {
             return Ok(BulkResult::default());
         }
 
+        // Chunks of an earlier, longer version of an article would outlive
+        // it, as later chunks are stored under IDs of their own
+        if self.has_chunks().await? {
+            let ids: Vec<&str> = documents.iter().map(|doc| doc.id.as_str()).collect();
+            self.delete_chunks(&ids).await?;
+        }
+        if documents.iter().any(|doc| doc.chunk_index > Some(0)) {
+            self.set_has_chunks(true);
+        }
+
         let start_time = std::time::Instant::now();
 
         // Build bulk operations
@@ -394,7 +776,7 @@ impl VectorStore {
 
         for doc in documents {
             let doc_json = serde_json::to_value(doc)?;
-            ops.push(BulkOperation::index(doc_json).id(&doc.id).into());
+            ops.push(BulkOperation::index(doc_json).id(doc.doc_id()).into());
         }
 
         let response = self
@@ -402,8 +784,14 @@ impl VectorStore {
             .bulk(BulkParts::Index(&self.index_name))
             .body(ops)
             .send()
-            .await
-            .context("Failed to execute bulk index")?;
+            .await;
+        crate::metrics::record_opensearch_bulk_latency(
+            start_time.elapsed().as_secs_f64(),
+            response
+                .as_ref()
+                .is_ok_and(|r| r.status_code().is_success()),
+        );
+        let response = response.context("Failed to execute bulk index")?;
 
         let took_ms = start_time.elapsed().as_millis() as u64;
 
@@ -423,6 +811,9 @@ impl VectorStore {
                         result.success += 1;
                     } else {
                         result.failed += 1;
+                        if let Some(id) = index_result["_id"].as_str() {
+                            result.failed_ids.push(id.to_string());
+                        }
                         if let Some(error) = index_result.get("error") {
                             result.errors.push(error.to_string());
                         }
@@ -451,7 +842,7 @@ impl VectorStore {
             "knn": {
                 "embedding": {
                     "vector": query_vector,
-                    "k": config.k
+                    "k": config.fetch_size()
                 }
             }
         });
@@ -536,8 +927,8 @@ impl VectorStore {
 
     /// Hybrid search combining k-NN and BM25 using OpenSearch native hybrid query.
     ///
-    /// Uses the `hybrid-pipeline` search pipeline for score normalization
-    /// (min_max normalization + arithmetic_mean combination with configurable weights).
+    /// Scores are combined by a temporary search pipeline sent with the
+    /// request (see [`hybrid_pipeline`]), weighted by `config.bm25_weight`.
     pub async fn search_hybrid(
         &self,
         query_text: &str,
@@ -560,7 +951,7 @@ impl VectorStore {
             "knn": {
                 "embedding": {
                     "vector": query_vector,
-                    "k": config.k
+                    "k": config.fetch_size()
                 }
             }
         });
@@ -594,26 +985,87 @@ impl VectorStore {
                     "queries": [bm25_query, knn_query]
                 }
             },
-            "size": config.k
+            "search_pipeline": hybrid_pipeline(config.bm25_weight)
         });
 
-        self.execute_search_with_pipeline(query, config, "hybrid-pipeline")
+        self.execute_search(query, config)
             .await
+            .context("Hybrid search failed")
+    }
+
+    /// Search a window of candidates, then rerank them
+    ///
+    /// RRF fuses a BM25 and a k-NN search (BM25 alone without a query
+    /// vector); the cross-encoder reorders hybrid candidates, or BM25 ones
+    /// without a query vector. The offset and `k` of `config` apply to the
+    /// reranked list.
+    pub async fn search_reranked(
+        &self,
+        query_text: &str,
+        query_vector: Option<&[f32]>,
+        config: &SearchConfig,
+        rerank_config: &RerankConfig,
+    ) -> Result<Vec<SearchResult>> {
+        let candidates = SearchConfig {
+            k: rerank_config.window.max(config.offset + config.k),
+            offset: 0,
+            ..config.clone()
+        };
+
+        let reranked = match rerank_config.method {
+            RerankMethod::Rrf => {
+                let mut lists = vec![self.search_bm25(query_text, &candidates).await?];
+                if let Some(query_vector) = query_vector {
+                    lists.push(self.search_knn(query_vector, &candidates).await?);
+                }
+                rerank::reciprocal_rank_fusion(lists, rerank_config.rrf_k)
+            }
+            RerankMethod::CrossEncoder => {
+                // The cross-encoder reads each candidate's best passage
+                let candidates = SearchConfig {
+                    passage_query: Some(query_text.to_string()),
+                    ..candidates
+                };
+                let results = match query_vector {
+                    Some(query_vector) => {
+                        self.search_hybrid(query_text, query_vector, &candidates)
+                            .await?
+                    }
+                    None => self.search_bm25(query_text, &candidates).await?,
+                };
+                let client = reqwest::Client::builder()
+                    .timeout(Duration::from_secs(60))
+                    .build()
+                    .context("Failed to build reranker HTTP client")?;
+                rerank::rerank_remote(&client, rerank_config, query_text, results).await?
+            }
+        };
+        Ok(finish_results(reranked, config))
     }
 
     /// Prepare query with common search options (source fields, highlights, min_score)
     fn prepare_search_query(query: &mut Value, config: &SearchConfig) {
-        query["size"] = json!(config.k);
+        query["size"] = json!(config.fetch_size());
 
         query["_source"] = json!([
-            "id", "title", "content", "category", "publisher", "url", "published_at"
+            "id",
+            "title",
+            "content",
+            "category",
+            "publisher",
+            "url",
+            "published_at",
+            "story_id",
+            "chunk_index",
+            "chunk_text"
         ]);
 
         if config.include_highlights {
             query["highlight"] = json!({
                 "fields": {
                     "title": { "number_of_fragments": 1 },
-                    "content": { "number_of_fragments": 3, "fragment_size": 150 }
+                    "content": { "number_of_fragments": 3, "fragment_size": 150 },
+                    "chunk_text": { "number_of_fragments": 1, "fragment_size": 150 }
                 },
                 "pre_tags": ["<mark>"],
                 "post_tags": ["</mark>"]
@@ -626,7 +1078,10 @@ impl VectorStore {
     }
 
     /// Parse search hits from OpenSearch response body
-    fn parse_search_hits(response_body: &Value) -> Vec<SearchResult> {
+    ///
+    /// Passages are picked from the full content, before it is truncated;
+    /// a hit of a chunked document takes its passage from its chunk.
+    fn parse_search_hits(response_body: &Value, config: &SearchConfig) -> Vec<SearchResult> {
         let mut results = Vec::new();
 
         if let Some(hits) = response_body["hits"]["hits"].as_array() {
@@ -641,10 +1096,12 @@ impl VectorStore {
                             }
                         }
                     }
-                    if let Some(content_highlights) = h["content"].as_array() {
-                        for hl in content_highlights {
-                            if let Some(s) = hl.as_str() {
-                                snippets.push(s.to_string());
+                    for field in ["content", "chunk_text"] {
+                        if let Some(content_highlights) = h[field].as_array() {
+                            for hl in content_highlights {
+                                if let Some(s) = hl.as_str() {
+                                    snippets.push(s.to_string());
+                                }
                             }
                         }
                     }
@@ -655,19 +1112,33 @@ impl VectorStore {
                     }
                 });
 
+                let chunk_text = source["chunk_text"].as_str();
+                // Later chunks of an article store only their own text
+                let content = match source["content"].as_str() {
+                    Some(content) if !content.is_empty() => content,
+                    _ => chunk_text.unwrap_or_default(),
+                };
+                let passage = config.passage_query.as_deref().and_then(|query| {
+                    match (chunk_text, source["chunk_index"].as_i64()) {
+                        (Some(chunk), Some(index)) => {
+                            passage::best_chunk_passage(content, chunk, index as i32, query)
+                        }
+                        _ => passage::best_passage(content, query),
+                    }
+                });
+
                 results.push(SearchResult {
                     id: source["id"].as_str().unwrap_or_default().to_string(),
                     score: hit["_score"].as_f64().unwrap_or(0.0) as f32,
                     title: source["title"].as_str().unwrap_or_default().to_string(),
-                    content: source["content"]
-                        .as_str()
-                        .map(|s| truncate_string(s, 500))
-                        .unwrap_or_default(),
+                    content: truncate_string(content, 500),
                     category: source["category"].as_str().unwrap_or_default().to_string(),
                     publisher: source["publisher"].as_str().map(String::from),
                     url: source["url"].as_str().unwrap_or_default().to_string(),
                     published_at: source["published_at"].as_str().map(String::from),
                     highlights,
+                    passage,
+                    story_id: source["story_id"].as_str().map(String::from),
                 });
             }
         }
@@ -703,73 +1174,130 @@ impl VectorStore {
             anyhow::bail!("Search failed ({}): {}", status.as_u16(), error_msg);
         }
 
-        Ok(Self::parse_search_hits(&response_body))
+        let results = Self::parse_search_hits(&response_body, config);
+        Ok(finish_results(results, config))
     }
 
-    /// Execute search query with a search pipeline and parse results.
-    ///
-    /// The opensearch-rs crate (2.3) does not expose a `search_pipeline` query
-    /// parameter, so we call the low-level transport directly.
-    async fn execute_search_with_pipeline(
-        &self,
-        mut query: Value,
-        config: &SearchConfig,
-        pipeline: &str,
-    ) -> Result<Vec<SearchResult>> {
-        Self::prepare_search_query(&mut query, config);
+    /// Delete documents by query
+    pub async fn delete_by_query(&self, field: &str, value: &str) -> Result<usize> {
+        let query = json!({
+            "query": {
+                "term": {
+                    field: value
+                }
+            }
+        });
 
-        let path = format!("/{}/_search", self.index_name);
+        let response = self
+            .client
+            .delete_by_query(DeleteByQueryParts::Index(&[&self.index_name]))
+            .body(query)
+            .send()
+            .await
+            .context("Failed to delete by query")?;
 
-        #[derive(Serialize)]
-        struct PipelineQueryParams<'a> {
-            search_pipeline: &'a str,
-        }
-        let params = PipelineQueryParams {
-            search_pipeline: pipeline,
-        };
+        let response_body: Value = response.json().await?;
+        let deleted = response_body["deleted"].as_u64().unwrap_or(0) as usize;
 
-        let body: JsonBody<Value> = query.into();
+        Ok(deleted)
+    }
+
+    /// Fetch an indexed document by ID
+    ///
+    /// Returns `None` if no document has the ID.
+    pub async fn get_document(&self, id: &str) -> Result<Option<IndexDocument>> {
         let response = self
             .client
-            .send(
-                Method::Post,
-                &path,
-                HeaderMap::new(),
-                Some(&params),
-                Some(body),
-                None,
-            )
+            .get(GetParts::IndexId(&self.index_name, id))
+            .send()
             .await
-            .context("Failed to execute hybrid search with pipeline")?;
+            .context("Failed to fetch document")?;
 
         let status = response.status_code();
-        let response_body: Value = response
-            .json()
-            .await
-            .context("Failed to parse hybrid search response")?;
-
+        if status.as_u16() == 404 {
+            return Ok(None);
+        }
+        let response_body: Value = response.json().await?;
         if !status.is_success() {
-            let error_msg = response_body["error"]
-                .as_object()
-                .and_then(|e| e.get("reason"))
-                .and_then(|r| r.as_str())
-                .unwrap_or("Unknown error");
             anyhow::bail!(
-                "Hybrid search failed ({}): {}",
+                "Document fetch failed ({}): {}",
                 status.as_u16(),
-                error_msg
+                response_body
             );
         }
+        if response_body["found"].as_bool() != Some(true) {
+            return Ok(None);
+        }
 
-        Ok(Self::parse_search_hits(&response_body))
+        let doc = serde_json::from_value(response_body["_source"].clone())
+            .context("Failed to parse indexed document")?;
+        Ok(Some(doc))
     }
 
-    /// Delete documents by query
-    pub async fn delete_by_query(&self, field: &str, value: &str) -> Result<usize> {
+    /// Find articles similar to an indexed document
+    ///
+    /// Uses the document's embedding for k-NN search, or BM25 on its title if
+    /// it only has a placeholder embedding. The document itself is excluded.
+    /// Returns `None` if no document has the ID.
+    pub async fn search_similar(
+        &self,
+        id: &str,
+        config: &SearchConfig,
+    ) -> Result<Option<Vec<SearchResult>>> {
+        let Some(doc) = self.get_document(id).await? else {
+            return Ok(None);
+        };
+
+        // One extra hit in case the document itself is returned
+        let mut fetch_config = config.clone();
+        fetch_config.k += 1;
+
+        let results = if doc.embedding.iter().all(|v| *v == 0.0) {
+            self.search_bm25(&doc.title, &fetch_config).await?
+        } else {
+            self.search_knn(&doc.embedding, &fetch_config).await?
+        };
+
+        let mut results: Vec<SearchResult> =
+            results.into_iter().filter(|r| r.id != doc.id).collect();
+        results.truncate(config.k);
+        Ok(Some(results))
+    }
+
+    /// Summarize the indexed articles by category, publisher and date
+    pub async fn index_stats(&self, top_publishers: usize) -> Result<IndexStats> {
+        let response = self
+            .raw_search(&index_stats_query(top_publishers))
+            .await
+            .context("Failed to aggregate index stats")?;
+        Ok(IndexStats::from_response(&response))
+    }
+
+    /// Newest `crawled_at` in the index, i.e. how far indexing has caught up
+    pub async fn latest_crawled_at(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let query = json!({
+            "size": 0,
+            "aggs": { "latest": { "max": { "field": "crawled_at" } } }
+        });
+        let response = self
+            .raw_search(&query)
+            .await
+            .context("Failed to query the latest crawl time")?;
+        Ok(response["aggregations"]["latest"]["value_as_string"]
+            .as_str()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&chrono::Utc)))
+    }
+
+    /// Delete the later chunks (`{id}#n`) of the articles `ids`
+    async fn delete_chunks(&self, ids: &[&str]) -> Result<usize> {
         let query = json!({
             "query": {
-                "term": {
-                    field: value
+                "bool": {
+                    "filter": [
+                        { "terms": { "id": ids } },
+                        { "range": { "chunk_index": { "gt": 0 } } }
+                    ]
                 }
             }
         });
@@ -780,12 +1308,55 @@ impl VectorStore {
             .body(query)
             .send()
             .await
-            .context("Failed to delete by query")?;
+            .context("Failed to delete stale chunks")?;
 
+        let status = response.status_code();
         let response_body: Value = response.json().await?;
-        let deleted = response_body["deleted"].as_u64().unwrap_or(0) as usize;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Stale chunk deletion failed ({}): {}",
+                status.as_u16(),
+                response_body
+            );
+        }
+        Ok(response_body["deleted"].as_u64().unwrap_or(0) as usize)
+    }
 
-        Ok(deleted)
+    /// Whether any article in the index was split into chunks
+    ///
+    /// The answer is counted at most once per [`HAS_CHUNKS_TTL`], as every
+    /// search asks.
+    pub async fn has_chunks(&self) -> Result<bool> {
+        let cached = *self
+            .has_chunks
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some((counted_at, has_chunks)) = cached {
+            if counted_at.elapsed() < HAS_CHUNKS_TTL {
+                return Ok(has_chunks);
+            }
+        }
+
+        let response = self
+            .client
+            .count(opensearch::CountParts::Index(&[&self.index_name]))
+            .body(json!({ "query": { "range": { "chunk_index": { "gt": 0 } } } }))
+            .send()
+            .await
+            .context("Failed to count chunk documents")?;
+
+        let response_body: Value = response.json().await?;
+        let has_chunks = response_body["count"].as_u64().unwrap_or(0) > 0;
+        self.set_has_chunks(has_chunks);
+        Ok(has_chunks)
+    }
+
+    fn set_has_chunks(&self, has_chunks: bool) {
+        *self
+            .has_chunks
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) =
+            Some((std::time::Instant::now(), has_chunks));
     }
 
     /// Get document count
@@ -851,6 +1422,61 @@ impl VectorStore {
     }
 }
 
+/// Dimension of the `embedding` field in a `/<index>/_mapping` body
+///
+/// The body is keyed by the concrete index name, which differs from the
+/// configured one when it is an alias.
+fn mapped_embedding_dimension(body: &Value) -> Option<usize> {
+    body.as_object()?
+        .values()
+        .next()?
+        .pointer("/mappings/properties/embedding/dimension")?
+        .as_u64()
+        .map(|dim| dim as usize)
+}
+
+/// Merge chunk hits, then apply story collapsing, the offset and the result
+/// limit to parsed hits
+fn finish_results(results: Vec<SearchResult>, config: &SearchConfig) -> Vec<SearchResult> {
+    let results = merge_chunk_hits(results);
+    let results = if config.collapse_by_story {
+        collapse_by_story(results)
+    } else {
+        results
+    };
+    results
+        .into_iter()
+        .skip(config.offset)
+        .take(config.k)
+        .collect()
+}
+
+/// Keep the first (best-scoring) hit of each article
+///
+/// Chunks of an article share its ID, so the article is ranked by its
+/// best-matching chunk, whose passage it keeps. Input order is preserved.
+pub fn merge_chunk_hits(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut seen = std::collections::HashSet::new();
+    results
+        .into_iter()
+        .filter(|result| seen.insert(result.id.clone()))
+        .collect()
+}
+
+/// Keep the first (best-scoring) result of each story
+///
+/// Results without a story ID are always kept. Input order is preserved.
+pub fn collapse_by_story(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let mut seen = std::collections::HashSet::new();
+    results
+        .into_iter()
+        .filter(|result| match &result.story_id {
+            Some(story_id) => seen.insert(story_id.clone()),
+            None => true,
+        })
+        .collect()
+}
+
 /// Helper to truncate string at word boundary
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -869,6 +1495,9 @@ fn truncate_string(s: &str, max_len: usize) -> String {
 }
 
 /// Convert ParsedArticle to IndexDocument
+///
+/// Comment, media and reaction counts are only set when the article has
+/// any, as an empty list usually means they were not collected.
 pub fn article_to_document(
     article: &ParsedArticle,
     embedding: Vec<f32>,
@@ -876,7 +1505,8 @@ pub fn article_to_document(
     chunk_text: Option<String>,
 ) -> IndexDocument {
     IndexDocument {
-        id: format!("{}_{}", article.oid, article.aid),
+        id: article.id(),
+        source: article.source,
         oid: article.oid.clone(),
         aid: article.aid.clone(),
         title: article.title.clone(),
@@ -887,13 +1517,23 @@ pub fn article_to_document(
         url: article.url.clone(),
         published_at: article.published_at.map(|dt| dt.to_rfc3339()),
         crawled_at: chrono::Utc::now().to_rfc3339(),
-        comment_count: None, // Comment count not stored in ParsedArticle
+        comment_count: count(article.comment_count()),
+        image_count: count(article.images.len()),
+        video_count: count(article.videos.len()),
+        reaction_count: (!article.reactions.is_empty())
+            .then(|| i64::try_from(article.reaction_count()).unwrap_or(i64::MAX)),
         embedding,
         chunk_index,
         chunk_text,
+        story_id: None,
     }
 }
 
+/// A non-zero count as stored in the index
+fn count(n: usize) -> Option<i32> {
+    (n > 0).then(|| i32::try_from(n).unwrap_or(i32::MAX))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -906,6 +1546,84 @@ mod tests {
         assert!((config.bm25_weight - 0.3).abs() < 0.001);
     }
 
+    #[test]
+    fn test_hybrid_pipeline_weights() {
+        let pipeline = hybrid_pipeline(0.25);
+        let weights = &pipeline["phase_results_processors"][0]["normalization-processor"]
+            ["combination"]["parameters"]["weights"];
+        assert_eq!(weights, &json!([0.25, 0.75]));
+
+        let clamped = hybrid_pipeline(1.5);
+        assert_eq!(
+            clamped["phase_results_processors"][0]["normalization-processor"]["combination"]
+                ["parameters"]["weights"],
+            json!([1.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_analysis_settings() {
+        let plain = analysis_settings(&Vocabulary::default());
+        assert!(plain["tokenizer"]["nori_mixed"]
+            .get("user_dictionary_rules")
+            .is_none());
+        assert_eq!(
+            plain["analyzer"]["nori_search_analyzer"]["filter"],
+            json!(["lowercase", "nori_posfilter"])
+        );
+
+        let vocabulary =
+            Vocabulary::parse("관계자", "세종시 세종 시", "금융감독원, 금감원").unwrap();
+        let settings = analysis_settings(&vocabulary);
+        assert_eq!(
+            settings["tokenizer"]["nori_mixed"]["user_dictionary_rules"],
+            json!(["세종시 세종 시"])
+        );
+        assert_eq!(
+            settings["analyzer"]["nori_analyzer"]["filter"],
+            json!([
+                "lowercase",
+                "nori_posfilter",
+                "nori_readingform",
+                "baram_stopwords"
+            ])
+        );
+        assert_eq!(
+            settings["analyzer"]["nori_search_analyzer"]["filter"],
+            json!([
+                "lowercase",
+                "nori_posfilter",
+                "baram_synonyms",
+                "baram_stopwords"
+            ])
+        );
+        assert_eq!(
+            settings["filter"]["baram_synonyms"]["synonyms"],
+            json!(["금융감독원, 금감원"])
+        );
+        assert_eq!(
+            settings["filter"]["baram_stopwords"]["stopwords"],
+            json!(["관계자"])
+        );
+    }
+
+    #[test]
+    fn test_mapped_embedding_dimension() {
+        let body = json!({
+            "baram-articles-v2": {
+                "mappings": { "properties": {
+                    "embedding": { "type": "knn_vector", "dimension": 1024 }
+                } }
+            }
+        });
+        assert_eq!(mapped_embedding_dimension(&body), Some(1024));
+        assert_eq!(
+            mapped_embedding_dimension(&json!({ "articles": { "mappings": {} } })),
+            None
+        );
+        assert_eq!(mapped_embedding_dimension(&json!({})), None);
+    }
+
     #[test]
     fn test_truncate_string() {
         let s = "This is a test string that is quite long";
@@ -933,6 +1651,7 @@ mod tests {
     fn test_index_document_serialization() {
         let doc = IndexDocument {
             id: "001_001".to_string(),
+            source: ArticleSource::Naver,
             oid: "001".to_string(),
             aid: "001".to_string(),
             title: "Test Article".to_string(),
@@ -944,15 +1663,66 @@ mod tests {
             published_at: None,
             crawled_at: "2024-01-01T00:00:00Z".to_string(),
             comment_count: Some(10),
+            image_count: None,
+            video_count: None,
+            reaction_count: None,
             embedding: vec![0.1, 0.2, 0.3],
             chunk_index: None,
             chunk_text: None,
+            story_id: None,
         };
 
         let json = serde_json::to_string(&doc).unwrap();
         assert!(json.contains("Test Article"));
     }
 
+    #[test]
+    fn test_chunk_documents_ids() {
+        let doc = IndexDocument {
+            id: "001_001".to_string(),
+            source: ArticleSource::Naver,
+            oid: "001".to_string(),
+            aid: "001".to_string(),
+            title: "Test Article".to_string(),
+            content: "첫 문단. 둘째 문단.".to_string(),
+            category: "politics".to_string(),
+            publisher: None,
+            author: None,
+            url: "https://example.com".to_string(),
+            published_at: None,
+            crawled_at: "2024-01-01T00:00:00Z".to_string(),
+            comment_count: None,
+            image_count: None,
+            video_count: None,
+            reaction_count: None,
+            embedding: vec![],
+            chunk_index: None,
+            chunk_text: None,
+            story_id: None,
+        };
+        assert_eq!(doc.doc_id(), "001_001");
+
+        let chunks: Vec<TextChunk> = ["첫 문단.", "둘째 문단."]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| TextChunk {
+                text: text.to_string(),
+                token_count: 3,
+                chunk_index: i,
+                total_chunks: 2,
+                start_pos: 0,
+                end_pos: 0,
+            })
+            .collect();
+        let docs = doc.chunk_documents(&chunks);
+        let ids: Vec<String> = docs.iter().map(IndexDocument::doc_id).collect();
+        assert_eq!(ids, ["001_001", "001_001#1"]);
+        assert_eq!(docs[1].id, "001_001");
+        assert_eq!(docs[1].chunk_text.as_deref(), Some("둘째 문단."));
+        assert_eq!(docs[0].content, doc.content);
+        assert_eq!(docs[1].content, "");
+    }
+
     #[test]
     fn test_article_to_document() {
         let article = ParsedArticle {
@@ -971,6 +1741,203 @@ mod tests {
         assert_eq!(doc.id, "001_002");
         assert_eq!(doc.title, "Test");
         assert_eq!(doc.embedding.len(), 3);
+        assert_eq!(doc.comment_count, None);
+
+        let mut article = article;
+        article.images.push(crate::models::ArticleMedia::default());
+        article.reactions.insert("like".to_string(), 4);
+        article.reactions.insert("angry".to_string(), 1);
+        let doc = article_to_document(&article, vec![], None, None);
+        assert_eq!(doc.image_count, Some(1));
+        assert_eq!(doc.video_count, None);
+        assert_eq!(doc.reaction_count, Some(5));
+    }
+
+    fn story_result(id: &str, story_id: Option<&str>) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            score: 1.0,
+            title: id.to_string(),
+            content: String::new(),
+            category: "politics".to_string(),
+            publisher: None,
+            url: String::new(),
+            published_at: None,
+            highlights: None,
+            passage: None,
+            story_id: story_id.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_collapse_by_story() {
+        let results = vec![
+            story_result("a", Some("story_1")),
+            story_result("b", None),
+            story_result("c", Some("story_1")),
+            story_result("d", Some("story_2")),
+            story_result("e", None),
+        ];
+
+        let ids: Vec<String> = collapse_by_story(results)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, ["a", "b", "d", "e"]);
+    }
+
+    #[test]
+    fn test_finish_results_limits_after_collapse() {
+        let config = SearchConfig {
+            k: 2,
+            collapse_by_story: true,
+            ..Default::default()
+        };
+        assert_eq!(config.fetch_size(), 6);
+
+        let results = vec![
+            story_result("a", Some("story_1")),
+            story_result("b", Some("story_1")),
+            story_result("c", Some("story_2")),
+            story_result("d", Some("story_3")),
+        ];
+        let ids: Vec<String> = finish_results(results, &config)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, ["a", "c"]);
+    }
+
+    #[test]
+    fn test_finish_results_offset() {
+        let config = SearchConfig {
+            k: 2,
+            offset: 1,
+            collapse_by_story: true,
+            ..Default::default()
+        };
+        assert_eq!(config.fetch_size(), 9);
+
+        let results = vec![
+            story_result("a", Some("story_1")),
+            story_result("b", Some("story_1")),
+            story_result("c", Some("story_2")),
+            story_result("d", None),
+            story_result("e", Some("story_3")),
+        ];
+        let ids: Vec<String> = finish_results(results, &config)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, ["c", "d"]);
+    }
+
+    #[test]
+    fn test_finish_results_merges_chunks() {
+        let config = SearchConfig {
+            k: 2,
+            chunked: true,
+            ..Default::default()
+        };
+        assert_eq!(config.fetch_size(), 6);
+
+        let results = vec![
+            story_result("a", None),
+            story_result("a", None),
+            story_result("b", None),
+            story_result("a", None),
+            story_result("c", None),
+        ];
+        let ids: Vec<String> = finish_results(results, &config)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_parse_search_hits_passages() {
+        let body = json!({ "hits": { "hits": [
+            { "_score": 2.0, "_source": {
+                "id": "001_0000000001",
+                "title": "금리 동결",
+                "content": "한국은행이 기준금리를 동결했다. 물가가 높다."
+            } },
+            { "_score": 1.0, "_source": {
+                "id": "001_0000000002",
+                "title": "물가",
+                "content": "서론이다. 물가 상승세가 이어졌다. 결론이다.",
+                "chunk_index": 1,
+                "chunk_text": "물가 상승세가 이어졌다. 결론이다."
+            } },
+            { "_score": 0.5, "_source": {
+                "id": "001_0000000003",
+                "title": "물가",
+                "content": "",
+                "chunk_index": 2,
+                "chunk_text": "물가 안정세가 이어졌다."
+            } }
+        ] } });
+
+        let results = VectorStore::parse_search_hits(&body, &SearchConfig::default());
+        assert!(results.iter().all(|r| r.passage.is_none()));
+
+        let config = SearchConfig {
+            passage_query: Some("물가".to_string()),
+            ..Default::default()
+        };
+        let results = VectorStore::parse_search_hits(&body, &config);
+        let passage = results[0].passage.as_ref().unwrap();
+        assert_eq!(passage.start, 0);
+        assert_eq!(passage.chunk_index, None);
+
+        let passage = results[1].passage.as_ref().unwrap();
+        assert_eq!(passage.chunk_index, Some(1));
+        assert_eq!(passage.start, 6);
+        assert!(passage.text.starts_with("물가 상승세가"));
+
+        // Later chunks store only their own text
+        assert_eq!(results[2].content, "물가 안정세가 이어졌다.");
+        let passage = results[2].passage.as_ref().unwrap();
+        assert_eq!(passage.chunk_index, Some(2));
+        assert_eq!(passage.start, 0);
+    }
+
+    #[test]
+    fn test_index_stats_from_response() {
+        let body = json!({
+            "hits": { "total": { "value": 120, "relation": "eq" } },
+            "aggregations": {
+                "categories": { "buckets": [
+                    { "key": "politics", "doc_count": 80 },
+                    { "key": "economy", "doc_count": 40 }
+                ] },
+                "publishers": { "buckets": [{ "key": "연합뉴스", "doc_count": 30 }] },
+                "stories": { "value": 45 },
+                "earliest": { "value": 1.0, "value_as_string": "2026-02-01T00:00:00.000Z" },
+                "latest": { "value": 2.0, "value_as_string": "2026-02-15T09:00:00.000Z" }
+            }
+        });
+
+        let stats = IndexStats::from_response(&body);
+        assert_eq!(stats.total_documents, 120);
+        assert_eq!(stats.stories, 45);
+        assert_eq!(stats.categories.len(), 2);
+        assert_eq!(
+            stats.publishers[0],
+            TermCount {
+                key: "연합뉴스".to_string(),
+                count: 30
+            }
+        );
+        assert_eq!(
+            stats.latest_published_at.as_deref(),
+            Some("2026-02-15T09:00:00.000Z")
+        );
+
+        let empty = IndexStats::from_response(&json!({}));
+        assert_eq!(empty.total_documents, 0);
+        assert!(empty.categories.is_empty());
     }
 
     // Integration tests require running OpenSearch
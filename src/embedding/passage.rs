@@ -0,0 +1,187 @@
+//! Best-matching passages of search results
+//!
+//! A passage is a run of whole sentences of an article, at most
+//! [`MAX_PASSAGE_CHARS`] long, that covers the most query terms. Korean terms
+//! usually carry particles (`금리를`, `금리는`), so a term missing from a
+//! passage still scores by the share of its character bigrams it contains.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::tokenizer::split_sentences;
+
+/// Longest passage picked, in characters (a single longer sentence is kept whole)
+pub const MAX_PASSAGE_CHARS: usize = 300;
+
+/// Passage of an article, located by character offsets into its content
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Passage {
+    /// Passage text
+    pub text: String,
+
+    /// Offset of the first character in the article content
+    pub start: usize,
+
+    /// Offset past the last character in the article content
+    pub end: usize,
+
+    /// Index of the chunk the passage was taken from (chunked documents)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_index: Option<i32>,
+}
+
+/// Passage of `content` that best matches `query`
+///
+/// Returns `None` if no passage shares a term or bigram with the query, e.g.
+/// for results found by vector similarity alone. Ties go to the earlier
+/// passage.
+#[must_use]
+pub fn best_passage(content: &str, query: &str) -> Option<Passage> {
+    let terms = query_terms(query);
+    if terms.is_empty() {
+        return None;
+    }
+
+    // Byte ranges of the sentences, which are contiguous slices of `content`
+    let base = content.as_ptr() as usize;
+    let sentences: Vec<(usize, usize)> = split_sentences(content)
+        .into_iter()
+        .map(|s| {
+            let start = s.as_ptr() as usize - base;
+            (start, start + s.len())
+        })
+        .collect();
+
+    let mut best: Option<(f32, usize, usize)> = None;
+    for (i, &(start, _)) in sentences.iter().enumerate() {
+        let mut end = sentences[i].1;
+        for &(_, next_end) in &sentences[i + 1..] {
+            if content[start..next_end].trim().chars().count() > MAX_PASSAGE_CHARS {
+                break;
+            }
+            end = next_end;
+        }
+
+        let score = score(&content[start..end].to_lowercase(), &terms);
+        if score > 0.0 && best.map_or(true, |(top, _, _)| score > top) {
+            best = Some((score, start, end));
+        }
+    }
+
+    let (_, start, end) = best?;
+    let text = &content[start..end];
+    let start = start + (text.len() - text.trim_start().len());
+    let end = end - (text.len() - text.trim_end().len());
+    Some(Passage {
+        text: content[start..end].to_string(),
+        start: content[..start].chars().count(),
+        end: content[..end].chars().count(),
+        chunk_index: None,
+    })
+}
+
+/// Best passage of a chunk of `content`, with offsets into `content`
+///
+/// Falls back to the whole content if the chunk text is not found in it.
+#[must_use]
+pub fn best_chunk_passage(
+    content: &str,
+    chunk_text: &str,
+    chunk_index: i32,
+    query: &str,
+) -> Option<Passage> {
+    let Some(offset) = content.find(chunk_text) else {
+        return best_passage(content, query);
+    };
+    let offset = content[..offset].chars().count();
+    best_passage(chunk_text, query).map(|passage| Passage {
+        start: passage.start + offset,
+        end: passage.end + offset,
+        chunk_index: Some(chunk_index),
+        ..passage
+    })
+}
+
+/// Distinct lowercase query terms, without punctuation
+fn query_terms(query: &str) -> Vec<Vec<char>> {
+    let mut terms: Vec<Vec<char>> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric()) {
+        let term: Vec<char> = word.to_lowercase().chars().collect();
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Whole terms count 1, others the share of their bigrams found in `text`
+fn score(text: &str, terms: &[Vec<char>]) -> f32 {
+    terms
+        .iter()
+        .map(|term| {
+            let word: String = term.iter().collect();
+            if text.contains(&word) {
+                return 1.0;
+            }
+            let bigrams = term.windows(2).count();
+            if bigrams == 0 {
+                return 0.0;
+            }
+            let found = term
+                .windows(2)
+                .filter(|pair| text.contains(&pair.iter().collect::<String>()))
+                .count();
+            // Stay below a whole-term match
+            0.9 * found as f32 / bigrams as f32
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = "한국은행이 기준금리를 동결했다. 물가 상승세가 여전히 높다는 판단이다. \
+                           시장은 하반기 인하를 예상한다.";
+
+    #[test]
+    fn test_best_passage_offsets() {
+        let content = format!("{ARTICLE}\n\n{}", "다른 이야기. ".repeat(40));
+        let passage = best_passage(&content, "물가 상승").unwrap();
+        assert!(passage.text.contains("물가 상승세가"));
+        assert!(passage.text.chars().count() <= MAX_PASSAGE_CHARS);
+
+        let chars: Vec<char> = content.chars().collect();
+        let located: String = chars[passage.start..passage.end].iter().collect();
+        assert_eq!(located, passage.text);
+        assert_eq!(passage.start, 0);
+    }
+
+    #[test]
+    fn test_best_passage_picks_matching_sentences() {
+        let content = format!("{}{ARTICLE}", "관련 없는 문장입니다. ".repeat(30));
+        let passage = best_passage(&content, "기준금리 인하 전망").unwrap();
+        let chars: Vec<char> = content.chars().collect();
+        let located: String = chars[passage.start..passage.end].iter().collect();
+        assert_eq!(located, passage.text);
+        assert!(passage.text.contains("기준금리를 동결했다"));
+
+        // Particles: "금리는" shares the bigram "금리" with the content
+        assert!(best_passage(ARTICLE, "금리는").is_some());
+        assert!(best_passage(ARTICLE, "축구").is_none());
+        assert!(best_passage(ARTICLE, "  ").is_none());
+    }
+
+    #[test]
+    fn test_best_chunk_passage() {
+        let content = format!("서론 문장입니다. {ARTICLE}");
+        let passage = best_chunk_passage(&content, ARTICLE, 1, "물가").unwrap();
+        assert_eq!(passage.chunk_index, Some(1));
+        let chars: Vec<char> = content.chars().collect();
+        let located: String = chars[passage.start..passage.end].iter().collect();
+        assert_eq!(located, passage.text);
+
+        let missing = best_chunk_passage(&content, "없는 청크", 2, "물가").unwrap();
+        assert_eq!(missing.chunk_index, None);
+    }
+}
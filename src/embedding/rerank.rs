@@ -0,0 +1,384 @@
+//! Second-stage reranking of search results
+//!
+//! The first stage fetches a window of candidates from OpenSearch; a
+//! reranker then reorders them before the top `k` are returned.
+//!
+//! - [`reciprocal_rank_fusion`] merges the BM25 and k-NN lists by rank alone,
+//!   so scores on different scales need no normalization
+//! - [`CrossEncoder`] reads the query and each candidate together and scores
+//!   their relevance; the embedding server loads one with `--rerank-model`
+//!   and serves it at `POST /rerank`, which [`rerank_remote`] calls
+
+use anyhow::{bail, Context, Result};
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::xlm_roberta::{
+    Config as XLMRobertaConfig, XLMRobertaForSequenceClassification,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokenizers::Tokenizer;
+
+use super::vectorize::hub_repo;
+use super::SearchResult;
+
+/// Characters of each candidate the cross-encoder reads
+const RERANK_TEXT_CHARS: usize = 1000;
+
+/// How search candidates are reordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RerankMethod {
+    /// Reciprocal rank fusion of the BM25 and k-NN lists
+    Rrf,
+    /// Cross-encoder scores from the embedding server
+    CrossEncoder,
+}
+
+impl RerankMethod {
+    /// Parse `rrf` or `cross-encoder`; `none` disables reranking
+    pub fn parse(s: &str) -> Result<Option<Self>> {
+        match s.to_lowercase().as_str() {
+            "none" | "off" => Ok(None),
+            "rrf" => Ok(Some(Self::Rrf)),
+            "cross-encoder" | "cross_encoder" | "ce" => Ok(Some(Self::CrossEncoder)),
+            other => bail!("Unknown rerank method: {other} (expected none, rrf or cross-encoder)"),
+        }
+    }
+}
+
+/// Reranking configuration
+#[derive(Debug, Clone)]
+pub struct RerankConfig {
+    /// Reranking method
+    pub method: RerankMethod,
+
+    /// Candidates fetched from each first-stage search
+    pub window: usize,
+
+    /// RRF rank constant; larger values flatten the weight of top ranks
+    pub rrf_k: f32,
+
+    /// Embedding server serving `POST /rerank`
+    pub server_url: String,
+
+    /// Reranker model to ask the server for (default: the one it loaded)
+    pub model: Option<String>,
+}
+
+impl RerankConfig {
+    /// Defaults for `method`, with the server from `EMBEDDING_SERVER_URL`
+    pub fn new(method: RerankMethod) -> Self {
+        Self {
+            method,
+            window: 50,
+            rrf_k: 60.0,
+            server_url: std::env::var("EMBEDDING_SERVER_URL")
+                .unwrap_or_else(|_| "http://localhost:8090".to_string()),
+            model: None,
+        }
+    }
+}
+
+/// Merge ranked lists by reciprocal rank fusion
+///
+/// Each result scores `1 / (rrf_k + rank)` in every list it appears in,
+/// summed, with ranks starting at 1. Ties keep the order in which results
+/// were first seen. A result keeps the fields of its first list, with
+/// highlights and passage filled in from later lists where missing.
+pub fn reciprocal_rank_fusion(lists: Vec<Vec<SearchResult>>, rrf_k: f32) -> Vec<SearchResult> {
+    let mut fused: Vec<(f32, SearchResult)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+
+    for list in lists {
+        for (rank, result) in list.into_iter().enumerate() {
+            let score = 1.0 / (rrf_k + rank as f32 + 1.0);
+            match positions.get(&result.id) {
+                Some(&i) => {
+                    let (total, existing) = &mut fused[i];
+                    *total += score;
+                    if existing.highlights.is_none() {
+                        existing.highlights = result.highlights;
+                    }
+                    if existing.passage.is_none() {
+                        existing.passage = result.passage;
+                    }
+                }
+                None => {
+                    positions.insert(result.id.clone(), fused.len());
+                    fused.push((score, result));
+                }
+            }
+        }
+    }
+
+    // Stable, so ties stay in first-seen order
+    fused.sort_by(|a, b| b.0.total_cmp(&a.0));
+    fused
+        .into_iter()
+        .map(|(score, mut result)| {
+            result.score = score;
+            result
+        })
+        .collect()
+}
+
+/// Text the cross-encoder reads for a result: its title and best passage
+pub fn rerank_text(result: &SearchResult) -> String {
+    let body = result
+        .passage
+        .as_ref()
+        .map_or(result.content.as_str(), |passage| passage.text.as_str());
+    format!("{} {}", result.title, body)
+        .chars()
+        .take(RERANK_TEXT_CHARS)
+        .collect()
+}
+
+/// Reorder `results` by `scores`, given in the same order, best first
+pub fn apply_scores(results: Vec<SearchResult>, scores: &[f32]) -> Result<Vec<SearchResult>> {
+    if results.len() != scores.len() {
+        bail!(
+            "Reranker returned {} scores for {} results",
+            scores.len(),
+            results.len()
+        );
+    }
+    let mut scored: Vec<(f32, SearchResult)> = scores.iter().copied().zip(results).collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    Ok(scored
+        .into_iter()
+        .map(|(score, mut result)| {
+            result.score = score;
+            result
+        })
+        .collect())
+}
+
+/// Request of `POST /rerank`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RerankRequest {
+    /// Search query
+    pub query: String,
+    /// Candidate texts
+    pub documents: Vec<String>,
+    /// Reranker model (default: the first the server loaded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+/// Response of `POST /rerank`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RerankResponse {
+    /// Relevance of each document, in request order
+    pub scores: Vec<f32>,
+}
+
+/// Rerank `results` with the cross-encoder of the embedding server
+pub async fn rerank_remote(
+    client: &reqwest::Client,
+    config: &RerankConfig,
+    query: &str,
+    results: Vec<SearchResult>,
+) -> Result<Vec<SearchResult>> {
+    if results.is_empty() {
+        return Ok(results);
+    }
+    let request = RerankRequest {
+        query: query.to_string(),
+        documents: results.iter().map(rerank_text).collect(),
+        model: config.model.clone(),
+    };
+    let url = format!("{}/rerank", config.server_url);
+    let response = client
+        .post(&url)
+        .json(&request)
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach the reranker at {url}"))?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!(
+            "Reranker returned {status}: {}",
+            response.text().await.unwrap_or_default()
+        );
+    }
+    let response: RerankResponse = response
+        .json()
+        .await
+        .context("Failed to parse reranker response")?;
+    apply_scores(results, &response.scores)
+}
+
+/// Cross-encoder scoring query-document pairs with an XLM-RoBERTa
+/// sequence classification model (e.g. the BGE rerankers)
+pub struct CrossEncoder {
+    model: XLMRobertaForSequenceClassification,
+    tokenizer: Tokenizer,
+    device: Device,
+    pad_token_id: u32,
+    max_seq_length: usize,
+    batch_size: usize,
+}
+
+impl CrossEncoder {
+    /// Load `model_id` from the HuggingFace Hub (or its cache)
+    pub fn from_pretrained(
+        model_id: &str,
+        max_seq_length: usize,
+        batch_size: usize,
+        use_gpu: bool,
+    ) -> Result<Self> {
+        let device = if use_gpu {
+            Device::cuda_if_available(0).unwrap_or(Device::Cpu)
+        } else {
+            Device::Cpu
+        };
+        tracing::info!(model = %model_id, "Loading reranker model");
+
+        let repo = hub_repo(model_id)?;
+        let tokenizer_path = repo
+            .get("tokenizer.json")
+            .context("Failed to download tokenizer")?;
+        let config_path = repo
+            .get("config.json")
+            .context("Failed to download config")?;
+        let weights_path = repo
+            .get("model.safetensors")
+            .context("Failed to download model weights")?;
+
+        let tokenizer = Tokenizer::from_file(&tokenizer_path)
+            .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {e}"))?;
+        let config: XLMRobertaConfig = serde_json::from_str(
+            &std::fs::read_to_string(&config_path).context("Failed to read config file")?,
+        )
+        .context("Failed to parse config")?;
+
+        // SAFETY: the weights file is not modified while the model is loaded,
+        // as for the embedding models
+        let vb = unsafe {
+            VarBuilder::from_mmaped_safetensors(&[weights_path], DType::F32, &device)
+                .context("Failed to load safetensors")?
+        };
+        let model = XLMRobertaForSequenceClassification::new(1, &config, vb)
+            .context("Failed to build reranker model")?;
+
+        Ok(Self {
+            model,
+            tokenizer,
+            device,
+            pad_token_id: config.pad_token_id,
+            max_seq_length: max_seq_length.min(config.max_position_embeddings - 2),
+            batch_size: batch_size.max(1),
+        })
+    }
+
+    /// Relevance of each document to `query`, between 0 and 1
+    pub fn score(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        let mut scores = Vec::with_capacity(documents.len());
+        for batch in documents.chunks(self.batch_size) {
+            scores.extend(self.score_batch(query, batch)?);
+        }
+        Ok(scores)
+    }
+
+    fn score_batch(&self, query: &str, documents: &[String]) -> Result<Vec<f32>> {
+        let encodings = documents
+            .iter()
+            .map(|document| {
+                self.tokenizer
+                    .encode((query, document.as_str()), true)
+                    .map_err(|e| anyhow::anyhow!("Tokenization failed: {e}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let max_len = encodings
+            .iter()
+            .map(|e| e.get_ids().len())
+            .max()
+            .unwrap_or(0)
+            .min(self.max_seq_length);
+        let batch_size = encodings.len();
+
+        let mut input_ids = Vec::with_capacity(batch_size * max_len);
+        let mut attention_mask = Vec::with_capacity(batch_size * max_len);
+        for encoding in &encodings {
+            let ids = encoding.get_ids();
+            let seq_len = ids.len().min(max_len);
+            input_ids.extend(ids.iter().take(seq_len).map(|&x| x as i64));
+            attention_mask.extend(std::iter::repeat(1i64).take(seq_len));
+
+            // XLM-RoBERTa derives positions from the non-padding tokens
+            let padding_len = max_len - seq_len;
+            input_ids.extend(std::iter::repeat(self.pad_token_id as i64).take(padding_len));
+            attention_mask.extend(std::iter::repeat(0i64).take(padding_len));
+        }
+
+        let input_ids = Tensor::from_vec(input_ids, (batch_size, max_len), &self.device)?;
+        let attention_mask = Tensor::from_vec(attention_mask, (batch_size, max_len), &self.device)?;
+        let token_type_ids = input_ids.zeros_like()?;
+
+        let logits = self
+            .model
+            .forward(&input_ids, &attention_mask, &token_type_ids)?;
+        let scores = candle_nn::ops::sigmoid(&logits.squeeze(1)?)?.to_vec1::<f32>()?;
+        Ok(scores)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(id: &str) -> SearchResult {
+        SearchResult {
+            id: id.to_string(),
+            score: 0.0,
+            title: id.to_string(),
+            content: String::new(),
+            category: "politics".to_string(),
+            publisher: None,
+            url: String::new(),
+            published_at: None,
+            highlights: None,
+            passage: None,
+            story_id: None,
+        }
+    }
+
+    #[test]
+    fn test_rerank_method_parse() {
+        assert_eq!(RerankMethod::parse("none").unwrap(), None);
+        assert_eq!(RerankMethod::parse("RRF").unwrap(), Some(RerankMethod::Rrf));
+        assert_eq!(
+            RerankMethod::parse("cross-encoder").unwrap(),
+            Some(RerankMethod::CrossEncoder)
+        );
+        assert!(RerankMethod::parse("bm25").is_err());
+    }
+
+    #[test]
+    fn test_reciprocal_rank_fusion() {
+        let mut highlighted = result("c");
+        highlighted.highlights = Some(vec!["<em>금리</em>".to_string()]);
+        let bm25 = vec![result("a"), result("b"), highlighted];
+        let knn = vec![result("c"), result("d"), result("a")];
+
+        let fused = reciprocal_rank_fusion(vec![bm25, knn], 60.0);
+        let ids: Vec<&str> = fused.iter().map(|r| r.id.as_str()).collect();
+        // a: 1/61 + 1/63, c: 1/63 + 1/61 (tie, a seen first), b: 1/62, d: 1/62
+        assert_eq!(ids, ["a", "c", "b", "d"]);
+        assert!((fused[0].score - (1.0 / 61.0 + 1.0 / 63.0)).abs() < 1e-6);
+        assert!(fused[1].highlights.is_some());
+    }
+
+    #[test]
+    fn test_apply_scores() {
+        let results = vec![result("a"), result("b"), result("c")];
+        let reranked = apply_scores(results, &[0.1, 0.9, 0.5]).unwrap();
+        let ids: Vec<&str> = reranked.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["b", "c", "a"]);
+        assert_eq!(reranked[0].score, 0.9);
+
+        assert!(apply_scores(vec![result("a")], &[]).is_err());
+    }
+}
@@ -291,7 +291,8 @@ impl TextTokenizer {
                 });
 
                 // Start new chunk with overlap
-                let overlap_text = get_overlap_text(&current_chunk, self.config.overlap_tokens);
+                let overlap_text =
+                    get_overlap_text(&current_chunk, current_tokens, self.config.overlap_tokens);
                 current_chunk = overlap_text;
                 current_tokens = self.count_tokens(&current_chunk)?;
                 chunk_start = chunk_end - current_chunk.len();
@@ -351,7 +352,7 @@ impl TextTokenizer {
 }
 
 /// Split text into sentences (simple implementation)
-fn split_sentences(text: &str) -> Vec<&str> {
+pub(crate) fn split_sentences(text: &str) -> Vec<&str> {
     let mut sentences = Vec::new();
     let mut start = 0;
 
@@ -384,11 +385,19 @@ fn split_sentences(text: &str) -> Vec<&str> {
 }
 
 /// Get overlap text from the end of a string (approximate by words)
-fn get_overlap_text(text: &str, _target_tokens: usize) -> String {
-    // Simple implementation: take last ~20% of words
+///
+/// Takes as many trailing words as make up `target_tokens`, estimated from
+/// the `text_tokens` the whole text has, and never more than half of it so
+/// chunks keep advancing.
+fn get_overlap_text(text: &str, text_tokens: usize, target_tokens: usize) -> String {
     let words: Vec<&str> = text.split_whitespace().collect();
-    let overlap_words = (words.len() / 5).max(1);
-    words[words.len().saturating_sub(overlap_words)..].join(" ")
+    if words.is_empty() || target_tokens == 0 {
+        return String::new();
+    }
+    let tokens_per_word = text_tokens.max(1) as f64 / words.len() as f64;
+    let overlap_words = ((target_tokens as f64 / tokens_per_word).ceil() as usize)
+        .clamp(1, (words.len() / 2).max(1));
+    words[words.len() - overlap_words..].join(" ")
 }
 
 #[cfg(test)]
@@ -425,9 +434,15 @@ mod tests {
     #[test]
     fn test_get_overlap_text() {
         let text = "one two three four five six seven eight nine ten";
-        let overlap = get_overlap_text(text, 2);
+        let overlap = get_overlap_text(text, 10, 2);
         assert!(!overlap.is_empty());
         assert!(overlap.contains("ten") || overlap.contains("nine"));
+        assert_eq!(overlap, "nine ten");
+
+        // Two tokens per word, capped at half the text
+        assert_eq!(get_overlap_text(text, 20, 4), "nine ten");
+        assert_eq!(get_overlap_text(text, 10, 64).split(' ').count(), 5);
+        assert_eq!(get_overlap_text(text, 10, 0), "");
     }
 
     #[test]
@@ -10,7 +10,10 @@ use anyhow::{Context, Result};
 use candle_core::{DType, Device, Tensor};
 use candle_nn::VarBuilder;
 use candle_transformers::models::bert::{BertModel, Config as BertConfig};
-use hf_hub::{api::sync::ApiBuilder, Cache, Repo, RepoType};
+use hf_hub::{
+    api::sync::{ApiBuilder, ApiRepo},
+    Cache, Repo, RepoType,
+};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use tokenizers::Tokenizer;
@@ -97,6 +100,29 @@ impl Default for EmbeddingStats {
     }
 }
 
+/// HuggingFace Hub repository of `model_id`, cached under `HF_HOME`,
+/// `HUGGINGFACE_HUB_CACHE` or `~/.cache/huggingface/hub`
+pub(crate) fn hub_repo(model_id: &str) -> Result<ApiRepo> {
+    let cache_dir = std::env::var("HF_HOME")
+        .or_else(|_| std::env::var("HUGGINGFACE_HUB_CACHE"))
+        .unwrap_or_else(|_| {
+            std::env::var("HOME")
+                .map(|h| format!("{h}/.cache/huggingface/hub"))
+                .unwrap_or_else(|_| ".cache/huggingface/hub".to_string())
+        });
+
+    tracing::info!(cache_dir = %cache_dir, "Using HuggingFace cache directory");
+
+    // Create cache directory if it doesn't exist
+    std::fs::create_dir_all(&cache_dir).context("Failed to create cache directory")?;
+
+    let api = ApiBuilder::from_cache(Cache::new(PathBuf::from(&cache_dir)))
+        .with_progress(true)
+        .build()
+        .context("Failed to create HuggingFace API")?;
+    Ok(api.repo(Repo::new(model_id.to_string(), RepoType::Model)))
+}
+
 /// Vector embedding generator
 pub struct Embedder {
     /// BERT model
@@ -138,24 +164,7 @@ impl Embedder {
         );
 
         // Download model from HuggingFace Hub
-        let cache_dir = std::env::var("HF_HOME")
-            .or_else(|_| std::env::var("HUGGINGFACE_HUB_CACHE"))
-            .unwrap_or_else(|_| {
-                std::env::var("HOME")
-                    .map(|h| format!("{h}/.cache/huggingface/hub"))
-                    .unwrap_or_else(|_| ".cache/huggingface/hub".to_string())
-            });
-
-        tracing::info!(cache_dir = %cache_dir, "Using HuggingFace cache directory");
-
-        // Create cache directory if it doesn't exist
-        std::fs::create_dir_all(&cache_dir).context("Failed to create cache directory")?;
-
-        let api = ApiBuilder::from_cache(Cache::new(PathBuf::from(&cache_dir)))
-            .with_progress(true)
-            .build()
-            .context("Failed to create HuggingFace API")?;
-        let repo = api.repo(Repo::new(config.model_id.clone(), RepoType::Model));
+        let repo = hub_repo(&config.model_id)?;
 
         let tokenizer_path = repo
             .get("tokenizer.json")
@@ -30,7 +30,7 @@ use thiserror::Error;
 // Re-export domain-specific errors for convenience
 pub use crate::ontology::error::OntologyError;
 pub use crate::scheduler::error::SchedulerError;
-pub use crate::utils::error::{CrawlerError, FetchError, ParseError};
+pub use crate::utils::error::{CrawlerError, ErrorClass, FetchError, ParseError};
 
 /// Common trait for all baram error types
 ///
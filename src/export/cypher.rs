@@ -0,0 +1,402 @@
+//! Knowledge graph export to Neo4j
+//!
+//! Each triple becomes one Cypher statement that `MERGE`s its two entities
+//! and the relationship between them, with the names and properties passed
+//! as parameters, so loading the same articles twice
+//! leaves the graph unchanged and entities named in several articles become
+//! one node. Entities are keyed by name under a label for their
+//! [`EntityType`] (`Person`, `Organization`, ...), and relationships are typed
+//! by [`RelationType`] (`WORKS_FOR`, `SAID`, ...) with the article, confidence
+//! and provenance as properties.
+//!
+//! [`write_cypher`] writes the statements to a script for `cypher-shell` or
+//! Neo4j Browser, with the parameters inlined as literals; [`Neo4jClient`]
+//! runs them through the Neo4j HTTP API.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::io::Write;
+use std::time::Duration;
+
+use crate::ontology::{EntityType, RelationType, Triple, TripleStore};
+
+/// Statements sent per HTTP transaction
+const STATEMENTS_PER_REQUEST: usize = 500;
+
+/// A Cypher statement and the values of its `$parameters`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Statement {
+    pub statement: String,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub parameters: Map<String, Value>,
+}
+
+impl Statement {
+    /// Statement without parameters
+    pub fn new(statement: impl Into<String>) -> Self {
+        Self {
+            statement: statement.into(),
+            parameters: Map::new(),
+        }
+    }
+
+    /// The statement with each `$parameter` replaced by its value as a
+    /// Cypher literal, for scripts
+    pub fn inline(&self) -> String {
+        let mut out = String::with_capacity(self.statement.len());
+        let mut rest = self.statement.as_str();
+        while let Some(start) = rest.find('$') {
+            out.push_str(&rest[..start]);
+            let name_len = rest[start + 1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len() - start - 1);
+            let name = &rest[start + 1..start + 1 + name_len];
+            match self.parameters.get(name) {
+                Some(value) => out.push_str(&value_literal(value)),
+                None => out.push_str(&rest[start..start + 1 + name_len]),
+            }
+            rest = &rest[start + 1 + name_len..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Node label for an entity type
+pub fn node_label(entity_type: EntityType) -> &'static str {
+    match entity_type {
+        EntityType::Person => "Person",
+        EntityType::Organization => "Organization",
+        EntityType::Location => "Location",
+        EntityType::DateTime => "DateTime",
+        EntityType::Money => "Money",
+        EntityType::Percentage => "Percentage",
+        EntityType::Event => "Event",
+        EntityType::Product => "Product",
+        EntityType::Policy => "Policy",
+        EntityType::Other => "Entity",
+    }
+}
+
+/// Relationship type for a relation type
+pub fn relationship_type(relation: RelationType) -> &'static str {
+    match relation {
+        RelationType::WorksFor => "WORKS_FOR",
+        RelationType::MemberOf => "MEMBER_OF",
+        RelationType::Leads => "LEADS",
+        RelationType::LocatedIn => "LOCATED_IN",
+        RelationType::Owns => "OWNS",
+        RelationType::Founded => "FOUNDED",
+        RelationType::Said => "SAID",
+        RelationType::ParticipatedIn => "PARTICIPATED_IN",
+        RelationType::Announced => "ANNOUNCED",
+        RelationType::Criticized => "CRITICIZED",
+        RelationType::Supported => "SUPPORTED",
+        RelationType::Opposed => "OPPOSED",
+        RelationType::InvestedIn => "INVESTED_IN",
+        RelationType::Acquired => "ACQUIRED",
+        RelationType::MergedWith => "MERGED_WITH",
+        RelationType::RelatedTo => "RELATED_TO",
+        RelationType::Unknown => "RELATED_TO",
+    }
+}
+
+/// Uniqueness constraints on entity names, one per node label
+///
+/// Besides keeping one node per name, they index the `MERGE` lookups.
+pub fn constraint_statements() -> Vec<Statement> {
+    [
+        EntityType::Person,
+        EntityType::Organization,
+        EntityType::Location,
+        EntityType::DateTime,
+        EntityType::Money,
+        EntityType::Percentage,
+        EntityType::Event,
+        EntityType::Product,
+        EntityType::Policy,
+        EntityType::Other,
+    ]
+    .into_iter()
+    .map(node_label)
+    .map(|label| {
+        Statement::new(format!(
+            "CREATE CONSTRAINT {}_name IF NOT EXISTS FOR (n:{label}) REQUIRE n.name IS UNIQUE",
+            label.to_lowercase()
+        ))
+    })
+    .collect()
+}
+
+/// `MERGE` statement for one triple of an article
+///
+/// Labels and the relationship type come from fixed names; everything taken
+/// from the article is a parameter.
+pub fn triple_statement(store: &TripleStore, triple: &Triple) -> Statement {
+    // Triples keep the Korean label, which maps back to exactly one type
+    let relation = RelationType::from_string(&triple.predicate_label);
+    let mut properties = Map::new();
+    properties.insert("article_title".into(), store.article_title.clone().into());
+    properties.insert("predicate".into(), triple.predicate.clone().into());
+    properties.insert("confidence".into(), triple.confidence.into());
+    properties.insert("verified".into(), triple.verified.into());
+    properties.insert("source".into(), triple.source.as_str().into());
+    if let Some(published_at) = &store.published_at {
+        properties.insert("published_at".into(), published_at.clone().into());
+    }
+    if let Some(evidence) = &triple.evidence {
+        properties.insert("evidence".into(), evidence.clone().into());
+    }
+
+    let mut parameters = Map::new();
+    parameters.insert("subject".into(), triple.subject.clone().into());
+    parameters.insert("object".into(), triple.object.clone().into());
+    parameters.insert("article_id".into(), store.article_id.clone().into());
+    parameters.insert("properties".into(), Value::Object(properties));
+
+    Statement {
+        statement: format!(
+            "MERGE (s:{} {{name: $subject}}) MERGE (o:{} {{name: $object}}) \
+             MERGE (s)-[r:{} {{article_id: $article_id}}]->(o) SET r += $properties",
+            node_label(triple.subject_type),
+            node_label(triple.object_type),
+            relationship_type(relation),
+        ),
+        parameters,
+    }
+}
+
+/// Statements for all triples of `stores`, in order
+pub fn triple_statements(stores: &[TripleStore]) -> Vec<Statement> {
+    stores
+        .iter()
+        .flat_map(|store| {
+            store
+                .triples
+                .iter()
+                .map(move |triple| triple_statement(store, triple))
+        })
+        .collect()
+}
+
+/// Write the constraints and triples as a Cypher script
+pub fn write_cypher<W: Write>(stores: &[TripleStore], mut writer: W) -> Result<()> {
+    for statement in constraint_statements() {
+        writeln!(writer, "{};", statement.inline())?;
+    }
+    writeln!(writer)?;
+
+    for store in stores {
+        writeln!(
+            writer,
+            "// Article: {}",
+            store.article_title.replace('\n', " ")
+        )?;
+        for triple in &store.triples {
+            writeln!(writer, "{};", triple_statement(store, triple).inline())?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Cypher string literal
+fn literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('\'');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\'' => out.push_str("\\'"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('\'');
+    out
+}
+
+/// Cypher literal of a parameter value
+fn value_literal(value: &Value) -> String {
+    match value {
+        Value::String(s) => literal(s),
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(value_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, value)| format!(
+                    "`{}`: {}",
+                    key.replace('`', "``"),
+                    value_literal(value)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        // null, booleans and numbers are written the same in JSON and Cypher
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// HTTP API
+// ============================================================================
+
+/// Runs Cypher statements through the Neo4j HTTP transaction API
+pub struct Neo4jClient {
+    client: reqwest::Client,
+    commit_url: String,
+    username: String,
+    password: String,
+}
+
+/// Response of `POST /db/<database>/tx/commit`
+#[derive(Debug, Deserialize)]
+struct CommitResponse {
+    #[serde(default)]
+    errors: Vec<CommitError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitError {
+    code: String,
+    message: String,
+}
+
+impl Neo4jClient {
+    /// Client for the server at `url` (e.g. `http://localhost:7474`)
+    pub fn new(url: &str, database: &str, username: &str, password: &str) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .context("Failed to build Neo4j HTTP client")?;
+        Ok(Self {
+            client,
+            commit_url: format!("{}/db/{database}/tx/commit", url.trim_end_matches('/')),
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    /// Run `statements` in transactions of [`STATEMENTS_PER_REQUEST`]
+    ///
+    /// Each transaction is committed on its own, so a failure leaves the
+    /// earlier batches in the graph; rerunning is safe because every
+    /// statement is a `MERGE`.
+    pub async fn run(&self, statements: &[Statement]) -> Result<usize> {
+        for batch in statements.chunks(STATEMENTS_PER_REQUEST) {
+            let body = serde_json::json!({ "statements": batch });
+            let response = self
+                .client
+                .post(&self.commit_url)
+                .basic_auth(&self.username, Some(&self.password))
+                .json(&body)
+                .send()
+                .await
+                .with_context(|| format!("Failed to reach Neo4j at {}", self.commit_url))?;
+            let status = response.status();
+            if !status.is_success() {
+                bail!(
+                    "Neo4j returned {status}: {}",
+                    response.text().await.unwrap_or_default()
+                );
+            }
+
+            let result: CommitResponse = response
+                .json()
+                .await
+                .context("Failed to parse Neo4j response")?;
+            if let Some(error) = result.errors.first() {
+                bail!(
+                    "Neo4j rejected the statements: {} ({})",
+                    error.message,
+                    error.code
+                );
+            }
+        }
+        Ok(statements.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::{ExtractedRelation, ExtractionResult};
+
+    fn store() -> TripleStore {
+        let result = ExtractionResult {
+            article_id: "001_0001".to_string(),
+            entities: vec![],
+            relations: vec![ExtractedRelation {
+                subject: "이재용".to_string(),
+                subject_type: EntityType::Person,
+                predicate: RelationType::Leads,
+                object: "삼성's 전자".to_string(),
+                object_type: EntityType::Organization,
+                confidence: 0.9,
+                evidence: "이재용 회장은\n삼성전자를".to_string(),
+                verified: true,
+            }],
+        };
+        TripleStore::from_extraction(&result, "삼성전자 실적")
+    }
+
+    #[test]
+    fn test_literal_escapes() {
+        assert_eq!(literal("a'b\\c\nd"), "'a\\'b\\\\c\\nd'");
+    }
+
+    #[test]
+    fn test_triple_statement() {
+        let store = store();
+        let statement = triple_statement(&store, &store.triples[0]);
+        assert!(statement
+            .statement
+            .starts_with("MERGE (s:Person {name: $subject})"));
+        // Leads and Founded share an RDF predicate but not a relationship type
+        assert!(statement
+            .statement
+            .contains("-[r:LEADS {article_id: $article_id}]->"));
+        assert_eq!(statement.parameters["object"], "삼성's 전자");
+        assert_eq!(statement.parameters["article_id"], "001_0001");
+        assert_eq!(statement.parameters["properties"]["source"], "regex");
+
+        let body = serde_json::to_value(&statement).unwrap();
+        assert_eq!(body["parameters"]["subject"], "이재용");
+
+        let inlined = statement.inline();
+        assert!(inlined.starts_with("MERGE (s:Person {name: '이재용'})"));
+        assert!(inlined.contains("MERGE (o:Organization {name: '삼성\\'s 전자'})"));
+        assert!(inlined.contains("`evidence`: '이재용 회장은\\n삼성전자를'"));
+        assert!(!inlined.contains('\n'));
+        assert!(!inlined.contains('$'));
+    }
+
+    #[test]
+    fn test_inline_leaves_parameter_text_in_values() {
+        let mut statement = Statement::new("RETURN $a, $b, $missing");
+        statement.parameters.insert("a".into(), "$b".into());
+        statement.parameters.insert("b".into(), 1.into());
+        assert_eq!(statement.inline(), "RETURN '$b', 1, $missing");
+    }
+
+    #[test]
+    fn test_write_cypher() {
+        let mut out = Vec::new();
+        write_cypher(&[store()], &mut out).unwrap();
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.starts_with("CREATE CONSTRAINT person_name IF NOT EXISTS"));
+        assert!(script.contains("// Article: 삼성전자 실적\nMERGE"));
+        assert_eq!(script.matches("MERGE (s)").count(), 1);
+    }
+}
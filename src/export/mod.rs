@@ -0,0 +1,1161 @@
+//! Dataset export
+//!
+//! Flattens crawled data into records and writes them in one of the export
+//! formats used by `baram export`:
+//! - [`ArticleRecord`]: one row per article
+//! - [`CommentRecord`]: one row per reader comment
+//! - [`TripleRecord`]: one row per extracted ontology triple
+//! - [`IndexDocument`]: one row per OpenSearch document, embedding included
+//! - [`EmbeddingRecord`]: one row per document embedding
+//!
+//! JSON Lines keeps every field as serialized by serde. CSV and Parquet use
+//! the fixed columns of each record's [`Tabular`] implementation; embeddings
+//! are a list column in Parquet and a JSON array in CSV. [`write_fields`]
+//! narrows any of the three to a selection of those columns. Turtle and
+//! Cypher (see [`cypher`]) are only available for triples, and NumPy (see
+//! [`npy`]) only for embeddings.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::export::{write_records, ArticleRecord, ExportFormat};
+//!
+//! # fn example(records: Vec<ArticleRecord>) -> anyhow::Result<()> {
+//! let file = std::fs::File::create("articles.parquet")?;
+//! write_records(&records, ExportFormat::Parquet, file)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use anyhow::{bail, Context, Result};
+use arrow_array::types::Float32Type;
+use arrow_array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, ListArray, RecordBatch, StringArray,
+    TimestampMillisecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::crawler::url::UrlExtractor;
+use crate::embedding::IndexDocument;
+use crate::models::{ArticleSource, ParsedArticle};
+use crate::ontology::{RelationSource, TripleStore};
+use crate::parser::Article;
+
+pub mod cypher;
+pub mod npy;
+
+/// What to export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    /// Articles
+    Articles,
+    /// Ontology triples extracted from articles
+    Triples,
+    /// Reader comments
+    Comments,
+    /// Raw OpenSearch index documents
+    Index,
+    /// Document IDs and embedding vectors
+    Embeddings,
+}
+
+impl ExportKind {
+    /// Name used on the command line
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Articles => "articles",
+            Self::Triples => "triples",
+            Self::Comments => "comments",
+            Self::Index => "index",
+            Self::Embeddings => "embeddings",
+        }
+    }
+}
+
+impl FromStr for ExportKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "articles" => Ok(Self::Articles),
+            "triples" => Ok(Self::Triples),
+            "comments" => Ok(Self::Comments),
+            "index" => Ok(Self::Index),
+            "embeddings" => Ok(Self::Embeddings),
+            _ => Err(format!(
+                "Unknown export target: {s}. Use articles, triples, comments, index or embeddings"
+            )),
+        }
+    }
+}
+
+/// Output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One JSON object per line
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+    /// Apache Parquet
+    Parquet,
+    /// RDF Turtle (triples only)
+    Turtle,
+    /// Cypher `MERGE` statements for Neo4j (triples only)
+    Cypher,
+    /// NumPy float32 matrix plus an IDs file (embeddings only)
+    Npy,
+}
+
+impl ExportFormat {
+    /// Name used on the command line
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+            Self::Turtle => "turtle",
+            Self::Cypher => "cypher",
+            Self::Npy => "npy",
+        }
+    }
+
+    /// Conventional file extension
+    #[must_use]
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+            Self::Turtle => "ttl",
+            Self::Cypher => "cypher",
+            Self::Npy => "npy",
+        }
+    }
+
+    /// Whether the format can hold the given export target
+    #[must_use]
+    pub fn supports(&self, kind: ExportKind) -> bool {
+        match self {
+            Self::Turtle | Self::Cypher => kind == ExportKind::Triples,
+            Self::Npy => kind == ExportKind::Embeddings,
+            _ => true,
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            "csv" => Ok(Self::Csv),
+            "parquet" => Ok(Self::Parquet),
+            "turtle" | "ttl" => Ok(Self::Turtle),
+            "cypher" | "cql" => Ok(Self::Cypher),
+            "npy" | "numpy" => Ok(Self::Npy),
+            _ => Err(format!(
+                "Unknown export format: {s}. Use jsonl, csv, parquet, turtle, cypher or npy"
+            )),
+        }
+    }
+}
+
+/// Publication date and category filter
+///
+/// Records without a publication time only pass when no date bound is set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportFilter {
+    /// Earliest publication time (inclusive)
+    pub since: Option<DateTime<Utc>>,
+    /// Latest publication time (exclusive)
+    pub until: Option<DateTime<Utc>>,
+    /// Allowed categories (empty = all)
+    pub categories: Vec<String>,
+}
+
+impl ExportFilter {
+    /// Whether a record with this publication time and category passes
+    #[must_use]
+    pub fn matches(&self, published_at: Option<DateTime<Utc>>, category: &str) -> bool {
+        if !self.categories.is_empty()
+            && !self
+                .categories
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(category))
+        {
+            return false;
+        }
+        match published_at {
+            Some(t) => {
+                self.since.map_or(true, |since| t >= since)
+                    && self.until.map_or(true, |until| t < until)
+            }
+            None => self.since.is_none() && self.until.is_none(),
+        }
+    }
+}
+
+/// Column value type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// UTF-8 string
+    Utf8,
+    /// 64-bit integer
+    Int64,
+    /// 64-bit float
+    Float64,
+    /// Boolean
+    Boolean,
+    /// UTC timestamp (millisecond precision in Parquet)
+    Timestamp,
+    /// List of 32-bit floats
+    FloatList,
+}
+
+/// Column of a tabular export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    /// Column name
+    pub name: &'static str,
+    /// Value type
+    pub kind: ColumnType,
+}
+
+const fn column(name: &'static str, kind: ColumnType) -> Column {
+    Column { name, kind }
+}
+
+/// One value of a row, matching its column's [`ColumnType`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Utf8(Option<String>),
+    Int64(Option<i64>),
+    Float64(Option<f64>),
+    Boolean(bool),
+    Timestamp(Option<DateTime<Utc>>),
+    FloatList(Vec<f32>),
+}
+
+impl Cell {
+    /// CSV field text (empty for missing values)
+    fn to_csv_field(&self) -> String {
+        match self {
+            Self::Utf8(value) => value.clone().unwrap_or_default(),
+            Self::Int64(value) => value.map(|v| v.to_string()).unwrap_or_default(),
+            Self::Float64(value) => value.map(|v| v.to_string()).unwrap_or_default(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Timestamp(value) => value.map(|t| t.to_rfc3339()).unwrap_or_default(),
+            Self::FloatList(values) => serde_json::to_string(values).unwrap_or_default(),
+        }
+    }
+}
+
+/// A record with a fixed column layout, for CSV and Parquet output
+pub trait Tabular: Serialize {
+    /// Column layout, in output order
+    fn columns() -> &'static [Column];
+
+    /// Values of this record, one per column
+    fn cells(&self) -> Vec<Cell>;
+}
+
+/// Flattened article
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArticleRecord {
+    /// Article ID (`{oid}_{aid}` for Naver articles when known)
+    pub id: String,
+    /// Site or feed the article came from
+    pub source: ArticleSource,
+    /// Publisher ID (Naver only)
+    pub oid: String,
+    /// Article number within the publisher
+    pub aid: String,
+    /// Title
+    pub title: String,
+    /// Body text
+    pub content: String,
+    /// Article URL
+    pub url: String,
+    /// News category
+    pub category: String,
+    /// Publisher name
+    pub publisher: Option<String>,
+    /// Author name
+    pub author: Option<String>,
+    /// Publication time
+    pub published_at: Option<DateTime<Utc>>,
+    /// Crawl time
+    pub crawled_at: Option<DateTime<Utc>>,
+    /// Number of reader comments, when known
+    pub comment_count: Option<i64>,
+}
+
+impl ArticleRecord {
+    /// Article as used by the ontology extractor
+    #[must_use]
+    pub fn to_parsed_article(&self) -> ParsedArticle {
+        let source_id = match ArticleSource::split_article_id(&self.id) {
+            (ArticleSource::Naver, _) => String::new(),
+            (_, source_id) => source_id.to_string(),
+        };
+        ParsedArticle {
+            source: self.source,
+            source_id,
+            oid: self.oid.clone(),
+            aid: self.aid.clone(),
+            title: self.title.clone(),
+            content: self.content.clone(),
+            url: self.url.clone(),
+            category: self.category.clone(),
+            publisher: self.publisher.clone(),
+            author: self.author.clone(),
+            published_at: self.published_at,
+            crawled_at: self.crawled_at.unwrap_or_else(Utc::now),
+            content_hash: None,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&Article> for ArticleRecord {
+    fn from(article: &Article) -> Self {
+        let (oid, aid) = UrlExtractor::new()
+            .extract_ids(&article.url)
+            .unwrap_or_default();
+        let id = if oid.is_empty() {
+            article.id.to_string()
+        } else {
+            format!("{oid}_{aid}")
+        };
+        Self {
+            id,
+            source: ArticleSource::Naver,
+            oid,
+            aid,
+            title: article.title.clone(),
+            content: article.body.clone(),
+            url: article.url.clone(),
+            category: article.category.clone().unwrap_or_default(),
+            publisher: None,
+            author: article.author.clone(),
+            published_at: article.published_at,
+            crawled_at: None,
+            comment_count: Some(article.comments.len() as i64),
+        }
+    }
+}
+
+impl From<&ParsedArticle> for ArticleRecord {
+    fn from(article: &ParsedArticle) -> Self {
+        Self {
+            id: article.id(),
+            source: article.source,
+            oid: article.oid.clone(),
+            aid: article.aid.clone(),
+            title: article.title.clone(),
+            content: article.content.clone(),
+            url: article.url.clone(),
+            category: article.category.clone(),
+            publisher: article.publisher.clone(),
+            author: article.author.clone(),
+            published_at: article.published_at,
+            crawled_at: Some(article.crawled_at),
+            comment_count: Some(article.comment_count() as i64),
+        }
+    }
+}
+
+impl From<&IndexDocument> for ArticleRecord {
+    fn from(doc: &IndexDocument) -> Self {
+        Self {
+            id: doc.id.clone(),
+            source: doc.source,
+            oid: doc.oid.clone(),
+            aid: doc.aid.clone(),
+            title: doc.title.clone(),
+            content: doc.content.clone(),
+            url: doc.url.clone(),
+            category: doc.category.clone(),
+            publisher: doc.publisher.clone(),
+            author: doc.author.clone(),
+            published_at: doc.published_at.as_deref().and_then(parse_time),
+            crawled_at: parse_time(&doc.crawled_at),
+            comment_count: doc.comment_count.map(i64::from),
+        }
+    }
+}
+
+impl Tabular for ArticleRecord {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            column("id", ColumnType::Utf8),
+            column("source", ColumnType::Utf8),
+            column("oid", ColumnType::Utf8),
+            column("aid", ColumnType::Utf8),
+            column("title", ColumnType::Utf8),
+            column("content", ColumnType::Utf8),
+            column("url", ColumnType::Utf8),
+            column("category", ColumnType::Utf8),
+            column("publisher", ColumnType::Utf8),
+            column("author", ColumnType::Utf8),
+            column("published_at", ColumnType::Timestamp),
+            column("crawled_at", ColumnType::Timestamp),
+            column("comment_count", ColumnType::Int64),
+        ];
+        COLUMNS
+    }
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Utf8(Some(self.id.clone())),
+            Cell::Utf8(Some(self.source.to_string())),
+            Cell::Utf8(Some(self.oid.clone())),
+            Cell::Utf8(Some(self.aid.clone())),
+            Cell::Utf8(Some(self.title.clone())),
+            Cell::Utf8(Some(self.content.clone())),
+            Cell::Utf8(Some(self.url.clone())),
+            Cell::Utf8(Some(self.category.clone())),
+            Cell::Utf8(self.publisher.clone()),
+            Cell::Utf8(self.author.clone()),
+            Cell::Timestamp(self.published_at),
+            Cell::Timestamp(self.crawled_at),
+            Cell::Int64(self.comment_count),
+        ]
+    }
+}
+
+/// Flattened reader comment
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommentRecord {
+    /// ID of the commented article
+    pub article_id: String,
+    /// URL of the commented article
+    pub article_url: String,
+    /// Comment ID
+    pub comment_id: String,
+    /// Comment author
+    pub author: String,
+    /// Comment text
+    pub text: String,
+    /// Comment time
+    pub created_at: DateTime<Utc>,
+    /// Number of likes
+    pub likes: i64,
+}
+
+impl CommentRecord {
+    /// One record per comment of an article
+    #[must_use]
+    pub fn from_article(article: &Article) -> Vec<Self> {
+        let article_id = ArticleRecord::from(article).id;
+        article
+            .comments
+            .iter()
+            .map(|comment| Self {
+                article_id: article_id.clone(),
+                article_url: article.url.clone(),
+                comment_id: comment.id.clone(),
+                author: comment.author.clone(),
+                text: comment.text.clone(),
+                created_at: comment.created_at,
+                likes: i64::from(comment.likes),
+            })
+            .collect()
+    }
+}
+
+impl Tabular for CommentRecord {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            column("article_id", ColumnType::Utf8),
+            column("article_url", ColumnType::Utf8),
+            column("comment_id", ColumnType::Utf8),
+            column("author", ColumnType::Utf8),
+            column("text", ColumnType::Utf8),
+            column("created_at", ColumnType::Timestamp),
+            column("likes", ColumnType::Int64),
+        ];
+        COLUMNS
+    }
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Utf8(Some(self.article_id.clone())),
+            Cell::Utf8(Some(self.article_url.clone())),
+            Cell::Utf8(Some(self.comment_id.clone())),
+            Cell::Utf8(Some(self.author.clone())),
+            Cell::Utf8(Some(self.text.clone())),
+            Cell::Timestamp(Some(self.created_at)),
+            Cell::Int64(Some(self.likes)),
+        ]
+    }
+}
+
+/// Flattened ontology triple with its article
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TripleRecord {
+    /// Source article ID
+    pub article_id: String,
+    /// Source article title
+    pub article_title: String,
+    /// Source article publication time
+    pub published_at: Option<DateTime<Utc>>,
+    /// Subject URI
+    pub subject_id: String,
+    /// Subject display name
+    pub subject: String,
+    /// Subject entity type
+    pub subject_type: String,
+    /// Predicate URI
+    pub predicate: String,
+    /// Predicate display name
+    pub predicate_label: String,
+    /// Object URI
+    pub object_id: String,
+    /// Object display name
+    pub object: String,
+    /// Object entity type
+    pub object_type: String,
+    /// Extraction confidence
+    pub confidence: f64,
+    /// Source evidence
+    pub evidence: Option<String>,
+    /// Whether the relation was verified
+    pub verified: bool,
+}
+
+impl TripleRecord {
+    /// One record per triple of an article's store
+    #[must_use]
+    pub fn from_store(store: &TripleStore) -> Vec<Self> {
+        let published_at = store.published_at.as_deref().and_then(parse_time);
+        store
+            .triples
+            .iter()
+            .map(|triple| Self {
+                article_id: store.article_id.clone(),
+                article_title: store.article_title.clone(),
+                published_at,
+                subject_id: triple.subject_id.clone(),
+                subject: triple.subject.clone(),
+                subject_type: format!("{:?}", triple.subject_type),
+                predicate: triple.predicate.clone(),
+                predicate_label: triple.predicate_label.clone(),
+                object_id: triple.object_id.clone(),
+                object: triple.object.clone(),
+                object_type: format!("{:?}", triple.object_type),
+                confidence: f64::from(triple.confidence),
+                evidence: triple.evidence.clone(),
+                verified: triple.verified,
+            })
+            .collect()
+    }
+}
+
+impl Tabular for TripleRecord {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            column("article_id", ColumnType::Utf8),
+            column("article_title", ColumnType::Utf8),
+            column("published_at", ColumnType::Timestamp),
+            column("subject_id", ColumnType::Utf8),
+            column("subject", ColumnType::Utf8),
+            column("subject_type", ColumnType::Utf8),
+            column("predicate", ColumnType::Utf8),
+            column("predicate_label", ColumnType::Utf8),
+            column("object_id", ColumnType::Utf8),
+            column("object", ColumnType::Utf8),
+            column("object_type", ColumnType::Utf8),
+            column("confidence", ColumnType::Float64),
+            column("evidence", ColumnType::Utf8),
+            column("verified", ColumnType::Boolean),
+        ];
+        COLUMNS
+    }
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Utf8(Some(self.article_id.clone())),
+            Cell::Utf8(Some(self.article_title.clone())),
+            Cell::Timestamp(self.published_at),
+            Cell::Utf8(Some(self.subject_id.clone())),
+            Cell::Utf8(Some(self.subject.clone())),
+            Cell::Utf8(Some(self.subject_type.clone())),
+            Cell::Utf8(Some(self.predicate.clone())),
+            Cell::Utf8(Some(self.predicate_label.clone())),
+            Cell::Utf8(Some(self.object_id.clone())),
+            Cell::Utf8(Some(self.object.clone())),
+            Cell::Utf8(Some(self.object_type.clone())),
+            Cell::Float64(Some(self.confidence)),
+            Cell::Utf8(self.evidence.clone()),
+            Cell::Boolean(self.verified),
+        ]
+    }
+}
+
+impl Tabular for IndexDocument {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            column("id", ColumnType::Utf8),
+            column("source", ColumnType::Utf8),
+            column("oid", ColumnType::Utf8),
+            column("aid", ColumnType::Utf8),
+            column("title", ColumnType::Utf8),
+            column("content", ColumnType::Utf8),
+            column("category", ColumnType::Utf8),
+            column("publisher", ColumnType::Utf8),
+            column("author", ColumnType::Utf8),
+            column("url", ColumnType::Utf8),
+            column("published_at", ColumnType::Timestamp),
+            column("crawled_at", ColumnType::Timestamp),
+            column("comment_count", ColumnType::Int64),
+            column("chunk_index", ColumnType::Int64),
+            column("chunk_text", ColumnType::Utf8),
+            column("story_id", ColumnType::Utf8),
+            column("embedding", ColumnType::FloatList),
+        ];
+        COLUMNS
+    }
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Utf8(Some(self.id.clone())),
+            Cell::Utf8(Some(self.source.to_string())),
+            Cell::Utf8(Some(self.oid.clone())),
+            Cell::Utf8(Some(self.aid.clone())),
+            Cell::Utf8(Some(self.title.clone())),
+            Cell::Utf8(Some(self.content.clone())),
+            Cell::Utf8(Some(self.category.clone())),
+            Cell::Utf8(self.publisher.clone()),
+            Cell::Utf8(self.author.clone()),
+            Cell::Utf8(Some(self.url.clone())),
+            Cell::Timestamp(self.published_at.as_deref().and_then(parse_time)),
+            Cell::Timestamp(parse_time(&self.crawled_at)),
+            Cell::Int64(self.comment_count.map(i64::from)),
+            Cell::Int64(self.chunk_index.map(i64::from)),
+            Cell::Utf8(self.chunk_text.clone()),
+            Cell::Utf8(self.story_id.clone()),
+            Cell::FloatList(self.embedding.clone()),
+        ]
+    }
+}
+
+/// Embedding of an indexed document
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EmbeddingRecord {
+    /// Document ID
+    pub id: String,
+    /// Embedding vector
+    pub embedding: Vec<f32>,
+}
+
+impl Tabular for EmbeddingRecord {
+    fn columns() -> &'static [Column] {
+        const COLUMNS: &[Column] = &[
+            column("id", ColumnType::Utf8),
+            column("embedding", ColumnType::FloatList),
+        ];
+        COLUMNS
+    }
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Utf8(Some(self.id.clone())),
+            Cell::FloatList(self.embedding.clone()),
+        ]
+    }
+}
+
+/// Parse an RFC 3339 or zone-less ISO 8601 timestamp (assumed UTC)
+#[must_use]
+pub fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            [
+                "%Y-%m-%dT%H:%M:%S%.f",
+                "%Y-%m-%d %H:%M:%S",
+                "%Y-%m-%d %H:%M",
+            ]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+            .map(|naive| naive.and_utc())
+        })
+}
+
+/// Positions of the named columns of `T`, in the given order
+///
+/// No names selects every column. Names are matched case-insensitively.
+pub fn select_columns<T: Tabular>(fields: &[String]) -> Result<Vec<usize>> {
+    let columns = T::columns();
+    if fields.is_empty() {
+        return Ok((0..columns.len()).collect());
+    }
+
+    let mut selected = Vec::with_capacity(fields.len());
+    for field in fields {
+        let Some(index) = columns
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(field.trim()))
+        else {
+            let names: Vec<&str> = columns.iter().map(|c| c.name).collect();
+            bail!("Unknown field: {field}. Available: {}", names.join(", "));
+        };
+        if selected.contains(&index) {
+            bail!("Field {field} is selected more than once");
+        }
+        selected.push(index);
+    }
+    Ok(selected)
+}
+
+/// Write records in a tabular format
+///
+/// Turtle, Cypher and NumPy are rejected here; triples go through
+/// [`write_turtle`] or [`cypher::write_cypher`] and embeddings through
+/// [`npy::EmbeddingDump`].
+pub fn write_records<T: Tabular, W: Write + Send>(
+    records: &[T],
+    format: ExportFormat,
+    writer: W,
+) -> Result<()> {
+    write_fields(records, format, &[], writer)
+}
+
+/// Write only the named columns of records, see [`select_columns`]
+///
+/// With no names JSON Lines keeps every serialized field; with names it
+/// holds exactly the selected columns, in the given order.
+pub fn write_fields<T: Tabular, W: Write + Send>(
+    records: &[T],
+    format: ExportFormat,
+    fields: &[String],
+    writer: W,
+) -> Result<()> {
+    let columns = select_columns::<T>(fields)?;
+    match format {
+        ExportFormat::Jsonl if fields.is_empty() => write_jsonl(records, writer),
+        ExportFormat::Jsonl => write_jsonl_columns(records, &columns, writer),
+        ExportFormat::Csv => write_csv_columns(records, &columns, writer),
+        ExportFormat::Parquet => write_parquet_columns(records, &columns, writer),
+        ExportFormat::Turtle => bail!("Turtle output is only available for triples"),
+        ExportFormat::Cypher => bail!("Cypher output is only available for triples"),
+        ExportFormat::Npy => bail!("NumPy output is only available for embeddings"),
+    }
+}
+
+/// Write one JSON object per line
+pub fn write_jsonl<T: Serialize, W: Write>(records: &[T], mut writer: W) -> Result<()> {
+    for record in records {
+        serde_json::to_writer(&mut writer, record).context("Failed to serialize record")?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_jsonl_columns<T: Tabular, W: Write>(
+    records: &[T],
+    columns: &[usize],
+    mut writer: W,
+) -> Result<()> {
+    let all = T::columns();
+    for record in records {
+        let value = serde_json::to_value(record).context("Failed to serialize record")?;
+        writer.write_all(b"{")?;
+        for (i, &index) in columns.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b",")?;
+            }
+            let name = all[index].name;
+            serde_json::to_writer(&mut writer, name)?;
+            writer.write_all(b":")?;
+            serde_json::to_writer(&mut writer, &value[name])?;
+        }
+        writer.write_all(b"}\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write CSV with a header row
+pub fn write_csv<T: Tabular, W: Write>(records: &[T], writer: W) -> Result<()> {
+    let columns: Vec<usize> = (0..T::columns().len()).collect();
+    write_csv_columns(records, &columns, writer)
+}
+
+fn write_csv_columns<T: Tabular, W: Write>(
+    records: &[T],
+    columns: &[usize],
+    writer: W,
+) -> Result<()> {
+    let all = T::columns();
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(columns.iter().map(|&i| all[i].name))?;
+    for record in records {
+        let cells = record.cells();
+        csv.write_record(columns.iter().map(|&i| cells[i].to_csv_field()))?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+/// Write an uncompressed Parquet file with one row group
+pub fn write_parquet<T: Tabular, W: Write + Send>(records: &[T], writer: W) -> Result<()> {
+    let columns: Vec<usize> = (0..T::columns().len()).collect();
+    write_parquet_columns(records, &columns, writer)
+}
+
+fn write_parquet_columns<T: Tabular, W: Write + Send>(
+    records: &[T],
+    columns: &[usize],
+    writer: W,
+) -> Result<()> {
+    let all = T::columns();
+    let schema = Arc::new(Schema::new(
+        columns
+            .iter()
+            .map(|&i| &all[i])
+            .map(|c| Field::new(c.name, arrow_type(c.kind), c.kind != ColumnType::Boolean))
+            .collect::<Vec<_>>(),
+    ));
+
+    let mut values: Vec<Vec<Cell>> = vec![Vec::with_capacity(records.len()); columns.len()];
+    for record in records {
+        let mut cells: Vec<Option<Cell>> = record.cells().into_iter().map(Some).collect();
+        for (column_values, &i) in values.iter_mut().zip(columns) {
+            column_values.push(cells[i].take().expect("columns are selected once"));
+        }
+    }
+    let arrays = columns
+        .iter()
+        .zip(values)
+        .map(|(&i, cells)| column_array(&all[i], cells))
+        .collect::<Result<Vec<_>>>()?;
+    let batch = RecordBatch::try_new(schema.clone(), arrays).context("Invalid record batch")?;
+
+    let mut parquet = parquet::arrow::ArrowWriter::try_new(writer, schema, None)
+        .context("Failed to create Parquet writer")?;
+    parquet.write(&batch).context("Failed to write Parquet")?;
+    parquet.close().context("Failed to finish Parquet")?;
+    Ok(())
+}
+
+fn arrow_type(kind: ColumnType) -> DataType {
+    match kind {
+        ColumnType::Utf8 => DataType::Utf8,
+        ColumnType::Int64 => DataType::Int64,
+        ColumnType::Float64 => DataType::Float64,
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::Timestamp => DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+        ColumnType::FloatList => {
+            DataType::List(Arc::new(Field::new("item", DataType::Float32, true)))
+        }
+    }
+}
+
+fn column_array(column: &Column, cells: Vec<Cell>) -> Result<ArrayRef> {
+    let mismatch = || anyhow::anyhow!("Column {} holds a value of another type", column.name);
+    let array: ArrayRef = match column.kind {
+        ColumnType::Utf8 => Arc::new(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Utf8(value) => Ok(value),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<StringArray>>()?,
+        ),
+        ColumnType::Int64 => Arc::new(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Int64(value) => Ok(value),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<Int64Array>>()?,
+        ),
+        ColumnType::Float64 => Arc::new(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Float64(value) => Ok(value),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<Float64Array>>()?,
+        ),
+        ColumnType::Boolean => Arc::new(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Boolean(value) => Ok(Some(value)),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<BooleanArray>>()?,
+        ),
+        ColumnType::Timestamp => Arc::new(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Timestamp(value) => Ok(value.map(|t| t.timestamp_millis())),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<TimestampMillisecondArray>>()?
+                .with_timezone("UTC"),
+        ),
+        ColumnType::FloatList => {
+            let lists = cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::FloatList(values) => Ok(Some(values.into_iter().map(Some))),
+                    _ => Err(mismatch()),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Arc::new(ListArray::from_iter_primitive::<Float32Type, _, _>(lists))
+        }
+    };
+    Ok(array)
+}
+
+/// Write the triples of several articles as one Turtle document
+///
+/// Prefixes are declared once, followed by each article's triples under an
+/// `# Article:` comment.
+pub fn write_turtle<W: Write>(stores: &[TripleStore], mut writer: W) -> Result<()> {
+    writer.write_all(b"@prefix schema: <https://schema.org/> .\n")?;
+    writer.write_all(b"@prefix baram: <https://baram.example.org/ontology/> .\n")?;
+    writer.write_all(b"@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\n")?;
+
+    for store in stores {
+        writeln!(writer, "# Article: {}", store.article_title)?;
+        for triple in &store.triples {
+            writeln!(
+                writer,
+                "# {} {} {}",
+                triple.subject, triple.predicate_label, triple.object
+            )?;
+            writeln!(writer, "{}", triple.to_turtle())?;
+            if let Some(evidence) = &triple.evidence {
+                writeln!(writer, "# Evidence: {evidence}")?;
+            }
+            if triple.source != RelationSource::Regex {
+                writeln!(writer, "# Source: {}", triple.source.as_str())?;
+            }
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::RelationExtractor;
+    use crate::parser::Comment;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn article() -> Article {
+        Article {
+            id: Uuid::new_v4(),
+            url: "https://n.news.naver.com/mnews/article/001/0014123456".to_string(),
+            title: "삼성전자 실적 발표".to_string(),
+            body: "삼성전자가 4분기 실적을 발표했다.".to_string(),
+            author: Some("기자".to_string()),
+            published_at: Some(Utc.with_ymd_and_hms(2026, 2, 15, 9, 0, 0).unwrap()),
+            category: Some("economy".to_string()),
+            content_hash: String::new(),
+            comments: vec![Comment {
+                id: "c1".to_string(),
+                author: "reader".to_string(),
+                text: "기대됩니다, \"좋아요\"".to_string(),
+                created_at: Utc.with_ymd_and_hms(2026, 2, 15, 10, 0, 0).unwrap(),
+                likes: 3,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_parse_kind_and_format() {
+        assert_eq!("Triples".parse::<ExportKind>(), Ok(ExportKind::Triples));
+        assert!("graphs".parse::<ExportKind>().is_err());
+        assert_eq!("ttl".parse::<ExportFormat>(), Ok(ExportFormat::Turtle));
+        assert_eq!(ExportFormat::Turtle.extension(), "ttl");
+        assert!(ExportFormat::Turtle.supports(ExportKind::Triples));
+        assert!(!ExportFormat::Turtle.supports(ExportKind::Articles));
+        assert_eq!("cql".parse::<ExportFormat>(), Ok(ExportFormat::Cypher));
+        assert!(ExportFormat::Cypher.supports(ExportKind::Triples));
+        assert!(!ExportFormat::Cypher.supports(ExportKind::Comments));
+        assert!(ExportFormat::Parquet.supports(ExportKind::Index));
+        assert_eq!(
+            "embeddings".parse::<ExportKind>(),
+            Ok(ExportKind::Embeddings)
+        );
+        assert_eq!("npy".parse::<ExportFormat>(), Ok(ExportFormat::Npy));
+        assert!(ExportFormat::Npy.supports(ExportKind::Embeddings));
+        assert!(!ExportFormat::Npy.supports(ExportKind::Index));
+        assert!(ExportFormat::Jsonl.supports(ExportKind::Embeddings));
+    }
+
+    #[test]
+    fn test_filter() {
+        let day = |d| Some(Utc.with_ymd_and_hms(2026, 2, d, 0, 0, 0).unwrap());
+        let filter = ExportFilter {
+            since: day(10),
+            until: day(20),
+            categories: vec!["economy".to_string()],
+        };
+
+        assert!(filter.matches(day(10), "Economy"));
+        assert!(!filter.matches(day(20), "economy"));
+        assert!(!filter.matches(day(9), "economy"));
+        assert!(!filter.matches(day(15), "politics"));
+        assert!(!filter.matches(None, "economy"));
+        assert!(ExportFilter::default().matches(None, "politics"));
+    }
+
+    #[test]
+    fn test_article_and_comment_records() {
+        let article = article();
+        let record = ArticleRecord::from(&article);
+        assert_eq!(record.id, "001_0014123456");
+        assert_eq!(record.comment_count, Some(1));
+        assert_eq!(record.to_parsed_article().id(), "001_0014123456");
+
+        let comments = CommentRecord::from_article(&article);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].article_id, "001_0014123456");
+        assert_eq!(comments[0].likes, 3);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let expected = Utc.with_ymd_and_hms(2026, 2, 15, 9, 30, 0).unwrap();
+        assert_eq!(parse_time("2026-02-15T18:30:00+09:00"), Some(expected));
+        assert_eq!(parse_time("2026-02-15T09:30:00"), Some(expected));
+        assert_eq!(parse_time("2026-02-15 09:30"), Some(expected));
+        assert_eq!(parse_time("yesterday"), None);
+    }
+
+    #[test]
+    fn test_write_jsonl_and_csv() {
+        let comments = CommentRecord::from_article(&article());
+
+        let mut jsonl = Vec::new();
+        write_records(&comments, ExportFormat::Jsonl, &mut jsonl).unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(String::from_utf8(jsonl).unwrap().trim()).unwrap();
+        assert_eq!(line["comment_id"], "c1");
+
+        let mut csv = Vec::new();
+        write_records(&comments, ExportFormat::Csv, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("article_id,article_url,comment_id,author,text,created_at,likes")
+        );
+        assert!(lines
+            .next()
+            .unwrap()
+            .contains("\"기대됩니다, \"\"좋아요\"\"\""));
+
+        assert!(write_records(&comments, ExportFormat::Turtle, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_write_fields() {
+        let records = vec![ArticleRecord::from(&article())];
+        let fields = vec!["Title".to_string(), "id".to_string()];
+        assert_eq!(select_columns::<ArticleRecord>(&fields).unwrap(), [4, 0]);
+        assert!(select_columns::<ArticleRecord>(&["body".to_string()]).is_err());
+        assert!(select_columns::<ArticleRecord>(&["id".to_string(), "ID".to_string()]).is_err());
+
+        let mut jsonl = Vec::new();
+        write_fields(&records, ExportFormat::Jsonl, &fields, &mut jsonl).unwrap();
+        assert_eq!(
+            String::from_utf8(jsonl).unwrap(),
+            "{\"title\":\"삼성전자 실적 발표\",\"id\":\"001_0014123456\"}\n"
+        );
+
+        let mut csv = Vec::new();
+        write_fields(&records, ExportFormat::Csv, &fields, &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "title,id\n삼성전자 실적 발표,001_0014123456\n"
+        );
+
+        let mut buffer = Vec::new();
+        write_fields(&records, ExportFormat::Parquet, &fields, &mut buffer).unwrap();
+        let reader =
+            parquet::file::reader::SerializedFileReader::new(bytes::Bytes::from(buffer)).unwrap();
+        let metadata = parquet::file::reader::FileReader::metadata(&reader);
+        let schema = metadata.file_metadata().schema_descr();
+        assert_eq!(schema.num_columns(), 2);
+        assert_eq!(schema.column(0).name(), "title");
+    }
+
+    #[test]
+    fn test_write_parquet() {
+        let doc = IndexDocument {
+            id: "001_0014123456".to_string(),
+            source: ArticleSource::Naver,
+            oid: "001".to_string(),
+            aid: "0014123456".to_string(),
+            title: "제목".to_string(),
+            content: "본문".to_string(),
+            category: "economy".to_string(),
+            publisher: None,
+            author: None,
+            url: String::new(),
+            published_at: Some("2026-02-15T09:00:00Z".to_string()),
+            crawled_at: "2026-02-15T10:00:00Z".to_string(),
+            comment_count: Some(2),
+            image_count: None,
+            video_count: None,
+            reaction_count: None,
+            embedding: vec![0.5, -0.5],
+            chunk_index: None,
+            chunk_text: None,
+            story_id: None,
+        };
+
+        let mut buffer = Vec::new();
+        write_records(&[doc.clone(), doc], ExportFormat::Parquet, &mut buffer).unwrap();
+
+        let reader =
+            parquet::file::reader::SerializedFileReader::new(bytes::Bytes::from(buffer)).unwrap();
+        let metadata = parquet::file::reader::FileReader::metadata(&reader);
+        assert_eq!(metadata.file_metadata().num_rows(), 2);
+        assert_eq!(
+            metadata.file_metadata().schema_descr().num_columns(),
+            IndexDocument::columns().len()
+        );
+    }
+
+    #[test]
+    fn test_write_turtle() {
+        let parsed = ArticleRecord::from(&article()).to_parsed_article();
+        let result = RelationExtractor::new().extract_from_article(&parsed);
+        let store = TripleStore::from_extraction(&result, &parsed.title);
+
+        let mut turtle = Vec::new();
+        write_turtle(&[store.clone(), store.clone()], &mut turtle).unwrap();
+        let turtle = String::from_utf8(turtle).unwrap();
+
+        assert_eq!(turtle.matches("@prefix schema:").count(), 1);
+        assert_eq!(turtle.matches("# Article: 삼성전자 실적 발표").count(), 2);
+        assert_eq!(TripleRecord::from_store(&store).len(), store.triples.len());
+    }
+}
@@ -0,0 +1,401 @@
+//! Embedding dumps in NumPy `.npy` format
+//!
+//! A dump is two files: `<name>.npy`, a little-endian float32 matrix with one
+//! row per document, and `<name>.ids`, the document IDs in row order, one
+//! per line. `numpy.load("<name>.npy")` reads the matrix as it is, and
+//! `baram index --embeddings <name>.npy` indexes articles with the dumped
+//! vectors instead of embedding them again.
+
+use anyhow::{bail, ensure, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::EmbeddingRecord;
+
+/// Magic string opening every `.npy` file
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Header alignment; numpy pads the header so the data starts on it
+const HEADER_ALIGN: usize = 64;
+
+/// Most bytes allocated for the data before any is read; larger matrices
+/// grow as their data arrives, so a lying header cannot exhaust memory
+const MAX_PREALLOC: usize = 64 * 1024 * 1024;
+
+lazy_static! {
+    static ref DESCR: Regex = Regex::new(r"'descr':\s*'([^']*)'").unwrap();
+    static ref FORTRAN_ORDER: Regex = Regex::new(r"'fortran_order':\s*(True|False)").unwrap();
+    static ref SHAPE: Regex = Regex::new(r"'shape':\s*\((\d+),\s*(\d+),?\s*\)").unwrap();
+}
+
+/// Document IDs and their embeddings, all of one dimension
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmbeddingDump {
+    ids: Vec<String>,
+    dim: usize,
+    data: Vec<f32>,
+}
+
+impl EmbeddingDump {
+    /// Dump of `records`, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the embeddings differ in dimension
+    pub fn from_records(records: &[EmbeddingRecord]) -> Result<Self> {
+        let dim = records.first().map_or(0, |r| r.embedding.len());
+        let mut dump = Self {
+            ids: Vec::with_capacity(records.len()),
+            dim,
+            data: Vec::with_capacity(records.len() * dim),
+        };
+        for record in records {
+            ensure!(
+                record.embedding.len() == dim,
+                "Embedding of {} has {} dimensions, expected {dim}",
+                record.id,
+                record.embedding.len()
+            );
+            dump.ids.push(record.id.clone());
+            dump.data.extend_from_slice(&record.embedding);
+        }
+        Ok(dump)
+    }
+
+    /// Embedding dimension (0 for an empty dump)
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Number of embeddings
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the dump holds no embeddings
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Document IDs and embeddings, in row order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[f32])> {
+        self.ids
+            .iter()
+            .map(String::as_str)
+            .zip(self.data.chunks(self.dim.max(1)))
+    }
+
+    /// Embeddings by document ID; a repeated ID keeps its last row
+    #[must_use]
+    pub fn into_map(self) -> HashMap<String, Vec<f32>> {
+        let dim = self.dim.max(1);
+        self.ids
+            .into_iter()
+            .zip(self.data.chunks(dim).map(<[f32]>::to_vec))
+            .collect()
+    }
+
+    /// Write the matrix to `path` and the IDs next to it (see [`ids_path`])
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either file cannot be written
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file =
+            File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        write_npy(&mut writer, self.len(), self.dim, &self.data)?;
+        writer.flush()?;
+
+        let ids = ids_path(path);
+        let file =
+            File::create(&ids).with_context(|| format!("Failed to create {}", ids.display()))?;
+        let mut writer = BufWriter::new(file);
+        for id in &self.ids {
+            writeln!(writer, "{id}")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Embedding dimension of the dump at `path`, from the matrix header
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is missing or not a 2-D little-endian
+    /// float32 array
+    pub fn read_dim(path: &Path) -> Result<usize> {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let (_, dim) = read_npy_header(&mut BufReader::new(file))
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Ok(dim)
+    }
+
+    /// Read a dump written by [`Self::write`], of `expected_dim` dimensions
+    /// if given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file is missing, the matrix is not a 2-D
+    /// little-endian float32 array of the expected dimension, or the ID
+    /// count differs from its rows
+    pub fn read(path: &Path, expected_dim: Option<usize>) -> Result<Self> {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let (rows, dim, data) = read_npy(BufReader::new(file), expected_dim)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        let ids_file = ids_path(path);
+        let file = File::open(&ids_file)
+            .with_context(|| format!("Failed to open {}", ids_file.display()))?;
+        let ids: Vec<String> = BufReader::new(file)
+            .lines()
+            .collect::<std::io::Result<_>>()
+            .with_context(|| format!("Failed to read {}", ids_file.display()))?;
+        ensure!(
+            ids.len() == rows,
+            "{} lists {} IDs for {rows} embeddings",
+            ids_file.display(),
+            ids.len()
+        );
+
+        Ok(Self { ids, dim, data })
+    }
+}
+
+/// IDs file of the dump at `path`: `vectors.npy` -> `vectors.ids`
+#[must_use]
+pub fn ids_path(path: &Path) -> PathBuf {
+    path.with_extension("ids")
+}
+
+/// Write a `rows` x `dim` float32 matrix in `.npy` format (version 1.0)
+///
+/// # Errors
+///
+/// Returns an error if writing fails
+pub fn write_npy<W: Write>(mut writer: W, rows: usize, dim: usize, data: &[f32]) -> Result<()> {
+    debug_assert_eq!(data.len(), rows * dim);
+
+    let mut header =
+        format!("{{'descr': '<f4', 'fortran_order': False, 'shape': ({rows}, {dim}), }}");
+    // Magic, version and length take 10 bytes; the header ends in a newline
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(HEADER_ALIGN) - unpadded));
+    header.push('\n');
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(header.as_bytes())?;
+    for value in data {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Read a 2-D little-endian float32 `.npy` matrix as (rows, dim, values)
+///
+/// With `expected_dim`, a matrix of another dimension is rejected before
+/// its data is read.
+///
+/// # Errors
+///
+/// Returns an error for anything else, including Fortran-ordered arrays
+/// and data shorter than the shape
+pub fn read_npy<R: Read>(
+    mut reader: R,
+    expected_dim: Option<usize>,
+) -> Result<(usize, usize, Vec<f32>)> {
+    let (rows, dim) = read_npy_header(&mut reader)?;
+    if let Some(expected) = expected_dim {
+        ensure!(
+            dim == expected,
+            "The matrix has {dim} dimensions, expected {expected}"
+        );
+    }
+    let len = rows
+        .checked_mul(dim)
+        .and_then(|values| values.checked_mul(4))
+        .with_context(|| format!("The .npy shape ({rows}, {dim}) is too large"))?;
+
+    let mut bytes = Vec::with_capacity(len.min(MAX_PREALLOC));
+    reader
+        .take(len as u64)
+        .read_to_end(&mut bytes)
+        .context("Failed to read the .npy data")?;
+    ensure!(
+        bytes.len() == len,
+        "The .npy data is shorter than its shape"
+    );
+    let data = bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    Ok((rows, dim, data))
+}
+
+/// Read the header of a 2-D little-endian float32 `.npy` matrix, leaving
+/// `reader` at the data, and return its shape
+fn read_npy_header<R: Read>(reader: &mut R) -> Result<(usize, usize)> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    ensure!(&preamble[..6] == MAGIC, "Not an .npy file");
+
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            usize::from(u16::from_le_bytes(len))
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => bail!("Unsupported .npy version {version}"),
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8_lossy(&header);
+
+    let descr = DESCR
+        .captures(&header)
+        .map(|caps| caps[1].to_string())
+        .context("No dtype in .npy header")?;
+    ensure!(
+        descr == "<f4",
+        "Expected little-endian float32 (<f4) data, found {descr}"
+    );
+    ensure!(
+        FORTRAN_ORDER
+            .captures(&header)
+            .is_some_and(|caps| &caps[1] == "False"),
+        "Fortran-ordered .npy arrays are not supported"
+    );
+    let shape = SHAPE
+        .captures(&header)
+        .context("Expected a 2-D array (documents x dimensions)")?;
+    let rows: usize = shape[1].parse()?;
+    let dim: usize = shape[2].parse()?;
+    Ok((rows, dim))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, embedding: &[f32]) -> EmbeddingRecord {
+        EmbeddingRecord {
+            id: id.to_string(),
+            embedding: embedding.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_npy_round_trip() {
+        let mut npy = Vec::new();
+        write_npy(&mut npy, 2, 3, &[1.0, 2.0, 3.0, -0.5, 0.0, 0.25]).unwrap();
+        assert_eq!(&npy[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        assert_eq!((10 + header_len) % HEADER_ALIGN, 0);
+        assert_eq!(npy[9 + header_len], b'\n');
+        assert_eq!(npy.len(), 10 + header_len + 6 * 4);
+
+        let (rows, dim, data) = read_npy(npy.as_slice(), None).unwrap();
+        assert_eq!((rows, dim), (2, 3));
+        assert_eq!(data, vec![1.0, 2.0, 3.0, -0.5, 0.0, 0.25]);
+    }
+
+    #[test]
+    fn test_read_npy_rejects_other_arrays() {
+        let npy = |header: &str| {
+            let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+            bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.extend_from_slice(&[0; 64]);
+            bytes
+        };
+        assert!(read_npy(&b"PK\x03\x04 not numpy"[..], None).is_err());
+        assert!(read_npy(
+            npy("{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2), }\n").as_slice(),
+            None
+        )
+        .is_err());
+        assert!(read_npy(
+            npy("{'descr': '<f4', 'fortran_order': True, 'shape': (1, 2), }\n").as_slice(),
+            None
+        )
+        .is_err());
+        assert!(read_npy(
+            npy("{'descr': '<f4', 'fortran_order': False, 'shape': (2,), }\n").as_slice(),
+            None
+        )
+        .is_err());
+        // Shape too large to allocate, or of another dimension
+        assert!(read_npy(
+            npy("{'descr': '<f4', 'fortran_order': False, 'shape': (18446744073709551615, 2), }\n")
+                .as_slice(),
+            None
+        )
+        .is_err());
+        assert!(read_npy(
+            npy("{'descr': '<f4', 'fortran_order': False, 'shape': (1, 2), }\n").as_slice(),
+            Some(3)
+        )
+        .is_err());
+        // Shape larger than the data
+        assert!(read_npy(
+            npy("{'descr': '<f4', 'fortran_order': False, 'shape': (100, 2), }\n").as_slice(),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_dump_write_and_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vectors.npy");
+        let dump = EmbeddingDump::from_records(&[
+            record("001_0000000001", &[0.1, 0.2]),
+            record("daum_20240115093000123", &[0.3, 0.4]),
+        ])
+        .unwrap();
+        dump.write(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("vectors.ids")).unwrap(),
+            "001_0000000001\ndaum_20240115093000123\n"
+        );
+
+        let read = EmbeddingDump::read(&path, Some(2)).unwrap();
+        assert_eq!(read, dump);
+        assert_eq!((read.len(), read.dim()), (2, 2));
+        assert_eq!(
+            read.iter().nth(1),
+            Some(("daum_20240115093000123", &[0.3f32, 0.4][..]))
+        );
+        let map = read.into_map();
+        assert_eq!(map["001_0000000001"], vec![0.1, 0.2]);
+
+        std::fs::write(dir.path().join("vectors.ids"), "001_0000000001\n").unwrap();
+        assert!(EmbeddingDump::read(&path, None).is_err());
+    }
+
+    #[test]
+    fn test_dump_rejects_mixed_dimensions() {
+        assert!(
+            EmbeddingDump::from_records(&[record("a", &[0.1, 0.2]), record("b", &[0.3])]).is_err()
+        );
+        let empty = EmbeddingDump::from_records(&[]).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.dim(), 0);
+    }
+}
@@ -0,0 +1,264 @@
+//! Capability detection for partial deployments
+//!
+//! Not every deployment runs every service. Before a command starts, the
+//! services it can do without are probed once, and a missing one switches the
+//! command to a documented degraded mode instead of failing with connection
+//! errors mid-run:
+//!
+//! | Capability | Service | Degraded mode |
+//! |------------|---------|---------------|
+//! | [`Capability::Search`] | OpenSearch | `search` runs a local full-text search over the markdown files; `pipeline` skips indexing |
+//! | [`Capability::Llm`] | vLLM or Ollama | `ontology --llm` extracts with the rule-based extractor only |
+//! | [`Capability::Cache`] | Redis (`REDIS_URL`) | nothing is cached |
+//!
+//! A capability is missing when its service is not configured, or when the
+//! probe fails within the timeout. Capabilities that were not detected are
+//! assumed available, so commands behave as before unless asked.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::config::Config;
+//! use baram::health::{Capabilities, Capability};
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let config = Config::default();
+//! let capabilities =
+//!     Capabilities::detect(&config, &[Capability::Search], Duration::from_secs(3)).await;
+//!
+//! if !capabilities.is_available(Capability::Search) {
+//!     // search the markdown files instead
+//! }
+//! # }
+//! ```
+
+use serde::Serialize;
+use std::time::Duration;
+
+use super::{Dependency, DependencyChecker, HealthStatus};
+use crate::config::{secrets, Config};
+use crate::llm::{LlmClient, LlmConfig};
+
+/// Default timeout of a capability probe
+pub const DEFAULT_DETECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Optional feature backed by a service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Capability {
+    /// Full-text and vector search (OpenSearch)
+    Search,
+    /// LLM relation extraction (vLLM or Ollama)
+    Llm,
+    /// Result caching (Redis)
+    Cache,
+}
+
+impl Capability {
+    /// Stable name used in logs
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Capability::Search => "search",
+            Capability::Llm => "llm",
+            Capability::Cache => "cache",
+        }
+    }
+
+    /// Service providing the capability
+    #[must_use]
+    pub fn service(&self) -> &'static str {
+        match self {
+            Capability::Search => "OpenSearch",
+            Capability::Llm => "LLM",
+            Capability::Cache => "Redis",
+        }
+    }
+}
+
+/// Whether a capability can be used
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", content = "reason", rename_all = "snake_case")]
+pub enum Availability {
+    Available,
+    /// The service is not configured
+    NotConfigured,
+    /// The service is configured but did not answer, with the probe error
+    Unreachable(String),
+}
+
+impl Availability {
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        matches!(self, Availability::Available)
+    }
+}
+
+/// Detected availability of the capabilities a command uses
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Capabilities {
+    detected: Vec<(Capability, Availability)>,
+}
+
+impl Capabilities {
+    /// Probe the services behind `wanted`, concurrently
+    ///
+    /// OpenSearch is configured by `opensearch.url` (with the environment
+    /// overrides), Redis by `REDIS_URL` and the LLM by the `LLM_*`
+    /// variables, whose defaults always count as configured.
+    pub async fn detect(config: &Config, wanted: &[Capability], timeout: Duration) -> Self {
+        let detected = futures::future::join_all(wanted.iter().map(|&capability| async move {
+            (capability, probe(config, capability, timeout).await)
+        }))
+        .await;
+        let capabilities = Self { detected };
+        for (capability, availability) in capabilities.missing() {
+            tracing::warn!(
+                capability = capability.as_str(),
+                availability = ?availability,
+                "{} unavailable, running in degraded mode",
+                capability.service()
+            );
+        }
+        capabilities
+    }
+
+    /// Record the availability of a capability, replacing an earlier one
+    #[must_use]
+    pub fn with(mut self, capability: Capability, availability: Availability) -> Self {
+        self.detected.retain(|(c, _)| *c != capability);
+        self.detected.push((capability, availability));
+        self
+    }
+
+    /// Detected availability of a capability, if it was detected
+    #[must_use]
+    pub fn availability(&self, capability: Capability) -> Option<&Availability> {
+        self.detected
+            .iter()
+            .find(|(c, _)| *c == capability)
+            .map(|(_, availability)| availability)
+    }
+
+    /// Whether a capability can be used; undetected ones are assumed to be
+    #[must_use]
+    pub fn is_available(&self, capability: Capability) -> bool {
+        self.availability(capability)
+            .map_or(true, Availability::is_available)
+    }
+
+    /// Detected capabilities that cannot be used, in detection order
+    pub fn missing(&self) -> impl Iterator<Item = (Capability, &Availability)> {
+        self.detected
+            .iter()
+            .filter(|(_, availability)| !availability.is_available())
+            .map(|(capability, availability)| (*capability, availability))
+    }
+}
+
+/// Availability of one capability
+async fn probe(config: &Config, capability: Capability, timeout: Duration) -> Availability {
+    match capability {
+        Capability::Search => {
+            let opensearch = config.opensearch.clone().with_env_overrides();
+            if opensearch.url.trim().is_empty() {
+                return Availability::NotConfigured;
+            }
+            let checker = DependencyChecker::new()
+                .with_timeout(timeout)
+                .with_opensearch(&opensearch);
+            dependency_availability(&checker, Dependency::OpenSearch).await
+        }
+        Capability::Cache => {
+            let url = match secrets::read_env("REDIS_URL") {
+                Ok(Some((_, url))) if !url.is_empty() => url,
+                Ok(_) => return Availability::NotConfigured,
+                Err(e) => return Availability::Unreachable(format!("{e:#}")),
+            };
+            let checker = DependencyChecker::new()
+                .with_timeout(timeout)
+                .with_redis(&url);
+            dependency_availability(&checker, Dependency::Redis).await
+        }
+        Capability::Llm => {
+            let llm = LlmConfig::from_env();
+            let endpoint = llm.endpoint.clone();
+            let client = match LlmClient::with_config(llm) {
+                Ok(client) => client,
+                Err(e) => return Availability::Unreachable(format!("{e:#}")),
+            };
+            match tokio::time::timeout(timeout, client.is_available()).await {
+                Ok(true) => Availability::Available,
+                Ok(false) => Availability::Unreachable(format!("{endpoint} did not answer")),
+                Err(_) => Availability::Unreachable(format!(
+                    "{endpoint} timed out after {:.1}s",
+                    timeout.as_secs_f64()
+                )),
+            }
+        }
+    }
+}
+
+/// Availability from a health probe; a degraded service is still usable
+async fn dependency_availability(
+    checker: &DependencyChecker,
+    dependency: Dependency,
+) -> Availability {
+    match checker.check_dependency(dependency).await {
+        Some(health) if health.status == HealthStatus::Unhealthy => Availability::Unreachable(
+            health
+                .message
+                .unwrap_or_else(|| format!("{} is unhealthy", health.target)),
+        ),
+        _ => Availability::Available,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undetected_capabilities_are_available() {
+        let capabilities = Capabilities::default()
+            .with(
+                Capability::Search,
+                Availability::Unreachable("refused".into()),
+            )
+            .with(Capability::Cache, Availability::NotConfigured)
+            .with(Capability::Cache, Availability::Available);
+
+        assert!(!capabilities.is_available(Capability::Search));
+        assert!(capabilities.is_available(Capability::Cache));
+        assert!(capabilities.is_available(Capability::Llm));
+        assert_eq!(
+            capabilities.missing().collect::<Vec<_>>(),
+            vec![(
+                Capability::Search,
+                &Availability::Unreachable("refused".into())
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detect_unconfigured_and_unreachable() {
+        let mut config = Config::default();
+        config.opensearch.url = "http://127.0.0.1:1".to_string();
+
+        let capabilities =
+            Capabilities::detect(&config, &[Capability::Search], Duration::from_secs(2)).await;
+        assert!(matches!(
+            capabilities.availability(Capability::Search),
+            Some(Availability::Unreachable(_))
+        ));
+
+        config.opensearch.url = String::new();
+        let capabilities =
+            Capabilities::detect(&config, &[Capability::Search], Duration::from_secs(2)).await;
+        assert_eq!(
+            capabilities.availability(Capability::Search),
+            Some(&Availability::NotConfigured)
+        );
+    }
+}
@@ -0,0 +1,868 @@
+//! Dependency health checks
+//!
+//! Probes the services baram depends on, each bounded by a timeout, and
+//! aggregates the results into one [`HealthReport`]:
+//! - SQLite: opens the metadata database read-only and runs `SELECT 1`
+//! - PostgreSQL: connects and runs `SELECT 1`
+//! - OpenSearch: reads `/_cluster/health` (yellow is degraded, red unhealthy)
+//! - OpenSearch index: reads the index mapping and compares the `embedding`
+//!   dimension with the embedding model's
+//! - Redis: `PING`
+//! - Ollama: lists the installed models via `/api/tags`
+//! - Embedding server: `GET /health`
+//!
+//! Only configured dependencies are probed, all of them concurrently. A report
+//! is ready when no required dependency is unhealthy; optional ones can only
+//! degrade it. Failed checks carry a suggested fix. Reports back
+//! `/api/health` and `/api/ready` of `baram serve` and the `baram doctor`
+//! command. Long-running modes keep probing the LLM and embedding server in
+//! the background with a [`DependencyMonitor`] (see [`monitor`]). Commands
+//! that can run without a service detect it once at startup and switch to a
+//! degraded mode (see [`capabilities`]).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::health::{Dependency, DependencyChecker};
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let checker = DependencyChecker::new()
+//!     .with_timeout(Duration::from_secs(3))
+//!     .with_postgres("postgres://baram@localhost/baram")
+//!     .with_redis("redis://localhost:6379")
+//!     .with_optional(Dependency::Redis);
+//!
+//! let report = checker.check().await;
+//! println!("{} (ready: {})", report.status.as_str(), report.is_ready());
+//! # }
+//! ```
+
+pub mod capabilities;
+pub mod monitor;
+
+pub use capabilities::{Availability, Capabilities, Capability, DEFAULT_DETECT_TIMEOUT};
+pub use monitor::{
+    DependencyGate, DependencyHealthConfig, DependencyMonitor, MonitorHandle, OutageNotifier,
+    Transition,
+};
+
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+
+use crate::config::{Config, OpenSearchConfig};
+use crate::embedding::EmbeddingConfig;
+use crate::llm::{LlmBackend, LlmConfig};
+
+/// Default timeout of a single probe
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+// ============================================================================
+// Report Types
+// ============================================================================
+
+/// Overall health status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl HealthStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Unhealthy => "unhealthy",
+        }
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            HealthStatus::Healthy => StatusCode::OK,
+            HealthStatus::Degraded => StatusCode::OK, // 200 to keep pod running
+            HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+/// A service baram depends on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Dependency {
+    Sqlite,
+    Postgres,
+    #[serde(rename = "opensearch")]
+    OpenSearch,
+    #[serde(rename = "opensearch_index")]
+    OpenSearchIndex,
+    Redis,
+    Ollama,
+    EmbeddingServer,
+}
+
+impl Dependency {
+    /// Stable name used in reports
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dependency::Sqlite => "sqlite",
+            Dependency::Postgres => "postgres",
+            Dependency::OpenSearch => "opensearch",
+            Dependency::OpenSearchIndex => "opensearch_index",
+            Dependency::Redis => "redis",
+            Dependency::Ollama => "ollama",
+            Dependency::EmbeddingServer => "embedding_server",
+        }
+    }
+}
+
+/// Result of probing one dependency
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DependencyHealth {
+    pub dependency: Dependency,
+    pub status: HealthStatus,
+    /// Whether the dependency is required for readiness
+    pub required: bool,
+    /// Probed endpoint, with credentials removed
+    pub target: String,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Suggested fix when the check is not healthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+/// Aggregated result of probing all configured dependencies
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checked_at: DateTime<Utc>,
+    pub checks: Vec<DependencyHealth>,
+}
+
+impl HealthReport {
+    /// Aggregate individual checks
+    ///
+    /// Unhealthy if a required dependency is unhealthy, degraded if any other
+    /// check is not healthy, healthy otherwise.
+    #[must_use]
+    pub fn from_checks(checks: Vec<DependencyHealth>) -> Self {
+        let status = if checks
+            .iter()
+            .any(|c| c.required && c.status == HealthStatus::Unhealthy)
+        {
+            HealthStatus::Unhealthy
+        } else if checks.iter().any(|c| c.status != HealthStatus::Healthy) {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        Self {
+            status,
+            checked_at: Utc::now(),
+            checks,
+        }
+    }
+
+    /// Whether every required dependency is usable
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.status != HealthStatus::Unhealthy
+    }
+
+    /// Check result of one dependency, if it was probed
+    #[must_use]
+    pub fn check(&self, dependency: Dependency) -> Option<&DependencyHealth> {
+        self.checks.iter().find(|c| c.dependency == dependency)
+    }
+}
+
+// ============================================================================
+// Dependency Checker
+// ============================================================================
+
+/// Endpoint of a dependency to probe
+#[derive(Debug, Clone)]
+enum Target {
+    Sqlite(PathBuf),
+    Postgres(String),
+    OpenSearch {
+        url: String,
+        username: Option<String>,
+        password: Option<String>,
+    },
+    OpenSearchIndex {
+        url: String,
+        index: String,
+        username: Option<String>,
+        password: Option<String>,
+        dimension: usize,
+    },
+    Redis(String),
+    Ollama(String),
+    EmbeddingServer(String),
+}
+
+impl Target {
+    fn dependency(&self) -> Dependency {
+        match self {
+            Target::Sqlite(_) => Dependency::Sqlite,
+            Target::Postgres(_) => Dependency::Postgres,
+            Target::OpenSearch { .. } => Dependency::OpenSearch,
+            Target::OpenSearchIndex { .. } => Dependency::OpenSearchIndex,
+            Target::Redis(_) => Dependency::Redis,
+            Target::Ollama(_) => Dependency::Ollama,
+            Target::EmbeddingServer(_) => Dependency::EmbeddingServer,
+        }
+    }
+
+    fn display(&self) -> String {
+        match self {
+            Target::Sqlite(path) => path.display().to_string(),
+            Target::Postgres(url) | Target::Redis(url) => redact_url(url),
+            Target::OpenSearch { url, .. } => redact_url(url),
+            Target::OpenSearchIndex { url, index, .. } => {
+                format!("{}/{index}", redact_url(url).trim_end_matches('/'))
+            }
+            Target::Ollama(url) | Target::EmbeddingServer(url) => url.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Probe {
+    target: Target,
+    required: bool,
+}
+
+/// Probe outcome before timing is attached
+type ProbeResult = Result<(HealthStatus, Option<String>), String>;
+
+/// Probes configured dependencies and aggregates a [`HealthReport`]
+#[derive(Debug, Clone)]
+pub struct DependencyChecker {
+    probes: Vec<Probe>,
+    timeout: Duration,
+    client: reqwest::Client,
+}
+
+impl Default for DependencyChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyChecker {
+    /// Create a checker with no dependencies and [`DEFAULT_CHECK_TIMEOUT`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            probes: Vec::new(),
+            timeout: DEFAULT_CHECK_TIMEOUT,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Checker for the dependencies configured for this deployment
+    ///
+    /// SQLite, PostgreSQL, OpenSearch and the article index mapping come from
+    /// the config and are required. The embedding server (`EMBEDDING_SERVER_URL`), Redis
+    /// (`REDIS_URL`, only if set) and Ollama (only if `LLM_BACKEND=ollama`)
+    /// are optional.
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        let embedding_url = std::env::var("EMBEDDING_SERVER_URL")
+            .unwrap_or_else(|_| "http://localhost:8090".to_string());
+
+        let mut checker = Self::new()
+            .with_sqlite(&config.database.sqlite_path)
+            .with_postgres(&config.database.postgres_url)
+            .with_opensearch(&config.opensearch)
+            .with_index_mapping(&config.opensearch, EmbeddingConfig::default().embedding_dim)
+            .with_embedding_server(&embedding_url)
+            .with_optional(Dependency::EmbeddingServer);
+
+        if let Ok(redis_url) = std::env::var("REDIS_URL") {
+            checker = checker
+                .with_redis(&redis_url)
+                .with_optional(Dependency::Redis);
+        }
+
+        let llm = LlmConfig::from_env();
+        if llm.backend == LlmBackend::Ollama {
+            checker = checker
+                .with_ollama(&llm.endpoint)
+                .with_optional(Dependency::Ollama);
+        }
+
+        checker
+    }
+
+    /// Set the timeout of each probe
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Probe the SQLite metadata database
+    #[must_use]
+    pub fn with_sqlite(self, path: impl AsRef<Path>) -> Self {
+        self.with_target(Target::Sqlite(path.as_ref().to_path_buf()))
+    }
+
+    /// Probe PostgreSQL
+    #[must_use]
+    pub fn with_postgres(self, url: &str) -> Self {
+        self.with_target(Target::Postgres(url.to_string()))
+    }
+
+    /// Probe the OpenSearch cluster
+    #[must_use]
+    pub fn with_opensearch(self, config: &OpenSearchConfig) -> Self {
+        self.with_target(Target::OpenSearch {
+            url: config.url.trim_end_matches('/').to_string(),
+            username: config.username.clone(),
+            password: config.password.clone(),
+        })
+    }
+
+    /// Check that the article index maps `embedding` with `dimension`
+    ///
+    /// A missing index only degrades the report, since `baram index`
+    /// creates it on first use.
+    #[must_use]
+    pub fn with_index_mapping(self, config: &OpenSearchConfig, dimension: usize) -> Self {
+        self.with_target(Target::OpenSearchIndex {
+            url: config.url.trim_end_matches('/').to_string(),
+            index: config.index_name.clone(),
+            username: config.username.clone(),
+            password: config.password.clone(),
+            dimension,
+        })
+    }
+
+    /// Probe Redis
+    #[must_use]
+    pub fn with_redis(self, url: &str) -> Self {
+        self.with_target(Target::Redis(url.to_string()))
+    }
+
+    /// Probe an Ollama server
+    #[must_use]
+    pub fn with_ollama(self, url: &str) -> Self {
+        self.with_target(Target::Ollama(url.trim_end_matches('/').to_string()))
+    }
+
+    /// Probe the embedding server
+    #[must_use]
+    pub fn with_embedding_server(self, url: &str) -> Self {
+        self.with_target(Target::EmbeddingServer(
+            url.trim_end_matches('/').to_string(),
+        ))
+    }
+
+    /// Mark a dependency as optional, so it can only degrade readiness
+    #[must_use]
+    pub fn with_optional(mut self, dependency: Dependency) -> Self {
+        for probe in &mut self.probes {
+            if probe.target.dependency() == dependency {
+                probe.required = false;
+            }
+        }
+        self
+    }
+
+    /// Dependencies that will be probed, in order
+    #[must_use]
+    pub fn dependencies(&self) -> Vec<Dependency> {
+        self.probes.iter().map(|p| p.target.dependency()).collect()
+    }
+
+    /// Add a required probe, replacing an earlier one for the same dependency
+    fn with_target(mut self, target: Target) -> Self {
+        let dependency = target.dependency();
+        self.probes.retain(|p| p.target.dependency() != dependency);
+        self.probes.push(Probe {
+            target,
+            required: true,
+        });
+        self
+    }
+
+    /// Probe all dependencies concurrently
+    pub async fn check(&self) -> HealthReport {
+        let checks = futures::future::join_all(self.probes.iter().map(|p| self.run(p))).await;
+        HealthReport::from_checks(checks)
+    }
+
+    /// Probe a single dependency, if configured
+    pub async fn check_dependency(&self, dependency: Dependency) -> Option<DependencyHealth> {
+        let probe = self
+            .probes
+            .iter()
+            .find(|p| p.target.dependency() == dependency)?;
+        Some(self.run(probe).await)
+    }
+
+    async fn run(&self, probe: &Probe) -> DependencyHealth {
+        let started = Instant::now();
+        let result = match tokio::time::timeout(self.timeout, self.probe(&probe.target)).await {
+            Ok(result) => result,
+            Err(_) => Err(format!(
+                "timed out after {:.1}s",
+                self.timeout.as_secs_f64()
+            )),
+        };
+        let (status, message) = match result {
+            Ok(outcome) => outcome,
+            Err(e) => (HealthStatus::Unhealthy, Some(e)),
+        };
+        let fix = (status != HealthStatus::Healthy)
+            .then(|| suggest_fix(&probe.target, message.as_deref().unwrap_or_default()));
+
+        DependencyHealth {
+            dependency: probe.target.dependency(),
+            status,
+            required: probe.required,
+            target: probe.target.display(),
+            latency_ms: started.elapsed().as_millis() as u64,
+            message,
+            fix,
+        }
+    }
+
+    async fn probe(&self, target: &Target) -> ProbeResult {
+        match target {
+            Target::Sqlite(path) => probe_sqlite(path.clone()).await,
+            Target::Postgres(url) => probe_postgres(url).await,
+            Target::OpenSearch {
+                url,
+                username,
+                password,
+            } => {
+                let mut request = self.client.get(format!("{url}/_cluster/health"));
+                if let Some(username) = username {
+                    request = request.basic_auth(username, password.as_ref());
+                }
+                let body = fetch_json(request).await?;
+                Ok(opensearch_status(&body))
+            }
+            Target::OpenSearchIndex {
+                url,
+                index,
+                username,
+                password,
+                dimension,
+            } => {
+                let mut request = self.client.get(format!("{url}/{index}/_mapping"));
+                if let Some(username) = username {
+                    request = request.basic_auth(username, password.as_ref());
+                }
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| format!("request failed: {e}"))?;
+                if response.status() == reqwest::StatusCode::NOT_FOUND {
+                    return Ok((
+                        HealthStatus::Degraded,
+                        Some(format!("index {index} does not exist")),
+                    ));
+                }
+                if !response.status().is_success() {
+                    return Err(format!("HTTP {}", response.status()));
+                }
+                let body: serde_json::Value = response
+                    .json()
+                    .await
+                    .map_err(|e| format!("invalid response: {e}"))?;
+                index_mapping_status(&body, *dimension)
+            }
+            Target::Redis(url) => probe_redis(url).await,
+            Target::Ollama(url) => {
+                let body = fetch_json(self.client.get(format!("{url}/api/tags"))).await?;
+                let models = body["models"].as_array().map_or(0, Vec::len);
+                if models == 0 {
+                    Ok((
+                        HealthStatus::Degraded,
+                        Some("no models installed".to_string()),
+                    ))
+                } else {
+                    Ok((HealthStatus::Healthy, Some(format!("{models} models"))))
+                }
+            }
+            Target::EmbeddingServer(url) => {
+                let response = self
+                    .client
+                    .get(format!("{url}/health"))
+                    .send()
+                    .await
+                    .map_err(|e| format!("request failed: {e}"))?;
+                if response.status().is_success() {
+                    Ok((HealthStatus::Healthy, None))
+                } else {
+                    Err(format!("HTTP {}", response.status()))
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Probes
+// ============================================================================
+
+async fn probe_sqlite(path: PathBuf) -> ProbeResult {
+    if !path.exists() {
+        return Ok((
+            HealthStatus::Degraded,
+            Some("database not created yet".to_string()),
+        ));
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let conn = rusqlite::Connection::open_with_flags(
+            &path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
+        )
+        .map_err(|e| format!("failed to open: {e}"))?;
+        conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+            .map_err(|e| format!("query failed: {e}"))?;
+        Ok((HealthStatus::Healthy, None))
+    })
+    .await
+    .map_err(|e| format!("probe panicked: {e}"))?
+}
+
+async fn probe_postgres(url: &str) -> ProbeResult {
+    let (client, connection) = tokio_postgres::connect(url, tokio_postgres::NoTls)
+        .await
+        .map_err(|e| format!("connection failed: {e}"))?;
+    let connection = tokio::spawn(connection);
+
+    let result = client
+        .simple_query("SELECT 1")
+        .await
+        .map(|_| (HealthStatus::Healthy, None))
+        .map_err(|e| format!("query failed: {e}"));
+
+    drop(client);
+    connection.abort();
+    result
+}
+
+async fn probe_redis(url: &str) -> ProbeResult {
+    let client = redis::Client::open(url).map_err(|e| format!("invalid URL: {e}"))?;
+    let mut conn = client
+        .get_multiplexed_async_connection()
+        .await
+        .map_err(|e| format!("connection failed: {e}"))?;
+    let reply: String = redis::cmd("PING")
+        .query_async(&mut conn)
+        .await
+        .map_err(|e| format!("PING failed: {e}"))?;
+
+    if reply == "PONG" {
+        Ok((HealthStatus::Healthy, None))
+    } else {
+        Err(format!("unexpected PING reply: {reply}"))
+    }
+}
+
+async fn fetch_json(request: reqwest::RequestBuilder) -> Result<serde_json::Value, String> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("invalid response: {e}"))
+}
+
+/// Map an OpenSearch `/_cluster/health` body to a status
+fn opensearch_status(body: &serde_json::Value) -> (HealthStatus, Option<String>) {
+    let cluster = body["status"].as_str().unwrap_or("unknown");
+    let status = match cluster {
+        "green" => HealthStatus::Healthy,
+        "yellow" => HealthStatus::Degraded,
+        _ => HealthStatus::Unhealthy,
+    };
+    (status, Some(format!("cluster status {cluster}")))
+}
+
+/// Check the `embedding` field of a `/<index>/_mapping` body
+///
+/// The body is keyed by the concrete index name, which differs from the
+/// configured one when it is an alias.
+fn index_mapping_status(body: &serde_json::Value, dimension: usize) -> ProbeResult {
+    let embedding = body
+        .as_object()
+        .and_then(|indices| indices.values().next())
+        .map(|index| &index["mappings"]["properties"]["embedding"])
+        .ok_or_else(|| "empty mapping response".to_string())?;
+
+    match embedding["type"].as_str() {
+        Some("knn_vector") => {}
+        Some(other) => return Err(format!("embedding is mapped as {other}, not knn_vector")),
+        None => return Err("index has no embedding field".to_string()),
+    }
+    match embedding["dimension"].as_u64() {
+        Some(found) if found as usize == dimension => Ok((
+            HealthStatus::Healthy,
+            Some(format!("embedding dimension {found}")),
+        )),
+        Some(found) => Err(format!(
+            "embedding dimension {found} does not match the model's {dimension}"
+        )),
+        None => Err("embedding mapping has no dimension".to_string()),
+    }
+}
+
+/// Actionable fix for a failed or degraded check
+fn suggest_fix(target: &Target, message: &str) -> String {
+    let unauthorized = message.contains("401") || message.contains("403");
+    let unreachable = message.starts_with("request failed") || message.starts_with("timed out");
+    match target {
+        Target::Sqlite(_) if message.contains("not created") => {
+            "Run `baram crawl` once to create it, or point database.sqlite_path at an existing database".to_string()
+        }
+        Target::Sqlite(_) => {
+            "Check that database.sqlite_path is a readable SQLite file".to_string()
+        }
+        Target::Postgres(_) if message.contains("password authentication failed") => {
+            "Check the user and password in database.postgres_url (or POSTGRES_URL)".to_string()
+        }
+        Target::Postgres(_) if message.contains("does not exist") => {
+            "Create the database (e.g. `createdb baram`) or fix its name in database.postgres_url"
+                .to_string()
+        }
+        Target::Postgres(_) => {
+            "Start PostgreSQL (`docker compose -f docker/docker-compose.yml up -d postgres`) and check database.postgres_url".to_string()
+        }
+        Target::OpenSearch { .. } | Target::OpenSearchIndex { .. } if unauthorized => {
+            "Set opensearch.username and opensearch.password (or OPENSEARCH_USERNAME and OPENSEARCH_PASSWORD)".to_string()
+        }
+        Target::OpenSearch { .. } if message.contains("yellow") => {
+            "Replicas cannot be allocated; on a single node set `index.number_of_replicas` to 0"
+                .to_string()
+        }
+        Target::OpenSearch { .. } | Target::OpenSearchIndex { .. } if unreachable => {
+            "Start OpenSearch (`docker compose -f docker/docker-compose.yml up -d opensearch`) and check opensearch.url".to_string()
+        }
+        Target::OpenSearch { .. } => {
+            "Check the cluster with `curl <opensearch.url>/_cluster/allocation/explain`".to_string()
+        }
+        Target::OpenSearchIndex { .. } if message.contains("does not exist") => {
+            "Run `baram index` to create the index".to_string()
+        }
+        Target::OpenSearchIndex { .. } => {
+            "Recreate the index with `baram index --force` so its mapping matches the embedding model".to_string()
+        }
+        Target::Redis(_) => {
+            "Start Redis (`docker compose -f docker/docker-compose.yml up -d redis`) or unset REDIS_URL".to_string()
+        }
+        Target::Ollama(_) if message.contains("no models") => {
+            "Pull the model set in LLM_MODEL (`ollama pull <model>`)".to_string()
+        }
+        Target::Ollama(_) => "Start Ollama (`ollama serve`) or fix LLM_ENDPOINT".to_string(),
+        Target::EmbeddingServer(_) => {
+            "Start it with `baram embedding-server --port 8090` or set EMBEDDING_SERVER_URL"
+                .to_string()
+        }
+    }
+}
+
+/// Remove the password from a connection URL
+pub(crate) fn redact_url(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(mut parsed) if parsed.password().is_some() => {
+            let _ = parsed.set_password(Some("***"));
+            parsed.to_string()
+        }
+        Ok(parsed) => parsed.to_string(),
+        // Key-value connection strings may carry a password anywhere
+        Err(_) => "<configured>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(dependency: Dependency, status: HealthStatus, required: bool) -> DependencyHealth {
+        DependencyHealth {
+            dependency,
+            status,
+            required,
+            target: String::new(),
+            latency_ms: 0,
+            message: None,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_report_aggregation() {
+        let healthy = HealthReport::from_checks(vec![
+            check(Dependency::Postgres, HealthStatus::Healthy, true),
+            check(Dependency::Redis, HealthStatus::Healthy, false),
+        ]);
+        assert_eq!(healthy.status, HealthStatus::Healthy);
+        assert!(healthy.is_ready());
+
+        let optional_down = HealthReport::from_checks(vec![
+            check(Dependency::Postgres, HealthStatus::Healthy, true),
+            check(Dependency::Redis, HealthStatus::Unhealthy, false),
+        ]);
+        assert_eq!(optional_down.status, HealthStatus::Degraded);
+        assert!(optional_down.is_ready());
+
+        let required_down = HealthReport::from_checks(vec![
+            check(Dependency::Postgres, HealthStatus::Unhealthy, true),
+            check(Dependency::Redis, HealthStatus::Healthy, false),
+        ]);
+        assert_eq!(required_down.status, HealthStatus::Unhealthy);
+        assert!(!required_down.is_ready());
+        assert_eq!(
+            required_down.check(Dependency::Postgres).unwrap().status,
+            HealthStatus::Unhealthy
+        );
+        assert!(required_down.check(Dependency::Ollama).is_none());
+    }
+
+    #[test]
+    fn test_builder_replaces_and_marks_optional() {
+        let checker = DependencyChecker::new()
+            .with_redis("redis://a:6379")
+            .with_ollama("http://localhost:11434/")
+            .with_redis("redis://b:6379")
+            .with_optional(Dependency::Redis);
+
+        assert_eq!(
+            checker.dependencies(),
+            vec![Dependency::Ollama, Dependency::Redis]
+        );
+        let redis = checker.probes.last().unwrap();
+        assert!(!redis.required);
+        assert_eq!(redis.target.display(), "redis://b:6379");
+        assert_eq!(checker.probes[0].target.display(), "http://localhost:11434");
+    }
+
+    #[test]
+    fn test_opensearch_status() {
+        let status = |s: &str| opensearch_status(&serde_json::json!({ "status": s })).0;
+        assert_eq!(status("green"), HealthStatus::Healthy);
+        assert_eq!(status("yellow"), HealthStatus::Degraded);
+        assert_eq!(status("red"), HealthStatus::Unhealthy);
+    }
+
+    #[test]
+    fn test_index_mapping_status() {
+        let mapping = |embedding: serde_json::Value| {
+            serde_json::json!({
+                "baram-articles-v2": { "mappings": { "properties": { "embedding": embedding } } }
+            })
+        };
+
+        let ok = index_mapping_status(
+            &mapping(serde_json::json!({ "type": "knn_vector", "dimension": 384 })),
+            384,
+        )
+        .unwrap();
+        assert_eq!(ok.0, HealthStatus::Healthy);
+
+        let mismatch = index_mapping_status(
+            &mapping(serde_json::json!({ "type": "knn_vector", "dimension": 1024 })),
+            384,
+        )
+        .unwrap_err();
+        assert!(mismatch.contains("1024"));
+        assert!(
+            index_mapping_status(&mapping(serde_json::json!({ "type": "float" })), 384).is_err()
+        );
+        assert!(index_mapping_status(&mapping(serde_json::Value::Null), 384).is_err());
+    }
+
+    #[test]
+    fn test_suggest_fix() {
+        let postgres = Target::Postgres("postgres://localhost/baram".to_string());
+        assert!(suggest_fix(
+            &postgres,
+            "connection failed: password authentication failed for user \"baram\""
+        )
+        .contains("password"));
+        assert!(suggest_fix(&postgres, "connection failed: refused").contains("docker compose"));
+
+        let opensearch = Target::OpenSearch {
+            url: "http://localhost:9200".to_string(),
+            username: None,
+            password: None,
+        };
+        assert!(suggest_fix(&opensearch, "HTTP 401 Unauthorized").contains("opensearch.username"));
+
+        let index = Target::OpenSearchIndex {
+            url: "http://localhost:9200".to_string(),
+            index: "baram-articles".to_string(),
+            username: None,
+            password: None,
+            dimension: 384,
+        };
+        assert!(suggest_fix(&index, "index baram-articles does not exist").contains("baram index"));
+        assert!(suggest_fix(&index, "embedding dimension 1024").contains("--force"));
+        assert!(suggest_fix(&index, "request failed: refused").contains("Start OpenSearch"));
+    }
+
+    #[test]
+    fn test_redact_url() {
+        assert_eq!(
+            redact_url("postgres://baram:secret@db:5432/baram"),
+            "postgres://baram:***@db:5432/baram"
+        );
+        assert_eq!(
+            redact_url("redis://localhost:6379"),
+            "redis://localhost:6379"
+        );
+        assert_eq!(
+            redact_url("host=db user=baram password=secret"),
+            "<configured>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_missing_sqlite_and_unreachable_server() {
+        let report = DependencyChecker::new()
+            .with_timeout(Duration::from_secs(2))
+            .with_sqlite("/nonexistent/baram/metadata.db")
+            .with_embedding_server("http://127.0.0.1:1")
+            .with_optional(Dependency::EmbeddingServer)
+            .check()
+            .await;
+
+        let sqlite = report.check(Dependency::Sqlite).unwrap();
+        assert_eq!(sqlite.status, HealthStatus::Degraded);
+        let embedding = report.check(Dependency::EmbeddingServer).unwrap();
+        assert_eq!(embedding.status, HealthStatus::Unhealthy);
+        assert!(embedding.message.is_some());
+        assert!(embedding
+            .fix
+            .as_deref()
+            .unwrap()
+            .contains("embedding-server"));
+        assert!(sqlite.fix.is_some());
+        assert_eq!(report.status, HealthStatus::Degraded);
+        assert!(report.is_ready());
+    }
+}
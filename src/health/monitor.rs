@@ -0,0 +1,550 @@
+//! Background probes of the LLM and embedding server
+//!
+//! Long-running modes (`baram pipeline --index`, `baram ontology --llm`) start
+//! a [`DependencyMonitor`] for the dependencies they call. It probes them every
+//! `probe_interval_secs` and publishes each one's availability through a
+//! [`DependencyGate`]. Stages check the gate before calling the dependency and
+//! hold their work back while it is down, picking it up again as soon as a
+//! probe succeeds, instead of failing every article during an outage.
+//!
+//! Every transition is logged, exported as `baram_dependency_up` and sent to
+//! the notification channels by an [`OutageNotifier`]. Outage notifications
+//! are rate-limited per dependency, so a flapping service is announced at most
+//! once per `notify_interval_secs`; a recovery is only announced when its
+//! outage was.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications.dependency_health]
+//! probe_interval_secs = 30
+//! notify_interval_secs = 900
+//! max_wait_secs = 600
+//! ```
+//!
+//! ```no_run
+//! use baram::health::{Dependency, DependencyChecker, DependencyMonitor};
+//! use std::time::Duration;
+//!
+//! # async fn example() {
+//! let monitor = DependencyMonitor::new(
+//!     DependencyChecker::new().with_embedding_server("http://localhost:8090"),
+//! );
+//! let gate = monitor.gate(Dependency::EmbeddingServer).unwrap();
+//! let _handle = monitor.start().await;
+//!
+//! if gate.wait_available(Duration::from_secs(600)).await {
+//!     // embed
+//! }
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, RwLock};
+use tokio::task::JoinHandle;
+
+use super::{Dependency, DependencyChecker, HealthStatus};
+use crate::metrics;
+use crate::notifications::{
+    Alert, AlertCondition, AlertSeverity, NotificationManager, NotificationsConfig,
+};
+
+/// Background probe and outage notification settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyHealthConfig {
+    /// How often the LLM and embedding server are probed (seconds)
+    #[serde(default = "default_probe_interval")]
+    pub probe_interval_secs: u64,
+    /// Minimum time between outage notifications of one dependency (seconds)
+    #[serde(default = "default_notify_interval")]
+    pub notify_interval_secs: u64,
+    /// How long a stage holds its work back for a dependency to recover (seconds)
+    #[serde(default = "default_max_wait")]
+    pub max_wait_secs: u64,
+}
+
+fn default_probe_interval() -> u64 {
+    30
+}
+
+fn default_notify_interval() -> u64 {
+    900
+}
+
+fn default_max_wait() -> u64 {
+    600
+}
+
+impl Default for DependencyHealthConfig {
+    fn default() -> Self {
+        Self {
+            probe_interval_secs: default_probe_interval(),
+            notify_interval_secs: default_notify_interval(),
+            max_wait_secs: default_max_wait(),
+        }
+    }
+}
+
+impl DependencyHealthConfig {
+    /// Get the probe interval
+    #[must_use]
+    pub fn probe_interval(&self) -> Duration {
+        Duration::from_secs(self.probe_interval_secs)
+    }
+
+    /// Get the minimum time between outage notifications
+    #[must_use]
+    pub fn notify_interval(&self) -> Duration {
+        Duration::from_secs(self.notify_interval_secs)
+    }
+
+    /// Get how long stages wait for a dependency to recover
+    #[must_use]
+    pub fn max_wait(&self) -> Duration {
+        Duration::from_secs(self.max_wait_secs)
+    }
+
+    /// Validate the settings
+    pub fn validate(&self) -> Result<(), String> {
+        if self.probe_interval_secs == 0 {
+            return Err("probe_interval_secs must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A dependency going down or coming back
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    pub dependency: Dependency,
+    /// Whether the dependency is available now
+    pub available: bool,
+    /// Probe error of an outage
+    pub message: Option<String>,
+    /// How long the dependency was down, on recovery
+    pub downtime: Option<Duration>,
+}
+
+/// Availability of one monitored dependency
+///
+/// Clones observe the same dependency. A gate whose monitor has stopped keeps
+/// its last state.
+#[derive(Debug, Clone)]
+pub struct DependencyGate {
+    dependency: Dependency,
+    rx: watch::Receiver<bool>,
+}
+
+impl DependencyGate {
+    /// Get the monitored dependency
+    #[must_use]
+    pub fn dependency(&self) -> Dependency {
+        self.dependency
+    }
+
+    /// Whether the last probe found the dependency available
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        *self.rx.borrow()
+    }
+
+    /// Wait until the dependency is available, at most `max_wait`
+    ///
+    /// Returns whether it is available.
+    pub async fn wait_available(&self, max_wait: Duration) -> bool {
+        let mut rx = self.rx.clone();
+        let waited = tokio::time::timeout(max_wait, rx.wait_for(|available| *available)).await;
+        matches!(waited, Ok(Ok(_)))
+    }
+}
+
+/// Probe state of one dependency
+struct Tracked {
+    dependency: Dependency,
+    tx: watch::Sender<bool>,
+    down_since: Option<Instant>,
+}
+
+/// Periodic probes of a set of dependencies
+pub struct DependencyMonitor {
+    checker: DependencyChecker,
+    tracked: Vec<Tracked>,
+    interval: Duration,
+    notifier: Option<OutageNotifier>,
+}
+
+impl DependencyMonitor {
+    /// Monitor every dependency of `checker`
+    ///
+    /// Dependencies count as available until the first probe.
+    #[must_use]
+    pub fn new(checker: DependencyChecker) -> Self {
+        let tracked = checker
+            .dependencies()
+            .into_iter()
+            .map(|dependency| Tracked {
+                dependency,
+                tx: watch::Sender::new(true),
+                down_since: None,
+            })
+            .collect();
+        Self {
+            checker,
+            tracked,
+            interval: DependencyHealthConfig::default().probe_interval(),
+            notifier: None,
+        }
+    }
+
+    /// Set the probe interval
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Send transitions through a notifier
+    #[must_use]
+    pub fn with_notifier(mut self, notifier: OutageNotifier) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Get the gate of a monitored dependency
+    #[must_use]
+    pub fn gate(&self, dependency: Dependency) -> Option<DependencyGate> {
+        self.tracked
+            .iter()
+            .find(|t| t.dependency == dependency)
+            .map(|t| DependencyGate {
+                dependency,
+                rx: t.tx.subscribe(),
+            })
+    }
+
+    /// Probe every dependency once and apply the results
+    ///
+    /// Degraded dependencies count as available. Returns the transitions,
+    /// which have already been logged and notified.
+    pub async fn probe(&mut self) -> Vec<Transition> {
+        let mut transitions = Vec::new();
+        for i in 0..self.tracked.len() {
+            let dependency = self.tracked[i].dependency;
+            let Some(health) = self.checker.check_dependency(dependency).await else {
+                continue;
+            };
+            let available = health.status != HealthStatus::Unhealthy;
+            metrics::update_dependency_up(dependency.as_str(), available);
+            if let Some(transition) = self.apply(i, available, health.message, Instant::now()) {
+                transitions.push(transition);
+            }
+        }
+
+        for transition in &transitions {
+            if transition.available {
+                tracing::info!(
+                    dependency = transition.dependency.as_str(),
+                    downtime_secs = transition.downtime.map(|d| d.as_secs()),
+                    "Dependency recovered, resuming held-back work"
+                );
+            } else {
+                tracing::warn!(
+                    dependency = transition.dependency.as_str(),
+                    error = transition.message.as_deref().unwrap_or_default(),
+                    "Dependency unavailable, holding back work"
+                );
+            }
+            if let Some(notifier) = self.notifier.as_mut() {
+                notifier.notify(transition).await;
+            }
+        }
+        transitions
+    }
+
+    /// Record a probe result, returning the transition it caused
+    fn apply(
+        &mut self,
+        index: usize,
+        available: bool,
+        message: Option<String>,
+        now: Instant,
+    ) -> Option<Transition> {
+        let tracked = &mut self.tracked[index];
+        let was_available = tracked.tx.send_replace(available);
+        if was_available == available {
+            return None;
+        }
+
+        let downtime = if available {
+            tracked.down_since.take().map(|since| now - since)
+        } else {
+            tracked.down_since = Some(now);
+            None
+        };
+        Some(Transition {
+            dependency: tracked.dependency,
+            available,
+            message: (!available).then_some(message).flatten(),
+            downtime,
+        })
+    }
+
+    /// Probe once, then keep probing in the background
+    ///
+    /// Gates reflect the first probe when this returns. Probing stops when
+    /// the handle is dropped.
+    pub async fn start(mut self) -> MonitorHandle {
+        self.probe().await;
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.interval);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                self.probe().await;
+            }
+        });
+        MonitorHandle { task }
+    }
+}
+
+/// Running [`DependencyMonitor`], stopped on drop
+pub struct MonitorHandle {
+    task: JoinHandle<()>,
+}
+
+impl Drop for MonitorHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Sends dependency outages and recoveries to the notification channels
+pub struct OutageNotifier {
+    manager: Arc<RwLock<NotificationManager>>,
+    interval: Duration,
+    /// When each dependency's last outage was announced
+    last_sent: HashMap<Dependency, Instant>,
+    /// Dependencies whose current outage was announced
+    announced: HashSet<Dependency>,
+}
+
+impl OutageNotifier {
+    /// Create a notifier announcing each dependency's outages at most once per `interval`
+    #[must_use]
+    pub fn new(manager: Arc<RwLock<NotificationManager>>, interval: Duration) -> Self {
+        Self {
+            manager,
+            interval,
+            last_sent: HashMap::new(),
+            announced: HashSet::new(),
+        }
+    }
+
+    /// Create a notifier from the `[notifications]` section
+    ///
+    /// Returns `Ok(None)` if no channels are configured.
+    pub fn from_config(config: &NotificationsConfig) -> Result<Option<Self>, String> {
+        if config.channels.is_empty() {
+            return Ok(None);
+        }
+        let manager = NotificationManager::from_config(config)?;
+        Ok(Some(Self::new(
+            Arc::new(RwLock::new(manager)),
+            config.dependency_health.notify_interval(),
+        )))
+    }
+
+    /// Announce a transition unless rate-limited
+    pub async fn notify(&mut self, transition: &Transition) {
+        let Some(alert) = self.alert_for(transition, Instant::now()) else {
+            tracing::debug!(
+                dependency = transition.dependency.as_str(),
+                "Dependency notification rate-limited"
+            );
+            return;
+        };
+        self.manager.read().await.deliver(&alert, &[]).await;
+    }
+
+    /// Alert to send for a transition at `now`, if any
+    fn alert_for(&mut self, transition: &Transition, now: Instant) -> Option<Alert> {
+        let dependency = transition.dependency;
+        let condition = AlertCondition::SourceFailure {
+            source: dependency.as_str().to_string(),
+            failure_count: 1,
+        };
+
+        if transition.available {
+            if !self.announced.remove(&dependency) {
+                return None;
+            }
+            let downtime = transition.downtime.unwrap_or_default().as_secs();
+            let message = format!(
+                "{} recovered after {}m {}s; held-back work resumed",
+                dependency.as_str(),
+                downtime / 60,
+                downtime % 60
+            );
+            return Some(Alert::new(condition, AlertSeverity::Info, message));
+        }
+
+        if self
+            .last_sent
+            .get(&dependency)
+            .is_some_and(|sent| now.duration_since(*sent) < self.interval)
+        {
+            return None;
+        }
+        self.last_sent.insert(dependency, now);
+        self.announced.insert(dependency);
+        let message = format!(
+            "{} is unavailable ({}); dependent work is held back until it recovers",
+            dependency.as_str(),
+            transition.message.as_deref().unwrap_or("probe failed")
+        );
+        Some(Alert::new(condition, AlertSeverity::Warning, message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> DependencyMonitor {
+        DependencyMonitor::new(
+            DependencyChecker::new()
+                .with_timeout(Duration::from_secs(1))
+                .with_embedding_server("http://127.0.0.1:1"),
+        )
+    }
+
+    fn outage(dependency: Dependency) -> Transition {
+        Transition {
+            dependency,
+            available: false,
+            message: Some("connection refused".to_string()),
+            downtime: None,
+        }
+    }
+
+    fn recovery(dependency: Dependency) -> Transition {
+        Transition {
+            dependency,
+            available: true,
+            message: None,
+            downtime: Some(Duration::from_secs(90)),
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let mut monitor = monitor();
+        let gate = monitor.gate(Dependency::EmbeddingServer).unwrap();
+        assert!(gate.is_available());
+        assert!(monitor.gate(Dependency::Ollama).is_none());
+
+        let start = Instant::now();
+        assert!(monitor.apply(0, true, None, start).is_none());
+
+        let down = monitor
+            .apply(0, false, Some("refused".to_string()), start)
+            .unwrap();
+        assert!(!down.available);
+        assert_eq!(down.message.as_deref(), Some("refused"));
+        assert!(!gate.is_available());
+        assert!(monitor.apply(0, false, None, start).is_none());
+
+        let up = monitor
+            .apply(0, true, None, start + Duration::from_secs(45))
+            .unwrap();
+        assert!(up.available);
+        assert_eq!(up.downtime, Some(Duration::from_secs(45)));
+        assert!(gate.is_available());
+    }
+
+    #[tokio::test]
+    async fn test_probe_marks_unreachable_dependency_down() {
+        let mut monitor = monitor();
+        let gate = monitor.gate(Dependency::EmbeddingServer).unwrap();
+
+        let transitions = monitor.probe().await;
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].dependency, Dependency::EmbeddingServer);
+        assert!(!gate.is_available());
+        assert!(!gate.wait_available(Duration::from_millis(20)).await);
+
+        // Still down: no new transition
+        assert!(monitor.probe().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gate_resumes_on_recovery() {
+        let mut monitor = monitor();
+        let gate = monitor.gate(Dependency::EmbeddingServer).unwrap();
+        monitor.apply(0, false, None, Instant::now());
+
+        let waiting = tokio::spawn({
+            let gate = gate.clone();
+            async move { gate.wait_available(Duration::from_secs(5)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        monitor.apply(0, true, None, Instant::now());
+        assert!(waiting.await.unwrap());
+    }
+
+    #[test]
+    fn test_notifier_rate_limits_outages() {
+        let manager = Arc::new(RwLock::new(NotificationManager::new()));
+        let mut notifier = OutageNotifier::new(manager, Duration::from_secs(900));
+        let start = Instant::now();
+
+        let alert = notifier
+            .alert_for(&outage(Dependency::Ollama), start)
+            .unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Warning);
+        assert!(alert.message.contains("ollama"));
+
+        // Recovery of an announced outage is announced
+        let alert = notifier
+            .alert_for(&recovery(Dependency::Ollama), start)
+            .unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Info);
+        assert!(alert.message.contains("1m 30s"));
+
+        // A flap within the interval is neither announced nor its recovery
+        let flap = start + Duration::from_secs(60);
+        assert!(notifier
+            .alert_for(&outage(Dependency::Ollama), flap)
+            .is_none());
+        assert!(notifier
+            .alert_for(&recovery(Dependency::Ollama), flap)
+            .is_none());
+
+        // Other dependencies are limited separately
+        assert!(notifier
+            .alert_for(&outage(Dependency::EmbeddingServer), flap)
+            .is_some());
+
+        let later = start + Duration::from_secs(901);
+        assert!(notifier
+            .alert_for(&outage(Dependency::Ollama), later)
+            .is_some());
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config: DependencyHealthConfig = toml::from_str("probe_interval_secs = 10").unwrap();
+        assert_eq!(config.probe_interval(), Duration::from_secs(10));
+        assert_eq!(config.notify_interval_secs, 900);
+        assert!(config.validate().is_ok());
+
+        let zero = DependencyHealthConfig {
+            probe_interval_secs: 0,
+            ..Default::default()
+        };
+        assert!(zero.validate().is_err());
+    }
+}
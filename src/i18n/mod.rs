@@ -80,7 +80,7 @@ pub fn init_from_env() {
 /// - ko-KR, ko_KR, korean -> ko
 /// - en-US, en_US, english -> en
 /// - zh-CN, zh_CN, chinese -> zh
-fn normalize_locale(locale: &str) -> String {
+pub fn normalize_locale(locale: &str) -> String {
     let lower = locale.to_lowercase();
 
     if lower.starts_with("ko") || lower == "korean" {
@@ -107,7 +107,7 @@ fn normalize_locale(locale: &str) -> String {
 /// use baram::i18n::t;
 ///
 /// let msg = t!("cli.crawl.starting");
-/// let msg_with_args = t!("cli.crawl.progress", count = 10, total = 100);
+/// let msg_with_args = t!("cli.crawl.found_urls", count = 10);
 /// ```
 #[doc(inline)]
 pub use rust_i18n::t;
@@ -134,6 +134,25 @@ mod tests {
         assert_eq!(normalize_locale("unknown"), "en");
     }
 
+    #[test]
+    fn test_locale_files_loaded() {
+        assert_eq!(rust_i18n::available_locales!(), vec!["en", "ko", "zh"]);
+
+        assert_eq!(
+            t!("cli.crawl.found_urls", locale = "en", count = 3),
+            "Found 3 article URLs"
+        );
+        assert_eq!(
+            t!("cli.crawl.found_urls", locale = "ko", count = 3),
+            "발견된 기사 URL: 3개"
+        );
+        assert_eq!(
+            t!("cli.crawl.found_urls", locale = "zh", count = 3),
+            "找到 3 个文章 URL"
+        );
+        assert_eq!(t!("errors.fetch.timeout", locale = "ko"), "요청 시간 초과");
+    }
+
     #[test]
     fn test_set_and_get_locale() {
         set_locale("ko");
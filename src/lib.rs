@@ -14,6 +14,7 @@
 //! - [`embedding`] - Vector embedding and OpenSearch integration
 //! - [`ontology`] - Knowledge graph and ontology extraction
 //! - [`utils`] - Common utilities and helpers
+//! - `python` - Python bindings (`python` feature)
 //!
 //! # Example
 //!
@@ -41,6 +42,8 @@ pub mod coordinator;
 pub mod crawler;
 pub mod embedding;
 pub mod error;
+pub mod export;
+pub mod health;
 pub mod i18n;
 pub mod llm;
 pub mod metrics;
@@ -48,6 +51,8 @@ pub mod models;
 pub mod notifications;
 pub mod ontology;
 pub mod parser;
+#[cfg(feature = "python")]
+pub mod python;
 pub mod scheduler;
 pub mod storage;
 pub mod utils;
@@ -63,4 +68,6 @@ pub mod prelude {
 }
 
 // Direct re-exports for convenience
-pub use models::{CrawlState, CrawlStats, NewsCategory, ParsedArticle};
+pub use models::{
+    ArticleMedia, ArticleSource, CrawlState, CrawlStats, NewsCategory, ParsedArticle,
+};
@@ -1,13 +1,31 @@
 //! LLM client for relation extraction
 //!
-//! This module provides LLM integration using vLLM (OpenAI-compatible API) or Ollama
-//! for advanced relation extraction tasks like "Said" relations from Korean news.
+//! This module provides LLM integration using vLLM, OpenAI-compatible hosted APIs
+//! (OpenAI, OpenRouter) or Ollama for advanced relation extraction tasks like
+//! "Said" relations from Korean news, and for answering questions from search
+//! result passages. The HTTP calls live in [`provider`].
+//!
+//! Extraction asks for JSON matching a [`schema`] through the backend's
+//! structured output mode and validates the reply. Backends without one get
+//! the plain prompt, and the reply goes through a lenient parser that repairs
+//! broken JSON.
+
+pub mod provider;
+pub mod schema;
+
+pub use provider::{LlmProvider, MockProvider, OllamaProvider, OpenAiProvider};
+pub use schema::OutputSchema;
 
 use anyhow::{Context, Result};
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
+use crate::config::secrets;
+
 /// LLM backend type
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum LlmBackend {
@@ -16,12 +34,31 @@ pub enum LlmBackend {
     Vllm,
     /// Ollama API
     Ollama,
+    /// Hosted OpenAI-compatible API (OpenAI, OpenRouter), authenticated with
+    /// `LLM_API_KEY`; named `hosted`, as `openai` has always meant vLLM
+    OpenAi,
+    /// Canned responses, no server (tests and dry runs)
+    Mock,
 }
 
 impl LlmBackend {
+    /// Backend name (`vllm`, `ollama`, `hosted` or `mock`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LlmBackend::Vllm => "vllm",
+            LlmBackend::Ollama => "ollama",
+            LlmBackend::OpenAi => "hosted",
+            LlmBackend::Mock => "mock",
+        }
+    }
+
+    /// Backend named `s`; unknown names, including `openai` (vLLM's
+    /// OpenAI-compatible API), are vLLM
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "ollama" => LlmBackend::Ollama,
+            "hosted" | "openrouter" => LlmBackend::OpenAi,
+            "mock" => LlmBackend::Mock,
             _ => LlmBackend::Vllm,
         }
     }
@@ -53,6 +90,12 @@ pub struct LlmConfig {
 
     /// Initial retry delay in milliseconds (doubles with each retry)
     pub retry_delay_ms: u64,
+
+    /// Bearer token for hosted APIs
+    pub api_key: Option<String>,
+
+    /// Request schema-constrained JSON where the backend supports it
+    pub json_mode: bool,
 }
 
 impl Default for LlmConfig {
@@ -66,6 +109,8 @@ impl Default for LlmConfig {
             temperature: 0.1,
             max_retries: 3,
             retry_delay_ms: 1000,
+            api_key: None,
+            json_mode: true,
         }
     }
 }
@@ -80,6 +125,8 @@ impl LlmConfig {
         let (default_endpoint, default_model) = match backend {
             LlmBackend::Vllm => ("http://localhost:8002", "qwen2.5"),
             LlmBackend::Ollama => ("http://localhost:11434", "qwen2.5:7b"),
+            LlmBackend::OpenAi => ("https://api.openai.com", "gpt-4o-mini"),
+            LlmBackend::Mock => ("", "mock"),
         };
 
         Self {
@@ -107,67 +154,20 @@ impl LlmConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(1000),
+            api_key: secrets::read_env("LLM_API_KEY")
+                .unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "Ignoring LLM API key");
+                    None
+                })
+                .map(|(_, key)| key)
+                .filter(|key| !key.is_empty()),
+            json_mode: std::env::var("LLM_JSON_MODE").map_or(true, |v| {
+                !matches!(v.to_lowercase().as_str(), "0" | "false" | "off")
+            }),
         }
     }
 }
 
-// ============================================================================
-// OpenAI-compatible API structures (for vLLM)
-// ============================================================================
-
-#[derive(Debug, Serialize)]
-struct OpenAIRequest {
-    model: String,
-    messages: Vec<OpenAIMessage>,
-    max_tokens: u32,
-    temperature: f32,
-    stream: bool,
-}
-
-#[derive(Debug, Serialize)]
-struct OpenAIMessage {
-    role: String,
-    content: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct OpenAIResponse {
-    choices: Vec<OpenAIChoice>,
-}
-
-#[derive(Debug, Deserialize)]
-struct OpenAIChoice {
-    message: OpenAIMessageResponse,
-}
-
-#[derive(Debug, Deserialize)]
-struct OpenAIMessageResponse {
-    content: String,
-}
-
-// ============================================================================
-// Ollama API structures
-// ============================================================================
-
-#[derive(Debug, Serialize)]
-struct OllamaRequest {
-    model: String,
-    prompt: String,
-    stream: bool,
-    options: OllamaOptions,
-}
-
-#[derive(Debug, Serialize)]
-struct OllamaOptions {
-    temperature: f32,
-    num_predict: u32,
-}
-
-#[derive(Debug, Deserialize)]
-struct OllamaResponse {
-    response: String,
-}
-
 // ============================================================================
 // Said relation extraction types
 // ============================================================================
@@ -210,6 +210,13 @@ pub struct BatchSaidResult {
     pub relations: Vec<SaidRelation>,
 }
 
+/// LLM response for batch Said extraction in structured mode
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchSaidResponse {
+    /// Per-article results
+    pub results: Vec<BatchSaidResult>,
+}
+
 /// LLM response for Said extraction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaidExtractionResponse {
@@ -224,8 +231,10 @@ pub struct SaidExtractionResponse {
 
 /// LLM client for relation extraction
 pub struct LlmClient {
-    client: Client,
+    provider: Box<dyn LlmProvider>,
     config: LlmConfig,
+    /// Cleared once the backend turns out not to support structured output
+    json_mode: AtomicBool,
 }
 
 impl LlmClient {
@@ -241,7 +250,22 @@ impl LlmClient {
             .build()
             .context("Failed to create HTTP client")?;
 
-        Ok(Self { client, config })
+        Ok(Self::with_provider(
+            config.clone(),
+            provider::provider_for(&config, client),
+        ))
+    }
+
+    /// Create a client that generates through `provider`
+    ///
+    /// `config` still supplies the retry policy and the backend name used
+    /// for metrics.
+    pub fn with_provider(config: LlmConfig, provider: Box<dyn LlmProvider>) -> Self {
+        Self {
+            provider,
+            json_mode: AtomicBool::new(config.json_mode),
+            config,
+        }
     }
 
     /// Create a client from environment variables
@@ -254,18 +278,25 @@ impl LlmClient {
         &self.config.backend
     }
 
+    /// Get the API endpoint URL
+    pub fn endpoint(&self) -> &str {
+        &self.config.endpoint
+    }
+
     /// Check if LLM service is available
     pub async fn is_available(&self) -> bool {
-        let url = match self.config.backend {
-            LlmBackend::Vllm => format!("{}/health", self.config.endpoint),
-            LlmBackend::Ollama => format!("{}/api/tags", self.config.endpoint),
-        };
-        self.client.get(&url).send().await.is_ok()
+        self.provider.is_available().await
     }
 
     /// Extract Said relations from article text
     pub async fn extract_said_relations(&self, text: &str) -> Result<Vec<SaidRelation>> {
         let prompt = self.build_said_prompt(text);
+        if let Some(parsed) = self
+            .generate_structured::<SaidExtractionResponse>(&prompt, &schema::said_extraction())
+            .await?
+        {
+            return Ok(parsed.relations);
+        }
         let response = self.generate(&prompt).await?;
         self.parse_said_response(&response)
     }
@@ -280,12 +311,93 @@ impl LlmClient {
         }
 
         let prompt = self.build_batch_prompt(articles);
+        if let Some(parsed) = self
+            .generate_structured::<BatchSaidResponse>(&prompt, &schema::batch_said_extraction())
+            .await?
+        {
+            let mut results: std::collections::HashMap<_, _> = articles
+                .iter()
+                .map(|article| (article.id.clone(), Vec::new()))
+                .collect();
+            for result in parsed.results {
+                results.insert(result.article_id, result.relations);
+            }
+            return Ok(results);
+        }
+
         let response = self.generate(&prompt).await?;
         self.parse_batch_response(&response, articles)
     }
 
+    /// Answer `question` from news passages, citing them as `[1]`, `[2]`, ...
+    ///
+    /// `content` of each [`ArticleInfo`] is the passage text. Returns an
+    /// empty string without calling the LLM if there are no passages.
+    pub async fn answer_question(
+        &self,
+        question: &str,
+        passages: &[ArticleInfo],
+    ) -> Result<String> {
+        if passages.is_empty() {
+            return Ok(String::new());
+        }
+        let prompt = self.build_answer_prompt(question, passages);
+        Ok(self.generate(&prompt).await?.trim().to_string())
+    }
+
     /// Generate text using the configured backend with retry logic
     async fn generate(&self, prompt: &str) -> Result<String> {
+        self.with_retries(|| self.provider.generate(prompt)).await
+    }
+
+    /// Generate JSON for `schema` in the backend's structured mode
+    ///
+    /// The reply is validated against the schema and deserialized; a reply
+    /// that does not match counts as a failed attempt and is retried.
+    /// Returns `Ok(None)` without retrying when structured mode is disabled
+    /// or unsupported, and remembers the latter for later calls.
+    async fn generate_structured<T: DeserializeOwned>(
+        &self,
+        prompt: &str,
+        schema: &OutputSchema,
+    ) -> Result<Option<T>> {
+        if !self.json_mode.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        let parsed = self
+            .with_retries(|| async {
+                let Some(text) = self.provider.generate_json(prompt, schema).await? else {
+                    return Ok(None);
+                };
+                // Some models still wrap the JSON in a code block
+                let value: serde_json::Value = serde_json::from_str(text.trim())
+                    .or_else(|_| serde_json::from_str(&self.extract_raw_json(&text)))
+                    .context("Structured LLM response is not valid JSON")?;
+                schema::validate(&value, &schema.schema).with_context(|| {
+                    format!("Structured LLM response does not match `{}`", schema.name)
+                })?;
+                Ok(Some(serde_json::from_value::<T>(value)?))
+            })
+            .await?;
+
+        if parsed.is_none() {
+            tracing::info!(
+                backend = self.config.backend.as_str(),
+                "Structured output unsupported, falling back to lenient JSON parsing"
+            );
+            self.json_mode.store(false, Ordering::Relaxed);
+        }
+        Ok(parsed)
+    }
+
+    /// Run `request` until it succeeds or the retries run out, doubling the
+    /// delay after each failure
+    async fn with_retries<T, F, Fut>(&self, mut request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         let mut last_error: Option<anyhow::Error> = None;
         let mut delay_ms = self.config.retry_delay_ms;
 
@@ -301,10 +413,13 @@ impl LlmClient {
                 delay_ms = (delay_ms * 2).min(30000);
             }
 
-            let result = match self.config.backend {
-                LlmBackend::Vllm => self.generate_vllm(prompt).await,
-                LlmBackend::Ollama => self.generate_ollama(prompt).await,
-            };
+            let started = std::time::Instant::now();
+            let result = request().await;
+            crate::metrics::record_llm_latency(
+                self.config.backend.as_str(),
+                started.elapsed().as_secs_f64(),
+                result.is_ok(),
+            );
 
             match result {
                 Ok(response) => {
@@ -323,83 +438,6 @@ impl LlmClient {
         Err(last_error.unwrap_or_else(|| anyhow::anyhow!("LLM request failed after all retries")))
     }
 
-    /// Generate using vLLM (OpenAI-compatible API)
-    async fn generate_vllm(&self, prompt: &str) -> Result<String> {
-        let url = format!("{}/v1/chat/completions", self.config.endpoint);
-
-        let request = OpenAIRequest {
-            model: self.config.model.clone(),
-            messages: vec![OpenAIMessage {
-                role: "user".to_string(),
-                content: prompt.to_string(),
-            }],
-            max_tokens: self.config.max_tokens,
-            temperature: self.config.temperature,
-            stream: false,
-        };
-
-        let response = self
-            .client
-            .post(&url)
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send request to vLLM")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("vLLM request failed: {} - {}", status, body);
-        }
-
-        let openai_response: OpenAIResponse = response
-            .json()
-            .await
-            .context("Failed to parse vLLM response")?;
-
-        openai_response
-            .choices
-            .first()
-            .map(|c| c.message.content.clone())
-            .ok_or_else(|| anyhow::anyhow!("No response from vLLM"))
-    }
-
-    /// Generate using Ollama API
-    async fn generate_ollama(&self, prompt: &str) -> Result<String> {
-        let url = format!("{}/api/generate", self.config.endpoint);
-
-        let request = OllamaRequest {
-            model: self.config.model.clone(),
-            prompt: prompt.to_string(),
-            stream: false,
-            options: OllamaOptions {
-                temperature: self.config.temperature,
-                num_predict: self.config.max_tokens,
-            },
-        };
-
-        let response = self
-            .client
-            .post(&url)
-            .json(&request)
-            .send()
-            .await
-            .context("Failed to send request to Ollama")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("Ollama request failed: {} - {}", status, body);
-        }
-
-        let ollama_response: OllamaResponse = response
-            .json()
-            .await
-            .context("Failed to parse Ollama response")?;
-
-        Ok(ollama_response.response)
-    }
-
     /// Build prompt for batch Said relation extraction
     fn build_batch_prompt(&self, articles: &[ArticleInfo]) -> String {
         let mut articles_text = String::new();
@@ -430,16 +468,18 @@ impl LlmClient {
 3. 발언이 없는 기사는 빈 배열로 표시하세요
 4. 신뢰도: 직접인용=0.95, 간접인용=0.8
 
-## 출력 형식 (JSON 배열):
+## 출력 형식 (JSON):
 ```json
-[
-  {{
-    "article_id": "기사ID",
-    "relations": [
-      {{"speaker": "이름", "content": "발언", "confidence": 0.9, "evidence": "근거문장"}}
-    ]
-  }}
-]
+{{
+  "results": [
+    {{
+      "article_id": "기사ID",
+      "relations": [
+        {{"speaker": "이름", "content": "발언", "confidence": 0.9, "evidence": "근거문장"}}
+      ]
+    }}
+  ]
+}}
 ```
 
 ## 뉴스 기사들:
@@ -449,6 +489,38 @@ impl LlmClient {
         )
     }
 
+    /// Build prompt for answering a question from numbered passages
+    fn build_answer_prompt(&self, question: &str, passages: &[ArticleInfo]) -> String {
+        let mut passages_text = String::new();
+        for (i, passage) in passages.iter().enumerate() {
+            passages_text.push_str(&format!(
+                "\n[{}] {}\n{}\n",
+                i + 1,
+                passage.title,
+                passage.content
+            ));
+        }
+
+        format!(
+            r#"당신은 뉴스 기사를 근거로 질문에 답하는 도우미입니다.
+
+다음 기사 발췌문만을 근거로 질문에 답하세요.
+
+## 규칙:
+1. 2-3문장 이내로 간결하게 답하세요
+2. 근거로 쓴 발췌문 번호를 [1]처럼 표시하세요
+3. 발췌문에 답이 없으면 "관련 기사에서 답을 찾을 수 없습니다."라고 답하세요
+4. 한국어로 작성하세요
+
+## 기사 발췌문:
+{passages_text}
+## 질문:
+{question}
+
+## 답변:"#
+        )
+    }
+
     /// Build prompt for Said relation extraction
     fn build_said_prompt(&self, text: &str) -> String {
         format!(
@@ -744,11 +816,110 @@ mod tests {
         assert_eq!(config.backend, LlmBackend::Vllm);
     }
 
+    #[test]
+    fn test_build_answer_prompt() {
+        let client = LlmClient::new().unwrap();
+        let passages = [
+            ArticleInfo {
+                id: "001_0000000001".to_string(),
+                title: "기준금리 동결".to_string(),
+                content: "한국은행이 기준금리를 동결했다.".to_string(),
+            },
+            ArticleInfo {
+                id: "001_0000000002".to_string(),
+                title: "물가 전망".to_string(),
+                content: "물가 상승세가 둔화될 전망이다.".to_string(),
+            },
+        ];
+        let prompt = client.build_answer_prompt("금리는 어떻게 됐나?", &passages);
+        assert!(prompt.contains("[1] 기준금리 동결\n한국은행이 기준금리를 동결했다."));
+        assert!(prompt.contains("[2] 물가 전망"));
+        assert!(prompt.contains("## 질문:\n금리는 어떻게 됐나?"));
+    }
+
+    #[tokio::test]
+    async fn test_answer_question_without_passages() {
+        let client = LlmClient::new().unwrap();
+        assert_eq!(client.answer_question("질문", &[]).await.unwrap(), "");
+    }
+
     #[test]
     fn test_backend_from_str() {
         assert_eq!(LlmBackend::from_str("ollama"), LlmBackend::Ollama);
         assert_eq!(LlmBackend::from_str("vllm"), LlmBackend::Vllm);
         assert_eq!(LlmBackend::from_str("openai"), LlmBackend::Vllm);
+        assert_eq!(LlmBackend::from_str("Hosted"), LlmBackend::OpenAi);
+        assert_eq!(LlmBackend::from_str("openrouter"), LlmBackend::OpenAi);
+        assert_eq!(LlmBackend::from_str("mock"), LlmBackend::Mock);
+        assert_eq!(LlmBackend::from_str("unknown"), LlmBackend::Vllm);
+    }
+
+    #[tokio::test]
+    async fn test_extract_said_relations_with_mock_provider() {
+        let config = LlmConfig {
+            backend: LlmBackend::Mock,
+            max_retries: 0,
+            ..Default::default()
+        };
+        let mock = MockProvider::new([r#"```json
+{"relations": [{"speaker": "김철수", "content": "경제가 회복되고 있다", "confidence": 0.95, "evidence": "김철수 장관은 \"경제가 회복되고 있다\"고 말했다."}]}
+```"#]);
+        let client = LlmClient::with_provider(config, Box::new(mock));
+
+        assert!(client.is_available().await);
+        let relations = client
+            .extract_said_relations("김철수 장관은 \"경제가 회복되고 있다\"고 말했다.")
+            .await
+            .unwrap();
+        assert_eq!(relations.len(), 1);
+        assert_eq!(relations[0].speaker, "김철수");
+    }
+
+    fn mock_client(mock: MockProvider) -> LlmClient {
+        let config = LlmConfig {
+            backend: LlmBackend::Mock,
+            max_retries: 1,
+            retry_delay_ms: 0,
+            ..Default::default()
+        };
+        LlmClient::with_provider(config, Box::new(mock))
+    }
+
+    #[tokio::test]
+    async fn test_structured_response_must_match_schema() {
+        // Out-of-range confidence fails validation on every attempt
+        let client = mock_client(MockProvider::new([
+            r#"{"relations": [{"speaker": "김철수", "content": "회복", "confidence": 9, "evidence": ""}]}"#,
+        ]));
+        let err = client.extract_said_relations("본문").await.unwrap_err();
+        assert!(format!("{err:#}").contains("does not match `said_extraction`"));
+
+        // A broken reply is retried and the valid one accepted
+        let client = mock_client(MockProvider::new([
+            r#"{"relations": [{"speaker": "김철수""#,
+            r#"{"results": [{"article_id": "a1", "relations": []}]}"#,
+        ]));
+        let articles = [ArticleInfo {
+            id: "a1".to_string(),
+            title: "제목".to_string(),
+            content: "본문".to_string(),
+        }];
+        let results = client.extract_said_batch(&articles).await.unwrap();
+        assert!(results["a1"].is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_without_structured_mode() {
+        // The plain reply goes through the lenient parser
+        let client = mock_client(
+            MockProvider::new([
+                r#"결과: {"relations": [{"speaker": "홍길동", "content": "테스트", "confidence": 0.9, "evidence": ""}]}"#,
+            ])
+            .without_json_mode(),
+        );
+        let relations = client.extract_said_relations("본문").await.unwrap();
+        assert_eq!(relations[0].speaker, "홍길동");
+        assert!(!client.json_mode.load(Ordering::Relaxed));
     }
 
     #[test]
@@ -0,0 +1,428 @@
+//! Text generation backends behind [`LlmClient`](super::LlmClient)
+//!
+//! [`LlmProvider`] is the one call the extraction and answering code needs:
+//! a prompt in, generated text out. [`OpenAiProvider`] speaks the
+//! OpenAI-compatible chat completions API served by vLLM, OpenRouter and
+//! OpenAI itself, [`OllamaProvider`] the Ollama `/api/generate` API, and
+//! [`MockProvider`] answers from canned responses so tests run without a
+//! model server. [`provider_for`] picks one from [`LlmConfig::backend`].
+//!
+//! [`LlmProvider::generate_json`] asks for output matching an
+//! [`OutputSchema`] where the backend can constrain its output to JSON.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::schema::OutputSchema;
+use super::{LlmBackend, LlmConfig};
+
+/// Generates text from a prompt
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Generate a completion for `prompt`
+    async fn generate(&self, prompt: &str) -> Result<String>;
+
+    /// Generate a completion constrained to JSON for `schema`
+    ///
+    /// Returns `Ok(None)` when the backend has no structured output mode or
+    /// the server rejects it, so the caller can fall back to [`generate`].
+    ///
+    /// [`generate`]: LlmProvider::generate
+    async fn generate_json(&self, _prompt: &str, _schema: &OutputSchema) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Whether the backend answers at all
+    async fn is_available(&self) -> bool;
+}
+
+/// Provider for the configured backend
+pub fn provider_for(config: &LlmConfig, client: Client) -> Box<dyn LlmProvider> {
+    match config.backend {
+        LlmBackend::Vllm | LlmBackend::OpenAi => {
+            Box::new(OpenAiProvider::new(config.clone(), client))
+        }
+        LlmBackend::Ollama => Box::new(OllamaProvider::new(config.clone(), client)),
+        LlmBackend::Mock => Box::new(MockProvider::default()),
+    }
+}
+
+// ============================================================================
+// OpenAI-compatible chat completions (vLLM, OpenRouter, OpenAI)
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+struct OpenAIRequest {
+    model: String,
+    messages: Vec<OpenAIMessage>,
+    max_tokens: u32,
+    temperature: f32,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAIMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIResponse {
+    choices: Vec<OpenAIChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIChoice {
+    message: OpenAIMessageResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIMessageResponse {
+    content: String,
+}
+
+/// OpenAI-compatible `/v1/chat/completions` backend
+pub struct OpenAiProvider {
+    client: Client,
+    config: LlmConfig,
+}
+
+impl OpenAiProvider {
+    /// Provider for `config.endpoint`, authenticated with `config.api_key`
+    /// when set
+    pub fn new(config: LlmConfig, client: Client) -> Self {
+        Self { client, config }
+    }
+
+    fn with_auth(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.config.api_key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self.config.backend {
+            LlmBackend::Vllm => "vLLM",
+            _ => "OpenAI-compatible API",
+        }
+    }
+
+    /// Send one chat completion; `Ok(None)` if the server rejected
+    /// `response_format`
+    async fn complete(
+        &self,
+        prompt: &str,
+        response_format: Option<serde_json::Value>,
+    ) -> Result<Option<String>> {
+        let structured = response_format.is_some();
+        let url = format!("{}/v1/chat/completions", self.config.endpoint);
+
+        let request = OpenAIRequest {
+            model: self.config.model.clone(),
+            messages: vec![OpenAIMessage {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+            max_tokens: self.config.max_tokens,
+            temperature: self.config.temperature,
+            stream: false,
+            response_format,
+        };
+
+        let response = self
+            .with_auth(self.client.post(&url))
+            .json(&request)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send request to {}", self.label()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if structured
+                && rejects_structured_output(status, &body, &["response_format", "json_schema"])
+            {
+                tracing::debug!(status = %status, body = %body, "Structured output rejected");
+                return Ok(None);
+            }
+            anyhow::bail!("{} request failed: {} - {}", self.label(), status, body);
+        }
+
+        let openai_response: OpenAIResponse = response
+            .json()
+            .await
+            .with_context(|| format!("Failed to parse {} response", self.label()))?;
+
+        openai_response
+            .choices
+            .first()
+            .map(|c| Some(c.message.content.clone()))
+            .ok_or_else(|| anyhow::anyhow!("No response from {}", self.label()))
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    async fn generate(&self, prompt: &str) -> Result<String> {
+        self.complete(prompt, None)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("No response from {}", self.label()))
+    }
+
+    async fn generate_json(&self, prompt: &str, schema: &OutputSchema) -> Result<Option<String>> {
+        // Not strict: strict mode rejects `minLength`/`minimum` on some
+        // models, and the reply is validated against the schema anyway
+        let response_format = serde_json::json!({
+            "type": "json_schema",
+            "json_schema": {
+                "name": schema.name,
+                "schema": schema.schema,
+                "strict": false,
+            }
+        });
+        self.complete(prompt, Some(response_format)).await
+    }
+
+    async fn is_available(&self) -> bool {
+        // Hosted APIs have no /health; listing models also checks the key
+        let url = match self.config.backend {
+            LlmBackend::Vllm => format!("{}/health", self.config.endpoint),
+            _ => format!("{}/v1/models", self.config.endpoint),
+        };
+        match self.with_auth(self.client.get(&url)).send().await {
+            Ok(response) => {
+                self.config.backend == LlmBackend::Vllm || response.status().is_success()
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+// ============================================================================
+// Ollama
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+struct OllamaRequest {
+    model: String,
+    prompt: String,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<&'static str>,
+    options: OllamaOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    temperature: f32,
+    num_predict: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaResponse {
+    response: String,
+}
+
+/// Ollama `/api/generate` backend
+pub struct OllamaProvider {
+    client: Client,
+    config: LlmConfig,
+}
+
+impl OllamaProvider {
+    /// Provider for the Ollama server at `config.endpoint`
+    pub fn new(config: LlmConfig, client: Client) -> Self {
+        Self { client, config }
+    }
+
+    /// Send one generate request; `Ok(None)` if the server rejected `format`
+    async fn complete(&self, prompt: &str, format: Option<&'static str>) -> Result<Option<String>> {
+        let url = format!("{}/api/generate", self.config.endpoint);
+
+        let request = OllamaRequest {
+            model: self.config.model.clone(),
+            prompt: prompt.to_string(),
+            stream: false,
+            format,
+            options: OllamaOptions {
+                temperature: self.config.temperature,
+                num_predict: self.config.max_tokens,
+            },
+        };
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send request to Ollama")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if format.is_some() && rejects_structured_output(status, &body, &["format"]) {
+                tracing::debug!(status = %status, body = %body, "JSON format rejected");
+                return Ok(None);
+            }
+            anyhow::bail!("Ollama request failed: {} - {}", status, body);
+        }
+
+        let ollama_response: OllamaResponse = response
+            .json()
+            .await
+            .context("Failed to parse Ollama response")?;
+
+        Ok(Some(ollama_response.response))
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OllamaProvider {
+    async fn generate(&self, prompt: &str) -> Result<String> {
+        self.complete(prompt, None)
+            .await?
+            .context("No response from Ollama")
+    }
+
+    /// Ollama's `format: json` guarantees well-formed JSON but not the
+    /// schema, which the caller validates
+    async fn generate_json(&self, prompt: &str, _schema: &OutputSchema) -> Result<Option<String>> {
+        self.complete(prompt, Some("json")).await
+    }
+
+    async fn is_available(&self) -> bool {
+        let url = format!("{}/api/tags", self.config.endpoint);
+        self.client.get(&url).send().await.is_ok()
+    }
+}
+
+/// Whether a failed structured request means the mode is unsupported
+/// rather than a transient failure
+///
+/// Only a client error whose body names one of the request `fields` counts;
+/// other 400s (a prompt over the context length, a bad model name) are
+/// errors of this request and leave JSON mode on.
+fn rejects_structured_output(status: StatusCode, body: &str, fields: &[&str]) -> bool {
+    let body = body.to_lowercase();
+    (status == StatusCode::BAD_REQUEST || status == StatusCode::UNPROCESSABLE_ENTITY)
+        && fields.iter().any(|field| body.contains(field))
+}
+
+// ============================================================================
+// Mock
+// ============================================================================
+
+/// Answers prompts with canned responses, in turn
+///
+/// With `LLM_BACKEND=mock` every prompt gets an empty extraction result.
+/// Structured requests are answered the same way unless
+/// [`without_json_mode`](Self::without_json_mode) is set.
+pub struct MockProvider {
+    responses: Vec<String>,
+    next: AtomicUsize,
+    json_mode: bool,
+}
+
+impl MockProvider {
+    /// Provider cycling through `responses`
+    pub fn new<S: Into<String>>(responses: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            responses: responses.into_iter().map(Into::into).collect(),
+            next: AtomicUsize::new(0),
+            json_mode: true,
+        }
+    }
+
+    /// Report structured output as unsupported
+    #[must_use]
+    pub fn without_json_mode(mut self) -> Self {
+        self.json_mode = false;
+        self
+    }
+
+    /// Number of prompts answered so far
+    pub fn calls(&self) -> usize {
+        self.next.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for MockProvider {
+    fn default() -> Self {
+        Self::new([r#"{"relations": []}"#])
+    }
+}
+
+#[async_trait]
+impl LlmProvider for MockProvider {
+    async fn generate(&self, _prompt: &str) -> Result<String> {
+        if self.responses.is_empty() {
+            anyhow::bail!("Mock LLM has no responses");
+        }
+        let call = self.next.fetch_add(1, Ordering::Relaxed);
+        Ok(self.responses[call % self.responses.len()].clone())
+    }
+
+    async fn generate_json(&self, prompt: &str, _schema: &OutputSchema) -> Result<Option<String>> {
+        if !self.json_mode {
+            return Ok(None);
+        }
+        self.generate(prompt).await.map(Some)
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_provider_cycles() {
+        let mock = MockProvider::new(["a", "b"]);
+        assert_eq!(mock.generate("1").await.unwrap(), "a");
+        assert_eq!(mock.generate("2").await.unwrap(), "b");
+        assert_eq!(mock.generate("3").await.unwrap(), "a");
+        assert_eq!(mock.calls(), 3);
+
+        let empty = MockProvider::new(Vec::<String>::new());
+        assert!(empty.generate("1").await.is_err());
+    }
+
+    #[test]
+    fn test_rejects_structured_output() {
+        let fields = ["response_format", "json_schema"];
+        let unsupported = r#"{"error": {"message": "Unknown parameter: 'response_format'."}}"#;
+        assert!(rejects_structured_output(
+            StatusCode::BAD_REQUEST,
+            unsupported,
+            &fields
+        ));
+        assert!(rejects_structured_output(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "json_schema is not supported",
+            &fields
+        ));
+
+        let too_long =
+            r#"{"error": {"message": "This model's maximum context length is 8192 tokens."}}"#;
+        assert!(!rejects_structured_output(
+            StatusCode::BAD_REQUEST,
+            too_long,
+            &fields
+        ));
+        assert!(!rejects_structured_output(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            unsupported,
+            &fields
+        ));
+    }
+}
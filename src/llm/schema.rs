@@ -0,0 +1,204 @@
+//! JSON schemas for structured LLM output
+//!
+//! Backends with a structured output mode (Ollama `format: json`, OpenAI
+//! `response_format: json_schema`) are asked for JSON matching one of these
+//! schemas, and [`validate`] checks the reply against the same schema before
+//! it is deserialized. Only the JSON Schema keywords used here are
+//! understood: `type`, `properties`, `required`, `items`, `minLength`,
+//! `minimum` and `maximum`.
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+
+/// Named JSON schema for one kind of LLM output
+#[derive(Debug, Clone)]
+pub struct OutputSchema {
+    /// Schema name (`[a-zA-Z0-9_-]`, as OpenAI requires)
+    pub name: &'static str,
+    /// JSON Schema document
+    pub schema: Value,
+}
+
+fn said_relation() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "speaker": { "type": "string", "minLength": 1 },
+            "content": { "type": "string", "minLength": 1 },
+            "confidence": { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+            "evidence": { "type": "string" }
+        },
+        "required": ["speaker", "content", "confidence", "evidence"],
+        "additionalProperties": false
+    })
+}
+
+/// Schema of [`SaidExtractionResponse`](super::SaidExtractionResponse)
+pub fn said_extraction() -> OutputSchema {
+    OutputSchema {
+        name: "said_extraction",
+        schema: json!({
+            "type": "object",
+            "properties": {
+                "relations": { "type": "array", "items": said_relation() }
+            },
+            "required": ["relations"],
+            "additionalProperties": false
+        }),
+    }
+}
+
+/// Schema of [`BatchSaidResponse`](super::BatchSaidResponse)
+///
+/// Wrapped in an object because structured modes need an object at the top.
+pub fn batch_said_extraction() -> OutputSchema {
+    OutputSchema {
+        name: "batch_said_extraction",
+        schema: json!({
+            "type": "object",
+            "properties": {
+                "results": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "article_id": { "type": "string", "minLength": 1 },
+                            "relations": { "type": "array", "items": said_relation() }
+                        },
+                        "required": ["article_id", "relations"],
+                        "additionalProperties": false
+                    }
+                }
+            },
+            "required": ["results"],
+            "additionalProperties": false
+        }),
+    }
+}
+
+/// Check `value` against `schema`, naming the first offending path
+pub fn validate(value: &Value, schema: &Value) -> Result<()> {
+    check(value, schema, "$")
+}
+
+fn check(value: &Value, schema: &Value, path: &str) -> Result<()> {
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let ok = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => true,
+        };
+        if !ok {
+            bail!("{path}: expected {expected}, found {value}");
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        bail!("{path}: missing required field `{key}`");
+                    }
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (key, property) in properties {
+                    if let Some(field) = map.get(key) {
+                        check(field, property, &format!("{path}.{key}"))?;
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check(item, item_schema, &format!("{path}[{i}]"))?;
+                }
+            }
+        }
+        Value::String(s) => {
+            let min = schema.get("minLength").and_then(Value::as_u64).unwrap_or(0);
+            if (s.trim().chars().count() as u64) < min {
+                bail!("{path}: shorter than {min} characters");
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if n < min {
+                    bail!("{path}: {n} is below {min}");
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if n > max {
+                    bail!("{path}: {n} is above {max}");
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_said_extraction() {
+        let schema = said_extraction().schema;
+        let valid = json!({"relations": [
+            {"speaker": "김철수", "content": "회복", "confidence": 0.9, "evidence": ""}
+        ]});
+        assert!(validate(&valid, &schema).is_ok());
+        assert!(validate(&json!({"relations": []}), &schema).is_ok());
+
+        let err = validate(&json!({}), &schema).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required field `relations`"));
+
+        let err = validate(
+            &json!({"relations": [
+                {"speaker": " ", "content": "회복", "confidence": 0.9, "evidence": ""}
+            ]}),
+            &schema,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("$.relations[0].speaker"));
+
+        let err = validate(
+            &json!({"relations": [
+                {"speaker": "김철수", "content": "회복", "confidence": "high", "evidence": ""}
+            ]}),
+            &schema,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("expected number"));
+
+        assert!(validate(
+            &json!({"relations": [
+                {"speaker": "김철수", "content": "회복", "confidence": 1.5, "evidence": ""}
+            ]}),
+            &schema,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_batch_requires_object() {
+        let schema = batch_said_extraction().schema;
+        assert!(validate(&json!([]), &schema).is_err());
+        assert!(validate(
+            &json!({"results": [{"article_id": "001_1", "relations": []}]}),
+            &schema
+        )
+        .is_ok());
+    }
+}
@@ -1,10 +1,17 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use baram::config::Config;
+use baram::analytics::Vocabulary;
+use baram::config::layers::parse_set;
+use baram::config::reload::{self, ConfigWatcher};
+use baram::config::{Config, ConfigLoader};
+use baram::health::{Availability, Capabilities, Capability, DEFAULT_DETECT_TIMEOUT};
 use baram::i18n;
+use baram::metrics::push::{init_if_configured, push_if_configured, JobMetrics};
 
 // Initialize rust-i18n for the binary crate
 rust_i18n::i18n!("locales", fallback = "en");
@@ -30,31 +37,159 @@ struct Cli {
     #[arg(long, global = true, default_value = "text")]
     log_format: String,
 
+    /// Progress output on stderr (bar, json, none)
+    #[arg(long, global = true, default_value = "bar")]
+    progress: String,
+
     /// Config file path
     #[arg(short, long, global = true, default_value = "config.toml")]
     config: PathBuf,
+
+    /// Config profile to apply ([profile.<name>] in the config file);
+    /// defaults to BARAM_PROFILE
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Override a config value, e.g. --set crawler.rate_limit=1.5
+    /// (repeatable; takes precedence over the file and environment)
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    overrides: Vec<String>,
+
+    /// Suppress decorative output (titles, settings and summaries)
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Print one JSON result object on stdout when the command ends
+    /// (implies --quiet)
+    #[arg(long, global = true)]
+    output_json: bool,
+
+    /// Fail instead of switching to a degraded mode when OpenSearch, the LLM
+    /// or Redis is unavailable
+    #[arg(long, global = true)]
+    no_degrade: bool,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Crawl Naver news articles
     Crawl {
+        /// News site to crawl (naver, daum) [default: naver]
+        #[arg(long)]
+        site: Option<String>,
+
         /// News category to crawl (politics, economy, society, culture, world, it)
         #[arg(short = 'C', long)]
         category: Option<String>,
 
-        /// Maximum number of articles to crawl
-        #[arg(short, long, default_value = "100")]
-        max_articles: usize,
+        /// Maximum number of articles to crawl per category [default: the
+        /// category's max_articles in the config, else 100]
+        #[arg(short, long)]
+        max_articles: Option<usize>,
 
         /// Specific article URL to crawl
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with = "url_file")]
         url: Option<String>,
 
+        /// File of article URLs to crawl, one per line (`-` reads stdin)
+        #[arg(long, conflicts_with = "category")]
+        url_file: Option<PathBuf>,
+
+        /// Crawl the Naver News search results for this keyword instead of a
+        /// category
+        #[arg(long, conflicts_with_all = ["category", "url", "url_file"])]
+        query: Option<String>,
+
+        /// First day of the search, YYYY-MM-DD [default: today]
+        #[arg(long, requires = "query")]
+        from: Option<String>,
+
+        /// Last day of the search, YYYY-MM-DD [default: today]
+        #[arg(long, requires = "query")]
+        to: Option<String>,
+
+        /// How category article URLs are discovered (list, rss, sitemap); rss
+        /// and sitemap read the category's feeds or sitemaps in the config
+        #[arg(long, default_value = "list", conflicts_with_all = ["url", "url_file", "query"])]
+        discovery: String,
+
         /// Include comments in crawl
         #[arg(long, default_value = "false")]
         with_comments: bool,
 
+        /// Download article images into assets/{oid}_{aid}/ next to the
+        /// output directory and link the markdown to the local copies
+        #[arg(long, default_value = "false")]
+        download_images: bool,
+
+        /// Output directory for markdown files
+        #[arg(short, long, default_value = "./output/raw")]
+        output: PathBuf,
+
+        /// Article file format: md, json (the full article with comments
+        /// and extraction metadata, for machine processing) or both
+        #[arg(long, default_value = "md")]
+        format: String,
+
+        /// Skip already crawled articles
+        #[arg(long, default_value = "true")]
+        skip_existing: bool,
+
+        /// Serve Prometheus metrics on this port while crawling
+        #[arg(long)]
+        metrics_port: Option<u16>,
+
+        /// Crawl a local mock of Naver News serving recorded pages instead
+        /// of the live site (needs the `mock` feature)
+        #[arg(long, conflicts_with_all = ["site", "query"])]
+        mock: bool,
+
+        /// Delay of every mock server response, in milliseconds
+        #[arg(long, requires = "mock", default_value = "0")]
+        mock_latency: u64,
+
+        /// Share of mock server responses (0.0-1.0) failed with a 503
+        #[arg(long, requires = "mock", default_value = "0")]
+        mock_error_rate: f64,
+
+        /// Seed picking the failed mock server responses
+        #[arg(long, requires = "mock", default_value = "0")]
+        mock_seed: u64,
+
+        /// Embed and index articles into OpenSearch as they are crawled
+        #[arg(long)]
+        index: bool,
+
+        /// Articles waiting to be indexed before more are spilled to disk
+        #[arg(long, requires = "index", default_value = "256")]
+        index_queue: usize,
+
+        /// Keep running and crawl on --schedule, like `baram watch`
+        #[arg(long, conflicts_with_all = ["site", "url", "url_file", "query", "mock", "index"])]
+        daemon: bool,
+
+        /// Daemon schedule: an interval (30m, 2h) or a cron expression for
+        /// every category, and/or category=schedule (repeatable)
+        /// [default: 30m]
+        #[arg(long, requires = "daemon")]
+        schedule: Vec<String>,
+
+        /// Serve the daemon's schedule and progress as JSON at /status on
+        /// this port
+        #[arg(long, requires = "daemon")]
+        status_port: Option<u16>,
+    },
+
+    /// Crawl, index and extract the ontology of articles in one pass
+    Pipeline {
+        /// News category to crawl (politics, economy, society, culture, world, it)
+        #[arg(short = 'C', long)]
+        category: Option<String>,
+
+        /// Maximum number of articles to crawl
+        #[arg(short, long = "max", default_value = "100")]
+        max_articles: usize,
+
         /// Output directory for markdown files
         #[arg(short, long, default_value = "./output/raw")]
         output: PathBuf,
@@ -62,6 +197,69 @@ enum Commands {
         /// Skip already crawled articles
         #[arg(long, default_value = "true")]
         skip_existing: bool,
+
+        /// Skip embedding and indexing into OpenSearch
+        #[arg(long, default_value = "false")]
+        no_index: bool,
+
+        /// Skip ontology extraction
+        #[arg(long, default_value = "false")]
+        no_ontology: bool,
+
+        /// Output file for the extracted ontology (JSON)
+        #[arg(long, default_value = "./output/ontology/pipeline.json")]
+        ontology_output: PathBuf,
+
+        /// Serve Prometheus metrics on this port while running
+        #[arg(long)]
+        metrics_port: Option<u16>,
+    },
+
+    /// Crawl categories continuously on an interval or cron schedule
+    Watch {
+        /// Category crawled on --interval (repeatable; default: all)
+        #[arg(short = 'C', long = "category")]
+        categories: Vec<String>,
+
+        /// Default schedule: an interval (30s, 15m, 2h, 1d) or a cron expression
+        #[arg(short, long, default_value = "30m")]
+        interval: String,
+
+        /// Per-category schedule as category=schedule (repeatable),
+        /// e.g. "politics=10m" or "economy=0 */2 * * *"; one without a
+        /// category replaces --interval
+        #[arg(short, long = "schedule")]
+        schedules: Vec<String>,
+
+        /// Maximum number of articles per category run
+        #[arg(short, long = "max", default_value = "100")]
+        max_articles: usize,
+
+        /// Output directory for markdown files
+        #[arg(short, long, default_value = "./output/raw")]
+        output: PathBuf,
+
+        /// Article file format: md, json (the full article with comments
+        /// and extraction metadata, for machine processing) or both
+        #[arg(long, default_value = "md")]
+        format: String,
+
+        /// Download article images into assets/{oid}_{aid}/ next to the
+        /// output directory and link the markdown to the local copies
+        #[arg(long, default_value = "false")]
+        download_images: bool,
+
+        /// Serve Prometheus metrics on this port
+        #[arg(long)]
+        metrics_port: Option<u16>,
+
+        /// Serve the schedule and progress as JSON at /status on this port
+        #[arg(long)]
+        status_port: Option<u16>,
+
+        /// Crawl every category once and exit
+        #[arg(long, default_value = "false")]
+        once: bool,
     },
 
     /// Index articles into OpenSearch
@@ -81,6 +279,55 @@ enum Commands {
         /// Only index files modified after this datetime (YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS)
         #[arg(long)]
         since: Option<String>,
+
+        /// Use the vectors of an embedding dump (`export --what embeddings
+        /// --format npy`) instead of embedding those articles again
+        #[arg(long)]
+        embeddings: Option<PathBuf>,
+
+        /// Batches embedded and bulk indexed at once (also bounds how far
+        /// parsing runs ahead of indexing)
+        #[arg(long, default_value = "4")]
+        parallelism: usize,
+
+        /// Where embeddings come from: local (load the model in-process),
+        /// server (EMBEDDING_SERVER_URL) or none (keyword search only)
+        #[arg(long, default_value = "server")]
+        embedder: String,
+
+        /// HuggingFace model for --embedder local, whose tokenizer also
+        /// measures --chunk-tokens
+        #[arg(
+            long,
+            value_name = "MODEL",
+            default_value = "sentence-transformers/paraphrase-multilingual-MiniLM-L12-v2"
+        )]
+        embedding_model: String,
+
+        /// Texts per forward pass of the local model
+        #[arg(long, default_value = "32")]
+        embed_batch_size: usize,
+
+        /// If the index was created for another embedding dimension, recreate
+        /// it for the embedder's, keeping its documents without embeddings
+        /// until they are indexed again (otherwise indexing stops)
+        #[arg(long, conflicts_with = "force")]
+        migrate_index: bool,
+
+        /// Split articles longer than this many tokens of the embedding
+        /// model's tokenizer into overlapping chunks, each embedded and
+        /// indexed on its own (0 = embed whole articles)
+        #[arg(long, default_value = "0")]
+        chunk_tokens: usize,
+
+        /// Tokens repeated at the start of each chunk from the previous one
+        #[arg(long, default_value = "64")]
+        chunk_overlap: usize,
+
+        /// Don't cache embeddings in Redis (REDIS_URL) or invalidate its
+        /// cached search results
+        #[arg(long)]
+        no_cache: bool,
     },
 
     /// Search articles using vector similarity
@@ -99,15 +346,66 @@ enum Commands {
         /// Search mode: hybrid (BM25+kNN), keyword/bm25 (text only), vector/knn (embedding only)
         #[arg(short, long, default_value = "hybrid")]
         mode: String,
+
+        /// Show only the best-scoring article of each story
+        #[arg(long)]
+        collapse: bool,
+
+        /// Output format (text, json, csv, table, markdown)
+        #[arg(short, long, default_value = "text")]
+        output: String,
+
+        /// Comma-separated fields for json/csv/table/markdown: rank, id, score,
+        /// title, content, category, publisher, url, published_at, highlights, story_id
+        #[arg(long)]
+        fields: Option<String>,
+
+        /// Embed the query with this HuggingFace model in-process instead of
+        /// the embedding server (falls back to BM25 if neither is available)
+        #[arg(long, value_name = "MODEL")]
+        local_model: Option<String>,
+
+        /// Search the article bodies stored in the crawl database next to
+        /// --input, or the markdown files in --input, with a local full-text
+        /// index instead of OpenSearch (the default when OpenSearch is
+        /// unavailable)
+        #[arg(long)]
+        local: bool,
+
+        /// Markdown directory searched without OpenSearch
+        #[arg(short, long, default_value = "./output/raw")]
+        input: PathBuf,
+
+        /// Rerank the top candidates: none, rrf (fuse BM25 and k-NN ranks)
+        /// or cross-encoder (the embedding server's /rerank model)
+        #[arg(long, default_value = "none")]
+        rerank: String,
+
+        /// Candidates fetched for reranking
+        #[arg(long, default_value = "50")]
+        rerank_window: usize,
+
+        /// Cross-encoder to ask the embedding server for (default: its first)
+        #[arg(long, value_name = "MODEL")]
+        rerank_model: Option<String>,
+
+        /// Don't use the query embeddings and results cached in Redis
+        /// (REDIS_URL)
+        #[arg(long)]
+        no_cache: bool,
     },
 
     /// Extract ontology from articles
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Ontology {
+        #[command(subcommand)]
+        command: Option<OntologyCommands>,
+
         /// Input file or database
-        #[arg(short, long)]
-        input: String,
+        #[arg(short, long, required = true)]
+        input: Option<String>,
 
-        /// Output format (json, turtle, rdf)
+        /// Output format (json, turtle, rdf, ntriples, jsonld; json-ld is an alias of json)
         #[arg(short, long, default_value = "json")]
         format: String,
 
@@ -115,8 +413,18 @@ enum Commands {
         #[arg(short, long)]
         output: Option<String>,
 
-        /// Use LLM for Said relation extraction (requires Ollama)
-        #[arg(long, default_value = "false")]
+        /// Link entities to the knowledge base (Wikidata/DBpedia URIs) for
+        /// ntriples and jsonld output
+        #[arg(long)]
+        link_entities: bool,
+
+        /// Also save each article's triples to this triple storage directory
+        /// (read by `ontology query` and `trends`)
+        #[arg(long)]
+        store: Option<PathBuf>,
+
+        /// Merge verified LLM Said relations into the regex triples (requires Ollama)
+        #[arg(long, visible_alias = "use-llm", default_value = "false")]
         llm: bool,
 
         /// Maximum concurrent LLM requests
@@ -139,6 +447,36 @@ enum Commands {
         output: PathBuf,
     },
 
+    /// Revisit crawled articles and record revisions of edited ones
+    Recrawl {
+        /// Markdown directory of the crawl (crawl.db is next to it)
+        #[arg(short, long, default_value = "./output/raw")]
+        output: PathBuf,
+
+        /// Only articles last crawled on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Maximum number of articles to revisit, most recently crawled first (0 = unlimited)
+        #[arg(short, long, default_value = "100")]
+        limit: usize,
+    },
+
+    /// Retry failed URLs and report the ones given up on
+    RetryFailed {
+        /// Markdown directory of the crawl (crawl.db is next to it)
+        #[arg(short, long, default_value = "./output/raw")]
+        output: PathBuf,
+
+        /// Maximum number of URLs to retry, least recently failed first (0 = unlimited)
+        #[arg(short, long, default_value = "0")]
+        limit: usize,
+
+        /// Write all permanently failed URLs to this JSON file
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+
     /// Show crawl statistics
     Stats {
         /// SQLite database path
@@ -177,6 +515,72 @@ enum Commands {
         summarize: bool,
     },
 
+    /// Discover emergent topics per week using embedding k-means
+    Topics {
+        /// Filter by category (politics, economy, society, culture, world, it)
+        #[arg(short = 'C', long)]
+        category: Option<String>,
+
+        /// Only use articles published after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Maximum number of topics per week
+        #[arg(short = 'k', long, default_value = "8")]
+        topics: usize,
+
+        /// Minimum number of articles per topic
+        #[arg(long, default_value = "3")]
+        min_size: usize,
+
+        /// Maximum number of articles to process (0 = unlimited)
+        #[arg(long, default_value = "0")]
+        max_articles: usize,
+
+        /// Output directory for the topics JSON file
+        #[arg(short, long, default_value = "./output/topics")]
+        output: String,
+
+        /// Generate topic labels and summaries using vLLM
+        #[arg(long, default_value = "false")]
+        summarize: bool,
+    },
+
+    /// Generate a daily or weekly report (Markdown or HTML)
+    #[command(args_conflicts_with_subcommands = true)]
+    Report {
+        #[command(subcommand)]
+        command: Option<ReportCommands>,
+
+        /// Report period (daily, weekly)
+        #[arg(short, long, default_value = "daily")]
+        period: String,
+
+        /// Last day covered by the report (YYYY-MM-DD, default: yesterday)
+        #[arg(long)]
+        date: Option<String>,
+
+        /// Output format (markdown, html)
+        #[arg(short, long, default_value = "markdown")]
+        format: String,
+
+        /// Write the report to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Number of keywords, entities, quotes and errors to list
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// Send the report summary to the notification channels
+        #[arg(long, default_value = "false")]
+        notify: bool,
+
+        /// Notification channel to send to (repeatable, default: all)
+        #[arg(long = "channel")]
+        channels: Vec<String>,
+    },
+
     /// Start REST API server with hybrid search
     Serve {
         /// Port to listen on
@@ -186,6 +590,10 @@ enum Commands {
         /// Host to bind to
         #[arg(long, default_value = "0.0.0.0")]
         host: String,
+
+        /// API key required on data endpoints (repeatable; also read from BARAM_API_KEYS)
+        #[arg(long = "api-key")]
+        api_keys: Vec<String>,
     },
 
     /// Start embedding server for vector generation
@@ -198,9 +606,20 @@ enum Commands {
         #[arg(long, default_value = "0.0.0.0")]
         host: String,
 
-        /// Model ID (HuggingFace model or local path)
-        #[arg(short, long, default_value = "intfloat/multilingual-e5-large")]
-        model: String,
+        /// Model ID (HuggingFace model or local path); repeat to serve
+        /// several models, the first is the default
+        #[arg(
+            short,
+            long = "model",
+            default_value = "intfloat/multilingual-e5-large"
+        )]
+        models: Vec<String>,
+
+        /// Cross-encoder served at /rerank for `search --rerank
+        /// cross-encoder` (e.g. BAAI/bge-reranker-v2-m3); repeat to serve
+        /// several, the first is the default
+        #[arg(long = "rerank-model", value_name = "MODEL")]
+        rerank_models: Vec<String>,
 
         /// Maximum sequence length
         #[arg(long, default_value = "512")]
@@ -248,6 +667,14 @@ enum Commands {
         /// Run once (execute current slot and exit)
         #[arg(long, default_value = "false")]
         once: bool,
+
+        /// Serve Prometheus metrics on this port
+        #[arg(long)]
+        metrics_port: Option<u16>,
+
+        /// Also store full articles with comments in PostgreSQL (the --database URL)
+        #[arg(long, default_value = "false")]
+        store_articles: bool,
     },
 
     /// Start coordinator server for distributed crawling
@@ -272,6 +699,22 @@ enum Commands {
         #[arg(long, default_value = "10")]
         max_instances: usize,
 
+        /// SQLite file that keeps registered instances and heartbeats across restarts
+        #[arg(long)]
+        registry_db: Option<String>,
+
+        /// Days of heartbeat history kept in the registry database
+        #[arg(long, default_value = "7")]
+        registry_retention_days: u32,
+
+        /// Seconds an instance has to complete a work lease before its URLs are requeued
+        #[arg(long, default_value = "300")]
+        work_lease: u64,
+
+        /// Maximum URLs in one work lease
+        #[arg(long, default_value = "50")]
+        work_batch_size: usize,
+
         /// Schedule cache file path
         #[arg(long)]
         schedule_cache: Option<String>,
@@ -284,109 +727,1226 @@ enum Commands {
         #[arg(long, default_value = "false")]
         disable_logging: bool,
     },
-}
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize i18n from environment variable (BARAM_LANG)
-    i18n::init_from_env();
+    /// Export articles, triples, comments, index documents or embeddings
+    Export {
+        /// What to export (articles, triples, comments, index, embeddings)
+        #[arg(short, long, default_value = "articles")]
+        what: String,
 
-    let cli = Cli::parse();
+        /// Output format (jsonl, csv, parquet, turtle, cypher, npy)
+        #[arg(short, long, default_value = "jsonl")]
+        format: String,
 
-    // Initialize tracing/logging
-    setup_tracing(&cli.log_format, cli.verbose)?;
+        /// Source to read from (db, sqlite, markdown, opensearch). Defaults to
+        /// markdown for articles and triples, db for comments and opensearch
+        /// for index and embeddings
+        #[arg(short, long)]
+        source: Option<String>,
 
-    tracing::info!("{}", rust_i18n::t!("cli.app.starting"));
+        /// Markdown directory for the markdown and sqlite sources
+        #[arg(short, long, default_value = "./output/raw")]
+        input: PathBuf,
 
-    // Load config
-    let config = if cli.config.exists() {
-        Config::from_file(&cli.config)?
-    } else {
-        tracing::warn!(
-            path = %cli.config.display(),
-            "{}",
-            rust_i18n::t!("cli.config.not_found")
-        );
-        Config::default()
-    };
+        /// Crawl database for the sqlite source (default: crawl.db next to --input)
+        #[arg(short, long)]
+        database: Option<PathBuf>,
 
-    match cli.command {
-        Commands::Crawl {
-            category,
-            max_articles,
-            url,
-            with_comments,
-            output,
-            skip_existing,
-        } => {
-            tracing::info!(
-                category = ?category,
-                max_articles = %max_articles,
-                url = ?url,
-                with_comments = %with_comments,
-                output = %output.display(),
-                "Starting crawl command"
-            );
-            commands::crawl(
-                config,
-                category,
-                max_articles,
-                url,
-                with_comments,
-                output,
-                skip_existing,
-            )
-            .await?;
-        }
+        /// Output file (stdout if not set)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
 
-        Commands::Index {
-            input,
-            batch_size,
-            force,
-            since,
-        } => {
-            tracing::info!(
-                input = %input,
-                batch_size = %batch_size,
-                force = %force,
-                since = ?since,
-                "Starting index command"
-            );
-            commands::index(input, batch_size, force, since).await?;
-        }
+        /// Only articles published on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
 
-        Commands::Search {
-            query,
-            k,
-            threshold,
-            mode,
-        } => {
-            tracing::info!(
-                query = %query,
-                k = %k,
-                threshold = ?threshold,
-                mode = %mode,
-                "Starting search command"
-            );
-            commands::search(query, k, threshold, &mode).await?;
-        }
+        /// Only articles published up to and including this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
 
-        Commands::Ontology {
-            input,
-            format,
-            output,
-            llm,
-            max_concurrent,
-        } => {
-            tracing::info!(
+        /// Filter by category, comma-separated (politics, economy, society, culture, world, it)
+        #[arg(short = 'C', long)]
+        category: Option<String>,
+
+        /// Maximum number of articles to export (0 = unlimited)
+        #[arg(short, long, default_value = "0")]
+        limit: usize,
+
+        /// Columns to write, comma-separated (e.g. id,title,published_at; default: all)
+        #[arg(long)]
+        fields: Option<String>,
+
+        /// Load triples into Neo4j over its HTTP API (e.g. http://localhost:7474);
+        /// the password is read from NEO4J_PASSWORD or NEO4J_PASSWORD_FILE
+        #[arg(long)]
+        neo4j_url: Option<String>,
+
+        /// Neo4j database to load into
+        #[arg(long, default_value = "neo4j")]
+        neo4j_database: String,
+
+        /// Neo4j user
+        #[arg(long, default_value = "neo4j")]
+        neo4j_user: String,
+    },
+
+    /// Find duplicate articles by content hash and SimHash, optionally removing them
+    Dedupe {
+        /// Markdown directory to scan
+        #[arg(short, long, default_value = "./output/raw")]
+        input: PathBuf,
+
+        /// Maximum SimHash distance (in bits) between near-duplicates
+        #[arg(long, default_value = "3")]
+        max_distance: u32,
+
+        /// Delete redundant markdown files (default: report only)
+        #[arg(long)]
+        delete: bool,
+
+        /// Also delete redundant documents from the OpenSearch index
+        #[arg(long, requires = "delete")]
+        index: bool,
+
+        /// Where to write the JSON report of merged articles
+        #[arg(short, long, default_value = "./output/dedupe-report.json")]
+        report: PathBuf,
+    },
+
+    /// Check config.toml and connectivity to SQLite, PostgreSQL, OpenSearch and other dependencies
+    Doctor {
+        /// Timeout per dependency check in seconds
+        #[arg(long, default_value = "5")]
+        timeout: u64,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Live terminal dashboard of crawl throughput, errors and instance status
+    Tui {
+        /// Crawl database written by crawl or watch
+        #[arg(short, long, default_value = "./output/crawl.db")]
+        database: PathBuf,
+
+        /// Metrics endpoint of a running crawler or coordinator,
+        /// e.g. http://localhost:9090/metrics
+        #[arg(long)]
+        metrics_url: Option<String>,
+
+        /// Coordinator URL for instance status, e.g. http://localhost:8080
+        #[arg(long)]
+        coordinator: Option<String>,
+
+        /// Seconds between refreshes
+        #[arg(long, default_value = "2")]
+        refresh: u64,
+    },
+
+    /// Analyze trends in crawled articles
+    Trends {
+        #[command(subcommand)]
+        command: TrendsCommands,
+    },
+
+    /// Measure search quality against labeled relevance judgments
+    Eval {
+        #[command(subcommand)]
+        command: EvalCommands,
+    },
+
+    /// Inspect the effective configuration or write a sample
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Inspect and manage the Redis cache (REDIS_URL)
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommands {
+    /// Show the Redis hit rate, memory use and cached entries of each kind
+    Stats {
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Delete the cached entries of one kind
+    Invalidate {
+        /// Entries to delete: search, embedding or metadata
+        #[arg(long)]
+        kind: String,
+    },
+
+    /// Embed articles into the cache ahead of a big `baram index` run
+    Warm {
+        /// Markdown file or directory to embed
+        #[arg(short, long, default_value = "./output/raw")]
+        input: PathBuf,
+
+        /// Articles per embedding request
+        #[arg(short, long, default_value = "50")]
+        batch_size: usize,
+
+        /// Where embeddings come from: local (load the model in-process) or
+        /// server (EMBEDDING_SERVER_URL); use the same as for `index`
+        #[arg(long, default_value = "server")]
+        embedder: String,
+
+        /// HuggingFace model for --embedder local, whose tokenizer also
+        /// measures --chunk-tokens
+        #[arg(
+            long,
+            value_name = "MODEL",
+            default_value = "sentence-transformers/paraphrase-multilingual-MiniLM-L12-v2"
+        )]
+        embedding_model: String,
+
+        /// Texts per forward pass of the local model
+        #[arg(long, default_value = "32")]
+        embed_batch_size: usize,
+
+        /// --chunk-tokens of the `index` run to warm up for (0 = whole articles)
+        #[arg(long, default_value = "0")]
+        chunk_tokens: usize,
+
+        /// --chunk-overlap of the `index` run to warm up for
+        #[arg(long, default_value = "64")]
+        chunk_overlap: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Print the effective config (defaults < file < environment < command line)
+    Show {
+        /// List every value with the layer it came from
+        #[arg(long)]
+        resolved: bool,
+    },
+
+    /// Write a commented sample config with every key to the --config path
+    Init {
+        /// Replace an existing file
+        #[arg(long)]
+        force: bool,
+
+        /// Print the sample instead of writing it
+        #[arg(long)]
+        stdout: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReportCommands {
+    /// Summarize a recorded `baram crawl` run
+    Crawl {
+        /// Run id (default: the latest run)
+        #[arg(short, long)]
+        run: Option<i64>,
+
+        /// SQLite database path
+        #[arg(short, long, default_value = "./output/crawl.db")]
+        database: PathBuf,
+
+        /// Number of error messages to list
+        #[arg(short, long, default_value = "5")]
+        errors: usize,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum OntologyCommands {
+    /// Query stored triples by subject, predicate and object patterns
+    ///
+    /// Patterns are case-insensitive and `*` matches any characters; leave a
+    /// position out (or pass `*` or `?var`) to match anything.
+    Query {
+        /// Triple storage directory or `baram ontology` JSON output
+        #[arg(short, long, default_value = "data/triples")]
+        input: PathBuf,
+
+        /// Subject pattern (e.g. "이재*")
+        #[arg(short, long)]
+        subject: Option<String>,
+
+        /// Predicate pattern: RDF predicate, label or relation type (e.g. said)
+        #[arg(short, long)]
+        predicate: Option<String>,
+
+        /// Object pattern (e.g. "*반도체*")
+        #[arg(long)]
+        object: Option<String>,
+
+        /// Minimum triple confidence
+        #[arg(long, default_value = "0.0")]
+        min_confidence: f32,
+
+        /// Only triples that passed verification against the article text
+        #[arg(long, default_value = "false")]
+        verified: bool,
+
+        /// Earliest article date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Latest article date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Maximum number of triples to show (0 = unlimited)
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+
+        /// Output format (table, json)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TrendsCommands {
+    /// Show the fastest rising and falling keywords
+    Keywords {
+        /// Number of days to analyze, ending today
+        #[arg(short, long, default_value = "7")]
+        days: usize,
+
+        /// Number of risers and fallers to show
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// Minimum number of articles mentioning a keyword over the period
+        #[arg(long, default_value = "5")]
+        min_count: u64,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Only tokenize titles, not article bodies
+        #[arg(long, default_value = "false")]
+        titles_only: bool,
+
+        /// Re-tokenize stored articles instead of reading the hourly time series
+        #[arg(long, default_value = "false")]
+        rescan: bool,
+    },
+
+    /// Detect keyword and entity bursts in recent hourly counts
+    Bursts {
+        /// Hours of history to load (baseline plus recent window)
+        #[arg(long, default_value = "48")]
+        hours: usize,
+
+        /// Only report bursts within the most recent N hours
+        #[arg(long, default_value = "3")]
+        recent: usize,
+
+        /// Detection method (zscore, ewma)
+        #[arg(short, long, default_value = "zscore")]
+        method: String,
+
+        /// Standard deviations above the baseline needed to flag a burst
+        #[arg(short, long, default_value = "3.0")]
+        sensitivity: f64,
+
+        /// Minimum mentions in one hour
+        #[arg(long, default_value = "5")]
+        min_count: u64,
+
+        /// Hours of history used as the z-score baseline
+        #[arg(long, default_value = "24")]
+        baseline_hours: usize,
+
+        /// EWMA smoothing factor (0 < alpha <= 1)
+        #[arg(long, default_value = "0.3")]
+        ewma_alpha: f64,
+
+        /// Also detect bursts for named entities
+        #[arg(long, default_value = "false")]
+        entities: bool,
+
+        /// Send bursts to matching KeywordSpike/EntitySurge notification rules
+        #[arg(long, default_value = "false")]
+        notify: bool,
+
+        /// Number of bursts to show
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Show daily sentiment by entity, publisher or category
+    Sentiment {
+        /// Dimension to aggregate by (entity, publisher, category)
+        #[arg(short, long, default_value = "category")]
+        by: String,
+
+        /// Show the daily series of one entity, publisher ID or category
+        #[arg(short, long)]
+        key: Option<String>,
+
+        /// Number of days to show, ending today
+        #[arg(short, long, default_value = "14")]
+        days: usize,
+
+        /// Number of entries to list when no key is given
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// Minimum articles per entry when listing
+        #[arg(long, default_value = "3")]
+        min_articles: u64,
+
+        /// Only query stored aggregates, without scoring new articles
+        #[arg(long, default_value = "false")]
+        no_update: bool,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Build the entity co-occurrence network and export it for visualization
+    Network {
+        /// Triple storage directory or `baram ontology` JSON output
+        #[arg(short, long, default_value = "data/triples")]
+        input: PathBuf,
+
+        /// Earliest article date (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Latest article date (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Minimum number of shared articles per edge
+        #[arg(long, default_value = "2")]
+        min_count: u64,
+
+        /// Minimum PMI score per edge
+        #[arg(long, default_value = "0.0")]
+        min_pmi: f64,
+
+        /// Output format (json, graphml)
+        #[arg(short, long, default_value = "json")]
+        format: String,
+
+        /// Output file (prints to stdout if not set)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Compare how publishers cover the same story clusters
+    Coverage {
+        /// Clusters JSON written by `baram cluster`
+        #[arg(short, long, default_value = "./output/clusters/clusters.json")]
+        input: PathBuf,
+
+        /// Minimum number of publishers covering a story
+        #[arg(long, default_value = "2")]
+        min_publishers: usize,
+
+        /// Emphasized entities to list per publisher
+        #[arg(long, default_value = "5")]
+        top_entities: usize,
+
+        /// Number of stories to report (0 = all)
+        #[arg(short, long, default_value = "20")]
+        limit: usize,
+
+        /// Compare titles only, without loading article bodies from PostgreSQL
+        #[arg(long, default_value = "false")]
+        no_bodies: bool,
+
+        /// Output format (text, json, csv)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Output file (prints to stdout if not set)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Profile one entity: mentions, quotes, related entities, sentiment and top articles
+    Entity {
+        /// Entity name (e.g. a politician or company)
+        name: String,
+
+        /// Triple storage directory or `baram ontology` JSON output
+        #[arg(short, long, default_value = "data/triples")]
+        input: PathBuf,
+
+        /// Number of days to profile, ending today
+        #[arg(short, long, default_value = "30")]
+        days: usize,
+
+        /// Number of quotes, related entities and articles to show
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum EvalCommands {
+    /// Score search modes with nDCG, MRR and recall on a relevance set
+    Search {
+        /// Relevance set: CSV (query,doc_id[,relevance]) or JSON
+        #[arg(long)]
+        qrels: PathBuf,
+
+        /// Comma-separated search modes to compare (bm25, knn, hybrid)
+        #[arg(short, long, default_value = "bm25,knn,hybrid")]
+        modes: String,
+
+        /// Comma-separated BM25 weights to try for hybrid search (0.0 - 1.0)
+        #[arg(long, default_value = "0.3")]
+        bm25_weights: String,
+
+        /// Rank cutoff for nDCG and recall
+        #[arg(short, long, default_value = "10")]
+        k: usize,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Score entity and relation extraction with precision/recall on gold articles
+    Ontology {
+        /// Gold-annotated articles (.json array or .jsonl)
+        #[arg(short, long)]
+        gold: PathBuf,
+
+        /// Add LLM-extracted Said relations (requires vLLM or Ollama)
+        #[arg(long, default_value = "false")]
+        llm: bool,
+
+        /// Earlier JSON report; fails if an F1 score dropped below it
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// F1 drop tolerated before it counts as a regression
+        #[arg(long, default_value = "0.01")]
+        tolerance: f64,
+
+        /// Output format (text, json)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Also write the JSON report to this file (to use as a baseline)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize i18n from environment variable (BARAM_LANG)
+    i18n::init_from_env();
+
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    // Initialize tracing/logging
+    setup_tracing(&cli.log_format, cli.verbose)?;
+
+    tracing::info!("{}", rust_i18n::t!("cli.app.starting"));
+
+    let output_json = cli.output_json;
+    commands::output::set_quiet(cli.quiet || output_json);
+
+    let started = Instant::now();
+    let mut counts = BTreeMap::new();
+    let result = run(cli, &matches, &mut counts).await;
+    if output_json {
+        commands::CommandResult::new(command_path(&matches), started.elapsed(), counts, &result)
+            .print();
+    }
+    result?;
+
+    tracing::info!("{}", rust_i18n::t!("cli.app.completed"));
+    Ok(())
+}
+
+/// Watch the config file of a long-running mode, applying changes to the
+/// `hot` keys without a restart
+fn watch_config(
+    loader: &ConfigLoader,
+    config: &Config,
+    hot: &'static [&'static str],
+) -> Option<ConfigWatcher> {
+    loader.file_path()?;
+    match ConfigWatcher::spawn(loader.clone(), config.clone(), hot) {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            tracing::warn!(error = %format!("{e:#}"), "Config hot-reload disabled");
+            None
+        }
+    }
+}
+
+/// Subcommand path of the invocation, e.g. `report crawl`
+fn command_path(matches: &ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name);
+        current = sub;
+    }
+    names.join(" ")
+}
+
+/// Config overrides given on the command line: every `--set`, plus
+/// `--log-format` and `--verbose` when passed explicitly
+fn cli_overrides(
+    cli: &Cli,
+    matches: &ArgMatches,
+) -> Result<Vec<(&'static str, String, toml::Value)>> {
+    let explicit =
+        |id: &str| matches.value_source(id) == Some(clap::parser::ValueSource::CommandLine);
+    let mut overrides = Vec::new();
+    if explicit("log_format") {
+        overrides.push((
+            "--log-format",
+            "logging.format".to_string(),
+            toml::Value::String(cli.log_format.clone()),
+        ));
+    }
+    if cli.verbose {
+        overrides.push((
+            "--verbose",
+            "logging.level".to_string(),
+            toml::Value::String("debug".to_string()),
+        ));
+    }
+    for arg in &cli.overrides {
+        let (key, value) = parse_set(arg)?;
+        overrides.push(("--set", key, value));
+    }
+    Ok(overrides)
+}
+
+/// Services the command can run without, each with the degraded mode it
+/// switches to
+fn degradable_capabilities(command: &Commands) -> Vec<(Capability, String)> {
+    match command {
+        Commands::Search {
+            local: false,
+            no_cache,
+            ..
+        } => {
+            let mut degradable = vec![(
+                Capability::Search,
+                rust_i18n::t!("cli.degraded.search").to_string(),
+            )];
+            if !no_cache {
+                degradable.push((
+                    Capability::Cache,
+                    rust_i18n::t!("cli.degraded.cache").to_string(),
+                ));
+            }
+            degradable
+        }
+        Commands::Index {
+            no_cache: false, ..
+        } => vec![(
+            Capability::Cache,
+            rust_i18n::t!("cli.degraded.cache").to_string(),
+        )],
+        Commands::Ontology { llm: true, .. } => {
+            vec![(
+                Capability::Llm,
+                rust_i18n::t!("cli.degraded.ontology_llm").to_string(),
+            )]
+        }
+        Commands::Pipeline {
+            no_index: false, ..
+        } => vec![(
+            Capability::Search,
+            rust_i18n::t!("cli.degraded.pipeline_index").to_string(),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Probe the services the command can run without and warn about each
+/// degraded mode, or fail with `--no-degrade`
+///
+/// Running without Redis is the default setup, so an unconfigured cache is
+/// not announced.
+async fn detect_capabilities(cli: &Cli, config: &Config) -> Result<Capabilities> {
+    let degradable = degradable_capabilities(&cli.command);
+    if degradable.is_empty() {
+        return Ok(Capabilities::default());
+    }
+    let wanted: Vec<Capability> = degradable.iter().map(|(c, _)| *c).collect();
+    let capabilities = Capabilities::detect(config, &wanted, DEFAULT_DETECT_TIMEOUT).await;
+
+    for (capability, availability) in capabilities.missing() {
+        let reason = match availability {
+            Availability::Unreachable(e) => e.clone(),
+            _ if capability == Capability::Cache => continue,
+            _ => rust_i18n::t!("cli.degraded.not_configured").to_string(),
+        };
+        if cli.no_degrade {
+            anyhow::bail!(
+                "{}",
+                rust_i18n::t!(
+                    "cli.degraded.refused",
+                    service = capability.service(),
+                    reason = reason
+                )
+            );
+        }
+        let mode = degradable
+            .iter()
+            .find(|(c, _)| *c == capability)
+            .map(|(_, mode)| mode.as_str())
+            .unwrap_or_default();
+        eprintln!(
+            "{}",
+            rust_i18n::t!(
+                "cli.degraded.warning",
+                service = capability.service(),
+                reason = reason,
+                mode = mode
+            )
+        );
+    }
+    Ok(capabilities)
+}
+
+/// Run the selected command; `counts` receives the items it handled, for
+/// `--output-json`
+async fn run(cli: Cli, matches: &ArgMatches, counts: &mut BTreeMap<String, u64>) -> Result<()> {
+    // The sample is written before any config file is looked for
+    if let Commands::Config {
+        command: ConfigCommands::Init { force, stdout },
+    } = cli.command
+    {
+        return commands::config_init(&cli.config, force, stdout);
+    }
+
+    // Load config
+    let profile = cli
+        .profile
+        .clone()
+        .or_else(|| std::env::var("BARAM_PROFILE").ok())
+        .filter(|p| !p.is_empty());
+    let is_doctor = matches!(cli.command, Commands::Doctor { .. });
+    let mut loader = ConfigLoader::new().with_env();
+    if cli.config.exists() {
+        loader = loader.file(&cli.config, profile.as_deref());
+    } else if let (Some(profile), false) = (&profile, is_doctor) {
+        // Never fall back to the defaults when a profile was asked for
+        anyhow::bail!(
+            "{}",
+            rust_i18n::t!(
+                "cli.config.profile_without_file",
+                profile = profile,
+                path = cli.config.display()
+            )
+        );
+    } else {
+        tracing::warn!(
+            path = %cli.config.display(),
+            "{}",
+            rust_i18n::t!("cli.config.not_found")
+        );
+    }
+    for (flag, key, value) in cli_overrides(&cli, matches)? {
+        loader = loader.set(flag, &key, value)?;
+    }
+    let resolved = match loader.load() {
+        Ok(resolved) => resolved,
+        // The doctor reports config errors itself
+        Err(e) if is_doctor => {
+            tracing::debug!(error = %e, "Invalid config file");
+            ConfigLoader::new().load()?
+        }
+        Err(e) => return Err(e),
+    };
+    let config = resolved.config.clone();
+    if let Some(profile) = &config.profile {
+        tracing::info!(profile = %profile, "Using config profile");
+    }
+    if !config.analysis.is_empty() {
+        match Vocabulary::load(&config.analysis) {
+            Ok(vocabulary) => {
+                vocabulary.install();
+            }
+            // The doctor reports unreadable vocabulary files itself
+            Err(e) if is_doctor => tracing::debug!(error = %e, "Invalid vocabulary"),
+            Err(e) => return Err(e.context("Failed to load the [analysis] vocabulary")),
+        }
+    }
+
+    let capabilities = detect_capabilities(&cli, &config).await?;
+
+    let progress_mode = match cli
+        .progress
+        .parse::<commands::ProgressMode>()
+        .map_err(anyhow::Error::msg)?
+    {
+        commands::ProgressMode::Bar if commands::output::is_quiet() => commands::ProgressMode::None,
+        mode => mode,
+    };
+    let progress = commands::Progress::new(progress_mode);
+
+    match cli.command {
+        Commands::Crawl {
+            category,
+            max_articles,
+            output,
+            format,
+            with_comments,
+            download_images,
+            skip_existing,
+            metrics_port,
+            daemon: true,
+            schedule,
+            status_port,
+            ..
+        } => {
+            let format = format
+                .parse::<baram::storage::StorageFormat>()
+                .map_err(anyhow::Error::msg)?;
+            tracing::info!(
+                category = ?category,
+                schedule = ?schedule,
+                with_comments = %with_comments,
+                "Starting crawl daemon"
+            );
+            let config_watcher = watch_config(
+                &loader,
+                &config,
+                &[reload::RATE_LIMIT, reload::CATEGORY_WEIGHTS],
+            );
+            commands::watch(
+                config.clone(),
+                commands::WatchParams {
+                    categories: category.into_iter().collect(),
+                    interval: "30m".to_string(),
+                    schedules: schedule,
+                    max_articles: max_articles.unwrap_or(100),
+                    output,
+                    format,
+                    download_images,
+                    with_comments,
+                    skip_existing,
+                    metrics_port,
+                    status_port,
+                    once: false,
+                    config_updates: config_watcher.as_ref().map(ConfigWatcher::subscribe),
+                    shutdown: commands::Shutdown::listen(),
+                },
+                &progress,
+            )
+            .await?;
+        }
+
+        Commands::Crawl {
+            site,
+            category,
+            max_articles,
+            url,
+            url_file,
+            query,
+            from,
+            to,
+            discovery,
+            with_comments,
+            download_images,
+            output,
+            format,
+            skip_existing,
+            metrics_port,
+            mock,
+            mock_latency,
+            mock_error_rate,
+            mock_seed,
+            index,
+            index_queue,
+            ..
+        } => {
+            tracing::info!(
+                site = ?site,
+                category = ?category,
+                max_articles = ?max_articles,
+                url = ?url,
+                url_file = ?url_file,
+                query = ?query,
+                discovery = %discovery,
+                with_comments = %with_comments,
+                download_images = %download_images,
+                output = %output.display(),
+                metrics_port = ?metrics_port,
+                mock = %mock,
+                index = %index,
+                "Starting crawl command"
+            );
+            let discovery = discovery
+                .parse::<baram::crawler::feed::Discovery>()
+                .map_err(anyhow::Error::msg)?;
+            let format = format
+                .parse::<baram::storage::StorageFormat>()
+                .map_err(anyhow::Error::msg)?;
+            #[cfg(not(feature = "mock"))]
+            if mock {
+                let _ = (mock_latency, mock_error_rate, mock_seed);
+                anyhow::bail!("--mock needs baram built with `--features mock`");
+            }
+            let mut job = JobMetrics::new("crawl");
+            let result = commands::crawl(
+                config,
+                commands::CrawlParams {
+                    site,
+                    category,
+                    max_articles,
+                    url,
+                    url_file,
+                    query,
+                    discovery,
+                    from,
+                    to,
+                    with_comments,
+                    download_images,
+                    output,
+                    format,
+                    skip_existing,
+                    metrics_port,
+                    #[cfg(feature = "mock")]
+                    mock: mock.then(|| baram::crawler::mock::MockNaverConfig {
+                        latency: std::time::Duration::from_millis(mock_latency),
+                        error_rate: mock_error_rate,
+                        seed: mock_seed,
+                    }),
+                    index,
+                    index_queue,
+                    shutdown: commands::Shutdown::listen(),
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::Pipeline {
+            category,
+            max_articles,
+            output,
+            skip_existing,
+            no_index,
+            no_ontology,
+            ontology_output,
+            metrics_port,
+        } => {
+            tracing::info!(
+                category = ?category,
+                max_articles = %max_articles,
+                index = %!no_index,
+                ontology = %!no_ontology,
+                "Starting pipeline command"
+            );
+            init_if_configured(&config.metrics);
+            let mut job = JobMetrics::new("pipeline");
+            let result = commands::pipeline(
+                config.clone(),
+                commands::PipelineParams {
+                    category,
+                    max_articles,
+                    output,
+                    skip_existing,
+                    index: !no_index && capabilities.is_available(Capability::Search),
+                    ontology: !no_ontology,
+                    ontology_output,
+                    metrics_port,
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            push_if_configured(&config.metrics, &job, result.is_ok()).await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::Watch {
+            categories,
+            interval,
+            schedules,
+            max_articles,
+            output,
+            format,
+            download_images,
+            metrics_port,
+            status_port,
+            once,
+        } => {
+            tracing::info!(
+                categories = ?categories,
+                interval = %interval,
+                schedules = ?schedules,
+                once = %once,
+                "Starting watch command"
+            );
+            let format = format
+                .parse::<baram::storage::StorageFormat>()
+                .map_err(anyhow::Error::msg)?;
+            let config_watcher = if once {
+                None
+            } else {
+                watch_config(
+                    &loader,
+                    &config,
+                    &[reload::RATE_LIMIT, reload::CATEGORY_WEIGHTS],
+                )
+            };
+            commands::watch(
+                config.clone(),
+                commands::WatchParams {
+                    categories,
+                    interval,
+                    schedules,
+                    max_articles,
+                    output,
+                    format,
+                    download_images,
+                    with_comments: false,
+                    skip_existing: true,
+                    metrics_port,
+                    status_port,
+                    once,
+                    config_updates: config_watcher.as_ref().map(ConfigWatcher::subscribe),
+                    shutdown: commands::Shutdown::listen(),
+                },
+                &progress,
+            )
+            .await?;
+        }
+
+        Commands::Index {
+            input,
+            batch_size,
+            force,
+            since,
+            embeddings,
+            parallelism,
+            embedder,
+            embedding_model,
+            embed_batch_size,
+            migrate_index,
+            chunk_tokens,
+            chunk_overlap,
+            no_cache,
+        } => {
+            tracing::info!(
+                input = %input,
+                batch_size = %batch_size,
+                force = %force,
+                since = ?since,
+                embeddings = ?embeddings,
+                parallelism = %parallelism,
+                embedder = %embedder,
+                "Starting index command"
+            );
+            init_if_configured(&config.metrics);
+            let mut job = JobMetrics::new("index");
+            let run = commands::RunRecord::start("index", Path::new(&input));
+            let shutdown = commands::Shutdown::listen();
+            let result = commands::index(
+                &config,
+                commands::IndexParams {
+                    input,
+                    batch_size,
+                    force,
+                    since,
+                    embeddings,
+                    parallelism,
+                    embedder,
+                    embedding_model,
+                    embed_batch_size,
+                    migrate_index,
+                    chunk_tokens,
+                    chunk_overlap,
+                    cache: !no_cache
+                        && capabilities.availability(Capability::Cache)
+                            == Some(&Availability::Available),
+                    shutdown: shutdown.clone(),
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            if let Some(run) = run {
+                run.finish(shutdown.run_status(result.is_ok()), job.counts());
+            }
+            push_if_configured(&config.metrics, &job, result.is_ok()).await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::Search {
+            query,
+            k,
+            threshold,
+            mode,
+            collapse,
+            output,
+            fields,
+            local_model,
+            local,
+            input,
+            rerank,
+            rerank_window,
+            rerank_model,
+            no_cache,
+        } => {
+            let local = local || !capabilities.is_available(Capability::Search);
+            tracing::info!(
+                query = %query,
+                k = %k,
+                threshold = ?threshold,
+                mode = %mode,
+                collapse = %collapse,
+                output = %output,
+                local_model = ?local_model,
+                local = %local,
+                rerank = %rerank,
+                "Starting search command"
+            );
+            commands::search(
+                &config,
+                commands::SearchParams {
+                    query,
+                    k,
+                    threshold,
+                    mode,
+                    collapse,
+                    output,
+                    fields,
+                    local_model,
+                    local,
+                    input,
+                    cache: !no_cache
+                        && capabilities.availability(Capability::Cache)
+                            == Some(&Availability::Available),
+                    rerank,
+                    rerank_window,
+                    rerank_model,
+                },
+            )
+            .await?;
+        }
+
+        Commands::Ontology {
+            command:
+                Some(OntologyCommands::Query {
+                    input,
+                    subject,
+                    predicate,
+                    object,
+                    min_confidence,
+                    verified,
+                    since,
+                    until,
+                    limit,
+                    format,
+                }),
+            ..
+        } => {
+            tracing::info!(
+                input = %input.display(),
+                subject = ?subject,
+                predicate = ?predicate,
+                object = ?object,
+                "Starting ontology query"
+            );
+            commands::ontology_query(commands::OntologyQueryParams {
+                input,
+                subject,
+                predicate,
+                object,
+                min_confidence,
+                verified,
+                since,
+                until,
+                limit,
+                format,
+            })?;
+        }
+
+        Commands::Ontology {
+            command: None,
+            input,
+            format,
+            output,
+            link_entities,
+            store,
+            llm,
+            max_concurrent,
+        } => {
+            // clap requires --input when no subcommand is given
+            let input = input.unwrap_or_default();
+            tracing::info!(
                 input = %input,
                 format = %format,
                 output = ?output,
+                link_entities = link_entities,
                 llm = llm,
                 max_concurrent = max_concurrent,
                 "Starting ontology command"
             );
-            commands::ontology(input, format, output, llm, max_concurrent).await?;
+            let llm = llm && capabilities.is_available(Capability::Llm);
+            init_if_configured(&config.metrics);
+            let mut job = JobMetrics::new("ontology");
+            let run = commands::RunRecord::start("ontology", Path::new(&input));
+            let shutdown = commands::Shutdown::listen();
+            let result = commands::ontology(
+                &config,
+                commands::OntologyParams {
+                    input,
+                    format,
+                    output,
+                    link_entities,
+                    store,
+                    llm,
+                    max_concurrent,
+                    shutdown: shutdown.clone(),
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            if let Some(run) = run {
+                run.finish(shutdown.run_status(result.is_ok()), job.counts());
+            }
+            push_if_configured(&config.metrics, &job, result.is_ok()).await;
+            *counts = job.counts().clone();
+            result?;
         }
 
         Commands::Resume {
@@ -399,13 +1959,166 @@ async fn main() -> Result<()> {
                 max_articles = ?max_articles,
                 "Starting resume command"
             );
-            commands::resume(checkpoint, max_articles, output).await?;
+            let mut job = JobMetrics::new("crawl");
+            let result =
+                commands::resume(checkpoint, max_articles, output, &mut job, &progress).await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::Recrawl {
+            output,
+            since,
+            limit,
+        } => {
+            tracing::info!(
+                output = %output.display(),
+                since = ?since,
+                limit,
+                "Starting recrawl command"
+            );
+            let mut job = JobMetrics::new("recrawl");
+            let result = commands::recrawl(
+                config,
+                commands::RecrawlParams {
+                    output,
+                    since,
+                    limit,
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::RetryFailed {
+            output,
+            limit,
+            report,
+        } => {
+            tracing::info!(
+                output = %output.display(),
+                limit,
+                report = ?report,
+                "Starting retry-failed command"
+            );
+            let mut job = JobMetrics::new("retry-failed");
+            let result = commands::retry_failed(
+                config,
+                commands::RetryFailedParams {
+                    output,
+                    limit,
+                    report,
+                },
+                &mut job,
+                &progress,
+            )
+            .await;
+            *counts = job.counts().clone();
+            result?;
         }
 
         Commands::Stats { database } => {
             commands::stats(database)?;
         }
 
+        Commands::Export {
+            what,
+            format,
+            source,
+            input,
+            database,
+            output,
+            since,
+            until,
+            category,
+            limit,
+            fields,
+            neo4j_url,
+            neo4j_database,
+            neo4j_user,
+        } => {
+            tracing::info!(
+                what = %what,
+                format = %format,
+                source = ?source,
+                since = ?since,
+                until = ?until,
+                category = ?category,
+                fields = ?fields,
+                "Starting export"
+            );
+            commands::export(
+                &config,
+                commands::ExportParams {
+                    what,
+                    format,
+                    source,
+                    input,
+                    database,
+                    output,
+                    since,
+                    until,
+                    category,
+                    limit,
+                    fields,
+                    neo4j_url,
+                    neo4j_database,
+                    neo4j_user,
+                },
+            )
+            .await?;
+        }
+
+        Commands::Dedupe {
+            input,
+            max_distance,
+            delete,
+            index,
+            report,
+        } => {
+            tracing::info!(
+                input = %input.display(),
+                max_distance,
+                delete,
+                index,
+                "Starting dedupe"
+            );
+            commands::dedupe(
+                &config,
+                commands::DedupeParams {
+                    input,
+                    max_distance,
+                    delete,
+                    index,
+                    report,
+                },
+                &progress,
+            )
+            .await?;
+        }
+
+        Commands::Doctor { timeout, format } => {
+            commands::doctor(&cli.config, profile.as_deref(), timeout, &format).await?;
+        }
+
+        Commands::Tui {
+            database,
+            metrics_url,
+            coordinator,
+            refresh,
+        } => {
+            commands::tui(commands::TuiParams {
+                database,
+                metrics_url,
+                coordinator_url: coordinator,
+                refresh_secs: refresh,
+            })
+            .await?;
+        }
+
         Commands::Cluster {
             category,
             since,
@@ -427,19 +2140,63 @@ async fn main() -> Result<()> {
                 .await?;
         }
 
-        Commands::Serve { port, host } => {
+        Commands::Topics {
+            category,
+            since,
+            topics,
+            min_size,
+            max_articles,
+            output,
+            summarize,
+        } => {
+            tracing::info!(
+                category = ?category,
+                since = ?since,
+                topics = %topics,
+                min_size = %min_size,
+                summarize = %summarize,
+                "Starting topic discovery"
+            );
+            commands::topics(commands::TopicsParams {
+                category,
+                since,
+                topics,
+                min_size,
+                max_articles,
+                output,
+                summarize,
+            })
+            .await?;
+        }
+
+        Commands::Serve {
+            port,
+            host,
+            api_keys,
+        } => {
             tracing::info!(
                 host = %host,
                 port = %port,
                 "Starting API server"
             );
-            commands::api_server(host, port).await?;
+            // The API server applies no config changes in place; the watcher
+            // reports the ones that need a restart
+            let _config_watcher = watch_config(&loader, &config, &[]);
+            commands::api_server(commands::ApiServerParams {
+                host,
+                port,
+                database: config.database,
+                opensearch: config.opensearch,
+                api_keys,
+            })
+            .await?;
         }
 
         Commands::EmbeddingServer {
             port,
             host,
-            model,
+            models,
+            rerank_models,
             max_seq_length,
             batch_size,
             use_gpu,
@@ -447,12 +2204,21 @@ async fn main() -> Result<()> {
             tracing::info!(
                 host = %host,
                 port = %port,
-                model = %model,
+                models = ?models,
+                rerank_models = ?rerank_models,
                 use_gpu = %use_gpu,
                 "Starting embedding server"
             );
-            commands::embedding_server(host, port, model, max_seq_length, batch_size, use_gpu)
-                .await?;
+            commands::embedding_server(
+                host,
+                port,
+                models,
+                rerank_models,
+                max_seq_length,
+                batch_size,
+                use_gpu,
+            )
+            .await?;
         }
 
         Commands::Distributed {
@@ -464,13 +2230,22 @@ async fn main() -> Result<()> {
             output,
             with_comments,
             once,
+            metrics_port,
+            store_articles,
         } => {
             tracing::info!(
                 instance = %instance,
                 coordinator = %coordinator,
                 once = %once,
+                metrics_port = ?metrics_port,
+                store_articles = %store_articles,
                 "Starting distributed crawler"
             );
+            let config_watcher = if once {
+                None
+            } else {
+                watch_config(&loader, &config, &[reload::RATE_LIMIT])
+            };
             commands::distributed_crawler(commands::DistributedCrawlerParams {
                 instance,
                 coordinator,
@@ -480,6 +2255,12 @@ async fn main() -> Result<()> {
                 output,
                 with_comments,
                 once,
+                metrics_port,
+                store_articles,
+                config_updates: config_watcher.as_ref().map(ConfigWatcher::subscribe),
+                categories: config.category.clone(),
+                notifications: config.notifications.clone(),
+                crawler: config.crawler.clone(),
             })
             .await?;
         }
@@ -490,6 +2271,10 @@ async fn main() -> Result<()> {
             heartbeat_timeout,
             heartbeat_interval,
             max_instances,
+            registry_db,
+            registry_retention_days,
+            work_lease,
+            work_batch_size,
             schedule_cache,
             disable_cors,
             disable_logging,
@@ -500,21 +2285,367 @@ async fn main() -> Result<()> {
                 max_instances = %max_instances,
                 "Starting coordinator server"
             );
+            let config_watcher = watch_config(&loader, &config, &[reload::ALERT_RULES]);
             commands::coordinator_server(commands::CoordinatorParams {
                 host,
                 port,
                 heartbeat_timeout,
                 heartbeat_interval,
                 max_instances,
+                registry_db,
+                registry_retention_days,
+                work_lease,
+                work_batch_size,
                 schedule_cache,
                 enable_cors: !disable_cors,
                 enable_logging: !disable_logging,
+                notifications: config.notifications,
+                database: config.database,
+                opensearch: config.opensearch,
+                config_updates: config_watcher.as_ref().map(ConfigWatcher::subscribe),
             })
             .await?;
         }
+
+        Commands::Report {
+            command:
+                Some(ReportCommands::Crawl {
+                    run,
+                    database,
+                    errors,
+                    format,
+                }),
+            ..
+        } => {
+            commands::crawl_report(commands::CrawlReportParams {
+                run,
+                database,
+                errors,
+                format,
+            })?;
+        }
+
+        Commands::Report {
+            command: None,
+            period,
+            date,
+            format,
+            output,
+            limit,
+            notify,
+            channels,
+        } => {
+            tracing::info!(
+                period = %period,
+                date = ?date,
+                format = %format,
+                "Starting report generation"
+            );
+            let metrics_config = config.metrics.clone();
+            init_if_configured(&metrics_config);
+            let mut job = JobMetrics::new("report");
+            let result = commands::report(
+                config,
+                commands::ReportParams {
+                    period: baram::analytics::ReportPeriod::parse(&period)
+                        .map_err(anyhow::Error::msg)?,
+                    date,
+                    format,
+                    output,
+                    limit,
+                    notify,
+                    channels,
+                },
+                &mut job,
+            )
+            .await;
+            push_if_configured(&metrics_config, &job, result.is_ok()).await;
+            *counts = job.counts().clone();
+            result?;
+        }
+
+        Commands::Trends { command } => match command {
+            TrendsCommands::Keywords {
+                days,
+                limit,
+                min_count,
+                format,
+                titles_only,
+                rescan,
+            } => {
+                tracing::info!(
+                    days = %days,
+                    limit = %limit,
+                    min_count = %min_count,
+                    "Starting keyword trends command"
+                );
+                commands::keyword_trends(
+                    config,
+                    commands::KeywordTrendsParams {
+                        days,
+                        limit,
+                        min_count,
+                        format,
+                        titles_only,
+                        rescan,
+                    },
+                )
+                .await?;
+            }
+            TrendsCommands::Bursts {
+                hours,
+                recent,
+                method,
+                sensitivity,
+                min_count,
+                baseline_hours,
+                ewma_alpha,
+                entities,
+                notify,
+                limit,
+                format,
+            } => {
+                tracing::info!(
+                    hours = %hours,
+                    method = %method,
+                    sensitivity = %sensitivity,
+                    notify = %notify,
+                    "Starting burst detection command"
+                );
+                let burst = baram::analytics::BurstConfig {
+                    method: baram::analytics::BurstMethod::parse(&method)
+                        .map_err(anyhow::Error::msg)?,
+                    sensitivity,
+                    min_count,
+                    baseline_hours,
+                    ewma_alpha,
+                };
+                commands::bursts(
+                    config,
+                    commands::BurstParams {
+                        hours,
+                        recent,
+                        burst,
+                        entities,
+                        notify,
+                        limit,
+                        format,
+                    },
+                )
+                .await?;
+            }
+            TrendsCommands::Sentiment {
+                by,
+                key,
+                days,
+                limit,
+                min_articles,
+                no_update,
+                format,
+            } => {
+                tracing::info!(
+                    by = %by,
+                    key = ?key,
+                    days = %days,
+                    "Starting sentiment trends command"
+                );
+                commands::sentiment_trends(
+                    config,
+                    commands::SentimentTrendsParams {
+                        by: baram::analytics::SentimentDimension::parse(&by)
+                            .map_err(anyhow::Error::msg)?,
+                        key,
+                        days,
+                        limit,
+                        min_articles,
+                        update: !no_update,
+                        format,
+                    },
+                )
+                .await?;
+            }
+            TrendsCommands::Network {
+                input,
+                from,
+                to,
+                min_count,
+                min_pmi,
+                format,
+                output,
+            } => {
+                tracing::info!(
+                    input = %input.display(),
+                    from = ?from,
+                    to = ?to,
+                    format = %format,
+                    "Starting entity network command"
+                );
+                commands::entity_network(commands::EntityNetworkParams {
+                    input,
+                    from,
+                    to,
+                    min_count,
+                    min_pmi,
+                    format,
+                    output,
+                })
+                .await?;
+            }
+            TrendsCommands::Coverage {
+                input,
+                min_publishers,
+                top_entities,
+                limit,
+                no_bodies,
+                format,
+                output,
+            } => {
+                tracing::info!(
+                    input = %input.display(),
+                    min_publishers = %min_publishers,
+                    format = %format,
+                    "Starting publisher coverage command"
+                );
+                commands::publisher_coverage(
+                    config,
+                    commands::CoverageParams {
+                        input,
+                        min_publishers,
+                        top_entities,
+                        limit,
+                        bodies: !no_bodies,
+                        format,
+                        output,
+                    },
+                )
+                .await?;
+            }
+            TrendsCommands::Entity {
+                name,
+                input,
+                days,
+                limit,
+                format,
+            } => {
+                tracing::info!(
+                    name = %name,
+                    input = %input.display(),
+                    days = %days,
+                    "Starting entity profile command"
+                );
+                commands::entity_profile(
+                    config,
+                    commands::EntityProfileParams {
+                        name,
+                        input,
+                        days,
+                        limit,
+                        format,
+                    },
+                )
+                .await?;
+            }
+        },
+
+        Commands::Eval { command } => match command {
+            EvalCommands::Search {
+                qrels,
+                modes,
+                bm25_weights,
+                k,
+                format,
+            } => {
+                tracing::info!(
+                    qrels = %qrels.display(),
+                    modes = %modes,
+                    bm25_weights = %bm25_weights,
+                    k = %k,
+                    "Starting search evaluation"
+                );
+                commands::eval_search(
+                    &config,
+                    commands::EvalSearchParams {
+                        qrels,
+                        modes,
+                        bm25_weights,
+                        k,
+                        format,
+                    },
+                )
+                .await?;
+            }
+            EvalCommands::Ontology {
+                gold,
+                llm,
+                baseline,
+                tolerance,
+                format,
+                output,
+            } => {
+                tracing::info!(
+                    gold = %gold.display(),
+                    llm = %llm,
+                    baseline = ?baseline,
+                    "Starting ontology evaluation"
+                );
+                commands::eval_ontology(commands::EvalOntologyParams {
+                    gold,
+                    llm,
+                    baseline,
+                    tolerance,
+                    format,
+                    output,
+                })
+                .await?;
+            }
+        },
+
+        Commands::Config { command } => match command {
+            ConfigCommands::Show { resolved: sources } => {
+                commands::config_show(&resolved, sources)?;
+            }
+            ConfigCommands::Init { .. } => unreachable!("handled before loading the config"),
+        },
+
+        Commands::Cache { command } => match command {
+            CacheCommands::Stats { format } => {
+                commands::cache_stats(&format).await?;
+            }
+            CacheCommands::Invalidate { kind } => {
+                commands::cache_invalidate(&kind).await?;
+            }
+            CacheCommands::Warm {
+                input,
+                batch_size,
+                embedder,
+                embedding_model,
+                embed_batch_size,
+                chunk_tokens,
+                chunk_overlap,
+            } => {
+                tracing::info!(
+                    input = %input.display(),
+                    embedder = %embedder,
+                    "Starting cache warm command"
+                );
+                commands::cache_warm(
+                    &config,
+                    commands::CacheWarmParams {
+                        input,
+                        batch_size,
+                        embedder,
+                        embedding_model,
+                        embed_batch_size,
+                        chunk_tokens,
+                        chunk_overlap,
+                    },
+                    &progress,
+                )
+                .await?;
+            }
+        },
     }
 
-    tracing::info!("{}", rust_i18n::t!("cli.app.completed"));
     Ok(())
 }
 
@@ -525,20 +2656,57 @@ fn setup_tracing(format: &str, verbose: bool) -> Result<()> {
         tracing_subscriber::EnvFilter::new("baram=info,warn")
     };
 
+    // Logs go to stderr so stdout carries only command output (e.g. JSON)
     match format {
         "json" => {
             tracing_subscriber::registry()
                 .with(env_filter)
-                .with(tracing_subscriber::fmt::layer().json())
+                .with(
+                    tracing_subscriber::fmt::layer()
+                        .json()
+                        .with_writer(std::io::stderr),
+                )
                 .init();
         }
         _ => {
             tracing_subscriber::registry()
                 .with(env_filter)
-                .with(tracing_subscriber::fmt::layer().pretty())
+                .with(
+                    tracing_subscriber::fmt::layer()
+                        .pretty()
+                        .with_writer(std::io::stderr),
+                )
                 .init();
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        // Catches clashing flags, which clap only reports when parsing
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_ontology_query_without_input() {
+        let cli =
+            Cli::try_parse_from(["baram", "ontology", "query", "--predicate", "said"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Ontology {
+                command: Some(OntologyCommands::Query { .. }),
+                ..
+            }
+        ));
+
+        // Extraction still needs its input
+        assert!(Cli::try_parse_from(["baram", "ontology"]).is_err());
+        assert!(Cli::try_parse_from(["baram", "ontology", "-i", "./output/raw"]).is_ok());
+    }
+}
@@ -3,18 +3,68 @@
 //! This module provides metrics tracking for:
 //! - Coordinator: instance registration, heartbeats, errors
 //! - Distributed Crawler: crawl duration, articles per category, dedup hits, pipeline stats
+//! - Scheduled crawls (`baram watch`): last and next run time per category
+//! - Fetch resilience: retries per host and reason, and the state of each
+//!   host's circuit breaker
+//! - Publishers: articles and errors per publisher (`oid`) and error class,
+//!   so a degrading outlet stands out from the aggregate error count
+//! - Latency: parse duration and client-observed embedding, OpenSearch bulk
+//!   and LLM request latency, recorded at the call sites
+//! - Runtime: process RSS, open file descriptors, tokio task counts and
+//!   rate-limiter wait time, so resource pressure on long-running instances
+//!   is visible; process and tokio gauges are refreshed on every scrape
+//! - Dependencies: whether the LLM and embedding server answered their last
+//!   background probe
 //!
 //! # Usage
 //!
-//! Call `init_metrics()` at application startup to register all metrics.
-//! If initialization fails, metrics operations become no-ops.
+//! Metrics are registered in a [`Registry`] owned by a [`Metrics`] handle, so
+//! embedders and tests can keep their own isolated set:
+//!
+//! ```
+//! use baram::metrics::Metrics;
+//!
+//! let metrics = Metrics::new().expect("metrics register");
+//! metrics.record_articles_crawled("main", "politics", 20);
+//! assert!(metrics.encode().unwrap().contains("baram_crawler_articles_per_category_total"));
+//! ```
+//!
+//! The binary calls `init_metrics()` at startup to create the global default
+//! set. Components that record metrics (`CoordinatorServer`,
+//! `DistributedRunner`, `CrawlerPipeline`) take a handle through
+//! `with_metrics()` and default to the global one; the free functions in this
+//! module record to the global default and are no-ops until it exists.
+//!
+//! The coordinator serves `/metrics` on its API port. Crawlers without an
+//! HTTP API can expose the same endpoint on a dedicated port with
+//! `spawn_metrics_server()`. Batch jobs push their final metrics to a
+//! Pushgateway (see [`push`]).
+
+pub mod process;
+pub mod push;
+pub mod scrape;
 
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+
+use prometheus::core::Collector;
+use prometheus::proto::MetricFamily;
 use prometheus::{
-    register_counter, register_counter_vec, register_gauge, register_gauge_vec,
-    register_histogram_vec, Counter, CounterVec, Encoder, Gauge, GaugeVec, HistogramVec,
+    Counter, CounterVec, Encoder, Gauge, GaugeVec, HistogramOpts, HistogramVec, Opts, Registry,
     TextEncoder,
 };
-use std::sync::OnceLock;
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock};
+use tokio::task::JoinHandle;
+
+use crate::utils::error::ErrorClass;
+
+/// Publisher label used when the `oid` of a failed URL is unknown
+pub const UNKNOWN_PUBLISHER: &str = "unknown";
 
 // ============================================================================
 // Metrics Storage
@@ -45,26 +95,886 @@ struct CrawlerMetrics {
     slot_errors: CounterVec,
     current_hour: GaugeVec,
     is_crawling: GaugeVec,
+    publisher_articles: CounterVec,
+    publisher_errors: CounterVec,
+    last_run: GaugeVec,
+    next_run: GaugeVec,
+    fetch_retries: CounterVec,
+    circuit_state: GaugeVec,
+    circuit_opened: CounterVec,
+    not_modified: CounterVec,
 }
 
-/// Global storage for coordinator metrics
-static COORDINATOR_METRICS: OnceLock<CoordinatorMetrics> = OnceLock::new();
+/// Container for pipeline stage latency metrics
+struct LatencyMetrics {
+    parse_duration: HistogramVec,
+    embedding_duration: HistogramVec,
+    opensearch_bulk_duration: HistogramVec,
+    llm_duration: HistogramVec,
+}
 
-/// Global storage for crawler metrics
-static CRAWLER_METRICS: OnceLock<CrawlerMetrics> = OnceLock::new();
+/// Container for process and runtime metrics
+struct RuntimeMetrics {
+    resident_memory: Gauge,
+    open_fds: Gauge,
+    tokio_workers: Gauge,
+    tokio_alive_tasks: Gauge,
+    tokio_global_queue_depth: Gauge,
+    rate_limiter_last_wait: GaugeVec,
+    rate_limiter_wait_total: CounterVec,
+    dependency_up: GaugeVec,
+}
+
+/// Names of the latency histograms, also pushed by batch jobs
+pub const LATENCY_METRIC_NAMES: [&str; 4] = [
+    "baram_parse_duration_seconds",
+    "baram_embedding_request_duration_seconds",
+    "baram_opensearch_bulk_duration_seconds",
+    "baram_llm_request_duration_seconds",
+];
 
-/// Flag to track if initialization was attempted
-static METRICS_INIT_ATTEMPTED: OnceLock<bool> = OnceLock::new();
+/// All metrics of one [`Metrics`] handle
+struct MetricsInner {
+    registry: Registry,
+    coordinator: CoordinatorMetrics,
+    crawler: CrawlerMetrics,
+    latency: LatencyMetrics,
+    runtime: RuntimeMetrics,
+}
+
+/// Handle to a set of baram metrics registered in one [`Registry`]
+///
+/// Clones share the same metrics. A disabled handle (the `Default`) records
+/// nothing, so components can always hold one.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Option<Arc<MetricsInner>>,
+}
+
+/// Global default metrics, created by [`init_metrics`]
+static GLOBAL_METRICS: OnceLock<Metrics> = OnceLock::new();
 
 // ============================================================================
 // Initialization
 // ============================================================================
 
-/// Initialize all Prometheus metrics
+/// Register a metric in `registry` and return it
+fn register<M: Collector + Clone + 'static>(
+    registry: &Registry,
+    metric: M,
+) -> prometheus::Result<M> {
+    registry.register(Box::new(metric.clone()))?;
+    Ok(metric)
+}
+
+impl MetricsInner {
+    fn new(registry: Registry) -> prometheus::Result<Self> {
+        // Register coordinator metrics
+        let coordinator = CoordinatorMetrics {
+            registered_instances: register(
+                &registry,
+                Gauge::new(
+                    "baram_coordinator_registered_instances",
+                    "Number of registered crawler instances",
+                )?,
+            )?,
+            online_instances: register(
+                &registry,
+                Gauge::new(
+                    "baram_coordinator_online_instances",
+                    "Number of currently online crawler instances",
+                )?,
+            )?,
+            total_heartbeats: register(
+                &registry,
+                Counter::new(
+                    "baram_coordinator_total_heartbeats",
+                    "Total number of heartbeats received",
+                )?,
+            )?,
+            heartbeat_errors: register(
+                &registry,
+                Counter::new(
+                    "baram_coordinator_heartbeat_errors_total",
+                    "Total number of heartbeat errors",
+                )?,
+            )?,
+            articles_crawled: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_coordinator_articles_crawled_total",
+                        "Total articles crawled by instance",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            errors: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_coordinator_errors_total",
+                        "Total errors reported by instance",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            api_requests: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_coordinator_api_requests_total",
+                        "Total API requests by endpoint and status",
+                    ),
+                    &["endpoint", "status"],
+                )?,
+            )?,
+            api_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        "baram_coordinator_api_request_duration_seconds",
+                        "API request duration in seconds",
+                    )
+                    .buckets(vec![
+                        0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+                    ]),
+                    &["endpoint"],
+                )?,
+            )?,
+        };
+
+        // Register crawler metrics
+        let crawler = CrawlerMetrics {
+            crawl_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        "baram_crawler_crawl_duration_seconds",
+                        "Time spent crawling a category in seconds",
+                    )
+                    .buckets(vec![
+                        1.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0,
+                    ]),
+                    &["instance", "category"],
+                )?,
+            )?,
+            articles_per_category: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_articles_per_category_total",
+                        "Total articles crawled per category",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            dedup_hits: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_dedup_hits_total",
+                        "Total deduplication cache hits (URLs already crawled)",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            dedup_misses: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_dedup_misses_total",
+                        "Total deduplication cache misses (new URLs)",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            pipeline_success: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_pipeline_success_total",
+                        "Total successful pipeline executions",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            pipeline_failure: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_pipeline_failure_total",
+                        "Total failed pipeline executions",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            pipeline_skipped: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_pipeline_skipped_total",
+                        "Total skipped articles in pipeline",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            slot_executions: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_slot_executions_total",
+                        "Total slot executions",
+                    ),
+                    &["instance", "hour"],
+                )?,
+            )?,
+            slot_errors: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_slot_errors_total",
+                        "Total slot execution errors",
+                    ),
+                    &["instance", "hour"],
+                )?,
+            )?,
+            current_hour: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_crawler_current_hour",
+                        "Current hour being crawled (0-23)",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            is_crawling: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_crawler_is_crawling",
+                        "Whether the crawler is currently crawling (1 = yes, 0 = no)",
+                    ),
+                    &["instance"],
+                )?,
+            )?,
+            publisher_articles: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_publisher_articles_total",
+                        "Total articles crawled per publisher",
+                    ),
+                    &["instance", "publisher"],
+                )?,
+            )?,
+            publisher_errors: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_publisher_errors_total",
+                        "Total crawl errors per publisher and error class",
+                    ),
+                    &["instance", "publisher", "class"],
+                )?,
+            )?,
+            last_run: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_crawler_last_run_timestamp_seconds",
+                        "Unix time the last scheduled crawl of a category finished",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            next_run: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_crawler_next_run_timestamp_seconds",
+                        "Unix time the next scheduled crawl of a category is due",
+                    ),
+                    &["instance", "category"],
+                )?,
+            )?,
+            fetch_retries: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_fetch_retries_total",
+                        "Total retried requests per host and reason (HTTP status, timeout, connect)",
+                    ),
+                    &["host", "reason"],
+                )?,
+            )?,
+            circuit_state: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_crawler_circuit_state",
+                        "Circuit breaker state of a host (0 = closed, 1 = half-open, 2 = open)",
+                    ),
+                    &["host"],
+                )?,
+            )?,
+            circuit_opened: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_circuit_opened_total",
+                        "Total times the circuit breaker of a host opened",
+                    ),
+                    &["host"],
+                )?,
+            )?,
+            not_modified: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_crawler_not_modified_total",
+                        "Total conditional requests answered 304 Not Modified per host",
+                    ),
+                    &["host"],
+                )?,
+            )?,
+        };
+
+        // Register latency metrics
+        let latency = LatencyMetrics {
+            parse_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        LATENCY_METRIC_NAMES[0],
+                        "Time spent parsing an article page in seconds",
+                    )
+                    .buckets(vec![
+                        0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+                    ]),
+                    &["result"],
+                )?,
+            )?,
+            embedding_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        LATENCY_METRIC_NAMES[1],
+                        "Embedding server request latency observed by the client in seconds",
+                    )
+                    .buckets(vec![
+                        0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+                    ]),
+                    &["operation", "result"],
+                )?,
+            )?,
+            opensearch_bulk_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(
+                        LATENCY_METRIC_NAMES[2],
+                        "OpenSearch bulk request latency in seconds",
+                    )
+                    .buckets(vec![0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]),
+                    &["result"],
+                )?,
+            )?,
+            llm_duration: register(
+                &registry,
+                HistogramVec::new(
+                    HistogramOpts::new(LATENCY_METRIC_NAMES[3], "LLM request latency in seconds")
+                        .buckets(vec![
+                            0.5, 1.0, 2.5, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0, 300.0,
+                        ]),
+                    &["backend", "result"],
+                )?,
+            )?,
+        };
+
+        // Register process and runtime metrics
+        let runtime = RuntimeMetrics {
+            resident_memory: register(
+                &registry,
+                Gauge::new(
+                    "baram_process_resident_memory_bytes",
+                    "Resident memory size of the process in bytes",
+                )?,
+            )?,
+            open_fds: register(
+                &registry,
+                Gauge::new(
+                    "baram_process_open_fds",
+                    "Number of open file descriptors of the process",
+                )?,
+            )?,
+            tokio_workers: register(
+                &registry,
+                Gauge::new(
+                    "baram_tokio_workers",
+                    "Number of tokio runtime worker threads",
+                )?,
+            )?,
+            tokio_alive_tasks: register(
+                &registry,
+                Gauge::new(
+                    "baram_tokio_alive_tasks",
+                    "Number of alive tasks in the tokio runtime",
+                )?,
+            )?,
+            tokio_global_queue_depth: register(
+                &registry,
+                Gauge::new(
+                    "baram_tokio_global_queue_depth",
+                    "Number of tasks waiting in the tokio global queue",
+                )?,
+            )?,
+            rate_limiter_last_wait: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_rate_limiter_last_wait_seconds",
+                        "Time the last request waited for the rate limiter in seconds",
+                    ),
+                    &["limiter"],
+                )?,
+            )?,
+            rate_limiter_wait_total: register(
+                &registry,
+                CounterVec::new(
+                    Opts::new(
+                        "baram_rate_limiter_wait_seconds_total",
+                        "Total time requests waited for the rate limiter in seconds",
+                    ),
+                    &["limiter"],
+                )?,
+            )?,
+            dependency_up: register(
+                &registry,
+                GaugeVec::new(
+                    Opts::new(
+                        "baram_dependency_up",
+                        "Whether a monitored dependency answered its last probe (1 = up, 0 = down)",
+                    ),
+                    &["dependency"],
+                )?,
+            )?,
+        };
+
+        Ok(Self {
+            registry,
+            coordinator,
+            crawler,
+            latency,
+            runtime,
+        })
+    }
+}
+
+impl Metrics {
+    /// Register all metrics in a new, empty registry
+    pub fn new() -> prometheus::Result<Self> {
+        Self::with_registry(Registry::new())
+    }
+
+    /// Register all metrics in `registry`
+    ///
+    /// Fails if `registry` already holds metrics with the same names.
+    pub fn with_registry(registry: Registry) -> prometheus::Result<Self> {
+        Ok(Self {
+            inner: Some(Arc::new(MetricsInner::new(registry)?)),
+        })
+    }
+
+    /// A handle that records nothing
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// The global default metrics, or a disabled handle before [`init_metrics`]
+    #[must_use]
+    pub fn global() -> Self {
+        GLOBAL_METRICS.get().cloned().unwrap_or_default()
+    }
+
+    /// Whether this handle records metrics
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Registry holding the metrics, `None` for a disabled handle
+    #[must_use]
+    pub fn registry(&self) -> Option<&Registry> {
+        self.inner.as_ref().map(|inner| &inner.registry)
+    }
+
+    /// Gather all metric families, refreshing the runtime gauges first
+    pub fn gather(&self) -> Vec<MetricFamily> {
+        self.update_runtime_metrics();
+        self.registry().map(Registry::gather).unwrap_or_default()
+    }
+
+    /// Encode all metrics to Prometheus text format
+    pub fn encode(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Respond with all metrics in the Prometheus text format
+    pub fn http_response(&self) -> Response {
+        match self.encode() {
+            Ok(body) => (
+                StatusCode::OK,
+                [("Content-Type", "text/plain; version=0.0.4")],
+                body,
+            )
+                .into_response(),
+            Err(e) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [("Content-Type", "text/plain; version=0.0.4")],
+                format!("Error encoding metrics: {e}"),
+            )
+                .into_response(),
+        }
+    }
+
+    fn coordinator(&self) -> Option<&CoordinatorMetrics> {
+        self.inner.as_ref().map(|inner| &inner.coordinator)
+    }
+
+    fn crawler(&self) -> Option<&CrawlerMetrics> {
+        self.inner.as_ref().map(|inner| &inner.crawler)
+    }
+
+    fn latency(&self) -> Option<&LatencyMetrics> {
+        self.inner.as_ref().map(|inner| &inner.latency)
+    }
+
+    fn runtime(&self) -> Option<&RuntimeMetrics> {
+        self.inner.as_ref().map(|inner| &inner.runtime)
+    }
+
+    /// Update coordinator instance metrics
+    pub fn update_coordinator_instance_metrics(&self, registered: usize, online: usize) {
+        if let Some(m) = self.coordinator() {
+            m.registered_instances.set(registered as f64);
+            m.online_instances.set(online as f64);
+        }
+    }
+
+    /// Record a heartbeat
+    pub fn record_heartbeat(&self, instance: &str, articles: u64, errors: u64) {
+        let Some(m) = self.coordinator() else {
+            return;
+        };
+
+        m.total_heartbeats.inc();
+
+        if articles > 0 {
+            m.articles_crawled
+                .with_label_values(&[instance])
+                .inc_by(articles as f64);
+        }
+
+        if errors > 0 {
+            m.errors
+                .with_label_values(&[instance])
+                .inc_by(errors as f64);
+        }
+    }
+
+    /// Record a heartbeat error
+    pub fn record_heartbeat_error(&self) {
+        if let Some(m) = self.coordinator() {
+            m.heartbeat_errors.inc();
+        }
+    }
+
+    /// Record API request
+    pub fn record_api_request(&self, endpoint: &str, status: u16, duration_secs: f64) {
+        let Some(m) = self.coordinator() else {
+            return;
+        };
+
+        let status_str = status.to_string();
+        m.api_requests
+            .with_label_values(&[endpoint, &status_str])
+            .inc();
+        m.api_duration
+            .with_label_values(&[endpoint])
+            .observe(duration_secs);
+    }
+
+    /// Start a crawl timer (returns a timer handle)
+    pub fn start_crawl_timer(&self, instance: &str, category: &str) -> MetricsTimer {
+        match self.crawler() {
+            Some(m) => MetricsTimer::new(
+                m.crawl_duration
+                    .with_label_values(&[instance, category])
+                    .start_timer(),
+            ),
+            None => MetricsTimer::noop(),
+        }
+    }
+
+    /// Record articles crawled for a category
+    pub fn record_articles_crawled(&self, instance: &str, category: &str, count: u64) {
+        if let Some(m) = self.crawler() {
+            m.articles_per_category
+                .with_label_values(&[instance, category])
+                .inc_by(count as f64);
+        }
+    }
+
+    /// Record deduplication results
+    pub fn record_dedup_results(&self, instance: &str, new_urls: usize, existing_urls: usize) {
+        let Some(m) = self.crawler() else {
+            return;
+        };
+
+        if new_urls > 0 {
+            m.dedup_misses
+                .with_label_values(&[instance])
+                .inc_by(new_urls as f64);
+        }
+        if existing_urls > 0 {
+            m.dedup_hits
+                .with_label_values(&[instance])
+                .inc_by(existing_urls as f64);
+        }
+    }
+
+    /// Record pipeline execution results
+    pub fn record_pipeline_results(
+        &self,
+        instance: &str,
+        category: &str,
+        success: u64,
+        failed: u64,
+        skipped: u64,
+    ) {
+        let Some(m) = self.crawler() else {
+            return;
+        };
+
+        if success > 0 {
+            m.pipeline_success
+                .with_label_values(&[instance, category])
+                .inc_by(success as f64);
+        }
+        if failed > 0 {
+            m.pipeline_failure
+                .with_label_values(&[instance, category])
+                .inc_by(failed as f64);
+        }
+        if skipped > 0 {
+            m.pipeline_skipped
+                .with_label_values(&[instance, category])
+                .inc_by(skipped as f64);
+        }
+    }
+
+    /// Record slot execution
+    pub fn record_slot_execution(&self, instance: &str, hour: u8, had_errors: bool) {
+        let Some(m) = self.crawler() else {
+            return;
+        };
+
+        let hour_str = hour.to_string();
+        m.slot_executions
+            .with_label_values(&[instance, &hour_str])
+            .inc();
+
+        if had_errors {
+            m.slot_errors
+                .with_label_values(&[instance, &hour_str])
+                .inc();
+        }
+    }
+
+    /// Update crawler state
+    pub fn update_crawler_state(
+        &self,
+        instance: &str,
+        is_crawling: bool,
+        current_hour: Option<u8>,
+    ) {
+        let Some(m) = self.crawler() else {
+            return;
+        };
+
+        m.is_crawling
+            .with_label_values(&[instance])
+            .set(if is_crawling { 1.0 } else { 0.0 });
+
+        if let Some(hour) = current_hour {
+            m.current_hour
+                .with_label_values(&[instance])
+                .set(hour as f64);
+        }
+    }
+
+    /// Update the last and next run times of a scheduled category crawl
+    pub fn update_schedule(
+        &self,
+        instance: &str,
+        category: &str,
+        last_run: Option<i64>,
+        next_run: Option<i64>,
+    ) {
+        let Some(m) = self.crawler() else {
+            return;
+        };
+
+        if let Some(last_run) = last_run {
+            m.last_run
+                .with_label_values(&[instance, category])
+                .set(last_run as f64);
+        }
+        if let Some(next_run) = next_run {
+            m.next_run
+                .with_label_values(&[instance, category])
+                .set(next_run as f64);
+        }
+    }
+
+    /// Record an article crawled successfully from a publisher
+    pub fn record_publisher_success(&self, instance: &str, publisher: &str) {
+        if let Some(m) = self.crawler() {
+            m.publisher_articles
+                .with_label_values(&[instance, publisher_label(publisher)])
+                .inc();
+        }
+    }
+
+    /// Record a crawl error for a publisher
+    pub fn record_crawl_error(&self, instance: &str, publisher: &str, class: ErrorClass) {
+        if let Some(m) = self.crawler() {
+            m.publisher_errors
+                .with_label_values(&[instance, publisher_label(publisher), class.as_str()])
+                .inc();
+        }
+    }
+
+    /// Record a retried request to a host
+    pub fn record_fetch_retry(&self, host: &str, reason: &str) {
+        if let Some(m) = self.crawler() {
+            m.fetch_retries.with_label_values(&[host, reason]).inc();
+        }
+    }
+
+    /// Set the circuit breaker state of a host (0 = closed, 1 = half-open,
+    /// 2 = open)
+    pub fn update_circuit_state(&self, host: &str, state: f64) {
+        if let Some(m) = self.crawler() {
+            m.circuit_state.with_label_values(&[host]).set(state);
+        }
+    }
+
+    /// Record that the circuit breaker of a host opened
+    pub fn record_circuit_opened(&self, host: &str) {
+        if let Some(m) = self.crawler() {
+            m.circuit_opened.with_label_values(&[host]).inc();
+        }
+    }
+
+    /// Record a page of a host that was not modified since it was last fetched
+    pub fn record_not_modified(&self, host: &str) {
+        if let Some(m) = self.crawler() {
+            m.not_modified.with_label_values(&[host]).inc();
+        }
+    }
+
+    /// Record the time spent parsing an article page
+    pub fn record_parse_duration(&self, duration_secs: f64, success: bool) {
+        if let Some(m) = self.latency() {
+            m.parse_duration
+                .with_label_values(&[result_label(success)])
+                .observe(duration_secs);
+        }
+    }
+
+    /// Record an embedding server request (`operation` is e.g. `batch` or `query`)
+    pub fn record_embedding_latency(&self, operation: &str, duration_secs: f64, success: bool) {
+        if let Some(m) = self.latency() {
+            m.embedding_duration
+                .with_label_values(&[operation, result_label(success)])
+                .observe(duration_secs);
+        }
+    }
+
+    /// Record an OpenSearch bulk request
+    pub fn record_opensearch_bulk_latency(&self, duration_secs: f64, success: bool) {
+        if let Some(m) = self.latency() {
+            m.opensearch_bulk_duration
+                .with_label_values(&[result_label(success)])
+                .observe(duration_secs);
+        }
+    }
+
+    /// Record an LLM request (one attempt, retries are recorded separately)
+    pub fn record_llm_latency(&self, backend: &str, duration_secs: f64, success: bool) {
+        if let Some(m) = self.latency() {
+            m.llm_duration
+                .with_label_values(&[backend, result_label(success)])
+                .observe(duration_secs);
+        }
+    }
+
+    /// Record the time a request waited for a rate limiter
+    pub fn record_rate_limiter_wait(&self, limiter: &str, wait_secs: f64) {
+        if let Some(m) = self.runtime() {
+            m.rate_limiter_last_wait
+                .with_label_values(&[limiter])
+                .set(wait_secs);
+            m.rate_limiter_wait_total
+                .with_label_values(&[limiter])
+                .inc_by(wait_secs);
+        }
+    }
+
+    /// Record the probed availability of a dependency
+    pub fn update_dependency_up(&self, dependency: &str, up: bool) {
+        if let Some(m) = self.runtime() {
+            m.dependency_up
+                .with_label_values(&[dependency])
+                .set(if up { 1.0 } else { 0.0 });
+        }
+    }
+
+    /// Refresh the process and tokio runtime gauges
+    ///
+    /// Called before every scrape is encoded. Tokio gauges are only updated when
+    /// called from within a runtime.
+    pub fn update_runtime_metrics(&self) {
+        let Some(m) = self.runtime() else {
+            return;
+        };
+
+        if let Some(rss) = process::resident_memory_bytes() {
+            m.resident_memory.set(rss as f64);
+        }
+        if let Some(fds) = process::open_fds() {
+            m.open_fds.set(fds as f64);
+        }
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let runtime = handle.metrics();
+            m.tokio_workers.set(runtime.num_workers() as f64);
+            m.tokio_alive_tasks.set(runtime.num_alive_tasks() as f64);
+            m.tokio_global_queue_depth
+                .set(runtime.global_queue_depth() as f64);
+        }
+    }
+}
+
+/// Initialize the global default metrics
 ///
-/// This function should be called once at application startup.
-/// If metric registration fails, errors are logged and subsequent
-/// metric operations become no-ops.
+/// This function should be called once at application startup; later calls
+/// are no-ops. If registration fails, the free recording functions stay
+/// no-ops.
 ///
 /// # Returns
 ///
@@ -80,192 +990,60 @@ static METRICS_INIT_ATTEMPTED: OnceLock<bool> = OnceLock::new();
 /// }
 /// ```
 pub fn init_metrics() -> Result<(), Box<dyn std::error::Error>> {
-    // Prevent double initialization
-    if METRICS_INIT_ATTEMPTED.get().is_some() {
+    if GLOBAL_METRICS.get().is_some() {
         return Ok(());
     }
-    METRICS_INIT_ATTEMPTED.set(true).ok();
-
-    // Register coordinator metrics
-    let coordinator = CoordinatorMetrics {
-        registered_instances: register_gauge!(
-            "baram_coordinator_registered_instances",
-            "Number of registered crawler instances"
-        )?,
-        online_instances: register_gauge!(
-            "baram_coordinator_online_instances",
-            "Number of currently online crawler instances"
-        )?,
-        total_heartbeats: register_counter!(
-            "baram_coordinator_total_heartbeats",
-            "Total number of heartbeats received"
-        )?,
-        heartbeat_errors: register_counter!(
-            "baram_coordinator_heartbeat_errors_total",
-            "Total number of heartbeat errors"
-        )?,
-        articles_crawled: register_counter_vec!(
-            "baram_coordinator_articles_crawled_total",
-            "Total articles crawled by instance",
-            &["instance"]
-        )?,
-        errors: register_counter_vec!(
-            "baram_coordinator_errors_total",
-            "Total errors reported by instance",
-            &["instance"]
-        )?,
-        api_requests: register_counter_vec!(
-            "baram_coordinator_api_requests_total",
-            "Total API requests by endpoint and status",
-            &["endpoint", "status"]
-        )?,
-        api_duration: register_histogram_vec!(
-            "baram_coordinator_api_request_duration_seconds",
-            "API request duration in seconds",
-            &["endpoint"],
-            vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
-        )?,
-    };
-
-    // Register crawler metrics
-    let crawler = CrawlerMetrics {
-        crawl_duration: register_histogram_vec!(
-            "baram_crawler_crawl_duration_seconds",
-            "Time spent crawling a category in seconds",
-            &["instance", "category"],
-            vec![1.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0]
-        )?,
-        articles_per_category: register_counter_vec!(
-            "baram_crawler_articles_per_category_total",
-            "Total articles crawled per category",
-            &["instance", "category"]
-        )?,
-        dedup_hits: register_counter_vec!(
-            "baram_crawler_dedup_hits_total",
-            "Total deduplication cache hits (URLs already crawled)",
-            &["instance"]
-        )?,
-        dedup_misses: register_counter_vec!(
-            "baram_crawler_dedup_misses_total",
-            "Total deduplication cache misses (new URLs)",
-            &["instance"]
-        )?,
-        pipeline_success: register_counter_vec!(
-            "baram_crawler_pipeline_success_total",
-            "Total successful pipeline executions",
-            &["instance", "category"]
-        )?,
-        pipeline_failure: register_counter_vec!(
-            "baram_crawler_pipeline_failure_total",
-            "Total failed pipeline executions",
-            &["instance", "category"]
-        )?,
-        pipeline_skipped: register_counter_vec!(
-            "baram_crawler_pipeline_skipped_total",
-            "Total skipped articles in pipeline",
-            &["instance", "category"]
-        )?,
-        slot_executions: register_counter_vec!(
-            "baram_crawler_slot_executions_total",
-            "Total slot executions",
-            &["instance", "hour"]
-        )?,
-        slot_errors: register_counter_vec!(
-            "baram_crawler_slot_errors_total",
-            "Total slot execution errors",
-            &["instance", "hour"]
-        )?,
-        current_hour: register_gauge_vec!(
-            "baram_crawler_current_hour",
-            "Current hour being crawled (0-23)",
-            &["instance"]
-        )?,
-        is_crawling: register_gauge_vec!(
-            "baram_crawler_is_crawling",
-            "Whether the crawler is currently crawling (1 = yes, 0 = no)",
-            &["instance"]
-        )?,
-    };
-
-    // Store metrics - these should always succeed since we just created them
-    COORDINATOR_METRICS
-        .set(coordinator)
-        .map_err(|_| "Coordinator metrics already initialized")?;
-    CRAWLER_METRICS
-        .set(crawler)
-        .map_err(|_| "Crawler metrics already initialized")?;
-
-    tracing::info!("Prometheus metrics initialized successfully");
+
+    let metrics = Metrics::new()?;
+    if GLOBAL_METRICS.set(metrics).is_ok() {
+        tracing::info!("Prometheus metrics initialized successfully");
+    }
     Ok(())
 }
 
-/// Check if metrics have been initialized
+/// Check if the global default metrics have been initialized
 pub fn metrics_initialized() -> bool {
-    COORDINATOR_METRICS.get().is_some() && CRAWLER_METRICS.get().is_some()
+    GLOBAL_METRICS.get().is_some()
 }
 
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
-/// Encode all metrics to Prometheus text format
+/// Encode the global default metrics to Prometheus text format
+///
+/// Returns an empty string before [`init_metrics`].
 pub fn encode_metrics() -> Result<String, Box<dyn std::error::Error>> {
-    let encoder = TextEncoder::new();
-    let metric_families = prometheus::gather();
-    let mut buffer = Vec::new();
-    encoder.encode(&metric_families, &mut buffer)?;
-    Ok(String::from_utf8(buffer)?)
+    Metrics::global().encode()
 }
 
-/// Update coordinator instance metrics
-pub fn update_coordinator_instance_metrics(registered: usize, online: usize) {
-    if let Some(m) = COORDINATOR_METRICS.get() {
-        m.registered_instances.set(registered as f64);
-        m.online_instances.set(online as f64);
-    }
+/// Respond with the global default metrics in the Prometheus text format
+pub async fn metrics_response() -> impl IntoResponse {
+    Metrics::global().http_response()
 }
 
-/// Record a heartbeat
-pub fn record_heartbeat(instance: &str, articles: u64, errors: u64) {
-    let Some(m) = COORDINATOR_METRICS.get() else {
-        return;
-    };
-
-    m.total_heartbeats.inc();
-
-    if articles > 0 {
-        m.articles_crawled
-            .with_label_values(&[instance])
-            .inc_by(articles as f64);
-    }
-
-    if errors > 0 {
-        m.errors
-            .with_label_values(&[instance])
-            .inc_by(errors as f64);
-    }
+/// Router serving only `GET /metrics` from the global default metrics
+pub fn metrics_router() -> Router {
+    Router::new().route("/metrics", get(metrics_response))
 }
 
-/// Record a heartbeat error
-pub fn record_heartbeat_error() {
-    if let Some(m) = COORDINATOR_METRICS.get() {
-        m.heartbeat_errors.inc();
-    }
-}
-
-/// Record API request
-pub fn record_api_request(endpoint: &str, status: u16, duration_secs: f64) {
-    let Some(m) = COORDINATOR_METRICS.get() else {
-        return;
-    };
+/// Serve `/metrics` on `addr` from a background task
+///
+/// Binding errors are returned immediately. Returns the bound address
+/// (useful with port 0) and the task handle; abort it to stop the listener.
+pub async fn spawn_metrics_server(
+    addr: SocketAddr,
+) -> std::io::Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let local_addr = listener.local_addr()?;
+    tracing::info!(addr = %local_addr, "Metrics endpoint listening");
 
-    let status_str = status.to_string();
-    m.api_requests
-        .with_label_values(&[endpoint, &status_str])
-        .inc();
-    m.api_duration
-        .with_label_values(&[endpoint])
-        .observe(duration_secs);
+    let handle = tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, metrics_router()).await {
+            tracing::warn!(error = %e, "Metrics endpoint stopped");
+        }
+    });
+    Ok((local_addr, handle))
 }
 
 /// Histogram timer guard that records duration on drop
@@ -292,42 +1070,60 @@ impl Drop for MetricsTimer {
     }
 }
 
+// ============================================================================
+// Default Metrics
+// ============================================================================
+//
+// Free functions recording to the global default metrics, for code without a
+// `Metrics` handle. They are no-ops before `init_metrics()`.
+
+/// Update coordinator instance metrics
+pub fn update_coordinator_instance_metrics(registered: usize, online: usize) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_coordinator_instance_metrics(registered, online);
+    }
+}
+
+/// Record a heartbeat
+pub fn record_heartbeat(instance: &str, articles: u64, errors: u64) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_heartbeat(instance, articles, errors);
+    }
+}
+
+/// Record a heartbeat error
+pub fn record_heartbeat_error() {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_heartbeat_error();
+    }
+}
+
+/// Record API request
+pub fn record_api_request(endpoint: &str, status: u16, duration_secs: f64) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_api_request(endpoint, status, duration_secs);
+    }
+}
+
 /// Start a crawl timer (returns a timer handle)
 pub fn start_crawl_timer(instance: &str, category: &str) -> MetricsTimer {
-    match CRAWLER_METRICS.get() {
-        Some(m) => MetricsTimer::new(
-            m.crawl_duration
-                .with_label_values(&[instance, category])
-                .start_timer(),
-        ),
+    match GLOBAL_METRICS.get() {
+        Some(m) => m.start_crawl_timer(instance, category),
         None => MetricsTimer::noop(),
     }
 }
 
 /// Record articles crawled for a category
 pub fn record_articles_crawled(instance: &str, category: &str, count: u64) {
-    if let Some(m) = CRAWLER_METRICS.get() {
-        m.articles_per_category
-            .with_label_values(&[instance, category])
-            .inc_by(count as f64);
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_articles_crawled(instance, category, count);
     }
 }
 
 /// Record deduplication results
 pub fn record_dedup_results(instance: &str, new_urls: usize, existing_urls: usize) {
-    let Some(m) = CRAWLER_METRICS.get() else {
-        return;
-    };
-
-    if new_urls > 0 {
-        m.dedup_misses
-            .with_label_values(&[instance])
-            .inc_by(new_urls as f64);
-    }
-    if existing_urls > 0 {
-        m.dedup_hits
-            .with_label_values(&[instance])
-            .inc_by(existing_urls as f64);
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_dedup_results(instance, new_urls, existing_urls);
     }
 }
 
@@ -339,59 +1135,141 @@ pub fn record_pipeline_results(
     failed: u64,
     skipped: u64,
 ) {
-    let Some(m) = CRAWLER_METRICS.get() else {
-        return;
-    };
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_pipeline_results(instance, category, success, failed, skipped);
+    }
+}
 
-    if success > 0 {
-        m.pipeline_success
-            .with_label_values(&[instance, category])
-            .inc_by(success as f64);
+/// Record slot execution
+pub fn record_slot_execution(instance: &str, hour: u8, had_errors: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_slot_execution(instance, hour, had_errors);
     }
-    if failed > 0 {
-        m.pipeline_failure
-            .with_label_values(&[instance, category])
-            .inc_by(failed as f64);
+}
+
+/// Update crawler state
+pub fn update_crawler_state(instance: &str, is_crawling: bool, current_hour: Option<u8>) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_crawler_state(instance, is_crawling, current_hour);
     }
-    if skipped > 0 {
-        m.pipeline_skipped
-            .with_label_values(&[instance, category])
-            .inc_by(skipped as f64);
+}
+
+/// Update the last and next run times of a scheduled category crawl
+pub fn update_schedule(
+    instance: &str,
+    category: &str,
+    last_run: Option<i64>,
+    next_run: Option<i64>,
+) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_schedule(instance, category, last_run, next_run);
     }
 }
 
-/// Record slot execution
-pub fn record_slot_execution(instance: &str, hour: u8, had_errors: bool) {
-    let Some(m) = CRAWLER_METRICS.get() else {
-        return;
-    };
+/// Record an article crawled successfully from a publisher
+pub fn record_publisher_success(instance: &str, publisher: &str) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_publisher_success(instance, publisher);
+    }
+}
 
-    let hour_str = hour.to_string();
-    m.slot_executions
-        .with_label_values(&[instance, &hour_str])
-        .inc();
+/// Record a crawl error for a publisher
+pub fn record_crawl_error(instance: &str, publisher: &str, class: ErrorClass) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_crawl_error(instance, publisher, class);
+    }
+}
 
-    if had_errors {
-        m.slot_errors
-            .with_label_values(&[instance, &hour_str])
-            .inc();
+/// Record a retried request to a host
+pub fn record_fetch_retry(host: &str, reason: &str) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_fetch_retry(host, reason);
     }
 }
 
-/// Update crawler state
-pub fn update_crawler_state(instance: &str, is_crawling: bool, current_hour: Option<u8>) {
-    let Some(m) = CRAWLER_METRICS.get() else {
-        return;
-    };
+/// Set the circuit breaker state of a host
+pub fn update_circuit_state(host: &str, state: f64) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_circuit_state(host, state);
+    }
+}
 
-    m.is_crawling
-        .with_label_values(&[instance])
-        .set(if is_crawling { 1.0 } else { 0.0 });
+/// Record that the circuit breaker of a host opened
+pub fn record_circuit_opened(host: &str) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_circuit_opened(host);
+    }
+}
 
-    if let Some(hour) = current_hour {
-        m.current_hour
-            .with_label_values(&[instance])
-            .set(hour as f64);
+/// Record a page of a host that was not modified since it was last fetched
+pub fn record_not_modified(host: &str) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_not_modified(host);
+    }
+}
+
+/// Record the time spent parsing an article page
+pub fn record_parse_duration(duration_secs: f64, success: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_parse_duration(duration_secs, success);
+    }
+}
+
+/// Record an embedding server request (`operation` is e.g. `batch` or `query`)
+pub fn record_embedding_latency(operation: &str, duration_secs: f64, success: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_embedding_latency(operation, duration_secs, success);
+    }
+}
+
+/// Record an OpenSearch bulk request
+pub fn record_opensearch_bulk_latency(duration_secs: f64, success: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_opensearch_bulk_latency(duration_secs, success);
+    }
+}
+
+/// Record an LLM request (one attempt, retries are recorded separately)
+pub fn record_llm_latency(backend: &str, duration_secs: f64, success: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_llm_latency(backend, duration_secs, success);
+    }
+}
+
+/// Record the time a request waited for a rate limiter
+pub fn record_rate_limiter_wait(limiter: &str, wait_secs: f64) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.record_rate_limiter_wait(limiter, wait_secs);
+    }
+}
+
+/// Record the probed availability of a dependency
+pub fn update_dependency_up(dependency: &str, up: bool) {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_dependency_up(dependency, up);
+    }
+}
+
+/// Refresh the process and tokio runtime gauges
+pub fn update_runtime_metrics() {
+    if let Some(m) = GLOBAL_METRICS.get() {
+        m.update_runtime_metrics();
+    }
+}
+
+fn result_label(success: bool) -> &'static str {
+    if success {
+        "ok"
+    } else {
+        "error"
+    }
+}
+
+fn publisher_label(publisher: &str) -> &str {
+    if publisher.is_empty() {
+        UNKNOWN_PUBLISHER
+    } else {
+        publisher
     }
 }
 
@@ -464,9 +1342,79 @@ mod tests {
         record_pipeline_results("main", "politics", 90, 5, 5);
         record_slot_execution("main", 14, false);
         update_crawler_state("main", true, Some(14));
+        update_schedule(
+            "watch",
+            "politics",
+            Some(1_772_000_000),
+            Some(1_772_001_800),
+        );
+        update_schedule("watch", "economy", None, None);
         // Verify it doesn't panic
     }
 
+    #[test]
+    fn test_publisher_metrics() {
+        ensure_metrics_initialized();
+        record_publisher_success("main", "001");
+        record_crawl_error("main", "023", ErrorClass::Blocked);
+        record_crawl_error("main", "", ErrorClass::Timeout);
+
+        let text = encode_metrics().unwrap();
+        assert!(text.contains(
+            "baram_crawler_publisher_errors_total{class=\"blocked\",instance=\"main\",publisher=\"023\"}"
+        ));
+        assert!(text.contains(
+            "baram_crawler_publisher_errors_total{class=\"timeout\",instance=\"main\",publisher=\"unknown\"}"
+        ));
+        assert!(text.contains("baram_crawler_publisher_articles_total"));
+    }
+
+    #[test]
+    fn test_fetch_resilience_metrics() {
+        ensure_metrics_initialized();
+        record_fetch_retry("n.news.naver.com", "503");
+        record_circuit_opened("n.news.naver.com");
+        record_not_modified("n.news.naver.com");
+        update_circuit_state("n.news.naver.com", 2.0);
+
+        let text = encode_metrics().unwrap();
+        assert!(text.contains(
+            "baram_crawler_fetch_retries_total{host=\"n.news.naver.com\",reason=\"503\"}"
+        ));
+        assert!(text.contains("baram_crawler_circuit_opened_total{host=\"n.news.naver.com\"}"));
+        assert!(text.contains("baram_crawler_not_modified_total{host=\"n.news.naver.com\"}"));
+        assert!(text.contains("baram_crawler_circuit_state{host=\"n.news.naver.com\"} 2"));
+    }
+
+    #[test]
+    fn test_latency_metrics() {
+        ensure_metrics_initialized();
+        record_parse_duration(0.004, true);
+        record_embedding_latency("batch", 0.3, true);
+        record_opensearch_bulk_latency(1.2, false);
+        record_llm_latency("vllm", 4.0, true);
+
+        let text = encode_metrics().unwrap();
+        assert!(text.contains("baram_parse_duration_seconds_bucket"));
+        assert!(text.contains(
+            "baram_embedding_request_duration_seconds_count{operation=\"batch\",result=\"ok\"}"
+        ));
+        assert!(text.contains("baram_opensearch_bulk_duration_seconds_count{result=\"error\"}"));
+        assert!(text.contains("baram_llm_request_duration_seconds_count{backend=\"vllm\""));
+    }
+
+    #[tokio::test]
+    async fn test_runtime_metrics() {
+        ensure_metrics_initialized();
+        record_rate_limiter_wait("fetcher", 0.2);
+
+        let text = encode_metrics().unwrap();
+        assert!(text.contains("baram_tokio_alive_tasks"));
+        assert!(text.contains("baram_rate_limiter_wait_seconds_total{limiter=\"fetcher\"}"));
+        #[cfg(target_os = "linux")]
+        assert!(text.contains("baram_process_resident_memory_bytes"));
+    }
+
     #[test]
     fn test_crawl_timer() {
         ensure_metrics_initialized();
@@ -474,6 +1422,58 @@ mod tests {
         // Timer should record duration when dropped
     }
 
+    #[tokio::test]
+    async fn test_spawn_metrics_server() {
+        ensure_metrics_initialized();
+        record_articles_crawled("standalone", "economy", 3);
+
+        let (addr, handle) = spawn_metrics_server("127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let response = reqwest::get(format!("http://{addr}/metrics"))
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let body = response.text().await.unwrap();
+        assert!(body.contains("baram_crawler_articles_per_category_total"));
+
+        let missing = reqwest::get(format!("http://{addr}/api/health"))
+            .await
+            .unwrap();
+        assert_eq!(missing.status(), reqwest::StatusCode::NOT_FOUND);
+        handle.abort();
+    }
+
+    #[test]
+    fn test_isolated_metrics() {
+        let first = Metrics::new().unwrap();
+        let second = Metrics::new().unwrap();
+        first.record_articles_crawled("main", "politics", 7);
+
+        assert!(first.encode().unwrap().contains(
+            "baram_crawler_articles_per_category_total{category=\"politics\",instance=\"main\"} 7"
+        ));
+        assert!(!second
+            .encode()
+            .unwrap()
+            .contains("baram_crawler_articles_per_category_total{"));
+
+        // A registry can only hold one set of baram metrics
+        let registry = first.registry().unwrap().clone();
+        assert!(Metrics::with_registry(registry).is_err());
+    }
+
+    #[test]
+    fn test_disabled_metrics() {
+        let metrics = Metrics::disabled();
+        metrics.record_heartbeat("main", 1, 0);
+        let _timer = metrics.start_crawl_timer("main", "politics");
+
+        assert!(!metrics.is_enabled());
+        assert!(metrics.registry().is_none());
+        assert_eq!(metrics.encode().unwrap(), "");
+    }
+
     #[test]
     fn test_metrics_noop_without_init() {
         // These should not panic even if called before initialization
@@ -486,6 +1486,14 @@ mod tests {
         record_pipeline_results("test", "test", 1, 0, 0);
         record_slot_execution("test", 0, false);
         update_crawler_state("test", false, None);
+        record_publisher_success("test", "001");
+        record_crawl_error("test", "001", ErrorClass::ParseFailure);
+        record_parse_duration(0.01, false);
+        record_embedding_latency("query", 0.1, true);
+        record_opensearch_bulk_latency(0.5, true);
+        record_llm_latency("ollama", 2.0, false);
+        record_rate_limiter_wait("test", 0.1);
+        update_runtime_metrics();
         let _timer = start_crawl_timer("test", "test");
     }
 }
@@ -0,0 +1,45 @@
+//! Process resource readings for the runtime gauges
+//!
+//! Values are read from `/proc/self` when a scrape is encoded, so they are
+//! only available on Linux; elsewhere the readers return `None` and the
+//! gauges are left unset.
+
+use std::path::Path;
+
+/// Resident set size of this process in bytes
+pub fn resident_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    parse_vm_rss(&status)
+}
+
+/// Number of open file descriptors of this process
+pub fn open_fds() -> Option<u64> {
+    let entries = std::fs::read_dir(Path::new("/proc/self/fd")).ok()?;
+    Some(entries.filter(|entry| entry.is_ok()).count() as u64)
+}
+
+/// Parse the `VmRSS` line of `/proc/self/status` (reported in kB)
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tbaram\nVmPeak:\t  204800 kB\nVmRSS:\t   51200 kB\nThreads:\t8\n";
+        assert_eq!(parse_vm_rss(status), Some(51200 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tbaram\n"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_process_readings() {
+        assert!(resident_memory_bytes().unwrap() > 0);
+        assert!(open_fds().unwrap() > 0);
+    }
+}
@@ -0,0 +1,238 @@
+//! Prometheus Pushgateway support for batch jobs
+//!
+//! Batch commands (`index`, `ontology`, `report`) usually exit before
+//! Prometheus scrapes them, so their final metrics are pushed to a
+//! Pushgateway instead. Each job pushes its own group
+//! (`/metrics/job/<job>[/instance/<instance>]`), replacing the previous run:
+//! - `baram_job_duration_seconds` - wall-clock duration of the run
+//! - `baram_job_success` - 1 if the run completed, 0 if it failed
+//! - `baram_job_last_completion_timestamp_seconds` - when the run ended
+//! - `baram_job_items{kind}` - job-specific counts (e.g. indexed, failed)
+//! - the latency histograms recorded during the run (see
+//!   [`LATENCY_METRIC_NAMES`]), once [`init_if_configured`] has registered them
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::metrics::push::{push_job_metrics, JobMetrics};
+//!
+//! # async fn example() -> Result<(), String> {
+//! let mut job = JobMetrics::new("index");
+//! job.set("indexed", 120);
+//! job.set("failed", 2);
+//! push_job_metrics("http://localhost:9091", None, &job, true).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use prometheus::{Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::{Metrics, LATENCY_METRIC_NAMES};
+use crate::config::MetricsConfig;
+
+/// Timeout for a push request
+const PUSH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Final metrics of one batch job run
+#[derive(Debug, Clone)]
+pub struct JobMetrics {
+    job: String,
+    started: Instant,
+    counts: BTreeMap<String, u64>,
+}
+
+impl JobMetrics {
+    /// Start timing a job run
+    #[must_use]
+    pub fn new(job: &str) -> Self {
+        Self {
+            job: job.to_string(),
+            started: Instant::now(),
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Job name (the Pushgateway `job` grouping label)
+    #[must_use]
+    pub fn job(&self) -> &str {
+        &self.job
+    }
+
+    /// Set a count, e.g. `set("indexed", 120)`
+    pub fn set(&mut self, kind: &str, value: u64) {
+        self.counts.insert(kind.to_string(), value);
+    }
+
+    /// Recorded counts by kind
+    #[must_use]
+    pub fn counts(&self) -> &BTreeMap<String, u64> {
+        &self.counts
+    }
+
+    /// Encode the run in the Prometheus text format
+    pub fn encode(&self, success: bool) -> Result<String, prometheus::Error> {
+        let registry = Registry::new();
+
+        let duration = Gauge::with_opts(Opts::new(
+            "baram_job_duration_seconds",
+            "Duration of the last batch job run in seconds",
+        ))?;
+        duration.set(self.started.elapsed().as_secs_f64());
+        registry.register(Box::new(duration))?;
+
+        let succeeded = Gauge::with_opts(Opts::new(
+            "baram_job_success",
+            "Whether the last batch job run completed (1 = yes, 0 = no)",
+        ))?;
+        succeeded.set(if success { 1.0 } else { 0.0 });
+        registry.register(Box::new(succeeded))?;
+
+        let completed = Gauge::with_opts(Opts::new(
+            "baram_job_last_completion_timestamp_seconds",
+            "Unix time the last batch job run ended",
+        ))?;
+        completed.set(chrono::Utc::now().timestamp() as f64);
+        registry.register(Box::new(completed))?;
+
+        if !self.counts.is_empty() {
+            let items = GaugeVec::new(
+                Opts::new("baram_job_items", "Items handled by the last batch job run"),
+                &["kind"],
+            )?;
+            for (kind, value) in &self.counts {
+                items.with_label_values(&[kind]).set(*value as f64);
+            }
+            registry.register(Box::new(items))?;
+        }
+
+        let mut families = registry.gather();
+        families.extend(
+            Metrics::global()
+                .gather()
+                .into_iter()
+                .filter(|family| LATENCY_METRIC_NAMES.contains(&family.name())),
+        );
+
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&families, &mut buffer)?;
+        String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+}
+
+/// Pushgateway URL of a job's grouping key
+pub fn push_url(base_url: &str, job: &str, instance: Option<&str>) -> Result<String, String> {
+    let mut url = url::Url::parse(base_url)
+        .map_err(|e| format!("Invalid Pushgateway URL {base_url}: {e}"))?;
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|_| format!("Invalid Pushgateway URL: {base_url}"))?;
+        segments.pop_if_empty().extend(["metrics", "job", job]);
+        if let Some(instance) = instance {
+            segments.extend(["instance", instance]);
+        }
+    }
+    Ok(url.to_string())
+}
+
+/// Push a job run to the Pushgateway, replacing the job's previous metrics
+pub async fn push_job_metrics(
+    base_url: &str,
+    instance: Option<&str>,
+    metrics: &JobMetrics,
+    success: bool,
+) -> Result<(), String> {
+    let body = metrics
+        .encode(success)
+        .map_err(|e| format!("Failed to encode job metrics: {e}"))?;
+    let url = push_url(base_url, metrics.job(), instance)?;
+
+    let response = reqwest::Client::new()
+        .put(&url)
+        .timeout(PUSH_TIMEOUT)
+        .header("Content-Type", "text/plain; version=0.0.4")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to push metrics to {url}: {e}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Pushgateway rejected metrics ({}): {}",
+            response.status(),
+            response.text().await.unwrap_or_default()
+        ));
+    }
+    Ok(())
+}
+
+/// Register the process metrics if a Pushgateway is configured
+///
+/// Call before the job runs so latency histograms recorded during the run
+/// are pushed with it.
+pub fn init_if_configured(config: &MetricsConfig) {
+    if config.pushgateway_url.is_some() {
+        if let Err(e) = super::init_metrics() {
+            tracing::warn!(error = %e, "Failed to initialize metrics");
+        }
+    }
+}
+
+/// Push a job run if a Pushgateway is configured
+///
+/// Failures are logged rather than returned, so an unreachable Pushgateway
+/// never fails the job itself.
+pub async fn push_if_configured(config: &MetricsConfig, metrics: &JobMetrics, success: bool) {
+    let Some(url) = config.pushgateway_url.as_deref() else {
+        return;
+    };
+
+    match push_job_metrics(url, config.instance.as_deref(), metrics, success).await {
+        Ok(()) => tracing::info!(job = metrics.job(), "Pushed job metrics"),
+        Err(e) => tracing::warn!(job = metrics.job(), error = %e, "Failed to push job metrics"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_job_metrics() {
+        let mut job = JobMetrics::new("index");
+        job.set("indexed", 120);
+        job.set("failed", 2);
+
+        let text = job.encode(true).unwrap();
+        assert!(text.contains("baram_job_success 1"));
+        assert!(text.contains("baram_job_items{kind=\"indexed\"} 120"));
+        assert!(text.contains("baram_job_items{kind=\"failed\"} 2"));
+        assert!(text.contains("baram_job_duration_seconds"));
+
+        let _ = crate::metrics::init_metrics();
+        crate::metrics::record_llm_latency("vllm", 3.0, true);
+        crate::metrics::record_articles_crawled("main", "politics", 1);
+        let text = job.encode(true).unwrap();
+        assert!(text.contains("baram_llm_request_duration_seconds_count"));
+        assert!(!text.contains("baram_crawler_"));
+
+        let failed = JobMetrics::new("report").encode(false).unwrap();
+        assert!(failed.contains("baram_job_success 0"));
+        assert!(!failed.contains("baram_job_items"));
+    }
+
+    #[test]
+    fn test_push_url() {
+        assert_eq!(
+            push_url("http://pushgateway:9091/", "index", None).unwrap(),
+            "http://pushgateway:9091/metrics/job/index"
+        );
+        assert_eq!(
+            push_url("http://pushgateway:9091", "report", Some("batch host")).unwrap(),
+            "http://pushgateway:9091/metrics/job/report/instance/batch%20host"
+        );
+        assert!(push_url("pushgateway:9091", "index", None).is_err());
+    }
+}
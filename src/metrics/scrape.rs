@@ -0,0 +1,177 @@
+//! Reading metrics back from a `/metrics` endpoint
+//!
+//! `baram tui` shows the live gauges and counters of a running crawler or
+//! coordinator by scraping its endpoint and parsing the Prometheus text
+//! format. Only sample lines are parsed; `# HELP` and `# TYPE` comments are
+//! skipped.
+//!
+//! ```
+//! use baram::metrics::scrape::parse_text;
+//!
+//! let samples = parse_text("baram_crawler_is_crawling{instance=\"main\"} 1\n");
+//! assert_eq!(samples[0].name, "baram_crawler_is_crawling");
+//! assert_eq!(samples[0].label("instance"), Some("main"));
+//! assert_eq!(samples[0].value, 1.0);
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Timeout for a scrape request
+const SCRAPE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One sample line of the text exposition format
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrapedSample {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+}
+
+impl ScrapedSample {
+    /// Value of a label
+    #[must_use]
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels.get(name).map(String::as_str)
+    }
+}
+
+/// Parse Prometheus text exposition format, skipping lines it cannot read
+#[must_use]
+pub fn parse_text(text: &str) -> Vec<ScrapedSample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<ScrapedSample> {
+    let (name, labels, rest) = match line.find('{') {
+        Some(open) => {
+            let close = line.rfind('}')?;
+            (
+                &line[..open],
+                parse_labels(&line[open + 1..close])?,
+                &line[close + 1..],
+            )
+        }
+        None => {
+            let (name, rest) = line.split_once(char::is_whitespace)?;
+            (name, BTreeMap::new(), rest)
+        }
+    };
+    // An optional timestamp may follow the value
+    let value = rest.split_whitespace().next()?;
+    let value = match value {
+        "+Inf" => f64::INFINITY,
+        "-Inf" => f64::NEG_INFINITY,
+        v => v.parse().ok()?,
+    };
+    Some(ScrapedSample {
+        name: name.trim().to_string(),
+        labels,
+        value,
+    })
+}
+
+/// Parse `a="x",b="y"`, unescaping `\"`, `\\` and `\n` in values
+fn parse_labels(text: &str) -> Option<BTreeMap<String, String>> {
+    let mut labels = BTreeMap::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.peek().is_some_and(|c| *c == ',' || c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            return Some(labels);
+        }
+
+        let name: String = chars.by_ref().take_while(|c| *c != '=').collect();
+        if chars.next()? != '"' {
+            return None;
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    'n' => value.push('\n'),
+                    c => value.push(c),
+                },
+                c => value.push(c),
+            }
+        }
+        labels.insert(name.trim().to_string(), value);
+    }
+}
+
+/// Sum of all samples of a metric whose labels include `filter`
+#[must_use]
+pub fn sum(samples: &[ScrapedSample], name: &str, filter: &[(&str, &str)]) -> Option<f64> {
+    let mut matching = samples
+        .iter()
+        .filter(|s| s.name == name)
+        .filter(|s| filter.iter().all(|(k, v)| s.label(k) == Some(*v)))
+        .peekable();
+    matching.peek()?;
+    Some(matching.map(|s| s.value).sum())
+}
+
+/// Fetch and parse a `/metrics` endpoint
+pub async fn scrape(client: &reqwest::Client, url: &str) -> Result<Vec<ScrapedSample>, String> {
+    let response = client
+        .get(url)
+        .timeout(SCRAPE_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("failed to read response: {e}"))?;
+    Ok(parse_text(&body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_text() {
+        let text = r#"
+# HELP baram_tokio_global_queue_depth Number of tasks waiting in the tokio global queue
+# TYPE baram_tokio_global_queue_depth gauge
+baram_tokio_global_queue_depth 7
+baram_crawler_publisher_errors_total{class="timeout",instance="main",publisher="023"} 3
+baram_crawler_publisher_errors_total{class="blocked",instance="main",publisher="001"} 2 1772000000000
+baram_parse_duration_seconds_bucket{result="ok",le="+Inf"} 12
+weird_label{path="a\"b\\c",x="1"} 1.5
+not a sample
+"#;
+        let samples = parse_text(text);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0].name, "baram_tokio_global_queue_depth");
+        assert_eq!(samples[0].value, 7.0);
+        assert_eq!(samples[2].value, 2.0);
+        assert_eq!(samples[3].label("le"), Some("+Inf"));
+        assert_eq!(samples[4].label("path"), Some("a\"b\\c"));
+        assert_eq!(samples[4].label("x"), Some("1"));
+    }
+
+    #[test]
+    fn test_sum() {
+        let samples = parse_text(
+            "errors{class=\"timeout\",instance=\"main\"} 3\n\
+             errors{class=\"blocked\",instance=\"main\"} 2\n\
+             errors{class=\"timeout\",instance=\"sub1\"} 4\n",
+        );
+        assert_eq!(sum(&samples, "errors", &[]), Some(9.0));
+        assert_eq!(sum(&samples, "errors", &[("instance", "main")]), Some(5.0));
+        assert_eq!(sum(&samples, "errors", &[("instance", "sub2")]), None);
+        assert_eq!(sum(&samples, "missing", &[]), None);
+    }
+}
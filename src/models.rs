@@ -3,14 +3,29 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
+use crate::crawler::comment::Comment;
+
 /// Parsed news article
+///
+/// An article is identified by its `(source, source_id)` pair, see
+/// [`ParsedArticle::id`]. Naver articles keep their publisher and article
+/// IDs in `oid`/`aid` and leave `source_id` empty; articles from other
+/// sources leave `oid`/`aid` empty.
+///
+/// `comments`, `images`, `videos` and `reactions` are empty when the parser
+/// or crawl mode does not collect them; they default when missing, so older
+/// serialized articles still load.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ParsedArticle {
-    pub oid: String, // Publisher ID (e.g., "001")
-    pub aid: String, // Article ID (e.g., "0014123456")
+    #[serde(default)]
+    pub source: ArticleSource,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub source_id: String, // Article ID at a non-Naver source (e.g. RSS guid)
+    pub oid: String, // Naver publisher ID (e.g., "001")
+    pub aid: String, // Naver article ID (e.g., "0014123456")
     pub title: String,
     pub content: String,
     pub url: String,
@@ -20,12 +35,39 @@ pub struct ParsedArticle {
     pub published_at: Option<DateTime<Utc>>,
     pub crawled_at: DateTime<Utc>,
     pub content_hash: Option<String>, // SHA256 for deduplication
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<Comment>, // Top-level comments with nested replies
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ArticleMedia>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub videos: Vec<ArticleMedia>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub reactions: BTreeMap<String, u64>, // Reaction type (e.g. "like") -> count
 }
 
 impl ParsedArticle {
-    /// Generate unique ID: {oid}_{aid}
+    /// Stable internal ID, `{oid}_{aid}` for Naver
+    ///
+    /// See [`ArticleSource::article_id`].
     pub fn id(&self) -> String {
-        format!("{}_{}", self.oid, self.aid)
+        self.source.article_id(&self.source_article_id())
+    }
+
+    /// ID of the article at its source: `{oid}_{aid}` for Naver
+    pub fn source_article_id(&self) -> String {
+        match self.source {
+            ArticleSource::Naver => format!("{}_{}", self.oid, self.aid),
+            _ => self.source_id.clone(),
+        }
+    }
+
+    /// Publisher label for metrics: the Naver oid, or the source name
+    pub fn publisher_id(&self) -> &str {
+        if self.oid.is_empty() {
+            self.source.as_str()
+        } else {
+            &self.oid
+        }
     }
 
     /// Calculate content hash using SHA256
@@ -42,6 +84,106 @@ impl ParsedArticle {
             ..Default::default()
         }
     }
+
+    /// Number of comments including nested replies
+    pub fn comment_count(&self) -> usize {
+        self.comments.iter().map(Comment::total_count).sum()
+    }
+
+    /// Total number of reactions of all types
+    pub fn reaction_count(&self) -> u64 {
+        self.reactions.values().sum()
+    }
+}
+
+/// Image or video embedded in an article
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ArticleMedia {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Downloaded copy, relative to the saved markdown file
+    /// (`crawl --download-images`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<String>,
+}
+
+/// Site or feed type an article was crawled from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleSource {
+    #[default]
+    Naver,
+    Daum,
+    Rss,
+}
+
+impl ArticleSource {
+    /// Longest source ID used verbatim in an internal ID; longer or unsafe
+    /// IDs are hashed
+    const MAX_VERBATIM_ID: usize = 40;
+
+    /// Get string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Naver => "naver",
+            Self::Daum => "daum",
+            Self::Rss => "rss",
+        }
+    }
+
+    /// Create from string
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "naver" | "네이버" => Some(Self::Naver),
+            "daum" | "다음" => Some(Self::Daum),
+            "rss" => Some(Self::Rss),
+            _ => None,
+        }
+    }
+
+    /// Stable internal ID of the article `source_id` from this source
+    ///
+    /// Naver IDs (`{oid}_{aid}`) are used as they are, so records, files and
+    /// index documents from before other sources existed keep their IDs.
+    /// Other sources get a `{source}_` prefix; source IDs that are long or
+    /// hold anything but ASCII letters, digits and `-` (URLs, RSS guids)
+    /// are replaced by a hash, so the ID is safe in file names and fits the
+    /// dedup table.
+    pub fn article_id(&self, source_id: &str) -> String {
+        if *self == Self::Naver {
+            return source_id.to_string();
+        }
+        let verbatim = !source_id.is_empty()
+            && source_id.len() <= Self::MAX_VERBATIM_ID
+            && source_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if verbatim {
+            format!("{}_{source_id}", self.as_str())
+        } else {
+            let hash = format!("{:x}", Sha256::digest(source_id.as_bytes()));
+            format!("{}_{}", self.as_str(), &hash[..32])
+        }
+    }
+
+    /// Split an internal ID made by [`ArticleSource::article_id`] into the
+    /// source and the (possibly hashed) source ID
+    pub fn split_article_id(id: &str) -> (Self, &str) {
+        match id.split_once('_') {
+            Some((prefix, rest)) => match Self::parse(prefix) {
+                Some(source) if source != Self::Naver => (source, rest),
+                _ => (Self::Naver, id),
+            },
+            None => (Self::Naver, id),
+        }
+    }
+}
+
+impl std::fmt::Display for ArticleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// News category enumeration
@@ -262,6 +404,69 @@ mod tests {
         assert_eq!(article.content_hash.as_ref().unwrap().len(), 64); // SHA256 hex = 64 chars
     }
 
+    #[test]
+    fn test_article_source_ids() {
+        let naver = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0014123456".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(naver.source, ArticleSource::Naver);
+        assert_eq!(naver.id(), "001_0014123456");
+        assert_eq!(naver.publisher_id(), "001");
+
+        let daum = ParsedArticle {
+            source: ArticleSource::Daum,
+            source_id: "20260101120000123".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(daum.id(), "daum_20260101120000123");
+        assert_eq!(daum.publisher_id(), "daum");
+
+        let rss = ParsedArticle {
+            source: ArticleSource::Rss,
+            source_id: "https://example.com/news/1?ref=rss".to_string(),
+            ..Default::default()
+        };
+        let id = rss.id();
+        assert!(id.starts_with("rss_") && id.len() == 36, "{id}");
+        assert_eq!(id, rss.clone().id());
+        assert_ne!(id, ArticleSource::Daum.article_id(&rss.source_id));
+
+        // Internal IDs split back into IDs that map to themselves
+        for id in ["001_0014123456", "daum_20260101120000123", id.as_str()] {
+            let (source, source_id) = ArticleSource::split_article_id(id);
+            assert_eq!(source.article_id(source_id), id);
+        }
+        assert_eq!(
+            ArticleSource::split_article_id("001_0014123456"),
+            (ArticleSource::Naver, "001_0014123456")
+        );
+    }
+
+    #[test]
+    fn test_media_fields_default_when_missing() {
+        let json = r#"{"oid":"001","aid":"1","title":"t","content":"c","url":"u",
+            "category":"it","publisher":null,"author":null,"published_at":null,
+            "crawled_at":"2026-01-01T00:00:00Z","content_hash":null}"#;
+        let article: ParsedArticle = serde_json::from_str(json).unwrap();
+        assert!(article.comments.is_empty() && article.images.is_empty());
+        assert_eq!(article.reaction_count(), 0);
+
+        let mut article = article;
+        article.images.push(ArticleMedia {
+            url: "https://imgnews.pstatic.net/1.jpg".to_string(),
+            caption: Some("사진".to_string()),
+            local_path: None,
+        });
+        article.reactions.insert("like".to_string(), 12);
+        article.reactions.insert("sad".to_string(), 3);
+        let restored: ParsedArticle =
+            serde_json::from_str(&serde_json::to_string(&article).unwrap()).unwrap();
+        assert_eq!(restored.images, article.images);
+        assert_eq!(restored.reaction_count(), 15);
+    }
+
     #[test]
     fn test_category_conversion() {
         assert_eq!(NewsCategory::Politics.to_section_id(), 100);
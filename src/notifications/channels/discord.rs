@@ -0,0 +1,397 @@
+//! Discord notification channel
+//!
+//! This module provides a Discord webhook channel that renders alerts as
+//! embeds, colored by severity, with the alert condition and metadata as
+//! embed fields.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::{Channel, ChannelError, ChannelResult, DeliveryStatus};
+use crate::notifications::{Alert, AlertSeverity};
+
+/// Discord allows at most 25 fields per embed
+const MAX_EMBED_FIELDS: usize = 25;
+
+/// Discord limits field values to 1024 characters
+const MAX_FIELD_VALUE_CHARS: usize = 1024;
+
+/// Discord channel configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscordConfig {
+    /// Discord webhook URL
+    pub webhook_url: String,
+    /// Display name override for the webhook
+    pub username: Option<String>,
+    /// Avatar image URL override
+    pub avatar_url: Option<String>,
+    /// Mention added to critical alerts (e.g. `@here` or `<@&role_id>`)
+    pub critical_mention: Option<String>,
+    /// Request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_timeout() -> u64 {
+    10
+}
+
+impl DiscordConfig {
+    /// Create a new Discord configuration
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        Self {
+            webhook_url: webhook_url.into(),
+            username: None,
+            avatar_url: None,
+            critical_mention: None,
+            timeout_secs: default_timeout(),
+        }
+    }
+
+    /// Set the webhook display name
+    pub fn with_username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Set the webhook avatar URL
+    pub fn with_avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.avatar_url = Some(avatar_url.into());
+        self
+    }
+
+    /// Set the mention prepended to critical alerts
+    pub fn with_critical_mention(mut self, mention: impl Into<String>) -> Self {
+        self.critical_mention = Some(mention.into());
+        self
+    }
+
+    /// Set request timeout
+    pub fn with_timeout(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    /// Validate configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.webhook_url.is_empty() {
+            return Err("Discord webhook URL cannot be empty".to_string());
+        }
+
+        if !self.webhook_url.starts_with("http://") && !self.webhook_url.starts_with("https://") {
+            return Err("Discord webhook URL must start with http:// or https://".to_string());
+        }
+
+        if self.timeout_secs == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Embed color (decimal RGB) used for each severity
+pub fn severity_color(severity: AlertSeverity) -> u32 {
+    match severity {
+        AlertSeverity::Info => 0x3498DB,
+        AlertSeverity::Warning => 0xF1C40F,
+        AlertSeverity::Critical => 0xE74C3C,
+    }
+}
+
+/// Discord notification channel
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use baram::notifications::channels::discord::{DiscordChannel, DiscordConfig};
+///
+/// let config = DiscordConfig::new("https://discord.com/api/webhooks/123/abc")
+///     .with_username("baram")
+///     .with_critical_mention("@here");
+///
+/// let channel = DiscordChannel::new(config)?;
+/// channel.send(&alert).await?;
+/// ```
+pub struct DiscordChannel {
+    config: DiscordConfig,
+    client: Client,
+}
+
+impl DiscordChannel {
+    /// Create a new Discord channel
+    pub fn new(config: DiscordConfig) -> ChannelResult<Self> {
+        config.validate().map_err(ChannelError::InvalidConfig)?;
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| ChannelError::Other(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self { config, client })
+    }
+
+    /// Create a Discord channel with just a webhook URL
+    pub fn from_url(url: impl Into<String>) -> ChannelResult<Self> {
+        Self::new(DiscordConfig::new(url))
+    }
+
+    /// Build the embed fields for an alert
+    fn build_fields(alert: &Alert) -> Vec<serde_json::Value> {
+        let mut fields = vec![
+            serde_json::json!({
+                "name": "Condition",
+                "value": truncate(&alert.condition.description()),
+                "inline": false,
+            }),
+            serde_json::json!({
+                "name": "Severity",
+                "value": alert.severity.as_str(),
+                "inline": true,
+            }),
+            serde_json::json!({
+                "name": "Status",
+                "value": alert.status.as_str(),
+                "inline": true,
+            }),
+        ];
+
+        if alert.occurrence_count > 1 {
+            fields.push(serde_json::json!({
+                "name": "Occurrences",
+                "value": alert.occurrence_count.to_string(),
+                "inline": true,
+            }));
+        }
+
+        if let Some(by) = &alert.acknowledged_by {
+            fields.push(serde_json::json!({
+                "name": "Acknowledged by",
+                "value": truncate(by),
+                "inline": true,
+            }));
+        }
+
+        let mut metadata: Vec<_> = alert.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            fields.push(serde_json::json!({
+                "name": key,
+                "value": truncate(value),
+                "inline": true,
+            }));
+        }
+
+        fields.truncate(MAX_EMBED_FIELDS);
+        fields
+    }
+
+    /// Build the webhook payload for an alert
+    fn build_payload(&self, alert: &Alert) -> serde_json::Value {
+        let embed = serde_json::json!({
+            "title": format!(
+                "{} [{}] {}",
+                alert.severity.emoji(),
+                alert.severity.as_str().to_uppercase(),
+                alert.message
+            ),
+            "description": alert.condition.korean_description(),
+            "color": severity_color(alert.severity),
+            "fields": Self::build_fields(alert),
+            "footer": { "text": format!("Alert {}", alert.id) },
+            "timestamp": alert.triggered_at.unwrap_or(alert.created_at).to_rfc3339(),
+        });
+
+        let mut payload = serde_json::json!({ "embeds": [embed] });
+
+        if alert.severity == AlertSeverity::Critical {
+            if let Some(mention) = &self.config.critical_mention {
+                payload["content"] = serde_json::json!(mention);
+            }
+        }
+        if let Some(username) = &self.config.username {
+            payload["username"] = serde_json::json!(username);
+        }
+        if let Some(avatar_url) = &self.config.avatar_url {
+            payload["avatar_url"] = serde_json::json!(avatar_url);
+        }
+
+        payload
+    }
+}
+
+/// Truncate a field value to Discord's limit
+fn truncate(value: &str) -> String {
+    if value.chars().count() <= MAX_FIELD_VALUE_CHARS {
+        value.to_string()
+    } else {
+        let mut truncated: String = value.chars().take(MAX_FIELD_VALUE_CHARS - 1).collect();
+        truncated.push('…');
+        truncated
+    }
+}
+
+#[async_trait]
+impl Channel for DiscordChannel {
+    fn name(&self) -> &str {
+        "discord"
+    }
+
+    async fn send(&self, alert: &Alert) -> ChannelResult<DeliveryStatus> {
+        let payload = self.build_payload(alert);
+
+        let result = match self
+            .client
+            .post(&self.config.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) if response.status().as_u16() == 429 => {
+                Err(ChannelError::RateLimited("Discord webhook".to_string()))
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                Err(ChannelError::Other(format!("HTTP {status}: {body}")))
+            }
+            Err(e) => Err(ChannelError::HttpError(e)),
+        };
+
+        match result {
+            Ok(()) => Ok(DeliveryStatus::success("discord")),
+            Err(e) => {
+                tracing::error!("Failed to deliver Discord alert {}: {}", alert.id, e);
+                Ok(DeliveryStatus::failure("discord", e.to_string()))
+            }
+        }
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name(),
+            "username": self.config.username,
+            "timeout_secs": self.config.timeout_secs,
+            "has_critical_mention": self.config.critical_mention.is_some(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::AlertCondition;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn sample_alert(severity: AlertSeverity) -> Alert {
+        let condition = AlertCondition::EntitySurge {
+            entity: "삼성전자".to_string(),
+            threshold: 20,
+            window_minutes: 30,
+        };
+        let mut alert = Alert::new(condition, severity, "Entity surge".to_string())
+            .with_metadata("category".to_string(), "economy".to_string());
+        alert.trigger();
+        alert
+    }
+
+    #[test]
+    fn test_discord_config_validation() {
+        assert!(DiscordConfig::new("https://discord.com/api/webhooks/1/abc")
+            .validate()
+            .is_ok());
+        assert!(DiscordConfig::new("").validate().is_err());
+        assert!(DiscordConfig::new("discord.com/api/webhooks")
+            .validate()
+            .is_err());
+        assert!(DiscordConfig::new("https://discord.com/api/webhooks/1/abc")
+            .with_timeout(0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_discord_payload_building() {
+        let config = DiscordConfig::new("https://discord.com/api/webhooks/1/abc")
+            .with_username("baram")
+            .with_critical_mention("@here");
+        let channel = DiscordChannel::new(config).unwrap();
+
+        let payload = channel.build_payload(&sample_alert(AlertSeverity::Critical));
+        let embed = &payload["embeds"][0];
+
+        assert_eq!(payload["content"], "@here");
+        assert_eq!(payload["username"], "baram");
+        assert_eq!(embed["color"], 0xE74C3C);
+        assert!(embed["title"].as_str().unwrap().contains("CRITICAL"));
+
+        let fields = embed["fields"].as_array().unwrap();
+        assert_eq!(fields[0]["name"], "Condition");
+        assert!(fields[0]["value"].as_str().unwrap().contains("삼성전자"));
+        assert!(fields
+            .iter()
+            .any(|f| f["name"] == "category" && f["value"] == "economy"));
+    }
+
+    #[test]
+    fn test_discord_mention_only_for_critical() {
+        let config = DiscordConfig::new("https://discord.com/api/webhooks/1/abc")
+            .with_critical_mention("@here");
+        let channel = DiscordChannel::new(config).unwrap();
+
+        let payload = channel.build_payload(&sample_alert(AlertSeverity::Warning));
+        assert!(payload.get("content").is_none());
+        assert_eq!(payload["embeds"][0]["color"], 0xF1C40F);
+    }
+
+    #[test]
+    fn test_discord_occurrences_field() {
+        let channel = DiscordChannel::from_url("https://discord.com/api/webhooks/1/abc").unwrap();
+        let mut alert = sample_alert(AlertSeverity::Info);
+
+        let fields = DiscordChannel::build_fields(&alert);
+        assert!(!fields.iter().any(|f| f["name"] == "Occurrences"));
+
+        alert.record_occurrence();
+        let payload = channel.build_payload(&alert);
+        let fields = payload["embeds"][0]["fields"].as_array().unwrap();
+        assert!(fields
+            .iter()
+            .any(|f| f["name"] == "Occurrences" && f["value"] == "2"));
+    }
+
+    #[test]
+    fn test_discord_field_truncation() {
+        let long = "가".repeat(2000);
+        let truncated = truncate(&long);
+        assert_eq!(truncated.chars().count(), MAX_FIELD_VALUE_CHARS);
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[tokio::test]
+    async fn test_discord_send() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "embeds": [{ "color": 0x3498DB }]
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let channel = DiscordChannel::from_url(format!("{}/webhook", server.uri())).unwrap();
+        let status = channel
+            .send(&sample_alert(AlertSeverity::Info))
+            .await
+            .unwrap();
+
+        assert!(status.success);
+        assert_eq!(status.channel, "discord");
+    }
+}
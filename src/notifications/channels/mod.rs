@@ -3,12 +3,15 @@
 //! This module provides various channels for sending notifications,
 //! including webhooks, email, and messaging platforms.
 
+pub mod discord;
+pub mod slack;
 pub mod webhook;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::notifications::digest::Digest;
 use crate::notifications::Alert;
 
 /// Result type for channel operations
@@ -109,6 +112,12 @@ pub trait Channel: Send + Sync {
     /// Send an alert through this channel
     async fn send(&self, alert: &Alert) -> ChannelResult<DeliveryStatus>;
 
+    /// Send a digest of batched low-severity alerts
+    async fn send_digest(&self, digest: &Digest) -> ChannelResult<DeliveryStatus> {
+        // Default implementation: deliver the digest as one summary alert
+        self.send(&digest.to_alert()).await
+    }
+
     /// Check if the channel is available
     async fn health_check(&self) -> ChannelResult<bool> {
         // Default implementation: always healthy
@@ -0,0 +1,558 @@
+//! Slack notification channel
+//!
+//! This module provides a Slack channel that renders alerts with Block Kit,
+//! colors them by severity and groups every update about the same alert into
+//! a single thread.
+//!
+//! Two delivery modes are supported:
+//!
+//! - **Incoming webhook**: simplest setup, but Slack does not return a message
+//!   timestamp, so updates cannot be threaded.
+//! - **Bot token** (`chat.postMessage`): the first message for an alert id
+//!   starts a thread and later updates (acknowledged, resolved, escalated) are
+//!   posted as replies in that thread.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::{Channel, ChannelError, ChannelResult, DeliveryStatus};
+use crate::notifications::{Alert, AlertSeverity};
+
+/// Default Slack Web API base URL
+const DEFAULT_API_BASE: &str = "https://slack.com/api";
+
+/// Slack channel configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackConfig {
+    /// Incoming webhook URL (used when no bot token is configured)
+    pub webhook_url: Option<String>,
+    /// Bot token for the Web API (`xoxb-...`), enables thread grouping
+    pub bot_token: Option<String>,
+    /// Target channel ID or name (required with a bot token)
+    pub channel: Option<String>,
+    /// Display name override for the bot
+    pub username: Option<String>,
+    /// Emoji icon override (e.g. `:rotating_light:`)
+    pub icon_emoji: Option<String>,
+    /// Mention added to critical alerts (e.g. `<!here>` or `<@U123>`)
+    pub critical_mention: Option<String>,
+    /// Web API base URL
+    #[serde(default = "default_api_base")]
+    pub api_base: String,
+    /// Request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_api_base() -> String {
+    DEFAULT_API_BASE.to_string()
+}
+
+fn default_timeout() -> u64 {
+    10
+}
+
+impl SlackConfig {
+    /// Create a configuration that posts through an incoming webhook
+    pub fn webhook(url: impl Into<String>) -> Self {
+        Self {
+            webhook_url: Some(url.into()),
+            bot_token: None,
+            channel: None,
+            username: None,
+            icon_emoji: None,
+            critical_mention: None,
+            api_base: default_api_base(),
+            timeout_secs: default_timeout(),
+        }
+    }
+
+    /// Create a configuration that posts through the Web API with a bot token
+    pub fn bot(token: impl Into<String>, channel: impl Into<String>) -> Self {
+        Self {
+            webhook_url: None,
+            bot_token: Some(token.into()),
+            channel: Some(channel.into()),
+            ..Self::webhook("")
+        }
+    }
+
+    /// Set the bot display name
+    pub fn with_username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Set the bot emoji icon
+    pub fn with_icon_emoji(mut self, icon_emoji: impl Into<String>) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Set the mention prepended to critical alerts
+    pub fn with_critical_mention(mut self, mention: impl Into<String>) -> Self {
+        self.critical_mention = Some(mention.into());
+        self
+    }
+
+    /// Override the Web API base URL
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+
+    /// Set request timeout
+    pub fn with_timeout(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    /// Whether messages are sent through the Web API (and can be threaded)
+    pub fn uses_bot_token(&self) -> bool {
+        self.bot_token.is_some()
+    }
+
+    /// Validate configuration
+    pub fn validate(&self) -> Result<(), String> {
+        match (&self.bot_token, &self.webhook_url) {
+            (Some(token), _) => {
+                if token.is_empty() {
+                    return Err("Slack bot token cannot be empty".to_string());
+                }
+                if self.channel.as_deref().unwrap_or_default().is_empty() {
+                    return Err("Slack channel is required when using a bot token".to_string());
+                }
+            }
+            (None, Some(url)) => {
+                if !url.starts_with("https://") && !url.starts_with("http://") {
+                    return Err("Slack webhook URL must start with http:// or https://".to_string());
+                }
+            }
+            (None, None) => {
+                return Err("Either a Slack webhook URL or bot token is required".to_string());
+            }
+        }
+
+        if self.timeout_secs == 0 {
+            return Err("Timeout must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Attachment color used for each severity
+pub fn severity_color(severity: AlertSeverity) -> &'static str {
+    match severity {
+        AlertSeverity::Info => "#439FE0",
+        AlertSeverity::Warning => "#ECB22E",
+        AlertSeverity::Critical => "#E01E5A",
+    }
+}
+
+/// Slack notification channel
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use baram::notifications::channels::slack::{SlackChannel, SlackConfig};
+///
+/// let config = SlackConfig::bot("xoxb-token", "#news-alerts")
+///     .with_username("baram")
+///     .with_critical_mention("<!here>");
+///
+/// let channel = SlackChannel::new(config)?;
+/// channel.send(&alert).await?;
+/// ```
+pub struct SlackChannel {
+    config: SlackConfig,
+    client: Client,
+    /// Thread timestamp of the first message posted for each alert id
+    threads: Mutex<HashMap<String, String>>,
+}
+
+impl SlackChannel {
+    /// Create a new Slack channel
+    pub fn new(config: SlackConfig) -> ChannelResult<Self> {
+        config.validate().map_err(ChannelError::InvalidConfig)?;
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| ChannelError::Other(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self {
+            config,
+            client,
+            threads: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Create a Slack channel posting to an incoming webhook
+    pub fn from_webhook_url(url: impl Into<String>) -> ChannelResult<Self> {
+        Self::new(SlackConfig::webhook(url))
+    }
+
+    /// Get the thread timestamp recorded for an alert, if any
+    pub fn thread_ts(&self, alert_id: &str) -> Option<String> {
+        self.threads
+            .lock()
+            .ok()
+            .and_then(|threads| threads.get(alert_id).cloned())
+    }
+
+    /// Build the Block Kit blocks for an alert
+    fn build_blocks(&self, alert: &Alert) -> Vec<serde_json::Value> {
+        let mut headline = format!(
+            "{} [{}] {}",
+            alert.severity.emoji(),
+            alert.severity.as_str().to_uppercase(),
+            alert.message
+        );
+        if alert.severity == AlertSeverity::Critical {
+            if let Some(mention) = &self.config.critical_mention {
+                headline = format!("{mention} {headline}");
+            }
+        }
+
+        let mut fields = vec![
+            serde_json::json!({
+                "type": "mrkdwn",
+                "text": format!("*Condition*\n{}", alert.condition.description()),
+            }),
+            serde_json::json!({
+                "type": "mrkdwn",
+                "text": format!("*Status*\n{}", alert.status.as_str()),
+            }),
+        ];
+
+        let mut metadata: Vec<_> = alert.metadata.iter().collect();
+        metadata.sort();
+        for (key, value) in metadata {
+            fields.push(serde_json::json!({
+                "type": "mrkdwn",
+                "text": format!("*{key}*\n{value}"),
+            }));
+        }
+        // Slack limits section blocks to 10 fields
+        fields.truncate(10);
+
+        let mut context = format!(
+            "Alert `{}` · created {}",
+            alert.id,
+            alert.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        if alert.occurrence_count > 1 {
+            context.push_str(&format!(" · {} occurrences", alert.occurrence_count));
+        }
+        if let Some(by) = &alert.acknowledged_by {
+            context.push_str(&format!(" · acknowledged by {by}"));
+        }
+
+        vec![
+            serde_json::json!({
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": headline },
+            }),
+            serde_json::json!({
+                "type": "section",
+                "fields": fields,
+            }),
+            serde_json::json!({
+                "type": "context",
+                "elements": [{ "type": "mrkdwn", "text": context }],
+            }),
+        ]
+    }
+
+    /// Build the message payload for an alert
+    fn build_payload(&self, alert: &Alert, thread_ts: Option<&str>) -> serde_json::Value {
+        let mut payload = serde_json::json!({
+            // Fallback text for notifications and clients without Block Kit
+            "text": format!(
+                "[{}] {}",
+                alert.severity.as_str().to_uppercase(),
+                alert.message
+            ),
+            "attachments": [{
+                "color": severity_color(alert.severity),
+                "blocks": self.build_blocks(alert),
+            }],
+        });
+
+        if let Some(channel) = &self.config.channel {
+            payload["channel"] = serde_json::json!(channel);
+        }
+        if let Some(username) = &self.config.username {
+            payload["username"] = serde_json::json!(username);
+        }
+        if let Some(icon) = &self.config.icon_emoji {
+            payload["icon_emoji"] = serde_json::json!(icon);
+        }
+        if let Some(ts) = thread_ts {
+            payload["thread_ts"] = serde_json::json!(ts);
+        }
+
+        payload
+    }
+
+    /// Post through an incoming webhook
+    async fn post_webhook(&self, url: &str, payload: &serde_json::Value) -> ChannelResult<()> {
+        let response = self.client.post(url).json(payload).send().await?;
+        let status = response.status();
+
+        if status.as_u16() == 429 {
+            return Err(ChannelError::RateLimited("Slack webhook".to_string()));
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ChannelError::Other(format!("HTTP {status}: {body}")));
+        }
+
+        Ok(())
+    }
+
+    /// Post through `chat.postMessage`, returning the message timestamp
+    async fn post_message(
+        &self,
+        token: &str,
+        payload: &serde_json::Value,
+    ) -> ChannelResult<String> {
+        let url = format!(
+            "{}/chat.postMessage",
+            self.config.api_base.trim_end_matches('/')
+        );
+        let response = self
+            .client
+            .post(&url)
+            .bearer_auth(token)
+            .json(payload)
+            .send()
+            .await?;
+
+        if response.status().as_u16() == 429 {
+            return Err(ChannelError::RateLimited("Slack Web API".to_string()));
+        }
+
+        let body: serde_json::Value = response.json().await?;
+        if body["ok"].as_bool() != Some(true) {
+            let error = body["error"].as_str().unwrap_or("unknown_error");
+            return Err(ChannelError::Other(format!("Slack API error: {error}")));
+        }
+
+        body["ts"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| ChannelError::Other("Slack API response missing 'ts'".to_string()))
+    }
+}
+
+#[async_trait]
+impl Channel for SlackChannel {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    async fn send(&self, alert: &Alert) -> ChannelResult<DeliveryStatus> {
+        let result = match (&self.config.bot_token, &self.config.webhook_url) {
+            (Some(token), _) => {
+                let thread_ts = self.thread_ts(&alert.id);
+                let payload = self.build_payload(alert, thread_ts.as_deref());
+
+                self.post_message(token, &payload).await.map(|ts| {
+                    if thread_ts.is_none() {
+                        if let Ok(mut threads) = self.threads.lock() {
+                            threads.insert(alert.id.clone(), ts);
+                        }
+                    }
+                    match thread_ts {
+                        Some(parent) => format!("Replied in thread {parent}"),
+                        None => "Started thread".to_string(),
+                    }
+                })
+            }
+            (None, Some(url)) => {
+                let payload = self.build_payload(alert, None);
+                self.post_webhook(url, &payload)
+                    .await
+                    .map(|()| "Delivered via webhook".to_string())
+            }
+            (None, None) => Err(ChannelError::InvalidConfig(
+                "No Slack delivery method configured".to_string(),
+            )),
+        };
+
+        match result {
+            Ok(message) => Ok(DeliveryStatus::success_with_message("slack", message)),
+            Err(e) => {
+                tracing::error!("Failed to deliver Slack alert {}: {}", alert.id, e);
+                Ok(DeliveryStatus::failure("slack", e.to_string()))
+            }
+        }
+    }
+
+    fn config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name(),
+            "mode": if self.config.uses_bot_token() { "bot" } else { "webhook" },
+            "channel": self.config.channel,
+            "username": self.config.username,
+            "timeout_secs": self.config.timeout_secs,
+            "has_critical_mention": self.config.critical_mention.is_some(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::AlertCondition;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn sample_alert(severity: AlertSeverity) -> Alert {
+        let condition = AlertCondition::KeywordSpike {
+            keyword: "경제위기".to_string(),
+            threshold: 10,
+            window_minutes: 60,
+        };
+        let mut alert = Alert::new(condition, severity, "Keyword spike detected".to_string())
+            .with_metadata("instance".to_string(), "main".to_string());
+        alert.trigger();
+        alert
+    }
+
+    #[test]
+    fn test_slack_config_validation() {
+        assert!(
+            SlackConfig::webhook("https://hooks.slack.com/services/T/B/X")
+                .validate()
+                .is_ok()
+        );
+        assert!(SlackConfig::bot("xoxb-token", "C123").validate().is_ok());
+
+        assert!(SlackConfig::webhook("hooks.slack.com").validate().is_err());
+        assert!(SlackConfig::bot("", "C123").validate().is_err());
+        assert!(SlackConfig::bot("xoxb-token", "").validate().is_err());
+        assert!(SlackConfig::webhook("https://hooks.slack.com/x")
+            .with_timeout(0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_severity_colors() {
+        assert_eq!(severity_color(AlertSeverity::Info), "#439FE0");
+        assert_eq!(severity_color(AlertSeverity::Warning), "#ECB22E");
+        assert_eq!(severity_color(AlertSeverity::Critical), "#E01E5A");
+    }
+
+    #[test]
+    fn test_slack_payload_building() {
+        let config = SlackConfig::bot("xoxb-token", "C123")
+            .with_username("baram")
+            .with_critical_mention("<!here>");
+        let channel = SlackChannel::new(config).unwrap();
+
+        let alert = sample_alert(AlertSeverity::Critical);
+        let payload = channel.build_payload(&alert, Some("1700000000.000100"));
+
+        assert_eq!(payload["channel"], "C123");
+        assert_eq!(payload["username"], "baram");
+        assert_eq!(payload["thread_ts"], "1700000000.000100");
+        assert_eq!(payload["attachments"][0]["color"], "#E01E5A");
+
+        let blocks = payload["attachments"][0]["blocks"].as_array().unwrap();
+        let headline = blocks[0]["text"]["text"].as_str().unwrap();
+        assert!(headline.starts_with("<!here>"));
+        assert!(headline.contains("CRITICAL"));
+
+        let fields = blocks[1]["fields"].to_string();
+        assert!(fields.contains("경제위기"));
+        assert!(fields.contains("instance"));
+    }
+
+    #[test]
+    fn test_slack_webhook_payload_has_no_thread() {
+        let channel =
+            SlackChannel::from_webhook_url("https://hooks.slack.com/services/T/B/X").unwrap();
+        let payload = channel.build_payload(&sample_alert(AlertSeverity::Info), None);
+
+        assert!(payload.get("thread_ts").is_none());
+        assert!(payload.get("channel").is_none());
+        assert_eq!(channel.config()["mode"], "webhook");
+    }
+
+    #[tokio::test]
+    async fn test_slack_threads_updates_per_alert() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .and(header("authorization", "Bearer xoxb-token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "ok": true, "ts": "111.222" })),
+            )
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .and(body_partial_json(
+                serde_json::json!({ "thread_ts": "111.222" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "ok": true, "ts": "111.333" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config = SlackConfig::bot("xoxb-token", "C123").with_api_base(server.uri());
+        let channel = SlackChannel::new(config).unwrap();
+
+        let mut alert = sample_alert(AlertSeverity::Warning);
+        let first = channel.send(&alert).await.unwrap();
+        assert!(first.success);
+        assert_eq!(channel.thread_ts(&alert.id), Some("111.222".to_string()));
+
+        alert.acknowledge("oncall".to_string());
+        let second = channel.send(&alert).await.unwrap();
+        assert!(second.success);
+        // The thread root stays the first message
+        assert_eq!(channel.thread_ts(&alert.id), Some("111.222".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_slack_api_error_reported_as_failure() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat.postMessage"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({ "ok": false, "error": "channel_not_found" }),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let config = SlackConfig::bot("xoxb-token", "C404").with_api_base(server.uri());
+        let channel = SlackChannel::new(config).unwrap();
+
+        let status = channel
+            .send(&sample_alert(AlertSeverity::Info))
+            .await
+            .unwrap();
+        assert!(!status.success);
+        assert!(status.message.unwrap().contains("channel_not_found"));
+    }
+}
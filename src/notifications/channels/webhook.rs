@@ -178,6 +178,7 @@ impl WebhookChannel {
             "acknowledged_at": alert.acknowledged_at.map(|t| t.to_rfc3339()),
             "acknowledged_by": alert.acknowledged_by,
             "resolved_at": alert.resolved_at.map(|t| t.to_rfc3339()),
+            "occurrence_count": alert.occurrence_count,
         })
     }
 
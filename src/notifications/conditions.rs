@@ -23,7 +23,7 @@ pub enum AlertCondition {
     /// }
     /// ```
     KeywordSpike {
-        /// The keyword to monitor
+        /// The keyword to monitor (`*` matches any keyword in detected bursts)
         keyword: String,
         /// Minimum occurrences to trigger alert
         threshold: u32,
@@ -45,7 +45,7 @@ pub enum AlertCondition {
     /// }
     /// ```
     EntitySurge {
-        /// The entity to monitor (person, organization, location)
+        /// The entity to monitor (person, organization, location; `*` matches any)
         entity: String,
         /// Minimum mentions to trigger alert
         threshold: u32,
@@ -129,6 +129,22 @@ pub enum AlertCondition {
         failure_count: u32,
     },
 
+    /// Triggered when consecutive scheduled crawl slots collect no articles
+    ///
+    /// # Example
+    ///
+    /// Alert when two slots in a row come back empty:
+    ///
+    /// ```rust,ignore
+    /// AlertCondition::ZeroArticleSlots {
+    ///     consecutive_slots: 2,
+    /// }
+    /// ```
+    ZeroArticleSlots {
+        /// Number of consecutive empty slots
+        consecutive_slots: u32,
+    },
+
     /// Custom condition with user-defined logic
     ///
     /// # Example
@@ -201,6 +217,9 @@ impl AlertCondition {
             } => {
                 format!("Source '{source}' failed {failure_count} consecutive times")
             }
+            Self::ZeroArticleSlots { consecutive_slots } => {
+                format!("No articles collected in {consecutive_slots} consecutive crawl slots")
+            }
             Self::Custom {
                 name, description, ..
             } => {
@@ -250,6 +269,9 @@ impl AlertCondition {
             } => {
                 format!("소스 '{source}' 연속 {failure_count}회 실패")
             }
+            Self::ZeroArticleSlots { consecutive_slots } => {
+                format!("연속 {consecutive_slots}개 크롤링 슬롯에서 수집된 기사 없음")
+            }
             Self::Custom { description, .. } => description.clone(),
         }
     }
@@ -263,6 +285,7 @@ impl AlertCondition {
             Self::ErrorRateThreshold { .. } => "error_rate_threshold",
             Self::ThroughputDrop { .. } => "throughput_drop",
             Self::SourceFailure { .. } => "source_failure",
+            Self::ZeroArticleSlots { .. } => "zero_article_slots",
             Self::Custom { .. } => "custom",
         }
     }
@@ -274,7 +297,10 @@ impl AlertCondition {
             | Self::EntitySurge { window_minutes, .. }
             | Self::ErrorRateThreshold { window_minutes, .. }
             | Self::ThroughputDrop { window_minutes, .. } => Some(*window_minutes),
-            Self::VolumeAnomaly { .. } | Self::SourceFailure { .. } | Self::Custom { .. } => None,
+            Self::VolumeAnomaly { .. }
+            | Self::SourceFailure { .. }
+            | Self::ZeroArticleSlots { .. }
+            | Self::Custom { .. } => None,
         }
     }
 
@@ -355,6 +381,11 @@ impl AlertCondition {
                     return Err("Failure count must be greater than 0".to_string());
                 }
             }
+            Self::ZeroArticleSlots { consecutive_slots } => {
+                if *consecutive_slots == 0 {
+                    return Err("Consecutive slots must be greater than 0".to_string());
+                }
+            }
             Self::Custom { name, .. } => {
                 if name.is_empty() {
                     return Err("Custom condition name cannot be empty".to_string());
@@ -446,6 +477,23 @@ mod tests {
         assert!(desc.contains("5"));
     }
 
+    #[test]
+    fn test_zero_article_slots() {
+        let condition = AlertCondition::ZeroArticleSlots {
+            consecutive_slots: 2,
+        };
+
+        assert_eq!(condition.condition_type(), "zero_article_slots");
+        assert_eq!(condition.window_minutes(), None);
+        assert!(condition.description().contains('2'));
+        assert!(condition.validate().is_ok());
+
+        let invalid = AlertCondition::ZeroArticleSlots {
+            consecutive_slots: 0,
+        };
+        assert!(invalid.validate().is_err());
+    }
+
     #[test]
     fn test_custom_condition() {
         let mut params = HashMap::new();
@@ -0,0 +1,268 @@
+//! Digest mode for low-severity alerts
+//!
+//! When enabled, Info alerts are not sent immediately. They are queued per
+//! channel and delivered as a single summary message every hour or day, while
+//! Warning and Critical alerts keep going out immediately.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications.digest]
+//! enabled = true
+//! interval = "hourly"   # hourly or daily
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+use super::{Alert, AlertCondition, AlertSeverity, NotificationManager};
+
+/// Maximum number of alerts listed individually in a digest message
+const MAX_LISTED_ALERTS: usize = 10;
+
+/// How often digests are sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestInterval {
+    /// Once per hour
+    #[default]
+    Hourly,
+    /// Once per day
+    Daily,
+}
+
+impl DigestInterval {
+    /// Get the interval duration
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Hourly => Duration::from_secs(60 * 60),
+            Self::Daily => Duration::from_secs(24 * 60 * 60),
+        }
+    }
+
+    /// Get the interval as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+        }
+    }
+}
+
+/// Digest configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DigestConfig {
+    /// Batch Info alerts into digests instead of sending them immediately
+    #[serde(default)]
+    pub enabled: bool,
+    /// How often digests are sent
+    #[serde(default)]
+    pub interval: DigestInterval,
+}
+
+impl DigestConfig {
+    /// Create an enabled digest configuration
+    pub fn new(interval: DigestInterval) -> Self {
+        Self {
+            enabled: true,
+            interval,
+        }
+    }
+
+    /// Check whether an alert should be batched into the digest
+    pub fn should_batch(&self, alert: &Alert) -> bool {
+        self.enabled && alert.severity == AlertSeverity::Info
+    }
+}
+
+/// A batch of alerts queued for one channel
+#[derive(Debug, Clone)]
+pub struct Digest {
+    /// Name of the channel the digest is for
+    pub channel: String,
+    /// Queued alerts, oldest first
+    pub alerts: Vec<Alert>,
+    /// When the first alert was queued
+    pub period_start: DateTime<Utc>,
+    /// When the digest was built
+    pub period_end: DateTime<Utc>,
+}
+
+impl Digest {
+    /// Create a digest from queued alerts
+    pub fn new(channel: impl Into<String>, alerts: Vec<Alert>) -> Self {
+        let period_end = Utc::now();
+        let period_start = alerts
+            .iter()
+            .map(|a| a.triggered_at.unwrap_or(a.created_at))
+            .min()
+            .unwrap_or(period_end);
+
+        Self {
+            channel: channel.into(),
+            alerts,
+            period_start,
+            period_end,
+        }
+    }
+
+    /// Number of queued alerts, counting deduplicated repeats
+    pub fn total_occurrences(&self) -> u32 {
+        self.alerts.iter().map(|a| a.occurrence_count).sum()
+    }
+
+    /// Count alerts by condition type
+    pub fn counts_by_condition(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for alert in &self.alerts {
+            *counts.entry(alert.condition.condition_type()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Render the digest body
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "{} info alerts between {} and {}",
+            self.alerts.len(),
+            self.period_start.format("%Y-%m-%d %H:%M UTC"),
+            self.period_end.format("%Y-%m-%d %H:%M UTC"),
+        )];
+
+        for alert in self.alerts.iter().take(MAX_LISTED_ALERTS) {
+            let repeats = if alert.occurrence_count > 1 {
+                format!(" (x{})", alert.occurrence_count)
+            } else {
+                String::new()
+            };
+            lines.push(format!("• {}{repeats}", alert.message));
+        }
+        if self.alerts.len() > MAX_LISTED_ALERTS {
+            lines.push(format!(
+                "… and {} more",
+                self.alerts.len() - MAX_LISTED_ALERTS
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Render the digest as a single Info alert for channel delivery
+    pub fn to_alert(&self) -> Alert {
+        let condition = AlertCondition::Custom {
+            name: "digest".to_string(),
+            description: format!("{} alerts batched into one digest", self.alerts.len()),
+            parameters: HashMap::new(),
+        };
+
+        let mut alert = Alert::new(condition, AlertSeverity::Info, self.summary())
+            .with_metadata("digest_alerts".to_string(), self.alerts.len().to_string())
+            .with_metadata(
+                "digest_occurrences".to_string(),
+                self.total_occurrences().to_string(),
+            );
+        for (condition_type, count) in self.counts_by_condition() {
+            alert
+                .metadata
+                .insert(condition_type.to_string(), count.to_string());
+        }
+        alert.trigger();
+        alert
+    }
+}
+
+/// Send queued digests every interval until the task is aborted
+pub async fn run_digest_loop(manager: Arc<RwLock<NotificationManager>>, interval: Duration) {
+    let start = tokio::time::Instant::now() + interval;
+    let mut ticker = tokio::time::interval_at(start, interval);
+    loop {
+        ticker.tick().await;
+
+        // Drain the queues under the lock, send after releasing it
+        let outbox = manager.write().await.take_digests();
+        let sent = outbox.send().await;
+        if sent > 0 {
+            tracing::info!(digests = sent, "Sent alert digests");
+        }
+    }
+}
+
+/// Spawn the background task that sends alert digests
+pub fn spawn_digest_task(
+    manager: Arc<RwLock<NotificationManager>>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(run_digest_loop(manager, interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info_alert(message: &str) -> Alert {
+        let condition = AlertCondition::KeywordSpike {
+            keyword: "태풍".to_string(),
+            threshold: 5,
+            window_minutes: 60,
+        };
+        let mut alert = Alert::new(condition, AlertSeverity::Info, message.to_string());
+        alert.trigger();
+        alert
+    }
+
+    #[test]
+    fn test_digest_interval() {
+        assert_eq!(DigestInterval::Hourly.duration().as_secs(), 3600);
+        assert_eq!(DigestInterval::Daily.duration().as_secs(), 86400);
+        assert_eq!(DigestInterval::default(), DigestInterval::Hourly);
+    }
+
+    #[test]
+    fn test_should_batch_only_info() {
+        let config = DigestConfig::new(DigestInterval::Daily);
+        assert!(config.should_batch(&info_alert("a")));
+
+        let mut warning = info_alert("b");
+        warning.severity = AlertSeverity::Warning;
+        assert!(!config.should_batch(&warning));
+
+        assert!(!DigestConfig::default().should_batch(&info_alert("c")));
+    }
+
+    #[test]
+    fn test_digest_summary() {
+        let mut repeated = info_alert("repeated");
+        repeated.record_occurrence();
+
+        let mut alerts = vec![repeated];
+        for i in 0..12 {
+            alerts.push(info_alert(&format!("alert {i}")));
+        }
+
+        let digest = Digest::new("ops", alerts);
+        assert_eq!(digest.total_occurrences(), 14);
+        assert_eq!(digest.counts_by_condition().get("keyword_spike"), Some(&13));
+
+        let summary = digest.summary();
+        assert!(summary.starts_with("13 info alerts"));
+        assert!(summary.contains("• repeated (x2)"));
+        assert!(summary.contains("… and 3 more"));
+
+        let alert = digest.to_alert();
+        assert_eq!(alert.severity, AlertSeverity::Info);
+        assert_eq!(alert.condition.condition_type(), "custom");
+        assert_eq!(alert.metadata.get("digest_alerts").unwrap(), "13");
+    }
+
+    #[test]
+    fn test_digest_config_from_toml() {
+        let config: DigestConfig = toml::from_str("enabled = true\ninterval = \"daily\"").unwrap();
+        assert!(config.enabled);
+        assert_eq!(config.interval, DigestInterval::Daily);
+    }
+}
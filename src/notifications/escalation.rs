@@ -0,0 +1,153 @@
+//! Escalation policies for unacknowledged alerts
+//!
+//! An escalation policy re-notifies (and optionally raises the severity of)
+//! alerts that stay triggered without acknowledgement for too long. Policies
+//! are evaluated periodically by [`run_escalation_loop`], which the
+//! coordinator runs in the background.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications]
+//! escalation_interval_secs = 60
+//!
+//! # Warning → Critical, paging on-call after 30 unacknowledged minutes
+//! [[notifications.escalations]]
+//! name = "page-oncall"
+//! severity = "warning"
+//! after_minutes = 30
+//! escalate_to = "critical"
+//! channels = ["oncall"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use super::{AlertSeverity, NotificationManager};
+
+/// Rule for escalating alerts that remain unacknowledged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscalationPolicy {
+    /// Unique policy name
+    pub name: String,
+    /// Severity of alerts this policy applies to
+    pub severity: AlertSeverity,
+    /// Minutes an alert may stay unacknowledged before escalating
+    pub after_minutes: u32,
+    /// New severity after escalation (keeps the current one if unset)
+    #[serde(default)]
+    pub escalate_to: Option<AlertSeverity>,
+    /// Channel names to re-notify (empty = all channels)
+    #[serde(default)]
+    pub channels: Vec<String>,
+}
+
+impl EscalationPolicy {
+    /// Create a policy that re-notifies all channels
+    pub fn new(name: impl Into<String>, severity: AlertSeverity, after_minutes: u32) -> Self {
+        Self {
+            name: name.into(),
+            severity,
+            after_minutes,
+            escalate_to: None,
+            channels: Vec::new(),
+        }
+    }
+
+    /// Raise the alert to this severity when escalating
+    pub fn with_escalate_to(mut self, severity: AlertSeverity) -> Self {
+        self.escalate_to = Some(severity);
+        self
+    }
+
+    /// Restrict re-notification to the given channels
+    pub fn with_channels<I, S>(mut self, channels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = channels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check whether an escalation should go to a channel
+    pub fn targets(&self, channel_name: &str) -> bool {
+        self.channels.is_empty() || self.channels.iter().any(|c| c == channel_name)
+    }
+
+    /// Validate the policy
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Escalation policy name cannot be empty".to_string());
+        }
+        if self.after_minutes == 0 {
+            return Err(format!(
+                "escalation '{}': after_minutes must be greater than 0",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate escalation policies until the task is aborted
+pub async fn run_escalation_loop(manager: Arc<RwLock<NotificationManager>>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+
+        // Decide under the lock, send after releasing it
+        let (escalated, outbox) = manager.write().await.queue_escalations();
+        outbox.send().await;
+        if !escalated.is_empty() {
+            tracing::info!(count = escalated.len(), "Escalated unacknowledged alerts");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_builder() {
+        let policy = EscalationPolicy::new("page-oncall", AlertSeverity::Warning, 30)
+            .with_escalate_to(AlertSeverity::Critical)
+            .with_channels(["oncall"]);
+
+        assert_eq!(policy.escalate_to, Some(AlertSeverity::Critical));
+        assert!(policy.targets("oncall"));
+        assert!(!policy.targets("ops"));
+        assert!(policy.validate().is_ok());
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        assert!(EscalationPolicy::new("", AlertSeverity::Info, 10)
+            .validate()
+            .is_err());
+        assert!(EscalationPolicy::new("p", AlertSeverity::Info, 0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_policy_from_toml() {
+        let policy: EscalationPolicy = toml::from_str(
+            r#"
+name = "page-oncall"
+severity = "warning"
+after_minutes = 30
+escalate_to = "critical"
+channels = ["oncall"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(policy.severity, AlertSeverity::Warning);
+        assert_eq!(policy.after_minutes, 30);
+        assert_eq!(policy.escalate_to, Some(AlertSeverity::Critical));
+    }
+}
@@ -0,0 +1,473 @@
+//! Live alerts for watched keywords
+//!
+//! `keyword_spike` rules name the keywords to watch (e.g. a company name).
+//! While `baram watch` or `baram distributed` crawls, every stored article is
+//! passed to a [`KeywordWatch`], which counts the articles mentioning each
+//! watched keyword per hour and remembers their links. After each run the
+//! counts go through the [`BurstDetector`] and bursts in the last hour are
+//! sent to the matching rules by [`KeywordAlerts::evaluate`], with links to
+//! the articles behind them.
+//!
+//! Counts start at zero when the process starts unless seeded from a
+//! time series ([`KeywordWatch::seed`]), so without history the first
+//! `MIN_BASELINE_POINTS` hours cannot raise alerts. Rules using `"*"` as the
+//! keyword are left to `baram trends bursts --notify`.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications.keyword_burst]
+//! sensitivity = 3.0
+//! min_count = 3
+//! baseline_hours = 24
+//!
+//! [[notifications.rules]]
+//! name = "samsung"
+//! severity = "warning"
+//! condition = { type = "keyword_spike", keyword = "삼성전자", threshold = 5, window_minutes = 60 }
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+use super::{Alert, AlertCondition, AlertRule, NotificationManager, NotificationsConfig};
+use crate::analytics::burst::WILDCARD;
+use crate::analytics::{
+    ArticleLink, BurstConfig, BurstDetector, BurstEvent, BurstSubject, TextTokenizer,
+};
+use crate::models::ParsedArticle;
+use crate::storage::{hour_bucket, TimeSeriesStore, METRIC_KEYWORD};
+
+/// Articles linked from one alert
+const MAX_ALERT_LINKS: usize = 5;
+
+/// Article links kept per keyword and hour
+const MAX_KEPT_LINKS: usize = 20;
+
+/// Hourly mention counts and article links for the keywords of
+/// `keyword_spike` rules
+#[derive(Debug, Clone)]
+pub struct KeywordWatch {
+    detector: BurstDetector,
+    /// Watched keywords as written in the rules
+    keywords: Vec<String>,
+    /// Articles mentioning each keyword, by hour
+    counts: HashMap<String, BTreeMap<DateTime<Utc>, u64>>,
+    /// Recent article links of each keyword, by hour
+    links: HashMap<String, BTreeMap<DateTime<Utc>, Vec<ArticleLink>>>,
+}
+
+impl KeywordWatch {
+    /// Watch the keywords of the enabled `keyword_spike` rules
+    ///
+    /// Keywords differing only in case are watched once; wildcard rules are
+    /// skipped.
+    #[must_use]
+    pub fn from_rules(rules: &[AlertRule], config: BurstConfig) -> Self {
+        let mut keywords: Vec<String> = Vec::new();
+        for rule in rules.iter().filter(|r| r.enabled) {
+            let AlertCondition::KeywordSpike { keyword, .. } = &rule.condition else {
+                continue;
+            };
+            let keyword = keyword.trim();
+            let lower = keyword.to_lowercase();
+            if keyword == WILDCARD || keywords.iter().any(|k| k.to_lowercase() == lower) {
+                continue;
+            }
+            keywords.push(keyword.to_string());
+        }
+
+        Self {
+            detector: BurstDetector::new(config),
+            keywords,
+            counts: HashMap::new(),
+            links: HashMap::new(),
+        }
+    }
+
+    /// Check whether no keyword is watched
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty()
+    }
+
+    /// Get the watched keywords
+    #[must_use]
+    pub fn keywords(&self) -> &[String] {
+        &self.keywords
+    }
+
+    /// Load the baseline of single-word keywords from a time series
+    ///
+    /// Reads the `keyword` metric for the detector's baseline window before
+    /// `now`. Call it once before observing articles, since articles already
+    /// in the series would otherwise be counted twice.
+    pub fn seed(&mut self, store: &TimeSeriesStore, now: DateTime<Utc>) -> anyhow::Result<()> {
+        let tokenizer = TextTokenizer::new();
+        let from = self.window_start(now);
+        let to = hour_bucket(now) + Duration::hours(1);
+
+        for keyword in &self.keywords {
+            let tokens = tokenizer.tokenize(keyword);
+            let [token] = tokens.as_slice() else {
+                continue;
+            };
+            let counts = self.counts.entry(keyword.clone()).or_default();
+            for point in store.query(METRIC_KEYWORD, &[("keyword", token)], from, to)? {
+                *counts.entry(point.bucket).or_insert(0) += point.value.max(0.0).round() as u64;
+            }
+        }
+        Ok(())
+    }
+
+    /// Count an article for every watched keyword in its title or body
+    ///
+    /// Matching ignores case, so "삼성전자가" counts for "삼성전자". Returns
+    /// whether any keyword matched.
+    pub fn observe(&mut self, article: &ParsedArticle) -> bool {
+        let text = format!("{} {}", article.title, article.content).to_lowercase();
+        let published_at = article.published_at.unwrap_or(article.crawled_at);
+        let bucket = hour_bucket(published_at);
+
+        let mut matched = false;
+        for keyword in &self.keywords {
+            if !text.contains(&keyword.to_lowercase()) {
+                continue;
+            }
+            matched = true;
+            *self
+                .counts
+                .entry(keyword.clone())
+                .or_default()
+                .entry(bucket)
+                .or_insert(0) += 1;
+
+            let links = self
+                .links
+                .entry(keyword.clone())
+                .or_default()
+                .entry(bucket)
+                .or_default();
+            links.push(ArticleLink {
+                title: article.title.clone(),
+                url: article.url.clone(),
+                published_at,
+            });
+            if links.len() > MAX_KEPT_LINKS {
+                links.remove(0);
+            }
+        }
+        matched
+    }
+
+    /// Detect bursts of watched keywords in the last complete hour or the
+    /// current one
+    ///
+    /// Hours older than the baseline window are dropped.
+    pub fn detect(&mut self, now: DateTime<Utc>) -> Vec<BurstEvent> {
+        let start = self.window_start(now);
+        let current = hour_bucket(now);
+        for counts in self.counts.values_mut() {
+            counts.retain(|hour, _| *hour >= start);
+        }
+        for links in self.links.values_mut() {
+            links.retain(|hour, _| *hour >= start);
+        }
+
+        let mut events = Vec::new();
+        for keyword in &self.keywords {
+            let Some(counts) = self.counts.get(keyword) else {
+                continue;
+            };
+            let mut series = Vec::new();
+            let mut hour = start;
+            while hour <= current {
+                series.push((hour, counts.get(&hour).copied().unwrap_or(0)));
+                hour += Duration::hours(1);
+            }
+
+            for spike in self.detector.detect(&series) {
+                if spike.timestamp < current - Duration::hours(1) {
+                    continue;
+                }
+                let articles: Vec<ArticleLink> = self
+                    .links
+                    .get(keyword)
+                    .and_then(|links| links.get(&spike.timestamp))
+                    .map(|links| links.iter().rev().take(MAX_ALERT_LINKS).cloned().collect())
+                    .unwrap_or_default();
+                events.push(
+                    BurstEvent::new(BurstSubject::Keyword(keyword.clone()), spike)
+                        .with_articles(articles),
+                );
+            }
+        }
+        events
+    }
+
+    fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        hour_bucket(now) - Duration::hours(self.detector.config().baseline_hours as i64)
+    }
+}
+
+/// A [`KeywordWatch`] and the manager its bursts are sent to
+///
+/// Cheap to clone; clones share the counts and the manager.
+#[derive(Clone)]
+pub struct KeywordAlerts {
+    manager: Arc<RwLock<NotificationManager>>,
+    watch: Arc<Mutex<KeywordWatch>>,
+}
+
+impl KeywordAlerts {
+    /// Create keyword alerts for the manager's rules
+    ///
+    /// Returns `None` if no rule watches a keyword.
+    #[cfg(test)]
+    async fn new(manager: Arc<RwLock<NotificationManager>>, config: BurstConfig) -> Option<Self> {
+        let watch = KeywordWatch::from_rules(manager.read().await.rules(), config);
+        if watch.is_empty() {
+            return None;
+        }
+        Some(Self {
+            manager,
+            watch: Arc::new(Mutex::new(watch)),
+        })
+    }
+
+    /// Create keyword alerts from the `[notifications]` section
+    ///
+    /// Returns `Ok(None)` if no rule watches a keyword.
+    pub fn from_config(config: &NotificationsConfig) -> Result<Option<Self>, String> {
+        let manager = NotificationManager::from_config(config)?;
+        let watch = KeywordWatch::from_rules(manager.rules(), config.keyword_burst.clone());
+        if watch.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            manager: Arc::new(RwLock::new(manager)),
+            watch: Arc::new(Mutex::new(watch)),
+        }))
+    }
+
+    /// Get the manager alerts are sent through
+    #[must_use]
+    pub fn manager(&self) -> &Arc<RwLock<NotificationManager>> {
+        &self.manager
+    }
+
+    /// Get the watched keywords
+    #[must_use]
+    pub fn keywords(&self) -> Vec<String> {
+        lock(&self.watch).keywords().to_vec()
+    }
+
+    /// Load the baseline from a time series (see [`KeywordWatch::seed`])
+    pub fn seed(&self, store: &TimeSeriesStore, now: DateTime<Utc>) -> anyhow::Result<()> {
+        lock(&self.watch).seed(store, now)
+    }
+
+    /// Get the shared keyword counts
+    #[cfg(test)]
+    fn watch(&self) -> &Arc<Mutex<KeywordWatch>> {
+        &self.watch
+    }
+
+    /// Count a stored article (see [`KeywordWatch::observe`])
+    pub fn observe(&self, article: &ParsedArticle) {
+        lock(&self.watch).observe(article);
+    }
+
+    /// Send keyword bursts up to `now` to the matching rules
+    ///
+    /// Returns the alerts that were raised; repeats within the dedup window
+    /// are counted on the existing alert instead.
+    pub async fn evaluate(&self, now: DateTime<Utc>) -> Result<Vec<Alert>, String> {
+        let events = lock(&self.watch).detect(now);
+        if events.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        tracing::debug!(bursts = events.len(), "Evaluating keyword bursts");
+        // Deliveries go out after the manager lock is released
+        let (alerts, outbox) = self.manager.write().await.queue_bursts(&events)?;
+        outbox.send().await;
+        Ok(alerts)
+    }
+
+    /// Send keyword bursts in the articles counted so far to the alert rules
+    ///
+    /// For crawl loops: failures are logged rather than returned.
+    pub async fn evaluate_now(&self) {
+        match self.evaluate(Utc::now()).await {
+            Ok(fired) if !fired.is_empty() => {
+                tracing::info!(count = fired.len(), "Raised keyword alerts");
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(error = %e, "Keyword alert evaluation failed"),
+        }
+    }
+}
+
+fn lock(watch: &Mutex<KeywordWatch>) -> std::sync::MutexGuard<'_, KeywordWatch> {
+    watch.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::AlertSeverity;
+
+    fn rule(name: &str, keyword: &str) -> AlertRule {
+        AlertRule::new(
+            name,
+            AlertCondition::KeywordSpike {
+                keyword: keyword.to_string(),
+                threshold: 5,
+                window_minutes: 60,
+            },
+            AlertSeverity::Warning,
+        )
+    }
+
+    fn article(n: usize, title: &str, published_at: DateTime<Utc>) -> ParsedArticle {
+        ParsedArticle {
+            oid: "001".to_string(),
+            aid: format!("{n:010}"),
+            title: title.to_string(),
+            url: format!("https://n.news.naver.com/mnews/article/001/{n:010}"),
+            published_at: Some(published_at),
+            ..Default::default()
+        }
+    }
+
+    /// Observe `counts[i]` articles mentioning the keyword in hour `i`,
+    /// ending with the current hour
+    fn observe_hours(watch: &mut KeywordWatch, counts: &[usize], now: DateTime<Utc>) {
+        let first = hour_bucket(now) - Duration::hours(counts.len() as i64 - 1);
+        let mut n = 0;
+        for (i, count) in counts.iter().enumerate() {
+            for _ in 0..*count {
+                n += 1;
+                let hour = first + Duration::hours(i as i64);
+                watch.observe(&article(n, &format!("삼성전자가 발표 {n}"), hour));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_rules_collects_keywords() {
+        let mut disabled = rule("disabled", "현대차");
+        disabled.enabled = false;
+        let rules = vec![
+            rule("samsung", "삼성전자"),
+            rule("any", WILDCARD),
+            rule("dup", "삼성전자"),
+            disabled,
+            AlertRule::new(
+                "slots",
+                AlertCondition::ZeroArticleSlots {
+                    consecutive_slots: 1,
+                },
+                AlertSeverity::Info,
+            ),
+        ];
+
+        let watch = KeywordWatch::from_rules(&rules, BurstConfig::default());
+        assert_eq!(watch.keywords(), ["삼성전자"]);
+        assert!(KeywordWatch::from_rules(&rules[1..2], BurstConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_detect_links_burst_articles() {
+        let now = Utc::now();
+        let mut watch =
+            KeywordWatch::from_rules(&[rule("samsung", "삼성전자")], BurstConfig::default());
+        observe_hours(&mut watch, &[1, 0, 2, 1, 0, 1, 12], now);
+        assert!(!watch.observe(&article(99, "날씨", now)));
+
+        let events = watch.detect(now);
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.subject, BurstSubject::Keyword("삼성전자".to_string()));
+        assert_eq!(event.spike.count, 12);
+        assert_eq!(event.spike.timestamp, hour_bucket(now));
+        assert_eq!(event.articles.len(), MAX_ALERT_LINKS);
+        assert!(event.articles[0].title.ends_with("17"));
+        assert!(event.message().contains(&event.articles[0].url));
+    }
+
+    #[test]
+    fn test_detect_ignores_old_bursts() {
+        let now = Utc::now();
+        let mut watch =
+            KeywordWatch::from_rules(&[rule("samsung", "삼성전자")], BurstConfig::default());
+        observe_hours(&mut watch, &[1, 0, 1, 1, 15, 1, 0, 1], now);
+
+        assert!(watch.detect(now).is_empty());
+    }
+
+    #[test]
+    fn test_seed_from_timeseries() {
+        let now = Utc::now();
+        let mut store = TimeSeriesStore::open_in_memory().unwrap();
+        for hours_ago in 1..=6 {
+            let samples = crate::storage::article_samples(
+                now - Duration::hours(hours_ago),
+                "economy",
+                None,
+                "삼성전자 실적",
+                "",
+            );
+            store
+                .record_article(&format!("001_{hours_ago}"), &samples)
+                .unwrap();
+        }
+
+        let mut watch =
+            KeywordWatch::from_rules(&[rule("samsung", "삼성전자")], BurstConfig::default());
+        watch.seed(&store, now).unwrap();
+        observe_hours(&mut watch, &[10], now);
+
+        // Six seeded mentions over the 24-hour baseline
+        let events = watch.detect(now);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].spike.expected, 0.25);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_fires_rule_with_links() {
+        let mut manager = NotificationManager::new();
+        manager.add_rule(rule("samsung", "삼성전자")).unwrap();
+        let manager = Arc::new(RwLock::new(manager));
+
+        let alerts = KeywordAlerts::new(manager.clone(), BurstConfig::default())
+            .await
+            .unwrap();
+        let now = Utc::now();
+        observe_hours(
+            &mut alerts.watch().lock().unwrap(),
+            &[1, 0, 2, 1, 0, 1, 12],
+            now,
+        );
+
+        let fired = alerts.evaluate(now).await.unwrap();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].metadata["rule"], "samsung");
+        assert!(fired[0].metadata["articles"].contains("n.news.naver.com"));
+
+        // The same burst is deduplicated on the next run
+        assert!(alerts.evaluate(now).await.unwrap().is_empty());
+        assert_eq!(manager.read().await.active_alerts().len(), 1);
+
+        let empty = Arc::new(RwLock::new(NotificationManager::new()));
+        assert!(KeywordAlerts::new(empty, BurstConfig::default())
+            .await
+            .is_none());
+        assert!(KeywordAlerts::from_config(&NotificationsConfig::default())
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,23 +1,123 @@
 //! Notification manager for alert orchestration
 
-use super::channels::Channel;
+use super::channels::discord::{DiscordChannel, DiscordConfig};
+use super::channels::slack::{SlackChannel, SlackConfig};
+use super::channels::webhook::WebhookChannel;
+use super::channels::{Channel, DeliveryStatus};
+use super::digest::{Digest, DigestConfig};
+use super::escalation::EscalationPolicy;
+use super::rules::{AlertRule, ChannelConfig, NotificationsConfig};
+use super::template::TemplatedChannel;
 use super::{Alert, AlertCondition, AlertSeverity, AlertStatus};
+use crate::analytics::volume::DEFAULT_VOLUME_WINDOW_HOURS;
+use crate::analytics::{BurstEvent, CategoryVolume};
 use chrono::{DateTime, Duration, Utc};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Metadata key linking an alert to the rule that raised it
+const RULE_METADATA_KEY: &str = "rule";
+
+/// Metadata key naming the last escalation policy applied to an alert
+const ESCALATION_METADATA_KEY: &str = "escalation";
+
+/// Default interval between escalation evaluations (seconds)
+const DEFAULT_ESCALATION_INTERVAL_SECS: u64 = 60;
+
+/// Default interval between volume anomaly evaluations (seconds)
+const DEFAULT_VOLUME_INTERVAL_SECS: u64 = 3600;
+
+/// Channel shared between the manager and the [`Outbox`]es it fills
+type SharedChannel = Arc<dyn Channel + Send + Sync>;
+
+/// A channel registered under a name that rules can target
+struct NamedChannel {
+    name: String,
+    channel: SharedChannel,
+}
+
+/// Notifications decided under the manager's lock, sent after releasing it
+///
+/// Webhook calls can take seconds, and holding the lock across them would
+/// stall every other user of a shared manager. The `queue_*` and
+/// [`NotificationManager::take_digests`] methods only update the manager and
+/// collect what to send; [`Outbox::send`] delivers it.
+#[derive(Default)]
+#[must_use = "notifications are only delivered by Outbox::send"]
+pub struct Outbox {
+    alerts: Vec<(SharedChannel, Alert)>,
+    digests: Vec<(SharedChannel, Digest)>,
+}
+
+impl Outbox {
+    /// Whether there is nothing to send
+    pub fn is_empty(&self) -> bool {
+        self.alerts.is_empty() && self.digests.is_empty()
+    }
+
+    /// Send the collected alerts and digests
+    ///
+    /// Failures are logged. Returns the number of digests sent.
+    pub async fn send(self) -> usize {
+        for (channel, alert) in &self.alerts {
+            if let Err(e) = channel.send(alert).await {
+                tracing::error!("Failed to send alert to channel: {}", e);
+            }
+        }
+
+        let mut sent = 0;
+        for (channel, digest) in &self.digests {
+            match channel.send_digest(digest).await {
+                Ok(_) => sent += 1,
+                Err(e) => tracing::error!("Failed to send digest to channel: {}", e),
+            }
+        }
+        sent
+    }
+}
+
+/// Dedup window opened by the first firing of a condition
+struct DedupWindow {
+    /// Alert that collects repeated firings
+    alert_id: String,
+    /// When the window opened
+    opened_at: DateTime<Utc>,
+}
 
 /// Notification manager that coordinates alerts and channels
 #[derive(Default)]
 pub struct NotificationManager {
     /// Registered notification channels
-    channels: Vec<Box<dyn Channel + Send + Sync>>,
+    channels: Vec<NamedChannel>,
+
+    /// Alert rules loaded from configuration
+    rules: Vec<AlertRule>,
+
+    /// Escalation policies for unacknowledged alerts
+    escalations: Vec<EscalationPolicy>,
+
+    /// Interval between escalation evaluations (seconds)
+    escalation_interval_secs: u64,
+
+    /// Interval between volume anomaly evaluations (seconds)
+    volume_interval_secs: u64,
+
+    /// Hours of history in the rolling volume baseline
+    volume_window_hours: usize,
+
+    /// Digest batching for Info alerts
+    digest: DigestConfig,
+
+    /// Alerts waiting for the next digest, by channel name
+    digest_queues: HashMap<String, Vec<Alert>>,
 
     /// Active alerts by ID
     alerts: HashMap<String, Alert>,
 
-    /// Deduplication: last trigger time per condition key
-    last_triggered: HashMap<String, DateTime<Utc>>,
+    /// Deduplication: open window per condition key
+    dedup_windows: HashMap<String, DedupWindow>,
 
-    /// Minimum time between duplicate alerts (minutes)
+    /// Window in which repeats of a condition are counted on one alert (minutes)
     dedup_window_minutes: i64,
 }
 
@@ -26,43 +126,208 @@ impl NotificationManager {
     pub fn new() -> Self {
         Self {
             channels: Vec::new(),
+            rules: Vec::new(),
+            escalations: Vec::new(),
+            escalation_interval_secs: DEFAULT_ESCALATION_INTERVAL_SECS,
+            volume_interval_secs: DEFAULT_VOLUME_INTERVAL_SECS,
+            volume_window_hours: DEFAULT_VOLUME_WINDOW_HOURS,
+            digest: DigestConfig::default(),
+            digest_queues: HashMap::new(),
             alerts: HashMap::new(),
-            last_triggered: HashMap::new(),
+            dedup_windows: HashMap::new(),
             dedup_window_minutes: 30,
         }
     }
 
+    /// Build a manager from the `[notifications]` configuration section
+    ///
+    /// Every declared channel is created under its configured name and every
+    /// rule is kept for [`fire_rule`](Self::fire_rule).
+    pub fn from_config(config: &NotificationsConfig) -> Result<Self, String> {
+        config.validate()?;
+
+        let mut manager = Self::new()
+            .with_dedup_window(config.dedup_window_minutes)
+            .with_digest(config.digest.clone());
+        manager.escalation_interval_secs = config.escalation_interval_secs;
+        manager.volume_interval_secs = config.volume_interval_secs;
+        manager.volume_window_hours = config.volume_window_hours;
+
+        for channel_config in &config.channels {
+            let channel: Box<dyn Channel + Send + Sync> = match channel_config {
+                ChannelConfig::Webhook { config, .. } => {
+                    Box::new(WebhookChannel::new(config.clone()).map_err(|e| e.to_string())?)
+                }
+                ChannelConfig::Slack { config, .. } => {
+                    Box::new(SlackChannel::new(config.clone()).map_err(|e| e.to_string())?)
+                }
+                ChannelConfig::Discord { config, .. } => {
+                    Box::new(DiscordChannel::new(config.clone()).map_err(|e| e.to_string())?)
+                }
+            };
+            let channel: Box<dyn Channel + Send + Sync> = match channel_config.message_template()? {
+                Some(template) => Box::new(TemplatedChannel::new(channel, template)),
+                None => channel,
+            };
+            manager.add_named_channel(channel_config.name(), channel);
+        }
+
+        for rule in &config.rules {
+            manager.add_rule(rule.clone())?;
+        }
+
+        for policy in &config.escalations {
+            manager.add_escalation_policy(policy.clone())?;
+        }
+
+        Ok(manager)
+    }
+
     /// Set deduplication window in minutes
     pub fn with_dedup_window(mut self, minutes: i64) -> Self {
         self.dedup_window_minutes = minutes;
         self
     }
 
-    /// Add a notification channel
+    /// Batch Info alerts into periodic digests
+    pub fn with_digest(mut self, digest: DigestConfig) -> Self {
+        self.digest = digest;
+        self
+    }
+
+    /// Get the digest configuration
+    pub fn digest_config(&self) -> &DigestConfig {
+        &self.digest
+    }
+
+    /// Add a notification channel, named after its channel type
     pub fn add_channel(&mut self, channel: Box<dyn Channel + Send + Sync>) {
-        self.channels.push(channel);
+        let name = channel.name().to_string();
+        self.add_named_channel(name, channel);
+    }
+
+    /// Add a notification channel under a name that rules can target
+    pub fn add_named_channel(
+        &mut self,
+        name: impl Into<String>,
+        channel: Box<dyn Channel + Send + Sync>,
+    ) {
+        self.channels.push(NamedChannel {
+            name: name.into(),
+            channel: Arc::from(channel),
+        });
     }
 
     /// Add a webhook channel with URL
     pub fn add_webhook_channel(&mut self, url: &str) -> Result<(), String> {
-        let channel =
-            super::channels::webhook::WebhookChannel::from_url(url).map_err(|e| e.to_string())?;
+        let channel = WebhookChannel::from_url(url).map_err(|e| e.to_string())?;
         self.add_channel(Box::new(channel));
         Ok(())
     }
 
-    /// Check if an alert should be deduplicated
-    fn should_deduplicate(&self, condition_key: &str) -> bool {
-        if let Some(&last_time) = self.last_triggered.get(condition_key) {
-            let now = Utc::now();
-            let elapsed = now - last_time;
-            elapsed < Duration::minutes(self.dedup_window_minutes)
-        } else {
-            false
+    /// Add a Slack channel
+    ///
+    /// With a bot token, every update for the same alert is posted in one thread.
+    pub fn add_slack_channel(&mut self, config: SlackConfig) -> Result<(), String> {
+        let channel = SlackChannel::new(config).map_err(|e| e.to_string())?;
+        self.add_channel(Box::new(channel));
+        Ok(())
+    }
+
+    /// Add a Discord webhook channel
+    pub fn add_discord_channel(&mut self, config: DiscordConfig) -> Result<(), String> {
+        let channel = DiscordChannel::new(config).map_err(|e| e.to_string())?;
+        self.add_channel(Box::new(channel));
+        Ok(())
+    }
+
+    /// Add an alert rule
+    pub fn add_rule(&mut self, rule: AlertRule) -> Result<(), String> {
+        rule.validate()?;
+        if self.rules.iter().any(|r| r.name == rule.name) {
+            return Err(format!("Duplicate rule name: {}", rule.name));
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Replace all alert rules, e.g. after a config reload
+    ///
+    /// The current rules are kept if any new rule is invalid.
+    pub fn set_rules(&mut self, rules: Vec<AlertRule>) -> Result<(), String> {
+        let mut names = HashSet::new();
+        for rule in &rules {
+            rule.validate()?;
+            if !names.insert(rule.name.as_str()) {
+                return Err(format!("Duplicate rule name: {}", rule.name));
+            }
+        }
+        self.rules = rules;
+        Ok(())
+    }
+
+    /// Get the configured alert rules
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    /// Get a rule by name
+    pub fn get_rule(&self, name: &str) -> Option<&AlertRule> {
+        self.rules.iter().find(|r| r.name == name)
+    }
+
+    /// Add an escalation policy
+    pub fn add_escalation_policy(&mut self, policy: EscalationPolicy) -> Result<(), String> {
+        policy.validate()?;
+        if self.escalations.iter().any(|p| p.name == policy.name) {
+            return Err(format!("Duplicate escalation policy name: {}", policy.name));
         }
+        self.escalations.push(policy);
+        Ok(())
+    }
+
+    /// Get the configured escalation policies
+    pub fn escalation_policies(&self) -> &[EscalationPolicy] {
+        &self.escalations
+    }
+
+    /// Get the interval between escalation evaluations
+    pub fn escalation_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.escalation_interval_secs)
+    }
+
+    /// Get the interval between volume anomaly evaluations
+    pub fn volume_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.volume_interval_secs)
+    }
+
+    /// Get the hours of history in the rolling volume baseline
+    pub fn volume_window_hours(&self) -> usize {
+        self.volume_window_hours
+    }
+
+    /// Get the names of registered channels
+    pub fn channel_names(&self) -> Vec<&str> {
+        self.channels.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Get the alert collecting repeats of a condition, if its window is still open
+    fn open_window_alert(&mut self, condition_key: &str) -> Option<&mut Alert> {
+        let window = self.dedup_windows.get(condition_key)?;
+        if Utc::now() - window.opened_at >= Duration::minutes(self.dedup_window_minutes) {
+            return None;
+        }
+
+        self.alerts
+            .get_mut(&window.alert_id)
+            .filter(|alert| alert.status != AlertStatus::Resolved)
     }
 
     /// Create and optionally trigger an alert
+    ///
+    /// If the same condition already raised an unresolved alert within the
+    /// dedup window, that alert's occurrence counter is bumped instead and
+    /// `None` is returned, so channels are not notified again.
     pub fn create_alert(
         &mut self,
         condition: AlertCondition,
@@ -72,21 +337,41 @@ impl NotificationManager {
         let condition_key = format!("{}:{}", condition.condition_type(), condition.description());
 
         // Check deduplication
-        if self.should_deduplicate(&condition_key) {
+        if let Some(existing) = self.open_window_alert(&condition_key) {
+            existing.record_occurrence();
+            tracing::debug!(
+                alert_id = %existing.id,
+                occurrences = existing.occurrence_count,
+                "Deduplicated repeated alert"
+            );
             return None;
         }
 
         let alert = Alert::new(condition, severity, message);
         let alert_id = alert.id.clone();
 
-        self.alerts.insert(alert_id, alert.clone());
-        self.last_triggered.insert(condition_key, Utc::now());
+        self.alerts.insert(alert_id.clone(), alert.clone());
+        self.dedup_windows.insert(
+            condition_key,
+            DedupWindow {
+                alert_id,
+                opened_at: Utc::now(),
+            },
+        );
 
         Some(alert)
     }
 
     /// Trigger an existing alert and send notifications
     pub async fn trigger_alert(&mut self, alert_id: &str) -> Result<(), String> {
+        let mut outbox = Outbox::default();
+        self.queue_trigger(alert_id, &mut outbox)?;
+        outbox.send().await;
+        Ok(())
+    }
+
+    /// Trigger an existing alert, adding its notifications to `outbox`
+    fn queue_trigger(&mut self, alert_id: &str, outbox: &mut Outbox) -> Result<(), String> {
         let alert = self
             .alerts
             .get_mut(alert_id)
@@ -94,16 +379,27 @@ impl NotificationManager {
 
         alert.trigger();
 
-        // Send to all channels
-        let errors: Vec<String> = Vec::new();
-        for channel in &self.channels {
-            if let Err(e) = channel.send(alert).await {
-                tracing::error!("Failed to send alert to channel: {}", e);
-            }
-        }
+        // Alerts raised by a rule only go to the rule's channels
+        let rule = alert
+            .metadata
+            .get(RULE_METADATA_KEY)
+            .and_then(|name| self.rules.iter().find(|r| &r.name == name));
 
-        if !errors.is_empty() {
-            return Err(errors.join("; "));
+        // Info alerts wait for the next digest when digest mode is on
+        let batch = self.digest.should_batch(alert);
+
+        for named in &self.channels {
+            if rule.is_some_and(|r| !r.targets(&named.name)) {
+                continue;
+            }
+            if batch {
+                self.digest_queues
+                    .entry(named.name.clone())
+                    .or_default()
+                    .push(alert.clone());
+                continue;
+            }
+            outbox.alerts.push((named.channel.clone(), alert.clone()));
         }
 
         Ok(())
@@ -125,6 +421,287 @@ impl NotificationManager {
         }
     }
 
+    /// Create and trigger an alert for a configured rule
+    ///
+    /// Returns `Ok(None)` when the rule is disabled or the alert was deduplicated.
+    pub async fn fire_rule(
+        &mut self,
+        rule_name: &str,
+        message: String,
+    ) -> Result<Option<Alert>, String> {
+        let rule = self
+            .get_rule(rule_name)
+            .ok_or_else(|| format!("Rule not found: {}", rule_name))?;
+
+        if !rule.enabled {
+            return Ok(None);
+        }
+
+        let (condition, severity) = (rule.condition.clone(), rule.severity);
+        let mut outbox = Outbox::default();
+        let alert = self.queue_fire(
+            rule_name,
+            condition,
+            severity,
+            message,
+            Vec::new(),
+            &mut outbox,
+        )?;
+        outbox.send().await;
+        Ok(alert)
+    }
+
+    /// Send an alert straight to channels, bypassing dedup and digests
+    ///
+    /// Used for scheduled content such as reports. `channels` selects channels
+    /// by name (empty = all channels).
+    pub async fn deliver(&self, alert: &Alert, channels: &[String]) -> Vec<DeliveryStatus> {
+        let mut statuses = Vec::new();
+
+        for named in &self.channels {
+            if !channels.is_empty() && !channels.contains(&named.name) {
+                continue;
+            }
+            let status = match named.channel.send(alert).await {
+                Ok(status) => status,
+                Err(e) => {
+                    tracing::error!(channel = %named.name, error = %e, "Failed to deliver alert");
+                    DeliveryStatus::failure(named.name.clone(), e.to_string())
+                }
+            };
+            statuses.push(status);
+        }
+
+        statuses
+    }
+
+    /// Fire every enabled rule matched by a detected burst
+    ///
+    /// `KeywordSpike` rules match keyword bursts and `EntitySurge` rules match
+    /// entity bursts (see [`BurstEvent::matched_condition`]). A rule using
+    /// `"*"` as its keyword or entity fires once per bursting name. Returns
+    /// the alerts that were raised (deduplicated ones are skipped).
+    pub async fn process_bursts(&mut self, events: &[BurstEvent]) -> Result<Vec<Alert>, String> {
+        let (fired, outbox) = self.queue_bursts(events)?;
+        outbox.send().await;
+        Ok(fired)
+    }
+
+    /// [`NotificationManager::process_bursts`], leaving the notifications in
+    /// an [`Outbox`] to send once the manager is unlocked
+    pub fn queue_bursts(&mut self, events: &[BurstEvent]) -> Result<(Vec<Alert>, Outbox), String> {
+        let mut fired = Vec::new();
+        let mut outbox = Outbox::default();
+
+        for event in events {
+            let matches: Vec<_> = self
+                .rules
+                .iter()
+                .filter(|rule| rule.enabled)
+                .filter_map(|rule| {
+                    event
+                        .matched_condition(&rule.condition)
+                        .map(|condition| (rule.name.clone(), condition, rule.severity))
+                })
+                .collect();
+
+            for (rule_name, condition, severity) in matches {
+                if let Some(alert) = self.queue_fire(
+                    &rule_name,
+                    condition,
+                    severity,
+                    event.message(),
+                    event.metadata(),
+                    &mut outbox,
+                )? {
+                    fired.push(alert);
+                }
+            }
+        }
+
+        Ok((fired, outbox))
+    }
+
+    /// Fire every enabled rule matched by an anomalous category volume
+    ///
+    /// `VolumeAnomaly` rules match volumes of their category (or any category
+    /// with `"*"`) deviating at least `threshold_stddev` from the rolling mean
+    /// (see [`CategoryVolume::matched_condition`]). Returns the alerts that
+    /// were raised (deduplicated ones are skipped).
+    pub async fn process_volume_anomalies(
+        &mut self,
+        volumes: &[CategoryVolume],
+    ) -> Result<Vec<Alert>, String> {
+        let (fired, outbox) = self.queue_volume_anomalies(volumes)?;
+        outbox.send().await;
+        Ok(fired)
+    }
+
+    /// [`NotificationManager::process_volume_anomalies`], leaving the
+    /// notifications in an [`Outbox`] to send once the manager is unlocked
+    pub fn queue_volume_anomalies(
+        &mut self,
+        volumes: &[CategoryVolume],
+    ) -> Result<(Vec<Alert>, Outbox), String> {
+        let mut fired = Vec::new();
+        let mut outbox = Outbox::default();
+
+        for volume in volumes {
+            let matches: Vec<_> = self
+                .rules
+                .iter()
+                .filter(|rule| rule.enabled)
+                .filter_map(|rule| {
+                    volume
+                        .matched_condition(&rule.condition)
+                        .map(|condition| (rule.name.clone(), condition, rule.severity))
+                })
+                .collect();
+
+            for (rule_name, condition, severity) in matches {
+                if let Some(alert) = self.queue_fire(
+                    &rule_name,
+                    condition,
+                    severity,
+                    volume.message(),
+                    volume.metadata(),
+                    &mut outbox,
+                )? {
+                    fired.push(alert);
+                }
+            }
+        }
+
+        Ok((fired, outbox))
+    }
+
+    /// Check whether any enabled rule watches category volume
+    pub fn has_volume_rules(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|r| r.enabled && matches!(r.condition, AlertCondition::VolumeAnomaly { .. }))
+    }
+
+    /// Create, tag and trigger an alert on behalf of a rule
+    fn queue_fire(
+        &mut self,
+        rule_name: &str,
+        condition: AlertCondition,
+        severity: AlertSeverity,
+        message: String,
+        metadata: Vec<(String, String)>,
+        outbox: &mut Outbox,
+    ) -> Result<Option<Alert>, String> {
+        let Some(alert) = self.create_alert(condition, severity, message) else {
+            return Ok(None);
+        };
+
+        let alert_id = alert.id.clone();
+        if let Some(stored) = self.alerts.get_mut(&alert_id) {
+            stored
+                .metadata
+                .insert(RULE_METADATA_KEY.to_string(), rule_name.to_string());
+            stored.metadata.extend(metadata);
+        }
+
+        self.queue_trigger(&alert_id, outbox)?;
+        Ok(self.alerts.get(&alert_id).cloned())
+    }
+
+    /// Escalate triggered alerts that stayed unacknowledged past a policy's limit
+    ///
+    /// Each policy is applied at most once per alert, and at most one policy
+    /// per alert per evaluation, so Warning → Critical → ... chains advance one
+    /// step at a time. Returns the IDs of escalated alerts.
+    pub async fn evaluate_escalations(&mut self) -> Vec<String> {
+        let (escalated, outbox) = self.queue_escalations();
+        outbox.send().await;
+        escalated
+    }
+
+    /// [`NotificationManager::evaluate_escalations`], leaving the
+    /// re-notifications in an [`Outbox`] to send once the manager is unlocked
+    pub fn queue_escalations(&mut self) -> (Vec<String>, Outbox) {
+        let now = Utc::now();
+        let mut escalated = Vec::new();
+        let mut outbox = Outbox::default();
+
+        for alert in self.alerts.values_mut() {
+            let Some(triggered_at) = alert.triggered_at.filter(|_| alert.is_active()) else {
+                continue;
+            };
+            let age = now - triggered_at;
+
+            let Some(policy) = self.escalations.iter().find(|p| {
+                p.severity == alert.severity
+                    && !alert.is_escalated_by(&p.name)
+                    && age >= Duration::minutes(p.after_minutes.into())
+            }) else {
+                continue;
+            };
+
+            alert.escalate(&policy.name, policy.escalate_to);
+            alert
+                .metadata
+                .insert(ESCALATION_METADATA_KEY.to_string(), policy.name.clone());
+
+            tracing::warn!(
+                alert_id = %alert.id,
+                policy = %policy.name,
+                severity = alert.severity.as_str(),
+                "Escalating unacknowledged alert"
+            );
+
+            for named in &self.channels {
+                if policy.targets(&named.name) {
+                    outbox.alerts.push((named.channel.clone(), alert.clone()));
+                }
+            }
+
+            escalated.push(alert.id.clone());
+        }
+
+        (escalated, outbox)
+    }
+
+    /// Number of alerts waiting for the next digest, across channels
+    pub fn pending_digest_alerts(&self) -> usize {
+        self.digest_queues.values().map(Vec::len).sum()
+    }
+
+    /// Send one digest per channel with queued alerts and clear the queues
+    ///
+    /// Returns the number of digests sent.
+    pub async fn flush_digests(&mut self) -> usize {
+        self.take_digests().send().await
+    }
+
+    /// Build one digest per channel with queued alerts and clear the queues,
+    /// leaving the digests in an [`Outbox`] to send once the manager is
+    /// unlocked
+    pub fn take_digests(&mut self) -> Outbox {
+        let mut outbox = Outbox::default();
+
+        for named in &self.channels {
+            let Some(queued) = self.digest_queues.remove(&named.name) else {
+                continue;
+            };
+            if queued.is_empty() {
+                continue;
+            }
+
+            // Pick up repeats counted after the alert was queued
+            let alerts = queued
+                .into_iter()
+                .map(|alert| self.alerts.get(&alert.id).cloned().unwrap_or(alert))
+                .collect();
+            let digest = Digest::new(&named.name, alerts);
+            outbox.digests.push((named.channel.clone(), digest));
+        }
+
+        outbox
+    }
+
     /// Acknowledge an alert
     pub fn acknowledge_alert(&mut self, alert_id: &str, by: String) -> Result<(), String> {
         let alert = self
@@ -147,6 +724,17 @@ impl NotificationManager {
         Ok(())
     }
 
+    /// Resolve an alert on behalf of an operator
+    pub fn resolve_alert_by(&mut self, alert_id: &str, by: String) -> Result<(), String> {
+        let alert = self
+            .alerts
+            .get_mut(alert_id)
+            .ok_or_else(|| format!("Alert not found: {}", alert_id))?;
+
+        alert.resolve_by(by);
+        Ok(())
+    }
+
     /// Get all active alerts
     pub fn active_alerts(&self) -> Vec<&Alert> {
         self.alerts
@@ -173,6 +761,10 @@ impl NotificationManager {
             Some(resolved) => resolved > cutoff,
             None => true,
         });
+
+        let alerts = &self.alerts;
+        self.dedup_windows
+            .retain(|_, window| alerts.contains_key(&window.alert_id));
     }
 }
 
@@ -187,6 +779,427 @@ mod tests {
         assert!(manager.channels.is_empty());
     }
 
+    #[test]
+    fn test_add_slack_channel() {
+        let mut manager = NotificationManager::new();
+
+        assert!(manager
+            .add_slack_channel(SlackConfig::webhook(
+                "https://hooks.slack.com/services/T/B/X"
+            ))
+            .is_ok());
+        assert!(manager
+            .add_slack_channel(SlackConfig::bot("xoxb-token", ""))
+            .is_err());
+        assert_eq!(manager.channels.len(), 1);
+        assert_eq!(manager.channels[0].channel.name(), "slack");
+    }
+
+    #[test]
+    fn test_add_discord_channel() {
+        let mut manager = NotificationManager::new();
+
+        assert!(manager
+            .add_discord_channel(DiscordConfig::new("https://discord.com/api/webhooks/1/abc"))
+            .is_ok());
+        assert!(manager
+            .add_discord_channel(DiscordConfig::new("not-a-url"))
+            .is_err());
+        assert_eq!(manager.channels.len(), 1);
+        assert_eq!(manager.channels[0].channel.name(), "discord");
+    }
+
+    fn rules_config(ops_url: &str, oncall_url: &str) -> NotificationsConfig {
+        toml::from_str(&format!(
+            r#"
+dedup_window_minutes = 10
+
+[[channels]]
+name = "ops"
+type = "webhook"
+url = "{ops_url}"
+max_retries = 0
+
+[[channels]]
+name = "oncall"
+type = "webhook"
+url = "{oncall_url}"
+max_retries = 0
+
+[[rules]]
+name = "empty-slots"
+severity = "critical"
+channels = ["oncall"]
+condition = {{ type = "zero_article_slots", consecutive_slots = 2 }}
+
+[[rules]]
+name = "paused"
+severity = "info"
+enabled = false
+condition = {{ type = "source_failure", source = "naver.com", failure_count = 5 }}
+"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = rules_config("https://ops.example.com", "https://oncall.example.com");
+        let manager = NotificationManager::from_config(&config).unwrap();
+
+        assert_eq!(manager.dedup_window_minutes, 10);
+        assert_eq!(manager.channel_names(), vec!["ops", "oncall"]);
+        assert_eq!(manager.escalation_interval().as_secs(), 60);
+        assert_eq!(manager.volume_interval().as_secs(), 3600);
+        assert_eq!(manager.volume_window_hours(), 168);
+        assert_eq!(manager.rules().len(), 2);
+        assert_eq!(
+            manager.get_rule("empty-slots").unwrap().severity,
+            AlertSeverity::Critical
+        );
+    }
+
+    #[test]
+    fn test_from_config_rejects_invalid() {
+        let mut config = rules_config("https://ops.example.com", "https://oncall.example.com");
+        config.rules[0].channels = vec!["missing".to_string()];
+
+        assert!(NotificationManager::from_config(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deliver_targets_named_channels() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let ops = MockServer::start().await;
+        let oncall = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&ops)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&oncall)
+            .await;
+
+        let config = rules_config(&ops.uri(), &oncall.uri());
+        let manager = NotificationManager::from_config(&config).unwrap();
+        let alert = Alert::new(
+            AlertCondition::Custom {
+                name: "daily_report".to_string(),
+                description: "Daily report".to_string(),
+                parameters: HashMap::new(),
+            },
+            AlertSeverity::Info,
+            "Daily report".to_string(),
+        );
+
+        // Delivered every time: no dedup window applies
+        for _ in 0..2 {
+            let statuses = manager.deliver(&alert, &["ops".to_string()]).await;
+            assert_eq!(statuses.len(), 1);
+            assert!(statuses[0].success);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fire_rule_routes_to_rule_channels() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let ops = MockServer::start().await;
+        let oncall = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&ops)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&oncall)
+            .await;
+
+        let config = rules_config(&ops.uri(), &oncall.uri());
+        let mut manager = NotificationManager::from_config(&config).unwrap();
+
+        let alert = manager
+            .fire_rule("empty-slots", "No articles since 09:00".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(alert.status, AlertStatus::Triggered);
+        assert_eq!(alert.metadata.get("rule").unwrap(), "empty-slots");
+
+        // Disabled rules never fire
+        assert!(manager
+            .fire_rule("paused", "ignored".to_string())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(manager
+            .fire_rule("unknown", "ignored".to_string())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_bursts_fires_matching_rules() {
+        use crate::analytics::{BurstSubject, Spike};
+
+        let mut manager = NotificationManager::new();
+        manager
+            .add_rule(AlertRule::new(
+                "any-keyword",
+                AlertCondition::KeywordSpike {
+                    keyword: "*".to_string(),
+                    threshold: 10,
+                    window_minutes: 60,
+                },
+                AlertSeverity::Warning,
+            ))
+            .unwrap();
+        manager
+            .add_rule(AlertRule::new(
+                "samsung",
+                AlertCondition::EntitySurge {
+                    entity: "삼성전자".to_string(),
+                    threshold: 10,
+                    window_minutes: 60,
+                },
+                AlertSeverity::Critical,
+            ))
+            .unwrap();
+
+        let burst = |subject: BurstSubject, count: u64| {
+            BurstEvent::new(
+                subject,
+                Spike {
+                    timestamp: Utc::now(),
+                    count,
+                    expected: 4.0,
+                    magnitude: count as f64 / 4.0,
+                    z_score: 5.0,
+                },
+            )
+        };
+        let events = vec![
+            burst(BurstSubject::Keyword("반도체".to_string()), 30),
+            burst(BurstSubject::Keyword("부동산".to_string()), 12),
+            burst(BurstSubject::Keyword("날씨".to_string()), 6),
+            burst(BurstSubject::Entity("삼성전자".to_string()), 20),
+            burst(BurstSubject::Entity("LG전자".to_string()), 20),
+        ];
+
+        let fired = manager.process_bursts(&events).await.unwrap();
+        assert_eq!(fired.len(), 3);
+        assert_eq!(fired[0].metadata.get("rule").unwrap(), "any-keyword");
+        assert_eq!(fired[0].metadata.get("burst_count").unwrap(), "30");
+        assert!(fired[0].condition.description().contains("반도체"));
+        assert_eq!(fired[2].severity, AlertSeverity::Critical);
+
+        // The same bursts within the dedup window do not fire again
+        assert!(manager.process_bursts(&events).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_process_volume_anomalies_fires_matching_rules() {
+        let mut manager = NotificationManager::new();
+        assert!(!manager.has_volume_rules());
+        manager
+            .add_rule(AlertRule::new(
+                "any-category",
+                AlertCondition::VolumeAnomaly {
+                    category: "*".to_string(),
+                    threshold_stddev: 3.0,
+                },
+                AlertSeverity::Warning,
+            ))
+            .unwrap();
+        assert!(manager.has_volume_rules());
+
+        let volume = |category: &str, count: u64| CategoryVolume {
+            category: category.to_string(),
+            hour: Utc::now(),
+            count,
+            mean: 16.0,
+            std_dev: 2.0,
+            history_hours: 168,
+        };
+        let volumes = vec![volume("politics", 40), volume("economy", 18)];
+
+        let fired = manager.process_volume_anomalies(&volumes).await.unwrap();
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].metadata.get("rule").unwrap(), "any-category");
+        assert_eq!(fired[0].metadata.get("volume_count").unwrap(), "40");
+        assert!(fired[0].condition.description().contains("politics"));
+
+        // Repeats within the dedup window do not fire again
+        assert!(manager
+            .process_volume_anomalies(&volumes)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_escalations() {
+        use crate::notifications::WebhookChannel;
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let ops = MockServer::start().await;
+        let oncall = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&ops)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "severity": "critical" }),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&oncall)
+            .await;
+
+        let mut manager = NotificationManager::new();
+        for (name, server) in [("ops", &ops), ("oncall", &oncall)] {
+            let channel = WebhookChannel::from_url(server.uri()).unwrap();
+            manager.add_named_channel(name, Box::new(channel));
+        }
+        manager
+            .add_escalation_policy(
+                EscalationPolicy::new("page-oncall", AlertSeverity::Warning, 30)
+                    .with_escalate_to(AlertSeverity::Critical)
+                    .with_channels(["oncall"]),
+            )
+            .unwrap();
+
+        let condition = AlertCondition::SourceFailure {
+            source: "naver.com".to_string(),
+            failure_count: 5,
+        };
+        let stale = manager
+            .create_alert(condition, AlertSeverity::Warning, "Source down".to_string())
+            .unwrap();
+        let fresh = manager
+            .create_alert(
+                AlertCondition::ZeroArticleSlots {
+                    consecutive_slots: 1,
+                },
+                AlertSeverity::Warning,
+                "Empty slot".to_string(),
+            )
+            .unwrap();
+        let acked = manager
+            .create_alert(
+                AlertCondition::ZeroArticleSlots {
+                    consecutive_slots: 3,
+                },
+                AlertSeverity::Warning,
+                "Empty slots".to_string(),
+            )
+            .unwrap();
+
+        // Mark alerts as triggered without notifying, backdating two of them
+        for (id, minutes_ago) in [(&stale.id, 45), (&fresh.id, 5), (&acked.id, 45)] {
+            let alert = manager.alerts.get_mut(id).unwrap();
+            alert.trigger();
+            alert.triggered_at = Some(Utc::now() - Duration::minutes(minutes_ago));
+        }
+        manager
+            .acknowledge_alert(&acked.id, "admin".to_string())
+            .unwrap();
+
+        // Nothing is sent until the outbox is, so no lock needs to be held
+        let (escalated, outbox) = manager.queue_escalations();
+        assert_eq!(escalated, vec![stale.id.clone()]);
+        assert!(oncall.received_requests().await.unwrap().is_empty());
+        outbox.send().await;
+
+        let alert = manager.get_alert(&stale.id).unwrap();
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert!(alert.is_escalated_by("page-oncall"));
+        assert_eq!(alert.metadata.get("escalation").unwrap(), "page-oncall");
+
+        // A policy fires once per alert
+        assert!(manager.evaluate_escalations().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_digest_batches_info_alerts() {
+        use crate::notifications::{DigestInterval, WebhookChannel};
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "severity": "warning" }),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "severity": "info",
+                "metadata": { "digest_alerts": "2" }
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut manager =
+            NotificationManager::new().with_digest(DigestConfig::new(DigestInterval::Hourly));
+        manager.add_channel(Box::new(WebhookChannel::from_url(server.uri()).unwrap()));
+
+        for keyword in ["태풍", "폭염"] {
+            let condition = AlertCondition::KeywordSpike {
+                keyword: keyword.to_string(),
+                threshold: 5,
+                window_minutes: 60,
+            };
+            manager
+                .alert(
+                    condition,
+                    AlertSeverity::Info,
+                    format!("{keyword} trending"),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(manager.pending_digest_alerts(), 2);
+
+        // Warning alerts are still sent immediately
+        let condition = AlertCondition::ErrorRateThreshold {
+            threshold_percent: 10.0,
+            window_minutes: 15,
+        };
+        manager
+            .alert(condition, AlertSeverity::Warning, "Errors".to_string())
+            .await
+            .unwrap();
+        assert_eq!(manager.pending_digest_alerts(), 2);
+
+        // Taking the digests empties the queues before anything is sent
+        let outbox = manager.take_digests();
+        assert_eq!(manager.pending_digest_alerts(), 0);
+        assert!(manager.take_digests().is_empty());
+        assert_eq!(outbox.send().await, 1);
+        assert_eq!(manager.flush_digests().await, 0);
+    }
+
     #[test]
     fn test_alert_creation_and_dedup() {
         let mut manager = NotificationManager::new().with_dedup_window(5);
@@ -206,9 +1219,52 @@ mod tests {
         assert!(alert1.is_some());
 
         // Duplicate should be deduplicated
-        let alert2 =
-            manager.create_alert(condition, AlertSeverity::Warning, "Test alert".to_string());
+        let alert2 = manager.create_alert(
+            condition.clone(),
+            AlertSeverity::Warning,
+            "Test alert".to_string(),
+        );
         assert!(alert2.is_none());
+
+        // ...but counted on the original alert
+        let alert3 =
+            manager.create_alert(condition, AlertSeverity::Warning, "Test alert".to_string());
+        assert!(alert3.is_none());
+
+        let stored = manager.get_alert(&alert1.unwrap().id).unwrap();
+        assert_eq!(stored.occurrence_count, 3);
+        assert!(stored.last_occurred_at.is_some());
+        assert_eq!(manager.all_alerts().len(), 1);
+    }
+
+    #[test]
+    fn test_dedup_window_expiry_and_resolution() {
+        let condition = AlertCondition::ThroughputDrop {
+            threshold_per_minute: 5.0,
+            window_minutes: 10,
+        };
+
+        // A zero-minute window never deduplicates
+        let mut manager = NotificationManager::new().with_dedup_window(0);
+        assert!(manager
+            .create_alert(condition.clone(), AlertSeverity::Info, "a".to_string())
+            .is_some());
+        assert!(manager
+            .create_alert(condition.clone(), AlertSeverity::Info, "b".to_string())
+            .is_some());
+
+        // A resolved alert does not absorb new firings
+        let mut manager = NotificationManager::new();
+        let first = manager
+            .create_alert(condition.clone(), AlertSeverity::Info, "a".to_string())
+            .unwrap();
+        manager.resolve_alert(&first.id).unwrap();
+
+        let second = manager
+            .create_alert(condition, AlertSeverity::Info, "b".to_string())
+            .unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!(manager.get_alert(&first.id).unwrap().occurrence_count, 1);
     }
 
     #[test]
@@ -17,7 +17,7 @@
 //!         ┌───────────┼───────────┐
 //!         ▼           ▼           ▼
 //!   ┌─────────┐ ┌─────────┐ ┌─────────┐
-//!   │ Webhook │ │  Email  │ │  Slack  │
+//!   │ Webhook │ │ Discord │ │  Slack  │
 //!   │ Channel │ │ Channel │ │ Channel │
 //!   └─────────┘ └─────────┘ └─────────┘
 //! ```
@@ -25,10 +25,16 @@
 //! # Features
 //!
 //! - **Alert Conditions**: Keyword spikes, entity surges, volume anomalies
+//! - **Keyword Watch**: Bursts of rule keywords alerted while crawling, with article links
+//! - **Volume Monitoring**: Hourly per-category article counts checked against a rolling baseline
 //! - **Severity Levels**: Info, Warning, Critical
-//! - **Multiple Channels**: Webhook, Email, Slack (extensible)
+//! - **Multiple Channels**: Webhook, Slack (Block Kit, threaded per alert), Discord, extensible
 //! - **Alert Lifecycle**: Created → Triggered → Acknowledged → Resolved
-//! - **Deduplication**: Prevent alert spam with time-based deduplication
+//! - **Deduplication**: Repeats within a time window are counted on one alert instead of re-sent
+//! - **Escalation**: Unacknowledged alerts are re-sent, optionally at a higher severity
+//! - **Digests**: Info alerts can be batched into hourly or daily summaries per channel
+//! - **Templates**: Per-channel Handlebars message templates in Korean, English or Chinese
+//! - **Config-driven Rules**: Channels and alert rules declared under `[notifications]`
 //!
 //! # Example
 //!
@@ -53,7 +59,13 @@
 
 pub mod channels;
 pub mod conditions;
+pub mod digest;
+pub mod escalation;
+pub mod keywords;
 mod manager;
+pub mod rules;
+pub mod template;
+pub mod volume;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -61,13 +73,21 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 // Re-exports
+pub use channels::discord::{DiscordChannel, DiscordConfig};
+pub use channels::slack::{SlackChannel, SlackConfig};
 pub use channels::webhook::WebhookChannel;
 pub use channels::Channel;
 pub use conditions::AlertCondition;
-pub use manager::NotificationManager;
+pub use digest::{DigestConfig, DigestInterval};
+pub use escalation::EscalationPolicy;
+pub use keywords::{KeywordAlerts, KeywordWatch};
+pub use manager::{NotificationManager, Outbox};
+pub use rules::{AlertRule, ChannelConfig, NotificationsConfig};
+pub use template::{MessageTemplate, TemplatedChannel};
 
 /// Severity level of an alert
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AlertSeverity {
     /// Informational alerts for tracking purposes
     Info,
@@ -168,6 +188,25 @@ pub struct Alert {
     pub acknowledged_by: Option<String>,
     /// When the alert was resolved
     pub resolved_at: Option<DateTime<Utc>>,
+    /// Who resolved the alert
+    #[serde(default)]
+    pub resolved_by: Option<String>,
+    /// How many times the condition fired within the dedup window
+    #[serde(default = "default_occurrence_count")]
+    pub occurrence_count: u32,
+    /// When the condition last fired again (deduplicated repeats only)
+    #[serde(default)]
+    pub last_occurred_at: Option<DateTime<Utc>>,
+    /// Names of escalation policies already applied
+    #[serde(default)]
+    pub escalations: Vec<String>,
+    /// When the alert was last escalated
+    #[serde(default)]
+    pub escalated_at: Option<DateTime<Utc>>,
+}
+
+fn default_occurrence_count() -> u32 {
+    1
 }
 
 impl Alert {
@@ -185,6 +224,11 @@ impl Alert {
             acknowledged_at: None,
             acknowledged_by: None,
             resolved_at: None,
+            resolved_by: None,
+            occurrence_count: default_occurrence_count(),
+            last_occurred_at: None,
+            escalations: Vec::new(),
+            escalated_at: None,
         }
     }
 
@@ -213,6 +257,32 @@ impl Alert {
         self.resolved_at = Some(Utc::now());
     }
 
+    /// Resolve the alert on behalf of an operator
+    pub fn resolve_by(&mut self, resolved_by: String) {
+        self.resolve();
+        self.resolved_by = Some(resolved_by);
+    }
+
+    /// Record another firing of the same condition
+    pub fn record_occurrence(&mut self) {
+        self.occurrence_count = self.occurrence_count.saturating_add(1);
+        self.last_occurred_at = Some(Utc::now());
+    }
+
+    /// Record an escalation, optionally raising the severity
+    pub fn escalate(&mut self, policy: &str, severity: Option<AlertSeverity>) {
+        if let Some(severity) = severity {
+            self.severity = severity;
+        }
+        self.escalations.push(policy.to_string());
+        self.escalated_at = Some(Utc::now());
+    }
+
+    /// Check whether an escalation policy was already applied
+    pub fn is_escalated_by(&self, policy: &str) -> bool {
+        self.escalations.iter().any(|p| p == policy)
+    }
+
     /// Check if alert is active (triggered but not acknowledged or resolved)
     pub fn is_active(&self) -> bool {
         self.status == AlertStatus::Triggered
@@ -230,14 +300,18 @@ impl Alert {
 
     /// Format alert for display
     pub fn format_message(&self) -> String {
-        format!(
+        let mut formatted = format!(
             "[{severity}] {message}\nCondition: {condition}\nStatus: {status}\nCreated: {created}",
             severity = self.severity.as_str().to_uppercase(),
             message = self.message,
             condition = self.condition.description(),
             status = self.status.as_str(),
             created = self.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
-        )
+        );
+        if self.occurrence_count > 1 {
+            formatted.push_str(&format!("\nOccurrences: {}", self.occurrence_count));
+        }
+        formatted
     }
 }
 
@@ -305,9 +379,10 @@ mod tests {
         assert_eq!(alert.acknowledged_by, Some("admin".to_string()));
 
         // Resolve
-        alert.resolve();
+        alert.resolve_by("oncall".to_string());
         assert_eq!(alert.status, AlertStatus::Resolved);
         assert!(alert.resolved_at.is_some());
+        assert_eq!(alert.resolved_by, Some("oncall".to_string()));
     }
 
     #[test]
@@ -344,5 +419,45 @@ mod tests {
         assert!(formatted.contains("WARNING"));
         assert!(formatted.contains("Keyword spike detected"));
         assert!(formatted.contains("경제위기"));
+        assert!(!formatted.contains("Occurrences"));
+    }
+
+    #[test]
+    fn test_alert_occurrences() {
+        let condition = AlertCondition::SourceFailure {
+            source: "naver.com".to_string(),
+            failure_count: 3,
+        };
+
+        let mut alert = Alert::new(condition, AlertSeverity::Warning, "Source down".to_string());
+        assert_eq!(alert.occurrence_count, 1);
+        assert!(alert.last_occurred_at.is_none());
+
+        alert.record_occurrence();
+        alert.record_occurrence();
+
+        assert_eq!(alert.occurrence_count, 3);
+        assert!(alert.last_occurred_at.is_some());
+        assert!(alert.format_message().contains("Occurrences: 3"));
+    }
+
+    #[test]
+    fn test_alert_escalation() {
+        let condition = AlertCondition::ErrorRateThreshold {
+            threshold_percent: 10.0,
+            window_minutes: 15,
+        };
+
+        let mut alert = Alert::new(condition, AlertSeverity::Warning, "Errors".to_string());
+        assert!(!alert.is_escalated_by("page-oncall"));
+
+        alert.escalate("page-oncall", Some(AlertSeverity::Critical));
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert!(alert.is_escalated_by("page-oncall"));
+        assert!(alert.escalated_at.is_some());
+
+        alert.escalate("reminder", None);
+        assert_eq!(alert.severity, AlertSeverity::Critical);
+        assert_eq!(alert.escalations, vec!["page-oncall", "reminder"]);
     }
 }
@@ -0,0 +1,555 @@
+//! Alert rules and channels declared in configuration
+//!
+//! This module defines the `[notifications]` section of `config.toml`, so
+//! alert conditions and their delivery channels can be declared without code
+//! changes and loaded into a [`NotificationManager`](super::NotificationManager)
+//! at startup.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications]
+//! dedup_window_minutes = 30
+//!
+//! [[notifications.channels]]
+//! name = "oncall"
+//! type = "slack"
+//! bot_token = "xoxb-..."
+//! channel = "C0123456"
+//!
+//! [[notifications.channels]]
+//! name = "ops-webhook"
+//! type = "webhook"
+//! url = "https://hooks.example.com/alerts"
+//!
+//! [[notifications.rules]]
+//! name = "economy-crisis"
+//! severity = "warning"
+//! channels = ["oncall"]
+//! condition = { type = "keyword_spike", keyword = "경제위기", threshold = 10, window_minutes = 60 }
+//!
+//! [[notifications.rules]]
+//! name = "empty-slots"
+//! severity = "critical"
+//! condition = { type = "zero_article_slots", consecutive_slots = 2 }
+//!
+//! # Evaluated hourly by the coordinator against a one-week rolling baseline
+//! [[notifications.rules]]
+//! name = "volume"
+//! severity = "warning"
+//! condition = { type = "volume_anomaly", category = "*", threshold_stddev = 3.0 }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::channels::discord::DiscordConfig;
+use super::channels::slack::SlackConfig;
+use super::channels::webhook::WebhookConfig;
+use super::template::MessageTemplate;
+use super::{AlertCondition, AlertSeverity, DigestConfig, EscalationPolicy};
+use crate::analytics::volume::{DEFAULT_VOLUME_WINDOW_HOURS, MIN_VOLUME_HISTORY_HOURS};
+use crate::analytics::BurstConfig;
+use crate::health::DependencyHealthConfig;
+
+/// A named notification channel declared in configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChannelConfig {
+    /// Generic JSON webhook
+    Webhook {
+        /// Channel name referenced by rules
+        name: String,
+        #[serde(flatten)]
+        config: WebhookConfig,
+        /// Handlebars message template
+        #[serde(default)]
+        template: Option<String>,
+        /// Locale for template labels (ko, en, zh)
+        #[serde(default)]
+        locale: Option<String>,
+    },
+    /// Slack webhook or bot
+    Slack {
+        /// Channel name referenced by rules
+        name: String,
+        #[serde(flatten)]
+        config: SlackConfig,
+        /// Handlebars message template
+        #[serde(default)]
+        template: Option<String>,
+        /// Locale for template labels (ko, en, zh)
+        #[serde(default)]
+        locale: Option<String>,
+    },
+    /// Discord webhook
+    Discord {
+        /// Channel name referenced by rules
+        name: String,
+        #[serde(flatten)]
+        config: DiscordConfig,
+        /// Handlebars message template
+        #[serde(default)]
+        template: Option<String>,
+        /// Locale for template labels (ko, en, zh)
+        #[serde(default)]
+        locale: Option<String>,
+    },
+}
+
+impl ChannelConfig {
+    /// Get the channel name referenced by rules
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Webhook { name, .. } | Self::Slack { name, .. } | Self::Discord { name, .. } => {
+                name
+            }
+        }
+    }
+
+    /// Get the channel type
+    pub fn channel_type(&self) -> &'static str {
+        match self {
+            Self::Webhook { .. } => "webhook",
+            Self::Slack { .. } => "slack",
+            Self::Discord { .. } => "discord",
+        }
+    }
+
+    /// Build the message template, if a template or locale is configured
+    pub fn message_template(&self) -> Result<Option<MessageTemplate>, String> {
+        let (template, locale) = match self {
+            Self::Webhook {
+                template, locale, ..
+            }
+            | Self::Slack {
+                template, locale, ..
+            }
+            | Self::Discord {
+                template, locale, ..
+            } => (template.as_deref(), locale.as_deref()),
+        };
+
+        match (template, locale) {
+            (Some(template), locale) => MessageTemplate::new(template, locale).map(Some),
+            (None, Some(locale)) => Ok(Some(MessageTemplate::localized(Some(locale)))),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Validate the channel configuration
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name().is_empty() {
+            return Err("Channel name cannot be empty".to_string());
+        }
+
+        self.message_template()
+            .map_err(|e| format!("channel '{}': {e}", self.name()))?;
+
+        match self {
+            Self::Webhook { config, .. } => config.validate(),
+            Self::Slack { config, .. } => config.validate(),
+            Self::Discord { config, .. } => config.validate(),
+        }
+        .map_err(|e| format!("channel '{}': {e}", self.name()))
+    }
+}
+
+/// An alert rule: a condition, its severity and where to send it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRule {
+    /// Unique rule name
+    pub name: String,
+    /// Condition that triggers the rule
+    pub condition: AlertCondition,
+    /// Severity of alerts raised by this rule
+    pub severity: AlertSeverity,
+    /// Target channel names (empty = all channels)
+    #[serde(default)]
+    pub channels: Vec<String>,
+    /// Whether the rule is active
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl AlertRule {
+    /// Create a new rule that notifies all channels
+    pub fn new(
+        name: impl Into<String>,
+        condition: AlertCondition,
+        severity: AlertSeverity,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            condition,
+            severity,
+            channels: Vec::new(),
+            enabled: true,
+        }
+    }
+
+    /// Restrict the rule to the given channels
+    pub fn with_channels<I, S>(mut self, channels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.channels = channels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check whether an alert from this rule should go to a channel
+    pub fn targets(&self, channel_name: &str) -> bool {
+        self.channels.is_empty() || self.channels.iter().any(|c| c == channel_name)
+    }
+
+    /// Validate the rule
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Rule name cannot be empty".to_string());
+        }
+        self.condition
+            .validate()
+            .map_err(|e| format!("rule '{}': {e}", self.name))
+    }
+}
+
+/// `[notifications]` configuration section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    /// Window in which repeats of a condition are counted on one alert (minutes)
+    #[serde(default = "default_dedup_window")]
+    pub dedup_window_minutes: i64,
+    /// Declared channels
+    #[serde(default)]
+    pub channels: Vec<ChannelConfig>,
+    /// Declared alert rules
+    #[serde(default)]
+    pub rules: Vec<AlertRule>,
+    /// Escalation policies for unacknowledged alerts
+    #[serde(default)]
+    pub escalations: Vec<EscalationPolicy>,
+    /// How often escalation policies are evaluated (seconds)
+    #[serde(default = "default_escalation_interval")]
+    pub escalation_interval_secs: u64,
+    /// Digest batching for Info alerts
+    #[serde(default)]
+    pub digest: DigestConfig,
+    /// How often `volume_anomaly` rules are evaluated (seconds)
+    #[serde(default = "default_volume_interval")]
+    pub volume_interval_secs: u64,
+    /// Hours of history in the rolling volume baseline
+    #[serde(default = "default_volume_window")]
+    pub volume_window_hours: usize,
+    /// Burst detection for `keyword_spike` rules evaluated while crawling
+    #[serde(default)]
+    pub keyword_burst: BurstConfig,
+    /// Background probes of the LLM and embedding server and their outage alerts
+    #[serde(default)]
+    pub dependency_health: DependencyHealthConfig,
+}
+
+fn default_dedup_window() -> i64 {
+    30
+}
+
+fn default_escalation_interval() -> u64 {
+    60
+}
+
+fn default_volume_interval() -> u64 {
+    3600
+}
+
+fn default_volume_window() -> usize {
+    DEFAULT_VOLUME_WINDOW_HOURS
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            dedup_window_minutes: default_dedup_window(),
+            channels: Vec::new(),
+            rules: Vec::new(),
+            escalations: Vec::new(),
+            escalation_interval_secs: default_escalation_interval(),
+            digest: DigestConfig::default(),
+            volume_interval_secs: default_volume_interval(),
+            volume_window_hours: default_volume_window(),
+            keyword_burst: BurstConfig::default(),
+            dependency_health: DependencyHealthConfig::default(),
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// Check whether any channels or rules are configured
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty() && self.rules.is_empty() && self.escalations.is_empty()
+    }
+
+    /// Validate channels, rules and the references between them
+    pub fn validate(&self) -> Result<(), String> {
+        if self.dedup_window_minutes < 0 {
+            return Err("dedup_window_minutes cannot be negative".to_string());
+        }
+        if self.escalation_interval_secs == 0 {
+            return Err("escalation_interval_secs must be greater than 0".to_string());
+        }
+        if self.volume_interval_secs == 0 {
+            return Err("volume_interval_secs must be greater than 0".to_string());
+        }
+        if self.volume_window_hours < MIN_VOLUME_HISTORY_HOURS {
+            return Err(format!(
+                "volume_window_hours must be at least {MIN_VOLUME_HISTORY_HOURS}"
+            ));
+        }
+        self.keyword_burst
+            .validate()
+            .map_err(|e| format!("keyword_burst: {e}"))?;
+        self.dependency_health
+            .validate()
+            .map_err(|e| format!("dependency_health: {e}"))?;
+
+        let mut channel_names = HashSet::new();
+        for channel in &self.channels {
+            channel.validate()?;
+            if !channel_names.insert(channel.name()) {
+                return Err(format!("Duplicate channel name: {}", channel.name()));
+            }
+        }
+
+        let mut rule_names = HashSet::new();
+        for rule in &self.rules {
+            rule.validate()?;
+            if !rule_names.insert(rule.name.as_str()) {
+                return Err(format!("Duplicate rule name: {}", rule.name));
+            }
+            for target in &rule.channels {
+                if !channel_names.contains(target.as_str()) {
+                    return Err(format!(
+                        "rule '{}' references unknown channel '{target}'",
+                        rule.name
+                    ));
+                }
+            }
+        }
+
+        let mut policy_names = HashSet::new();
+        for policy in &self.escalations {
+            policy.validate()?;
+            if !policy_names.insert(policy.name.as_str()) {
+                return Err(format!("Duplicate escalation policy name: {}", policy.name));
+            }
+            for target in &policy.channels {
+                if !channel_names.contains(target.as_str()) {
+                    return Err(format!(
+                        "escalation '{}' references unknown channel '{target}'",
+                        policy.name
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::DigestInterval;
+
+    const SAMPLE: &str = r#"
+dedup_window_minutes = 15
+
+[[channels]]
+name = "oncall"
+type = "slack"
+webhook_url = "https://hooks.slack.com/services/T/B/X"
+
+[[channels]]
+name = "ops"
+type = "webhook"
+url = "https://hooks.example.com/alerts"
+timeout_secs = 5
+
+[[channels]]
+name = "community"
+type = "discord"
+webhook_url = "https://discord.com/api/webhooks/1/abc"
+locale = "ko"
+template = "{{emoji}} {{severity_label}}: {{message}}"
+
+[[rules]]
+name = "economy-crisis"
+severity = "warning"
+channels = ["oncall"]
+condition = { type = "keyword_spike", keyword = "경제위기", threshold = 10, window_minutes = 60 }
+
+[[rules]]
+name = "crawl-failures"
+severity = "critical"
+condition = { type = "error_rate_threshold", threshold_percent = 20.0, window_minutes = 15 }
+
+[[rules]]
+name = "empty-slots"
+severity = "critical"
+channels = ["oncall", "ops"]
+enabled = false
+condition = { type = "zero_article_slots", consecutive_slots = 2 }
+
+[digest]
+enabled = true
+interval = "daily"
+
+[[escalations]]
+name = "page-oncall"
+severity = "warning"
+after_minutes = 30
+escalate_to = "critical"
+channels = ["oncall"]
+"#;
+
+    #[test]
+    fn test_parse_notifications_config() {
+        let config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+
+        assert_eq!(config.dedup_window_minutes, 15);
+        assert_eq!(config.channels.len(), 3);
+        assert_eq!(config.channels[0].name(), "oncall");
+        assert_eq!(config.channels[0].channel_type(), "slack");
+        assert_eq!(config.channels[2].channel_type(), "discord");
+        assert!(config.channels[0].message_template().unwrap().is_none());
+        let template = config.channels[2].message_template().unwrap().unwrap();
+        assert_eq!(template.locale(), "ko");
+
+        match &config.channels[1] {
+            ChannelConfig::Webhook { config, .. } => assert_eq!(config.timeout_secs, 5),
+            other => panic!("unexpected channel: {other:?}"),
+        }
+
+        assert_eq!(config.rules.len(), 3);
+        assert_eq!(config.rules[0].severity, AlertSeverity::Warning);
+        assert_eq!(config.rules[0].condition.condition_type(), "keyword_spike");
+        assert!(config.rules[1].enabled);
+        assert!(!config.rules[2].enabled);
+
+        assert_eq!(config.escalations.len(), 1);
+        assert_eq!(config.escalations[0].after_minutes, 30);
+        assert_eq!(config.escalation_interval_secs, 60);
+        assert_eq!(config.volume_interval_secs, 3600);
+        assert_eq!(config.volume_window_hours, 168);
+        assert!(config.digest.enabled);
+        assert_eq!(config.digest.interval, DigestInterval::Daily);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_rule_targets() {
+        let rule = AlertRule::new(
+            "r",
+            AlertCondition::ZeroArticleSlots {
+                consecutive_slots: 1,
+            },
+            AlertSeverity::Info,
+        );
+        assert!(rule.targets("anything"));
+
+        let rule = rule.with_channels(["oncall"]);
+        assert!(rule.targets("oncall"));
+        assert!(!rule.targets("ops"));
+    }
+
+    #[test]
+    fn test_unknown_channel_reference_rejected() {
+        let mut config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+        config.rules[0].channels = vec!["missing".to_string()];
+
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("unknown channel 'missing'"));
+
+        let mut config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+        config.escalations[0].channels = vec!["missing".to_string()];
+
+        let err = config.validate().unwrap_err();
+        assert!(err.contains("escalation 'page-oncall'"));
+    }
+
+    #[test]
+    fn test_volume_window_validated() {
+        let mut config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+        config.volume_window_hours = 12;
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .contains("volume_window_hours"));
+    }
+
+    #[test]
+    fn test_keyword_burst_validated() {
+        let config: NotificationsConfig =
+            toml::from_str("[keyword_burst]\nsensitivity = 2.0\nbaseline_hours = 12").unwrap();
+        assert_eq!(config.keyword_burst.sensitivity, 2.0);
+        assert_eq!(config.keyword_burst.baseline_hours, 12);
+        assert!(config.validate().is_ok());
+
+        let mut config = NotificationsConfig::default();
+        config.keyword_burst.sensitivity = 0.0;
+        assert!(config.validate().unwrap_err().starts_with("keyword_burst"));
+    }
+
+    #[test]
+    fn test_duplicate_names_rejected() {
+        let mut config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+        config.rules.push(config.rules[0].clone());
+        assert!(config.validate().unwrap_err().contains("Duplicate rule"));
+
+        let mut config: NotificationsConfig = toml::from_str(SAMPLE).unwrap();
+        config.channels.push(config.channels[0].clone());
+        assert!(config.validate().unwrap_err().contains("Duplicate channel"));
+    }
+
+    #[test]
+    fn test_invalid_template_rejected() {
+        let config: NotificationsConfig = toml::from_str(
+            r#"
+[[channels]]
+name = "broken"
+type = "webhook"
+url = "https://hooks.example.com/alerts"
+template = "{{#if}}"
+"#,
+        )
+        .unwrap();
+
+        assert!(config.validate().unwrap_err().contains("channel 'broken'"));
+    }
+
+    #[test]
+    fn test_invalid_condition_rejected() {
+        let config: NotificationsConfig = toml::from_str(
+            r#"
+[[rules]]
+name = "bad"
+severity = "info"
+condition = { type = "keyword_spike", keyword = "", threshold = 10, window_minutes = 60 }
+"#,
+        )
+        .unwrap();
+
+        assert!(config.validate().unwrap_err().contains("rule 'bad'"));
+    }
+
+    #[test]
+    fn test_default_is_empty() {
+        let config = NotificationsConfig::default();
+        assert!(config.is_empty());
+        assert_eq!(config.dedup_window_minutes, 30);
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,302 @@
+//! Templated notification messages
+//!
+//! Channels can render alerts through a Handlebars template instead of the
+//! built-in message, with labels translated to Korean, English or Chinese via
+//! the [`i18n`](crate::i18n) module.
+//!
+//! # Template Variables
+//!
+//! | Variable | Description |
+//! |----------|-------------|
+//! | `id` | Alert ID |
+//! | `message` | Alert message |
+//! | `severity` / `severity_label` | `warning` / localized name (`경고`) |
+//! | `status` / `status_label` | `triggered` / localized name (`발생`) |
+//! | `emoji` | Severity emoji |
+//! | `condition` | Localized condition description |
+//! | `condition_type` | Condition type (`keyword_spike`, ...) |
+//! | `occurrence_count` | Repeats counted within the dedup window |
+//! | `acknowledged_by` | Who acknowledged the alert, if anyone |
+//! | `created_at` / `triggered_at` | Timestamps (`YYYY-MM-DD HH:MM:SS UTC`) |
+//! | `metadata.<key>` | Alert metadata values |
+//! | `labels.<key>` | Localized field labels (`condition`, `status`, ...) |
+//!
+//! # Example
+//!
+//! ```toml
+//! [[notifications.channels]]
+//! name = "newsroom"
+//! type = "slack"
+//! webhook_url = "https://hooks.slack.com/services/T000/B000/XXXX"
+//! locale = "ko"
+//! template = "{{emoji}} [{{severity_label}}] {{message}} ({{metadata.category}})"
+//! ```
+
+use async_trait::async_trait;
+use handlebars::Handlebars;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+use super::channels::{Channel, ChannelResult, DeliveryStatus};
+use super::Alert;
+use crate::i18n;
+
+/// Name the template is registered under
+const TEMPLATE_NAME: &str = "message";
+
+/// Template used when only a locale is configured
+const DEFAULT_TEMPLATE: &str = "{{emoji}} [{{severity_label}}] {{message}}\n\
+{{labels.condition}}: {{condition}}\n\
+{{labels.status}}: {{status_label}}\
+{{#if (gt occurrence_count 1)}}\n{{labels.occurrences}}: {{occurrence_count}}{{/if}}\
+{{#if acknowledged_by}}\n{{labels.acknowledged_by}}: {{acknowledged_by}}{{/if}}";
+
+/// Label keys exposed to templates as `labels.<key>`
+const LABEL_KEYS: [&str; 6] = [
+    "condition",
+    "severity",
+    "status",
+    "occurrences",
+    "acknowledged_by",
+    "created_at",
+];
+
+/// Values available to message templates
+#[derive(Debug, Serialize)]
+struct TemplateContext<'a> {
+    id: &'a str,
+    message: &'a str,
+    severity: &'static str,
+    severity_label: String,
+    status: &'static str,
+    status_label: String,
+    emoji: &'static str,
+    condition: String,
+    condition_type: &'static str,
+    occurrence_count: u32,
+    acknowledged_by: Option<&'a str>,
+    created_at: String,
+    triggered_at: Option<String>,
+    metadata: &'a HashMap<String, String>,
+    labels: BTreeMap<&'static str, String>,
+    locale: &'a str,
+}
+
+impl<'a> TemplateContext<'a> {
+    fn new(alert: &'a Alert, locale: &'a str) -> Self {
+        let translate = |key: String| rust_i18n::t!(&key, locale = locale).to_string();
+
+        let condition = if locale == "ko" {
+            alert.condition.korean_description()
+        } else {
+            alert.condition.description()
+        };
+
+        Self {
+            id: &alert.id,
+            message: &alert.message,
+            severity: alert.severity.as_str(),
+            severity_label: translate(format!(
+                "notifications.severity.{}",
+                alert.severity.as_str()
+            )),
+            status: alert.status.as_str(),
+            status_label: translate(format!("notifications.status.{}", alert.status.as_str())),
+            emoji: alert.severity.emoji(),
+            condition,
+            condition_type: alert.condition.condition_type(),
+            occurrence_count: alert.occurrence_count,
+            acknowledged_by: alert.acknowledged_by.as_deref(),
+            created_at: alert.created_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            triggered_at: alert
+                .triggered_at
+                .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+            metadata: &alert.metadata,
+            labels: LABEL_KEYS
+                .iter()
+                .map(|key| (*key, translate(format!("notifications.labels.{key}"))))
+                .collect(),
+            locale,
+        }
+    }
+}
+
+/// A compiled message template with its locale
+pub struct MessageTemplate {
+    handlebars: Handlebars<'static>,
+    locale: Option<String>,
+}
+
+impl MessageTemplate {
+    /// Compile a template
+    ///
+    /// Without a locale, labels follow [`i18n::current_locale`] at render time.
+    pub fn new(template: &str, locale: Option<&str>) -> Result<Self, String> {
+        let mut handlebars = Handlebars::new();
+        // Chat platforms are not HTML; keep text as written
+        handlebars.register_escape_fn(handlebars::no_escape);
+        handlebars
+            .register_template_string(TEMPLATE_NAME, template)
+            .map_err(|e| format!("Invalid message template: {e}"))?;
+
+        Ok(Self {
+            handlebars,
+            locale: locale.map(i18n::normalize_locale),
+        })
+    }
+
+    /// Create the built-in template, localized
+    pub fn localized(locale: Option<&str>) -> Self {
+        Self::new(DEFAULT_TEMPLATE, locale).expect("default template is valid")
+    }
+
+    /// Get the locale used for rendering
+    pub fn locale(&self) -> String {
+        self.locale.clone().unwrap_or_else(i18n::current_locale)
+    }
+
+    /// Render an alert
+    pub fn render(&self, alert: &Alert) -> Result<String, String> {
+        let locale = self.locale();
+        let context = TemplateContext::new(alert, &locale);
+        self.handlebars
+            .render(TEMPLATE_NAME, &context)
+            .map_err(|e| format!("Failed to render message template: {e}"))
+    }
+}
+
+/// Channel wrapper that renders alert messages through a template
+///
+/// The rendered text replaces `Alert::message`, so each channel keeps its own
+/// layout (Slack blocks, Discord embeds, webhook JSON) around it.
+pub struct TemplatedChannel {
+    inner: Box<dyn Channel + Send + Sync>,
+    template: MessageTemplate,
+}
+
+impl TemplatedChannel {
+    /// Wrap a channel with a message template
+    pub fn new(inner: Box<dyn Channel + Send + Sync>, template: MessageTemplate) -> Self {
+        Self { inner, template }
+    }
+}
+
+#[async_trait]
+impl Channel for TemplatedChannel {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn send(&self, alert: &Alert) -> ChannelResult<DeliveryStatus> {
+        let mut rendered = alert.clone();
+        match self.template.render(alert) {
+            Ok(message) => rendered.message = message,
+            Err(e) => tracing::warn!("{}; sending default message", e),
+        }
+        self.inner.send(&rendered).await
+    }
+
+    async fn health_check(&self) -> ChannelResult<bool> {
+        self.inner.health_check().await
+    }
+
+    fn config(&self) -> serde_json::Value {
+        let mut config = self.inner.config();
+        config["locale"] = serde_json::json!(self.template.locale());
+        config["templated"] = serde_json::json!(true);
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::{AlertCondition, AlertSeverity};
+
+    fn sample_alert() -> Alert {
+        let condition = AlertCondition::KeywordSpike {
+            keyword: "경제위기".to_string(),
+            threshold: 10,
+            window_minutes: 60,
+        };
+        let mut alert = Alert::new(
+            condition,
+            AlertSeverity::Warning,
+            "Keyword spike".to_string(),
+        )
+        .with_metadata("category".to_string(), "economy".to_string());
+        alert.trigger();
+        alert
+    }
+
+    #[test]
+    fn test_custom_template_with_metadata() {
+        let template = MessageTemplate::new(
+            "[{{severity}}] {{message}} <{{metadata.category}}> x{{occurrence_count}}",
+            Some("en"),
+        )
+        .unwrap();
+
+        let rendered = template.render(&sample_alert()).unwrap();
+        assert_eq!(rendered, "[warning] Keyword spike <economy> x1");
+    }
+
+    #[test]
+    fn test_localized_default_template() {
+        let korean = MessageTemplate::localized(Some("ko-KR"))
+            .render(&sample_alert())
+            .unwrap();
+        assert!(korean.contains("[경고] Keyword spike"));
+        assert!(korean.contains("조건: 키워드 '경제위기'"));
+        assert!(korean.contains("상태: 발생"));
+        assert!(!korean.contains("발생 횟수"));
+
+        let mut alert = sample_alert();
+        alert.record_occurrence();
+        let english = MessageTemplate::localized(Some("en"))
+            .render(&alert)
+            .unwrap();
+        assert!(english.contains("[Warning] Keyword spike"));
+        assert!(english.contains("Condition: Keyword '경제위기'"));
+        assert!(english.contains("Occurrences: 2"));
+    }
+
+    #[test]
+    fn test_invalid_template() {
+        assert!(MessageTemplate::new("{{#if}}", None).is_err());
+    }
+
+    #[test]
+    fn test_no_html_escaping() {
+        let template = MessageTemplate::new("{{metadata.query}}", Some("en")).unwrap();
+        let alert = sample_alert().with_metadata("query".to_string(), "a & <b>".to_string());
+        assert_eq!(template.render(&alert).unwrap(), "a & <b>");
+    }
+
+    #[tokio::test]
+    async fn test_templated_channel_replaces_message() {
+        use crate::notifications::WebhookChannel;
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "message": "경고: Keyword spike"
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let inner = WebhookChannel::from_url(server.uri()).unwrap();
+        let template = MessageTemplate::new("{{severity_label}}: {{message}}", Some("ko")).unwrap();
+        let channel = TemplatedChannel::new(Box::new(inner), template);
+
+        assert_eq!(channel.name(), "webhook");
+        assert_eq!(channel.config()["locale"], "ko");
+
+        let status = channel.send(&sample_alert()).await.unwrap();
+        assert!(status.success);
+    }
+}
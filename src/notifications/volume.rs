@@ -0,0 +1,87 @@
+//! Background evaluation of category volume anomalies
+//!
+//! Every interval, the hourly article counts per category are read from the
+//! PostgreSQL time series and the latest complete hour is compared with its
+//! rolling baseline (see [`CategoryVolume`]). `volume_anomaly` rules fire when
+//! the deviation reaches their `threshold_stddev`. The coordinator runs
+//! [`run_volume_anomaly_loop`] in the background.
+//!
+//! # Example
+//!
+//! ```toml
+//! [notifications]
+//! volume_interval_secs = 3600
+//! volume_window_hours = 168
+//!
+//! [[notifications.rules]]
+//! name = "politics-volume"
+//! severity = "warning"
+//! condition = { type = "volume_anomaly", category = "politics", threshold_stddev = 3.0 }
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use super::{Alert, NotificationManager};
+use crate::analytics::{category_volumes, CategoryVolume};
+use crate::storage::{hour_bucket, PgTimeSeriesStore, METRIC_ARTICLES};
+
+/// Evaluate `volume_anomaly` rules for the last complete hour before `now`
+///
+/// Returns the alerts that were raised.
+pub async fn evaluate_volume_anomalies(
+    manager: &RwLock<NotificationManager>,
+    store: &PgTimeSeriesStore,
+    now: DateTime<Utc>,
+) -> Result<Vec<Alert>, String> {
+    let window_hours = manager.read().await.volume_window_hours();
+    let hour = hour_bucket(now) - chrono::Duration::hours(1);
+    let from = hour - chrono::Duration::hours(window_hours as i64);
+
+    let points = store
+        .query(
+            METRIC_ARTICLES,
+            &[],
+            from,
+            hour + chrono::Duration::hours(1),
+        )
+        .await
+        .map_err(|e| format!("Failed to load article volume: {e}"))?;
+    let counts = points.into_iter().filter_map(|point| {
+        let category = point.dimension("category")?.to_string();
+        Some((category, point.bucket, point.value.max(0.0).round() as u64))
+    });
+    let volumes: Vec<CategoryVolume> = category_volumes(counts, hour, window_hours);
+
+    tracing::debug!(
+        hour = %hour,
+        categories = volumes.len(),
+        "Evaluating category volume"
+    );
+    // Decide under the lock, send after releasing it
+    let (fired, outbox) = manager.write().await.queue_volume_anomalies(&volumes)?;
+    outbox.send().await;
+    Ok(fired)
+}
+
+/// Evaluate volume anomalies every interval until the task is aborted
+pub async fn run_volume_anomaly_loop(
+    manager: Arc<RwLock<NotificationManager>>,
+    store: PgTimeSeriesStore,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+
+        match evaluate_volume_anomalies(&manager, &store, Utc::now()).await {
+            Ok(fired) if !fired.is_empty() => {
+                tracing::info!(count = fired.len(), "Raised volume anomaly alerts");
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(error = %e, "Volume anomaly evaluation failed"),
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//! Extraction quality evaluation against gold-annotated articles
+//!
+//! A gold set holds articles with the entities and relations an annotator
+//! found in them. Extraction results are matched against the annotations and
+//! scored with precision, recall and F1 per entity and relation type, so the
+//! effect of extractor or prompt changes can be measured and a saved report
+//! can serve as the baseline that later runs must not regress from.
+//!
+//! # Gold set format
+//!
+//! A JSON array, or JSON Lines with one article per line:
+//!
+//! ```json
+//! [
+//!   {
+//!     "id": "001_0015812889",
+//!     "title": "삼성전자, 반도체 투자 확대",
+//!     "content": "이재용 회장은 ...",
+//!     "entities": [
+//!       { "text": "삼성전자", "type": "organization" },
+//!       { "text": "이재용", "type": "person" }
+//!     ],
+//!     "relations": [
+//!       { "subject": "이재용", "predicate": "leads", "object": "삼성전자" }
+//!     ]
+//!   }
+//! ]
+//! ```
+//!
+//! Types are parsed like LLM output ([`EntityType::from_string`],
+//! [`RelationType::from_string`]), so `person`, `Person` and `인물` are the
+//! same type.
+//!
+//! # Matching
+//!
+//! Texts are compared case-insensitively with whitespace removed. An entity
+//! matches on text and type. A relation matches on subject and predicate, and
+//! on an object that equals or contains the gold object (or is contained in
+//! it), since quoted statements rarely share exact boundaries.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use super::extractor::{EntityType, ExtractionResult, RelationType};
+use crate::models::ParsedArticle;
+
+/// An annotated entity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldEntity {
+    /// Entity text
+    pub text: String,
+    /// Entity type name
+    #[serde(rename = "type")]
+    pub entity_type: String,
+}
+
+/// An annotated relation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldRelation {
+    /// Subject entity text
+    pub subject: String,
+    /// Relation type name
+    pub predicate: String,
+    /// Object entity text
+    pub object: String,
+}
+
+/// An article with its gold annotations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldArticle {
+    /// Article ID (defaults to the position in the set)
+    #[serde(default)]
+    pub id: String,
+    /// Article title
+    #[serde(default)]
+    pub title: String,
+    /// Article body
+    pub content: String,
+    /// Annotated entities
+    #[serde(default)]
+    pub entities: Vec<GoldEntity>,
+    /// Annotated relations
+    #[serde(default)]
+    pub relations: Vec<GoldRelation>,
+}
+
+impl GoldArticle {
+    /// The article to run extractors on
+    pub fn to_article(&self) -> ParsedArticle {
+        ParsedArticle {
+            source_id: self.id.clone(),
+            title: self.title.clone(),
+            content: self.content.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Gold-annotated articles
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GoldSet {
+    /// Articles in file order
+    pub articles: Vec<GoldArticle>,
+}
+
+impl GoldSet {
+    /// Load a gold set from a JSON array or JSON Lines file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read gold set {}", path.display()))?;
+        let set =
+            Self::parse(&data).with_context(|| format!("Invalid gold set {}", path.display()))?;
+        if set.articles.is_empty() {
+            bail!("Gold set {} has no articles", path.display());
+        }
+        Ok(set)
+    }
+
+    /// Parse a JSON array, or JSON Lines when the data is not an array
+    pub fn parse(data: &str) -> Result<Self> {
+        let mut articles: Vec<GoldArticle> = if data.trim_start().starts_with('[') {
+            serde_json::from_str(data)?
+        } else {
+            data.lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| {
+                    serde_json::from_str(line).with_context(|| format!("line {}", i + 1))
+                })
+                .collect::<Result<_>>()?
+        };
+        for (i, article) in articles.iter_mut().enumerate() {
+            if article.id.is_empty() {
+                article.id = (i + 1).to_string();
+            }
+        }
+        Ok(Self { articles })
+    }
+}
+
+/// Match counts of one type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeScores {
+    /// Extracted and annotated
+    pub true_positives: usize,
+    /// Extracted but not annotated
+    pub false_positives: usize,
+    /// Annotated but not extracted
+    pub false_negatives: usize,
+}
+
+impl TypeScores {
+    /// Share of the extracted items that are correct
+    pub fn precision(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        )
+    }
+
+    /// Share of the annotated items that were extracted
+    pub fn recall(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        )
+    }
+
+    /// Harmonic mean of precision and recall
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r > 0.0 {
+            2.0 * p * r / (p + r)
+        } else {
+            0.0
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.true_positives += other.true_positives;
+        self.false_positives += other.false_positives;
+        self.false_negatives += other.false_negatives;
+    }
+}
+
+fn ratio(part: usize, whole: usize) -> f64 {
+    if whole > 0 {
+        part as f64 / whole as f64
+    } else {
+        0.0
+    }
+}
+
+/// Scores per entity and relation type, accumulated over articles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OntologyEval {
+    /// Articles evaluated
+    pub articles: usize,
+    /// Scores by entity type name
+    pub entities: BTreeMap<String, TypeScores>,
+    /// Scores by relation type name
+    pub relations: BTreeMap<String, TypeScores>,
+}
+
+/// An F1 score that dropped from the baseline
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Regression {
+    /// `entity` or `relation`
+    pub kind: &'static str,
+    /// Type name, or `all` for the micro-averaged total
+    pub name: String,
+    /// Baseline F1
+    pub baseline: f64,
+    /// Current F1
+    pub current: f64,
+}
+
+impl OntologyEval {
+    /// Score one article's extraction against its annotations
+    pub fn add(&mut self, gold: &GoldArticle, extracted: &ExtractionResult) {
+        self.articles += 1;
+
+        let gold_entities: HashSet<(String, EntityType)> = gold
+            .entities
+            .iter()
+            .map(|e| (normalize(&e.text), EntityType::from_string(&e.entity_type)))
+            .collect();
+        let extracted_entities: HashSet<(String, EntityType)> = extracted
+            .entities
+            .iter()
+            .map(|e| (normalize(&e.text), e.entity_type))
+            .collect();
+        for (_, entity_type) in gold_entities.difference(&extracted_entities) {
+            self.entity(*entity_type).false_negatives += 1;
+        }
+        for key @ (_, entity_type) in &extracted_entities {
+            let scores = self.entity(*entity_type);
+            if gold_entities.contains(key) {
+                scores.true_positives += 1;
+            } else {
+                scores.false_positives += 1;
+            }
+        }
+
+        let gold_relations: Vec<(String, RelationType, String)> = gold
+            .relations
+            .iter()
+            .map(|r| {
+                (
+                    normalize(&r.subject),
+                    RelationType::from_string(&r.predicate),
+                    normalize(&r.object),
+                )
+            })
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let extracted_relations: HashSet<(String, RelationType, String)> = extracted
+            .relations
+            .iter()
+            .map(|r| (normalize(&r.subject), r.predicate, normalize(&r.object)))
+            .collect();
+
+        // Each gold relation is matched at most once
+        let mut unmatched: Vec<&(String, RelationType, String)> = gold_relations.iter().collect();
+        for (subject, predicate, object) in &extracted_relations {
+            let found = unmatched.iter().position(|(s, p, o)| {
+                s == subject && p == predicate && (o.contains(object) || object.contains(o))
+            });
+            let scores = self.relation(*predicate);
+            match found {
+                Some(i) => {
+                    scores.true_positives += 1;
+                    unmatched.swap_remove(i);
+                }
+                None => scores.false_positives += 1,
+            }
+        }
+        for (_, predicate, _) in unmatched {
+            self.relation(*predicate).false_negatives += 1;
+        }
+    }
+
+    fn entity(&mut self, entity_type: EntityType) -> &mut TypeScores {
+        self.entities.entry(format!("{entity_type:?}")).or_default()
+    }
+
+    fn relation(&mut self, relation_type: RelationType) -> &mut TypeScores {
+        self.relations
+            .entry(format!("{relation_type:?}"))
+            .or_default()
+    }
+
+    /// Micro-averaged scores over all entity types
+    pub fn entity_total(&self) -> TypeScores {
+        total(&self.entities)
+    }
+
+    /// Micro-averaged scores over all relation types
+    pub fn relation_total(&self) -> TypeScores {
+        total(&self.relations)
+    }
+
+    /// F1 scores that dropped more than `tolerance` below `baseline`
+    ///
+    /// Types missing from this evaluation count as F1 0; types new in this
+    /// evaluation cannot regress.
+    pub fn regressions(&self, baseline: &OntologyEval, tolerance: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        let mut check = |kind, name: &str, before: &TypeScores, after: Option<&TypeScores>| {
+            let (baseline, current) = (before.f1(), after.map(TypeScores::f1).unwrap_or(0.0));
+            if baseline - current > tolerance {
+                regressions.push(Regression {
+                    kind,
+                    name: name.to_string(),
+                    baseline,
+                    current,
+                });
+            }
+        };
+
+        check(
+            "entity",
+            "all",
+            &baseline.entity_total(),
+            Some(&self.entity_total()),
+        );
+        for (name, scores) in &baseline.entities {
+            check("entity", name, scores, self.entities.get(name));
+        }
+        check(
+            "relation",
+            "all",
+            &baseline.relation_total(),
+            Some(&self.relation_total()),
+        );
+        for (name, scores) in &baseline.relations {
+            check("relation", name, scores, self.relations.get(name));
+        }
+        regressions
+    }
+}
+
+fn total(scores: &BTreeMap<String, TypeScores>) -> TypeScores {
+    let mut total = TypeScores::default();
+    for s in scores.values() {
+        total.add(s);
+    }
+    total
+}
+
+/// Lowercase and drop whitespace and quotes
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '"' | '\'' | '“' | '”' | '‘' | '’'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::{EntitySource, ExtractedEntity, ExtractedRelation};
+
+    fn entity(text: &str, entity_type: EntityType) -> ExtractedEntity {
+        ExtractedEntity {
+            text: text.to_string(),
+            canonical_name: None,
+            entity_type,
+            start: 0,
+            end: 0,
+            confidence: 0.9,
+            source: EntitySource::Content,
+        }
+    }
+
+    fn relation(subject: &str, predicate: RelationType, object: &str) -> ExtractedRelation {
+        ExtractedRelation {
+            subject: subject.to_string(),
+            subject_type: EntityType::Person,
+            predicate,
+            object: object.to_string(),
+            object_type: EntityType::Other,
+            confidence: 0.9,
+            evidence: String::new(),
+            verified: true,
+        }
+    }
+
+    fn gold() -> GoldArticle {
+        GoldSet::parse(
+            r#"{"title": "t", "content": "c", "entities": [{"text": "삼성전자", "type": "organization"}, {"text": "이재용", "type": "인물"}], "relations": [{"subject": "이재용", "predicate": "said", "object": "투자를 늘리겠다"}, {"subject": "이재용", "predicate": "leads", "object": "삼성전자"}]}"#,
+        )
+        .unwrap()
+        .articles
+        .remove(0)
+    }
+
+    #[test]
+    fn test_parse_jsonl_and_array() {
+        let set = GoldSet::parse("{\"content\": \"a\"}\n\n{\"content\": \"b\"}\n").unwrap();
+        assert_eq!(set.articles.len(), 2);
+        assert_eq!(set.articles[1].id, "2");
+
+        let set = GoldSet::parse(r#"[{"id": "x", "content": "a"}]"#).unwrap();
+        assert_eq!(set.articles[0].id, "x");
+        assert!(set.articles[0].entities.is_empty());
+    }
+
+    #[test]
+    fn test_scores_per_type() {
+        let extracted = ExtractionResult {
+            article_id: "1".to_string(),
+            entities: vec![
+                entity("삼성 전자", EntityType::Organization),
+                entity("이재용", EntityType::Organization),
+            ],
+            relations: vec![
+                relation("이재용", RelationType::Said, "\"투자를 늘리겠다\"고"),
+                relation("이재용", RelationType::WorksFor, "삼성전자"),
+            ],
+        };
+
+        let mut eval = OntologyEval::default();
+        eval.add(&gold(), &extracted);
+
+        let org = eval.entities["Organization"];
+        assert_eq!(
+            (org.true_positives, org.false_positives, org.false_negatives),
+            (1, 1, 0)
+        );
+        assert_eq!(eval.entities["Person"].false_negatives, 1);
+        assert_eq!(eval.relations["Said"].true_positives, 1);
+        assert_eq!(eval.relations["WorksFor"].false_positives, 1);
+        assert_eq!(eval.relations["Leads"].false_negatives, 1);
+
+        let entities = eval.entity_total();
+        assert_eq!(entities.precision(), 0.5);
+        assert_eq!(entities.recall(), 0.5);
+        assert_eq!(entities.f1(), 0.5);
+    }
+
+    #[test]
+    fn test_empty_scores_are_zero() {
+        let scores = TypeScores::default();
+        assert_eq!(scores.precision(), 0.0);
+        assert_eq!(scores.f1(), 0.0);
+    }
+
+    #[test]
+    fn test_regressions_against_baseline() {
+        let perfect = TypeScores {
+            true_positives: 4,
+            ..Default::default()
+        };
+        let half = TypeScores {
+            true_positives: 2,
+            false_negatives: 2,
+            ..Default::default()
+        };
+        let mut baseline = OntologyEval::default();
+        baseline.entities.insert("Person".to_string(), perfect);
+        baseline.relations.insert("Said".to_string(), half);
+
+        let mut current = OntologyEval::default();
+        current.entities.insert("Person".to_string(), half);
+        current.relations.insert("Said".to_string(), half);
+
+        let regressions = current.regressions(&baseline, 0.01);
+        let names: Vec<(&str, &str)> = regressions
+            .iter()
+            .map(|r| (r.kind, r.name.as_str()))
+            .collect();
+        assert_eq!(names, vec![("entity", "all"), ("entity", "Person")]);
+        assert!(current.regressions(&baseline, 0.5).is_empty());
+        assert!(baseline.regressions(&baseline, 0.0).is_empty());
+    }
+}
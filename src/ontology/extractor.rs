@@ -559,6 +559,61 @@ impl HallucinationVerifier {
         confidence.clamp(0.0, 1.0)
     }
 
+    /// Turn LLM Said relations into relations checked against `text`
+    ///
+    /// Only the speaker is looked up, since the quote may be paraphrased.
+    /// Relations whose speaker is missing are kept only when the LLM was
+    /// confident (>= 0.8), unverified and with a small confidence penalty.
+    pub fn verify_said(
+        &self,
+        said_relations: Vec<crate::llm::SaidRelation>,
+        text: &str,
+    ) -> Vec<ExtractedRelation> {
+        let mut extracted = Vec::new();
+        for said in said_relations {
+            // For Said relations, only verify the speaker exists in the text
+            // We create a minimal relation with just the speaker for verification
+            let speaker_only = ExtractedRelation {
+                subject: said.speaker.clone(),
+                subject_type: EntityType::Person,
+                predicate: RelationType::Said,
+                object: String::new(), // Empty - don't verify content
+                object_type: EntityType::Other,
+                confidence: said.confidence,
+                evidence: String::new(), // Empty - don't verify evidence
+                verified: false,
+            };
+
+            // Verify only the speaker name exists in text
+            let verification = self.verify(&speaker_only, text);
+
+            // Accept if speaker is found OR if LLM confidence is high (>= 0.8)
+            let should_accept = verification.subject_match.found || said.confidence >= 0.8;
+
+            if should_accept {
+                // Use original confidence, slightly adjusted based on speaker match
+                let final_confidence = if verification.subject_match.found {
+                    said.confidence // Keep original confidence if speaker found
+                } else {
+                    said.confidence * 0.9 // Small penalty if speaker not found but high confidence
+                };
+
+                extracted.push(ExtractedRelation {
+                    subject: said.speaker,
+                    subject_type: EntityType::Person,
+                    predicate: RelationType::Said,
+                    object: said.content,
+                    object_type: EntityType::Other,
+                    confidence: final_confidence,
+                    evidence: said.evidence,
+                    verified: verification.subject_match.found, // Only verified if speaker found
+                });
+            }
+        }
+
+        extracted
+    }
+
     /// Verify multiple relations and return results
     pub fn verify_batch(
         &self,
@@ -746,6 +801,27 @@ pub enum EntitySource {
     Both,
 }
 
+/// How a relation was extracted
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum RelationSource {
+    /// Regex patterns of [`RelationExtractor`]
+    #[default]
+    Regex,
+    /// LLM extraction, verified against the article text
+    Llm,
+}
+
+impl RelationSource {
+    /// Name used in exports (`regex`, `llm`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationSource::Regex => "regex",
+            RelationSource::Llm => "llm",
+        }
+    }
+}
+
 /// Extracted relation (triple)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedRelation {
@@ -1279,6 +1355,10 @@ pub struct Triple {
 
     /// Verification status
     pub verified: bool,
+
+    /// How the relation was extracted
+    #[serde(default)]
+    pub source: RelationSource,
 }
 
 impl Triple {
@@ -1303,14 +1383,23 @@ impl Triple {
                 Some(relation.evidence.clone())
             },
             verified: relation.verified,
+            source: RelationSource::Regex,
         }
     }
 
-    /// Convert to N-Triples format string
+    /// Mark how the relation was extracted
+    pub fn with_source(mut self, source: RelationSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Convert to N-Triples format string, prefixed names expanded to IRIs
     pub fn to_ntriples(&self) -> String {
         format!(
             "<{}> <{}> <{}> .",
-            self.subject_id, self.predicate, self.object_id
+            expand_iri(&self.subject_id),
+            expand_iri(&self.predicate),
+            expand_iri(&self.object_id)
         )
     }
 
@@ -1329,7 +1418,7 @@ impl Triple {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TripleStore {
     /// JSON-LD context
-    #[serde(rename = "@context")]
+    #[serde(rename = "@context", default)]
     pub context: TripleContext,
 
     /// Article identifier
@@ -1341,13 +1430,19 @@ pub struct TripleStore {
     /// Extraction timestamp
     pub extracted_at: String,
 
+    /// Article publication timestamp (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+
     /// All triples
     pub triples: Vec<Triple>,
 
     /// Extracted entities
+    #[serde(default)]
     pub entities: Vec<ExtractedEntity>,
 
     /// Statistics
+    #[serde(default)]
     pub stats: TripleStats,
 }
 
@@ -1407,6 +1502,7 @@ impl TripleStore {
             article_id: result.article_id.clone(),
             article_title: article_title.to_string(),
             extracted_at: chrono::Utc::now().to_rfc3339(),
+            published_at: None,
             triples,
             entities: result.entities.clone(),
             stats: TripleStats {
@@ -1419,6 +1515,41 @@ impl TripleStore {
         }
     }
 
+    /// Add triples for `relations` extracted by `source`, e.g. LLM Said
+    /// relations on top of the regex extraction
+    pub fn add_relations(&mut self, relations: &[ExtractedRelation], source: RelationSource) {
+        for relation in relations {
+            self.triples
+                .push(Triple::from_relation(relation, &self.article_id).with_source(source));
+            *self
+                .stats
+                .relation_types
+                .entry(relation.predicate.korean_label().to_string())
+                .or_insert(0) += 1;
+        }
+        self.stats.total_relations += relations.len();
+        self.stats.verified_relations += relations.iter().filter(|r| r.verified).count();
+    }
+
+    /// Set the article publication timestamp
+    pub fn with_published_at(
+        mut self,
+        published_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Self {
+        self.published_at = published_at.map(|t| t.to_rfc3339());
+        self
+    }
+
+    /// Get the article publication time, falling back to the extraction time
+    pub fn timestamp(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.published_at
+            .as_deref()
+            .unwrap_or(&self.extracted_at)
+            .parse::<chrono::DateTime<chrono::FixedOffset>>()
+            .ok()
+            .map(|t| t.with_timezone(&chrono::Utc))
+    }
+
     /// Export to JSON-LD format
     pub fn to_json_ld(&self) -> Result<String> {
         serde_json::to_string_pretty(self).context("Failed to serialize TripleStore to JSON-LD")
@@ -1494,6 +1625,30 @@ fn turtle_escape(s: &str) -> String {
     }
 }
 
+/// Namespaces of the prefixed names used in triples
+const IRI_PREFIXES: &[(&str, &str)] = &[
+    ("schema:", "https://schema.org/"),
+    ("baram:", "https://baram.example.org/ontology/"),
+    ("wd:", "http://www.wikidata.org/entity/"),
+    ("dbpedia:", "http://dbpedia.org/resource/"),
+    ("rdf:", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs:", "http://www.w3.org/2000/01/rdf-schema#"),
+];
+
+/// Helper: Expand a prefixed name such as `schema:worksFor` to a full IRI
+///
+/// N-Triples has no prefixes, so every term must be absolute. Terms that are
+/// already IRIs or use an unknown prefix are returned unchanged.
+pub(crate) fn expand_iri(term: &str) -> String {
+    IRI_PREFIXES
+        .iter()
+        .find_map(|(prefix, namespace)| {
+            term.strip_prefix(prefix)
+                .map(|local| format!("{namespace}{local}"))
+        })
+        .unwrap_or_else(|| term.to_string())
+}
+
 /// Entity and relation extractor
 pub struct RelationExtractor {
     /// Configuration
@@ -1827,7 +1982,7 @@ impl RelationExtractor {
         all_relations.extend(relations);
 
         ExtractionResult {
-            article_id: format!("{}_{}", article.oid, article.aid),
+            article_id: article.id(),
             entities: all_entities,
             relations: all_relations,
         }
@@ -1963,50 +2118,7 @@ impl LlmSaidExtractor {
     /// The quote content is trusted from the LLM since it may paraphrase or summarize.
     pub async fn extract(&self, text: &str) -> Result<Vec<ExtractedRelation>> {
         let said_relations = self.client.extract_said_relations(text).await?;
-
-        let mut extracted = Vec::new();
-        for said in said_relations {
-            // For Said relations, only verify the speaker exists in the text
-            // We create a minimal relation with just the speaker for verification
-            let speaker_only = ExtractedRelation {
-                subject: said.speaker.clone(),
-                subject_type: EntityType::Person,
-                predicate: RelationType::Said,
-                object: String::new(), // Empty - don't verify content
-                object_type: EntityType::Other,
-                confidence: said.confidence,
-                evidence: String::new(), // Empty - don't verify evidence
-                verified: false,
-            };
-
-            // Verify only the speaker name exists in text
-            let verification = self.verifier.verify(&speaker_only, text);
-
-            // Accept if speaker is found OR if LLM confidence is high (>= 0.8)
-            let should_accept = verification.subject_match.found || said.confidence >= 0.8;
-
-            if should_accept {
-                // Use original confidence, slightly adjusted based on speaker match
-                let final_confidence = if verification.subject_match.found {
-                    said.confidence // Keep original confidence if speaker found
-                } else {
-                    said.confidence * 0.9 // Small penalty if speaker not found but high confidence
-                };
-
-                extracted.push(ExtractedRelation {
-                    subject: said.speaker,
-                    subject_type: EntityType::Person,
-                    predicate: RelationType::Said,
-                    object: said.content,
-                    object_type: EntityType::Other,
-                    confidence: final_confidence,
-                    evidence: said.evidence,
-                    verified: verification.subject_match.found, // Only verified if speaker found
-                });
-            }
-        }
-
-        Ok(extracted)
+        Ok(self.verifier.verify_said(said_relations, text))
     }
 
     /// Extract Said relations from a parsed article
@@ -2463,11 +2575,25 @@ mod tests {
 
         let triple = Triple::from_relation(&relation, "test");
         let ntriples = triple.to_ntriples();
-        assert!(ntriples.contains("<"));
-        assert!(ntriples.contains(">"));
+        assert!(ntriples.starts_with("<https://baram.example.org/ontology/entity/test/"));
+        assert!(ntriples.contains("<https://schema.org/author>"));
         assert!(ntriples.ends_with(" ."));
     }
 
+    #[test]
+    fn test_expand_iri() {
+        assert_eq!(expand_iri("schema:worksFor"), "https://schema.org/worksFor");
+        assert_eq!(
+            expand_iri("wd:Q20718"),
+            "http://www.wikidata.org/entity/Q20718"
+        );
+        assert_eq!(
+            expand_iri("http://example.org/entity/A"),
+            "http://example.org/entity/A"
+        );
+        assert_eq!(expand_iri("unknown:x"), "unknown:x");
+    }
+
     #[test]
     fn test_triple_store_from_extraction() {
         let result = ExtractionResult {
@@ -2495,11 +2621,80 @@ mod tests {
 
         let store = TripleStore::from_extraction(&result, "테스트 기사");
         assert_eq!(store.triples.len(), 1);
+        assert_eq!(store.triples[0].source, RelationSource::Regex);
         assert_eq!(store.stats.total_entities, 1);
         assert_eq!(store.stats.total_relations, 1);
         assert_eq!(store.stats.verified_relations, 1);
     }
 
+    #[test]
+    fn test_triple_store_llm_relations() {
+        let text = "이재용 회장은 \"투자를 늘리겠다\"고 말했다.";
+        let said = |speaker: &str, confidence| crate::llm::SaidRelation {
+            speaker: speaker.to_string(),
+            content: "투자를 늘리겠다".to_string(),
+            confidence,
+            evidence: text.to_string(),
+        };
+        let relations = HallucinationVerifier::for_said_relations().verify_said(
+            vec![
+                said("이재용", 0.7),
+                said("홍길동", 0.9),
+                said("김철수", 0.5),
+            ],
+            text,
+        );
+        // Unknown speakers are only kept when the LLM was confident
+        assert_eq!(relations.len(), 2);
+        assert!(relations[0].verified);
+        assert!(!relations[1].verified);
+
+        let result = ExtractionResult {
+            article_id: "001_0001".to_string(),
+            entities: vec![],
+            relations: vec![],
+        };
+        let mut store = TripleStore::from_extraction(&result, "테스트 기사");
+        let verified: Vec<_> = relations.into_iter().filter(|r| r.verified).collect();
+        store.add_relations(&verified, RelationSource::Llm);
+
+        assert_eq!(store.stats.total_relations, 1);
+        assert_eq!(store.stats.verified_relations, 1);
+        assert_eq!(store.triples[0].source, RelationSource::Llm);
+        let json = serde_json::to_value(&store.triples[0]).unwrap();
+        assert_eq!(json["source"], "llm");
+    }
+
+    #[test]
+    fn test_triple_store_published_at() {
+        let result = ExtractionResult {
+            article_id: "001_0001".to_string(),
+            entities: vec![],
+            relations: vec![],
+        };
+        let published = chrono::Utc::now() - chrono::Duration::days(3);
+
+        let store = TripleStore::from_extraction(&result, "Test");
+        assert!(store.published_at.is_none());
+        assert!(store.timestamp().unwrap() > published);
+
+        let store = store.with_published_at(Some(published));
+        assert_eq!(
+            store.timestamp().unwrap().timestamp(),
+            published.timestamp()
+        );
+
+        // Exports from `baram ontology` omit the JSON-LD context and entities
+        let json = r#"{"article_id": "a", "article_title": "t",
+            "extracted_at": "2024-01-15T09:00:00+09:00", "triples": []}"#;
+        let parsed: TripleStore = serde_json::from_str(json).unwrap();
+        assert!(parsed.entities.is_empty());
+        assert_eq!(
+            parsed.timestamp().unwrap().to_rfc3339(),
+            "2024-01-15T00:00:00+00:00"
+        );
+    }
+
     #[test]
     fn test_triple_store_to_json_ld() {
         let result = ExtractionResult {
@@ -11,7 +11,7 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::extractor::{EntityType, ExtractedEntity, ExtractionResult, TripleStore};
+use super::extractor::{expand_iri, EntityType, ExtractedEntity, ExtractionResult, TripleStore};
 
 /// Entity linking configuration
 #[derive(Debug, Clone)]
@@ -1049,11 +1049,13 @@ pub struct LinkedTriple {
 }
 
 impl LinkedTriple {
-    /// Convert to N-Triples format
+    /// Convert to N-Triples format, prefixed names expanded to IRIs
     pub fn to_ntriples(&self) -> String {
         format!(
             "<{}> <{}> <{}> .",
-            self.subject_uri, self.predicate_uri, self.object_uri
+            expand_iri(&self.subject_uri),
+            expand_iri(&self.predicate_uri),
+            expand_iri(&self.object_uri)
         )
     }
 
@@ -1174,8 +1176,11 @@ impl LinkedTripleStore {
     }
 
     /// Export to JSON-LD format with @graph
+    ///
+    /// The graph holds a node per linked entity followed by a node per
+    /// relation, so loading it yields the same statements as [`Self::to_ntriples`].
     pub fn to_json_ld(&self) -> Result<String> {
-        let graph: Vec<serde_json::Value> = self
+        let mut graph: Vec<serde_json::Value> = self
             .entities
             .iter()
             .filter_map(|e| {
@@ -1200,6 +1205,13 @@ impl LinkedTripleStore {
             })
             .collect();
 
+        graph.extend(self.triples.iter().map(|t| {
+            let mut obj = serde_json::json!({ "@id": expand_iri(&t.subject_uri) });
+            obj[expand_iri(&t.predicate_uri)] =
+                serde_json::json!({ "@id": expand_iri(&t.object_uri) });
+            obj
+        }));
+
         let json_ld = serde_json::json!({
             "@context": {
                 "rdf": "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
@@ -1290,6 +1302,40 @@ impl LinkedTripleStore {
     }
 }
 
+impl From<&TripleStore> for LinkedTripleStore {
+    /// Carry the triples over without linking; entities keep their
+    /// article-local URIs
+    fn from(store: &TripleStore) -> Self {
+        let triples = store
+            .triples
+            .iter()
+            .map(|t| LinkedTriple {
+                subject_uri: t.subject_id.clone(),
+                subject: t.subject.clone(),
+                subject_canonical: t.subject.clone(),
+                subject_type: t.subject_type,
+                predicate_uri: t.predicate.clone(),
+                predicate_label: t.predicate_label.clone(),
+                object_uri: t.object_id.clone(),
+                object: t.object.clone(),
+                object_canonical: t.object.clone(),
+                object_type: t.object_type,
+                confidence: t.confidence,
+                evidence: t.evidence.clone(),
+                verified: t.verified,
+            })
+            .collect();
+
+        Self {
+            article_id: store.article_id.clone(),
+            article_title: store.article_title.clone(),
+            extracted_at: store.extracted_at.clone(),
+            entities: Vec::new(),
+            triples,
+        }
+    }
+}
+
 /// Escape string for Turtle format
 fn escape_turtle_string(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -1618,6 +1664,7 @@ mod tests {
 
         let ntriples = triple.to_ntriples();
         assert!(ntriples.contains("<http://example.org/entity/A>"));
+        assert!(ntriples.contains("<https://schema.org/worksFor>"));
         assert!(ntriples.ends_with(" ."));
     }
 
@@ -1665,13 +1712,39 @@ mod tests {
                 rdf_uri: Some("http://www.wikidata.org/entity/Q8684".to_string()),
                 in_knowledge_base: true,
             }],
-            triples: vec![],
+            triples: vec![LinkedTriple {
+                subject_uri: "baram:entity/test_001/시청".to_string(),
+                subject: "시청".to_string(),
+                subject_canonical: "시청".to_string(),
+                subject_type: EntityType::Organization,
+                predicate_uri: "schema:location".to_string(),
+                predicate_label: "위치".to_string(),
+                object_uri: "http://www.wikidata.org/entity/Q8684".to_string(),
+                object: "서울".to_string(),
+                object_canonical: "서울".to_string(),
+                object_type: EntityType::Location,
+                confidence: 0.8,
+                evidence: None,
+                verified: true,
+            }],
         };
 
         let json_ld = store.to_json_ld().unwrap();
         assert!(json_ld.contains("@context"));
         assert!(json_ld.contains("@graph"));
         assert!(json_ld.contains("wikidata.org"));
+
+        let value: serde_json::Value = serde_json::from_str(&json_ld).unwrap();
+        let graph = value["@graph"].as_array().unwrap();
+        assert_eq!(graph.len(), 2);
+        assert_eq!(
+            graph[1]["@id"],
+            "https://baram.example.org/ontology/entity/test_001/시청"
+        );
+        assert_eq!(
+            graph[1]["https://schema.org/location"]["@id"],
+            "http://www.wikidata.org/entity/Q8684"
+        );
     }
 
     #[test]
@@ -18,8 +18,10 @@
 //! - [`extractor`] - Entity and relation extraction using regex patterns and LLM
 //! - [`linker`] - Entity linking and normalization with Wikidata/DBpedia knowledge base
 //! - [`storage`] - Triple persistence and indexing with JSON storage
+//! - [`query`] - Triple-pattern queries with confidence and date filters
 //! - [`error`] - Custom error types for ontology operations
 //! - [`stats`] - Statistics and profiling for extraction pipelines
+//! - [`eval`] - Precision/recall of extraction against gold-annotated articles
 //!
 //! # Quick Start
 //!
@@ -122,8 +124,10 @@ use crate::parser::Article;
 
 // Submodules
 pub mod error;
+pub mod eval;
 pub mod extractor;
 pub mod linker;
+pub mod query;
 pub mod stats;
 pub mod storage;
 
@@ -134,8 +138,8 @@ pub use error::{OntologyError, OntologyResult};
 pub use extractor::{
     EntitySource, EntityType, ExtractedEntity, ExtractedRelation, ExtractionConfig,
     ExtractionConfigBuilder, ExtractionResult, LlmEntityResponse, LlmExtractionResponse,
-    LlmRelationResponse, PromptTemplate, RelationExtractor, RelationType, Triple, TripleContext,
-    TripleStats, TripleStore,
+    LlmRelationResponse, PromptTemplate, RelationExtractor, RelationSource, RelationType, Triple,
+    TripleContext, TripleStats, TripleStore,
 };
 
 // Re-export verification types from extractor
@@ -158,6 +162,9 @@ pub use storage::{
     IndexEntry, StorageConfig, StorageConfigBuilder, StorageIndex, StorageStats, TripleStorage,
 };
 
+// Re-export query types
+pub use query::{QueryMatch, TriplePattern, TripleQuery};
+
 // Re-export stats types
 pub use stats::{
     format_bytes, parse_bytes, BatchStats, ExtractionStats, MemoryEstimator, PipelineProfiler,
@@ -0,0 +1,295 @@
+//! Triple-pattern queries over extracted triples
+//!
+//! A small subset of SPARQL for the local knowledge graph: each of subject,
+//! predicate and object is either unbound or a pattern, and matching triples
+//! can be narrowed by confidence, verification and article date.
+//!
+//! Patterns are case-insensitive. `*` matches any run of characters and a
+//! pattern that is just `*` or a SPARQL-style variable such as `?who` leaves
+//! the position unbound. Predicates match the RDF predicate
+//! (`schema:worksFor`), its local name (`worksFor`), the Korean label, or a
+//! relation type name understood by [`RelationType::from_string`] (`said`).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use baram::ontology::{TriplePattern, TripleQuery};
+//!
+//! let query = TripleQuery {
+//!     pattern: TriplePattern::new(None, Some("said"), Some("*반도체*")),
+//!     min_confidence: 0.7,
+//!     ..Default::default()
+//! };
+//! let matches = storage.query(&query)?;
+//! ```
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::cmp::Reverse;
+
+use super::extractor::{RelationType, Triple, TripleStore};
+
+/// Subject, predicate and object patterns; `None` matches anything
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TriplePattern {
+    /// Subject pattern
+    pub subject: Option<String>,
+    /// Predicate pattern
+    pub predicate: Option<String>,
+    /// Object pattern
+    pub object: Option<String>,
+}
+
+impl TriplePattern {
+    /// Create a pattern; `*` and `?variable` positions are left unbound
+    pub fn new(subject: Option<&str>, predicate: Option<&str>, object: Option<&str>) -> Self {
+        let bound = |term: Option<&str>| {
+            term.map(str::trim)
+                .filter(|t| !t.is_empty() && *t != "*" && !t.starts_with('?'))
+                .map(str::to_string)
+        };
+        Self {
+            subject: bound(subject),
+            predicate: bound(predicate),
+            object: bound(object),
+        }
+    }
+
+    /// Whether the triple matches every bound position
+    pub fn matches(&self, triple: &Triple) -> bool {
+        let term = |pattern: &Option<String>, value: &str| {
+            pattern
+                .as_deref()
+                .map_or(true, |p| wildcard_match(p, value))
+        };
+        term(&self.subject, &triple.subject)
+            && term(&self.object, &triple.object)
+            && self
+                .predicate
+                .as_deref()
+                .map_or(true, |p| predicate_matches(p, triple))
+    }
+}
+
+/// A triple pattern with result filters
+#[derive(Debug, Clone, Default)]
+pub struct TripleQuery {
+    /// Triple pattern to match
+    pub pattern: TriplePattern,
+    /// Minimum triple confidence
+    pub min_confidence: f32,
+    /// Only triples that passed hallucination verification
+    pub verified_only: bool,
+    /// Earliest article date (inclusive)
+    pub since: Option<NaiveDate>,
+    /// Latest article date (inclusive)
+    pub until: Option<NaiveDate>,
+    /// Maximum number of matches (0 = unlimited)
+    pub limit: usize,
+}
+
+impl TripleQuery {
+    /// Whether the article falls inside the date range
+    ///
+    /// Uses the publication date, falling back to the extraction date.
+    /// Articles without a parsable date only match an open range.
+    fn in_range(&self, store: &TripleStore) -> bool {
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let Some(date) = store.timestamp().map(|t| t.date_naive()) else {
+            return false;
+        };
+        self.since.map_or(true, |since| date >= since)
+            && self.until.map_or(true, |until| date <= until)
+    }
+
+    /// Matching triples across `stores`, newest article first
+    pub fn run<'a>(&self, stores: impl IntoIterator<Item = &'a TripleStore>) -> Vec<QueryMatch> {
+        let mut matches: Vec<QueryMatch> = stores
+            .into_iter()
+            .filter(|store| self.in_range(store))
+            .flat_map(|store| {
+                store
+                    .triples
+                    .iter()
+                    .filter(|t| t.confidence >= self.min_confidence)
+                    .filter(|t| !self.verified_only || t.verified)
+                    .filter(|t| self.pattern.matches(t))
+                    .map(|t| QueryMatch {
+                        article_id: store.article_id.clone(),
+                        article_title: store.article_title.clone(),
+                        date: store.timestamp().map(|t| t.date_naive()),
+                        triple: t.clone(),
+                    })
+            })
+            .collect();
+
+        matches.sort_by_key(|m| Reverse(m.date));
+        if self.limit > 0 {
+            matches.truncate(self.limit);
+        }
+        matches
+    }
+}
+
+/// A matching triple with the article it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryMatch {
+    /// Article ID
+    pub article_id: String,
+    /// Article title
+    pub article_title: String,
+    /// Article date (publication, else extraction)
+    pub date: Option<NaiveDate>,
+    /// The matching triple
+    pub triple: Triple,
+}
+
+/// Case-insensitive match where `*` stands for any run of characters
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*`: the whole text must equal the pattern
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Match a predicate pattern against a triple's predicate and label
+fn predicate_matches(pattern: &str, triple: &Triple) -> bool {
+    let local = triple
+        .predicate
+        .rsplit_once(':')
+        .map_or(triple.predicate.as_str(), |(_, local)| local);
+    if [triple.predicate.as_str(), local, &triple.predicate_label]
+        .iter()
+        .any(|value| wildcard_match(pattern, value))
+    {
+        return true;
+    }
+
+    !pattern.contains('*')
+        && match RelationType::from_string(pattern) {
+            RelationType::Unknown => false,
+            relation => relation.rdf_predicate() == triple.predicate,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::extractor::{EntityType, ExtractedRelation, ExtractionResult};
+
+    fn store(
+        article_id: &str,
+        published: &str,
+        relations: &[(&str, RelationType, &str, f32)],
+    ) -> TripleStore {
+        let result = ExtractionResult {
+            article_id: article_id.to_string(),
+            entities: vec![],
+            relations: relations
+                .iter()
+                .map(
+                    |(subject, predicate, object, confidence)| ExtractedRelation {
+                        subject: subject.to_string(),
+                        subject_type: EntityType::Person,
+                        predicate: *predicate,
+                        object: object.to_string(),
+                        object_type: EntityType::Organization,
+                        confidence: *confidence,
+                        evidence: String::new(),
+                        verified: *confidence >= 0.8,
+                    },
+                )
+                .collect(),
+        };
+        let mut store = TripleStore::from_extraction(&result, article_id);
+        store.published_at = Some(published.to_string());
+        store
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("삼성전자", "삼성전자"));
+        assert!(!wildcard_match("삼성", "삼성전자"));
+        assert!(wildcard_match("삼성*", "삼성전자"));
+        assert!(wildcard_match("*전자", "삼성전자"));
+        assert!(wildcard_match("*성전*", "삼성전자"));
+        assert!(wildcard_match("S*G", "samsung"));
+        assert!(!wildcard_match("a*a", "a"));
+        assert!(wildcard_match("*", ""));
+    }
+
+    #[test]
+    fn test_pattern_unbound_positions() {
+        let pattern = TriplePattern::new(Some("?who"), Some("*"), Some(" "));
+        assert_eq!(pattern, TriplePattern::default());
+    }
+
+    #[test]
+    fn test_query_pattern_and_filters() {
+        let stores = vec![
+            store(
+                "a1",
+                "2026-10-01T09:00:00+09:00",
+                &[
+                    ("이재용", RelationType::WorksFor, "삼성전자", 0.9),
+                    ("홍길동", RelationType::Said, "반도체 투자 확대", 0.6),
+                ],
+            ),
+            store(
+                "a2",
+                "2026-10-10T09:00:00+09:00",
+                &[("김철수", RelationType::WorksFor, "삼성SDI", 0.85)],
+            ),
+        ];
+
+        let query = TripleQuery {
+            pattern: TriplePattern::new(None, Some("worksFor"), Some("삼성*")),
+            ..Default::default()
+        };
+        let matches = query.run(&stores);
+        assert_eq!(matches.len(), 2);
+        // Newest article first
+        assert_eq!(matches[0].article_id, "a2");
+
+        let query = TripleQuery {
+            pattern: TriplePattern::new(None, Some("said"), None),
+            ..Default::default()
+        };
+        assert_eq!(query.run(&stores)[0].triple.subject, "홍길동");
+
+        let query = TripleQuery {
+            min_confidence: 0.7,
+            verified_only: true,
+            since: NaiveDate::from_ymd_opt(2026, 10, 5),
+            ..Default::default()
+        };
+        let matches = query.run(&stores);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].triple.subject, "김철수");
+
+        let query = TripleQuery {
+            limit: 1,
+            ..Default::default()
+        };
+        assert_eq!(query.run(&stores).len(), 1);
+    }
+}
@@ -14,6 +14,7 @@ use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 use super::extractor::{ExtractionResult, TripleStore};
+use super::query::{QueryMatch, TripleQuery};
 
 /// Storage configuration
 #[derive(Debug, Clone)]
@@ -481,6 +482,16 @@ impl TripleStorage {
         Ok(stores)
     }
 
+    /// Run a triple-pattern query over every stored article
+    pub fn query(&self, query: &TripleQuery) -> Result<Vec<QueryMatch>> {
+        let ids: Vec<&str> = self
+            .list_articles()
+            .into_iter()
+            .map(String::as_str)
+            .collect();
+        Ok(query.run(&self.load_batch(&ids)?))
+    }
+
     /// Export all triples to a single JSON file
     pub fn export_all(&self, output_path: &Path) -> Result<usize> {
         let mut all_stores: Vec<TripleStore> = Vec::new();
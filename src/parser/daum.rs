@@ -0,0 +1,234 @@
+//! Daum News article parser
+//!
+//! Parses article pages at `v.daum.net/v/{id}`. The page carries the title in
+//! `h3.tit_view`, the body as `p` elements under `.article_view section`, and
+//! the publisher, publication time and section in `og:`/`article:` meta tags.
+//! Photos are the `img` elements of the body.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use lazy_static::lazy_static;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::models::ParsedArticle;
+use crate::parser::media::body_images;
+use crate::parser::sanitize::{has_content, sanitize_text};
+use crate::utils::error::ParseError;
+
+macro_rules! parse_selector {
+    ($s:expr) => {
+        Selector::parse($s).expect(concat!("Invalid CSS selector: ", $s))
+    };
+}
+
+lazy_static! {
+    static ref TITLE: Selector = parse_selector!("h3.tit_view");
+    static ref OG_TITLE: Selector = parse_selector!(r#"meta[property="og:title"]"#);
+    static ref BODY: Selector = parse_selector!(".article_view section");
+    static ref BODY_FALLBACK: Selector = parse_selector!(".article_view");
+    static ref PARAGRAPH: Selector = parse_selector!("p");
+    static ref REG_DATE: Selector = parse_selector!(r#"meta[property="og:regDate"]"#);
+    static ref NUM_DATE: Selector = parse_selector!(".num_date");
+    static ref PUBLISHER: Selector = parse_selector!(r#"meta[property="og:article:author"]"#);
+    static ref PUBLISHER_LOGO: Selector = parse_selector!("#kakaoServiceLogo");
+    static ref INFO: Selector = parse_selector!(".info_view .txt_info");
+    static ref SECTION: Selector =
+        parse_selector!(r#"meta[property="article:section"], meta[name="article:section"]"#);
+}
+
+/// Daum News article parser
+///
+/// Fills in everything but the source and IDs, which come from the URL
+/// (see [`crate::crawler::sites::DaumSite`]). `category` is the section name
+/// shown on the page, e.g. `국제`.
+pub struct DaumParser;
+
+impl DaumParser {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse the Daum article page at `url`, which relative image URLs are
+    /// resolved against
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::TitleNotFound` or `ParseError::ContentNotFound`
+    /// if the page has no title or no article text
+    pub fn parse(&self, html: &str, url: &str) -> Result<ParsedArticle, ParseError> {
+        let document = Html::parse_document(html);
+
+        let title = text(&document, &TITLE)
+            .or_else(|| meta(&document, &OG_TITLE))
+            .ok_or(ParseError::TitleNotFound)?;
+
+        let content = content(&document).ok_or(ParseError::ContentNotFound)?;
+
+        let published_at = meta(&document, &REG_DATE)
+            .and_then(|d| parse_date(&d, "%Y%m%d%H%M%S"))
+            .or_else(|| {
+                text(&document, &NUM_DATE).and_then(|d| parse_date(&d, "%Y. %m. %d. %H:%M"))
+            });
+
+        let publisher = meta(&document, &PUBLISHER).or_else(|| text(&document, &PUBLISHER_LOGO));
+
+        let author = document.select(&INFO).map(element_text).find(|info| {
+            !info.is_empty() && !info.starts_with("입력") && !info.starts_with("수정")
+        });
+
+        let images = body(&document)
+            .map(|body| body_images(body, Some(url)))
+            .unwrap_or_default();
+
+        Ok(ParsedArticle {
+            title: sanitize_text(&title),
+            content,
+            images,
+            category: meta(&document, &SECTION).unwrap_or_default(),
+            publisher,
+            author,
+            published_at,
+            crawled_at: Utc::now(),
+            ..Default::default()
+        })
+    }
+}
+
+impl Default for DaumParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn element_text(element: ElementRef<'_>) -> String {
+    element.text().collect::<String>().trim().to_string()
+}
+
+/// Trimmed text of the first non-empty match
+fn text(document: &Html, selector: &Selector) -> Option<String> {
+    document
+        .select(selector)
+        .map(element_text)
+        .find(|t| !t.is_empty())
+}
+
+/// Trimmed `content` of the first matching meta tag
+fn meta(document: &Html, selector: &Selector) -> Option<String> {
+    document
+        .select(selector)
+        .filter_map(|el| el.value().attr("content"))
+        .map(str::trim)
+        .find(|c| !c.is_empty())
+        .map(str::to_string)
+}
+
+/// Article body element
+fn body(document: &Html) -> Option<ElementRef<'_>> {
+    document
+        .select(&BODY)
+        .next()
+        .or_else(|| document.select(&BODY_FALLBACK).next())
+}
+
+/// Article paragraphs, one per blank-line separated block
+fn content(document: &Html) -> Option<String> {
+    let body = body(document)?;
+
+    let paragraphs: Vec<String> = body
+        .select(&PARAGRAPH)
+        .map(element_text)
+        .filter(|p| !p.is_empty())
+        .collect();
+    let content = if paragraphs.is_empty() {
+        element_text(body)
+    } else {
+        paragraphs.join("\n\n")
+    };
+
+    let content = sanitize_text(&content);
+    has_content(&content).then_some(content)
+}
+
+/// Page times are KST, stored as they are like Naver's
+fn parse_date(date: &str, format: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(date.trim(), format)
+        .ok()
+        .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_article() {
+        let html = r#"<html><head>
+            <meta property="og:title" content="OG 제목">
+            <meta property="og:article:author" content="연합뉴스">
+            <meta property="og:regDate" content="20240115093000">
+            <meta property="article:section" content="국제">
+            </head><body>
+            <h3 class="tit_view">미 연준, 기준금리 동결</h3>
+            <div class="info_view">
+              <span class="txt_info">홍길동 기자</span>
+              <span class="txt_info">입력 <span class="num_date">2024. 1. 15. 09:30</span></span>
+            </div>
+            <div class="article_view"><section>
+              <p dmcf-ptype="general">미국 연방준비제도가 기준금리를 동결했다.</p>
+              <p dmcf-ptype="general"></p>
+              <p dmcf-ptype="general">시장은 3월 인하를 점치고 있다.</p>
+              <figure><img src="/thumb/fed.jpg"></figure>
+            </section></div>
+            </body></html>"#;
+
+        let article = DaumParser::new()
+            .parse(html, "https://v.daum.net/v/20240115093000123")
+            .unwrap();
+        assert_eq!(article.title, "미 연준, 기준금리 동결");
+        assert_eq!(
+            article.content,
+            "미국 연방준비제도가 기준금리를 동결했다.\n\n시장은 3월 인하를 점치고 있다."
+        );
+        assert_eq!(article.publisher.as_deref(), Some("연합뉴스"));
+        assert_eq!(article.author.as_deref(), Some("홍길동 기자"));
+        assert_eq!(article.category, "국제");
+        assert_eq!(article.images[0].url, "https://v.daum.net/thumb/fed.jpg");
+        assert_eq!(
+            article
+                .published_at
+                .unwrap()
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            "2024-01-15 09:30"
+        );
+    }
+
+    #[test]
+    fn test_parse_fallbacks_and_errors() {
+        let html = r#"<html><head><meta property="og:title" content="OG 제목"></head><body>
+            <span class="num_date">2024. 01. 15. 09:30</span>
+            <div class="article_view">본문만 있는 기사</div>
+            </body></html>"#;
+        let url = "https://v.daum.net/v/20240115093000123";
+        let article = DaumParser::new().parse(html, url).unwrap();
+        assert_eq!(article.title, "OG 제목");
+        assert_eq!(article.content, "본문만 있는 기사");
+        assert!(article.published_at.is_some());
+
+        let parser = DaumParser::new();
+        assert!(matches!(
+            parser.parse(
+                "<html><body><div class=\"article_view\">x</div></body></html>",
+                url
+            ),
+            Err(ParseError::TitleNotFound)
+        ));
+        assert!(matches!(
+            parser.parse(
+                "<html><body><h3 class=\"tit_view\">제목</h3></body></html>",
+                url
+            ),
+            Err(ParseError::ContentNotFound)
+        ));
+    }
+}
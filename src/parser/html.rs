@@ -7,7 +7,8 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 use scraper::{Html, Selector};
 
 use crate::crawler::url::UrlExtractor;
-use crate::models::ParsedArticle;
+use crate::models::{ArticleMedia, ParsedArticle};
+use crate::parser::media::body_images;
 use crate::parser::sanitize::{has_content, sanitize_text};
 use crate::parser::selectors::{
     ArticleFormat, CardNewsSelectors, EntertainmentSelectors, GeneralSelectors, NoiseSelectors,
@@ -163,6 +164,7 @@ impl ArticleParser {
             title: sanitize_text(&title),
             content: sanitize_text(&content),
             url: url.to_string(),
+            images: self.extract_images(document, &self.general.content, url),
             published_at: date.and_then(|d| self.parse_date(&d)),
             publisher,
             author,
@@ -193,6 +195,7 @@ impl ArticleParser {
             title: sanitize_text(&title),
             content: sanitize_text(&content),
             url: url.to_string(),
+            images: self.extract_images(document, &self.entertainment.content, url),
             category: "entertainment".to_string(),
             published_at: date.and_then(|d| self.parse_date(&d)),
             publisher,
@@ -224,6 +227,7 @@ impl ArticleParser {
             title: sanitize_text(&title),
             content: sanitize_text(&content),
             url: url.to_string(),
+            images: self.extract_images(document, &self.sports.content, url),
             category: "sports".to_string(),
             published_at: date.and_then(|d| self.parse_date(&d)),
             publisher,
@@ -249,6 +253,7 @@ impl ArticleParser {
             title: sanitize_text(&title),
             content: sanitize_text(&content),
             url: url.to_string(),
+            images: self.extract_images(document, &self.card.content, url),
             category: "card".to_string(),
             crawled_at: Utc::now(),
             ..Default::default()
@@ -287,6 +292,20 @@ impl ArticleParser {
         None
     }
 
+    /// Images of the first matching content element
+    fn extract_images(
+        &self,
+        document: &Html,
+        selectors: &[Selector],
+        url: &str,
+    ) -> Vec<ArticleMedia> {
+        selectors
+            .iter()
+            .find_map(|selector| document.select(selector).next())
+            .map(|body| body_images(body, Some(url)))
+            .unwrap_or_default()
+    }
+
     /// Extract publisher name from img alt or text
     fn extract_publisher(&self, document: &Html, selectors: &[Selector]) -> Option<String> {
         for selector in selectors {
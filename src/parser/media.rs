@@ -0,0 +1,123 @@
+//! Images embedded in article bodies
+//!
+//! Both portals lazy-load photos, so the real URL is in `data-src` more
+//! often than in `src`. A photo's caption is the `em.img_desc` or
+//! `figcaption` of the block around it (`.end_photo_org` on Naver,
+//! `figure` on Daum), else the image's `alt` text.
+
+use lazy_static::lazy_static;
+use scraper::{ElementRef, Selector};
+use url::Url;
+
+use crate::models::ArticleMedia;
+use crate::parser::sanitize::has_content;
+
+macro_rules! parse_selector {
+    ($s:expr) => {
+        Selector::parse($s).expect(concat!("Invalid CSS selector: ", $s))
+    };
+}
+
+lazy_static! {
+    static ref IMAGE: Selector = parse_selector!("img");
+    static ref PHOTO_BLOCK: Selector = parse_selector!(".end_photo_org, figure");
+    static ref CAPTION: Selector = parse_selector!("em.img_desc, figcaption");
+}
+
+/// Images in `body`, in page order and without repeats
+///
+/// Relative URLs are resolved against `page_url`; images without a usable
+/// URL (missing, inline `data:`) are left out.
+pub fn body_images(body: ElementRef<'_>, page_url: Option<&str>) -> Vec<ArticleMedia> {
+    let base = page_url.and_then(|url| Url::parse(url).ok());
+    let mut images: Vec<ArticleMedia> = Vec::new();
+    for image in body.select(&IMAGE) {
+        let Some(url) = image_url(image, base.as_ref()) else {
+            continue;
+        };
+        if images.iter().any(|known| known.url == url) {
+            continue;
+        }
+        images.push(ArticleMedia {
+            url,
+            caption: caption(image, body),
+            local_path: None,
+        });
+    }
+    images
+}
+
+fn image_url(image: ElementRef<'_>, base: Option<&Url>) -> Option<String> {
+    let element = image.value();
+    let src = ["data-src", "data-original", "src"]
+        .iter()
+        .filter_map(|name| element.attr(name))
+        .map(str::trim)
+        .find(|src| !src.is_empty() && !src.starts_with("data:"))?;
+    let url = match Url::parse(src) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => base?.join(src).ok()?,
+        Err(_) => return None,
+    };
+    matches!(url.scheme(), "http" | "https").then(|| url.to_string())
+}
+
+/// Caption of the photo block around `image` (inside `body`), else its `alt`
+fn caption(image: ElementRef<'_>, body: ElementRef<'_>) -> Option<String> {
+    let block = image
+        .ancestors()
+        .take_while(|node| node.id() != body.id())
+        .filter_map(ElementRef::wrap)
+        .find(|element| PHOTO_BLOCK.matches(element));
+    let text = block
+        .and_then(|block| block.select(&CAPTION).next())
+        .map(|caption| caption.text().collect::<String>())
+        .or_else(|| image.value().attr("alt").map(str::to_string))?;
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    has_content(&text).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::Html;
+
+    #[test]
+    fn test_body_images() {
+        let html = r#"<div id="dic_area">
+            <span class="end_photo_org">
+                <div><img data-src="https://imgnews.pstatic.net/image/001/a.jpg?type=w860" src="data:image/gif;base64,R0l"></div>
+                <em class="img_desc">  서울 도심
+                  전경  </em>
+            </span>
+            본문
+            <img src="/static/b.png" alt="도표">
+            <img src="https://imgnews.pstatic.net/image/001/a.jpg?type=w860">
+            <img src="data:image/gif;base64,R0l">
+        </div>"#;
+        let document = Html::parse_document(html);
+        let body = document
+            .select(&Selector::parse("#dic_area").unwrap())
+            .next()
+            .unwrap();
+
+        let images = body_images(body, Some("https://n.news.naver.com/mnews/article/001/1"));
+        assert_eq!(
+            images,
+            vec![
+                ArticleMedia {
+                    url: "https://imgnews.pstatic.net/image/001/a.jpg?type=w860".to_string(),
+                    caption: Some("서울 도심 전경".to_string()),
+                    local_path: None,
+                },
+                ArticleMedia {
+                    url: "https://n.news.naver.com/static/b.png".to_string(),
+                    caption: Some("도표".to_string()),
+                    local_path: None,
+                },
+            ]
+        );
+        // Relative URLs need the page URL
+        assert_eq!(body_images(body, None).len(), 1);
+    }
+}
@@ -3,7 +3,9 @@
 //! This module handles parsing Naver News HTML pages and extracting
 //! structured article data.
 
+pub mod daum;
 pub mod html;
+pub mod media;
 pub mod sanitize;
 pub mod selectors;
 
@@ -0,0 +1,378 @@
+//! Python bindings (`python` feature)
+//!
+//! Builds the `baram` Python extension module with [maturin], so notebooks can
+//! parse articles, embed text, search the index and extract knowledge-graph
+//! triples without spawning the CLI:
+//!
+//! ```python
+//! import baram
+//!
+//! article = baram.parse_article(html, "https://n.news.naver.com/mnews/article/001/0014123456")
+//! graph = baram.extract_ontology(article["title"], article["content"])
+//!
+//! embedder = baram.EmbeddingClient("http://localhost:8090")
+//! store = baram.VectorStore("http://localhost:9200", "baram-articles")
+//! hits = store.search("반도체 수출", k=5, embedding=embedder.embed("반도체 수출"))
+//! ```
+//!
+//! Results are returned as plain `dict`/`list` values with the same fields as
+//! the JSON the CLI writes. Network calls release the GIL while they run.
+//!
+//! [maturin]: https://www.maturin.rs
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::OnceLock;
+
+use crate::config::OpenSearchConfig;
+use crate::embedding::SearchConfig;
+use crate::models::ParsedArticle;
+use crate::ontology::{RelationExtractor, TripleStore};
+use crate::parser::ArticleParser;
+
+/// Runtime driving the async clients for every call from Python
+fn runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start the tokio runtime")
+    })
+}
+
+/// Run `future` to completion without holding the GIL
+fn block_on<F>(py: Python<'_>, future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    py.allow_threads(|| runtime().block_on(future))
+}
+
+/// Convert a serializable value to Python objects through `json.loads`
+fn to_py(py: Python<'_>, value: &impl Serialize) -> PyResult<PyObject> {
+    let json = serde_json::to_string(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
+fn runtime_error(e: anyhow::Error) -> PyErr {
+    PyRuntimeError::new_err(format!("{e:#}"))
+}
+
+/// Parse a Naver News article page into a dict
+///
+/// Raises `ValueError` if the page is not a recognizable article.
+#[pyfunction]
+fn parse_article(py: Python<'_>, html: &str, url: &str) -> PyResult<PyObject> {
+    let article = ArticleParser::new()
+        .parse_with_fallback(html, url)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    to_py(py, &article)
+}
+
+fn article(title: &str, content: &str, article_id: &str) -> ParsedArticle {
+    let (oid, aid) = article_id.split_once('_').unwrap_or(("", article_id));
+    ParsedArticle {
+        oid: oid.to_string(),
+        aid: aid.to_string(),
+        title: title.to_string(),
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Extract entities and relations from an article
+///
+/// Returns a dict with `article_id`, `entities` and `relations`, or the
+/// triples as RDF Turtle when `format` is `"turtle"`.
+#[pyfunction]
+#[pyo3(signature = (title, content, article_id = "", format = "json"))]
+fn extract_ontology(
+    py: Python<'_>,
+    title: &str,
+    content: &str,
+    article_id: &str,
+    format: &str,
+) -> PyResult<PyObject> {
+    let article = article(title, content, article_id);
+    let result = RelationExtractor::new().extract_from_article(&article);
+    match format {
+        "json" => to_py(py, &result),
+        "turtle" | "ttl" => Ok(TripleStore::from_extraction(&result, title)
+            .to_turtle()
+            .into_pyobject(py)?
+            .into_any()
+            .unbind()),
+        other => Err(PyValueError::new_err(format!(
+            "Unknown format: {other}. Use json or turtle"
+        ))),
+    }
+}
+
+/// Client for a running `baram embedding-server`
+#[pyclass(module = "baram")]
+struct EmbeddingClient {
+    client: reqwest::Client,
+    url: String,
+    model: Option<String>,
+}
+
+#[derive(Serialize)]
+struct BatchEmbedRequest<'a> {
+    texts: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct BatchEmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+impl EmbeddingClient {
+    async fn request(&self, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+        use anyhow::Context;
+
+        let response = self
+            .client
+            .post(format!("{}/embed/batch", self.url))
+            .json(&BatchEmbedRequest {
+                texts,
+                model: self.model.as_deref(),
+            })
+            .send()
+            .await
+            .context("Failed to send batch embedding request")?
+            .error_for_status()
+            .context("Embedding server rejected the request")?;
+        let body: BatchEmbedResponse = response
+            .json()
+            .await
+            .context("Failed to parse batch embedding response")?;
+        Ok(body.embeddings)
+    }
+}
+
+#[pymethods]
+impl EmbeddingClient {
+    /// `model` picks one of the models the server loaded (default: its first)
+    #[new]
+    #[pyo3(signature = (url = "http://localhost:8090", model = None))]
+    fn new(url: &str, model: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            model,
+        }
+    }
+
+    /// Embedding of one text
+    fn embed(&self, py: Python<'_>, text: String) -> PyResult<Vec<f32>> {
+        let mut embeddings = self.embed_batch(py, vec![text])?;
+        embeddings
+            .pop()
+            .ok_or_else(|| PyRuntimeError::new_err("Embedding server returned no embedding"))
+    }
+
+    /// Embeddings of several texts, in order
+    fn embed_batch(&self, py: Python<'_>, texts: Vec<String>) -> PyResult<Vec<Vec<f32>>> {
+        block_on(py, self.request(&texts)).map_err(runtime_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("EmbeddingClient(url={:?})", self.url)
+    }
+}
+
+/// Search client for the article index in OpenSearch
+#[pyclass(module = "baram")]
+struct VectorStore {
+    store: crate::embedding::VectorStore,
+}
+
+#[pymethods]
+impl VectorStore {
+    #[new]
+    #[pyo3(signature = (
+        url = "http://localhost:9200",
+        index = "baram-articles",
+        username = None,
+        password = None,
+    ))]
+    fn new(
+        url: &str,
+        index: &str,
+        username: Option<String>,
+        password: Option<String>,
+    ) -> PyResult<Self> {
+        let config = OpenSearchConfig {
+            url: url.to_string(),
+            index_name: index.to_string(),
+            username,
+            password,
+        };
+        let store = crate::embedding::VectorStore::new(&config).map_err(runtime_error)?;
+        Ok(Self { store })
+    }
+
+    /// Search articles by text, or hybrid text and vector when `embedding`
+    /// is given; returns a list of result dicts, best first
+    #[pyo3(signature = (query, k = 10, category = None, embedding = None))]
+    fn search(
+        &self,
+        py: Python<'_>,
+        query: &str,
+        k: usize,
+        category: Option<String>,
+        embedding: Option<Vec<f32>>,
+    ) -> PyResult<PyObject> {
+        let config = SearchConfig {
+            k,
+            category,
+            hybrid: embedding.is_some(),
+            ..Default::default()
+        };
+        let results = block_on(py, async {
+            match &embedding {
+                Some(vector) => self.store.search_hybrid(query, vector, &config).await,
+                None => self.store.search_bm25(query, &config).await,
+            }
+        })
+        .map_err(runtime_error)?;
+        to_py(py, &results)
+    }
+
+    /// Nearest articles to an embedding
+    #[pyo3(signature = (embedding, k = 10, category = None))]
+    fn search_vector(
+        &self,
+        py: Python<'_>,
+        embedding: Vec<f32>,
+        k: usize,
+        category: Option<String>,
+    ) -> PyResult<PyObject> {
+        let config = SearchConfig {
+            k,
+            category,
+            ..Default::default()
+        };
+        let results =
+            block_on(py, self.store.search_knn(&embedding, &config)).map_err(runtime_error)?;
+        to_py(py, &results)
+    }
+
+    /// Indexed document by ID, or `None`
+    fn get(&self, py: Python<'_>, id: &str) -> PyResult<PyObject> {
+        match block_on(py, self.store.get_document(id)).map_err(runtime_error)? {
+            Some(doc) => to_py(py, &doc),
+            None => Ok(py.None()),
+        }
+    }
+
+    /// Number of indexed documents
+    fn count(&self, py: Python<'_>) -> PyResult<usize> {
+        block_on(py, self.store.count()).map_err(runtime_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("VectorStore(index={:?})", self.store.index_name())
+    }
+}
+
+/// The `baram` Python module
+#[pymodule]
+#[pyo3(name = "baram")]
+fn baram_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(parse_article, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_ontology, m)?)?;
+    m.add_class::<EmbeddingClient>()?;
+    m.add_class::<VectorStore>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_splits_id() {
+        let parsed = article("제목", "본문", "001_0014123456");
+        assert_eq!(
+            (parsed.oid.as_str(), parsed.aid.as_str()),
+            ("001", "0014123456")
+        );
+        assert_eq!(parsed.title, "제목");
+
+        let parsed = article("제목", "본문", "0014123456");
+        assert_eq!(
+            (parsed.oid.as_str(), parsed.aid.as_str()),
+            ("", "0014123456")
+        );
+    }
+
+    #[test]
+    fn test_batch_embed_request_omits_default_model() {
+        let texts = vec!["반도체".to_string()];
+        let request = BatchEmbedRequest {
+            texts: &texts,
+            model: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({ "texts": ["반도체"] })
+        );
+    }
+
+    #[test]
+    fn test_to_py_converts_to_builtins() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let value = serde_json::json!({ "title": "제목", "scores": [1, 2] });
+            let object = to_py(py, &value).unwrap();
+            let object = object.bind(py);
+            let title: String = object.get_item("title").unwrap().extract().unwrap();
+            assert_eq!(title, "제목");
+            let scores: Vec<i64> = object.get_item("scores").unwrap().extract().unwrap();
+            assert_eq!(scores, [1, 2]);
+        });
+    }
+
+    #[test]
+    fn test_runtime_error_keeps_context() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = runtime_error(anyhow::anyhow!("connection refused").context("Search failed"));
+            assert!(err.is_instance_of::<PyRuntimeError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "Search failed: connection refused"
+            );
+        });
+    }
+
+    #[test]
+    fn test_extract_ontology_formats() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let content = "삼성전자는 서울에 본사를 두고 있다.";
+            let result = extract_ontology(py, "삼성전자", content, "001_1", "json").unwrap();
+            let article_id: String = result
+                .bind(py)
+                .get_item("article_id")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(article_id, "001_1");
+
+            let turtle = extract_ontology(py, "삼성전자", content, "001_1", "ttl").unwrap();
+            assert!(turtle.bind(py).extract::<String>().is_ok());
+
+            let err = extract_ontology(py, "삼성전자", content, "001_1", "xml").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
@@ -52,6 +52,19 @@ pub enum DistributionEvent {
     },
 }
 
+impl DistributionEvent {
+    /// Short event name, e.g. for server-sent events
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ScheduleReady { .. } => "schedule_ready",
+            Self::ScheduleUpdated { .. } => "schedule_updated",
+            Self::AssignmentChanged { .. } => "assignment_changed",
+            Self::RotationTriggered { .. } => "rotation_triggered",
+            Self::EmergencyOverride { .. } => "emergency_override",
+        }
+    }
+}
+
 /// Reasons for schedule updates
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum UpdateReason {
@@ -11,6 +11,7 @@ use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 use super::error::{SchedulerError, SchedulerResult};
 use super::schedule::{DailySchedule, HourlySlot};
@@ -20,7 +21,7 @@ use super::schedule::{DailySchedule, HourlySlot};
 // ============================================================================
 
 /// Represents a crawler instance in the distributed system
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum CrawlerInstance {
     /// Main instance (primary)
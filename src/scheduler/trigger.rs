@@ -6,7 +6,10 @@
 use chrono::{DateTime, Duration, Local, NaiveTime, TimeZone, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, Notify, RwLock};
+
+use crate::crawler::watch::WatchJob;
+use crate::models::NewsCategory;
 
 use super::error::{SchedulerError, SchedulerResult};
 use super::rotation::RotationScheduler;
@@ -170,6 +173,19 @@ pub enum TriggerEvent {
         reason: String,
         schedule: DailySchedule,
     },
+
+    /// A crawl job's category is due (see [`ScheduleTrigger::with_crawl_jobs`])
+    CategoryCrawl {
+        category: NewsCategory,
+        triggered_at: DateTime<Utc>,
+    },
+}
+
+/// A crawl job and whether its crawl is under way
+#[derive(Debug, Clone)]
+struct CrawlJob {
+    job: WatchJob,
+    in_flight: bool,
 }
 
 // ============================================================================
@@ -183,6 +199,9 @@ pub struct ScheduleTrigger {
     cache: Arc<ScheduleCache>,
     event_sender: broadcast::Sender<TriggerEvent>,
     is_running: Arc<RwLock<bool>>,
+    crawl_jobs: RwLock<Vec<CrawlJob>>,
+    /// Wakes the trigger loop once a crawl is finished
+    crawl_finished: Notify,
 }
 
 impl ScheduleTrigger {
@@ -198,9 +217,75 @@ impl ScheduleTrigger {
             cache,
             event_sender,
             is_running: Arc::new(RwLock::new(false)),
+            crawl_jobs: RwLock::new(Vec::new()),
+            crawl_finished: Notify::new(),
         })
     }
 
+    /// Also trigger crawls of categories on their own schedules
+    ///
+    /// The loop emits [`TriggerEvent::CategoryCrawl`] when a job is due. The
+    /// job is not due again until its crawl is reported with
+    /// [`Self::finish_crawl`], and its next run counts from then.
+    pub fn with_crawl_jobs(mut self, jobs: Vec<WatchJob>) -> Self {
+        self.crawl_jobs = RwLock::new(
+            jobs.into_iter()
+                .map(|job| CrawlJob {
+                    job,
+                    in_flight: false,
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Record that the crawl of `category` finished at `finished_at`
+    pub async fn finish_crawl(&self, category: NewsCategory, finished_at: DateTime<Utc>) {
+        let mut jobs = self.crawl_jobs.write().await;
+        if let Some(crawl) = jobs.iter_mut().find(|c| c.job.category == category) {
+            crawl.job.last_run = Some(finished_at);
+            crawl.in_flight = false;
+        }
+        drop(jobs);
+        self.crawl_finished.notify_one();
+    }
+
+    /// Whether a crawl job is under way or has a run left
+    pub async fn has_crawls_left(&self, now: DateTime<Utc>) -> bool {
+        self.crawl_jobs
+            .read()
+            .await
+            .iter()
+            .any(|c| c.in_flight || c.job.next_run(now).is_some())
+    }
+
+    /// Next run of the crawl jobs that are not under way
+    async fn next_crawl(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.crawl_jobs
+            .read()
+            .await
+            .iter()
+            .filter(|c| !c.in_flight)
+            .filter_map(|c| c.job.next_run(now))
+            .min()
+    }
+
+    /// Emit [`TriggerEvent::CategoryCrawl`] for each due crawl job
+    async fn trigger_due_crawls(&self) {
+        let now = Utc::now();
+        let mut jobs = self.crawl_jobs.write().await;
+        for crawl in jobs.iter_mut() {
+            if crawl.in_flight || !crawl.job.is_due(now) {
+                continue;
+            }
+            crawl.in_flight = true;
+            let _ = self.event_sender.send(TriggerEvent::CategoryCrawl {
+                category: crawl.job.category,
+                triggered_at: now,
+            });
+        }
+    }
+
     /// Create with default config
     pub fn with_defaults(cache: Arc<ScheduleCache>) -> SchedulerResult<Self> {
         Self::new(TriggerConfig::default(), cache)
@@ -330,12 +415,25 @@ impl ScheduleTrigger {
 
         // Main trigger loop
         while *self.is_running.read().await {
-            let sleep_duration = self.calculate_next_sleep_duration()?;
+            let mut sleep_duration = self.calculate_next_sleep_duration()?;
+            let now = Utc::now();
+            let crawl_first = match self.next_crawl(now).await {
+                Some(next) if next - now < sleep_duration => {
+                    sleep_duration = (next - now).max(Duration::zero());
+                    true
+                }
+                _ => false,
+            };
 
             tokio::select! {
                 _ = tokio::time::sleep(sleep_duration.to_std().unwrap_or(std::time::Duration::from_secs(60))) => {
-                    self.handle_trigger().await?;
+                    if !crawl_first {
+                        self.handle_trigger().await?;
+                    }
+                    self.trigger_due_crawls().await;
                 }
+                // A finished crawl moves its job's next run
+                _ = self.crawl_finished.notified() => {}
                 _ = self.wait_for_stop() => {
                     break;
                 }
@@ -610,6 +708,53 @@ mod tests {
         assert_eq!(status.config.rotation_time, "23:00");
     }
 
+    #[tokio::test]
+    async fn test_crawl_jobs_trigger_when_due() {
+        let cache = Arc::new(ScheduleCache::new());
+        let job = WatchJob::new(NewsCategory::Politics, "1h".parse().unwrap());
+        let trigger = Arc::new(
+            ScheduleTrigger::new(
+                TriggerConfig {
+                    trigger_on_startup: false,
+                    enable_hourly_triggers: false,
+                    ..Default::default()
+                },
+                cache,
+            )
+            .unwrap()
+            .with_crawl_jobs(vec![job]),
+        );
+        let mut receiver = trigger.subscribe();
+        let runner = tokio::spawn({
+            let trigger = Arc::clone(&trigger);
+            async move { trigger.start().await }
+        });
+
+        // A job that never ran is due right away
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            event,
+            TriggerEvent::CategoryCrawl {
+                category: NewsCategory::Politics,
+                ..
+            }
+        ));
+        assert!(trigger.has_crawls_left(Utc::now()).await);
+
+        let finished = Utc::now();
+        trigger.finish_crawl(NewsCategory::Politics, finished).await;
+        assert_eq!(
+            trigger.next_crawl(finished).await,
+            Some(finished + Duration::hours(1))
+        );
+
+        trigger.stop().await;
+        runner.await.unwrap().unwrap();
+    }
+
     #[tokio::test]
     async fn test_force_rotation() {
         let cache = Arc::new(ScheduleCache::new());
@@ -0,0 +1,356 @@
+//! PostgreSQL store of full articles
+//!
+//! Crawlers write markdown files to their local output directory. With an
+//! article store they also upsert every parsed article, including its
+//! comments and content hash, into the shared `articles` table, so the
+//! bodies crawled by all distributed instances are available in one place.
+//!
+//! Articles are keyed by URL. Their UUID is derived from the internal article
+//! ID (`{oid}_{aid}` for Naver, see [`article_uuid`]), so an article can be
+//! looked up by either.
+
+use anyhow::{Context, Result};
+use deadpool_postgres::Pool;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use super::timeseries::{article_samples, PgTimeSeriesStore, TIMESERIES_SCHEMA};
+use crate::analytics::ReactionAnalyzer;
+use crate::crawler::comment::Comment as CrawledComment;
+use crate::crawler::url::UrlExtractor;
+use crate::models::ParsedArticle;
+use crate::parser::{Article, Comment};
+
+/// Schema of the `articles` table
+pub(crate) const ARTICLES_SCHEMA: &str = r#"
+    CREATE TABLE IF NOT EXISTS articles (
+        id UUID PRIMARY KEY,
+        url TEXT NOT NULL UNIQUE,
+        title TEXT NOT NULL,
+        body TEXT NOT NULL,
+        author TEXT,
+        published_at TIMESTAMPTZ,
+        category TEXT,
+        content_hash TEXT NOT NULL,
+        comments JSONB NOT NULL DEFAULT '[]',
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_articles_url ON articles(url);
+    CREATE INDEX IF NOT EXISTS idx_articles_content_hash ON articles(content_hash);
+    CREATE INDEX IF NOT EXISTS idx_articles_published_at ON articles(published_at);
+    CREATE INDEX IF NOT EXISTS idx_articles_category ON articles(category);
+"#;
+
+const UPSERT_ARTICLE: &str = r#"
+    INSERT INTO articles (
+        id, url, title, body, author, published_at, category, content_hash, comments
+    )
+    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    ON CONFLICT (url) DO UPDATE SET
+        title = EXCLUDED.title,
+        body = EXCLUDED.body,
+        author = EXCLUDED.author,
+        published_at = EXCLUDED.published_at,
+        category = EXCLUDED.category,
+        content_hash = EXCLUDED.content_hash,
+        comments = EXCLUDED.comments,
+        updated_at = NOW()
+"#;
+
+/// Stable UUID of an article, derived from its internal ID
+#[must_use]
+pub fn article_uuid(article_id: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, article_id.as_bytes())
+}
+
+impl From<&ParsedArticle> for Article {
+    /// Convert a crawled article for storage
+    ///
+    /// Comment threads are flattened, replies after their parent, and deleted
+    /// comments are dropped. The content hash is computed if the crawler did
+    /// not set one.
+    fn from(article: &ParsedArticle) -> Self {
+        let content_hash = article.content_hash.clone().unwrap_or_else(|| {
+            let mut hasher = Sha256::new();
+            hasher.update(article.content.as_bytes());
+            format!("{:x}", hasher.finalize())
+        });
+
+        let mut comments = Vec::new();
+        flatten_comments(&article.comments, &mut comments);
+
+        Self {
+            id: article_uuid(&article.id()),
+            url: article.url.clone(),
+            title: article.title.clone(),
+            body: article.content.clone(),
+            author: article.author.clone(),
+            published_at: article.published_at,
+            category: Some(article.category.clone()).filter(|c| !c.is_empty()),
+            content_hash,
+            comments,
+        }
+    }
+}
+
+fn flatten_comments(thread: &[CrawledComment], out: &mut Vec<Comment>) {
+    for comment in thread {
+        if !comment.is_deleted {
+            out.push(Comment {
+                id: comment.id.clone(),
+                author: comment.author.clone(),
+                text: comment.content.clone(),
+                created_at: comment.created_at,
+                likes: i32::try_from(comment.likes).unwrap_or(i32::MAX),
+            });
+        }
+        flatten_comments(&comment.replies, out);
+    }
+}
+
+/// PostgreSQL-backed article store
+///
+/// Cheap to clone; clones share the pool and create the schema once.
+#[derive(Clone)]
+pub struct PgArticleStore {
+    pool: Pool,
+    schema: Arc<OnceCell<()>>,
+}
+
+impl PgArticleStore {
+    /// Create a store on an existing connection pool
+    #[must_use]
+    pub fn new(pool: Pool) -> Self {
+        Self::with_schema(pool, Arc::default())
+    }
+
+    /// Create a store with its own connection pool
+    pub fn connect(url: &str) -> Result<Self> {
+        Ok(Self::new(super::create_postgres_pool(url)?))
+    }
+
+    /// Create a store sharing the schema state of other stores on `pool`
+    pub(crate) fn with_schema(pool: Pool, schema: Arc<OnceCell<()>>) -> Self {
+        Self { pool, schema }
+    }
+
+    /// Create the articles and time-series schema, once per store
+    pub async fn ensure_schema(&self) -> Result<()> {
+        self.schema
+            .get_or_try_init(|| async {
+                let client = self.pool.get().await.context("Failed to get connection")?;
+                client
+                    .batch_execute(ARTICLES_SCHEMA)
+                    .await
+                    .context("Failed to create PostgreSQL schema")?;
+                client
+                    .batch_execute(TIMESERIES_SCHEMA)
+                    .await
+                    .context("Failed to create PostgreSQL time-series schema")?;
+                tracing::info!("PostgreSQL articles schema initialized");
+                Ok::<_, anyhow::Error>(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Insert or update articles in one transaction
+    ///
+    /// Also records each article in the analytics time series and replaces
+    /// its comment reaction summary. Returns the number of articles stored.
+    pub async fn store(&self, articles: &[Article]) -> Result<usize> {
+        if articles.is_empty() {
+            return Ok(0);
+        }
+        self.ensure_schema().await?;
+
+        let mut client = self.pool.get().await.context("Failed to get connection")?;
+        let tx = client
+            .transaction()
+            .await
+            .context("Failed to start transaction")?;
+        let stmt = tx.prepare(UPSERT_ARTICLE).await?;
+        for article in articles {
+            let comments_json =
+                serde_json::to_value(&article.comments).context("Failed to serialize comments")?;
+            tx.execute(
+                &stmt,
+                &[
+                    &article.id,
+                    &article.url,
+                    &article.title,
+                    &article.body,
+                    &article.author,
+                    &article.published_at,
+                    &article.category,
+                    &article.content_hash,
+                    &comments_json,
+                ],
+            )
+            .await
+            .with_context(|| format!("Failed to store article {}", article.url))?;
+        }
+        tx.commit().await.context("Failed to commit articles")?;
+
+        let timeseries = PgTimeSeriesStore::new(self.pool.clone());
+        let extractor = UrlExtractor::new();
+        let reactions = ReactionAnalyzer::new();
+        for article in articles {
+            if let Some(published_at) = article.published_at {
+                let publisher = extractor.extract_ids(&article.url).ok().map(|(oid, _)| oid);
+                let samples = article_samples(
+                    published_at,
+                    article.category.as_deref().unwrap_or("unknown"),
+                    publisher.as_deref(),
+                    &article.title,
+                    &article.body,
+                );
+                timeseries
+                    .record_article(&article.id.to_string(), &samples)
+                    .await?;
+            }
+            if let Some(reaction) = reactions.analyze(article) {
+                timeseries.record_reaction(&reaction).await?;
+            }
+        }
+
+        tracing::debug!(count = articles.len(), "Articles stored");
+        Ok(articles.len())
+    }
+
+    /// Insert or update crawled articles, see [`Self::store`]
+    pub async fn store_parsed(&self, articles: &[ParsedArticle]) -> Result<usize> {
+        let articles: Vec<Article> = articles.iter().map(Article::from).collect();
+        self.store(&articles).await
+    }
+
+    /// Article by UUID or internal article ID (`{oid}_{aid}`)
+    pub async fn get(&self, id: &str) -> Result<Option<Article>> {
+        let uuid = Uuid::parse_str(id).unwrap_or_else(|_| article_uuid(id));
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        let row = client
+            .query_opt(
+                r#"
+                SELECT id, url, title, body, author, published_at, category, content_hash, comments
+                FROM articles
+                WHERE id = $1
+                "#,
+                &[&uuid],
+            )
+            .await
+            .context("Failed to query article")?;
+
+        row.as_ref().map(super::article_from_row).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn comment(id: &str, deleted: bool, replies: Vec<CrawledComment>) -> CrawledComment {
+        CrawledComment {
+            id: id.to_string(),
+            parent_id: None,
+            content: format!("댓글 {id}"),
+            author: "작성자".to_string(),
+            author_id: "abc****".to_string(),
+            created_at: Utc::now(),
+            modified_at: None,
+            likes: 3,
+            dislikes: 0,
+            reply_count: replies.len() as i64,
+            is_best: false,
+            is_deleted: deleted,
+            replies,
+        }
+    }
+
+    #[test]
+    fn test_article_from_parsed() {
+        let mut parsed = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0014000001".to_string(),
+            title: "제목".to_string(),
+            content: "본문".to_string(),
+            url: "https://n.news.naver.com/mnews/article/001/0014000001".to_string(),
+            category: "economy".to_string(),
+            comments: vec![
+                comment("1", false, vec![comment("2", false, vec![])]),
+                comment("3", true, vec![comment("4", false, vec![])]),
+            ],
+            ..Default::default()
+        };
+
+        let article = Article::from(&parsed);
+        assert_eq!(article.id, article_uuid("001_0014000001"));
+        assert_eq!(article.category.as_deref(), Some("economy"));
+        let ids: Vec<&str> = article.comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "4"]);
+        assert_eq!(article.comments[0].text, "댓글 1");
+
+        // The hash is computed when missing and kept when set
+        parsed.compute_hash();
+        assert_eq!(
+            article.content_hash,
+            parsed.content_hash.clone().unwrap(),
+            "hash of the content"
+        );
+        parsed.content_hash = Some("fixed".to_string());
+        assert_eq!(Article::from(&parsed).content_hash, "fixed");
+    }
+
+    // Integration test; set DATABASE_URL to a scratch database
+    #[tokio::test]
+    #[ignore = "Requires running PostgreSQL"]
+    async fn test_store_and_get_articles() {
+        let url = crate::config::secrets::read_env("DATABASE_URL")
+            .unwrap()
+            .map(|(_, url)| url)
+            .unwrap_or_else(|| "postgresql://localhost/baram_test".to_string());
+        let store = PgArticleStore::connect(&url).unwrap();
+
+        let mut articles: Vec<ParsedArticle> = (1..=3)
+            .map(|i| ParsedArticle {
+                oid: "001".to_string(),
+                aid: format!("001400000{i}"),
+                title: format!("제목 {i}"),
+                content: format!("본문 {i}"),
+                url: format!("https://n.news.naver.com/mnews/article/001/001400000{i}"),
+                category: "economy".to_string(),
+                published_at: Some(Utc::now()),
+                comments: vec![comment("1", false, vec![])],
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(store.store_parsed(&articles).await.unwrap(), 3);
+
+        // Upserting again updates in place
+        articles[0].content = "수정된 본문".to_string();
+        assert_eq!(store.store_parsed(&articles[..1]).await.unwrap(), 1);
+
+        let by_id = store.get("001_0014000001").await.unwrap().unwrap();
+        assert_eq!(by_id.body, "수정된 본문");
+        assert_eq!(by_id.comments.len(), 1);
+        let by_uuid = store.get(&by_id.id.to_string()).await.unwrap().unwrap();
+        assert_eq!(by_uuid.url, by_id.url);
+        assert!(store.get("001_0019999999").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_article_uuid_is_stable() {
+        assert_eq!(
+            article_uuid("001_0014000001"),
+            article_uuid("001_0014000001")
+        );
+        assert_ne!(
+            article_uuid("001_0014000001"),
+            article_uuid("001_0014000002")
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Downloaded article images
+//!
+//! `baram crawl --download-images` saves the images of each article under
+//! `assets/{oid}_{aid}/` next to the markdown directory and links the
+//! markdown to those copies. Each file is recorded in `article_assets`
+//! with the URL it came from, so the original stays known and the copies
+//! can be checked with [`Database::get_assets`](super::Database::get_assets).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+/// One downloaded file of an article
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArticleAsset {
+    pub article_id: String,
+    /// Where the file was downloaded from
+    pub url: String,
+    /// Where it was saved
+    pub path: String,
+    /// `Content-Type` the server sent, if any
+    pub content_type: Option<String>,
+    pub bytes: u64,
+    /// SHA-256 of the file, hex encoded
+    pub sha256: String,
+    pub downloaded_at: DateTime<Utc>,
+}
+
+/// Create the assets table
+pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS article_assets (
+            article_id TEXT NOT NULL,
+            url TEXT NOT NULL,
+            path TEXT NOT NULL,
+            content_type TEXT,
+            bytes INTEGER NOT NULL,
+            sha256 TEXT NOT NULL,
+            downloaded_at TEXT NOT NULL,
+            PRIMARY KEY (article_id, url)
+        );
+        "#,
+    )
+    .context("Failed to create assets schema")
+}
+
+/// Record `assets`, replacing earlier downloads of the same URLs
+pub(crate) fn record(conn: &Connection, assets: &[ArticleAsset]) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for asset in assets {
+        tx.execute(
+            r#"
+            INSERT OR REPLACE INTO article_assets
+                (article_id, url, path, content_type, bytes, sha256, downloaded_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            params![
+                asset.article_id,
+                asset.url,
+                asset.path,
+                asset.content_type,
+                asset.bytes as i64,
+                asset.sha256,
+                asset.downloaded_at.to_rfc3339(),
+            ],
+        )
+        .context("Failed to save article asset")?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Every downloaded file of an article, by path
+pub(crate) fn list(conn: &Connection, article_id: &str) -> Result<Vec<ArticleAsset>> {
+    let mut statement = conn.prepare(
+        "SELECT article_id, url, path, content_type, bytes, sha256, downloaded_at
+         FROM article_assets WHERE article_id = ?1 ORDER BY path",
+    )?;
+    let assets = statement
+        .query_map(params![article_id], |row| {
+            Ok(ArticleAsset {
+                article_id: row.get(0)?,
+                url: row.get(1)?,
+                path: row.get(2)?,
+                content_type: row.get(3)?,
+                bytes: row.get::<_, i64>(4)? as u64,
+                sha256: row.get(5)?,
+                downloaded_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(6)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to get article assets")?;
+    Ok(assets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(url: &str, path: &str, bytes: u64) -> ArticleAsset {
+        ArticleAsset {
+            article_id: "001_0000000001".to_string(),
+            url: url.to_string(),
+            path: path.to_string(),
+            content_type: Some("image/jpeg".to_string()),
+            bytes,
+            sha256: "ab".repeat(32),
+            downloaded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_record_replaces_by_url() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_schema(&conn).unwrap();
+
+        let first = "https://imgnews.pstatic.net/image/001/a.jpg";
+        let second = "https://imgnews.pstatic.net/image/001/b.png";
+        record(
+            &conn,
+            &[
+                asset(first, "assets/001_0000000001/01.jpg", 10),
+                asset(second, "assets/001_0000000001/02.png", 20),
+            ],
+        )
+        .unwrap();
+        record(&conn, &[asset(first, "assets/001_0000000001/01.jpg", 30)]).unwrap();
+
+        let assets = list(&conn, "001_0000000001").unwrap();
+        let files: Vec<(&str, u64)> = assets.iter().map(|a| (a.url.as_str(), a.bytes)).collect();
+        assert_eq!(files, [(first, 30), (second, 20)]);
+        assert!(list(&conn, "001_0000000002").unwrap().is_empty());
+    }
+}
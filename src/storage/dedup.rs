@@ -20,6 +20,8 @@ use deadpool_postgres::{Config as PoolConfig, ManagerConfig, Pool, RecyclingMeth
 use tokio_postgres::types::ToSql;
 use tokio_postgres::NoTls;
 
+use crate::config::secrets;
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -62,9 +64,11 @@ impl Default for DedupConfig {
 impl DedupConfig {
     /// Create config from environment variables
     pub fn from_env() -> Result<Self> {
-        let database_url = std::env::var("DATABASE_URL")
-            .or_else(|_| std::env::var("POSTGRES_URL"))
-            .unwrap_or_else(|_| "postgresql://localhost/baram".to_string());
+        let database_url = match secrets::read_env("DATABASE_URL")? {
+            Some((_, url)) => Some(url),
+            None => secrets::read_env("POSTGRES_URL")?.map(|(_, url)| url),
+        }
+        .unwrap_or_else(|| "postgresql://localhost/baram".to_string());
 
         let pool_size = std::env::var("DB_POOL_SIZE")
             .ok()
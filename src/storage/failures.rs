@@ -0,0 +1,212 @@
+//! Dead-letter queue of failed URLs
+//!
+//! Every failed crawl is also kept in `failed_urls`, one row per URL with
+//! the [`ErrorClass`] and message of its latest failure and the number of
+//! attempts so far. `baram retry-failed` attempts the pending URLs again
+//! under the `[crawler.dead_letter]` policy: a URL that crawls is removed
+//! from the queue, one that has used up the attempts of its error class is
+//! marked dead and reported instead of being retried forever.
+//!
+//! Failures recorded in `crawl_metadata` before the queue existed are
+//! queued as pending with one attempt and class `other`.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::fmt;
+
+use crate::error::ErrorClass;
+
+/// Whether a failed URL is still retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureStatus {
+    /// Waiting to be retried
+    Pending,
+    /// Given up: out of attempts, or an error class that is not retried
+    Dead,
+}
+
+impl FailureStatus {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+impl fmt::Display for FailureStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A URL in the dead-letter queue
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QueuedFailure {
+    pub url: String,
+    /// Class of the latest failure
+    #[serde(serialize_with = "serialize_class")]
+    pub error_class: ErrorClass,
+    /// Message of the latest failure
+    pub error_message: String,
+    /// Attempts so far, counting the crawl that first failed
+    pub attempts: u32,
+    pub first_failed_at: DateTime<Utc>,
+    pub last_failed_at: DateTime<Utc>,
+    pub status: FailureStatus,
+}
+
+fn serialize_class<S: serde::Serializer>(class: &ErrorClass, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(class.as_str())
+}
+
+/// Create the queue table, queueing failures recorded before it existed
+pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
+    let existed: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE name = 'failed_urls')",
+            [],
+            |row| row.get(0),
+        )
+        .context("Failed to read SQLite schema")?;
+
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS failed_urls (
+            url TEXT PRIMARY KEY,
+            error_class TEXT NOT NULL,
+            error_message TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 1,
+            first_failed_at TEXT NOT NULL,
+            last_failed_at TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'pending'
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_failed_urls_status ON failed_urls(status);
+        "#,
+    )
+    .context("Failed to create dead-letter schema")?;
+
+    if !existed {
+        conn.execute(
+            r#"
+            INSERT OR IGNORE INTO failed_urls
+                (url, error_class, error_message, first_failed_at, last_failed_at)
+            SELECT url, ?1, COALESCE(error_message, ''), crawled_at, crawled_at
+            FROM crawl_metadata WHERE status = 'failed'
+            "#,
+            params![ErrorClass::Other.as_str()],
+        )
+        .context("Failed to queue earlier failures")?;
+    }
+    Ok(())
+}
+
+/// Queue a failure of `url`, or count another attempt of a queued one
+pub(crate) fn record(conn: &Connection, url: &str, error: &str, class: ErrorClass) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    conn.execute(
+        r#"
+        INSERT INTO failed_urls (url, error_class, error_message, first_failed_at, last_failed_at)
+        VALUES (?1, ?2, ?3, ?4, ?4)
+        ON CONFLICT(url) DO UPDATE SET
+            error_class = excluded.error_class,
+            error_message = excluded.error_message,
+            attempts = attempts + 1,
+            last_failed_at = excluded.last_failed_at
+        "#,
+        params![url, class.as_str(), error, now],
+    )
+    .context("Failed to queue failed URL")?;
+    Ok(())
+}
+
+/// Remove `url` from the queue
+pub(crate) fn resolve(conn: &Connection, url: &str) -> Result<()> {
+    conn.execute("DELETE FROM failed_urls WHERE url = ?1", params![url])
+        .context("Failed to dequeue URL")?;
+    Ok(())
+}
+
+/// Give up on `url`
+pub(crate) fn mark_dead(conn: &Connection, url: &str) -> Result<()> {
+    conn.execute(
+        "UPDATE failed_urls SET status = ?2 WHERE url = ?1",
+        params![url, FailureStatus::Dead.as_str()],
+    )
+    .context("Failed to mark URL as dead")?;
+    Ok(())
+}
+
+/// Queued URLs with `status`, least recently failed first
+pub(crate) fn list(conn: &Connection, status: FailureStatus) -> Result<Vec<QueuedFailure>> {
+    let mut statement = conn.prepare(
+        "SELECT url, error_class, error_message, attempts, first_failed_at, last_failed_at
+         FROM failed_urls WHERE status = ?1 ORDER BY last_failed_at, url",
+    )?;
+    let parse_time = |value: String| {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now())
+    };
+    let failures = statement
+        .query_map(params![status.as_str()], |row| {
+            Ok(QueuedFailure {
+                url: row.get(0)?,
+                error_class: ErrorClass::parse(&row.get::<_, String>(1)?)
+                    .unwrap_or(ErrorClass::Other),
+                error_message: row.get(2)?,
+                attempts: row.get(3)?,
+                first_failed_at: parse_time(row.get(4)?),
+                last_failed_at: parse_time(row.get(5)?),
+                status,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to list failed URLs")?;
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_lifecycle() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE crawl_metadata (url TEXT, crawled_at TEXT, status TEXT, error_message TEXT);
+             INSERT INTO crawl_metadata VALUES
+                 ('https://a', '2026-10-01T00:00:00+00:00', 'failed', 'HTTP 503'),
+                 ('https://b', '2026-10-01T00:00:00+00:00', 'success', NULL);",
+        )
+        .unwrap();
+        create_schema(&conn).unwrap();
+        // Earlier failures are only queued when the table is created
+        create_schema(&conn).unwrap();
+
+        let pending = list(&conn, FailureStatus::Pending).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].url, "https://a");
+        assert_eq!(pending[0].error_class, ErrorClass::Other);
+
+        record(&conn, "https://a", "timed out", ErrorClass::Timeout).unwrap();
+        record(&conn, "https://c", "HTTP 404", ErrorClass::NotFound).unwrap();
+        let pending = list(&conn, FailureStatus::Pending).unwrap();
+        let a = pending.iter().find(|f| f.url == "https://a").unwrap();
+        assert_eq!((a.attempts, a.error_class), (2, ErrorClass::Timeout));
+        assert_eq!(a.error_message, "timed out");
+
+        mark_dead(&conn, "https://c").unwrap();
+        resolve(&conn, "https://a").unwrap();
+        assert!(list(&conn, FailureStatus::Pending).unwrap().is_empty());
+        let dead = list(&conn, FailureStatus::Dead).unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].status, FailureStatus::Dead);
+        assert_eq!(dead[0].attempts, 1);
+    }
+}
@@ -0,0 +1,225 @@
+//! Article bodies with a full-text index
+//!
+//! With `[database] store_bodies = true`, `baram crawl` keeps the text of
+//! every article in the crawl database next to its metadata:
+//! - `article_bodies` holds the title, body and search metadata
+//! - `article_bodies_fts` is an FTS5 table of the same rows (by `rowid`)
+//!   holding their [`TextTokenizer`] tokens, so Korean particles are already
+//!   stripped (`금리를` is indexed as `금리`)
+//!
+//! [`LocalSearch`](crate::embedding::LocalSearch) queries these tables, from
+//! the crawl database or from an in-memory copy of markdown files. Tokens are
+//! those of the vocabulary installed at crawl time; recrawl after changing
+//! `[analysis]` files to reindex.
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::analytics::TextTokenizer;
+use crate::models::ParsedArticle;
+
+/// Text and search metadata of an article
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredBody {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    pub body: String,
+    pub category: String,
+    pub publisher: Option<String>,
+    /// RFC 3339 publication time
+    pub published_at: Option<String>,
+    pub story_id: Option<String>,
+}
+
+impl From<&ParsedArticle> for StoredBody {
+    fn from(article: &ParsedArticle) -> Self {
+        Self {
+            id: article.id(),
+            url: article.url.clone(),
+            title: article.title.clone(),
+            body: article.content.clone(),
+            category: article.category.clone(),
+            publisher: article.publisher.clone(),
+            published_at: article.published_at.map(|time| time.to_rfc3339()),
+            story_id: None,
+        }
+    }
+}
+
+/// Create the body and full-text tables
+pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS article_bodies (
+            id TEXT PRIMARY KEY,
+            url TEXT NOT NULL,
+            title TEXT NOT NULL,
+            body TEXT NOT NULL,
+            category TEXT NOT NULL DEFAULT '',
+            publisher TEXT,
+            published_at TEXT,
+            story_id TEXT
+        );
+
+        CREATE VIRTUAL TABLE IF NOT EXISTS article_bodies_fts USING fts5(title, body);
+        "#,
+    )
+    .context("Failed to create full-text schema")
+}
+
+/// Whether `conn` has the body tables, as databases created before they
+/// existed do not
+pub(crate) fn has_schema(conn: &Connection) -> Result<bool> {
+    let count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE name IN ('article_bodies', 'article_bodies_fts')",
+            [],
+            |row| row.get(0),
+        )
+        .context("Failed to read SQLite schema")?;
+    Ok(count == 2)
+}
+
+/// Insert or replace the body of an article and its index entry
+pub(crate) fn save(conn: &Connection, body: &StoredBody, tokenizer: &TextTokenizer) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    let rowid: Option<i64> = tx
+        .query_row(
+            "SELECT rowid FROM article_bodies WHERE id = ?1",
+            params![body.id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if let Some(rowid) = rowid {
+        tx.execute(
+            "DELETE FROM article_bodies_fts WHERE rowid = ?1",
+            params![rowid],
+        )?;
+        tx.execute(
+            "DELETE FROM article_bodies WHERE rowid = ?1",
+            params![rowid],
+        )?;
+    }
+
+    tx.execute(
+        r#"
+        INSERT INTO article_bodies
+            (id, url, title, body, category, publisher, published_at, story_id)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        "#,
+        params![
+            body.id,
+            body.url,
+            body.title,
+            body.body,
+            body.category,
+            body.publisher,
+            body.published_at,
+            body.story_id,
+        ],
+    )
+    .context("Failed to save article body")?;
+    tx.execute(
+        "INSERT INTO article_bodies_fts (rowid, title, body) VALUES (?1, ?2, ?3)",
+        params![
+            tx.last_insert_rowid(),
+            tokenizer.tokenize(&body.title).join(" "),
+            tokenizer.tokenize(&body.body).join(" "),
+        ],
+    )
+    .context("Failed to index article body")?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// Number of stored bodies
+pub(crate) fn count(conn: &Connection) -> Result<usize> {
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM article_bodies", [], |row| row.get(0))
+        .context("Failed to count article bodies")?;
+    Ok(count as usize)
+}
+
+/// Bodies matching the FTS5 `expression`, best first, with their BM25 score
+/// (higher is better) weighting the title `title_weight` times the body
+pub(crate) fn search(
+    conn: &Connection,
+    expression: &str,
+    title_weight: f64,
+) -> Result<Vec<(StoredBody, f32)>> {
+    let mut statement = conn.prepare(&format!(
+        "SELECT b.id, b.url, b.title, b.body, b.category, b.publisher, b.published_at, \
+                b.story_id, -bm25(article_bodies_fts, {title_weight}, 1.0) AS score \
+         FROM article_bodies_fts JOIN article_bodies b ON b.rowid = article_bodies_fts.rowid \
+         WHERE article_bodies_fts MATCH ?1 ORDER BY score DESC"
+    ))?;
+    let hits = statement
+        .query_map(params![expression], |row| {
+            Ok((
+                StoredBody {
+                    id: row.get(0)?,
+                    url: row.get(1)?,
+                    title: row.get(2)?,
+                    body: row.get(3)?,
+                    category: row.get(4)?,
+                    publisher: row.get(5)?,
+                    published_at: row.get(6)?,
+                    story_id: row.get(7)?,
+                },
+                row.get::<_, f64>(8)? as f32,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Full-text search failed")?;
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(id: &str, title: &str, text: &str) -> StoredBody {
+        StoredBody {
+            id: id.to_string(),
+            url: format!("https://n.news.naver.com/{id}"),
+            title: title.to_string(),
+            body: text.to_string(),
+            category: "economy".to_string(),
+            publisher: None,
+            published_at: None,
+            story_id: None,
+        }
+    }
+
+    #[test]
+    fn test_save_replaces_body_and_index() {
+        let conn = Connection::open_in_memory().unwrap();
+        assert!(!has_schema(&conn).unwrap());
+        create_schema(&conn).unwrap();
+        assert!(has_schema(&conn).unwrap());
+
+        let tokenizer = TextTokenizer::new();
+        save(
+            &conn,
+            &body("a", "금리 인상", "기준금리를 올렸다."),
+            &tokenizer,
+        )
+        .unwrap();
+        save(&conn, &body("b", "국회 본회의", "예산안 처리"), &tokenizer).unwrap();
+        assert_eq!(search(&conn, "\"금리\"", 2.0).unwrap()[0].0.id, "a");
+
+        save(
+            &conn,
+            &body("a", "환율 하락", "원화가 강세였다."),
+            &tokenizer,
+        )
+        .unwrap();
+        assert_eq!(count(&conn).unwrap(), 2);
+        assert!(search(&conn, "\"금리\"", 2.0).unwrap().is_empty());
+        let hits = search(&conn, "\"환율\"", 2.0).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0.title, "환율 하락");
+        assert!(hits[0].1 > 0.0);
+    }
+}
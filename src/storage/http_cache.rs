@@ -0,0 +1,152 @@
+//! HTTP validators for conditional requests
+//!
+//! [`Crawler`](crate::crawler::Crawler) keeps the `ETag` and
+//! `Last-Modified` of each page it fetched with
+//! [`fetch_text_if_modified`](crate::crawler::Crawler::fetch_text_if_modified)
+//! in `http_validators`, and sends them back as `If-None-Match` and
+//! `If-Modified-Since` the next time. A `304 Not Modified` answer then
+//! tells a recrawl that the page is unchanged without downloading it.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+use std::sync::{Mutex, PoisonError};
+
+/// Validators a server sent with a page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpValidators {
+    /// `ETag`, sent back as `If-None-Match`
+    pub etag: Option<String>,
+    /// `Last-Modified`, sent back as `If-Modified-Since`
+    pub last_modified: Option<String>,
+}
+
+impl HttpValidators {
+    /// Whether the server sent neither validator
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// SQLite-backed store of [`HttpValidators`] by URL
+///
+/// Usually opened on the crawl database, next to the crawl records.
+pub struct HttpCache {
+    conn: Mutex<Connection>,
+}
+
+impl HttpCache {
+    /// Open (or create) the store at the given database path
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Failed to open SQLite database")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        Self::with_connection(conn)
+    }
+
+    /// Create an in-memory store (for tests)
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS http_validators (
+                url TEXT PRIMARY KEY,
+                etag TEXT,
+                last_modified TEXT,
+                updated_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .context("Failed to create HTTP cache schema")?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Validators stored for `url`, if any
+    pub fn get(&self, url: &str) -> Result<Option<HttpValidators>> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        conn.query_row(
+            "SELECT etag, last_modified FROM http_validators WHERE url = ?1",
+            params![url],
+            |row| {
+                Ok(HttpValidators {
+                    etag: row.get(0)?,
+                    last_modified: row.get(1)?,
+                })
+            },
+        )
+        .optional()
+        .context("Failed to read HTTP validators")
+    }
+
+    /// Store the validators of `url`, replacing earlier ones; empty
+    /// validators remove them, as the page can no longer be revalidated
+    pub fn put(&self, url: &str, validators: &HttpValidators) -> Result<()> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        if validators.is_empty() {
+            conn.execute("DELETE FROM http_validators WHERE url = ?1", params![url])
+        } else {
+            conn.execute(
+                "INSERT OR REPLACE INTO http_validators (url, etag, last_modified, updated_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    url,
+                    validators.etag,
+                    validators.last_modified,
+                    Utc::now().to_rfc3339()
+                ],
+            )
+        }
+        .context("Failed to save HTTP validators")?;
+        Ok(())
+    }
+
+    /// Number of URLs with validators
+    pub fn count(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap_or_else(PoisonError::into_inner);
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM http_validators", [], |row| row.get(0))
+            .context("Failed to count HTTP validators")?;
+        Ok(count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_replaces_and_removes() {
+        let cache = HttpCache::open_in_memory().unwrap();
+        let url = "https://n.news.naver.com/mnews/article/001/0000000001";
+        assert_eq!(cache.get(url).unwrap(), None);
+
+        let validators = HttpValidators {
+            etag: Some("\"v1\"".to_string()),
+            last_modified: Some("Mon, 15 Jan 2024 09:30:00 GMT".to_string()),
+        };
+        cache.put(url, &validators).unwrap();
+        assert_eq!(cache.get(url).unwrap(), Some(validators));
+
+        let etag_only = HttpValidators {
+            etag: Some("\"v2\"".to_string()),
+            last_modified: None,
+        };
+        cache.put(url, &etag_only).unwrap();
+        assert_eq!(cache.get(url).unwrap(), Some(etag_only));
+        assert_eq!(cache.count().unwrap(), 1);
+
+        cache.put(url, &HttpValidators::default()).unwrap();
+        assert_eq!(cache.get(url).unwrap(), None);
+        assert_eq!(cache.count().unwrap(), 0);
+    }
+}
@@ -0,0 +1,301 @@
+//! Per-file progress of `baram index` in SQLite
+//!
+//! Every markdown file handed to the indexer is recorded with its
+//! modification time and outcome, batch by batch, so an interrupted run over
+//! a large archive resumes where it stopped: files already indexed into the
+//! same OpenSearch index are skipped unless they changed since, and failed
+//! files are retried.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::storage::{IndexProgress, IndexRecord};
+//!
+//! # fn example() -> anyhow::Result<()> {
+//! let mut progress = IndexProgress::open("data/metadata.db", "baram-articles")?;
+//! progress.record(&[IndexRecord::indexed(
+//!     "/data/raw/001_0015812889_title.md",
+//!     1_767_225_600,
+//!     "001_0015812889",
+//! )])?;
+//!
+//! let indexed = progress.indexed_files()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Outcome of indexing one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexStatus {
+    /// Stored in the index
+    Indexed,
+    /// Could not be parsed or was rejected by the index
+    Failed,
+}
+
+impl IndexStatus {
+    /// Get the status name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Indexed => "indexed",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// Outcome of one file, as recorded by [`IndexProgress::record`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexRecord {
+    /// File path
+    pub path: String,
+    /// File modification time (Unix seconds)
+    pub modified: i64,
+    /// Document ID, if known
+    pub doc_id: Option<String>,
+    /// Outcome
+    pub status: IndexStatus,
+    /// Error message of a failed file
+    pub error: Option<String>,
+}
+
+impl IndexRecord {
+    /// Record of an indexed file
+    pub fn indexed(path: impl Into<String>, modified: i64, doc_id: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            modified,
+            doc_id: Some(doc_id.into()),
+            status: IndexStatus::Indexed,
+            error: None,
+        }
+    }
+
+    /// Record of a file that failed
+    pub fn failed(
+        path: impl Into<String>,
+        modified: i64,
+        doc_id: Option<String>,
+        error: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            modified,
+            doc_id,
+            status: IndexStatus::Failed,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Files recorded for one index
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct IndexProgressStats {
+    /// Files indexed
+    pub indexed: usize,
+    /// Files whose last attempt failed
+    pub failed: usize,
+}
+
+/// SQLite-backed indexing progress of one OpenSearch index
+pub struct IndexProgress {
+    conn: Connection,
+    index: String,
+}
+
+impl IndexProgress {
+    /// Open (or create) the progress of `index` in the database at `path`
+    pub fn open(path: impl AsRef<Path>, index: &str) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Failed to open SQLite database")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        Self::with_connection(conn, index)
+    }
+
+    /// Create an in-memory store (for tests and dry runs)
+    pub fn open_in_memory(index: &str) -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?, index)
+    }
+
+    fn with_connection(conn: Connection, index: &str) -> Result<Self> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS index_progress (
+                index_name TEXT NOT NULL,
+                path TEXT NOT NULL,
+                modified INTEGER NOT NULL,
+                doc_id TEXT,
+                status TEXT NOT NULL,
+                error TEXT,
+                updated_at TEXT NOT NULL,
+                PRIMARY KEY (index_name, path)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_index_progress_status
+                ON index_progress(index_name, status);
+            "#,
+        )
+        .context("Failed to create index progress schema")?;
+
+        Ok(Self {
+            conn,
+            index: index.to_string(),
+        })
+    }
+
+    /// Get the index name
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /// Modification times of the indexed files, by path
+    ///
+    /// A file needs indexing unless it is listed here with the same
+    /// modification time.
+    pub fn indexed_files(&self) -> Result<HashMap<String, i64>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT path, modified FROM index_progress
+             WHERE index_name = ?1 AND status = ?2",
+        )?;
+        let rows = stmt.query_map(params![self.index, IndexStatus::Indexed.as_str()], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })?;
+        rows.collect::<rusqlite::Result<_>>()
+            .context("Failed to load index progress")
+    }
+
+    /// Record the outcome of a batch of files in one transaction
+    ///
+    /// A later record of the same path replaces the earlier one.
+    pub fn record(&mut self, records: &[IndexRecord]) -> Result<()> {
+        let updated_at = Utc::now().to_rfc3339();
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO index_progress
+                    (index_name, path, modified, doc_id, status, error, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                 ON CONFLICT (index_name, path) DO UPDATE SET
+                    modified = excluded.modified,
+                    doc_id = excluded.doc_id,
+                    status = excluded.status,
+                    error = excluded.error,
+                    updated_at = excluded.updated_at",
+            )?;
+            for record in records {
+                stmt.execute(params![
+                    self.index,
+                    record.path,
+                    record.modified,
+                    record.doc_id,
+                    record.status.as_str(),
+                    record.error,
+                    updated_at,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Count the recorded files by status
+    pub fn stats(&self) -> Result<IndexProgressStats> {
+        let mut stmt = self.conn.prepare(
+            "SELECT status, COUNT(*) FROM index_progress
+             WHERE index_name = ?1 GROUP BY status",
+        )?;
+        let rows = stmt.query_map(params![self.index], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+
+        let mut stats = IndexProgressStats::default();
+        for row in rows {
+            let (status, count) = row?;
+            if status == IndexStatus::Indexed.as_str() {
+                stats.indexed = count as usize;
+            } else {
+                stats.failed = count as usize;
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Forget all progress of the index, e.g. after it was recreated
+    ///
+    /// Returns the number of files forgotten.
+    pub fn clear(&mut self) -> Result<usize> {
+        Ok(self.conn.execute(
+            "DELETE FROM index_progress WHERE index_name = ?1",
+            params![self.index],
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_resume() {
+        let mut progress = IndexProgress::open_in_memory("articles").unwrap();
+        progress
+            .record(&[
+                IndexRecord::indexed("/raw/a.md", 100, "001_1"),
+                IndexRecord::indexed("/raw/b.md", 200, "001_2"),
+                IndexRecord::failed("/raw/c.md", 300, None, "missing title"),
+            ])
+            .unwrap();
+
+        let indexed = progress.indexed_files().unwrap();
+        assert_eq!(indexed.len(), 2);
+        assert_eq!(indexed["/raw/a.md"], 100);
+        assert!(!indexed.contains_key("/raw/c.md"));
+        assert_eq!(
+            progress.stats().unwrap(),
+            IndexProgressStats {
+                indexed: 2,
+                failed: 1
+            }
+        );
+
+        // A retried file replaces its failure, a changed file its old time
+        progress
+            .record(&[
+                IndexRecord::indexed("/raw/c.md", 300, "001_3"),
+                IndexRecord::indexed("/raw/a.md", 150, "001_1"),
+            ])
+            .unwrap();
+        let indexed = progress.indexed_files().unwrap();
+        assert_eq!(indexed.len(), 3);
+        assert_eq!(indexed["/raw/a.md"], 150);
+        assert_eq!(progress.stats().unwrap().failed, 0);
+    }
+
+    #[test]
+    fn test_progress_is_per_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("metadata.db");
+
+        let mut articles = IndexProgress::open(&db, "articles").unwrap();
+        articles
+            .record(&[IndexRecord::indexed("/raw/a.md", 100, "001_1")])
+            .unwrap();
+
+        let other = IndexProgress::open(&db, "articles-v2").unwrap();
+        assert!(other.indexed_files().unwrap().is_empty());
+
+        assert_eq!(articles.clear().unwrap(), 1);
+        assert!(articles.indexed_files().unwrap().is_empty());
+    }
+}
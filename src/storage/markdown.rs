@@ -9,17 +9,32 @@
 //! - Top-level comments start without indentation
 //! - Each reply level adds one `>` prefix
 //! - Maximum depth is configurable (default: 10 levels)
+//!
+//! [`MarkdownWriter`] renders the comments, images, videos and reactions
+//! collected on a [`ParsedArticle`]; each section is left out when empty.
+//!
+//! With [`StorageFormat::Json`], [`ArticleStorage`] writes an [`ArticleJson`]
+//! file instead (or next to it, with [`StorageFormat::Both`]), which keeps
+//! every field of the article for machine processing.
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use handlebars::Handlebars;
-use serde::Serialize;
-use std::fmt::Write as FmtWrite;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write as FmtWrite};
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use crate::crawler::comment::Comment;
-use crate::models::ParsedArticle;
+use crate::models::{ArticleMedia, ArticleSource, ParsedArticle};
+
+/// Line the default template writes after the article body
+///
+/// Readers of saved articles stop collecting the body here, so images,
+/// reactions and comments are not taken for article text.
+pub const BODY_END_MARKER: &str = "<!-- body-end -->";
 
 /// Default article template
 const DEFAULT_TEMPLATE: &str = include_str!("../../templates/article.hbs");
@@ -36,13 +51,22 @@ struct ArticleTemplateData {
     published_at: String,
     crawled_at: String,
     url: String,
+    source: String,
     oid: String,
     aid: String,
     content_hash: String,
+    comment_count: usize,
+    has_comments: bool,
+    comments_markdown: String,
+    comment_stats: String,
+    images: Vec<ArticleMedia>,
+    videos: Vec<ArticleMedia>,
+    reactions: String,
 }
 
 impl From<&ParsedArticle> for ArticleTemplateData {
     fn from(article: &ParsedArticle) -> Self {
+        let renderer = CommentRenderer::new();
         Self {
             id: article.id(),
             title: article.title.clone(),
@@ -56,13 +80,48 @@ impl From<&ParsedArticle> for ArticleTemplateData {
                 .unwrap_or_default(),
             crawled_at: article.crawled_at.format("%Y-%m-%d %H:%M:%S").to_string(),
             url: article.url.clone(),
+            source: article.source.to_string(),
             oid: article.oid.clone(),
             aid: article.aid.clone(),
             content_hash: article.content_hash.clone().unwrap_or_default(),
+            comment_count: article.comment_count(),
+            has_comments: !article.comments.is_empty(),
+            comments_markdown: renderer.render_comments(&article.comments),
+            comment_stats: if article.comments.is_empty() {
+                String::new()
+            } else {
+                renderer.render_stats(&article.comments)
+            },
+            images: article.images.clone(),
+            videos: article.videos.clone(),
+            reactions: render_reactions(article),
         }
     }
 }
 
+/// Reaction counts as one line, e.g. `좋아요 12 · 슬퍼요 3`
+fn render_reactions(article: &ParsedArticle) -> String {
+    article
+        .reactions
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(reaction, count)| format!("{} {count}", reaction_label(reaction)))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+/// Label Naver shows for a reaction type
+fn reaction_label(reaction: &str) -> &str {
+    match reaction {
+        "like" => "좋아요",
+        "warm" => "훈훈해요",
+        "sad" => "슬퍼요",
+        "angry" => "화나요",
+        "want" => "후속기사 원해요",
+        other => other,
+    }
+}
+
 /// Markdown writer with Handlebars template engine
 pub struct MarkdownWriter<'a> {
     /// Handlebars template engine
@@ -195,19 +254,10 @@ impl<'a> MarkdownWriter<'a> {
 
     /// Generate filename for article
     ///
-    /// Format: {oid}_{aid}_{sanitized_title}.md
+    /// Format: {id}_{sanitized_title}.md, where the ID is `{oid}_{aid}` for
+    /// Naver articles and `{source}_{source_id}` otherwise
     fn generate_filename(&self, article: &ParsedArticle) -> String {
-        let sanitized_title = sanitize_filename(&article.title, 50);
-        format!(
-            "{}_{}{}.md",
-            article.oid,
-            article.aid,
-            if sanitized_title.is_empty() {
-                String::new()
-            } else {
-                format!("_{sanitized_title}")
-            }
-        )
+        format!("{}.md", file_stem(article))
     }
 
     /// Get output directory
@@ -222,6 +272,16 @@ impl<'a> MarkdownWriter<'a> {
     }
 }
 
+/// Name of the files of an article without extension: `{id}_{sanitized_title}`
+fn file_stem(article: &ParsedArticle) -> String {
+    let sanitized_title = sanitize_filename(&article.title, 50);
+    if sanitized_title.is_empty() {
+        article.id()
+    } else {
+        format!("{}_{sanitized_title}", article.id())
+    }
+}
+
 /// Sanitize string for use as filename
 ///
 /// # Arguments
@@ -283,29 +343,250 @@ impl Default for BatchSaveResult {
     }
 }
 
+/// File in an output directory naming the [`StorageFormat`] of its articles
+const FORMAT_FILE: &str = ".baram-format";
+
+/// File formats [`ArticleStorage`] saves articles in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageFormat {
+    /// Markdown rendered from the article template
+    #[default]
+    Markdown,
+    /// [`ArticleJson`] with the full article
+    Json,
+    /// Markdown and JSON side by side
+    Both,
+}
+
+impl StorageFormat {
+    /// Name used on the command line
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageFormat::Markdown => "md",
+            StorageFormat::Json => "json",
+            StorageFormat::Both => "both",
+        }
+    }
+
+    /// Whether a markdown file is written
+    #[must_use]
+    pub fn markdown(&self) -> bool {
+        matches!(self, StorageFormat::Markdown | StorageFormat::Both)
+    }
+
+    /// Whether a JSON file is written
+    #[must_use]
+    pub fn json(&self) -> bool {
+        matches!(self, StorageFormat::Json | StorageFormat::Both)
+    }
+
+    /// Format the articles of `output_dir` were last saved in, markdown if
+    /// none is recorded
+    #[must_use]
+    pub fn recorded(output_dir: &Path) -> Self {
+        fs::read_to_string(output_dir.join(FORMAT_FILE))
+            .ok()
+            .and_then(|format| format.trim().parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for StorageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StorageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(StorageFormat::Markdown),
+            "json" => Ok(StorageFormat::Json),
+            "both" => Ok(StorageFormat::Both),
+            other => Err(format!(
+                "Unknown article format: {other}. Use md, json or both"
+            )),
+        }
+    }
+}
+
+/// Version of the [`ArticleJson`] layout, raised on incompatible changes
+pub const ARTICLE_JSON_VERSION: u32 = 1;
+
+/// Article file written with [`StorageFormat::Json`]
+///
+/// Unlike the markdown file, which keeps what the template renders, it holds
+/// every field of the article, comment trees included, and reads back into
+/// the same [`ParsedArticle`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleJson {
+    /// [`ARTICLE_JSON_VERSION`] of the writer
+    pub version: u32,
+    /// Internal article ID (`{oid}_{aid}` for Naver articles)
+    pub id: String,
+    pub article: ParsedArticle,
+    pub extraction: ExtractionMetadata,
+}
+
+/// How an [`ArticleJson`] file came to be
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionMetadata {
+    /// baram version that crawled the article
+    pub crawler_version: String,
+    pub saved_at: DateTime<Utc>,
+    /// Comments including replies
+    pub comment_count: usize,
+    /// Characters in the article body
+    pub content_chars: usize,
+    pub image_count: usize,
+    pub video_count: usize,
+}
+
+impl ArticleJson {
+    /// File contents for `article`, saved now
+    pub fn new(article: &ParsedArticle) -> Self {
+        Self {
+            version: ARTICLE_JSON_VERSION,
+            id: article.id(),
+            extraction: ExtractionMetadata {
+                crawler_version: env!("CARGO_PKG_VERSION").to_string(),
+                saved_at: Utc::now(),
+                comment_count: article.comment_count(),
+                content_chars: article.content.chars().count(),
+                image_count: article.images.len(),
+                video_count: article.videos.len(),
+            },
+            article: article.clone(),
+        }
+    }
+
+    /// Read an article JSON file
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse article JSON: {}", path.display()))
+    }
+}
+
 /// Extended markdown writer with batch operations and skip logic
 pub struct ArticleStorage<'a> {
     writer: MarkdownWriter<'a>,
     skip_existing: bool,
+    format: StorageFormat,
 }
 
 impl<'a> ArticleStorage<'a> {
-    /// Create new article storage
+    /// Create new article storage writing markdown files
     pub fn new(output_dir: &Path, skip_existing: bool) -> Result<Self> {
+        Self::with_format(output_dir, skip_existing, StorageFormat::Markdown)
+    }
+
+    /// Create new article storage writing `format` files
+    ///
+    /// The format is recorded in `output_dir`, so commands revisiting its
+    /// articles keep it (see [`Self::with_recorded_format`]).
+    pub fn with_format(
+        output_dir: &Path,
+        skip_existing: bool,
+        format: StorageFormat,
+    ) -> Result<Self> {
+        let writer = MarkdownWriter::new(output_dir)?;
+        fs::write(output_dir.join(FORMAT_FILE), format.as_str())
+            .context("Failed to record the article format")?;
         Ok(Self {
-            writer: MarkdownWriter::new(output_dir)?,
+            writer,
             skip_existing,
+            format,
         })
     }
 
+    /// Create new article storage writing the format recorded in
+    /// `output_dir`
+    pub fn with_recorded_format(output_dir: &Path, skip_existing: bool) -> Result<Self> {
+        Self::with_format(
+            output_dir,
+            skip_existing,
+            StorageFormat::recorded(output_dir),
+        )
+    }
+
+    /// Formats articles are saved in
+    pub fn format(&self) -> StorageFormat {
+        self.format
+    }
+
+    /// Whether articles saved before are left as they are
+    pub fn skip_existing(&self) -> bool {
+        self.skip_existing
+    }
+
+    /// Directory articles are saved in
+    pub fn output_dir(&self) -> &Path {
+        self.writer.output_dir()
+    }
+
+    /// Files `article` is saved to, markdown first
+    pub fn paths(&self, article: &ParsedArticle) -> Vec<PathBuf> {
+        let stem = file_stem(article);
+        let mut paths = Vec::with_capacity(2);
+        if self.format.markdown() {
+            paths.push(self.writer.output_dir.join(format!("{stem}.md")));
+        }
+        if self.format.json() {
+            paths.push(self.writer.output_dir.join(format!("{stem}.json")));
+        }
+        paths
+    }
+
+    /// Check if all files of the article already exist
+    pub fn exists(&self, article: &ParsedArticle) -> bool {
+        self.paths(article).iter().all(|path| path.exists())
+    }
+
     /// Save article with optional skip logic
+    ///
+    /// Returns the first file written: the markdown file, unless only JSON
+    /// is written.
     pub fn save(&self, article: &ParsedArticle) -> Result<Option<PathBuf>> {
-        if self.skip_existing && self.writer.exists(article) {
+        if self.skip_existing && self.exists(article) {
             tracing::debug!(id = %article.id(), "Skipping existing article");
             return Ok(None);
         }
 
-        self.writer.save(article).map(Some)
+        self.write(article).map(Some)
+    }
+
+    /// Write the files of `article` in the configured formats and return
+    /// the first one
+    fn write(&self, article: &ParsedArticle) -> Result<PathBuf> {
+        let json = if self.format.json() {
+            Some(self.save_json(article)?)
+        } else {
+            None
+        };
+        match json {
+            Some(path) if !self.format.markdown() => Ok(path),
+            _ => self.writer.save(article),
+        }
+    }
+
+    /// Save `article` as an [`ArticleJson`] file
+    fn save_json(&self, article: &ParsedArticle) -> Result<PathBuf> {
+        let filepath = self
+            .writer
+            .output_dir
+            .join(format!("{}.json", file_stem(article)));
+        let json = serde_json::to_string_pretty(&ArticleJson::new(article))
+            .context("Failed to serialize article")?;
+        fs::write(&filepath, json)
+            .with_context(|| format!("Failed to write to file: {}", filepath.display()))?;
+
+        tracing::debug!(path = %filepath.display(), "Saved article to JSON");
+        Ok(filepath)
     }
 
     /// Save batch with detailed result
@@ -313,12 +594,12 @@ impl<'a> ArticleStorage<'a> {
         let mut result = BatchSaveResult::new();
 
         for article in articles {
-            if self.skip_existing && self.writer.exists(article) {
+            if self.skip_existing && self.exists(article) {
                 result.skipped.push(article.id());
                 continue;
             }
 
-            match self.writer.save(article) {
+            match self.write(article) {
                 Ok(path) => result.saved.push(path),
                 Err(e) => result.failed.push((article.id(), e.to_string())),
             }
@@ -340,6 +621,419 @@ impl<'a> ArticleStorage<'a> {
     }
 }
 
+// ============================================================================
+// Markdown Reading
+// ============================================================================
+
+/// Frontmatter of an article markdown file, as written by [`MarkdownWriter`]
+///
+/// Every field is optional: files written by other tools or older versions
+/// may leave any of them out, and empty values read as `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct Frontmatter {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub publisher: Option<String>,
+    pub author: Option<String>,
+    /// Publication time; `date` is accepted as well
+    #[serde(alias = "date")]
+    pub published_at: Option<String>,
+    pub crawled_at: Option<String>,
+    pub url: Option<String>,
+    pub source: Option<String>,
+    pub oid: Option<String>,
+    pub aid: Option<String>,
+    pub content_hash: Option<String>,
+    /// Comments including replies; 0 usually means they were not crawled
+    pub comment_count: Option<u64>,
+}
+
+impl Frontmatter {
+    /// Parse the YAML between the `---` lines
+    fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        // An empty block is YAML null rather than an empty mapping
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_yaml::from_str::<Self>(yaml).map(Self::without_empty)
+    }
+
+    /// Read `key: value` lines one by one, for frontmatter that is not valid
+    /// YAML (e.g. a title with unescaped quotes)
+    fn from_lines(yaml: &str) -> Self {
+        let mut frontmatter = Self::default();
+        for line in yaml.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = Some(value.trim().trim_matches('"').to_string());
+            match key.trim() {
+                "id" => frontmatter.id = value,
+                "title" => frontmatter.title = value,
+                "category" => frontmatter.category = value,
+                "publisher" => frontmatter.publisher = value,
+                "author" => frontmatter.author = value,
+                "published_at" | "date" => frontmatter.published_at = value,
+                "crawled_at" => frontmatter.crawled_at = value,
+                "url" => frontmatter.url = value,
+                "source" => frontmatter.source = value,
+                "oid" => frontmatter.oid = value,
+                "aid" => frontmatter.aid = value,
+                "content_hash" => frontmatter.content_hash = value,
+                "comment_count" => {
+                    frontmatter.comment_count = value.and_then(|v| v.parse().ok());
+                }
+                _ => {}
+            }
+        }
+        frontmatter.without_empty()
+    }
+
+    fn without_empty(mut self) -> Self {
+        for field in [
+            &mut self.id,
+            &mut self.title,
+            &mut self.category,
+            &mut self.publisher,
+            &mut self.author,
+            &mut self.published_at,
+            &mut self.crawled_at,
+            &mut self.url,
+            &mut self.source,
+            &mut self.oid,
+            &mut self.aid,
+            &mut self.content_hash,
+        ] {
+            if field.as_deref().is_some_and(|v| v.trim().is_empty()) {
+                *field = None;
+            }
+        }
+        self
+    }
+}
+
+/// Article read from a markdown file by [`MarkdownReader`]
+#[derive(Debug, Clone)]
+pub struct MarkdownArticle {
+    /// File the article was read from
+    pub path: PathBuf,
+    pub frontmatter: Frontmatter,
+    /// First `# ` heading, else the frontmatter title, else `Untitled`
+    pub title: String,
+    /// Article text, without the title heading and byline the template
+    /// writes above it and the media and comments it writes below
+    pub body: String,
+}
+
+impl MarkdownArticle {
+    /// Source site; Naver unless the frontmatter names another
+    pub fn source(&self) -> ArticleSource {
+        self.frontmatter
+            .source
+            .as_deref()
+            .and_then(ArticleSource::parse)
+            .unwrap_or_default()
+    }
+
+    /// Naver publisher and article IDs, from the frontmatter or else the
+    /// file name; empty for other sources
+    pub fn oid_aid(&self) -> (String, String) {
+        if self.source() != ArticleSource::Naver {
+            let field = |value: &Option<String>| value.clone().unwrap_or_default();
+            return (field(&self.frontmatter.oid), field(&self.frontmatter.aid));
+        }
+        if let (Some(oid), Some(aid)) = (&self.frontmatter.oid, &self.frontmatter.aid) {
+            return (oid.clone(), aid.clone());
+        }
+        if let Some((oid, aid)) = article_id_from_path(&self.path)
+            .as_deref()
+            .and_then(|id| id.split_once('_'))
+        {
+            return (oid.to_string(), aid.to_string());
+        }
+        ("000".to_string(), self.file_stem().to_string())
+    }
+
+    /// Internal article ID, as [`ParsedArticle::id`] would give
+    ///
+    /// Naver IDs are made of `oid` and `aid`; other sources take the
+    /// frontmatter `id`, else the one in the file name.
+    pub fn id(&self) -> String {
+        let source = self.source();
+        if source == ArticleSource::Naver {
+            let (oid, aid) = self.oid_aid();
+            return format!("{oid}_{aid}");
+        }
+        self.frontmatter
+            .id
+            .clone()
+            .or_else(|| article_id_from_path(&self.path))
+            .unwrap_or_else(|| source.article_id(self.file_stem()))
+    }
+
+    /// Publication time, if the frontmatter has a valid one
+    pub fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter
+            .published_at
+            .as_deref()
+            .and_then(parse_frontmatter_date)
+    }
+
+    /// Crawl time, if the frontmatter has a valid one
+    pub fn crawled_at(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter
+            .crawled_at
+            .as_deref()
+            .and_then(parse_frontmatter_date)
+    }
+
+    /// The article as far as markdown keeps it: comments, media and
+    /// reactions are not read back (see [`ArticleJson`] for that)
+    pub fn into_article(self) -> ParsedArticle {
+        let source = self.source();
+        let (oid, aid) = self.oid_aid();
+        let source_id = match source {
+            ArticleSource::Naver => String::new(),
+            _ => ArticleSource::split_article_id(&self.id()).1.to_string(),
+        };
+        let published_at = self.published_at();
+        let crawled_at = self.crawled_at().unwrap_or_else(Utc::now);
+        let Frontmatter {
+            category,
+            publisher,
+            author,
+            url,
+            content_hash,
+            ..
+        } = self.frontmatter;
+        ParsedArticle {
+            source,
+            source_id,
+            oid,
+            aid,
+            title: self.title,
+            content: self.body,
+            url: url.unwrap_or_default(),
+            category: category.unwrap_or_default(),
+            publisher,
+            author,
+            published_at,
+            crawled_at,
+            content_hash,
+            ..Default::default()
+        }
+    }
+
+    fn file_stem(&self) -> &str {
+        self.path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown")
+    }
+}
+
+/// Reads article markdown files written by [`MarkdownWriter`], or by hand
+///
+/// The YAML frontmatter is optional. Frontmatter that is not valid YAML is
+/// read line by line as `key: value` pairs, unless the reader is strict.
+///
+/// # Example
+/// ```no_run
+/// use baram::storage::MarkdownReader;
+/// use std::path::Path;
+///
+/// let article = MarkdownReader::new()
+///     .read(Path::new("./output/raw/001_0014000001_title.md"))
+///     .unwrap();
+/// println!("{} ({:?})", article.title, article.published_at());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownReader {
+    strict: bool,
+}
+
+impl MarkdownReader {
+    /// Create a lenient reader
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fail on frontmatter that is not valid YAML
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Read the markdown file at `path`
+    pub fn read(&self, path: &Path) -> Result<MarkdownArticle> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {}", path.display()))?;
+        self.parse(&content, path)
+    }
+
+    /// Read only the frontmatter of the markdown file at `path`, stopping
+    /// at its closing `---`
+    pub fn read_frontmatter(&self, path: &Path) -> Result<Frontmatter> {
+        use std::io::{BufRead, BufReader};
+
+        let file =
+            File::open(path).with_context(|| format!("Failed to read file: {}", path.display()))?;
+        let mut lines = BufReader::new(file).lines();
+        let Some(first) = lines.next().transpose()? else {
+            return Ok(Frontmatter::default());
+        };
+        if first.strip_prefix('\u{feff}').unwrap_or(&first).trim_end() != "---" {
+            return Ok(Frontmatter::default());
+        }
+        let mut yaml = Vec::new();
+        for line in lines {
+            let line = line?;
+            if line.trim_end() == "---" {
+                return self.frontmatter(&yaml.join("\n"), path);
+            }
+            yaml.push(line);
+        }
+        anyhow::bail!("Unterminated frontmatter in {}", path.display())
+    }
+
+    /// Parse markdown read from `path`, which names the article when the
+    /// frontmatter does not
+    pub fn parse(&self, content: &str, path: &Path) -> Result<MarkdownArticle> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let mut lines = content.lines().peekable();
+
+        let mut frontmatter = Frontmatter::default();
+        if lines.peek().is_some_and(|line| line.trim_end() == "---") {
+            lines.next();
+            let mut yaml = Vec::new();
+            let mut closed = false;
+            for line in lines.by_ref() {
+                if line.trim_end() == "---" {
+                    closed = true;
+                    break;
+                }
+                yaml.push(line);
+            }
+            if !closed {
+                anyhow::bail!("Unterminated frontmatter in {}", path.display());
+            }
+            frontmatter = self.frontmatter(&yaml.join("\n"), path)?;
+        }
+
+        // Images, reactions and comments follow the body
+        let rest: Vec<&str> = lines.take_while(|line| *line != BODY_END_MARKER).collect();
+        let (heading, body) = split_header(&rest);
+
+        let title = heading
+            .map(str::to_string)
+            .or_else(|| frontmatter.title.clone())
+            .unwrap_or_else(|| "Untitled".to_string());
+
+        Ok(MarkdownArticle {
+            path: path.to_path_buf(),
+            frontmatter,
+            title,
+            body: body.join("\n").trim().to_string(),
+        })
+    }
+
+    /// Parse the frontmatter `yaml` of the file at `path`
+    fn frontmatter(&self, yaml: &str, path: &Path) -> Result<Frontmatter> {
+        match Frontmatter::from_yaml(yaml) {
+            Ok(frontmatter) => Ok(frontmatter),
+            Err(e) if self.strict => {
+                Err(e).with_context(|| format!("Invalid frontmatter in {}", path.display()))
+            }
+            Err(e) => {
+                tracing::debug!(
+                    path = %path.display(),
+                    error = %e,
+                    "Frontmatter is not valid YAML, reading it line by line"
+                );
+                Ok(Frontmatter::from_lines(yaml))
+            }
+        }
+    }
+}
+
+/// Split the lines after the frontmatter into the title heading and the body
+///
+/// The template writes `# title`, a `**publisher** | ...` byline and a
+/// `---` rule above the body; each is skipped when present.
+fn split_header<'a, 'b>(lines: &'b [&'a str]) -> (Option<&'a str>, &'b [&'a str]) {
+    let skip_blank = |lines: &'b [&'a str]| {
+        let start = lines
+            .iter()
+            .position(|line| !line.trim().is_empty())
+            .unwrap_or(lines.len());
+        &lines[start..]
+    };
+
+    let mut rest = skip_blank(lines);
+    let heading = rest.first().and_then(|line| line.strip_prefix("# "));
+    if heading.is_some() {
+        rest = skip_blank(&rest[1..]);
+    }
+    if rest.first().is_some_and(|line| line.starts_with("**")) {
+        let after_byline = skip_blank(&rest[1..]);
+        if after_byline
+            .first()
+            .is_some_and(|line| line.trim_end() == "---")
+        {
+            rest = &after_byline[1..];
+        }
+    }
+    (heading.map(str::trim), rest)
+}
+
+/// Parse a frontmatter date: RFC 3339, or `YYYY-MM-DD HH:MM[:SS]` or
+/// `YYYY-MM-DD` in UTC, as [`MarkdownWriter`] writes them
+pub fn parse_frontmatter_date(value: &str) -> Option<DateTime<Utc>> {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    })
+    .map(|dt| dt.and_utc())
+}
+
+/// Article ID in a markdown file name written by [`MarkdownWriter`]
+///
+/// File names are `{oid}_{aid}_{sanitized_title}.md` for Naver articles,
+/// where both IDs are numeric, and `{source}_{source_id}_{sanitized_title}.md`
+/// for other sources.
+pub fn article_id_from_path(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let mut parts = stem.splitn(3, '_');
+    let oid = parts.next()?;
+    let aid = parts.next()?;
+
+    let other_source = ArticleSource::parse(oid)
+        .is_some_and(|source| source != ArticleSource::Naver && source.as_str() == oid);
+    let naver = oid.chars().all(|c| c.is_ascii_digit()) && aid.chars().all(|c| c.is_ascii_digit());
+    if naver || (other_source && !aid.is_empty()) {
+        Some(format!("{oid}_{aid}"))
+    } else {
+        None
+    }
+}
+
 // ============================================================================
 // Comment Rendering
 // ============================================================================
@@ -574,6 +1268,7 @@ pub struct ArticleWithCommentsData {
     pub published_at: String,
     pub crawled_at: String,
     pub url: String,
+    pub source: String,
     pub oid: String,
     pub aid: String,
     pub content_hash: String,
@@ -607,6 +1302,7 @@ impl ArticleWithCommentsData {
                 .unwrap_or_default(),
             crawled_at: article.crawled_at.format("%Y-%m-%d %H:%M:%S").to_string(),
             url: article.url.clone(),
+            source: article.source.to_string(),
             oid: article.oid.clone(),
             aid: article.aid.clone(),
             content_hash: article.content_hash.clone().unwrap_or_default(),
@@ -640,6 +1336,7 @@ author: {{author}}
 published_at: {{published_at}}
 crawled_at: {{crawled_at}}
 url: {{url}}
+source: {{source}}
 oid: {{oid}}
 aid: {{aid}}
 content_hash: {{content_hash}}
@@ -741,9 +1438,8 @@ impl<'a> ArticleWithCommentsWriter<'a> {
         let suffix = if has_comments { "_with_comments" } else { "" };
 
         format!(
-            "{}_{}{}{}.md",
-            article.oid,
-            article.aid,
+            "{}{}{}.md",
+            article.id(),
             if sanitized_title.is_empty() {
                 String::new()
             } else {
@@ -767,7 +1463,7 @@ impl<'a> ArticleWithCommentsWriter<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
     use tempfile::TempDir;
 
     fn create_test_article() -> ParsedArticle {
@@ -783,6 +1479,7 @@ mod tests {
             published_at: Some(Utc::now()),
             crawled_at: Utc::now(),
             content_hash: Some("abc123".to_string()),
+            ..Default::default()
         }
     }
 
@@ -808,6 +1505,52 @@ mod tests {
         assert!(md.contains("테스트언론사"));
     }
 
+    #[test]
+    fn test_render_comments_and_media() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = MarkdownWriter::new(temp_dir.path()).unwrap();
+
+        let plain = writer.render(&create_test_article()).unwrap();
+        assert!(plain.contains("comment_count: 0"));
+        assert!(!plain.contains("## 사진") && !plain.contains("## 댓글"));
+
+        let mut article = create_test_article();
+        let mut comment = create_test_comment("c1", "첫 댓글", 5);
+        comment
+            .replies
+            .push(create_test_reply("c2", "c1", "답글입니다"));
+        article.comments.push(comment);
+        article.images.push(ArticleMedia {
+            url: "https://imgnews.pstatic.net/1.jpg".to_string(),
+            caption: Some("현장 사진".to_string()),
+            local_path: None,
+        });
+        article.images.push(ArticleMedia {
+            url: "https://imgnews.pstatic.net/2.jpg".to_string(),
+            caption: Some("도표".to_string()),
+            local_path: Some("../assets/001_0014000001/02.jpg".to_string()),
+        });
+        article.videos.push(ArticleMedia {
+            url: "https://tv.naver.com/v/1".to_string(),
+            caption: None,
+            local_path: None,
+        });
+        article.reactions.insert("like".to_string(), 12);
+        article.reactions.insert("sad".to_string(), 3);
+
+        let md = writer.render(&article).unwrap();
+        let (body, extras) = md.split_once(BODY_END_MARKER).unwrap();
+        assert!(body.contains("테스트 기사의 본문 내용입니다"));
+        assert!(!body.contains("현장 사진") && extras.contains("현장 사진"));
+        assert!(md.contains("comment_count: 2"));
+        assert!(md.contains("![현장 사진](https://imgnews.pstatic.net/1.jpg)"));
+        assert!(md.contains("![도표](../assets/001_0014000001/02.jpg)"));
+        assert!(md.contains("- [https://tv.naver.com/v/1](https://tv.naver.com/v/1)"));
+        assert!(md.contains("**반응**: 좋아요 12 · 슬퍼요 3"));
+        assert!(md.contains("## 댓글 (2 개)"));
+        assert!(md.contains("답글입니다"));
+    }
+
     #[test]
     fn test_save_article() {
         let temp_dir = TempDir::new().unwrap();
@@ -831,6 +1574,17 @@ mod tests {
         let filename = writer.generate_filename(&article);
         assert!(filename.starts_with("001_0014000001"));
         assert!(filename.ends_with(".md"));
+
+        let daum = ParsedArticle {
+            source: crate::models::ArticleSource::Daum,
+            source_id: "20240115093000123".to_string(),
+            oid: String::new(),
+            aid: String::new(),
+            ..article
+        };
+        assert!(writer
+            .generate_filename(&daum)
+            .starts_with("daum_20240115093000123_"));
     }
 
     #[test]
@@ -923,6 +1677,62 @@ mod tests {
         assert_eq!(result.saved.len(), 0);
     }
 
+    #[test]
+    fn test_storage_format_parse() {
+        assert_eq!("md".parse(), Ok(StorageFormat::Markdown));
+        assert_eq!("Markdown".parse(), Ok(StorageFormat::Markdown));
+        assert_eq!("json".parse(), Ok(StorageFormat::Json));
+        assert_eq!("both".parse(), Ok(StorageFormat::Both));
+        assert!("html".parse::<StorageFormat>().is_err());
+        assert_eq!(StorageFormat::Both.to_string(), "both");
+    }
+
+    #[test]
+    fn test_article_storage_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut article = create_test_article();
+        let mut comment = create_test_comment("c1", "첫 댓글", 5);
+        comment
+            .replies
+            .push(create_test_reply("c2", "c1", "답글입니다"));
+        article.comments.push(comment);
+
+        let json_only =
+            ArticleStorage::with_format(temp_dir.path(), true, StorageFormat::Json).unwrap();
+        let path = json_only.save(&article).unwrap().unwrap();
+        assert!(path.to_string_lossy().ends_with(".json"));
+        assert!(!json_only.writer().exists(&article));
+        assert!(json_only.save(&article).unwrap().is_none());
+
+        let saved = ArticleJson::read(&path).unwrap();
+        assert_eq!(saved.version, ARTICLE_JSON_VERSION);
+        assert_eq!(saved.id, "001_0014000001");
+        assert_eq!(saved.extraction.comment_count, 2);
+        assert_eq!(saved.article.content_hash, article.content_hash);
+        assert_eq!(saved.article.published_at, article.published_at);
+        assert_eq!(saved.article.comments[0].replies[0].content, "답글입니다");
+
+        // The JSON file alone does not count as saved in both formats
+        let both = ArticleStorage::with_format(temp_dir.path(), true, StorageFormat::Both).unwrap();
+        assert_eq!(both.paths(&article).len(), 2);
+        let path = both.save(&article).unwrap().unwrap();
+        assert!(path.to_string_lossy().ends_with(".md"));
+        assert!(both.paths(&article).iter().all(|path| path.exists()));
+
+        // Revisiting the directory keeps the last format
+        assert_eq!(
+            StorageFormat::recorded(temp_dir.path()),
+            StorageFormat::Both
+        );
+        let reopened = ArticleStorage::with_recorded_format(temp_dir.path(), false).unwrap();
+        assert_eq!(reopened.format(), StorageFormat::Both);
+        let empty = TempDir::new().unwrap();
+        assert_eq!(
+            StorageFormat::recorded(empty.path()),
+            StorageFormat::Markdown
+        );
+    }
+
     // ========================================================================
     // Comment Renderer Tests
     // ========================================================================
@@ -1264,4 +2074,123 @@ mod tests {
         assert!(!data.comments_markdown.is_empty());
         assert!(!data.comment_stats.is_empty());
     }
+
+    #[test]
+    fn test_markdown_reader_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = MarkdownWriter::new(temp_dir.path()).unwrap();
+        let mut article = create_test_article();
+        article.published_at = Some(Utc.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap());
+        let path = writer.save(&article).unwrap();
+
+        let markdown = MarkdownReader::new().strict(true).read(&path).unwrap();
+        assert_eq!(markdown.id(), article.id());
+        assert_eq!(markdown.title, article.title);
+        assert_eq!(markdown.body, article.content.trim());
+        assert_eq!(markdown.published_at(), article.published_at);
+        assert_eq!(
+            MarkdownReader::new().read_frontmatter(&path).unwrap(),
+            markdown.frontmatter
+        );
+
+        let read = markdown.into_article();
+        assert_eq!((read.oid, read.aid), (article.oid, article.aid));
+        assert_eq!(read.publisher, article.publisher);
+    }
+
+    #[test]
+    fn test_markdown_reader_frontmatter() {
+        let path = Path::new("daum_20240115093000123_title.md");
+        let content = "---\ntitle: \"Quoted \"title\"\"\nsource: daum\ndate: 2024-01-15\nauthor:\n---\n\nBody\n";
+
+        // Not valid YAML: read line by line unless strict
+        assert!(MarkdownReader::new()
+            .strict(true)
+            .parse(content, path)
+            .is_err());
+        let markdown = MarkdownReader::new().parse(content, path).unwrap();
+        assert_eq!(markdown.source(), ArticleSource::Daum);
+        assert_eq!(markdown.id(), "daum_20240115093000123");
+        assert_eq!(markdown.title, "Quoted \"title");
+        assert_eq!(markdown.frontmatter.author, None);
+        assert_eq!(
+            markdown.published_at(),
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap())
+        );
+        assert_eq!(markdown.body, "Body");
+
+        let markdown = MarkdownReader::new()
+            .parse("# Title\n\nBody", Path::new("001_0000000001.md"))
+            .unwrap();
+        assert_eq!(markdown.frontmatter, Frontmatter::default());
+        assert_eq!(markdown.id(), "001_0000000001");
+        assert_eq!(markdown.title, "Title");
+
+        assert!(MarkdownReader::new()
+            .parse("---\nid: 1\n", Path::new("a.md"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_date() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 9, 30, 0).unwrap();
+        for value in [
+            "2024-01-15T09:30:00Z",
+            "2024-01-15T18:30:00+09:00",
+            "2024-01-15 09:30",
+            "2024-01-15 09:30:00",
+        ] {
+            assert_eq!(parse_frontmatter_date(value), Some(expected), "{value}");
+        }
+        assert_eq!(parse_frontmatter_date(""), None);
+        assert_eq!(parse_frontmatter_date("yesterday"), None);
+    }
+
+    #[test]
+    fn test_article_id_from_path_standard_filename() {
+        let path = PathBuf::from("001_0015812889_강남구_국민권익위_청렴도_평가서.md");
+        assert_eq!(
+            article_id_from_path(&path),
+            Some("001_0015812889".to_string())
+        );
+    }
+
+    #[test]
+    fn test_article_id_from_path_no_title() {
+        let path = PathBuf::from("422_0000832799.md");
+        assert_eq!(
+            article_id_from_path(&path),
+            Some("422_0000832799".to_string())
+        );
+    }
+
+    #[test]
+    fn test_article_id_from_path_invalid_format() {
+        let path = PathBuf::from("unknown.md");
+        assert_eq!(article_id_from_path(&path), None);
+    }
+
+    #[test]
+    fn test_article_id_from_path_non_numeric_oid() {
+        let path = PathBuf::from("abc_0015812889_title.md");
+        assert_eq!(article_id_from_path(&path), None);
+    }
+
+    #[test]
+    fn test_article_id_from_path_other_source() {
+        let path = PathBuf::from("daum_20240115093000123_제목.md");
+        assert_eq!(
+            article_id_from_path(&path),
+            Some("daum_20240115093000123".to_string())
+        );
+    }
+
+    #[test]
+    fn test_article_id_from_path_three_digit_oid() {
+        let path = PathBuf::from("661_0000071158_강득구_지방선거_이후_합당이.md");
+        assert_eq!(
+            article_id_from_path(&path),
+            Some("661_0000071158".to_string())
+        );
+    }
 }
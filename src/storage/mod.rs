@@ -6,13 +6,33 @@
 //! - Async PostgreSQL deduplication for distributed crawling
 //! - Markdown files for article output
 //! - Checkpointing for resumable crawls
+//! - Per-file progress for resumable indexing
+//! - SimHash fingerprints for near-duplicate detection
+//! - Per-run crawl summaries
+//! - Article bodies with a full-text index for local search
+//! - Revisions of articles edited after they were crawled
+//! - A dead-letter queue of failed URLs
+//! - HTTP validators (`ETag`, `Last-Modified`) for conditional requests
 //! - **Repository pattern** for database abstraction
 
+pub mod articles;
+pub mod assets;
 pub mod checkpoint;
 pub mod dedup;
+pub mod failures;
+pub mod fulltext;
+pub mod http_cache;
+pub mod index_progress;
 pub mod markdown;
 pub mod repository;
-
+pub mod revisions;
+pub mod runs;
+pub mod sentiment;
+pub mod simhash;
+pub mod timeseries;
+
+pub use articles::{article_uuid, PgArticleStore};
+pub use assets::ArticleAsset;
 pub use checkpoint::{
     AsyncCheckpointManager, CheckpointManager, CheckpointStats, ConcurrencyConfig,
     ConcurrencyMonitor, CrawlState, FailedUrl,
@@ -21,25 +41,44 @@ pub use dedup::{
     create_shared_checker, AsyncDedupChecker, DedupCheckResult, DedupConfig, DedupRecord,
     DedupStats, PoolStatus, SharedDedupChecker,
 };
+pub use failures::{FailureStatus, QueuedFailure};
+pub use fulltext::StoredBody;
+pub use http_cache::{HttpCache, HttpValidators};
+pub use index_progress::{IndexProgress, IndexProgressStats, IndexRecord, IndexStatus};
 pub use markdown::{
-    ArticleStorage, ArticleWithCommentsData, ArticleWithCommentsWriter, BatchSaveResult,
-    CommentRenderConfig, CommentRenderer, MarkdownWriter,
+    article_id_from_path, parse_frontmatter_date, ArticleJson, ArticleStorage,
+    ArticleWithCommentsData, ArticleWithCommentsWriter, BatchSaveResult, CommentRenderConfig,
+    CommentRenderer, ExtractionMetadata, Frontmatter, MarkdownArticle, MarkdownReader,
+    MarkdownWriter, StorageFormat, ARTICLE_JSON_VERSION, BODY_END_MARKER,
 };
 pub use repository::{
     create_mock_repository, create_sqlite_repository, ArticleRepository, CrawlMetadataRepository,
     CrawlRecord, CrawlStats, CrawlStatus, MockCrawlMetadataRepository,
     SharedCrawlMetadataRepository, SqliteCrawlMetadataRepository,
 };
+pub use revisions::ArticleRevision;
+pub use runs::{CategoryRunStats, CrawlRun, RunInfo, RunStatus};
+pub use sentiment::{SentimentPoint, SentimentStore, SentimentSummary};
+pub use timeseries::{
+    article_samples, hour_bucket, top_deltas, DimensionDelta, PgTimeSeriesStore, Sample,
+    TimeSeriesPoint, TimeSeriesStore, METRIC_ARTICLES, METRIC_ENTITY, METRIC_KEYWORD,
+    METRIC_TITLE_KEYWORD,
+};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use deadpool_postgres::{Config as PoolConfig, ManagerConfig, Pool, RecyclingMethod, Runtime};
 use rusqlite::{params, Connection, OptionalExtension};
 use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
 use tokio_postgres::NoTls;
 
+use crate::analytics::TextTokenizer;
 use crate::config::DatabaseConfig;
+use crate::error::ErrorClass;
 use crate::models::ParsedArticle;
 use crate::parser::Article;
 
@@ -50,14 +89,26 @@ pub struct Database {
 
     /// PostgreSQL connection pool
     postgres: Option<Pool>,
+
+    /// Run that URLs marked as crawled are attributed to
+    run_id: Option<i64>,
+
+    /// Whether the PostgreSQL articles schema was created
+    postgres_schema: Arc<OnceCell<()>>,
+
+    /// Whether successful crawls also store the article body
+    store_bodies: bool,
 }
 
 impl Database {
     /// Create a new database instance
-    pub fn new(_config: &DatabaseConfig) -> Result<Self> {
+    pub fn new(config: &DatabaseConfig) -> Result<Self> {
         Ok(Self {
             sqlite: None,
             postgres: None,
+            run_id: None,
+            postgres_schema: Arc::default(),
+            store_bodies: config.store_bodies,
         })
     }
 
@@ -88,18 +139,7 @@ impl Database {
 
     /// Initialize PostgreSQL connection pool
     pub async fn init_postgres(&mut self, url: &str) -> Result<()> {
-        let mut cfg = PoolConfig::new();
-        cfg.url = Some(url.to_string());
-        cfg.manager = Some(ManagerConfig {
-            recycling_method: RecyclingMethod::Fast,
-        });
-
-        let pool = cfg
-            .create_pool(Some(Runtime::Tokio1), NoTls)
-            .context("Failed to create PostgreSQL connection pool")?;
-
-        self.postgres = Some(pool);
-
+        self.postgres = Some(create_postgres_pool(url)?);
         Ok(())
     }
 
@@ -113,7 +153,8 @@ impl Database {
                 content_hash TEXT NOT NULL,
                 crawled_at TEXT NOT NULL,
                 status TEXT NOT NULL DEFAULT 'success',
-                error_message TEXT
+                error_message TEXT,
+                run_id INTEGER REFERENCES crawl_runs(id)
             );
 
             CREATE INDEX IF NOT EXISTS idx_crawl_metadata_url
@@ -130,108 +171,118 @@ impl Database {
                 value TEXT NOT NULL,
                 updated_at TEXT NOT NULL
             );
+
+            CREATE TABLE IF NOT EXISTS crawl_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at TEXT NOT NULL,
+                finished_at TEXT,
+                status TEXT NOT NULL DEFAULT 'running',
+                output_dir TEXT NOT NULL,
+                categories TEXT NOT NULL DEFAULT '[]',
+                command TEXT NOT NULL DEFAULT 'crawl',
+                args TEXT NOT NULL DEFAULT '[]',
+                counts TEXT NOT NULL DEFAULT '{}',
+                app_version TEXT NOT NULL DEFAULT '',
+                git_commit TEXT
+            );
+
+            -- Comments, media and reactions of articles that have any (JSON)
+            CREATE TABLE IF NOT EXISTS article_details (
+                id TEXT PRIMARY KEY,
+                comment_count INTEGER NOT NULL DEFAULT 0,
+                comments TEXT NOT NULL DEFAULT '[]',
+                images TEXT NOT NULL DEFAULT '[]',
+                videos TEXT NOT NULL DEFAULT '[]',
+                reactions TEXT NOT NULL DEFAULT '{}'
+            );
             "#,
         )
         .context("Failed to create SQLite schema")?;
 
+        // Databases created before runs were tracked per article
+        add_missing_columns(conn, "crawl_metadata", &[("run_id", "INTEGER")])?;
+        add_missing_columns(
+            conn,
+            "crawl_runs",
+            &[
+                ("command", "TEXT NOT NULL DEFAULT 'crawl'"),
+                ("args", "TEXT NOT NULL DEFAULT '[]'"),
+                ("counts", "TEXT NOT NULL DEFAULT '{}'"),
+                ("app_version", "TEXT NOT NULL DEFAULT ''"),
+                ("git_commit", "TEXT"),
+            ],
+        )?;
+        conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS idx_crawl_metadata_run ON crawl_metadata(run_id);",
+        )
+        .context("Failed to create SQLite schema")?;
+        fulltext::create_schema(conn)?;
+        revisions::create_schema(conn)?;
+        failures::create_schema(conn)?;
+        assets::create_schema(conn)?;
+
         Ok(())
     }
 
-    /// Create PostgreSQL schema for articles
-    async fn create_postgres_schema(&self) -> Result<()> {
+    /// Store article in PostgreSQL
+    ///
+    /// Also records the article in the analytics time series and replaces
+    /// its comment reaction summary.
+    pub async fn store_article(&self, article: &Article) -> Result<()> {
+        self.articles()?
+            .store(std::slice::from_ref(article))
+            .await?;
+        tracing::debug!(article_id = %article.id, url = %article.url, "Article stored");
+        Ok(())
+    }
+
+    /// Insert or update crawled articles in PostgreSQL in one transaction
+    ///
+    /// Returns the number of articles stored. See [`PgArticleStore::store`].
+    pub async fn store_articles(&self, articles: &[ParsedArticle]) -> Result<usize> {
+        self.articles()?.store_parsed(articles).await
+    }
+
+    /// PostgreSQL article store sharing this database's pool
+    pub fn articles(&self) -> Result<PgArticleStore> {
         let pool = self
             .postgres
             .as_ref()
             .context("PostgreSQL not initialized")?;
-
-        let client = pool.get().await.context("Failed to get connection")?;
-
-        client
-            .execute(
-                r#"
-                CREATE TABLE IF NOT EXISTS articles (
-                    id UUID PRIMARY KEY,
-                    url TEXT NOT NULL UNIQUE,
-                    title TEXT NOT NULL,
-                    body TEXT NOT NULL,
-                    author TEXT,
-                    published_at TIMESTAMPTZ,
-                    category TEXT,
-                    content_hash TEXT NOT NULL,
-                    comments JSONB NOT NULL DEFAULT '[]',
-                    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
-                    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
-                );
-
-                CREATE INDEX IF NOT EXISTS idx_articles_url ON articles(url);
-                CREATE INDEX IF NOT EXISTS idx_articles_content_hash ON articles(content_hash);
-                CREATE INDEX IF NOT EXISTS idx_articles_published_at ON articles(published_at);
-                CREATE INDEX IF NOT EXISTS idx_articles_category ON articles(category);
-                "#,
-                &[],
-            )
-            .await
-            .context("Failed to create PostgreSQL schema")?;
-
-        tracing::info!("PostgreSQL articles schema initialized");
-        Ok(())
+        Ok(PgArticleStore::with_schema(
+            pool.clone(),
+            self.postgres_schema.clone(),
+        ))
     }
 
-    /// Store article in PostgreSQL
-    pub async fn store_article(&self, article: &Article) -> Result<()> {
+    /// PostgreSQL time-series store sharing this database's pool
+    pub fn timeseries(&self) -> Result<PgTimeSeriesStore> {
         let pool = self
             .postgres
             .as_ref()
             .context("PostgreSQL not initialized")?;
-
-        // Ensure schema exists
-        self.create_postgres_schema().await?;
-
-        let client = pool.get().await.context("Failed to get connection")?;
-
-        // Serialize comments to JSON
-        let comments_json =
-            serde_json::to_value(&article.comments).context("Failed to serialize comments")?;
-
-        // Insert or update article
-        client
-            .execute(
-                r#"
-                INSERT INTO articles (
-                    id, url, title, body, author, published_at, category, content_hash, comments
-                )
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-                ON CONFLICT (url) DO UPDATE SET
-                    title = EXCLUDED.title,
-                    body = EXCLUDED.body,
-                    author = EXCLUDED.author,
-                    published_at = EXCLUDED.published_at,
-                    category = EXCLUDED.category,
-                    content_hash = EXCLUDED.content_hash,
-                    comments = EXCLUDED.comments,
-                    updated_at = NOW()
-                "#,
-                &[
-                    &article.id,
-                    &article.url,
-                    &article.title,
-                    &article.body,
-                    &article.author,
-                    &article.published_at,
-                    &article.category,
-                    &article.content_hash,
-                    &comments_json,
-                ],
-            )
-            .await
-            .context("Failed to store article")?;
-
-        tracing::debug!(article_id = %article.id, url = %article.url, "Article stored");
-        Ok(())
+        Ok(PgTimeSeriesStore::new(pool.clone()))
     }
 
-    /// Retrieve article by ID
+    /// Retrieve article by UUID or internal article ID (`{oid}_{aid}`)
     pub async fn get_article(&self, id: &str) -> Result<Option<Article>> {
+        let article = self.articles()?.get(id).await?;
+        if let Some(article) = &article {
+            tracing::debug!(article_id = %article.id, "Article retrieved");
+        }
+        Ok(article)
+    }
+
+    /// Retrieve articles published at or after `since`, oldest first
+    ///
+    /// # Arguments
+    /// * `since` - Earliest publication time
+    /// * `limit` - Maximum number of articles (0 = unlimited)
+    pub async fn get_articles_since(
+        &self,
+        since: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Article>> {
         let pool = self
             .postgres
             .as_ref()
@@ -239,46 +290,28 @@ impl Database {
 
         let client = pool.get().await.context("Failed to get connection")?;
 
-        // Parse UUID
-        let article_id = uuid::Uuid::parse_str(id).context("Invalid UUID format")?;
-
-        // Query article
-        let row = client
-            .query_opt(
+        let limit = if limit == 0 { i64::MAX } else { limit as i64 };
+        let rows = client
+            .query(
                 r#"
                 SELECT id, url, title, body, author, published_at, category, content_hash, comments
                 FROM articles
-                WHERE id = $1
+                WHERE published_at >= $1
+                ORDER BY published_at
+                LIMIT $2
                 "#,
-                &[&article_id],
+                &[&since, &limit],
             )
             .await
-            .context("Failed to query article")?;
-
-        match row {
-            Some(row) => {
-                let comments_json: serde_json::Value = row.get(8);
-                let comments: Vec<crate::parser::Comment> =
-                    serde_json::from_value(comments_json)
-                        .context("Failed to deserialize comments")?;
-
-                let article = Article {
-                    id: row.get(0),
-                    url: row.get(1),
-                    title: row.get(2),
-                    body: row.get(3),
-                    author: row.get(4),
-                    published_at: row.get(5),
-                    category: row.get(6),
-                    content_hash: row.get(7),
-                    comments,
-                };
-
-                tracing::debug!(article_id = %article.id, "Article retrieved");
-                Ok(Some(article))
-            }
-            None => Ok(None),
-        }
+            .context("Failed to query articles")?;
+
+        let articles = rows
+            .iter()
+            .map(article_from_row)
+            .collect::<Result<Vec<_>>>()?;
+
+        tracing::debug!(count = articles.len(), since = %since, "Articles retrieved");
+        Ok(articles)
     }
 
     /// Check if URL has been crawled
@@ -325,6 +358,9 @@ impl Database {
 
     /// Mark URL as crawled
     ///
+    /// The URL is attributed to the run started with
+    /// [`start_crawl_run`](Self::start_crawl_run), if any.
+    ///
     /// # Arguments
     /// * `id` - Article ID (oid_aid)
     /// * `url` - Article URL
@@ -357,10 +393,18 @@ impl Database {
         // Use INSERT OR REPLACE to handle both id and url conflicts
         conn.execute(
             r#"
-            INSERT OR REPLACE INTO crawl_metadata (id, url, content_hash, crawled_at, status, error_message)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            INSERT OR REPLACE INTO crawl_metadata (id, url, content_hash, crawled_at, status, error_message, run_id)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
             "#,
-            params![effective_id, url, content_hash, now, status.as_str(), error_message],
+            params![
+                effective_id,
+                url,
+                content_hash,
+                now,
+                status.as_str(),
+                error_message,
+                self.run_id
+            ],
         )
         .context("Failed to mark URL as crawled")?;
 
@@ -368,6 +412,10 @@ impl Database {
     }
 
     /// Record successful crawl
+    ///
+    /// Also stores the article's comments, images, videos and reactions in
+    /// `article_details`, replacing any earlier ones, and its body when
+    /// `store_bodies` is set. The URL leaves the dead-letter queue.
     pub fn record_success(&self, article: &ParsedArticle) -> Result<()> {
         let hash = article.content_hash.as_deref().unwrap_or("");
         self.mark_url_crawled(
@@ -376,12 +424,164 @@ impl Database {
             hash,
             CrawlStatus::Success,
             None,
+        )?;
+        self.save_article_details(article)?;
+        if self.store_bodies {
+            self.save_article_body(article)?;
+        }
+        self.resolve_failure(&article.url)
+    }
+
+    /// Store the title and body of `article` with a full-text index entry,
+    /// replacing any earlier ones (see [`fulltext`])
+    pub fn save_article_body(&self, article: &ParsedArticle) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        fulltext::save(conn, &StoredBody::from(article), &TextTokenizer::new())
+    }
+
+    /// Number of article bodies stored
+    pub fn article_body_count(&self) -> Result<usize> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        fulltext::count(conn)
+    }
+
+    /// Record that `article` changed since the crawl described by `previous`
+    ///
+    /// `previous` becomes version 1 if the article has no revisions yet; the
+    /// new text is attributed to the current run. Returns its version number
+    /// (see [`revisions`]).
+    pub fn record_revision(
+        &self,
+        previous: &ArticleRevision,
+        article: &ParsedArticle,
+    ) -> Result<u32> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        let current = ArticleRevision {
+            article_id: article.id(),
+            url: article.url.clone(),
+            version: 0,
+            content_hash: article.content_hash.clone().unwrap_or_default(),
+            title: article.title.clone(),
+            body: article.content.clone(),
+            recorded_at: article.crawled_at,
+            run_id: self.run_id,
+        };
+        revisions::append(conn, previous, &current)
+    }
+
+    /// Every recorded version of an article, oldest first
+    pub fn get_revisions(&self, article_id: &str) -> Result<Vec<ArticleRevision>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        revisions::list(conn, article_id)
+    }
+
+    /// Record the downloaded images of an article (see [`assets`])
+    pub fn record_assets(&self, assets: &[ArticleAsset]) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        assets::record(conn, assets)
+    }
+
+    /// Every downloaded image of an article
+    pub fn get_assets(&self, article_id: &str) -> Result<Vec<ArticleAsset>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        assets::list(conn, article_id)
+    }
+
+    ///
+    /// An article without any has no row.
+    pub fn save_article_details(&self, article: &ParsedArticle) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        let id = article.id();
+
+        if article.comments.is_empty()
+            && article.images.is_empty()
+            && article.videos.is_empty()
+            && article.reactions.is_empty()
+        {
+            conn.execute("DELETE FROM article_details WHERE id = ?1", params![id])
+                .context("Failed to clear article details")?;
+            return Ok(());
+        }
+
+        conn.execute(
+            r#"
+            INSERT OR REPLACE INTO article_details
+                (id, comment_count, comments, images, videos, reactions)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+            params![
+                id,
+                article.comment_count() as i64,
+                serde_json::to_string(&article.comments)?,
+                serde_json::to_string(&article.images)?,
+                serde_json::to_string(&article.videos)?,
+                serde_json::to_string(&article.reactions)?,
+            ],
         )
+        .context("Failed to save article details")?;
+
+        Ok(())
+    }
+
+    /// Fill in the comments, media and reactions stored for `article`
+    ///
+    /// Returns whether any were stored.
+    pub fn load_article_details(&self, article: &mut ParsedArticle) -> Result<bool> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let row = conn
+            .query_row(
+                "SELECT comments, images, videos, reactions FROM article_details WHERE id = ?1",
+                params![article.id()],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                    ))
+                },
+            )
+            .optional()
+            .context("Failed to load article details")?;
+        let Some((comments, images, videos, reactions)) = row else {
+            return Ok(false);
+        };
+
+        article.comments = serde_json::from_str(&comments).context("Invalid stored comments")?;
+        article.images = serde_json::from_str(&images).context("Invalid stored images")?;
+        article.videos = serde_json::from_str(&videos).context("Invalid stored videos")?;
+        article.reactions = serde_json::from_str(&reactions).context("Invalid stored reactions")?;
+        Ok(true)
     }
 
     /// Record failed crawl
-    pub fn record_failure(&self, url: &str, error: &str) -> Result<()> {
-        self.mark_url_crawled("", url, "", CrawlStatus::Failed, Some(error))
+    ///
+    /// The URL is queued in the dead-letter queue, or its attempts counted
+    /// if it already is (see [`failures`]).
+    pub fn record_failure(&self, url: &str, error: &str, class: ErrorClass) -> Result<()> {
+        self.mark_url_crawled("", url, "", CrawlStatus::Failed, Some(error))?;
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        failures::record(conn, url, error, class)
+    }
+
+    /// Remove `url` from the dead-letter queue
+    pub fn resolve_failure(&self, url: &str) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        failures::resolve(conn, url)
+    }
+
+    /// Stop retrying `url`
+    pub fn mark_failure_dead(&self, url: &str) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        failures::mark_dead(conn, url)
+    }
+
+    /// URLs in the dead-letter queue with `status`, least recently failed
+    /// first
+    pub fn get_failed_urls(&self, status: FailureStatus) -> Result<Vec<QueuedFailure>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+        failures::list(conn, status)
     }
 
     /// Get crawl record by URL
@@ -415,6 +615,104 @@ impl Database {
         Ok(record)
     }
 
+    /// Get failed crawls recorded in `[start, end)`, newest first
+    pub fn get_failures_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<CrawlRecord>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, url, content_hash, crawled_at, status, error_message
+             FROM crawl_metadata
+             WHERE status = 'failed' AND crawled_at >= ?1 AND crawled_at < ?2
+             ORDER BY crawled_at DESC",
+        )?;
+
+        let records = stmt
+            .query_map(params![start.to_rfc3339(), end.to_rfc3339()], |row| {
+                Ok(CrawlRecord {
+                    id: row.get(0)?,
+                    url: row.get(1)?,
+                    content_hash: row.get(2)?,
+                    crawled_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(3)?)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                    status: CrawlStatus::Failed,
+                    error_message: row.get(5)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to get failed crawls")?;
+
+        Ok(records)
+    }
+
+    /// Get the time and status of every crawl recorded in `[start, end)`, oldest first
+    pub fn get_crawls_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, CrawlStatus)>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let mut stmt = conn.prepare(
+            "SELECT crawled_at, status
+             FROM crawl_metadata
+             WHERE crawled_at >= ?1 AND crawled_at < ?2
+             ORDER BY crawled_at",
+        )?;
+
+        let crawls = stmt
+            .query_map(params![start.to_rfc3339(), end.to_rfc3339()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to get crawls")?
+            .into_iter()
+            .filter_map(|(crawled_at, status)| {
+                let crawled_at = DateTime::parse_from_rfc3339(&crawled_at).ok()?;
+                Some((
+                    crawled_at.with_timezone(&Utc),
+                    status.parse().unwrap_or(CrawlStatus::Failed),
+                ))
+            })
+            .collect();
+
+        Ok(crawls)
+    }
+
+    /// Get every successful crawl, oldest first
+    pub fn get_successful_crawls(&self) -> Result<Vec<CrawlRecord>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, url, content_hash, crawled_at, error_message
+             FROM crawl_metadata
+             WHERE status = 'success'
+             ORDER BY crawled_at, id",
+        )?;
+
+        let records = stmt
+            .query_map([], |row| {
+                Ok(CrawlRecord {
+                    id: row.get(0)?,
+                    url: row.get(1)?,
+                    content_hash: row.get(2)?,
+                    crawled_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(3)?)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                    status: CrawlStatus::Success,
+                    error_message: row.get(4)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to get successful crawls")?;
+
+        Ok(records)
+    }
+
     /// Get crawl statistics
     pub fn get_stats(&self) -> Result<CrawlStats> {
         let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
@@ -485,6 +783,140 @@ impl Database {
         Ok(value)
     }
 
+    /// Record the start of a run and return its id
+    ///
+    /// URLs marked as crawled from now on reference the run.
+    pub fn start_crawl_run(&mut self, info: &RunInfo, output_dir: &Path) -> Result<i64> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        conn.execute(
+            "INSERT INTO crawl_runs (started_at, status, output_dir, command, args, app_version, git_commit)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                Utc::now().to_rfc3339(),
+                RunStatus::Running.as_str(),
+                output_dir.display().to_string(),
+                info.command,
+                serde_json::to_string(&info.args)?,
+                info.app_version,
+                info.git_commit
+            ],
+        )
+        .context("Failed to start crawl run")?;
+
+        let id = conn.last_insert_rowid();
+        self.run_id = Some(id);
+        Ok(id)
+    }
+
+    /// Save the per-category stats of a run
+    ///
+    /// With a final `status` other than `Running` the run is also marked as
+    /// finished now.
+    pub fn update_crawl_run(
+        &self,
+        id: i64,
+        status: RunStatus,
+        categories: &[CategoryRunStats],
+    ) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let finished_at = (status != RunStatus::Running).then(|| Utc::now().to_rfc3339());
+        let categories = serde_json::to_string(categories)?;
+        conn.execute(
+            "UPDATE crawl_runs SET status = ?2, finished_at = ?3, categories = ?4 WHERE id = ?1",
+            params![id, status.as_str(), finished_at, categories],
+        )
+        .context("Failed to update crawl run")?;
+
+        Ok(())
+    }
+
+    /// Save the item counts of a run
+    pub fn set_crawl_run_counts(&self, id: i64, counts: &BTreeMap<String, u64>) -> Result<()> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        conn.execute(
+            "UPDATE crawl_runs SET counts = ?2 WHERE id = ?1",
+            params![id, serde_json::to_string(counts)?],
+        )
+        .context("Failed to update crawl run")?;
+
+        Ok(())
+    }
+
+    /// Get a crawl run by id, or the latest run without one
+    pub fn get_crawl_run(&self, id: Option<i64>) -> Result<Option<CrawlRun>> {
+        let conn = self.sqlite.as_ref().context("SQLite not initialized")?;
+
+        let row = conn
+            .query_row(
+                "SELECT id, started_at, finished_at, status, output_dir, categories,
+                        command, args, counts, app_version, git_commit,
+                        (SELECT COUNT(*) FROM crawl_metadata WHERE run_id = crawl_runs.id)
+                 FROM crawl_runs
+                 WHERE ?1 IS NULL OR id = ?1
+                 ORDER BY id DESC
+                 LIMIT 1",
+                params![id],
+                |row| {
+                    Ok((
+                        (
+                            row.get::<_, i64>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, Option<String>>(2)?,
+                            row.get::<_, String>(3)?,
+                            row.get::<_, String>(4)?,
+                            row.get::<_, String>(5)?,
+                        ),
+                        (
+                            row.get::<_, String>(6)?,
+                            row.get::<_, String>(7)?,
+                            row.get::<_, String>(8)?,
+                            row.get::<_, String>(9)?,
+                            row.get::<_, Option<String>>(10)?,
+                            row.get::<_, i64>(11)?,
+                        ),
+                    ))
+                },
+            )
+            .optional()
+            .context("Failed to get crawl run")?;
+
+        let Some((
+            (id, started_at, finished_at, status, output_dir, categories),
+            (command, args, counts, app_version, git_commit, articles),
+        )) = row
+        else {
+            return Ok(None);
+        };
+        let parse_time = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .with_context(|| format!("Invalid time in crawl run {id}: {value}"))
+        };
+
+        Ok(Some(CrawlRun {
+            id,
+            info: RunInfo {
+                command,
+                args: serde_json::from_str(&args)
+                    .with_context(|| format!("Invalid arguments in crawl run {id}"))?,
+                app_version,
+                git_commit,
+            },
+            started_at: parse_time(&started_at)?,
+            finished_at: finished_at.as_deref().map(parse_time).transpose()?,
+            status: status.parse().map_err(anyhow::Error::msg)?,
+            output_dir,
+            categories: serde_json::from_str(&categories)
+                .with_context(|| format!("Invalid category stats in crawl run {id}"))?,
+            counts: serde_json::from_str(&counts)
+                .with_context(|| format!("Invalid counts in crawl run {id}"))?,
+            articles: articles as usize,
+        }))
+    }
+
     /// Filter URLs that haven't been crawled
     ///
     /// Uses batch query with `WHERE url IN (...)` for O(1) database round trips
@@ -579,6 +1011,58 @@ impl Database {
     }
 }
 
+/// Add the `columns` (name and declaration) that `table` does not have yet
+fn add_missing_columns(conn: &Connection, table: &str, columns: &[(&str, &str)]) -> Result<()> {
+    let existing: HashSet<String> = conn
+        .prepare(&format!("PRAGMA table_info({table})"))?
+        .query_map([], |row| row.get(1))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    for (name, declaration) in columns {
+        if !existing.contains(*name) {
+            conn.execute_batch(&format!(
+                "ALTER TABLE {table} ADD COLUMN {name} {declaration}"
+            ))
+            .with_context(|| format!("Failed to add column {table}.{name}"))?;
+        }
+    }
+    Ok(())
+}
+
+/// Create a PostgreSQL connection pool; connections are opened lazily
+fn create_postgres_pool(url: &str) -> Result<Pool> {
+    let mut cfg = PoolConfig::new();
+    cfg.url = Some(url.to_string());
+    cfg.manager = Some(ManagerConfig {
+        recycling_method: RecyclingMethod::Fast,
+    });
+
+    cfg.create_pool(Some(Runtime::Tokio1), NoTls)
+        .context("Failed to create PostgreSQL connection pool")
+}
+
+/// Build an [`Article`] from an `articles` row
+///
+/// Expects the columns `id, url, title, body, author, published_at, category,
+/// content_hash, comments` in that order.
+fn article_from_row(row: &tokio_postgres::Row) -> Result<Article> {
+    let comments_json: serde_json::Value = row.get(8);
+    let comments: Vec<crate::parser::Comment> =
+        serde_json::from_value(comments_json).context("Failed to deserialize comments")?;
+
+    Ok(Article {
+        id: row.get(0),
+        url: row.get(1),
+        title: row.get(2),
+        body: row.get(3),
+        author: row.get(4),
+        published_at: row.get(5),
+        category: row.get(6),
+        content_hash: row.get(7),
+        comments,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -590,6 +1074,7 @@ mod tests {
             sqlite_path: temp_file.path().to_path_buf(),
             postgres_url: String::from("postgresql://localhost/test"),
             pool_size: 5,
+            store_bodies: false,
         };
 
         let mut db = Database::new(&config).unwrap();
@@ -604,6 +1089,7 @@ mod tests {
             sqlite_path: temp_file.path().to_path_buf(),
             postgres_url: String::from("postgresql://localhost/test"),
             pool_size: 5,
+            store_bodies: false,
         };
 
         let mut db = Database::new(&config).unwrap();
@@ -666,14 +1152,117 @@ mod tests {
 
         db.record_success(&article).unwrap();
         assert!(db.is_url_crawled(&article.url).unwrap());
+        assert_eq!(db.article_body_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_record_success_stores_body() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let config = DatabaseConfig {
+            sqlite_path: temp_file.path().to_path_buf(),
+            postgres_url: String::new(),
+            pool_size: 5,
+            store_bodies: true,
+        };
+        let mut db = Database::new(&config).unwrap();
+        db.init_sqlite(temp_file.path()).unwrap();
+
+        let mut article = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0003".to_string(),
+            title: "반도체 수출".to_string(),
+            content: "반도체 수출이 늘었다.".to_string(),
+            url: "https://example.com/article/3".to_string(),
+            ..Default::default()
+        };
+        db.record_success(&article).unwrap();
+        article.content = "수출이 줄었다.".to_string();
+        db.record_success(&article).unwrap();
+        assert_eq!(db.article_body_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_record_revision() {
+        let (mut db, _temp) = create_test_db();
+        let run_id = db
+            .start_crawl_run(&RunInfo::current("recrawl"), Path::new("output/raw"))
+            .unwrap();
+
+        let mut article = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0004".to_string(),
+            title: "속보".to_string(),
+            content: "사망자 3명".to_string(),
+            url: "https://example.com/article/4".to_string(),
+            content_hash: Some("new".to_string()),
+            ..Default::default()
+        };
+        let previous = ArticleRevision {
+            article_id: article.id(),
+            url: article.url.clone(),
+            version: 0,
+            content_hash: "old".to_string(),
+            title: "속보".to_string(),
+            body: "사망자 1명".to_string(),
+            recorded_at: Utc::now(),
+            run_id: None,
+        };
+        assert_eq!(db.record_revision(&previous, &article).unwrap(), 2);
+
+        let revisions = db.get_revisions(&article.id()).unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].body, "사망자 1명");
+        assert_eq!(revisions[1].content_hash, "new");
+        assert_eq!(revisions[1].run_id, Some(run_id));
+
+        article.content_hash = Some("newer".to_string());
+        assert_eq!(db.record_revision(&previous, &article).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_article_details_round_trip() {
+        let (db, _temp) = create_test_db();
+
+        let mut article = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0002".to_string(),
+            url: "https://example.com/article/2".to_string(),
+            ..Default::default()
+        };
+        article.images.push(crate::models::ArticleMedia {
+            url: "https://imgnews.pstatic.net/1.jpg".to_string(),
+            caption: Some("사진".to_string()),
+            local_path: None,
+        });
+        article.reactions.insert("like".to_string(), 7);
+        db.record_success(&article).unwrap();
+
+        let mut loaded = ParsedArticle {
+            oid: "001".to_string(),
+            aid: "0002".to_string(),
+            ..Default::default()
+        };
+        assert!(db.load_article_details(&mut loaded).unwrap());
+        assert_eq!(loaded.images, article.images);
+        assert_eq!(loaded.reactions, article.reactions);
+
+        // Recrawled without details: the stale row is removed
+        article.images.clear();
+        article.reactions.clear();
+        db.record_success(&article).unwrap();
+        assert!(!db.load_article_details(&mut loaded).unwrap());
     }
 
     #[test]
     fn test_record_failure() {
         let (db, _temp) = create_test_db();
 
-        db.record_failure("https://example.com/failed", "Connection timeout")
-            .unwrap();
+        db.record_failure(
+            "https://example.com/failed",
+            "Connection timeout",
+            ErrorClass::Timeout,
+        )
+        .unwrap();
 
         let record = db
             .get_crawl_record("https://example.com/failed")
@@ -681,6 +1270,67 @@ mod tests {
             .unwrap();
         assert_eq!(record.status, CrawlStatus::Failed);
         assert_eq!(record.error_message, Some("Connection timeout".to_string()));
+
+        let queued = db.get_failed_urls(FailureStatus::Pending).unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].error_class, ErrorClass::Timeout);
+    }
+
+    #[test]
+    fn test_get_failures_between() {
+        let (db, _temp) = create_test_db();
+
+        db.mark_url_crawled("1", "url1", "h1", CrawlStatus::Success, None)
+            .unwrap();
+        db.mark_url_crawled("2", "url2", "", CrawlStatus::Failed, Some("HTTP 404"))
+            .unwrap();
+
+        let now = Utc::now();
+        let failures = db
+            .get_failures_between(
+                now - chrono::Duration::hours(1),
+                now + chrono::Duration::hours(1),
+            )
+            .unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].url, "url2");
+        assert_eq!(failures[0].error_message.as_deref(), Some("HTTP 404"));
+
+        let earlier = db
+            .get_failures_between(
+                now - chrono::Duration::hours(2),
+                now - chrono::Duration::hours(1),
+            )
+            .unwrap();
+        assert!(earlier.is_empty());
+    }
+
+    #[test]
+    fn test_get_crawls_between() {
+        let (db, _temp) = create_test_db();
+        db.mark_url_crawled("1", "url1", "h1", CrawlStatus::Success, None)
+            .unwrap();
+        db.mark_url_crawled("2", "url2", "h2", CrawlStatus::Skipped, None)
+            .unwrap();
+
+        let now = Utc::now();
+        let crawls = db
+            .get_crawls_between(
+                now - chrono::Duration::minutes(5),
+                now + chrono::Duration::minutes(5),
+            )
+            .unwrap();
+        let statuses: Vec<_> = crawls.iter().map(|(_, status)| *status).collect();
+        assert_eq!(statuses, [CrawlStatus::Success, CrawlStatus::Skipped]);
+        assert!(crawls[0].0 <= crawls[1].0);
+
+        let later = db
+            .get_crawls_between(
+                now + chrono::Duration::minutes(5),
+                now + chrono::Duration::hours(1),
+            )
+            .unwrap();
+        assert!(later.is_empty());
     }
 
     #[test]
@@ -717,6 +1367,108 @@ mod tests {
         assert!(missing.is_none());
     }
 
+    #[test]
+    fn test_crawl_runs() {
+        let (mut db, _temp) = create_test_db();
+        assert!(db.get_crawl_run(None).unwrap().is_none());
+
+        let info = RunInfo {
+            command: "crawl".to_string(),
+            args: vec![
+                "crawl".to_string(),
+                "--category".to_string(),
+                "politics".to_string(),
+            ],
+            app_version: "0.1.6".to_string(),
+            git_commit: Some("1a2b3c4d5e6f".to_string()),
+        };
+        let first = db
+            .start_crawl_run(&info, Path::new("./output/raw"))
+            .unwrap();
+        db.mark_url_crawled("001_1", "https://a", "h1", CrawlStatus::Success, None)
+            .unwrap();
+        db.mark_url_crawled("001_2", "https://b", "h2", CrawlStatus::Success, None)
+            .unwrap();
+        let counts = BTreeMap::from([("saved".to_string(), 2)]);
+        db.set_crawl_run_counts(first, &counts).unwrap();
+
+        let second = db
+            .start_crawl_run(&RunInfo::current("index"), Path::new("./output/raw"))
+            .unwrap();
+        // Re-recorded articles move to the later run
+        db.mark_url_crawled("001_2", "https://b", "h2", CrawlStatus::Success, None)
+            .unwrap();
+        let mut stats = CategoryRunStats::new("politics");
+        stats.saved = 3;
+        stats.record_error("timeout");
+        db.update_crawl_run(first, RunStatus::Finished, std::slice::from_ref(&stats))
+            .unwrap();
+
+        let run = db.get_crawl_run(Some(first)).unwrap().unwrap();
+        assert_eq!(run.status, RunStatus::Finished);
+        assert!(run.finished_at.is_some());
+        assert_eq!(run.output_dir, "./output/raw");
+        assert_eq!(run.categories, vec![stats]);
+        assert_eq!(run.info, info);
+        assert_eq!(run.counts, counts);
+        assert_eq!(run.articles, 1);
+
+        let latest = db.get_crawl_run(None).unwrap().unwrap();
+        assert_eq!(latest.id, second);
+        assert_eq!(latest.info.command, "index");
+        assert_eq!(latest.articles, 1);
+        assert!(latest.counts.is_empty());
+        assert_eq!(latest.status, RunStatus::Running);
+        assert!(latest.finished_at.is_none());
+        assert!(db.get_crawl_run(Some(second + 1)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_migrates_run_columns() {
+        let temp = NamedTempFile::new().unwrap();
+        {
+            let conn = Connection::open(temp.path()).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE crawl_metadata (
+                    id TEXT PRIMARY KEY,
+                    url TEXT NOT NULL UNIQUE,
+                    content_hash TEXT NOT NULL,
+                    crawled_at TEXT NOT NULL,
+                    status TEXT NOT NULL DEFAULT 'success',
+                    error_message TEXT
+                );
+                CREATE TABLE crawl_runs (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    started_at TEXT NOT NULL,
+                    finished_at TEXT,
+                    status TEXT NOT NULL DEFAULT 'running',
+                    output_dir TEXT NOT NULL,
+                    categories TEXT NOT NULL DEFAULT '[]'
+                );
+                INSERT INTO crawl_runs (started_at, output_dir)
+                    VALUES ('2026-01-01T00:00:00+00:00', './output/raw');",
+            )
+            .unwrap();
+        }
+
+        let config = DatabaseConfig {
+            sqlite_path: temp.path().to_path_buf(),
+            postgres_url: String::new(),
+            pool_size: 5,
+            store_bodies: false,
+        };
+        let mut db = Database::new(&config).unwrap();
+        db.init_sqlite(temp.path()).unwrap();
+        let run = db.get_crawl_run(None).unwrap().unwrap();
+        assert_eq!(run.info.command, "crawl");
+        assert!(run.info.args.is_empty());
+        assert!(run.info.git_commit.is_none());
+        assert_eq!(run.articles, 0);
+
+        // Migrating again is a no-op
+        db.init_sqlite(temp.path()).unwrap();
+    }
+
     #[test]
     fn test_filter_uncrawled() {
         let (db, _temp) = create_test_db();
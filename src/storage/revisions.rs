@@ -0,0 +1,170 @@
+//! Article revisions
+//!
+//! `baram recrawl` revisits crawled articles and compares the hash of their
+//! current text with `crawl_metadata.content_hash`. When a publisher has
+//! edited a story, every version seen is kept in `article_revisions`:
+//! version 1 is the text crawled first, and each later version is one edit
+//! found by a recrawl, so the history of a breaking story can be read back
+//! in order with [`Database::get_revisions`](super::Database::get_revisions).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+
+/// One version of an article
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArticleRevision {
+    pub article_id: String,
+    pub url: String,
+    /// 1 for the first crawl, counting up with each edit
+    pub version: u32,
+    pub content_hash: String,
+    pub title: String,
+    pub body: String,
+    /// When this version was crawled
+    pub recorded_at: DateTime<Utc>,
+    /// Run that crawled this version, if recorded
+    pub run_id: Option<i64>,
+}
+
+/// Create the revisions table
+pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        r#"
+        CREATE TABLE IF NOT EXISTS article_revisions (
+            article_id TEXT NOT NULL,
+            version INTEGER NOT NULL,
+            url TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            title TEXT NOT NULL,
+            body TEXT NOT NULL,
+            recorded_at TEXT NOT NULL,
+            run_id INTEGER,
+            PRIMARY KEY (article_id, version)
+        );
+        "#,
+    )
+    .context("Failed to create revisions schema")
+}
+
+/// Append `current` to the revisions of its article, first recording
+/// `previous` as version 1 if the article has none yet
+///
+/// Returns the version number given to `current`.
+pub(crate) fn append(
+    conn: &Connection,
+    previous: &ArticleRevision,
+    current: &ArticleRevision,
+) -> Result<u32> {
+    let tx = conn.unchecked_transaction()?;
+    let latest: Option<u32> = tx
+        .query_row(
+            "SELECT MAX(version) FROM article_revisions WHERE article_id = ?1",
+            params![current.article_id],
+            |row| row.get(0),
+        )
+        .context("Failed to read article revisions")?;
+    let latest = match latest {
+        Some(latest) => latest,
+        None => {
+            insert(&tx, previous, 1)?;
+            1
+        }
+    };
+    insert(&tx, current, latest + 1)?;
+    tx.commit()?;
+    Ok(latest + 1)
+}
+
+fn insert(conn: &Connection, revision: &ArticleRevision, version: u32) -> Result<()> {
+    conn.execute(
+        r#"
+        INSERT INTO article_revisions
+            (article_id, version, url, content_hash, title, body, recorded_at, run_id)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        "#,
+        params![
+            revision.article_id,
+            version,
+            revision.url,
+            revision.content_hash,
+            revision.title,
+            revision.body,
+            revision.recorded_at.to_rfc3339(),
+            revision.run_id,
+        ],
+    )
+    .context("Failed to save article revision")?;
+    Ok(())
+}
+
+/// Every version of an article, oldest first
+pub(crate) fn list(conn: &Connection, article_id: &str) -> Result<Vec<ArticleRevision>> {
+    let mut statement = conn.prepare(
+        "SELECT article_id, version, url, content_hash, title, body, recorded_at, run_id
+         FROM article_revisions WHERE article_id = ?1 ORDER BY version",
+    )?;
+    let revisions = statement
+        .query_map(params![article_id], |row| {
+            Ok(ArticleRevision {
+                article_id: row.get(0)?,
+                version: row.get(1)?,
+                url: row.get(2)?,
+                content_hash: row.get(3)?,
+                title: row.get(4)?,
+                body: row.get(5)?,
+                recorded_at: DateTime::parse_from_rfc3339(&row.get::<_, String>(6)?)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                run_id: row.get(7)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("Failed to get article revisions")?;
+    Ok(revisions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(hash: &str, body: &str) -> ArticleRevision {
+        ArticleRevision {
+            article_id: "001_0000000001".to_string(),
+            url: "https://n.news.naver.com/mnews/article/001/0000000001".to_string(),
+            version: 0,
+            content_hash: hash.to_string(),
+            title: "속보".to_string(),
+            body: body.to_string(),
+            recorded_at: Utc::now(),
+            run_id: None,
+        }
+    }
+
+    #[test]
+    fn test_append_numbers_versions() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_schema(&conn).unwrap();
+
+        let first = revision("a", "사망자 1명");
+        assert_eq!(
+            append(&conn, &first, &revision("b", "사망자 3명")).unwrap(),
+            2
+        );
+        // The first version is only recorded once
+        assert_eq!(
+            append(&conn, &first, &revision("c", "사망자 5명")).unwrap(),
+            3
+        );
+
+        let revisions = list(&conn, "001_0000000001").unwrap();
+        let versions: Vec<(u32, &str)> = revisions
+            .iter()
+            .map(|r| (r.version, r.content_hash.as_str()))
+            .collect();
+        assert_eq!(versions, [(1, "a"), (2, "b"), (3, "c")]);
+        assert_eq!(revisions[2].body, "사망자 5명");
+        assert!(list(&conn, "001_0000000002").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,372 @@
+//! Per-run crawl summaries
+//!
+//! `baram crawl` records one row in the `crawl_runs` table for each
+//! invocation: when it started and finished, how it ended, and what
+//! happened in each category. `baram index` and `baram ontology` record
+//! their runs over a crawled dataset in the same table. Each row also keeps
+//! the command line, the item counts and the version that ran, and every
+//! article in `crawl_metadata` references the run that last recorded it,
+//! so a dataset can be traced back to the runs that produced it.
+//! `baram report crawl` reads them back.
+//!
+//! ```
+//! use baram::storage::CategoryRunStats;
+//!
+//! let mut stats = CategoryRunStats::new("politics");
+//! stats.record_error("HTTP 503 for https://n.news.naver.com/article/001/0000000001");
+//! stats.record_error("HTTP 503 for https://n.news.naver.com/article/001/0000000002");
+//! assert_eq!(stats.failed, 2);
+//! assert_eq!(stats.errors["HTTP 503 for <url>"], 2);
+//! ```
+
+use crate::config::secrets;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Distinct error messages kept per category; further ones count as `other`
+pub const MAX_ERROR_MESSAGES: usize = 50;
+
+/// Bucket for error messages past [`MAX_ERROR_MESSAGES`]
+const OTHER_ERRORS: &str = "other";
+
+/// How a crawl run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    /// Still running, or killed before it could record its end
+    Running,
+    /// Finished normally
+    Finished,
+    /// Stopped by an error
+    Failed,
+    /// Stopped early by SIGINT or SIGTERM, after recording its progress
+    Interrupted,
+}
+
+impl RunStatus {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Finished => "finished",
+            Self::Failed => "failed",
+            Self::Interrupted => "interrupted",
+        }
+    }
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RunStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "running" => Ok(Self::Running),
+            "finished" => Ok(Self::Finished),
+            "failed" => Ok(Self::Failed),
+            "interrupted" => Ok(Self::Interrupted),
+            _ => Err(format!("Unknown run status: {s}")),
+        }
+    }
+}
+
+/// What happened in one category of a run
+///
+/// URL files and single URLs are recorded under the pseudo-categories
+/// `url-file` and `url`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryRunStats {
+    pub category: String,
+    /// URLs found in the article list (or read from the URL file)
+    pub found: usize,
+    /// URLs not crawled before, i.e. queued for this run
+    pub new: usize,
+    /// URLs skipped because they were already crawled or repeated
+    pub skipped: usize,
+    /// Articles saved
+    pub saved: usize,
+    /// Articles whose content duplicated an earlier article
+    pub duplicates: usize,
+    /// Articles that failed to fetch or parse
+    pub failed: usize,
+    /// Size of the markdown files written
+    pub bytes_written: u64,
+    /// Failure count per error message, with URLs replaced by `<url>`
+    #[serde(default)]
+    pub errors: BTreeMap<String, usize>,
+}
+
+impl CategoryRunStats {
+    pub fn new(category: impl Into<String>) -> Self {
+        Self {
+            category: category.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Count a failed article and its error message
+    ///
+    /// URLs are replaced so failures of the same kind share one message.
+    pub fn record_error(&mut self, message: &str) {
+        self.failed += 1;
+        let message = normalize_error(message);
+        let key = if self.errors.contains_key(&message) || self.errors.len() < MAX_ERROR_MESSAGES {
+            message
+        } else {
+            OTHER_ERRORS.to_string()
+        };
+        *self.errors.entry(key).or_default() += 1;
+    }
+
+    /// Add the counts of `other`, e.g. to total a run
+    pub fn merge(&mut self, other: &CategoryRunStats) {
+        self.found += other.found;
+        self.new += other.new;
+        self.skipped += other.skipped;
+        self.saved += other.saved;
+        self.duplicates += other.duplicates;
+        self.failed += other.failed;
+        self.bytes_written += other.bytes_written;
+        for (message, count) in &other.errors {
+            *self.errors.entry(message.clone()).or_default() += count;
+        }
+    }
+
+    /// Error messages, most frequent first
+    #[must_use]
+    pub fn top_errors(&self, limit: usize) -> Vec<(&str, usize)> {
+        let mut errors: Vec<(&str, usize)> = self
+            .errors
+            .iter()
+            .map(|(message, count)| (message.as_str(), *count))
+            .collect();
+        errors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        errors.truncate(limit);
+        errors
+    }
+}
+
+/// Replace URLs in an error message with `<url>`
+fn normalize_error(message: &str) -> String {
+    message
+        .split(' ')
+        .map(|word| {
+            if word.starts_with("http://") || word.starts_with("https://") {
+                "<url>"
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// What ran: the invocation and the build that ran it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RunInfo {
+    /// Subcommand, e.g. `crawl` or `index`
+    pub command: String,
+    /// Command-line arguments, without the program name
+    pub args: Vec<String>,
+    /// Version of baram
+    pub app_version: String,
+    /// Git commit baram was built from, when known
+    pub git_commit: Option<String>,
+}
+
+impl RunInfo {
+    /// The current invocation of `command`
+    #[must_use]
+    pub fn current(command: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            args: redact_overrides(std::env::args().skip(1)),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: option_env!("BARAM_GIT_COMMIT").map(str::to_string),
+        }
+    }
+
+    /// Version with the commit, e.g. `0.1.6 (1a2b3c4d5e6f)`
+    #[must_use]
+    pub fn version(&self) -> String {
+        match &self.git_commit {
+            Some(commit) => format!("{} ({commit})", self.app_version),
+            None => self.app_version.clone(),
+        }
+    }
+}
+
+/// `args` with the values of credential `--set` overrides masked, so a
+/// password given on the command line is not recorded with the run
+fn redact_overrides(args: impl IntoIterator<Item = String>) -> Vec<String> {
+    fn redact(value: &str) -> String {
+        match value.split_once('=') {
+            Some((key, _)) if secrets::is_credential(key.rsplit('.').next().unwrap_or(key)) => {
+                format!("{key}=********")
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    let mut redacted = Vec::new();
+    let mut after_set = false;
+    for arg in args {
+        let arg = if after_set {
+            redact(&arg)
+        } else if let Some(value) = arg.strip_prefix("--set=") {
+            format!("--set={}", redact(value))
+        } else {
+            arg
+        };
+        after_set = arg == "--set";
+        redacted.push(arg);
+    }
+    redacted
+}
+
+/// One recorded run of `baram crawl`, `index` or `ontology`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CrawlRun {
+    pub id: i64,
+    #[serde(flatten)]
+    pub info: RunInfo,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub status: RunStatus,
+    /// Markdown directory written (crawl) or read (index, ontology)
+    pub output_dir: String,
+    /// Per-category stats; only crawls have any
+    pub categories: Vec<CategoryRunStats>,
+    /// Items handled by the run, as reported with `--output-json`
+    pub counts: BTreeMap<String, u64>,
+    /// Articles in `crawl_metadata` last recorded by this run
+    pub articles: usize,
+}
+
+impl CrawlRun {
+    /// Time from start to finish; `None` while the run has not finished
+    #[must_use]
+    pub fn wall_time(&self) -> Option<Duration> {
+        self.finished_at.map(|finished| finished - self.started_at)
+    }
+
+    /// Counts over all categories
+    #[must_use]
+    pub fn totals(&self) -> CategoryRunStats {
+        let mut totals = CategoryRunStats::new("total");
+        for stats in &self.categories {
+            totals.merge(stats);
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_overrides() {
+        let args = [
+            "crawl",
+            "--set",
+            "opensearch.password=hunter2",
+            "--set=database.postgres_url=postgres://baram:pw@db/baram",
+            "--set",
+            "crawler.rate_limit=1.5",
+            "--output",
+            "output/raw",
+        ]
+        .map(String::from);
+        assert_eq!(
+            redact_overrides(args),
+            [
+                "crawl",
+                "--set",
+                "opensearch.password=********",
+                "--set=database.postgres_url=********",
+                "--set",
+                "crawler.rate_limit=1.5",
+                "--output",
+                "output/raw",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_record_error_caps_messages() {
+        let mut stats = CategoryRunStats::new("it");
+        for i in 0..MAX_ERROR_MESSAGES + 5 {
+            stats.record_error(&format!("error {i}"));
+        }
+        stats.record_error("error 0");
+
+        assert_eq!(stats.failed, MAX_ERROR_MESSAGES + 6);
+        assert_eq!(stats.errors.len(), MAX_ERROR_MESSAGES + 1);
+        assert_eq!(stats.errors[OTHER_ERRORS], 5);
+        assert_eq!(stats.errors["error 0"], 2);
+        assert_eq!(stats.top_errors(2), vec![(OTHER_ERRORS, 5), ("error 0", 2)]);
+    }
+
+    #[test]
+    fn test_totals() {
+        let start = Utc::now();
+        let mut politics = CategoryRunStats::new("politics");
+        politics.found = 40;
+        politics.new = 30;
+        politics.skipped = 10;
+        politics.saved = 28;
+        politics.bytes_written = 1000;
+        politics.record_error("timeout");
+        let mut economy = CategoryRunStats::new("economy");
+        economy.saved = 5;
+        economy.duplicates = 1;
+        economy.record_error("timeout");
+
+        let run = CrawlRun {
+            id: 1,
+            info: RunInfo::current("crawl"),
+            started_at: start,
+            finished_at: Some(start + Duration::seconds(90)),
+            status: RunStatus::Finished,
+            output_dir: "./output/raw".to_string(),
+            categories: vec![politics, economy],
+            counts: BTreeMap::new(),
+            articles: 33,
+        };
+        let totals = run.totals();
+        assert_eq!(totals.saved, 33);
+        assert_eq!(totals.failed, 2);
+        assert_eq!(totals.duplicates, 1);
+        assert_eq!(totals.errors["timeout"], 2);
+        assert_eq!(run.wall_time(), Some(Duration::seconds(90)));
+        assert_eq!("failed".parse::<RunStatus>(), Ok(RunStatus::Failed));
+        assert_eq!(
+            "interrupted".parse::<RunStatus>(),
+            Ok(RunStatus::Interrupted)
+        );
+    }
+
+    #[test]
+    fn test_run_info_version() {
+        let mut info = RunInfo::current("index");
+        assert_eq!(info.command, "index");
+        assert_eq!(info.app_version, env!("CARGO_PKG_VERSION"));
+
+        info.git_commit = Some("1a2b3c4d5e6f".to_string());
+        assert_eq!(
+            info.version(),
+            format!("{} (1a2b3c4d5e6f)", info.app_version)
+        );
+        info.git_commit = None;
+        assert_eq!(info.version(), info.app_version);
+    }
+}
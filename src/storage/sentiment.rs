@@ -0,0 +1,357 @@
+//! Daily sentiment aggregates in SQLite
+//!
+//! Stores per-article sentiment scores rolled up by day and by entity,
+//! publisher or category, so sentiment trends can be queried without
+//! rescoring articles:
+//!
+//! - `sentiment_daily`: one row per (day, dimension, key) with article count,
+//!   score sum and positive/negative article counts
+//! - `sentiment_articles`: articles already recorded, so re-running an
+//!   update over the same period does not double count
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::analytics::{SentimentDimension, SentimentScore};
+//! use baram::storage::SentimentStore;
+//! use chrono::NaiveDate;
+//!
+//! # fn example() -> anyhow::Result<()> {
+//! let mut store = SentimentStore::open("data/metadata.db")?;
+//! let day = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+//! store.record_article(
+//!     "001_0015812889",
+//!     day,
+//!     &SentimentScore::from_counts(3, 1),
+//!     &[(SentimentDimension::Category, "economy".to_string())],
+//! )?;
+//!
+//! let series = store.series(SentimentDimension::Category, "economy", day, day)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, Utc};
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::path::Path;
+
+use crate::analytics::{SentimentDimension, SentimentLabel, SentimentScore};
+
+const DAY_FORMAT: &str = "%Y-%m-%d";
+
+/// Sentiment of one key on one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SentimentPoint {
+    /// Day (UTC)
+    pub day: NaiveDate,
+    /// Articles recorded for the key on this day
+    pub articles: u64,
+    /// Average article score in `[-1.0, 1.0]`
+    pub average: f64,
+    /// Articles labeled positive
+    pub positive: u64,
+    /// Articles labeled negative
+    pub negative: u64,
+}
+
+/// Sentiment of one key over a period
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SentimentSummary {
+    /// Entity name, publisher ID or category
+    pub key: String,
+    /// Articles recorded for the key
+    pub articles: u64,
+    /// Average article score in `[-1.0, 1.0]`
+    pub average: f64,
+    /// Articles labeled positive
+    pub positive: u64,
+    /// Articles labeled negative
+    pub negative: u64,
+}
+
+/// SQLite-backed store of daily sentiment aggregates
+pub struct SentimentStore {
+    conn: Connection,
+}
+
+impl SentimentStore {
+    /// Open (or create) the store at the given database path
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Failed to open SQLite database")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        Self::with_connection(conn)
+    }
+
+    /// Create an in-memory store (for tests and dry runs)
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS sentiment_daily (
+                day TEXT NOT NULL,
+                dimension TEXT NOT NULL,
+                key TEXT NOT NULL,
+                articles INTEGER NOT NULL,
+                score_sum REAL NOT NULL,
+                positive INTEGER NOT NULL,
+                negative INTEGER NOT NULL,
+                PRIMARY KEY (day, dimension, key)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_sentiment_daily_key
+                ON sentiment_daily(dimension, key, day);
+
+            CREATE TABLE IF NOT EXISTS sentiment_articles (
+                article_id TEXT PRIMARY KEY,
+                day TEXT NOT NULL,
+                score REAL NOT NULL,
+                recorded_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .context("Failed to create sentiment schema")?;
+
+        Ok(Self { conn })
+    }
+
+    /// Check whether an article has already been recorded
+    pub fn is_recorded(&self, article_id: &str) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM sentiment_articles WHERE article_id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// Add an article's score to the daily aggregates of each key
+    ///
+    /// Returns `false` without changing anything if the article was already
+    /// recorded.
+    pub fn record_article(
+        &mut self,
+        article_id: &str,
+        day: NaiveDate,
+        score: &SentimentScore,
+        keys: &[(SentimentDimension, String)],
+    ) -> Result<bool> {
+        let day = day.format(DAY_FORMAT).to_string();
+        let label = score.label();
+        let positive = i64::from(label == SentimentLabel::Positive);
+        let negative = i64::from(label == SentimentLabel::Negative);
+
+        let tx = self.conn.transaction()?;
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO sentiment_articles (article_id, day, score, recorded_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![article_id, day, score.score, Utc::now().to_rfc3339()],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for (dimension, key) in keys {
+            tx.execute(
+                "INSERT INTO sentiment_daily
+                    (day, dimension, key, articles, score_sum, positive, negative)
+                 VALUES (?1, ?2, ?3, 1, ?4, ?5, ?6)
+                 ON CONFLICT (day, dimension, key) DO UPDATE SET
+                    articles = articles + 1,
+                    score_sum = score_sum + excluded.score_sum,
+                    positive = positive + excluded.positive,
+                    negative = negative + excluded.negative",
+                params![
+                    day,
+                    dimension.as_str(),
+                    key,
+                    score.score,
+                    positive,
+                    negative
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Daily series for one key between `from` and `to` (inclusive)
+    ///
+    /// Only days with recorded articles are returned, oldest first.
+    pub fn series(
+        &self,
+        dimension: SentimentDimension,
+        key: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<SentimentPoint>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT day, articles, score_sum, positive, negative FROM sentiment_daily
+             WHERE dimension = ?1 AND key = ?2 AND day >= ?3 AND day <= ?4
+             ORDER BY day",
+        )?;
+
+        let rows = stmt.query_map(
+            params![
+                dimension.as_str(),
+                key,
+                from.format(DAY_FORMAT).to_string(),
+                to.format(DAY_FORMAT).to_string()
+            ],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, f64>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, i64>(4)?,
+                ))
+            },
+        )?;
+
+        let mut series = Vec::new();
+        for row in rows {
+            let (day, articles, score_sum, positive, negative) = row?;
+            series.push(SentimentPoint {
+                day: NaiveDate::parse_from_str(&day, DAY_FORMAT)
+                    .with_context(|| format!("Invalid day in sentiment store: {day}"))?,
+                articles: articles as u64,
+                average: score_sum / articles.max(1) as f64,
+                positive: positive as u64,
+                negative: negative as u64,
+            });
+        }
+
+        Ok(series)
+    }
+
+    /// Keys with the most articles between `from` and `to` (inclusive)
+    pub fn top_keys(
+        &self,
+        dimension: SentimentDimension,
+        from: NaiveDate,
+        to: NaiveDate,
+        min_articles: u64,
+        limit: usize,
+    ) -> Result<Vec<SentimentSummary>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT key, SUM(articles) AS total, SUM(score_sum), SUM(positive), SUM(negative)
+             FROM sentiment_daily
+             WHERE dimension = ?1 AND day >= ?2 AND day <= ?3
+             GROUP BY key
+             HAVING total >= ?4
+             ORDER BY total DESC, key
+             LIMIT ?5",
+        )?;
+
+        let rows = stmt.query_map(
+            params![
+                dimension.as_str(),
+                from.format(DAY_FORMAT).to_string(),
+                to.format(DAY_FORMAT).to_string(),
+                min_articles as i64,
+                limit as i64
+            ],
+            |row| {
+                let articles: i64 = row.get(1)?;
+                let score_sum: f64 = row.get(2)?;
+                Ok(SentimentSummary {
+                    key: row.get(0)?,
+                    articles: articles as u64,
+                    average: score_sum / articles.max(1) as f64,
+                    positive: row.get::<_, i64>(3)? as u64,
+                    negative: row.get::<_, i64>(4)? as u64,
+                })
+            },
+        )?;
+
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to query sentiment summaries")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 2, d).unwrap()
+    }
+
+    #[test]
+    fn test_record_and_query_series() {
+        let mut store = SentimentStore::open_in_memory().unwrap();
+        let keys = vec![
+            (SentimentDimension::Category, "economy".to_string()),
+            (SentimentDimension::Entity, "삼성전자".to_string()),
+        ];
+
+        assert!(store
+            .record_article("a1", day(1), &SentimentScore::from_counts(3, 0), &keys)
+            .unwrap());
+        assert!(store
+            .record_article("a2", day(1), &SentimentScore::from_counts(0, 1), &keys)
+            .unwrap());
+        assert!(store
+            .record_article("a3", day(2), &SentimentScore::from_counts(1, 1), &keys[..1])
+            .unwrap());
+
+        // Recording the same article again is a no-op
+        assert!(!store
+            .record_article("a1", day(1), &SentimentScore::from_counts(3, 0), &keys)
+            .unwrap());
+        assert!(store.is_recorded("a1").unwrap());
+
+        let series = store
+            .series(SentimentDimension::Category, "economy", day(1), day(7))
+            .unwrap();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].articles, 2);
+        assert!(series[0].average.abs() < f64::EPSILON);
+        assert_eq!((series[0].positive, series[0].negative), (1, 1));
+        assert_eq!(series[1].day, day(2));
+
+        let entity = store
+            .series(SentimentDimension::Entity, "삼성전자", day(2), day(7))
+            .unwrap();
+        assert!(entity.is_empty());
+    }
+
+    #[test]
+    fn test_top_keys() {
+        let mut store = SentimentStore::open_in_memory().unwrap();
+        for (i, publisher) in ["001", "001", "001", "023"].iter().enumerate() {
+            store
+                .record_article(
+                    &format!("a{i}"),
+                    day(3),
+                    &SentimentScore::from_counts(0, 2),
+                    &[(SentimentDimension::Publisher, publisher.to_string())],
+                )
+                .unwrap();
+        }
+
+        let top = store
+            .top_keys(SentimentDimension::Publisher, day(1), day(7), 1, 10)
+            .unwrap();
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].key, "001");
+        assert_eq!(top[0].articles, 3);
+        assert!((top[0].average + 1.0).abs() < f64::EPSILON);
+
+        let filtered = store
+            .top_keys(SentimentDimension::Publisher, day(1), day(7), 2, 10)
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+    }
+}
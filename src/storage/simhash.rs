@@ -0,0 +1,279 @@
+//! SimHash fingerprints for near-duplicate article detection
+//!
+//! The same story is often syndicated by several publishers with small edits
+//! (a different byline, a trailing paragraph), so the SHA-256 content hash
+//! only catches verbatim copies. A 64-bit SimHash over character trigrams
+//! changes in only a few bits for such edits; two articles whose fingerprints
+//! differ in at most `max_distance` bits are treated as near-duplicates.
+//!
+//! ```
+//! use baram::storage::simhash::{hamming_distance, simhash};
+//!
+//! let a = simhash("정부는 오늘 내년도 예산안을 국회에 제출했다. 총지출은 역대 최대 규모다.");
+//! let b = simhash("정부는 오늘 내년도 예산안을 국회에 제출했다. 총지출은 역대 최대 규모다!");
+//! let c = simhash("프로야구 개막전에서 홈팀이 연장 접전 끝에 승리를 거뒀다.");
+//! assert!(hamming_distance(a, b) < hamming_distance(a, c));
+//! ```
+
+use std::collections::HashMap;
+
+/// Default maximum Hamming distance between near-duplicates
+pub const DEFAULT_MAX_DISTANCE: u32 = 3;
+
+/// Largest supported `max_distance`; each allowed bit needs its own band
+pub const MAX_DISTANCE_LIMIT: u32 = 15;
+
+/// Characters per shingle
+const SHINGLE_LEN: usize = 3;
+
+/// 64-bit SimHash of `text` over character trigrams
+///
+/// Case and whitespace runs are normalized first. Returns 0 for text without
+/// any non-whitespace characters.
+#[must_use]
+pub fn simhash(text: &str) -> u64 {
+    let chars: Vec<char> = text
+        .split_whitespace()
+        .flat_map(|word| word.chars().flat_map(char::to_lowercase).chain([' ']))
+        .collect();
+    let chars = chars.strip_suffix(&[' ']).unwrap_or(&chars);
+    if chars.is_empty() {
+        return 0;
+    }
+
+    let mut weights = [0i64; 64];
+    let mut add = |shingle: &[char]| {
+        let hash = fnv1a(shingle);
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if hash >> bit & 1 == 1 {
+                *weight += 1;
+            } else {
+                *weight -= 1;
+            }
+        }
+    };
+    if chars.len() < SHINGLE_LEN {
+        add(chars);
+    } else {
+        chars.windows(SHINGLE_LEN).for_each(&mut add);
+    }
+
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| **weight > 0)
+        .fold(0, |hash, (bit, _)| hash | 1 << bit)
+}
+
+/// FNV-1a, so fingerprints stay stable across builds and can be stored
+fn fnv1a(chars: &[char]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for c in chars {
+        let mut buf = [0u8; 4];
+        for byte in c.encode_utf8(&mut buf).bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Number of differing bits
+#[must_use]
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// What an article is compared on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// SHA-256 content hash; empty if unknown
+    pub content_hash: String,
+    /// SimHash of the article text; `None` excludes it from near-duplicate
+    /// matching (e.g. an empty body)
+    pub simhash: Option<u64>,
+}
+
+/// Group fingerprints that share a content hash or whose SimHashes are within
+/// `max_distance` bits
+///
+/// Grouping is transitive: if A is near B and B is near C, all three form one
+/// group. Returns the indices of each group with at least two members, in
+/// ascending order, with groups ordered by their first index.
+///
+/// Candidate pairs come from splitting fingerprints into `max_distance + 1`
+/// bands: fingerprints within `max_distance` bits agree exactly on at least
+/// one band, so only fingerprints sharing a band are compared.
+/// `max_distance` is capped at [`MAX_DISTANCE_LIMIT`].
+#[must_use]
+pub fn duplicate_groups(fingerprints: &[Fingerprint], max_distance: u32) -> Vec<Vec<usize>> {
+    let mut sets = DisjointSets::new(fingerprints.len());
+
+    let mut by_hash: HashMap<&str, usize> = HashMap::new();
+    for (i, fingerprint) in fingerprints.iter().enumerate() {
+        if fingerprint.content_hash.is_empty() {
+            continue;
+        }
+        match by_hash.get(fingerprint.content_hash.as_str()) {
+            Some(&first) => sets.union(first, i),
+            None => {
+                by_hash.insert(&fingerprint.content_hash, i);
+            }
+        }
+    }
+
+    let max_distance = max_distance.min(MAX_DISTANCE_LIMIT);
+    let bands = max_distance as usize + 1;
+    let width = 64 / bands;
+    let mut buckets: HashMap<(usize, u64), Vec<usize>> = HashMap::new();
+    for (i, fingerprint) in fingerprints.iter().enumerate() {
+        let Some(hash) = fingerprint.simhash else {
+            continue;
+        };
+        for band in 0..bands {
+            // The last band takes the bits left over by the integer division
+            let bits = if band == bands - 1 {
+                64 - band * width
+            } else {
+                width
+            };
+            let mask = if bits == 64 {
+                u64::MAX
+            } else {
+                (1 << bits) - 1
+            };
+            let key = hash >> (band * width) & mask;
+            buckets.entry((band, key)).or_default().push(i);
+        }
+    }
+    for members in buckets.values() {
+        for (n, &a) in members.iter().enumerate() {
+            for &b in &members[n + 1..] {
+                if sets.find(a) == sets.find(b) {
+                    continue;
+                }
+                if let (Some(x), Some(y)) = (fingerprints[a].simhash, fingerprints[b].simhash) {
+                    if hamming_distance(x, y) <= max_distance {
+                        sets.union(a, b);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..fingerprints.len() {
+        groups.entry(sets.find(i)).or_default().push(i);
+    }
+    let mut groups: Vec<Vec<usize>> = groups.into_values().filter(|g| g.len() > 1).collect();
+    groups.sort_by_key(|g| g[0]);
+    groups
+}
+
+/// Union-find over indices
+struct DisjointSets {
+    parent: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut i = i;
+        while self.parent[i] != root {
+            let next = self.parent[i];
+            self.parent[i] = root;
+            i = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            // Keep the smaller index as root so groups are found in order
+            self.parent[a.max(b)] = a.min(b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str =
+        "한국은행 금융통화위원회는 18일 통화정책방향 회의를 열고 기준금리를 연 3.25%로 동결했다. \
+        물가 상승세가 둔화하고 있지만 가계부채 증가세와 환율 변동성을 고려한 결정이다. \
+        총재는 기자간담회에서 수도권 주택 가격 상승이 금융안정에 부담이 되고 있다고 말했다. \
+        금통위원 6명 가운데 1명은 0.25%포인트 인하 의견을 냈다. \
+        시장에서는 연내 추가 인하 가능성에 주목하고 있다. \
+        증권가는 다음 회의가 열리는 11월을 인하 시점으로 보고 있다.";
+
+    fn fingerprint(hash: &str, text: &str) -> Fingerprint {
+        Fingerprint {
+            content_hash: hash.to_string(),
+            simhash: Some(simhash(text)),
+        }
+    }
+
+    #[test]
+    fn test_simhash() {
+        assert_eq!(simhash(""), 0);
+        assert_eq!(simhash("  \n "), 0);
+        assert_eq!(simhash("Hello   World"), simhash("hello world"));
+        assert_ne!(simhash("ab"), 0);
+
+        let edited = format!("{ARTICLE} 홍길동 기자");
+        let other =
+            "국내 연구진이 차세대 배터리 소재를 개발했다. 충전 속도가 기존 대비 두 배 빨라졌다.";
+        assert!(hamming_distance(simhash(ARTICLE), simhash(&edited)) <= 8);
+        assert!(hamming_distance(simhash(ARTICLE), simhash(other)) > 16);
+    }
+
+    #[test]
+    fn test_duplicate_groups() {
+        let fingerprints = vec![
+            fingerprint("a", ARTICLE),
+            fingerprint("b", "국내 연구진이 차세대 배터리 소재를 개발했다."),
+            // Same content hash, different text (e.g. re-rendered markdown)
+            fingerprint("a", "완전히 다른 본문"),
+            // Same text, different hash
+            fingerprint("c", ARTICLE),
+            Fingerprint {
+                content_hash: String::new(),
+                simhash: None,
+            },
+            Fingerprint {
+                content_hash: String::new(),
+                simhash: None,
+            },
+        ];
+
+        let groups = duplicate_groups(&fingerprints, DEFAULT_MAX_DISTANCE);
+        assert_eq!(groups, vec![vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn test_duplicate_groups_respects_distance() {
+        let fingerprints: Vec<Fingerprint> = [0u64, 0b111, 0b1111_1111]
+            .into_iter()
+            .map(|hash| Fingerprint {
+                content_hash: String::new(),
+                simhash: Some(hash),
+            })
+            .collect();
+
+        assert!(duplicate_groups(&fingerprints, 0).is_empty());
+        assert_eq!(duplicate_groups(&fingerprints, 3), vec![vec![0, 1]]);
+        // 0b111 is within 5 bits of both, so all three are grouped
+        assert_eq!(duplicate_groups(&fingerprints, 5), vec![vec![0, 1, 2]]);
+    }
+}
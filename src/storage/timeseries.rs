@@ -0,0 +1,783 @@
+//! Hourly time-series aggregates for analytics
+//!
+//! Article counts and keyword mentions are rolled up into a compact
+//! `(metric, dimensions, hour bucket, value)` table as articles are crawled,
+//! so trend queries read a few aggregate rows instead of re-tokenizing every
+//! article. Dimensions are stored as a canonical JSON object (sorted keys).
+//!
+//! The same layout is used in SQLite ([`TimeSeriesStore`]) and PostgreSQL
+//! ([`PgTimeSeriesStore`], maintained by
+//! [`Database::store_article`](super::Database::store_article)).
+//!
+//! | Metric | Dimensions | Value |
+//! |---|---|---|
+//! | `articles` | `category`, `publisher` | Articles published |
+//! | `keyword` | `keyword` | Articles mentioning the keyword (title or body) |
+//! | `title_keyword` | `keyword` | Articles mentioning the keyword in the title |
+//! | `entity` | `entity`, `type` | Articles mentioning the named entity |
+//!
+//! Comment reactions ([`ArticleReaction`]) are kept per article in a separate
+//! table and replaced whenever the article is stored again, since comments
+//! keep arriving after publication.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use baram::storage::{article_samples, TimeSeriesStore, METRIC_KEYWORD};
+//! use chrono::{Duration, Utc};
+//!
+//! # fn example() -> anyhow::Result<()> {
+//! let mut store = TimeSeriesStore::open("output/crawl.db")?;
+//! let now = Utc::now();
+//! let samples = article_samples(now, "economy", Some("001"), "반도체 수출 호조", "");
+//! store.record_article("001_0015812889", &samples)?;
+//!
+//! let points = store.query(METRIC_KEYWORD, &[("keyword", "반도체")], now - Duration::days(1), now)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use deadpool_postgres::Pool;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use crate::analytics::{ArticleReaction, EntityType, TextTokenizer};
+use crate::ontology::{EntitySource, RelationExtractor};
+
+/// Articles published, by category and publisher
+pub const METRIC_ARTICLES: &str = "articles";
+
+/// Articles mentioning a keyword in the title or body
+pub const METRIC_KEYWORD: &str = "keyword";
+
+/// Articles mentioning a keyword in the title
+pub const METRIC_TITLE_KEYWORD: &str = "title_keyword";
+
+/// Articles mentioning a named entity
+pub const METRIC_ENTITY: &str = "entity";
+
+/// Schema shared by SQLite and PostgreSQL
+pub(crate) const TIMESERIES_SCHEMA: &str = r#"
+    CREATE TABLE IF NOT EXISTS analytics_timeseries (
+        metric TEXT NOT NULL,
+        dimensions TEXT NOT NULL,
+        bucket TEXT NOT NULL,
+        value DOUBLE PRECISION NOT NULL,
+        PRIMARY KEY (metric, bucket, dimensions)
+    );
+
+    CREATE TABLE IF NOT EXISTS analytics_timeseries_articles (
+        article_id TEXT PRIMARY KEY,
+        recorded_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS analytics_comment_reactions (
+        article_id TEXT PRIMARY KEY,
+        published_at TEXT NOT NULL,
+        reaction TEXT NOT NULL
+    );
+"#;
+
+/// Upsert adding to an existing bucket (SQLite and PostgreSQL syntax)
+pub(crate) const UPSERT_SAMPLE: &str =
+    "INSERT INTO analytics_timeseries (metric, dimensions, bucket, value)
+     VALUES ($1, $2, $3, $4)
+     ON CONFLICT (metric, bucket, dimensions) DO UPDATE SET
+        value = analytics_timeseries.value + excluded.value";
+
+/// Insert or replace an article's comment reaction (SQLite and PostgreSQL syntax)
+pub(crate) const UPSERT_REACTION: &str =
+    "INSERT INTO analytics_comment_reactions (article_id, published_at, reaction)
+     VALUES ($1, $2, $3)
+     ON CONFLICT (article_id) DO UPDATE SET
+        published_at = excluded.published_at,
+        reaction = excluded.reaction";
+
+/// Comment reactions of articles published in `[$1, $2)`
+const SELECT_REACTIONS: &str = "SELECT reaction FROM analytics_comment_reactions
+     WHERE published_at >= $1 AND published_at < $2
+     ORDER BY published_at, article_id";
+
+/// One increment to an hourly bucket
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Sample {
+    /// Metric name
+    pub metric: String,
+    /// Dimension values, e.g. `category = economy`
+    pub dimensions: BTreeMap<String, String>,
+    /// Start of the hour
+    pub bucket: DateTime<Utc>,
+    /// Amount added to the bucket
+    pub value: f64,
+}
+
+impl Sample {
+    /// Create a sample of 1.0 in the hour containing `timestamp`
+    #[must_use]
+    pub fn new(metric: impl Into<String>, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            metric: metric.into(),
+            dimensions: BTreeMap::new(),
+            bucket: hour_bucket(timestamp),
+            value: 1.0,
+        }
+    }
+
+    /// Add a dimension
+    #[must_use]
+    pub fn with_dimension(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.dimensions.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the value
+    #[must_use]
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub(crate) fn dimensions_key(&self) -> String {
+        encode_dimensions(&self.dimensions)
+    }
+
+    pub(crate) fn bucket_key(&self) -> String {
+        format_bucket(self.bucket)
+    }
+}
+
+/// Aggregated value of one bucket
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimeSeriesPoint {
+    /// Dimension values
+    pub dimensions: BTreeMap<String, String>,
+    /// Start of the hour
+    pub bucket: DateTime<Utc>,
+    /// Aggregated value
+    pub value: f64,
+}
+
+impl TimeSeriesPoint {
+    /// Get a dimension value
+    #[must_use]
+    pub fn dimension(&self, key: &str) -> Option<&str> {
+        self.dimensions.get(key).map(String::as_str)
+    }
+
+    pub(crate) fn from_row(dimensions: &str, bucket: &str, value: f64) -> Result<Self> {
+        Ok(Self {
+            dimensions: serde_json::from_str(dimensions)
+                .with_context(|| format!("Invalid time-series dimensions: {dimensions}"))?,
+            bucket: DateTime::parse_from_rfc3339(bucket)
+                .with_context(|| format!("Invalid time-series bucket: {bucket}"))?
+                .with_timezone(&Utc),
+            value,
+        })
+    }
+
+    /// Whether every `(key, value)` filter matches this point's dimensions
+    pub(crate) fn matches(&self, filter: &[(&str, &str)]) -> bool {
+        filter
+            .iter()
+            .all(|(key, value)| self.dimension(key) == Some(*value))
+    }
+}
+
+/// Samples recorded for one article
+///
+/// Keywords count once per article, matching how trend commands count them.
+#[must_use]
+pub fn article_samples(
+    published_at: DateTime<Utc>,
+    category: &str,
+    publisher: Option<&str>,
+    title: &str,
+    body: &str,
+) -> Vec<Sample> {
+    let tokenizer = TextTokenizer::new();
+
+    let mut article =
+        Sample::new(METRIC_ARTICLES, published_at).with_dimension("category", category);
+    if let Some(publisher) = publisher {
+        article = article.with_dimension("publisher", publisher);
+    }
+    let mut samples = vec![article];
+
+    let title_keywords: HashSet<String> = tokenizer.tokenize(title).into_iter().collect();
+    let keywords: HashSet<String> = tokenizer
+        .tokenize(body)
+        .into_iter()
+        .chain(title_keywords.iter().cloned())
+        .collect();
+
+    for (metric, keywords) in [
+        (METRIC_TITLE_KEYWORD, title_keywords),
+        (METRIC_KEYWORD, keywords),
+    ] {
+        let mut keywords: Vec<String> = keywords.into_iter().collect();
+        keywords.sort();
+        samples.extend(
+            keywords.into_iter().map(|keyword| {
+                Sample::new(metric, published_at).with_dimension("keyword", keyword)
+            }),
+        );
+    }
+
+    let entities: BTreeMap<String, EntityType> = RelationExtractor::new()
+        .extract_entities(&format!("{title} {body}"), EntitySource::Both)
+        .into_iter()
+        .filter_map(|e| {
+            let entity_type = EntityType::from_ontology(e.entity_type)?;
+            Some((e.canonical_name.unwrap_or(e.text), entity_type))
+        })
+        .collect();
+    samples.extend(entities.into_iter().map(|(entity, entity_type)| {
+        Sample::new(METRIC_ENTITY, published_at)
+            .with_dimension("entity", entity)
+            .with_dimension("type", entity_type.as_str())
+    }));
+
+    samples
+}
+
+/// Change of one dimension value between two windows
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DimensionDelta {
+    /// Dimension value, e.g. the keyword
+    pub name: String,
+    /// All dimensions of the series
+    pub dimensions: BTreeMap<String, String>,
+    /// Total in the current window
+    pub count: f64,
+    /// Total in the previous window
+    pub previous: f64,
+}
+
+impl DimensionDelta {
+    /// Absolute change from the previous window
+    #[must_use]
+    pub fn delta(&self) -> f64 {
+        self.count - self.previous
+    }
+
+    /// Relative change from the previous window (`None` if it was zero)
+    #[must_use]
+    pub fn change(&self) -> Option<f64> {
+        (self.previous > 0.0).then(|| self.delta() / self.previous)
+    }
+}
+
+/// Top values of `dimension` in the current window, with previous-window totals
+///
+/// Series are grouped by all their dimensions (so an entity name with two
+/// types stays two entries) and ranked by current total, or by absolute
+/// change if `by_delta` is set. Only series present in the current window
+/// are returned.
+#[must_use]
+pub fn top_deltas(
+    current: &[TimeSeriesPoint],
+    previous: &[TimeSeriesPoint],
+    dimension: &str,
+    limit: usize,
+    by_delta: bool,
+) -> Vec<DimensionDelta> {
+    let mut previous_totals: HashMap<&BTreeMap<String, String>, f64> = HashMap::new();
+    for point in previous {
+        *previous_totals.entry(&point.dimensions).or_insert(0.0) += point.value;
+    }
+
+    let mut totals: HashMap<&BTreeMap<String, String>, f64> = HashMap::new();
+    for point in current {
+        *totals.entry(&point.dimensions).or_insert(0.0) += point.value;
+    }
+
+    let mut deltas: Vec<DimensionDelta> = totals
+        .into_iter()
+        .filter_map(|(dimensions, count)| {
+            Some(DimensionDelta {
+                name: dimensions.get(dimension)?.clone(),
+                dimensions: dimensions.clone(),
+                count,
+                previous: previous_totals.get(dimensions).copied().unwrap_or(0.0),
+            })
+        })
+        .collect();
+
+    deltas.sort_by(|a, b| {
+        let (a_key, b_key) = if by_delta {
+            (a.delta(), b.delta())
+        } else {
+            (a.count, b.count)
+        };
+        b_key
+            .total_cmp(&a_key)
+            .then_with(|| b.count.total_cmp(&a.count))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    deltas.truncate(limit);
+    deltas
+}
+
+/// Start of the UTC hour containing `timestamp`
+#[must_use]
+pub fn hour_bucket(timestamp: DateTime<Utc>) -> DateTime<Utc> {
+    timestamp
+        .duration_trunc(TimeDelta::hours(1))
+        .unwrap_or(timestamp)
+}
+
+pub(crate) fn format_bucket(bucket: DateTime<Utc>) -> String {
+    bucket.format("%Y-%m-%dT%H:00:00Z").to_string()
+}
+
+/// Sortable timestamp key for reaction rows
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn encode_reaction(reaction: &ArticleReaction) -> Result<String> {
+    serde_json::to_string(reaction).context("Failed to serialize comment reaction")
+}
+
+fn decode_reaction(json: &str) -> Result<ArticleReaction> {
+    serde_json::from_str(json).context("Invalid comment reaction")
+}
+
+fn encode_dimensions(dimensions: &BTreeMap<String, String>) -> String {
+    serde_json::to_string(dimensions).expect("string map serializes")
+}
+
+/// SQLite-backed time-series store
+pub struct TimeSeriesStore {
+    conn: Connection,
+}
+
+impl TimeSeriesStore {
+    /// Open (or create) the store at the given database path
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(path).context("Failed to open SQLite database")?;
+        conn.execute_batch("PRAGMA journal_mode=WAL;")?;
+        Self::with_connection(conn)
+    }
+
+    /// Create an in-memory store (for tests and dry runs)
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(TIMESERIES_SCHEMA)
+            .context("Failed to create time-series schema")?;
+        Ok(Self { conn })
+    }
+
+    /// Add samples to their buckets
+    pub fn add(&mut self, samples: &[Sample]) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        insert_samples(&tx, samples)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Add an article's samples once
+    ///
+    /// Returns `false` without changing anything if the article was already
+    /// recorded, so re-crawls do not double count.
+    pub fn record_article(&mut self, article_id: &str, samples: &[Sample]) -> Result<bool> {
+        let tx = self.conn.transaction()?;
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO analytics_timeseries_articles (article_id, recorded_at)
+             VALUES (?1, ?2)",
+            params![article_id, Utc::now().to_rfc3339()],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        insert_samples(&tx, samples)?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Buckets of a metric in `[from, to)` matching all dimension filters, oldest first
+    pub fn query(
+        &self,
+        metric: &str,
+        filter: &[(&str, &str)],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeSeriesPoint>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT dimensions, bucket, value FROM analytics_timeseries
+             WHERE metric = ?1 AND bucket >= ?2 AND bucket < ?3
+             ORDER BY bucket, dimensions",
+        )?;
+
+        let rows = stmt.query_map(
+            params![metric, format_bucket(hour_bucket(from)), format_bucket(to)],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, f64>(2)?,
+                ))
+            },
+        )?;
+
+        let mut points = Vec::new();
+        for row in rows {
+            let (dimensions, bucket, value) = row?;
+            let point = TimeSeriesPoint::from_row(&dimensions, &bucket, value)?;
+            if point.matches(filter) {
+                points.push(point);
+            }
+        }
+        Ok(points)
+    }
+
+    /// Earliest bucket recorded for a metric
+    pub fn first_bucket(&self, metric: &str) -> Result<Option<DateTime<Utc>>> {
+        let bucket: Option<String> = self.conn.query_row(
+            "SELECT MIN(bucket) FROM analytics_timeseries WHERE metric = ?1",
+            params![metric],
+            |row| row.get(0),
+        )?;
+        bucket
+            .map(|b| {
+                DateTime::parse_from_rfc3339(&b)
+                    .map(|t| t.with_timezone(&Utc))
+                    .with_context(|| format!("Invalid time-series bucket: {b}"))
+            })
+            .transpose()
+    }
+}
+
+/// PostgreSQL-backed time-series store
+///
+/// Cheap to clone; the schema is created with the articles schema by
+/// [`Database::store_article`](super::Database::store_article).
+#[derive(Clone)]
+pub struct PgTimeSeriesStore {
+    pool: Pool,
+}
+
+impl PgTimeSeriesStore {
+    /// Create a store on an existing connection pool
+    #[must_use]
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Add an article's samples once
+    ///
+    /// Returns `false` if the article was already recorded.
+    pub async fn record_article(&self, article_id: &str, samples: &[Sample]) -> Result<bool> {
+        let mut client = self.pool.get().await.context("Failed to get connection")?;
+        let tx = client
+            .transaction()
+            .await
+            .context("Failed to start transaction")?;
+
+        let inserted = tx
+            .execute(
+                "INSERT INTO analytics_timeseries_articles (article_id, recorded_at)
+                 VALUES ($1, $2)
+                 ON CONFLICT (article_id) DO NOTHING",
+                &[&article_id, &Utc::now().to_rfc3339()],
+            )
+            .await
+            .context("Failed to record time-series article")?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        let stmt = tx.prepare(UPSERT_SAMPLE).await?;
+        for sample in samples {
+            tx.execute(
+                &stmt,
+                &[
+                    &sample.metric,
+                    &sample.dimensions_key(),
+                    &sample.bucket_key(),
+                    &sample.value,
+                ],
+            )
+            .await
+            .context("Failed to update time series")?;
+        }
+
+        tx.commit().await.context("Failed to commit time series")?;
+        Ok(true)
+    }
+
+    /// Buckets of a metric in `[from, to)`, oldest first
+    ///
+    /// Only buckets whose dimensions match every `(key, value)` filter are returned.
+    pub async fn query(
+        &self,
+        metric: &str,
+        filter: &[(&str, &str)],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TimeSeriesPoint>> {
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        let rows = client
+            .query(
+                "SELECT dimensions, bucket, value FROM analytics_timeseries
+                 WHERE metric = $1 AND bucket >= $2 AND bucket < $3
+                 ORDER BY bucket, dimensions",
+                &[
+                    &metric,
+                    &format_bucket(hour_bucket(from)),
+                    &format_bucket(to),
+                ],
+            )
+            .await
+            .context("Failed to query time series")?;
+
+        let mut points = Vec::new();
+        for row in rows {
+            let point = TimeSeriesPoint::from_row(row.get(0), row.get(1), row.get(2))?;
+            if point.matches(filter) {
+                points.push(point);
+            }
+        }
+        Ok(points)
+    }
+
+    /// Earliest bucket recorded for a metric
+    pub async fn first_bucket(&self, metric: &str) -> Result<Option<DateTime<Utc>>> {
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        let row = client
+            .query_one(
+                "SELECT MIN(bucket) FROM analytics_timeseries WHERE metric = $1",
+                &[&metric],
+            )
+            .await
+            .context("Failed to query time series")?;
+
+        row.get::<_, Option<String>>(0)
+            .map(|b| {
+                DateTime::parse_from_rfc3339(&b)
+                    .map(|t| t.with_timezone(&Utc))
+                    .with_context(|| format!("Invalid time-series bucket: {b}"))
+            })
+            .transpose()
+    }
+
+    /// When the most recent article was recorded
+    pub async fn last_recorded_at(&self) -> Result<Option<DateTime<Utc>>> {
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        let row = client
+            .query_one(
+                "SELECT MAX(recorded_at) FROM analytics_timeseries_articles",
+                &[],
+            )
+            .await
+            .context("Failed to query time series")?;
+
+        row.get::<_, Option<String>>(0)
+            .map(|t| {
+                DateTime::parse_from_rfc3339(&t)
+                    .map(|t| t.with_timezone(&Utc))
+                    .with_context(|| format!("Invalid time-series record time: {t}"))
+            })
+            .transpose()
+    }
+
+    /// Store an article's comment reaction, replacing an earlier one
+    pub async fn record_reaction(&self, reaction: &ArticleReaction) -> Result<()> {
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        client
+            .execute(
+                UPSERT_REACTION,
+                &[
+                    &reaction.article_id,
+                    &format_timestamp(reaction.published_at),
+                    &encode_reaction(reaction)?,
+                ],
+            )
+            .await
+            .context("Failed to store comment reaction")?;
+        Ok(())
+    }
+
+    /// Comment reactions of articles published in `[from, to)`, oldest first
+    pub async fn reactions(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ArticleReaction>> {
+        let client = self.pool.get().await.context("Failed to get connection")?;
+        let rows = client
+            .query(
+                SELECT_REACTIONS,
+                &[&format_timestamp(from), &format_timestamp(to)],
+            )
+            .await
+            .context("Failed to query comment reactions")?;
+
+        rows.iter().map(|row| decode_reaction(row.get(0))).collect()
+    }
+}
+
+fn insert_samples(conn: &Connection, samples: &[Sample]) -> Result<()> {
+    let mut stmt = conn.prepare_cached(UPSERT_SAMPLE)?;
+    for sample in samples {
+        stmt.execute(params![
+            sample.metric,
+            sample.dimensions_key(),
+            sample.bucket_key(),
+            sample.value
+        ])?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 15, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_article_samples() {
+        let samples = article_samples(
+            at(9, 41),
+            "economy",
+            Some("001"),
+            "반도체 수출",
+            "반도체 투자",
+        );
+
+        let article = &samples[0];
+        assert_eq!(article.metric, METRIC_ARTICLES);
+        assert_eq!(article.bucket, at(9, 0));
+        assert_eq!(article.dimensions["publisher"], "001");
+
+        let keywords: Vec<&str> = samples
+            .iter()
+            .filter(|s| s.metric == METRIC_KEYWORD)
+            .map(|s| s.dimensions["keyword"].as_str())
+            .collect();
+        // 반도체 appears in title and body but counts once
+        assert_eq!(keywords, vec!["반도체", "수출", "투자"]);
+        assert_eq!(
+            samples
+                .iter()
+                .filter(|s| s.metric == METRIC_TITLE_KEYWORD)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_article_entity_samples() {
+        let samples = article_samples(at(9, 0), "economy", None, "삼성전자 실적 발표", "");
+        let entity = samples
+            .iter()
+            .find(|s| s.metric == METRIC_ENTITY)
+            .expect("entity sample");
+        assert_eq!(entity.dimensions["entity"], "삼성전자");
+        assert_eq!(entity.dimensions["type"], "organization");
+    }
+
+    #[test]
+    fn test_top_deltas() {
+        let point = |keyword: &str, hour: u32, value: f64| TimeSeriesPoint {
+            dimensions: [("keyword".to_string(), keyword.to_string())].into(),
+            bucket: at(hour, 0),
+            value,
+        };
+        let previous = vec![point("반도체", 1, 5.0), point("부동산", 2, 1.0)];
+        let current = vec![
+            point("반도체", 10, 3.0),
+            point("반도체", 11, 3.0),
+            point("부동산", 12, 4.0),
+            point("선거", 12, 2.0),
+        ];
+
+        let top = top_deltas(&current, &previous, "keyword", 10, false);
+        assert_eq!(top[0].name, "반도체");
+        assert!((top[0].delta() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(top[1].change(), Some(3.0));
+        assert_eq!(top[2].change(), None);
+
+        let rising = top_deltas(&current, &previous, "keyword", 1, true);
+        assert_eq!(rising.len(), 1);
+        assert_eq!(rising[0].name, "부동산");
+    }
+
+    #[test]
+    fn test_record_and_query() {
+        let mut store = TimeSeriesStore::open_in_memory().unwrap();
+        let samples = article_samples(at(9, 10), "economy", None, "반도체 수출", "");
+
+        assert!(store.record_article("a1", &samples).unwrap());
+        assert!(!store.record_article("a1", &samples).unwrap());
+        store
+            .record_article(
+                "a2",
+                &article_samples(at(9, 50), "politics", None, "반도체 규제", ""),
+            )
+            .unwrap();
+        store
+            .add(&[Sample::new(METRIC_ARTICLES, at(11, 0))
+                .with_dimension("category", "economy")
+                .with_value(3.0)])
+            .unwrap();
+
+        let semis = store
+            .query(
+                METRIC_KEYWORD,
+                &[("keyword", "반도체")],
+                at(0, 0),
+                at(23, 0),
+            )
+            .unwrap();
+        assert_eq!(semis.len(), 1);
+        assert_eq!(semis[0].bucket, at(9, 0));
+        assert!((semis[0].value - 2.0).abs() < f64::EPSILON);
+
+        let economy = store
+            .query(
+                METRIC_ARTICLES,
+                &[("category", "economy")],
+                at(0, 0),
+                at(23, 0),
+            )
+            .unwrap();
+        let values: Vec<f64> = economy.iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![1.0, 3.0]);
+
+        // `to` is exclusive
+        let early = store
+            .query(METRIC_ARTICLES, &[], at(0, 0), at(11, 0))
+            .unwrap();
+        assert_eq!(early.len(), 2);
+
+        assert_eq!(store.first_bucket(METRIC_ARTICLES).unwrap(), Some(at(9, 0)));
+        assert_eq!(store.first_bucket("missing").unwrap(), None);
+        assert!(store
+            .query(
+                METRIC_KEYWORD,
+                &[],
+                at(0, 0) + Duration::days(1),
+                at(23, 0) + Duration::days(1)
+            )
+            .unwrap()
+            .is_empty());
+    }
+}
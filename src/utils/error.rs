@@ -34,6 +34,18 @@ pub enum FetchError {
     /// Invalid URL
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
+
+    /// URL disallowed by the host's robots.txt
+    #[error("Disallowed by robots.txt: {0}")]
+    Disallowed(String),
+
+    /// Request refused because the host's circuit breaker is open
+    #[error("Circuit open for host: {0}")]
+    CircuitOpen(String),
+
+    /// Every proxy of the pool is out of rotation
+    #[error("No live proxy")]
+    ProxyUnavailable,
 }
 
 /// Errors that can occur during parsing operations
@@ -88,12 +100,124 @@ pub enum CrawlerError {
     RateLimited,
 }
 
+/// Coarse class of a crawl failure, used as a metrics label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// Request timed out
+    Timeout,
+    /// Rate limited or refused by the server (HTTP 403/429)
+    Blocked,
+    /// Article missing or deleted (HTTP 404/410)
+    NotFound,
+    /// Server-side failure (HTTP 5xx)
+    ServerError,
+    /// Connection or other transport failure
+    Network,
+    /// Fetched page could not be decoded or parsed
+    ParseFailure,
+    /// Saving the article failed
+    Storage,
+    /// Anything else
+    Other,
+}
+
+impl ErrorClass {
+    /// Label value (`timeout`, `blocked`, `parse_failure`, ...)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Timeout => "timeout",
+            Self::Blocked => "blocked",
+            Self::NotFound => "not_found",
+            Self::ServerError => "server_error",
+            Self::Network => "network",
+            Self::ParseFailure => "parse_failure",
+            Self::Storage => "storage",
+            Self::Other => "other",
+        }
+    }
+
+    /// Class of a label value, the inverse of [`as_str`](Self::as_str)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "timeout" => Some(Self::Timeout),
+            "blocked" => Some(Self::Blocked),
+            "not_found" => Some(Self::NotFound),
+            "server_error" => Some(Self::ServerError),
+            "network" => Some(Self::Network),
+            "parse_failure" => Some(Self::ParseFailure),
+            "storage" => Some(Self::Storage),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+
+    /// Class of a non-success HTTP status code
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            403 | 429 => Self::Blocked,
+            404 | 410 => Self::NotFound,
+            500..=599 => Self::ServerError,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classify an error by the first typed error in its chain
+    pub fn of(error: &anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if let Some(e) = cause.downcast_ref::<CrawlerError>() {
+                return e.class();
+            }
+            if let Some(e) = cause.downcast_ref::<FetchError>() {
+                return e.class();
+            }
+            if let Some(e) = cause.downcast_ref::<ParseError>() {
+                return e.class();
+            }
+            if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+                return reqwest_class(e);
+            }
+        }
+        Self::Other
+    }
+}
+
+fn reqwest_class(error: &reqwest::Error) -> ErrorClass {
+    if error.is_timeout() {
+        ErrorClass::Timeout
+    } else if let Some(status) = error.status() {
+        ErrorClass::from_status(status.as_u16())
+    } else if error.is_decode() || error.is_body() {
+        ErrorClass::ParseFailure
+    } else {
+        ErrorClass::Network
+    }
+}
+
 impl FetchError {
+    /// Get the error class for metrics
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            FetchError::Http(e) => reqwest_class(e),
+            FetchError::RateLimit | FetchError::Disallowed(_) => ErrorClass::Blocked,
+            FetchError::ProxyUnavailable => ErrorClass::Network,
+            FetchError::ServerError(status) => ErrorClass::from_status(*status),
+            FetchError::Timeout => ErrorClass::Timeout,
+            FetchError::Decode(_) => ErrorClass::ParseFailure,
+            FetchError::MaxRetriesExceeded
+            | FetchError::InvalidUrl(_)
+            | FetchError::CircuitOpen(_) => ErrorClass::Other,
+        }
+    }
+
     /// Check if this error is recoverable (can be retried)
     pub fn is_recoverable(&self) -> bool {
         matches!(
             self,
-            FetchError::RateLimit | FetchError::Timeout | FetchError::ServerError(_)
+            FetchError::RateLimit
+                | FetchError::Timeout
+                | FetchError::ServerError(_)
+                | FetchError::CircuitOpen(_)
+                | FetchError::ProxyUnavailable
         )
     }
 
@@ -109,6 +233,13 @@ impl FetchError {
             }
             FetchError::Decode(_) => crate::i18n::t!("errors.fetch.decode_error").to_string(),
             FetchError::InvalidUrl(_) => crate::i18n::t!("errors.fetch.invalid_url").to_string(),
+            FetchError::Disallowed(_) => {
+                crate::i18n::t!("errors.fetch.robots_disallowed").to_string()
+            }
+            FetchError::CircuitOpen(_) => crate::i18n::t!("errors.fetch.circuit_open").to_string(),
+            FetchError::ProxyUnavailable => {
+                crate::i18n::t!("errors.fetch.proxy_unavailable").to_string()
+            }
         }
     }
 
@@ -123,11 +254,25 @@ impl FetchError {
             FetchError::MaxRetriesExceeded => "최대 재시도 횟수 초과",
             FetchError::Decode(_) => "디코딩 오류",
             FetchError::InvalidUrl(_) => "잘못된 URL",
+            FetchError::Disallowed(_) => "robots.txt로 금지된 URL",
+            FetchError::CircuitOpen(_) => "호스트 차단기 열림",
+            FetchError::ProxyUnavailable => "사용 가능한 프록시 없음",
         }
     }
 }
 
 impl ParseError {
+    /// Get the error class for metrics
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            ParseError::ArticleNotFound => ErrorClass::NotFound,
+            ParseError::InvalidUrl(_) | ParseError::IdExtractionFailed => ErrorClass::Other,
+            ParseError::TitleNotFound | ParseError::ContentNotFound | ParseError::UnknownFormat => {
+                ErrorClass::ParseFailure
+            }
+        }
+    }
+
     /// Check if this error is recoverable
     pub fn is_recoverable(&self) -> bool {
         matches!(self, ParseError::ArticleNotFound)
@@ -168,6 +313,17 @@ impl ParseError {
 }
 
 impl CrawlerError {
+    /// Get the error class for metrics
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            CrawlerError::Fetch(e) => e.class(),
+            CrawlerError::Parse(e) => e.class(),
+            CrawlerError::RateLimited => ErrorClass::Blocked,
+            CrawlerError::InvalidDate(_) => ErrorClass::ParseFailure,
+            CrawlerError::NoArticlesFound => ErrorClass::NotFound,
+        }
+    }
+
     /// Check if this error is recoverable
     pub fn is_recoverable(&self) -> bool {
         match self {
@@ -259,4 +415,45 @@ mod tests {
             "기사를 찾을 수 없음"
         );
     }
+
+    #[test]
+    fn test_error_class() {
+        assert_eq!(FetchError::Timeout.class(), ErrorClass::Timeout);
+        assert_eq!(FetchError::RateLimit.class(), ErrorClass::Blocked);
+        assert_eq!(
+            FetchError::Disallowed("/search".to_string()).class(),
+            ErrorClass::Blocked
+        );
+        assert_eq!(FetchError::ServerError(403).class(), ErrorClass::Blocked);
+        assert_eq!(FetchError::ServerError(404).class(), ErrorClass::NotFound);
+        assert_eq!(
+            FetchError::ServerError(503).class(),
+            ErrorClass::ServerError
+        );
+        assert_eq!(
+            ParseError::ContentNotFound.class(),
+            ErrorClass::ParseFailure
+        );
+        assert_eq!(
+            CrawlerError::Parse(ParseError::ArticleNotFound).class(),
+            ErrorClass::NotFound
+        );
+        assert_eq!(ErrorClass::ParseFailure.as_str(), "parse_failure");
+        assert_eq!(
+            ErrorClass::parse("parse_failure"),
+            Some(ErrorClass::ParseFailure)
+        );
+        assert_eq!(ErrorClass::parse("404"), None);
+    }
+
+    #[test]
+    fn test_error_class_of_anyhow_chain() {
+        let err = anyhow::Error::new(FetchError::ServerError(429)).context("Request failed");
+        assert_eq!(ErrorClass::of(&err), ErrorClass::Blocked);
+
+        let err = anyhow::Error::new(ParseError::TitleNotFound).context("Failed to parse");
+        assert_eq!(ErrorClass::of(&err), ErrorClass::ParseFailure);
+
+        assert_eq!(ErrorClass::of(&anyhow::anyhow!("boom")), ErrorClass::Other);
+    }
 }
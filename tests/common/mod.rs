@@ -17,6 +17,7 @@ pub fn create_test_article() -> ParsedArticle {
         published_at: Some(Utc::now()),
         crawled_at: Utc::now(),
         content_hash: None,
+        ..Default::default()
     }
 }
 
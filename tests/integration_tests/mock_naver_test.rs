@@ -0,0 +1,85 @@
+//! Crawls against the built-in mock Naver server
+//!
+//! Tests the list → article flow on recorded pages:
+//! 1. List page URL collection through the mock site adapter
+//! 2. Parsing of every recorded article format
+//! 3. Deleted and missing articles
+//! 4. Latency and error injection
+
+use baram::crawler::fetcher::NaverFetcher;
+use baram::crawler::list::NewsListCrawler;
+use baram::crawler::mock::{MockNaver, MockNaverConfig};
+use baram::crawler::sites::SiteAdapter;
+use baram::models::NewsCategory;
+use baram::utils::error::{FetchError, ParseError};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// Crawl Flow Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mock_list_and_articles() {
+    let mock = MockNaver::start(MockNaverConfig::default()).await;
+    let site = Arc::new(mock.site());
+    let list = NewsListCrawler::with_site(NaverFetcher::new(100).unwrap(), site.clone());
+
+    let urls = list
+        .collect_urls(NewsCategory::Politics, "20241215", 1)
+        .await
+        .unwrap();
+    assert_eq!(urls.len(), 7, "Recorded list page links 7 articles");
+    assert!(urls.iter().all(|url| url.starts_with(&mock.uri())));
+
+    let fetcher = NaverFetcher::with_config(100, 0, Duration::from_secs(5)).unwrap();
+    let mut titles = Vec::new();
+    for url in &urls {
+        match fetcher.fetch_article(url, 100).await {
+            Ok(html) => match site.parse(&html, url) {
+                Ok(article) => {
+                    assert!(article.url.starts_with("https://n.news.naver.com/"));
+                    titles.push(article.title);
+                }
+                Err(e) => assert!(matches!(e, ParseError::ArticleNotFound), "{url}: {e}"),
+            },
+            Err(e) => assert!(matches!(e, FetchError::ServerError(404)), "{url}: {e}"),
+        }
+    }
+    assert_eq!(titles.len(), 4, "Four recorded article formats parse");
+    assert!(titles.iter().any(|t| t.contains("정치 뉴스 테스트 기사")));
+}
+
+// ============================================================================
+// Fault Injection Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mock_latency() {
+    let mock = MockNaver::start(MockNaverConfig {
+        latency: Duration::from_millis(200),
+        ..Default::default()
+    })
+    .await;
+    let fetcher = NaverFetcher::new(100).unwrap();
+
+    let started = Instant::now();
+    let url = format!("{}/mnews/article/001/0014500001", mock.uri());
+    fetcher.fetch_article(&url, 100).await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_mock_error_injection() {
+    let mock = MockNaver::start(MockNaverConfig {
+        error_rate: 1.0,
+        ..Default::default()
+    })
+    .await;
+    let fetcher = NaverFetcher::with_config(100, 1, Duration::from_secs(5)).unwrap();
+
+    let url = format!("{}/mnews/article/001/0014500001", mock.uri());
+    let result = fetcher.fetch_article(&url, 100).await;
+    assert!(matches!(result, Err(FetchError::MaxRetriesExceeded)));
+    assert_eq!(mock.request_count().await, 2, "One attempt and one retry");
+}
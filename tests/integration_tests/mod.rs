@@ -5,8 +5,11 @@
 //! - Complete crawl → parse → store pipeline
 //! - Distributed crawler coordination
 //! - Error handling and recovery scenarios
+//! - Crawls against the built-in mock Naver server
 
 pub mod distributed_test;
 pub mod error_scenarios;
 pub mod fixtures;
+#[cfg(feature = "mock")]
+pub mod mock_naver_test;
 pub mod pipeline_test;
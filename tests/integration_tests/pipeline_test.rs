@@ -49,6 +49,7 @@ async fn test_pipeline_single_article_success() {
         request_timeout: Duration::from_secs(5),
         crawl_comments: false,
         max_retries: 1,
+        metrics_instance: "test".to_string(),
     };
 
     let pipeline = CrawlerPipeline::new(config).await.unwrap();